@@ -0,0 +1,153 @@
+//! The HTTP API to query the history of auctions.
+use std::{
+    future::{
+        Future,
+        IntoFuture as _,
+    },
+    net::SocketAddr,
+};
+
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use axum::{
+    extract::{
+        Query,
+        State,
+    },
+    response::{
+        IntoResponse,
+        Response,
+    },
+    routing::get,
+    Json,
+    Router,
+};
+use futures::FutureExt as _;
+use http::status::StatusCode;
+use serde::Serialize;
+use tokio_util::sync::CancellationToken;
+use tracing::{
+    instrument,
+    warn,
+};
+
+use crate::history::{
+    self,
+    AuctionRecord,
+    History,
+};
+
+/// Binds the API server to `socket_addr`, returning the future serving it.
+///
+/// Must be called from within a tokio runtime.
+pub(crate) fn serve(
+    socket_addr: &str,
+    history: History,
+    shutdown_token: CancellationToken,
+) -> eyre::Result<Serve> {
+    let app = Router::new()
+        .route("/v1/auctions", get(get_auctions))
+        .with_state(history);
+    let listener = std::net::TcpListener::bind(socket_addr)
+        .wrap_err_with(|| format!("failed to bind TCP socket at `{socket_addr}`"))?;
+    listener
+        .set_nonblocking(true)
+        .wrap_err("failed to set TCP socket to non-blocking")?;
+    let listener = tokio::net::TcpListener::from_std(listener)
+        .wrap_err("failed to register TCP socket with the tokio runtime")?;
+    let serve = axum::serve(listener, app).with_graceful_shutdown(shutdown_token.cancelled_owned());
+    let local_addr = serve
+        .local_addr()
+        .wrap_err("bound TCP listener failed to yield local address")?;
+    Ok(Serve {
+        local_addr,
+        fut: serve.into_future().boxed(),
+    })
+}
+
+/// A wrapper around a type-erased [`axum::Serve::serve`] future.
+pub(crate) struct Serve {
+    local_addr: SocketAddr,
+    fut: futures::future::BoxFuture<'static, std::io::Result<()>>,
+}
+
+impl Serve {
+    pub(crate) fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+}
+
+impl Future for Serve {
+    type Output = std::io::Result<()>;
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        self.fut.as_mut().poll(cx)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct AuctionsBody {
+    auctions: Vec<AuctionRecord>,
+}
+
+/// Handler of a call to `/v1/auctions`.
+///
+/// Returns the most recent auctions, optionally filtered by the hex encoded rollup block hash
+/// the bids were based on and the hex encoded address of a searcher that submitted a bid.
+#[instrument(skip_all)]
+async fn get_auctions(
+    State(history): State<History>,
+    Query(query): Query<history::Query>,
+) -> Result<Json<AuctionsBody>, ApiError> {
+    for (name, value) in [
+        ("rollup_block_hash", &query.rollup_block_hash),
+        ("searcher_address", &query.searcher_address),
+    ] {
+        if let Some(value) = value {
+            if hex::decode(value).is_err() {
+                return Err(ApiError::BadRequest(format!(
+                    "query parameter `{name}` is not hex encoded"
+                )));
+            }
+        }
+    }
+    let auctions = history.query(query).await.map_err(|error| {
+        warn!(%error, "failed to query auction history");
+        ApiError::Internal
+    })?;
+    Ok(Json(AuctionsBody {
+        auctions,
+    }))
+}
+
+enum ApiError {
+    BadRequest(String),
+    Internal,
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        #[derive(Debug, Serialize)]
+        struct ErrorBody {
+            error: String,
+        }
+        let (status, error) = match self {
+            Self::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+            Self::Internal => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "failed to query auction history".to_string(),
+            ),
+        };
+        let mut response = Json(ErrorBody {
+            error,
+        })
+        .into_response();
+        *response.status_mut() = status;
+        response
+    }
+}
@@ -1,5 +1,5 @@
 //! The allocation rule is the mechanism by which the auction processes incoming bids and determines
-//! the winner.
+//! the winner and the price it is charged.
 use std::sync::Arc;
 
 use tracing::{
@@ -7,7 +7,34 @@ use tracing::{
     instrument,
 };
 
-use super::Bid;
+use crate::bid::Bid;
+
+/// Creates the allocation rule selected in the auctioneer config.
+pub(super) fn from_config(rule: crate::config::AllocationRule) -> Box<dyn AllocationRule> {
+    match rule {
+        crate::config::AllocationRule::FirstPrice => Box::new(FirstPrice::new()),
+        crate::config::AllocationRule::SecondPrice => Box::new(SecondPrice::new()),
+    }
+}
+
+/// The winner of an auction.
+pub(super) struct Winner {
+    /// The winning bid.
+    pub(super) bid: Arc<Bid>,
+    /// The price charged to the winning bid.
+    pub(super) price: u64,
+}
+
+pub(super) trait AllocationRule: Send {
+    /// Submit a bid to the auction.
+    fn bid(&mut self, candidate: &Arc<Bid>);
+
+    /// Returns the number of bids submitted to the auction.
+    fn bids_seen(&self) -> usize;
+
+    /// Returns the winner of the auction, if one exists.
+    fn take_winner(&mut self) -> Option<Winner>;
+}
 
 pub(super) struct FirstPrice {
     highest_bid: Option<Arc<Bid>>,
@@ -21,16 +48,15 @@ impl FirstPrice {
             bids_seen: 0,
         }
     }
+}
 
-    /// Submit a bid with a bid.
-    ///
-    /// Returns `true` if the bid is accepted as the highest bid.
+impl AllocationRule for FirstPrice {
     // TODO: identify the incumbant and candidate by their hash?
     #[instrument(skip_all, fields(
         current_winner.bid = self.highest_bid.as_ref().map(|bid| bid.bid()),
         candidate.bid = candidate.bid(),
     ))]
-    pub(super) fn bid(&mut self, candidate: &Arc<Bid>) {
+    fn bid(&mut self, candidate: &Arc<Bid>) {
         self.bids_seen = self.bids_seen.saturating_add(1);
         let winner = if let Some(current) = self.highest_bid.as_mut() {
             if candidate.bid() > current.bid() {
@@ -46,12 +72,127 @@ impl FirstPrice {
         info!("highest bidder is {winner}");
     }
 
-    pub(super) fn bids_seen(&self) -> usize {
+    fn bids_seen(&self) -> usize {
         self.bids_seen
     }
 
-    /// Returns the winner of the auction, if one exists.
-    pub(super) fn take_winner(&mut self) -> Option<Arc<Bid>> {
-        self.highest_bid.take()
+    /// The winner is charged the fee of its own bid.
+    fn take_winner(&mut self) -> Option<Winner> {
+        self.highest_bid.take().map(|bid| Winner {
+            price: bid.bid(),
+            bid,
+        })
+    }
+}
+
+/// A second-price (Vickrey) auction.
+///
+/// The highest bid wins, but is charged the fee of the highest losing bid, so that bidding
+/// one's true valuation is the dominant strategy. A lone bid is charged its own fee, as there
+/// is no losing bid to set the price and the block space must not be sold for nothing.
+pub(super) struct SecondPrice {
+    highest_bid: Option<Arc<Bid>>,
+    runner_up_fee: Option<u64>,
+    bids_seen: usize,
+}
+
+impl SecondPrice {
+    pub(super) fn new() -> Self {
+        Self {
+            highest_bid: None,
+            runner_up_fee: None,
+            bids_seen: 0,
+        }
+    }
+}
+
+impl AllocationRule for SecondPrice {
+    #[instrument(skip_all, fields(
+        current_winner.bid = self.highest_bid.as_ref().map(|bid| bid.bid()),
+        current_runner_up.bid = self.runner_up_fee,
+        candidate.bid = candidate.bid(),
+    ))]
+    fn bid(&mut self, candidate: &Arc<Bid>) {
+        self.bids_seen = self.bids_seen.saturating_add(1);
+        let winner = if let Some(current) = self.highest_bid.as_mut() {
+            if candidate.bid() > current.bid() {
+                self.runner_up_fee = Some(current.bid());
+                *current = candidate.clone();
+                "candidate"
+            } else {
+                self.runner_up_fee = self.runner_up_fee.max(Some(candidate.bid()));
+                "incumbant"
+            }
+        } else {
+            self.highest_bid = Some(candidate.clone());
+            "candidate"
+        };
+        info!("highest bidder is {winner}");
+    }
+
+    fn bids_seen(&self) -> usize {
+        self.bids_seen
+    }
+
+    /// The winner is charged the fee of the highest losing bid, or the fee of its own bid if
+    /// there was no other bid.
+    fn take_winner(&mut self) -> Option<Winner> {
+        let runner_up_fee = self.runner_up_fee.take();
+        self.highest_bid.take().map(|bid| Winner {
+            price: runner_up_fee.unwrap_or_else(|| bid.bid()),
+            bid,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::generated::astria::auction::v1alpha1 as raw;
+
+    use super::*;
+
+    fn bid(fee: u64) -> Arc<Bid> {
+        Arc::new(
+            Bid::try_from_raw(raw::Bid {
+                rollup_parent_block_hash: vec![1; 32].into(),
+                sequencer_parent_block_hash: vec![2; 32].into(),
+                fee,
+                transactions: vec![],
+                searcher_address: vec![3; 20].into(),
+            })
+            .unwrap(),
+        )
+    }
+
+    fn run_auction(mut rule: Box<dyn AllocationRule>, fees: &[u64]) -> Option<(u64, u64)> {
+        for fee in fees {
+            rule.bid(&bid(*fee));
+        }
+        assert_eq!(fees.len(), rule.bids_seen());
+        rule.take_winner()
+            .map(|winner| (winner.bid.bid(), winner.price))
+    }
+
+    #[test]
+    fn first_price_charges_the_winning_bid() {
+        let rule = || from_config(crate::config::AllocationRule::FirstPrice);
+        assert!(run_auction(rule(), &[]).is_none());
+        assert_eq!(Some((30, 30)), run_auction(rule(), &[10, 30, 20]));
+    }
+
+    #[test]
+    fn second_price_charges_the_runner_up_bid() {
+        let rule = || from_config(crate::config::AllocationRule::SecondPrice);
+        assert!(run_auction(rule(), &[]).is_none());
+        assert_eq!(Some((30, 20)), run_auction(rule(), &[10, 30, 20]));
+        assert_eq!(Some((30, 20)), run_auction(rule(), &[20, 10, 30]));
+        assert_eq!(Some((30, 30)), run_auction(rule(), &[30, 30]));
+    }
+
+    #[test]
+    fn second_price_charges_a_lone_bid_its_own_fee() {
+        let rule = || from_config(crate::config::AllocationRule::SecondPrice);
+        assert_eq!(Some((10, 10)), run_auction(rule(), &[10]));
+        assert_eq!(Some((0, 0)), run_auction(rule(), &[0]));
     }
 }
@@ -1,4 +1,6 @@
 //! The auction [`Factory`] to start new auctions.
+use std::sync::Arc;
+
 use astria_core::{
     primitive::v1::{
         asset,
@@ -14,34 +16,37 @@ use tokio_util::sync::CancellationToken;
 
 use super::{
     Auction,
+    Nonces,
     SequencerKey,
     Worker,
 };
-use crate::sequencer_channel::SequencerChannel;
+use crate::{
+    history::History,
+    sequencer_channel::SequencerChannel,
+};
 
-/// The auction `Factory` is used to spawn a new auction.
+/// The auction `Factory` is used to spawn new auctions for a rollup.
 ///
-/// It exposes two methods, `Factory::start_new` to start an
-/// [`Auction`] given a `FilteredSequencerBlock`, and
-/// `Factory::set_last_successful_nonce` to record the
-/// nonce used to submit a winning bid to Sequencer.
-/// This last successful nonce is passed to the auction and
-/// acts as a cached value in case Sequencer does not return
-/// the current pending nonce of the auctioneer account.
+/// It exposes `Factory::start_new` to start an [`Auction`] given a
+/// `FilteredSequencerBlock`. The [`Nonces`] used to submit winning bids
+/// to Sequencer are shared by the factories of all rollups and passed to
+/// each auction.
 pub(in crate::auctioneer) struct Factory {
     pub(in crate::auctioneer) sequencer_abci_client: sequencer_client::HttpClient,
     pub(in crate::auctioneer) sequencer_channel: SequencerChannel,
     pub(in crate::auctioneer) latency_margin: std::time::Duration,
+    pub(in crate::auctioneer) allocation_rule: crate::config::AllocationRule,
+    pub(in crate::auctioneer) sealed_bids: bool,
     pub(in crate::auctioneer) sequencer_key: SequencerKey,
     pub(in crate::auctioneer) fee_asset_denomination: asset::Denom,
     pub(in crate::auctioneer) sequencer_chain_id: String,
     pub(in crate::auctioneer) rollup_id: RollupId,
     pub(in crate::auctioneer) cancellation_token: CancellationToken,
-    /// `last_successful_nonce + 1` is used for submitting an auction winner to Sequencer
-    /// if an auction worker was not able to receive the last pending
-    /// nonce from Sequencer in time. Starts unset at the beginning of the program and
-    /// is set externally via `Factory::set_last_succesful_nonce`.
-    pub(in crate::auctioneer) last_successful_nonce: Option<u32>,
+    /// The nonces used to submit auction winners to Sequencer, shared with the factories of
+    /// all other rollups.
+    pub(in crate::auctioneer) nonces: Arc<Nonces>,
+    /// The history every finished auction is recorded in.
+    pub(in crate::auctioneer) history: History,
     pub(in crate::auctioneer) metrics: &'static crate::Metrics,
 }
 
@@ -64,13 +69,21 @@ impl Factory {
             start_timer: Some(start_timer_rx),
             bids: bids_rx,
             latency_margin: self.latency_margin,
+            allocation_rule: self.allocation_rule,
+            sealed_bids: self.sealed_bids,
             id,
             sequencer_key: self.sequencer_key.clone(),
             fee_asset_denomination: self.fee_asset_denomination.clone(),
             sequencer_chain_id: self.sequencer_chain_id.clone(),
             rollup_id: self.rollup_id,
             cancellation_token: cancellation_token.clone(),
-            last_successful_nonce: self.last_successful_nonce,
+            nonces: self.nonces.clone(),
+            history: self.history.clone(),
+            sequencer_block_hash: block_hash,
+            sequencer_height: height,
+            bid_records: vec![],
+            bids_unprocessed: 0,
+            winner_record: None,
             metrics: self.metrics,
         };
 
@@ -88,8 +101,4 @@ impl Factory {
             started_at: std::time::Instant::now(),
         }
     }
-
-    pub(in crate::auctioneer) fn set_last_successful_nonce(&mut self, nonce: u32) {
-        self.last_successful_nonce.replace(nonce);
-    }
 }
@@ -1,7 +1,4 @@
-use std::{
-    fmt::Display,
-    sync::Arc,
-};
+use std::fmt::Display;
 
 use astria_core::{
     sequencerblock,
@@ -31,8 +28,8 @@ use tracing::instrument;
 
 use crate::{
     bid::{
-        Bid,
         RollupBlockHash,
+        Submission,
     },
     sequencer_key::SequencerKey,
 };
@@ -40,6 +37,9 @@ use crate::{
 pub(super) mod factory;
 pub(super) use factory::Factory;
 mod allocation_rule;
+mod nonces;
+pub(super) use nonces::Nonces;
+mod sealed_bids;
 mod worker;
 use worker::Worker;
 
@@ -81,7 +81,7 @@ pub(super) struct Auction {
     start_timer: Option<oneshot::Sender<()>>,
     /// A channel to forward bids from Auctioneer's stream connected to its Rollup to the
     /// background auction task.
-    bids: mpsc::UnboundedSender<Arc<Bid>>,
+    bids: mpsc::UnboundedSender<Submission>,
     /// Used to cancel the worker task.
     cancellation_token: CancellationToken,
     /// The actual event loop running in the background that receives bids, times the
@@ -108,7 +108,7 @@ impl Auction {
     #[instrument(skip_all, fields(id = %self.id), err)]
     pub(super) fn start_timer(
         &mut self,
-        commitment: sequencerblock::optimistic::v1alpha1::SequencerBlockCommit,
+        commitment: &sequencerblock::optimistic::v1alpha1::SequencerBlockCommit,
     ) -> eyre::Result<()> {
         ensure!(
             &self.block_hash == commitment.block_hash() && self.height == commitment.height(),
@@ -168,7 +168,7 @@ impl Auction {
     ), err)]
     pub(in crate::auctioneer) fn forward_bid_to_auction(
         &mut self,
-        bid: Arc<Bid>,
+        bid: Submission,
     ) -> eyre::Result<()> {
         if let err @ Err(_) = self.does_bid_match_auction(&bid) {
             self.metrics
@@ -184,7 +184,7 @@ impl Auction {
             .wrap_err("failed to submit bid to auction; the bid is lost")
     }
 
-    fn does_bid_match_auction(&self, bid: &Bid) -> eyre::Result<()> {
+    fn does_bid_match_auction(&self, bid: &Submission) -> eyre::Result<()> {
         ensure!(
             &self.block_hash == bid.sequencer_parent_block_hash()
                 && self.hash_of_executed_block_on_rollup.as_ref()
@@ -0,0 +1,129 @@
+//! The nonces used to submit auction winners to Sequencer, shared by the auctions of all rollups.
+use std::{
+    collections::BTreeSet,
+    sync::Mutex,
+    time::Instant,
+};
+
+/// Hands out the nonces used to submit auction winners to Sequencer.
+///
+/// Auctions for different rollups run concurrently and all submit their winners from the same
+/// Sequencer account. The pending nonce each auction fetches from Sequencer does not account for
+/// submissions of other auctions that are still in flight, or that finished after the nonce was
+/// fetched. `Nonces` keeps track of both so that concurrent submissions don't use the same nonce.
+pub(in crate::auctioneer) struct Nonces {
+    inner: Mutex<Inner>,
+}
+
+struct Inner {
+    /// Nonces reserved for submissions that did not yet finish.
+    in_flight: BTreeSet<u32>,
+    /// The nonce of the last successful submission and when it finished.
+    last_successful: Option<(u32, Instant)>,
+}
+
+impl Nonces {
+    pub(in crate::auctioneer) fn new() -> Self {
+        Self {
+            inner: Mutex::new(Inner {
+                in_flight: BTreeSet::new(),
+                last_successful: None,
+            }),
+        }
+    }
+
+    /// Reserves a nonce to submit an auction winner with.
+    ///
+    /// `pending` is the pending nonce returned by Sequencer together with the time the request
+    /// for it was sent, or `None` if Sequencer did not answer in time. The reserved nonce is the
+    /// pending nonce, unless it is taken by an in-flight submission or a submission that
+    /// finished after the request was sent, in which case the nonce following those is used.
+    ///
+    /// Returns `None` if no pending nonce was given and no submission succeeded so far.
+    ///
+    /// Every reserved nonce must be released with [`Nonces::release`] once its submission
+    /// finishes.
+    pub(in crate::auctioneer) fn reserve(&self, pending: Option<(u32, Instant)>) -> Option<u32> {
+        let mut inner = self.inner.lock().expect("the lock is never poisoned");
+        let after_in_flight = inner.in_flight.last().map(|nonce| nonce.saturating_add(1));
+        let after_last_successful =
+            inner
+                .last_successful
+                .and_then(|(nonce, finished_at)| match pending {
+                    Some((_, requested_at)) if finished_at < requested_at => None,
+                    _ => Some(nonce.saturating_add(1)),
+                });
+        let nonce = [
+            pending.map(|(nonce, _)| nonce),
+            after_in_flight,
+            after_last_successful,
+        ]
+        .into_iter()
+        .flatten()
+        .max()?;
+        inner.in_flight.insert(nonce);
+        Some(nonce)
+    }
+
+    /// Releases a nonce previously returned by [`Nonces::reserve`] once its submission finished.
+    pub(in crate::auctioneer) fn release(&self, nonce: u32, succeeded: bool) {
+        let mut inner = self.inner.lock().expect("the lock is never poisoned");
+        inner.in_flight.remove(&nonce);
+        if succeeded {
+            inner.last_successful = Some((nonce, Instant::now()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn no_nonce_without_pending_or_successful_submission() {
+        let nonces = Nonces::new();
+        assert_eq!(None, nonces.reserve(None));
+    }
+
+    #[test]
+    fn concurrent_reservations_do_not_collide() {
+        let nonces = Nonces::new();
+        let requested_at = Instant::now();
+        assert_eq!(Some(5), nonces.reserve(Some((5, requested_at))));
+        assert_eq!(Some(6), nonces.reserve(Some((5, requested_at))));
+        nonces.release(5, true);
+        nonces.release(6, true);
+        // The request was sent before the submissions finished, so its answer is stale.
+        assert_eq!(Some(7), nonces.reserve(Some((5, requested_at))));
+    }
+
+    #[test]
+    fn pending_nonce_is_used_if_requested_after_last_submission() {
+        let nonces = Nonces::new();
+        assert_eq!(Some(5), nonces.reserve(Some((5, Instant::now()))));
+        nonces.release(5, true);
+        let requested_at = Instant::now()
+            .checked_add(Duration::from_millis(1))
+            .unwrap();
+        // The submission with nonce 5 was not executed, so Sequencer returns 5 again.
+        assert_eq!(Some(5), nonces.reserve(Some((5, requested_at))));
+    }
+
+    #[test]
+    fn failed_submissions_are_not_used_as_fallback() {
+        let nonces = Nonces::new();
+        assert_eq!(Some(5), nonces.reserve(Some((5, Instant::now()))));
+        nonces.release(5, false);
+        assert_eq!(None, nonces.reserve(None));
+    }
+
+    #[test]
+    fn last_successful_nonce_is_used_as_fallback() {
+        let nonces = Nonces::new();
+        assert_eq!(Some(5), nonces.reserve(Some((5, Instant::now()))));
+        nonces.release(5, true);
+        assert_eq!(Some(6), nonces.reserve(None));
+    }
+}
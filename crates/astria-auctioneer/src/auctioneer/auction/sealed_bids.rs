@@ -0,0 +1,175 @@
+//! Bookkeeping of sealed bids, which commit to a bid before revealing it.
+//!
+//! A sealed-bid auction has two phases: commitments to bids are accepted until the auction timer
+//! starts, after which bids can only be revealed. Only revealed bids matching a commitment are
+//! submitted to the allocation rule.
+use std::collections::HashMap;
+
+use astria_eyre::eyre::{
+    self,
+    ensure,
+    OptionExt as _,
+};
+
+use crate::bid::{
+    Bid,
+    BidReveal,
+    SealedBid,
+};
+
+pub(super) struct SealedBids {
+    commitments: HashMap<[u8; 32], SealedBid>,
+    accepting_commitments: bool,
+}
+
+impl SealedBids {
+    pub(super) fn new() -> Self {
+        Self {
+            commitments: HashMap::new(),
+            accepting_commitments: true,
+        }
+    }
+
+    /// Records the commitment of a sealed bid.
+    ///
+    /// Fails if the auction no longer accepts commitments.
+    pub(super) fn commit(&mut self, sealed_bid: &SealedBid) -> eyre::Result<()> {
+        ensure!(
+            self.accepting_commitments,
+            "the auction timer has started; sealed bids are no longer accepted"
+        );
+        ensure!(
+            !self.commitments.contains_key(sealed_bid.commitment()),
+            "a sealed bid with the same commitment was already received"
+        );
+        self.commitments
+            .insert(*sealed_bid.commitment(), sealed_bid.clone());
+        Ok(())
+    }
+
+    /// Stops accepting commitments and starts accepting reveals.
+    pub(super) fn close_commitments(&mut self) {
+        self.accepting_commitments = false;
+    }
+
+    /// Opens a bid against its commitment, returning the bid.
+    ///
+    /// Fails if the auction still accepts commitments, if the bid was not committed to, or if the
+    /// revealed bid does not match the parent block hashes and searcher address of its sealed
+    /// bid. Each commitment can only be revealed once.
+    pub(super) fn reveal(&mut self, reveal: BidReveal) -> eyre::Result<Bid> {
+        ensure!(
+            !self.accepting_commitments,
+            "the auction timer has not yet started; bids cannot be revealed yet"
+        );
+        let sealed_bid = self
+            .commitments
+            .remove(&reveal.commitment())
+            .ok_or_eyre("the revealed bid does not match any commitment")?;
+        let bid = reveal.bid();
+        ensure!(
+            bid.rollup_parent_block_hash() == sealed_bid.rollup_parent_block_hash(),
+            "the rollup parent block hash of the revealed bid does not match its sealed bid"
+        );
+        ensure!(
+            bid.sequencer_parent_block_hash() == sealed_bid.sequencer_parent_block_hash(),
+            "the sequencer parent block hash of the revealed bid does not match its sealed bid"
+        );
+        ensure!(
+            bid.searcher_address() == sealed_bid.searcher_address(),
+            "the searcher address of the revealed bid does not match its sealed bid"
+        );
+        Ok(reveal.into_bid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::generated::astria::auction::v1alpha1 as raw;
+    use prost::Message as _;
+    use sha2::{
+        Digest as _,
+        Sha256,
+    };
+
+    use super::*;
+    use crate::bid::Submission;
+
+    const SALT: &[u8] = b"salt";
+
+    fn raw_bid(fee: u64) -> raw::Bid {
+        raw::Bid {
+            rollup_parent_block_hash: vec![1; 32].into(),
+            sequencer_parent_block_hash: vec![2; 32].into(),
+            fee,
+            transactions: vec![vec![4; 8].into()],
+            searcher_address: vec![3; 20].into(),
+        }
+    }
+
+    fn sealed(bid: &raw::Bid, searcher_address: Vec<u8>) -> SealedBid {
+        let commitment: [u8; 32] = Sha256::new()
+            .chain_update(bid.encode_to_vec())
+            .chain_update(SALT)
+            .finalize()
+            .into();
+        let value = raw::get_bid_stream_response::Value::SealedBid(raw::SealedBid {
+            rollup_parent_block_hash: bid.rollup_parent_block_hash.clone(),
+            sequencer_parent_block_hash: bid.sequencer_parent_block_hash.clone(),
+            commitment: commitment.to_vec().into(),
+            searcher_address: searcher_address.into(),
+        });
+        let Submission::Sealed(sealed_bid) = Submission::try_from_raw(value).unwrap() else {
+            panic!("expected a sealed bid");
+        };
+        sealed_bid
+    }
+
+    fn reveal(bid: raw::Bid) -> BidReveal {
+        let value = raw::get_bid_stream_response::Value::BidReveal(raw::BidReveal {
+            bid: Some(bid),
+            salt: SALT.to_vec().into(),
+        });
+        let Submission::Reveal(reveal) = Submission::try_from_raw(value).unwrap() else {
+            panic!("expected a bid reveal");
+        };
+        reveal
+    }
+
+    #[test]
+    fn reveal_of_committed_bid_succeeds_once() {
+        let mut sealed_bids = SealedBids::new();
+        sealed_bids
+            .commit(&sealed(&raw_bid(10), vec![3; 20]))
+            .unwrap();
+        sealed_bids.close_commitments();
+
+        let bid = sealed_bids.reveal(reveal(raw_bid(10))).unwrap();
+        assert_eq!(10, bid.bid());
+        assert!(sealed_bids.reveal(reveal(raw_bid(10))).is_err());
+    }
+
+    #[test]
+    fn reveal_with_different_transactions_is_rejected() {
+        let mut sealed_bids = SealedBids::new();
+        sealed_bids
+            .commit(&sealed(&raw_bid(10), vec![3; 20]))
+            .unwrap();
+        sealed_bids.close_commitments();
+
+        let mut swapped = raw_bid(10);
+        swapped.transactions = vec![vec![5; 8].into()];
+        assert!(sealed_bids.reveal(reveal(swapped)).is_err());
+    }
+
+    #[test]
+    fn reveal_not_matching_sealed_bid_is_rejected() {
+        let mut sealed_bids = SealedBids::new();
+        sealed_bids
+            .commit(&sealed(&raw_bid(10), vec![9; 20]))
+            .unwrap();
+        sealed_bids.close_commitments();
+
+        assert!(sealed_bids.reveal(reveal(raw_bid(10))).is_err());
+    }
+}
@@ -3,7 +3,10 @@ use std::{
     future::Future,
     pin::pin,
     sync::Arc,
-    time::Duration,
+    time::{
+        Duration,
+        Instant,
+    },
 };
 
 use astria_core::{
@@ -13,7 +16,9 @@ use astria_core::{
         RollupId,
     },
     protocol::transaction::v1::Transaction,
+    sequencerblock::v1::block,
 };
+use astria_eyre::eyre;
 use futures::FutureExt as _;
 use sequencer_client::{
     tendermint_rpc::endpoint::broadcast::tx_sync,
@@ -36,16 +41,31 @@ use tracing::{
     error,
     info,
     instrument,
+    warn,
     Instrument as _,
     Level,
 };
 
 use super::{
-    allocation_rule::FirstPrice,
+    allocation_rule::{
+        self,
+        AllocationRule,
+        Winner,
+    },
+    sealed_bids::SealedBids,
+    Nonces,
     Summary,
 };
 use crate::{
-    bid::Bid,
+    bid::Submission,
+    history::{
+        AuctionRecord,
+        BidKind,
+        BidRecord,
+        History,
+        Outcome,
+        WinnerRecord,
+    },
     sequencer_channel::SequencerChannel,
     sequencer_key::SequencerKey,
 };
@@ -59,9 +79,13 @@ pub(super) struct Worker {
     pub(super) start_bids: Option<oneshot::Receiver<()>>,
     pub(super) start_timer: Option<oneshot::Receiver<()>>,
     /// Channel for receiving new bids.
-    pub(super) bids: tokio::sync::mpsc::UnboundedReceiver<Arc<Bid>>,
+    pub(super) bids: tokio::sync::mpsc::UnboundedReceiver<Submission>,
     /// The time between receiving a block commitment
     pub(super) latency_margin: Duration,
+    /// The rule determining the winner of the auction and the price charged to it
+    pub(super) allocation_rule: crate::config::AllocationRule,
+    /// Whether only sealed bids are accepted
+    pub(super) sealed_bids: bool,
     /// The ID of the auction
     pub(super) id: super::Id,
     /// The key used to sign transactions on the sequencer
@@ -73,12 +97,24 @@ pub(super) struct Worker {
     /// Rollup ID to submit the auction result to
     pub(super) rollup_id: RollupId,
     pub(super) cancellation_token: CancellationToken,
-    /// `last_successful_nonce + 1` is used for submitting an auction winner to
+    /// The nonces used to submit auction winners, shared with the auctions of all rollups.
+    /// Falls back to `last_successful_nonce + 1` for submitting an auction winner to
     /// Sequencer if the worker was not able to receive the last pending nonce
     /// from Sequencer in time (in time = by the time the winner was ready to be
-    /// submitted). Is usually only unset if no auction was yet submitted (for example
-    /// at the beginning of the program).
-    pub(super) last_successful_nonce: Option<u32>,
+    /// submitted).
+    pub(super) nonces: Arc<Nonces>,
+    /// The history the auction is recorded in once it finished
+    pub(super) history: History,
+    /// The hash of the proposed Sequencer block that triggered the auction
+    pub(super) sequencer_block_hash: block::Hash,
+    /// The height of the proposed Sequencer block that triggered the auction
+    pub(super) sequencer_height: u64,
+    /// All bids processed by the auction, in the order they were received
+    pub(super) bid_records: Vec<BidRecord>,
+    /// The number of bids left unprocessed when the auction timer was up
+    pub(super) bids_unprocessed: usize,
+    /// The winner of the auction, once determined
+    pub(super) winner_record: Option<WinnerRecord>,
     pub(super) metrics: &'static crate::Metrics,
 }
 
@@ -87,11 +123,17 @@ impl Worker {
     // See this discussion: https://github.com/tokio-rs/tracing/discussions/1906
     #[instrument(
         skip_all,
-        fields(id = %self.id),
+        fields(id = %self.id, rollup_id = %self.rollup_id),
         err(level = Level::WARN, Display),
         ret(Display),
     )]
     pub(super) async fn run(mut self) -> Result<Summary, Error> {
+        let result = self.run_auction().await;
+        self.record(&result).await;
+        result
+    }
+
+    async fn run_auction(&mut self) -> Result<Summary, Error> {
         let Some(auction_result) = self
             .cancellation_token
             .clone()
@@ -108,39 +150,41 @@ impl Worker {
             return Ok(Summary::NoBids);
         };
 
-        let nonce_fetch = nonce_fetch.expect(
+        let (nonce_requested_at, nonce_fetch) = nonce_fetch.expect(
             "if the auction loop produced a winner, then a nonce fetch must have been spawned",
         );
 
-        let pending_nonce = match nonce_fetch.now_or_never() {
-            Some(Ok(nonce)) => nonce,
+        let fetched_nonce = match nonce_fetch.now_or_never() {
+            Some(Ok(nonce)) => Some(nonce),
             Some(Err(source)) => {
                 return Err(Error::NonceFetchPanicked {
                     source,
                 });
             }
-            None if self.last_successful_nonce.is_some() => {
-                let nonce = self
-                    .last_successful_nonce
-                    .expect("in arm that checks for last_successful_nonce == Some")
-                    .saturating_add(1);
+            None => {
                 info!(
-                    "request for latest pending nonce did not return in time; using `{nonce}`
-                    instead (last successful nonce + 1)"
+                    "request for latest pending nonce did not return in time; falling back to \
+                     last successful nonce + 1"
                 );
-                nonce
+                None
             }
-            None => return Err(Error::NoNonce),
+        };
+        let Some(pending_nonce) = self
+            .nonces
+            .reserve(fetched_nonce.map(|nonce| (nonce, nonce_requested_at)))
+        else {
+            return Err(Error::NoNonce);
         };
 
         // TODO: report the pending nonce that we ended up using.
-        let transaction = Arc::unwrap_or_clone(winner)
+        let transaction = Arc::unwrap_or_clone(winner.bid)
+            .with_price(winner.price)
             .into_transaction_body(
                 pending_nonce,
                 self.rollup_id,
                 &self.sequencer_key,
                 self.fee_asset_denomination.clone(),
-                self.sequencer_chain_id,
+                self.sequencer_chain_id.clone(),
             )
             .sign(self.sequencer_key.signing_key());
 
@@ -157,7 +201,7 @@ impl Worker {
         tokio::pin!(submission_fut);
 
         let submission_start = std::time::Instant::now();
-        loop {
+        let result = loop {
             select!(
                 () = self.cancellation_token.clone().cancelled_owned(),
                     if !self.cancellation_token.is_cancelled() =>
@@ -182,13 +226,54 @@ impl Worker {
                     }
                 }
             );
+        };
+        let succeeded = matches!(
+            &result,
+            Ok(Summary::Submitted { response, .. }) if response.code.is_ok()
+        );
+        self.nonces.release(pending_nonce, succeeded);
+        result
+    }
+
+    /// Records the auction and its outcome in the auction history.
+    ///
+    /// Failing to record the auction is not fatal and only emits a warning.
+    async fn record(&mut self, result: &Result<Summary, Error>) {
+        let outcome = match result {
+            Ok(Summary::CancelledDuringAuction) => Outcome::Cancelled,
+            Ok(Summary::NoBids) => Outcome::NoBids,
+            Ok(Summary::Submitted {
+                response,
+                nonce_used,
+            }) => Outcome::Submitted {
+                tx_hash: hex::encode(response.hash.as_bytes()),
+                nonce: *nonce_used,
+                code: response.code.value(),
+                log: response.log.clone(),
+            },
+            Err(error) => Outcome::Failed {
+                reason: error.to_string(),
+            },
+        };
+        let record = AuctionRecord {
+            auction_id: self.id.to_string(),
+            rollup_id: self.rollup_id.to_string(),
+            sequencer_block_hash: hex::encode(self.sequencer_block_hash.as_bytes()),
+            sequencer_height: self.sequencer_height,
+            bids: std::mem::take(&mut self.bid_records),
+            bids_unprocessed: self.bids_unprocessed,
+            winner: self.winner_record.take(),
+            outcome,
+        };
+        if let Err(error) = self.history.insert(record).await {
+            warn!(%error, "failed to record auction in history");
         }
     }
 
     async fn run_auction_loop(&mut self) -> Result<AuctionItems, Error> {
         let mut latency_margin_timer = pin!(None::<Sleep>);
-        // TODO: do we want to make this configurable to allow for more complex allocation rules?
-        let mut allocation_rule = FirstPrice::new();
+        let mut allocation_rule = allocation_rule::from_config(self.allocation_rule);
+        let mut sealed_bids = self.sealed_bids.then(SealedBids::new);
         let mut auction_is_open = false;
 
         let mut nonce_fetch = None;
@@ -208,12 +293,18 @@ impl Worker {
                 }, if latency_margin_timer.is_some() => {
                     info!("timer is up; bids left unprocessed: {}", self.bids.len());
 
+                    self.bids_unprocessed = self.bids.len();
                     self.metrics.record_bids_per_auction_dropped_histogram(self.bids.len());
                     self.metrics.record_bids_per_auction_processed_histogram(allocation_rule.bids_seen());
 
                     let winner = allocation_rule.take_winner();
                     if let Some(winner) = &winner {
-                        self.metrics.record_auction_winning_bid_histogram(winner.bid());
+                        self.metrics.record_auction_winning_bid_histogram(winner.bid.bid());
+                        self.winner_record = Some(WinnerRecord {
+                            searcher_address: hex::encode(winner.bid.searcher_address()),
+                            fee: winner.bid.bid(),
+                            price: winner.price,
+                        });
                     }
 
                     break Ok(AuctionItems {
@@ -250,11 +341,14 @@ impl Worker {
                         );
                     }
 
+                    if let Some(sealed_bids) = &mut sealed_bids {
+                        sealed_bids.close_commitments();
+                    }
                     latency_margin_timer.set(Some(sleep(self.latency_margin)));
-                    nonce_fetch = Some(spawn_aborting(get_pending_nonce(
+                    nonce_fetch = Some((Instant::now(), spawn_aborting(get_pending_nonce(
                         self.sequencer_channel.clone(),
                         *self.sequencer_key.address(),
-                    ).in_current_span()));
+                    ).in_current_span())));
                     info!(
                         duration = %astria_telemetry::display::format_duration(self.latency_margin),
                         "started auction timer and request for latest nonce",
@@ -263,7 +357,16 @@ impl Worker {
 
                 // TODO: this is an unbounded channel. Can we process multiple bids at a time?
                 Some(bid) = self.bids.recv(), if auction_is_open => {
-                    allocation_rule.bid(&bid);
+                    let mut record = bid_record(&bid);
+                    if let Err(error) = submit_bid(
+                        bid,
+                        allocation_rule.as_mut(),
+                        sealed_bids.as_mut(),
+                    ) {
+                        info!(%error, "rejected bid");
+                        record.rejection_reason = Some(error.to_string());
+                    }
+                    self.bid_records.push(record);
                 }
 
                 else => {
@@ -305,9 +408,52 @@ where
     AbortJoinHandle(tokio::spawn(fut))
 }
 
+/// Submits `bid` to the allocation rule, going through `sealed_bids` if set.
+///
+/// If `sealed_bids` is set, open bids are rejected, sealed bids are only recorded, and revealed
+/// bids are submitted to the allocation rule if they match a previously recorded sealed bid. If
+/// `sealed_bids` is not set, only open bids are accepted.
+fn submit_bid(
+    bid: Submission,
+    allocation_rule: &mut dyn AllocationRule,
+    sealed_bids: Option<&mut SealedBids>,
+) -> eyre::Result<()> {
+    match (bid, sealed_bids) {
+        (Submission::Open(bid), None) => allocation_rule.bid(&Arc::new(bid)),
+        (Submission::Sealed(sealed_bid), Some(sealed_bids)) => sealed_bids.commit(&sealed_bid)?,
+        (Submission::Reveal(reveal), Some(sealed_bids)) => {
+            allocation_rule.bid(&Arc::new(sealed_bids.reveal(reveal)?));
+        }
+        (Submission::Open(_), Some(_)) => {
+            eyre::bail!("the auction only accepts sealed bids but received an open bid")
+        }
+        (Submission::Sealed(_) | Submission::Reveal(_), None) => {
+            eyre::bail!("the auction only accepts open bids but received a sealed or revealed bid")
+        }
+    }
+    Ok(())
+}
+
+/// Creates the audit record of `bid`, assuming it was accepted.
+fn bid_record(bid: &Submission) -> BidRecord {
+    let kind = match bid {
+        Submission::Open(_) => BidKind::Open,
+        Submission::Sealed(_) => BidKind::Sealed,
+        Submission::Reveal(_) => BidKind::Reveal,
+    };
+    BidRecord {
+        kind,
+        searcher_address: hex::encode(bid.searcher_address()),
+        rollup_parent_block_hash: hex::encode(bid.rollup_parent_block_hash().as_bytes()),
+        fee: bid.fee(),
+        rejection_reason: None,
+    }
+}
+
 struct AuctionItems {
-    winner: Option<Arc<Bid>>,
-    nonce_fetch: Option<AbortJoinHandle<u32>>,
+    winner: Option<Winner>,
+    /// The time the nonce was requested, and the task fetching it.
+    nonce_fetch: Option<(Instant, AbortJoinHandle<u32>)>,
 }
 
 /// A wrapper around [`JoinHandle`] that aborts the task rather than disassocating.
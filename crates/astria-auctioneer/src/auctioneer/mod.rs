@@ -1,6 +1,9 @@
 //! The Astria Auctioneer business logic.
 use std::{
+    collections::HashMap,
+    future::Future,
     sync::Arc,
+    task::Poll,
     time::Duration,
 };
 
@@ -13,14 +16,17 @@ use astria_core::{
 };
 use astria_eyre::eyre::{
     self,
+    eyre,
     OptionExt as _,
     WrapErr as _,
 };
 use futures::{
     stream::FuturesUnordered,
+    FutureExt as _,
     StreamExt as _,
 };
 use tokio::select;
+use tokio_stream::StreamMap;
 use tokio_util::sync::CancellationToken;
 use tracing::{
     error,
@@ -47,16 +53,20 @@ use crate::{
 mod auction;
 
 /// The implementation of the auctioneer business logic.
+///
+/// Auctions for each configured rollup run independently of each other, each with their own
+/// streams of proposed blocks, executed blocks and bids. Block commitments are shared by all
+/// rollups because they are all derived from the same Sequencer block.
 pub(super) struct Auctioneer {
-    auction_factory: auction::Factory,
+    api: crate::api::Serve,
+    auction_factories: HashMap<RollupId, auction::Factory>,
     block_commitments: BlockCommitmentStream,
-    bids: BidStream,
+    bids: StreamMap<RollupId, BidStream>,
     cancelled_auctions: FuturesUnordered<auction::Auction>,
     metrics: &'static crate::Metrics,
-    executed_blocks: ExecuteOptimisticBlockStream,
-    running_auction: Option<auction::Auction>,
-    proposed_blocks: ProposedBlockStream,
-    rollup_id: RollupId,
+    executed_blocks: StreamMap<RollupId, ExecuteOptimisticBlockStream>,
+    running_auctions: HashMap<RollupId, auction::Auction>,
+    proposed_blocks: StreamMap<RollupId, ProposedBlockStream>,
     shutdown_token: CancellationToken,
 }
 
@@ -67,21 +77,23 @@ impl Auctioneer {
         metrics: &'static crate::Metrics,
         shutdown_token: CancellationToken,
     ) -> eyre::Result<Self> {
+        let rollups = config
+            .parse_rollups()
+            .wrap_err("failed to parse rollups from config")?;
         let Config {
             sequencer_grpc_endpoint,
             sequencer_abci_endpoint,
-            latency_margin_ms,
-            rollup_grpc_endpoint,
-            rollup_id,
+            allocation_rule,
+            sealed_bids,
             sequencer_chain_id,
             sequencer_private_key_path,
             sequencer_address_prefix,
             fee_asset_denomination,
+            auction_history_path,
+            api_addr,
             ..
         } = config;
 
-        let rollup_id = RollupId::from_unhashed_bytes(rollup_id);
-        let rollup_channel = crate::rollup_channel::open(&rollup_grpc_endpoint)?;
         let sequencer_channel = crate::sequencer_channel::open(&sequencer_grpc_endpoint)?;
 
         let sequencer_key = SequencerKey::builder()
@@ -95,29 +107,63 @@ impl Auctioneer {
             sequencer_client::HttpClient::new(sequencer_abci_endpoint.as_str())
                 .wrap_err("failed constructing sequencer abci client")?;
 
-        let auction_factory = auction::Factory {
-            sequencer_abci_client,
-            sequencer_channel: sequencer_channel.clone(),
-            latency_margin: Duration::from_millis(latency_margin_ms),
-            sequencer_key: sequencer_key.clone(),
-            fee_asset_denomination,
-            sequencer_chain_id,
-            rollup_id,
-            cancellation_token: shutdown_token.child_token(),
-            last_successful_nonce: None,
-            metrics,
-        };
+        let nonces = Arc::new(auction::Nonces::new());
+
+        let history = crate::history::History::open(&auction_history_path)
+            .wrap_err("failed to open auction history")?;
+        let api = crate::api::serve(&api_addr, history.clone(), shutdown_token.child_token())
+            .wrap_err("failed to start auction history API server")?;
+        info!(api_addr = %api.local_addr(), "serving auction history API");
+
+        let mut auction_factories = HashMap::new();
+        let mut bids = StreamMap::new();
+        let mut executed_blocks = StreamMap::new();
+        let mut proposed_blocks = StreamMap::new();
+        for rollup in rollups {
+            let rollup_id = RollupId::from_unhashed_bytes(&rollup.name);
+            let rollup_channel = crate::rollup_channel::open(&rollup.grpc_endpoint)
+                .wrap_err_with(|| format!("failed to open channel to rollup `{}`", rollup.name))?;
+            info!(rollup_name = %rollup.name, %rollup_id, "running auctions for rollup");
+
+            auction_factories.insert(
+                rollup_id,
+                auction::Factory {
+                    sequencer_abci_client: sequencer_abci_client.clone(),
+                    sequencer_channel: sequencer_channel.clone(),
+                    latency_margin: Duration::from_millis(rollup.latency_margin_ms),
+                    allocation_rule,
+                    sealed_bids,
+                    sequencer_key: sequencer_key.clone(),
+                    fee_asset_denomination: fee_asset_denomination.clone(),
+                    sequencer_chain_id: sequencer_chain_id.clone(),
+                    rollup_id,
+                    cancellation_token: shutdown_token.child_token(),
+                    nonces: nonces.clone(),
+                    history: history.clone(),
+                    metrics,
+                },
+            );
+            bids.insert(rollup_id, rollup_channel.open_bid_stream());
+            executed_blocks.insert(
+                rollup_id,
+                rollup_channel.open_execute_optimistic_block_stream(),
+            );
+            proposed_blocks.insert(
+                rollup_id,
+                sequencer_channel.open_get_proposed_block_stream(rollup_id),
+            );
+        }
 
         Ok(Self {
-            auction_factory,
+            api,
+            auction_factories,
             block_commitments: sequencer_channel.open_get_block_commitment_stream(),
-            bids: rollup_channel.open_bid_stream(),
+            bids,
             cancelled_auctions: FuturesUnordered::new(),
-            executed_blocks: rollup_channel.open_execute_optimistic_block_stream(),
+            executed_blocks,
             metrics,
-            proposed_blocks: sequencer_channel.open_get_proposed_block_stream(rollup_id),
-            rollup_id,
-            running_auction: None,
+            proposed_blocks,
+            running_auctions: HashMap::new(),
             shutdown_token,
         })
     }
@@ -135,6 +181,13 @@ impl Auctioneer {
                         break Ok("received shutdown signal");
                     },
 
+                    res = &mut self.api => {
+                        break match res {
+                            Ok(()) => Err(eyre!("auction history API server exited unexpectedly")),
+                            Err(err) => Err(err).wrap_err("auction history API server exited with error"),
+                        };
+                    }
+
                     res = self.handle_event() => {
                         if let Err(err) = res {
                             break Err(err);
@@ -150,8 +203,8 @@ impl Auctioneer {
     async fn handle_event(&mut self) -> eyre::Result<()> {
         select!(
             res = self.proposed_blocks.next() => {
-                let res = res.ok_or_eyre("proposed block stream closed")?;
-                let _ = self.handle_proposed_block(res);
+                let (rollup_id, res) = res.ok_or_eyre("proposed block streams closed")?;
+                let _ = self.handle_proposed_block(rollup_id, res);
             },
 
             res = self.block_commitments.next() => {
@@ -160,16 +213,16 @@ impl Auctioneer {
             },
 
             res = self.executed_blocks.next() => {
-                let res = res.ok_or_eyre("executed block stream closed")?;
-                let _ = self.handle_executed_block(res);
+                let (rollup_id, res) = res.ok_or_eyre("executed block streams closed")?;
+                let _ = self.handle_executed_block(rollup_id, res);
             }
 
-            (id, res) = async { self.running_auction.as_mut().unwrap().await }, if self.running_auction.is_some() => {
-                let _ = self.handle_completed_auction(id, res);
+            (rollup_id, (id, res)) = next_completed(&mut self.running_auctions), if !self.running_auctions.is_empty() => {
+                let _ = self.handle_completed_auction(rollup_id, id, res);
             }
 
-            Some(res) = self.bids.next() => {
-                let _ = self.handle_bids(res);
+            Some((rollup_id, res)) = self.bids.next() => {
+                let _ = self.handle_bids(rollup_id, res);
             }
 
              Some((id, res)) = self.cancelled_auctions.next() => {
@@ -184,19 +237,14 @@ impl Auctioneer {
     /// This method exists to ensure that panicking auctions receive an event.
     /// It is assumed that auctions that ran to completion (returning a success or failure)
     /// will emit an event in their own span.
-    #[instrument(skip_all, fields(%auction_id), err)]
+    #[instrument(skip_all, fields(%rollup_id, %auction_id), err)]
     fn handle_completed_auction(
         &mut self,
+        rollup_id: RollupId,
         auction_id: auction::Id,
         res: Result<auction::Summary, auction::Error>,
     ) -> Result<auction::Summary, auction::Error> {
-        if let Ok(auction::Summary::Submitted {
-            nonce_used, ..
-        }) = &res
-        {
-            self.auction_factory.set_last_successful_nonce(*nonce_used);
-        }
-        let _ = self.running_auction.take();
+        let _ = self.running_auctions.remove(&rollup_id);
         res
     }
 
@@ -214,9 +262,10 @@ impl Auctioneer {
         res
     }
 
-    #[instrument(skip_all, fields(block_hash = field::Empty), err)]
+    #[instrument(skip_all, fields(%rollup_id, block_hash = field::Empty), err)]
     fn handle_proposed_block(
         &mut self,
+        rollup_id: RollupId,
         proposed_block: eyre::Result<FilteredSequencerBlock>,
     ) -> eyre::Result<()> {
         let proposed_block =
@@ -225,10 +274,14 @@ impl Auctioneer {
 
         self.metrics.increment_proposed_blocks_received_counter();
 
-        let new_auction = self.auction_factory.start_new(&proposed_block);
+        let new_auction = self
+            .auction_factories
+            .get(&rollup_id)
+            .ok_or_else(|| eyre!("no auction factory exists for the rollup; this is a bug"))?
+            .start_new(&proposed_block);
         info!(auction_id = %new_auction.id(), "started new auction");
 
-        if let Some(old_auction) = self.running_auction.replace(new_auction) {
+        if let Some(old_auction) = self.running_auctions.insert(rollup_id, new_auction) {
             old_auction.cancel();
             self.metrics.increment_auctions_cancelled_count();
             info!(auction_id = %old_auction.id(), "cancelled running auction");
@@ -237,10 +290,14 @@ impl Auctioneer {
 
         // TODO: do conversion && sending in one operation
         let base_block = crate::block::Proposed::new(proposed_block)
-            .try_into_base_block(self.rollup_id)
+            .try_into_base_block(rollup_id)
             // FIXME: give this their proper wire names
             .wrap_err("failed to create BaseBlock from FilteredSequencerBlock")?;
         self.executed_blocks
+            .iter_mut()
+            .find(|(id, _)| *id == rollup_id)
+            .map(|(_, stream)| stream)
+            .ok_or_else(|| eyre!("no execution stream exists for the rollup; this is a bug"))?
             .try_send(base_block)
             .wrap_err("failed to forward block to execution stream")?;
 
@@ -257,24 +314,30 @@ impl Auctioneer {
 
         self.metrics.increment_block_commitments_received_counter();
 
-        if let Some(running_auction) = &mut self.running_auction {
-            running_auction
-                .start_timer(block_commitment)
-                .wrap_err("failed to start timer")?;
-            info!(auction_id = %running_auction.id(), "started auction timer");
-        } else {
+        if self.running_auctions.is_empty() {
             info!(
                 "received a block commitment but did not start auction timer because no auction \
                  was running"
             );
         }
+        // Errors are emitted in the span of `start_timer`.
+        for (rollup_id, running_auction) in &mut self.running_auctions {
+            if running_auction.start_timer(&block_commitment).is_ok() {
+                info!(
+                    %rollup_id,
+                    auction_id = %running_auction.id(),
+                    "started auction timer",
+                );
+            }
+        }
 
         Ok(())
     }
 
-    #[instrument(skip_all, fields(block_hash = field::Empty), err)]
+    #[instrument(skip_all, fields(%rollup_id, block_hash = field::Empty), err)]
     fn handle_executed_block(
         &mut self,
+        rollup_id: RollupId,
         executed_block: eyre::Result<crate::block::Executed>,
     ) -> eyre::Result<()> {
         let executed_block = executed_block.wrap_err("failed to receive executed block")?;
@@ -285,7 +348,7 @@ impl Auctioneer {
 
         self.metrics.increment_executed_blocks_received_counter();
 
-        if let Some(running_auction) = &mut self.running_auction {
+        if let Some(running_auction) = self.running_auctions.get_mut(&rollup_id) {
             running_auction
                 .start_bids(executed_block)
                 .wrap_err("failed to start processing bids")?;
@@ -302,9 +365,13 @@ impl Auctioneer {
         Ok(())
     }
 
-    #[instrument(skip_all, fields(block_hash = field::Empty), err)]
-    fn handle_bids(&mut self, bid: eyre::Result<crate::bid::Bid>) -> eyre::Result<()> {
-        let bid = Arc::new(bid.wrap_err("received problematic bid")?);
+    #[instrument(skip_all, fields(%rollup_id, block_hash = field::Empty), err)]
+    fn handle_bids(
+        &mut self,
+        rollup_id: RollupId,
+        bid: eyre::Result<crate::bid::Submission>,
+    ) -> eyre::Result<()> {
+        let bid = bid.wrap_err("received problematic bid")?;
         Span::current().record(
             "block_hash",
             field::display(bid.sequencer_parent_block_hash()),
@@ -312,7 +379,7 @@ impl Auctioneer {
 
         self.metrics.increment_auction_bids_received_counter();
 
-        if let Some(running_auction) = &mut self.running_auction {
+        if let Some(running_auction) = self.running_auctions.get_mut(&rollup_id) {
             running_auction
                 .forward_bid_to_auction(bid)
                 .wrap_err("failed to forward bid to auction")?;
@@ -345,9 +412,23 @@ impl Auctioneer {
             Ok(reason) => info!(%reason, message),
             Err(reason) => error!(%reason, message),
         };
-        if let Some(running_auction) = self.running_auction.take() {
+        for (_, running_auction) in self.running_auctions.drain() {
             running_auction.abort();
         }
         reason.map(|_| ())
     }
 }
+
+/// Resolves to the rollup ID and output of the first of `auctions` to complete.
+fn next_completed(
+    auctions: &mut HashMap<RollupId, auction::Auction>,
+) -> impl Future<Output = (RollupId, <auction::Auction as Future>::Output)> + '_ {
+    std::future::poll_fn(move |cx| {
+        for (rollup_id, auction) in auctions.iter_mut() {
+            if let Poll::Ready(output) = auction.poll_unpin(cx) {
+                return Poll::Ready((*rollup_id, output));
+            }
+        }
+        Poll::Pending
+    })
+}
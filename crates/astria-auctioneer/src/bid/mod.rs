@@ -18,6 +18,7 @@ use astria_core::{
 };
 use astria_eyre::eyre::{
     self,
+    eyre,
     WrapErr as _,
 };
 use bytes::Bytes;
@@ -25,6 +26,10 @@ use prost::{
     Message as _,
     Name,
 };
+use sha2::{
+    Digest as _,
+    Sha256,
+};
 
 use crate::sequencer_key::SequencerKey;
 
@@ -79,6 +84,8 @@ pub(crate) struct Bid {
     /// The hash of the sequencer block used to derive the rollup block that this bid is based
     /// on.
     sequencer_parent_block_hash: block::Hash,
+    /// The address of the searcher on the rollup that submitted the bid.
+    searcher_address: Bytes,
 }
 
 impl Bid {
@@ -88,6 +95,7 @@ impl Bid {
             transactions,
             sequencer_parent_block_hash,
             rollup_parent_block_hash,
+            searcher_address,
         } = raw;
         Ok(Self {
             fee,
@@ -97,6 +105,7 @@ impl Bid {
                 .as_ref()
                 .try_into()
                 .wrap_err("invalid field .sequencer_parent_block_hash")?,
+            searcher_address,
         })
     }
 
@@ -110,6 +119,7 @@ impl Bid {
             rollup_parent_block_hash: Bytes::copy_from_slice(
                 self.rollup_parent_block_hash.as_bytes(),
             ),
+            searcher_address: self.searcher_address,
         }
     }
 
@@ -141,6 +151,15 @@ impl Bid {
         self.fee
     }
 
+    /// Sets the fee of the bid to `price`, the amount charged to the bid as the auction winner.
+    #[must_use]
+    pub(crate) fn with_price(self, price: u64) -> Self {
+        Self {
+            fee: price,
+            ..self
+        }
+    }
+
     pub(crate) fn rollup_parent_block_hash(&self) -> &RollupBlockHash {
         &self.rollup_parent_block_hash
     }
@@ -148,6 +167,166 @@ impl Bid {
     pub(crate) fn sequencer_parent_block_hash(&self) -> &block::Hash {
         &self.sequencer_parent_block_hash
     }
+
+    pub(crate) fn searcher_address(&self) -> &[u8] {
+        &self.searcher_address
+    }
+}
+
+/// A commitment to a bid that is revealed later in a sealed-bid auction.
+#[derive(Debug, Clone)]
+pub(crate) struct SealedBid {
+    commitment: [u8; 32],
+    rollup_parent_block_hash: RollupBlockHash,
+    sequencer_parent_block_hash: block::Hash,
+    searcher_address: Bytes,
+}
+
+impl SealedBid {
+    fn try_from_raw(raw: raw::SealedBid) -> eyre::Result<Self> {
+        let raw::SealedBid {
+            rollup_parent_block_hash,
+            sequencer_parent_block_hash,
+            commitment,
+            searcher_address,
+        } = raw;
+        Ok(Self {
+            commitment: commitment.as_ref().try_into().map_err(|_| {
+                eyre!(
+                    "invalid field .commitment: expected 32 bytes, got {}",
+                    commitment.len()
+                )
+            })?,
+            rollup_parent_block_hash: rollup_parent_block_hash.into(),
+            sequencer_parent_block_hash: sequencer_parent_block_hash
+                .as_ref()
+                .try_into()
+                .wrap_err("invalid field .sequencer_parent_block_hash")?,
+            searcher_address,
+        })
+    }
+
+    pub(crate) fn commitment(&self) -> &[u8; 32] {
+        &self.commitment
+    }
+
+    pub(crate) fn rollup_parent_block_hash(&self) -> &RollupBlockHash {
+        &self.rollup_parent_block_hash
+    }
+
+    pub(crate) fn sequencer_parent_block_hash(&self) -> &block::Hash {
+        &self.sequencer_parent_block_hash
+    }
+
+    pub(crate) fn searcher_address(&self) -> &[u8] {
+        &self.searcher_address
+    }
+}
+
+/// The reveal of a bid that was previously committed to by a [`SealedBid`].
+#[derive(Debug, Clone)]
+pub(crate) struct BidReveal {
+    bid: Bid,
+    /// The protobuf encoding of the revealed bid as received.
+    encoded_bid: Bytes,
+    salt: Bytes,
+}
+
+impl BidReveal {
+    fn try_from_raw(raw: raw::BidReveal) -> eyre::Result<Self> {
+        let raw::BidReveal {
+            bid,
+            salt,
+        } = raw;
+        let bid = bid.ok_or_else(|| eyre!("field not set: .bid"))?;
+        let encoded_bid = bid.encode_to_vec().into();
+        Ok(Self {
+            bid: Bid::try_from_raw(bid).wrap_err("invalid field .bid")?,
+            encoded_bid,
+            salt,
+        })
+    }
+
+    /// Returns the commitment to the revealed bid: the SHA256 hash of the protobuf encoded bid
+    /// followed by the salt.
+    ///
+    /// Committing to the entire bid rather than only its fee prevents a searcher from swapping
+    /// the transactions of a sealed bid upon revealing it.
+    pub(crate) fn commitment(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(&self.encoded_bid);
+        hasher.update(&self.salt);
+        hasher.finalize().into()
+    }
+
+    pub(crate) fn bid(&self) -> &Bid {
+        &self.bid
+    }
+
+    pub(crate) fn into_bid(self) -> Bid {
+        self.bid
+    }
+}
+
+/// A bid as submitted to the auctioneer by the rollup.
+#[derive(Debug, Clone)]
+pub(crate) enum Submission {
+    /// A bid whose fee is visible upon submission.
+    Open(Bid),
+    /// A commitment to a bid in a sealed-bid auction.
+    Sealed(SealedBid),
+    /// The reveal of a previously sealed bid.
+    Reveal(BidReveal),
+}
+
+impl Submission {
+    pub(crate) fn try_from_raw(raw: raw::get_bid_stream_response::Value) -> eyre::Result<Self> {
+        use raw::get_bid_stream_response::Value;
+        match raw {
+            Value::Bid(bid) => Bid::try_from_raw(bid)
+                .map(Self::Open)
+                .wrap_err("invalid field .bid"),
+            Value::SealedBid(sealed) => SealedBid::try_from_raw(sealed)
+                .map(Self::Sealed)
+                .wrap_err("invalid field .sealed_bid"),
+            Value::BidReveal(reveal) => BidReveal::try_from_raw(reveal)
+                .map(Self::Reveal)
+                .wrap_err("invalid field .bid_reveal"),
+        }
+    }
+
+    pub(crate) fn rollup_parent_block_hash(&self) -> &RollupBlockHash {
+        match self {
+            Self::Open(bid) => bid.rollup_parent_block_hash(),
+            Self::Sealed(sealed) => &sealed.rollup_parent_block_hash,
+            Self::Reveal(reveal) => reveal.bid.rollup_parent_block_hash(),
+        }
+    }
+
+    pub(crate) fn sequencer_parent_block_hash(&self) -> &block::Hash {
+        match self {
+            Self::Open(bid) => bid.sequencer_parent_block_hash(),
+            Self::Sealed(sealed) => &sealed.sequencer_parent_block_hash,
+            Self::Reveal(reveal) => reveal.bid.sequencer_parent_block_hash(),
+        }
+    }
+
+    pub(crate) fn searcher_address(&self) -> &[u8] {
+        match self {
+            Self::Open(bid) => bid.searcher_address(),
+            Self::Sealed(sealed) => &sealed.searcher_address,
+            Self::Reveal(reveal) => reveal.bid.searcher_address(),
+        }
+    }
+
+    /// Returns the fee of the bid, or `None` if the bid is sealed.
+    pub(crate) fn fee(&self) -> Option<u64> {
+        match self {
+            Self::Open(bid) => Some(bid.bid()),
+            Self::Sealed(_) => None,
+            Self::Reveal(reveal) => Some(reveal.bid.bid()),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -1,4 +1,12 @@
+use std::collections::HashSet;
+
 use astria_core::primitive::v1::asset;
+use astria_eyre::eyre::{
+    self,
+    ensure,
+    eyre,
+    WrapErr as _,
+};
 use serde::{
     Deserialize,
     Serialize,
@@ -21,14 +29,26 @@ pub struct Config {
     pub sequencer_address_prefix: String,
     // The fee asset denomination to use for the sequnecer transactions.
     pub fee_asset_denomination: asset::Denom,
-    /// The endpoint for the rollup gRPC service used for the optimistic execution and bundle
-    /// streams
-    pub rollup_grpc_endpoint: String,
-    /// The rollup ID used to filter the proposed blocks stream
-    pub rollup_id: String,
+    /// The rollups to run auctions for, as a comma separated list of
+    /// `<rollup_name>::<rollup_grpc_endpoint>` or
+    /// `<rollup_name>::<rollup_grpc_endpoint>::<latency_margin_ms>`.
+    ///
+    /// The rollup name is used to derive the rollup ID to filter the proposed blocks stream by.
+    /// The endpoint is that of the rollup gRPC service used for the optimistic execution and
+    /// bundle streams.
+    pub rollups: String,
     /// The amount of time in miliseconds to wait after a commit before closing the auction for
-    /// bids and submitting the result to the sequencer.
+    /// bids and submitting the result to the sequencer, for rollups that do not set their own.
     pub latency_margin_ms: u64,
+    /// The rule by which the winner of an auction and the price charged to it are determined.
+    pub allocation_rule: AllocationRule,
+    /// If set, only sealed bids are accepted: bids commit to their fee until the auction timer
+    /// starts, and only bids revealed after that and before the auction ends participate.
+    pub sealed_bids: bool,
+    /// The directory in which the history of all auctions is persisted.
+    pub auction_history_path: String,
+    /// The socket address at which the API to query the auction history is served.
+    pub api_addr: String,
     /// Log level for the service.
     pub log: String,
     /// Forces writing trace data to stdout no matter if connected to a tty or not.
@@ -41,13 +61,95 @@ pub struct Config {
     pub metrics_http_listener_addr: String,
 }
 
+impl Config {
+    /// Returns the rollups to run auctions for, in the order they were listed.
+    pub(crate) fn parse_rollups(&self) -> eyre::Result<Vec<RollupConfig>> {
+        let mut seen = HashSet::new();
+        let mut rollups = vec![];
+        for item in self.rollups.split(',').filter(|item| !item.is_empty()) {
+            let rollup = RollupConfig::parse(item, self.latency_margin_ms)
+                .wrap_err_with(|| format!("failed to parse rollup definition `{item}`"))?;
+            ensure!(
+                seen.insert(rollup.name.clone()),
+                "rollup `{}` is defined more than once",
+                rollup.name,
+            );
+            rollups.push(rollup);
+        }
+        ensure!(!rollups.is_empty(), "no rollups are defined");
+        Ok(rollups)
+    }
+}
+
 impl config::Config for Config {
     const PREFIX: &'static str = "ASTRIA_AUCTIONEER_";
 }
 
+/// A rollup to run auctions for, parsed from [`Config::rollups`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct RollupConfig {
+    /// The rollup name from which the rollup ID is derived.
+    pub(crate) name: String,
+    /// The endpoint of the rollup's Auction and Optimistic Execution gRPC services.
+    pub(crate) grpc_endpoint: String,
+    /// The amount of time in miliseconds to wait after a commit before closing the auction.
+    pub(crate) latency_margin_ms: u64,
+}
+
+impl RollupConfig {
+    /// Parses `<name>::<grpc_endpoint>` or `<name>::<grpc_endpoint>::<latency_margin_ms>`,
+    /// using `default_latency_margin_ms` in the former case.
+    fn parse(item: &str, default_latency_margin_ms: u64) -> eyre::Result<Self> {
+        let (name, rest) = item
+            .split_once("::")
+            .ok_or_else(|| eyre!("expected `<rollup_name>::<rollup_grpc_endpoint>`"))?;
+        ensure!(!name.is_empty(), "rollup name must not be empty");
+        // The endpoint can itself contain `::` (as in IPv6 addresses), so the trailing segment is
+        // only a latency margin if it is a number.
+        let (grpc_endpoint, latency_margin_ms) = match rest.rsplit_once("::") {
+            Some((grpc_endpoint, latency_margin_ms))
+                if latency_margin_ms.parse::<u64>().is_ok() =>
+            {
+                (
+                    grpc_endpoint,
+                    latency_margin_ms
+                        .parse()
+                        .expect("the arm guard checked that parsing succeeds"),
+                )
+            }
+            _ => (rest, default_latency_margin_ms),
+        };
+        ensure!(
+            !grpc_endpoint.is_empty(),
+            "rollup gRPC endpoint must not be empty"
+        );
+        Ok(Self {
+            name: name.to_string(),
+            grpc_endpoint: grpc_endpoint.to_string(),
+            latency_margin_ms,
+        })
+    }
+}
+
+/// The rule by which the winner of an auction and the price charged to it are determined.
+///
+/// Under all rules the bid with the highest fee wins the auction.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum AllocationRule {
+    /// The winner is charged the fee of its own bid.
+    #[default]
+    FirstPrice,
+    /// The winner is charged the fee of the highest losing bid (a Vickrey auction), or the fee
+    /// of its own bid if there was no other bid.
+    SecondPrice,
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Config;
+    use super::{
+        Config,
+        RollupConfig,
+    };
 
     const EXAMPLE_ENV: &str = include_str!("../local.env.example");
 
@@ -55,4 +157,32 @@ mod tests {
     fn example_env_config_is_up_to_date() {
         config::tests::example_env_config_is_up_to_date::<Config>(EXAMPLE_ENV);
     }
+
+    #[test]
+    fn rollup_definitions_are_parsed() {
+        assert_eq!(
+            RollupConfig {
+                name: "astriachain".to_string(),
+                grpc_endpoint: "http://127.0.0.1:50051".to_string(),
+                latency_margin_ms: 1000,
+            },
+            RollupConfig::parse("astriachain::http://127.0.0.1:50051", 1000).unwrap(),
+        );
+        assert_eq!(
+            RollupConfig {
+                name: "other".to_string(),
+                grpc_endpoint: "http://[::1]:50051".to_string(),
+                latency_margin_ms: 500,
+            },
+            RollupConfig::parse("other::http://[::1]:50051::500", 1000).unwrap(),
+        );
+        assert_eq!(
+            "http://[::1]:50051",
+            RollupConfig::parse("other::http://[::1]:50051", 1000)
+                .unwrap()
+                .grpc_endpoint,
+        );
+        RollupConfig::parse("http://127.0.0.1:50051", 1000).unwrap_err();
+        RollupConfig::parse("::http://127.0.0.1:50051", 1000).unwrap_err();
+    }
 }
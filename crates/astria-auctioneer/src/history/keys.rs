@@ -0,0 +1,63 @@
+//! Keys of the auction history database.
+//!
+//! Sequence numbers are zero-padded so that keys sort by sequence number.
+
+pub(super) const NEXT_SEQUENCE: &str = "next_sequence";
+pub(super) const AUCTION_PREFIX: &str = "auction/";
+const BY_ROLLUP_BLOCK_HASH_PREFIX: &str = "by_rollup_block_hash/";
+const BY_SEARCHER_PREFIX: &str = "by_searcher/";
+
+pub(super) fn auction(sequence: u64) -> String {
+    format!("{AUCTION_PREFIX}{sequence:020}")
+}
+
+pub(super) fn by_rollup_block_hash_prefix(hex_hash: &str) -> String {
+    format!("{BY_ROLLUP_BLOCK_HASH_PREFIX}{hex_hash}/")
+}
+
+pub(super) fn by_rollup_block_hash(hex_hash: &str, sequence: u64) -> String {
+    format!("{}{sequence:020}", by_rollup_block_hash_prefix(hex_hash))
+}
+
+pub(super) fn by_searcher_prefix(hex_address: &str) -> String {
+    format!("{BY_SEARCHER_PREFIX}{hex_address}/")
+}
+
+pub(super) fn by_searcher(hex_address: &str, sequence: u64) -> String {
+    format!("{}{sequence:020}", by_searcher_prefix(hex_address))
+}
+
+/// Returns a key that sorts after all keys made of `prefix` followed by a sequence number.
+pub(super) fn prefix_upper_bound(prefix: &str) -> String {
+    format!("{prefix}~")
+}
+
+/// Returns the sequence number of `key` if it is `prefix` followed by a sequence number.
+pub(super) fn sequence_from_key(key: &[u8], prefix: &str) -> Option<u64> {
+    let sequence = key.strip_prefix(prefix.as_bytes())?;
+    if sequence.len() != 20 {
+        return None;
+    }
+    std::str::from_utf8(sequence).ok()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequence_is_read_back_from_keys() {
+        let prefix = by_searcher_prefix("01");
+        assert_eq!(
+            Some(42),
+            sequence_from_key(by_searcher("01", 42).as_bytes(), &prefix)
+        );
+        assert_eq!(
+            None,
+            sequence_from_key(by_searcher("0102", 42).as_bytes(), &prefix)
+        );
+        assert_eq!(None, sequence_from_key(auction(42).as_bytes(), &prefix));
+        assert!(auction(9).as_str() < auction(10).as_str());
+        assert!(auction(u64::MAX).as_str() < prefix_upper_bound(AUCTION_PREFIX).as_str());
+    }
+}
@@ -0,0 +1,354 @@
+//! The history of all auctions run by Auctioneer, persisted to an embedded database.
+//!
+//! Every auction is stored as a JSON encoded [`AuctionRecord`] under a sequence number that
+//! increases with each stored auction. Secondary indices map the rollup parent block hashes and
+//! searcher addresses of all bids in an auction to its sequence number, so that the history can
+//! be filtered by either.
+use std::{
+    path::Path,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use rocksdb::{
+    Direction,
+    IteratorMode,
+    WriteBatch,
+    DB,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+mod keys;
+
+/// The maximum number of auctions returned by a single query.
+pub(crate) const MAX_QUERY_LIMIT: usize = 1000;
+
+/// A record of a single auction.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub(crate) struct AuctionRecord {
+    /// The ID of the auction, derived from the hash of the proposed Sequencer block.
+    pub(crate) auction_id: String,
+    /// The ID of the rollup the auction was run for.
+    pub(crate) rollup_id: String,
+    /// The hex encoded hash of the proposed Sequencer block that triggered the auction.
+    pub(crate) sequencer_block_hash: String,
+    /// The height of the proposed Sequencer block that triggered the auction.
+    pub(crate) sequencer_height: u64,
+    /// All bids processed by the auction, in the order they were received.
+    pub(crate) bids: Vec<BidRecord>,
+    /// The number of bids that were received but not processed before the auction ended.
+    pub(crate) bids_unprocessed: usize,
+    /// The winner of the auction, if any.
+    pub(crate) winner: Option<WinnerRecord>,
+    /// How the auction ended.
+    pub(crate) outcome: Outcome,
+}
+
+/// A bid processed by an auction.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub(crate) struct BidRecord {
+    pub(crate) kind: BidKind,
+    /// The hex encoded address of the searcher on the rollup.
+    pub(crate) searcher_address: String,
+    /// The hex encoded hash of the rollup block the bid was based on.
+    pub(crate) rollup_parent_block_hash: String,
+    /// The fee of the bid; not set for sealed bids.
+    pub(crate) fee: Option<u64>,
+    /// Why the bid was rejected by the auction, if it was.
+    pub(crate) rejection_reason: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum BidKind {
+    Open,
+    Sealed,
+    Reveal,
+}
+
+/// The winner of an auction.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub(crate) struct WinnerRecord {
+    /// The hex encoded address of the winning searcher on the rollup.
+    pub(crate) searcher_address: String,
+    /// The fee of the winning bid.
+    pub(crate) fee: u64,
+    /// The price charged to the winning bid.
+    pub(crate) price: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub(crate) enum Outcome {
+    /// The auction was cancelled before it ended.
+    Cancelled,
+    /// The auction ended without bids.
+    NoBids,
+    /// The winner was submitted to Sequencer.
+    Submitted {
+        /// The hex encoded hash of the submitted transaction.
+        tx_hash: String,
+        /// The nonce used to submit the transaction.
+        nonce: u32,
+        /// The ABCI code of Sequencer's response to the submission.
+        code: u32,
+        /// The log of Sequencer's response to the submission.
+        log: String,
+    },
+    /// The auction failed.
+    Failed { reason: String },
+}
+
+/// A query for auctions in the history.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub(crate) struct Query {
+    /// Only return auctions with a bid based on this hex encoded rollup block hash.
+    pub(crate) rollup_block_hash: Option<String>,
+    /// Only return auctions with a bid by this hex encoded searcher address.
+    pub(crate) searcher_address: Option<String>,
+    /// The maximum number of auctions to return. Capped at [`MAX_QUERY_LIMIT`].
+    pub(crate) limit: Option<usize>,
+}
+
+/// The history of auctions, backed by a `RocksDB` database.
+#[derive(Clone)]
+pub(crate) struct History {
+    db: Arc<DB>,
+    /// Serializes inserts, which read and increment the next sequence number.
+    insert_lock: Arc<Mutex<()>>,
+}
+
+impl History {
+    /// Opens the history at `path`, creating it if it does not exist.
+    pub(crate) fn open(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        let db = DB::open_default(path).wrap_err_with(|| {
+            format!(
+                "failed to open auction history database at `{}`",
+                path.display()
+            )
+        })?;
+        Ok(Self {
+            db: Arc::new(db),
+            insert_lock: Arc::new(Mutex::new(())),
+        })
+    }
+
+    /// Stores `record` as the latest auction.
+    pub(crate) async fn insert(&self, record: AuctionRecord) -> eyre::Result<()> {
+        let db = self.db.clone();
+        let insert_lock = self.insert_lock.clone();
+        tokio::task::spawn_blocking(move || {
+            let _guard = insert_lock.lock().expect("the lock is never poisoned");
+            insert_record(&db, &record)
+        })
+        .await
+        .wrap_err("task storing auction record panicked")?
+    }
+
+    /// Returns the auctions matching `query`, most recent first.
+    pub(crate) async fn query(&self, query: Query) -> eyre::Result<Vec<AuctionRecord>> {
+        let db = self.db.clone();
+        tokio::task::spawn_blocking(move || query_records(&db, &query))
+            .await
+            .wrap_err("task querying auction records panicked")?
+    }
+}
+
+fn insert_record(db: &DB, record: &AuctionRecord) -> eyre::Result<()> {
+    let sequence = match db
+        .get(keys::NEXT_SEQUENCE)
+        .wrap_err("failed to read next sequence number")?
+    {
+        Some(bytes) => u64::from_be_bytes(
+            bytes
+                .as_slice()
+                .try_into()
+                .wrap_err("stored next sequence number is not 8 bytes")?,
+        ),
+        None => 0,
+    };
+
+    let mut batch = WriteBatch::default();
+    batch.put(
+        keys::auction(sequence),
+        serde_json::to_vec(record).wrap_err("failed to encode auction record as JSON")?,
+    );
+    for bid in &record.bids {
+        batch.put(
+            keys::by_rollup_block_hash(&bid.rollup_parent_block_hash, sequence),
+            b"",
+        );
+        batch.put(keys::by_searcher(&bid.searcher_address, sequence), b"");
+    }
+    batch.put(
+        keys::NEXT_SEQUENCE,
+        sequence.saturating_add(1).to_be_bytes(),
+    );
+    db.write(batch)
+        .wrap_err("failed to write auction record to database")
+}
+
+fn query_records(db: &DB, query: &Query) -> eyre::Result<Vec<AuctionRecord>> {
+    let limit = query.limit.unwrap_or(MAX_QUERY_LIMIT).min(MAX_QUERY_LIMIT);
+    let rollup_block_hash = query.rollup_block_hash.as_deref().map(str::to_lowercase);
+    let searcher_address = query.searcher_address.as_deref().map(str::to_lowercase);
+
+    // Iterate the narrowest index available and filter the records by the remaining criteria.
+    let prefix = match (&rollup_block_hash, &searcher_address) {
+        (Some(hash), _) => keys::by_rollup_block_hash_prefix(hash),
+        (None, Some(address)) => keys::by_searcher_prefix(address),
+        (None, None) => keys::AUCTION_PREFIX.to_string(),
+    };
+
+    let mut records = vec![];
+    for item in db.iterator(IteratorMode::From(
+        keys::prefix_upper_bound(&prefix).as_bytes(),
+        Direction::Reverse,
+    )) {
+        if records.len() >= limit {
+            break;
+        }
+        let (key, value) = item.wrap_err("failed to read from auction history database")?;
+        let Some(sequence) = keys::sequence_from_key(&key, &prefix) else {
+            break;
+        };
+        let record = if prefix == keys::AUCTION_PREFIX {
+            decode_record(&value)?
+        } else {
+            let value = db
+                .get(keys::auction(sequence))
+                .wrap_err("failed to read auction record")?
+                .ok_or_else(|| {
+                    eyre::eyre!("auction `{sequence}` is indexed but does not exist; this is a bug")
+                })?;
+            decode_record(&value)?
+        };
+        let matches_searcher = searcher_address.as_ref().map_or(true, |address| {
+            record
+                .bids
+                .iter()
+                .any(|bid| &bid.searcher_address == address)
+        });
+        if matches_searcher {
+            records.push(record);
+        }
+    }
+    Ok(records)
+}
+
+fn decode_record(bytes: &[u8]) -> eyre::Result<AuctionRecord> {
+    serde_json::from_slice(bytes).wrap_err("failed to decode auction record from JSON")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bid(rollup_parent_block_hash: &str, searcher_address: &str) -> BidRecord {
+        BidRecord {
+            kind: BidKind::Open,
+            searcher_address: searcher_address.to_string(),
+            rollup_parent_block_hash: rollup_parent_block_hash.to_string(),
+            fee: Some(10),
+            rejection_reason: None,
+        }
+    }
+
+    fn record(height: u64, bids: Vec<BidRecord>) -> AuctionRecord {
+        AuctionRecord {
+            auction_id: format!("auction-{height}"),
+            rollup_id: "rollup".to_string(),
+            sequencer_block_hash: "00".repeat(32),
+            sequencer_height: height,
+            bids,
+            bids_unprocessed: 0,
+            winner: None,
+            outcome: Outcome::NoBids,
+        }
+    }
+
+    fn heights(records: &[AuctionRecord]) -> Vec<u64> {
+        records
+            .iter()
+            .map(|record| record.sequencer_height)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn query_filters_by_rollup_block_hash_and_searcher() {
+        let dir = tempfile::tempdir().unwrap();
+        let history = History::open(dir.path()).unwrap();
+        history
+            .insert(record(1, vec![bid("aa", "01")]))
+            .await
+            .unwrap();
+        history
+            .insert(record(2, vec![bid("bb", "01"), bid("bb", "02")]))
+            .await
+            .unwrap();
+        history.insert(record(3, vec![])).await.unwrap();
+
+        let all = history.query(Query::default()).await.unwrap();
+        assert_eq!(vec![3, 2, 1], heights(&all));
+
+        let by_hash = history
+            .query(Query {
+                rollup_block_hash: Some("BB".to_string()),
+                ..Query::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(vec![2], heights(&by_hash));
+
+        let by_searcher = history
+            .query(Query {
+                searcher_address: Some("01".to_string()),
+                ..Query::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(vec![2, 1], heights(&by_searcher));
+
+        let by_both = history
+            .query(Query {
+                rollup_block_hash: Some("aa".to_string()),
+                searcher_address: Some("02".to_string()),
+                limit: None,
+            })
+            .await
+            .unwrap();
+        assert!(by_both.is_empty());
+
+        let limited = history
+            .query(Query {
+                limit: Some(1),
+                ..Query::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(vec![3], heights(&limited));
+    }
+
+    #[tokio::test]
+    async fn history_persists_across_reopening() {
+        let dir = tempfile::tempdir().unwrap();
+        {
+            let history = History::open(dir.path()).unwrap();
+            history.insert(record(1, vec![])).await.unwrap();
+        }
+        let history = History::open(dir.path()).unwrap();
+        history.insert(record(2, vec![])).await.unwrap();
+        let all = history.query(Query::default()).await.unwrap();
+        assert_eq!(vec![2, 1], heights(&all));
+    }
+}
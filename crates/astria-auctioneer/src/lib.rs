@@ -13,6 +13,12 @@
 //! triggers Auctioneer to cancel a still running auction and start
 //! a new one.
 //!
+//! Auctioneer can run auctions for several rollups, each connected to
+//! its own Rollup node. The auctions of different rollups are
+//! independent of each other: each rollup receives its own proposed
+//! blocks filtered for the rollup, and has its own bids, auction
+//! timer, and running auction.
+//!
 //! Auctioneer forwards the block it received from Sequencer to its
 //! the Rollup for (optimistic) execution, and then selects a winner
 //! among the bids that are on top of this optimistically constructed
@@ -29,7 +35,12 @@
 //! The signal to open the auction for bids is usually given after
 //! Auctioneer receives the executed block hash from its connected
 //! rollup. Afterwards the running auction starts processing its received
-//! bids given an allocation rule (right now first price only).
+//! bids given the allocation rule set in the config (first price or
+//! second price).
+//!
+//! If sealed bids are enabled, bids are first received as commitments to
+//! the entire bid and must be revealed after the auction timer started to
+//! participate in the auction.
 //!
 //! The signal to start the auction timer is usually given after
 //! Auctioneer receives a commit message from Sequencer. Once the
@@ -49,17 +60,34 @@
 //! used to submit the winning allocation. If Sequencer does not
 //! answer then the auction worker submits the winning bid using
 //! the cached nonce of the last successful submission.
+//!
+//! Because the auctions of all rollups submit from the same account,
+//! nonces are reserved from a book shared by all auctions: a nonce
+//! used by a still in-flight submission, or by a submission that
+//! finished after the nonce was requested, is skipped in favor of
+//! the next one.
+//!
+//! # Auction history
+//!
+//! Every finished auction is persisted to an embedded database: all bids
+//! it processed (and why any were rejected), its winner, and the outcome
+//! of submitting the winner to Sequencer. The history is served over
+//! HTTP at `GET /v1/auctions`, most recent auction first, and can be
+//! filtered by the hex encoded `rollup_block_hash` the bids were based on
+//! and the hex encoded `searcher_address` of a bidder.
 
 use std::{
     future::Future,
     task::Poll,
 };
 
+mod api;
 mod auctioneer;
 mod bid;
 mod block;
 mod build_info;
 pub mod config;
+mod history;
 pub(crate) mod metrics;
 mod rollup_channel;
 mod sequencer_channel;
@@ -34,7 +34,7 @@ use tracing::{
 };
 
 use crate::{
-    bid::Bid,
+    bid::Submission,
     streaming_utils::{
         make_instrumented_channel,
         restarting_stream,
@@ -133,11 +133,11 @@ impl RollupChannel {
 }
 
 pub(crate) struct BidStream {
-    inner: BoxStream<'static, eyre::Result<Bid>>,
+    inner: BoxStream<'static, eyre::Result<Submission>>,
 }
 
 impl Stream for BidStream {
-    type Item = eyre::Result<Bid>;
+    type Item = eyre::Result<Submission>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         self.inner.poll_next_unpin(cx)
@@ -149,7 +149,7 @@ struct InnerBidStream {
 }
 
 impl Stream for InnerBidStream {
-    type Item = eyre::Result<Bid>;
+    type Item = eyre::Result<Submission>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let Some(res) = ready!(self.inner.poll_next_unpin(cx)) else {
@@ -158,22 +158,22 @@ impl Stream for InnerBidStream {
 
         let raw = res
             .wrap_err("error while receiving streamed message from server")?
-            .bid
+            .value
             .ok_or_else(|| {
                 eyre!(
-                    "message field not set: `{}.bid`",
+                    "message field not set: `{}.value`",
                     GetBidStreamResponse::full_name()
                 )
             })?;
 
-        let bid = Bid::try_from_raw(raw).wrap_err_with(|| {
+        let submission = Submission::try_from_raw(raw).wrap_err_with(|| {
             format!(
                 "failed to validate received message `{}`",
-                astria_core::generated::astria::auction::v1alpha1::Bid::full_name()
+                GetBidStreamResponse::full_name()
             )
         })?;
 
-        Poll::Ready(Some(Ok(bid)))
+        Poll::Ready(Some(Ok(submission)))
     }
 }
 
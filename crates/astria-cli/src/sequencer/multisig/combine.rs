@@ -0,0 +1,76 @@
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use astria_core::{
+    protocol::transaction::v1::TransactionSignature,
+    Protobuf,
+};
+use clap_stdin::FileOrStdin;
+use color_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+
+use crate::sequencer::sign::{
+    read_transaction_body,
+    stdout_or_file,
+};
+
+#[derive(clap::Args, Debug)]
+pub(super) struct Command {
+    /// A file containing a pbjson formatted astria.protocol.transaction.v1.TransactionSignature
+    /// produced by `multisig sign`. Repeat the argument once per collected signature.
+    #[arg(long = "signature", required = true)]
+    signatures: Vec<PathBuf>,
+    /// Target to write the combined transaction in pbjson format (omit to write to STDOUT).
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+    /// Forces an overwrite of `--output` if a file at that location exists.
+    #[arg(long, short)]
+    force: bool,
+    /// The source to read the pbjson formatted astra.protocol.transaction.v1.TransactionBody (use
+    /// `-` to pass via STDIN). Its `multisigAddress` field names the multisig account the
+    /// transaction is sent on behalf of.
+    input: FileOrStdin,
+}
+
+impl Command {
+    pub(super) fn run(self) -> eyre::Result<()> {
+        let filename = self.input.filename().to_string();
+        let transaction_body = read_transaction_body(self.input)
+            .wrap_err_with(|| format!("failed to read transaction body from `{filename}`"))?;
+        let signatures = self
+            .signatures
+            .iter()
+            .map(|path| {
+                read_signature(path)
+                    .wrap_err_with(|| format!("failed to read signature from `{}`", path.display()))
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+        let transaction = transaction_body
+            .into_multisig_transaction(signatures)
+            .wrap_err("failed to combine signatures into a transaction")?;
+
+        serde_json::to_writer(
+            stdout_or_file(self.output.as_ref(), self.force)
+                .wrap_err("failed to determine output target")?,
+            &transaction.to_raw(),
+        )
+        .wrap_err("failed to write signed transaction")?;
+        Ok(())
+    }
+}
+
+fn read_signature(path: &Path) -> eyre::Result<TransactionSignature> {
+    let file = std::fs::File::open(path).wrap_err("failed to open file for reading")?;
+    let wire_signature: <TransactionSignature as Protobuf>::Raw =
+        serde_json::from_reader(std::io::BufReader::new(file)).wrap_err_with(|| {
+            format!(
+                "failed to parse input as json `{}`",
+                TransactionSignature::full_name()
+            )
+        })?;
+    TransactionSignature::try_from_raw(wire_signature).wrap_err("failed to validate signature")
+}
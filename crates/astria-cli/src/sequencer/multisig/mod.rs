@@ -0,0 +1,43 @@
+use color_eyre::eyre;
+
+mod combine;
+mod register;
+mod sign;
+
+#[derive(Debug, clap::Args)]
+pub(super) struct Command {
+    #[command(subcommand)]
+    command: SubCommand,
+}
+
+impl Command {
+    pub(super) async fn run(self) -> eyre::Result<()> {
+        match self.command {
+            SubCommand::Register(register) => register.run().await,
+            SubCommand::Sign(sign) => sign.run(),
+            SubCommand::Combine(combine) => combine.run(),
+        }
+    }
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum SubCommand {
+    /// Turn the signing account into a multisig account controlled by a set of keys.
+    Register(register::Command),
+    /// Sign a pbjson formatted TransactionBody on behalf of a multisig account, producing
+    /// a single pbjson formatted TransactionSignature.
+    #[expect(
+        clippy::doc_markdown,
+        reason = "doc comments are turned into CLI help strings which currently don't use \
+                  backticks"
+    )]
+    Sign(sign::Command),
+    /// Combine the collected signatures over a pbjson formatted TransactionBody into a
+    /// Transaction which can be sent with `sequencer submit`.
+    #[expect(
+        clippy::doc_markdown,
+        reason = "doc comments are turned into CLI help strings which currently don't use \
+                  backticks"
+    )]
+    Combine(combine::Command),
+}
@@ -0,0 +1,79 @@
+use astria_core::{
+    crypto::VerificationKey,
+    primitive::v1::asset,
+    protocol::transaction::v1::{
+        action::RegisterMultisigAccount,
+        Action,
+    },
+};
+use color_eyre::eyre::{
+    self,
+    eyre,
+    WrapErr as _,
+};
+
+#[derive(clap::Args, Debug)]
+pub(super) struct Command {
+    /// The bech32m prefix that will be used for constructing addresses using the private key
+    #[arg(long, default_value = "astria")]
+    prefix: String,
+    /// The private key of the account being turned into a multisig account
+    #[arg(long, env = "SEQUENCER_PRIVATE_KEY")]
+    // TODO: https://github.com/astriaorg/astria/issues/594
+    // Don't use a plain text private, prefer wrapper like from
+    // the secrecy crate with specialized `Debug` and `Drop` implementations
+    // that overwrite the key on drop and don't reveal it when printing.
+    private_key: String,
+    /// The url of the Sequencer node
+    #[arg(long, env = "SEQUENCER_URL")]
+    sequencer_url: String,
+    /// The chain id of the sequencing chain being used
+    #[arg(long = "sequencer.chain-id", env = "ROLLUP_SEQUENCER_CHAIN_ID")]
+    sequencer_chain_id: String,
+    /// A hex encoded ed25519 verification key of a member of the multisig account.
+    /// Repeat the argument once per member.
+    #[arg(long = "verification-key", required = true)]
+    verification_keys: Vec<String>,
+    /// The number of distinct members that must sign a transaction of the account.
+    #[arg(long)]
+    threshold: u32,
+    /// The asset to pay the transaction fees with.
+    #[arg(long, default_value = "nria")]
+    fee_asset: asset::Denom,
+}
+
+impl Command {
+    pub(super) async fn run(self) -> eyre::Result<()> {
+        let verification_keys = self
+            .verification_keys
+            .iter()
+            .map(|key| {
+                verification_key_from_hex(key)
+                    .wrap_err_with(|| format!("invalid verification key `{key}`"))
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+        let res = crate::utils::submit_transaction(
+            self.sequencer_url.as_str(),
+            self.sequencer_chain_id.clone(),
+            &self.prefix,
+            self.private_key.as_str(),
+            Action::RegisterMultisigAccount(RegisterMultisigAccount {
+                verification_keys,
+                threshold: self.threshold,
+                fee_asset: self.fee_asset,
+            }),
+        )
+        .await
+        .wrap_err("failed to submit RegisterMultisigAccount transaction")?;
+
+        println!("RegisterMultisigAccount completed!");
+        println!("Included in block: {}", res.height);
+        Ok(())
+    }
+}
+
+fn verification_key_from_hex(key: &str) -> eyre::Result<VerificationKey> {
+    let bytes = hex::decode(key).wrap_err("failed to decode verification key bytes from hex")?;
+    VerificationKey::try_from(bytes.as_slice())
+        .map_err(|_| eyre!("invalid verification key length; must be 32 bytes"))
+}
@@ -0,0 +1,63 @@
+use std::path::PathBuf;
+
+use astria_core::Protobuf as _;
+use clap_stdin::FileOrStdin;
+use color_eyre::eyre::{
+    self,
+    ensure,
+    WrapErr as _,
+};
+
+use crate::{
+    sequencer::sign::{
+        read_transaction_body,
+        stdout_or_file,
+    },
+    utils::signing_key_from_private_key,
+};
+
+#[derive(clap::Args, Debug)]
+pub(super) struct Command {
+    /// The private key of the multisig member signing the transaction
+    #[arg(long, env = "SEQUENCER_PRIVATE_KEY")]
+    // TODO: https://github.com/astriaorg/astria/issues/594
+    // Don't use a plain text private, prefer wrapper like from
+    // the secrecy crate with specialized `Debug` and `Drop` implementations
+    // that overwrite the key on drop and don't reveal it when printing.
+    private_key: String,
+    /// Target to write the signature in pbjson format (omit to write to STDOUT).
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+    /// Forces an overwrite of `--output` if a file at that location exists.
+    #[arg(long, short)]
+    force: bool,
+    /// The source to read the pbjson formatted astra.protocol.transaction.v1.TransactionBody (use
+    /// `-` to pass via STDIN). Its `multisigAddress` field must name the multisig account the
+    /// transaction is sent on behalf of.
+    input: FileOrStdin,
+}
+
+// Every member of a multisig account signs the same `TransactionBody` independently. The
+// resulting signatures are collected and combined with the `combine` command.
+impl Command {
+    pub(super) fn run(self) -> eyre::Result<()> {
+        let key = signing_key_from_private_key(self.private_key.as_str())?;
+
+        let filename = self.input.filename().to_string();
+        let transaction_body = read_transaction_body(self.input)
+            .wrap_err_with(|| format!("failed to read transaction body from `{filename}`"))?;
+        ensure!(
+            transaction_body.multisig_address().is_some(),
+            "transaction body does not name the multisig account it is sent on behalf of"
+        );
+        let signature = transaction_body.sign_detached(&key);
+
+        serde_json::to_writer(
+            stdout_or_file(self.output.as_ref(), self.force)
+                .wrap_err("failed to determine output target")?,
+            &signature.to_raw(),
+        )
+        .wrap_err("failed to write transaction signature")?;
+        Ok(())
+    }
+}
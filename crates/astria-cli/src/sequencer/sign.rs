@@ -60,7 +60,7 @@ impl Command {
     }
 }
 
-fn read_transaction_body(input: FileOrStdin) -> eyre::Result<TransactionBody> {
+pub(super) fn read_transaction_body(input: FileOrStdin) -> eyre::Result<TransactionBody> {
     let wire_body: <TransactionBody as Protobuf>::Raw = serde_json::from_reader(
         std::io::BufReader::new(input.into_reader()?),
     )
@@ -73,7 +73,7 @@ fn read_transaction_body(input: FileOrStdin) -> eyre::Result<TransactionBody> {
     TransactionBody::try_from_raw(wire_body).wrap_err("failed to validate transaction body")
 }
 
-fn stdout_or_file<P: AsRef<Path>>(
+pub(super) fn stdout_or_file<P: AsRef<Path>>(
     output: Option<P>,
     force_overwrite: bool,
 ) -> eyre::Result<Box<dyn Write>> {
@@ -0,0 +1,175 @@
+use std::{
+    future::{
+        Future,
+        IntoFuture as _,
+    },
+    net::SocketAddr,
+};
+
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use axum::{
+    extract::{
+        FromRef,
+        State,
+    },
+    response::{
+        IntoResponse,
+        Response,
+    },
+    routing::get,
+    Json,
+    Router,
+};
+use futures::FutureExt as _;
+use http::status::StatusCode;
+use serde::Serialize;
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+use tracing::instrument;
+
+use crate::status::StatusSnapshot;
+
+/// A future wrapping a type-erased [`axum::serve::Serve`].
+pub(super) struct Serve {
+    local_addr: SocketAddr,
+    fut: futures::future::BoxFuture<'static, std::io::Result<()>>,
+}
+
+impl Serve {
+    pub(super) fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+}
+
+impl Future for Serve {
+    type Output = std::io::Result<()>;
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        self.fut.as_mut().poll(cx)
+    }
+}
+
+#[derive(Clone)]
+/// `AppState` is used for as an axum extractor in its method handlers.
+struct AppState {
+    conductor_status: watch::Receiver<StatusSnapshot>,
+}
+
+impl FromRef<AppState> for watch::Receiver<StatusSnapshot> {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.conductor_status.clone()
+    }
+}
+
+pub(super) async fn serve(
+    socket_addr: SocketAddr,
+    conductor_status: watch::Receiver<StatusSnapshot>,
+    shutdown_token: CancellationToken,
+) -> eyre::Result<Serve> {
+    let app = Router::new()
+        .route("/healthz", get(get_healthz))
+        .route("/readyz", get(get_readyz))
+        .route("/status", get(get_status))
+        .with_state(AppState {
+            conductor_status,
+        });
+    let listener = tokio::net::TcpListener::bind(socket_addr)
+        .await
+        .wrap_err_with(|| format!("failed to bind TCP socket at `{socket_addr}`"))?;
+    let serve = axum::serve(listener, app).with_graceful_shutdown(shutdown_token.cancelled_owned());
+    let local_addr = serve
+        .local_addr()
+        .wrap_err("bound TCP listener failed to produce local addr")?;
+
+    Ok(Serve {
+        local_addr,
+        fut: serve.into_future().boxed(),
+    })
+}
+
+/// Handler of a call to `/healthz`.
+///
+/// Returns `Healthz::Degraded` if one of the readers for Sequencer or Celestia has exited.
+#[instrument(skip_all)]
+async fn get_healthz(State(conductor_status): State<watch::Receiver<StatusSnapshot>>) -> Healthz {
+    if conductor_status.borrow().is_healthy() {
+        Healthz::Ok
+    } else {
+        Healthz::Degraded
+    }
+}
+
+/// Handler of a call to `/readyz`.
+///
+/// Returns `Readyz::Ok` once the executors of all rollups have completed their initialization, i.e.
+/// they have started execution sessions with their rollups and subscribed to the readers.
+#[instrument(skip_all)]
+async fn get_readyz(State(conductor_status): State<watch::Receiver<StatusSnapshot>>) -> Readyz {
+    let is_conductor_ready = conductor_status.borrow().is_ready();
+    if is_conductor_ready {
+        Readyz::Ok
+    } else {
+        Readyz::NotReady
+    }
+}
+
+#[instrument(skip_all)]
+async fn get_status(
+    State(conductor_status): State<watch::Receiver<StatusSnapshot>>,
+) -> Json<StatusSnapshot> {
+    Json(conductor_status.borrow().clone())
+}
+
+enum Healthz {
+    Ok,
+    Degraded,
+}
+
+impl IntoResponse for Healthz {
+    fn into_response(self) -> Response {
+        #[derive(Debug, Serialize)]
+        struct ReadyzBody {
+            status: &'static str,
+        }
+        let (status, msg) = match self {
+            Self::Ok => (StatusCode::OK, "ok"),
+            Self::Degraded => (StatusCode::INTERNAL_SERVER_ERROR, "degraded"),
+        };
+        let mut response = Json(ReadyzBody {
+            status: msg,
+        })
+        .into_response();
+        *response.status_mut() = status;
+        response
+    }
+}
+
+enum Readyz {
+    Ok,
+    NotReady,
+}
+
+impl IntoResponse for Readyz {
+    fn into_response(self) -> Response {
+        #[derive(Debug, Serialize)]
+        struct ReadyzBody {
+            status: &'static str,
+        }
+        let (status, msg) = match self {
+            Self::Ok => (StatusCode::OK, "ok"),
+            Self::NotReady => (StatusCode::SERVICE_UNAVAILABLE, "not ready"),
+        };
+        let mut response = Json(ReadyzBody {
+            status: msg,
+        })
+        .into_response();
+        *response.status_mut() = status;
+        response
+    }
+}
@@ -1,71 +1,115 @@
 //! Boilerplate to construct a [`super::Reader`] via a type-state builder.
 
-use std::time::Duration;
+use std::{
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
 
+use astria_data_availability::{
+    Backend,
+    BlobReader,
+    LocalDa,
+};
 use astria_eyre::eyre::{
     self,
+    eyre,
     WrapErr as _,
 };
-use jsonrpsee::http_client::HttpClient as CelestiaClient;
+use jsonrpsee::http_client::HttpClient;
 use tendermint_rpc::HttpClient as SequencerClient;
+use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 
 use super::{
+    client::CelestiaClient,
+    disk_cache::DiskCache,
+    Handle,
     Reader,
-    ReconstructedBlock,
-};
-use crate::{
-    metrics::Metrics,
-    state::StateReceiver,
 };
+use crate::metrics::Metrics;
 
 pub(crate) struct Builder {
+    /// The data availability layer to read blobs from.
+    pub(crate) da_backend: Backend,
+    /// The directory of the local data availability layer. Must be set if `da_backend` is
+    /// [`Backend::Local`].
+    pub(crate) local_da_dir: Option<PathBuf>,
     pub(crate) celestia_block_time: Duration,
     pub(crate) celestia_http_endpoint: String,
     pub(crate) celestia_token: Option<String>,
-    pub(crate) firm_blocks: tokio::sync::mpsc::Sender<Box<ReconstructedBlock>>,
-    pub(crate) rollup_state: StateReceiver,
     pub(crate) sequencer_cometbft_client: SequencerClient,
     pub(crate) sequencer_requests_per_second: u32,
+    /// The directory of the disk cache of verified blobs. The cache is disabled if unset.
+    pub(crate) firm_block_cache_dir: Option<PathBuf>,
+    /// The maximum total size of the files in the disk cache.
+    pub(crate) firm_block_cache_max_bytes: u64,
     pub(crate) shutdown: CancellationToken,
     pub(crate) metrics: &'static Metrics,
 }
 
 impl Builder {
-    /// Creates a new [`Reader`] instance,
-    pub(crate) fn build(self) -> eyre::Result<Reader> {
+    /// Creates a new [`Reader`] instance and the [`Handle`] to subscribe to it.
+    pub(crate) fn build(self) -> eyre::Result<(Reader, Handle)> {
         let Self {
+            da_backend,
+            local_da_dir,
             celestia_block_time,
             celestia_http_endpoint,
             celestia_token,
             sequencer_cometbft_client,
             sequencer_requests_per_second,
+            firm_block_cache_dir,
+            firm_block_cache_max_bytes,
             shutdown,
             metrics,
-            firm_blocks,
-            rollup_state,
         } = self;
 
-        let celestia_client = create_celestia_client(celestia_http_endpoint, celestia_token)
-            .wrap_err("failed initializing client for Celestia HTTP RPC")?;
+        let celestia_client: Arc<dyn BlobReader> = match da_backend {
+            Backend::Celestia => {
+                let client = create_celestia_client(celestia_http_endpoint, celestia_token)
+                    .wrap_err("failed initializing client for Celestia HTTP RPC")?;
+                Arc::new(CelestiaClient::new(client))
+            }
+            Backend::Local => {
+                let dir = local_da_dir.ok_or_else(|| {
+                    eyre!(
+                        "a local data availability directory must be set if the local backend is \
+                         selected"
+                    )
+                })?;
+                Arc::new(LocalDa::new(dir))
+            }
+        };
+
+        let disk_cache = firm_block_cache_dir
+            .map(|dir| DiskCache::open(dir, firm_block_cache_max_bytes).map(Arc::new))
+            .transpose()
+            .wrap_err("failed opening disk cache of firm blocks")?;
+
+        let (subscriptions_tx, subscriptions) = mpsc::channel(16);
 
-        Ok(Reader {
+        let reader = Reader {
             celestia_block_time,
             celestia_client,
-            firm_blocks,
-            rollup_state,
+            subscriptions,
             sequencer_cometbft_client,
             sequencer_requests_per_second,
             shutdown,
+            disk_cache,
             metrics,
-        })
+        };
+        let handle = Handle {
+            subscriptions: subscriptions_tx,
+        };
+        Ok((reader, handle))
     }
 }
 
 fn create_celestia_client(
     endpoint: String,
     bearer_token: Option<String>,
-) -> eyre::Result<CelestiaClient> {
+) -> eyre::Result<HttpClient> {
     use jsonrpsee::http_client::{
         HeaderMap,
         HttpClientBuilder,
@@ -0,0 +1,74 @@
+//! The Celestia implementation of [`BlobReader`], reading from the JSON-RPC API of a Celestia
+//! node.
+
+use astria_data_availability::{
+    Blob,
+    BlobReader,
+    Namespace,
+    ReadError,
+};
+use async_trait::async_trait;
+use celestia_rpc::{
+    BlobClient as _,
+    HeaderClient as _,
+};
+use jsonrpsee::{
+    core::ClientError,
+    http_client::HttpClient,
+};
+
+/// Reads heights and blobs from a Celestia node.
+#[derive(Clone)]
+pub(super) struct CelestiaClient {
+    inner: HttpClient,
+}
+
+impl CelestiaClient {
+    pub(super) fn new(inner: HttpClient) -> Self {
+        Self {
+            inner,
+        }
+    }
+}
+
+#[async_trait]
+impl BlobReader for CelestiaClient {
+    async fn chain_id(&self) -> Result<String, ReadError> {
+        let network_head = self
+            .inner
+            .header_network_head()
+            .await
+            .map_err(into_read_error)?;
+        Ok(network_head.chain_id().to_string())
+    }
+
+    async fn latest_height(&self) -> Result<u64, ReadError> {
+        let network_head = self
+            .inner
+            .header_network_head()
+            .await
+            .map_err(into_read_error)?;
+        Ok(network_head.height().value())
+    }
+
+    async fn fetch_blobs(&self, height: u64, namespace: Namespace) -> Result<Vec<Blob>, ReadError> {
+        let blobs = self
+            .inner
+            .blob_get_all(height, &[namespace])
+            .await
+            .map_err(into_read_error)?;
+        Ok(blobs.unwrap_or_default())
+    }
+}
+
+/// Only failures of the transport are considered transient.
+fn into_read_error(error: ClientError) -> ReadError {
+    if matches!(
+        error,
+        ClientError::Transport(_) | ClientError::RequestTimeout
+    ) {
+        ReadError::Unavailable(error.into())
+    } else {
+        ReadError::Failed(error.into())
+    }
+}
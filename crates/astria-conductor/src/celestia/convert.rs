@@ -35,7 +35,7 @@ type StdError = dyn std::error::Error;
 /// them grouped by their block hashes.
 pub(super) fn decode_raw_blobs(
     raw_blobs: RawBlobs,
-    rollup_namespace: Namespace,
+    rollup_namespaces: &[Namespace],
     sequencer_namespace: Namespace,
 ) -> ConvertedBlobs {
     let mut converted_blobs = ConvertedBlobs::new(raw_blobs.celestia_height);
@@ -54,15 +54,14 @@ pub(super) fn decode_raw_blobs(
     }
 
     for blob in raw_blobs.rollup_blobs {
-        if blob.namespace == rollup_namespace {
+        if rollup_namespaces.contains(&blob.namespace) {
             if let Some(rollup_list) = convert_blob_to_rollup_data_list(&blob) {
                 converted_blobs.extend_from_rollup_data_list_if_well_formed(rollup_list);
             }
         } else {
             warn!(
-                rollup_namespace = %base64(rollup_namespace.as_ref()),
                 namespace_in_blob = %base64(blob.namespace.as_ref()),
-                "blob's namespaces was not the expected rollup namespace; dropping",
+                "blob's namespaces was not one of the expected rollup namespaces; dropping",
             );
         }
     }
@@ -0,0 +1,603 @@
+//! A persistent cache of the verified blobs read from Celestia.
+//!
+//! Fetching and verifying the blobs of a Celestia height requires a number of requests to
+//! Celestia and Sequencer. After a restart, Conductor would have to repeat these requests for
+//! all Celestia heights between the rollup's base Celestia height and its current firm height.
+//! [`DiskCache`] stores the verified blobs of each Celestia height in a file so that blocks can be
+//! reconstructed from them without contacting either network.
+//!
+//! Each file contains the SHA256 checksum of its payload followed by the protobuf encoded
+//! payload. Entries are checked for integrity when loaded: the checksum must match, and the
+//! header blobs are validated again, including their inclusion proofs. Entries failing these
+//! checks are removed.
+//!
+//! The cache is bounded by the total size of its files. If it grows beyond its bound, the entries
+//! for the lowest Celestia heights are removed first.
+
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
+    fs,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Mutex,
+};
+
+use astria_core::{
+    generated::astria::{
+        primitive::v1 as raw_primitive,
+        sequencerblock::v1 as raw,
+    },
+    primitive::v1::RollupId,
+    sequencerblock::v1::{
+        SubmittedMetadata,
+        SubmittedRollupData,
+    },
+};
+use astria_eyre::eyre::{
+    self,
+    ensure,
+    WrapErr as _,
+};
+use prost::Message as _;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tracing::{
+    info,
+    warn,
+};
+
+use super::verify::VerifiedBlobs;
+
+const FILE_EXTENSION: &str = "blobs";
+const TMP_FILE_EXTENSION: &str = "tmp";
+const CHECKSUM_LEN: usize = 32;
+
+/// The payload of a cache file.
+#[derive(Clone, PartialEq, prost::Message)]
+struct RawEntry {
+    #[prost(uint64, tag = "1")]
+    celestia_height: u64,
+    /// The next expected firm Sequencer height at the time the blobs were verified. Header blobs
+    /// below this height were dropped without verifying them.
+    #[prost(uint64, tag = "2")]
+    lowest_sequencer_height: u64,
+    #[prost(string, tag = "3")]
+    sequencer_chain_id: String,
+    /// The rollups whose blobs were fetched.
+    #[prost(message, repeated, tag = "4")]
+    rollup_ids: Vec<raw_primitive::RollupId>,
+    #[prost(message, repeated, tag = "5")]
+    header_blobs: Vec<raw::SubmittedMetadata>,
+    #[prost(message, repeated, tag = "6")]
+    rollup_blobs: Vec<raw::SubmittedRollupData>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Entry {
+    lowest_sequencer_height: u64,
+    size: u64,
+}
+
+#[derive(Debug, Default)]
+struct Index {
+    entries: BTreeMap<u64, Entry>,
+    total_bytes: u64,
+}
+
+impl Index {
+    fn insert(&mut self, celestia_height: u64, entry: Entry) -> Option<Entry> {
+        self.total_bytes = self.total_bytes.saturating_add(entry.size);
+        let old = self.entries.insert(celestia_height, entry);
+        if let Some(old) = old {
+            self.total_bytes = self.total_bytes.saturating_sub(old.size);
+        }
+        old
+    }
+
+    fn remove(&mut self, celestia_height: u64) -> Option<Entry> {
+        let old = self.entries.remove(&celestia_height);
+        if let Some(old) = old {
+            self.total_bytes = self.total_bytes.saturating_sub(old.size);
+        }
+        old
+    }
+}
+
+/// A size-bounded cache of verified blobs on disk, keyed by Celestia height.
+///
+/// All operations block on file system access and should be run on a blocking thread.
+#[derive(Debug)]
+pub(super) struct DiskCache {
+    dir: PathBuf,
+    max_bytes: u64,
+    index: Mutex<Index>,
+}
+
+impl DiskCache {
+    /// Opens the cache stored in `dir`, creating the directory if it does not exist.
+    ///
+    /// Entries beyond `max_bytes` are removed immediately, for example if the bound was lowered
+    /// since the cache was last used.
+    ///
+    /// # Errors
+    /// Returns an error if the directory could not be created or read.
+    pub(super) fn open(dir: PathBuf, max_bytes: u64) -> eyre::Result<Self> {
+        fs::create_dir_all(&dir)
+            .wrap_err_with(|| format!("failed to create directory `{}`", dir.display()))?;
+
+        let mut index = Index::default();
+        let mut superseded = vec![];
+        for dir_entry in fs::read_dir(&dir)
+            .wrap_err_with(|| format!("failed to read directory `{}`", dir.display()))?
+        {
+            let dir_entry = dir_entry.wrap_err("failed to read directory entry")?;
+            let path = dir_entry.path();
+            if path
+                .extension()
+                .is_some_and(|ext| ext == TMP_FILE_EXTENSION)
+            {
+                // Left over from an insertion that was interrupted before its file was renamed.
+                remove_file(&path);
+                continue;
+            }
+            let Some((celestia_height, lowest_sequencer_height)) = parse_file_name(&path) else {
+                continue;
+            };
+            let size = dir_entry
+                .metadata()
+                .wrap_err_with(|| format!("failed to read metadata of `{}`", path.display()))?
+                .len();
+            let entry = Entry {
+                lowest_sequencer_height,
+                size,
+            };
+            // Of two entries for the same Celestia height, keep the one containing more blobs.
+            match index.entries.get(&celestia_height) {
+                Some(existing) if existing.lowest_sequencer_height <= lowest_sequencer_height => {
+                    superseded.push(file_path(&dir, celestia_height, lowest_sequencer_height));
+                }
+                Some(existing) => {
+                    superseded.push(file_path(
+                        &dir,
+                        celestia_height,
+                        existing.lowest_sequencer_height,
+                    ));
+                    index.insert(celestia_height, entry);
+                }
+                None => {
+                    index.insert(celestia_height, entry);
+                }
+            }
+        }
+        for path in superseded {
+            remove_file(&path);
+        }
+
+        let cache = Self {
+            dir,
+            max_bytes,
+            index: Mutex::new(index),
+        };
+        {
+            let mut index = cache.lock_index();
+            cache.evict(&mut index);
+            info!(
+                dir = %cache.dir.display(),
+                max_bytes,
+                number_of_entries = index.entries.len(),
+                total_bytes = index.total_bytes,
+                "opened firm block cache",
+            );
+        }
+        Ok(cache)
+    }
+
+    /// Returns the blobs cached for `celestia_height`, containing the blobs of all `rollup_ids`
+    /// and all header blobs at or above `next_expected_firm_sequencer_height`.
+    ///
+    /// Returns `None` if there is no such entry. Entries that fail their integrity check or that
+    /// were stored for a different Sequencer network are removed.
+    pub(super) fn get(
+        &self,
+        celestia_height: u64,
+        rollup_ids: &[RollupId],
+        sequencer_chain_id: &str,
+        next_expected_firm_sequencer_height: u64,
+    ) -> Option<VerifiedBlobs> {
+        // Copy the entry out so that the index is not locked while the file is read and
+        // validated.
+        let entry = *self.lock_index().entries.get(&celestia_height)?;
+        if entry.lowest_sequencer_height > next_expected_firm_sequencer_height {
+            return None;
+        }
+        let path = file_path(&self.dir, celestia_height, entry.lowest_sequencer_height);
+        match read_entry(&path, celestia_height, entry.lowest_sequencer_height) {
+            Ok(cached) if cached.sequencer_chain_id != sequencer_chain_id => {
+                warn!(
+                    path = %path.display(),
+                    cached.sequencer_chain_id,
+                    sequencer_chain_id,
+                    "cached blobs were read from a different Sequencer network; removing them",
+                );
+            }
+            Ok(cached) => {
+                if !rollup_ids.iter().all(|id| cached.rollup_ids.contains(id)) {
+                    return None;
+                }
+                return Some(
+                    cached.into_verified_blobs(rollup_ids, next_expected_firm_sequencer_height),
+                );
+            }
+            Err(error) => {
+                warn!(
+                    path = %path.display(),
+                    %error,
+                    "failed loading cached blobs; removing them",
+                );
+            }
+        }
+        // The entry might have been replaced by a concurrent insertion while its file was read.
+        let mut index = self.lock_index();
+        if index.entries.get(&celestia_height) == Some(&entry) {
+            index.remove(celestia_height);
+            remove_file(&path);
+        }
+        None
+    }
+
+    /// Stores `verified_blobs`, replacing any entry for the same Celestia height.
+    ///
+    /// `rollup_ids` are the rollups whose blobs were fetched, and `lowest_sequencer_height` the
+    /// height below which header blobs were dropped without verifying them. Errors are logged
+    /// and otherwise ignored because the cache is not required to read blocks from Celestia.
+    pub(super) fn insert(
+        &self,
+        verified_blobs: &VerifiedBlobs,
+        rollup_ids: &[RollupId],
+        sequencer_chain_id: &str,
+        lowest_sequencer_height: u64,
+    ) {
+        let celestia_height = verified_blobs.celestia_height();
+        let raw = RawEntry {
+            celestia_height,
+            lowest_sequencer_height,
+            sequencer_chain_id: sequencer_chain_id.to_string(),
+            rollup_ids: rollup_ids.iter().map(RollupId::to_raw).collect(),
+            header_blobs: verified_blobs
+                .header_blobs()
+                .cloned()
+                .map(SubmittedMetadata::into_raw)
+                .collect(),
+            rollup_blobs: verified_blobs
+                .rollup_blobs()
+                .iter()
+                .cloned()
+                .map(SubmittedRollupData::into_raw)
+                .collect(),
+        };
+        let payload = raw.encode_to_vec();
+        let mut contents = Vec::with_capacity(CHECKSUM_LEN.saturating_add(payload.len()));
+        contents.extend_from_slice(&Sha256::digest(&payload));
+        contents.extend_from_slice(&payload);
+        let size = u64::try_from(contents.len()).unwrap_or(u64::MAX);
+        if size > self.max_bytes {
+            info!(
+                celestia_height,
+                size,
+                max_bytes = self.max_bytes,
+                "verified blobs exceed the size of the firm block cache; not caching them",
+            );
+            return;
+        }
+
+        let mut index = self.lock_index();
+        let path = file_path(&self.dir, celestia_height, lowest_sequencer_height);
+        if let Err(error) = write_atomically(&path, &contents) {
+            warn!(
+                path = %path.display(),
+                error = &error as &dyn std::error::Error,
+                "failed writing verified blobs to the firm block cache",
+            );
+            return;
+        }
+        let entry = Entry {
+            lowest_sequencer_height,
+            size,
+        };
+        if let Some(old) = index.insert(celestia_height, entry) {
+            if old.lowest_sequencer_height != lowest_sequencer_height {
+                remove_file(&file_path(
+                    &self.dir,
+                    celestia_height,
+                    old.lowest_sequencer_height,
+                ));
+            }
+        }
+        self.evict(&mut index);
+    }
+
+    /// Removes the entries for the lowest Celestia heights until the cache is within its bound.
+    fn evict(&self, index: &mut Index) {
+        while index.total_bytes > self.max_bytes {
+            let Some((celestia_height, entry)) = index.entries.pop_first() else {
+                break;
+            };
+            index.total_bytes = index.total_bytes.saturating_sub(entry.size);
+            remove_file(&file_path(
+                &self.dir,
+                celestia_height,
+                entry.lowest_sequencer_height,
+            ));
+        }
+    }
+
+    fn lock_index(&self) -> std::sync::MutexGuard<'_, Index> {
+        self.index
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// The contents of a cache file after their integrity was checked.
+struct CachedEntry {
+    celestia_height: u64,
+    sequencer_chain_id: String,
+    rollup_ids: Vec<RollupId>,
+    header_blobs: Vec<SubmittedMetadata>,
+    rollup_blobs: Vec<SubmittedRollupData>,
+}
+
+impl CachedEntry {
+    /// Converts the entry to verified blobs, keeping only the blobs of `rollup_ids` and the
+    /// header blobs at or above `next_expected_firm_sequencer_height`.
+    fn into_verified_blobs(
+        self,
+        rollup_ids: &[RollupId],
+        next_expected_firm_sequencer_height: u64,
+    ) -> VerifiedBlobs {
+        let header_blobs: HashMap<_, _> = self
+            .header_blobs
+            .into_iter()
+            .filter(|blob| blob.height().value() >= next_expected_firm_sequencer_height)
+            .map(|blob| (*blob.block_hash(), blob))
+            .collect();
+        let rollup_blobs = self
+            .rollup_blobs
+            .into_iter()
+            .filter(|blob| rollup_ids.contains(&blob.rollup_id()))
+            .collect();
+        VerifiedBlobs::from_parts(self.celestia_height, header_blobs, rollup_blobs)
+    }
+}
+
+fn read_entry(
+    path: &Path,
+    celestia_height: u64,
+    lowest_sequencer_height: u64,
+) -> eyre::Result<CachedEntry> {
+    let contents = fs::read(path).wrap_err("failed to read file")?;
+    ensure!(
+        contents.len() >= CHECKSUM_LEN,
+        "file is shorter than its checksum"
+    );
+    let (checksum, payload) = contents.split_at(CHECKSUM_LEN);
+    ensure!(
+        Sha256::digest(payload).as_slice() == checksum,
+        "checksum does not match the file's payload"
+    );
+    let raw = RawEntry::decode(payload).wrap_err("failed to decode payload")?;
+    ensure!(
+        raw.celestia_height == celestia_height
+            && raw.lowest_sequencer_height == lowest_sequencer_height,
+        "heights in payload do not match the file name",
+    );
+    let rollup_ids = raw
+        .rollup_ids
+        .into_iter()
+        .map(RollupId::try_from_raw)
+        .collect::<Result<_, _>>()
+        .wrap_err("failed to validate rollup ID")?;
+    let header_blobs = raw
+        .header_blobs
+        .into_iter()
+        .map(SubmittedMetadata::try_from_raw)
+        .collect::<Result<_, _>>()
+        .wrap_err("failed to validate header blob")?;
+    let rollup_blobs = raw
+        .rollup_blobs
+        .into_iter()
+        .map(SubmittedRollupData::try_from_raw)
+        .collect::<Result<_, _>>()
+        .wrap_err("failed to validate rollup blob")?;
+    Ok(CachedEntry {
+        celestia_height,
+        sequencer_chain_id: raw.sequencer_chain_id,
+        rollup_ids,
+        header_blobs,
+        rollup_blobs,
+    })
+}
+
+/// Writes `contents` to a temporary file first and renames it to `path` so that a file at `path`
+/// is never partially written.
+fn write_atomically(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let tmp_path = path.with_extension(TMP_FILE_EXTENSION);
+    fs::write(&tmp_path, contents)?;
+    fs::rename(&tmp_path, path)
+}
+
+fn remove_file(path: &Path) {
+    if let Err(error) = fs::remove_file(path) {
+        if error.kind() != io::ErrorKind::NotFound {
+            warn!(
+                path = %path.display(),
+                error = &error as &dyn std::error::Error,
+                "failed removing file from the firm block cache",
+            );
+        }
+    }
+}
+
+fn file_path(dir: &Path, celestia_height: u64, lowest_sequencer_height: u64) -> PathBuf {
+    dir.join(format!(
+        "{celestia_height}-{lowest_sequencer_height}.{FILE_EXTENSION}"
+    ))
+}
+
+/// Parses a file name of the form `<celestia height>-<lowest sequencer height>.blobs`.
+fn parse_file_name(path: &Path) -> Option<(u64, u64)> {
+    if path.extension()? != FILE_EXTENSION {
+        return None;
+    }
+    let (celestia_height, lowest_sequencer_height) = path.file_stem()?.to_str()?.split_once('-')?;
+    Some((
+        celestia_height.parse().ok()?,
+        lowest_sequencer_height.parse().ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::protocol::test_utils::ConfigureSequencerBlock;
+
+    use super::*;
+
+    const SEQUENCER_CHAIN_ID: &str = "test-sequencer";
+    const ROLLUP_ID: RollupId = RollupId::new([42u8; 32]);
+
+    fn make_verified_blobs(celestia_height: u64, sequencer_heights: &[u32]) -> VerifiedBlobs {
+        let mut header_blobs = HashMap::new();
+        let mut rollup_blobs = vec![];
+        for &height in sequencer_heights {
+            let (header, rollups) = ConfigureSequencerBlock {
+                chain_id: Some(SEQUENCER_CHAIN_ID.to_string()),
+                height,
+                sequence_data: vec![(ROLLUP_ID, vec![1, 2, 3])],
+                ..Default::default()
+            }
+            .make()
+            .split_for_celestia();
+            header_blobs.insert(*header.block_hash(), header);
+            rollup_blobs.extend(rollups);
+        }
+        VerifiedBlobs::from_parts(celestia_height, header_blobs, rollup_blobs)
+    }
+
+    fn files_in(dir: &Path) -> usize {
+        fs::read_dir(dir).unwrap().count()
+    }
+
+    #[test]
+    fn cached_blobs_survive_reopening() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = DiskCache::open(dir.path().to_path_buf(), u64::MAX).unwrap();
+        cache.insert(
+            &make_verified_blobs(10, &[2, 3]),
+            &[ROLLUP_ID],
+            SEQUENCER_CHAIN_ID,
+            2,
+        );
+        drop(cache);
+
+        let cache = DiskCache::open(dir.path().to_path_buf(), u64::MAX).unwrap();
+        let cached = cache.get(10, &[ROLLUP_ID], SEQUENCER_CHAIN_ID, 2).unwrap();
+        assert_eq!(cached.celestia_height(), 10);
+        assert_eq!(cached.len_header_blobs(), 2);
+        assert_eq!(cached.len_rollup_blobs(), 2);
+
+        let cached = cache.get(10, &[ROLLUP_ID], SEQUENCER_CHAIN_ID, 3).unwrap();
+        assert_eq!(cached.len_header_blobs(), 1);
+    }
+
+    #[test]
+    fn entries_missing_blobs_are_not_returned() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = DiskCache::open(dir.path().to_path_buf(), u64::MAX).unwrap();
+        cache.insert(
+            &make_verified_blobs(10, &[3]),
+            &[ROLLUP_ID],
+            SEQUENCER_CHAIN_ID,
+            3,
+        );
+
+        assert!(cache.get(11, &[ROLLUP_ID], SEQUENCER_CHAIN_ID, 3).is_none());
+        // header blobs below height 3 were dropped before caching.
+        assert!(cache.get(10, &[ROLLUP_ID], SEQUENCER_CHAIN_ID, 2).is_none());
+        // the blobs of other rollups were not fetched.
+        let other_rollup = RollupId::new([69u8; 32]);
+        assert!(cache
+            .get(10, &[ROLLUP_ID, other_rollup], SEQUENCER_CHAIN_ID, 3)
+            .is_none());
+        assert!(cache.get(10, &[ROLLUP_ID], SEQUENCER_CHAIN_ID, 3).is_some());
+    }
+
+    #[test]
+    fn corrupted_or_foreign_entries_are_removed() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = DiskCache::open(dir.path().to_path_buf(), u64::MAX).unwrap();
+        cache.insert(
+            &make_verified_blobs(10, &[2]),
+            &[ROLLUP_ID],
+            SEQUENCER_CHAIN_ID,
+            2,
+        );
+        cache.insert(
+            &make_verified_blobs(11, &[3]),
+            &[ROLLUP_ID],
+            SEQUENCER_CHAIN_ID,
+            3,
+        );
+
+        let path = file_path(dir.path(), 10, 2);
+        let mut contents = fs::read(&path).unwrap();
+        let last = contents.last_mut().unwrap();
+        *last = last.wrapping_add(1);
+        fs::write(&path, contents).unwrap();
+
+        assert!(cache.get(10, &[ROLLUP_ID], SEQUENCER_CHAIN_ID, 2).is_none());
+        assert!(!path.exists());
+
+        assert!(cache.get(11, &[ROLLUP_ID], "other-sequencer", 3).is_none());
+        assert_eq!(files_in(dir.path()), 0);
+    }
+
+    #[test]
+    fn lowest_celestia_heights_are_evicted_first() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = DiskCache::open(dir.path().to_path_buf(), u64::MAX).unwrap();
+        for celestia_height in 10..13 {
+            cache.insert(
+                &make_verified_blobs(celestia_height, &[2]),
+                &[ROLLUP_ID],
+                SEQUENCER_CHAIN_ID,
+                2,
+            );
+        }
+        let entry_size = fs::metadata(file_path(dir.path(), 12, 2)).unwrap().len();
+        drop(cache);
+
+        let cache = DiskCache::open(dir.path().to_path_buf(), entry_size * 2).unwrap();
+        assert_eq!(files_in(dir.path()), 2);
+        assert!(cache.get(10, &[ROLLUP_ID], SEQUENCER_CHAIN_ID, 2).is_none());
+        assert!(cache.get(11, &[ROLLUP_ID], SEQUENCER_CHAIN_ID, 2).is_some());
+
+        cache.insert(
+            &make_verified_blobs(13, &[2]),
+            &[ROLLUP_ID],
+            SEQUENCER_CHAIN_ID,
+            2,
+        );
+        assert_eq!(files_in(dir.path()), 2);
+        assert!(cache.get(11, &[ROLLUP_ID], SEQUENCER_CHAIN_ID, 2).is_none());
+        assert!(cache.get(12, &[ROLLUP_ID], SEQUENCER_CHAIN_ID, 2).is_some());
+        assert!(cache.get(13, &[ROLLUP_ID], SEQUENCER_CHAIN_ID, 2).is_some());
+    }
+}
@@ -1,8 +1,15 @@
 use std::{
-    sync::atomic::AtomicU32,
+    sync::{
+        atomic::AtomicU32,
+        Arc,
+    },
     time::Duration,
 };
 
+use astria_data_availability::{
+    BlobReader,
+    ReadError,
+};
 use astria_eyre::{
     eyre,
     eyre::WrapErr as _,
@@ -11,10 +18,7 @@ use celestia_types::{
     nmt::Namespace,
     Blob,
 };
-use jsonrpsee::{
-    self,
-    http_client::HttpClient as CelestiaClient,
-};
+use futures::future::try_join_all;
 use telemetry::display::base64;
 use tokio::try_join;
 use tracing::{
@@ -44,20 +48,20 @@ impl RawBlobs {
     }
 }
 
-/// Fetch Celestia blobs at `celestia_height` matching `sequencer_namespace` and `rollup_namespace`.
+/// Fetch Celestia blobs at `celestia_height` matching `sequencer_namespace` and any of
+/// `rollup_namespaces`.
 ///
-/// Retries indefinitely if the underlying transport failed. Immediately returns with an error in
-/// all other cases.
+/// Retries indefinitely if the data availability layer was unavailable. Immediately returns with
+/// an error in all other cases.
 #[instrument(skip_all, fields(
     celestia_height,
     sequencer_namespace = %base64(sequencer_namespace.as_ref()),
-    rollup_namespace = %base64(rollup_namespace.as_ref()),
     err,
 ))]
 pub(super) async fn fetch_new_blobs(
-    client: CelestiaClient,
+    client: Arc<dyn BlobReader>,
     celestia_height: u64,
-    rollup_namespace: Namespace,
+    rollup_namespaces: &[Namespace],
     sequencer_namespace: Namespace,
     metrics: &'static Metrics,
 ) -> eyre::Result<RawBlobs> {
@@ -72,9 +76,12 @@ pub(super) async fn fetch_new_blobs(
         .wrap_err("failed to fetch header blobs")
     };
     let rollup_blobs = async {
-        fetch_blobs_with_retry(client.clone(), celestia_height, rollup_namespace, metrics)
-            .await
-            .wrap_err("failed to fetch rollup blobs")
+        try_join_all(rollup_namespaces.iter().map(|&rollup_namespace| {
+            fetch_blobs_with_retry(client.clone(), celestia_height, rollup_namespace, metrics)
+        }))
+        .await
+        .map(|blobs| blobs.into_iter().flatten().collect::<Vec<_>>())
+        .wrap_err("failed to fetch rollup blobs")
     };
 
     let (header_blobs, rollup_blobs) = try_join!(header_blobs, rollup_blobs)?;
@@ -86,21 +93,19 @@ pub(super) async fn fetch_new_blobs(
     })
 }
 
-#[instrument(skip_all, err)]
+#[instrument(skip_all, fields(namespace = %base64(namespace.as_ref())), err)]
 async fn fetch_blobs_with_retry(
-    client: CelestiaClient,
+    client: Arc<dyn BlobReader>,
     height: u64,
     namespace: Namespace,
     metrics: &'static Metrics,
 ) -> eyre::Result<Vec<Blob>> {
-    use celestia_rpc::BlobClient as _;
-
     let number_attempts = AtomicU32::new(0);
     let retry_config = tryhard::RetryFutureConfig::new(u32::MAX)
         .custom_backoff(FetchBlobsRetryStrategy::new(Duration::from_millis(100)))
         .max_delay(Duration::from_secs(20))
         .on_retry(
-            |attempt: u32, next_delay: Option<Duration>, error: &jsonrpsee::core::ClientError| {
+            |attempt: u32, next_delay: Option<Duration>, error: &ReadError| {
                 number_attempts.store(attempt, std::sync::atomic::Ordering::Relaxed);
                 let wait_duration = next_delay
                     .map(telemetry::display::format_duration)
@@ -118,13 +123,7 @@ async fn fetch_blobs_with_retry(
 
     tryhard::retry_fn(move || {
         let client = client.clone();
-        async move {
-            match client.blob_get_all(height, &[namespace]).await {
-                Ok(Some(blobs)) => Ok(blobs),
-                Ok(None) => Ok(vec![]),
-                Err(err) => Err(err),
-            }
-        }
+        async move { client.fetch_blobs(height, namespace).await }
     })
     .with_config(retry_config)
     .await
@@ -143,11 +142,11 @@ impl FetchBlobsRetryStrategy {
     }
 }
 
-impl<'a> BackoffStrategy<'a, jsonrpsee::core::ClientError> for FetchBlobsRetryStrategy {
+impl<'a> BackoffStrategy<'a, ReadError> for FetchBlobsRetryStrategy {
     type Output = RetryPolicy;
 
-    fn delay(&mut self, _attempt: u32, error: &'a jsonrpsee::core::ClientError) -> Self::Output {
-        if should_retry(error) {
+    fn delay(&mut self, _attempt: u32, error: &'a ReadError) -> Self::Output {
+        if error.is_transient() {
             let prev_delay = self.delay;
             self.delay = self.delay.saturating_mul(2);
             RetryPolicy::Delay(prev_delay)
@@ -156,10 +155,3 @@ impl<'a> BackoffStrategy<'a, jsonrpsee::core::ClientError> for FetchBlobsRetrySt
         }
     }
 }
-
-fn should_retry(error: &jsonrpsee::core::ClientError) -> bool {
-    matches!(
-        error,
-        jsonrpsee::core::ClientError::Transport(_) | jsonrpsee::core::ClientError::RequestTimeout,
-    )
-}
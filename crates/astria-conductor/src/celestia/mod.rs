@@ -1,5 +1,6 @@
 use std::{
     cmp::max,
+    collections::BTreeMap,
     sync::Arc,
     time::Duration,
 };
@@ -12,24 +13,23 @@ use astria_core::{
         SequencerBlockHeader,
     },
 };
+use astria_data_availability::{
+    BlobReader,
+    ReadError,
+};
 use astria_eyre::eyre::{
     self,
-    bail,
     ensure,
+    eyre,
     WrapErr as _,
 };
 use bytes::Bytes;
-use celestia_rpc::HeaderClient as _;
 use celestia_types::nmt::Namespace;
 use futures::{
-    future::{
-        BoxFuture,
-        Fuse,
-        FusedFuture as _,
-    },
+    future::BoxFuture,
+    stream::FuturesUnordered,
     FutureExt as _,
 };
-use jsonrpsee::http_client::HttpClient as CelestiaClient;
 use sequencer_client::{
     tendermint,
     tendermint::block::Height as SequencerHeight,
@@ -42,7 +42,10 @@ use telemetry::display::{
 };
 use tokio::{
     select,
-    sync::mpsc,
+    sync::{
+        mpsc,
+        oneshot,
+    },
     task::spawn_blocking,
     try_join,
 };
@@ -52,12 +55,12 @@ use tokio_util::{
     task::JoinMap,
 };
 use tracing::{
+    debug,
     error,
     info,
     info_span,
     instrument,
     trace,
-    trace_span,
     warn,
     Instrument as _,
 };
@@ -71,7 +74,9 @@ use crate::{
 
 mod block_verifier;
 mod builder;
+mod client;
 mod convert;
+mod disk_cache;
 mod fetch;
 mod latest_height_stream;
 mod reconstruct;
@@ -85,12 +90,14 @@ use reporting::ReportReconstructedBlocks;
 use self::{
     block_verifier::ensure_commit_has_quorum,
     convert::decode_raw_blobs,
+    disk_cache::DiskCache,
     fetch::fetch_new_blobs,
     latest_height_stream::stream_latest_heights,
     reconstruct::reconstruct_blocks_from_verified_blobs,
     verify::{
         verify_metadata,
         BlobVerifier,
+        VerifiedBlobs,
     },
 };
 use crate::block_cache::BlockCache;
@@ -125,20 +132,60 @@ impl GetSequencerHeight for ReconstructedBlock {
 
 pub(super) struct ReconstructedBlocks {
     celestia_height: u64,
-    blocks: Vec<ReconstructedBlock>,
+    /// The blocks reconstructed for each rollup that was requested.
+    blocks: BTreeMap<RollupId, Vec<ReconstructedBlock>>,
+}
+
+/// A handle to subscribe executors to the blocks read by a [`Reader`].
+#[derive(Clone)]
+pub(crate) struct Handle {
+    subscriptions: mpsc::Sender<Subscription>,
+}
+
+impl Handle {
+    /// Subscribes to the firm blocks of the rollup tracked by `rollup_state`.
+    ///
+    /// Blocks are sent over `firm_blocks` in strictly sequential order of their Sequencer heights,
+    /// starting at the next expected firm height. `firm_blocks` is dropped once the stop height in
+    /// `rollup_state` is reached.
+    ///
+    /// # Errors
+    /// Returns an error if the reader is not running or if it rejected the subscription, for
+    /// example because the Celestia or Sequencer chain IDs in `rollup_state` do not match.
+    pub(crate) async fn subscribe(
+        &self,
+        rollup_state: StateReceiver,
+        firm_blocks: mpsc::Sender<Box<ReconstructedBlock>>,
+    ) -> eyre::Result<()> {
+        let (ack, accepted) = oneshot::channel();
+        self.subscriptions
+            .send(Subscription {
+                rollup_state,
+                firm_blocks,
+                ack,
+            })
+            .await
+            .map_err(|_| eyre!("celestia reader is not running"))?;
+        accepted
+            .await
+            .wrap_err("celestia reader exited before accepting the subscription")?
+    }
+}
+
+struct Subscription {
+    rollup_state: StateReceiver,
+    firm_blocks: mpsc::Sender<Box<ReconstructedBlock>>,
+    ack: oneshot::Sender<eyre::Result<()>>,
 }
 
 pub(crate) struct Reader {
     celestia_block_time: Duration,
 
     /// Client to fetch heights and blocks from Celestia.
-    celestia_client: CelestiaClient,
-
-    /// The channel to forward firm blocks to the executor.
-    firm_blocks: mpsc::Sender<Box<ReconstructedBlock>>,
+    celestia_client: Arc<dyn BlobReader>,
 
-    /// The channel to read updates of the rollup state from.
-    rollup_state: StateReceiver,
+    /// The subscriptions of executors sent through a [`Handle`].
+    subscriptions: mpsc::Receiver<Subscription>,
 
     /// The client to get the sequencer namespace and verify blocks.
     sequencer_cometbft_client: SequencerClient,
@@ -150,12 +197,15 @@ pub(crate) struct Reader {
     /// Token to listen for Conductor being shut down.
     shutdown: CancellationToken,
 
+    /// The cache of verified blobs on disk, if enabled.
+    disk_cache: Option<Arc<DiskCache>>,
+
     metrics: &'static Metrics,
 }
 
 impl Reader {
     pub(crate) async fn run_until_stopped(mut self) -> eyre::Result<()> {
-        let sequencer_chain_id = select!(
+        let (celestia_chain_id, sequencer_chain_id) = select!(
             () = self.shutdown.clone().cancelled_owned() => {
                 info_span!("conductor::celestia::Reader::run_until_stopped").in_scope(||
                     info!("received shutdown signal while waiting for Celestia reader task to initialize")
@@ -168,60 +218,41 @@ impl Reader {
             }
         );
 
-        RunningReader::from_parts(self, sequencer_chain_id)
+        RunningReader::from_parts(self, celestia_chain_id, sequencer_chain_id)
             .wrap_err("failed entering run loop")?
             .run_until_stopped()
             .await
     }
 
     #[instrument(skip_all, err)]
-    async fn initialize(&mut self) -> eyre::Result<tendermint::chain::Id> {
-        let expected_celestia_chain_id = self.rollup_state.celestia_chain_id();
-        let validate_celestia_chain_id = async {
-            let actual_celestia_chain_id = get_celestia_chain_id(&self.celestia_client)
+    async fn initialize(&mut self) -> eyre::Result<(tendermint::chain::Id, tendermint::chain::Id)> {
+        let get_celestia_chain_id = async {
+            get_celestia_chain_id(&*self.celestia_client)
                 .await
-                .wrap_err("failed to fetch Celestia chain ID")?;
-            ensure!(
-                expected_celestia_chain_id == actual_celestia_chain_id.as_str(),
-                "expected Celestia chain id `{expected_celestia_chain_id}` does not match actual: \
-                 `{actual_celestia_chain_id}`"
-            );
-            Ok(())
+                .wrap_err("failed to fetch Celestia chain ID")
         }
         .in_current_span();
 
-        let expected_sequencer_chain_id = self.rollup_state.sequencer_chain_id();
-        let get_and_validate_sequencer_chain_id = async {
-            let actual_sequencer_chain_id =
-                get_sequencer_chain_id(self.sequencer_cometbft_client.clone())
-                    .await
-                    .wrap_err("failed to get sequencer chain ID")?;
-            ensure!(
-                expected_sequencer_chain_id == actual_sequencer_chain_id.as_str(),
-                "expected Celestia chain id `{expected_sequencer_chain_id}` does not match \
-                 actual: `{actual_sequencer_chain_id}`"
-            );
-            Ok(actual_sequencer_chain_id)
+        let get_sequencer_chain_id = async {
+            get_sequencer_chain_id(self.sequencer_cometbft_client.clone())
+                .await
+                .wrap_err("failed to get sequencer chain ID")
         }
         .in_current_span();
 
-        try_join!(
-            validate_celestia_chain_id,
-            get_and_validate_sequencer_chain_id
-        )
-        .map(|((), sequencer_chain_id)| sequencer_chain_id)
+        try_join!(get_celestia_chain_id, get_sequencer_chain_id)
     }
 }
 
 #[instrument(skip_all, err, ret(Display))]
 async fn get_celestia_chain_id(
-    celestia_client: &CelestiaClient,
+    celestia_client: &dyn BlobReader,
 ) -> eyre::Result<tendermint::chain::Id> {
     let retry_config = tryhard::RetryFutureConfig::new(u32::MAX)
         .exponential_backoff(Duration::from_millis(100))
         .max_delay(Duration::from_secs(20))
         .on_retry(
-            |attempt: u32, next_delay: Option<Duration>, error: &jsonrpsee::core::ClientError| {
+            |attempt: u32, next_delay: Option<Duration>, error: &ReadError| {
                 let wait_duration = next_delay
                     .map(telemetry::display::format_duration)
                     .map(tracing::field::display);
@@ -234,25 +265,101 @@ async fn get_celestia_chain_id(
                 futures::future::ready(())
             },
         );
-    let network_head = tryhard::retry_fn(|| celestia_client.header_network_head())
+    let chain_id = tryhard::retry_fn(|| celestia_client.chain_id())
         .with_config(retry_config)
         .await?;
-    Ok(network_head.chain_id().clone())
+    tendermint::chain::Id::try_from(chain_id).wrap_err("received invalid Celestia chain ID")
 }
 
-struct RunningReader {
-    block_cache: BlockCache<ReconstructedBlock>,
+type SubscriberId = u64;
 
-    blob_verifier: Arc<BlobVerifier>,
-
-    // Client to fetch heights and blocks from Celestia.
-    celestia_client: CelestiaClient,
+/// An executor subscribed to the blocks reconstructed from Celestia.
+struct Subscriber {
+    rollup_state: StateReceiver,
 
     /// The channel to forward firm blocks to the executor.
     firm_blocks: mpsc::Sender<Box<ReconstructedBlock>>,
 
-    /// The channel to read updates of the rollup state from.
-    rollup_state: StateReceiver,
+    block_cache: BlockCache<ReconstructedBlock>,
+
+    /// Set if a block is waiting for the executor task to have capacity again. Used as a back
+    /// pressure mechanism so that this task does not fetch more blobs if there is no capacity in
+    /// the executor to execute them against the rollup in time.
+    is_block_enqueued: bool,
+
+    /// The reference Celestia height. `celestia_reference_height` +
+    /// `celestia_search_height_max_look_ahead` = C is the maximum Celestia height up to which
+    /// Celestia's blobs will be fetched for this subscriber. `celestia_reference_height` is
+    /// initialized to the base Celestia height stored in the rollup state. It is later advanced
+    /// to that Celestia height from which the next block is derived that will be executed against
+    /// the rollup (only if greater than the current value; it will never go down).
+    celestia_reference_height: u64,
+
+    /// `celestia_search_height_max_look_ahead` + `celestia_reference_height` define the maximum
+    /// Celestia height from Celestia blobs that can be fetched. Set once during subscription
+    /// to the value stored in the rollup state.
+    celestia_search_height_max_look_ahead: u64,
+
+    /// The rollup ID of the rollup that the executor is driving. Set once during subscription to
+    /// the value stored in the rollup state.
+    rollup_id: RollupId,
+
+    /// The Celestia namespace for which rollup-specific blobs will be requested. Derived from
+    /// `rollup_id`.
+    rollup_namespace: Namespace,
+}
+
+impl Subscriber {
+    /// The stop height is reached if a) the next height to be forwarded would be greater
+    /// than the stop height, and b) there is no block currently in flight.
+    fn has_reached_stop_height(&self) -> bool {
+        self.rollup_state
+            .sequencer_stop_height()
+            .map_or(false, |height| {
+                self.block_cache.next_height_to_pop() > height.get() && !self.is_block_enqueued
+            })
+    }
+
+    /// Returns the maximum permitted Celestia height for this subscriber.
+    ///
+    /// The maximum permitted Celestia height is calculated as `ref_height +
+    /// celestia_search_height_max_look_ahead`, with:
+    ///
+    /// - `ref_height` the height from which the last expected sequencer block was derived,
+    /// - `celestia_search_height_max_look_ahead` received from the rollup state at subscription.
+    fn max_permitted_celestia_height(&self) -> u64 {
+        self.celestia_reference_height
+            .saturating_add(self.celestia_search_height_max_look_ahead)
+    }
+
+    fn advance_reference_celestia_height(&mut self, candidate: u64) {
+        let reference_height = &mut self.celestia_reference_height;
+        *reference_height = max(*reference_height, candidate);
+    }
+}
+
+type EnqueuedBlock = (
+    SubscriberId,
+    Result<u64, mpsc::error::SendError<Box<ReconstructedBlock>>>,
+);
+
+struct RunningReader {
+    /// The subscriptions of executors sent through a [`Handle`].
+    subscriptions: mpsc::Receiver<Subscription>,
+
+    /// The executors subscribed to this reader.
+    subscribers: BTreeMap<SubscriberId, Subscriber>,
+
+    /// The ID assigned to the next subscriber.
+    next_subscriber_id: SubscriberId,
+
+    /// Blocks enqueued for subscribers whose executor channel was full.
+    enqueued_blocks: FuturesUnordered<BoxFuture<'static, EnqueuedBlock>>,
+
+    blob_verifier: Arc<BlobVerifier>,
+
+    // Client to fetch heights and blocks from Celestia.
+    celestia_client: Arc<dyn BlobReader>,
 
     /// Token to listen for Conductor being shut down.
     shutdown: CancellationToken,
@@ -264,13 +371,6 @@ struct RunningReader {
     /// are fetched).
     latest_heights: LatestHeightStream,
 
-    /// A block (reconstructed from Celestia blobs) that's waiting for the executor task to have
-    /// capacity again. Used as a back pressure mechanism so that this task does not fetch more
-    /// blobs if there is no capacity in the executor to execute them against the rollup in
-    /// time.
-    enqueued_block:
-        Fuse<BoxFuture<'static, Result<u64, mpsc::error::SendError<Box<ReconstructedBlock>>>>>,
-
     /// The latest observed head height of the Celestia network. Set by values read from
     /// the `latest_height` stream.
     celestia_head_height: Option<u64>,
@@ -278,26 +378,8 @@ struct RunningReader {
     /// The next Celestia height that will be fetched.
     celestia_next_height: u64,
 
-    /// The reference Celestia height. `celestia_reference_height` +
-    /// `celestia_search_height_max_look_ahead` = C is the maximum Celestia height up to which
-    /// Celestia's blobs will be fetched. `celestia_reference_height` is initialized to the
-    /// base Celestia height stored in the rollup state. It is later advanced to that Celestia
-    /// height from which the next block is derived that will be executed against the rollup
-    /// (only if greater than the current value; it will never go down).
-    celestia_reference_height: u64,
-
-    /// `celestia_search_height_max_look_ahead` + `celestia_reference_height` define the maximum
-    /// Celestia height from Celestia blobs that can be fetched. Set once during initialization
-    /// to the value stored in the rollup state.
-    celestia_search_height_max_look_ahead: u64,
-
-    /// The rollup ID of the rollup that conductor is driving. Set once during initialization to
-    /// the value stored in the
-    rollup_id: RollupId,
-
-    /// The Celestia namespace for which rollup-specific blobs will be requested. Derived from
-    /// `rollup_id`.
-    rollup_namespace: Namespace,
+    /// The chain ID of Celestia. Set once during initialization by querying Celestia.
+    celestia_chain_id: tendermint::chain::Id,
 
     /// The cometbft ID of Sequencer. Set once during initialization by querying sequencer.
     sequencer_chain_id: tendermint::chain::Id,
@@ -306,63 +388,54 @@ struct RunningReader {
     /// `sequencer_chain_id`.
     sequencer_namespace: Namespace,
 
+    /// The cache of verified blobs on disk, if enabled.
+    disk_cache: Option<Arc<DiskCache>>,
+
     metrics: &'static Metrics,
 }
 
 impl RunningReader {
     fn from_parts(
         exposed_reader: Reader,
+        celestia_chain_id: tendermint::chain::Id,
         sequencer_chain_id: tendermint::chain::Id,
     ) -> eyre::Result<Self> {
         let Reader {
             celestia_block_time,
             celestia_client,
+            subscriptions,
             sequencer_cometbft_client,
             shutdown,
             sequencer_requests_per_second,
+            disk_cache,
             metrics,
-            firm_blocks,
-            rollup_state,
-            ..
         } = exposed_reader;
-        let block_cache =
-            BlockCache::with_next_height(rollup_state.next_expected_firm_sequencer_height())
-                .wrap_err("failed constructing sequential block cache")?;
 
         let latest_heights = stream_latest_heights(celestia_client.clone(), celestia_block_time);
-        let rollup_id = rollup_state.rollup_id();
-        let rollup_namespace = astria_core::celestia::namespace_v0_from_rollup_id(rollup_id);
         let sequencer_namespace =
             astria_core::celestia::namespace_v0_from_sha256_of_bytes(sequencer_chain_id.as_bytes());
 
-        let celestia_next_height = rollup_state.lowest_celestia_search_height();
-        let celestia_reference_height = rollup_state.lowest_celestia_search_height();
-        let celestia_search_height_max_look_ahead =
-            rollup_state.celestia_search_height_max_look_ahead();
-
         Ok(Self {
-            block_cache,
+            subscriptions,
+            subscribers: BTreeMap::new(),
+            next_subscriber_id: 0,
+            enqueued_blocks: FuturesUnordered::new(),
             blob_verifier: Arc::new(
                 BlobVerifier::try_new(sequencer_cometbft_client, sequencer_requests_per_second)
                     .wrap_err("failed to construct blob verifier")?,
             ),
             celestia_client,
-            enqueued_block: Fuse::terminated(),
-            firm_blocks,
-            rollup_state,
             latest_heights,
             shutdown,
             reconstruction_tasks: JoinMap::new(),
 
             celestia_head_height: None,
-            celestia_next_height,
-            celestia_reference_height,
-            celestia_search_height_max_look_ahead,
+            celestia_next_height: 0,
 
-            rollup_id,
-            rollup_namespace,
+            celestia_chain_id,
             sequencer_chain_id,
             sequencer_namespace,
+            disk_cache,
             metrics,
         })
     }
@@ -370,11 +443,7 @@ impl RunningReader {
     async fn run_until_stopped(mut self) -> eyre::Result<()> {
         info_span!("conductor::celestia::RunningReader::run_until_stopped").in_scope(|| {
             info!(
-                initial_celestia_height = self.celestia_next_height,
-                initial_max_celestia_height = self.max_permitted_celestia_height(),
-                celestia_search_height_max_look_ahead = self.celestia_search_height_max_look_ahead,
-                rollup_namespace = %base64(&self.rollup_namespace.as_bytes()),
-                rollup_id = %self.rollup_id,
+                celestia_chain_id = %self.celestia_chain_id,
                 sequencer_chain_id = %self.sequencer_chain_id,
                 sequencer_namespace = %base64(&self.sequencer_namespace.as_bytes()),
                 "starting firm block read loop",
@@ -382,10 +451,8 @@ impl RunningReader {
         });
 
         let reason = loop {
-            if self.has_reached_stop_height() {
-                break Ok("stop height reached");
-            }
-
+            self.forward_cached_blocks();
+            self.remove_subscribers_at_stop_height();
             self.schedule_new_blobs();
 
             select!(
@@ -395,22 +462,12 @@ impl RunningReader {
                     break Ok("received shutdown signal");
                 }
 
-                res = &mut self.enqueued_block, if self.waiting_for_executor_capacity() => {
-                    match res {
-                        Ok(celestia_height_of_forwarded_block) => {
-                            trace_span!("conductor::celestia::RunningReader::run_until_stopped")
-                                .in_scope(||
-                            trace!("submitted enqueued block to executor, resuming normal operation"));
-                            self.advance_reference_celestia_height(celestia_height_of_forwarded_block);
-                        }
-                        Err(err) => break Err(err).wrap_err("failed sending enqueued block to executor"),
-                    }
+                Some(subscription) = self.subscriptions.recv() => {
+                    self.add_subscriber(subscription);
                 }
 
-                Some(block) = self.block_cache.next_block(), if !self.waiting_for_executor_capacity() => {
-                    if let Err(err) = self.forward_block_to_executor(block) {
-                        break Err(err);
-                    }
+                Some((id, res)) = self.enqueued_blocks.next() => {
+                    self.handle_enqueued_block(id, res);
                 }
 
                 Some((celestia_height, res)) = self.reconstruction_tasks.join_next() => {
@@ -451,31 +508,128 @@ impl RunningReader {
         }
     }
 
-    /// The stop height is reached if a) the next height to be forwarded would be greater
-    /// than the stop height, and b) there is no block currently in flight.
-    fn has_reached_stop_height(&self) -> bool {
-        self.rollup_state
-            .sequencer_stop_height()
-            .map_or(false, |height| {
-                self.block_cache.next_height_to_pop() > height.get()
-                    && self.enqueued_block.is_terminated()
-            })
+    #[instrument(skip_all)]
+    fn add_subscriber(&mut self, subscription: Subscription) {
+        let Subscription {
+            rollup_state,
+            firm_blocks,
+            ack,
+        } = subscription;
+
+        let res = self.try_add_subscriber(rollup_state, firm_blocks);
+        if let Err(error) = &res {
+            warn!(%error, "rejected subscription to celestia reader");
+        }
+        // The executor is not waiting for the subscription anymore if this fails. It will be
+        // removed once its channel is found to be closed.
+        let _ = ack.send(res);
+    }
+
+    fn try_add_subscriber(
+        &mut self,
+        rollup_state: StateReceiver,
+        firm_blocks: mpsc::Sender<Box<ReconstructedBlock>>,
+    ) -> eyre::Result<()> {
+        let expected_celestia_chain_id = rollup_state.celestia_chain_id();
+        ensure!(
+            expected_celestia_chain_id == self.celestia_chain_id.as_str(),
+            "expected Celestia chain id `{expected_celestia_chain_id}` does not match actual: `{}`",
+            self.celestia_chain_id,
+        );
+        let expected_sequencer_chain_id = rollup_state.sequencer_chain_id();
+        ensure!(
+            expected_sequencer_chain_id == self.sequencer_chain_id.as_str(),
+            "expected Sequencer chain id `{expected_sequencer_chain_id}` does not match actual: \
+             `{}`",
+            self.sequencer_chain_id,
+        );
+
+        let block_cache =
+            BlockCache::with_next_height(rollup_state.next_expected_firm_sequencer_height())
+                .wrap_err("failed constructing sequential block cache")?;
+        let rollup_id = rollup_state.rollup_id();
+        let rollup_namespace = astria_core::celestia::namespace_v0_from_rollup_id(rollup_id);
+        let lowest_celestia_search_height = rollup_state.lowest_celestia_search_height();
+        let celestia_search_height_max_look_ahead =
+            rollup_state.celestia_search_height_max_look_ahead();
+
+        let id = self.next_subscriber_id;
+        self.next_subscriber_id = self.next_subscriber_id.saturating_add(1);
+        info!(
+            subscriber.id = id,
+            initial_celestia_height = lowest_celestia_search_height,
+            celestia_search_height_max_look_ahead,
+            rollup_namespace = %base64(&rollup_namespace.as_bytes()),
+            %rollup_id,
+            "adding subscriber",
+        );
+
+        // Blobs already fetched or in flight did not contain this subscriber's rollup blobs, so
+        // they are fetched again starting from the subscriber's lowest search height.
+        if self.subscribers.is_empty() {
+            self.celestia_next_height = lowest_celestia_search_height;
+        } else {
+            self.celestia_next_height =
+                std::cmp::min(self.celestia_next_height, lowest_celestia_search_height);
+        }
+
+        self.subscribers.insert(
+            id,
+            Subscriber {
+                rollup_state,
+                firm_blocks,
+                block_cache,
+                is_block_enqueued: false,
+                celestia_reference_height: lowest_celestia_search_height,
+                celestia_search_height_max_look_ahead,
+                rollup_id,
+                rollup_namespace,
+            },
+        );
+        Ok(())
+    }
+
+    fn remove_subscriber(&mut self, id: SubscriberId, reason: &'static str) {
+        if self.subscribers.remove(&id).is_some() {
+            info!(subscriber.id = id, reason, "removed subscriber");
+        }
+    }
+
+    fn remove_subscribers_at_stop_height(&mut self) {
+        let at_stop_height: Vec<_> = self
+            .subscribers
+            .iter()
+            .filter(|(_, subscriber)| subscriber.has_reached_stop_height())
+            .map(|(id, _)| *id)
+            .collect();
+        for id in at_stop_height {
+            self.remove_subscriber(id, "stop height reached");
+        }
     }
 
     #[instrument(skip_all)]
     fn cache_reconstructed_blocks(&mut self, reconstructed: ReconstructedBlocks) {
-        for block in reconstructed.blocks {
-            let block_hash = block.block_hash;
-            let celestia_height = block.celestia_height;
-            let sequencer_height = block.sequencer_height().value();
-            if let Err(e) = self.block_cache.insert(block) {
-                warn!(
-                    error = %eyre::Report::new(e),
-                    source_celestia_height = celestia_height,
-                    sequencer_height,
-                    block_hash = %block_hash,
-                    "failed pushing reconstructed block into sequential cache; dropping it",
-                );
+        for (&id, subscriber) in &mut self.subscribers {
+            // Subscribers that were added after the blobs were requested are skipped; the blobs
+            // will be fetched again for them.
+            let Some(blocks) = reconstructed.blocks.get(&subscriber.rollup_id) else {
+                continue;
+            };
+            for block in blocks {
+                let sequencer_height = block.sequencer_height().value();
+                if sequencer_height < subscriber.block_cache.next_height_to_pop() {
+                    continue;
+                }
+                if let Err(e) = subscriber.block_cache.insert(block.clone()) {
+                    debug!(
+                        subscriber.id = id,
+                        error = %eyre::Report::new(e),
+                        source_celestia_height = block.celestia_height,
+                        sequencer_height,
+                        block_hash = %block.block_hash,
+                        "failed pushing reconstructed block into sequential cache; dropping it",
+                    );
+                }
             }
         }
     }
@@ -484,9 +638,12 @@ impl RunningReader {
         let Some(head_height) = self.celestia_head_height else {
             return false;
         };
+        let Some(max_permitted_celestia_height) = self.max_permitted_celestia_height() else {
+            return false;
+        };
 
         let is_next_below_head = self.celestia_next_height <= head_height;
-        let is_next_in_window = self.celestia_next_height <= self.max_permitted_celestia_height();
+        let is_next_in_window = self.celestia_next_height <= max_permitted_celestia_height;
         let is_capacity_in_task_set = self.reconstruction_tasks.len() < 10;
 
         is_next_below_head && is_next_in_window && is_capacity_in_task_set
@@ -498,14 +655,27 @@ impl RunningReader {
         while self.can_schedule_blobs() {
             let height = self.celestia_next_height;
             self.celestia_next_height = self.celestia_next_height.saturating_add(1);
+            let mut rollups: Vec<(RollupId, Namespace)> = vec![];
+            for subscriber in self.subscribers.values() {
+                if !rollups.iter().any(|(id, _)| *id == subscriber.rollup_id) {
+                    rollups.push((subscriber.rollup_id, subscriber.rollup_namespace));
+                }
+            }
+            let next_expected_firm_sequencer_height = self
+                .subscribers
+                .values()
+                .map(|subscriber| subscriber.block_cache.next_height_to_pop())
+                .min()
+                .expect("blobs are only scheduled if there are subscribers");
             let task = FetchConvertVerifyAndReconstruct {
                 blob_verifier: self.blob_verifier.clone(),
                 celestia_client: self.celestia_client.clone(),
                 celestia_height: height,
-                rollup_id: self.rollup_id,
-                rollup_namespace: self.rollup_namespace,
+                rollups,
+                sequencer_chain_id: self.sequencer_chain_id.clone(),
                 sequencer_namespace: self.sequencer_namespace,
-                rollup_state: self.rollup_state.clone(),
+                next_expected_firm_sequencer_height,
+                disk_cache: self.disk_cache.clone(),
                 metrics: self.metrics,
             };
             self.reconstruction_tasks.spawn(height, task.execute());
@@ -519,68 +689,101 @@ impl RunningReader {
         }
     }
 
-    fn advance_reference_celestia_height(&mut self, candidate: u64) {
-        let reference_height = &mut self.celestia_reference_height;
-        *reference_height = max(*reference_height, candidate);
-    }
-
+    /// Sends the next cached blocks to each subscriber's executor.
+    ///
+    /// Enqueues a block if an executor's channel is full, sending it once it frees up.
     #[instrument(skip_all)]
-    fn forward_block_to_executor(&mut self, block: ReconstructedBlock) -> eyre::Result<()> {
-        let celestia_height = block.celestia_height;
-        match self.firm_blocks.try_send(block.into()) {
-            Ok(()) => self.advance_reference_celestia_height(celestia_height),
-            Err(mpsc::error::TrySendError::Full(block)) => {
-                trace!(
-                    "executor channel is full; rescheduling block fetch until the channel opens up"
-                );
-                self.enqueued_block = enqueue_block(self.firm_blocks.clone(), block)
-                    .boxed()
-                    .fuse();
+    fn forward_cached_blocks(&mut self) {
+        let mut closed = vec![];
+        for (&id, subscriber) in &mut self.subscribers {
+            while !subscriber.is_block_enqueued {
+                let Some(block) = subscriber.block_cache.pop() else {
+                    break;
+                };
+                let celestia_height = block.celestia_height;
+                match subscriber.firm_blocks.try_send(block.into()) {
+                    Ok(()) => subscriber.advance_reference_celestia_height(celestia_height),
+                    Err(mpsc::error::TrySendError::Full(block)) => {
+                        trace!(
+                            subscriber.id = id,
+                            "executor channel is full; rescheduling block fetch until the channel \
+                             opens up"
+                        );
+                        self.enqueued_blocks.push(
+                            enqueue_block(subscriber.firm_blocks.clone(), block)
+                                .map(move |res| (id, res))
+                                .boxed(),
+                        );
+                        subscriber.is_block_enqueued = true;
+                    }
+                    Err(mpsc::error::TrySendError::Closed(_)) => {
+                        closed.push(id);
+                        break;
+                    }
+                }
             }
-            Err(mpsc::error::TrySendError::Closed(_)) => {
-                bail!("exiting because executor channel is closed");
+        }
+        for id in closed {
+            self.remove_subscriber(id, "executor channel was closed");
+        }
+    }
+
+    fn handle_enqueued_block(
+        &mut self,
+        id: SubscriberId,
+        res: Result<u64, mpsc::error::SendError<Box<ReconstructedBlock>>>,
+    ) {
+        match res {
+            Ok(celestia_height_of_forwarded_block) => {
+                if let Some(subscriber) = self.subscribers.get_mut(&id) {
+                    trace!(
+                        subscriber.id = id,
+                        "submitted enqueued block to executor, resuming normal operation"
+                    );
+                    subscriber.is_block_enqueued = false;
+                    subscriber
+                        .advance_reference_celestia_height(celestia_height_of_forwarded_block);
+                }
             }
-        };
-        Ok(())
+            Err(_) => self.remove_subscriber(id, "executor channel was closed"),
+        }
     }
 
-    /// Returns the maximum permitted Celestia height given the current state.
-    ///
-    /// The maximum permitted Celestia height is calculated as `ref_height +
-    /// celestia_search_height_max_look_ahead`, with:
+    /// Returns the maximum permitted Celestia height given the current state, which is the lowest
+    /// of the maximum permitted heights of all subscribers.
     ///
-    /// - `ref_height` the height from which the last expected sequencer block was derived,
-    /// - `celestia_search_height_max_look_ahead` received from the current rollup state,
-    fn max_permitted_celestia_height(&self) -> u64 {
-        self.celestia_reference_height
-            .saturating_add(self.celestia_search_height_max_look_ahead)
+    /// Returns `None` if there are no subscribers.
+    fn max_permitted_celestia_height(&self) -> Option<u64> {
+        self.subscribers
+            .values()
+            .map(Subscriber::max_permitted_celestia_height)
+            .min()
     }
 
     fn record_latest_celestia_height(&mut self, height: u64) {
         let head_height = self.celestia_head_height.get_or_insert(height);
         *head_height = max(*head_height, height);
     }
-
-    fn waiting_for_executor_capacity(&self) -> bool {
-        !self.enqueued_block.is_terminated()
-    }
 }
 
 struct FetchConvertVerifyAndReconstruct {
     blob_verifier: Arc<BlobVerifier>,
-    celestia_client: CelestiaClient,
+    celestia_client: Arc<dyn BlobReader>,
     celestia_height: u64,
-    rollup_id: RollupId,
-    rollup_namespace: Namespace,
+    /// The rollups for which blocks are reconstructed, with the namespaces of their blobs.
+    rollups: Vec<(RollupId, Namespace)>,
+    sequencer_chain_id: tendermint::chain::Id,
     sequencer_namespace: Namespace,
-    rollup_state: StateReceiver,
+    /// Header blobs below this height are dropped without verifying them.
+    next_expected_firm_sequencer_height: u64,
+    /// Consulted before fetching blobs from Celestia, and populated with the verified blobs.
+    disk_cache: Option<Arc<DiskCache>>,
     metrics: &'static Metrics,
 }
 
 impl FetchConvertVerifyAndReconstruct {
     #[instrument(skip_all, fields(
         celestia_height = self.celestia_height,
-        rollup_namespace = %base64(self.rollup_namespace.as_bytes()),
         sequencer_namespace = %base64(self.sequencer_namespace.as_bytes()),
         err,
     ))]
@@ -589,64 +792,79 @@ impl FetchConvertVerifyAndReconstruct {
             blob_verifier,
             celestia_client,
             celestia_height,
-            rollup_id,
-            rollup_namespace,
+            rollups,
+            sequencer_chain_id,
             sequencer_namespace,
-            rollup_state,
+            next_expected_firm_sequencer_height,
+            disk_cache,
             metrics,
         } = self;
+        let (rollup_ids, rollup_namespaces): (Vec<_>, Vec<_>) = rollups.into_iter().unzip();
+
+        let cached_blobs = if let Some(disk_cache) = disk_cache.clone() {
+            let rollup_ids = rollup_ids.clone();
+            let sequencer_chain_id = sequencer_chain_id.clone();
+            let get_span = info_span!("get_cached_blobs");
+            spawn_blocking(move || {
+                get_span.in_scope(|| {
+                    disk_cache.get(
+                        celestia_height,
+                        &rollup_ids,
+                        sequencer_chain_id.as_str(),
+                        next_expected_firm_sequencer_height,
+                    )
+                })
+            })
+            .await
+            .wrap_err("encountered panic while reading verified blobs from the disk cache")?
+        } else {
+            None
+        };
 
-        let new_blobs = fetch_new_blobs(
-            celestia_client,
-            celestia_height,
-            rollup_namespace,
-            sequencer_namespace,
-            metrics,
-        )
-        .await
-        .wrap_err("failed fetching blobs from Celestia")?;
-
-        metrics.record_metadata_blobs_per_celestia_fetch(new_blobs.len_header_blobs());
-        metrics.record_rollup_data_blobs_per_celestia_fetch(new_blobs.len_rollup_blobs());
-        info!(
-            number_of_metadata_blobs = new_blobs.len_header_blobs(),
-            number_of_rollup_blobs = new_blobs.len_rollup_blobs(),
-            "received new Celestia blobs"
-        );
-
-        let decode_span = info_span!("decode_blobs");
-        let decoded_blobs = spawn_blocking(move || {
-            decode_span
-                .in_scope(|| decode_raw_blobs(new_blobs, rollup_namespace, sequencer_namespace))
-        })
-        .await
-        .wrap_err("encountered panic while decoding raw Celestia blobs")?;
-
-        metrics.record_decoded_metadata_items_per_celestia_fetch(decoded_blobs.len_headers());
-        metrics.record_decoded_rollup_data_items_per_celestia_fetch(
-            decoded_blobs.len_rollup_data_entries(),
-        );
-        info!(
-            number_of_metadata_blobs = decoded_blobs.len_headers(),
-            number_of_rollup_blobs = decoded_blobs.len_rollup_data_entries(),
-            "decoded Sequencer header and rollup info from raw Celestia blobs",
-        );
-
-        let verified_blobs = verify_metadata(blob_verifier, decoded_blobs, rollup_state).await;
-
-        metrics.record_sequencer_blocks_metadata_verified_per_celestia_fetch(
-            verified_blobs.len_header_blobs(),
-        );
-        info!(
-            number_of_verified_header_blobs = verified_blobs.len_header_blobs(),
-            number_of_rollup_blobs = verified_blobs.len_rollup_blobs(),
-            "verified header blobs against Sequencer",
-        );
+        let verified_blobs = if let Some(cached_blobs) = cached_blobs {
+            info!(
+                number_of_verified_header_blobs = cached_blobs.len_header_blobs(),
+                number_of_rollup_blobs = cached_blobs.len_rollup_blobs(),
+                "read verified blobs from the disk cache",
+            );
+            cached_blobs
+        } else {
+            let verified_blobs = fetch_convert_and_verify(
+                blob_verifier,
+                celestia_client,
+                celestia_height,
+                rollup_namespaces,
+                sequencer_namespace,
+                next_expected_firm_sequencer_height,
+                metrics,
+            )
+            .await?;
+            match disk_cache {
+                Some(disk_cache) if verified_blobs.is_completely_verified() => {
+                    let rollup_ids = rollup_ids.clone();
+                    let insert_span = info_span!("insert_cached_blobs");
+                    spawn_blocking(move || {
+                        insert_span.in_scope(|| {
+                            disk_cache.insert(
+                                &verified_blobs,
+                                &rollup_ids,
+                                sequencer_chain_id.as_str(),
+                                next_expected_firm_sequencer_height,
+                            );
+                        });
+                        verified_blobs
+                    })
+                    .await
+                    .wrap_err("encountered panic while writing verified blobs to the disk cache")?
+                }
+                _ => verified_blobs,
+            }
+        };
 
         let reconstruct_span = info_span!("reconstruct_blocks");
         let reconstructed = spawn_blocking(move || {
             reconstruct_span
-                .in_scope(|| reconstruct_blocks_from_verified_blobs(verified_blobs, rollup_id))
+                .in_scope(|| reconstruct_blocks_from_verified_blobs(verified_blobs, &rollup_ids))
         })
         .await
         .wrap_err("encountered panic while reconstructing blocks from verified blobs")?;
@@ -656,10 +874,10 @@ impl FetchConvertVerifyAndReconstruct {
         };
 
         metrics.record_sequencer_block_information_reconstructed_per_celestia_fetch(
-            reconstructed_blocks.blocks.len(),
+            reconstructed_blocks.blocks.values().map(Vec::len).sum(),
         );
         info!(
-            number_of_final_reconstructed_blocks = reconstructed_blocks.blocks.len(),
+            number_of_final_reconstructed_blocks = reconstructed_blocks.blocks.values().map(Vec::len).sum::<usize>(),
             blocks = %json(&ReportReconstructedBlocks(&reconstructed_blocks)),
             "reconstructed block information by matching verified Sequencer header blobs to rollup blobs",
         );
@@ -668,6 +886,72 @@ impl FetchConvertVerifyAndReconstruct {
     }
 }
 
+/// Fetches the blobs at `celestia_height` from Celestia, decodes them, and verifies the
+/// Sequencer header blobs against Sequencer.
+async fn fetch_convert_and_verify(
+    blob_verifier: Arc<BlobVerifier>,
+    celestia_client: Arc<dyn BlobReader>,
+    celestia_height: u64,
+    rollup_namespaces: Vec<Namespace>,
+    sequencer_namespace: Namespace,
+    next_expected_firm_sequencer_height: u64,
+    metrics: &'static Metrics,
+) -> eyre::Result<VerifiedBlobs> {
+    let new_blobs = fetch_new_blobs(
+        celestia_client,
+        celestia_height,
+        &rollup_namespaces,
+        sequencer_namespace,
+        metrics,
+    )
+    .await
+    .wrap_err("failed fetching blobs from Celestia")?;
+
+    metrics.record_metadata_blobs_per_celestia_fetch(new_blobs.len_header_blobs());
+    metrics.record_rollup_data_blobs_per_celestia_fetch(new_blobs.len_rollup_blobs());
+    info!(
+        number_of_metadata_blobs = new_blobs.len_header_blobs(),
+        number_of_rollup_blobs = new_blobs.len_rollup_blobs(),
+        "received new Celestia blobs"
+    );
+
+    let decode_span = info_span!("decode_blobs");
+    let decoded_blobs = spawn_blocking(move || {
+        decode_span
+            .in_scope(|| decode_raw_blobs(new_blobs, &rollup_namespaces, sequencer_namespace))
+    })
+    .await
+    .wrap_err("encountered panic while decoding raw Celestia blobs")?;
+
+    metrics.record_decoded_metadata_items_per_celestia_fetch(decoded_blobs.len_headers());
+    metrics.record_decoded_rollup_data_items_per_celestia_fetch(
+        decoded_blobs.len_rollup_data_entries(),
+    );
+    info!(
+        number_of_metadata_blobs = decoded_blobs.len_headers(),
+        number_of_rollup_blobs = decoded_blobs.len_rollup_data_entries(),
+        "decoded Sequencer header and rollup info from raw Celestia blobs",
+    );
+
+    let verified_blobs = verify_metadata(
+        blob_verifier,
+        decoded_blobs,
+        next_expected_firm_sequencer_height,
+    )
+    .await;
+
+    metrics.record_sequencer_blocks_metadata_verified_per_celestia_fetch(
+        verified_blobs.len_header_blobs(),
+    );
+    info!(
+        number_of_verified_header_blobs = verified_blobs.len_header_blobs(),
+        number_of_rollup_blobs = verified_blobs.len_rollup_blobs(),
+        "verified header blobs against Sequencer",
+    );
+
+    Ok(verified_blobs)
+}
+
 #[instrument(skip_all, err)]
 async fn enqueue_block(
     firm_blocks_tx: mpsc::Sender<Box<ReconstructedBlock>>,
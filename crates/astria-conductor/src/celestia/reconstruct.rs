@@ -1,4 +1,7 @@
-use std::collections::HashMap;
+use std::collections::{
+    BTreeMap,
+    HashMap,
+};
 
 use astria_core::{
     primitive::v1::RollupId,
@@ -19,16 +22,57 @@ use super::{
     ReconstructedBlock,
 };
 
-/// Reconstructs block information from verified blocks.
+/// Reconstructs block information from verified blocks for each of `rollup_ids`.
+///
+/// The header blobs are shared by all rollups, while rollup blobs are only matched against the
+/// header blobs for the rollup they contain. See [`reconstruct_blocks_for_rollup`] for how blocks
+/// are reconstructed for a single rollup.
+pub(super) fn reconstruct_blocks_from_verified_blobs(
+    verified_blobs: VerifiedBlobs,
+    rollup_ids: &[RollupId],
+) -> BTreeMap<RollupId, Vec<ReconstructedBlock>> {
+    let (celestia_height, header_blobs, rollup_blobs) = verified_blobs.into_parts();
+
+    let mut rollup_blobs_by_id: BTreeMap<RollupId, Vec<SubmittedRollupData>> = rollup_ids
+        .iter()
+        .map(|&rollup_id| (rollup_id, vec![]))
+        .collect();
+    for rollup in rollup_blobs {
+        if let Some(blobs) = rollup_blobs_by_id.get_mut(&rollup.rollup_id()) {
+            blobs.push(rollup);
+        } else {
+            info!(
+                block_hash = %rollup.sequencer_block_hash(),
+                rollup_id = %rollup.rollup_id(),
+                "dropping rollup blob because its rollup ID is not one of the requested rollups",
+            );
+        }
+    }
+
+    rollup_blobs_by_id
+        .into_iter()
+        .map(|(rollup_id, rollup_blobs)| {
+            let blocks = reconstruct_blocks_for_rollup(
+                celestia_height,
+                header_blobs.clone(),
+                rollup_blobs,
+                rollup_id,
+            );
+            (rollup_id, blocks)
+        })
+        .collect()
+}
+
+/// Reconstructs block information for `rollup_id` from verified blobs.
 ///
 /// The reconstructed blocks contain a block hash, Sequencer header and rollup transactions
 /// (from rollup blobs, if present).
 ///
-/// The `verified_blobs` are guaranteed to have unique Sequencer block hashes.
+/// The `header_blobs` are guaranteed to have unique Sequencer block hashes.
 ///
 /// This process works in the following way:
 /// 1. Block execution containing rollup data: each rollup blob contains a sequencer block hash that
-///    is matched against `verified_blocks`
+///    is matched against `header_blobs`
 ///    - The rollup blob's rollup ID, transactions, and proof area used to reconstruct a Merkle Tree
 ///      Hash, which must match the root stored in the Sequencer header blob. If it does, a block is
 ///      reconstructed from the information stored in the header and rollup blobs. The sequencer
@@ -40,12 +84,12 @@ use super::{
 ///    - if they contained `rollup_id` they are dropped (as they should have had a matching blob but
 ///      none was found).
 ///    - if they did not contain `rollup_id` a Sequencer block is reconstructed.
-pub(super) fn reconstruct_blocks_from_verified_blobs(
-    verified_blobs: VerifiedBlobs,
+fn reconstruct_blocks_for_rollup(
+    celestia_height: u64,
+    mut header_blobs: HashMap<block::Hash, SubmittedMetadata>,
+    rollup_blobs: Vec<SubmittedRollupData>,
     rollup_id: RollupId,
 ) -> Vec<ReconstructedBlock> {
-    let (celestia_height, mut header_blobs, rollup_blobs) = verified_blobs.into_parts();
-
     let mut reconstructed_blocks = Vec::new();
 
     // match rollup blobs to header blobs
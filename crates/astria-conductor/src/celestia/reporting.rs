@@ -1,6 +1,10 @@
 //! Various newtype-wrappers to emit serde-serialized tracing event fields.
+use std::collections::BTreeMap;
+
+use astria_core::primitive::v1::RollupId;
 use serde::ser::{
     Serialize,
+    SerializeMap,
     SerializeSeq,
     SerializeStruct,
 };
@@ -19,11 +23,28 @@ impl Serialize for ReportReconstructedBlocks<'_> {
         const FIELDS: [&str; 2] = ["celestia_height", "reconstructed_blocks"];
         let mut state = serializer.serialize_struct("ReconstructedBlocksInfo", FIELDS.len())?;
         state.serialize_field(FIELDS[0], &self.0.celestia_height)?;
-        state.serialize_field(FIELDS[1], &ReportReconstructedBlocksSeq(&self.0.blocks))?;
+        state.serialize_field(
+            FIELDS[1],
+            &ReportReconstructedBlocksByRollup(&self.0.blocks),
+        )?;
         state.end()
     }
 }
 
+struct ReportReconstructedBlocksByRollup<'a>(&'a BTreeMap<RollupId, Vec<ReconstructedBlock>>);
+impl Serialize for ReportReconstructedBlocksByRollup<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (rollup_id, blocks) in self.0 {
+            map.serialize_entry(rollup_id, &ReportReconstructedBlocksSeq(blocks))?;
+        }
+        map.end()
+    }
+}
+
 struct ReportReconstructedBlocksSeq<'a>(&'a [ReconstructedBlock]);
 impl Serialize for ReportReconstructedBlocksSeq<'_> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -51,15 +51,52 @@ use super::{
     block_verifier,
     convert::ConvertedBlobs,
 };
-use crate::state::StateReceiver;
 
 pub(super) struct VerifiedBlobs {
     celestia_height: u64,
     header_blobs: HashMap<block::Hash, SubmittedMetadata>,
     rollup_blobs: Vec<SubmittedRollupData>,
+    /// The number of header blobs that could not be verified, for example because Sequencer
+    /// could not be reached.
+    len_unverified_header_blobs: usize,
 }
 
 impl VerifiedBlobs {
+    /// Constructs verified blobs from blobs that were verified before, for example when they
+    /// were loaded from the disk cache.
+    pub(super) fn from_parts(
+        celestia_height: u64,
+        header_blobs: HashMap<block::Hash, SubmittedMetadata>,
+        rollup_blobs: Vec<SubmittedRollupData>,
+    ) -> Self {
+        Self {
+            celestia_height,
+            header_blobs,
+            rollup_blobs,
+            len_unverified_header_blobs: 0,
+        }
+    }
+
+    pub(super) fn celestia_height(&self) -> u64 {
+        self.celestia_height
+    }
+
+    pub(super) fn header_blobs(&self) -> impl Iterator<Item = &SubmittedMetadata> {
+        self.header_blobs.values()
+    }
+
+    pub(super) fn rollup_blobs(&self) -> &[SubmittedRollupData] {
+        &self.rollup_blobs
+    }
+
+    /// Returns if all header blobs at or above the next expected firm height were verified.
+    ///
+    /// Header blobs that could not be verified are dropped, so blobs that are not completely
+    /// verified might be missing blocks if they were read again.
+    pub(super) fn is_completely_verified(&self) -> bool {
+        self.len_unverified_header_blobs == 0
+    }
+
     pub(super) fn len_header_blobs(&self) -> usize {
         self.header_blobs.len()
     }
@@ -91,20 +128,19 @@ struct VerificationTaskKey {
 
 /// Verifies Sequencer header blobs against Sequencer commits and validator sets.
 ///
-/// Drops blobs that could not be verified.
+/// Drops blobs that could not be verified, as well as blobs below
+/// `next_expected_firm_sequencer_height` without verifying them.
 #[instrument(skip_all)]
 pub(super) async fn verify_metadata(
     blob_verifier: Arc<BlobVerifier>,
     converted_blobs: ConvertedBlobs,
-    rollup_state: StateReceiver,
+    next_expected_firm_sequencer_height: u64,
 ) -> VerifiedBlobs {
     let (celestia_height, header_blobs, rollup_blobs) = converted_blobs.into_parts();
 
     let mut verification_tasks = JoinMap::new();
     let mut verified_header_blobs = HashMap::with_capacity(header_blobs.len());
-
-    let next_expected_firm_sequencer_height =
-        rollup_state.next_expected_firm_sequencer_height().value();
+    let mut len_unverified_header_blobs = 0usize;
 
     for (index, blob) in header_blobs.into_iter().enumerate() {
         if blob.height().value() < next_expected_firm_sequencer_height {
@@ -148,8 +184,11 @@ pub(super) async fn verify_metadata(
                     );
                 }
             }
-            Ok(None) => {}
+            Ok(None) => {
+                len_unverified_header_blobs = len_unverified_header_blobs.saturating_add(1);
+            }
             Err(error) => {
+                len_unverified_header_blobs = len_unverified_header_blobs.saturating_add(1);
                 info!(
                     block_hash = %key.block_hash,
                     sequencer_height = %key.sequencer_height,
@@ -164,6 +203,7 @@ pub(super) async fn verify_metadata(
         celestia_height,
         header_blobs: verified_header_blobs,
         rollup_blobs,
+        len_unverified_header_blobs,
     }
 }
 
@@ -22,10 +22,13 @@ use tracing::{
 };
 
 use crate::{
+    celestia,
     executor::{
         self,
     },
+    sequencer,
     state::State,
+    status::RollupStatusSender,
     Config,
     Metrics,
 };
@@ -48,7 +51,7 @@ impl std::fmt::Display for RestartOrShutdown {
     }
 }
 
-/// The business logic of Conductor.
+/// The business logic of Conductor, driving a single rollup.
 pub(super) struct Inner {
     /// Token to signal to all tasks to shut down gracefully.
     shutdown_token: CancellationToken,
@@ -64,19 +67,27 @@ pub(super) struct Inner {
 impl Inner {
     /// Create a new [`Inner`] from a [`Config`].
     ///
+    /// The executor subscribes to the readers behind `sequencer_reader` and `celestia_reader`,
+    /// which are shared with the executors of all other rollups.
+    ///
     /// # Errors
-    /// Returns an error in the following cases if one of its constituent
-    /// actors could not be spawned (executor, sequencer reader, or data availability reader).
-    /// This usually happens if the actors failed to connect to their respective endpoints.
+    /// Returns an error if the executor could not be constructed. This usually happens if the
+    /// execution API endpoint could not be parsed.
     pub(super) fn new(
         config: Config,
         metrics: &'static Metrics,
+        status: RollupStatusSender,
+        sequencer_reader: Option<sequencer::Handle>,
+        celestia_reader: Option<celestia::Handle>,
         shutdown_token: CancellationToken,
     ) -> eyre::Result<Self> {
         let executor = executor::Builder {
             config: config.clone(),
             shutdown: shutdown_token.clone(),
             metrics,
+            status,
+            sequencer_reader,
+            celestia_reader,
         }
         .build()
         .wrap_err("failed constructing executor")?;
@@ -240,6 +251,7 @@ mod tests {
         ExecutedBlockMetadata,
         ExecutionSessionParameters,
     };
+    use astria_data_availability::Backend;
     use astria_eyre::eyre::WrapErr as _;
     use pbjson_types::Timestamp;
 
@@ -261,10 +273,15 @@ mod tests {
             celestia_node_http_url: String::new(),
             no_celestia_auth: false,
             celestia_bearer_token: String::new(),
+            da_backend: Backend::Celestia,
+            local_da_dir: String::new(),
             sequencer_grpc_url: String::new(),
             sequencer_cometbft_url: String::new(),
             sequencer_block_time_ms: 0,
             sequencer_requests_per_second: 0,
+            firm_block_cache_dir: String::new(),
+            firm_block_cache_max_bytes: 0,
+            sequencer_optimistic_blocks: false,
             execution_rpc_url: String::new(),
             log: String::new(),
             execution_commit_level: CommitLevel::SoftAndFirm,
@@ -272,6 +289,7 @@ mod tests {
             no_otel: false,
             no_metrics: false,
             metrics_http_listener_addr: String::new(),
+            api_addr: String::new(),
         }
     }
 
@@ -1,29 +1,52 @@
 mod inner;
+mod rollup;
 
 use std::{
     future::Future,
+    net::SocketAddr,
     task::ready,
+    time::Duration,
 };
 
 use astria_eyre::eyre::{
     self,
+    ensure,
+    eyre,
     Result,
     WrapErr as _,
 };
-use inner::{
-    Inner,
-    RestartOrShutdown,
-};
 use pin_project_lite::pin_project;
-use tokio::task::{
-    JoinError,
-    JoinHandle,
+use rollup::Rollup;
+use sequencer_client::HttpClient;
+use tokio::{
+    select,
+    task::{
+        JoinError,
+        JoinHandle,
+    },
+    time::timeout,
+};
+use tokio_util::{
+    sync::CancellationToken,
+    task::JoinMap,
+};
+use tracing::{
+    error,
+    info,
+    instrument,
+    warn,
 };
-use tokio_util::sync::CancellationToken;
-use tracing::instrument;
 
 use crate::{
+    api,
+    celestia,
     metrics::Metrics,
+    sequencer,
+    status::{
+        ReaderStatus,
+        StatusSender,
+    },
+    utils::flatten,
     Config,
 };
 
@@ -68,81 +91,270 @@ impl Future for Handle {
             .expect("the Conductor handle must not be polled after shutdown");
 
         let res = ready!(task.poll_unpin(cx));
-        std::task::Poll::Ready(crate::utils::flatten(res))
+        std::task::Poll::Ready(flatten(res))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum ReaderKind {
+    Sequencer,
+    Celestia,
+}
+
+impl std::fmt::Display for ReaderKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            ReaderKind::Sequencer => "soft sequencer reader",
+            ReaderKind::Celestia => "firm celestia reader",
+        };
+        f.write_str(msg)
     }
 }
 
-/// A wrapper around [`ConductorInner`] that manages shutdown and restart of the conductor.
+/// Drives one or more rollups, each through its own executor, while sharing one Sequencer and one
+/// Celestia reader between them.
 pub struct Conductor {
     /// Token to signal to all tasks to shut down gracefully.
     shutdown_token: CancellationToken,
 
-    /// Handle for the inner conductor task.
-    inner: JoinHandle<eyre::Result<RestartOrShutdown>>,
+    /// Token to signal to the readers to shut down. Only cancelled after all rollups have shut
+    /// down so that no executor observes its channels closing before it.
+    readers_shutdown_token: CancellationToken,
+
+    /// The tasks driving the rollups, keyed by the URL of their execution API.
+    rollups: JoinMap<String, eyre::Result<()>>,
 
-    /// Configuration for the conductor, necessary upon a restart.
-    cfg: Config,
+    /// The tasks reading blocks from Sequencer and Celestia, shared by all rollups.
+    readers: JoinMap<ReaderKind, eyre::Result<()>>,
 
-    /// Metrics used by tasks, necessary upon a restart.
-    metrics: &'static Metrics,
+    /// The status of the conductor, shared by all tasks and served by the API.
+    status: StatusSender,
+
+    /// The address at which the API is served.
+    api_addr: SocketAddr,
 }
 
 impl Conductor {
     /// Creates a new `Conductor` from a [`Config`].
     ///
+    /// Spawns the readers required by the configured commit level and one task for each rollup
+    /// listed in the execution API URLs.
+    ///
     /// # Errors
-    /// Returns an error if [`ConductorInner`] could not be created.
+    /// Returns an error if the API address or the execution API URLs could not be parsed, if
+    /// optimistic execution is enabled without soft blocks, if a reader could not be constructed,
+    /// or if the task of a rollup could not be created.
     pub fn new(cfg: Config, metrics: &'static Metrics) -> eyre::Result<Self> {
+        let api_addr = cfg
+            .api_addr
+            .parse()
+            .wrap_err_with(|| format!("failed to parse API address `{}`", cfg.api_addr))?;
+        let execution_rpc_urls = cfg
+            .execution_rpc_urls()
+            .wrap_err("failed to parse execution API URLs")?;
+        ensure!(
+            !cfg.sequencer_optimistic_blocks || cfg.is_with_soft(),
+            "optimistic execution of Sequencer blocks requires a commit level that includes soft \
+             blocks, but the commit level is `{}`",
+            cfg.execution_commit_level,
+        );
         let shutdown_token = CancellationToken::new();
-        let inner = Inner::new(cfg.clone(), metrics, shutdown_token.child_token())?;
+        let readers_shutdown_token = CancellationToken::new();
+        let status = StatusSender::new();
+
+        let sequencer_cometbft_client = HttpClient::new(&*cfg.sequencer_cometbft_url)
+            .wrap_err("failed constructing sequencer cometbft RPC client")?;
+
+        let mut readers = JoinMap::new();
+
+        let sequencer_reader = if cfg.is_with_soft() {
+            let sequencer_grpc_client =
+                sequencer::SequencerGrpcClient::new(&cfg.sequencer_grpc_url)
+                    .wrap_err("failed constructing grpc client for Sequencer")?;
+            let (reader, handle) = sequencer::Builder {
+                sequencer_grpc_client,
+                sequencer_cometbft_client: sequencer_cometbft_client.clone(),
+                sequencer_block_time: Duration::from_millis(cfg.sequencer_block_time_ms),
+                shutdown: readers_shutdown_token.clone(),
+            }
+            .build();
+            readers.spawn(ReaderKind::Sequencer, reader.run_until_stopped());
+            status.set_sequencer_reader(ReaderStatus::Running);
+            Some(handle)
+        } else {
+            None
+        };
+
+        let celestia_reader = if cfg.is_with_firm() {
+            let celestia_token = if cfg.no_celestia_auth {
+                None
+            } else {
+                Some(cfg.celestia_bearer_token.clone())
+            };
+            let (reader, handle) = celestia::Builder {
+                da_backend: cfg.da_backend,
+                local_da_dir: (!cfg.local_da_dir.is_empty())
+                    .then(|| cfg.local_da_dir.clone().into()),
+                celestia_http_endpoint: cfg.celestia_node_http_url.clone(),
+                celestia_token,
+                celestia_block_time: Duration::from_millis(cfg.celestia_block_time_ms),
+                sequencer_cometbft_client,
+                sequencer_requests_per_second: cfg.sequencer_requests_per_second,
+                firm_block_cache_dir: (!cfg.firm_block_cache_dir.is_empty())
+                    .then(|| cfg.firm_block_cache_dir.clone().into()),
+                firm_block_cache_max_bytes: cfg.firm_block_cache_max_bytes,
+                shutdown: readers_shutdown_token.clone(),
+                metrics,
+            }
+            .build()
+            .wrap_err("failed to build Celestia Reader")?;
+            readers.spawn(ReaderKind::Celestia, reader.run_until_stopped());
+            status.set_celestia_reader(ReaderStatus::Running);
+            Some(handle)
+        } else {
+            None
+        };
+
+        let mut rollups = JoinMap::new();
+        for execution_rpc_url in execution_rpc_urls {
+            let rollup = Rollup::new(
+                Config {
+                    execution_rpc_url: execution_rpc_url.clone(),
+                    ..cfg.clone()
+                },
+                metrics,
+                status.rollup(execution_rpc_url.clone()),
+                sequencer_reader.clone(),
+                celestia_reader.clone(),
+                shutdown_token.child_token(),
+            )
+            .wrap_err_with(|| {
+                format!("failed to create task for rollup at `{execution_rpc_url}`")
+            })?;
+            rollups.spawn(execution_rpc_url, rollup.run_until_stopped());
+        }
+
         Ok(Self {
             shutdown_token,
-            inner: tokio::spawn(inner.run_until_stopped()),
-            cfg,
-            metrics,
+            readers_shutdown_token,
+            rollups,
+            readers,
+            status,
+            api_addr,
         })
     }
 
     async fn run_until_stopped(mut self) -> eyre::Result<()> {
-        loop {
-            let exit_reason = (&mut self.inner).await;
-            match self.restart_or_shutdown(exit_reason).await? {
-                RestartOrShutdown::Restart => self.restart()?,
-                RestartOrShutdown::Shutdown => break Ok(()),
+        let api_shutdown_token = CancellationToken::new();
+        let api_server = match api::serve(
+            self.api_addr,
+            self.status.subscribe(),
+            api_shutdown_token.clone(),
+        )
+        .await
+        {
+            Ok(api_server) => api_server,
+            Err(err) => {
+                self.shutdown_token.cancel();
+                self.shutdown_readers().await;
+                return Err(err.wrap_err("failed to start API server"));
+            }
+        };
+        info!(
+            api_address = %api_server.local_addr(),
+            "serving conductor API",
+        );
+        let mut api_task = tokio::spawn(async move {
+            match api_server.await {
+                Ok(()) => info!("API server exited"),
+                Err(error) => error!(%error, "API server exited with an error"),
             }
+        });
+
+        let res = self.run_rollups_until_stopped().await;
+        self.shutdown_rollups().await;
+        self.shutdown_readers().await;
+
+        api_shutdown_token.cancel();
+        if timeout(Duration::from_secs(1), &mut api_task)
+            .await
+            .is_err()
+        {
+            warn!("API server did not shut down within 1 second; aborting it");
+            api_task.abort();
         }
+        res
     }
 
-    /// Creates and spawns a new [`ConductorInner`] task with the same configuration, replacing
-    /// the previous one. This function should only be called after a graceful shutdown of the
-    /// inner conductor task.
-    #[instrument(skip_all, err)]
-    fn restart(&mut self) -> eyre::Result<()> {
-        self.inner = tokio::spawn(
-            Inner::new(
-                self.cfg.clone(),
-                self.metrics,
-                self.shutdown_token.child_token(),
-            )
-            .wrap_err("failed to instantiate Conductor for restart")?
-            .run_until_stopped(),
-        );
-        Ok(())
+    /// Runs the rollups until a shutdown signal is received, until all rollups have shut down, or
+    /// until a rollup or a reader failed.
+    async fn run_rollups_until_stopped(&mut self) -> eyre::Result<()> {
+        loop {
+            select!(
+                biased;
+
+                () = self.shutdown_token.cancelled() => break Ok(()),
+
+                Some((execution_rpc_url, res)) = self.rollups.join_next() => {
+                    if let Err(error) = flatten(res) {
+                        break Err(error.wrap_err(format!(
+                            "failed driving rollup at `{execution_rpc_url}`"
+                        )));
+                    }
+                    info!(%execution_rpc_url, "rollup shut down");
+                    if self.rollups.is_empty() {
+                        break Ok(());
+                    }
+                }
+
+                Some((reader, res)) = self.readers.join_next() => {
+                    set_reader_exited(&self.status, reader);
+                    let error = match flatten(res) {
+                        Ok(()) => eyre!("reader exited with success value"),
+                        Err(error) => error,
+                    };
+                    break Err(error.wrap_err(format!("task `{reader}` exited unexpectedly")));
+                }
+
+                else => break Ok(()),
+            );
+        }
     }
 
-    /// Reports if conductor will shutdown or restart.
-    ///
-    /// This method only exists to encapsulate tracing and generate
-    /// events for restart, shutdown, or errors.
-    #[instrument(skip_all, err, ret(Display))]
-    async fn restart_or_shutdown(
-        &mut self,
-        exit_reason: Result<Result<RestartOrShutdown>, JoinError>,
-    ) -> eyre::Result<RestartOrShutdown> {
-        match exit_reason {
-            Ok(Ok(restart_or_shutdown)) => Ok(restart_or_shutdown),
-            Ok(Err(err)) => Err(err.wrap_err("conductor exited with an error")),
-            Err(err) => Err(eyre::Report::new(err).wrap_err("conductor panicked")),
+    /// Signals all rollups to shut down and waits for them. Each rollup waits for its executor
+    /// to shut down before aborting it.
+    #[instrument(skip_all)]
+    async fn shutdown_rollups(&mut self) {
+        self.shutdown_token.cancel();
+        while let Some((execution_rpc_url, res)) = self.rollups.join_next().await {
+            match flatten(res) {
+                Ok(()) => info!(%execution_rpc_url, "rollup shut down"),
+                Err(error) => warn!(%execution_rpc_url, %error, "rollup shut down with an error"),
+            }
+        }
+    }
+
+    /// Signals the readers to shut down and waits 1 second for them before aborting them.
+    #[instrument(skip_all)]
+    async fn shutdown_readers(&mut self) {
+        self.readers_shutdown_token.cancel();
+        let readers = &mut self.readers;
+        let status = &self.status;
+        let wait_for_readers = async {
+            while let Some((reader, res)) = readers.join_next().await {
+                set_reader_exited(status, reader);
+                match flatten(res) {
+                    Ok(()) => info!(%reader, "task exited"),
+                    Err(error) => warn!(%reader, %error, "task exited"),
+                }
+            }
+        };
+        if timeout(Duration::from_secs(1), wait_for_readers)
+            .await
+            .is_err()
+        {
+            warn!("readers did not shut down within 1 second; aborting them");
+            self.readers.abort_all();
         }
     }
 
@@ -156,3 +368,10 @@ impl Conductor {
         }
     }
 }
+
+fn set_reader_exited(status: &StatusSender, reader: ReaderKind) {
+    match reader {
+        ReaderKind::Sequencer => status.set_sequencer_reader(ReaderStatus::Exited),
+        ReaderKind::Celestia => status.set_celestia_reader(ReaderStatus::Exited),
+    }
+}
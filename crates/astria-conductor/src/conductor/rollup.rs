@@ -0,0 +1,131 @@
+use astria_eyre::eyre::{
+    self,
+    Result,
+    WrapErr as _,
+};
+use tokio::task::{
+    JoinError,
+    JoinHandle,
+};
+use tokio_util::sync::CancellationToken;
+use tracing::instrument;
+
+use super::inner::{
+    Inner,
+    RestartOrShutdown,
+};
+use crate::{
+    celestia,
+    sequencer,
+    status::RollupStatusSender,
+    Config,
+    Metrics,
+};
+
+/// Drives a single rollup by running an [`Inner`] task, restarting it whenever a new execution
+/// session is required.
+pub(super) struct Rollup {
+    /// Token to signal to all tasks of this rollup to shut down gracefully.
+    shutdown_token: CancellationToken,
+
+    /// Handle for the inner conductor task.
+    inner: JoinHandle<eyre::Result<RestartOrShutdown>>,
+
+    /// Configuration for the rollup, necessary upon a restart.
+    cfg: Config,
+
+    /// Metrics used by tasks, necessary upon a restart.
+    metrics: &'static Metrics,
+
+    /// The status of the rollup, shared by all inner conductor tasks and served by the API.
+    status: RollupStatusSender,
+
+    /// The handle to subscribe to the shared Sequencer reader, necessary upon a restart.
+    sequencer_reader: Option<sequencer::Handle>,
+
+    /// The handle to subscribe to the shared Celestia reader, necessary upon a restart.
+    celestia_reader: Option<celestia::Handle>,
+}
+
+impl Rollup {
+    /// Creates a new `Rollup` and spawns its first [`Inner`] task.
+    ///
+    /// `cfg` is the configuration of this rollup, i.e. its `execution_rpc_url` is a single URL.
+    ///
+    /// # Errors
+    /// Returns an error if [`Inner`] could not be created.
+    pub(super) fn new(
+        cfg: Config,
+        metrics: &'static Metrics,
+        status: RollupStatusSender,
+        sequencer_reader: Option<sequencer::Handle>,
+        celestia_reader: Option<celestia::Handle>,
+        shutdown_token: CancellationToken,
+    ) -> eyre::Result<Self> {
+        let inner = Inner::new(
+            cfg.clone(),
+            metrics,
+            status.clone(),
+            sequencer_reader.clone(),
+            celestia_reader.clone(),
+            shutdown_token.child_token(),
+        )?;
+        Ok(Self {
+            shutdown_token,
+            inner: tokio::spawn(inner.run_until_stopped()),
+            cfg,
+            metrics,
+            status,
+            sequencer_reader,
+            celestia_reader,
+        })
+    }
+
+    /// Runs the inner conductor task, restarting it until it signals shutdown or fails.
+    #[instrument(skip_all, fields(execution_rpc_url = %self.cfg.execution_rpc_url), err)]
+    pub(super) async fn run_until_stopped(mut self) -> eyre::Result<()> {
+        loop {
+            let exit_reason = (&mut self.inner).await;
+            match self.restart_or_shutdown(exit_reason).await? {
+                RestartOrShutdown::Restart => self.restart()?,
+                RestartOrShutdown::Shutdown => break Ok(()),
+            }
+        }
+    }
+
+    /// Creates and spawns a new [`Inner`] task with the same configuration, replacing
+    /// the previous one. This function should only be called after a graceful shutdown of the
+    /// inner conductor task.
+    #[instrument(skip_all, err)]
+    fn restart(&mut self) -> eyre::Result<()> {
+        self.inner = tokio::spawn(
+            Inner::new(
+                self.cfg.clone(),
+                self.metrics,
+                self.status.clone(),
+                self.sequencer_reader.clone(),
+                self.celestia_reader.clone(),
+                self.shutdown_token.child_token(),
+            )
+            .wrap_err("failed to instantiate Conductor for restart")?
+            .run_until_stopped(),
+        );
+        Ok(())
+    }
+
+    /// Reports if conductor will shutdown or restart.
+    ///
+    /// This method only exists to encapsulate tracing and generate
+    /// events for restart, shutdown, or errors.
+    #[instrument(skip_all, err, ret(Display))]
+    async fn restart_or_shutdown(
+        &mut self,
+        exit_reason: Result<Result<RestartOrShutdown>, JoinError>,
+    ) -> eyre::Result<RestartOrShutdown> {
+        match exit_reason {
+            Ok(Ok(restart_or_shutdown)) => Ok(restart_or_shutdown),
+            Ok(Err(err)) => Err(err.wrap_err("conductor exited with an error")),
+            Err(err) => Err(eyre::Report::new(err).wrap_err("conductor panicked")),
+        }
+    }
+}
@@ -1,5 +1,10 @@
 //! The conductor configuration.
 
+use astria_data_availability::Backend;
+use astria_eyre::eyre::{
+    self,
+    ensure,
+};
 use serde::{
     Deserialize,
     Serialize,
@@ -52,6 +57,12 @@ pub struct Config {
     /// The JWT bearer token supplied with each jsonrpc call
     pub celestia_bearer_token: String,
 
+    /// The data availability layer from which firm blocks are read.
+    pub da_backend: Backend,
+
+    /// The directory used as data availability layer if `da_backend` is `Local`.
+    pub local_da_dir: String,
+
     /// URL of the Sequencer Cometbft gRPC service.
     pub sequencer_grpc_url: String,
 
@@ -63,7 +74,19 @@ pub struct Config {
     /// The number of requests per second that will be sent to Sequencer.
     pub sequencer_requests_per_second: u32,
 
-    /// Address of the RPC server for execution
+    /// The directory in which blobs read from Celestia and verified against Sequencer are cached
+    /// across restarts. The cache is disabled if empty.
+    pub firm_block_cache_dir: String,
+
+    /// The maximum total size of the files in `firm_block_cache_dir` in bytes.
+    pub firm_block_cache_max_bytes: u64,
+
+    /// Executes blocks proposed to Sequencer ahead of their commitment, promoting them to soft
+    /// once they are committed.
+    pub sequencer_optimistic_blocks: bool,
+
+    /// Address of the RPC server for execution, or a comma separated list of addresses to drive
+    /// one rollup per address.
     pub execution_rpc_url: String,
 
     /// log directive to use for telemetry.
@@ -84,6 +107,10 @@ pub struct Config {
 
     /// The endpoint which will be listened on for serving prometheus metrics
     pub metrics_http_listener_addr: String,
+
+    /// The socket address at which the HTTP API serving `/healthz`, `/readyz` and `/status`
+    /// listens.
+    pub api_addr: String,
 }
 
 impl Config {
@@ -94,6 +121,26 @@ impl Config {
     pub(crate) fn is_with_soft(&self) -> bool {
         self.execution_commit_level.is_with_soft()
     }
+
+    /// Returns the addresses of the execution RPC servers listed in `execution_rpc_url`, in the
+    /// order they were listed.
+    pub(crate) fn execution_rpc_urls(&self) -> eyre::Result<Vec<String>> {
+        let mut urls: Vec<String> = vec![];
+        for url in self
+            .execution_rpc_url
+            .split(',')
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+        {
+            ensure!(
+                !urls.iter().any(|listed| listed == url),
+                "execution RPC URL `{url}` is listed more than once"
+            );
+            urls.push(url.to_string());
+        }
+        ensure!(!urls.is_empty(), "no execution RPC URLs are defined");
+        Ok(urls)
+    }
 }
 
 impl config::Config for Config {
@@ -103,6 +150,7 @@ impl config::Config for Config {
 #[cfg(test)]
 mod tests {
     use super::{
+        Backend,
         CommitLevel,
         Config,
     };
@@ -131,4 +179,49 @@ mod tests {
         assert!(SoftAndFirm.is_with_firm());
         assert!(SoftAndFirm.is_with_soft());
     }
+
+    fn config_with_execution_rpc_url(execution_rpc_url: &str) -> Config {
+        Config {
+            celestia_block_time_ms: 0,
+            celestia_node_http_url: String::new(),
+            no_celestia_auth: false,
+            celestia_bearer_token: String::new(),
+            da_backend: Backend::Celestia,
+            local_da_dir: String::new(),
+            sequencer_grpc_url: String::new(),
+            sequencer_cometbft_url: String::new(),
+            sequencer_block_time_ms: 0,
+            sequencer_requests_per_second: 0,
+            firm_block_cache_dir: String::new(),
+            firm_block_cache_max_bytes: 0,
+            sequencer_optimistic_blocks: false,
+            execution_rpc_url: execution_rpc_url.to_string(),
+            log: String::new(),
+            execution_commit_level: CommitLevel::SoftAndFirm,
+            force_stdout: false,
+            no_otel: false,
+            no_metrics: false,
+            metrics_http_listener_addr: String::new(),
+            api_addr: String::new(),
+        }
+    }
+
+    #[test]
+    fn execution_rpc_urls_are_split_by_comma() {
+        let config =
+            config_with_execution_rpc_url("http://127.0.0.1:50051, http://127.0.0.1:50052,");
+        assert_eq!(
+            config.execution_rpc_urls().unwrap(),
+            vec!["http://127.0.0.1:50051", "http://127.0.0.1:50052"],
+        );
+    }
+
+    #[test]
+    fn duplicate_or_missing_execution_rpc_urls_are_rejected() {
+        let config = config_with_execution_rpc_url("http://127.0.0.1:50051,http://127.0.0.1:50051");
+        assert!(config.execution_rpc_urls().is_err());
+
+        let config = config_with_execution_rpc_url(" , ");
+        assert!(config.execution_rpc_urls().is_err());
+    }
 }
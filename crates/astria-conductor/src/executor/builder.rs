@@ -5,12 +5,20 @@ use astria_eyre::eyre::{
 use tokio_util::sync::CancellationToken;
 
 use super::Executor;
-use crate::metrics::Metrics;
+use crate::{
+    celestia,
+    metrics::Metrics,
+    sequencer,
+    status::RollupStatusSender,
+};
 
 pub(crate) struct Builder {
     pub(crate) config: crate::Config,
     pub(crate) shutdown: CancellationToken,
     pub(crate) metrics: &'static Metrics,
+    pub(crate) status: RollupStatusSender,
+    pub(crate) sequencer_reader: Option<sequencer::Handle>,
+    pub(crate) celestia_reader: Option<celestia::Handle>,
 }
 
 impl Builder {
@@ -19,6 +27,9 @@ impl Builder {
             config,
             shutdown,
             metrics,
+            status,
+            sequencer_reader,
+            celestia_reader,
         } = self;
 
         let client =
@@ -34,6 +45,9 @@ impl Builder {
             client,
             shutdown,
             metrics,
+            status,
+            sequencer_reader,
+            celestia_reader,
         };
         Ok(executor)
     }
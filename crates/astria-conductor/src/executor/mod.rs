@@ -1,7 +1,4 @@
-use std::{
-    collections::HashMap,
-    time::Duration,
-};
+use std::collections::HashMap;
 
 use astria_core::{
     execution::v2::{
@@ -10,12 +7,15 @@ use astria_core::{
     },
     primitive::v1::RollupId,
     protocol::price_feed::v1::ExtendedCommitInfoWithCurrencyPairMapping,
-    sequencerblock::v1::block::{
-        self,
-        FilteredSequencerBlock,
-        FilteredSequencerBlockParts,
-        PriceFeedData,
-        RollupData,
+    sequencerblock::{
+        optimistic::v1alpha1::SequencerBlockCommit,
+        v1::block::{
+            self,
+            FilteredSequencerBlock,
+            FilteredSequencerBlockParts,
+            PriceFeedData,
+            RollupData,
+        },
     },
 };
 use astria_eyre::eyre::{
@@ -23,25 +23,19 @@ use astria_eyre::eyre::{
     bail,
     ensure,
     eyre,
+    OptionExt as _,
     WrapErr as _,
 };
 use bytes::Bytes;
-use sequencer_client::{
-    tendermint::{
-        block::Height as SequencerHeight,
-        Time as TendermintTime,
-    },
-    HttpClient,
+use sequencer_client::tendermint::{
+    block::Height as SequencerHeight,
+    Time as TendermintTime,
 };
 use tokio::{
     select,
     sync::mpsc,
-    task::JoinError,
-};
-use tokio_util::{
-    sync::CancellationToken,
-    task::JoinMap,
 };
+use tokio_util::sync::CancellationToken;
 use tracing::{
     debug,
     debug_span,
@@ -53,14 +47,19 @@ use tracing::{
 };
 
 use crate::{
-    celestia::ReconstructedBlock,
+    celestia::{
+        self,
+        ReconstructedBlock,
+    },
     config::CommitLevel,
     metrics::Metrics,
+    sequencer,
     state::{
         self,
         State,
         StateSender,
     },
+    status::RollupStatusSender,
 };
 
 mod builder;
@@ -84,22 +83,15 @@ pub(crate) struct Executor {
     shutdown: CancellationToken,
 
     metrics: &'static Metrics,
-}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-enum ReaderKind {
-    Firm,
-    Soft,
-}
+    /// The status of the rollup served by Conductor's HTTP API.
+    status: RollupStatusSender,
 
-impl std::fmt::Display for ReaderKind {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let msg = match self {
-            ReaderKind::Firm => "firm celestia reader",
-            ReaderKind::Soft => "soft sequencer reader",
-        };
-        f.write_str(msg)
-    }
+    /// The handle to subscribe to soft blocks. Set if the commit level includes soft blocks.
+    sequencer_reader: Option<sequencer::Handle>,
+
+    /// The handle to subscribe to firm blocks. Set if the commit level includes firm blocks.
+    celestia_reader: Option<celestia::Handle>,
 }
 
 impl Executor {
@@ -115,6 +107,8 @@ impl Executor {
     pub(crate) async fn run_until_stopped_or_stop_height_reached(
         self,
     ) -> eyre::Result<Option<State>> {
+        self.status.reset();
+
         let initialized = select!(
             biased;
 
@@ -139,11 +133,7 @@ impl Executor {
             .create_initial_node_state()
             .await
             .wrap_err("failed setting initial rollup node state")?;
-
-        let sequencer_cometbft_client = HttpClient::new(&*self.config.sequencer_cometbft_url)
-            .wrap_err("failed constructing sequencer cometbft RPC client")?;
-
-        let reader_cancellation_token = self.shutdown.child_token();
+        self.status.set_rollup_state(&state.get());
 
         let Channels {
             firm_sender: firm_blocks_tx,
@@ -153,58 +143,54 @@ impl Executor {
         } = create_block_channels(self.config.execution_commit_level, &state)
             .wrap_err("failed to create channels")?;
 
-        let mut reader_tasks = JoinMap::new();
         if self.config.is_with_firm() {
-            let celestia_token = if self.config.no_celestia_auth {
-                None
+            self.celestia_reader
+                .as_ref()
+                .ok_or_eyre("commit level includes firm blocks but no Celestia reader is running")?
+                .subscribe(state.subscribe(), firm_blocks_tx)
+                .await
+                .wrap_err("failed subscribing to Celestia reader")?;
+        }
+
+        let (optimistic, proposed_blocks, block_commitments) =
+            if self.config.is_with_soft() && self.config.sequencer_optimistic_blocks {
+                let (proposed_blocks_tx, proposed_blocks_rx) = mpsc::channel(16);
+                let (block_commitments_tx, block_commitments_rx) = mpsc::channel(16);
+                (
+                    Some(sequencer::OptimisticSenders {
+                        proposed_blocks: proposed_blocks_tx,
+                        block_commitments: block_commitments_tx,
+                    }),
+                    Some(proposed_blocks_rx),
+                    Some(block_commitments_rx),
+                )
             } else {
-                Some(self.config.celestia_bearer_token.clone())
+                (None, None, None)
             };
 
-            let reader = crate::celestia::Builder {
-                celestia_http_endpoint: self.config.celestia_node_http_url.clone(),
-                celestia_token,
-                celestia_block_time: Duration::from_millis(self.config.celestia_block_time_ms),
-                firm_blocks: firm_blocks_tx,
-                rollup_state: state.subscribe(),
-                sequencer_cometbft_client: sequencer_cometbft_client.clone(),
-                sequencer_requests_per_second: self.config.sequencer_requests_per_second,
-                shutdown: reader_cancellation_token.child_token(),
-                metrics: self.metrics,
-            }
-            .build()
-            .wrap_err("failed to build Celestia Reader")?;
-            reader_tasks.spawn(ReaderKind::Firm, reader.run_until_stopped());
-        }
-
         if self.config.is_with_soft() {
-            let sequencer_grpc_client =
-                crate::sequencer::SequencerGrpcClient::new(&self.config.sequencer_grpc_url)
-                    .wrap_err("failed constructing grpc client for Sequencer")?;
-
-            let sequencer_reader = crate::sequencer::Builder {
-                sequencer_grpc_client,
-                sequencer_cometbft_client: sequencer_cometbft_client.clone(),
-                sequencer_block_time: Duration::from_millis(self.config.sequencer_block_time_ms),
-                shutdown: reader_cancellation_token.child_token(),
-                soft_blocks: soft_blocks_tx,
-                rollup_state: state.subscribe(),
-            }
-            .build();
-            reader_tasks.spawn(ReaderKind::Soft, sequencer_reader.run_until_stopped());
-        };
+            self.sequencer_reader
+                .as_ref()
+                .ok_or_eyre("commit level includes soft blocks but no Sequencer reader is running")?
+                .subscribe(state.subscribe(), soft_blocks_tx, optimistic)
+                .await
+                .wrap_err("failed subscribing to Sequencer reader")?;
+        }
 
+        self.status.set_executor_initialized();
         Ok(Initialized {
             config: self.config,
             client: self.client,
             firm_blocks: firm_blocks_rx,
             soft_blocks: soft_blocks_rx,
+            proposed_blocks,
+            block_commitments,
+            optimistic_block: None,
             shutdown: self.shutdown,
             state,
             blocks_pending_finalization: HashMap::new(),
             metrics: self.metrics,
-            reader_tasks,
-            reader_cancellation_token,
+            status: self.status,
         })
     }
 
@@ -302,6 +288,19 @@ struct Initialized {
     /// soft commitments.
     soft_blocks: mpsc::Receiver<FilteredSequencerBlock>,
 
+    /// The channel of which this executor receives blocks proposed to Sequencer, executed
+    /// optimistically ahead of their commitment. Only set if optimistic execution is enabled.
+    proposed_blocks: Option<mpsc::Receiver<FilteredSequencerBlock>>,
+
+    /// The channel of which this executor receives commitments of blocks proposed to Sequencer,
+    /// used to promote optimistically executed blocks to soft. Only set if optimistic execution
+    /// is enabled.
+    block_commitments: Option<mpsc::Receiver<SequencerBlockCommit>>,
+
+    /// The last proposed block that was executed on top of the soft commitment and that is
+    /// waiting for its commitment.
+    optimistic_block: Option<OptimisticBlock>,
+
     /// Token to listen for Conductor being shut down.
     shutdown: CancellationToken,
 
@@ -316,11 +315,8 @@ struct Initialized {
 
     metrics: &'static Metrics,
 
-    /// The tasks reading block data off Celestia or Sequencer.
-    reader_tasks: JoinMap<ReaderKind, eyre::Result<()>>,
-
-    /// The cancellation token specifically for signaling the `reader_tasks` to shut down.
-    reader_cancellation_token: CancellationToken,
+    /// The status of the rollup served by Conductor's HTTP API.
+    status: RollupStatusSender,
 }
 
 impl Initialized {
@@ -343,6 +339,11 @@ impl Initialized {
 
     async fn run_event_loop(&mut self) -> eyre::Result<Option<State>> {
         loop {
+            if self.has_reached_stop_height() {
+                info!("rollup has reached stop height; closing channels");
+                break Ok(Some(self.state.get().clone()));
+            }
+
             select!(
                 biased;
 
@@ -366,15 +367,44 @@ impl Initialized {
                     self.execute_soft(block).await.wrap_err("failed executing soft block")?;
                 }
 
-                Some((task, res)) = self.reader_tasks.join_next() => {
-                    self.handle_task_exit(task, res)?;
+                Some(commitment) = recv_optional(&mut self.block_commitments) => {
+                    self.handle_block_commitment(commitment)
+                        .await
+                        .wrap_err("failed promoting optimistic block to soft")?;
                 }
 
-                else => break Ok(Some(self.state.get().clone()))
+                Some(block) = recv_optional(&mut self.proposed_blocks), if !self.is_spread_too_large() => {
+                    debug_span!("conductor::Executor::run_until_stopped").in_scope(||debug!(
+                        block.height = %block.height(),
+                        block.hash = %block.block_hash(),
+                        "received proposed block from sequencer",
+                    ));
+                    self.execute_optimistic(block)
+                        .await
+                        .wrap_err("failed executing optimistic block")?;
+                }
+
+                else => break Err(eyre!(
+                    "the readers closed all block channels before the stop height was reached"
+                )),
             );
         }
     }
 
+    /// Returns if the stop height was reached by the commitments that are driven by the
+    /// Celestia reader if the commit level includes firm blocks, or by the Sequencer reader
+    /// otherwise.
+    ///
+    /// The readers drop their channels to the executor once they forwarded the block at the stop
+    /// height.
+    fn has_reached_stop_height(&self) -> bool {
+        if self.config.is_with_firm() {
+            self.state.has_firm_number_reached_stop_height()
+        } else {
+            self.state.has_soft_number_reached_stop_height()
+        }
+    }
+
     /// Returns if the spread between firm and soft commitment heights in the tracked state is too
     /// large.
     ///
@@ -428,6 +458,18 @@ impl Initialized {
             std::cmp::Ordering::Equal => {}
         }
 
+        if let Some(optimistic_block) = self.optimistic_block.take() {
+            if optimistic_block.block_hash == executable_block.hash {
+                info!("block was already executed optimistically; promoting it to soft");
+                return self.promote_optimistic_block(optimistic_block).await;
+            }
+            info!(
+                optimistic_block.height = %optimistic_block.sequencer_height,
+                optimistic_block.hash = %optimistic_block.block_hash,
+                "optimistically executed block was not committed; reverting it",
+            );
+        }
+
         let sequencer_start_block_height = self.state.sequencer_start_block_height();
         let rollup_start_block_number = self.state.rollup_start_block_number();
         let current_block_height = executable_block.height;
@@ -464,6 +506,130 @@ impl Initialized {
         Ok(())
     }
 
+    /// Executes a block proposed to Sequencer on top of the soft commitment without updating
+    /// the commitment state.
+    ///
+    /// The executed block replaces (and thus reverts) a previously executed optimistic block.
+    /// Blocks that are not proposed at the next expected soft height are ignored.
+    #[instrument(skip_all, fields(
+        block.hash = %block.block_hash(),
+        block.height = block.height().value(),
+        err,
+    ))]
+    async fn execute_optimistic(&mut self, block: FilteredSequencerBlock) -> eyre::Result<()> {
+        let expected_height = self.state.next_expected_soft_sequencer_height();
+        if block.height() != expected_height {
+            debug!(
+                expected_height.sequencer_block = %expected_height,
+                "proposed block is not at the next expected soft height; ignoring it",
+            );
+            return Ok(());
+        }
+        if self
+            .optimistic_block
+            .as_ref()
+            .is_some_and(|optimistic_block| &optimistic_block.block_hash == block.block_hash())
+        {
+            debug!("proposed block was already executed optimistically; ignoring it");
+            return Ok(());
+        }
+
+        let executable_block = ExecutableBlock::from_sequencer(block, self.state.rollup_id());
+        let sequencer_height = executable_block.height;
+        let block_hash = executable_block.hash;
+        let block_number = state::try_map_sequencer_height_to_rollup_height(
+            self.state.sequencer_start_block_height(),
+            self.state.rollup_start_block_number(),
+            sequencer_height,
+        )
+        .wrap_err("failed to map current block height to rollup number")?;
+
+        let parent_hash = self.state.soft_hash();
+        let session_id = self.state.execution_session_id();
+        let executed_block = self
+            .execute_block(session_id, parent_hash, executable_block)
+            .await
+            .wrap_err("failed to execute block")?;
+
+        self.does_block_response_fulfill_contract(ExecutionKind::Soft, &executed_block)
+            .wrap_err("execution API server violated contract")?;
+
+        if let Some(reverted) = self.optimistic_block.replace(OptimisticBlock {
+            sequencer_height,
+            block_hash,
+            block_number,
+            executed_block,
+        }) {
+            info!(
+                reverted.hash = %reverted.block_hash,
+                "replaced optimistically executed block at the same height; reverting it",
+            );
+        }
+        Ok(())
+    }
+
+    /// Promotes the optimistically executed block to soft if `commitment` commits it, and reverts
+    /// it if the commitment is for a different block at its height.
+    #[instrument(skip_all, fields(
+        commitment.height = commitment.height(),
+        commitment.hash = %commitment.block_hash(),
+        err,
+    ))]
+    async fn handle_block_commitment(
+        &mut self,
+        commitment: SequencerBlockCommit,
+    ) -> eyre::Result<()> {
+        let Some(optimistic_block) = self.optimistic_block.take() else {
+            return Ok(());
+        };
+        match optimistic_block_fate(
+            &optimistic_block,
+            &commitment,
+            self.state.next_expected_soft_sequencer_height(),
+        ) {
+            OptimisticBlockFate::Promote => {
+                self.promote_optimistic_block(optimistic_block).await?;
+            }
+            OptimisticBlockFate::Revert => {
+                info!(
+                    optimistic_block.height = %optimistic_block.sequencer_height,
+                    optimistic_block.hash = %optimistic_block.block_hash,
+                    "optimistically executed block was not committed or is obsolete; reverting it",
+                );
+            }
+            OptimisticBlockFate::Keep => {
+                self.optimistic_block = Some(optimistic_block);
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the soft commitment to the optimistically executed block.
+    async fn promote_optimistic_block(
+        &mut self,
+        optimistic_block: OptimisticBlock,
+    ) -> eyre::Result<()> {
+        let OptimisticBlock {
+            block_number,
+            executed_block,
+            ..
+        } = optimistic_block;
+
+        self.update_commitment_state(Update::OnlySoft(executed_block.clone()))
+            .await
+            .wrap_err("failed to update soft commitment state")?;
+
+        self.blocks_pending_finalization
+            .insert(block_number, executed_block);
+
+        // XXX: We set an absolute number value here to avoid any potential issues of the remote
+        // rollup state and the local state falling out of lock-step.
+        self.metrics
+            .absolute_set_executed_soft_block_number(block_number);
+
+        Ok(())
+    }
+
     #[instrument(skip_all, fields(
         block.hash = %block.block_hash(),
         block.height = block.sequencer_height().value(),
@@ -637,6 +803,7 @@ impl Initialized {
         self.state
             .try_update_commitment_state(new_state, commit_level)
             .wrap_err("failed updating internal state tracking rollup state; invalid?")?;
+        self.status.set_rollup_state(&self.state.get());
         Ok(())
     }
 
@@ -661,83 +828,6 @@ impl Initialized {
         )
     }
 
-    #[instrument(skip_all, err)]
-    fn handle_task_exit(
-        &mut self,
-        task: ReaderKind,
-        res: Result<eyre::Result<()>, JoinError>,
-    ) -> eyre::Result<()> {
-        match task {
-            ReaderKind::Firm if self.config.is_with_firm() => {
-                match (
-                    self.state.rollup_end_block_number().is_some(),
-                    self.state.has_firm_number_reached_stop_height(),
-                ) {
-                    (true, true) => {
-                        info!(
-                            "firm number has reached stop height; signalling all readers to stop \
-                             and closing channels"
-                        );
-                        self.reader_cancellation_token.cancel();
-                        self.firm_blocks.close();
-                        self.soft_blocks.close();
-                        Ok(())
-                    }
-
-                    (true, false) => match res {
-                        Ok(Ok(())) => Err(eyre!("task exited with success value")),
-                        Ok(Err(err)) => Err(err).wrap_err("task exited with error"),
-                        Err(err) => Err(err).wrap_err("task panicked"),
-                    }
-                    .wrap_err_with(|| {
-                        format!("task `{task}` exited unexpectedly before stop height was reached")
-                    }),
-
-                    // fall-through case, no stop height was configured
-                    (false, _) => match res {
-                        Ok(Ok(())) => Err(eyre!("task exited with sucess value")),
-                        Ok(Err(err)) => Err(err).wrap_err("task exited with error"),
-                        Err(err) => Err(err).wrap_err("task panicked"),
-                    }
-                    .wrap_err_with(|| format!("task `{task}` exited unexpectedly")),
-                }
-            }
-
-            ReaderKind::Soft if self.config.is_with_soft() => {
-                match (
-                    self.state.rollup_end_block_number().is_some(),
-                    self.state.has_soft_number_reached_stop_height(),
-                ) {
-                    (true, true) => {
-                        info!("soft number has reached stop height");
-                        Ok(())
-                    }
-                    (true, false) => match res {
-                        Ok(Ok(())) => Err(eyre!("task exited with success value")),
-                        Ok(Err(err)) => Err(err).wrap_err("task exited with error"),
-                        Err(err) => Err(err).wrap_err("task panicked"),
-                    }
-                    .wrap_err_with(|| {
-                        format!("task `{task}` exited unexpectedly before stop height was reached")
-                    }),
-
-                    (false, _) => match res {
-                        Ok(Ok(())) => Err(eyre!("task exited with sucess value")),
-                        Ok(Err(err)) => Err(err).wrap_err("task exited with error"),
-                        Err(err) => Err(err).wrap_err("task panicked"),
-                    }
-                    .wrap_err_with(|| format!("task `{task}` exited unexpectedly")),
-                }
-            }
-
-            ReaderKind::Firm | ReaderKind::Soft => Err(eyre!(
-                "task `{task}` exited but it shouldn't have run in the first place because \
-                 because commit level is set to `{}`",
-                self.config.execution_commit_level
-            )),
-        }
-    }
-
     #[instrument(skip_all)]
     async fn shutdown(mut self, reason: &eyre::Result<Option<State>>) {
         let message = "shutting down";
@@ -750,16 +840,9 @@ impl Initialized {
             }
         }
 
-        info!("signaling all reader tasks to exit, closing all channels");
-        self.reader_cancellation_token.cancel();
+        info!("closing all channels, unsubscribing from the readers");
         self.firm_blocks.close();
         self.soft_blocks.close();
-        while let Some((task, exit_status)) = self.reader_tasks.join_next().await {
-            match crate::utils::flatten(exit_status) {
-                Ok(()) => info!(%task, "task exited"),
-                Err(error) => warn!(%task, %error, "task exited"),
-            }
-        }
     }
 }
 
@@ -769,6 +852,52 @@ enum Update {
     ToSame(ExecutedBlockMetadata, CelestiaHeight),
 }
 
+/// A block proposed to Sequencer that was executed on top of the soft commitment ahead of its
+/// commitment.
+#[derive(Debug)]
+struct OptimisticBlock {
+    sequencer_height: SequencerHeight,
+    block_hash: block::Hash,
+    block_number: u64,
+    executed_block: ExecutedBlockMetadata,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum OptimisticBlockFate {
+    /// The block was committed; set the soft commitment to it.
+    Promote,
+    /// The block was not committed or the soft commitment moved past it; drop it.
+    Revert,
+    /// The commitment is for an earlier block; wait for the block's own commitment.
+    Keep,
+}
+
+fn optimistic_block_fate(
+    optimistic_block: &OptimisticBlock,
+    commitment: &SequencerBlockCommit,
+    next_expected_soft_height: SequencerHeight,
+) -> OptimisticBlockFate {
+    let height = optimistic_block.sequencer_height.value();
+    if height != next_expected_soft_height.value() {
+        return OptimisticBlockFate::Revert;
+    }
+    match commitment.height().cmp(&height) {
+        std::cmp::Ordering::Less => OptimisticBlockFate::Keep,
+        std::cmp::Ordering::Equal if commitment.block_hash() == &optimistic_block.block_hash => {
+            OptimisticBlockFate::Promote
+        }
+        std::cmp::Ordering::Equal | std::cmp::Ordering::Greater => OptimisticBlockFate::Revert,
+    }
+}
+
+/// Receives the next item over `channel`, or returns `None` if it is not set.
+async fn recv_optional<T>(channel: &mut Option<mpsc::Receiver<T>>) -> Option<T> {
+    match channel {
+        Some(channel) => channel.recv().await,
+        None => None,
+    }
+}
+
 #[derive(Debug)]
 struct ExecutableBlock {
     hash: block::Hash,
@@ -6,6 +6,10 @@ use astria_core::{
     },
     generated::astria::execution::v2 as raw,
     protocol::price_feed::v1::ExtendedCommitInfoWithCurrencyPairMapping,
+    sequencerblock::{
+        optimistic::v1alpha1::SequencerBlockCommit,
+        v1::block,
+    },
     Protobuf as _,
 };
 use bytes::Bytes;
@@ -24,12 +28,15 @@ use tendermint::{
 };
 
 use super::{
+    optimistic_block_fate,
     should_execute_firm_block,
     state::{
         State,
         StateReceiver,
         StateSender,
     },
+    OptimisticBlock,
+    OptimisticBlockFate,
 };
 use crate::{
     config::CommitLevel,
@@ -224,6 +231,45 @@ fn should_execute_firm() {
     );
 }
 
+#[test]
+fn optimistic_block_is_promoted_only_if_committed_at_next_soft_height() {
+    let optimistic_block = OptimisticBlock {
+        sequencer_height: 5u32.into(),
+        block_hash: block::Hash::new([1; 32]),
+        block_number: 5,
+        executed_block: ExecutedBlockMetadata::try_from_raw(make_block_metadata(5)).unwrap(),
+    };
+    let fate = |commitment_height: u64, commitment_hash: [u8; 32], next_soft_height: u32| {
+        optimistic_block_fate(
+            &optimistic_block,
+            &SequencerBlockCommit::new(commitment_height, block::Hash::new(commitment_hash)),
+            next_soft_height.into(),
+        )
+    };
+
+    assert_eq!(fate(5, [1; 32], 5), OptimisticBlockFate::Promote);
+    assert_eq!(
+        fate(5, [2; 32], 5),
+        OptimisticBlockFate::Revert,
+        "a different block committed at the same height must revert the optimistic block"
+    );
+    assert_eq!(
+        fate(4, [2; 32], 5),
+        OptimisticBlockFate::Keep,
+        "the commitment of an earlier block must not affect the optimistic block"
+    );
+    assert_eq!(
+        fate(6, [2; 32], 5),
+        OptimisticBlockFate::Revert,
+        "a later commitment means the optimistic block was skipped"
+    );
+    assert_eq!(
+        fate(5, [1; 32], 6),
+        OptimisticBlockFate::Revert,
+        "an optimistic block below the next soft height is obsolete"
+    );
+}
+
 #[test]
 fn should_prepend_valid_price_feed_data_to_txs() {
     let tx = Bytes::from(vec![1; 1]);
@@ -30,6 +30,7 @@ use tracing::{
 #[derive(Clone)]
 pub(crate) struct SequencerGrpcClient {
     inner: SequencerServiceClient<Channel>,
+    channel: Channel,
     uri: Uri,
 }
 
@@ -42,14 +43,21 @@ impl SequencerGrpcClient {
         let endpoint = Endpoint::from(uri.clone())
             .tls_config(ClientTlsConfig::new().with_enabled_roots())
             .wrap_err("failed to configure TLS for sequencer client")?;
-        let inner = SequencerServiceClient::new(endpoint.connect_lazy());
+        let channel = endpoint.connect_lazy();
+        let inner = SequencerServiceClient::new(channel.clone());
         Ok(Self {
             inner,
+            channel,
             uri,
         })
     }
 
-    /// Fetch a sequencer block filtered by `rollup_id`.
+    /// The channel to Sequencer, shared with clients of its other gRPC services.
+    pub(super) fn channel(&self) -> Channel {
+        self.channel.clone()
+    }
+
+    /// Fetch a sequencer block filtered by `rollup_ids`.
     ///
     /// This method includes retry logic with a maximum delay
     /// up to 10 seconds. The retry logic must live in this method rather
@@ -59,13 +67,12 @@ impl SequencerGrpcClient {
     #[instrument(skip_all, fields(
         uri = %self.uri,
         height,
-        %rollup_id,
         err,
     ))]
     pub(super) async fn get(
         &mut self,
         height: u64,
-        rollup_id: RollupId,
+        rollup_ids: Vec<RollupId>,
     ) -> eyre::Result<FilteredSequencerBlock> {
         debug!("requesting filtered block from sequencer");
 
@@ -95,7 +102,7 @@ impl SequencerGrpcClient {
             let mut client = client.clone();
             let req = GetFilteredSequencerBlockRequest {
                 height,
-                rollup_ids: vec![rollup_id.to_raw()],
+                rollup_ids: rollup_ids.iter().map(RollupId::to_raw).collect(),
             };
             async move { client.get_filtered_sequencer_block(req).await }
         })
@@ -1,21 +1,34 @@
 //! [`Reader`] reads reads blocks from sequencer and forwards them to [`crate::executor::Executor`].
+//!
+//! A single [`Reader`] is shared by the executors of all rollups driven by conductor. Executors
+//! subscribe to it through a [`Handle`]. If requested by its subscribers, the [`Reader`] also
+//! forwards the blocks proposed to Sequencer and their commitments for optimistic execution.
+
+use std::{
+    collections::BTreeMap,
+    num::NonZeroU64,
+    time::Duration,
+};
 
-use std::time::Duration;
-
-use astria_core::sequencerblock::v1::block::FilteredSequencerBlock;
+use astria_core::{
+    primitive::v1::RollupId,
+    sequencerblock::{
+        optimistic::v1alpha1::SequencerBlockCommit,
+        v1::block::FilteredSequencerBlock,
+    },
+};
 use astria_eyre::eyre::{
     self,
-    bail,
     ensure,
+    eyre,
     Report,
     WrapErr as _,
 };
 use futures::{
-    future::{
-        self,
-        BoxFuture,
-        Fuse,
-        FusedFuture as _,
+    future::BoxFuture,
+    stream::{
+        BoxStream,
+        FuturesUnordered,
     },
     FutureExt as _,
     StreamExt as _,
@@ -28,18 +41,21 @@ use sequencer_client::{
 };
 use tokio::{
     select,
-    sync::mpsc,
+    sync::{
+        mpsc,
+        oneshot,
+        watch::error::RecvError,
+    },
 };
+use tokio_stream::StreamMap;
 use tokio_util::sync::CancellationToken;
 use tracing::{
     debug,
-    debug_span,
     error,
     info,
     instrument,
     trace,
     warn,
-    warn_span,
 };
 
 use crate::{
@@ -51,17 +67,77 @@ use crate::{
 mod block_stream;
 mod builder;
 mod client;
+mod optimistic;
 mod reporting;
 pub(crate) use builder::Builder;
 pub(crate) use client::SequencerGrpcClient;
 
-/// [`Reader`] reads Sequencer blocks and forwards them to the [`crate::Executor`] task.
+/// A handle to subscribe executors to the blocks read by a [`Reader`].
+#[derive(Clone)]
+pub(crate) struct Handle {
+    subscriptions: mpsc::Sender<Subscription>,
+}
+
+impl Handle {
+    /// Subscribes to the soft blocks of the rollup tracked by `rollup_state`.
+    ///
+    /// Blocks are sent over `soft_blocks` in strictly sequential order of their Sequencer heights,
+    /// starting at the next expected soft height. `soft_blocks` is dropped once the stop height in
+    /// `rollup_state` is reached.
+    ///
+    /// If `optimistic` is set, the blocks proposed to Sequencer for the rollup and the
+    /// commitments of all proposed blocks are sent over its channels as well. These are
+    /// best-effort: items are dropped while a channel is full.
+    ///
+    /// # Errors
+    /// Returns an error if the reader is not running or if it rejected the subscription, for
+    /// example because the Sequencer chain ID in `rollup_state` does not match.
+    pub(crate) async fn subscribe(
+        &self,
+        rollup_state: StateReceiver,
+        soft_blocks: mpsc::Sender<FilteredSequencerBlock>,
+        optimistic: Option<OptimisticSenders>,
+    ) -> eyre::Result<()> {
+        let (ack, accepted) = oneshot::channel();
+        self.subscriptions
+            .send(Subscription {
+                rollup_state,
+                soft_blocks,
+                optimistic,
+                ack,
+            })
+            .await
+            .map_err(|_| eyre!("sequencer reader is not running"))?;
+        accepted
+            .await
+            .wrap_err("sequencer reader exited before accepting the subscription")?
+    }
+}
+
+/// The channels over which a subscriber receives the items of Sequencer's optimistic block
+/// service.
+pub(crate) struct OptimisticSenders {
+    /// The blocks proposed to Sequencer, filtered for the subscriber's rollup.
+    pub(crate) proposed_blocks: mpsc::Sender<FilteredSequencerBlock>,
+    /// The commitments of the blocks proposed to Sequencer.
+    pub(crate) block_commitments: mpsc::Sender<SequencerBlockCommit>,
+}
+
+struct Subscription {
+    rollup_state: StateReceiver,
+    soft_blocks: mpsc::Sender<FilteredSequencerBlock>,
+    optimistic: Option<OptimisticSenders>,
+    ack: oneshot::Sender<eyre::Result<()>>,
+}
+
+/// [`Reader`] reads Sequencer blocks and forwards them to the subscribed [`crate::Executor`]
+/// tasks.
 ///
 /// The blocks are forwarded in strictly sequential order of their Sequencr heights.
 /// A [`Reader`] is created with [`Builder::build`] and run with [`Reader::run_until_stopped`].
 pub(crate) struct Reader {
-    rollup_state: StateReceiver,
-    soft_blocks: mpsc::Sender<FilteredSequencerBlock>,
+    /// The subscriptions of executors sent through a [`Handle`].
+    subscriptions: mpsc::Receiver<Subscription>,
 
     /// The gRPC client to fetch new blocks from the Sequencer network.
     sequencer_grpc_client: SequencerGrpcClient,
@@ -80,100 +156,142 @@ pub(crate) struct Reader {
 
 impl Reader {
     pub(crate) async fn run_until_stopped(mut self) -> eyre::Result<()> {
-        select!(
+        let sequencer_chain_id = select!(
             () = self.shutdown.clone().cancelled_owned() => {
                 return report_exit(Ok("received shutdown signal while waiting for Sequencer reader task to initialize"), "");
             }
             res = self.initialize() => {
-                res?;
+                res?
             }
         );
-        RunningReader::try_from_parts(self)
-            .wrap_err("failed entering run loop")?
+        RunningReader::from_parts(self, sequencer_chain_id)
             .run_until_stopped()
             .await
     }
 
     #[instrument(skip_all, err)]
-    async fn initialize(&mut self) -> eyre::Result<()> {
-        let expected_sequencer_chain_id = self.rollup_state.sequencer_chain_id();
-        let actual_sequencer_chain_id =
-            get_sequencer_chain_id(self.sequencer_cometbft_client.clone())
-                .await
-                .wrap_err("failed to get chain ID from Sequencer")?;
-        ensure!(
-            expected_sequencer_chain_id == actual_sequencer_chain_id.as_str(),
-            "expected chain id `{expected_sequencer_chain_id}` does not match actual: \
-             `{actual_sequencer_chain_id}`"
-        );
-        Ok(())
+    async fn initialize(&mut self) -> eyre::Result<tendermint::chain::Id> {
+        get_sequencer_chain_id(self.sequencer_cometbft_client.clone())
+            .await
+            .wrap_err("failed to get chain ID from Sequencer")
     }
 }
 
-struct RunningReader {
+type SubscriberId = u64;
+
+/// An executor subscribed to the blocks read from Sequencer.
+struct Subscriber {
     rollup_state: StateReceiver,
     soft_blocks: mpsc::Sender<FilteredSequencerBlock>,
 
+    /// Set if the executor executes proposed blocks optimistically.
+    optimistic: Option<OptimisticSenders>,
+
     /// Caches the filtered sequencer blocks retrieved from the Sequencer.
     /// This cache will yield a block if it contains a block that matches the
     /// next expected soft block height of the executor task (as indicated by
     /// the handle).
     block_cache: BlockCache<FilteredSequencerBlock>,
 
+    /// Set if a block is waiting for the executor to free up because it exhibits backpressure.
+    is_block_enqueued: bool,
+}
+
+impl Subscriber {
+    /// The stop height is reached if a) the next height to be forwarded would be greater
+    /// than the stop height, and b) there is no block currently in flight.
+    fn has_reached_stop_height(&self) -> bool {
+        self.rollup_state
+            .sequencer_stop_height()
+            .map_or(false, |height| {
+                self.block_cache.next_height_to_pop() > height.get() && !self.is_block_enqueued
+            })
+    }
+}
+
+type StateChange = (SubscriberId, StateReceiver, Result<Height, RecvError>);
+
+type EnqueuedBlock = (
+    SubscriberId,
+    Result<(), mpsc::error::SendError<FilteredSequencerBlock>>,
+);
+
+struct RunningReader {
+    /// The subscriptions of executors sent through a [`Handle`].
+    subscriptions: mpsc::Receiver<Subscription>,
+
+    /// The executors subscribed to this reader.
+    subscribers: BTreeMap<SubscriberId, Subscriber>,
+
+    /// The ID assigned to the next subscriber.
+    next_subscriber_id: SubscriberId,
+
+    /// Changes to the rollup state of each subscriber.
+    state_changes: FuturesUnordered<BoxFuture<'static, StateChange>>,
+
+    /// Blocks enqueued for subscribers whose executor channel was full.
+    enqueued_blocks: FuturesUnordered<BoxFuture<'static, EnqueuedBlock>>,
+
     /// A stream of the latest heights observed from the Sequencer network.
     latest_height_stream: LatestHeightStream,
 
+    /// The latest height observed from the Sequencer network, used to seed
+    /// `blocks_from_heights` when it is recreated for a new subscriber.
+    latest_observed_height: Option<Height>,
+
     /// A stream of block heights fetched from the Sequencer network up to
     /// the latest observed sequencer height (as obtained from the `latest_height_stream`) field.
-    blocks_from_heights: BlocksFromHeightStream,
+    ///
+    /// Unset if there are no subscribers.
+    blocks_from_heights: Option<BlocksFromHeightStream>,
+
+    /// The streams of blocks proposed to Sequencer, one per rollup of the subscribers executing
+    /// them optimistically.
+    proposed_blocks: StreamMap<RollupId, BoxStream<'static, FilteredSequencerBlock>>,
+
+    /// The stream of commitments of blocks proposed to Sequencer.
+    ///
+    /// Unset if there are no subscribers executing proposed blocks optimistically.
+    block_commitments: Option<BoxStream<'static, SequencerBlockCommit>>,
+
+    /// The gRPC client to fetch new blocks from the Sequencer network.
+    sequencer_grpc_client: SequencerGrpcClient,
 
-    /// An enqueued block waiting for executor to free up. Set if the executor exhibits
-    /// backpressure.
-    enqueued_block:
-        Fuse<BoxFuture<'static, Result<(), mpsc::error::SendError<FilteredSequencerBlock>>>>,
+    /// The cometbft ID of Sequencer. Set once during initialization by querying sequencer.
+    sequencer_chain_id: tendermint::chain::Id,
 
     /// Token to listen for Conductor being shut down.
     shutdown: CancellationToken,
 }
 
 impl RunningReader {
-    fn try_from_parts(reader: Reader) -> eyre::Result<Self> {
+    fn from_parts(reader: Reader, sequencer_chain_id: tendermint::chain::Id) -> Self {
         let Reader {
+            subscriptions,
             sequencer_grpc_client,
             sequencer_cometbft_client,
             sequencer_block_time,
             shutdown,
-            rollup_state,
-            soft_blocks,
-            ..
         } = reader;
 
-        let next_expected_height = rollup_state.next_expected_soft_sequencer_height();
-        let sequencer_stop_height = rollup_state.sequencer_stop_height();
-
         let latest_height_stream =
             sequencer_cometbft_client.stream_latest_height(sequencer_block_time);
 
-        let block_cache = BlockCache::with_next_height(next_expected_height)
-            .wrap_err("failed constructing sequential block cache")?;
-
-        let blocks_from_heights = BlocksFromHeightStream::new(
-            rollup_state.rollup_id(),
-            next_expected_height,
-            sequencer_stop_height,
-            sequencer_grpc_client,
-        );
-
-        let enqueued_block: Fuse<BoxFuture<Result<_, _>>> = future::Fuse::terminated();
-        Ok(RunningReader {
-            rollup_state,
-            soft_blocks,
-            block_cache,
+        RunningReader {
+            subscriptions,
+            subscribers: BTreeMap::new(),
+            next_subscriber_id: 0,
+            state_changes: FuturesUnordered::new(),
+            enqueued_blocks: FuturesUnordered::new(),
             latest_height_stream,
-            blocks_from_heights,
-            enqueued_block,
+            latest_observed_height: None,
+            blocks_from_heights: None,
+            proposed_blocks: StreamMap::new(),
+            block_commitments: None,
+            sequencer_grpc_client,
+            sequencer_chain_id,
             shutdown,
-        })
+        }
     }
 
     async fn run_until_stopped(mut self) -> eyre::Result<()> {
@@ -186,9 +304,8 @@ impl RunningReader {
 
     async fn run_loop(&mut self) -> eyre::Result<&'static str> {
         loop {
-            if self.has_reached_stop_height() {
-                return Ok("stop height reached");
-            }
+            self.forward_cached_blocks();
+            self.remove_subscribers_at_stop_height();
 
             select! {
                 biased;
@@ -197,108 +314,420 @@ impl RunningReader {
                     return Ok("received shutdown signal");
                 }
 
-                // Process block execution which was enqueued due to executor channel being full.
-                res = &mut self.enqueued_block, if !self.enqueued_block.is_terminated() => {
-                    res.wrap_err("failed sending enqueued block to executor")?;
-                    debug_span!("conductor::sequencer::RunningReader::run_loop").in_scope(||
-                        debug!("submitted enqueued block to executor, resuming normal operation")
-                    );
+                Some(subscription) = self.subscriptions.recv() => {
+                    self.add_subscriber(subscription);
                 }
 
-                // Skip heights that executor has already executed (e.g. firm blocks from Celestia)
-                Ok(next_height) = self.rollup_state.next_expected_soft_height_if_changed() => {
-                    self.update_next_expected_height(next_height);
+                // Process block execution which was enqueued due to executor channel being full.
+                Some((id, res)) = self.enqueued_blocks.next() => {
+                    self.handle_enqueued_block(id, res);
                 }
 
-                // Forward the next block to executor. Enqueue if the executor channel is full.
-                Some(block) = self.block_cache.next_block(), if self.enqueued_block.is_terminated() => {
-                    self.send_to_executor(block)?;
+                // Skip heights that executor has already executed (e.g. firm blocks from Celestia)
+                Some((id, rollup_state, res)) = self.state_changes.next() => {
+                    self.handle_state_change(id, rollup_state, res);
                 }
 
-                // Pull a block from the stream and put it in the block cache.
-                Some(block) = self.blocks_from_heights.next() => {
+                // Pull a block from the stream and put it in the block caches.
+                Some(block) = next_fetched_block(&mut self.blocks_from_heights) => {
                     // XXX: blocks_from_heights stream uses self::client::SequencerGrpcClient::get, which has
                     // retry logic. An error here means that it could not retry or
                     // otherwise recover from a failed block fetch.
                     let block = block.wrap_err("the stream of new blocks returned a catastrophic error")?;
-                    if let Err(error) = self.block_cache.insert(block) {
-                        warn_span!("conductor::sequencer::RunningReader::run_loop").in_scope(||
-                            warn!(%error, "failed pushing block into sequential cache, dropping it")
-                        );
-                    }
+                    self.cache_block(block);
                 }
 
                 // Record the latest height of the Sequencer network, allowing `blocks_from_heights` to progress.
                 Some(res) = self.latest_height_stream.next() => {
                     self.handle_latest_height(res);
                 }
+
+                Some((rollup_id, block)) = self.proposed_blocks.next() => {
+                    self.forward_proposed_block(rollup_id, block);
+                }
+
+                Some(commitment) = next_block_commitment(&mut self.block_commitments) => {
+                    self.forward_block_commitment(commitment);
+                }
             }
         }
     }
 
     #[instrument(skip_all)]
-    fn handle_latest_height(&mut self, res: Result<Height, tendermint_rpc::Error>) {
-        match res {
-            Ok(height) => {
-                debug!(%height, "received latest height from sequencer");
-                self.blocks_from_heights
-                    .set_latest_observed_height_if_greater(height);
+    fn add_subscriber(&mut self, subscription: Subscription) {
+        let Subscription {
+            rollup_state,
+            soft_blocks,
+            optimistic,
+            ack,
+        } = subscription;
+
+        let res = self.try_add_subscriber(rollup_state, soft_blocks, optimistic);
+        if let Err(error) = &res {
+            warn!(%error, "rejected subscription to sequencer reader");
+        }
+        // The executor is not waiting for the subscription anymore if this fails. It will be
+        // removed once its channel is found to be closed.
+        let _ = ack.send(res);
+    }
+
+    fn try_add_subscriber(
+        &mut self,
+        rollup_state: StateReceiver,
+        soft_blocks: mpsc::Sender<FilteredSequencerBlock>,
+        optimistic: Option<OptimisticSenders>,
+    ) -> eyre::Result<()> {
+        let expected_sequencer_chain_id = rollup_state.sequencer_chain_id();
+        ensure!(
+            expected_sequencer_chain_id == self.sequencer_chain_id.as_str(),
+            "expected chain id `{expected_sequencer_chain_id}` does not match actual: `{}`",
+            self.sequencer_chain_id,
+        );
+        let block_cache =
+            BlockCache::with_next_height(rollup_state.next_expected_soft_sequencer_height())
+                .wrap_err("failed constructing sequential block cache")?;
+
+        let id = self.next_subscriber_id;
+        self.next_subscriber_id = self.next_subscriber_id.saturating_add(1);
+        info!(
+            subscriber.id = id,
+            rollup_id = %rollup_state.rollup_id(),
+            next_expected_height = block_cache.next_height_to_pop(),
+            is_optimistic = optimistic.is_some(),
+            "adding subscriber",
+        );
+        self.state_changes
+            .push(watch_state_change(id, rollup_state.clone()));
+        self.subscribers.insert(
+            id,
+            Subscriber {
+                rollup_state,
+                soft_blocks,
+                optimistic,
+                block_cache,
+                is_block_enqueued: false,
+            },
+        );
+        self.reset_blocks_from_heights();
+        self.update_optimistic_streams();
+        Ok(())
+    }
+
+    /// Recreates the stream of blocks so that its blocks contain the transactions of all
+    /// subscribed rollups, starting at the lowest height expected by any subscriber.
+    ///
+    /// Blocks that were already in flight are dropped.
+    fn reset_blocks_from_heights(&mut self) {
+        let Some(first_height) = self.lowest_next_height() else {
+            self.blocks_from_heights = None;
+            return;
+        };
+        let mut rollup_ids: Vec<RollupId> = vec![];
+        let mut stop_height = Some(NonZeroU64::MIN);
+        for subscriber in self.subscribers.values() {
+            let rollup_id = subscriber.rollup_state.rollup_id();
+            if !rollup_ids.contains(&rollup_id) {
+                rollup_ids.push(rollup_id);
             }
-            Err(error) => {
-                warn!(
-                    error = %Report::new(error),
-                    "failed fetching latest height from sequencer; waiting until next tick",
+            stop_height = stop_height
+                .zip(subscriber.rollup_state.sequencer_stop_height())
+                .map(|(current, other)| current.max(other));
+        }
+        let mut blocks_from_heights = BlocksFromHeightStream::new(
+            rollup_ids,
+            first_height,
+            stop_height,
+            self.sequencer_grpc_client.clone(),
+        );
+        if let Some(height) = self.latest_observed_height {
+            blocks_from_heights.set_latest_observed_height_if_greater(height);
+        }
+        self.blocks_from_heights = Some(blocks_from_heights);
+    }
+
+    /// Opens the streams of Sequencer's optimistic block service that are needed by the
+    /// subscribers executing proposed blocks optimistically, and closes those no longer needed.
+    fn update_optimistic_streams(&mut self) {
+        let optimistic_rollup_ids: Vec<RollupId> = self
+            .subscribers
+            .values()
+            .filter(|subscriber| subscriber.optimistic.is_some())
+            .map(|subscriber| subscriber.rollup_state.rollup_id())
+            .collect();
+
+        let obsolete_rollup_ids: Vec<RollupId> = self
+            .proposed_blocks
+            .keys()
+            .filter(|rollup_id| !optimistic_rollup_ids.contains(rollup_id))
+            .copied()
+            .collect();
+        for rollup_id in obsolete_rollup_ids {
+            self.proposed_blocks.remove(&rollup_id);
+        }
+        for &rollup_id in &optimistic_rollup_ids {
+            if !self.proposed_blocks.contains_key(&rollup_id) {
+                self.proposed_blocks.insert(
+                    rollup_id,
+                    optimistic::stream_proposed_blocks(&self.sequencer_grpc_client, rollup_id),
                 );
             }
         }
+
+        if optimistic_rollup_ids.is_empty() {
+            self.block_commitments = None;
+        } else if self.block_commitments.is_none() {
+            self.block_commitments = Some(optimistic::stream_block_commitments(
+                &self.sequencer_grpc_client,
+            ));
+        }
+    }
+
+    /// Returns the lowest height expected by any subscriber, or `None` if there are none.
+    fn lowest_next_height(&self) -> Option<Height> {
+        self.subscribers
+            .values()
+            .map(|subscriber| subscriber.block_cache.next_height_to_pop())
+            .min()
+            .map(|height| {
+                Height::try_from(height).expect("block cache heights must be valid heights")
+            })
     }
 
-    /// Sends `block` to the executor task.
+    fn remove_subscriber(&mut self, id: SubscriberId, reason: &'static str) {
+        if self.subscribers.remove(&id).is_some() {
+            info!(subscriber.id = id, reason, "removed subscriber");
+            if self.subscribers.is_empty() {
+                self.blocks_from_heights = None;
+            } else {
+                self.update_next_expected_height();
+            }
+            self.update_optimistic_streams();
+        }
+    }
+
+    fn remove_subscribers_at_stop_height(&mut self) {
+        let at_stop_height: Vec<_> = self
+            .subscribers
+            .iter()
+            .filter(|(_, subscriber)| subscriber.has_reached_stop_height())
+            .map(|(id, _)| *id)
+            .collect();
+        for id in at_stop_height {
+            self.remove_subscriber(id, "stop height reached");
+        }
+    }
+
+    /// Sends the next cached blocks to each subscriber's executor.
     ///
-    /// Enqueues the block is the channel to the executor is full, sending it once
-    /// it frees up.
-    fn send_to_executor(&mut self, block: FilteredSequencerBlock) -> eyre::Result<()> {
-        if let Err(err) = self.soft_blocks.try_send(block) {
-            match err {
-                mpsc::error::TrySendError::Full(block) => {
-                    trace!(
-                        "executor channel is full; scheduling block and stopping block fetch \
-                         until a slot opens up"
-                    );
-                    let chan = self.soft_blocks.clone();
-                    self.enqueued_block = async move { chan.send(block).await }.boxed().fuse();
+    /// Enqueues a block if an executor's channel is full, sending it once it frees up.
+    fn forward_cached_blocks(&mut self) {
+        let mut closed = vec![];
+        for (&id, subscriber) in &mut self.subscribers {
+            while !subscriber.is_block_enqueued {
+                let Some(block) = subscriber.block_cache.pop() else {
+                    break;
+                };
+                match subscriber.soft_blocks.try_send(block) {
+                    Ok(()) => {}
+                    Err(mpsc::error::TrySendError::Full(block)) => {
+                        trace!(
+                            subscriber.id = id,
+                            "executor channel is full; scheduling block and stopping block \
+                             forwarding until a slot opens up"
+                        );
+                        let chan = subscriber.soft_blocks.clone();
+                        self.enqueued_blocks
+                            .push(async move { (id, chan.send(block).await) }.boxed());
+                        subscriber.is_block_enqueued = true;
+                    }
+                    Err(mpsc::error::TrySendError::Closed(_)) => {
+                        closed.push(id);
+                        break;
+                    }
                 }
-                mpsc::error::TrySendError::Closed(_) => {
-                    bail!("could not send block to executor because its channel was closed")
+            }
+        }
+        for id in closed {
+            self.remove_subscriber(id, "executor channel was closed");
+        }
+    }
+
+    /// Sends `block` proposed to Sequencer to the executors of `rollup_id` executing proposed
+    /// blocks optimistically.
+    ///
+    /// The block is dropped for executors whose channel is full: the soft block at its height
+    /// will be forwarded regardless.
+    fn forward_proposed_block(&mut self, rollup_id: RollupId, block: FilteredSequencerBlock) {
+        let mut closed = vec![];
+        for (&id, subscriber) in &self.subscribers {
+            let Some(optimistic) = &subscriber.optimistic else {
+                continue;
+            };
+            if subscriber.rollup_state.rollup_id() != rollup_id {
+                continue;
+            }
+            match optimistic.proposed_blocks.try_send(block.clone()) {
+                Ok(()) => {}
+                Err(mpsc::error::TrySendError::Full(_)) => {
+                    debug!(
+                        subscriber.id = id,
+                        "proposed block channel is full; dropping proposed block",
+                    );
                 }
+                Err(mpsc::error::TrySendError::Closed(_)) => closed.push(id),
             }
         }
-        Ok(())
+        for id in closed {
+            self.remove_subscriber(id, "executor channel was closed");
+        }
     }
 
-    /// Updates the next expected height to forward to the executor.
+    /// Sends `commitment` to all executors executing proposed blocks optimistically.
     ///
-    /// This will all older heights from the cache and advance the stream of blocks
-    /// so that blocks older than `next_height` will not be fetched.
+    /// The commitment is dropped for executors whose channel is full: an optimistically executed
+    /// block is also promoted once the soft block at its height is forwarded.
+    fn forward_block_commitment(&mut self, commitment: SequencerBlockCommit) {
+        let mut closed = vec![];
+        for (&id, subscriber) in &self.subscribers {
+            let Some(optimistic) = &subscriber.optimistic else {
+                continue;
+            };
+            match optimistic.block_commitments.try_send(commitment.clone()) {
+                Ok(()) => {}
+                Err(mpsc::error::TrySendError::Full(_)) => {
+                    debug!(
+                        subscriber.id = id,
+                        "block commitment channel is full; dropping block commitment",
+                    );
+                }
+                Err(mpsc::error::TrySendError::Closed(_)) => closed.push(id),
+            }
+        }
+        for id in closed {
+            self.remove_subscriber(id, "executor channel was closed");
+        }
+    }
+
+    fn handle_enqueued_block(
+        &mut self,
+        id: SubscriberId,
+        res: Result<(), mpsc::error::SendError<FilteredSequencerBlock>>,
+    ) {
+        if res.is_err() {
+            self.remove_subscriber(id, "executor channel was closed");
+        } else if let Some(subscriber) = self.subscribers.get_mut(&id) {
+            debug!(
+                subscriber.id = id,
+                "submitted enqueued block to executor, resuming normal operation"
+            );
+            subscriber.is_block_enqueued = false;
+        }
+    }
+
+    fn handle_state_change(
+        &mut self,
+        id: SubscriberId,
+        rollup_state: StateReceiver,
+        res: Result<Height, RecvError>,
+    ) {
+        let Ok(next_height) = res else {
+            self.remove_subscriber(id, "executor dropped its rollup state");
+            return;
+        };
+        if let Some(subscriber) = self.subscribers.get_mut(&id) {
+            subscriber.block_cache.drop_obsolete(next_height);
+            self.update_next_expected_height();
+            self.state_changes
+                .push(watch_state_change(id, rollup_state));
+        }
+    }
+
+    /// Advances the stream of blocks to the lowest height expected by any subscriber so that
+    /// blocks older than it will not be fetched.
     ///
     /// Already in-flight fetches will still run their course but be rejected by
-    /// the block cache.
-    fn update_next_expected_height(&mut self, next_height: Height) {
-        self.blocks_from_heights
-            .set_next_expected_height_if_greater(next_height);
-        self.block_cache.drop_obsolete(next_height);
+    /// the block caches.
+    fn update_next_expected_height(&mut self) {
+        if let (Some(blocks_from_heights), Some(next_height)) =
+            (self.blocks_from_heights.as_mut(), self.lowest_next_height())
+        {
+            blocks_from_heights.set_next_expected_height_if_greater(next_height);
+        }
     }
 
-    /// The stop height is reached if a) the next height to be forwarded would be greater
-    /// than the stop height, and b) there is no block currently in flight.
-    fn has_reached_stop_height(&self) -> bool {
-        self.rollup_state
-            .sequencer_stop_height()
-            .map_or(false, |height| {
-                self.block_cache.next_height_to_pop() > height.get()
-                    && self.enqueued_block.is_terminated()
-            })
+    /// Puts `block` into the cache of every subscriber that has not yet moved past it.
+    fn cache_block(&mut self, block: FilteredSequencerBlock) {
+        let height = block.height().value();
+        for (&id, subscriber) in &mut self.subscribers {
+            if height < subscriber.block_cache.next_height_to_pop() {
+                continue;
+            }
+            // Failing to insert is expected after the block stream was recreated for a new
+            // subscriber and refetches blocks that were already cached.
+            if let Err(error) = subscriber.block_cache.insert(block.clone()) {
+                debug!(
+                    subscriber.id = id,
+                    %error,
+                    "failed pushing block into sequential cache, dropping it",
+                );
+            }
+        }
+    }
+
+    #[instrument(skip_all)]
+    fn handle_latest_height(&mut self, res: Result<Height, tendermint_rpc::Error>) {
+        match res {
+            Ok(height) => {
+                debug!(%height, "received latest height from sequencer");
+                if self
+                    .latest_observed_height
+                    .map_or(true, |latest| height > latest)
+                {
+                    self.latest_observed_height = Some(height);
+                }
+                if let Some(blocks_from_heights) = self.blocks_from_heights.as_mut() {
+                    blocks_from_heights.set_latest_observed_height_if_greater(height);
+                }
+            }
+            Err(error) => {
+                warn!(
+                    error = %Report::new(error),
+                    "failed fetching latest height from sequencer; waiting until next tick",
+                );
+            }
+        }
+    }
+}
+
+/// Returns the next block fetched from Sequencer, or `None` if there are no subscribers.
+async fn next_fetched_block(
+    blocks_from_heights: &mut Option<BlocksFromHeightStream>,
+) -> Option<eyre::Result<FilteredSequencerBlock>> {
+    match blocks_from_heights {
+        Some(blocks_from_heights) => blocks_from_heights.next().await,
+        None => None,
+    }
+}
+
+/// Returns the next commitment of a proposed block, or `None` if the stream is not open.
+async fn next_block_commitment(
+    block_commitments: &mut Option<BoxStream<'static, SequencerBlockCommit>>,
+) -> Option<SequencerBlockCommit> {
+    match block_commitments {
+        Some(block_commitments) => block_commitments.next().await,
+        None => None,
+    }
+}
+
+fn watch_state_change(
+    id: SubscriberId,
+    mut rollup_state: StateReceiver,
+) -> BoxFuture<'static, StateChange> {
+    async move {
+        let res = rollup_state.next_expected_soft_height_if_changed().await;
+        (id, rollup_state, res)
     }
+    .boxed()
 }
 
 #[instrument(skip_all)]
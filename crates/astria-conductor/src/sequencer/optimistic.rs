@@ -0,0 +1,168 @@
+//! Streams of blocks proposed to Sequencer and of their commitments.
+//!
+//! Both streams are served by Sequencer's optimistic block service and opened by
+//! [`super::Reader`], which forwards their items to its subscribers. They are only used to
+//! optimistically execute blocks ahead of their commitment: the blocks fetched by
+//! [`super::Reader`] remain the source of truth for soft commitments. Errors are therefore not
+//! propagated. Instead, the streams are reopened after a delay.
+
+use std::{
+    future::Future,
+    time::Duration,
+};
+
+use astria_core::{
+    generated::astria::sequencerblock::optimistic::v1alpha1::{
+        optimistic_block_service_client::OptimisticBlockServiceClient,
+        GetBlockCommitmentStreamRequest,
+        GetBlockCommitmentStreamResponse,
+        GetOptimisticBlockStreamRequest,
+        GetOptimisticBlockStreamResponse,
+    },
+    primitive::v1::RollupId,
+    sequencerblock::{
+        optimistic::v1alpha1::SequencerBlockCommit,
+        v1::block::FilteredSequencerBlock,
+    },
+    Protobuf as _,
+};
+use astria_eyre::eyre::{
+    self,
+    OptionExt as _,
+    WrapErr as _,
+};
+use futures::{
+    stream::BoxStream,
+    StreamExt as _,
+};
+use tonic::transport::Channel;
+use tracing::{
+    info,
+    warn,
+};
+
+use super::SequencerGrpcClient;
+
+/// The time to wait before reopening a stream that failed or was closed by Sequencer.
+const REOPEN_DELAY: Duration = Duration::from_secs(1);
+
+/// Streams the blocks proposed to Sequencer, filtered for `rollup_id`.
+pub(crate) fn stream_proposed_blocks(
+    client: &SequencerGrpcClient,
+    rollup_id: RollupId,
+) -> BoxStream<'static, FilteredSequencerBlock> {
+    reopening_stream(
+        "optimistic block stream",
+        OptimisticBlockServiceClient::new(client.channel()),
+        move |mut client| async move {
+            client
+                .get_optimistic_block_stream(GetOptimisticBlockStreamRequest {
+                    rollup_id: Some(rollup_id.into_raw()),
+                })
+                .await
+        },
+        |response: GetOptimisticBlockStreamResponse| {
+            let raw = response
+                .block
+                .ok_or_eyre("expected field `block` was not set")?;
+            FilteredSequencerBlock::try_from_raw(raw)
+                .wrap_err("failed validating proposed filtered block")
+        },
+    )
+}
+
+/// Streams the commitments of the blocks proposed to Sequencer.
+pub(crate) fn stream_block_commitments(
+    client: &SequencerGrpcClient,
+) -> BoxStream<'static, SequencerBlockCommit> {
+    reopening_stream(
+        "block commitment stream",
+        OptimisticBlockServiceClient::new(client.channel()),
+        |mut client| async move {
+            client
+                .get_block_commitment_stream(GetBlockCommitmentStreamRequest {})
+                .await
+        },
+        |response: GetBlockCommitmentStreamResponse| {
+            let raw = response
+                .commitment
+                .ok_or_eyre("expected field `commitment` was not set")?;
+            SequencerBlockCommit::try_from_raw_ref(&raw)
+                .wrap_err("failed validating block commitment")
+        },
+    )
+}
+
+/// Returns a stream of the messages received over the stream returned by `open`, converted by
+/// `convert`.
+///
+/// Messages that fail conversion are logged and skipped. The stream is reopened after
+/// [`REOPEN_DELAY`] if it could not be opened, failed, or was closed by Sequencer. The returned
+/// stream hence never ends.
+fn reopening_stream<F, Fut, R, T>(
+    name: &'static str,
+    client: OptimisticBlockServiceClient<Channel>,
+    open: F,
+    convert: fn(R) -> eyre::Result<T>,
+) -> BoxStream<'static, T>
+where
+    F: Fn(OptimisticBlockServiceClient<Channel>) -> Fut + Send + 'static,
+    Fut: Future<Output = tonic::Result<tonic::Response<tonic::Streaming<R>>>> + Send,
+    R: Send + 'static,
+    T: Send + 'static,
+{
+    futures::stream::unfold(
+        (client, open, None::<tonic::Streaming<R>>),
+        move |(client, open, mut stream)| async move {
+            loop {
+                let Some(running) = stream.as_mut() else {
+                    match open(client.clone()).await {
+                        Ok(response) => {
+                            info!(stream = name, "opened stream to Sequencer");
+                            stream = Some(response.into_inner());
+                        }
+                        Err(error) => {
+                            warn!(
+                                stream = name,
+                                error = &error as &dyn std::error::Error,
+                                "failed opening stream to Sequencer; retrying after delay",
+                            );
+                            tokio::time::sleep(REOPEN_DELAY).await;
+                        }
+                    }
+                    continue;
+                };
+                match running.message().await {
+                    Ok(Some(message)) => match convert(message) {
+                        Ok(item) => return Some((item, (client, open, stream))),
+                        Err(error) => {
+                            warn!(
+                                stream = name,
+                                %error,
+                                "received invalid message from Sequencer; skipping it",
+                            );
+                        }
+                    },
+                    Ok(None) => {
+                        warn!(
+                            stream = name,
+                            "stream was closed by Sequencer; reopening after delay",
+                        );
+                        stream = None;
+                        tokio::time::sleep(REOPEN_DELAY).await;
+                    }
+                    Err(error) => {
+                        warn!(
+                            stream = name,
+                            error = &error as &dyn std::error::Error,
+                            "stream to Sequencer failed; reopening after delay",
+                        );
+                        stream = None;
+                        tokio::time::sleep(REOPEN_DELAY).await;
+                    }
+                }
+            }
+        },
+    )
+    .boxed()
+}
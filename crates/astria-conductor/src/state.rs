@@ -78,18 +78,7 @@ impl StateReceiver {
     }
 
     pub(crate) fn sequencer_stop_height(&self) -> Option<NonZeroU64> {
-        let rollup_end_block_number = self.inner.borrow().rollup_end_block_number()?;
-        let sequencer_start_height = self.inner.borrow().sequencer_start_block_height();
-        let rollup_start_block_number = self.inner.borrow().rollup_start_block_number();
-        NonZeroU64::new(
-            map_rollup_number_to_sequencer_height(
-                sequencer_start_height,
-                rollup_start_block_number,
-                rollup_end_block_number.get(),
-            )
-            .expect("the tracked state must never be set to an invalid state. this is a bug")
-            .into(),
-        )
+        self.inner.borrow().sequencer_stop_height()
     }
 }
 
@@ -316,7 +305,7 @@ impl State {
         &self.execution_session_parameters
     }
 
-    fn execution_session_id(&self) -> String {
+    pub(crate) fn execution_session_id(&self) -> String {
         self.execution_session_id.clone()
     }
 
@@ -344,7 +333,7 @@ impl State {
         self.soft().hash().to_string()
     }
 
-    fn lowest_celestia_search_height(&self) -> u64 {
+    pub(crate) fn lowest_celestia_search_height(&self) -> u64 {
         self.commitment_state.lowest_celestia_search_height()
     }
 
@@ -399,15 +388,34 @@ impl State {
             )
     }
 
-    fn next_expected_firm_sequencer_height(&self) -> Result<SequencerHeight, InvalidState> {
+    pub(crate) fn next_expected_firm_sequencer_height(
+        &self,
+    ) -> Result<SequencerHeight, InvalidState> {
         map_firm_to_sequencer_height(&self.execution_session_parameters, &self.commitment_state)
             .map(SequencerHeight::increment)
     }
 
-    fn next_expected_soft_sequencer_height(&self) -> Result<SequencerHeight, InvalidState> {
+    pub(crate) fn next_expected_soft_sequencer_height(
+        &self,
+    ) -> Result<SequencerHeight, InvalidState> {
         map_soft_to_sequencer_height(&self.execution_session_parameters, &self.commitment_state)
             .map(SequencerHeight::increment)
     }
+
+    /// Returns the sequencer height that the rollup end block number maps to, if the execution
+    /// session sets one.
+    pub(crate) fn sequencer_stop_height(&self) -> Option<NonZeroU64> {
+        let rollup_end_block_number = self.rollup_end_block_number()?;
+        NonZeroU64::new(
+            map_rollup_number_to_sequencer_height(
+                self.sequencer_start_block_height(),
+                self.rollup_start_block_number(),
+                rollup_end_block_number.get(),
+            )
+            .expect("the tracked state must never be set to an invalid state. this is a bug")
+            .into(),
+        )
+    }
 }
 
 /// Maps a rollup height to a sequencer height.
@@ -0,0 +1,243 @@
+//! Tracks the status of Conductor as served by its HTTP API.
+//!
+//! The status outlives restarts of the executors (for example, when a new execution session is
+//! started after the stop height is reached). The status of a rollup is reset whenever a new
+//! executor is started for it.
+use std::{
+    collections::BTreeMap,
+    num::NonZeroU64,
+};
+
+use serde::Serialize;
+use tokio::sync::watch;
+
+use crate::state::State;
+
+/// The liveness of a task reading blocks from Sequencer or Celestia.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ReaderStatus {
+    /// The reader is not spawned because it is not needed at the configured commit level.
+    #[default]
+    NotRunning,
+    Running,
+    Exited,
+}
+
+#[derive(Clone)]
+pub(crate) struct StatusSender {
+    inner: watch::Sender<StatusSnapshot>,
+}
+
+impl StatusSender {
+    pub(crate) fn new() -> Self {
+        let (inner, _) = watch::channel(StatusSnapshot::default());
+        Self {
+            inner,
+        }
+    }
+
+    pub(crate) fn subscribe(&self) -> watch::Receiver<StatusSnapshot> {
+        self.inner.subscribe()
+    }
+
+    /// Registers the rollup driven through `execution_rpc_url` and returns a handle to update its
+    /// status.
+    pub(crate) fn rollup(&self, execution_rpc_url: String) -> RollupStatusSender {
+        self.inner.send_modify(|status| {
+            status.rollups.entry(execution_rpc_url.clone()).or_default();
+        });
+        RollupStatusSender {
+            inner: self.inner.clone(),
+            execution_rpc_url,
+        }
+    }
+
+    pub(crate) fn set_sequencer_reader(&self, reader: ReaderStatus) {
+        self.inner.send_if_modified(|status| {
+            let changed = status.sequencer_reader != reader;
+            status.sequencer_reader = reader;
+            changed
+        });
+    }
+
+    pub(crate) fn set_celestia_reader(&self, reader: ReaderStatus) {
+        self.inner.send_if_modified(|status| {
+            let changed = status.celestia_reader != reader;
+            status.celestia_reader = reader;
+            changed
+        });
+    }
+}
+
+/// Updates the status of a single rollup, as registered through [`StatusSender::rollup`].
+#[derive(Clone)]
+pub(crate) struct RollupStatusSender {
+    inner: watch::Sender<StatusSnapshot>,
+    execution_rpc_url: String,
+}
+
+impl RollupStatusSender {
+    /// Resets the status to that of an executor that is not yet initialized.
+    pub(crate) fn reset(&self) {
+        self.modify(|rollup| std::mem::take(rollup) != RollupStatus::default());
+    }
+
+    pub(crate) fn set_executor_initialized(&self) {
+        self.modify(|rollup| {
+            let changed = !rollup.executor_initialized;
+            rollup.executor_initialized = true;
+            changed
+        });
+    }
+
+    pub(crate) fn set_rollup_state(&self, state: &State) {
+        let rollup_state = RollupState::from_state(state);
+        self.modify(|rollup| {
+            let changed = rollup.state.as_ref() != Some(&rollup_state);
+            rollup.state = Some(rollup_state);
+            changed
+        });
+    }
+
+    fn modify(&self, modify: impl FnOnce(&mut RollupStatus) -> bool) {
+        self.inner.send_if_modified(|status| {
+            modify(
+                status
+                    .rollups
+                    .entry(self.execution_rpc_url.clone())
+                    .or_default(),
+            )
+        });
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub(crate) struct StatusSnapshot {
+    sequencer_reader: ReaderStatus,
+    celestia_reader: ReaderStatus,
+    /// The status of every rollup, keyed by the URL of its execution API.
+    rollups: BTreeMap<String, RollupStatus>,
+}
+
+impl StatusSnapshot {
+    /// Returns if the executors of all rollups have completed their initialization, i.e. they
+    /// have started an execution session with their rollups and subscribed to the readers.
+    pub(crate) fn is_ready(&self) -> bool {
+        !self.rollups.is_empty()
+            && self
+                .rollups
+                .values()
+                .all(|rollup| rollup.executor_initialized)
+    }
+
+    /// Returns if none of the spawned readers has exited.
+    pub(crate) fn is_healthy(&self) -> bool {
+        self.sequencer_reader != ReaderStatus::Exited
+            && self.celestia_reader != ReaderStatus::Exited
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+struct RollupStatus {
+    executor_initialized: bool,
+    state: Option<RollupState>,
+}
+
+/// The rollup's commitment state and the parameters of its execution session.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+struct RollupState {
+    execution_session_id: String,
+    firm_number: u64,
+    soft_number: u64,
+    /// `None` if the firm number cannot be mapped to a sequencer height, which is only
+    /// possible if the commit level does not include firm blocks.
+    next_expected_firm_sequencer_height: Option<u64>,
+    /// `None` if the soft number cannot be mapped to a sequencer height, which is only
+    /// possible if the commit level does not include soft blocks.
+    next_expected_soft_sequencer_height: Option<u64>,
+    lowest_celestia_search_height: u64,
+    sequencer_start_block_height: u64,
+    sequencer_stop_height: Option<NonZeroU64>,
+    rollup_start_block_number: u64,
+    rollup_end_block_number: Option<NonZeroU64>,
+}
+
+impl RollupState {
+    fn from_state(state: &State) -> Self {
+        Self {
+            execution_session_id: state.execution_session_id(),
+            firm_number: state.firm_number(),
+            soft_number: state.soft_number(),
+            next_expected_firm_sequencer_height: state
+                .next_expected_firm_sequencer_height()
+                .ok()
+                .map(|height| height.value()),
+            next_expected_soft_sequencer_height: state
+                .next_expected_soft_sequencer_height()
+                .ok()
+                .map(|height| height.value()),
+            lowest_celestia_search_height: state.lowest_celestia_search_height(),
+            sequencer_start_block_height: state.sequencer_start_block_height(),
+            sequencer_stop_height: state.sequencer_stop_height(),
+            rollup_start_block_number: state.rollup_start_block_number(),
+            rollup_end_block_number: state.rollup_end_block_number(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        make_commitment_state,
+        make_execution_session_parameters,
+        make_rollup_state,
+    };
+
+    #[test]
+    fn ready_after_all_executors_initialized() {
+        let status = StatusSender::new();
+        let rx = status.subscribe();
+        assert!(!rx.borrow().is_ready());
+
+        let first = status.rollup("http://first".to_string());
+        let second = status.rollup("http://second".to_string());
+        first.set_executor_initialized();
+        assert!(!rx.borrow().is_ready());
+
+        second.set_executor_initialized();
+        assert!(rx.borrow().is_ready());
+
+        first.reset();
+        assert!(!rx.borrow().is_ready());
+    }
+
+    #[test]
+    fn degraded_if_a_reader_exited() {
+        let status = StatusSender::new();
+        let rx = status.subscribe();
+        assert!(rx.borrow().is_healthy());
+
+        status.set_sequencer_reader(ReaderStatus::Running);
+        status.set_celestia_reader(ReaderStatus::Running);
+        assert!(rx.borrow().is_healthy());
+
+        status.set_celestia_reader(ReaderStatus::Exited);
+        assert!(!rx.borrow().is_healthy());
+    }
+
+    #[test]
+    fn rollup_state_maps_rollup_numbers_to_sequencer_heights() {
+        let state = make_rollup_state(
+            "test_session".to_string(),
+            make_execution_session_parameters(),
+            make_commitment_state(),
+        );
+        let rollup = RollupState::from_state(&state);
+        assert_eq!(rollup.next_expected_firm_sequencer_height, Some(11));
+        assert_eq!(rollup.next_expected_soft_sequencer_height, Some(12));
+        assert_eq!(rollup.sequencer_stop_height, NonZeroU64::new(19));
+        assert_eq!(rollup.rollup_end_block_number, NonZeroU64::new(10));
+    }
+}
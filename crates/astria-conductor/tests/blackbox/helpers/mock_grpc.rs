@@ -1,6 +1,10 @@
 use std::{
     net::SocketAddr,
-    sync::Arc,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
 };
 
 use astria_core::generated::{
@@ -19,17 +23,30 @@ use astria_core::generated::{
             GetExecutedBlockMetadataRequest,
             UpdateCommitmentStateRequest,
         },
-        sequencerblock::v1::{
-            sequencer_service_server::{
-                SequencerService,
-                SequencerServiceServer,
+        sequencerblock::{
+            optimistic::v1alpha1::{
+                optimistic_block_service_server::{
+                    OptimisticBlockService,
+                    OptimisticBlockServiceServer,
+                },
+                GetBlockCommitmentStreamRequest,
+                GetBlockCommitmentStreamResponse,
+                GetOptimisticBlockStreamRequest,
+                GetOptimisticBlockStreamResponse,
+                SequencerBlockCommit,
+            },
+            v1::{
+                sequencer_service_server::{
+                    SequencerService,
+                    SequencerServiceServer,
+                },
+                FilteredSequencerBlock,
+                GetFilteredSequencerBlockRequest,
+                GetPendingNonceRequest,
+                GetPendingNonceResponse,
+                GetSequencerBlockRequest,
+                SequencerBlock,
             },
-            FilteredSequencerBlock,
-            GetFilteredSequencerBlockRequest,
-            GetPendingNonceRequest,
-            GetPendingNonceResponse,
-            GetSequencerBlockRequest,
-            SequencerBlock,
         },
     },
     sequencerblock::v1::{
@@ -44,7 +61,11 @@ use astria_eyre::eyre::{
     WrapErr as _,
 };
 use astria_grpc_mock::MockServer;
-use tokio::task::JoinHandle;
+use tokio::{
+    sync::mpsc,
+    task::JoinHandle,
+};
+use tokio_stream::wrappers::ReceiverStream;
 use tonic::{
     transport::Server,
     Request,
@@ -55,6 +76,7 @@ pub struct MockGrpc {
     _server: JoinHandle<eyre::Result<()>>,
     pub mock_server: MockServer,
     pub local_addr: SocketAddr,
+    pub optimistic_streams: OptimisticStreams,
 }
 
 impl MockGrpc {
@@ -65,14 +87,19 @@ impl MockGrpc {
         let local_addr = listener.local_addr().unwrap();
 
         let mock_server = MockServer::new();
+        let optimistic_streams = OptimisticStreams::default();
 
         let server = {
             let execution_service = ExecutionServiceImpl::new(mock_server.clone());
             let sequencer_service = SequencerServiceImpl::new(mock_server.clone());
+            let optimistic_block_service = OptimisticBlockServiceImpl {
+                streams: optimistic_streams.clone(),
+            };
             tokio::spawn(async move {
                 Server::builder()
                     .add_service(ExecutionServiceServer::new(execution_service))
                     .add_service(SequencerServiceServer::new(sequencer_service))
+                    .add_service(OptimisticBlockServiceServer::new(optimistic_block_service))
                     .serve_with_incoming(TcpListenerStream::new(listener))
                     .await
                     .wrap_err("gRPC server failed")
@@ -82,10 +109,95 @@ impl MockGrpc {
             _server: server,
             mock_server,
             local_addr,
+            optimistic_streams,
         }
     }
 }
 
+type StreamSenders<T> = Arc<Mutex<Vec<mpsc::Sender<tonic::Result<T>>>>>;
+
+/// The streams opened against the mocked optimistic block service.
+///
+/// [`MockServer`] only supports unary RPCs, so the messages of the streams are sent through this
+/// type instead of being mounted.
+#[derive(Clone, Default)]
+pub struct OptimisticStreams {
+    proposed_blocks: StreamSenders<GetOptimisticBlockStreamResponse>,
+    block_commitments: StreamSenders<GetBlockCommitmentStreamResponse>,
+}
+
+impl OptimisticStreams {
+    /// Sends `block` over all open optimistic block streams, waiting until at least one is open.
+    pub async fn send_proposed_block(&self, block: FilteredSequencerBlock) {
+        send_once_open(
+            &self.proposed_blocks,
+            GetOptimisticBlockStreamResponse {
+                block: Some(block),
+            },
+        )
+        .await;
+    }
+
+    /// Sends `commitment` over all open block commitment streams, waiting until at least one is
+    /// open.
+    pub async fn send_block_commitment(&self, commitment: SequencerBlockCommit) {
+        send_once_open(
+            &self.block_commitments,
+            GetBlockCommitmentStreamResponse {
+                commitment: Some(commitment),
+            },
+        )
+        .await;
+    }
+}
+
+async fn send_once_open<T: Clone>(senders: &StreamSenders<T>, message: T) {
+    loop {
+        let open = {
+            let mut senders = senders.lock().unwrap();
+            senders.retain(|sender| !sender.is_closed());
+            senders.clone()
+        };
+        if !open.is_empty() {
+            for sender in open {
+                let _ = sender.send(Ok(message.clone())).await;
+            }
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+struct OptimisticBlockServiceImpl {
+    streams: OptimisticStreams,
+}
+
+#[tonic::async_trait]
+impl OptimisticBlockService for OptimisticBlockServiceImpl {
+    type GetBlockCommitmentStreamStream =
+        ReceiverStream<tonic::Result<GetBlockCommitmentStreamResponse>>;
+    type GetOptimisticBlockStreamStream =
+        ReceiverStream<tonic::Result<GetOptimisticBlockStreamResponse>>;
+
+    async fn get_optimistic_block_stream(
+        self: Arc<Self>,
+        _request: Request<GetOptimisticBlockStreamRequest>,
+    ) -> tonic::Result<Response<Self::GetOptimisticBlockStreamStream>> {
+        let (tx, rx) = mpsc::channel(16);
+        self.streams.proposed_blocks.lock().unwrap().push(tx);
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+
+    async fn get_block_commitment_stream(
+        self: Arc<Self>,
+        _request: Request<GetBlockCommitmentStreamRequest>,
+    ) -> tonic::Result<Response<Self::GetBlockCommitmentStreamStream>> {
+        let (tx, rx) = mpsc::channel(16);
+        self.streams.block_commitments.lock().unwrap().push(tx);
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+}
+
 struct SequencerServiceImpl {
     mock_server: MockServer,
 }
@@ -99,15 +211,16 @@ impl SequencerServiceImpl {
 }
 
 // XXX: Manually implementing this trait instead of using the `define_and_impl_service!` macro
-// because `GetSequencerBlockRequest` and `SequencerBlock` don't currently implement
-// `serde::Serialize`.
+// because not all of its methods are mocked.
 #[tonic::async_trait]
 impl SequencerService for SequencerServiceImpl {
     async fn get_sequencer_block(
         self: Arc<Self>,
-        _request: Request<GetSequencerBlockRequest>,
+        request: Request<GetSequencerBlockRequest>,
     ) -> tonic::Result<Response<SequencerBlock>> {
-        unimplemented!()
+        self.mock_server
+            .handle_request("get_sequencer_block", request)
+            .await
     }
 
     async fn get_filtered_sequencer_block(
@@ -1,4 +1,5 @@
 use std::{
+    path::Path,
     sync::LazyLock,
     time::Duration,
 };
@@ -23,6 +24,7 @@ use astria_core::{
     primitive::v1::RollupId,
     sequencerblock::v1::block,
 };
+use astria_data_availability::Backend;
 use astria_grpc_mock::response::error_response;
 use bytes::Bytes;
 use celestia_types::{
@@ -40,8 +42,13 @@ use telemetry::metrics;
 #[macro_use]
 mod macros;
 mod mock_grpc;
+mod relayer;
 use astria_eyre;
 pub use mock_grpc::MockGrpc;
+pub use relayer::{
+    spawn_relayer_with_local_da,
+    TestRelayer,
+};
 use serde_json::json;
 use tracing::debug;
 use wiremock::MockServer;
@@ -51,6 +58,10 @@ pub const CELESTIA_BEARER_TOKEN: &str = "ABCDEFGH";
 pub const ROLLUP_ID: RollupId = RollupId::new([42; 32]);
 pub static ROLLUP_ID_BYTES: Bytes = Bytes::from_static(ROLLUP_ID.as_bytes());
 
+/// The ID of the rollup driven by the conductor spawned with [`spawn_conductor_with_other_rollup`]
+/// in addition to [`ROLLUP_ID`].
+pub const OTHER_ROLLUP_ID: RollupId = RollupId::new([69; 32]);
+
 pub const SEQUENCER_CHAIN_ID: &str = "test_sequencer-1000";
 pub const CELESTIA_CHAIN_ID: &str = "test_celestia-1000";
 pub const EXECUTION_SESSION_ID: &str = "test_execution_session";
@@ -79,6 +90,70 @@ static TELEMETRY: LazyLock<()> = LazyLock::new(|| {
 });
 
 pub async fn spawn_conductor(execution_commit_level: CommitLevel) -> TestConductor {
+    spawn_conductor_with_backend(
+        execution_commit_level,
+        Backend::Celestia,
+        String::new(),
+        None,
+        false,
+    )
+    .await
+}
+
+/// Spawns a conductor that optimistically executes the blocks proposed to Sequencer, which are
+/// served by [`MockGrpc::optimistic_streams`].
+pub async fn spawn_conductor_with_optimistic_blocks(
+    execution_commit_level: CommitLevel,
+) -> TestConductor {
+    spawn_conductor_with_backend(
+        execution_commit_level,
+        Backend::Celestia,
+        String::new(),
+        None,
+        true,
+    )
+    .await
+}
+
+/// Spawns a conductor driving a second rollup with ID [`OTHER_ROLLUP_ID`], whose execution API is
+/// served by [`TestConductor::other_rollup`].
+pub async fn spawn_conductor_with_other_rollup(
+    execution_commit_level: CommitLevel,
+) -> TestConductor {
+    let other_rollup = MockGrpc::spawn().await;
+    spawn_conductor_with_backend(
+        execution_commit_level,
+        Backend::Celestia,
+        String::new(),
+        Some(other_rollup),
+        false,
+    )
+    .await
+}
+
+/// Spawns a conductor reading firm blocks from the local data availability layer in
+/// `local_da_dir` instead of Celestia.
+pub async fn spawn_conductor_with_local_da(
+    execution_commit_level: CommitLevel,
+    local_da_dir: &Path,
+) -> TestConductor {
+    spawn_conductor_with_backend(
+        execution_commit_level,
+        Backend::Local,
+        local_da_dir.display().to_string(),
+        None,
+        false,
+    )
+    .await
+}
+
+async fn spawn_conductor_with_backend(
+    execution_commit_level: CommitLevel,
+    da_backend: Backend,
+    local_da_dir: String,
+    other_rollup: Option<MockGrpc>,
+    sequencer_optimistic_blocks: bool,
+) -> TestConductor {
     assert_ne!(
         tokio::runtime::Handle::current().runtime_flavor(),
         tokio::runtime::RuntimeFlavor::CurrentThread,
@@ -91,12 +166,20 @@ pub async fn spawn_conductor(execution_commit_level: CommitLevel) -> TestConduct
     let mock_grpc = MockGrpc::spawn().await;
     let mock_http = wiremock::MockServer::start().await;
 
+    let mut execution_rpc_url = format!("http://{}", mock_grpc.local_addr);
+    if let Some(other_rollup) = &other_rollup {
+        execution_rpc_url.push_str(&format!(",http://{}", other_rollup.local_addr));
+    }
+
     let config = Config {
         celestia_node_http_url: mock_http.uri(),
-        execution_rpc_url: format!("http://{}", mock_grpc.local_addr),
+        execution_rpc_url,
         sequencer_cometbft_url: mock_http.uri(),
         sequencer_grpc_url: format!("http://{}", mock_grpc.local_addr),
         execution_commit_level,
+        da_backend,
+        local_da_dir,
+        sequencer_optimistic_blocks,
         ..make_config()
     };
 
@@ -116,6 +199,7 @@ pub async fn spawn_conductor(execution_commit_level: CommitLevel) -> TestConduct
         mock_grpc,
         mock_http,
         metrics_handle,
+        other_rollup,
     }
 }
 
@@ -124,6 +208,8 @@ pub struct TestConductor {
     pub mock_grpc: MockGrpc,
     pub mock_http: wiremock::MockServer,
     pub metrics_handle: metrics::Handle,
+    /// The execution API of the rollup with ID [`OTHER_ROLLUP_ID`], if the conductor drives it.
+    pub other_rollup: Option<MockGrpc>,
 }
 
 impl Drop for TestConductor {
@@ -519,9 +605,14 @@ pub(crate) fn make_config() -> Config {
         celestia_node_http_url: "http://127.0.0.1:26658".into(),
         no_celestia_auth: false,
         celestia_bearer_token: CELESTIA_BEARER_TOKEN.into(),
+        da_backend: Backend::Celestia,
+        local_da_dir: String::new(),
         sequencer_grpc_url: "http://127.0.0.1:8080".into(),
         sequencer_cometbft_url: "http://127.0.0.1:26657".into(),
         sequencer_requests_per_second: 500,
+        firm_block_cache_dir: String::new(),
+        firm_block_cache_max_bytes: 0,
+        sequencer_optimistic_blocks: false,
         sequencer_block_time_ms: 2000,
         execution_rpc_url: "http://127.0.0.1:50051".into(),
         log: "info".into(),
@@ -530,11 +621,42 @@ pub(crate) fn make_config() -> Config {
         no_otel: false,
         no_metrics: true,
         metrics_http_listener_addr: String::new(),
+        api_addr: "127.0.0.1:0".into(),
     }
 }
 
 #[must_use]
 pub fn make_sequencer_block(height: u32) -> astria_core::sequencerblock::v1::SequencerBlock {
+    make_sequencer_block_with_sequence_data(height, vec![(crate::ROLLUP_ID, data())])
+}
+
+/// Makes the Sequencer block at `height` containing the transactions of both [`ROLLUP_ID`] and
+/// [`OTHER_ROLLUP_ID`].
+///
+/// The block has the same hash as the one returned by [`make_sequencer_block`] so that it can be
+/// verified against the commit returned by [`make_commit`].
+#[must_use]
+pub fn make_sequencer_block_with_other_rollup(
+    height: u32,
+) -> astria_core::sequencerblock::v1::SequencerBlock {
+    make_sequencer_block_with_sequence_data(
+        height,
+        vec![(crate::ROLLUP_ID, data()), (OTHER_ROLLUP_ID, other_data())],
+    )
+}
+
+/// Makes the block at `height` filtered for both [`ROLLUP_ID`] and [`OTHER_ROLLUP_ID`].
+#[must_use]
+pub fn make_filtered_sequencer_block_with_other_rollup(height: u32) -> FilteredSequencerBlock {
+    make_sequencer_block_with_other_rollup(height)
+        .into_filtered_block([crate::ROLLUP_ID, OTHER_ROLLUP_ID])
+        .into_raw()
+}
+
+fn make_sequencer_block_with_sequence_data(
+    height: u32,
+    sequence_data: Vec<(RollupId, Vec<u8>)>,
+) -> astria_core::sequencerblock::v1::SequencerBlock {
     fn repeat_bytes_of_u32_as_array(val: u32) -> [u8; 32] {
         let repr = val.to_le_bytes();
         [
@@ -549,7 +671,7 @@ pub fn make_sequencer_block(height: u32) -> astria_core::sequencerblock::v1::Seq
         block_hash: Some(block::Hash::new(repeat_bytes_of_u32_as_array(height))),
         chain_id: Some(crate::SEQUENCER_CHAIN_ID.to_string()),
         height,
-        sequence_data: vec![(crate::ROLLUP_ID, data())],
+        sequence_data,
         unix_timestamp: (1i64, 1u32).into(),
         signing_key: Some(signing_key()),
         proposer_address: None,
@@ -581,37 +703,76 @@ pub fn make_blobs(heights: &[u32]) -> Blobs {
         );
         rollup_data.push(tail.swap_remove(0).into_raw());
     }
-    let header_list = SubmittedMetadataList {
-        entries: metadata,
-    };
-    let rollup_data_list = SubmittedRollupDataList {
-        entries: rollup_data,
-    };
-
-    let raw_header_list = ::prost::Message::encode_to_vec(&header_list);
-    let head_list_compressed = compress_bytes(&raw_header_list).unwrap();
-    let header = Blob::new(
-        sequencer_namespace(),
-        head_list_compressed,
-        celestia_types::AppVersion::V3,
-    )
-    .unwrap();
+    Blobs {
+        header: make_blob(
+            sequencer_namespace(),
+            &SubmittedMetadataList {
+                entries: metadata,
+            },
+        ),
+        rollup: make_blob(
+            rollup_namespace(),
+            &SubmittedRollupDataList {
+                entries: rollup_data,
+            },
+        ),
+    }
+}
 
-    let raw_rollup_data_list = ::prost::Message::encode_to_vec(&rollup_data_list);
-    let rollup_data_list_compressed = compress_bytes(&raw_rollup_data_list).unwrap();
-    let rollup = Blob::new(
-        rollup_namespace(),
-        rollup_data_list_compressed,
-        celestia_types::AppVersion::V3,
-    )
-    .unwrap();
+/// The blobs of the blocks returned by [`make_sequencer_block_with_other_rollup`].
+pub struct BlobsWithOtherRollup {
+    pub header: Blob,
+    pub rollup: Blob,
+    pub other_rollup: Blob,
+}
 
-    Blobs {
-        header,
-        rollup,
+#[must_use]
+pub fn make_blobs_with_other_rollup(heights: &[u32]) -> BlobsWithOtherRollup {
+    use astria_core::generated::astria::sequencerblock::v1::{
+        SubmittedMetadataList,
+        SubmittedRollupDataList,
+    };
+    let mut metadata = Vec::new();
+    let mut rollup_data = Vec::new();
+    let mut other_rollup_data = Vec::new();
+    for &height in heights {
+        let (head, tail) = make_sequencer_block_with_other_rollup(height).split_for_celestia();
+        metadata.push(head.into_raw());
+        for rollup in tail {
+            if rollup.rollup_id() == OTHER_ROLLUP_ID {
+                other_rollup_data.push(rollup.into_raw());
+            } else {
+                rollup_data.push(rollup.into_raw());
+            }
+        }
+    }
+    BlobsWithOtherRollup {
+        header: make_blob(
+            sequencer_namespace(),
+            &SubmittedMetadataList {
+                entries: metadata,
+            },
+        ),
+        rollup: make_blob(
+            rollup_namespace(),
+            &SubmittedRollupDataList {
+                entries: rollup_data,
+            },
+        ),
+        other_rollup: make_blob(
+            other_rollup_namespace(),
+            &SubmittedRollupDataList {
+                entries: other_rollup_data,
+            },
+        ),
     }
 }
 
+fn make_blob(namespace: Namespace, list: &impl Message) -> Blob {
+    let compressed = compress_bytes(&list.encode_to_vec()).unwrap();
+    Blob::new(namespace, compressed, celestia_types::AppVersion::V3).unwrap()
+}
+
 fn signing_key() -> astria_core::crypto::SigningKey {
     use rand_chacha::{
         rand_core::SeedableRng as _,
@@ -708,6 +869,12 @@ pub fn data() -> Vec<u8> {
     b"hello_world".to_vec()
 }
 
+/// The transactions of the rollup with ID [`OTHER_ROLLUP_ID`].
+#[must_use]
+pub fn other_data() -> Vec<u8> {
+    b"hello_other_world".to_vec()
+}
+
 #[must_use]
 pub fn make_validator_set(height: u32) -> tendermint_rpc::endpoint::validators::Response {
     tendermint_rpc::endpoint::validators::Response::new(height.into(), vec![validator()], 1)
@@ -718,6 +885,11 @@ pub fn rollup_namespace() -> Namespace {
     astria_core::celestia::namespace_v0_from_rollup_id(ROLLUP_ID)
 }
 
+#[must_use]
+pub fn other_rollup_namespace() -> Namespace {
+    astria_core::celestia::namespace_v0_from_rollup_id(OTHER_ROLLUP_ID)
+}
+
 #[must_use]
 pub fn sequencer_namespace() -> Namespace {
     astria_core::celestia::namespace_v0_from_sha256_of_bytes(SEQUENCER_CHAIN_ID.as_bytes())
@@ -0,0 +1,257 @@
+//! A sequencer-relayer writing to a local data availability layer, so that the blobs read by
+//! conductor are produced by the relayer itself.
+
+use std::{
+    path::Path,
+    time::Duration,
+};
+
+use astria_core::generated::astria::sequencerblock::v1::GetSequencerBlockRequest;
+use astria_data_availability::{
+    Backend,
+    BlobReader as _,
+    LocalDa,
+};
+use astria_sequencer_relayer::{
+    Config,
+    SequencerRelayer,
+    ShutdownHandle,
+};
+use sequencer_client::tendermint_rpc;
+use serde_json::json;
+use telemetry::metrics;
+use tempfile::NamedTempFile;
+use tokio::task::JoinHandle;
+use tracing::debug;
+use wiremock::MockServer;
+
+use super::{
+    make_sequencer_block,
+    MockGrpc,
+    CELESTIA_CHAIN_ID,
+    SEQUENCER_CHAIN_ID,
+};
+
+/// Spawns a sequencer-relayer submitting the blocks after `last_written_sequencer_height` to the
+/// local data availability layer in `local_da_dir`.
+///
+/// The relayer is served the sequencer blocks created by [`make_sequencer_block`], the same
+/// blocks conductor is served.
+pub async fn spawn_relayer_with_local_da(
+    local_da_dir: &Path,
+    last_written_sequencer_height: u64,
+) -> TestRelayer {
+    let mock_grpc = MockGrpc::spawn().await;
+    let mock_http = MockServer::start().await;
+    mount_cometbft_status(&mock_http).await;
+
+    let submission_state_file = NamedTempFile::new().unwrap();
+    serde_json::to_writer(
+        &submission_state_file,
+        &json!({
+            "state": "started",
+            "last_submission": {
+                "celestia_height": 0,
+                "sequencer_height": last_written_sequencer_height,
+            }
+        }),
+    )
+    .unwrap();
+
+    let config = Config {
+        sequencer_chain_id: SEQUENCER_CHAIN_ID.to_string(),
+        celestia_chain_id: CELESTIA_CHAIN_ID.to_string(),
+        cometbft_endpoint: mock_http.uri(),
+        sequencer_grpc_endpoint: format!("http://{}", mock_grpc.local_addr),
+        celestia_app_grpc_endpoint: String::new(),
+        celestia_app_key_file: String::new(),
+        da_backend: Backend::Local,
+        local_da_dir: local_da_dir.display().to_string(),
+        block_time: 1000,
+        only_include_rollups: String::new(),
+        api_addr: "127.0.0.1:0".into(),
+        log: String::new(),
+        force_stdout: false,
+        no_otel: true,
+        no_metrics: true,
+        metrics_http_listener_addr: String::new(),
+        submission_state_path: submission_state_file.path().to_owned(),
+    };
+
+    let (metrics, _) = metrics::ConfigBuilder::new()
+        .set_global_recorder(false)
+        .build(&())
+        .unwrap();
+    let metrics = Box::leak(Box::new(metrics));
+
+    let (relayer, shutdown_handle) = SequencerRelayer::new(config, metrics).await.unwrap();
+    let relayer = tokio::spawn(relayer.run());
+
+    TestRelayer {
+        relayer,
+        shutdown_handle: Some(shutdown_handle),
+        mock_grpc,
+        mock_http,
+        local_da: LocalDa::new(local_da_dir),
+        _submission_state_file: submission_state_file,
+    }
+}
+
+pub struct TestRelayer {
+    relayer: JoinHandle<()>,
+    shutdown_handle: Option<ShutdownHandle>,
+    pub mock_grpc: MockGrpc,
+    pub mock_http: MockServer,
+    local_da: LocalDa,
+    _submission_state_file: NamedTempFile,
+}
+
+impl Drop for TestRelayer {
+    fn drop(&mut self) {
+        // Dropping the shutdown handle shuts the relayer down.
+        let _ = self.shutdown_handle.take();
+        let relayer = std::mem::replace(&mut self.relayer, tokio::spawn(async {}));
+        futures::executor::block_on(async move {
+            if tokio::time::timeout(Duration::from_secs(2), relayer)
+                .await
+                .is_err()
+            {
+                debug!("timed out waiting for sequencer-relayer to shut down");
+            }
+        });
+    }
+}
+
+impl TestRelayer {
+    /// Mounts the latest sequencer height observed by the relayer.
+    pub async fn mount_abci_info(&self, latest_block_height: u32) {
+        use wiremock::{
+            matchers::body_partial_json,
+            Mock,
+            ResponseTemplate,
+        };
+        Mock::given(body_partial_json(
+            json!({"jsonrpc": "2.0", "method": "abci_info", "params": null}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(
+            tendermint_rpc::response::Wrapper::new_with_id(
+                tendermint_rpc::Id::uuid_v4(),
+                Some(tendermint_rpc::endpoint::abci_info::Response {
+                    response: sequencer_client::tendermint::abci::response::Info {
+                        last_block_height: latest_block_height.into(),
+                        ..Default::default()
+                    },
+                }),
+                None,
+            ),
+        ))
+        .expect(1..)
+        .mount(&self.mock_http)
+        .await;
+    }
+
+    /// Mounts the sequencer block at `height`, as created by [`make_sequencer_block`].
+    pub async fn mount_get_sequencer_block(&self, height: u32) {
+        use astria_grpc_mock::{
+            matcher::message_partial_pbjson,
+            response::constant_response,
+            Mock,
+        };
+        Mock::for_rpc_given(
+            "get_sequencer_block",
+            message_partial_pbjson(&GetSequencerBlockRequest {
+                height: height.into(),
+            }),
+        )
+        .respond_with(constant_response(make_sequencer_block(height).into_raw()))
+        .expect(1..)
+        .mount(&self.mock_grpc.mock_server)
+        .await;
+    }
+
+    /// Waits until the local data availability layer has reached `height`.
+    ///
+    /// # Panics
+    /// Panics if the height was not reached within `within`.
+    pub async fn wait_for_local_da_height(&self, height: u64, within: Duration) {
+        tokio::time::timeout(within, async {
+            loop {
+                if self
+                    .local_da
+                    .latest_height()
+                    .await
+                    .is_ok_and(|latest_height| latest_height >= height)
+                {
+                    return;
+                }
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+        })
+        .await
+        .unwrap_or_else(|_| {
+            panic!(
+                "sequencer-relayer should have submitted blobs at local data availability height \
+                 {height} within {within:?}"
+            )
+        });
+    }
+}
+
+/// Mounts the `CometBFT` status response the relayer uses to confirm the sequencer chain ID.
+async fn mount_cometbft_status(mock_http: &MockServer) {
+    use wiremock::{
+        matchers::body_partial_json,
+        Mock,
+        ResponseTemplate,
+    };
+    let status: tendermint_rpc::endpoint::status::Response = serde_json::from_value(json!({
+        "node_info": {
+            "protocol_version": {
+                "p2p": "8",
+                "block": "11",
+                "app": "0"
+            },
+            "id": "a1d3bbddb7800c6da2e64169fec281494e963ba3",
+            "listen_addr": "tcp://0.0.0.0:26656",
+            "network": SEQUENCER_CHAIN_ID,
+            "version": "0.38.6",
+            "channels": "40202122233038606100",
+            "moniker": "fullnode",
+            "other": {
+                "tx_index": "on",
+                "rpc_address": "tcp://0.0.0.0:26657"
+            }
+        },
+        "sync_info": {
+            "latest_block_hash": "A4202E4E367712AC2A797860265A7EBEA8A3ACE513CB0105C2C9058449641202",
+            "latest_app_hash": "BCC9C9B82A49EC37AADA41D32B4FBECD2441563703955413195BDA2236775A68",
+            "latest_block_height": "3",
+            "latest_block_time": "2024-05-09T15:59:17.849713071Z",
+            "earliest_block_hash": "C34B7B0B82423554B844F444044D7D08A026D6E413E6F72848DB2F8C77ACE165",
+            "earliest_app_hash": "6B776065775471CEF46AC75DE09A4B869A0E0EB1D7725A04A342C0E46C16F472",
+            "earliest_block_height": "1",
+            "earliest_block_time": "2024-04-23T00:49:11.964127Z",
+            "catching_up": false
+        },
+        "validator_info": {
+            "address": "0B46F33BA2FA5C2E2AD4C4C4E5ECE3F1CA03D195",
+            "pub_key": {
+                "type": "tendermint/PubKeyEd25519",
+                "value": "bA6GipHUijVuiYhv+4XymdePBsn8EeTqjGqNQrBGZ4I="
+            },
+            "voting_power": "0"
+        }
+    }))
+    .unwrap();
+    Mock::given(body_partial_json(json!({"method": "status"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(
+            tendermint_rpc::response::Wrapper::new_with_id(
+                tendermint_rpc::Id::uuid_v4(),
+                Some(status),
+                None,
+            ),
+        ))
+        .expect(1..)
+        .mount(mock_http)
+        .await;
+}
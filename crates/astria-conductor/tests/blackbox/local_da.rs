@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use astria_conductor::config::CommitLevel;
+use futures::future::join;
+use tokio::time::timeout;
+
+use crate::{
+    helpers::{
+        spawn_conductor_with_local_da,
+        spawn_relayer_with_local_da,
+        SEQUENCER_CHAIN_ID,
+    },
+    mount_create_execution_session,
+    mount_execute_block,
+    mount_sequencer_commit,
+    mount_sequencer_genesis,
+    mount_sequencer_validator_set,
+    mount_update_commitment_state,
+};
+
+/// Tests that a block submitted by sequencer-relayer to a local data availability layer is read,
+/// verified and executed as a firm block by conductor reading from the same directory.
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn block_relayed_to_local_da_is_executed_as_firm() {
+    let local_da_dir = tempfile::tempdir().unwrap();
+
+    let test_relayer = spawn_relayer_with_local_da(local_da_dir.path(), 2).await;
+    test_relayer.mount_abci_info(3).await;
+    test_relayer.mount_get_sequencer_block(3).await;
+    test_relayer
+        .wait_for_local_da_height(1, Duration::from_millis(3000))
+        .await;
+
+    let test_conductor =
+        spawn_conductor_with_local_da(CommitLevel::FirmOnly, local_da_dir.path()).await;
+
+    mount_create_execution_session!(
+        test_conductor,
+        execution_session_parameters: (
+            rollup_start_block_number: 2,
+            rollup_end_block_number: 9,
+            sequencer_start_block_height: 3,
+            celestia_max_look_ahead: 10,
+        ),
+        commitment_state: (
+            firm: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            soft: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            lowest_celestia_search_height: 1,
+        )
+    );
+
+    mount_sequencer_genesis!(test_conductor);
+
+    mount_sequencer_commit!(
+        test_conductor,
+        height: 3u32,
+    );
+
+    mount_sequencer_validator_set!(test_conductor, height: 2u32);
+
+    let execute_block = mount_execute_block!(
+        test_conductor,
+        number: 2,
+        hash: "2",
+        parent: "1",
+    );
+
+    let update_commitment_state = mount_update_commitment_state!(
+        test_conductor,
+        firm: (
+            number: 2,
+            hash: "2",
+            parent: "1",
+        ),
+        soft: (
+            number: 2,
+            hash: "2",
+            parent: "1",
+        ),
+        lowest_celestia_search_height: 1,
+    );
+
+    timeout(
+        Duration::from_millis(2000),
+        join(
+            execute_block.wait_until_satisfied(),
+            update_commitment_state.wait_until_satisfied(),
+        ),
+    )
+    .await
+    .expect(
+        "conductor should have executed the firm block read from the local data availability \
+         layer and updated the firm commitment state within 2000ms",
+    );
+}
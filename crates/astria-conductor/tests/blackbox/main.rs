@@ -5,6 +5,9 @@
 
 pub mod firm_only;
 pub mod helpers;
+pub mod local_da;
+pub mod multiple_rollups;
+pub mod optimistic;
 pub mod shutdown;
 pub mod soft_and_firm;
 pub mod soft_only;
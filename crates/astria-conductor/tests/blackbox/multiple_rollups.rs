@@ -0,0 +1,695 @@
+//! Tests of a conductor driving two rollups that share its Sequencer and Celestia readers.
+//!
+//! The first rollup with ID [`crate::ROLLUP_ID`] is served by [`TestConductor::mock_grpc`] and
+//! mounted using the macros of the helpers module. The second rollup with ID [`OTHER_ROLLUP_ID`]
+//! is served by [`TestConductor::other_rollup`] and mounted using the functions of this module.
+
+use std::time::Duration;
+
+use astria_conductor::config::CommitLevel;
+use astria_core::generated::astria::{
+    execution::v2::{
+        CommitmentState,
+        CreateExecutionSessionRequest,
+        ExecuteBlockResponse,
+        ExecutedBlockMetadata,
+        UpdateCommitmentStateRequest,
+    },
+    sequencerblock::v1::GetFilteredSequencerBlockRequest,
+};
+use astria_grpc_mock::{
+    matcher::{
+        message_partial_pbjson,
+        message_type,
+    },
+    response::{
+        constant_response,
+        error_response,
+    },
+    Mock,
+    MockGuard,
+    MockServer,
+};
+use base64::prelude::*;
+use futures::future::{
+    join,
+    join4,
+    join_all,
+};
+use serde_json::json;
+use tokio::time::timeout;
+
+use crate::{
+    block_metadata,
+    execution_session,
+    helpers::{
+        make_blobs_with_other_rollup,
+        make_filtered_sequencer_block_with_other_rollup,
+        other_data,
+        other_rollup_namespace,
+        spawn_conductor_with_other_rollup,
+        TestConductor,
+        EXECUTION_SESSION_ID,
+        OTHER_ROLLUP_ID,
+    },
+    mount_abci_info,
+    mount_celestia_header_network_head,
+    mount_create_execution_session,
+    mount_execute_block,
+    mount_sequencer_commit,
+    mount_sequencer_genesis,
+    mount_sequencer_validator_set,
+    mount_update_commitment_state,
+    rollup_namespace,
+    sequencer_namespace,
+    SEQUENCER_CHAIN_ID,
+};
+
+/// The first rollup starts at Sequencer height 4 and executes its first block before the second
+/// rollup, starting at the lower Sequencer height 3, subscribes to the shared Sequencer reader.
+///
+/// Asserts that the second rollup still receives the block at height 3, and that each rollup only
+/// executes its own transactions from the blocks fetched for both.
+#[expect(
+    clippy::too_many_lines,
+    reason = "All lines reasonably necessary for the thoroughness of this test"
+)]
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn soft_blocks_are_sent_to_rollup_subscribing_later_at_lower_height() {
+    let test_conductor = spawn_conductor_with_other_rollup(CommitLevel::SoftOnly).await;
+    let _foreign_transactions =
+        mount_execute_block_with_foreign_transactions(&test_conductor).await;
+
+    mount_create_execution_session!(
+        test_conductor,
+        execution_session_parameters: (
+            rollup_start_block_number: 2,
+            rollup_end_block_number: 9,
+            sequencer_start_block_height: 4,
+            celestia_max_look_ahead: 10,
+        ),
+        commitment_state: (
+            firm: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            soft: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            lowest_celestia_search_height: 1,
+        )
+    );
+
+    mount_sequencer_genesis!(test_conductor);
+
+    mount_abci_info!(
+        test_conductor,
+        latest_sequencer_height: 4,
+    );
+
+    mount_get_filtered_sequencer_block_with_other_rollup(&test_conductor, 3).await;
+    mount_get_filtered_sequencer_block_with_other_rollup(&test_conductor, 4).await;
+
+    let execute_block = mount_execute_block!(
+        test_conductor,
+        number: 2,
+        hash: "2",
+        parent: "1",
+    );
+
+    let update_commitment_state = mount_update_commitment_state!(
+        test_conductor,
+        firm: (
+            number: 1,
+            hash: "1",
+            parent: "0",
+        ),
+        soft: (
+            number: 2,
+            hash: "2",
+            parent: "1",
+        ),
+        lowest_celestia_search_height: 1,
+    );
+
+    timeout(
+        Duration::from_millis(1000),
+        join(
+            execute_block.wait_until_satisfied(),
+            update_commitment_state.wait_until_satisfied(),
+        ),
+    )
+    .await
+    .expect(
+        "conductor should have executed the first rollup's soft block at sequencer height 4 \
+         within 1000ms",
+    );
+
+    // The other rollup only receives its execution session now, so that it subscribes after the
+    // reader already forwarded the block at height 4.
+    mount_other_create_execution_session(&test_conductor, 3, 9, 1).await;
+
+    let other_execute_block_number_2 = mount_other_execute_block(
+        &test_conductor,
+        block_metadata!(number: 2, hash: "2", parent: "1"),
+    )
+    .await;
+    let other_update_commitment_state_number_2 = mount_other_update_commitment_state(
+        &test_conductor,
+        block_metadata!(number: 1, hash: "1", parent: "0"),
+        block_metadata!(number: 2, hash: "2", parent: "1"),
+    )
+    .await;
+    let other_execute_block_number_3 = mount_other_execute_block(
+        &test_conductor,
+        block_metadata!(number: 3, hash: "3", parent: "2"),
+    )
+    .await;
+    let other_update_commitment_state_number_3 = mount_other_update_commitment_state(
+        &test_conductor,
+        block_metadata!(number: 1, hash: "1", parent: "0"),
+        block_metadata!(number: 3, hash: "3", parent: "2"),
+    )
+    .await;
+
+    // The other rollup's executor retries creating its execution session with exponential
+    // backoff, hence the longer timeout.
+    timeout(
+        Duration::from_millis(3000),
+        join4(
+            other_execute_block_number_2.wait_until_satisfied(),
+            other_update_commitment_state_number_2.wait_until_satisfied(),
+            other_execute_block_number_3.wait_until_satisfied(),
+            other_update_commitment_state_number_3.wait_until_satisfied(),
+        ),
+    )
+    .await
+    .expect(
+        "conductor should have executed the other rollup's soft blocks at sequencer heights 3 and \
+         4 within 3000ms",
+    );
+}
+
+/// The firm counterpart of [`soft_blocks_are_sent_to_rollup_subscribing_later_at_lower_height`]:
+/// the second rollup subscribes to the shared Celestia reader after it reconstructed the first
+/// rollup's block from the same Celestia height.
+#[expect(
+    clippy::too_many_lines,
+    reason = "All lines reasonably necessary for the thoroughness of this test"
+)]
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn firm_blocks_are_sent_to_rollup_subscribing_later_at_lower_height() {
+    let test_conductor = spawn_conductor_with_other_rollup(CommitLevel::FirmOnly).await;
+    let _foreign_transactions =
+        mount_execute_block_with_foreign_transactions(&test_conductor).await;
+
+    mount_create_execution_session!(
+        test_conductor,
+        execution_session_parameters: (
+            rollup_start_block_number: 2,
+            rollup_end_block_number: 9,
+            sequencer_start_block_height: 4,
+            celestia_max_look_ahead: 10,
+        ),
+        commitment_state: (
+            firm: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            soft: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            lowest_celestia_search_height: 1,
+        )
+    );
+
+    mount_sequencer_genesis!(test_conductor);
+
+    mount_celestia_header_network_head!(
+        test_conductor,
+        height: 1u32,
+    );
+
+    let blobs = make_blobs_with_other_rollup(&[3, 4]);
+    test_conductor
+        .mount_celestia_blob_get_all(1, sequencer_namespace(), vec![blobs.header], None)
+        .await;
+    test_conductor
+        .mount_celestia_blob_get_all(1, rollup_namespace(), vec![blobs.rollup], None)
+        .await;
+    test_conductor
+        .mount_celestia_blob_get_all(1, other_rollup_namespace(), vec![blobs.other_rollup], None)
+        .await;
+
+    mount_sequencer_commit!(
+        test_conductor,
+        height: 3u32,
+    );
+    mount_sequencer_validator_set!(test_conductor, height: 2u32);
+    mount_sequencer_commit!(
+        test_conductor,
+        height: 4u32,
+    );
+    mount_sequencer_validator_set!(test_conductor, height: 3u32);
+
+    let execute_block = mount_execute_block!(
+        test_conductor,
+        number: 2,
+        hash: "2",
+        parent: "1",
+    );
+
+    let update_commitment_state = mount_update_commitment_state!(
+        test_conductor,
+        firm: (
+            number: 2,
+            hash: "2",
+            parent: "1",
+        ),
+        soft: (
+            number: 2,
+            hash: "2",
+            parent: "1",
+        ),
+        lowest_celestia_search_height: 1,
+    );
+
+    timeout(
+        Duration::from_millis(1000),
+        join(
+            execute_block.wait_until_satisfied(),
+            update_commitment_state.wait_until_satisfied(),
+        ),
+    )
+    .await
+    .expect(
+        "conductor should have executed the first rollup's firm block at sequencer height 4 \
+         within 1000ms",
+    );
+
+    mount_other_create_execution_session(&test_conductor, 3, 9, 1).await;
+
+    let other_execute_block_number_2 = mount_other_execute_block(
+        &test_conductor,
+        block_metadata!(number: 2, hash: "2", parent: "1"),
+    )
+    .await;
+    let other_update_commitment_state_number_2 = mount_other_update_commitment_state(
+        &test_conductor,
+        block_metadata!(number: 2, hash: "2", parent: "1"),
+        block_metadata!(number: 2, hash: "2", parent: "1"),
+    )
+    .await;
+    let other_execute_block_number_3 = mount_other_execute_block(
+        &test_conductor,
+        block_metadata!(number: 3, hash: "3", parent: "2"),
+    )
+    .await;
+    let other_update_commitment_state_number_3 = mount_other_update_commitment_state(
+        &test_conductor,
+        block_metadata!(number: 3, hash: "3", parent: "2"),
+        block_metadata!(number: 3, hash: "3", parent: "2"),
+    )
+    .await;
+
+    timeout(
+        Duration::from_millis(3000),
+        join4(
+            other_execute_block_number_2.wait_until_satisfied(),
+            other_update_commitment_state_number_2.wait_until_satisfied(),
+            other_execute_block_number_3.wait_until_satisfied(),
+            other_update_commitment_state_number_3.wait_until_satisfied(),
+        ),
+    )
+    .await
+    .expect(
+        "conductor should have executed the other rollup's firm blocks at sequencer heights 3 and \
+         4 within 3000ms",
+    );
+}
+
+/// The first rollup reaches its stop height at Sequencer height 3 and does not receive a new
+/// execution session until the second rollup executed the blocks at heights 4 and 5.
+///
+/// Asserts that the restarting rollup does not stall the other one, and that it catches up once
+/// it receives its new execution session.
+#[expect(
+    clippy::too_many_lines,
+    reason = "All lines reasonably necessary for the thoroughness of this test"
+)]
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn rollup_restarting_at_stop_height_does_not_stall_other_rollup() {
+    let test_conductor = spawn_conductor_with_other_rollup(CommitLevel::SoftOnly).await;
+    let _foreign_transactions =
+        mount_execute_block_with_foreign_transactions(&test_conductor).await;
+
+    mount_create_execution_session!(
+        test_conductor,
+        execution_session_parameters: (
+            rollup_start_block_number: 2,
+            rollup_end_block_number: 2,
+            sequencer_start_block_height: 3,
+            celestia_max_look_ahead: 10,
+        ),
+        commitment_state: (
+            firm: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            soft: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            lowest_celestia_search_height: 1,
+        ),
+        up_to_n_times: 1, // We need a new execution session after restart
+    );
+    mount_other_create_execution_session(&test_conductor, 3, 9, 1).await;
+
+    mount_sequencer_genesis!(test_conductor);
+
+    mount_abci_info!(
+        test_conductor,
+        latest_sequencer_height: 5,
+    );
+
+    mount_get_filtered_sequencer_block_with_other_rollup(&test_conductor, 3).await;
+    mount_get_filtered_sequencer_block_with_other_rollup(&test_conductor, 4).await;
+    mount_get_filtered_sequencer_block_with_other_rollup(&test_conductor, 5).await;
+
+    let execute_block_number_2 = mount_execute_block!(
+        test_conductor,
+        mock_name: "execute_block_number_2",
+        number: 2,
+        hash: "2",
+        parent: "1",
+        expected_calls: 1,
+    );
+
+    let update_commitment_state_number_2 = mount_update_commitment_state!(
+        test_conductor,
+        mock_name: "update_commitment_state_number_2",
+        firm: (
+            number: 1,
+            hash: "1",
+            parent: "0",
+        ),
+        soft: (
+            number: 2,
+            hash: "2",
+            parent: "1",
+        ),
+        lowest_celestia_search_height: 1,
+        expected_calls: 1,
+    );
+
+    let mut other_execute_blocks = vec![];
+    let mut other_update_commitment_states = vec![];
+    for number in 2..=4 {
+        let hash = number.to_string();
+        let parent = (number - 1).to_string();
+        other_execute_blocks.push(
+            mount_other_execute_block(
+                &test_conductor,
+                block_metadata!(number: number, hash: hash, parent: parent),
+            )
+            .await,
+        );
+        other_update_commitment_states.push(
+            mount_other_update_commitment_state(
+                &test_conductor,
+                block_metadata!(number: 1, hash: "1", parent: "0"),
+                block_metadata!(number: number, hash: hash, parent: parent),
+            )
+            .await,
+        );
+    }
+
+    timeout(
+        Duration::from_millis(2000),
+        join4(
+            execute_block_number_2.wait_until_satisfied(),
+            update_commitment_state_number_2.wait_until_satisfied(),
+            join_all(
+                other_execute_blocks
+                    .iter()
+                    .map(MockGuard::wait_until_satisfied),
+            ),
+            join_all(
+                other_update_commitment_states
+                    .iter()
+                    .map(MockGuard::wait_until_satisfied),
+            ),
+        ),
+    )
+    .await
+    .expect(
+        "conductor should have executed the other rollup's soft blocks at sequencer heights 3 to \
+         5 while the first rollup was waiting for a new execution session after reaching its stop \
+         height",
+    );
+
+    mount_create_execution_session!(
+        test_conductor,
+        execution_session_parameters: (
+            rollup_start_block_number: 3,
+            rollup_end_block_number: 9,
+            sequencer_start_block_height: 4,
+            celestia_max_look_ahead: 10,
+        ),
+        commitment_state: (
+            firm: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            soft: (
+                number: 2,
+                hash: "2",
+                parent: "1",
+            ),
+            lowest_celestia_search_height: 1,
+        ),
+    );
+
+    let execute_block_number_3 = mount_execute_block!(
+        test_conductor,
+        mock_name: "execute_block_number_3",
+        number: 3,
+        hash: "3",
+        parent: "2",
+        expected_calls: 1,
+    );
+
+    let update_commitment_state_number_3 = mount_update_commitment_state!(
+        test_conductor,
+        mock_name: "update_commitment_state_number_3",
+        firm: (
+            number: 1,
+            hash: "1",
+            parent: "0",
+        ),
+        soft: (
+            number: 3,
+            hash: "3",
+            parent: "2",
+        ),
+        lowest_celestia_search_height: 1,
+        expected_calls: 1,
+    );
+
+    let execute_block_number_4 = mount_execute_block!(
+        test_conductor,
+        mock_name: "execute_block_number_4",
+        number: 4,
+        hash: "4",
+        parent: "3",
+        expected_calls: 1,
+    );
+
+    let update_commitment_state_number_4 = mount_update_commitment_state!(
+        test_conductor,
+        mock_name: "update_commitment_state_number_4",
+        firm: (
+            number: 1,
+            hash: "1",
+            parent: "0",
+        ),
+        soft: (
+            number: 4,
+            hash: "4",
+            parent: "3",
+        ),
+        lowest_celestia_search_height: 1,
+        expected_calls: 1,
+    );
+
+    // The restarted executor retries creating its execution session with exponential backoff,
+    // hence the longer timeout.
+    timeout(
+        Duration::from_millis(5000),
+        join4(
+            execute_block_number_3.wait_until_satisfied(),
+            update_commitment_state_number_3.wait_until_satisfied(),
+            execute_block_number_4.wait_until_satisfied(),
+            update_commitment_state_number_4.wait_until_satisfied(),
+        ),
+    )
+    .await
+    .expect(
+        "conductor should have executed the first rollup's soft blocks at sequencer heights 4 and \
+         5 after restarting within 5000ms",
+    );
+}
+
+fn other_rollup(test_conductor: &TestConductor) -> &MockServer {
+    &test_conductor
+        .other_rollup
+        .as_ref()
+        .expect("the conductor must drive the other rollup")
+        .mock_server
+}
+
+/// Mounts the block at `height` containing the transactions of both rollups, independent of the
+/// rollup IDs it is requested for.
+async fn mount_get_filtered_sequencer_block_with_other_rollup(
+    test_conductor: &TestConductor,
+    height: u32,
+) {
+    test_conductor
+        .mount_get_filtered_sequencer_block(
+            GetFilteredSequencerBlockRequest {
+                height: height.into(),
+                rollup_ids: vec![],
+            },
+            make_filtered_sequencer_block_with_other_rollup(height),
+            Duration::from_secs(0),
+        )
+        .await;
+}
+
+/// Mounts a mock on the execution APIs of both rollups that matches blocks containing more than
+/// the price feed data and the rollup's own sequenced data, and which must thus never be called.
+async fn mount_execute_block_with_foreign_transactions(
+    test_conductor: &TestConductor,
+) -> [MockGuard; 2] {
+    [
+        execute_block_with_foreign_transactions()
+            .mount_as_scoped(&test_conductor.mock_grpc.mock_server)
+            .await,
+        execute_block_with_foreign_transactions()
+            .mount_as_scoped(other_rollup(test_conductor))
+            .await,
+    ]
+}
+
+fn execute_block_with_foreign_transactions() -> Mock {
+    Mock::for_rpc_given(
+        "execute_block",
+        message_partial_pbjson(&json!({
+            "transactions": [{}, {}, {}],
+        })),
+    )
+    .respond_with(error_response(tonic::Code::InvalidArgument))
+    .with_name("execute_block_with_foreign_transactions")
+    .expect(0)
+}
+
+/// Mounts the execution session of the other rollup, with its firm and soft commitments at
+/// `commitment_number`.
+async fn mount_other_create_execution_session(
+    test_conductor: &TestConductor,
+    sequencer_start_block_height: u64,
+    rollup_end_block_number: u64,
+    commitment_number: u64,
+) {
+    let commitment_hash = commitment_number.to_string();
+    let commitment_parent = commitment_number.saturating_sub(1).to_string();
+    let mut execution_session = execution_session!(
+        execution_session_parameters: (
+            rollup_start_block_number: commitment_number + 1,
+            rollup_end_block_number: rollup_end_block_number,
+            sequencer_start_block_height: sequencer_start_block_height,
+            celestia_max_look_ahead: 10,
+        ),
+        commitment_state: (
+            firm: ( number: commitment_number, hash: commitment_hash, parent: commitment_parent ),
+            soft: ( number: commitment_number, hash: commitment_hash, parent: commitment_parent ),
+            lowest_celestia_search_height: 1,
+        ),
+    );
+    execution_session
+        .execution_session_parameters
+        .as_mut()
+        .unwrap()
+        .rollup_id = Some(OTHER_ROLLUP_ID.to_raw());
+    Mock::for_rpc_given(
+        "create_execution_session",
+        message_type::<CreateExecutionSessionRequest>(),
+    )
+    .respond_with(constant_response(execution_session))
+    .up_to_n_times(1)
+    .expect(1)
+    .mount(other_rollup(test_conductor))
+    .await;
+}
+
+/// Mounts the execution of the other rollup's block, expecting it to contain only the other
+/// rollup's transactions.
+async fn mount_other_execute_block(
+    test_conductor: &TestConductor,
+    block_metadata: ExecutedBlockMetadata,
+) -> MockGuard {
+    Mock::for_rpc_given(
+        "execute_block",
+        message_partial_pbjson(&json!({
+            "sessionId": EXECUTION_SESSION_ID,
+            "parentHash": block_metadata.parent_hash,
+            "transactions": [
+                {"priceFeedData": {}},
+                {"sequencedData": BASE64_STANDARD.encode(other_data())}
+            ],
+        })),
+    )
+    .respond_with(constant_response(ExecuteBlockResponse {
+        executed_block_metadata: Some(block_metadata.clone()),
+    }))
+    .with_name(format!(
+        "other_execute_block_number_{}",
+        block_metadata.number
+    ))
+    .expect(1)
+    .mount_as_scoped(other_rollup(test_conductor))
+    .await
+}
+
+async fn mount_other_update_commitment_state(
+    test_conductor: &TestConductor,
+    firm: ExecutedBlockMetadata,
+    soft: ExecutedBlockMetadata,
+) -> MockGuard {
+    let commitment_state = CommitmentState {
+        firm_executed_block_metadata: Some(firm),
+        soft_executed_block_metadata: Some(soft),
+        lowest_celestia_search_height: 1,
+    };
+    Mock::for_rpc_given(
+        "update_commitment_state",
+        message_partial_pbjson(&UpdateCommitmentStateRequest {
+            session_id: EXECUTION_SESSION_ID.to_string(),
+            commitment_state: Some(commitment_state.clone()),
+        }),
+    )
+    .respond_with(constant_response(commitment_state))
+    .expect(1)
+    .mount_as_scoped(other_rollup(test_conductor))
+    .await
+}
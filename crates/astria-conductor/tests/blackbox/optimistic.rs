@@ -0,0 +1,306 @@
+//! Tests of the optimistic execution of blocks proposed to Sequencer.
+//!
+//! The proposed blocks and their commitments are sent over the streams of the mocked optimistic
+//! block service, [`crate::helpers::MockGrpc::optimistic_streams`].
+
+use std::time::Duration;
+
+use astria_conductor::config::CommitLevel;
+use astria_core::{
+    generated::astria::sequencerblock::v1::{
+        FilteredSequencerBlock,
+        GetFilteredSequencerBlockRequest,
+    },
+    protocol::test_utils::ConfigureSequencerBlock,
+    sequencerblock::{
+        optimistic::v1alpha1::SequencerBlockCommit,
+        v1::block,
+    },
+    Protobuf as _,
+};
+use base64::prelude::*;
+use futures::future::join;
+use serde_json::json;
+use tokio::time::timeout;
+
+use crate::{
+    block_metadata,
+    helpers::{
+        make_sequencer_block,
+        spawn_conductor_with_optimistic_blocks,
+        EXECUTION_SESSION_ID,
+    },
+    mount_abci_info,
+    mount_create_execution_session,
+    mount_execute_block,
+    mount_sequencer_genesis,
+    mount_update_commitment_state,
+    ROLLUP_ID,
+    SEQUENCER_CHAIN_ID,
+};
+
+/// Tests that a proposed block is executed ahead of its commitment and promoted to soft once its
+/// commitment is received.
+///
+/// The latest Sequencer height is below the height of the proposed block so that the soft block
+/// at its height is never fetched: the soft commitment can only be updated because of the
+/// received commitment.
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn proposed_block_is_promoted_to_soft_on_matching_commitment() {
+    let test_conductor = spawn_conductor_with_optimistic_blocks(CommitLevel::SoftOnly).await;
+
+    mount_create_execution_session!(
+        test_conductor,
+        execution_session_parameters: (
+            rollup_start_block_number: 2,
+            rollup_end_block_number: 9,
+            sequencer_start_block_height: 3,
+            celestia_max_look_ahead: 10,
+        ),
+        commitment_state: (
+            firm: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            soft: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            lowest_celestia_search_height: 1,
+        )
+    );
+
+    mount_sequencer_genesis!(test_conductor);
+
+    mount_abci_info!(
+        test_conductor,
+        latest_sequencer_height: 2,
+    );
+
+    let execute_block = mount_execute_block!(
+        test_conductor,
+        number: 2,
+        hash: "2",
+        parent: "1",
+    );
+
+    let update_commitment_state = mount_update_commitment_state!(
+        test_conductor,
+        firm: (
+            number: 1,
+            hash: "1",
+            parent: "0",
+        ),
+        soft: (
+            number: 2,
+            hash: "2",
+            parent: "1",
+        ),
+        lowest_celestia_search_height: 1,
+    );
+
+    let optimistic_streams = &test_conductor.mock_grpc.optimistic_streams;
+    timeout(
+        Duration::from_millis(1000),
+        join(
+            optimistic_streams.send_proposed_block(committed_block(3)),
+            execute_block.wait_until_satisfied(),
+        ),
+    )
+    .await
+    .expect("conductor should have executed the proposed block within 1000ms");
+
+    timeout(
+        Duration::from_millis(1000),
+        join(
+            optimistic_streams.send_block_commitment(commitment(3)),
+            update_commitment_state.wait_until_satisfied(),
+        ),
+    )
+    .await
+    .expect(
+        "conductor should have promoted the proposed block to soft after receiving its commitment \
+         within 1000ms",
+    );
+}
+
+/// Tests that a proposed block is reverted once a commitment for a different block at its height
+/// is received, and that the committed block is executed in its place.
+///
+/// The committed block is only served by Sequencer after the commitment was sent, so that it is
+/// fetched (and executed) as a soft block.
+#[expect(
+    clippy::too_many_lines,
+    reason = "All lines reasonably necessary for the thoroughness of this test"
+)]
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn committed_block_is_executed_on_mismatching_commitment() {
+    let test_conductor = spawn_conductor_with_optimistic_blocks(CommitLevel::SoftOnly).await;
+
+    mount_create_execution_session!(
+        test_conductor,
+        execution_session_parameters: (
+            rollup_start_block_number: 2,
+            rollup_end_block_number: 9,
+            sequencer_start_block_height: 3,
+            celestia_max_look_ahead: 10,
+        ),
+        commitment_state: (
+            firm: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            soft: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            lowest_celestia_search_height: 1,
+        )
+    );
+
+    mount_sequencer_genesis!(test_conductor);
+
+    mount_abci_info!(
+        test_conductor,
+        latest_sequencer_height: 3,
+    );
+
+    let execute_uncommitted_block = test_conductor
+        .mount_execute_block(
+            Some("execute_uncommitted_block"),
+            json!({
+                "sessionId": EXECUTION_SESSION_ID,
+                "parentHash": "1",
+                "transactions": [
+                    {"priceFeedData": {}},
+                    {"sequencedData": BASE64_STANDARD.encode(UNCOMMITTED_DATA)}
+                ],
+            }),
+            block_metadata!(number: 2, hash: "uncommitted", parent: "1"),
+            1,
+        )
+        .await;
+
+    let update_commitment_state_uncommitted = mount_update_commitment_state!(
+        test_conductor,
+        mock_name: "update_commitment_state_uncommitted",
+        firm: (
+            number: 1,
+            hash: "1",
+            parent: "0",
+        ),
+        soft: (
+            number: 2,
+            hash: "uncommitted",
+            parent: "1",
+        ),
+        lowest_celestia_search_height: 1,
+        expected_calls: 0,
+    );
+
+    let optimistic_streams = &test_conductor.mock_grpc.optimistic_streams;
+    timeout(
+        Duration::from_millis(1000),
+        join(
+            optimistic_streams.send_proposed_block(uncommitted_block(3)),
+            execute_uncommitted_block.wait_until_satisfied(),
+        ),
+    )
+    .await
+    .expect("conductor should have executed the proposed block within 1000ms");
+
+    timeout(
+        Duration::from_millis(1000),
+        optimistic_streams.send_block_commitment(commitment(3)),
+    )
+    .await
+    .expect("conductor should have opened the block commitment stream within 1000ms");
+
+    let execute_committed_block = mount_execute_block!(
+        test_conductor,
+        mock_name: "execute_committed_block",
+        number: 2,
+        hash: "2",
+        parent: "1",
+        expected_calls: 1,
+    );
+
+    let update_commitment_state_committed = mount_update_commitment_state!(
+        test_conductor,
+        mock_name: "update_commitment_state_committed",
+        firm: (
+            number: 1,
+            hash: "1",
+            parent: "0",
+        ),
+        soft: (
+            number: 2,
+            hash: "2",
+            parent: "1",
+        ),
+        lowest_celestia_search_height: 1,
+        expected_calls: 1,
+    );
+
+    test_conductor
+        .mount_get_filtered_sequencer_block(
+            GetFilteredSequencerBlockRequest {
+                height: 3,
+                rollup_ids: vec![ROLLUP_ID.to_raw()],
+            },
+            committed_block(3),
+            Duration::from_secs(0),
+        )
+        .await;
+
+    // The Sequencer reader retries fetching the committed block with exponential backoff, hence
+    // the longer timeout.
+    timeout(
+        Duration::from_millis(3000),
+        join(
+            execute_committed_block.wait_until_satisfied(),
+            update_commitment_state_committed.wait_until_satisfied(),
+        ),
+    )
+    .await
+    .expect(
+        "conductor should have executed the committed block in place of the proposed block within \
+         3000ms",
+    );
+
+    drop(update_commitment_state_uncommitted);
+}
+
+const UNCOMMITTED_DATA: &[u8] = b"uncommitted";
+
+/// The block at `height` that is committed by [`commitment`].
+fn committed_block(height: u32) -> FilteredSequencerBlock {
+    make_sequencer_block(height)
+        .into_filtered_block([ROLLUP_ID])
+        .into_raw()
+}
+
+/// A block proposed at `height` that is not committed: both its hash and its transactions differ
+/// from [`committed_block`].
+fn uncommitted_block(height: u32) -> FilteredSequencerBlock {
+    ConfigureSequencerBlock {
+        block_hash: Some(block::Hash::new([0xff; 32])),
+        chain_id: Some(SEQUENCER_CHAIN_ID.to_string()),
+        height,
+        sequence_data: vec![(ROLLUP_ID, UNCOMMITTED_DATA.to_vec())],
+        ..Default::default()
+    }
+    .make()
+    .into_filtered_block([ROLLUP_ID])
+    .into_raw()
+}
+
+fn commitment(
+    height: u32,
+) -> astria_core::generated::astria::sequencerblock::optimistic::v1alpha1::SequencerBlockCommit {
+    SequencerBlockCommit::new(height.into(), *make_sequencer_block(height).block_hash()).to_raw()
+}
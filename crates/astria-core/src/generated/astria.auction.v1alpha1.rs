@@ -18,6 +18,10 @@ pub struct Allocation {
     /// google.protobuf.Any to avoid decoding and re-encoding after receiving an Allocation
     /// over the wire and checking if signature and public key match the signed bid.
     /// Implementors are expected to read and write an encoded Bid into this field.
+    ///
+    /// The fee of the allocated bid is the price charged to the winner as determined by the
+    /// Auctioneer's allocation rule, which can be lower than the fee of the bid as submitted
+    /// (for example, the fee of the runner-up in a second-price auction).
     #[prost(message, optional, tag = "3")]
     pub bid: ::core::option::Option<::pbjson_types::Any>,
 }
@@ -65,6 +69,10 @@ pub struct Bid {
     /// The list of serialized rollup transactions from the bundle.
     #[prost(bytes = "bytes", repeated, tag = "4")]
     pub transactions: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
+    /// The address of the searcher on the rollup that submitted the bundle. Set by the rollup node
+    /// and opaque to the auctioneer, which uses it to attribute bids in its auction history.
+    #[prost(bytes = "bytes", tag = "5")]
+    pub searcher_address: ::prost::bytes::Bytes,
 }
 impl ::prost::Name for Bid {
     const NAME: &'static str = "Bid";
@@ -76,10 +84,75 @@ impl ::prost::Name for Bid {
         "/astria.auction.v1alpha1.Bid".into()
     }
 }
+/// A sealed bid commits to a bid without revealing it. It is sent to the auctioneer
+/// while the auction is accepting commitments, and must be followed by a `BidReveal` once the
+/// auction stops accepting commitments for the bid to participate.
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetBidStreamResponse {
+pub struct SealedBid {
+    /// The hash of previous rollup block, on top of which the bundle will be executed as ToB.
+    #[prost(bytes = "bytes", tag = "1")]
+    pub rollup_parent_block_hash: ::prost::bytes::Bytes,
+    /// The hash of the previous sequencer block, identifying the auction for which the bid is intended.
+    #[prost(bytes = "bytes", tag = "2")]
+    pub sequencer_parent_block_hash: ::prost::bytes::Bytes,
+    /// The commitment to the bid: the 32 byte SHA256 hash of the protobuf encoded `Bid` followed by
+    /// the salt that is later revealed.
+    #[prost(bytes = "bytes", tag = "3")]
+    pub commitment: ::prost::bytes::Bytes,
+    /// The address of the searcher on the rollup that submitted the sealed bid.
+    #[prost(bytes = "bytes", tag = "4")]
+    pub searcher_address: ::prost::bytes::Bytes,
+}
+impl ::prost::Name for SealedBid {
+    const NAME: &'static str = "SealedBid";
+    const PACKAGE: &'static str = "astria.auction.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.auction.v1alpha1.SealedBid".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.auction.v1alpha1.SealedBid".into()
+    }
+}
+/// Reveals a bid that was previously committed to by a `SealedBid`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BidReveal {
+    /// The revealed bid. Its encoding must match the commitment of the sealed bid, and its parent
+    /// block hashes and searcher address must match those of the sealed bid.
     #[prost(message, optional, tag = "1")]
     pub bid: ::core::option::Option<Bid>,
+    /// The salt used to construct the commitment of the sealed bid.
+    #[prost(bytes = "bytes", tag = "2")]
+    pub salt: ::prost::bytes::Bytes,
+}
+impl ::prost::Name for BidReveal {
+    const NAME: &'static str = "BidReveal";
+    const PACKAGE: &'static str = "astria.auction.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.auction.v1alpha1.BidReveal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.auction.v1alpha1.BidReveal".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBidStreamResponse {
+    #[prost(oneof = "get_bid_stream_response::Value", tags = "1, 2, 3")]
+    pub value: ::core::option::Option<get_bid_stream_response::Value>,
+}
+/// Nested message and enum types in `GetBidStreamResponse`.
+pub mod get_bid_stream_response {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Value {
+        /// A bid that is open to the auctioneer upon submission.
+        #[prost(message, tag = "1")]
+        Bid(super::Bid),
+        /// A commitment to a bid in a sealed-bid auction.
+        #[prost(message, tag = "2")]
+        SealedBid(super::SealedBid),
+        /// The reveal of a bid previously committed to in a sealed-bid auction.
+        #[prost(message, tag = "3")]
+        BidReveal(super::BidReveal),
+    }
 }
 impl ::prost::Name for GetBidStreamResponse {
     const NAME: &'static str = "GetBidStreamResponse";
@@ -152,6 +152,9 @@ impl serde::Serialize for Bid {
         if !self.transactions.is_empty() {
             len += 1;
         }
+        if !self.searcher_address.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("astria.auction.v1alpha1.Bid", len)?;
         if !self.rollup_parent_block_hash.is_empty() {
             #[allow(clippy::needless_borrow)]
@@ -171,6 +174,11 @@ impl serde::Serialize for Bid {
         if !self.transactions.is_empty() {
             struct_ser.serialize_field("transactions", &self.transactions.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
         }
+        if !self.searcher_address.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("searcherAddress", pbjson::private::base64::encode(&self.searcher_address).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -187,6 +195,8 @@ impl<'de> serde::Deserialize<'de> for Bid {
             "sequencerParentBlockHash",
             "fee",
             "transactions",
+            "searcher_address",
+            "searcherAddress",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -195,6 +205,7 @@ impl<'de> serde::Deserialize<'de> for Bid {
             SequencerParentBlockHash,
             Fee,
             Transactions,
+            SearcherAddress,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -220,6 +231,7 @@ impl<'de> serde::Deserialize<'de> for Bid {
                             "sequencerParentBlockHash" | "sequencer_parent_block_hash" => Ok(GeneratedField::SequencerParentBlockHash),
                             "fee" => Ok(GeneratedField::Fee),
                             "transactions" => Ok(GeneratedField::Transactions),
+                            "searcherAddress" | "searcher_address" => Ok(GeneratedField::SearcherAddress),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -243,6 +255,7 @@ impl<'de> serde::Deserialize<'de> for Bid {
                 let mut sequencer_parent_block_hash__ = None;
                 let mut fee__ = None;
                 let mut transactions__ = None;
+                let mut searcher_address__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::RollupParentBlockHash => {
@@ -278,6 +291,14 @@ impl<'de> serde::Deserialize<'de> for Bid {
                                     .into_iter().map(|x| x.0).collect())
                             ;
                         }
+                        GeneratedField::SearcherAddress => {
+                            if searcher_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("searcherAddress"));
+                            }
+                            searcher_address__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(Bid {
@@ -285,12 +306,125 @@ impl<'de> serde::Deserialize<'de> for Bid {
                     sequencer_parent_block_hash: sequencer_parent_block_hash__.unwrap_or_default(),
                     fee: fee__.unwrap_or_default(),
                     transactions: transactions__.unwrap_or_default(),
+                    searcher_address: searcher_address__.unwrap_or_default(),
                 })
             }
         }
         deserializer.deserialize_struct("astria.auction.v1alpha1.Bid", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for BidReveal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.bid.is_some() {
+            len += 1;
+        }
+        if !self.salt.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.auction.v1alpha1.BidReveal", len)?;
+        if let Some(v) = self.bid.as_ref() {
+            struct_ser.serialize_field("bid", v)?;
+        }
+        if !self.salt.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("salt", pbjson::private::base64::encode(&self.salt).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BidReveal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "bid",
+            "salt",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Bid,
+            Salt,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "bid" => Ok(GeneratedField::Bid),
+                            "salt" => Ok(GeneratedField::Salt),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BidReveal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.auction.v1alpha1.BidReveal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BidReveal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut bid__ = None;
+                let mut salt__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Bid => {
+                            if bid__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bid"));
+                            }
+                            bid__ = map_.next_value()?;
+                        }
+                        GeneratedField::Salt => {
+                            if salt__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("salt"));
+                            }
+                            salt__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(BidReveal {
+                    bid: bid__,
+                    salt: salt__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.auction.v1alpha1.BidReveal", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for GetBidStreamRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -370,12 +504,22 @@ impl serde::Serialize for GetBidStreamResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.bid.is_some() {
+        if self.value.is_some() {
             len += 1;
         }
         let mut struct_ser = serializer.serialize_struct("astria.auction.v1alpha1.GetBidStreamResponse", len)?;
-        if let Some(v) = self.bid.as_ref() {
-            struct_ser.serialize_field("bid", v)?;
+        if let Some(v) = self.value.as_ref() {
+            match v {
+                get_bid_stream_response::Value::Bid(v) => {
+                    struct_ser.serialize_field("bid", v)?;
+                }
+                get_bid_stream_response::Value::SealedBid(v) => {
+                    struct_ser.serialize_field("sealedBid", v)?;
+                }
+                get_bid_stream_response::Value::BidReveal(v) => {
+                    struct_ser.serialize_field("bidReveal", v)?;
+                }
+            }
         }
         struct_ser.end()
     }
@@ -388,11 +532,17 @@ impl<'de> serde::Deserialize<'de> for GetBidStreamResponse {
     {
         const FIELDS: &[&str] = &[
             "bid",
+            "sealed_bid",
+            "sealedBid",
+            "bid_reveal",
+            "bidReveal",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Bid,
+            SealedBid,
+            BidReveal,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -415,6 +565,8 @@ impl<'de> serde::Deserialize<'de> for GetBidStreamResponse {
                     {
                         match value {
                             "bid" => Ok(GeneratedField::Bid),
+                            "sealedBid" | "sealed_bid" => Ok(GeneratedField::SealedBid),
+                            "bidReveal" | "bid_reveal" => Ok(GeneratedField::BidReveal),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -434,22 +586,198 @@ impl<'de> serde::Deserialize<'de> for GetBidStreamResponse {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut bid__ = None;
+                let mut value__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Bid => {
-                            if bid__.is_some() {
+                            if value__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("bid"));
                             }
-                            bid__ = map_.next_value()?;
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(get_bid_stream_response::Value::Bid)
+;
+                        }
+                        GeneratedField::SealedBid => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sealedBid"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(get_bid_stream_response::Value::SealedBid)
+;
+                        }
+                        GeneratedField::BidReveal => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bidReveal"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(get_bid_stream_response::Value::BidReveal)
+;
                         }
                     }
                 }
                 Ok(GetBidStreamResponse {
-                    bid: bid__,
+                    value: value__,
                 })
             }
         }
         deserializer.deserialize_struct("astria.auction.v1alpha1.GetBidStreamResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for SealedBid {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.rollup_parent_block_hash.is_empty() {
+            len += 1;
+        }
+        if !self.sequencer_parent_block_hash.is_empty() {
+            len += 1;
+        }
+        if !self.commitment.is_empty() {
+            len += 1;
+        }
+        if !self.searcher_address.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.auction.v1alpha1.SealedBid", len)?;
+        if !self.rollup_parent_block_hash.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("rollupParentBlockHash", pbjson::private::base64::encode(&self.rollup_parent_block_hash).as_str())?;
+        }
+        if !self.sequencer_parent_block_hash.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("sequencerParentBlockHash", pbjson::private::base64::encode(&self.sequencer_parent_block_hash).as_str())?;
+        }
+        if !self.commitment.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("commitment", pbjson::private::base64::encode(&self.commitment).as_str())?;
+        }
+        if !self.searcher_address.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("searcherAddress", pbjson::private::base64::encode(&self.searcher_address).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SealedBid {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rollup_parent_block_hash",
+            "rollupParentBlockHash",
+            "sequencer_parent_block_hash",
+            "sequencerParentBlockHash",
+            "commitment",
+            "searcher_address",
+            "searcherAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RollupParentBlockHash,
+            SequencerParentBlockHash,
+            Commitment,
+            SearcherAddress,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rollupParentBlockHash" | "rollup_parent_block_hash" => Ok(GeneratedField::RollupParentBlockHash),
+                            "sequencerParentBlockHash" | "sequencer_parent_block_hash" => Ok(GeneratedField::SequencerParentBlockHash),
+                            "commitment" => Ok(GeneratedField::Commitment),
+                            "searcherAddress" | "searcher_address" => Ok(GeneratedField::SearcherAddress),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SealedBid;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.auction.v1alpha1.SealedBid")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SealedBid, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rollup_parent_block_hash__ = None;
+                let mut sequencer_parent_block_hash__ = None;
+                let mut commitment__ = None;
+                let mut searcher_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RollupParentBlockHash => {
+                            if rollup_parent_block_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rollupParentBlockHash"));
+                            }
+                            rollup_parent_block_hash__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SequencerParentBlockHash => {
+                            if sequencer_parent_block_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequencerParentBlockHash"));
+                            }
+                            sequencer_parent_block_hash__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Commitment => {
+                            if commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commitment"));
+                            }
+                            commitment__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SearcherAddress => {
+                            if searcher_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("searcherAddress"));
+                            }
+                            searcher_address__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(SealedBid {
+                    rollup_parent_block_hash: rollup_parent_block_hash__.unwrap_or_default(),
+                    sequencer_parent_block_hash: sequencer_parent_block_hash__.unwrap_or_default(),
+                    commitment: commitment__.unwrap_or_default(),
+                    searcher_address: searcher_address__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.auction.v1alpha1.SealedBid", FIELDS, GeneratedVisitor)
+    }
+}
@@ -1,4 +1,127 @@
 // This file is @generated by prost-build.
+/// A request to estimate the fee rate a transaction must pay to be included in one of the
+/// next blocks of the Astria Sequencer.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EstimateFeeRequest {
+    /// The number of blocks within which the transaction should be included. Treated as 1
+    /// if zero.
+    #[prost(uint32, tag = "1")]
+    pub target_blocks: u32,
+}
+impl ::prost::Name for EstimateFeeRequest {
+    const NAME: &'static str = "EstimateFeeRequest";
+    const PACKAGE: &'static str = "astria.mempool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.mempool.v1.EstimateFeeRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.mempool.v1.EstimateFeeRequest".into()
+    }
+}
+/// A response to a request to estimate fees, describing recent block fullness, the
+/// pending transactions in the mempool and the fee rate recommended to land within the
+/// requested number of blocks.
+///
+/// Fee rates are the fees paid by a transaction in the Sequencer's native asset per 1000
+/// bytes of the encoded transaction, rounded down. Fees paid in other assets are not
+/// counted, as amounts of different assets are not comparable.
+///
+/// There is no tip mechanism: fees are fixed by the fee components of a transaction's
+/// actions, so its fee rate is determined by its contents. The recommended fee rate
+/// tells a client whether a transaction is expected to be included within the requested
+/// number of blocks, not how much to pay on top of the fees.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimateFeeResponse {
+    /// The fullness of the most recent blocks, ordered by ascending height.
+    #[prost(message, repeated, tag = "1")]
+    pub recent_blocks: ::prost::alloc::vec::Vec<estimate_fee_response::BlockFullness>,
+    /// The pending transactions in the mempool, grouped by fee rate and ordered by
+    /// descending fee rate.
+    #[prost(message, repeated, tag = "2")]
+    pub fee_levels: ::prost::alloc::vec::Vec<estimate_fee_response::FeeLevel>,
+    /// The fee rate a transaction must at least pay to be included within the requested
+    /// number of blocks. Zero if the transaction is expected to be included regardless of
+    /// its fee rate.
+    #[prost(message, optional, tag = "3")]
+    pub recommended_fee_per_kb: ::core::option::Option<
+        super::super::primitive::v1::Uint128,
+    >,
+}
+/// Nested message and enum types in `EstimateFeeResponse`.
+pub mod estimate_fee_response {
+    /// The size of a block's contents relative to the Sequencer's block size limit.
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct BlockFullness {
+        /// The height of the block.
+        #[prost(uint64, tag = "1")]
+        pub height: u64,
+        /// The number of user submitted transactions in the block.
+        #[prost(uint64, tag = "2")]
+        pub transaction_count: u64,
+        /// The total size of the encoded user submitted transactions in the block.
+        #[prost(uint64, tag = "3")]
+        pub transaction_bytes: u64,
+        /// The total size of the rollup data in the block.
+        #[prost(uint64, tag = "4")]
+        pub rollup_data_bytes: u64,
+        /// The maximum total size of the rollup data in a block.
+        #[prost(uint64, tag = "5")]
+        pub max_rollup_data_bytes: u64,
+        /// The maximum size of a block set by the consensus parameters, which bounds the total
+        /// size of the encoded transactions in the block. Zero if not known.
+        #[prost(uint64, tag = "6")]
+        pub max_block_bytes: u64,
+    }
+    impl ::prost::Name for BlockFullness {
+        const NAME: &'static str = "BlockFullness";
+        const PACKAGE: &'static str = "astria.mempool.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "astria.mempool.v1.EstimateFeeResponse.BlockFullness".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/astria.mempool.v1.EstimateFeeResponse.BlockFullness".into()
+        }
+    }
+    /// The pending transactions paying a fee rate of at least `min_fee_per_kb`, and less
+    /// than the `min_fee_per_kb` of the next higher level.
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct FeeLevel {
+        /// The lowest fee rate of the level.
+        #[prost(message, optional, tag = "1")]
+        pub min_fee_per_kb: ::core::option::Option<
+            super::super::super::primitive::v1::Uint128,
+        >,
+        /// The number of pending transactions in the level.
+        #[prost(uint64, tag = "2")]
+        pub transaction_count: u64,
+        /// The total size of the rollup data of the pending transactions in the level.
+        #[prost(uint64, tag = "3")]
+        pub rollup_data_bytes: u64,
+        /// The total size of the encoded pending transactions in the level.
+        #[prost(uint64, tag = "4")]
+        pub transaction_bytes: u64,
+    }
+    impl ::prost::Name for FeeLevel {
+        const NAME: &'static str = "FeeLevel";
+        const PACKAGE: &'static str = "astria.mempool.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "astria.mempool.v1.EstimateFeeResponse.FeeLevel".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/astria.mempool.v1.EstimateFeeResponse.FeeLevel".into()
+        }
+    }
+}
+impl ::prost::Name for EstimateFeeResponse {
+    const NAME: &'static str = "EstimateFeeResponse";
+    const PACKAGE: &'static str = "astria.mempool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.mempool.v1.EstimateFeeResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.mempool.v1.EstimateFeeResponse".into()
+    }
+}
 /// A request to get the status of a transaction in the Astria Sequencer's mempool.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetTransactionStatusRequest {
@@ -16,6 +139,163 @@ impl ::prost::Name for GetTransactionStatusRequest {
         "/astria.mempool.v1.GetTransactionStatusRequest".into()
     }
 }
+/// `MempoolEvent` is a resource which represents a change to the state of a transaction
+/// in the Astria Sequencer's mempool.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MempoolEvent {
+    /// The transaction hash.
+    #[prost(bytes = "bytes", tag = "1")]
+    pub transaction_hash: ::prost::bytes::Bytes,
+    /// The address of the transaction's signer.
+    #[prost(message, optional, tag = "2")]
+    pub signer: ::core::option::Option<super::super::primitive::v1::Address>,
+    /// The event. Can be one of:
+    /// - `Inserted`: The transaction was inserted into the mempool, either as pending or
+    ///       as parked.
+    /// - `Promoted`: The transaction was moved from parked to pending.
+    /// - `Demoted`: The transaction was moved from pending to parked.
+    /// - `Removed`: The transaction was removed from the mempool. Includes the reason for
+    ///       removal.
+    /// - `Included`: The transaction was included in a sequencer block and removed from
+    ///       the mempool. Specifies the block height in which the transaction was included.
+    #[prost(oneof = "mempool_event::Event", tags = "3, 4, 5, 6, 7")]
+    pub event: ::core::option::Option<mempool_event::Event>,
+}
+/// Nested message and enum types in `MempoolEvent`.
+pub mod mempool_event {
+    /// Event representing a transaction's insertion into the Astria Sequencer's mempool.
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Inserted {
+        /// Whether the transaction was inserted as parked rather than pending.
+        #[prost(bool, tag = "1")]
+        pub parked: bool,
+    }
+    impl ::prost::Name for Inserted {
+        const NAME: &'static str = "Inserted";
+        const PACKAGE: &'static str = "astria.mempool.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "astria.mempool.v1.MempoolEvent.Inserted".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/astria.mempool.v1.MempoolEvent.Inserted".into()
+        }
+    }
+    /// Event representing a transaction's promotion from parked to pending.
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Promoted {}
+    impl ::prost::Name for Promoted {
+        const NAME: &'static str = "Promoted";
+        const PACKAGE: &'static str = "astria.mempool.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "astria.mempool.v1.MempoolEvent.Promoted".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/astria.mempool.v1.MempoolEvent.Promoted".into()
+        }
+    }
+    /// Event representing a transaction's demotion from pending to parked.
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Demoted {}
+    impl ::prost::Name for Demoted {
+        const NAME: &'static str = "Demoted";
+        const PACKAGE: &'static str = "astria.mempool.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "astria.mempool.v1.MempoolEvent.Demoted".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/astria.mempool.v1.MempoolEvent.Demoted".into()
+        }
+    }
+    /// Event representing a transaction's removal from the Astria Sequencer's mempool.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Removed {
+        /// The reason for the transaction's removal from the mempool.
+        #[prost(string, tag = "1")]
+        pub reason: ::prost::alloc::string::String,
+    }
+    impl ::prost::Name for Removed {
+        const NAME: &'static str = "Removed";
+        const PACKAGE: &'static str = "astria.mempool.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "astria.mempool.v1.MempoolEvent.Removed".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/astria.mempool.v1.MempoolEvent.Removed".into()
+        }
+    }
+    /// Event representing a transaction's inclusion in a sequencer block.
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Included {
+        /// The height of the block in which the transaction was included.
+        #[prost(uint64, tag = "1")]
+        pub height: u64,
+    }
+    impl ::prost::Name for Included {
+        const NAME: &'static str = "Included";
+        const PACKAGE: &'static str = "astria.mempool.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "astria.mempool.v1.MempoolEvent.Included".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/astria.mempool.v1.MempoolEvent.Included".into()
+        }
+    }
+    /// The event. Can be one of:
+    /// - `Inserted`: The transaction was inserted into the mempool, either as pending or
+    ///       as parked.
+    /// - `Promoted`: The transaction was moved from parked to pending.
+    /// - `Demoted`: The transaction was moved from pending to parked.
+    /// - `Removed`: The transaction was removed from the mempool. Includes the reason for
+    ///       removal.
+    /// - `Included`: The transaction was included in a sequencer block and removed from
+    ///       the mempool. Specifies the block height in which the transaction was included.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag = "3")]
+        Inserted(Inserted),
+        #[prost(message, tag = "4")]
+        Promoted(Promoted),
+        #[prost(message, tag = "5")]
+        Demoted(Demoted),
+        #[prost(message, tag = "6")]
+        Removed(Removed),
+        #[prost(message, tag = "7")]
+        Included(Included),
+    }
+}
+impl ::prost::Name for MempoolEvent {
+    const NAME: &'static str = "MempoolEvent";
+    const PACKAGE: &'static str = "astria.mempool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.mempool.v1.MempoolEvent".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.mempool.v1.MempoolEvent".into()
+    }
+}
+/// A request to stream events from the Astria Sequencer's mempool.
+///
+/// If both filters are set, only events matching both are streamed. If neither is set,
+/// all events are streamed.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StreamMempoolEventsRequest {
+    /// If set, only stream events for transactions signed by this address.
+    #[prost(message, optional, tag = "1")]
+    pub signer: ::core::option::Option<super::super::primitive::v1::Address>,
+    /// If non-empty, only stream events for the transaction with this hash.
+    #[prost(bytes = "bytes", tag = "2")]
+    pub transaction_hash: ::prost::bytes::Bytes,
+}
+impl ::prost::Name for StreamMempoolEventsRequest {
+    const NAME: &'static str = "StreamMempoolEventsRequest";
+    const PACKAGE: &'static str = "astria.mempool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.mempool.v1.StreamMempoolEventsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.mempool.v1.StreamMempoolEventsRequest".into()
+    }
+}
 /// A request to submit a transaction directly to the Astria Sequencer's mempool.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SubmitTransactionRequest {
@@ -182,8 +462,8 @@ pub mod transaction_service_client {
     use tonic::codegen::*;
     use tonic::codegen::http::Uri;
     /// `TransactionService` is a gRPC service which provides access to the Astria Sequencer's
-    /// app-side mempool. It allows clients to query a transaction's status and submit
-    /// transactions directly to the mempool.
+    /// app-side mempool. It allows clients to query a transaction's status, submit
+    /// transactions directly to the mempool and estimate the fees needed for inclusion.
     ///
     /// Submission to the app-side mempool via `TransactionService` is notably different
     /// than submission directly to CometBFT, and users should consider the implications
@@ -334,6 +614,72 @@ pub mod transaction_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// `StreamMempoolEvents` streams changes to the state of transactions in the app-side
+        /// mempool as they happen, optionally filtered by signer or transaction hash.
+        ///
+        /// Events are not replayed: only events occurring after the stream was opened are sent.
+        /// The stream is terminated if the client falls too far behind in consuming events.
+        pub async fn stream_mempool_events(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StreamMempoolEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::MempoolEvent>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/astria.mempool.v1.TransactionService/StreamMempoolEvents",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "astria.mempool.v1.TransactionService",
+                        "StreamMempoolEvents",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// `EstimateFee` reports the fullness of recent blocks and the pending transactions in
+        /// the app-side mempool by fee rate, and recommends the fee rate a transaction should
+        /// pay to be included within a given number of blocks.
+        pub async fn estimate_fee(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EstimateFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimateFeeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/astria.mempool.v1.TransactionService/EstimateFee",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "astria.mempool.v1.TransactionService",
+                        "EstimateFee",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -368,10 +714,38 @@ pub mod transaction_service_server {
             tonic::Response<super::SubmitTransactionResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the StreamMempoolEvents method.
+        type StreamMempoolEventsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::MempoolEvent, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// `StreamMempoolEvents` streams changes to the state of transactions in the app-side
+        /// mempool as they happen, optionally filtered by signer or transaction hash.
+        ///
+        /// Events are not replayed: only events occurring after the stream was opened are sent.
+        /// The stream is terminated if the client falls too far behind in consuming events.
+        async fn stream_mempool_events(
+            self: std::sync::Arc<Self>,
+            request: tonic::Request<super::StreamMempoolEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::StreamMempoolEventsStream>,
+            tonic::Status,
+        >;
+        /// `EstimateFee` reports the fullness of recent blocks and the pending transactions in
+        /// the app-side mempool by fee rate, and recommends the fee rate a transaction should
+        /// pay to be included within a given number of blocks.
+        async fn estimate_fee(
+            self: std::sync::Arc<Self>,
+            request: tonic::Request<super::EstimateFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimateFeeResponse>,
+            tonic::Status,
+        >;
     }
     /// `TransactionService` is a gRPC service which provides access to the Astria Sequencer's
-    /// app-side mempool. It allows clients to query a transaction's status and submit
-    /// transactions directly to the mempool.
+    /// app-side mempool. It allows clients to query a transaction's status, submit
+    /// transactions directly to the mempool and estimate the fees needed for inclusion.
     ///
     /// Submission to the app-side mempool via `TransactionService` is notably different
     /// than submission directly to CometBFT, and users should consider the implications
@@ -554,6 +928,103 @@ pub mod transaction_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/astria.mempool.v1.TransactionService/StreamMempoolEvents" => {
+                    #[allow(non_camel_case_types)]
+                    struct StreamMempoolEventsSvc<T: TransactionService>(pub Arc<T>);
+                    impl<
+                        T: TransactionService,
+                    > tonic::server::ServerStreamingService<
+                        super::StreamMempoolEventsRequest,
+                    > for StreamMempoolEventsSvc<T> {
+                        type Response = super::MempoolEvent;
+                        type ResponseStream = T::StreamMempoolEventsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::StreamMempoolEventsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionService>::stream_mempool_events(
+                                        inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = StreamMempoolEventsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/astria.mempool.v1.TransactionService/EstimateFee" => {
+                    #[allow(non_camel_case_types)]
+                    struct EstimateFeeSvc<T: TransactionService>(pub Arc<T>);
+                    impl<
+                        T: TransactionService,
+                    > tonic::server::UnaryService<super::EstimateFeeRequest>
+                    for EstimateFeeSvc<T> {
+                        type Response = super::EstimateFeeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EstimateFeeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionService>::estimate_fee(inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EstimateFeeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(empty_body());
@@ -1,3 +1,588 @@
+impl serde::Serialize for EstimateFeeRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.target_blocks != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.mempool.v1.EstimateFeeRequest", len)?;
+        if self.target_blocks != 0 {
+            struct_ser.serialize_field("targetBlocks", &self.target_blocks)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EstimateFeeRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "target_blocks",
+            "targetBlocks",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TargetBlocks,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "targetBlocks" | "target_blocks" => Ok(GeneratedField::TargetBlocks),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EstimateFeeRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.mempool.v1.EstimateFeeRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EstimateFeeRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut target_blocks__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TargetBlocks => {
+                            if target_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("targetBlocks"));
+                            }
+                            target_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(EstimateFeeRequest {
+                    target_blocks: target_blocks__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.mempool.v1.EstimateFeeRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EstimateFeeResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.recent_blocks.is_empty() {
+            len += 1;
+        }
+        if !self.fee_levels.is_empty() {
+            len += 1;
+        }
+        if self.recommended_fee_per_kb.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.mempool.v1.EstimateFeeResponse", len)?;
+        if !self.recent_blocks.is_empty() {
+            struct_ser.serialize_field("recentBlocks", &self.recent_blocks)?;
+        }
+        if !self.fee_levels.is_empty() {
+            struct_ser.serialize_field("feeLevels", &self.fee_levels)?;
+        }
+        if let Some(v) = self.recommended_fee_per_kb.as_ref() {
+            struct_ser.serialize_field("recommendedFeePerKb", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EstimateFeeResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "recent_blocks",
+            "recentBlocks",
+            "fee_levels",
+            "feeLevels",
+            "recommended_fee_per_kb",
+            "recommendedFeePerKb",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RecentBlocks,
+            FeeLevels,
+            RecommendedFeePerKb,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "recentBlocks" | "recent_blocks" => Ok(GeneratedField::RecentBlocks),
+                            "feeLevels" | "fee_levels" => Ok(GeneratedField::FeeLevels),
+                            "recommendedFeePerKb" | "recommended_fee_per_kb" => Ok(GeneratedField::RecommendedFeePerKb),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EstimateFeeResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.mempool.v1.EstimateFeeResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EstimateFeeResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut recent_blocks__ = None;
+                let mut fee_levels__ = None;
+                let mut recommended_fee_per_kb__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RecentBlocks => {
+                            if recent_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recentBlocks"));
+                            }
+                            recent_blocks__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FeeLevels => {
+                            if fee_levels__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeLevels"));
+                            }
+                            fee_levels__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RecommendedFeePerKb => {
+                            if recommended_fee_per_kb__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recommendedFeePerKb"));
+                            }
+                            recommended_fee_per_kb__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(EstimateFeeResponse {
+                    recent_blocks: recent_blocks__.unwrap_or_default(),
+                    fee_levels: fee_levels__.unwrap_or_default(),
+                    recommended_fee_per_kb: recommended_fee_per_kb__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.mempool.v1.EstimateFeeResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for estimate_fee_response::BlockFullness {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.transaction_count != 0 {
+            len += 1;
+        }
+        if self.transaction_bytes != 0 {
+            len += 1;
+        }
+        if self.rollup_data_bytes != 0 {
+            len += 1;
+        }
+        if self.max_rollup_data_bytes != 0 {
+            len += 1;
+        }
+        if self.max_block_bytes != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.mempool.v1.EstimateFeeResponse.BlockFullness", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if self.transaction_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("transactionCount", ToString::to_string(&self.transaction_count).as_str())?;
+        }
+        if self.transaction_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("transactionBytes", ToString::to_string(&self.transaction_bytes).as_str())?;
+        }
+        if self.rollup_data_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("rollupDataBytes", ToString::to_string(&self.rollup_data_bytes).as_str())?;
+        }
+        if self.max_rollup_data_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("maxRollupDataBytes", ToString::to_string(&self.max_rollup_data_bytes).as_str())?;
+        }
+        if self.max_block_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("maxBlockBytes", ToString::to_string(&self.max_block_bytes).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for estimate_fee_response::BlockFullness {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "transaction_count",
+            "transactionCount",
+            "transaction_bytes",
+            "transactionBytes",
+            "rollup_data_bytes",
+            "rollupDataBytes",
+            "max_rollup_data_bytes",
+            "maxRollupDataBytes",
+            "max_block_bytes",
+            "maxBlockBytes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            TransactionCount,
+            TransactionBytes,
+            RollupDataBytes,
+            MaxRollupDataBytes,
+            MaxBlockBytes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "transactionCount" | "transaction_count" => Ok(GeneratedField::TransactionCount),
+                            "transactionBytes" | "transaction_bytes" => Ok(GeneratedField::TransactionBytes),
+                            "rollupDataBytes" | "rollup_data_bytes" => Ok(GeneratedField::RollupDataBytes),
+                            "maxRollupDataBytes" | "max_rollup_data_bytes" => Ok(GeneratedField::MaxRollupDataBytes),
+                            "maxBlockBytes" | "max_block_bytes" => Ok(GeneratedField::MaxBlockBytes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = estimate_fee_response::BlockFullness;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.mempool.v1.EstimateFeeResponse.BlockFullness")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<estimate_fee_response::BlockFullness, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut transaction_count__ = None;
+                let mut transaction_bytes__ = None;
+                let mut rollup_data_bytes__ = None;
+                let mut max_rollup_data_bytes__ = None;
+                let mut max_block_bytes__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TransactionCount => {
+                            if transaction_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionCount"));
+                            }
+                            transaction_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TransactionBytes => {
+                            if transaction_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionBytes"));
+                            }
+                            transaction_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RollupDataBytes => {
+                            if rollup_data_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rollupDataBytes"));
+                            }
+                            rollup_data_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxRollupDataBytes => {
+                            if max_rollup_data_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxRollupDataBytes"));
+                            }
+                            max_rollup_data_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxBlockBytes => {
+                            if max_block_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxBlockBytes"));
+                            }
+                            max_block_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(estimate_fee_response::BlockFullness {
+                    height: height__.unwrap_or_default(),
+                    transaction_count: transaction_count__.unwrap_or_default(),
+                    transaction_bytes: transaction_bytes__.unwrap_or_default(),
+                    rollup_data_bytes: rollup_data_bytes__.unwrap_or_default(),
+                    max_rollup_data_bytes: max_rollup_data_bytes__.unwrap_or_default(),
+                    max_block_bytes: max_block_bytes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.mempool.v1.EstimateFeeResponse.BlockFullness", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for estimate_fee_response::FeeLevel {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.min_fee_per_kb.is_some() {
+            len += 1;
+        }
+        if self.transaction_count != 0 {
+            len += 1;
+        }
+        if self.rollup_data_bytes != 0 {
+            len += 1;
+        }
+        if self.transaction_bytes != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.mempool.v1.EstimateFeeResponse.FeeLevel", len)?;
+        if let Some(v) = self.min_fee_per_kb.as_ref() {
+            struct_ser.serialize_field("minFeePerKb", v)?;
+        }
+        if self.transaction_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("transactionCount", ToString::to_string(&self.transaction_count).as_str())?;
+        }
+        if self.rollup_data_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("rollupDataBytes", ToString::to_string(&self.rollup_data_bytes).as_str())?;
+        }
+        if self.transaction_bytes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("transactionBytes", ToString::to_string(&self.transaction_bytes).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for estimate_fee_response::FeeLevel {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "min_fee_per_kb",
+            "minFeePerKb",
+            "transaction_count",
+            "transactionCount",
+            "rollup_data_bytes",
+            "rollupDataBytes",
+            "transaction_bytes",
+            "transactionBytes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MinFeePerKb,
+            TransactionCount,
+            RollupDataBytes,
+            TransactionBytes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "minFeePerKb" | "min_fee_per_kb" => Ok(GeneratedField::MinFeePerKb),
+                            "transactionCount" | "transaction_count" => Ok(GeneratedField::TransactionCount),
+                            "rollupDataBytes" | "rollup_data_bytes" => Ok(GeneratedField::RollupDataBytes),
+                            "transactionBytes" | "transaction_bytes" => Ok(GeneratedField::TransactionBytes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = estimate_fee_response::FeeLevel;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.mempool.v1.EstimateFeeResponse.FeeLevel")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<estimate_fee_response::FeeLevel, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut min_fee_per_kb__ = None;
+                let mut transaction_count__ = None;
+                let mut rollup_data_bytes__ = None;
+                let mut transaction_bytes__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MinFeePerKb => {
+                            if min_fee_per_kb__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minFeePerKb"));
+                            }
+                            min_fee_per_kb__ = map_.next_value()?;
+                        }
+                        GeneratedField::TransactionCount => {
+                            if transaction_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionCount"));
+                            }
+                            transaction_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RollupDataBytes => {
+                            if rollup_data_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rollupDataBytes"));
+                            }
+                            rollup_data_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TransactionBytes => {
+                            if transaction_bytes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionBytes"));
+                            }
+                            transaction_bytes__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(estimate_fee_response::FeeLevel {
+                    min_fee_per_kb: min_fee_per_kb__,
+                    transaction_count: transaction_count__.unwrap_or_default(),
+                    rollup_data_bytes: rollup_data_bytes__.unwrap_or_default(),
+                    transaction_bytes: transaction_bytes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.mempool.v1.EstimateFeeResponse.FeeLevel", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for GetTransactionStatusRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -9,7 +594,715 @@ impl serde::Serialize for GetTransactionStatusRequest {
         if !self.transaction_hash.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.mempool.v1.GetTransactionStatusRequest", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.mempool.v1.GetTransactionStatusRequest", len)?;
+        if !self.transaction_hash.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("transactionHash", pbjson::private::base64::encode(&self.transaction_hash).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetTransactionStatusRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "transaction_hash",
+            "transactionHash",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TransactionHash,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "transactionHash" | "transaction_hash" => Ok(GeneratedField::TransactionHash),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetTransactionStatusRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.mempool.v1.GetTransactionStatusRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetTransactionStatusRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut transaction_hash__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TransactionHash => {
+                            if transaction_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionHash"));
+                            }
+                            transaction_hash__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(GetTransactionStatusRequest {
+                    transaction_hash: transaction_hash__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.mempool.v1.GetTransactionStatusRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MempoolEvent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.transaction_hash.is_empty() {
+            len += 1;
+        }
+        if self.signer.is_some() {
+            len += 1;
+        }
+        if self.event.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.mempool.v1.MempoolEvent", len)?;
+        if !self.transaction_hash.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("transactionHash", pbjson::private::base64::encode(&self.transaction_hash).as_str())?;
+        }
+        if let Some(v) = self.signer.as_ref() {
+            struct_ser.serialize_field("signer", v)?;
+        }
+        if let Some(v) = self.event.as_ref() {
+            match v {
+                mempool_event::Event::Inserted(v) => {
+                    struct_ser.serialize_field("inserted", v)?;
+                }
+                mempool_event::Event::Promoted(v) => {
+                    struct_ser.serialize_field("promoted", v)?;
+                }
+                mempool_event::Event::Demoted(v) => {
+                    struct_ser.serialize_field("demoted", v)?;
+                }
+                mempool_event::Event::Removed(v) => {
+                    struct_ser.serialize_field("removed", v)?;
+                }
+                mempool_event::Event::Included(v) => {
+                    struct_ser.serialize_field("included", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MempoolEvent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "transaction_hash",
+            "transactionHash",
+            "signer",
+            "inserted",
+            "promoted",
+            "demoted",
+            "removed",
+            "included",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TransactionHash,
+            Signer,
+            Inserted,
+            Promoted,
+            Demoted,
+            Removed,
+            Included,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "transactionHash" | "transaction_hash" => Ok(GeneratedField::TransactionHash),
+                            "signer" => Ok(GeneratedField::Signer),
+                            "inserted" => Ok(GeneratedField::Inserted),
+                            "promoted" => Ok(GeneratedField::Promoted),
+                            "demoted" => Ok(GeneratedField::Demoted),
+                            "removed" => Ok(GeneratedField::Removed),
+                            "included" => Ok(GeneratedField::Included),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MempoolEvent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.mempool.v1.MempoolEvent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MempoolEvent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut transaction_hash__ = None;
+                let mut signer__ = None;
+                let mut event__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TransactionHash => {
+                            if transaction_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionHash"));
+                            }
+                            transaction_hash__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Signer => {
+                            if signer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signer"));
+                            }
+                            signer__ = map_.next_value()?;
+                        }
+                        GeneratedField::Inserted => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inserted"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(mempool_event::Event::Inserted)
+;
+                        }
+                        GeneratedField::Promoted => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("promoted"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(mempool_event::Event::Promoted)
+;
+                        }
+                        GeneratedField::Demoted => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("demoted"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(mempool_event::Event::Demoted)
+;
+                        }
+                        GeneratedField::Removed => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("removed"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(mempool_event::Event::Removed)
+;
+                        }
+                        GeneratedField::Included => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("included"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(mempool_event::Event::Included)
+;
+                        }
+                    }
+                }
+                Ok(MempoolEvent {
+                    transaction_hash: transaction_hash__.unwrap_or_default(),
+                    signer: signer__,
+                    event: event__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.mempool.v1.MempoolEvent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for mempool_event::Demoted {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("astria.mempool.v1.MempoolEvent.Demoted", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for mempool_event::Demoted {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = mempool_event::Demoted;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.mempool.v1.MempoolEvent.Demoted")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<mempool_event::Demoted, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(mempool_event::Demoted {
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.mempool.v1.MempoolEvent.Demoted", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for mempool_event::Included {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.mempool.v1.MempoolEvent.Included", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for mempool_event::Included {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = mempool_event::Included;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.mempool.v1.MempoolEvent.Included")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<mempool_event::Included, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(mempool_event::Included {
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.mempool.v1.MempoolEvent.Included", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for mempool_event::Inserted {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.parked {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.mempool.v1.MempoolEvent.Inserted", len)?;
+        if self.parked {
+            struct_ser.serialize_field("parked", &self.parked)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for mempool_event::Inserted {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "parked",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Parked,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "parked" => Ok(GeneratedField::Parked),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = mempool_event::Inserted;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.mempool.v1.MempoolEvent.Inserted")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<mempool_event::Inserted, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut parked__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Parked => {
+                            if parked__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parked"));
+                            }
+                            parked__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(mempool_event::Inserted {
+                    parked: parked__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.mempool.v1.MempoolEvent.Inserted", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for mempool_event::Promoted {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("astria.mempool.v1.MempoolEvent.Promoted", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for mempool_event::Promoted {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = mempool_event::Promoted;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.mempool.v1.MempoolEvent.Promoted")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<mempool_event::Promoted, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(mempool_event::Promoted {
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.mempool.v1.MempoolEvent.Promoted", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for mempool_event::Removed {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.mempool.v1.MempoolEvent.Removed", len)?;
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for mempool_event::Removed {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reason,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reason" => Ok(GeneratedField::Reason),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = mempool_event::Removed;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.mempool.v1.MempoolEvent.Removed")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<mempool_event::Removed, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(mempool_event::Removed {
+                    reason: reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.mempool.v1.MempoolEvent.Removed", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StreamMempoolEventsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.signer.is_some() {
+            len += 1;
+        }
+        if !self.transaction_hash.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.mempool.v1.StreamMempoolEventsRequest", len)?;
+        if let Some(v) = self.signer.as_ref() {
+            struct_ser.serialize_field("signer", v)?;
+        }
         if !self.transaction_hash.is_empty() {
             #[allow(clippy::needless_borrow)]
             #[allow(clippy::needless_borrows_for_generic_args)]
@@ -18,19 +1311,21 @@ impl serde::Serialize for GetTransactionStatusRequest {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GetTransactionStatusRequest {
+impl<'de> serde::Deserialize<'de> for StreamMempoolEventsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "signer",
             "transaction_hash",
             "transactionHash",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Signer,
             TransactionHash,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -53,6 +1348,7 @@ impl<'de> serde::Deserialize<'de> for GetTransactionStatusRequest {
                         E: serde::de::Error,
                     {
                         match value {
+                            "signer" => Ok(GeneratedField::Signer),
                             "transactionHash" | "transaction_hash" => Ok(GeneratedField::TransactionHash),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
@@ -63,19 +1359,26 @@ impl<'de> serde::Deserialize<'de> for GetTransactionStatusRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GetTransactionStatusRequest;
+            type Value = StreamMempoolEventsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.mempool.v1.GetTransactionStatusRequest")
+                formatter.write_str("struct astria.mempool.v1.StreamMempoolEventsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetTransactionStatusRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StreamMempoolEventsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
+                let mut signer__ = None;
                 let mut transaction_hash__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
+                        GeneratedField::Signer => {
+                            if signer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signer"));
+                            }
+                            signer__ = map_.next_value()?;
+                        }
                         GeneratedField::TransactionHash => {
                             if transaction_hash__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("transactionHash"));
@@ -86,12 +1389,13 @@ impl<'de> serde::Deserialize<'de> for GetTransactionStatusRequest {
                         }
                     }
                 }
-                Ok(GetTransactionStatusRequest {
+                Ok(StreamMempoolEventsRequest {
+                    signer: signer__,
                     transaction_hash: transaction_hash__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("astria.mempool.v1.GetTransactionStatusRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.mempool.v1.StreamMempoolEventsRequest", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for SubmitTransactionRequest {
@@ -52,3 +52,64 @@ impl ::prost::Name for NonceResponse {
         "/astria.protocol.accounts.v1.NonceResponse".into()
     }
 }
+/// Funds escrowed by a scheduled transfer, pending release to the recipient.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Escrow {
+    /// the unique id of the escrow
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    /// the account which scheduled the transfer
+    #[prost(message, optional, tag = "2")]
+    pub from: ::core::option::Option<super::super::super::primitive::v1::Address>,
+    /// the account receiving the funds on release
+    #[prost(message, optional, tag = "3")]
+    pub to: ::core::option::Option<super::super::super::primitive::v1::Address>,
+    #[prost(string, tag = "4")]
+    pub denom: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "5")]
+    pub amount: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    /// the point at which the escrowed funds are released
+    #[prost(oneof = "escrow::ReleaseAt", tags = "6, 7")]
+    pub release_at: ::core::option::Option<escrow::ReleaseAt>,
+}
+/// Nested message and enum types in `Escrow`.
+pub mod escrow {
+    /// the point at which the escrowed funds are released
+    #[derive(Clone, Copy, PartialEq, ::prost::Oneof)]
+    pub enum ReleaseAt {
+        /// the sequencer block height
+        #[prost(uint64, tag = "6")]
+        Height(u64),
+        /// the sequencer block timestamp, in seconds since the unix epoch
+        #[prost(uint64, tag = "7")]
+        UnixTimestampSeconds(u64),
+    }
+}
+impl ::prost::Name for Escrow {
+    const NAME: &'static str = "Escrow";
+    const PACKAGE: &'static str = "astria.protocol.accounts.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.accounts.v1.Escrow".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.accounts.v1.Escrow".into()
+    }
+}
+/// A response containing the pending escrows sent from or to an account.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingEscrowsResponse {
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    #[prost(message, repeated, tag = "3")]
+    pub escrows: ::prost::alloc::vec::Vec<Escrow>,
+}
+impl ::prost::Name for PendingEscrowsResponse {
+    const NAME: &'static str = "PendingEscrowsResponse";
+    const PACKAGE: &'static str = "astria.protocol.accounts.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.accounts.v1.PendingEscrowsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.accounts.v1.PendingEscrowsResponse".into()
+    }
+}
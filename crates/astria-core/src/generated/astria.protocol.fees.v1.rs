@@ -322,6 +322,176 @@ impl ::prost::Name for MarketsChangeFeeComponents {
         "/astria.protocol.fees.v1.MarketsChangeFeeComponents".into()
     }
 }
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RegisterMultisigAccountFeeComponents {
+    #[prost(message, optional, tag = "1")]
+    pub base: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    #[prost(message, optional, tag = "2")]
+    pub multiplier: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for RegisterMultisigAccountFeeComponents {
+    const NAME: &'static str = "RegisterMultisigAccountFeeComponents";
+    const PACKAGE: &'static str = "astria.protocol.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.fees.v1.RegisterMultisigAccountFeeComponents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.fees.v1.RegisterMultisigAccountFeeComponents".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ScheduledTransferFeeComponents {
+    #[prost(message, optional, tag = "1")]
+    pub base: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    #[prost(message, optional, tag = "2")]
+    pub multiplier: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for ScheduledTransferFeeComponents {
+    const NAME: &'static str = "ScheduledTransferFeeComponents";
+    const PACKAGE: &'static str = "astria.protocol.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.fees.v1.ScheduledTransferFeeComponents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.fees.v1.ScheduledTransferFeeComponents".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimitChangeFeeComponents {
+    #[prost(message, optional, tag = "1")]
+    pub base: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    #[prost(message, optional, tag = "2")]
+    pub multiplier: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for Ics20RateLimitChangeFeeComponents {
+    const NAME: &'static str = "Ics20RateLimitChangeFeeComponents";
+    const PACKAGE: &'static str = "astria.protocol.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.fees.v1.Ics20RateLimitChangeFeeComponents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.fees.v1.Ics20RateLimitChangeFeeComponents".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct VoteExtensionParticipationChangeFeeComponents {
+    #[prost(message, optional, tag = "1")]
+    pub base: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    #[prost(message, optional, tag = "2")]
+    pub multiplier: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for VoteExtensionParticipationChangeFeeComponents {
+    const NAME: &'static str = "VoteExtensionParticipationChangeFeeComponents";
+    const PACKAGE: &'static str = "astria.protocol.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.fees.v1.VoteExtensionParticipationChangeFeeComponents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.fees.v1.VoteExtensionParticipationChangeFeeComponents".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct BondFeeComponents {
+    #[prost(message, optional, tag = "1")]
+    pub base: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    #[prost(message, optional, tag = "2")]
+    pub multiplier: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for BondFeeComponents {
+    const NAME: &'static str = "BondFeeComponents";
+    const PACKAGE: &'static str = "astria.protocol.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.fees.v1.BondFeeComponents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.fees.v1.BondFeeComponents".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct UnbondFeeComponents {
+    #[prost(message, optional, tag = "1")]
+    pub base: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    #[prost(message, optional, tag = "2")]
+    pub multiplier: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for UnbondFeeComponents {
+    const NAME: &'static str = "UnbondFeeComponents";
+    const PACKAGE: &'static str = "astria.protocol.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.fees.v1.UnbondFeeComponents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.fees.v1.UnbondFeeComponents".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ProposalSubmissionFeeComponents {
+    #[prost(message, optional, tag = "1")]
+    pub base: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    #[prost(message, optional, tag = "2")]
+    pub multiplier: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for ProposalSubmissionFeeComponents {
+    const NAME: &'static str = "ProposalSubmissionFeeComponents";
+    const PACKAGE: &'static str = "astria.protocol.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.fees.v1.ProposalSubmissionFeeComponents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.fees.v1.ProposalSubmissionFeeComponents".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ProposalVoteFeeComponents {
+    #[prost(message, optional, tag = "1")]
+    pub base: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    #[prost(message, optional, tag = "2")]
+    pub multiplier: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for ProposalVoteFeeComponents {
+    const NAME: &'static str = "ProposalVoteFeeComponents";
+    const PACKAGE: &'static str = "astria.protocol.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.fees.v1.ProposalVoteFeeComponents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.fees.v1.ProposalVoteFeeComponents".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct StakingParametersChangeFeeComponents {
+    #[prost(message, optional, tag = "1")]
+    pub base: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    #[prost(message, optional, tag = "2")]
+    pub multiplier: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for StakingParametersChangeFeeComponents {
+    const NAME: &'static str = "StakingParametersChangeFeeComponents";
+    const PACKAGE: &'static str = "astria.protocol.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.fees.v1.StakingParametersChangeFeeComponents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.fees.v1.StakingParametersChangeFeeComponents".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GovernanceParametersChangeFeeComponents {
+    #[prost(message, optional, tag = "1")]
+    pub base: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    #[prost(message, optional, tag = "2")]
+    pub multiplier: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for GovernanceParametersChangeFeeComponents {
+    const NAME: &'static str = "GovernanceParametersChangeFeeComponents";
+    const PACKAGE: &'static str = "astria.protocol.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.fees.v1.GovernanceParametersChangeFeeComponents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.fees.v1.GovernanceParametersChangeFeeComponents".into()
+    }
+}
 /// Response to a transaction fee ABCI query.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TransactionFeeResponse {
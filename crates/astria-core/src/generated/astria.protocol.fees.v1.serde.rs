@@ -1,3 +1,111 @@
+impl serde::Serialize for BondFeeComponents {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.base.is_some() {
+            len += 1;
+        }
+        if self.multiplier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.BondFeeComponents", len)?;
+        if let Some(v) = self.base.as_ref() {
+            struct_ser.serialize_field("base", v)?;
+        }
+        if let Some(v) = self.multiplier.as_ref() {
+            struct_ser.serialize_field("multiplier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BondFeeComponents {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "base",
+            "multiplier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Base,
+            Multiplier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "base" => Ok(GeneratedField::Base),
+                            "multiplier" => Ok(GeneratedField::Multiplier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BondFeeComponents;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.fees.v1.BondFeeComponents")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BondFeeComponents, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut base__ = None;
+                let mut multiplier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = map_.next_value()?;
+                        }
+                        GeneratedField::Multiplier => {
+                            if multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiplier"));
+                            }
+                            multiplier__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(BondFeeComponents {
+                    base: base__,
+                    multiplier: multiplier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.fees.v1.BondFeeComponents", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for BridgeLockFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -754,7 +862,7 @@ impl<'de> serde::Deserialize<'de> for FeeChangeFeeComponents {
         deserializer.deserialize_struct("astria.protocol.fees.v1.FeeChangeFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IbcRelayFeeComponents {
+impl serde::Serialize for GovernanceParametersChangeFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -768,7 +876,7 @@ impl serde::Serialize for IbcRelayFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.IbcRelayFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.GovernanceParametersChangeFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -778,7 +886,7 @@ impl serde::Serialize for IbcRelayFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IbcRelayFeeComponents {
+impl<'de> serde::Deserialize<'de> for GovernanceParametersChangeFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -825,13 +933,13 @@ impl<'de> serde::Deserialize<'de> for IbcRelayFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IbcRelayFeeComponents;
+            type Value = GovernanceParametersChangeFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.IbcRelayFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.GovernanceParametersChangeFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcRelayFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GovernanceParametersChangeFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -853,16 +961,16 @@ impl<'de> serde::Deserialize<'de> for IbcRelayFeeComponents {
                         }
                     }
                 }
-                Ok(IbcRelayFeeComponents {
+                Ok(GovernanceParametersChangeFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.IbcRelayFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.GovernanceParametersChangeFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IbcRelayerChangeFeeComponents {
+impl serde::Serialize for IbcRelayFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -876,7 +984,7 @@ impl serde::Serialize for IbcRelayerChangeFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.IbcRelayerChangeFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.IbcRelayFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -886,7 +994,7 @@ impl serde::Serialize for IbcRelayerChangeFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IbcRelayerChangeFeeComponents {
+impl<'de> serde::Deserialize<'de> for IbcRelayFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -933,13 +1041,13 @@ impl<'de> serde::Deserialize<'de> for IbcRelayerChangeFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IbcRelayerChangeFeeComponents;
+            type Value = IbcRelayFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.IbcRelayerChangeFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.IbcRelayFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcRelayerChangeFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcRelayFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -961,16 +1069,16 @@ impl<'de> serde::Deserialize<'de> for IbcRelayerChangeFeeComponents {
                         }
                     }
                 }
-                Ok(IbcRelayerChangeFeeComponents {
+                Ok(IbcRelayFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.IbcRelayerChangeFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.IbcRelayFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IbcSudoChangeFeeComponents {
+impl serde::Serialize for IbcRelayerChangeFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -984,7 +1092,7 @@ impl serde::Serialize for IbcSudoChangeFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.IbcSudoChangeFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.IbcRelayerChangeFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -994,7 +1102,7 @@ impl serde::Serialize for IbcSudoChangeFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IbcSudoChangeFeeComponents {
+impl<'de> serde::Deserialize<'de> for IbcRelayerChangeFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1041,13 +1149,13 @@ impl<'de> serde::Deserialize<'de> for IbcSudoChangeFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IbcSudoChangeFeeComponents;
+            type Value = IbcRelayerChangeFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.IbcSudoChangeFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.IbcRelayerChangeFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcSudoChangeFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcRelayerChangeFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -1069,16 +1177,16 @@ impl<'de> serde::Deserialize<'de> for IbcSudoChangeFeeComponents {
                         }
                     }
                 }
-                Ok(IbcSudoChangeFeeComponents {
+                Ok(IbcRelayerChangeFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.IbcSudoChangeFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.IbcRelayerChangeFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Ics20WithdrawalFeeComponents {
+impl serde::Serialize for IbcSudoChangeFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1092,7 +1200,7 @@ impl serde::Serialize for Ics20WithdrawalFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.Ics20WithdrawalFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.IbcSudoChangeFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -1102,7 +1210,7 @@ impl serde::Serialize for Ics20WithdrawalFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Ics20WithdrawalFeeComponents {
+impl<'de> serde::Deserialize<'de> for IbcSudoChangeFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1149,13 +1257,13 @@ impl<'de> serde::Deserialize<'de> for Ics20WithdrawalFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Ics20WithdrawalFeeComponents;
+            type Value = IbcSudoChangeFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.Ics20WithdrawalFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.IbcSudoChangeFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20WithdrawalFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcSudoChangeFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -1177,16 +1285,16 @@ impl<'de> serde::Deserialize<'de> for Ics20WithdrawalFeeComponents {
                         }
                     }
                 }
-                Ok(Ics20WithdrawalFeeComponents {
+                Ok(IbcSudoChangeFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.Ics20WithdrawalFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.IbcSudoChangeFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for InitBridgeAccountFeeComponents {
+impl serde::Serialize for Ics20RateLimitChangeFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1200,7 +1308,7 @@ impl serde::Serialize for InitBridgeAccountFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.InitBridgeAccountFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.Ics20RateLimitChangeFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -1210,7 +1318,7 @@ impl serde::Serialize for InitBridgeAccountFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for InitBridgeAccountFeeComponents {
+impl<'de> serde::Deserialize<'de> for Ics20RateLimitChangeFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1257,13 +1365,13 @@ impl<'de> serde::Deserialize<'de> for InitBridgeAccountFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = InitBridgeAccountFeeComponents;
+            type Value = Ics20RateLimitChangeFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.InitBridgeAccountFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.Ics20RateLimitChangeFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InitBridgeAccountFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20RateLimitChangeFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -1285,16 +1393,16 @@ impl<'de> serde::Deserialize<'de> for InitBridgeAccountFeeComponents {
                         }
                     }
                 }
-                Ok(InitBridgeAccountFeeComponents {
+                Ok(Ics20RateLimitChangeFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.InitBridgeAccountFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.Ics20RateLimitChangeFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for MarketsChangeFeeComponents {
+impl serde::Serialize for Ics20WithdrawalFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1308,7 +1416,7 @@ impl serde::Serialize for MarketsChangeFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.MarketsChangeFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.Ics20WithdrawalFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -1318,7 +1426,7 @@ impl serde::Serialize for MarketsChangeFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for MarketsChangeFeeComponents {
+impl<'de> serde::Deserialize<'de> for Ics20WithdrawalFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1365,13 +1473,13 @@ impl<'de> serde::Deserialize<'de> for MarketsChangeFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = MarketsChangeFeeComponents;
+            type Value = Ics20WithdrawalFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.MarketsChangeFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.Ics20WithdrawalFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MarketsChangeFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20WithdrawalFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -1393,16 +1501,16 @@ impl<'de> serde::Deserialize<'de> for MarketsChangeFeeComponents {
                         }
                     }
                 }
-                Ok(MarketsChangeFeeComponents {
+                Ok(Ics20WithdrawalFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.MarketsChangeFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.Ics20WithdrawalFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for RecoverIbcClientFeeComponents {
+impl serde::Serialize for InitBridgeAccountFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1416,7 +1524,7 @@ impl serde::Serialize for RecoverIbcClientFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.RecoverIbcClientFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.InitBridgeAccountFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -1426,7 +1534,7 @@ impl serde::Serialize for RecoverIbcClientFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for RecoverIbcClientFeeComponents {
+impl<'de> serde::Deserialize<'de> for InitBridgeAccountFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1473,13 +1581,13 @@ impl<'de> serde::Deserialize<'de> for RecoverIbcClientFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = RecoverIbcClientFeeComponents;
+            type Value = InitBridgeAccountFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.RecoverIbcClientFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.InitBridgeAccountFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RecoverIbcClientFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InitBridgeAccountFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -1501,16 +1609,16 @@ impl<'de> serde::Deserialize<'de> for RecoverIbcClientFeeComponents {
                         }
                     }
                 }
-                Ok(RecoverIbcClientFeeComponents {
+                Ok(InitBridgeAccountFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.RecoverIbcClientFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.InitBridgeAccountFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for RollupDataSubmissionFeeComponents {
+impl serde::Serialize for MarketsChangeFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1524,7 +1632,7 @@ impl serde::Serialize for RollupDataSubmissionFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.RollupDataSubmissionFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.MarketsChangeFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -1534,7 +1642,7 @@ impl serde::Serialize for RollupDataSubmissionFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for RollupDataSubmissionFeeComponents {
+impl<'de> serde::Deserialize<'de> for MarketsChangeFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1581,13 +1689,13 @@ impl<'de> serde::Deserialize<'de> for RollupDataSubmissionFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = RollupDataSubmissionFeeComponents;
+            type Value = MarketsChangeFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.RollupDataSubmissionFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.MarketsChangeFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RollupDataSubmissionFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MarketsChangeFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -1609,16 +1717,16 @@ impl<'de> serde::Deserialize<'de> for RollupDataSubmissionFeeComponents {
                         }
                     }
                 }
-                Ok(RollupDataSubmissionFeeComponents {
+                Ok(MarketsChangeFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.RollupDataSubmissionFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.MarketsChangeFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SudoAddressChangeFeeComponents {
+impl serde::Serialize for ProposalSubmissionFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1632,7 +1740,7 @@ impl serde::Serialize for SudoAddressChangeFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.SudoAddressChangeFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.ProposalSubmissionFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -1642,7 +1750,7 @@ impl serde::Serialize for SudoAddressChangeFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SudoAddressChangeFeeComponents {
+impl<'de> serde::Deserialize<'de> for ProposalSubmissionFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1689,13 +1797,13 @@ impl<'de> serde::Deserialize<'de> for SudoAddressChangeFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SudoAddressChangeFeeComponents;
+            type Value = ProposalSubmissionFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.SudoAddressChangeFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.ProposalSubmissionFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SudoAddressChangeFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalSubmissionFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -1717,16 +1825,16 @@ impl<'de> serde::Deserialize<'de> for SudoAddressChangeFeeComponents {
                         }
                     }
                 }
-                Ok(SudoAddressChangeFeeComponents {
+                Ok(ProposalSubmissionFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.SudoAddressChangeFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.ProposalSubmissionFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionFee {
+impl serde::Serialize for ProposalVoteFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1734,37 +1842,37 @@ impl serde::Serialize for TransactionFee {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.asset.is_empty() {
+        if self.base.is_some() {
             len += 1;
         }
-        if self.fee.is_some() {
+        if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.TransactionFee", len)?;
-        if !self.asset.is_empty() {
-            struct_ser.serialize_field("asset", &self.asset)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.ProposalVoteFeeComponents", len)?;
+        if let Some(v) = self.base.as_ref() {
+            struct_ser.serialize_field("base", v)?;
         }
-        if let Some(v) = self.fee.as_ref() {
-            struct_ser.serialize_field("fee", v)?;
+        if let Some(v) = self.multiplier.as_ref() {
+            struct_ser.serialize_field("multiplier", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionFee {
+impl<'de> serde::Deserialize<'de> for ProposalVoteFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "asset",
-            "fee",
+            "base",
+            "multiplier",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Asset,
-            Fee,
+            Base,
+            Multiplier,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1786,8 +1894,8 @@ impl<'de> serde::Deserialize<'de> for TransactionFee {
                         E: serde::de::Error,
                     {
                         match value {
-                            "asset" => Ok(GeneratedField::Asset),
-                            "fee" => Ok(GeneratedField::Fee),
+                            "base" => Ok(GeneratedField::Base),
+                            "multiplier" => Ok(GeneratedField::Multiplier),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1797,22 +1905,778 @@ impl<'de> serde::Deserialize<'de> for TransactionFee {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionFee;
+            type Value = ProposalVoteFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.TransactionFee")
+                formatter.write_str("struct astria.protocol.fees.v1.ProposalVoteFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionFee, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalVoteFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut asset__ = None;
-                let mut fee__ = None;
+                let mut base__ = None;
+                let mut multiplier__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Asset => {
-                            if asset__.is_some() {
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = map_.next_value()?;
+                        }
+                        GeneratedField::Multiplier => {
+                            if multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiplier"));
+                            }
+                            multiplier__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ProposalVoteFeeComponents {
+                    base: base__,
+                    multiplier: multiplier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.fees.v1.ProposalVoteFeeComponents", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RecoverIbcClientFeeComponents {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.base.is_some() {
+            len += 1;
+        }
+        if self.multiplier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.RecoverIbcClientFeeComponents", len)?;
+        if let Some(v) = self.base.as_ref() {
+            struct_ser.serialize_field("base", v)?;
+        }
+        if let Some(v) = self.multiplier.as_ref() {
+            struct_ser.serialize_field("multiplier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RecoverIbcClientFeeComponents {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "base",
+            "multiplier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Base,
+            Multiplier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "base" => Ok(GeneratedField::Base),
+                            "multiplier" => Ok(GeneratedField::Multiplier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RecoverIbcClientFeeComponents;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.fees.v1.RecoverIbcClientFeeComponents")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RecoverIbcClientFeeComponents, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut base__ = None;
+                let mut multiplier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = map_.next_value()?;
+                        }
+                        GeneratedField::Multiplier => {
+                            if multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiplier"));
+                            }
+                            multiplier__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(RecoverIbcClientFeeComponents {
+                    base: base__,
+                    multiplier: multiplier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.fees.v1.RecoverIbcClientFeeComponents", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RegisterMultisigAccountFeeComponents {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.base.is_some() {
+            len += 1;
+        }
+        if self.multiplier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.RegisterMultisigAccountFeeComponents", len)?;
+        if let Some(v) = self.base.as_ref() {
+            struct_ser.serialize_field("base", v)?;
+        }
+        if let Some(v) = self.multiplier.as_ref() {
+            struct_ser.serialize_field("multiplier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RegisterMultisigAccountFeeComponents {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "base",
+            "multiplier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Base,
+            Multiplier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "base" => Ok(GeneratedField::Base),
+                            "multiplier" => Ok(GeneratedField::Multiplier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RegisterMultisigAccountFeeComponents;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.fees.v1.RegisterMultisigAccountFeeComponents")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RegisterMultisigAccountFeeComponents, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut base__ = None;
+                let mut multiplier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = map_.next_value()?;
+                        }
+                        GeneratedField::Multiplier => {
+                            if multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiplier"));
+                            }
+                            multiplier__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(RegisterMultisigAccountFeeComponents {
+                    base: base__,
+                    multiplier: multiplier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.fees.v1.RegisterMultisigAccountFeeComponents", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RollupDataSubmissionFeeComponents {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.base.is_some() {
+            len += 1;
+        }
+        if self.multiplier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.RollupDataSubmissionFeeComponents", len)?;
+        if let Some(v) = self.base.as_ref() {
+            struct_ser.serialize_field("base", v)?;
+        }
+        if let Some(v) = self.multiplier.as_ref() {
+            struct_ser.serialize_field("multiplier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RollupDataSubmissionFeeComponents {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "base",
+            "multiplier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Base,
+            Multiplier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "base" => Ok(GeneratedField::Base),
+                            "multiplier" => Ok(GeneratedField::Multiplier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RollupDataSubmissionFeeComponents;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.fees.v1.RollupDataSubmissionFeeComponents")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RollupDataSubmissionFeeComponents, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut base__ = None;
+                let mut multiplier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = map_.next_value()?;
+                        }
+                        GeneratedField::Multiplier => {
+                            if multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiplier"));
+                            }
+                            multiplier__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(RollupDataSubmissionFeeComponents {
+                    base: base__,
+                    multiplier: multiplier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.fees.v1.RollupDataSubmissionFeeComponents", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ScheduledTransferFeeComponents {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.base.is_some() {
+            len += 1;
+        }
+        if self.multiplier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.ScheduledTransferFeeComponents", len)?;
+        if let Some(v) = self.base.as_ref() {
+            struct_ser.serialize_field("base", v)?;
+        }
+        if let Some(v) = self.multiplier.as_ref() {
+            struct_ser.serialize_field("multiplier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ScheduledTransferFeeComponents {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "base",
+            "multiplier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Base,
+            Multiplier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "base" => Ok(GeneratedField::Base),
+                            "multiplier" => Ok(GeneratedField::Multiplier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ScheduledTransferFeeComponents;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.fees.v1.ScheduledTransferFeeComponents")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduledTransferFeeComponents, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut base__ = None;
+                let mut multiplier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = map_.next_value()?;
+                        }
+                        GeneratedField::Multiplier => {
+                            if multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiplier"));
+                            }
+                            multiplier__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ScheduledTransferFeeComponents {
+                    base: base__,
+                    multiplier: multiplier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.fees.v1.ScheduledTransferFeeComponents", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StakingParametersChangeFeeComponents {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.base.is_some() {
+            len += 1;
+        }
+        if self.multiplier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.StakingParametersChangeFeeComponents", len)?;
+        if let Some(v) = self.base.as_ref() {
+            struct_ser.serialize_field("base", v)?;
+        }
+        if let Some(v) = self.multiplier.as_ref() {
+            struct_ser.serialize_field("multiplier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StakingParametersChangeFeeComponents {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "base",
+            "multiplier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Base,
+            Multiplier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "base" => Ok(GeneratedField::Base),
+                            "multiplier" => Ok(GeneratedField::Multiplier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StakingParametersChangeFeeComponents;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.fees.v1.StakingParametersChangeFeeComponents")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StakingParametersChangeFeeComponents, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut base__ = None;
+                let mut multiplier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = map_.next_value()?;
+                        }
+                        GeneratedField::Multiplier => {
+                            if multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiplier"));
+                            }
+                            multiplier__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(StakingParametersChangeFeeComponents {
+                    base: base__,
+                    multiplier: multiplier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.fees.v1.StakingParametersChangeFeeComponents", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SudoAddressChangeFeeComponents {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.base.is_some() {
+            len += 1;
+        }
+        if self.multiplier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.SudoAddressChangeFeeComponents", len)?;
+        if let Some(v) = self.base.as_ref() {
+            struct_ser.serialize_field("base", v)?;
+        }
+        if let Some(v) = self.multiplier.as_ref() {
+            struct_ser.serialize_field("multiplier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SudoAddressChangeFeeComponents {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "base",
+            "multiplier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Base,
+            Multiplier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "base" => Ok(GeneratedField::Base),
+                            "multiplier" => Ok(GeneratedField::Multiplier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SudoAddressChangeFeeComponents;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.fees.v1.SudoAddressChangeFeeComponents")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SudoAddressChangeFeeComponents, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut base__ = None;
+                let mut multiplier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = map_.next_value()?;
+                        }
+                        GeneratedField::Multiplier => {
+                            if multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiplier"));
+                            }
+                            multiplier__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SudoAddressChangeFeeComponents {
+                    base: base__,
+                    multiplier: multiplier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.fees.v1.SudoAddressChangeFeeComponents", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionFee {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if self.fee.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.TransactionFee", len)?;
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if let Some(v) = self.fee.as_ref() {
+            struct_ser.serialize_field("fee", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionFee {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset",
+            "fee",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Asset,
+            Fee,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "asset" => Ok(GeneratedField::Asset),
+                            "fee" => Ok(GeneratedField::Fee),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionFee;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.fees.v1.TransactionFee")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionFee, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset__ = None;
+                let mut fee__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("asset"));
                             }
                             asset__ = Some(map_.next_value()?);
@@ -2054,6 +2918,114 @@ impl<'de> serde::Deserialize<'de> for TransferFeeComponents {
         deserializer.deserialize_struct("astria.protocol.fees.v1.TransferFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for UnbondFeeComponents {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.base.is_some() {
+            len += 1;
+        }
+        if self.multiplier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.UnbondFeeComponents", len)?;
+        if let Some(v) = self.base.as_ref() {
+            struct_ser.serialize_field("base", v)?;
+        }
+        if let Some(v) = self.multiplier.as_ref() {
+            struct_ser.serialize_field("multiplier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UnbondFeeComponents {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "base",
+            "multiplier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Base,
+            Multiplier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "base" => Ok(GeneratedField::Base),
+                            "multiplier" => Ok(GeneratedField::Multiplier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UnbondFeeComponents;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.fees.v1.UnbondFeeComponents")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnbondFeeComponents, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut base__ = None;
+                let mut multiplier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = map_.next_value()?;
+                        }
+                        GeneratedField::Multiplier => {
+                            if multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiplier"));
+                            }
+                            multiplier__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(UnbondFeeComponents {
+                    base: base__,
+                    multiplier: multiplier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.fees.v1.UnbondFeeComponents", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ValidatorUpdateFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2162,3 +3134,111 @@ impl<'de> serde::Deserialize<'de> for ValidatorUpdateFeeComponents {
         deserializer.deserialize_struct("astria.protocol.fees.v1.ValidatorUpdateFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for VoteExtensionParticipationChangeFeeComponents {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.base.is_some() {
+            len += 1;
+        }
+        if self.multiplier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.VoteExtensionParticipationChangeFeeComponents", len)?;
+        if let Some(v) = self.base.as_ref() {
+            struct_ser.serialize_field("base", v)?;
+        }
+        if let Some(v) = self.multiplier.as_ref() {
+            struct_ser.serialize_field("multiplier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VoteExtensionParticipationChangeFeeComponents {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "base",
+            "multiplier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Base,
+            Multiplier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "base" => Ok(GeneratedField::Base),
+                            "multiplier" => Ok(GeneratedField::Multiplier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VoteExtensionParticipationChangeFeeComponents;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.fees.v1.VoteExtensionParticipationChangeFeeComponents")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VoteExtensionParticipationChangeFeeComponents, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut base__ = None;
+                let mut multiplier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = map_.next_value()?;
+                        }
+                        GeneratedField::Multiplier => {
+                            if multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiplier"));
+                            }
+                            multiplier__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(VoteExtensionParticipationChangeFeeComponents {
+                    base: base__,
+                    multiplier: multiplier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.fees.v1.VoteExtensionParticipationChangeFeeComponents", FIELDS, GeneratedVisitor)
+    }
+}
@@ -27,6 +27,14 @@ pub struct GenesisAppState {
     pub allowed_fee_assets: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     #[prost(message, optional, tag = "10")]
     pub fees: ::core::option::Option<GenesisFees>,
+    /// If set, the validator set is managed by bonding the native asset to
+    /// validators instead of by `ValidatorUpdate` actions.
+    #[prost(message, optional, tag = "11")]
+    pub staking: ::core::option::Option<StakingParameters>,
+    /// If set, privileged actions can also be executed through governance
+    /// proposals voted on by the validators.
+    #[prost(message, optional, tag = "12")]
+    pub governance: ::core::option::Option<GovernanceParameters>,
 }
 impl ::prost::Name for GenesisAppState {
     const NAME: &'static str = "GenesisAppState";
@@ -98,6 +106,55 @@ impl ::prost::Name for IbcParameters {
         "/astria.protocol.genesis.v1.IbcParameters".into()
     }
 }
+/// Staking configuration data.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct StakingParameters {
+    /// The number of blocks after which unbonded stake is returned to the
+    /// delegator. Must be non-zero.
+    #[prost(uint64, tag = "1")]
+    pub unbonding_period_blocks: u64,
+    /// The amount of bonded native asset corresponding to one unit of validator
+    /// power. Must be non-zero.
+    #[prost(message, optional, tag = "2")]
+    pub stake_per_power: ::core::option::Option<
+        super::super::super::primitive::v1::Uint128,
+    >,
+}
+impl ::prost::Name for StakingParameters {
+    const NAME: &'static str = "StakingParameters";
+    const PACKAGE: &'static str = "astria.protocol.genesis.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.genesis.v1.StakingParameters".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.genesis.v1.StakingParameters".into()
+    }
+}
+/// Governance configuration data.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GovernanceParameters {
+    /// The number of blocks during which validators can vote on a proposal after
+    /// its submission. Must be non-zero.
+    #[prost(uint64, tag = "1")]
+    pub voting_period_blocks: u64,
+    /// The amount of the native asset a proposer deposits when submitting a
+    /// proposal, refunded to the proposer once the proposal is finalized. No
+    /// deposit is required if unset.
+    #[prost(message, optional, tag = "2")]
+    pub proposal_deposit: ::core::option::Option<
+        super::super::super::primitive::v1::Uint128,
+    >,
+}
+impl ::prost::Name for GovernanceParameters {
+    const NAME: &'static str = "GovernanceParameters";
+    const PACKAGE: &'static str = "astria.protocol.genesis.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.genesis.v1.GovernanceParameters".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.genesis.v1.GovernanceParameters".into()
+    }
+}
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GenesisFees {
     #[prost(message, optional, tag = "1")]
@@ -168,6 +225,42 @@ pub struct GenesisFees {
     pub markets_change: ::core::option::Option<
         super::super::fees::v1::MarketsChangeFeeComponents,
     >,
+    #[prost(message, optional, tag = "19")]
+    pub register_multisig_account: ::core::option::Option<
+        super::super::fees::v1::RegisterMultisigAccountFeeComponents,
+    >,
+    #[prost(message, optional, tag = "20")]
+    pub scheduled_transfer: ::core::option::Option<
+        super::super::fees::v1::ScheduledTransferFeeComponents,
+    >,
+    #[prost(message, optional, tag = "21")]
+    pub ics20_rate_limit_change: ::core::option::Option<
+        super::super::fees::v1::Ics20RateLimitChangeFeeComponents,
+    >,
+    #[prost(message, optional, tag = "22")]
+    pub vote_extension_participation_change: ::core::option::Option<
+        super::super::fees::v1::VoteExtensionParticipationChangeFeeComponents,
+    >,
+    #[prost(message, optional, tag = "23")]
+    pub bond: ::core::option::Option<super::super::fees::v1::BondFeeComponents>,
+    #[prost(message, optional, tag = "24")]
+    pub unbond: ::core::option::Option<super::super::fees::v1::UnbondFeeComponents>,
+    #[prost(message, optional, tag = "25")]
+    pub proposal_submission: ::core::option::Option<
+        super::super::fees::v1::ProposalSubmissionFeeComponents,
+    >,
+    #[prost(message, optional, tag = "26")]
+    pub proposal_vote: ::core::option::Option<
+        super::super::fees::v1::ProposalVoteFeeComponents,
+    >,
+    #[prost(message, optional, tag = "27")]
+    pub staking_parameters_change: ::core::option::Option<
+        super::super::fees::v1::StakingParametersChangeFeeComponents,
+    >,
+    #[prost(message, optional, tag = "28")]
+    pub governance_parameters_change: ::core::option::Option<
+        super::super::fees::v1::GovernanceParametersChangeFeeComponents,
+    >,
 }
 impl ::prost::Name for GenesisFees {
     const NAME: &'static str = "GenesisFees";
@@ -253,6 +253,12 @@ impl serde::Serialize for GenesisAppState {
         if self.fees.is_some() {
             len += 1;
         }
+        if self.staking.is_some() {
+            len += 1;
+        }
+        if self.governance.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("astria.protocol.genesis.v1.GenesisAppState", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -284,6 +290,12 @@ impl serde::Serialize for GenesisAppState {
         if let Some(v) = self.fees.as_ref() {
             struct_ser.serialize_field("fees", v)?;
         }
+        if let Some(v) = self.staking.as_ref() {
+            struct_ser.serialize_field("staking", v)?;
+        }
+        if let Some(v) = self.governance.as_ref() {
+            struct_ser.serialize_field("governance", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -312,6 +324,8 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
             "allowed_fee_assets",
             "allowedFeeAssets",
             "fees",
+            "staking",
+            "governance",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -326,6 +340,8 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
             IbcParameters,
             AllowedFeeAssets,
             Fees,
+            Staking,
+            Governance,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -357,6 +373,8 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                             "ibcParameters" | "ibc_parameters" => Ok(GeneratedField::IbcParameters),
                             "allowedFeeAssets" | "allowed_fee_assets" => Ok(GeneratedField::AllowedFeeAssets),
                             "fees" => Ok(GeneratedField::Fees),
+                            "staking" => Ok(GeneratedField::Staking),
+                            "governance" => Ok(GeneratedField::Governance),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -386,6 +404,8 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                 let mut ibc_parameters__ = None;
                 let mut allowed_fee_assets__ = None;
                 let mut fees__ = None;
+                let mut staking__ = None;
+                let mut governance__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -448,6 +468,18 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                             }
                             fees__ = map_.next_value()?;
                         }
+                        GeneratedField::Staking => {
+                            if staking__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("staking"));
+                            }
+                            staking__ = map_.next_value()?;
+                        }
+                        GeneratedField::Governance => {
+                            if governance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("governance"));
+                            }
+                            governance__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(GenesisAppState {
@@ -461,6 +493,8 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                     ibc_parameters: ibc_parameters__,
                     allowed_fee_assets: allowed_fee_assets__.unwrap_or_default(),
                     fees: fees__,
+                    staking: staking__,
+                    governance: governance__,
                 })
             }
         }
@@ -529,6 +563,36 @@ impl serde::Serialize for GenesisFees {
         if self.markets_change.is_some() {
             len += 1;
         }
+        if self.register_multisig_account.is_some() {
+            len += 1;
+        }
+        if self.scheduled_transfer.is_some() {
+            len += 1;
+        }
+        if self.ics20_rate_limit_change.is_some() {
+            len += 1;
+        }
+        if self.vote_extension_participation_change.is_some() {
+            len += 1;
+        }
+        if self.bond.is_some() {
+            len += 1;
+        }
+        if self.unbond.is_some() {
+            len += 1;
+        }
+        if self.proposal_submission.is_some() {
+            len += 1;
+        }
+        if self.proposal_vote.is_some() {
+            len += 1;
+        }
+        if self.staking_parameters_change.is_some() {
+            len += 1;
+        }
+        if self.governance_parameters_change.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("astria.protocol.genesis.v1.GenesisFees", len)?;
         if let Some(v) = self.bridge_lock.as_ref() {
             struct_ser.serialize_field("bridgeLock", v)?;
@@ -584,6 +648,36 @@ impl serde::Serialize for GenesisFees {
         if let Some(v) = self.markets_change.as_ref() {
             struct_ser.serialize_field("marketsChange", v)?;
         }
+        if let Some(v) = self.register_multisig_account.as_ref() {
+            struct_ser.serialize_field("registerMultisigAccount", v)?;
+        }
+        if let Some(v) = self.scheduled_transfer.as_ref() {
+            struct_ser.serialize_field("scheduledTransfer", v)?;
+        }
+        if let Some(v) = self.ics20_rate_limit_change.as_ref() {
+            struct_ser.serialize_field("ics20RateLimitChange", v)?;
+        }
+        if let Some(v) = self.vote_extension_participation_change.as_ref() {
+            struct_ser.serialize_field("voteExtensionParticipationChange", v)?;
+        }
+        if let Some(v) = self.bond.as_ref() {
+            struct_ser.serialize_field("bond", v)?;
+        }
+        if let Some(v) = self.unbond.as_ref() {
+            struct_ser.serialize_field("unbond", v)?;
+        }
+        if let Some(v) = self.proposal_submission.as_ref() {
+            struct_ser.serialize_field("proposalSubmission", v)?;
+        }
+        if let Some(v) = self.proposal_vote.as_ref() {
+            struct_ser.serialize_field("proposalVote", v)?;
+        }
+        if let Some(v) = self.staking_parameters_change.as_ref() {
+            struct_ser.serialize_field("stakingParametersChange", v)?;
+        }
+        if let Some(v) = self.governance_parameters_change.as_ref() {
+            struct_ser.serialize_field("governanceParametersChange", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -629,6 +723,24 @@ impl<'de> serde::Deserialize<'de> for GenesisFees {
             "currencyPairsChange",
             "markets_change",
             "marketsChange",
+            "register_multisig_account",
+            "registerMultisigAccount",
+            "scheduled_transfer",
+            "scheduledTransfer",
+            "ics20_rate_limit_change",
+            "ics20RateLimitChange",
+            "vote_extension_participation_change",
+            "voteExtensionParticipationChange",
+            "bond",
+            "unbond",
+            "proposal_submission",
+            "proposalSubmission",
+            "proposal_vote",
+            "proposalVote",
+            "staking_parameters_change",
+            "stakingParametersChange",
+            "governance_parameters_change",
+            "governanceParametersChange",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -651,6 +763,16 @@ impl<'de> serde::Deserialize<'de> for GenesisFees {
             RecoverIbcClient,
             CurrencyPairsChange,
             MarketsChange,
+            RegisterMultisigAccount,
+            ScheduledTransfer,
+            Ics20RateLimitChange,
+            VoteExtensionParticipationChange,
+            Bond,
+            Unbond,
+            ProposalSubmission,
+            ProposalVote,
+            StakingParametersChange,
+            GovernanceParametersChange,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -690,6 +812,16 @@ impl<'de> serde::Deserialize<'de> for GenesisFees {
                             "recoverIbcClient" | "recover_ibc_client" => Ok(GeneratedField::RecoverIbcClient),
                             "currencyPairsChange" | "currency_pairs_change" => Ok(GeneratedField::CurrencyPairsChange),
                             "marketsChange" | "markets_change" => Ok(GeneratedField::MarketsChange),
+                            "registerMultisigAccount" | "register_multisig_account" => Ok(GeneratedField::RegisterMultisigAccount),
+                            "scheduledTransfer" | "scheduled_transfer" => Ok(GeneratedField::ScheduledTransfer),
+                            "ics20RateLimitChange" | "ics20_rate_limit_change" => Ok(GeneratedField::Ics20RateLimitChange),
+                            "voteExtensionParticipationChange" | "vote_extension_participation_change" => Ok(GeneratedField::VoteExtensionParticipationChange),
+                            "bond" => Ok(GeneratedField::Bond),
+                            "unbond" => Ok(GeneratedField::Unbond),
+                            "proposalSubmission" | "proposal_submission" => Ok(GeneratedField::ProposalSubmission),
+                            "proposalVote" | "proposal_vote" => Ok(GeneratedField::ProposalVote),
+                            "stakingParametersChange" | "staking_parameters_change" => Ok(GeneratedField::StakingParametersChange),
+                            "governanceParametersChange" | "governance_parameters_change" => Ok(GeneratedField::GovernanceParametersChange),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -727,6 +859,16 @@ impl<'de> serde::Deserialize<'de> for GenesisFees {
                 let mut recover_ibc_client__ = None;
                 let mut currency_pairs_change__ = None;
                 let mut markets_change__ = None;
+                let mut register_multisig_account__ = None;
+                let mut scheduled_transfer__ = None;
+                let mut ics20_rate_limit_change__ = None;
+                let mut vote_extension_participation_change__ = None;
+                let mut bond__ = None;
+                let mut unbond__ = None;
+                let mut proposal_submission__ = None;
+                let mut proposal_vote__ = None;
+                let mut staking_parameters_change__ = None;
+                let mut governance_parameters_change__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::BridgeLock => {
@@ -837,6 +979,66 @@ impl<'de> serde::Deserialize<'de> for GenesisFees {
                             }
                             markets_change__ = map_.next_value()?;
                         }
+                        GeneratedField::RegisterMultisigAccount => {
+                            if register_multisig_account__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("registerMultisigAccount"));
+                            }
+                            register_multisig_account__ = map_.next_value()?;
+                        }
+                        GeneratedField::ScheduledTransfer => {
+                            if scheduled_transfer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledTransfer"));
+                            }
+                            scheduled_transfer__ = map_.next_value()?;
+                        }
+                        GeneratedField::Ics20RateLimitChange => {
+                            if ics20_rate_limit_change__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ics20RateLimitChange"));
+                            }
+                            ics20_rate_limit_change__ = map_.next_value()?;
+                        }
+                        GeneratedField::VoteExtensionParticipationChange => {
+                            if vote_extension_participation_change__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("voteExtensionParticipationChange"));
+                            }
+                            vote_extension_participation_change__ = map_.next_value()?;
+                        }
+                        GeneratedField::Bond => {
+                            if bond__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bond"));
+                            }
+                            bond__ = map_.next_value()?;
+                        }
+                        GeneratedField::Unbond => {
+                            if unbond__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbond"));
+                            }
+                            unbond__ = map_.next_value()?;
+                        }
+                        GeneratedField::ProposalSubmission => {
+                            if proposal_submission__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalSubmission"));
+                            }
+                            proposal_submission__ = map_.next_value()?;
+                        }
+                        GeneratedField::ProposalVote => {
+                            if proposal_vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalVote"));
+                            }
+                            proposal_vote__ = map_.next_value()?;
+                        }
+                        GeneratedField::StakingParametersChange => {
+                            if staking_parameters_change__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stakingParametersChange"));
+                            }
+                            staking_parameters_change__ = map_.next_value()?;
+                        }
+                        GeneratedField::GovernanceParametersChange => {
+                            if governance_parameters_change__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("governanceParametersChange"));
+                            }
+                            governance_parameters_change__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(GenesisFees {
@@ -858,12 +1060,136 @@ impl<'de> serde::Deserialize<'de> for GenesisFees {
                     recover_ibc_client: recover_ibc_client__,
                     currency_pairs_change: currency_pairs_change__,
                     markets_change: markets_change__,
+                    register_multisig_account: register_multisig_account__,
+                    scheduled_transfer: scheduled_transfer__,
+                    ics20_rate_limit_change: ics20_rate_limit_change__,
+                    vote_extension_participation_change: vote_extension_participation_change__,
+                    bond: bond__,
+                    unbond: unbond__,
+                    proposal_submission: proposal_submission__,
+                    proposal_vote: proposal_vote__,
+                    staking_parameters_change: staking_parameters_change__,
+                    governance_parameters_change: governance_parameters_change__,
                 })
             }
         }
         deserializer.deserialize_struct("astria.protocol.genesis.v1.GenesisFees", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for GovernanceParameters {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.voting_period_blocks != 0 {
+            len += 1;
+        }
+        if self.proposal_deposit.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.genesis.v1.GovernanceParameters", len)?;
+        if self.voting_period_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("votingPeriodBlocks", ToString::to_string(&self.voting_period_blocks).as_str())?;
+        }
+        if let Some(v) = self.proposal_deposit.as_ref() {
+            struct_ser.serialize_field("proposalDeposit", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GovernanceParameters {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "voting_period_blocks",
+            "votingPeriodBlocks",
+            "proposal_deposit",
+            "proposalDeposit",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            VotingPeriodBlocks,
+            ProposalDeposit,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "votingPeriodBlocks" | "voting_period_blocks" => Ok(GeneratedField::VotingPeriodBlocks),
+                            "proposalDeposit" | "proposal_deposit" => Ok(GeneratedField::ProposalDeposit),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GovernanceParameters;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.genesis.v1.GovernanceParameters")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GovernanceParameters, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut voting_period_blocks__ = None;
+                let mut proposal_deposit__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::VotingPeriodBlocks => {
+                            if voting_period_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votingPeriodBlocks"));
+                            }
+                            voting_period_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ProposalDeposit => {
+                            if proposal_deposit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalDeposit"));
+                            }
+                            proposal_deposit__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(GovernanceParameters {
+                    voting_period_blocks: voting_period_blocks__.unwrap_or_default(),
+                    proposal_deposit: proposal_deposit__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.genesis.v1.GovernanceParameters", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for IbcParameters {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -992,3 +1318,117 @@ impl<'de> serde::Deserialize<'de> for IbcParameters {
         deserializer.deserialize_struct("astria.protocol.genesis.v1.IbcParameters", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for StakingParameters {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.unbonding_period_blocks != 0 {
+            len += 1;
+        }
+        if self.stake_per_power.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.genesis.v1.StakingParameters", len)?;
+        if self.unbonding_period_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("unbondingPeriodBlocks", ToString::to_string(&self.unbonding_period_blocks).as_str())?;
+        }
+        if let Some(v) = self.stake_per_power.as_ref() {
+            struct_ser.serialize_field("stakePerPower", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StakingParameters {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "unbonding_period_blocks",
+            "unbondingPeriodBlocks",
+            "stake_per_power",
+            "stakePerPower",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UnbondingPeriodBlocks,
+            StakePerPower,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "unbondingPeriodBlocks" | "unbonding_period_blocks" => Ok(GeneratedField::UnbondingPeriodBlocks),
+                            "stakePerPower" | "stake_per_power" => Ok(GeneratedField::StakePerPower),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StakingParameters;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.genesis.v1.StakingParameters")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StakingParameters, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut unbonding_period_blocks__ = None;
+                let mut stake_per_power__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::UnbondingPeriodBlocks => {
+                            if unbonding_period_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondingPeriodBlocks"));
+                            }
+                            unbonding_period_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StakePerPower => {
+                            if stake_per_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stakePerPower"));
+                            }
+                            stake_per_power__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(StakingParameters {
+                    unbonding_period_blocks: unbonding_period_blocks__.unwrap_or_default(),
+                    stake_per_power: stake_per_power__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.genesis.v1.StakingParameters", FIELDS, GeneratedVisitor)
+    }
+}
@@ -0,0 +1,56 @@
+// This file is @generated by prost-build.
+/// The rate limit on ICS20 transfers of an asset over a channel, and the
+/// amounts transferred in the current window.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimitInfo {
+    /// the asset the rate limit applies to
+    #[prost(string, tag = "1")]
+    pub asset: ::prost::alloc::string::String,
+    /// the maximum amount received over the channel per window
+    #[prost(message, optional, tag = "2")]
+    pub max_inflow: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    /// the maximum amount sent over the channel per window
+    #[prost(message, optional, tag = "3")]
+    pub max_outflow: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    /// the length of a window in sequencer blocks
+    #[prost(uint64, tag = "4")]
+    pub window_blocks: u64,
+    /// the amount received over the channel in the current window
+    #[prost(message, optional, tag = "5")]
+    pub inflow: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    /// the amount sent over the channel in the current window
+    #[prost(message, optional, tag = "6")]
+    pub outflow: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    /// the height of the earliest sequencer block counted in the current window
+    #[prost(uint64, tag = "7")]
+    pub window_start_height: u64,
+}
+impl ::prost::Name for Ics20RateLimitInfo {
+    const NAME: &'static str = "Ics20RateLimitInfo";
+    const PACKAGE: &'static str = "astria.protocol.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.ibc.v1.Ics20RateLimitInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.ibc.v1.Ics20RateLimitInfo".into()
+    }
+}
+/// A response to the `ibc/rate_limits/:channel` ABCI query containing the
+/// rate limits on ICS20 transfers over the channel.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimitsResponse {
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    #[prost(message, repeated, tag = "3")]
+    pub rate_limits: ::prost::alloc::vec::Vec<Ics20RateLimitInfo>,
+}
+impl ::prost::Name for Ics20RateLimitsResponse {
+    const NAME: &'static str = "Ics20RateLimitsResponse";
+    const PACKAGE: &'static str = "astria.protocol.ibc.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.ibc.v1.Ics20RateLimitsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.ibc.v1.Ics20RateLimitsResponse".into()
+    }
+}
@@ -0,0 +1,318 @@
+impl serde::Serialize for Ics20RateLimitInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if self.max_inflow.is_some() {
+            len += 1;
+        }
+        if self.max_outflow.is_some() {
+            len += 1;
+        }
+        if self.window_blocks != 0 {
+            len += 1;
+        }
+        if self.inflow.is_some() {
+            len += 1;
+        }
+        if self.outflow.is_some() {
+            len += 1;
+        }
+        if self.window_start_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.ibc.v1.Ics20RateLimitInfo", len)?;
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if let Some(v) = self.max_inflow.as_ref() {
+            struct_ser.serialize_field("maxInflow", v)?;
+        }
+        if let Some(v) = self.max_outflow.as_ref() {
+            struct_ser.serialize_field("maxOutflow", v)?;
+        }
+        if self.window_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("windowBlocks", ToString::to_string(&self.window_blocks).as_str())?;
+        }
+        if let Some(v) = self.inflow.as_ref() {
+            struct_ser.serialize_field("inflow", v)?;
+        }
+        if let Some(v) = self.outflow.as_ref() {
+            struct_ser.serialize_field("outflow", v)?;
+        }
+        if self.window_start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("windowStartHeight", ToString::to_string(&self.window_start_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20RateLimitInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset",
+            "max_inflow",
+            "maxInflow",
+            "max_outflow",
+            "maxOutflow",
+            "window_blocks",
+            "windowBlocks",
+            "inflow",
+            "outflow",
+            "window_start_height",
+            "windowStartHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Asset,
+            MaxInflow,
+            MaxOutflow,
+            WindowBlocks,
+            Inflow,
+            Outflow,
+            WindowStartHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "asset" => Ok(GeneratedField::Asset),
+                            "maxInflow" | "max_inflow" => Ok(GeneratedField::MaxInflow),
+                            "maxOutflow" | "max_outflow" => Ok(GeneratedField::MaxOutflow),
+                            "windowBlocks" | "window_blocks" => Ok(GeneratedField::WindowBlocks),
+                            "inflow" => Ok(GeneratedField::Inflow),
+                            "outflow" => Ok(GeneratedField::Outflow),
+                            "windowStartHeight" | "window_start_height" => Ok(GeneratedField::WindowStartHeight),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20RateLimitInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.ibc.v1.Ics20RateLimitInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20RateLimitInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset__ = None;
+                let mut max_inflow__ = None;
+                let mut max_outflow__ = None;
+                let mut window_blocks__ = None;
+                let mut inflow__ = None;
+                let mut outflow__ = None;
+                let mut window_start_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MaxInflow => {
+                            if max_inflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxInflow"));
+                            }
+                            max_inflow__ = map_.next_value()?;
+                        }
+                        GeneratedField::MaxOutflow => {
+                            if max_outflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxOutflow"));
+                            }
+                            max_outflow__ = map_.next_value()?;
+                        }
+                        GeneratedField::WindowBlocks => {
+                            if window_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("windowBlocks"));
+                            }
+                            window_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Inflow => {
+                            if inflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inflow"));
+                            }
+                            inflow__ = map_.next_value()?;
+                        }
+                        GeneratedField::Outflow => {
+                            if outflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outflow"));
+                            }
+                            outflow__ = map_.next_value()?;
+                        }
+                        GeneratedField::WindowStartHeight => {
+                            if window_start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("windowStartHeight"));
+                            }
+                            window_start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(Ics20RateLimitInfo {
+                    asset: asset__.unwrap_or_default(),
+                    max_inflow: max_inflow__,
+                    max_outflow: max_outflow__,
+                    window_blocks: window_blocks__.unwrap_or_default(),
+                    inflow: inflow__,
+                    outflow: outflow__,
+                    window_start_height: window_start_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.ibc.v1.Ics20RateLimitInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Ics20RateLimitsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if !self.rate_limits.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.ibc.v1.Ics20RateLimitsResponse", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if !self.rate_limits.is_empty() {
+            struct_ser.serialize_field("rateLimits", &self.rate_limits)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20RateLimitsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "rate_limits",
+            "rateLimits",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            RateLimits,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "rateLimits" | "rate_limits" => Ok(GeneratedField::RateLimits),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20RateLimitsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.ibc.v1.Ics20RateLimitsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20RateLimitsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut rate_limits__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RateLimits => {
+                            if rate_limits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateLimits"));
+                            }
+                            rate_limits__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Ics20RateLimitsResponse {
+                    height: height__.unwrap_or_default(),
+                    rate_limits: rate_limits__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.ibc.v1.Ics20RateLimitsResponse", FIELDS, GeneratedVisitor)
+    }
+}
@@ -0,0 +1,66 @@
+// This file is @generated by prost-build.
+/// The native asset bonded by a delegator to a validator.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Delegation {
+    /// the ed25519 verification key of the validator
+    #[prost(bytes = "bytes", tag = "1")]
+    pub validator: ::prost::bytes::Bytes,
+    /// the amount of the native asset bonded to the validator
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for Delegation {
+    const NAME: &'static str = "Delegation";
+    const PACKAGE: &'static str = "astria.protocol.staking.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.staking.v1.Delegation".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.staking.v1.Delegation".into()
+    }
+}
+/// A response to the `staking/delegations/:account` ABCI query containing the
+/// delegations of the account.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationsResponse {
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    #[prost(message, repeated, tag = "3")]
+    pub delegations: ::prost::alloc::vec::Vec<Delegation>,
+}
+impl ::prost::Name for DelegationsResponse {
+    const NAME: &'static str = "DelegationsResponse";
+    const PACKAGE: &'static str = "astria.protocol.staking.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.staking.v1.DelegationsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.staking.v1.DelegationsResponse".into()
+    }
+}
+/// A response to the `staking/validator/:address` ABCI query containing the
+/// total stake bonded to the validator and the power derived from it.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorStakeResponse {
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    /// the ed25519 verification key of the validator
+    #[prost(bytes = "bytes", tag = "3")]
+    pub validator: ::prost::bytes::Bytes,
+    /// the total amount of the native asset bonded to the validator
+    #[prost(message, optional, tag = "4")]
+    pub stake: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    /// the validator power derived from the stake
+    #[prost(uint32, tag = "5")]
+    pub power: u32,
+}
+impl ::prost::Name for ValidatorStakeResponse {
+    const NAME: &'static str = "ValidatorStakeResponse";
+    const PACKAGE: &'static str = "astria.protocol.staking.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.staking.v1.ValidatorStakeResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.staking.v1.ValidatorStakeResponse".into()
+    }
+}
@@ -3,7 +3,7 @@
 pub struct Action {
     #[prost(
         oneof = "action::Value",
-        tags = "1, 2, 11, 12, 13, 14, 15, 21, 22, 50, 51, 52, 53, 55, 56, 57, 71, 72"
+        tags = "1, 2, 3, 4, 5, 6, 11, 12, 13, 14, 15, 21, 22, 50, 51, 52, 53, 55, 56, 57, 58, 59, 60, 61, 71, 72, 81, 82"
     )]
     pub value: ::core::option::Option<action::Value>,
 }
@@ -16,6 +16,14 @@ pub mod action {
         Transfer(super::Transfer),
         #[prost(message, tag = "2")]
         RollupDataSubmission(super::RollupDataSubmission),
+        #[prost(message, tag = "3")]
+        RegisterMultisigAccount(super::RegisterMultisigAccount),
+        #[prost(message, tag = "4")]
+        ScheduledTransfer(super::ScheduledTransfer),
+        #[prost(message, tag = "5")]
+        Bond(super::Bond),
+        #[prost(message, tag = "6")]
+        Unbond(super::Unbond),
         /// Bridge actions are defined on 11-20
         #[prost(message, tag = "11")]
         InitBridgeAccount(super::InitBridgeAccount),
@@ -47,11 +55,24 @@ pub mod action {
         IbcSudoChange(super::IbcSudoChange),
         #[prost(message, tag = "57")]
         RecoverIbcClient(super::RecoverIbcClient),
+        #[prost(message, tag = "58")]
+        Ics20RateLimitChange(super::Ics20RateLimitChange),
+        #[prost(message, tag = "59")]
+        VoteExtensionParticipationChange(super::VoteExtensionParticipationChange),
+        #[prost(message, tag = "60")]
+        StakingParametersChange(super::StakingParametersChange),
+        #[prost(message, tag = "61")]
+        GovernanceParametersChange(super::GovernanceParametersChange),
         /// Price feed actions are defined on 71-80
         #[prost(message, tag = "71")]
         CurrencyPairsChange(super::CurrencyPairsChange),
         #[prost(message, tag = "72")]
         MarketsChange(super::MarketsChange),
+        /// Governance actions are defined on 81-90
+        #[prost(message, tag = "81")]
+        ProposalSubmission(super::ProposalSubmission),
+        #[prost(message, tag = "82")]
+        ProposalVote(super::ProposalVote),
     }
 }
 impl ::prost::Name for Action {
@@ -64,6 +85,33 @@ impl ::prost::Name for Action {
         "/astria.protocol.transaction.v1.Action".into()
     }
 }
+/// `RegisterMultisigAccount` turns the account of the transaction signer into a
+/// multisig account. Transactions sent on behalf of a multisig account must be
+/// signed by at least `threshold` of its `verification_keys`.
+///
+/// If the account already is a multisig account, its key set is replaced.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RegisterMultisigAccount {
+    /// the ed25519 verification keys of the account's signers
+    #[prost(bytes = "bytes", repeated, tag = "1")]
+    pub verification_keys: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
+    /// the number of distinct signers required to authorize a transaction
+    #[prost(uint32, tag = "2")]
+    pub threshold: u32,
+    /// the asset used to pay the transaction fee
+    #[prost(string, tag = "3")]
+    pub fee_asset: ::prost::alloc::string::String,
+}
+impl ::prost::Name for RegisterMultisigAccount {
+    const NAME: &'static str = "RegisterMultisigAccount";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.RegisterMultisigAccount".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.RegisterMultisigAccount".into()
+    }
+}
 /// `TransferAction` represents a value transfer transaction.
 ///
 /// Note: all values must be set (ie. not `None`), otherwise it will
@@ -91,6 +139,170 @@ impl ::prost::Name for Transfer {
         "/astria.protocol.transaction.v1.Transfer".into()
     }
 }
+/// `ScheduledTransfer` moves funds out of the signer's account into an escrow
+/// which is released to `to` at the end of the first sequencer block at or after
+/// the given release point.
+///
+/// Note: all values must be set (ie. not `None`), otherwise it will
+/// be considered invalid by the sequencer.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScheduledTransfer {
+    #[prost(message, optional, tag = "1")]
+    pub to: ::core::option::Option<super::super::super::primitive::v1::Address>,
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    /// the asset to be transferred
+    #[prost(string, tag = "3")]
+    pub asset: ::prost::alloc::string::String,
+    /// the asset used to pay the transaction fee
+    #[prost(string, tag = "4")]
+    pub fee_asset: ::prost::alloc::string::String,
+    /// the point at which the escrowed funds are released
+    #[prost(oneof = "scheduled_transfer::ReleaseAt", tags = "5, 6")]
+    pub release_at: ::core::option::Option<scheduled_transfer::ReleaseAt>,
+}
+/// Nested message and enum types in `ScheduledTransfer`.
+pub mod scheduled_transfer {
+    /// the point at which the escrowed funds are released
+    #[derive(Clone, Copy, PartialEq, ::prost::Oneof)]
+    pub enum ReleaseAt {
+        /// the sequencer block height
+        #[prost(uint64, tag = "5")]
+        Height(u64),
+        /// the sequencer block timestamp, in seconds since the unix epoch
+        #[prost(uint64, tag = "6")]
+        UnixTimestampSeconds(u64),
+    }
+}
+impl ::prost::Name for ScheduledTransfer {
+    const NAME: &'static str = "ScheduledTransfer";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.ScheduledTransfer".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.ScheduledTransfer".into()
+    }
+}
+/// `Bond` moves `amount` of the native asset out of the signer's account and
+/// bonds it to the validator identified by `validator`. The validator's power is
+/// recomputed from its total bonded stake at the end of the block.
+///
+/// Stake can only be bonded to a validator not yet in the validator set by the
+/// validator itself, ie. if the signer's address is derived from `validator`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Bond {
+    /// the ed25519 verification key of the validator
+    #[prost(bytes = "bytes", tag = "1")]
+    pub validator: ::prost::bytes::Bytes,
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    /// the asset used to pay the transaction fee
+    #[prost(string, tag = "3")]
+    pub fee_asset: ::prost::alloc::string::String,
+}
+impl ::prost::Name for Bond {
+    const NAME: &'static str = "Bond";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.Bond".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.Bond".into()
+    }
+}
+/// `Unbond` removes `amount` of the native asset previously bonded by the
+/// signer from the validator identified by `validator`. The validator's power is
+/// recomputed at the end of the block, while the unbonded funds are held in an
+/// escrow and returned to the signer once the unbonding period has passed.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Unbond {
+    /// the ed25519 verification key of the validator
+    #[prost(bytes = "bytes", tag = "1")]
+    pub validator: ::prost::bytes::Bytes,
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    /// the asset used to pay the transaction fee
+    #[prost(string, tag = "3")]
+    pub fee_asset: ::prost::alloc::string::String,
+}
+impl ::prost::Name for Unbond {
+    const NAME: &'static str = "Unbond";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.Unbond".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.Unbond".into()
+    }
+}
+/// `ProposalSubmission` submits a governance proposal to execute `action`.
+///
+/// Validators vote on the proposal within the voting period configured at
+/// genesis. If validators holding more than two thirds of the total validator
+/// power approve it, the action is executed at the end of the block in which
+/// the voting period ends, with the authority otherwise held by the sudo
+/// address (or the IBC sudo address for `IbcRelayerChange`).
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalSubmission {
+    /// the asset used to pay the transaction fee
+    #[prost(string, tag = "10")]
+    pub fee_asset: ::prost::alloc::string::String,
+    /// the privileged action to execute if the proposal passes
+    #[prost(oneof = "proposal_submission::Action", tags = "1, 2, 3, 4, 5")]
+    pub action: ::core::option::Option<proposal_submission::Action>,
+}
+/// Nested message and enum types in `ProposalSubmission`.
+pub mod proposal_submission {
+    /// the privileged action to execute if the proposal passes
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Action {
+        #[prost(message, tag = "1")]
+        FeeChange(super::FeeChange),
+        #[prost(message, tag = "2")]
+        FeeAssetChange(super::FeeAssetChange),
+        #[prost(message, tag = "3")]
+        IbcRelayerChange(super::IbcRelayerChange),
+        #[prost(message, tag = "4")]
+        MarketsChange(super::MarketsChange),
+        #[prost(message, tag = "5")]
+        SudoAddressChange(super::SudoAddressChange),
+    }
+}
+impl ::prost::Name for ProposalSubmission {
+    const NAME: &'static str = "ProposalSubmission";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.ProposalSubmission".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.ProposalSubmission".into()
+    }
+}
+/// `ProposalVote` records the vote of the signing validator on the proposal
+/// with ID `proposal_id`. A validator may change its vote until the voting
+/// period ends.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalVote {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    /// whether the validator approves of the proposal
+    #[prost(bool, tag = "2")]
+    pub approve: bool,
+    /// the asset used to pay the transaction fee
+    #[prost(string, tag = "3")]
+    pub fee_asset: ::prost::alloc::string::String,
+}
+impl ::prost::Name for ProposalVote {
+    const NAME: &'static str = "ProposalVote";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.ProposalVote".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.ProposalVote".into()
+    }
+}
 /// `RollupDataSubmission` represents a transaction destined for another
 /// chain, ordered by the sequencer.
 ///
@@ -215,6 +427,54 @@ impl ::prost::Name for IbcHeight {
         "/astria.protocol.transaction.v1.IbcHeight".into()
     }
 }
+/// `Ics20RateLimitChange` sets or removes the rate limit on ICS20 transfers
+/// of `asset` over `channel`. Can only be submitted by the IBC sudo address.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimitChange {
+    /// the channel on this chain the rate limit applies to
+    #[prost(string, tag = "1")]
+    pub channel: ::prost::alloc::string::String,
+    /// the asset the rate limit applies to
+    #[prost(string, tag = "2")]
+    pub asset: ::prost::alloc::string::String,
+    /// the new rate limit. if unset, the existing rate limit is removed.
+    #[prost(message, optional, tag = "3")]
+    pub rate_limit: ::core::option::Option<Ics20RateLimit>,
+}
+impl ::prost::Name for Ics20RateLimitChange {
+    const NAME: &'static str = "Ics20RateLimitChange";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.Ics20RateLimitChange".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.Ics20RateLimitChange".into()
+    }
+}
+/// The maximum amounts of an asset that can be transferred over an IBC channel
+/// within any window of consecutive sequencer blocks.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimit {
+    /// the maximum amount received over the channel per window
+    #[prost(message, optional, tag = "1")]
+    pub max_inflow: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    /// the maximum amount sent over the channel per window
+    #[prost(message, optional, tag = "2")]
+    pub max_outflow: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    /// the length of a window in sequencer blocks. must be non-zero.
+    #[prost(uint64, tag = "3")]
+    pub window_blocks: u64,
+}
+impl ::prost::Name for Ics20RateLimit {
+    const NAME: &'static str = "Ics20RateLimit";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.Ics20RateLimit".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.Ics20RateLimit".into()
+    }
+}
 /// `IbcRelayerChange` represents a transaction that adds
 /// or removes an IBC relayer address.
 /// The bytes contained in each variant are the address to add or remove.
@@ -492,12 +752,108 @@ impl ::prost::Name for ValidatorUpdate {
         "/astria.protocol.transaction.v1.ValidatorUpdate".into()
     }
 }
+/// `VoteExtensionParticipationChange` sets or removes the requirement that
+/// validators provide price vote extensions. Can only be submitted by the sudo
+/// address.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct VoteExtensionParticipationChange {
+    /// the new requirement. if unset, participation is no longer tracked.
+    #[prost(message, optional, tag = "1")]
+    pub participation: ::core::option::Option<VoteExtensionParticipation>,
+}
+impl ::prost::Name for VoteExtensionParticipationChange {
+    const NAME: &'static str = "VoteExtensionParticipationChange";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.VoteExtensionParticipationChange".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.VoteExtensionParticipationChange".into()
+    }
+}
+/// The required participation of validators in price vote extensions.
+///
+/// A validator that missed more than `max_missed_blocks` vote extensions within
+/// the last `window_blocks` blocks has its power reduced by
+/// `power_reduction_percent` percent.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct VoteExtensionParticipation {
+    /// the number of most recent blocks over which participation is tracked.
+    /// must be non-zero and at most 65536.
+    #[prost(uint64, tag = "1")]
+    pub window_blocks: u64,
+    /// the maximum number of vote extensions a validator may miss within the
+    /// window. must be less than `window_blocks`.
+    #[prost(uint64, tag = "2")]
+    pub max_missed_blocks: u64,
+    /// the percentage by which the power of an absent validator is reduced. must
+    /// be between 1 and 100; 100 removes the validator from the validator set.
+    #[prost(uint32, tag = "3")]
+    pub power_reduction_percent: u32,
+}
+impl ::prost::Name for VoteExtensionParticipation {
+    const NAME: &'static str = "VoteExtensionParticipation";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.VoteExtensionParticipation".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.VoteExtensionParticipation".into()
+    }
+}
+/// `StakingParametersChange` replaces the staking parameters, enabling staking
+/// if it was not enabled. Can only be submitted by the sudo address.
+///
+/// If the stake per unit of power changes, the power of every validator with
+/// bonded stake is recomputed at the end of the block. Stake which is already
+/// unbonding is released at the height set when it was unbonded.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct StakingParametersChange {
+    /// the new staking parameters
+    #[prost(message, optional, tag = "1")]
+    pub parameters: ::core::option::Option<
+        super::super::genesis::v1::StakingParameters,
+    >,
+}
+impl ::prost::Name for StakingParametersChange {
+    const NAME: &'static str = "StakingParametersChange";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.StakingParametersChange".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.StakingParametersChange".into()
+    }
+}
+/// `GovernanceParametersChange` replaces the governance parameters, enabling
+/// governance if it was not enabled. Can only be submitted by the sudo address.
+///
+/// Proposals already submitted keep the voting period and deposit they were
+/// submitted with.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GovernanceParametersChange {
+    /// the new governance parameters
+    #[prost(message, optional, tag = "1")]
+    pub parameters: ::core::option::Option<
+        super::super::genesis::v1::GovernanceParameters,
+    >,
+}
+impl ::prost::Name for GovernanceParametersChange {
+    const NAME: &'static str = "GovernanceParametersChange";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.GovernanceParametersChange".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.GovernanceParametersChange".into()
+    }
+}
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct FeeChange {
     /// the new fee components values
     #[prost(
         oneof = "fee_change::FeeComponents",
-        tags = "1, 2, 3, 4, 5, 7, 6, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18"
+        tags = "1, 2, 3, 4, 5, 7, 6, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28"
     )]
     pub fee_components: ::core::option::Option<fee_change::FeeComponents>,
 }
@@ -546,6 +902,38 @@ pub mod fee_change {
         ),
         #[prost(message, tag = "18")]
         MarketsChange(super::super::super::fees::v1::MarketsChangeFeeComponents),
+        #[prost(message, tag = "19")]
+        RegisterMultisigAccount(
+            super::super::super::fees::v1::RegisterMultisigAccountFeeComponents,
+        ),
+        #[prost(message, tag = "20")]
+        ScheduledTransfer(super::super::super::fees::v1::ScheduledTransferFeeComponents),
+        #[prost(message, tag = "21")]
+        Ics20RateLimitChange(
+            super::super::super::fees::v1::Ics20RateLimitChangeFeeComponents,
+        ),
+        #[prost(message, tag = "22")]
+        VoteExtensionParticipationChange(
+            super::super::super::fees::v1::VoteExtensionParticipationChangeFeeComponents,
+        ),
+        #[prost(message, tag = "23")]
+        Bond(super::super::super::fees::v1::BondFeeComponents),
+        #[prost(message, tag = "24")]
+        Unbond(super::super::super::fees::v1::UnbondFeeComponents),
+        #[prost(message, tag = "25")]
+        ProposalSubmission(
+            super::super::super::fees::v1::ProposalSubmissionFeeComponents,
+        ),
+        #[prost(message, tag = "26")]
+        ProposalVote(super::super::super::fees::v1::ProposalVoteFeeComponents),
+        #[prost(message, tag = "27")]
+        StakingParametersChange(
+            super::super::super::fees::v1::StakingParametersChangeFeeComponents,
+        ),
+        #[prost(message, tag = "28")]
+        GovernanceParametersChange(
+            super::super::super::fees::v1::GovernanceParametersChangeFeeComponents,
+        ),
     }
 }
 impl ::prost::Name for FeeChange {
@@ -702,6 +1090,15 @@ pub struct Transaction {
     pub public_key: ::prost::bytes::Bytes,
     #[prost(message, optional, tag = "3")]
     pub body: ::core::option::Option<::pbjson_types::Any>,
+    /// Further signatures over `body`, which together with `signature` must meet
+    /// the threshold of the multisig account named in the `multisig_address`
+    /// field of `body`.
+    #[prost(message, repeated, tag = "4")]
+    pub cosignatures: ::prost::alloc::vec::Vec<TransactionSignature>,
+    /// The signature of the account named in the `fee_payer` field of `body`.
+    /// Must be set if and only if `body` names a fee payer.
+    #[prost(message, optional, tag = "5")]
+    pub fee_payer_signature: ::core::option::Option<TransactionSignature>,
 }
 impl ::prost::Name for Transaction {
     const NAME: &'static str = "Transaction";
@@ -713,6 +1110,25 @@ impl ::prost::Name for Transaction {
         "/astria.protocol.transaction.v1.Transaction".into()
     }
 }
+/// A signature over a `TransactionBody` together with the public key to verify
+/// it.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionSignature {
+    #[prost(bytes = "bytes", tag = "1")]
+    pub signature: ::prost::bytes::Bytes,
+    #[prost(bytes = "bytes", tag = "2")]
+    pub public_key: ::prost::bytes::Bytes,
+}
+impl ::prost::Name for TransactionSignature {
+    const NAME: &'static str = "TransactionSignature";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.TransactionSignature".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.TransactionSignature".into()
+    }
+}
 /// The `TransactionBody` of the `Transaction` that is being signed over.
 /// It contains transaction `TransactionParams` and `Actions`.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -721,6 +1137,17 @@ pub struct TransactionBody {
     pub params: ::core::option::Option<TransactionParams>,
     #[prost(message, repeated, tag = "2")]
     pub actions: ::prost::alloc::vec::Vec<Action>,
+    /// The account paying the fees of all actions in the transaction. If unset,
+    /// the fees are paid by the account the transaction is sent on behalf of.
+    #[prost(message, optional, tag = "3")]
+    pub fee_payer: ::core::option::Option<super::super::super::primitive::v1::Address>,
+    /// The multisig account the transaction is sent on behalf of. If unset, the
+    /// transaction is sent on behalf of the account of the `public_key` of the
+    /// `Transaction`.
+    #[prost(message, optional, tag = "4")]
+    pub multisig_address: ::core::option::Option<
+        super::super::super::primitive::v1::Address,
+    >,
 }
 impl ::prost::Name for TransactionBody {
     const NAME: &'static str = "TransactionBody";
@@ -18,6 +18,18 @@ impl serde::Serialize for Action {
                 action::Value::RollupDataSubmission(v) => {
                     struct_ser.serialize_field("rollupDataSubmission", v)?;
                 }
+                action::Value::RegisterMultisigAccount(v) => {
+                    struct_ser.serialize_field("registerMultisigAccount", v)?;
+                }
+                action::Value::ScheduledTransfer(v) => {
+                    struct_ser.serialize_field("scheduledTransfer", v)?;
+                }
+                action::Value::Bond(v) => {
+                    struct_ser.serialize_field("bond", v)?;
+                }
+                action::Value::Unbond(v) => {
+                    struct_ser.serialize_field("unbond", v)?;
+                }
                 action::Value::InitBridgeAccount(v) => {
                     struct_ser.serialize_field("initBridgeAccount", v)?;
                 }
@@ -60,12 +72,30 @@ impl serde::Serialize for Action {
                 action::Value::RecoverIbcClient(v) => {
                     struct_ser.serialize_field("recoverIbcClient", v)?;
                 }
+                action::Value::Ics20RateLimitChange(v) => {
+                    struct_ser.serialize_field("ics20RateLimitChange", v)?;
+                }
+                action::Value::VoteExtensionParticipationChange(v) => {
+                    struct_ser.serialize_field("voteExtensionParticipationChange", v)?;
+                }
+                action::Value::StakingParametersChange(v) => {
+                    struct_ser.serialize_field("stakingParametersChange", v)?;
+                }
+                action::Value::GovernanceParametersChange(v) => {
+                    struct_ser.serialize_field("governanceParametersChange", v)?;
+                }
                 action::Value::CurrencyPairsChange(v) => {
                     struct_ser.serialize_field("currencyPairsChange", v)?;
                 }
                 action::Value::MarketsChange(v) => {
                     struct_ser.serialize_field("marketsChange", v)?;
                 }
+                action::Value::ProposalSubmission(v) => {
+                    struct_ser.serialize_field("proposalSubmission", v)?;
+                }
+                action::Value::ProposalVote(v) => {
+                    struct_ser.serialize_field("proposalVote", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -81,6 +111,12 @@ impl<'de> serde::Deserialize<'de> for Action {
             "transfer",
             "rollup_data_submission",
             "rollupDataSubmission",
+            "register_multisig_account",
+            "registerMultisigAccount",
+            "scheduled_transfer",
+            "scheduledTransfer",
+            "bond",
+            "unbond",
             "init_bridge_account",
             "initBridgeAccount",
             "bridge_lock",
@@ -108,16 +144,32 @@ impl<'de> serde::Deserialize<'de> for Action {
             "ibcSudoChange",
             "recover_ibc_client",
             "recoverIbcClient",
+            "ics20_rate_limit_change",
+            "ics20RateLimitChange",
+            "vote_extension_participation_change",
+            "voteExtensionParticipationChange",
+            "staking_parameters_change",
+            "stakingParametersChange",
+            "governance_parameters_change",
+            "governanceParametersChange",
             "currency_pairs_change",
             "currencyPairsChange",
             "markets_change",
             "marketsChange",
+            "proposal_submission",
+            "proposalSubmission",
+            "proposal_vote",
+            "proposalVote",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Transfer,
             RollupDataSubmission,
+            RegisterMultisigAccount,
+            ScheduledTransfer,
+            Bond,
+            Unbond,
             InitBridgeAccount,
             BridgeLock,
             BridgeUnlock,
@@ -132,8 +184,14 @@ impl<'de> serde::Deserialize<'de> for Action {
             FeeChange,
             IbcSudoChange,
             RecoverIbcClient,
+            Ics20RateLimitChange,
+            VoteExtensionParticipationChange,
+            StakingParametersChange,
+            GovernanceParametersChange,
             CurrencyPairsChange,
             MarketsChange,
+            ProposalSubmission,
+            ProposalVote,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -157,6 +215,10 @@ impl<'de> serde::Deserialize<'de> for Action {
                         match value {
                             "transfer" => Ok(GeneratedField::Transfer),
                             "rollupDataSubmission" | "rollup_data_submission" => Ok(GeneratedField::RollupDataSubmission),
+                            "registerMultisigAccount" | "register_multisig_account" => Ok(GeneratedField::RegisterMultisigAccount),
+                            "scheduledTransfer" | "scheduled_transfer" => Ok(GeneratedField::ScheduledTransfer),
+                            "bond" => Ok(GeneratedField::Bond),
+                            "unbond" => Ok(GeneratedField::Unbond),
                             "initBridgeAccount" | "init_bridge_account" => Ok(GeneratedField::InitBridgeAccount),
                             "bridgeLock" | "bridge_lock" => Ok(GeneratedField::BridgeLock),
                             "bridgeUnlock" | "bridge_unlock" => Ok(GeneratedField::BridgeUnlock),
@@ -171,8 +233,14 @@ impl<'de> serde::Deserialize<'de> for Action {
                             "feeChange" | "fee_change" => Ok(GeneratedField::FeeChange),
                             "ibcSudoChange" | "ibc_sudo_change" => Ok(GeneratedField::IbcSudoChange),
                             "recoverIbcClient" | "recover_ibc_client" => Ok(GeneratedField::RecoverIbcClient),
+                            "ics20RateLimitChange" | "ics20_rate_limit_change" => Ok(GeneratedField::Ics20RateLimitChange),
+                            "voteExtensionParticipationChange" | "vote_extension_participation_change" => Ok(GeneratedField::VoteExtensionParticipationChange),
+                            "stakingParametersChange" | "staking_parameters_change" => Ok(GeneratedField::StakingParametersChange),
+                            "governanceParametersChange" | "governance_parameters_change" => Ok(GeneratedField::GovernanceParametersChange),
                             "currencyPairsChange" | "currency_pairs_change" => Ok(GeneratedField::CurrencyPairsChange),
                             "marketsChange" | "markets_change" => Ok(GeneratedField::MarketsChange),
+                            "proposalSubmission" | "proposal_submission" => Ok(GeneratedField::ProposalSubmission),
+                            "proposalVote" | "proposal_vote" => Ok(GeneratedField::ProposalVote),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -207,6 +275,34 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("rollupDataSubmission"));
                             }
                             value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::RollupDataSubmission)
+;
+                        }
+                        GeneratedField::RegisterMultisigAccount => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("registerMultisigAccount"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::RegisterMultisigAccount)
+;
+                        }
+                        GeneratedField::ScheduledTransfer => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledTransfer"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::ScheduledTransfer)
+;
+                        }
+                        GeneratedField::Bond => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bond"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::Bond)
+;
+                        }
+                        GeneratedField::Unbond => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbond"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::Unbond)
 ;
                         }
                         GeneratedField::InitBridgeAccount => {
@@ -305,6 +401,34 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("recoverIbcClient"));
                             }
                             value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::RecoverIbcClient)
+;
+                        }
+                        GeneratedField::Ics20RateLimitChange => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ics20RateLimitChange"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::Ics20RateLimitChange)
+;
+                        }
+                        GeneratedField::VoteExtensionParticipationChange => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("voteExtensionParticipationChange"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::VoteExtensionParticipationChange)
+;
+                        }
+                        GeneratedField::StakingParametersChange => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stakingParametersChange"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::StakingParametersChange)
+;
+                        }
+                        GeneratedField::GovernanceParametersChange => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("governanceParametersChange"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::GovernanceParametersChange)
 ;
                         }
                         GeneratedField::CurrencyPairsChange => {
@@ -319,6 +443,20 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("marketsChange"));
                             }
                             value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::MarketsChange)
+;
+                        }
+                        GeneratedField::ProposalSubmission => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalSubmission"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::ProposalSubmission)
+;
+                        }
+                        GeneratedField::ProposalVote => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalVote"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::ProposalVote)
 ;
                         }
                     }
@@ -331,6 +469,136 @@ impl<'de> serde::Deserialize<'de> for Action {
         deserializer.deserialize_struct("astria.protocol.transaction.v1.Action", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for Bond {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.validator.is_empty() {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if !self.fee_asset.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.Bond", len)?;
+        if !self.validator.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("validator", pbjson::private::base64::encode(&self.validator).as_str())?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Bond {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "validator",
+            "amount",
+            "fee_asset",
+            "feeAsset",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Validator,
+            Amount,
+            FeeAsset,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "validator" => Ok(GeneratedField::Validator),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Bond;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.Bond")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Bond, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut validator__ = None;
+                let mut amount__ = None;
+                let mut fee_asset__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Validator => {
+                            if validator__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validator"));
+                            }
+                            validator__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                            }
+                            fee_asset__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Bond {
+                    validator: validator__.unwrap_or_default(),
+                    amount: amount__,
+                    fee_asset: fee_asset__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.Bond", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for BridgeLock {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1416,6 +1684,36 @@ impl serde::Serialize for FeeChange {
                 fee_change::FeeComponents::MarketsChange(v) => {
                     struct_ser.serialize_field("marketsChange", v)?;
                 }
+                fee_change::FeeComponents::RegisterMultisigAccount(v) => {
+                    struct_ser.serialize_field("registerMultisigAccount", v)?;
+                }
+                fee_change::FeeComponents::ScheduledTransfer(v) => {
+                    struct_ser.serialize_field("scheduledTransfer", v)?;
+                }
+                fee_change::FeeComponents::Ics20RateLimitChange(v) => {
+                    struct_ser.serialize_field("ics20RateLimitChange", v)?;
+                }
+                fee_change::FeeComponents::VoteExtensionParticipationChange(v) => {
+                    struct_ser.serialize_field("voteExtensionParticipationChange", v)?;
+                }
+                fee_change::FeeComponents::Bond(v) => {
+                    struct_ser.serialize_field("bond", v)?;
+                }
+                fee_change::FeeComponents::Unbond(v) => {
+                    struct_ser.serialize_field("unbond", v)?;
+                }
+                fee_change::FeeComponents::ProposalSubmission(v) => {
+                    struct_ser.serialize_field("proposalSubmission", v)?;
+                }
+                fee_change::FeeComponents::ProposalVote(v) => {
+                    struct_ser.serialize_field("proposalVote", v)?;
+                }
+                fee_change::FeeComponents::StakingParametersChange(v) => {
+                    struct_ser.serialize_field("stakingParametersChange", v)?;
+                }
+                fee_change::FeeComponents::GovernanceParametersChange(v) => {
+                    struct_ser.serialize_field("governanceParametersChange", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -1463,6 +1761,24 @@ impl<'de> serde::Deserialize<'de> for FeeChange {
             "currencyPairsChange",
             "markets_change",
             "marketsChange",
+            "register_multisig_account",
+            "registerMultisigAccount",
+            "scheduled_transfer",
+            "scheduledTransfer",
+            "ics20_rate_limit_change",
+            "ics20RateLimitChange",
+            "vote_extension_participation_change",
+            "voteExtensionParticipationChange",
+            "bond",
+            "unbond",
+            "proposal_submission",
+            "proposalSubmission",
+            "proposal_vote",
+            "proposalVote",
+            "staking_parameters_change",
+            "stakingParametersChange",
+            "governance_parameters_change",
+            "governanceParametersChange",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1485,6 +1801,16 @@ impl<'de> serde::Deserialize<'de> for FeeChange {
             RecoverIbcClient,
             CurrencyPairsChange,
             MarketsChange,
+            RegisterMultisigAccount,
+            ScheduledTransfer,
+            Ics20RateLimitChange,
+            VoteExtensionParticipationChange,
+            Bond,
+            Unbond,
+            ProposalSubmission,
+            ProposalVote,
+            StakingParametersChange,
+            GovernanceParametersChange,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1524,6 +1850,16 @@ impl<'de> serde::Deserialize<'de> for FeeChange {
                             "recoverIbcClient" | "recover_ibc_client" => Ok(GeneratedField::RecoverIbcClient),
                             "currencyPairsChange" | "currency_pairs_change" => Ok(GeneratedField::CurrencyPairsChange),
                             "marketsChange" | "markets_change" => Ok(GeneratedField::MarketsChange),
+                            "registerMultisigAccount" | "register_multisig_account" => Ok(GeneratedField::RegisterMultisigAccount),
+                            "scheduledTransfer" | "scheduled_transfer" => Ok(GeneratedField::ScheduledTransfer),
+                            "ics20RateLimitChange" | "ics20_rate_limit_change" => Ok(GeneratedField::Ics20RateLimitChange),
+                            "voteExtensionParticipationChange" | "vote_extension_participation_change" => Ok(GeneratedField::VoteExtensionParticipationChange),
+                            "bond" => Ok(GeneratedField::Bond),
+                            "unbond" => Ok(GeneratedField::Unbond),
+                            "proposalSubmission" | "proposal_submission" => Ok(GeneratedField::ProposalSubmission),
+                            "proposalVote" | "proposal_vote" => Ok(GeneratedField::ProposalVote),
+                            "stakingParametersChange" | "staking_parameters_change" => Ok(GeneratedField::StakingParametersChange),
+                            "governanceParametersChange" | "governance_parameters_change" => Ok(GeneratedField::GovernanceParametersChange),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1670,6 +2006,76 @@ impl<'de> serde::Deserialize<'de> for FeeChange {
                                 return Err(serde::de::Error::duplicate_field("marketsChange"));
                             }
                             fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::MarketsChange)
+;
+                        }
+                        GeneratedField::RegisterMultisigAccount => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("registerMultisigAccount"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::RegisterMultisigAccount)
+;
+                        }
+                        GeneratedField::ScheduledTransfer => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledTransfer"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::ScheduledTransfer)
+;
+                        }
+                        GeneratedField::Ics20RateLimitChange => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ics20RateLimitChange"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::Ics20RateLimitChange)
+;
+                        }
+                        GeneratedField::VoteExtensionParticipationChange => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("voteExtensionParticipationChange"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::VoteExtensionParticipationChange)
+;
+                        }
+                        GeneratedField::Bond => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bond"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::Bond)
+;
+                        }
+                        GeneratedField::Unbond => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbond"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::Unbond)
+;
+                        }
+                        GeneratedField::ProposalSubmission => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalSubmission"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::ProposalSubmission)
+;
+                        }
+                        GeneratedField::ProposalVote => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalVote"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::ProposalVote)
+;
+                        }
+                        GeneratedField::StakingParametersChange => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stakingParametersChange"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::StakingParametersChange)
+;
+                        }
+                        GeneratedField::GovernanceParametersChange => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("governanceParametersChange"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::GovernanceParametersChange)
 ;
                         }
                     }
@@ -1682,7 +2088,7 @@ impl<'de> serde::Deserialize<'de> for FeeChange {
         deserializer.deserialize_struct("astria.protocol.transaction.v1.FeeChange", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IbcHeight {
+impl serde::Serialize for GovernanceParametersChange {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1690,43 +2096,29 @@ impl serde::Serialize for IbcHeight {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.revision_number != 0 {
+        if self.parameters.is_some() {
             len += 1;
         }
-        if self.revision_height != 0 {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.IbcHeight", len)?;
-        if self.revision_number != 0 {
-            #[allow(clippy::needless_borrow)]
-            #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("revisionNumber", ToString::to_string(&self.revision_number).as_str())?;
-        }
-        if self.revision_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("revisionHeight", ToString::to_string(&self.revision_height).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.GovernanceParametersChange", len)?;
+        if let Some(v) = self.parameters.as_ref() {
+            struct_ser.serialize_field("parameters", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IbcHeight {
+impl<'de> serde::Deserialize<'de> for GovernanceParametersChange {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "revision_number",
-            "revisionNumber",
-            "revision_height",
-            "revisionHeight",
+            "parameters",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            RevisionNumber,
-            RevisionHeight,
+            Parameters,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1748,8 +2140,7 @@ impl<'de> serde::Deserialize<'de> for IbcHeight {
                         E: serde::de::Error,
                     {
                         match value {
-                            "revisionNumber" | "revision_number" => Ok(GeneratedField::RevisionNumber),
-                            "revisionHeight" | "revision_height" => Ok(GeneratedField::RevisionHeight),
+                            "parameters" => Ok(GeneratedField::Parameters),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1759,41 +2150,147 @@ impl<'de> serde::Deserialize<'de> for IbcHeight {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IbcHeight;
+            type Value = GovernanceParametersChange;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.IbcHeight")
+                formatter.write_str("struct astria.protocol.transaction.v1.GovernanceParametersChange")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcHeight, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GovernanceParametersChange, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut revision_number__ = None;
-                let mut revision_height__ = None;
+                let mut parameters__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::RevisionNumber => {
-                            if revision_number__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("revisionNumber"));
-                            }
-                            revision_number__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::RevisionHeight => {
-                            if revision_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("revisionHeight"));
+                        GeneratedField::Parameters => {
+                            if parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parameters"));
                             }
-                            revision_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            parameters__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(IbcHeight {
-                    revision_number: revision_number__.unwrap_or_default(),
-                    revision_height: revision_height__.unwrap_or_default(),
+                Ok(GovernanceParametersChange {
+                    parameters: parameters__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.GovernanceParametersChange", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IbcHeight {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.revision_number != 0 {
+            len += 1;
+        }
+        if self.revision_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.IbcHeight", len)?;
+        if self.revision_number != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("revisionNumber", ToString::to_string(&self.revision_number).as_str())?;
+        }
+        if self.revision_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("revisionHeight", ToString::to_string(&self.revision_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IbcHeight {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "revision_number",
+            "revisionNumber",
+            "revision_height",
+            "revisionHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RevisionNumber,
+            RevisionHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "revisionNumber" | "revision_number" => Ok(GeneratedField::RevisionNumber),
+                            "revisionHeight" | "revision_height" => Ok(GeneratedField::RevisionHeight),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IbcHeight;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.IbcHeight")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcHeight, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut revision_number__ = None;
+                let mut revision_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RevisionNumber => {
+                            if revision_number__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("revisionNumber"));
+                            }
+                            revision_number__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RevisionHeight => {
+                            if revision_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("revisionHeight"));
+                            }
+                            revision_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(IbcHeight {
+                    revision_number: revision_number__.unwrap_or_default(),
+                    revision_height: revision_height__.unwrap_or_default(),
                 })
             }
         }
@@ -2001,7 +2498,7 @@ impl<'de> serde::Deserialize<'de> for IbcSudoChange {
         deserializer.deserialize_struct("astria.protocol.transaction.v1.IbcSudoChange", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Ics20Withdrawal {
+impl serde::Serialize for Ics20RateLimit {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2009,119 +2506,50 @@ impl serde::Serialize for Ics20Withdrawal {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.amount.is_some() {
-            len += 1;
-        }
-        if !self.denom.is_empty() {
-            len += 1;
-        }
-        if !self.destination_chain_address.is_empty() {
-            len += 1;
-        }
-        if self.return_address.is_some() {
+        if self.max_inflow.is_some() {
             len += 1;
         }
-        if self.timeout_height.is_some() {
-            len += 1;
-        }
-        if self.timeout_time != 0 {
-            len += 1;
-        }
-        if !self.source_channel.is_empty() {
-            len += 1;
-        }
-        if !self.fee_asset.is_empty() {
-            len += 1;
-        }
-        if !self.memo.is_empty() {
-            len += 1;
-        }
-        if self.bridge_address.is_some() {
+        if self.max_outflow.is_some() {
             len += 1;
         }
-        if self.use_compat_address {
+        if self.window_blocks != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.Ics20Withdrawal", len)?;
-        if let Some(v) = self.amount.as_ref() {
-            struct_ser.serialize_field("amount", v)?;
-        }
-        if !self.denom.is_empty() {
-            struct_ser.serialize_field("denom", &self.denom)?;
-        }
-        if !self.destination_chain_address.is_empty() {
-            struct_ser.serialize_field("destinationChainAddress", &self.destination_chain_address)?;
-        }
-        if let Some(v) = self.return_address.as_ref() {
-            struct_ser.serialize_field("returnAddress", v)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.Ics20RateLimit", len)?;
+        if let Some(v) = self.max_inflow.as_ref() {
+            struct_ser.serialize_field("maxInflow", v)?;
         }
-        if let Some(v) = self.timeout_height.as_ref() {
-            struct_ser.serialize_field("timeoutHeight", v)?;
+        if let Some(v) = self.max_outflow.as_ref() {
+            struct_ser.serialize_field("maxOutflow", v)?;
         }
-        if self.timeout_time != 0 {
+        if self.window_blocks != 0 {
             #[allow(clippy::needless_borrow)]
             #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("timeoutTime", ToString::to_string(&self.timeout_time).as_str())?;
-        }
-        if !self.source_channel.is_empty() {
-            struct_ser.serialize_field("sourceChannel", &self.source_channel)?;
-        }
-        if !self.fee_asset.is_empty() {
-            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
-        }
-        if !self.memo.is_empty() {
-            struct_ser.serialize_field("memo", &self.memo)?;
-        }
-        if let Some(v) = self.bridge_address.as_ref() {
-            struct_ser.serialize_field("bridgeAddress", v)?;
-        }
-        if self.use_compat_address {
-            struct_ser.serialize_field("useCompatAddress", &self.use_compat_address)?;
+            struct_ser.serialize_field("windowBlocks", ToString::to_string(&self.window_blocks).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
+impl<'de> serde::Deserialize<'de> for Ics20RateLimit {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "amount",
-            "denom",
-            "destination_chain_address",
-            "destinationChainAddress",
-            "return_address",
-            "returnAddress",
-            "timeout_height",
-            "timeoutHeight",
-            "timeout_time",
-            "timeoutTime",
-            "source_channel",
-            "sourceChannel",
-            "fee_asset",
-            "feeAsset",
-            "memo",
-            "bridge_address",
-            "bridgeAddress",
-            "use_compat_address",
-            "useCompatAddress",
+            "max_inflow",
+            "maxInflow",
+            "max_outflow",
+            "maxOutflow",
+            "window_blocks",
+            "windowBlocks",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Amount,
-            Denom,
-            DestinationChainAddress,
-            ReturnAddress,
-            TimeoutHeight,
-            TimeoutTime,
-            SourceChannel,
-            FeeAsset,
-            Memo,
-            BridgeAddress,
-            UseCompatAddress,
+            MaxInflow,
+            MaxOutflow,
+            WindowBlocks,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2143,17 +2571,9 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
                         E: serde::de::Error,
                     {
                         match value {
-                            "amount" => Ok(GeneratedField::Amount),
-                            "denom" => Ok(GeneratedField::Denom),
-                            "destinationChainAddress" | "destination_chain_address" => Ok(GeneratedField::DestinationChainAddress),
-                            "returnAddress" | "return_address" => Ok(GeneratedField::ReturnAddress),
-                            "timeoutHeight" | "timeout_height" => Ok(GeneratedField::TimeoutHeight),
-                            "timeoutTime" | "timeout_time" => Ok(GeneratedField::TimeoutTime),
-                            "sourceChannel" | "source_channel" => Ok(GeneratedField::SourceChannel),
-                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
-                            "memo" => Ok(GeneratedField::Memo),
-                            "bridgeAddress" | "bridge_address" => Ok(GeneratedField::BridgeAddress),
-                            "useCompatAddress" | "use_compat_address" => Ok(GeneratedField::UseCompatAddress),
+                            "maxInflow" | "max_inflow" => Ok(GeneratedField::MaxInflow),
+                            "maxOutflow" | "max_outflow" => Ok(GeneratedField::MaxOutflow),
+                            "windowBlocks" | "window_blocks" => Ok(GeneratedField::WindowBlocks),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2163,72 +2583,1484 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Ics20Withdrawal;
+            type Value = Ics20RateLimit;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.Ics20Withdrawal")
+                formatter.write_str("struct astria.protocol.transaction.v1.Ics20RateLimit")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20Withdrawal, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20RateLimit, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut amount__ = None;
-                let mut denom__ = None;
-                let mut destination_chain_address__ = None;
-                let mut return_address__ = None;
-                let mut timeout_height__ = None;
-                let mut timeout_time__ = None;
-                let mut source_channel__ = None;
-                let mut fee_asset__ = None;
-                let mut memo__ = None;
-                let mut bridge_address__ = None;
-                let mut use_compat_address__ = None;
+                let mut max_inflow__ = None;
+                let mut max_outflow__ = None;
+                let mut window_blocks__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Amount => {
-                            if amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("amount"));
-                            }
-                            amount__ = map_.next_value()?;
-                        }
-                        GeneratedField::Denom => {
-                            if denom__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("denom"));
+                        GeneratedField::MaxInflow => {
+                            if max_inflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxInflow"));
                             }
-                            denom__ = Some(map_.next_value()?);
+                            max_inflow__ = map_.next_value()?;
                         }
-                        GeneratedField::DestinationChainAddress => {
-                            if destination_chain_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("destinationChainAddress"));
+                        GeneratedField::MaxOutflow => {
+                            if max_outflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxOutflow"));
                             }
-                            destination_chain_address__ = Some(map_.next_value()?);
+                            max_outflow__ = map_.next_value()?;
                         }
-                        GeneratedField::ReturnAddress => {
-                            if return_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("returnAddress"));
+                        GeneratedField::WindowBlocks => {
+                            if window_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("windowBlocks"));
                             }
-                            return_address__ = map_.next_value()?;
+                            window_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::TimeoutHeight => {
-                            if timeout_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("timeoutHeight"));
-                            }
+                    }
+                }
+                Ok(Ics20RateLimit {
+                    max_inflow: max_inflow__,
+                    max_outflow: max_outflow__,
+                    window_blocks: window_blocks__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.Ics20RateLimit", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Ics20RateLimitChange {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.channel.is_empty() {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if self.rate_limit.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.Ics20RateLimitChange", len)?;
+        if !self.channel.is_empty() {
+            struct_ser.serialize_field("channel", &self.channel)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if let Some(v) = self.rate_limit.as_ref() {
+            struct_ser.serialize_field("rateLimit", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20RateLimitChange {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "channel",
+            "asset",
+            "rate_limit",
+            "rateLimit",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Channel,
+            Asset,
+            RateLimit,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "channel" => Ok(GeneratedField::Channel),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "rateLimit" | "rate_limit" => Ok(GeneratedField::RateLimit),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20RateLimitChange;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.Ics20RateLimitChange")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20RateLimitChange, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut channel__ = None;
+                let mut asset__ = None;
+                let mut rate_limit__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Channel => {
+                            if channel__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("channel"));
+                            }
+                            channel__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RateLimit => {
+                            if rate_limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateLimit"));
+                            }
+                            rate_limit__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(Ics20RateLimitChange {
+                    channel: channel__.unwrap_or_default(),
+                    asset: asset__.unwrap_or_default(),
+                    rate_limit: rate_limit__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.Ics20RateLimitChange", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Ics20Withdrawal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if !self.denom.is_empty() {
+            len += 1;
+        }
+        if !self.destination_chain_address.is_empty() {
+            len += 1;
+        }
+        if self.return_address.is_some() {
+            len += 1;
+        }
+        if self.timeout_height.is_some() {
+            len += 1;
+        }
+        if self.timeout_time != 0 {
+            len += 1;
+        }
+        if !self.source_channel.is_empty() {
+            len += 1;
+        }
+        if !self.fee_asset.is_empty() {
+            len += 1;
+        }
+        if !self.memo.is_empty() {
+            len += 1;
+        }
+        if self.bridge_address.is_some() {
+            len += 1;
+        }
+        if self.use_compat_address {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.Ics20Withdrawal", len)?;
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if !self.denom.is_empty() {
+            struct_ser.serialize_field("denom", &self.denom)?;
+        }
+        if !self.destination_chain_address.is_empty() {
+            struct_ser.serialize_field("destinationChainAddress", &self.destination_chain_address)?;
+        }
+        if let Some(v) = self.return_address.as_ref() {
+            struct_ser.serialize_field("returnAddress", v)?;
+        }
+        if let Some(v) = self.timeout_height.as_ref() {
+            struct_ser.serialize_field("timeoutHeight", v)?;
+        }
+        if self.timeout_time != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("timeoutTime", ToString::to_string(&self.timeout_time).as_str())?;
+        }
+        if !self.source_channel.is_empty() {
+            struct_ser.serialize_field("sourceChannel", &self.source_channel)?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+        }
+        if !self.memo.is_empty() {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
+        if let Some(v) = self.bridge_address.as_ref() {
+            struct_ser.serialize_field("bridgeAddress", v)?;
+        }
+        if self.use_compat_address {
+            struct_ser.serialize_field("useCompatAddress", &self.use_compat_address)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "amount",
+            "denom",
+            "destination_chain_address",
+            "destinationChainAddress",
+            "return_address",
+            "returnAddress",
+            "timeout_height",
+            "timeoutHeight",
+            "timeout_time",
+            "timeoutTime",
+            "source_channel",
+            "sourceChannel",
+            "fee_asset",
+            "feeAsset",
+            "memo",
+            "bridge_address",
+            "bridgeAddress",
+            "use_compat_address",
+            "useCompatAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Amount,
+            Denom,
+            DestinationChainAddress,
+            ReturnAddress,
+            TimeoutHeight,
+            TimeoutTime,
+            SourceChannel,
+            FeeAsset,
+            Memo,
+            BridgeAddress,
+            UseCompatAddress,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "amount" => Ok(GeneratedField::Amount),
+                            "denom" => Ok(GeneratedField::Denom),
+                            "destinationChainAddress" | "destination_chain_address" => Ok(GeneratedField::DestinationChainAddress),
+                            "returnAddress" | "return_address" => Ok(GeneratedField::ReturnAddress),
+                            "timeoutHeight" | "timeout_height" => Ok(GeneratedField::TimeoutHeight),
+                            "timeoutTime" | "timeout_time" => Ok(GeneratedField::TimeoutTime),
+                            "sourceChannel" | "source_channel" => Ok(GeneratedField::SourceChannel),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "bridgeAddress" | "bridge_address" => Ok(GeneratedField::BridgeAddress),
+                            "useCompatAddress" | "use_compat_address" => Ok(GeneratedField::UseCompatAddress),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20Withdrawal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.Ics20Withdrawal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20Withdrawal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut amount__ = None;
+                let mut denom__ = None;
+                let mut destination_chain_address__ = None;
+                let mut return_address__ = None;
+                let mut timeout_height__ = None;
+                let mut timeout_time__ = None;
+                let mut source_channel__ = None;
+                let mut fee_asset__ = None;
+                let mut memo__ = None;
+                let mut bridge_address__ = None;
+                let mut use_compat_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::Denom => {
+                            if denom__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("denom"));
+                            }
+                            denom__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DestinationChainAddress => {
+                            if destination_chain_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("destinationChainAddress"));
+                            }
+                            destination_chain_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ReturnAddress => {
+                            if return_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("returnAddress"));
+                            }
+                            return_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::TimeoutHeight => {
+                            if timeout_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeoutHeight"));
+                            }
                             timeout_height__ = map_.next_value()?;
                         }
-                        GeneratedField::TimeoutTime => {
-                            if timeout_time__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("timeoutTime"));
+                        GeneratedField::TimeoutTime => {
+                            if timeout_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeoutTime"));
+                            }
+                            timeout_time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SourceChannel => {
+                            if source_channel__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sourceChannel"));
+                            }
+                            source_channel__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                            }
+                            fee_asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BridgeAddress => {
+                            if bridge_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeAddress"));
+                            }
+                            bridge_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::UseCompatAddress => {
+                            if use_compat_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("useCompatAddress"));
+                            }
+                            use_compat_address__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Ics20Withdrawal {
+                    amount: amount__,
+                    denom: denom__.unwrap_or_default(),
+                    destination_chain_address: destination_chain_address__.unwrap_or_default(),
+                    return_address: return_address__,
+                    timeout_height: timeout_height__,
+                    timeout_time: timeout_time__.unwrap_or_default(),
+                    source_channel: source_channel__.unwrap_or_default(),
+                    fee_asset: fee_asset__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
+                    bridge_address: bridge_address__,
+                    use_compat_address: use_compat_address__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.Ics20Withdrawal", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for InitBridgeAccount {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rollup_id.is_some() {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if !self.fee_asset.is_empty() {
+            len += 1;
+        }
+        if self.sudo_address.is_some() {
+            len += 1;
+        }
+        if self.withdrawer_address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.InitBridgeAccount", len)?;
+        if let Some(v) = self.rollup_id.as_ref() {
+            struct_ser.serialize_field("rollupId", v)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+        }
+        if let Some(v) = self.sudo_address.as_ref() {
+            struct_ser.serialize_field("sudoAddress", v)?;
+        }
+        if let Some(v) = self.withdrawer_address.as_ref() {
+            struct_ser.serialize_field("withdrawerAddress", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for InitBridgeAccount {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rollup_id",
+            "rollupId",
+            "asset",
+            "fee_asset",
+            "feeAsset",
+            "sudo_address",
+            "sudoAddress",
+            "withdrawer_address",
+            "withdrawerAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RollupId,
+            Asset,
+            FeeAsset,
+            SudoAddress,
+            WithdrawerAddress,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rollupId" | "rollup_id" => Ok(GeneratedField::RollupId),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
+                            "sudoAddress" | "sudo_address" => Ok(GeneratedField::SudoAddress),
+                            "withdrawerAddress" | "withdrawer_address" => Ok(GeneratedField::WithdrawerAddress),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = InitBridgeAccount;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.InitBridgeAccount")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InitBridgeAccount, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rollup_id__ = None;
+                let mut asset__ = None;
+                let mut fee_asset__ = None;
+                let mut sudo_address__ = None;
+                let mut withdrawer_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RollupId => {
+                            if rollup_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rollupId"));
+                            }
+                            rollup_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                            }
+                            fee_asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SudoAddress => {
+                            if sudo_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sudoAddress"));
+                            }
+                            sudo_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::WithdrawerAddress => {
+                            if withdrawer_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawerAddress"));
+                            }
+                            withdrawer_address__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(InitBridgeAccount {
+                    rollup_id: rollup_id__,
+                    asset: asset__.unwrap_or_default(),
+                    fee_asset: fee_asset__.unwrap_or_default(),
+                    sudo_address: sudo_address__,
+                    withdrawer_address: withdrawer_address__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.InitBridgeAccount", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Markets {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.markets.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.Markets", len)?;
+        if !self.markets.is_empty() {
+            struct_ser.serialize_field("markets", &self.markets)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Markets {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "markets",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Markets,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "markets" => Ok(GeneratedField::Markets),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Markets;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.Markets")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Markets, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut markets__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Markets => {
+                            if markets__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("markets"));
+                            }
+                            markets__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Markets {
+                    markets: markets__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.Markets", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MarketsChange {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.action.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.MarketsChange", len)?;
+        if let Some(v) = self.action.as_ref() {
+            match v {
+                markets_change::Action::Creation(v) => {
+                    struct_ser.serialize_field("creation", v)?;
+                }
+                markets_change::Action::Removal(v) => {
+                    struct_ser.serialize_field("removal", v)?;
+                }
+                markets_change::Action::Update(v) => {
+                    struct_ser.serialize_field("update", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MarketsChange {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "creation",
+            "removal",
+            "update",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Creation,
+            Removal,
+            Update,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "creation" => Ok(GeneratedField::Creation),
+                            "removal" => Ok(GeneratedField::Removal),
+                            "update" => Ok(GeneratedField::Update),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MarketsChange;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.MarketsChange")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MarketsChange, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut action__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Creation => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("creation"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(markets_change::Action::Creation)
+;
+                        }
+                        GeneratedField::Removal => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("removal"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(markets_change::Action::Removal)
+;
+                        }
+                        GeneratedField::Update => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("update"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(markets_change::Action::Update)
+;
+                        }
+                    }
+                }
+                Ok(MarketsChange {
+                    action: action__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.MarketsChange", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalSubmission {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.fee_asset.is_empty() {
+            len += 1;
+        }
+        if self.action.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.ProposalSubmission", len)?;
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+        }
+        if let Some(v) = self.action.as_ref() {
+            match v {
+                proposal_submission::Action::FeeChange(v) => {
+                    struct_ser.serialize_field("feeChange", v)?;
+                }
+                proposal_submission::Action::FeeAssetChange(v) => {
+                    struct_ser.serialize_field("feeAssetChange", v)?;
+                }
+                proposal_submission::Action::IbcRelayerChange(v) => {
+                    struct_ser.serialize_field("ibcRelayerChange", v)?;
+                }
+                proposal_submission::Action::MarketsChange(v) => {
+                    struct_ser.serialize_field("marketsChange", v)?;
+                }
+                proposal_submission::Action::SudoAddressChange(v) => {
+                    struct_ser.serialize_field("sudoAddressChange", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalSubmission {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "fee_asset",
+            "feeAsset",
+            "fee_change",
+            "feeChange",
+            "fee_asset_change",
+            "feeAssetChange",
+            "ibc_relayer_change",
+            "ibcRelayerChange",
+            "markets_change",
+            "marketsChange",
+            "sudo_address_change",
+            "sudoAddressChange",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FeeAsset,
+            FeeChange,
+            FeeAssetChange,
+            IbcRelayerChange,
+            MarketsChange,
+            SudoAddressChange,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
+                            "feeChange" | "fee_change" => Ok(GeneratedField::FeeChange),
+                            "feeAssetChange" | "fee_asset_change" => Ok(GeneratedField::FeeAssetChange),
+                            "ibcRelayerChange" | "ibc_relayer_change" => Ok(GeneratedField::IbcRelayerChange),
+                            "marketsChange" | "markets_change" => Ok(GeneratedField::MarketsChange),
+                            "sudoAddressChange" | "sudo_address_change" => Ok(GeneratedField::SudoAddressChange),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalSubmission;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.ProposalSubmission")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalSubmission, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut fee_asset__ = None;
+                let mut action__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                            }
+                            fee_asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FeeChange => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeChange"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_submission::Action::FeeChange)
+;
+                        }
+                        GeneratedField::FeeAssetChange => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAssetChange"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_submission::Action::FeeAssetChange)
+;
+                        }
+                        GeneratedField::IbcRelayerChange => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcRelayerChange"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_submission::Action::IbcRelayerChange)
+;
+                        }
+                        GeneratedField::MarketsChange => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketsChange"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_submission::Action::MarketsChange)
+;
+                        }
+                        GeneratedField::SudoAddressChange => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sudoAddressChange"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_submission::Action::SudoAddressChange)
+;
+                        }
+                    }
+                }
+                Ok(ProposalSubmission {
+                    fee_asset: fee_asset__.unwrap_or_default(),
+                    action: action__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.ProposalSubmission", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalVote {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal_id != 0 {
+            len += 1;
+        }
+        if self.approve {
+            len += 1;
+        }
+        if !self.fee_asset.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.ProposalVote", len)?;
+        if self.proposal_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        }
+        if self.approve {
+            struct_ser.serialize_field("approve", &self.approve)?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalVote {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal_id",
+            "proposalId",
+            "approve",
+            "fee_asset",
+            "feeAsset",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProposalId,
+            Approve,
+            FeeAsset,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            "approve" => Ok(GeneratedField::Approve),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalVote;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.ProposalVote")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalVote, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal_id__ = None;
+                let mut approve__ = None;
+                let mut fee_asset__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                            }
+                            proposal_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Approve => {
+                            if approve__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approve"));
+                            }
+                            approve__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                            }
+                            fee_asset__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ProposalVote {
+                    proposal_id: proposal_id__.unwrap_or_default(),
+                    approve: approve__.unwrap_or_default(),
+                    fee_asset: fee_asset__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.ProposalVote", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RecoverIbcClient {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.client_id.is_empty() {
+            len += 1;
+        }
+        if !self.replacement_client_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.RecoverIbcClient", len)?;
+        if !self.client_id.is_empty() {
+            struct_ser.serialize_field("clientId", &self.client_id)?;
+        }
+        if !self.replacement_client_id.is_empty() {
+            struct_ser.serialize_field("replacementClientId", &self.replacement_client_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RecoverIbcClient {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "client_id",
+            "clientId",
+            "replacement_client_id",
+            "replacementClientId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ClientId,
+            ReplacementClientId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "clientId" | "client_id" => Ok(GeneratedField::ClientId),
+                            "replacementClientId" | "replacement_client_id" => Ok(GeneratedField::ReplacementClientId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RecoverIbcClient;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.RecoverIbcClient")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RecoverIbcClient, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut client_id__ = None;
+                let mut replacement_client_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ClientId => {
+                            if client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("clientId"));
+                            }
+                            client_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ReplacementClientId => {
+                            if replacement_client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("replacementClientId"));
+                            }
+                            replacement_client_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(RecoverIbcClient {
+                    client_id: client_id__.unwrap_or_default(),
+                    replacement_client_id: replacement_client_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.RecoverIbcClient", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RegisterMultisigAccount {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.verification_keys.is_empty() {
+            len += 1;
+        }
+        if self.threshold != 0 {
+            len += 1;
+        }
+        if !self.fee_asset.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.RegisterMultisigAccount", len)?;
+        if !self.verification_keys.is_empty() {
+            struct_ser.serialize_field("verificationKeys", &self.verification_keys.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
+        }
+        if self.threshold != 0 {
+            struct_ser.serialize_field("threshold", &self.threshold)?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RegisterMultisigAccount {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "verification_keys",
+            "verificationKeys",
+            "threshold",
+            "fee_asset",
+            "feeAsset",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            VerificationKeys,
+            Threshold,
+            FeeAsset,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "verificationKeys" | "verification_keys" => Ok(GeneratedField::VerificationKeys),
+                            "threshold" => Ok(GeneratedField::Threshold),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RegisterMultisigAccount;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.RegisterMultisigAccount")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RegisterMultisigAccount, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut verification_keys__ = None;
+                let mut threshold__ = None;
+                let mut fee_asset__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::VerificationKeys => {
+                            if verification_keys__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("verificationKeys"));
+                            }
+                            verification_keys__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::Threshold => {
+                            if threshold__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("threshold"));
+                            }
+                            threshold__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                            }
+                            fee_asset__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(RegisterMultisigAccount {
+                    verification_keys: verification_keys__.unwrap_or_default(),
+                    threshold: threshold__.unwrap_or_default(),
+                    fee_asset: fee_asset__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.RegisterMultisigAccount", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RollupDataSubmission {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rollup_id.is_some() {
+            len += 1;
+        }
+        if !self.data.is_empty() {
+            len += 1;
+        }
+        if !self.fee_asset.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.RollupDataSubmission", len)?;
+        if let Some(v) = self.rollup_id.as_ref() {
+            struct_ser.serialize_field("rollupId", v)?;
+        }
+        if !self.data.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("data", pbjson::private::base64::encode(&self.data).as_str())?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RollupDataSubmission {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rollup_id",
+            "rollupId",
+            "data",
+            "fee_asset",
+            "feeAsset",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RollupId,
+            Data,
+            FeeAsset,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rollupId" | "rollup_id" => Ok(GeneratedField::RollupId),
+                            "data" => Ok(GeneratedField::Data),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RollupDataSubmission;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.RollupDataSubmission")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RollupDataSubmission, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rollup_id__ = None;
+                let mut data__ = None;
+                let mut fee_asset__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RollupId => {
+                            if rollup_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rollupId"));
                             }
-                            timeout_time__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            rollup_id__ = map_.next_value()?;
                         }
-                        GeneratedField::SourceChannel => {
-                            if source_channel__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sourceChannel"));
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
                             }
-                            source_channel__ = Some(map_.next_value()?);
+                            data__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::FeeAsset => {
                             if fee_asset__.is_some() {
@@ -2236,45 +4068,19 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
                             }
                             fee_asset__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::Memo => {
-                            if memo__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("memo"));
-                            }
-                            memo__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::BridgeAddress => {
-                            if bridge_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("bridgeAddress"));
-                            }
-                            bridge_address__ = map_.next_value()?;
-                        }
-                        GeneratedField::UseCompatAddress => {
-                            if use_compat_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("useCompatAddress"));
-                            }
-                            use_compat_address__ = Some(map_.next_value()?);
-                        }
                     }
                 }
-                Ok(Ics20Withdrawal {
-                    amount: amount__,
-                    denom: denom__.unwrap_or_default(),
-                    destination_chain_address: destination_chain_address__.unwrap_or_default(),
-                    return_address: return_address__,
-                    timeout_height: timeout_height__,
-                    timeout_time: timeout_time__.unwrap_or_default(),
-                    source_channel: source_channel__.unwrap_or_default(),
+                Ok(RollupDataSubmission {
+                    rollup_id: rollup_id__,
+                    data: data__.unwrap_or_default(),
                     fee_asset: fee_asset__.unwrap_or_default(),
-                    memo: memo__.unwrap_or_default(),
-                    bridge_address: bridge_address__,
-                    use_compat_address: use_compat_address__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.Ics20Withdrawal", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.RollupDataSubmission", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for InitBridgeAccount {
+impl serde::Serialize for ScheduledTransfer {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2282,7 +4088,10 @@ impl serde::Serialize for InitBridgeAccount {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.rollup_id.is_some() {
+        if self.to.is_some() {
+            len += 1;
+        }
+        if self.amount.is_some() {
             len += 1;
         }
         if !self.asset.is_empty() {
@@ -2291,15 +4100,15 @@ impl serde::Serialize for InitBridgeAccount {
         if !self.fee_asset.is_empty() {
             len += 1;
         }
-        if self.sudo_address.is_some() {
+        if self.release_at.is_some() {
             len += 1;
         }
-        if self.withdrawer_address.is_some() {
-            len += 1;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.ScheduledTransfer", len)?;
+        if let Some(v) = self.to.as_ref() {
+            struct_ser.serialize_field("to", v)?;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.InitBridgeAccount", len)?;
-        if let Some(v) = self.rollup_id.as_ref() {
-            struct_ser.serialize_field("rollupId", v)?;
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
         }
         if !self.asset.is_empty() {
             struct_ser.serialize_field("asset", &self.asset)?;
@@ -2307,40 +4116,48 @@ impl serde::Serialize for InitBridgeAccount {
         if !self.fee_asset.is_empty() {
             struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
         }
-        if let Some(v) = self.sudo_address.as_ref() {
-            struct_ser.serialize_field("sudoAddress", v)?;
-        }
-        if let Some(v) = self.withdrawer_address.as_ref() {
-            struct_ser.serialize_field("withdrawerAddress", v)?;
+        if let Some(v) = self.release_at.as_ref() {
+            match v {
+                scheduled_transfer::ReleaseAt::Height(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    #[allow(clippy::needless_borrows_for_generic_args)]
+                    struct_ser.serialize_field("height", ToString::to_string(&v).as_str())?;
+                }
+                scheduled_transfer::ReleaseAt::UnixTimestampSeconds(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    #[allow(clippy::needless_borrows_for_generic_args)]
+                    struct_ser.serialize_field("unixTimestampSeconds", ToString::to_string(&v).as_str())?;
+                }
+            }
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for InitBridgeAccount {
+impl<'de> serde::Deserialize<'de> for ScheduledTransfer {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "rollup_id",
-            "rollupId",
+            "to",
+            "amount",
             "asset",
             "fee_asset",
             "feeAsset",
-            "sudo_address",
-            "sudoAddress",
-            "withdrawer_address",
-            "withdrawerAddress",
+            "height",
+            "unix_timestamp_seconds",
+            "unixTimestampSeconds",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            RollupId,
+            To,
+            Amount,
             Asset,
             FeeAsset,
-            SudoAddress,
-            WithdrawerAddress,
+            Height,
+            UnixTimestampSeconds,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2362,11 +4179,12 @@ impl<'de> serde::Deserialize<'de> for InitBridgeAccount {
                         E: serde::de::Error,
                     {
                         match value {
-                            "rollupId" | "rollup_id" => Ok(GeneratedField::RollupId),
+                            "to" => Ok(GeneratedField::To),
+                            "amount" => Ok(GeneratedField::Amount),
                             "asset" => Ok(GeneratedField::Asset),
                             "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
-                            "sudoAddress" | "sudo_address" => Ok(GeneratedField::SudoAddress),
-                            "withdrawerAddress" | "withdrawer_address" => Ok(GeneratedField::WithdrawerAddress),
+                            "height" => Ok(GeneratedField::Height),
+                            "unixTimestampSeconds" | "unix_timestamp_seconds" => Ok(GeneratedField::UnixTimestampSeconds),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2376,28 +4194,34 @@ impl<'de> serde::Deserialize<'de> for InitBridgeAccount {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = InitBridgeAccount;
+            type Value = ScheduledTransfer;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.InitBridgeAccount")
+                formatter.write_str("struct astria.protocol.transaction.v1.ScheduledTransfer")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InitBridgeAccount, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduledTransfer, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut rollup_id__ = None;
+                let mut to__ = None;
+                let mut amount__ = None;
                 let mut asset__ = None;
                 let mut fee_asset__ = None;
-                let mut sudo_address__ = None;
-                let mut withdrawer_address__ = None;
+                let mut release_at__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::RollupId => {
-                            if rollup_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rollupId"));
+                        GeneratedField::To => {
+                            if to__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("to"));
                             }
-                            rollup_id__ = map_.next_value()?;
+                            to__ = map_.next_value()?;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
                         }
                         GeneratedField::Asset => {
                             if asset__.is_some() {
@@ -2411,33 +4235,33 @@ impl<'de> serde::Deserialize<'de> for InitBridgeAccount {
                             }
                             fee_asset__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::SudoAddress => {
-                            if sudo_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sudoAddress"));
+                        GeneratedField::Height => {
+                            if release_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            sudo_address__ = map_.next_value()?;
+                            release_at__ = map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| scheduled_transfer::ReleaseAt::Height(x.0));
                         }
-                        GeneratedField::WithdrawerAddress => {
-                            if withdrawer_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("withdrawerAddress"));
+                        GeneratedField::UnixTimestampSeconds => {
+                            if release_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unixTimestampSeconds"));
                             }
-                            withdrawer_address__ = map_.next_value()?;
+                            release_at__ = map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| scheduled_transfer::ReleaseAt::UnixTimestampSeconds(x.0));
                         }
                     }
                 }
-                Ok(InitBridgeAccount {
-                    rollup_id: rollup_id__,
+                Ok(ScheduledTransfer {
+                    to: to__,
+                    amount: amount__,
                     asset: asset__.unwrap_or_default(),
                     fee_asset: fee_asset__.unwrap_or_default(),
-                    sudo_address: sudo_address__,
-                    withdrawer_address: withdrawer_address__,
+                    release_at: release_at__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.InitBridgeAccount", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.ScheduledTransfer", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Markets {
+impl serde::Serialize for StakingParametersChange {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2445,29 +4269,121 @@ impl serde::Serialize for Markets {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.markets.is_empty() {
+        if self.parameters.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.Markets", len)?;
-        if !self.markets.is_empty() {
-            struct_ser.serialize_field("markets", &self.markets)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.StakingParametersChange", len)?;
+        if let Some(v) = self.parameters.as_ref() {
+            struct_ser.serialize_field("parameters", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Markets {
+impl<'de> serde::Deserialize<'de> for StakingParametersChange {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "parameters",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Parameters,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "parameters" => Ok(GeneratedField::Parameters),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StakingParametersChange;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.StakingParametersChange")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StakingParametersChange, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut parameters__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Parameters => {
+                            if parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parameters"));
+                            }
+                            parameters__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(StakingParametersChange {
+                    parameters: parameters__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.StakingParametersChange", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SudoAddressChange {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.new_address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.SudoAddressChange", len)?;
+        if let Some(v) = self.new_address.as_ref() {
+            struct_ser.serialize_field("newAddress", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SudoAddressChange {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "markets",
+            "new_address",
+            "newAddress",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Markets,
+            NewAddress,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2489,7 +4405,7 @@ impl<'de> serde::Deserialize<'de> for Markets {
                         E: serde::de::Error,
                     {
                         match value {
-                            "markets" => Ok(GeneratedField::Markets),
+                            "newAddress" | "new_address" => Ok(GeneratedField::NewAddress),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2499,36 +4415,36 @@ impl<'de> serde::Deserialize<'de> for Markets {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Markets;
+            type Value = SudoAddressChange;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.Markets")
+                formatter.write_str("struct astria.protocol.transaction.v1.SudoAddressChange")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Markets, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SudoAddressChange, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut markets__ = None;
+                let mut new_address__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Markets => {
-                            if markets__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("markets"));
+                        GeneratedField::NewAddress => {
+                            if new_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newAddress"));
                             }
-                            markets__ = Some(map_.next_value()?);
+                            new_address__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(Markets {
-                    markets: markets__.unwrap_or_default(),
+                Ok(SudoAddressChange {
+                    new_address: new_address__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.Markets", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.SudoAddressChange", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for MarketsChange {
+impl serde::Serialize for Transaction {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2536,43 +4452,67 @@ impl serde::Serialize for MarketsChange {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.action.is_some() {
+        if !self.signature.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.MarketsChange", len)?;
-        if let Some(v) = self.action.as_ref() {
-            match v {
-                markets_change::Action::Creation(v) => {
-                    struct_ser.serialize_field("creation", v)?;
-                }
-                markets_change::Action::Removal(v) => {
-                    struct_ser.serialize_field("removal", v)?;
-                }
-                markets_change::Action::Update(v) => {
-                    struct_ser.serialize_field("update", v)?;
-                }
-            }
+        if !self.public_key.is_empty() {
+            len += 1;
+        }
+        if self.body.is_some() {
+            len += 1;
+        }
+        if !self.cosignatures.is_empty() {
+            len += 1;
+        }
+        if self.fee_payer_signature.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.Transaction", len)?;
+        if !self.signature.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("signature", pbjson::private::base64::encode(&self.signature).as_str())?;
+        }
+        if !self.public_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("publicKey", pbjson::private::base64::encode(&self.public_key).as_str())?;
+        }
+        if let Some(v) = self.body.as_ref() {
+            struct_ser.serialize_field("body", v)?;
+        }
+        if !self.cosignatures.is_empty() {
+            struct_ser.serialize_field("cosignatures", &self.cosignatures)?;
+        }
+        if let Some(v) = self.fee_payer_signature.as_ref() {
+            struct_ser.serialize_field("feePayerSignature", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for MarketsChange {
+impl<'de> serde::Deserialize<'de> for Transaction {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "creation",
-            "removal",
-            "update",
+            "signature",
+            "public_key",
+            "publicKey",
+            "body",
+            "cosignatures",
+            "fee_payer_signature",
+            "feePayerSignature",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Creation,
-            Removal,
-            Update,
+            Signature,
+            PublicKey,
+            Body,
+            Cosignatures,
+            FeePayerSignature,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2594,9 +4534,11 @@ impl<'de> serde::Deserialize<'de> for MarketsChange {
                         E: serde::de::Error,
                     {
                         match value {
-                            "creation" => Ok(GeneratedField::Creation),
-                            "removal" => Ok(GeneratedField::Removal),
-                            "update" => Ok(GeneratedField::Update),
+                            "signature" => Ok(GeneratedField::Signature),
+                            "publicKey" | "public_key" => Ok(GeneratedField::PublicKey),
+                            "body" => Ok(GeneratedField::Body),
+                            "cosignatures" => Ok(GeneratedField::Cosignatures),
+                            "feePayerSignature" | "fee_payer_signature" => Ok(GeneratedField::FeePayerSignature),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2606,51 +4548,72 @@ impl<'de> serde::Deserialize<'de> for MarketsChange {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = MarketsChange;
+            type Value = Transaction;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.MarketsChange")
+                formatter.write_str("struct astria.protocol.transaction.v1.Transaction")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MarketsChange, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Transaction, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut action__ = None;
+                let mut signature__ = None;
+                let mut public_key__ = None;
+                let mut body__ = None;
+                let mut cosignatures__ = None;
+                let mut fee_payer_signature__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Creation => {
-                            if action__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("creation"));
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
                             }
-                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(markets_change::Action::Creation)
-;
+                            signature__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Removal => {
-                            if action__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("removal"));
+                        GeneratedField::PublicKey => {
+                            if public_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publicKey"));
                             }
-                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(markets_change::Action::Removal)
-;
+                            public_key__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Update => {
-                            if action__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("update"));
+                        GeneratedField::Body => {
+                            if body__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("body"));
                             }
-                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(markets_change::Action::Update)
-;
+                            body__ = map_.next_value()?;
+                        }
+                        GeneratedField::Cosignatures => {
+                            if cosignatures__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cosignatures"));
+                            }
+                            cosignatures__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FeePayerSignature => {
+                            if fee_payer_signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feePayerSignature"));
+                            }
+                            fee_payer_signature__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(MarketsChange {
-                    action: action__,
+                Ok(Transaction {
+                    signature: signature__.unwrap_or_default(),
+                    public_key: public_key__.unwrap_or_default(),
+                    body: body__,
+                    cosignatures: cosignatures__.unwrap_or_default(),
+                    fee_payer_signature: fee_payer_signature__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.MarketsChange", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.Transaction", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for RecoverIbcClient {
+impl serde::Serialize for TransactionBody {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2658,39 +4621,55 @@ impl serde::Serialize for RecoverIbcClient {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.client_id.is_empty() {
+        if self.params.is_some() {
             len += 1;
         }
-        if !self.replacement_client_id.is_empty() {
+        if !self.actions.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.RecoverIbcClient", len)?;
-        if !self.client_id.is_empty() {
-            struct_ser.serialize_field("clientId", &self.client_id)?;
+        if self.fee_payer.is_some() {
+            len += 1;
         }
-        if !self.replacement_client_id.is_empty() {
-            struct_ser.serialize_field("replacementClientId", &self.replacement_client_id)?;
+        if self.multisig_address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.TransactionBody", len)?;
+        if let Some(v) = self.params.as_ref() {
+            struct_ser.serialize_field("params", v)?;
+        }
+        if !self.actions.is_empty() {
+            struct_ser.serialize_field("actions", &self.actions)?;
+        }
+        if let Some(v) = self.fee_payer.as_ref() {
+            struct_ser.serialize_field("feePayer", v)?;
+        }
+        if let Some(v) = self.multisig_address.as_ref() {
+            struct_ser.serialize_field("multisigAddress", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for RecoverIbcClient {
+impl<'de> serde::Deserialize<'de> for TransactionBody {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "client_id",
-            "clientId",
-            "replacement_client_id",
-            "replacementClientId",
+            "params",
+            "actions",
+            "fee_payer",
+            "feePayer",
+            "multisig_address",
+            "multisigAddress",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ClientId,
-            ReplacementClientId,
+            Params,
+            Actions,
+            FeePayer,
+            MultisigAddress,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2712,8 +4691,10 @@ impl<'de> serde::Deserialize<'de> for RecoverIbcClient {
                         E: serde::de::Error,
                     {
                         match value {
-                            "clientId" | "client_id" => Ok(GeneratedField::ClientId),
-                            "replacementClientId" | "replacement_client_id" => Ok(GeneratedField::ReplacementClientId),
+                            "params" => Ok(GeneratedField::Params),
+                            "actions" => Ok(GeneratedField::Actions),
+                            "feePayer" | "fee_payer" => Ok(GeneratedField::FeePayer),
+                            "multisigAddress" | "multisig_address" => Ok(GeneratedField::MultisigAddress),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2723,44 +4704,60 @@ impl<'de> serde::Deserialize<'de> for RecoverIbcClient {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = RecoverIbcClient;
+            type Value = TransactionBody;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.RecoverIbcClient")
+                formatter.write_str("struct astria.protocol.transaction.v1.TransactionBody")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RecoverIbcClient, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionBody, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut client_id__ = None;
-                let mut replacement_client_id__ = None;
+                let mut params__ = None;
+                let mut actions__ = None;
+                let mut fee_payer__ = None;
+                let mut multisig_address__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ClientId => {
-                            if client_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("clientId"));
+                        GeneratedField::Params => {
+                            if params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("params"));
                             }
-                            client_id__ = Some(map_.next_value()?);
+                            params__ = map_.next_value()?;
                         }
-                        GeneratedField::ReplacementClientId => {
-                            if replacement_client_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("replacementClientId"));
+                        GeneratedField::Actions => {
+                            if actions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actions"));
                             }
-                            replacement_client_id__ = Some(map_.next_value()?);
+                            actions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FeePayer => {
+                            if fee_payer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feePayer"));
+                            }
+                            fee_payer__ = map_.next_value()?;
+                        }
+                        GeneratedField::MultisigAddress => {
+                            if multisig_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multisigAddress"));
+                            }
+                            multisig_address__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(RecoverIbcClient {
-                    client_id: client_id__.unwrap_or_default(),
-                    replacement_client_id: replacement_client_id__.unwrap_or_default(),
+                Ok(TransactionBody {
+                    params: params__,
+                    actions: actions__.unwrap_or_default(),
+                    fee_payer: fee_payer__,
+                    multisig_address: multisig_address__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.RecoverIbcClient", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.TransactionBody", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for RollupDataSubmission {
+impl serde::Serialize for TransactionParams {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2768,49 +4765,38 @@ impl serde::Serialize for RollupDataSubmission {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.rollup_id.is_some() {
-            len += 1;
-        }
-        if !self.data.is_empty() {
+        if self.nonce != 0 {
             len += 1;
         }
-        if !self.fee_asset.is_empty() {
+        if !self.chain_id.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.RollupDataSubmission", len)?;
-        if let Some(v) = self.rollup_id.as_ref() {
-            struct_ser.serialize_field("rollupId", v)?;
-        }
-        if !self.data.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("data", pbjson::private::base64::encode(&self.data).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.TransactionParams", len)?;
+        if self.nonce != 0 {
+            struct_ser.serialize_field("nonce", &self.nonce)?;
         }
-        if !self.fee_asset.is_empty() {
-            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for RollupDataSubmission {
+impl<'de> serde::Deserialize<'de> for TransactionParams {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "rollup_id",
-            "rollupId",
-            "data",
-            "fee_asset",
-            "feeAsset",
+            "nonce",
+            "chain_id",
+            "chainId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            RollupId,
-            Data,
-            FeeAsset,
+            Nonce,
+            ChainId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2832,9 +4818,8 @@ impl<'de> serde::Deserialize<'de> for RollupDataSubmission {
                         E: serde::de::Error,
                     {
                         match value {
-                            "rollupId" | "rollup_id" => Ok(GeneratedField::RollupId),
-                            "data" => Ok(GeneratedField::Data),
-                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2844,54 +4829,46 @@ impl<'de> serde::Deserialize<'de> for RollupDataSubmission {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = RollupDataSubmission;
+            type Value = TransactionParams;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.RollupDataSubmission")
+                formatter.write_str("struct astria.protocol.transaction.v1.TransactionParams")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RollupDataSubmission, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionParams, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut rollup_id__ = None;
-                let mut data__ = None;
-                let mut fee_asset__ = None;
+                let mut nonce__ = None;
+                let mut chain_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::RollupId => {
-                            if rollup_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rollupId"));
-                            }
-                            rollup_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
                             }
-                            data__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            nonce__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::FeeAsset => {
-                            if fee_asset__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
                             }
-                            fee_asset__ = Some(map_.next_value()?);
+                            chain_id__ = Some(map_.next_value()?);
                         }
                     }
                 }
-                Ok(RollupDataSubmission {
-                    rollup_id: rollup_id__,
-                    data: data__.unwrap_or_default(),
-                    fee_asset: fee_asset__.unwrap_or_default(),
+                Ok(TransactionParams {
+                    nonce: nonce__.unwrap_or_default(),
+                    chain_id: chain_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.RollupDataSubmission", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.TransactionParams", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SudoAddressChange {
+impl serde::Serialize for TransactionSignature {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2899,30 +4876,42 @@ impl serde::Serialize for SudoAddressChange {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.new_address.is_some() {
+        if !self.signature.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.SudoAddressChange", len)?;
-        if let Some(v) = self.new_address.as_ref() {
-            struct_ser.serialize_field("newAddress", v)?;
+        if !self.public_key.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.TransactionSignature", len)?;
+        if !self.signature.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("signature", pbjson::private::base64::encode(&self.signature).as_str())?;
+        }
+        if !self.public_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("publicKey", pbjson::private::base64::encode(&self.public_key).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SudoAddressChange {
+impl<'de> serde::Deserialize<'de> for TransactionSignature {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "new_address",
-            "newAddress",
+            "signature",
+            "public_key",
+            "publicKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NewAddress,
+            Signature,
+            PublicKey,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2944,7 +4933,8 @@ impl<'de> serde::Deserialize<'de> for SudoAddressChange {
                         E: serde::de::Error,
                     {
                         match value {
-                            "newAddress" | "new_address" => Ok(GeneratedField::NewAddress),
+                            "signature" => Ok(GeneratedField::Signature),
+                            "publicKey" | "public_key" => Ok(GeneratedField::PublicKey),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2954,36 +4944,48 @@ impl<'de> serde::Deserialize<'de> for SudoAddressChange {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SudoAddressChange;
+            type Value = TransactionSignature;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.SudoAddressChange")
+                formatter.write_str("struct astria.protocol.transaction.v1.TransactionSignature")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SudoAddressChange, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionSignature, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut new_address__ = None;
+                let mut signature__ = None;
+                let mut public_key__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::NewAddress => {
-                            if new_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("newAddress"));
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
                             }
-                            new_address__ = map_.next_value()?;
+                            signature__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PublicKey => {
+                            if public_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publicKey"));
+                            }
+                            public_key__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                     }
                 }
-                Ok(SudoAddressChange {
-                    new_address: new_address__,
+                Ok(TransactionSignature {
+                    signature: signature__.unwrap_or_default(),
+                    public_key: public_key__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.SudoAddressChange", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.TransactionSignature", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Transaction {
+impl serde::Serialize for Transfer {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2991,50 +4993,54 @@ impl serde::Serialize for Transaction {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.signature.is_empty() {
+        if self.to.is_some() {
             len += 1;
         }
-        if !self.public_key.is_empty() {
+        if self.amount.is_some() {
             len += 1;
         }
-        if self.body.is_some() {
+        if !self.asset.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.Transaction", len)?;
-        if !self.signature.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("signature", pbjson::private::base64::encode(&self.signature).as_str())?;
+        if !self.fee_asset.is_empty() {
+            len += 1;
         }
-        if !self.public_key.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("publicKey", pbjson::private::base64::encode(&self.public_key).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.Transfer", len)?;
+        if let Some(v) = self.to.as_ref() {
+            struct_ser.serialize_field("to", v)?;
         }
-        if let Some(v) = self.body.as_ref() {
-            struct_ser.serialize_field("body", v)?;
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Transaction {
+impl<'de> serde::Deserialize<'de> for Transfer {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "signature",
-            "public_key",
-            "publicKey",
-            "body",
+            "to",
+            "amount",
+            "asset",
+            "fee_asset",
+            "feeAsset",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Signature,
-            PublicKey,
-            Body,
+            To,
+            Amount,
+            Asset,
+            FeeAsset,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3056,9 +5062,10 @@ impl<'de> serde::Deserialize<'de> for Transaction {
                         E: serde::de::Error,
                     {
                         match value {
-                            "signature" => Ok(GeneratedField::Signature),
-                            "publicKey" | "public_key" => Ok(GeneratedField::PublicKey),
-                            "body" => Ok(GeneratedField::Body),
+                            "to" => Ok(GeneratedField::To),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3068,56 +5075,60 @@ impl<'de> serde::Deserialize<'de> for Transaction {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Transaction;
+            type Value = Transfer;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.Transaction")
+                formatter.write_str("struct astria.protocol.transaction.v1.Transfer")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Transaction, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Transfer, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut signature__ = None;
-                let mut public_key__ = None;
-                let mut body__ = None;
+                let mut to__ = None;
+                let mut amount__ = None;
+                let mut asset__ = None;
+                let mut fee_asset__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Signature => {
-                            if signature__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("signature"));
+                        GeneratedField::To => {
+                            if to__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("to"));
                             }
-                            signature__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            to__ = map_.next_value()?;
                         }
-                        GeneratedField::PublicKey => {
-                            if public_key__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("publicKey"));
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
                             }
-                            public_key__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            amount__ = map_.next_value()?;
                         }
-                        GeneratedField::Body => {
-                            if body__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("body"));
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
                             }
-                            body__ = map_.next_value()?;
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                            }
+                            fee_asset__ = Some(map_.next_value()?);
                         }
                     }
                 }
-                Ok(Transaction {
-                    signature: signature__.unwrap_or_default(),
-                    public_key: public_key__.unwrap_or_default(),
-                    body: body__,
+                Ok(Transfer {
+                    to: to__,
+                    amount: amount__,
+                    asset: asset__.unwrap_or_default(),
+                    fee_asset: fee_asset__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.Transaction", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.Transfer", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionBody {
+impl serde::Serialize for Unbond {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3125,37 +5136,48 @@ impl serde::Serialize for TransactionBody {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.params.is_some() {
+        if !self.validator.is_empty() {
             len += 1;
         }
-        if !self.actions.is_empty() {
+        if self.amount.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.TransactionBody", len)?;
-        if let Some(v) = self.params.as_ref() {
-            struct_ser.serialize_field("params", v)?;
+        if !self.fee_asset.is_empty() {
+            len += 1;
         }
-        if !self.actions.is_empty() {
-            struct_ser.serialize_field("actions", &self.actions)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.Unbond", len)?;
+        if !self.validator.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("validator", pbjson::private::base64::encode(&self.validator).as_str())?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionBody {
+impl<'de> serde::Deserialize<'de> for Unbond {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "params",
-            "actions",
+            "validator",
+            "amount",
+            "fee_asset",
+            "feeAsset",
         ];
 
         #[allow(clippy::enum_variant_names)]
-        enum GeneratedField {
-            Params,
-            Actions,
+        enum GeneratedField {
+            Validator,
+            Amount,
+            FeeAsset,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3177,8 +5199,9 @@ impl<'de> serde::Deserialize<'de> for TransactionBody {
                         E: serde::de::Error,
                     {
                         match value {
-                            "params" => Ok(GeneratedField::Params),
-                            "actions" => Ok(GeneratedField::Actions),
+                            "validator" => Ok(GeneratedField::Validator),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3188,44 +5211,54 @@ impl<'de> serde::Deserialize<'de> for TransactionBody {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionBody;
+            type Value = Unbond;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.TransactionBody")
+                formatter.write_str("struct astria.protocol.transaction.v1.Unbond")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionBody, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Unbond, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut params__ = None;
-                let mut actions__ = None;
+                let mut validator__ = None;
+                let mut amount__ = None;
+                let mut fee_asset__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Params => {
-                            if params__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("params"));
+                        GeneratedField::Validator => {
+                            if validator__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validator"));
                             }
-                            params__ = map_.next_value()?;
+                            validator__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Actions => {
-                            if actions__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("actions"));
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
                             }
-                            actions__ = Some(map_.next_value()?);
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                            }
+                            fee_asset__ = Some(map_.next_value()?);
                         }
                     }
                 }
-                Ok(TransactionBody {
-                    params: params__,
-                    actions: actions__.unwrap_or_default(),
+                Ok(Unbond {
+                    validator: validator__.unwrap_or_default(),
+                    amount: amount__,
+                    fee_asset: fee_asset__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.TransactionBody", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.Unbond", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionParams {
+impl serde::Serialize for ValidatorUpdate {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3233,38 +5266,48 @@ impl serde::Serialize for TransactionParams {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.nonce != 0 {
+        if self.pub_key.is_some() {
             len += 1;
         }
-        if !self.chain_id.is_empty() {
+        if self.power != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.TransactionParams", len)?;
-        if self.nonce != 0 {
-            struct_ser.serialize_field("nonce", &self.nonce)?;
+        if !self.name.is_empty() {
+            len += 1;
         }
-        if !self.chain_id.is_empty() {
-            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.ValidatorUpdate", len)?;
+        if let Some(v) = self.pub_key.as_ref() {
+            struct_ser.serialize_field("pubKey", v)?;
+        }
+        if self.power != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("power", ToString::to_string(&self.power).as_str())?;
+        }
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionParams {
+impl<'de> serde::Deserialize<'de> for ValidatorUpdate {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "nonce",
-            "chain_id",
-            "chainId",
+            "pub_key",
+            "pubKey",
+            "power",
+            "name",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Nonce,
-            ChainId,
+            PubKey,
+            Power,
+            Name,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3286,8 +5329,9 @@ impl<'de> serde::Deserialize<'de> for TransactionParams {
                         E: serde::de::Error,
                     {
                         match value {
-                            "nonce" => Ok(GeneratedField::Nonce),
-                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "pubKey" | "pub_key" => Ok(GeneratedField::PubKey),
+                            "power" => Ok(GeneratedField::Power),
+                            "name" => Ok(GeneratedField::Name),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3297,46 +5341,54 @@ impl<'de> serde::Deserialize<'de> for TransactionParams {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionParams;
+            type Value = ValidatorUpdate;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.TransactionParams")
+                formatter.write_str("struct astria.protocol.transaction.v1.ValidatorUpdate")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionParams, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorUpdate, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut nonce__ = None;
-                let mut chain_id__ = None;
+                let mut pub_key__ = None;
+                let mut power__ = None;
+                let mut name__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Nonce => {
-                            if nonce__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nonce"));
+                        GeneratedField::PubKey => {
+                            if pub_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pubKey"));
                             }
-                            nonce__ = 
+                            pub_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::Power => {
+                            if power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("power"));
+                            }
+                            power__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::ChainId => {
-                            if chain_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("chainId"));
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
                             }
-                            chain_id__ = Some(map_.next_value()?);
+                            name__ = Some(map_.next_value()?);
                         }
                     }
                 }
-                Ok(TransactionParams {
-                    nonce: nonce__.unwrap_or_default(),
-                    chain_id: chain_id__.unwrap_or_default(),
+                Ok(ValidatorUpdate {
+                    pub_key: pub_key__,
+                    power: power__.unwrap_or_default(),
+                    name: name__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.TransactionParams", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.ValidatorUpdate", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Transfer {
+impl serde::Serialize for VoteExtensionParticipation {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3344,54 +5396,52 @@ impl serde::Serialize for Transfer {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.to.is_some() {
-            len += 1;
-        }
-        if self.amount.is_some() {
+        if self.window_blocks != 0 {
             len += 1;
         }
-        if !self.asset.is_empty() {
+        if self.max_missed_blocks != 0 {
             len += 1;
         }
-        if !self.fee_asset.is_empty() {
+        if self.power_reduction_percent != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.Transfer", len)?;
-        if let Some(v) = self.to.as_ref() {
-            struct_ser.serialize_field("to", v)?;
-        }
-        if let Some(v) = self.amount.as_ref() {
-            struct_ser.serialize_field("amount", v)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.VoteExtensionParticipation", len)?;
+        if self.window_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("windowBlocks", ToString::to_string(&self.window_blocks).as_str())?;
         }
-        if !self.asset.is_empty() {
-            struct_ser.serialize_field("asset", &self.asset)?;
+        if self.max_missed_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("maxMissedBlocks", ToString::to_string(&self.max_missed_blocks).as_str())?;
         }
-        if !self.fee_asset.is_empty() {
-            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+        if self.power_reduction_percent != 0 {
+            struct_ser.serialize_field("powerReductionPercent", &self.power_reduction_percent)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Transfer {
+impl<'de> serde::Deserialize<'de> for VoteExtensionParticipation {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "to",
-            "amount",
-            "asset",
-            "fee_asset",
-            "feeAsset",
+            "window_blocks",
+            "windowBlocks",
+            "max_missed_blocks",
+            "maxMissedBlocks",
+            "power_reduction_percent",
+            "powerReductionPercent",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            To,
-            Amount,
-            Asset,
-            FeeAsset,
+            WindowBlocks,
+            MaxMissedBlocks,
+            PowerReductionPercent,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3413,10 +5463,9 @@ impl<'de> serde::Deserialize<'de> for Transfer {
                         E: serde::de::Error,
                     {
                         match value {
-                            "to" => Ok(GeneratedField::To),
-                            "amount" => Ok(GeneratedField::Amount),
-                            "asset" => Ok(GeneratedField::Asset),
-                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
+                            "windowBlocks" | "window_blocks" => Ok(GeneratedField::WindowBlocks),
+                            "maxMissedBlocks" | "max_missed_blocks" => Ok(GeneratedField::MaxMissedBlocks),
+                            "powerReductionPercent" | "power_reduction_percent" => Ok(GeneratedField::PowerReductionPercent),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3426,60 +5475,58 @@ impl<'de> serde::Deserialize<'de> for Transfer {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Transfer;
+            type Value = VoteExtensionParticipation;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.Transfer")
+                formatter.write_str("struct astria.protocol.transaction.v1.VoteExtensionParticipation")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Transfer, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VoteExtensionParticipation, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut to__ = None;
-                let mut amount__ = None;
-                let mut asset__ = None;
-                let mut fee_asset__ = None;
+                let mut window_blocks__ = None;
+                let mut max_missed_blocks__ = None;
+                let mut power_reduction_percent__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::To => {
-                            if to__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("to"));
-                            }
-                            to__ = map_.next_value()?;
-                        }
-                        GeneratedField::Amount => {
-                            if amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("amount"));
+                        GeneratedField::WindowBlocks => {
+                            if window_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("windowBlocks"));
                             }
-                            amount__ = map_.next_value()?;
+                            window_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Asset => {
-                            if asset__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("asset"));
+                        GeneratedField::MaxMissedBlocks => {
+                            if max_missed_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxMissedBlocks"));
                             }
-                            asset__ = Some(map_.next_value()?);
+                            max_missed_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::FeeAsset => {
-                            if fee_asset__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                        GeneratedField::PowerReductionPercent => {
+                            if power_reduction_percent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("powerReductionPercent"));
                             }
-                            fee_asset__ = Some(map_.next_value()?);
+                            power_reduction_percent__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                     }
                 }
-                Ok(Transfer {
-                    to: to__,
-                    amount: amount__,
-                    asset: asset__.unwrap_or_default(),
-                    fee_asset: fee_asset__.unwrap_or_default(),
+                Ok(VoteExtensionParticipation {
+                    window_blocks: window_blocks__.unwrap_or_default(),
+                    max_missed_blocks: max_missed_blocks__.unwrap_or_default(),
+                    power_reduction_percent: power_reduction_percent__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.Transfer", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.VoteExtensionParticipation", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ValidatorUpdate {
+impl serde::Serialize for VoteExtensionParticipationChange {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3487,48 +5534,29 @@ impl serde::Serialize for ValidatorUpdate {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.pub_key.is_some() {
-            len += 1;
-        }
-        if self.power != 0 {
-            len += 1;
-        }
-        if !self.name.is_empty() {
+        if self.participation.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.ValidatorUpdate", len)?;
-        if let Some(v) = self.pub_key.as_ref() {
-            struct_ser.serialize_field("pubKey", v)?;
-        }
-        if self.power != 0 {
-            #[allow(clippy::needless_borrow)]
-            #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("power", ToString::to_string(&self.power).as_str())?;
-        }
-        if !self.name.is_empty() {
-            struct_ser.serialize_field("name", &self.name)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.VoteExtensionParticipationChange", len)?;
+        if let Some(v) = self.participation.as_ref() {
+            struct_ser.serialize_field("participation", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ValidatorUpdate {
+impl<'de> serde::Deserialize<'de> for VoteExtensionParticipationChange {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "pub_key",
-            "pubKey",
-            "power",
-            "name",
+            "participation",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PubKey,
-            Power,
-            Name,
+            Participation,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3550,9 +5578,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorUpdate {
                         E: serde::de::Error,
                     {
                         match value {
-                            "pubKey" | "pub_key" => Ok(GeneratedField::PubKey),
-                            "power" => Ok(GeneratedField::Power),
-                            "name" => Ok(GeneratedField::Name),
+                            "participation" => Ok(GeneratedField::Participation),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3562,50 +5588,32 @@ impl<'de> serde::Deserialize<'de> for ValidatorUpdate {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ValidatorUpdate;
+            type Value = VoteExtensionParticipationChange;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.ValidatorUpdate")
+                formatter.write_str("struct astria.protocol.transaction.v1.VoteExtensionParticipationChange")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorUpdate, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VoteExtensionParticipationChange, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut pub_key__ = None;
-                let mut power__ = None;
-                let mut name__ = None;
+                let mut participation__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PubKey => {
-                            if pub_key__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("pubKey"));
-                            }
-                            pub_key__ = map_.next_value()?;
-                        }
-                        GeneratedField::Power => {
-                            if power__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("power"));
-                            }
-                            power__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Name => {
-                            if name__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("name"));
+                        GeneratedField::Participation => {
+                            if participation__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("participation"));
                             }
-                            name__ = Some(map_.next_value()?);
+                            participation__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(ValidatorUpdate {
-                    pub_key: pub_key__,
-                    power: power__.unwrap_or_default(),
-                    name: name__.unwrap_or_default(),
+                Ok(VoteExtensionParticipationChange {
+                    participation: participation__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.ValidatorUpdate", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.VoteExtensionParticipationChange", FIELDS, GeneratedVisitor)
     }
 }
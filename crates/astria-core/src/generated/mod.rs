@@ -163,6 +163,18 @@ pub mod astria {
             }
         }
         #[path = ""]
+        pub mod ibc {
+            pub mod v1 {
+                include!("astria.protocol.ibc.v1.rs");
+
+                #[cfg(feature = "serde")]
+                mod _serde_impls {
+                    use super::*;
+                    include!("astria.protocol.ibc.v1.serde.rs");
+                }
+            }
+        }
+        #[path = ""]
         pub mod memos {
             pub mod v1 {
                 include!("astria.protocol.memos.v1.rs");
@@ -174,6 +186,18 @@ pub mod astria {
                 }
             }
         }
+        #[path = ""]
+        pub mod staking {
+            pub mod v1 {
+                include!("astria.protocol.staking.v1.rs");
+
+                #[cfg(feature = "serde")]
+                mod _serde_impls {
+                    use super::*;
+                    include!("astria.protocol.staking.v1.serde.rs");
+                }
+            }
+        }
 
         #[path = ""]
         pub mod transaction {
@@ -30,6 +30,10 @@ impl AbciErrorCode {
     pub const TRANSACTION_FAILED_CHECK_TX: Self = Self(unsafe { NonZeroU32::new_unchecked(19) });
     pub const INVALID_TRANSACTION_BYTES: Self = Self(unsafe { NonZeroU32::new_unchecked(20) });
     pub const INVALID_TRANSACTION: Self = Self(unsafe { NonZeroU32::new_unchecked(21) });
+    pub const TRANSACTION_REPLACED: Self = Self(unsafe { NonZeroU32::new_unchecked(22) });
+    pub const HEIGHT_PRUNED: Self = Self(unsafe { NonZeroU32::new_unchecked(23) });
+    pub const INSUFFICIENT_SIGNATURES: Self = Self(unsafe { NonZeroU32::new_unchecked(24) });
+    pub const REPLACEMENT_FEE_TOO_LOW: Self = Self(unsafe { NonZeroU32::new_unchecked(25) });
     // NOTE: When adding a new code, ensure it is added to `ALL_CODES` in the `tests` module below.
 }
 
@@ -75,6 +79,20 @@ impl AbciErrorCode {
             Self::TRANSACTION_FAILED_CHECK_TX => "the transaction failed check_tx".into(),
             Self::INVALID_TRANSACTION_BYTES => "the provided transaction bytes were invalid".into(),
             Self::INVALID_TRANSACTION => "the provided transaction was invalid".into(),
+            Self::TRANSACTION_REPLACED => "the transaction was removed from the mempool after \
+                                           being replaced by a transaction with the same nonce \
+                                           paying higher fees"
+                .into(),
+            Self::HEIGHT_PRUNED => {
+                "the state at the requested height is no longer retained by the node".into()
+            }
+            Self::INSUFFICIENT_SIGNATURES => "the transaction was not signed by enough keys of \
+                                              the account it was sent on behalf of"
+                .into(),
+            Self::REPLACEMENT_FEE_TOO_LOW => "the transaction does not pay a sufficiently higher \
+                                              fee rate than the transaction with the same nonce \
+                                              in the mempool to replace it"
+                .into(),
             Self(other) => {
                 format!("invalid error code {other}: should be unreachable (this is a bug)")
             }
@@ -94,7 +112,7 @@ mod tests {
 
     use super::*;
 
-    const ALL_CODES: [AbciErrorCode; 21] = [
+    const ALL_CODES: [AbciErrorCode; 25] = [
         AbciErrorCode::UNKNOWN_PATH,
         AbciErrorCode::INVALID_PARAMETER,
         AbciErrorCode::INTERNAL_ERROR,
@@ -116,6 +134,10 @@ mod tests {
         AbciErrorCode::TRANSACTION_FAILED_CHECK_TX,
         AbciErrorCode::INVALID_TRANSACTION_BYTES,
         AbciErrorCode::INVALID_TRANSACTION,
+        AbciErrorCode::TRANSACTION_REPLACED,
+        AbciErrorCode::HEIGHT_PRUNED,
+        AbciErrorCode::INSUFFICIENT_SIGNATURES,
+        AbciErrorCode::REPLACEMENT_FEE_TOO_LOW,
     ];
 
     #[test]
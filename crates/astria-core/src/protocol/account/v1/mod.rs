@@ -1,9 +1,15 @@
 use crate::{
     generated::astria::protocol::accounts::v1 as raw,
-    primitive::v1::asset::{
-        Denom,
-        ParseDenomError,
+    primitive::v1::{
+        asset::{
+            Denom,
+            ParseDenomError,
+        },
+        Address,
+        AddressError,
     },
+    protocol::transaction::v1::action::ReleaseCondition,
+    Protobuf as _,
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -189,12 +195,199 @@ impl NonceResponse {
     }
 }
 
+impl From<ReleaseCondition> for raw::escrow::ReleaseAt {
+    fn from(value: ReleaseCondition) -> Self {
+        match value {
+            ReleaseCondition::Height(height) => Self::Height(height),
+            ReleaseCondition::UnixTimestamp(seconds) => Self::UnixTimestampSeconds(seconds),
+        }
+    }
+}
+
+impl From<raw::escrow::ReleaseAt> for ReleaseCondition {
+    fn from(value: raw::escrow::ReleaseAt) -> Self {
+        match value {
+            raw::escrow::ReleaseAt::Height(height) => Self::Height(height),
+            raw::escrow::ReleaseAt::UnixTimestampSeconds(seconds) => Self::UnixTimestamp(seconds),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct EscrowError(EscrowErrorKind);
+
+impl EscrowError {
+    #[must_use]
+    fn field_not_set(field: &'static str) -> Self {
+        Self(EscrowErrorKind::FieldNotSet(field))
+    }
+
+    #[must_use]
+    fn address(field: &'static str, source: AddressError) -> Self {
+        Self(EscrowErrorKind::Address {
+            field,
+            source,
+        })
+    }
+
+    #[must_use]
+    fn invalid_denom(source: ParseDenomError) -> Self {
+        Self(EscrowErrorKind::InvalidDenom {
+            source,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum EscrowErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("`{field}` field did not contain a valid address")]
+    Address {
+        field: &'static str,
+        source: AddressError,
+    },
+    #[error("`denom` field was invalid")]
+    InvalidDenom { source: ParseDenomError },
+}
+
+/// Funds escrowed by a scheduled transfer, pending release to the recipient.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Escrow {
+    pub id: u64,
+    pub from: Address,
+    pub to: Address,
+    pub denom: Denom,
+    pub amount: u128,
+    pub release_at: ReleaseCondition,
+}
+
+impl Escrow {
+    /// Converts a protobuf [`raw::Escrow`] to an astria native [`Escrow`].
+    ///
+    /// # Errors
+    /// Returns an error if the `from` or `to` addresses or the `denom` are invalid, or if any of
+    /// `from`, `to` or `release_at` is not set.
+    pub fn try_from_raw(proto: &raw::Escrow) -> Result<Self, EscrowError> {
+        let raw::Escrow {
+            id,
+            from,
+            to,
+            denom,
+            amount,
+            release_at,
+        } = proto;
+        let from = from
+            .as_ref()
+            .ok_or_else(|| EscrowError::field_not_set("from"))
+            .and_then(|from| {
+                Address::try_from_raw_ref(from).map_err(|e| EscrowError::address("from", e))
+            })?;
+        let to = to
+            .as_ref()
+            .ok_or_else(|| EscrowError::field_not_set("to"))
+            .and_then(|to| {
+                Address::try_from_raw_ref(to).map_err(|e| EscrowError::address("to", e))
+            })?;
+        let release_at = release_at.ok_or_else(|| EscrowError::field_not_set("release_at"))?;
+        Ok(Self {
+            id: *id,
+            from,
+            to,
+            denom: denom.parse().map_err(EscrowError::invalid_denom)?,
+            amount: amount.map_or(0, Into::into),
+            release_at: release_at.into(),
+        })
+    }
+
+    /// Converts an astria native [`Escrow`] to a protobuf [`raw::Escrow`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::Escrow {
+        raw::Escrow {
+            id: self.id,
+            from: Some(self.from.into_raw()),
+            to: Some(self.to.into_raw()),
+            denom: self.denom.to_string(),
+            amount: Some(self.amount.into()),
+            release_at: Some(self.release_at.into()),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct PendingEscrowsResponseError(PendingEscrowsResponseErrorKind);
+
+impl PendingEscrowsResponseError {
+    #[must_use]
+    fn escrow(source: EscrowError) -> Self {
+        Self(PendingEscrowsResponseErrorKind::Escrow {
+            source,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum PendingEscrowsResponseErrorKind {
+    #[error("`escrows` contained an invalid escrow")]
+    Escrow { source: EscrowError },
+}
+
+/// The sequencer response to a request for the pending escrows sent from or to a given account
+/// at a given height.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingEscrowsResponse {
+    pub height: u64,
+    pub escrows: Vec<Escrow>,
+}
+
+impl PendingEscrowsResponse {
+    /// Converts a protobuf [`raw::PendingEscrowsResponse`] to an astria
+    /// native [`PendingEscrowsResponse`].
+    ///
+    /// # Errors
+    /// Returns an error if one or more of the entries in the protobuf `escrows` field is invalid.
+    pub fn try_from_raw(
+        proto: &raw::PendingEscrowsResponse,
+    ) -> Result<Self, PendingEscrowsResponseError> {
+        let raw::PendingEscrowsResponse {
+            height,
+            escrows,
+        } = proto;
+        Ok(Self {
+            height: *height,
+            escrows: escrows
+                .iter()
+                .map(Escrow::try_from_raw)
+                .collect::<Result<_, _>>()
+                .map_err(PendingEscrowsResponseError::escrow)?,
+        })
+    }
+
+    /// Converts an astria native [`PendingEscrowsResponse`] to a
+    /// protobuf [`raw::PendingEscrowsResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::PendingEscrowsResponse {
+        raw::PendingEscrowsResponse {
+            height: self.height,
+            escrows: self.escrows.into_iter().map(Escrow::into_raw).collect(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
         AssetBalance,
         BalanceResponse,
+        Escrow,
         NonceResponse,
+        PendingEscrowsResponse,
+    };
+    use crate::{
+        primitive::v1::Address,
+        protocol::transaction::v1::action::ReleaseCondition,
     };
 
     #[test]
@@ -220,4 +413,38 @@ mod tests {
         let actual = expected.into_raw().into_native();
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn pending_escrows_roundtrip_is_correct() {
+        let address = |byte| {
+            Address::builder()
+                .array([byte; 20])
+                .prefix("astria")
+                .try_build()
+                .unwrap()
+        };
+        let expected = PendingEscrowsResponse {
+            height: 42,
+            escrows: vec![
+                Escrow {
+                    id: 0,
+                    from: address(1),
+                    to: address(2),
+                    denom: "nria".parse().unwrap(),
+                    amount: 999,
+                    release_at: ReleaseCondition::Height(100),
+                },
+                Escrow {
+                    id: 1,
+                    from: address(2),
+                    to: address(1),
+                    denom: "nria".parse().unwrap(),
+                    amount: 1,
+                    release_at: ReleaseCondition::UnixTimestamp(1_700_000_000),
+                },
+            ],
+        };
+        let actual = PendingEscrowsResponse::try_from_raw(&expected.clone().into_raw()).unwrap();
+        assert_eq!(expected, actual);
+    }
 }
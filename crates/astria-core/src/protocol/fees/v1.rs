@@ -14,6 +14,7 @@ use crate::{
     generated::astria::protocol::fees::v1 as raw,
     primitive::v1::asset,
     protocol::transaction::v1::action::{
+        Bond,
         BridgeLock,
         BridgeSudoChange,
         BridgeTransfer,
@@ -21,16 +22,25 @@ use crate::{
         CurrencyPairsChange,
         FeeAssetChange,
         FeeChange,
+        GovernanceParametersChange,
         IbcRelayerChange,
         IbcSudoChange,
+        Ics20RateLimitChange,
         Ics20Withdrawal,
         InitBridgeAccount,
         MarketsChange,
+        ProposalSubmission,
+        ProposalVote,
         RecoverIbcClient,
+        RegisterMultisigAccount,
         RollupDataSubmission,
+        ScheduledTransfer,
+        StakingParametersChange,
         SudoAddressChange,
         Transfer,
+        Unbond,
         ValidatorUpdate,
+        VoteExtensionParticipationChange,
     },
     Protobuf,
 };
@@ -116,6 +126,16 @@ impl_protobuf_for_fee_components!(
     FeeComponents<RecoverIbcClient> => raw::RecoverIbcClientFeeComponents,
     FeeComponents<CurrencyPairsChange> => raw::CurrencyPairsChangeFeeComponents,
     FeeComponents<MarketsChange> => raw::MarketsChangeFeeComponents,
+    FeeComponents<RegisterMultisigAccount> => raw::RegisterMultisigAccountFeeComponents,
+    FeeComponents<ScheduledTransfer> => raw::ScheduledTransferFeeComponents,
+    FeeComponents<Ics20RateLimitChange> => raw::Ics20RateLimitChangeFeeComponents,
+    FeeComponents<VoteExtensionParticipationChange> => raw::VoteExtensionParticipationChangeFeeComponents,
+    FeeComponents<Bond> => raw::BondFeeComponents,
+    FeeComponents<Unbond> => raw::UnbondFeeComponents,
+    FeeComponents<ProposalSubmission> => raw::ProposalSubmissionFeeComponents,
+    FeeComponents<ProposalVote> => raw::ProposalVoteFeeComponents,
+    FeeComponents<StakingParametersChange> => raw::StakingParametersChangeFeeComponents,
+    FeeComponents<GovernanceParametersChange> => raw::GovernanceParametersChangeFeeComponents,
 );
 
 pub struct FeeComponents<T: ?Sized> {
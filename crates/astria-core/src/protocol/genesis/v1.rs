@@ -22,6 +22,7 @@ use crate::{
             FeeComponents,
         },
         transaction::v1::action::{
+            Bond,
             BridgeLock,
             BridgeSudoChange,
             BridgeTransfer,
@@ -29,16 +30,25 @@ use crate::{
             CurrencyPairsChange,
             FeeAssetChange,
             FeeChange,
+            GovernanceParametersChange,
             IbcRelayerChange,
             IbcSudoChange,
+            Ics20RateLimitChange,
             Ics20Withdrawal,
             InitBridgeAccount,
             MarketsChange,
+            ProposalSubmission,
+            ProposalVote,
             RecoverIbcClient,
+            RegisterMultisigAccount,
             RollupDataSubmission,
+            ScheduledTransfer,
+            StakingParametersChange,
             SudoAddressChange,
             Transfer,
+            Unbond,
             ValidatorUpdate,
+            VoteExtensionParticipationChange,
         },
     },
     Protobuf,
@@ -65,6 +75,8 @@ pub struct GenesisAppState {
     ibc_parameters: IBCParameters,
     allowed_fee_assets: Vec<asset::Denom>,
     fees: GenesisFees,
+    staking: Option<StakingParameters>,
+    governance: Option<GovernanceParameters>,
 }
 
 impl GenesisAppState {
@@ -118,6 +130,18 @@ impl GenesisAppState {
         &self.fees
     }
 
+    /// Returns the staking parameters, if the validator set is managed by staking.
+    #[must_use]
+    pub fn staking(&self) -> Option<&StakingParameters> {
+        self.staking.as_ref()
+    }
+
+    /// Returns the governance parameters, if governance proposals are enabled.
+    #[must_use]
+    pub fn governance(&self) -> Option<&GovernanceParameters> {
+        self.governance.as_ref()
+    }
+
     fn ensure_address_has_base_prefix(
         &self,
         address: &Address,
@@ -169,6 +193,8 @@ impl Protobuf for GenesisAppState {
             ibc_parameters,
             allowed_fee_assets,
             fees,
+            staking,
+            governance,
         } = raw;
         let address_prefixes = address_prefixes
             .as_ref()
@@ -230,6 +256,18 @@ impl Protobuf for GenesisAppState {
             .ok_or_else(|| Self::Error::field_not_set("fees"))
             .and_then(|fees| GenesisFees::try_from_raw_ref(fees).map_err(Self::Error::fees))?;
 
+        let staking = staking
+            .as_ref()
+            .map(StakingParameters::try_from_raw_ref)
+            .transpose()
+            .map_err(Self::Error::staking)?;
+
+        let governance = governance
+            .as_ref()
+            .map(GovernanceParameters::try_from_raw_ref)
+            .transpose()
+            .map_err(Self::Error::governance)?;
+
         let this = Self {
             address_prefixes,
             accounts,
@@ -241,6 +279,8 @@ impl Protobuf for GenesisAppState {
             ibc_parameters,
             allowed_fee_assets,
             fees,
+            staking,
+            governance,
         };
         this.ensure_all_addresses_have_base_prefix()
             .map_err(Self::Error::address_does_not_match_base)?;
@@ -259,6 +299,8 @@ impl Protobuf for GenesisAppState {
             ibc_parameters,
             allowed_fee_assets,
             fees,
+            staking,
+            governance,
         } = self;
         Self::Raw {
             address_prefixes: Some(address_prefixes.to_raw()),
@@ -273,6 +315,8 @@ impl Protobuf for GenesisAppState {
             ibc_parameters: Some(ibc_parameters.to_raw()),
             allowed_fee_assets: allowed_fee_assets.iter().map(ToString::to_string).collect(),
             fees: Some(fees.to_raw()),
+            staking: staking.as_ref().map(StakingParameters::to_raw),
+            governance: governance.as_ref().map(GovernanceParameters::to_raw),
         }
     }
 }
@@ -355,6 +399,18 @@ impl GenesisAppStateError {
             source,
         })
     }
+
+    fn staking(source: StakingParametersError) -> Self {
+        Self(GenesisAppStateErrorKind::Staking {
+            source,
+        })
+    }
+
+    fn governance(source: GovernanceParametersError) -> Self {
+        Self(GenesisAppStateErrorKind::Governance {
+            source,
+        })
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -382,6 +438,10 @@ enum GenesisAppStateErrorKind {
     FieldNotSet { name: &'static str },
     #[error("`native_asset_base_denomination` field was invalid")]
     NativeAssetBaseDenomination { source: ParseTracePrefixedError },
+    #[error("`staking` field was invalid")]
+    Staking { source: StakingParametersError },
+    #[error("`governance` field was invalid")]
+    Governance { source: GovernanceParametersError },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -582,6 +642,166 @@ impl From<raw::IbcParameters> for IBCParameters {
     }
 }
 
+/// The parameters of the native staking subsystem.
+///
+/// Both the unbonding period and the stake per unit of validator power are guaranteed to be
+/// non-zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StakingParameters {
+    unbonding_period_blocks: u64,
+    stake_per_power: u128,
+}
+
+impl StakingParameters {
+    /// Returns the number of blocks after which unbonded stake is returned to the delegator.
+    #[must_use]
+    pub fn unbonding_period_blocks(&self) -> u64 {
+        self.unbonding_period_blocks
+    }
+
+    /// Returns the amount of bonded native asset corresponding to one unit of validator power.
+    #[must_use]
+    pub fn stake_per_power(&self) -> u128 {
+        self.stake_per_power
+    }
+}
+
+impl Protobuf for StakingParameters {
+    type Error = StakingParametersError;
+    type Raw = raw::StakingParameters;
+
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let Self::Raw {
+            unbonding_period_blocks,
+            stake_per_power,
+        } = raw;
+        if *unbonding_period_blocks == 0 {
+            return Err(Self::Error::zero("unbonding_period_blocks"));
+        }
+        let stake_per_power: u128 = stake_per_power
+            .ok_or_else(|| Self::Error::field_not_set("stake_per_power"))?
+            .into();
+        if stake_per_power == 0 {
+            return Err(Self::Error::zero("stake_per_power"));
+        }
+        Ok(Self {
+            unbonding_period_blocks: *unbonding_period_blocks,
+            stake_per_power,
+        })
+    }
+
+    fn to_raw(&self) -> Self::Raw {
+        let Self {
+            unbonding_period_blocks,
+            stake_per_power,
+        } = self;
+        Self::Raw {
+            unbonding_period_blocks: *unbonding_period_blocks,
+            stake_per_power: Some((*stake_per_power).into()),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct StakingParametersError(StakingParametersErrorKind);
+
+impl StakingParametersError {
+    fn field_not_set(name: &'static str) -> Self {
+        Self(StakingParametersErrorKind::FieldNotSet {
+            name,
+        })
+    }
+
+    fn zero(name: &'static str) -> Self {
+        Self(StakingParametersErrorKind::Zero {
+            name,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed ensuring invariants of {}", StakingParameters::full_name())]
+enum StakingParametersErrorKind {
+    #[error("field was not set: `{name}`")]
+    FieldNotSet { name: &'static str },
+    #[error("field must not be zero: `{name}`")]
+    Zero { name: &'static str },
+}
+
+/// The parameters of the governance subsystem.
+///
+/// The voting period is guaranteed to be non-zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GovernanceParameters {
+    voting_period_blocks: u64,
+    proposal_deposit: u128,
+}
+
+impl GovernanceParameters {
+    /// Returns the number of blocks during which validators can vote on a proposal.
+    #[must_use]
+    pub fn voting_period_blocks(&self) -> u64 {
+        self.voting_period_blocks
+    }
+
+    /// Returns the amount of the native asset deposited by the proposer of a proposal until the
+    /// proposal is finalized. Zero if no deposit is required.
+    #[must_use]
+    pub fn proposal_deposit(&self) -> u128 {
+        self.proposal_deposit
+    }
+}
+
+impl Protobuf for GovernanceParameters {
+    type Error = GovernanceParametersError;
+    type Raw = raw::GovernanceParameters;
+
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let Self::Raw {
+            voting_period_blocks,
+            proposal_deposit,
+        } = raw;
+        if *voting_period_blocks == 0 {
+            return Err(Self::Error::zero("voting_period_blocks"));
+        }
+        Ok(Self {
+            voting_period_blocks: *voting_period_blocks,
+            proposal_deposit: proposal_deposit.map_or(0, u128::from),
+        })
+    }
+
+    fn to_raw(&self) -> Self::Raw {
+        let Self {
+            voting_period_blocks,
+            proposal_deposit,
+        } = self;
+        Self::Raw {
+            voting_period_blocks: *voting_period_blocks,
+            proposal_deposit: Some((*proposal_deposit).into()),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct GovernanceParametersError(GovernanceParametersErrorKind);
+
+impl GovernanceParametersError {
+    fn zero(name: &'static str) -> Self {
+        Self(GovernanceParametersErrorKind::Zero {
+            name,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed ensuring invariants of {}", GovernanceParameters::full_name())]
+enum GovernanceParametersErrorKind {
+    #[error("field must not be zero: `{name}`")]
+    Zero { name: &'static str },
+}
+
 #[derive(Clone, Debug)]
 pub struct GenesisFees {
     pub rollup_data_submission: Option<FeeComponents<RollupDataSubmission>>,
@@ -602,6 +822,17 @@ pub struct GenesisFees {
     pub recover_ibc_client: Option<FeeComponents<RecoverIbcClient>>,
     pub currency_pairs_change: Option<FeeComponents<CurrencyPairsChange>>,
     pub markets_change: Option<FeeComponents<MarketsChange>>,
+    pub register_multisig_account: Option<FeeComponents<RegisterMultisigAccount>>,
+    pub scheduled_transfer: Option<FeeComponents<ScheduledTransfer>>,
+    pub ics20_rate_limit_change: Option<FeeComponents<Ics20RateLimitChange>>,
+    pub vote_extension_participation_change:
+        Option<FeeComponents<VoteExtensionParticipationChange>>,
+    pub bond: Option<FeeComponents<Bond>>,
+    pub unbond: Option<FeeComponents<Unbond>>,
+    pub proposal_submission: Option<FeeComponents<ProposalSubmission>>,
+    pub proposal_vote: Option<FeeComponents<ProposalVote>>,
+    pub staking_parameters_change: Option<FeeComponents<StakingParametersChange>>,
+    pub governance_parameters_change: Option<FeeComponents<GovernanceParametersChange>>,
 }
 
 impl Protobuf for GenesisFees {
@@ -629,6 +860,16 @@ impl Protobuf for GenesisFees {
             recover_ibc_client,
             currency_pairs_change,
             markets_change,
+            register_multisig_account,
+            scheduled_transfer,
+            ics20_rate_limit_change,
+            vote_extension_participation_change,
+            bond,
+            unbond,
+            proposal_submission,
+            proposal_vote,
+            staking_parameters_change,
+            governance_parameters_change,
         } = raw;
         let rollup_data_submission = rollup_data_submission
             .map(FeeComponents::<RollupDataSubmission>::try_from_raw)
@@ -720,6 +961,56 @@ impl Protobuf for GenesisFees {
             .transpose()
             .map_err(|e| FeesError::fee_components("markets_change", e))?;
 
+        let register_multisig_account = register_multisig_account
+            .map(FeeComponents::<RegisterMultisigAccount>::try_from_raw)
+            .transpose()
+            .map_err(|e| FeesError::fee_components("register_multisig_account", e))?;
+
+        let scheduled_transfer = scheduled_transfer
+            .map(FeeComponents::<ScheduledTransfer>::try_from_raw)
+            .transpose()
+            .map_err(|e| FeesError::fee_components("scheduled_transfer", e))?;
+
+        let ics20_rate_limit_change = ics20_rate_limit_change
+            .map(FeeComponents::<Ics20RateLimitChange>::try_from_raw)
+            .transpose()
+            .map_err(|e| FeesError::fee_components("ics20_rate_limit_change", e))?;
+
+        let vote_extension_participation_change = vote_extension_participation_change
+            .map(FeeComponents::<VoteExtensionParticipationChange>::try_from_raw)
+            .transpose()
+            .map_err(|e| FeesError::fee_components("vote_extension_participation_change", e))?;
+
+        let bond = bond
+            .map(FeeComponents::<Bond>::try_from_raw)
+            .transpose()
+            .map_err(|e| FeesError::fee_components("bond", e))?;
+
+        let unbond = unbond
+            .map(FeeComponents::<Unbond>::try_from_raw)
+            .transpose()
+            .map_err(|e| FeesError::fee_components("unbond", e))?;
+
+        let proposal_submission = proposal_submission
+            .map(FeeComponents::<ProposalSubmission>::try_from_raw)
+            .transpose()
+            .map_err(|e| FeesError::fee_components("proposal_submission", e))?;
+
+        let proposal_vote = proposal_vote
+            .map(FeeComponents::<ProposalVote>::try_from_raw)
+            .transpose()
+            .map_err(|e| FeesError::fee_components("proposal_vote", e))?;
+
+        let staking_parameters_change = staking_parameters_change
+            .map(FeeComponents::<StakingParametersChange>::try_from_raw)
+            .transpose()
+            .map_err(|e| FeesError::fee_components("staking_parameters_change", e))?;
+
+        let governance_parameters_change = governance_parameters_change
+            .map(FeeComponents::<GovernanceParametersChange>::try_from_raw)
+            .transpose()
+            .map_err(|e| FeesError::fee_components("governance_parameters_change", e))?;
+
         Ok(Self {
             rollup_data_submission,
             transfer,
@@ -739,6 +1030,16 @@ impl Protobuf for GenesisFees {
             recover_ibc_client,
             currency_pairs_change,
             markets_change,
+            register_multisig_account,
+            scheduled_transfer,
+            ics20_rate_limit_change,
+            vote_extension_participation_change,
+            bond,
+            unbond,
+            proposal_submission,
+            proposal_vote,
+            staking_parameters_change,
+            governance_parameters_change,
         })
     }
 
@@ -762,6 +1063,16 @@ impl Protobuf for GenesisFees {
             recover_ibc_client,
             currency_pairs_change,
             markets_change,
+            register_multisig_account,
+            scheduled_transfer,
+            ics20_rate_limit_change,
+            vote_extension_participation_change,
+            bond,
+            unbond,
+            proposal_submission,
+            proposal_vote,
+            staking_parameters_change,
+            governance_parameters_change,
         } = self;
         Self::Raw {
             transfer: transfer.map(|act| FeeComponents::<Transfer>::to_raw(&act)),
@@ -794,6 +1105,23 @@ impl Protobuf for GenesisFees {
             currency_pairs_change: currency_pairs_change
                 .map(|act| FeeComponents::<CurrencyPairsChange>::to_raw(&act)),
             markets_change: markets_change.map(|act| FeeComponents::<MarketsChange>::to_raw(&act)),
+            register_multisig_account: register_multisig_account
+                .map(|act| FeeComponents::<RegisterMultisigAccount>::to_raw(&act)),
+            scheduled_transfer: scheduled_transfer
+                .map(|act| FeeComponents::<ScheduledTransfer>::to_raw(&act)),
+            ics20_rate_limit_change: ics20_rate_limit_change
+                .map(|act| FeeComponents::<Ics20RateLimitChange>::to_raw(&act)),
+            vote_extension_participation_change: vote_extension_participation_change
+                .map(|act| FeeComponents::<VoteExtensionParticipationChange>::to_raw(&act)),
+            bond: bond.map(|act| FeeComponents::<Bond>::to_raw(&act)),
+            unbond: unbond.map(|act| FeeComponents::<Unbond>::to_raw(&act)),
+            proposal_submission: proposal_submission
+                .map(|act| FeeComponents::<ProposalSubmission>::to_raw(&act)),
+            proposal_vote: proposal_vote.map(|act| FeeComponents::<ProposalVote>::to_raw(&act)),
+            staking_parameters_change: staking_parameters_change
+                .map(|act| FeeComponents::<StakingParametersChange>::to_raw(&act)),
+            governance_parameters_change: governance_parameters_change
+                .map(|act| FeeComponents::<GovernanceParametersChange>::to_raw(&act)),
         }
     }
 }
@@ -890,6 +1218,26 @@ mod tests {
             recover_ibc_client: Some(FeeComponents::<RecoverIbcClient>::new(0, 0).to_raw()),
             currency_pairs_change: Some(FeeComponents::<CurrencyPairsChange>::new(0, 0).to_raw()),
             markets_change: Some(FeeComponents::<MarketsChange>::new(0, 0).to_raw()),
+            register_multisig_account: Some(
+                FeeComponents::<RegisterMultisigAccount>::new(0, 0).to_raw(),
+            ),
+            scheduled_transfer: Some(FeeComponents::<ScheduledTransfer>::new(0, 0).to_raw()),
+            ics20_rate_limit_change: Some(
+                FeeComponents::<Ics20RateLimitChange>::new(0, 0).to_raw(),
+            ),
+            vote_extension_participation_change: Some(
+                FeeComponents::<VoteExtensionParticipationChange>::new(0, 0).to_raw(),
+            ),
+            bond: Some(FeeComponents::<Bond>::new(0, 0).to_raw()),
+            unbond: Some(FeeComponents::<Unbond>::new(0, 0).to_raw()),
+            proposal_submission: Some(FeeComponents::<ProposalSubmission>::new(0, 0).to_raw()),
+            proposal_vote: Some(FeeComponents::<ProposalVote>::new(0, 0).to_raw()),
+            staking_parameters_change: Some(
+                FeeComponents::<StakingParametersChange>::new(0, 0).to_raw(),
+            ),
+            governance_parameters_change: Some(
+                FeeComponents::<GovernanceParametersChange>::new(0, 0).to_raw(),
+            ),
         }
     }
 
@@ -925,6 +1273,14 @@ mod tests {
             }),
             allowed_fee_assets: vec!["nria".into()],
             fees: Some(genesis_fees()),
+            staking: Some(raw::StakingParameters {
+                unbonding_period_blocks: 100,
+                stake_per_power: Some(1_000_000.into()),
+            }),
+            governance: Some(raw::GovernanceParameters {
+                voting_period_blocks: 50,
+                proposal_deposit: Some(1_000.into()),
+            }),
         }
     }
 
@@ -999,6 +1355,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn zero_staking_parameters_are_rejected() {
+        #[track_caller]
+        fn assert_zero(staking: raw::StakingParameters, zero_field: &'static str) {
+            let unchecked = raw::GenesisAppState {
+                staking: Some(staking),
+                ..proto_genesis_state()
+            };
+            match GenesisAppState::try_from(unchecked)
+                .expect_err("zero staking parameters should have been rejected")
+                .0
+            {
+                GenesisAppStateErrorKind::Staking {
+                    source:
+                        StakingParametersError(StakingParametersErrorKind::Zero {
+                            name,
+                        }),
+                } => assert_eq!(name, zero_field),
+                other => panic!("expected: `StakingParametersErrorKind::Zero`\ngot: {other:?}"),
+            }
+        }
+        assert_zero(
+            raw::StakingParameters {
+                unbonding_period_blocks: 0,
+                stake_per_power: Some(1.into()),
+            },
+            "unbonding_period_blocks",
+        );
+        assert_zero(
+            raw::StakingParameters {
+                unbonding_period_blocks: 1,
+                stake_per_power: Some(0.into()),
+            },
+            "stake_per_power",
+        );
+    }
+
+    #[test]
+    fn zero_voting_period_is_rejected() {
+        let unchecked = raw::GenesisAppState {
+            governance: Some(raw::GovernanceParameters {
+                voting_period_blocks: 0,
+            }),
+            ..proto_genesis_state()
+        };
+        match GenesisAppState::try_from(unchecked)
+            .expect_err("a zero voting period should have been rejected")
+            .0
+        {
+            GenesisAppStateErrorKind::Governance {
+                source:
+                    GovernanceParametersError(GovernanceParametersErrorKind::Zero {
+                        name,
+                    }),
+            } => assert_eq!(name, "voting_period_blocks"),
+            other => panic!("expected: `GovernanceParametersErrorKind::Zero`\ngot: {other:?}"),
+        }
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn genesis_state_is_unchanged() {
@@ -0,0 +1,184 @@
+use super::raw;
+use crate::primitive::v1::asset::{
+    self,
+    denom::ParseDenomError,
+};
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct Ics20RateLimitInfoError(Ics20RateLimitInfoErrorKind);
+
+impl Ics20RateLimitInfoError {
+    #[must_use]
+    fn invalid_asset(source: ParseDenomError) -> Self {
+        Self(Ics20RateLimitInfoErrorKind::InvalidAsset {
+            source,
+        })
+    }
+
+    #[must_use]
+    fn zero_window_blocks() -> Self {
+        Self(Ics20RateLimitInfoErrorKind::ZeroWindowBlocks)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum Ics20RateLimitInfoErrorKind {
+    #[error("`asset` field was invalid")]
+    InvalidAsset { source: ParseDenomError },
+    #[error("`window_blocks` field must be non-zero")]
+    ZeroWindowBlocks,
+}
+
+/// The rate limit on ICS20 transfers of an asset over a channel, and the amounts transferred in
+/// the current window.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ics20RateLimitInfo {
+    pub asset: asset::Denom,
+    pub max_inflow: u128,
+    pub max_outflow: u128,
+    pub window_blocks: u64,
+    pub inflow: u128,
+    pub outflow: u128,
+    pub window_start_height: u64,
+}
+
+impl Ics20RateLimitInfo {
+    /// Converts a protobuf [`raw::Ics20RateLimitInfo`] to an astria native
+    /// [`Ics20RateLimitInfo`].
+    ///
+    /// # Errors
+    /// Returns an error if the `asset` is invalid or `window_blocks` is zero.
+    pub fn try_from_raw(proto: &raw::Ics20RateLimitInfo) -> Result<Self, Ics20RateLimitInfoError> {
+        let raw::Ics20RateLimitInfo {
+            asset,
+            max_inflow,
+            max_outflow,
+            window_blocks,
+            inflow,
+            outflow,
+            window_start_height,
+        } = proto;
+        if *window_blocks == 0 {
+            return Err(Ics20RateLimitInfoError::zero_window_blocks());
+        }
+        Ok(Self {
+            asset: asset
+                .parse()
+                .map_err(Ics20RateLimitInfoError::invalid_asset)?,
+            max_inflow: max_inflow.map_or(0, Into::into),
+            max_outflow: max_outflow.map_or(0, Into::into),
+            window_blocks: *window_blocks,
+            inflow: inflow.map_or(0, Into::into),
+            outflow: outflow.map_or(0, Into::into),
+            window_start_height: *window_start_height,
+        })
+    }
+
+    /// Converts an astria native [`Ics20RateLimitInfo`] to a protobuf
+    /// [`raw::Ics20RateLimitInfo`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::Ics20RateLimitInfo {
+        raw::Ics20RateLimitInfo {
+            asset: self.asset.to_string(),
+            max_inflow: Some(self.max_inflow.into()),
+            max_outflow: Some(self.max_outflow.into()),
+            window_blocks: self.window_blocks,
+            inflow: Some(self.inflow.into()),
+            outflow: Some(self.outflow.into()),
+            window_start_height: self.window_start_height,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct Ics20RateLimitsResponseError(Ics20RateLimitsResponseErrorKind);
+
+impl Ics20RateLimitsResponseError {
+    #[must_use]
+    fn rate_limit(source: Ics20RateLimitInfoError) -> Self {
+        Self(Ics20RateLimitsResponseErrorKind::RateLimit {
+            source,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum Ics20RateLimitsResponseErrorKind {
+    #[error("`rate_limits` contained an invalid rate limit")]
+    RateLimit { source: Ics20RateLimitInfoError },
+}
+
+/// The sequencer response to a request for the rate limits on ICS20 transfers over a channel at a
+/// given height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ics20RateLimitsResponse {
+    pub height: u64,
+    pub rate_limits: Vec<Ics20RateLimitInfo>,
+}
+
+impl Ics20RateLimitsResponse {
+    /// Converts a protobuf [`raw::Ics20RateLimitsResponse`] to an astria native
+    /// [`Ics20RateLimitsResponse`].
+    ///
+    /// # Errors
+    /// Returns an error if one or more of the entries in the protobuf `rate_limits` field is
+    /// invalid.
+    pub fn try_from_raw(
+        proto: &raw::Ics20RateLimitsResponse,
+    ) -> Result<Self, Ics20RateLimitsResponseError> {
+        let raw::Ics20RateLimitsResponse {
+            height,
+            rate_limits,
+        } = proto;
+        Ok(Self {
+            height: *height,
+            rate_limits: rate_limits
+                .iter()
+                .map(Ics20RateLimitInfo::try_from_raw)
+                .collect::<Result<_, _>>()
+                .map_err(Ics20RateLimitsResponseError::rate_limit)?,
+        })
+    }
+
+    /// Converts an astria native [`Ics20RateLimitsResponse`] to a protobuf
+    /// [`raw::Ics20RateLimitsResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::Ics20RateLimitsResponse {
+        raw::Ics20RateLimitsResponse {
+            height: self.height,
+            rate_limits: self
+                .rate_limits
+                .into_iter()
+                .map(Ics20RateLimitInfo::into_raw)
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Ics20RateLimitInfo,
+        Ics20RateLimitsResponse,
+    };
+
+    #[test]
+    fn ics20_rate_limits_response_roundtrip_is_correct() {
+        let expected = Ics20RateLimitsResponse {
+            height: 42,
+            rate_limits: vec![Ics20RateLimitInfo {
+                asset: "transfer/channel-0/uatom".parse().unwrap(),
+                max_inflow: 1000,
+                max_outflow: 500,
+                window_blocks: 100,
+                inflow: 10,
+                outflow: 20,
+                window_start_height: 40,
+            }],
+        };
+        let actual = Ics20RateLimitsResponse::try_from_raw(&expected.clone().into_raw()).unwrap();
+        assert_eq!(expected, actual);
+    }
+}
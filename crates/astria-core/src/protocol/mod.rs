@@ -13,8 +13,11 @@ pub mod asset;
 pub mod bridge;
 pub mod fees;
 pub mod genesis;
+pub mod ibc;
 pub mod memos;
 pub mod price_feed;
+pub mod proof;
+pub mod staking;
 pub mod transaction;
 
 #[cfg(any(feature = "test-utils", test))]
@@ -0,0 +1,132 @@
+//! The storage layout of the sequencer state returned with proofs by ABCI queries.
+//!
+//! The sequencer reads and writes the state under these keys, and clients use the keys and value
+//! encodings to check that the values proven against an app hash match the query responses.
+
+/// The type of the proof operations attached to ABCI query responses.
+///
+/// The data of each operation is a protobuf-encoded `ibc.core.commitment.v1.MerkleProof`,
+/// containing an ICS-23 existence or non-existence proof of the key against the JMT root hash,
+/// which is the app hash of the block committing the queried state.
+pub const PROOF_OP_TYPE: &str = "ics23:jmt";
+
+pub mod keys {
+    //! Storage keys of proven values.
+
+    use base64::{
+        display::Base64Display,
+        engine::general_purpose::URL_SAFE,
+    };
+
+    use crate::primitive::v1::{
+        asset::IbcPrefixed,
+        ADDRESS_LEN,
+    };
+
+    fn account_key(prefix: &str, address: &[u8; ADDRESS_LEN]) -> String {
+        format!("{prefix}{}", Base64Display::new(address, &URL_SAFE))
+    }
+
+    /// Example: `accounts/gGhH....zZ4=/balance/ibc/0202....0202`.
+    ///                   |base64 chars|           |64 hex chars|
+    #[must_use]
+    pub fn balance(address: &[u8; ADDRESS_LEN], asset: &IbcPrefixed) -> String {
+        format!("{}/balance/{asset}", account_key("accounts/", address))
+    }
+
+    /// Example: `accounts/gGhH....zZ4=/nonce`.
+    ///                   |base64 chars|
+    #[must_use]
+    pub fn nonce(address: &[u8; ADDRESS_LEN]) -> String {
+        format!("{}/nonce", account_key("accounts/", address))
+    }
+
+    /// Example: `bridge/account/gGhH....zZ4=/rollup_id`.
+    ///                         |base64 chars|
+    #[must_use]
+    pub fn bridge_account_rollup_id(address: &[u8; ADDRESS_LEN]) -> String {
+        format!("{}/rollup_id", account_key("bridge/account/", address))
+    }
+
+    /// Example: `bridge/account/gGhH....zZ4=/asset_id`.
+    ///                         |base64 chars|
+    #[must_use]
+    pub fn bridge_account_asset_id(address: &[u8; ADDRESS_LEN]) -> String {
+        format!("{}/asset_id", account_key("bridge/account/", address))
+    }
+
+    /// Example: `bridge/sudo/gGhH....zZ4=`.
+    ///                      |base64 chars|
+    #[must_use]
+    pub fn bridge_account_sudo_address(address: &[u8; ADDRESS_LEN]) -> String {
+        account_key("bridge/sudo/", address)
+    }
+
+    /// Example: `bridge/withdrawer/gGhH....zZ4=`.
+    ///                            |base64 chars|
+    #[must_use]
+    pub fn bridge_account_withdrawer_address(address: &[u8; ADDRESS_LEN]) -> String {
+        account_key("bridge/withdrawer/", address)
+    }
+}
+
+pub mod values {
+    //! Encodings of proven values.
+    //!
+    //! These are the borsh encodings of the values as stored by the sequencer: a tag identifying
+    //! the component, a tag identifying the type of the value within the component, followed by
+    //! the value itself. The sequencer tests that its stored values encode identically.
+
+    use crate::primitive::v1::{
+        asset::IbcPrefixed,
+        RollupId,
+        ADDRESS_LEN,
+    };
+
+    const ACCOUNTS: u8 = 3;
+    const ACCOUNTS_BALANCE: u8 = 0;
+    const ACCOUNTS_NONCE: u8 = 1;
+
+    const BRIDGE: u8 = 6;
+    const BRIDGE_ROLLUP_ID: u8 = 0;
+    const BRIDGE_IBC_PREFIXED_DENOM: u8 = 1;
+    const BRIDGE_ADDRESS_BYTES: u8 = 2;
+
+    fn encode(component: u8, kind: u8, value: &[u8]) -> Vec<u8> {
+        [&[component, kind][..], value].concat()
+    }
+
+    /// The value stored under [`keys::balance`](super::keys::balance).
+    #[must_use]
+    pub fn balance(balance: u128) -> Vec<u8> {
+        encode(ACCOUNTS, ACCOUNTS_BALANCE, &balance.to_le_bytes())
+    }
+
+    /// The value stored under [`keys::nonce`](super::keys::nonce).
+    #[must_use]
+    pub fn nonce(nonce: u32) -> Vec<u8> {
+        encode(ACCOUNTS, ACCOUNTS_NONCE, &nonce.to_le_bytes())
+    }
+
+    /// The value stored under
+    /// [`keys::bridge_account_rollup_id`](super::keys::bridge_account_rollup_id).
+    #[must_use]
+    pub fn bridge_account_rollup_id(rollup_id: &RollupId) -> Vec<u8> {
+        encode(BRIDGE, BRIDGE_ROLLUP_ID, rollup_id.as_bytes())
+    }
+
+    /// The value stored under
+    /// [`keys::bridge_account_asset_id`](super::keys::bridge_account_asset_id).
+    #[must_use]
+    pub fn bridge_account_asset_id(asset: &IbcPrefixed) -> Vec<u8> {
+        encode(BRIDGE, BRIDGE_IBC_PREFIXED_DENOM, asset.as_bytes())
+    }
+
+    /// The value stored under
+    /// [`keys::bridge_account_sudo_address`](super::keys::bridge_account_sudo_address) and
+    /// [`keys::bridge_account_withdrawer_address`](super::keys::bridge_account_withdrawer_address).
+    #[must_use]
+    pub fn bridge_account_address(address: &[u8; ADDRESS_LEN]) -> Vec<u8> {
+        encode(BRIDGE, BRIDGE_ADDRESS_BYTES, address)
+    }
+}
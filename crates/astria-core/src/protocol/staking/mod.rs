@@ -0,0 +1,3 @@
+pub mod v1;
+
+use crate::generated::astria::protocol::staking::v1 as raw;
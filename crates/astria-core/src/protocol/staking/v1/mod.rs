@@ -0,0 +1,232 @@
+use bytes::Bytes;
+
+use super::raw;
+use crate::crypto::{
+    self,
+    VerificationKey,
+};
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct DelegationError(DelegationErrorKind);
+
+impl DelegationError {
+    #[must_use]
+    fn invalid_validator(source: crypto::Error) -> Self {
+        Self(DelegationErrorKind::InvalidValidator {
+            source,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum DelegationErrorKind {
+    #[error("`validator` field did not contain a valid verification key")]
+    InvalidValidator { source: crypto::Error },
+}
+
+/// The native asset bonded by a delegator to a validator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Delegation {
+    pub validator: VerificationKey,
+    pub amount: u128,
+}
+
+impl Delegation {
+    /// Converts a protobuf [`raw::Delegation`] to an astria native [`Delegation`].
+    ///
+    /// # Errors
+    /// Returns an error if the `validator` is not a valid verification key.
+    pub fn try_from_raw(proto: &raw::Delegation) -> Result<Self, DelegationError> {
+        let raw::Delegation {
+            validator,
+            amount,
+        } = proto;
+        Ok(Self {
+            validator: VerificationKey::try_from(&**validator)
+                .map_err(DelegationError::invalid_validator)?,
+            amount: amount.map_or(0, Into::into),
+        })
+    }
+
+    /// Converts an astria native [`Delegation`] to a protobuf [`raw::Delegation`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::Delegation {
+        raw::Delegation {
+            validator: Bytes::copy_from_slice(&self.validator.to_bytes()),
+            amount: Some(self.amount.into()),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct DelegationsResponseError(DelegationsResponseErrorKind);
+
+impl DelegationsResponseError {
+    #[must_use]
+    fn delegation(source: DelegationError) -> Self {
+        Self(DelegationsResponseErrorKind::Delegation {
+            source,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum DelegationsResponseErrorKind {
+    #[error("`delegations` contained an invalid delegation")]
+    Delegation { source: DelegationError },
+}
+
+/// The sequencer response to a request for the delegations of an account at a given height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DelegationsResponse {
+    pub height: u64,
+    pub delegations: Vec<Delegation>,
+}
+
+impl DelegationsResponse {
+    /// Converts a protobuf [`raw::DelegationsResponse`] to an astria native
+    /// [`DelegationsResponse`].
+    ///
+    /// # Errors
+    /// Returns an error if one or more of the entries in the protobuf `delegations` field is
+    /// invalid.
+    pub fn try_from_raw(
+        proto: &raw::DelegationsResponse,
+    ) -> Result<Self, DelegationsResponseError> {
+        let raw::DelegationsResponse {
+            height,
+            delegations,
+        } = proto;
+        Ok(Self {
+            height: *height,
+            delegations: delegations
+                .iter()
+                .map(Delegation::try_from_raw)
+                .collect::<Result<_, _>>()
+                .map_err(DelegationsResponseError::delegation)?,
+        })
+    }
+
+    /// Converts an astria native [`DelegationsResponse`] to a protobuf
+    /// [`raw::DelegationsResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::DelegationsResponse {
+        raw::DelegationsResponse {
+            height: self.height,
+            delegations: self
+                .delegations
+                .into_iter()
+                .map(Delegation::into_raw)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct ValidatorStakeResponseError(ValidatorStakeResponseErrorKind);
+
+impl ValidatorStakeResponseError {
+    #[must_use]
+    fn invalid_validator(source: crypto::Error) -> Self {
+        Self(ValidatorStakeResponseErrorKind::InvalidValidator {
+            source,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum ValidatorStakeResponseErrorKind {
+    #[error("`validator` field did not contain a valid verification key")]
+    InvalidValidator { source: crypto::Error },
+}
+
+/// The sequencer response to a request for the total stake bonded to a validator at a given
+/// height, and the validator power derived from it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidatorStakeResponse {
+    pub height: u64,
+    pub validator: VerificationKey,
+    pub stake: u128,
+    pub power: u32,
+}
+
+impl ValidatorStakeResponse {
+    /// Converts a protobuf [`raw::ValidatorStakeResponse`] to an astria native
+    /// [`ValidatorStakeResponse`].
+    ///
+    /// # Errors
+    /// Returns an error if the `validator` is not a valid verification key.
+    pub fn try_from_raw(
+        proto: &raw::ValidatorStakeResponse,
+    ) -> Result<Self, ValidatorStakeResponseError> {
+        let raw::ValidatorStakeResponse {
+            height,
+            validator,
+            stake,
+            power,
+        } = proto;
+        Ok(Self {
+            height: *height,
+            validator: VerificationKey::try_from(&**validator)
+                .map_err(ValidatorStakeResponseError::invalid_validator)?,
+            stake: stake.map_or(0, Into::into),
+            power: *power,
+        })
+    }
+
+    /// Converts an astria native [`ValidatorStakeResponse`] to a protobuf
+    /// [`raw::ValidatorStakeResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::ValidatorStakeResponse {
+        raw::ValidatorStakeResponse {
+            height: self.height,
+            validator: Bytes::copy_from_slice(&self.validator.to_bytes()),
+            stake: Some(self.stake.into()),
+            power: self.power,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Delegation,
+        DelegationsResponse,
+        ValidatorStakeResponse,
+    };
+    use crate::crypto::SigningKey;
+
+    #[test]
+    fn delegations_response_roundtrip_is_correct() {
+        let expected = DelegationsResponse {
+            height: 42,
+            delegations: vec![
+                Delegation {
+                    validator: SigningKey::from([1; 32]).verification_key(),
+                    amount: 1000,
+                },
+                Delegation {
+                    validator: SigningKey::from([2; 32]).verification_key(),
+                    amount: 1,
+                },
+            ],
+        };
+        let actual = DelegationsResponse::try_from_raw(&expected.clone().into_raw()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn validator_stake_response_roundtrip_is_correct() {
+        let expected = ValidatorStakeResponse {
+            height: 42,
+            validator: SigningKey::from([1; 32]).verification_key(),
+            stake: 1000,
+            power: 10,
+        };
+        let actual = ValidatorStakeResponse::try_from_raw(&expected.clone().into_raw()).unwrap();
+        assert_eq!(expected, actual);
+    }
+}
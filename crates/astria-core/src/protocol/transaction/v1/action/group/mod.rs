@@ -20,12 +20,16 @@ impl Action {
             | Action::FeeChange(_)
             | Action::FeeAssetChange(_)
             | Action::RecoverIbcClient(_)
+            | Action::Ics20RateLimitChange(_)
+            | Action::VoteExtensionParticipationChange(_)
+            | Action::StakingParametersChange(_)
+            | Action::GovernanceParametersChange(_)
             | Action::CurrencyPairsChange(_)
             | Action::MarketsChange(_) => Group::BundleableSudo,
 
-            Action::InitBridgeAccount(_) | Action::BridgeSudoChange(_) => {
-                Group::UnbundleableGeneral
-            }
+            Action::InitBridgeAccount(_)
+            | Action::BridgeSudoChange(_)
+            | Action::RegisterMultisigAccount(_) => Group::UnbundleableGeneral,
 
             Action::RollupDataSubmission(_)
             | Action::Transfer(_)
@@ -34,7 +38,12 @@ impl Action {
             | Action::BridgeLock(_)
             | Action::BridgeUnlock(_)
             | Action::BridgeTransfer(_)
-            | Action::Ibc(_) => Group::BundleableGeneral,
+            | Action::Ibc(_)
+            | Action::ScheduledTransfer(_)
+            | Action::Bond(_)
+            | Action::Unbond(_)
+            | Action::ProposalSubmission(_)
+            | Action::ProposalVote(_) => Group::BundleableGeneral,
         }
     }
 }
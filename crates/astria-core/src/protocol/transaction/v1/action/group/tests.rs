@@ -2,38 +2,60 @@ use ibc_types::core::client::Height;
 
 use crate::{
     crypto::VerificationKey,
+    generated::astria::protocol::genesis::v1 as raw_genesis,
     primitive::v1::{
         asset::Denom,
         Address,
         RollupId,
     },
-    protocol::transaction::v1::action::{
-        group::{
-            Actions,
-            ErrorKind,
-            Group,
+    protocol::{
+        genesis::v1::{
+            GovernanceParameters,
+            StakingParameters,
+        },
+        transaction::v1::action::{
+            group::{
+                Actions,
+                ErrorKind,
+                Group,
+            },
+            Action,
+            Bond,
+            BridgeLock,
+            BridgeSudoChange,
+            BridgeTransfer,
+            BridgeUnlock,
+            CurrencyPairsChange,
+            FeeAssetChange,
+            FeeChange,
+            FeeComponents,
+            GovernanceParametersChange,
+            IbcRelay,
+            IbcRelayerChange,
+            IbcSudoChange,
+            Ics20RateLimit,
+            Ics20RateLimitChange,
+            Ics20Withdrawal,
+            InitBridgeAccount,
+            MarketsChange,
+            ProposalSubmission,
+            ProposalVote,
+            ProposedAction,
+            RecoverIbcClient,
+            RegisterMultisigAccount,
+            ReleaseCondition,
+            RollupDataSubmission,
+            ScheduledTransfer,
+            StakingParametersChange,
+            SudoAddressChange,
+            Transfer,
+            Unbond,
+            ValidatorUpdate,
+            VoteExtensionParticipation,
+            VoteExtensionParticipationChange,
         },
-        Action,
-        BridgeLock,
-        BridgeSudoChange,
-        BridgeTransfer,
-        BridgeUnlock,
-        CurrencyPairsChange,
-        FeeAssetChange,
-        FeeChange,
-        FeeComponents,
-        IbcRelay,
-        IbcRelayerChange,
-        IbcSudoChange,
-        Ics20Withdrawal,
-        InitBridgeAccount,
-        MarketsChange,
-        RecoverIbcClient,
-        RollupDataSubmission,
-        SudoAddressChange,
-        Transfer,
-        ValidatorUpdate,
     },
+    Protobuf as _,
 };
 const ASTRIA_ADDRESS_PREFIX: &str = "astria";
 
@@ -102,6 +124,32 @@ fn try_from_list_of_actions_bundleable_general() {
             rollup_withdrawal_event_id: String::new(),
         }),
         Action::Ibc(IbcRelay::Unknown(pbjson_types::Any::default())),
+        Action::ScheduledTransfer(ScheduledTransfer {
+            to: address,
+            amount: 100,
+            asset: asset.clone(),
+            fee_asset: asset.clone(),
+            release_at: ReleaseCondition::Height(10),
+        }),
+        Action::Bond(Bond {
+            validator: VerificationKey::try_from([0; 32]).unwrap(),
+            amount: 100,
+            fee_asset: asset.clone(),
+        }),
+        Action::Unbond(Unbond {
+            validator: VerificationKey::try_from([0; 32]).unwrap(),
+            amount: 100,
+            fee_asset: asset.clone(),
+        }),
+        Action::ProposalSubmission(ProposalSubmission {
+            action: ProposedAction::FeeAssetChange(FeeAssetChange::Addition(asset.clone())),
+            fee_asset: asset.clone(),
+        }),
+        Action::ProposalVote(ProposalVote {
+            proposal_id: 0,
+            approve: true,
+            fee_asset: asset.clone(),
+        }),
     ];
 
     assert!(matches!(
@@ -129,6 +177,36 @@ fn from_list_of_actions_bundleable_sudo() {
         }),
         Action::CurrencyPairsChange(CurrencyPairsChange::Addition(vec![])),
         Action::MarketsChange(MarketsChange::Creation(vec![])),
+        Action::Ics20RateLimitChange(Ics20RateLimitChange {
+            channel: "channel-0".parse().unwrap(),
+            asset: "nria".parse().unwrap(),
+            rate_limit: Some(Ics20RateLimit {
+                max_inflow: 100,
+                max_outflow: 100,
+                window_blocks: 10,
+            }),
+        }),
+        Action::VoteExtensionParticipationChange(VoteExtensionParticipationChange {
+            participation: Some(VoteExtensionParticipation {
+                window_blocks: 100,
+                max_missed_blocks: 50,
+                power_reduction_percent: 100,
+            }),
+        }),
+        Action::StakingParametersChange(StakingParametersChange {
+            parameters: StakingParameters::try_from_raw(raw_genesis::StakingParameters {
+                unbonding_period_blocks: 100,
+                stake_per_power: Some(1_000_000.into()),
+            })
+            .unwrap(),
+        }),
+        Action::GovernanceParametersChange(GovernanceParametersChange {
+            parameters: GovernanceParameters::try_from_raw(raw_genesis::GovernanceParameters {
+                voting_period_blocks: 100,
+                proposal_deposit: Some(1_000.into()),
+            })
+            .unwrap(),
+        }),
     ];
 
     assert!(matches!(
@@ -218,6 +296,19 @@ fn from_list_of_actions_unbundleable_general() {
         Group::UnbundleableGeneral
     ));
 
+    let register_multisig_account_action = RegisterMultisigAccount {
+        verification_keys: vec![VerificationKey::try_from([0; 32]).unwrap()],
+        threshold: 1,
+        fee_asset: asset.clone(),
+    };
+
+    let actions = vec![register_multisig_account_action.clone().into()];
+
+    assert!(matches!(
+        Actions::try_from_list_of_actions(actions).unwrap().group(),
+        Group::UnbundleableGeneral
+    ));
+
     let actions = vec![
         init_bridge_account_action.into(),
         sudo_bridge_address_change_action.into(),
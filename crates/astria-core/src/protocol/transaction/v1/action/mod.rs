@@ -16,6 +16,10 @@ use prost::Name as _;
 
 use super::raw;
 use crate::{
+    crypto::{
+        self,
+        VerificationKey,
+    },
     oracles::price_feed::{
         market_map::v2::{
             Market,
@@ -36,9 +40,17 @@ use crate::{
         IncorrectRollupIdLength,
         RollupId,
     },
-    protocol::fees::v1::{
-        FeeComponentError,
-        FeeComponents,
+    protocol::{
+        fees::v1::{
+            FeeComponentError,
+            FeeComponents,
+        },
+        genesis::v1::{
+            GovernanceParameters,
+            GovernanceParametersError,
+            StakingParameters,
+            StakingParametersError,
+        },
     },
     Protobuf,
 };
@@ -46,6 +58,8 @@ use crate::{
 pub mod group;
 
 const MAX_VALIDATOR_NAME_LENGTH: usize = 32;
+/// The maximum number of verification keys of a multisig account.
+pub const MAX_MULTISIG_VERIFICATION_KEYS: usize = 32;
 
 #[derive(Clone, Debug)]
 #[cfg_attr(
@@ -72,6 +86,16 @@ pub enum Action {
     RecoverIbcClient(RecoverIbcClient),
     CurrencyPairsChange(CurrencyPairsChange),
     MarketsChange(MarketsChange),
+    RegisterMultisigAccount(RegisterMultisigAccount),
+    ScheduledTransfer(ScheduledTransfer),
+    Bond(Bond),
+    Unbond(Unbond),
+    Ics20RateLimitChange(Ics20RateLimitChange),
+    VoteExtensionParticipationChange(VoteExtensionParticipationChange),
+    StakingParametersChange(StakingParametersChange),
+    GovernanceParametersChange(GovernanceParametersChange),
+    ProposalSubmission(ProposalSubmission),
+    ProposalVote(ProposalVote),
 }
 
 impl Protobuf for Action {
@@ -100,6 +124,20 @@ impl Protobuf for Action {
             Action::RecoverIbcClient(act) => Value::RecoverIbcClient(act.to_raw()),
             Action::CurrencyPairsChange(act) => Value::CurrencyPairsChange(act.to_raw()),
             Action::MarketsChange(act) => Value::MarketsChange(act.to_raw()),
+            Action::RegisterMultisigAccount(act) => Value::RegisterMultisigAccount(act.to_raw()),
+            Action::ScheduledTransfer(act) => Value::ScheduledTransfer(act.to_raw()),
+            Action::Bond(act) => Value::Bond(act.to_raw()),
+            Action::Unbond(act) => Value::Unbond(act.to_raw()),
+            Action::Ics20RateLimitChange(act) => Value::Ics20RateLimitChange(act.to_raw()),
+            Action::VoteExtensionParticipationChange(act) => {
+                Value::VoteExtensionParticipationChange(act.to_raw())
+            }
+            Action::StakingParametersChange(act) => Value::StakingParametersChange(act.to_raw()),
+            Action::GovernanceParametersChange(act) => {
+                Value::GovernanceParametersChange(act.to_raw())
+            }
+            Action::ProposalSubmission(act) => Value::ProposalSubmission(act.to_raw()),
+            Action::ProposalVote(act) => Value::ProposalVote(act.to_raw()),
         };
         raw::Action {
             value: Some(kind),
@@ -185,6 +223,36 @@ impl Protobuf for Action {
             Value::MarketsChange(act) => Self::MarketsChange(
                 MarketsChange::try_from_raw(act).map_err(Error::markets_change)?,
             ),
+            Value::RegisterMultisigAccount(act) => Self::RegisterMultisigAccount(
+                RegisterMultisigAccount::try_from_raw(act)
+                    .map_err(Error::register_multisig_account)?,
+            ),
+            Value::ScheduledTransfer(act) => Self::ScheduledTransfer(
+                ScheduledTransfer::try_from_raw(act).map_err(Error::scheduled_transfer)?,
+            ),
+            Value::Bond(act) => Self::Bond(Bond::try_from_raw(act).map_err(Error::bond)?),
+            Value::Unbond(act) => Self::Unbond(Unbond::try_from_raw(act).map_err(Error::unbond)?),
+            Value::Ics20RateLimitChange(act) => Self::Ics20RateLimitChange(
+                Ics20RateLimitChange::try_from_raw(act).map_err(Error::ics20_rate_limit_change)?,
+            ),
+            Value::VoteExtensionParticipationChange(act) => Self::VoteExtensionParticipationChange(
+                VoteExtensionParticipationChange::try_from_raw(act)
+                    .map_err(Error::vote_extension_participation_change)?,
+            ),
+            Value::StakingParametersChange(act) => Self::StakingParametersChange(
+                StakingParametersChange::try_from_raw(act)
+                    .map_err(Error::staking_parameters_change)?,
+            ),
+            Value::GovernanceParametersChange(act) => Self::GovernanceParametersChange(
+                GovernanceParametersChange::try_from_raw(act)
+                    .map_err(Error::governance_parameters_change)?,
+            ),
+            Value::ProposalSubmission(act) => Self::ProposalSubmission(
+                ProposalSubmission::try_from_raw(act).map_err(Error::proposal_submission)?,
+            ),
+            Value::ProposalVote(act) => {
+                Self::ProposalVote(ProposalVote::try_from_raw(act).map_err(Error::proposal_vote)?)
+            }
         };
         Ok(action)
     }
@@ -325,6 +393,95 @@ impl From<MarketsChange> for Action {
     }
 }
 
+impl From<RegisterMultisigAccount> for Action {
+    fn from(value: RegisterMultisigAccount) -> Self {
+        Self::RegisterMultisigAccount(value)
+    }
+}
+
+impl From<ScheduledTransfer> for Action {
+    fn from(value: ScheduledTransfer) -> Self {
+        Self::ScheduledTransfer(value)
+    }
+}
+
+impl From<Bond> for Action {
+    fn from(value: Bond) -> Self {
+        Self::Bond(value)
+    }
+}
+
+impl From<Unbond> for Action {
+    fn from(value: Unbond) -> Self {
+        Self::Unbond(value)
+    }
+}
+
+impl From<Ics20RateLimitChange> for Action {
+    fn from(value: Ics20RateLimitChange) -> Self {
+        Self::Ics20RateLimitChange(value)
+    }
+}
+
+impl From<VoteExtensionParticipationChange> for Action {
+    fn from(value: VoteExtensionParticipationChange) -> Self {
+        Self::VoteExtensionParticipationChange(value)
+    }
+}
+
+impl From<StakingParametersChange> for Action {
+    fn from(value: StakingParametersChange) -> Self {
+        Self::StakingParametersChange(value)
+    }
+}
+
+impl From<GovernanceParametersChange> for Action {
+    fn from(value: GovernanceParametersChange) -> Self {
+        Self::GovernanceParametersChange(value)
+    }
+}
+
+impl From<ProposalSubmission> for Action {
+    fn from(value: ProposalSubmission) -> Self {
+        Self::ProposalSubmission(value)
+    }
+}
+
+impl From<ProposalVote> for Action {
+    fn from(value: ProposalVote) -> Self {
+        Self::ProposalVote(value)
+    }
+}
+
+impl From<ProposedAction> for Action {
+    fn from(value: ProposedAction) -> Self {
+        match value {
+            ProposedAction::FeeChange(act) => Self::FeeChange(act),
+            ProposedAction::FeeAssetChange(act) => Self::FeeAssetChange(act),
+            ProposedAction::IbcRelayerChange(act) => Self::IbcRelayerChange(act),
+            ProposedAction::MarketsChange(act) => Self::MarketsChange(act),
+            ProposedAction::SudoAddressChange(act) => Self::SudoAddressChange(act),
+        }
+    }
+}
+
+impl TryFrom<Action> for ProposedAction {
+    type Error = Action;
+
+    /// Converts an [`Action`] into a [`ProposedAction`], returning the action unchanged as the
+    /// error if it cannot be proposed through governance.
+    fn try_from(value: Action) -> Result<Self, Self::Error> {
+        match value {
+            Action::FeeChange(act) => Ok(Self::FeeChange(act)),
+            Action::FeeAssetChange(act) => Ok(Self::FeeAssetChange(act)),
+            Action::IbcRelayerChange(act) => Ok(Self::IbcRelayerChange(act)),
+            Action::MarketsChange(act) => Ok(Self::MarketsChange(act)),
+            Action::SudoAddressChange(act) => Ok(Self::SudoAddressChange(act)),
+            other => Err(other),
+        }
+    }
+}
+
 impl From<Action> for raw::Action {
     fn from(value: Action) -> Self {
         value.into_raw()
@@ -366,6 +523,16 @@ impl ActionName for Action {
             Action::RecoverIbcClient(_) => "RecoverIbcClient",
             Action::CurrencyPairsChange(_) => "CurrencyPairsChange",
             Action::MarketsChange(_) => "MarketsChange",
+            Action::RegisterMultisigAccount(_) => "RegisterMultisigAccount",
+            Action::ScheduledTransfer(_) => "ScheduledTransfer",
+            Action::Bond(_) => "Bond",
+            Action::Unbond(_) => "Unbond",
+            Action::Ics20RateLimitChange(_) => "Ics20RateLimitChange",
+            Action::VoteExtensionParticipationChange(_) => "VoteExtensionParticipationChange",
+            Action::StakingParametersChange(_) => "StakingParametersChange",
+            Action::GovernanceParametersChange(_) => "GovernanceParametersChange",
+            Action::ProposalSubmission(_) => "ProposalSubmission",
+            Action::ProposalVote(_) => "ProposalVote",
         }
     }
 }
@@ -450,6 +617,46 @@ impl Error {
     fn markets_change(inner: MarketsChangeError) -> Self {
         Self(ActionErrorKind::MarketsChange(inner))
     }
+
+    fn register_multisig_account(inner: RegisterMultisigAccountError) -> Self {
+        Self(ActionErrorKind::RegisterMultisigAccount(inner))
+    }
+
+    fn scheduled_transfer(inner: ScheduledTransferError) -> Self {
+        Self(ActionErrorKind::ScheduledTransfer(inner))
+    }
+
+    fn bond(inner: BondError) -> Self {
+        Self(ActionErrorKind::Bond(inner))
+    }
+
+    fn unbond(inner: UnbondError) -> Self {
+        Self(ActionErrorKind::Unbond(inner))
+    }
+
+    fn ics20_rate_limit_change(inner: Ics20RateLimitChangeError) -> Self {
+        Self(ActionErrorKind::Ics20RateLimitChange(inner))
+    }
+
+    fn vote_extension_participation_change(inner: VoteExtensionParticipationChangeError) -> Self {
+        Self(ActionErrorKind::VoteExtensionParticipationChange(inner))
+    }
+
+    fn staking_parameters_change(inner: StakingParametersChangeError) -> Self {
+        Self(ActionErrorKind::StakingParametersChange(inner))
+    }
+
+    fn governance_parameters_change(inner: GovernanceParametersChangeError) -> Self {
+        Self(ActionErrorKind::GovernanceParametersChange(inner))
+    }
+
+    fn proposal_submission(inner: ProposalSubmissionError) -> Self {
+        Self(ActionErrorKind::ProposalSubmission(inner))
+    }
+
+    fn proposal_vote(inner: ProposalVoteError) -> Self {
+        Self(ActionErrorKind::ProposalVote(inner))
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -492,6 +699,26 @@ enum ActionErrorKind {
     CurrencyPairsChange(#[source] CurrencyPairsChangeError),
     #[error("markets change action was not valid")]
     MarketsChange(#[source] MarketsChangeError),
+    #[error("register multisig account action was not valid")]
+    RegisterMultisigAccount(#[source] RegisterMultisigAccountError),
+    #[error("scheduled transfer action was not valid")]
+    ScheduledTransfer(#[source] ScheduledTransferError),
+    #[error("bond action was not valid")]
+    Bond(#[source] BondError),
+    #[error("unbond action was not valid")]
+    Unbond(#[source] UnbondError),
+    #[error("ics20 rate limit change action was not valid")]
+    Ics20RateLimitChange(#[source] Ics20RateLimitChangeError),
+    #[error("vote extension participation change action was not valid")]
+    VoteExtensionParticipationChange(#[source] VoteExtensionParticipationChangeError),
+    #[error("staking parameters change action was not valid")]
+    StakingParametersChange(#[source] StakingParametersChangeError),
+    #[error("governance parameters change action was not valid")]
+    GovernanceParametersChange(#[source] GovernanceParametersChangeError),
+    #[error("proposal submission action was not valid")]
+    ProposalSubmission(#[source] ProposalSubmissionError),
+    #[error("proposal vote action was not valid")]
+    ProposalVote(#[source] ProposalVoteError),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -668,1109 +895,1627 @@ enum TransferActionErrorKind {
     FeeAsset(#[source] asset::ParseDenomError),
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error(
-    "input was `{length}` bytes but validator names can only be up to \
-     `{MAX_VALIDATOR_NAME_LENGTH}` bytes long"
-)]
-pub struct ValidatorNameError {
-    length: usize,
+/// The point at which the funds escrowed by a [`ScheduledTransfer`] are released.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReleaseCondition {
+    /// Release at the end of the sequencer block at this height.
+    Height(u64),
+    /// Release at the end of the first sequencer block with a timestamp at or after this many
+    /// seconds since the unix epoch.
+    UnixTimestamp(u64),
 }
 
-/// Wrapper for validator name field of [`ValidatorUpdate`]. Cannot be longer than
-/// [`MAX_VALIDATOR_NAME_LENGTH`].
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct ValidatorName(String);
-
-impl ValidatorName {
+impl ReleaseCondition {
+    /// Returns if the condition is met by a block of the given height and timestamp.
     #[must_use]
-    pub fn empty() -> Self {
-        Self(String::new())
+    pub fn is_met(&self, height: u64, unix_timestamp_seconds: u64) -> bool {
+        match *self {
+            Self::Height(release_height) => release_height <= height,
+            Self::UnixTimestamp(release_time) => release_time <= unix_timestamp_seconds,
+        }
     }
+}
 
-    #[must_use]
-    pub fn as_str(&self) -> &str {
-        self.0.as_str()
+impl Display for ReleaseCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Height(height) => write!(f, "height {height}"),
+            Self::UnixTimestamp(seconds) => write!(f, "unix timestamp {seconds}"),
+        }
     }
+}
 
-    #[must_use]
-    pub fn into_inner(self) -> String {
-        self.0
+impl From<ReleaseCondition> for raw::scheduled_transfer::ReleaseAt {
+    fn from(value: ReleaseCondition) -> Self {
+        match value {
+            ReleaseCondition::Height(height) => Self::Height(height),
+            ReleaseCondition::UnixTimestamp(seconds) => Self::UnixTimestampSeconds(seconds),
+        }
     }
 }
 
-impl FromStr for ValidatorName {
-    type Err = ValidatorNameError;
-
-    fn from_str(value: &str) -> Result<Self, Self::Err> {
-        if value.len() > MAX_VALIDATOR_NAME_LENGTH {
-            return Err(ValidatorNameError {
-                length: value.len(),
-            });
+impl From<raw::scheduled_transfer::ReleaseAt> for ReleaseCondition {
+    fn from(value: raw::scheduled_transfer::ReleaseAt) -> Self {
+        match value {
+            raw::scheduled_transfer::ReleaseAt::Height(height) => Self::Height(height),
+            raw::scheduled_transfer::ReleaseAt::UnixTimestampSeconds(seconds) => {
+                Self::UnixTimestamp(seconds)
+            }
         }
-        Ok(Self(value.to_string()))
     }
 }
 
-impl Display for ValidatorName {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+/// Moves funds out of the signer's account into an escrow, which is released to `to` at the end
+/// of the first sequencer block meeting `release_at`.
+#[derive(Clone, Debug)]
+pub struct ScheduledTransfer {
+    pub to: Address,
+    pub amount: u128,
+    /// asset to be transferred.
+    pub asset: asset::Denom,
+    /// asset to use for fee payment.
+    pub fee_asset: asset::Denom,
+    pub release_at: ReleaseCondition,
+}
+
+impl Protobuf for ScheduledTransfer {
+    type Error = ScheduledTransferError;
+    type Raw = raw::ScheduledTransfer;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::ScheduledTransfer {
+        let Self {
+            to,
+            amount,
+            asset,
+            fee_asset,
+            release_at,
+        } = self;
+        raw::ScheduledTransfer {
+            to: Some(to.to_raw()),
+            amount: Some((*amount).into()),
+            asset: asset.to_string(),
+            fee_asset: fee_asset.to_string(),
+            release_at: Some((*release_at).into()),
+        }
+    }
+
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    /// Returns `ScheduledTransferError` if the raw action's `to` address, `asset`, or `fee_asset`
+    /// are invalid, or if `release_at` is not set.
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::ScheduledTransfer {
+            to,
+            amount,
+            asset,
+            fee_asset,
+            release_at,
+        } = raw;
+        let Some(to) = to else {
+            return Err(ScheduledTransferError::field_not_set("to"));
+        };
+        let to = Address::try_from_raw_ref(to).map_err(ScheduledTransferError::address)?;
+        let amount = amount.map_or(0, Into::into);
+        let asset = asset.parse().map_err(ScheduledTransferError::asset)?;
+        let fee_asset = fee_asset
+            .parse()
+            .map_err(ScheduledTransferError::fee_asset)?;
+        let Some(release_at) = *release_at else {
+            return Err(ScheduledTransferError::field_not_set("release_at"));
+        };
+
+        Ok(Self {
+            to,
+            amount,
+            asset,
+            fee_asset,
+            release_at: release_at.into(),
+        })
     }
 }
 
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
-pub struct ValidatorUpdateError(ValidatorUpdateErrorKind);
+pub struct ScheduledTransferError(ScheduledTransferErrorKind);
 
-impl ValidatorUpdateError {
-    fn negative_power(power: i64) -> Self {
-        Self(ValidatorUpdateErrorKind::NegativePower {
-            power,
-        })
+impl ScheduledTransferError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(ScheduledTransferErrorKind::FieldNotSet(field))
     }
 
-    fn public_key_not_set() -> Self {
-        Self(ValidatorUpdateErrorKind::PublicKeyNotSet)
+    fn address(inner: AddressError) -> Self {
+        Self(ScheduledTransferErrorKind::Address(inner))
     }
 
-    fn secp256k1_not_supported() -> Self {
-        Self(ValidatorUpdateErrorKind::Secp256k1NotSupported)
+    fn asset(inner: asset::ParseDenomError) -> Self {
+        Self(ScheduledTransferErrorKind::Asset(inner))
     }
 
-    fn verification_key(source: crate::crypto::Error) -> Self {
-        Self(ValidatorUpdateErrorKind::VerificationKey {
-            source,
-        })
+    fn fee_asset(inner: asset::ParseDenomError) -> Self {
+        Self(ScheduledTransferErrorKind::FeeAsset(inner))
     }
+}
 
-    fn invalid_name(source: ValidatorNameError) -> Self {
-        Self(ValidatorUpdateErrorKind::InvalidName {
-            source,
+#[derive(Debug, thiserror::Error)]
+enum ScheduledTransferErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("`to` field did not contain a valid address")]
+    Address(#[source] AddressError),
+    #[error("`asset` field did not contain a valid asset ID")]
+    Asset(#[source] asset::ParseDenomError),
+    #[error("`fee_asset` field did not contain a valid asset ID")]
+    FeeAsset(#[source] asset::ParseDenomError),
+}
+
+/// Moves `amount` of the native asset out of the signer's account and bonds it to `validator`.
+#[derive(Clone, Debug)]
+pub struct Bond {
+    pub validator: VerificationKey,
+    pub amount: u128,
+    /// asset to use for fee payment.
+    pub fee_asset: asset::Denom,
+}
+
+impl Protobuf for Bond {
+    type Error = BondError;
+    type Raw = raw::Bond;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::Bond {
+        let Self {
+            validator,
+            amount,
+            fee_asset,
+        } = self;
+        raw::Bond {
+            validator: Bytes::copy_from_slice(&validator.to_bytes()),
+            amount: Some((*amount).into()),
+            fee_asset: fee_asset.to_string(),
+        }
+    }
+
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    /// Returns `BondError` if the raw action's `validator` key or `fee_asset` are invalid.
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::Bond {
+            validator,
+            amount,
+            fee_asset,
+        } = raw;
+        let validator = VerificationKey::try_from(&**validator).map_err(BondError::validator)?;
+        let amount = amount.map_or(0, Into::into);
+        let fee_asset = fee_asset.parse().map_err(BondError::fee_asset)?;
+
+        Ok(Self {
+            validator,
+            amount,
+            fee_asset,
         })
     }
 }
 
 #[derive(Debug, thiserror::Error)]
-enum ValidatorUpdateErrorKind {
-    #[error("field .power had negative value `{power}`, which is not permitted")]
-    NegativePower { power: i64 },
-    #[error(".pub_key field was not set")]
-    PublicKeyNotSet,
-    #[error(".pub_key field was set to secp256k1, but only ed25519 keys are supported")]
-    Secp256k1NotSupported,
-    #[error("bytes stored in the .pub_key field could not be read as an ed25519 verification key")]
-    VerificationKey { source: crate::crypto::Error },
-    #[error("field `.name` was invalid")]
-    InvalidName { source: ValidatorNameError },
+#[error(transparent)]
+pub struct BondError(BondErrorKind);
+
+impl BondError {
+    fn validator(inner: crypto::Error) -> Self {
+        Self(BondErrorKind::Validator(inner))
+    }
+
+    fn fee_asset(inner: asset::ParseDenomError) -> Self {
+        Self(BondErrorKind::FeeAsset(inner))
+    }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct ValidatorUpdate {
-    pub power: u32,
-    pub verification_key: crate::crypto::VerificationKey,
-    pub name: ValidatorName,
+#[derive(Debug, thiserror::Error)]
+enum BondErrorKind {
+    #[error("`validator` field did not contain a valid verification key")]
+    Validator(#[source] crypto::Error),
+    #[error("`fee_asset` field did not contain a valid asset ID")]
+    FeeAsset(#[source] asset::ParseDenomError),
 }
 
-impl Protobuf for ValidatorUpdate {
-    type Error = ValidatorUpdateError;
-    type Raw = raw::ValidatorUpdate;
+/// Unbonds `amount` of the native asset bonded by the signer from `validator`. The unbonded funds
+/// are returned to the signer once the unbonding period has passed.
+#[derive(Clone, Debug)]
+pub struct Unbond {
+    pub validator: VerificationKey,
+    pub amount: u128,
+    /// asset to use for fee payment.
+    pub fee_asset: asset::Denom,
+}
 
-    /// Create a validator update by verifying a raw protobuf-decoded
-    /// [`crate::generated::protocol::transaction::v1alpha1::ValidatorUpdate`].
-    ///
-    /// # Errors
-    /// Returns an error if the `.power` field is negative, if `.pub_key`
-    /// is not set, or if `.pub_key` contains a non-ed25519 variant, or
-    /// if the ed25519 has invalid bytes (that is, bytes from which an
-    /// ed25519 public key cannot be constructed).
-    fn try_from_raw(value: Self::Raw) -> Result<Self, Self::Error> {
-        use crate::generated::astria_vendored::tendermint::crypto::{
-            public_key,
-            PublicKey,
-        };
-        let Self::Raw {
-            pub_key,
-            power,
-            name,
-        } = value;
-        let name = name.parse().map_err(ValidatorUpdateError::invalid_name)?;
-        let power = power
-            .try_into()
-            .map_err(|_| Self::Error::negative_power(power))?;
-        let verification_key = match pub_key {
-            None
-            | Some(PublicKey {
-                sum: None,
-            }) => Err(Self::Error::public_key_not_set()),
-            Some(PublicKey {
-                sum: Some(public_key::Sum::Secp256k1(..)),
-            }) => Err(Self::Error::secp256k1_not_supported()),
+impl Protobuf for Unbond {
+    type Error = UnbondError;
+    type Raw = raw::Unbond;
 
-            Some(PublicKey {
-                sum: Some(public_key::Sum::Ed25519(bytes)),
-            }) => crate::crypto::VerificationKey::try_from(&*bytes)
-                .map_err(Self::Error::verification_key),
-        }?;
-        Ok(Self {
-            power,
-            verification_key,
-            name,
-        })
+    #[must_use]
+    fn to_raw(&self) -> raw::Unbond {
+        let Self {
+            validator,
+            amount,
+            fee_asset,
+        } = self;
+        raw::Unbond {
+            validator: Bytes::copy_from_slice(&validator.to_bytes()),
+            amount: Some((*amount).into()),
+            fee_asset: fee_asset.to_string(),
+        }
     }
 
-    /// Create a validator update by verifying a reference to raw protobuf-decoded
-    /// [`crate::generated::protocol::transaction::v1alpha1::ValidatorUpdate`].
+    /// Convert from a reference to the raw protobuf type.
     ///
     /// # Errors
-    /// Returns an error if the `.power` field is negative, if `.pub_key`
-    /// is not set, or if `.pub_key` contains a non-ed25519 variant, or
-    /// if the ed25519 has invalid bytes (that is, bytes from which an
-    /// ed25519 public key cannot be constructed).
+    /// Returns `UnbondError` if the raw action's `validator` key or `fee_asset` are invalid.
     fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
-        Self::try_from_raw(raw.clone())
+        let raw::Unbond {
+            validator,
+            amount,
+            fee_asset,
+        } = raw;
+        let validator = VerificationKey::try_from(&**validator).map_err(UnbondError::validator)?;
+        let amount = amount.map_or(0, Into::into);
+        let fee_asset = fee_asset.parse().map_err(UnbondError::fee_asset)?;
+
+        Ok(Self {
+            validator,
+            amount,
+            fee_asset,
+        })
     }
+}
 
-    #[must_use]
-    fn to_raw(&self) -> Self::Raw {
-        use crate::generated::astria_vendored::tendermint::crypto::{
-            public_key,
-            PublicKey,
-        };
-        let Self {
-            power,
-            verification_key,
-            name,
-        } = self;
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct UnbondError(UnbondErrorKind);
 
-        Self::Raw {
-            power: (*power).into(),
-            pub_key: Some(PublicKey {
-                sum: Some(public_key::Sum::Ed25519(
-                    verification_key.to_bytes().to_vec(),
-                )),
-            }),
-            name: name.clone().into_inner(),
-        }
+impl UnbondError {
+    fn validator(inner: crypto::Error) -> Self {
+        Self(UnbondErrorKind::Validator(inner))
     }
-}
 
-impl From<ValidatorUpdate>
-    for crate::generated::astria::protocol::transaction::v1::ValidatorUpdate
-{
-    fn from(value: ValidatorUpdate) -> Self {
-        value.into_raw()
+    fn fee_asset(inner: asset::ParseDenomError) -> Self {
+        Self(UnbondErrorKind::FeeAsset(inner))
     }
 }
 
-impl TryFrom<crate::generated::astria::protocol::transaction::v1::ValidatorUpdate>
-    for ValidatorUpdate
-{
-    type Error = ValidatorUpdateError;
-
-    fn try_from(
-        value: crate::generated::astria::protocol::transaction::v1::ValidatorUpdate,
-    ) -> Result<Self, Self::Error> {
-        Self::try_from_raw(value)
-    }
+#[derive(Debug, thiserror::Error)]
+enum UnbondErrorKind {
+    #[error("`validator` field did not contain a valid verification key")]
+    Validator(#[source] crypto::Error),
+    #[error("`fee_asset` field did not contain a valid asset ID")]
+    FeeAsset(#[source] asset::ParseDenomError),
 }
 
+/// A privileged action which can be executed through a governance proposal.
 #[derive(Clone, Debug)]
-pub struct SudoAddressChange {
-    pub new_address: Address,
+pub enum ProposedAction {
+    FeeChange(FeeChange),
+    FeeAssetChange(FeeAssetChange),
+    IbcRelayerChange(IbcRelayerChange),
+    MarketsChange(MarketsChange),
+    SudoAddressChange(SudoAddressChange),
 }
 
-impl Protobuf for SudoAddressChange {
-    type Error = SudoAddressChangeError;
-    type Raw = raw::SudoAddressChange;
-
-    fn into_raw(self) -> raw::SudoAddressChange {
-        let Self {
-            new_address,
-        } = self;
-        raw::SudoAddressChange {
-            new_address: Some(new_address.into_raw()),
+impl ProposedAction {
+    /// Returns the name of the proposed action.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::FeeChange(_) => "FeeChange",
+            Self::FeeAssetChange(_) => "FeeAssetChange",
+            Self::IbcRelayerChange(_) => "IbcRelayerChange",
+            Self::MarketsChange(_) => "MarketsChange",
+            Self::SudoAddressChange(_) => "SudoAddressChange",
         }
     }
+}
+
+/// Submits a governance proposal to execute `action`.
+///
+/// The proposal passes if validators holding more than two thirds of the total validator power
+/// approve of it before its voting period ends.
+#[derive(Clone, Debug)]
+pub struct ProposalSubmission {
+    pub action: ProposedAction,
+    /// asset to use for fee payment.
+    pub fee_asset: asset::Denom,
+}
+
+impl Protobuf for ProposalSubmission {
+    type Error = ProposalSubmissionError;
+    type Raw = raw::ProposalSubmission;
 
     #[must_use]
-    fn to_raw(&self) -> raw::SudoAddressChange {
+    fn to_raw(&self) -> raw::ProposalSubmission {
+        use raw::proposal_submission::Action as RawAction;
         let Self {
-            new_address,
+            action,
+            fee_asset,
         } = self;
-        raw::SudoAddressChange {
-            new_address: Some(new_address.to_raw()),
+        let action = match action {
+            ProposedAction::FeeChange(act) => RawAction::FeeChange(act.to_raw()),
+            ProposedAction::FeeAssetChange(act) => RawAction::FeeAssetChange(act.to_raw()),
+            ProposedAction::IbcRelayerChange(act) => RawAction::IbcRelayerChange(act.to_raw()),
+            ProposedAction::MarketsChange(act) => RawAction::MarketsChange(act.to_raw()),
+            ProposedAction::SudoAddressChange(act) => RawAction::SudoAddressChange(act.to_raw()),
+        };
+        raw::ProposalSubmission {
+            action: Some(action),
+            fee_asset: fee_asset.to_string(),
         }
     }
 
-    /// Convert from a reference to a raw, unchecked protobuf [`raw::SudoAddressChange`].
+    /// Convert from a reference to the raw protobuf type.
     ///
     /// # Errors
-    ///
-    /// Returns an error if the raw action's `new_address` did not have the expected
-    /// length.
-    fn try_from_raw_ref(proto: &Self::Raw) -> Result<Self, SudoAddressChangeError> {
-        let raw::SudoAddressChange {
-            new_address,
-        } = proto;
-        let Some(new_address) = new_address else {
-            return Err(SudoAddressChangeError::field_not_set("new_address"));
+    /// Returns `ProposalSubmissionError` if the raw action's `action` is not set or invalid, or if
+    /// its `fee_asset` is invalid.
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        use raw::proposal_submission::Action as RawAction;
+        let raw::ProposalSubmission {
+            action,
+            fee_asset,
+        } = raw;
+        let action = match action {
+            None => return Err(ProposalSubmissionError::field_not_set("action")),
+            Some(RawAction::FeeChange(act)) => ProposedAction::FeeChange(
+                FeeChange::try_from_raw_ref(act).map_err(ProposalSubmissionError::fee_change)?,
+            ),
+            Some(RawAction::FeeAssetChange(act)) => ProposedAction::FeeAssetChange(
+                FeeAssetChange::try_from_raw_ref(act)
+                    .map_err(ProposalSubmissionError::fee_asset_change)?,
+            ),
+            Some(RawAction::IbcRelayerChange(act)) => ProposedAction::IbcRelayerChange(
+                IbcRelayerChange::try_from_raw_ref(act)
+                    .map_err(ProposalSubmissionError::ibc_relayer_change)?,
+            ),
+            Some(RawAction::MarketsChange(act)) => ProposedAction::MarketsChange(
+                MarketsChange::try_from_raw_ref(act)
+                    .map_err(ProposalSubmissionError::markets_change)?,
+            ),
+            Some(RawAction::SudoAddressChange(act)) => ProposedAction::SudoAddressChange(
+                SudoAddressChange::try_from_raw_ref(act)
+                    .map_err(ProposalSubmissionError::sudo_address_change)?,
+            ),
         };
-        let new_address =
-            Address::try_from_raw_ref(new_address).map_err(SudoAddressChangeError::address)?;
+        let fee_asset = fee_asset
+            .parse()
+            .map_err(ProposalSubmissionError::fee_asset)?;
+
         Ok(Self {
-            new_address,
+            action,
+            fee_asset,
         })
     }
 }
 
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
-pub struct SudoAddressChangeError(SudoAddressChangeErrorKind);
+pub struct ProposalSubmissionError(ProposalSubmissionErrorKind);
 
-impl SudoAddressChangeError {
+impl ProposalSubmissionError {
     fn field_not_set(field: &'static str) -> Self {
-        Self(SudoAddressChangeErrorKind::FieldNotSet(field))
+        Self(ProposalSubmissionErrorKind::FieldNotSet(field))
     }
 
-    fn address(source: AddressError) -> Self {
-        Self(SudoAddressChangeErrorKind::Address {
-            source,
-        })
+    fn fee_change(inner: FeeChangeError) -> Self {
+        Self(ProposalSubmissionErrorKind::FeeChange(inner))
+    }
+
+    fn fee_asset_change(inner: FeeAssetChangeError) -> Self {
+        Self(ProposalSubmissionErrorKind::FeeAssetChange(inner))
+    }
+
+    fn ibc_relayer_change(inner: IbcRelayerChangeError) -> Self {
+        Self(ProposalSubmissionErrorKind::IbcRelayerChange(inner))
+    }
+
+    fn markets_change(inner: MarketsChangeError) -> Self {
+        Self(ProposalSubmissionErrorKind::MarketsChange(inner))
+    }
+
+    fn sudo_address_change(inner: SudoAddressChangeError) -> Self {
+        Self(ProposalSubmissionErrorKind::SudoAddressChange(inner))
+    }
+
+    fn fee_asset(inner: asset::ParseDenomError) -> Self {
+        Self(ProposalSubmissionErrorKind::FeeAsset(inner))
     }
 }
 
 #[derive(Debug, thiserror::Error)]
-enum SudoAddressChangeErrorKind {
+enum ProposalSubmissionErrorKind {
     #[error("the expected field in the raw source type was not set: `{0}`")]
     FieldNotSet(&'static str),
-    #[error("`new_address` field did not contain a valid address")]
-    Address { source: AddressError },
+    #[error("proposed fee change action was not valid")]
+    FeeChange(#[source] FeeChangeError),
+    #[error("proposed fee asset change action was not valid")]
+    FeeAssetChange(#[source] FeeAssetChangeError),
+    #[error("proposed ibc relayer change action was not valid")]
+    IbcRelayerChange(#[source] IbcRelayerChangeError),
+    #[error("proposed markets change action was not valid")]
+    MarketsChange(#[source] MarketsChangeError),
+    #[error("proposed sudo address change action was not valid")]
+    SudoAddressChange(#[source] SudoAddressChangeError),
+    #[error("`fee_asset` field did not contain a valid asset ID")]
+    FeeAsset(#[source] asset::ParseDenomError),
 }
 
-#[derive(Debug, Clone)]
-pub struct IbcSudoChange {
-    pub new_address: Address,
+/// Records the signing validator's vote on the governance proposal with ID `proposal_id`.
+///
+/// A validator may change its vote until the voting period of the proposal ends.
+#[derive(Clone, Debug)]
+pub struct ProposalVote {
+    pub proposal_id: u64,
+    pub approve: bool,
+    /// asset to use for fee payment.
+    pub fee_asset: asset::Denom,
 }
 
-impl Protobuf for IbcSudoChange {
-    type Error = IbcSudoChangeError;
-    type Raw = raw::IbcSudoChange;
-
-    fn into_raw(self) -> raw::IbcSudoChange {
-        raw::IbcSudoChange {
-            new_address: Some(self.new_address.into_raw()),
-        }
-    }
+impl Protobuf for ProposalVote {
+    type Error = ProposalVoteError;
+    type Raw = raw::ProposalVote;
 
     #[must_use]
-    fn to_raw(&self) -> raw::IbcSudoChange {
-        raw::IbcSudoChange {
-            new_address: Some(self.new_address.to_raw()),
+    fn to_raw(&self) -> raw::ProposalVote {
+        let Self {
+            proposal_id,
+            approve,
+            fee_asset,
+        } = self;
+        raw::ProposalVote {
+            proposal_id: *proposal_id,
+            approve: *approve,
+            fee_asset: fee_asset.to_string(),
         }
     }
 
-    /// Convert from a reference to a raw, unchecked protobuf [`raw::IbcSudoChangeAction`].
+    /// Convert from a reference to the raw protobuf type.
     ///
     /// # Errors
-    ///
-    /// Returns an error if the raw action's `new_address` did not have the expected
-    /// length or if the field was not set.
-    fn try_from_raw_ref(proto: &Self::Raw) -> Result<Self, IbcSudoChangeError> {
-        let raw::IbcSudoChange {
-            new_address,
-        } = proto;
-        let Some(new_address) = new_address else {
-            return Err(IbcSudoChangeError::field_not_set("new_address"));
-        };
-        let new_address =
-            Address::try_from_raw_ref(new_address).map_err(IbcSudoChangeError::address)?;
+    /// Returns `ProposalVoteError` if the raw action's `fee_asset` is invalid.
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::ProposalVote {
+            proposal_id,
+            approve,
+            fee_asset,
+        } = raw;
+        let fee_asset = fee_asset.parse().map_err(ProposalVoteError::fee_asset)?;
+
         Ok(Self {
-            new_address,
+            proposal_id: *proposal_id,
+            approve: *approve,
+            fee_asset,
         })
     }
 }
 
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
-pub struct IbcSudoChangeError(IbcSudoChangeErrorKind);
-
-impl IbcSudoChangeError {
-    fn field_not_set(field: &'static str) -> Self {
-        Self(IbcSudoChangeErrorKind::FieldNotSet(field))
-    }
+pub struct ProposalVoteError(ProposalVoteErrorKind);
 
-    fn address(source: AddressError) -> Self {
-        Self(IbcSudoChangeErrorKind::Address {
-            source,
-        })
+impl ProposalVoteError {
+    fn fee_asset(inner: asset::ParseDenomError) -> Self {
+        Self(ProposalVoteErrorKind::FeeAsset(inner))
     }
 }
 
 #[derive(Debug, thiserror::Error)]
-enum IbcSudoChangeErrorKind {
-    #[error("the expected field in the raw source type was not set: `{0}`")]
-    FieldNotSet(&'static str),
-    #[error("`new_sudo` field did not contain a valid address")]
-    Address { source: AddressError },
+enum ProposalVoteErrorKind {
+    #[error("`fee_asset` field did not contain a valid asset ID")]
+    FeeAsset(#[source] asset::ParseDenomError),
 }
 
-/// Represents an IBC withdrawal of an asset from a source chain to a destination chain.
-///
-/// The parameters match the arguments to the `sendFungibleTokens` function in the
-/// [ICS 20 spec](https://github.com/cosmos/ibc/blob/fe150abb629de5c1a598e8c7896a7568f2083681/spec/app/ics-020-fungible-token-transfer/README.md#packet-relay).
-///
-/// Note that it does not contain `source_port` as that is implicit (it uses the `transfer`) port.
-///
-/// It also contains a `return_address` field which may or may not be the same as the signer
-/// of the packet. The funds will be returned to the `return_address` in the case of a timeout.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Ics20Withdrawal {
-    // a transparent value consisting of an amount and a denom.
-    pub amount: u128,
-    pub denom: Denom,
-    // the address on the destination chain to send the transfer to.
-    pub destination_chain_address: String,
-    // an Astria address to use to return funds from this withdrawal
-    // in the case it fails.
-    pub return_address: Address,
-    // the height (on the counterparty chain) at which this transfer expires.
-    pub timeout_height: IbcHeight,
-    // the unix timestamp (in nanoseconds) at which this transfer expires.
-    pub timeout_time: u64,
-    // the source channel used for the withdrawal.
-    pub source_channel: ChannelId,
-    // the asset to use for fee payment.
-    pub fee_asset: asset::Denom,
-    // a memo to include with the transfer
-    pub memo: String,
-    // the address of the bridge account to transfer from, if this is a withdrawal
-    // from a bridge account and the sender of the tx is the bridge's withdrawer,
-    // which differs from the bridge account's address.
-    //
-    // if unset, and the transaction sender is not a bridge account, the withdrawal
-    // is treated as a user (non-bridge) withdrawal.
-    //
-    // if unset, and the transaction sender is a bridge account, the withdrawal is
-    // treated as a bridge withdrawal (ie. the bridge account's withdrawer address is checked).
-    pub bridge_address: Option<Address>,
-
-    // whether to use a bech32-compatible format of the `.return_address` when generating
-    // fungible token packets (as opposed to Astria-native bech32m addresses). This is
-    // necessary for chains like noble which enforce a strict bech32 format.
-    pub use_compat_address: bool,
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "input was `{length}` bytes but validator names can only be up to \
+     `{MAX_VALIDATOR_NAME_LENGTH}` bytes long"
+)]
+pub struct ValidatorNameError {
+    length: usize,
 }
 
-impl Ics20Withdrawal {
-    #[must_use]
-    pub fn amount(&self) -> u128 {
-        self.amount
-    }
+/// Wrapper for validator name field of [`ValidatorUpdate`]. Cannot be longer than
+/// [`MAX_VALIDATOR_NAME_LENGTH`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ValidatorName(String);
 
+impl ValidatorName {
     #[must_use]
-    pub fn denom(&self) -> &Denom {
-        &self.denom
+    pub fn empty() -> Self {
+        Self(String::new())
     }
 
     #[must_use]
-    pub fn destination_chain_address(&self) -> &str {
-        &self.destination_chain_address
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
     }
 
     #[must_use]
-    pub fn return_address(&self) -> &Address {
-        &self.return_address
+    pub fn into_inner(self) -> String {
+        self.0
     }
+}
 
-    #[must_use]
-    pub fn timeout_height(&self) -> &IbcHeight {
-        &self.timeout_height
-    }
+impl FromStr for ValidatorName {
+    type Err = ValidatorNameError;
 
-    #[must_use]
-    pub fn timeout_time(&self) -> u64 {
-        self.timeout_time
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.len() > MAX_VALIDATOR_NAME_LENGTH {
+            return Err(ValidatorNameError {
+                length: value.len(),
+            });
+        }
+        Ok(Self(value.to_string()))
     }
+}
 
-    #[must_use]
-    pub fn source_channel(&self) -> &ChannelId {
-        &self.source_channel
+impl Display for ValidatorName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
     }
+}
 
-    #[must_use]
-    pub fn fee_asset(&self) -> &asset::Denom {
-        &self.fee_asset
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct ValidatorUpdateError(ValidatorUpdateErrorKind);
+
+impl ValidatorUpdateError {
+    fn negative_power(power: i64) -> Self {
+        Self(ValidatorUpdateErrorKind::NegativePower {
+            power,
+        })
     }
 
-    #[must_use]
-    pub fn memo(&self) -> &str {
-        &self.memo
+    fn public_key_not_set() -> Self {
+        Self(ValidatorUpdateErrorKind::PublicKeyNotSet)
     }
-}
 
-impl Protobuf for Ics20Withdrawal {
-    type Error = Ics20WithdrawalError;
-    type Raw = raw::Ics20Withdrawal;
+    fn secp256k1_not_supported() -> Self {
+        Self(ValidatorUpdateErrorKind::Secp256k1NotSupported)
+    }
 
-    #[must_use]
-    fn to_raw(&self) -> raw::Ics20Withdrawal {
-        raw::Ics20Withdrawal {
-            amount: Some(self.amount.into()),
-            denom: self.denom.to_string(),
-            destination_chain_address: self.destination_chain_address.clone(),
-            return_address: Some(self.return_address.into_raw()),
-            timeout_height: Some(self.timeout_height.into_raw()),
-            timeout_time: self.timeout_time,
-            source_channel: self.source_channel.to_string(),
-            fee_asset: self.fee_asset.to_string(),
-            memo: self.memo.clone(),
-            bridge_address: self.bridge_address.as_ref().map(Address::to_raw),
-            use_compat_address: self.use_compat_address,
-        }
+    fn verification_key(source: crate::crypto::Error) -> Self {
+        Self(ValidatorUpdateErrorKind::VerificationKey {
+            source,
+        })
     }
 
-    #[must_use]
-    fn into_raw(self) -> raw::Ics20Withdrawal {
-        raw::Ics20Withdrawal {
-            amount: Some(self.amount.into()),
-            denom: self.denom.to_string(),
-            destination_chain_address: self.destination_chain_address,
-            return_address: Some(self.return_address.into_raw()),
-            timeout_height: Some(self.timeout_height.into_raw()),
-            timeout_time: self.timeout_time,
-            source_channel: self.source_channel.to_string(),
-            fee_asset: self.fee_asset.to_string(),
-            memo: self.memo,
-            bridge_address: self.bridge_address.map(Address::into_raw),
-            use_compat_address: self.use_compat_address,
-        }
+    fn invalid_name(source: ValidatorNameError) -> Self {
+        Self(ValidatorUpdateErrorKind::InvalidName {
+            source,
+        })
     }
+}
 
-    /// Convert from a raw, unchecked protobuf [`raw::Ics20Withdrawal`].
-    ///
-    /// # Errors
-    ///
-    /// - if the `amount` field is missing
-    /// - if the `denom` field is invalid
-    /// - if the `return_address` field is invalid or missing
-    /// - if the `timeout_height` field is missing
-    /// - if the `source_channel` field is invalid
-    fn try_from_raw(proto: raw::Ics20Withdrawal) -> Result<Self, Ics20WithdrawalError> {
-        let raw::Ics20Withdrawal {
-            amount,
-            denom,
-            destination_chain_address,
-            return_address,
-            timeout_height,
-            timeout_time,
-            source_channel,
-            fee_asset,
-            memo,
-            bridge_address,
-            use_compat_address,
-        } = proto;
-        let amount = amount.ok_or(Ics20WithdrawalError::field_not_set("amount"))?;
-        let return_address = Address::try_from_raw(
-            return_address.ok_or(Ics20WithdrawalError::field_not_set("return_address"))?,
-        )
-        .map_err(Ics20WithdrawalError::return_address)?;
+#[derive(Debug, thiserror::Error)]
+enum ValidatorUpdateErrorKind {
+    #[error("field .power had negative value `{power}`, which is not permitted")]
+    NegativePower { power: i64 },
+    #[error(".pub_key field was not set")]
+    PublicKeyNotSet,
+    #[error(".pub_key field was set to secp256k1, but only ed25519 keys are supported")]
+    Secp256k1NotSupported,
+    #[error("bytes stored in the .pub_key field could not be read as an ed25519 verification key")]
+    VerificationKey { source: crate::crypto::Error },
+    #[error("field `.name` was invalid")]
+    InvalidName { source: ValidatorNameError },
+}
 
-        let timeout_height = timeout_height
-            .ok_or(Ics20WithdrawalError::field_not_set("timeout_height"))?
-            .into();
-        let bridge_address = bridge_address
-            .map(Address::try_from_raw)
-            .transpose()
-            .map_err(Ics20WithdrawalError::invalid_bridge_address)?;
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidatorUpdate {
+    pub power: u32,
+    pub verification_key: crate::crypto::VerificationKey,
+    pub name: ValidatorName,
+}
 
-        Ok(Self {
-            amount: amount.into(),
-            denom: denom.parse().map_err(Ics20WithdrawalError::invalid_denom)?,
-            destination_chain_address,
-            return_address,
-            timeout_height,
-            timeout_time,
-            source_channel: source_channel
-                .parse()
-                .map_err(Ics20WithdrawalError::invalid_source_channel)?,
-            fee_asset: fee_asset
-                .parse()
-                .map_err(Ics20WithdrawalError::invalid_fee_asset)?,
-            memo,
-            bridge_address,
-            use_compat_address,
-        })
-    }
+impl Protobuf for ValidatorUpdate {
+    type Error = ValidatorUpdateError;
+    type Raw = raw::ValidatorUpdate;
 
-    /// Convert from a reference to raw, unchecked protobuf [`raw::Ics20Withdrawal`].
+    /// Create a validator update by verifying a raw protobuf-decoded
+    /// [`crate::generated::protocol::transaction::v1alpha1::ValidatorUpdate`].
     ///
     /// # Errors
-    ///
-    /// - if the `amount` field is missing
-    /// - if the `denom` field is invalid
-    /// - if the `return_address` field is invalid or missing
-    /// - if the `timeout_height` field is missing
-    /// - if the `source_channel` field is invalid
-    fn try_from_raw_ref(proto: &raw::Ics20Withdrawal) -> Result<Self, Ics20WithdrawalError> {
-        let raw::Ics20Withdrawal {
-            amount,
-            denom,
-            destination_chain_address,
-            return_address,
-            timeout_height,
-            timeout_time,
-            source_channel,
-            fee_asset,
-            memo,
-            bridge_address,
-            use_compat_address,
-        } = proto;
-        let amount = amount.ok_or(Ics20WithdrawalError::field_not_set("amount"))?;
-        let return_address = return_address
-            .as_ref()
-            .ok_or_else(|| Ics20WithdrawalError::field_not_set("return_address"))
-            .and_then(|return_address| {
-                Address::try_from_raw_ref(return_address)
-                    .map_err(Ics20WithdrawalError::return_address)
-            })?;
-
-        let timeout_height = timeout_height
-            .ok_or(Ics20WithdrawalError::field_not_set("timeout_height"))?
-            .into();
-        let bridge_address = bridge_address
-            .as_ref()
-            .map(Address::try_from_raw_ref)
-            .transpose()
-            .map_err(Ics20WithdrawalError::invalid_bridge_address)?;
+    /// Returns an error if the `.power` field is negative, if `.pub_key`
+    /// is not set, or if `.pub_key` contains a non-ed25519 variant, or
+    /// if the ed25519 has invalid bytes (that is, bytes from which an
+    /// ed25519 public key cannot be constructed).
+    fn try_from_raw(value: Self::Raw) -> Result<Self, Self::Error> {
+        use crate::generated::astria_vendored::tendermint::crypto::{
+            public_key,
+            PublicKey,
+        };
+        let Self::Raw {
+            pub_key,
+            power,
+            name,
+        } = value;
+        let name = name.parse().map_err(ValidatorUpdateError::invalid_name)?;
+        let power = power
+            .try_into()
+            .map_err(|_| Self::Error::negative_power(power))?;
+        let verification_key = match pub_key {
+            None
+            | Some(PublicKey {
+                sum: None,
+            }) => Err(Self::Error::public_key_not_set()),
+            Some(PublicKey {
+                sum: Some(public_key::Sum::Secp256k1(..)),
+            }) => Err(Self::Error::secp256k1_not_supported()),
 
+            Some(PublicKey {
+                sum: Some(public_key::Sum::Ed25519(bytes)),
+            }) => crate::crypto::VerificationKey::try_from(&*bytes)
+                .map_err(Self::Error::verification_key),
+        }?;
         Ok(Self {
-            amount: amount.into(),
-            denom: denom.parse().map_err(Ics20WithdrawalError::invalid_denom)?,
-            destination_chain_address: destination_chain_address.clone(),
-            return_address,
-            timeout_height,
-            timeout_time: *timeout_time,
-            source_channel: source_channel
-                .parse()
-                .map_err(Ics20WithdrawalError::invalid_source_channel)?,
-            fee_asset: fee_asset
-                .parse()
-                .map_err(Ics20WithdrawalError::invalid_fee_asset)?,
-            memo: memo.clone(),
-            bridge_address,
-            use_compat_address: *use_compat_address,
+            power,
+            verification_key,
+            name,
         })
     }
-}
-
-impl From<raw::IbcHeight> for IbcHeight {
-    fn from(h: raw::IbcHeight) -> Self {
-        Self {
-            revision_number: h.revision_number,
-            revision_height: h.revision_height,
-        }
-    }
-}
-
-impl Protobuf for IbcHeight {
-    type Error = ::std::convert::Infallible;
-    type Raw = raw::IbcHeight;
 
+    /// Create a validator update by verifying a reference to raw protobuf-decoded
+    /// [`crate::generated::protocol::transaction::v1alpha1::ValidatorUpdate`].
+    ///
+    /// # Errors
+    /// Returns an error if the `.power` field is negative, if `.pub_key`
+    /// is not set, or if `.pub_key` contains a non-ed25519 variant, or
+    /// if the ed25519 has invalid bytes (that is, bytes from which an
+    /// ed25519 public key cannot be constructed).
     fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
-        Ok(Self {
-            revision_number: raw.revision_number,
-            revision_height: raw.revision_height,
-        })
+        Self::try_from_raw(raw.clone())
     }
 
+    #[must_use]
     fn to_raw(&self) -> Self::Raw {
+        use crate::generated::astria_vendored::tendermint::crypto::{
+            public_key,
+            PublicKey,
+        };
+        let Self {
+            power,
+            verification_key,
+            name,
+        } = self;
+
         Self::Raw {
-            revision_number: self.revision_number,
-            revision_height: self.revision_height,
+            power: (*power).into(),
+            pub_key: Some(PublicKey {
+                sum: Some(public_key::Sum::Ed25519(
+                    verification_key.to_bytes().to_vec(),
+                )),
+            }),
+            name: name.clone().into_inner(),
         }
     }
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error(transparent)]
-pub struct Ics20WithdrawalError(Ics20WithdrawalErrorKind);
+impl From<ValidatorUpdate>
+    for crate::generated::astria::protocol::transaction::v1::ValidatorUpdate
+{
+    fn from(value: ValidatorUpdate) -> Self {
+        value.into_raw()
+    }
+}
 
-impl Ics20WithdrawalError {
-    #[must_use]
-    fn field_not_set(field: &'static str) -> Self {
-        Self(Ics20WithdrawalErrorKind::FieldNotSet {
-            field,
-        })
+impl TryFrom<crate::generated::astria::protocol::transaction::v1::ValidatorUpdate>
+    for ValidatorUpdate
+{
+    type Error = ValidatorUpdateError;
+
+    fn try_from(
+        value: crate::generated::astria::protocol::transaction::v1::ValidatorUpdate,
+    ) -> Result<Self, Self::Error> {
+        Self::try_from_raw(value)
     }
+}
 
-    #[must_use]
-    fn return_address(source: AddressError) -> Self {
-        Self(Ics20WithdrawalErrorKind::ReturnAddress {
-            source,
-        })
+#[derive(Clone, Debug)]
+pub struct SudoAddressChange {
+    pub new_address: Address,
+}
+
+impl Protobuf for SudoAddressChange {
+    type Error = SudoAddressChangeError;
+    type Raw = raw::SudoAddressChange;
+
+    fn into_raw(self) -> raw::SudoAddressChange {
+        let Self {
+            new_address,
+        } = self;
+        raw::SudoAddressChange {
+            new_address: Some(new_address.into_raw()),
+        }
     }
 
     #[must_use]
-    fn invalid_source_channel(err: IdentifierError) -> Self {
-        Self(Ics20WithdrawalErrorKind::InvalidSourceChannel(err))
+    fn to_raw(&self) -> raw::SudoAddressChange {
+        let Self {
+            new_address,
+        } = self;
+        raw::SudoAddressChange {
+            new_address: Some(new_address.to_raw()),
+        }
     }
 
-    #[must_use]
-    fn invalid_fee_asset(err: asset::ParseDenomError) -> Self {
-        Self(Ics20WithdrawalErrorKind::InvalidFeeAsset(err))
+    /// Convert from a reference to a raw, unchecked protobuf [`raw::SudoAddressChange`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the raw action's `new_address` did not have the expected
+    /// length.
+    fn try_from_raw_ref(proto: &Self::Raw) -> Result<Self, SudoAddressChangeError> {
+        let raw::SudoAddressChange {
+            new_address,
+        } = proto;
+        let Some(new_address) = new_address else {
+            return Err(SudoAddressChangeError::field_not_set("new_address"));
+        };
+        let new_address =
+            Address::try_from_raw_ref(new_address).map_err(SudoAddressChangeError::address)?;
+        Ok(Self {
+            new_address,
+        })
     }
+}
 
-    #[must_use]
-    fn invalid_bridge_address(err: AddressError) -> Self {
-        Self(Ics20WithdrawalErrorKind::InvalidBridgeAddress(err))
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct SudoAddressChangeError(SudoAddressChangeErrorKind);
+
+impl SudoAddressChangeError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(SudoAddressChangeErrorKind::FieldNotSet(field))
     }
 
-    fn invalid_denom(source: asset::ParseDenomError) -> Self {
-        Self(Ics20WithdrawalErrorKind::InvalidDenom {
+    fn address(source: AddressError) -> Self {
+        Self(SudoAddressChangeErrorKind::Address {
             source,
         })
     }
 }
 
 #[derive(Debug, thiserror::Error)]
-enum Ics20WithdrawalErrorKind {
-    #[error("expected field `{field}` was not set`")]
-    FieldNotSet { field: &'static str },
-    #[error("`return_address` field was invalid")]
-    ReturnAddress { source: AddressError },
-    #[error("`source_channel` field was invalid")]
-    InvalidSourceChannel(#[source] IdentifierError),
-    #[error("field `fee_asset` could not be parsed")]
-    InvalidFeeAsset(#[source] asset::ParseDenomError),
-    #[error("`bridge_address` field was invalid")]
-    InvalidBridgeAddress(#[source] AddressError),
-    #[error("`denom` field was invalid")]
-    InvalidDenom { source: asset::ParseDenomError },
+enum SudoAddressChangeErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("`new_address` field did not contain a valid address")]
+    Address { source: AddressError },
 }
 
 #[derive(Debug, Clone)]
-pub enum IbcRelayerChange {
-    Addition(Address),
-    Removal(Address),
+pub struct IbcSudoChange {
+    pub new_address: Address,
 }
 
-impl Protobuf for IbcRelayerChange {
-    type Error = IbcRelayerChangeError;
-    type Raw = raw::IbcRelayerChange;
+impl Protobuf for IbcSudoChange {
+    type Error = IbcSudoChangeError;
+    type Raw = raw::IbcSudoChange;
 
-    #[must_use]
-    fn to_raw(&self) -> raw::IbcRelayerChange {
-        match self {
-            IbcRelayerChange::Addition(address) => raw::IbcRelayerChange {
-                value: Some(raw::ibc_relayer_change::Value::Addition(address.to_raw())),
-            },
-            IbcRelayerChange::Removal(address) => raw::IbcRelayerChange {
-                value: Some(raw::ibc_relayer_change::Value::Removal(address.to_raw())),
-            },
+    fn into_raw(self) -> raw::IbcSudoChange {
+        raw::IbcSudoChange {
+            new_address: Some(self.new_address.into_raw()),
         }
     }
 
-    /// Convert from a raw, unchecked protobuf [`raw::IbcRelayerChangeAction`].
+    #[must_use]
+    fn to_raw(&self) -> raw::IbcSudoChange {
+        raw::IbcSudoChange {
+            new_address: Some(self.new_address.to_raw()),
+        }
+    }
+
+    /// Convert from a reference to a raw, unchecked protobuf [`raw::IbcSudoChangeAction`].
     ///
     /// # Errors
     ///
-    /// - if the `address` field is invalid
-    fn try_from_raw_ref(raw: &raw::IbcRelayerChange) -> Result<Self, IbcRelayerChangeError> {
-        match raw {
-            raw::IbcRelayerChange {
-                value: Some(raw::ibc_relayer_change::Value::Addition(address)),
-            } => {
-                let address =
-                    Address::try_from_raw_ref(address).map_err(IbcRelayerChangeError::address)?;
-                Ok(IbcRelayerChange::Addition(address))
-            }
-            raw::IbcRelayerChange {
-                value: Some(raw::ibc_relayer_change::Value::Removal(address)),
-            } => {
-                let address =
-                    Address::try_from_raw_ref(address).map_err(IbcRelayerChangeError::address)?;
-                Ok(IbcRelayerChange::Removal(address))
-            }
-            _ => Err(IbcRelayerChangeError::missing_address()),
-        }
+    /// Returns an error if the raw action's `new_address` did not have the expected
+    /// length or if the field was not set.
+    fn try_from_raw_ref(proto: &Self::Raw) -> Result<Self, IbcSudoChangeError> {
+        let raw::IbcSudoChange {
+            new_address,
+        } = proto;
+        let Some(new_address) = new_address else {
+            return Err(IbcSudoChangeError::field_not_set("new_address"));
+        };
+        let new_address =
+            Address::try_from_raw_ref(new_address).map_err(IbcSudoChangeError::address)?;
+        Ok(Self {
+            new_address,
+        })
     }
 }
 
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
-pub struct IbcRelayerChangeError(IbcRelayerChangeErrorKind);
+pub struct IbcSudoChangeError(IbcSudoChangeErrorKind);
+
+impl IbcSudoChangeError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(IbcSudoChangeErrorKind::FieldNotSet(field))
+    }
 
-impl IbcRelayerChangeError {
-    #[must_use]
     fn address(source: AddressError) -> Self {
-        Self(IbcRelayerChangeErrorKind::Address {
+        Self(IbcSudoChangeErrorKind::Address {
             source,
         })
     }
-
-    #[must_use]
-    fn missing_address() -> Self {
-        Self(IbcRelayerChangeErrorKind::MissingAddress)
-    }
 }
 
 #[derive(Debug, thiserror::Error)]
-enum IbcRelayerChangeErrorKind {
-    #[error("the `address` was invalid")]
+enum IbcSudoChangeErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("`new_sudo` field did not contain a valid address")]
     Address { source: AddressError },
-    #[error("the `address` was not set")]
-    MissingAddress,
 }
 
-#[derive(Debug, Clone)]
-pub enum FeeAssetChange {
-    Addition(asset::Denom),
-    Removal(asset::Denom),
+/// Represents an IBC withdrawal of an asset from a source chain to a destination chain.
+///
+/// The parameters match the arguments to the `sendFungibleTokens` function in the
+/// [ICS 20 spec](https://github.com/cosmos/ibc/blob/fe150abb629de5c1a598e8c7896a7568f2083681/spec/app/ics-020-fungible-token-transfer/README.md#packet-relay).
+///
+/// Note that it does not contain `source_port` as that is implicit (it uses the `transfer`) port.
+///
+/// It also contains a `return_address` field which may or may not be the same as the signer
+/// of the packet. The funds will be returned to the `return_address` in the case of a timeout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ics20Withdrawal {
+    // a transparent value consisting of an amount and a denom.
+    pub amount: u128,
+    pub denom: Denom,
+    // the address on the destination chain to send the transfer to.
+    pub destination_chain_address: String,
+    // an Astria address to use to return funds from this withdrawal
+    // in the case it fails.
+    pub return_address: Address,
+    // the height (on the counterparty chain) at which this transfer expires.
+    pub timeout_height: IbcHeight,
+    // the unix timestamp (in nanoseconds) at which this transfer expires.
+    pub timeout_time: u64,
+    // the source channel used for the withdrawal.
+    pub source_channel: ChannelId,
+    // the asset to use for fee payment.
+    pub fee_asset: asset::Denom,
+    // a memo to include with the transfer
+    pub memo: String,
+    // the address of the bridge account to transfer from, if this is a withdrawal
+    // from a bridge account and the sender of the tx is the bridge's withdrawer,
+    // which differs from the bridge account's address.
+    //
+    // if unset, and the transaction sender is not a bridge account, the withdrawal
+    // is treated as a user (non-bridge) withdrawal.
+    //
+    // if unset, and the transaction sender is a bridge account, the withdrawal is
+    // treated as a bridge withdrawal (ie. the bridge account's withdrawer address is checked).
+    pub bridge_address: Option<Address>,
+
+    // whether to use a bech32-compatible format of the `.return_address` when generating
+    // fungible token packets (as opposed to Astria-native bech32m addresses). This is
+    // necessary for chains like noble which enforce a strict bech32 format.
+    pub use_compat_address: bool,
 }
 
-impl Protobuf for FeeAssetChange {
-    type Error = FeeAssetChangeError;
-    type Raw = raw::FeeAssetChange;
+impl Ics20Withdrawal {
+    #[must_use]
+    pub fn amount(&self) -> u128 {
+        self.amount
+    }
 
     #[must_use]
-    fn to_raw(&self) -> raw::FeeAssetChange {
-        match self {
-            FeeAssetChange::Addition(asset) => raw::FeeAssetChange {
-                value: Some(raw::fee_asset_change::Value::Addition(asset.to_string())),
-            },
-            FeeAssetChange::Removal(asset) => raw::FeeAssetChange {
-                value: Some(raw::fee_asset_change::Value::Removal(asset.to_string())),
-            },
-        }
+    pub fn denom(&self) -> &Denom {
+        &self.denom
     }
 
-    /// Convert from a reference to a raw, unchecked protobuf [`raw::FeeAssetChangeAction`].
-    ///
-    /// # Errors
-    ///
-    /// - if the `asset` field is invalid
-    fn try_from_raw_ref(raw: &raw::FeeAssetChange) -> Result<Self, FeeAssetChangeError> {
-        match raw {
-            raw::FeeAssetChange {
-                value: Some(raw::fee_asset_change::Value::Addition(asset)),
-            } => {
-                let asset = asset.parse().map_err(FeeAssetChangeError::invalid_asset)?;
-                Ok(FeeAssetChange::Addition(asset))
-            }
-            raw::FeeAssetChange {
-                value: Some(raw::fee_asset_change::Value::Removal(asset)),
-            } => {
-                let asset = asset.parse().map_err(FeeAssetChangeError::invalid_asset)?;
-                Ok(FeeAssetChange::Removal(asset))
-            }
-            _ => Err(FeeAssetChangeError::missing_asset()),
-        }
+    #[must_use]
+    pub fn destination_chain_address(&self) -> &str {
+        &self.destination_chain_address
     }
-}
 
-#[derive(Debug, thiserror::Error)]
-#[error(transparent)]
-pub struct FeeAssetChangeError(FeeAssetChangeErrorKind);
+    #[must_use]
+    pub fn return_address(&self) -> &Address {
+        &self.return_address
+    }
 
-impl FeeAssetChangeError {
     #[must_use]
-    fn invalid_asset(err: asset::ParseDenomError) -> Self {
-        Self(FeeAssetChangeErrorKind::InvalidAsset(err))
+    pub fn timeout_height(&self) -> &IbcHeight {
+        &self.timeout_height
     }
 
     #[must_use]
-    fn missing_asset() -> Self {
-        Self(FeeAssetChangeErrorKind::MissingAsset)
+    pub fn timeout_time(&self) -> u64 {
+        self.timeout_time
     }
-}
 
-#[derive(Debug, thiserror::Error)]
-enum FeeAssetChangeErrorKind {
-    #[error("the `asset` field was invalid")]
-    InvalidAsset(#[source] asset::ParseDenomError),
-    #[error("the `asset` field was not set")]
-    MissingAsset,
-}
+    #[must_use]
+    pub fn source_channel(&self) -> &ChannelId {
+        &self.source_channel
+    }
 
-#[derive(Debug, Clone)]
-pub struct InitBridgeAccount {
-    // the rollup ID to register for the sender of this action
-    pub rollup_id: RollupId,
-    // the asset accepted by the bridge account
-    pub asset: asset::Denom,
-    // the fee asset which to pay this action's fees with
-    pub fee_asset: asset::Denom,
-    // the address corresponding to the key which has sudo capabilities;
-    // ie. can change the sudo and withdrawer addresses for this bridge account.
-    // if unset, this is set to the sender of the transaction.
-    pub sudo_address: Option<Address>,
-    // the address corresponding to the key which can withdraw funds from this bridge account.
-    // if unset, this is set to the sender of the transaction.
-    pub withdrawer_address: Option<Address>,
+    #[must_use]
+    pub fn fee_asset(&self) -> &asset::Denom {
+        &self.fee_asset
+    }
+
+    #[must_use]
+    pub fn memo(&self) -> &str {
+        &self.memo
+    }
 }
 
-impl Protobuf for InitBridgeAccount {
-    type Error = InitBridgeAccountError;
-    type Raw = raw::InitBridgeAccount;
+impl Protobuf for Ics20Withdrawal {
+    type Error = Ics20WithdrawalError;
+    type Raw = raw::Ics20Withdrawal;
 
     #[must_use]
-    fn into_raw(self) -> raw::InitBridgeAccount {
-        raw::InitBridgeAccount {
-            rollup_id: Some(self.rollup_id.to_raw()),
-            asset: self.asset.to_string(),
+    fn to_raw(&self) -> raw::Ics20Withdrawal {
+        raw::Ics20Withdrawal {
+            amount: Some(self.amount.into()),
+            denom: self.denom.to_string(),
+            destination_chain_address: self.destination_chain_address.clone(),
+            return_address: Some(self.return_address.into_raw()),
+            timeout_height: Some(self.timeout_height.into_raw()),
+            timeout_time: self.timeout_time,
+            source_channel: self.source_channel.to_string(),
             fee_asset: self.fee_asset.to_string(),
-            sudo_address: self.sudo_address.map(Address::into_raw),
-            withdrawer_address: self.withdrawer_address.map(Address::into_raw),
+            memo: self.memo.clone(),
+            bridge_address: self.bridge_address.as_ref().map(Address::to_raw),
+            use_compat_address: self.use_compat_address,
         }
     }
 
     #[must_use]
-    fn to_raw(&self) -> raw::InitBridgeAccount {
-        raw::InitBridgeAccount {
-            rollup_id: Some(self.rollup_id.to_raw()),
-            asset: self.asset.to_string(),
+    fn into_raw(self) -> raw::Ics20Withdrawal {
+        raw::Ics20Withdrawal {
+            amount: Some(self.amount.into()),
+            denom: self.denom.to_string(),
+            destination_chain_address: self.destination_chain_address,
+            return_address: Some(self.return_address.into_raw()),
+            timeout_height: Some(self.timeout_height.into_raw()),
+            timeout_time: self.timeout_time,
+            source_channel: self.source_channel.to_string(),
             fee_asset: self.fee_asset.to_string(),
-            sudo_address: self.sudo_address.as_ref().map(Address::to_raw),
-            withdrawer_address: self.withdrawer_address.as_ref().map(Address::to_raw),
+            memo: self.memo,
+            bridge_address: self.bridge_address.map(Address::into_raw),
+            use_compat_address: self.use_compat_address,
         }
     }
 
-    /// Convert from a raw, unchecked protobuf [`raw::InitBridgeAccountAction`].
+    /// Convert from a raw, unchecked protobuf [`raw::Ics20Withdrawal`].
     ///
     /// # Errors
     ///
-    /// - if the `rollup_id` field is not set
-    /// - if the `rollup_id` field is invalid
-    /// - if the `sudo_address` field is invalid
-    /// - if the `withdrawer_address` field is invalid
-    fn try_from_raw(proto: raw::InitBridgeAccount) -> Result<Self, InitBridgeAccountError> {
-        let Some(rollup_id) = proto.rollup_id else {
-            return Err(InitBridgeAccountError::field_not_set("rollup_id"));
-        };
-        let rollup_id =
-            RollupId::try_from_raw(rollup_id).map_err(InitBridgeAccountError::invalid_rollup_id)?;
-        let asset = proto
-            .asset
-            .parse()
-            .map_err(InitBridgeAccountError::invalid_asset)?;
-        let fee_asset = proto
-            .fee_asset
-            .parse()
-            .map_err(InitBridgeAccountError::invalid_fee_asset)?;
-        let sudo_address = proto
-            .sudo_address
-            .map(Address::try_from_raw)
-            .transpose()
-            .map_err(InitBridgeAccountError::invalid_sudo_address)?;
-        let withdrawer_address = proto
-            .withdrawer_address
+    /// - if the `amount` field is missing
+    /// - if the `denom` field is invalid
+    /// - if the `return_address` field is invalid or missing
+    /// - if the `timeout_height` field is missing
+    /// - if the `source_channel` field is invalid
+    fn try_from_raw(proto: raw::Ics20Withdrawal) -> Result<Self, Ics20WithdrawalError> {
+        let raw::Ics20Withdrawal {
+            amount,
+            denom,
+            destination_chain_address,
+            return_address,
+            timeout_height,
+            timeout_time,
+            source_channel,
+            fee_asset,
+            memo,
+            bridge_address,
+            use_compat_address,
+        } = proto;
+        let amount = amount.ok_or(Ics20WithdrawalError::field_not_set("amount"))?;
+        let return_address = Address::try_from_raw(
+            return_address.ok_or(Ics20WithdrawalError::field_not_set("return_address"))?,
+        )
+        .map_err(Ics20WithdrawalError::return_address)?;
+
+        let timeout_height = timeout_height
+            .ok_or(Ics20WithdrawalError::field_not_set("timeout_height"))?
+            .into();
+        let bridge_address = bridge_address
             .map(Address::try_from_raw)
             .transpose()
-            .map_err(InitBridgeAccountError::invalid_withdrawer_address)?;
+            .map_err(Ics20WithdrawalError::invalid_bridge_address)?;
 
         Ok(Self {
-            rollup_id,
-            asset,
-            fee_asset,
-            sudo_address,
-            withdrawer_address,
+            amount: amount.into(),
+            denom: denom.parse().map_err(Ics20WithdrawalError::invalid_denom)?,
+            destination_chain_address,
+            return_address,
+            timeout_height,
+            timeout_time,
+            source_channel: source_channel
+                .parse()
+                .map_err(Ics20WithdrawalError::invalid_source_channel)?,
+            fee_asset: fee_asset
+                .parse()
+                .map_err(Ics20WithdrawalError::invalid_fee_asset)?,
+            memo,
+            bridge_address,
+            use_compat_address,
         })
     }
 
-    /// Convert from a reference to a raw, unchecked protobuf [`raw::InitBridgeAccountAction`].
+    /// Convert from a reference to raw, unchecked protobuf [`raw::Ics20Withdrawal`].
     ///
     /// # Errors
     ///
-    /// - if the `rollup_id` field is not set
-    /// - if the `rollup_id` field is invalid
-    /// - if the `sudo_address` field is invalid
-    /// - if the `withdrawer_address` field is invalid
-    fn try_from_raw_ref(proto: &Self::Raw) -> Result<Self, InitBridgeAccountError> {
-        Self::try_from_raw(proto.clone())
+    /// - if the `amount` field is missing
+    /// - if the `denom` field is invalid
+    /// - if the `return_address` field is invalid or missing
+    /// - if the `timeout_height` field is missing
+    /// - if the `source_channel` field is invalid
+    fn try_from_raw_ref(proto: &raw::Ics20Withdrawal) -> Result<Self, Ics20WithdrawalError> {
+        let raw::Ics20Withdrawal {
+            amount,
+            denom,
+            destination_chain_address,
+            return_address,
+            timeout_height,
+            timeout_time,
+            source_channel,
+            fee_asset,
+            memo,
+            bridge_address,
+            use_compat_address,
+        } = proto;
+        let amount = amount.ok_or(Ics20WithdrawalError::field_not_set("amount"))?;
+        let return_address = return_address
+            .as_ref()
+            .ok_or_else(|| Ics20WithdrawalError::field_not_set("return_address"))
+            .and_then(|return_address| {
+                Address::try_from_raw_ref(return_address)
+                    .map_err(Ics20WithdrawalError::return_address)
+            })?;
+
+        let timeout_height = timeout_height
+            .ok_or(Ics20WithdrawalError::field_not_set("timeout_height"))?
+            .into();
+        let bridge_address = bridge_address
+            .as_ref()
+            .map(Address::try_from_raw_ref)
+            .transpose()
+            .map_err(Ics20WithdrawalError::invalid_bridge_address)?;
+
+        Ok(Self {
+            amount: amount.into(),
+            denom: denom.parse().map_err(Ics20WithdrawalError::invalid_denom)?,
+            destination_chain_address: destination_chain_address.clone(),
+            return_address,
+            timeout_height,
+            timeout_time: *timeout_time,
+            source_channel: source_channel
+                .parse()
+                .map_err(Ics20WithdrawalError::invalid_source_channel)?,
+            fee_asset: fee_asset
+                .parse()
+                .map_err(Ics20WithdrawalError::invalid_fee_asset)?,
+            memo: memo.clone(),
+            bridge_address,
+            use_compat_address: *use_compat_address,
+        })
+    }
+}
+
+impl From<raw::IbcHeight> for IbcHeight {
+    fn from(h: raw::IbcHeight) -> Self {
+        Self {
+            revision_number: h.revision_number,
+            revision_height: h.revision_height,
+        }
+    }
+}
+
+impl Protobuf for IbcHeight {
+    type Error = ::std::convert::Infallible;
+    type Raw = raw::IbcHeight;
+
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        Ok(Self {
+            revision_number: raw.revision_number,
+            revision_height: raw.revision_height,
+        })
+    }
+
+    fn to_raw(&self) -> Self::Raw {
+        Self::Raw {
+            revision_number: self.revision_number,
+            revision_height: self.revision_height,
+        }
     }
 }
 
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
-pub struct InitBridgeAccountError(InitBridgeAccountErrorKind);
+pub struct Ics20WithdrawalError(Ics20WithdrawalErrorKind);
 
-impl InitBridgeAccountError {
+impl Ics20WithdrawalError {
     #[must_use]
     fn field_not_set(field: &'static str) -> Self {
-        Self(InitBridgeAccountErrorKind::FieldNotSet(field))
+        Self(Ics20WithdrawalErrorKind::FieldNotSet {
+            field,
+        })
     }
 
     #[must_use]
-    fn invalid_rollup_id(err: IncorrectRollupIdLength) -> Self {
-        Self(InitBridgeAccountErrorKind::InvalidRollupId(err))
+    fn return_address(source: AddressError) -> Self {
+        Self(Ics20WithdrawalErrorKind::ReturnAddress {
+            source,
+        })
     }
 
     #[must_use]
-    fn invalid_asset(err: asset::ParseDenomError) -> Self {
-        Self(InitBridgeAccountErrorKind::InvalidAsset(err))
+    fn invalid_source_channel(err: IdentifierError) -> Self {
+        Self(Ics20WithdrawalErrorKind::InvalidSourceChannel(err))
     }
 
     #[must_use]
     fn invalid_fee_asset(err: asset::ParseDenomError) -> Self {
-        Self(InitBridgeAccountErrorKind::InvalidFeeAsset(err))
+        Self(Ics20WithdrawalErrorKind::InvalidFeeAsset(err))
     }
 
     #[must_use]
-    fn invalid_sudo_address(err: AddressError) -> Self {
-        Self(InitBridgeAccountErrorKind::InvalidSudoAddress(err))
+    fn invalid_bridge_address(err: AddressError) -> Self {
+        Self(Ics20WithdrawalErrorKind::InvalidBridgeAddress(err))
     }
 
-    #[must_use]
-    fn invalid_withdrawer_address(err: AddressError) -> Self {
-        Self(InitBridgeAccountErrorKind::InvalidWithdrawerAddress(err))
+    fn invalid_denom(source: asset::ParseDenomError) -> Self {
+        Self(Ics20WithdrawalErrorKind::InvalidDenom {
+            source,
+        })
     }
 }
 
 #[derive(Debug, thiserror::Error)]
-enum InitBridgeAccountErrorKind {
-    #[error("the expected field in the raw source type was not set: `{0}`")]
-    FieldNotSet(&'static str),
-    #[error("the `rollup_id` field was invalid")]
-    InvalidRollupId(#[source] IncorrectRollupIdLength),
-    #[error("an asset ID was invalid")]
-    InvalidAsset(#[source] asset::ParseDenomError),
-    #[error("the `fee_asset` field was invalid")]
+enum Ics20WithdrawalErrorKind {
+    #[error("expected field `{field}` was not set`")]
+    FieldNotSet { field: &'static str },
+    #[error("`return_address` field was invalid")]
+    ReturnAddress { source: AddressError },
+    #[error("`source_channel` field was invalid")]
+    InvalidSourceChannel(#[source] IdentifierError),
+    #[error("field `fee_asset` could not be parsed")]
     InvalidFeeAsset(#[source] asset::ParseDenomError),
-    #[error("the `sudo_address` field was invalid")]
-    InvalidSudoAddress(#[source] AddressError),
-    #[error("the `withdrawer_address` field was invalid")]
-    InvalidWithdrawerAddress(#[source] AddressError),
+    #[error("`bridge_address` field was invalid")]
+    InvalidBridgeAddress(#[source] AddressError),
+    #[error("`denom` field was invalid")]
+    InvalidDenom { source: asset::ParseDenomError },
 }
 
 #[derive(Debug, Clone)]
-pub struct BridgeLock {
-    pub to: Address,
-    pub amount: u128,
-    // asset to be transferred.
-    pub asset: asset::Denom,
-    // asset to use for fee payment.
-    pub fee_asset: asset::Denom,
-    // the address on the destination chain to send the transfer to.
-    pub destination_chain_address: String,
+pub enum IbcRelayerChange {
+    Addition(Address),
+    Removal(Address),
 }
 
-impl Protobuf for BridgeLock {
-    type Error = BridgeLockError;
-    type Raw = raw::BridgeLock;
+impl Protobuf for IbcRelayerChange {
+    type Error = IbcRelayerChangeError;
+    type Raw = raw::IbcRelayerChange;
 
     #[must_use]
-    fn into_raw(self) -> raw::BridgeLock {
-        raw::BridgeLock {
-            to: Some(self.to.to_raw()),
-            amount: Some(self.amount.into()),
-            asset: self.asset.to_string(),
-            fee_asset: self.fee_asset.to_string(),
-            destination_chain_address: self.destination_chain_address,
+    fn to_raw(&self) -> raw::IbcRelayerChange {
+        match self {
+            IbcRelayerChange::Addition(address) => raw::IbcRelayerChange {
+                value: Some(raw::ibc_relayer_change::Value::Addition(address.to_raw())),
+            },
+            IbcRelayerChange::Removal(address) => raw::IbcRelayerChange {
+                value: Some(raw::ibc_relayer_change::Value::Removal(address.to_raw())),
+            },
         }
     }
 
-    #[must_use]
-    fn to_raw(&self) -> raw::BridgeLock {
-        raw::BridgeLock {
-            to: Some(self.to.to_raw()),
-            amount: Some(self.amount.into()),
+    /// Convert from a raw, unchecked protobuf [`raw::IbcRelayerChangeAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `address` field is invalid
+    fn try_from_raw_ref(raw: &raw::IbcRelayerChange) -> Result<Self, IbcRelayerChangeError> {
+        match raw {
+            raw::IbcRelayerChange {
+                value: Some(raw::ibc_relayer_change::Value::Addition(address)),
+            } => {
+                let address =
+                    Address::try_from_raw_ref(address).map_err(IbcRelayerChangeError::address)?;
+                Ok(IbcRelayerChange::Addition(address))
+            }
+            raw::IbcRelayerChange {
+                value: Some(raw::ibc_relayer_change::Value::Removal(address)),
+            } => {
+                let address =
+                    Address::try_from_raw_ref(address).map_err(IbcRelayerChangeError::address)?;
+                Ok(IbcRelayerChange::Removal(address))
+            }
+            _ => Err(IbcRelayerChangeError::missing_address()),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct IbcRelayerChangeError(IbcRelayerChangeErrorKind);
+
+impl IbcRelayerChangeError {
+    #[must_use]
+    fn address(source: AddressError) -> Self {
+        Self(IbcRelayerChangeErrorKind::Address {
+            source,
+        })
+    }
+
+    #[must_use]
+    fn missing_address() -> Self {
+        Self(IbcRelayerChangeErrorKind::MissingAddress)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum IbcRelayerChangeErrorKind {
+    #[error("the `address` was invalid")]
+    Address { source: AddressError },
+    #[error("the `address` was not set")]
+    MissingAddress,
+}
+
+#[derive(Debug, Clone)]
+pub enum FeeAssetChange {
+    Addition(asset::Denom),
+    Removal(asset::Denom),
+}
+
+impl Protobuf for FeeAssetChange {
+    type Error = FeeAssetChangeError;
+    type Raw = raw::FeeAssetChange;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::FeeAssetChange {
+        match self {
+            FeeAssetChange::Addition(asset) => raw::FeeAssetChange {
+                value: Some(raw::fee_asset_change::Value::Addition(asset.to_string())),
+            },
+            FeeAssetChange::Removal(asset) => raw::FeeAssetChange {
+                value: Some(raw::fee_asset_change::Value::Removal(asset.to_string())),
+            },
+        }
+    }
+
+    /// Convert from a reference to a raw, unchecked protobuf [`raw::FeeAssetChangeAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `asset` field is invalid
+    fn try_from_raw_ref(raw: &raw::FeeAssetChange) -> Result<Self, FeeAssetChangeError> {
+        match raw {
+            raw::FeeAssetChange {
+                value: Some(raw::fee_asset_change::Value::Addition(asset)),
+            } => {
+                let asset = asset.parse().map_err(FeeAssetChangeError::invalid_asset)?;
+                Ok(FeeAssetChange::Addition(asset))
+            }
+            raw::FeeAssetChange {
+                value: Some(raw::fee_asset_change::Value::Removal(asset)),
+            } => {
+                let asset = asset.parse().map_err(FeeAssetChangeError::invalid_asset)?;
+                Ok(FeeAssetChange::Removal(asset))
+            }
+            _ => Err(FeeAssetChangeError::missing_asset()),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct FeeAssetChangeError(FeeAssetChangeErrorKind);
+
+impl FeeAssetChangeError {
+    #[must_use]
+    fn invalid_asset(err: asset::ParseDenomError) -> Self {
+        Self(FeeAssetChangeErrorKind::InvalidAsset(err))
+    }
+
+    #[must_use]
+    fn missing_asset() -> Self {
+        Self(FeeAssetChangeErrorKind::MissingAsset)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum FeeAssetChangeErrorKind {
+    #[error("the `asset` field was invalid")]
+    InvalidAsset(#[source] asset::ParseDenomError),
+    #[error("the `asset` field was not set")]
+    MissingAsset,
+}
+
+#[derive(Debug, Clone)]
+pub struct InitBridgeAccount {
+    // the rollup ID to register for the sender of this action
+    pub rollup_id: RollupId,
+    // the asset accepted by the bridge account
+    pub asset: asset::Denom,
+    // the fee asset which to pay this action's fees with
+    pub fee_asset: asset::Denom,
+    // the address corresponding to the key which has sudo capabilities;
+    // ie. can change the sudo and withdrawer addresses for this bridge account.
+    // if unset, this is set to the sender of the transaction.
+    pub sudo_address: Option<Address>,
+    // the address corresponding to the key which can withdraw funds from this bridge account.
+    // if unset, this is set to the sender of the transaction.
+    pub withdrawer_address: Option<Address>,
+}
+
+impl Protobuf for InitBridgeAccount {
+    type Error = InitBridgeAccountError;
+    type Raw = raw::InitBridgeAccount;
+
+    #[must_use]
+    fn into_raw(self) -> raw::InitBridgeAccount {
+        raw::InitBridgeAccount {
+            rollup_id: Some(self.rollup_id.to_raw()),
             asset: self.asset.to_string(),
             fee_asset: self.fee_asset.to_string(),
-            destination_chain_address: self.destination_chain_address.clone(),
+            sudo_address: self.sudo_address.map(Address::into_raw),
+            withdrawer_address: self.withdrawer_address.map(Address::into_raw),
         }
     }
 
-    /// Convert from a raw, unchecked protobuf [`raw::BridgeLockAction`].
+    #[must_use]
+    fn to_raw(&self) -> raw::InitBridgeAccount {
+        raw::InitBridgeAccount {
+            rollup_id: Some(self.rollup_id.to_raw()),
+            asset: self.asset.to_string(),
+            fee_asset: self.fee_asset.to_string(),
+            sudo_address: self.sudo_address.as_ref().map(Address::to_raw),
+            withdrawer_address: self.withdrawer_address.as_ref().map(Address::to_raw),
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::InitBridgeAccountAction`].
     ///
     /// # Errors
     ///
-    /// - if the `to` field is not set
-    /// - if the `to` field is invalid
-    /// - if the `asset` field is invalid
-    /// - if the `fee_asset` field is invalid
-    fn try_from_raw(proto: raw::BridgeLock) -> Result<Self, BridgeLockError> {
-        let Some(to) = proto.to else {
-            return Err(BridgeLockError::field_not_set("to"));
+    /// - if the `rollup_id` field is not set
+    /// - if the `rollup_id` field is invalid
+    /// - if the `sudo_address` field is invalid
+    /// - if the `withdrawer_address` field is invalid
+    fn try_from_raw(proto: raw::InitBridgeAccount) -> Result<Self, InitBridgeAccountError> {
+        let Some(rollup_id) = proto.rollup_id else {
+            return Err(InitBridgeAccountError::field_not_set("rollup_id"));
         };
-        let to = Address::try_from_raw(to).map_err(BridgeLockError::address)?;
-        let amount = proto.amount.ok_or(BridgeLockError::missing_amount())?;
+        let rollup_id =
+            RollupId::try_from_raw(rollup_id).map_err(InitBridgeAccountError::invalid_rollup_id)?;
         let asset = proto
             .asset
             .parse()
-            .map_err(BridgeLockError::invalid_asset)?;
+            .map_err(InitBridgeAccountError::invalid_asset)?;
         let fee_asset = proto
             .fee_asset
             .parse()
-            .map_err(BridgeLockError::invalid_fee_asset)?;
+            .map_err(InitBridgeAccountError::invalid_fee_asset)?;
+        let sudo_address = proto
+            .sudo_address
+            .map(Address::try_from_raw)
+            .transpose()
+            .map_err(InitBridgeAccountError::invalid_sudo_address)?;
+        let withdrawer_address = proto
+            .withdrawer_address
+            .map(Address::try_from_raw)
+            .transpose()
+            .map_err(InitBridgeAccountError::invalid_withdrawer_address)?;
+
         Ok(Self {
-            to,
-            amount: amount.into(),
+            rollup_id,
             asset,
             fee_asset,
-            destination_chain_address: proto.destination_chain_address,
+            sudo_address,
+            withdrawer_address,
         })
     }
 
-    /// Convert from a reference to a raw, unchecked protobuf [`raw::BridgeLockAction`].
+    /// Convert from a reference to a raw, unchecked protobuf [`raw::InitBridgeAccountAction`].
     ///
     /// # Errors
     ///
-    /// - if the `to` field is not set
-    /// - if the `to` field is invalid
-    /// - if the `asset` field is invalid
-    /// - if the `fee_asset` field is invalid
-    fn try_from_raw_ref(proto: &raw::BridgeLock) -> Result<Self, BridgeLockError> {
+    /// - if the `rollup_id` field is not set
+    /// - if the `rollup_id` field is invalid
+    /// - if the `sudo_address` field is invalid
+    /// - if the `withdrawer_address` field is invalid
+    fn try_from_raw_ref(proto: &Self::Raw) -> Result<Self, InitBridgeAccountError> {
         Self::try_from_raw(proto.clone())
     }
 }
 
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
-pub struct BridgeLockError(BridgeLockErrorKind);
+pub struct InitBridgeAccountError(InitBridgeAccountErrorKind);
 
-impl BridgeLockError {
+impl InitBridgeAccountError {
     #[must_use]
     fn field_not_set(field: &'static str) -> Self {
-        Self(BridgeLockErrorKind::FieldNotSet(field))
+        Self(InitBridgeAccountErrorKind::FieldNotSet(field))
     }
 
     #[must_use]
-    fn address(source: AddressError) -> Self {
-        Self(BridgeLockErrorKind::Address {
-            source,
-        })
+    fn invalid_rollup_id(err: IncorrectRollupIdLength) -> Self {
+        Self(InitBridgeAccountErrorKind::InvalidRollupId(err))
     }
 
     #[must_use]
-    fn missing_amount() -> Self {
-        Self(BridgeLockErrorKind::MissingAmount)
+    fn invalid_asset(err: asset::ParseDenomError) -> Self {
+        Self(InitBridgeAccountErrorKind::InvalidAsset(err))
     }
 
     #[must_use]
-    fn invalid_asset(err: asset::ParseDenomError) -> Self {
-        Self(BridgeLockErrorKind::InvalidAsset(err))
+    fn invalid_fee_asset(err: asset::ParseDenomError) -> Self {
+        Self(InitBridgeAccountErrorKind::InvalidFeeAsset(err))
     }
 
     #[must_use]
-    fn invalid_fee_asset(err: asset::ParseDenomError) -> Self {
-        Self(BridgeLockErrorKind::InvalidFeeAsset(err))
+    fn invalid_sudo_address(err: AddressError) -> Self {
+        Self(InitBridgeAccountErrorKind::InvalidSudoAddress(err))
+    }
+
+    #[must_use]
+    fn invalid_withdrawer_address(err: AddressError) -> Self {
+        Self(InitBridgeAccountErrorKind::InvalidWithdrawerAddress(err))
     }
 }
 
 #[derive(Debug, thiserror::Error)]
-enum BridgeLockErrorKind {
+enum InitBridgeAccountErrorKind {
     #[error("the expected field in the raw source type was not set: `{0}`")]
     FieldNotSet(&'static str),
-    #[error("the `to` field was invalid")]
-    Address { source: AddressError },
-    #[error("the `amount` field was not set")]
-    MissingAmount,
-    #[error("the `asset` field was invalid")]
+    #[error("the `rollup_id` field was invalid")]
+    InvalidRollupId(#[source] IncorrectRollupIdLength),
+    #[error("an asset ID was invalid")]
     InvalidAsset(#[source] asset::ParseDenomError),
     #[error("the `fee_asset` field was invalid")]
     InvalidFeeAsset(#[source] asset::ParseDenomError),
-}
-
-#[derive(Debug, Clone, PartialEq, Eq)]
+    #[error("the `sudo_address` field was invalid")]
+    InvalidSudoAddress(#[source] AddressError),
+    #[error("the `withdrawer_address` field was invalid")]
+    InvalidWithdrawerAddress(#[source] AddressError),
+}
+
+#[derive(Debug, Clone)]
+pub struct BridgeLock {
+    pub to: Address,
+    pub amount: u128,
+    // asset to be transferred.
+    pub asset: asset::Denom,
+    // asset to use for fee payment.
+    pub fee_asset: asset::Denom,
+    // the address on the destination chain to send the transfer to.
+    pub destination_chain_address: String,
+}
+
+impl Protobuf for BridgeLock {
+    type Error = BridgeLockError;
+    type Raw = raw::BridgeLock;
+
+    #[must_use]
+    fn into_raw(self) -> raw::BridgeLock {
+        raw::BridgeLock {
+            to: Some(self.to.to_raw()),
+            amount: Some(self.amount.into()),
+            asset: self.asset.to_string(),
+            fee_asset: self.fee_asset.to_string(),
+            destination_chain_address: self.destination_chain_address,
+        }
+    }
+
+    #[must_use]
+    fn to_raw(&self) -> raw::BridgeLock {
+        raw::BridgeLock {
+            to: Some(self.to.to_raw()),
+            amount: Some(self.amount.into()),
+            asset: self.asset.to_string(),
+            fee_asset: self.fee_asset.to_string(),
+            destination_chain_address: self.destination_chain_address.clone(),
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::BridgeLockAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `to` field is not set
+    /// - if the `to` field is invalid
+    /// - if the `asset` field is invalid
+    /// - if the `fee_asset` field is invalid
+    fn try_from_raw(proto: raw::BridgeLock) -> Result<Self, BridgeLockError> {
+        let Some(to) = proto.to else {
+            return Err(BridgeLockError::field_not_set("to"));
+        };
+        let to = Address::try_from_raw(to).map_err(BridgeLockError::address)?;
+        let amount = proto.amount.ok_or(BridgeLockError::missing_amount())?;
+        let asset = proto
+            .asset
+            .parse()
+            .map_err(BridgeLockError::invalid_asset)?;
+        let fee_asset = proto
+            .fee_asset
+            .parse()
+            .map_err(BridgeLockError::invalid_fee_asset)?;
+        Ok(Self {
+            to,
+            amount: amount.into(),
+            asset,
+            fee_asset,
+            destination_chain_address: proto.destination_chain_address,
+        })
+    }
+
+    /// Convert from a reference to a raw, unchecked protobuf [`raw::BridgeLockAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `to` field is not set
+    /// - if the `to` field is invalid
+    /// - if the `asset` field is invalid
+    /// - if the `fee_asset` field is invalid
+    fn try_from_raw_ref(proto: &raw::BridgeLock) -> Result<Self, BridgeLockError> {
+        Self::try_from_raw(proto.clone())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct BridgeLockError(BridgeLockErrorKind);
+
+impl BridgeLockError {
+    #[must_use]
+    fn field_not_set(field: &'static str) -> Self {
+        Self(BridgeLockErrorKind::FieldNotSet(field))
+    }
+
+    #[must_use]
+    fn address(source: AddressError) -> Self {
+        Self(BridgeLockErrorKind::Address {
+            source,
+        })
+    }
+
+    #[must_use]
+    fn missing_amount() -> Self {
+        Self(BridgeLockErrorKind::MissingAmount)
+    }
+
+    #[must_use]
+    fn invalid_asset(err: asset::ParseDenomError) -> Self {
+        Self(BridgeLockErrorKind::InvalidAsset(err))
+    }
+
+    #[must_use]
+    fn invalid_fee_asset(err: asset::ParseDenomError) -> Self {
+        Self(BridgeLockErrorKind::InvalidFeeAsset(err))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum BridgeLockErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("the `to` field was invalid")]
+    Address { source: AddressError },
+    #[error("the `amount` field was not set")]
+    MissingAmount,
+    #[error("the `asset` field was invalid")]
+    InvalidAsset(#[source] asset::ParseDenomError),
+    #[error("the `fee_asset` field was invalid")]
+    InvalidFeeAsset(#[source] asset::ParseDenomError),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BridgeUnlock {
     pub to: Address,
     pub amount: u128,
@@ -2244,6 +2989,16 @@ pub enum FeeChange {
     RecoverIbcClient(FeeComponents<RecoverIbcClient>),
     CurrencyPairsChange(FeeComponents<CurrencyPairsChange>),
     MarketsChange(FeeComponents<MarketsChange>),
+    RegisterMultisigAccount(FeeComponents<RegisterMultisigAccount>),
+    ScheduledTransfer(FeeComponents<ScheduledTransfer>),
+    Ics20RateLimitChange(FeeComponents<Ics20RateLimitChange>),
+    VoteExtensionParticipationChange(FeeComponents<VoteExtensionParticipationChange>),
+    Bond(FeeComponents<Bond>),
+    Unbond(FeeComponents<Unbond>),
+    ProposalSubmission(FeeComponents<ProposalSubmission>),
+    ProposalVote(FeeComponents<ProposalVote>),
+    StakingParametersChange(FeeComponents<StakingParametersChange>),
+    GovernanceParametersChange(FeeComponents<GovernanceParametersChange>),
 }
 
 impl Protobuf for FeeChange {
@@ -2308,6 +3063,36 @@ impl Protobuf for FeeChange {
                 Self::MarketsChange(fee_change) => {
                     raw::fee_change::FeeComponents::MarketsChange(fee_change.to_raw())
                 }
+                Self::RegisterMultisigAccount(fee_change) => {
+                    raw::fee_change::FeeComponents::RegisterMultisigAccount(fee_change.to_raw())
+                }
+                Self::ScheduledTransfer(fee_change) => {
+                    raw::fee_change::FeeComponents::ScheduledTransfer(fee_change.to_raw())
+                }
+                Self::Ics20RateLimitChange(fee_change) => {
+                    raw::fee_change::FeeComponents::Ics20RateLimitChange(fee_change.to_raw())
+                }
+                Self::VoteExtensionParticipationChange(fee_change) => {
+                    raw::fee_change::FeeComponents::VoteExtensionParticipationChange(
+                        fee_change.to_raw(),
+                    )
+                }
+                Self::Bond(fee_change) => raw::fee_change::FeeComponents::Bond(fee_change.to_raw()),
+                Self::Unbond(fee_change) => {
+                    raw::fee_change::FeeComponents::Unbond(fee_change.to_raw())
+                }
+                Self::ProposalSubmission(fee_change) => {
+                    raw::fee_change::FeeComponents::ProposalSubmission(fee_change.to_raw())
+                }
+                Self::ProposalVote(fee_change) => {
+                    raw::fee_change::FeeComponents::ProposalVote(fee_change.to_raw())
+                }
+                Self::StakingParametersChange(fee_change) => {
+                    raw::fee_change::FeeComponents::StakingParametersChange(fee_change.to_raw())
+                }
+                Self::GovernanceParametersChange(fee_change) => {
+                    raw::fee_change::FeeComponents::GovernanceParametersChange(fee_change.to_raw())
+                }
             }),
         }
     }
@@ -2396,185 +3181,730 @@ impl Protobuf for FeeChange {
             Some(raw::fee_change::FeeComponents::MarketsChange(fee_change)) => Self::MarketsChange(
                 FeeComponents::<MarketsChange>::try_from_raw_ref(fee_change)?,
             ),
+            Some(raw::fee_change::FeeComponents::RegisterMultisigAccount(fee_change)) => {
+                Self::RegisterMultisigAccount(
+                    FeeComponents::<RegisterMultisigAccount>::try_from_raw_ref(fee_change)?,
+                )
+            }
+            Some(raw::fee_change::FeeComponents::ScheduledTransfer(fee_change)) => {
+                Self::ScheduledTransfer(FeeComponents::<ScheduledTransfer>::try_from_raw_ref(
+                    fee_change,
+                )?)
+            }
+            Some(raw::fee_change::FeeComponents::Ics20RateLimitChange(fee_change)) => {
+                Self::Ics20RateLimitChange(FeeComponents::<Ics20RateLimitChange>::try_from_raw_ref(
+                    fee_change,
+                )?)
+            }
+            Some(raw::fee_change::FeeComponents::VoteExtensionParticipationChange(fee_change)) => {
+                Self::VoteExtensionParticipationChange(FeeComponents::<
+                    VoteExtensionParticipationChange,
+                >::try_from_raw_ref(
+                    fee_change
+                )?)
+            }
+            Some(raw::fee_change::FeeComponents::Bond(fee_change)) => {
+                Self::Bond(FeeComponents::<Bond>::try_from_raw_ref(fee_change)?)
+            }
+            Some(raw::fee_change::FeeComponents::Unbond(fee_change)) => {
+                Self::Unbond(FeeComponents::<Unbond>::try_from_raw_ref(fee_change)?)
+            }
+            Some(raw::fee_change::FeeComponents::ProposalSubmission(fee_change)) => {
+                Self::ProposalSubmission(FeeComponents::<ProposalSubmission>::try_from_raw_ref(
+                    fee_change,
+                )?)
+            }
+            Some(raw::fee_change::FeeComponents::ProposalVote(fee_change)) => {
+                Self::ProposalVote(FeeComponents::<ProposalVote>::try_from_raw_ref(fee_change)?)
+            }
+            Some(raw::fee_change::FeeComponents::StakingParametersChange(fee_change)) => {
+                Self::StakingParametersChange(
+                    FeeComponents::<StakingParametersChange>::try_from_raw_ref(fee_change)?,
+                )
+            }
+            Some(raw::fee_change::FeeComponents::GovernanceParametersChange(fee_change)) => {
+                Self::GovernanceParametersChange(
+                    FeeComponents::<GovernanceParametersChange>::try_from_raw_ref(fee_change)?,
+                )
+            }
             None => return Err(FeeChangeError::field_unset("fee_components")),
         })
     }
-}
+}
+
+impl From<FeeComponents<Transfer>> for FeeChange {
+    fn from(fee: FeeComponents<Transfer>) -> Self {
+        FeeChange::Transfer(fee)
+    }
+}
+
+impl From<FeeComponents<RollupDataSubmission>> for FeeChange {
+    fn from(fee: FeeComponents<RollupDataSubmission>) -> Self {
+        FeeChange::RollupDataSubmission(fee)
+    }
+}
+
+impl From<FeeComponents<Ics20Withdrawal>> for FeeChange {
+    fn from(fee: FeeComponents<Ics20Withdrawal>) -> Self {
+        FeeChange::Ics20Withdrawal(fee)
+    }
+}
+
+impl From<FeeComponents<InitBridgeAccount>> for FeeChange {
+    fn from(fee: FeeComponents<InitBridgeAccount>) -> Self {
+        FeeChange::InitBridgeAccount(fee)
+    }
+}
+
+impl From<FeeComponents<BridgeLock>> for FeeChange {
+    fn from(fee: FeeComponents<BridgeLock>) -> Self {
+        FeeChange::BridgeLock(fee)
+    }
+}
+
+impl From<FeeComponents<BridgeUnlock>> for FeeChange {
+    fn from(fee: FeeComponents<BridgeUnlock>) -> Self {
+        FeeChange::BridgeUnlock(fee)
+    }
+}
+
+impl From<FeeComponents<BridgeSudoChange>> for FeeChange {
+    fn from(fee: FeeComponents<BridgeSudoChange>) -> Self {
+        FeeChange::BridgeSudoChange(fee)
+    }
+}
+
+impl From<FeeComponents<IbcRelay>> for FeeChange {
+    fn from(fee: FeeComponents<IbcRelay>) -> Self {
+        FeeChange::IbcRelay(fee)
+    }
+}
+
+impl From<FeeComponents<ValidatorUpdate>> for FeeChange {
+    fn from(fee: FeeComponents<ValidatorUpdate>) -> Self {
+        FeeChange::ValidatorUpdate(fee)
+    }
+}
+
+impl From<FeeComponents<FeeAssetChange>> for FeeChange {
+    fn from(fee: FeeComponents<FeeAssetChange>) -> Self {
+        FeeChange::FeeAssetChange(fee)
+    }
+}
+
+impl From<FeeComponents<FeeChange>> for FeeChange {
+    fn from(fee: FeeComponents<FeeChange>) -> Self {
+        FeeChange::FeeChange(fee)
+    }
+}
+
+impl From<FeeComponents<IbcRelayerChange>> for FeeChange {
+    fn from(fee: FeeComponents<IbcRelayerChange>) -> Self {
+        FeeChange::IbcRelayerChange(fee)
+    }
+}
+
+impl From<FeeComponents<SudoAddressChange>> for FeeChange {
+    fn from(fee: FeeComponents<SudoAddressChange>) -> Self {
+        FeeChange::SudoAddressChange(fee)
+    }
+}
+
+impl From<FeeComponents<IbcSudoChange>> for FeeChange {
+    fn from(fee: FeeComponents<IbcSudoChange>) -> Self {
+        FeeChange::IbcSudoChange(fee)
+    }
+}
+
+impl From<FeeComponents<BridgeTransfer>> for FeeChange {
+    fn from(fee: FeeComponents<BridgeTransfer>) -> Self {
+        FeeChange::BridgeTransfer(fee)
+    }
+}
+
+impl From<FeeComponents<RecoverIbcClient>> for FeeChange {
+    fn from(fee: FeeComponents<RecoverIbcClient>) -> Self {
+        FeeChange::RecoverIbcClient(fee)
+    }
+}
+
+impl From<FeeComponents<CurrencyPairsChange>> for FeeChange {
+    fn from(fee: FeeComponents<CurrencyPairsChange>) -> Self {
+        FeeChange::CurrencyPairsChange(fee)
+    }
+}
+
+impl From<FeeComponents<MarketsChange>> for FeeChange {
+    fn from(fee: FeeComponents<MarketsChange>) -> Self {
+        FeeChange::MarketsChange(fee)
+    }
+}
+
+impl From<FeeComponents<RegisterMultisigAccount>> for FeeChange {
+    fn from(fee: FeeComponents<RegisterMultisigAccount>) -> Self {
+        FeeChange::RegisterMultisigAccount(fee)
+    }
+}
+
+impl From<FeeComponents<ScheduledTransfer>> for FeeChange {
+    fn from(fee: FeeComponents<ScheduledTransfer>) -> Self {
+        FeeChange::ScheduledTransfer(fee)
+    }
+}
+
+impl From<FeeComponents<Ics20RateLimitChange>> for FeeChange {
+    fn from(fee: FeeComponents<Ics20RateLimitChange>) -> Self {
+        FeeChange::Ics20RateLimitChange(fee)
+    }
+}
+
+impl From<FeeComponents<VoteExtensionParticipationChange>> for FeeChange {
+    fn from(fee: FeeComponents<VoteExtensionParticipationChange>) -> Self {
+        FeeChange::VoteExtensionParticipationChange(fee)
+    }
+}
+
+impl From<FeeComponents<Bond>> for FeeChange {
+    fn from(fee: FeeComponents<Bond>) -> Self {
+        FeeChange::Bond(fee)
+    }
+}
+
+impl From<FeeComponents<Unbond>> for FeeChange {
+    fn from(fee: FeeComponents<Unbond>) -> Self {
+        FeeChange::Unbond(fee)
+    }
+}
+
+impl From<FeeComponents<ProposalSubmission>> for FeeChange {
+    fn from(fee: FeeComponents<ProposalSubmission>) -> Self {
+        FeeChange::ProposalSubmission(fee)
+    }
+}
+
+impl From<FeeComponents<ProposalVote>> for FeeChange {
+    fn from(fee: FeeComponents<ProposalVote>) -> Self {
+        FeeChange::ProposalVote(fee)
+    }
+}
+
+impl From<FeeComponents<StakingParametersChange>> for FeeChange {
+    fn from(fee: FeeComponents<StakingParametersChange>) -> Self {
+        FeeChange::StakingParametersChange(fee)
+    }
+}
+
+impl From<FeeComponents<GovernanceParametersChange>> for FeeChange {
+    fn from(fee: FeeComponents<GovernanceParametersChange>) -> Self {
+        FeeChange::GovernanceParametersChange(fee)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RecoverIbcClient {
+    pub client_id: ibc_types::core::client::ClientId,
+    pub replacement_client_id: ibc_types::core::client::ClientId,
+}
+
+impl Protobuf for RecoverIbcClient {
+    type Error = RecoverIbcClientError;
+    type Raw = raw::RecoverIbcClient;
+
+    #[must_use]
+    fn into_raw(self) -> raw::RecoverIbcClient {
+        raw::RecoverIbcClient {
+            client_id: self.client_id.to_string(),
+            replacement_client_id: self.replacement_client_id.to_string(),
+        }
+    }
+
+    #[must_use]
+    fn to_raw(&self) -> raw::RecoverIbcClient {
+        raw::RecoverIbcClient {
+            client_id: self.client_id.clone().to_string(),
+            replacement_client_id: self.replacement_client_id.clone().to_string(),
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::RecoverIbcClientAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `client_id` field is not set
+    /// - if the `replacement_client_id` field is not set
+    fn try_from_raw(proto: raw::RecoverIbcClient) -> Result<Self, RecoverIbcClientError> {
+        let client_id = proto.client_id.parse().map_err(|_| {
+            RecoverIbcClientError(RecoverIbcClientErrorKind::InvalidSubjectClientId)
+        })?;
+        let replacement_client_id = proto.replacement_client_id.parse().map_err(|_| {
+            RecoverIbcClientError(RecoverIbcClientErrorKind::InvalidSubstituteClientId)
+        })?;
+        Ok(Self {
+            client_id,
+            replacement_client_id,
+        })
+    }
+
+    /// Convert from a reference to a raw, unchecked protobuf [`raw::RecoverIbcClientAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `client_id` field is not set
+    /// - if the `replacement_client_id` field is not set
+    fn try_from_raw_ref(proto: &Self::Raw) -> Result<Self, RecoverIbcClientError> {
+        Self::try_from_raw(proto.clone())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct RecoverIbcClientError(RecoverIbcClientErrorKind);
+
+#[derive(Debug, thiserror::Error)]
+enum RecoverIbcClientErrorKind {
+    #[error("the `client_id` field was invalid")]
+    InvalidSubjectClientId,
+    #[error("the `replacement_client_id` field was invalid")]
+    InvalidSubstituteClientId,
+}
+
+/// The maximum amounts of an asset that can be transferred over an IBC channel within any window of
+/// `window_blocks` consecutive sequencer blocks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ics20RateLimit {
+    /// The maximum amount received over the channel per window.
+    pub max_inflow: u128,
+    /// The maximum amount sent over the channel per window.
+    pub max_outflow: u128,
+    /// The length of a window in sequencer blocks. Always non-zero.
+    pub window_blocks: u64,
+}
+
+impl Protobuf for Ics20RateLimit {
+    type Error = Ics20RateLimitChangeError;
+    type Raw = raw::Ics20RateLimit;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::Ics20RateLimit {
+        let Self {
+            max_inflow,
+            max_outflow,
+            window_blocks,
+        } = *self;
+        raw::Ics20RateLimit {
+            max_inflow: Some(max_inflow.into()),
+            max_outflow: Some(max_outflow.into()),
+            window_blocks,
+        }
+    }
+
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    /// Returns `Ics20RateLimitChangeError` if `max_inflow` or `max_outflow` are not set, or if
+    /// `window_blocks` is zero.
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::Ics20RateLimit {
+            max_inflow,
+            max_outflow,
+            window_blocks,
+        } = *raw;
+        let max_inflow = max_inflow.ok_or(Ics20RateLimitChangeError::field_not_set(
+            "rate_limit.max_inflow",
+        ))?;
+        let max_outflow = max_outflow.ok_or(Ics20RateLimitChangeError::field_not_set(
+            "rate_limit.max_outflow",
+        ))?;
+        if window_blocks == 0 {
+            return Err(Ics20RateLimitChangeError::zero_window_blocks());
+        }
+        Ok(Self {
+            max_inflow: max_inflow.into(),
+            max_outflow: max_outflow.into(),
+            window_blocks,
+        })
+    }
+}
+
+/// Sets or removes the rate limit on ICS20 transfers of `asset` over `channel`.
+///
+/// Can only be submitted by the IBC sudo address.
+#[derive(Clone, Debug)]
+pub struct Ics20RateLimitChange {
+    /// The channel on this chain the rate limit applies to.
+    pub channel: ChannelId,
+    /// The asset the rate limit applies to.
+    pub asset: asset::Denom,
+    /// The new rate limit, or `None` to remove the existing rate limit.
+    pub rate_limit: Option<Ics20RateLimit>,
+}
+
+impl Protobuf for Ics20RateLimitChange {
+    type Error = Ics20RateLimitChangeError;
+    type Raw = raw::Ics20RateLimitChange;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::Ics20RateLimitChange {
+        let Self {
+            channel,
+            asset,
+            rate_limit,
+        } = self;
+        raw::Ics20RateLimitChange {
+            channel: channel.to_string(),
+            asset: asset.to_string(),
+            rate_limit: rate_limit.as_ref().map(Ics20RateLimit::to_raw),
+        }
+    }
+
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    /// Returns `Ics20RateLimitChangeError` if the raw action's `channel` or `asset` are invalid,
+    /// or if its `rate_limit` is invalid.
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::Ics20RateLimitChange {
+            channel,
+            asset,
+            rate_limit,
+        } = raw;
+        let channel = channel
+            .parse()
+            .map_err(Ics20RateLimitChangeError::invalid_channel)?;
+        let asset = asset.parse().map_err(Ics20RateLimitChangeError::asset)?;
+        let rate_limit = rate_limit
+            .as_ref()
+            .map(Ics20RateLimit::try_from_raw_ref)
+            .transpose()?;
+        Ok(Self {
+            channel,
+            asset,
+            rate_limit,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct Ics20RateLimitChangeError(Ics20RateLimitChangeErrorKind);
+
+impl Ics20RateLimitChangeError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(Ics20RateLimitChangeErrorKind::FieldNotSet(field))
+    }
+
+    fn invalid_channel(inner: IdentifierError) -> Self {
+        Self(Ics20RateLimitChangeErrorKind::InvalidChannel(inner))
+    }
+
+    fn asset(inner: asset::ParseDenomError) -> Self {
+        Self(Ics20RateLimitChangeErrorKind::Asset(inner))
+    }
 
-impl From<FeeComponents<Transfer>> for FeeChange {
-    fn from(fee: FeeComponents<Transfer>) -> Self {
-        FeeChange::Transfer(fee)
+    fn zero_window_blocks() -> Self {
+        Self(Ics20RateLimitChangeErrorKind::ZeroWindowBlocks)
     }
 }
 
-impl From<FeeComponents<RollupDataSubmission>> for FeeChange {
-    fn from(fee: FeeComponents<RollupDataSubmission>) -> Self {
-        FeeChange::RollupDataSubmission(fee)
-    }
+#[derive(Debug, thiserror::Error)]
+enum Ics20RateLimitChangeErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("`channel` field was invalid")]
+    InvalidChannel(#[source] IdentifierError),
+    #[error("`asset` field did not contain a valid asset ID")]
+    Asset(#[source] asset::ParseDenomError),
+    #[error("`rate_limit.window_blocks` field must be non-zero")]
+    ZeroWindowBlocks,
 }
 
-impl From<FeeComponents<Ics20Withdrawal>> for FeeChange {
-    fn from(fee: FeeComponents<Ics20Withdrawal>) -> Self {
-        FeeChange::Ics20Withdrawal(fee)
-    }
+/// The required participation of validators in price vote extensions.
+///
+/// A validator that missed more than `max_missed_blocks` vote extensions within the last
+/// `window_blocks` blocks has its power reduced by `power_reduction_percent` percent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VoteExtensionParticipation {
+    /// The number of most recent blocks over which participation is tracked. Always non-zero and
+    /// at most [`VoteExtensionParticipation::MAX_WINDOW_BLOCKS`].
+    pub window_blocks: u64,
+    /// The maximum number of vote extensions a validator may miss within the window. Always less
+    /// than `window_blocks`.
+    pub max_missed_blocks: u64,
+    /// The percentage by which the power of an absent validator is reduced. Always between 1 and
+    /// 100, with 100 removing the validator from the validator set.
+    pub power_reduction_percent: u32,
 }
 
-impl From<FeeComponents<InitBridgeAccount>> for FeeChange {
-    fn from(fee: FeeComponents<InitBridgeAccount>) -> Self {
-        FeeChange::InitBridgeAccount(fee)
-    }
+impl VoteExtensionParticipation {
+    /// The maximum number of blocks in a participation window.
+    ///
+    /// The sequencer keeps one bit per block in the window for every validator.
+    pub const MAX_WINDOW_BLOCKS: u64 = 1 << 16;
 }
 
-impl From<FeeComponents<BridgeLock>> for FeeChange {
-    fn from(fee: FeeComponents<BridgeLock>) -> Self {
-        FeeChange::BridgeLock(fee)
+impl Protobuf for VoteExtensionParticipation {
+    type Error = VoteExtensionParticipationChangeError;
+    type Raw = raw::VoteExtensionParticipation;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::VoteExtensionParticipation {
+        let Self {
+            window_blocks,
+            max_missed_blocks,
+            power_reduction_percent,
+        } = *self;
+        raw::VoteExtensionParticipation {
+            window_blocks,
+            max_missed_blocks,
+            power_reduction_percent,
+        }
     }
-}
 
-impl From<FeeComponents<BridgeUnlock>> for FeeChange {
-    fn from(fee: FeeComponents<BridgeUnlock>) -> Self {
-        FeeChange::BridgeUnlock(fee)
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    /// Returns `VoteExtensionParticipationChangeError` if `window_blocks` is zero or exceeds
+    /// [`VoteExtensionParticipation::MAX_WINDOW_BLOCKS`], if `max_missed_blocks` is not less than
+    /// `window_blocks`, or if `power_reduction_percent` is not between 1 and 100.
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::VoteExtensionParticipation {
+            window_blocks,
+            max_missed_blocks,
+            power_reduction_percent,
+        } = *raw;
+        if window_blocks == 0 {
+            return Err(VoteExtensionParticipationChangeError::zero_window_blocks());
+        }
+        if window_blocks > Self::MAX_WINDOW_BLOCKS {
+            return Err(
+                VoteExtensionParticipationChangeError::window_blocks_too_large(window_blocks),
+            );
+        }
+        if max_missed_blocks >= window_blocks {
+            return Err(
+                VoteExtensionParticipationChangeError::max_missed_blocks_not_below_window(),
+            );
+        }
+        if !(1..=100).contains(&power_reduction_percent) {
+            return Err(
+                VoteExtensionParticipationChangeError::power_reduction_percent_out_of_range(
+                    power_reduction_percent,
+                ),
+            );
+        }
+        Ok(Self {
+            window_blocks,
+            max_missed_blocks,
+            power_reduction_percent,
+        })
     }
 }
 
-impl From<FeeComponents<BridgeSudoChange>> for FeeChange {
-    fn from(fee: FeeComponents<BridgeSudoChange>) -> Self {
-        FeeChange::BridgeSudoChange(fee)
-    }
+/// Sets or removes the requirement that validators provide price vote extensions.
+///
+/// Can only be submitted by the sudo address.
+#[derive(Clone, Debug)]
+pub struct VoteExtensionParticipationChange {
+    /// The new requirement, or `None` to stop tracking participation.
+    pub participation: Option<VoteExtensionParticipation>,
 }
 
-impl From<FeeComponents<IbcRelay>> for FeeChange {
-    fn from(fee: FeeComponents<IbcRelay>) -> Self {
-        FeeChange::IbcRelay(fee)
+impl Protobuf for VoteExtensionParticipationChange {
+    type Error = VoteExtensionParticipationChangeError;
+    type Raw = raw::VoteExtensionParticipationChange;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::VoteExtensionParticipationChange {
+        raw::VoteExtensionParticipationChange {
+            participation: self
+                .participation
+                .as_ref()
+                .map(VoteExtensionParticipation::to_raw),
+        }
     }
-}
 
-impl From<FeeComponents<ValidatorUpdate>> for FeeChange {
-    fn from(fee: FeeComponents<ValidatorUpdate>) -> Self {
-        FeeChange::ValidatorUpdate(fee)
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    /// Returns `VoteExtensionParticipationChangeError` if the raw action's `participation` is
+    /// invalid.
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let participation = raw
+            .participation
+            .as_ref()
+            .map(VoteExtensionParticipation::try_from_raw_ref)
+            .transpose()?;
+        Ok(Self {
+            participation,
+        })
     }
 }
 
-impl From<FeeComponents<FeeAssetChange>> for FeeChange {
-    fn from(fee: FeeComponents<FeeAssetChange>) -> Self {
-        FeeChange::FeeAssetChange(fee)
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct VoteExtensionParticipationChangeError(VoteExtensionParticipationChangeErrorKind);
+
+impl VoteExtensionParticipationChangeError {
+    fn zero_window_blocks() -> Self {
+        Self(VoteExtensionParticipationChangeErrorKind::ZeroWindowBlocks)
     }
-}
 
-impl From<FeeComponents<FeeChange>> for FeeChange {
-    fn from(fee: FeeComponents<FeeChange>) -> Self {
-        FeeChange::FeeChange(fee)
+    fn window_blocks_too_large(window_blocks: u64) -> Self {
+        Self(
+            VoteExtensionParticipationChangeErrorKind::WindowBlocksTooLarge {
+                window_blocks,
+            },
+        )
     }
-}
 
-impl From<FeeComponents<IbcRelayerChange>> for FeeChange {
-    fn from(fee: FeeComponents<IbcRelayerChange>) -> Self {
-        FeeChange::IbcRelayerChange(fee)
+    fn max_missed_blocks_not_below_window() -> Self {
+        Self(VoteExtensionParticipationChangeErrorKind::MaxMissedBlocksNotBelowWindow)
     }
-}
 
-impl From<FeeComponents<SudoAddressChange>> for FeeChange {
-    fn from(fee: FeeComponents<SudoAddressChange>) -> Self {
-        FeeChange::SudoAddressChange(fee)
+    fn power_reduction_percent_out_of_range(percent: u32) -> Self {
+        Self(
+            VoteExtensionParticipationChangeErrorKind::PowerReductionPercentOutOfRange {
+                percent,
+            },
+        )
     }
 }
 
-impl From<FeeComponents<IbcSudoChange>> for FeeChange {
-    fn from(fee: FeeComponents<IbcSudoChange>) -> Self {
-        FeeChange::IbcSudoChange(fee)
-    }
+#[derive(Debug, thiserror::Error)]
+enum VoteExtensionParticipationChangeErrorKind {
+    #[error("`participation.window_blocks` field must be non-zero")]
+    ZeroWindowBlocks,
+    #[error(
+        "`participation.window_blocks` field must be at most {}, got `{window_blocks}`",
+        VoteExtensionParticipation::MAX_WINDOW_BLOCKS
+    )]
+    WindowBlocksTooLarge { window_blocks: u64 },
+    #[error(
+        "`participation.max_missed_blocks` field must be less than `participation.window_blocks`"
+    )]
+    MaxMissedBlocksNotBelowWindow,
+    #[error(
+        "`participation.power_reduction_percent` field must be between 1 and 100, got `{percent}`"
+    )]
+    PowerReductionPercentOutOfRange { percent: u32 },
 }
 
-impl From<FeeComponents<BridgeTransfer>> for FeeChange {
-    fn from(fee: FeeComponents<BridgeTransfer>) -> Self {
-        FeeChange::BridgeTransfer(fee)
-    }
+/// Replaces the staking parameters, enabling staking if it was not enabled.
+///
+/// Can only be submitted by the sudo address.
+#[derive(Clone, Debug)]
+pub struct StakingParametersChange {
+    pub parameters: StakingParameters,
 }
 
-impl From<FeeComponents<RecoverIbcClient>> for FeeChange {
-    fn from(fee: FeeComponents<RecoverIbcClient>) -> Self {
-        FeeChange::RecoverIbcClient(fee)
+impl Protobuf for StakingParametersChange {
+    type Error = StakingParametersChangeError;
+    type Raw = raw::StakingParametersChange;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::StakingParametersChange {
+        raw::StakingParametersChange {
+            parameters: Some(self.parameters.to_raw()),
+        }
     }
-}
 
-impl From<FeeComponents<CurrencyPairsChange>> for FeeChange {
-    fn from(fee: FeeComponents<CurrencyPairsChange>) -> Self {
-        FeeChange::CurrencyPairsChange(fee)
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    /// Returns `StakingParametersChangeError` if the raw action's `parameters` are not set or
+    /// invalid.
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let parameters = raw
+            .parameters
+            .as_ref()
+            .ok_or_else(|| StakingParametersChangeError::field_not_set("parameters"))?;
+        let parameters = StakingParameters::try_from_raw_ref(parameters)
+            .map_err(StakingParametersChangeError::parameters)?;
+        Ok(Self {
+            parameters,
+        })
     }
 }
 
-impl From<FeeComponents<MarketsChange>> for FeeChange {
-    fn from(fee: FeeComponents<MarketsChange>) -> Self {
-        FeeChange::MarketsChange(fee)
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct StakingParametersChangeError(StakingParametersChangeErrorKind);
+
+impl StakingParametersChangeError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(StakingParametersChangeErrorKind::FieldNotSet(field))
+    }
+
+    fn parameters(inner: StakingParametersError) -> Self {
+        Self(StakingParametersChangeErrorKind::Parameters(inner))
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct RecoverIbcClient {
-    pub client_id: ibc_types::core::client::ClientId,
-    pub replacement_client_id: ibc_types::core::client::ClientId,
+#[derive(Debug, thiserror::Error)]
+enum StakingParametersChangeErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("`parameters` field was invalid")]
+    Parameters(#[source] StakingParametersError),
 }
 
-impl Protobuf for RecoverIbcClient {
-    type Error = RecoverIbcClientError;
-    type Raw = raw::RecoverIbcClient;
+/// Replaces the governance parameters, enabling governance if it was not enabled.
+///
+/// Can only be submitted by the sudo address.
+#[derive(Clone, Debug)]
+pub struct GovernanceParametersChange {
+    pub parameters: GovernanceParameters,
+}
 
-    #[must_use]
-    fn into_raw(self) -> raw::RecoverIbcClient {
-        raw::RecoverIbcClient {
-            client_id: self.client_id.to_string(),
-            replacement_client_id: self.replacement_client_id.to_string(),
-        }
-    }
+impl Protobuf for GovernanceParametersChange {
+    type Error = GovernanceParametersChangeError;
+    type Raw = raw::GovernanceParametersChange;
 
     #[must_use]
-    fn to_raw(&self) -> raw::RecoverIbcClient {
-        raw::RecoverIbcClient {
-            client_id: self.client_id.clone().to_string(),
-            replacement_client_id: self.replacement_client_id.clone().to_string(),
+    fn to_raw(&self) -> raw::GovernanceParametersChange {
+        raw::GovernanceParametersChange {
+            parameters: Some(self.parameters.to_raw()),
         }
     }
 
-    /// Convert from a raw, unchecked protobuf [`raw::RecoverIbcClientAction`].
+    /// Convert from a reference to the raw protobuf type.
     ///
     /// # Errors
-    ///
-    /// - if the `client_id` field is not set
-    /// - if the `replacement_client_id` field is not set
-    fn try_from_raw(proto: raw::RecoverIbcClient) -> Result<Self, RecoverIbcClientError> {
-        let client_id = proto.client_id.parse().map_err(|_| {
-            RecoverIbcClientError(RecoverIbcClientErrorKind::InvalidSubjectClientId)
-        })?;
-        let replacement_client_id = proto.replacement_client_id.parse().map_err(|_| {
-            RecoverIbcClientError(RecoverIbcClientErrorKind::InvalidSubstituteClientId)
-        })?;
+    /// Returns `GovernanceParametersChangeError` if the raw action's `parameters` are not set or
+    /// invalid.
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let parameters = raw
+            .parameters
+            .as_ref()
+            .ok_or_else(|| GovernanceParametersChangeError::field_not_set("parameters"))?;
+        let parameters = GovernanceParameters::try_from_raw_ref(parameters)
+            .map_err(GovernanceParametersChangeError::parameters)?;
         Ok(Self {
-            client_id,
-            replacement_client_id,
+            parameters,
         })
     }
-
-    /// Convert from a reference to a raw, unchecked protobuf [`raw::RecoverIbcClientAction`].
-    ///
-    /// # Errors
-    ///
-    /// - if the `client_id` field is not set
-    /// - if the `replacement_client_id` field is not set
-    fn try_from_raw_ref(proto: &Self::Raw) -> Result<Self, RecoverIbcClientError> {
-        Self::try_from_raw(proto.clone())
-    }
 }
 
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
-pub struct RecoverIbcClientError(RecoverIbcClientErrorKind);
+pub struct GovernanceParametersChangeError(GovernanceParametersChangeErrorKind);
+
+impl GovernanceParametersChangeError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(GovernanceParametersChangeErrorKind::FieldNotSet(field))
+    }
+
+    fn parameters(inner: GovernanceParametersError) -> Self {
+        Self(GovernanceParametersChangeErrorKind::Parameters(inner))
+    }
+}
 
 #[derive(Debug, thiserror::Error)]
-enum RecoverIbcClientErrorKind {
-    #[error("the `client_id` field was invalid")]
-    InvalidSubjectClientId,
-    #[error("the `replacement_client_id` field was invalid")]
-    InvalidSubstituteClientId,
+enum GovernanceParametersChangeErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("`parameters` field was invalid")]
+    Parameters(#[source] GovernanceParametersError),
 }
 
 #[derive(Debug, Clone)]
@@ -2777,3 +4107,153 @@ pub enum MarketsChangeErrorKind {
     #[error("change market action contained no markets to change")]
     MissingMarkets,
 }
+
+/// Turns the account of the transaction signer into a multisig account.
+///
+/// Transactions sent on behalf of a multisig account must be signed by at least `threshold`
+/// distinct keys of its `verification_keys`. If the account already is a multisig account, its
+/// key set is replaced.
+#[derive(Debug, Clone)]
+pub struct RegisterMultisigAccount {
+    pub verification_keys: Vec<VerificationKey>,
+    pub threshold: u32,
+    pub fee_asset: asset::Denom,
+}
+
+impl Protobuf for RegisterMultisigAccount {
+    type Error = RegisterMultisigAccountError;
+    type Raw = raw::RegisterMultisigAccount;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::RegisterMultisigAccount {
+        let Self {
+            verification_keys,
+            threshold,
+            fee_asset,
+        } = self;
+        raw::RegisterMultisigAccount {
+            verification_keys: verification_keys
+                .iter()
+                .map(|key| Bytes::copy_from_slice(&key.to_bytes()))
+                .collect(),
+            threshold: *threshold,
+            fee_asset: fee_asset.to_string(),
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::RegisterMultisigAccount`].
+    ///
+    /// # Errors
+    ///
+    /// - if any of the `verification_keys` is invalid
+    /// - if the `verification_keys` contain duplicates
+    /// - if there are more than [`MAX_MULTISIG_VERIFICATION_KEYS`] verification keys
+    /// - if the `threshold` is zero or exceeds the number of verification keys
+    /// - if the `fee_asset` field is invalid
+    fn try_from_raw(
+        proto: raw::RegisterMultisigAccount,
+    ) -> Result<Self, RegisterMultisigAccountError> {
+        let raw::RegisterMultisigAccount {
+            verification_keys,
+            threshold,
+            fee_asset,
+        } = proto;
+        if verification_keys.len() > MAX_MULTISIG_VERIFICATION_KEYS {
+            return Err(RegisterMultisigAccountError::too_many_verification_keys(
+                verification_keys.len(),
+            ));
+        }
+        let verification_keys = verification_keys
+            .iter()
+            .map(|key| VerificationKey::try_from(&**key))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(RegisterMultisigAccountError::invalid_verification_key)?;
+        let distinct_keys: std::collections::BTreeSet<_> = verification_keys.iter().collect();
+        if distinct_keys.len() != verification_keys.len() {
+            return Err(RegisterMultisigAccountError::duplicate_verification_key());
+        }
+        let number_of_keys = u32::try_from(verification_keys.len())
+            .expect("number of keys is bounded by `MAX_MULTISIG_VERIFICATION_KEYS`");
+        if threshold == 0 || threshold > number_of_keys {
+            return Err(RegisterMultisigAccountError::invalid_threshold(
+                threshold,
+                number_of_keys,
+            ));
+        }
+        let fee_asset = fee_asset
+            .parse()
+            .map_err(RegisterMultisigAccountError::invalid_fee_asset)?;
+        Ok(Self {
+            verification_keys,
+            threshold,
+            fee_asset,
+        })
+    }
+
+    /// Convert from a reference to a raw, unchecked protobuf [`raw::RegisterMultisigAccount`].
+    ///
+    /// # Errors
+    ///
+    /// See [`RegisterMultisigAccount::try_from_raw`].
+    fn try_from_raw_ref(proto: &Self::Raw) -> Result<Self, RegisterMultisigAccountError> {
+        Self::try_from_raw(proto.clone())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct RegisterMultisigAccountError(RegisterMultisigAccountErrorKind);
+
+impl RegisterMultisigAccountError {
+    #[must_use]
+    fn invalid_verification_key(err: crypto::Error) -> Self {
+        Self(RegisterMultisigAccountErrorKind::InvalidVerificationKey(
+            err,
+        ))
+    }
+
+    #[must_use]
+    fn duplicate_verification_key() -> Self {
+        Self(RegisterMultisigAccountErrorKind::DuplicateVerificationKey)
+    }
+
+    #[must_use]
+    fn too_many_verification_keys(count: usize) -> Self {
+        Self(RegisterMultisigAccountErrorKind::TooManyVerificationKeys {
+            count,
+        })
+    }
+
+    #[must_use]
+    fn invalid_threshold(threshold: u32, number_of_keys: u32) -> Self {
+        Self(RegisterMultisigAccountErrorKind::InvalidThreshold {
+            threshold,
+            number_of_keys,
+        })
+    }
+
+    #[must_use]
+    fn invalid_fee_asset(err: asset::ParseDenomError) -> Self {
+        Self(RegisterMultisigAccountErrorKind::InvalidFeeAsset(err))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum RegisterMultisigAccountErrorKind {
+    #[error("a verification key in the `verification_keys` field was invalid")]
+    InvalidVerificationKey(#[source] crypto::Error),
+    #[error("the `verification_keys` field contained duplicate keys")]
+    DuplicateVerificationKey,
+    #[error(
+        "the `verification_keys` field contained {count} keys, but at most \
+         {MAX_MULTISIG_VERIFICATION_KEYS} are allowed"
+    )]
+    TooManyVerificationKeys { count: usize },
+    #[error(
+        "the `threshold` field must be between 1 and the number of verification keys \
+         ({number_of_keys}), but was {threshold}"
+    )]
+    InvalidThreshold { threshold: u32, number_of_keys: u32 },
+    #[error("the `fee_asset` field was invalid")]
+    InvalidFeeAsset(#[source] asset::ParseDenomError),
+}
@@ -13,6 +13,8 @@ use crate::{
     },
     generated::astria::protocol::transaction::v1 as raw,
     primitive::v1::{
+        Address,
+        AddressError,
         TransactionId,
         ADDRESS_LEN,
     },
@@ -53,6 +55,37 @@ impl TransactionError {
     fn unset_body() -> Self {
         Self(TransactionErrorKind::UnsetBody)
     }
+
+    fn cosignature(index: usize, inner: TransactionSignatureError) -> Self {
+        Self(TransactionErrorKind::Cosignature {
+            index,
+            source: inner,
+        })
+    }
+
+    fn missing_multisig_address() -> Self {
+        Self(TransactionErrorKind::MissingMultisigAddress)
+    }
+
+    fn no_signatures() -> Self {
+        Self(TransactionErrorKind::NoSignatures)
+    }
+
+    fn fee_payer_signature(inner: TransactionSignatureError) -> Self {
+        Self(TransactionErrorKind::FeePayerSignature(inner))
+    }
+
+    fn missing_fee_payer_signature() -> Self {
+        Self(TransactionErrorKind::MissingFeePayerSignature)
+    }
+
+    fn unexpected_fee_payer_signature() -> Self {
+        Self(TransactionErrorKind::UnexpectedFeePayerSignature)
+    }
+
+    fn fee_payer_mismatch() -> Self {
+        Self(TransactionErrorKind::FeePayerMismatch)
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -67,16 +100,40 @@ enum TransactionErrorKind {
     VerificationKey(#[source] crypto::Error),
     #[error("transaction could not be verified given the signature and verification key")]
     Verification(crypto::Error),
+    #[error("cosignature at index {index} of the `cosignatures` field is invalid")]
+    Cosignature {
+        index: usize,
+        source: TransactionSignatureError,
+    },
+    #[error("`body` does not name the multisig account to send the transaction on behalf of")]
+    MissingMultisigAddress,
+    #[error("at least one signature is required to form a transaction")]
+    NoSignatures,
+    #[error("`fee_payer_signature` field invalid")]
+    FeePayerSignature(#[source] TransactionSignatureError),
+    #[error("`body` names a fee payer, but `fee_payer_signature` field is not set")]
+    MissingFeePayerSignature,
+    #[error("`fee_payer_signature` field is set, but `body` does not name a fee payer")]
+    UnexpectedFeePayerSignature,
+    #[error("the key of `fee_payer_signature` does not belong to the fee payer named in `body`")]
+    FeePayerMismatch,
 }
 
 /// An Astria transaction.
 ///
 /// [`Transaction`] contains an [`Body`] together
 /// with its signature and public key.
+///
+/// A transaction sent on behalf of a multisig account names that account in its body and
+/// additionally carries the signatures of further members of the account's key set.
+///
+/// If its body names a fee payer, the transaction also carries the fee payer's signature.
 #[derive(Clone, Debug)]
 pub struct Transaction {
     signature: Signature,
     verification_key: VerificationKey,
+    cosignatures: Vec<TransactionSignature>,
+    fee_payer_signature: Option<TransactionSignature>,
     body: TransactionBody,
     body_bytes: bytes::Bytes,
 }
@@ -86,29 +143,7 @@ impl Protobuf for Transaction {
     type Raw = raw::Transaction;
 
     fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
-        let Self::Raw {
-            signature,
-            public_key,
-            body,
-        } = raw;
-        let signature = Signature::try_from(&**signature).map_err(TransactionError::signature)?;
-        let verification_key =
-            VerificationKey::try_from(&**public_key).map_err(TransactionError::verification_key)?;
-        let Some(body) = body else {
-            return Err(TransactionError::unset_body());
-        };
-        let bytes = body.value.clone();
-        verification_key
-            .verify(&signature, &bytes)
-            .map_err(TransactionError::verification)?;
-        let transaction =
-            TransactionBody::try_from_any(body.clone()).map_err(TransactionError::body)?;
-        Ok(Self {
-            signature,
-            verification_key,
-            body: transaction,
-            body_bytes: bytes,
-        })
+        Self::try_from_raw(raw.clone())
     }
 
     fn try_from_raw(raw: Self::Raw) -> Result<Self, TransactionError> {
@@ -116,6 +151,8 @@ impl Protobuf for Transaction {
             signature,
             public_key,
             body,
+            cosignatures,
+            fee_payer_signature,
         } = raw;
         let signature = Signature::try_from(&*signature).map_err(TransactionError::signature)?;
         let verification_key =
@@ -127,10 +164,29 @@ impl Protobuf for Transaction {
         verification_key
             .verify(&signature, &bytes)
             .map_err(TransactionError::verification)?;
+        let cosignatures = cosignatures
+            .into_iter()
+            .enumerate()
+            .map(|(index, raw)| {
+                TransactionSignature::try_from_raw(raw)
+                    .and_then(|cosignature| cosignature.verify(&bytes).map(|()| cosignature))
+                    .map_err(|err| TransactionError::cosignature(index, err))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let fee_payer_signature = fee_payer_signature
+            .map(|raw| {
+                TransactionSignature::try_from_raw(raw)
+                    .and_then(|signature| signature.verify(&bytes).map(|()| signature))
+                    .map_err(TransactionError::fee_payer_signature)
+            })
+            .transpose()?;
         let transaction = TransactionBody::try_from_any(body).map_err(TransactionError::body)?;
+        check_fee_payer_signature(&transaction, fee_payer_signature.as_ref())?;
         Ok(Self {
             signature,
             verification_key,
+            cosignatures,
+            fee_payer_signature,
             body: transaction,
             body_bytes: bytes,
         })
@@ -140,6 +196,8 @@ impl Protobuf for Transaction {
         let Self {
             signature,
             verification_key,
+            cosignatures,
+            fee_payer_signature,
             body_bytes: transaction_bytes,
             ..
         } = self;
@@ -150,6 +208,13 @@ impl Protobuf for Transaction {
                 type_url: raw::TransactionBody::type_url(),
                 value: transaction_bytes,
             }),
+            cosignatures: cosignatures
+                .iter()
+                .map(TransactionSignature::to_raw)
+                .collect(),
+            fee_payer_signature: fee_payer_signature
+                .as_ref()
+                .map(TransactionSignature::to_raw),
         }
     }
 
@@ -157,6 +222,8 @@ impl Protobuf for Transaction {
         let Self {
             signature,
             verification_key,
+            cosignatures,
+            fee_payer_signature,
             body_bytes: transaction_bytes,
             ..
         } = self;
@@ -167,13 +234,27 @@ impl Protobuf for Transaction {
                 type_url: raw::TransactionBody::type_url(),
                 value: transaction_bytes.clone(),
             }),
+            cosignatures: cosignatures
+                .iter()
+                .map(TransactionSignature::to_raw)
+                .collect(),
+            fee_payer_signature: fee_payer_signature
+                .as_ref()
+                .map(TransactionSignature::to_raw),
         }
     }
 }
 
 impl Transaction {
+    /// Returns the address bytes of the account the transaction is sent on behalf of.
+    ///
+    /// This is the multisig address named in the body if set, or the address of the signer
+    /// otherwise.
     pub fn address_bytes(&self) -> &[u8; ADDRESS_LEN] {
-        self.verification_key.address_bytes()
+        self.body
+            .multisig_address
+            .as_ref()
+            .map_or_else(|| self.verification_key.address_bytes(), Address::as_bytes)
     }
 
     /// Returns the transaction ID, containing the transaction hash.
@@ -220,6 +301,54 @@ impl Transaction {
         &self.verification_key
     }
 
+    #[must_use]
+    pub fn cosignatures(&self) -> &[TransactionSignature] {
+        &self.cosignatures
+    }
+
+    #[must_use]
+    pub fn multisig_address(&self) -> Option<&Address> {
+        self.body.multisig_address()
+    }
+
+    #[must_use]
+    pub fn fee_payer_signature(&self) -> Option<&TransactionSignature> {
+        self.fee_payer_signature.as_ref()
+    }
+
+    /// Returns the address bytes of the account paying the fees of the transaction.
+    ///
+    /// This is the fee payer named in the body if set, or [`Transaction::address_bytes`]
+    /// otherwise.
+    #[must_use]
+    pub fn fee_payer_address_bytes(&self) -> &[u8; ADDRESS_LEN] {
+        self.body
+            .fee_payer
+            .as_ref()
+            .map_or_else(|| self.address_bytes(), Address::as_bytes)
+    }
+
+    /// Attaches the signature of the fee payer named in the body to the transaction.
+    ///
+    /// # Errors
+    ///
+    /// - if the body does not name a fee payer
+    /// - if the signature was not made by the fee payer's key
+    /// - if the signature does not verify against the transaction body
+    pub fn with_fee_payer_signature(
+        self,
+        fee_payer_signature: TransactionSignature,
+    ) -> Result<Self, TransactionError> {
+        fee_payer_signature
+            .verify(&self.body_bytes)
+            .map_err(TransactionError::fee_payer_signature)?;
+        check_fee_payer_signature(&self.body, Some(&fee_payer_signature))?;
+        Ok(Self {
+            fee_payer_signature: Some(fee_payer_signature),
+            ..self
+        })
+    }
+
     #[must_use]
     pub fn body(&self) -> &TransactionBody {
         &self.body
@@ -242,6 +371,33 @@ impl Transaction {
             group,
             params: self.body.params,
             verification_key: self.verification_key,
+            cosigner_keys: self
+                .cosignatures
+                .into_iter()
+                .map(|cosignature| cosignature.verification_key)
+                .collect(),
+            multisig_address: self.body.multisig_address,
+            fee_payer: self.body.fee_payer,
+        }
+    }
+}
+
+/// Ensures `fee_payer_signature` is present if and only if `body` names a fee payer, and that it
+/// was made by the fee payer's key.
+fn check_fee_payer_signature(
+    body: &TransactionBody,
+    fee_payer_signature: Option<&TransactionSignature>,
+) -> Result<(), TransactionError> {
+    match (body.fee_payer(), fee_payer_signature) {
+        (None, None) => Ok(()),
+        (Some(_), None) => Err(TransactionError::missing_fee_payer_signature()),
+        (None, Some(_)) => Err(TransactionError::unexpected_fee_payer_signature()),
+        (Some(fee_payer), Some(signature)) => {
+            if signature.verification_key.address_bytes() == fee_payer.as_bytes() {
+                Ok(())
+            } else {
+                Err(TransactionError::fee_payer_mismatch())
+            }
         }
     }
 }
@@ -264,6 +420,8 @@ impl TryFrom<raw::Transaction> for Transaction {
 pub struct TransactionBody {
     actions: Actions,
     params: TransactionParams,
+    fee_payer: Option<Address>,
+    multisig_address: Option<Address>,
 }
 
 impl Protobuf for TransactionBody {
@@ -274,6 +432,8 @@ impl Protobuf for TransactionBody {
         let raw::TransactionBody {
             actions,
             params,
+            fee_payer,
+            multisig_address,
         } = raw;
 
         let Some(params) = params else {
@@ -285,11 +445,23 @@ impl Protobuf for TransactionBody {
             .map(Action::try_from_raw_ref)
             .collect::<Result<_, _>>()
             .map_err(TransactionBodyError::action)?;
+        let fee_payer = fee_payer
+            .as_ref()
+            .map(Address::try_from_raw_ref)
+            .transpose()
+            .map_err(TransactionBodyError::fee_payer)?;
+        let multisig_address = multisig_address
+            .as_ref()
+            .map(Address::try_from_raw_ref)
+            .transpose()
+            .map_err(TransactionBodyError::multisig_address)?;
 
         TransactionBody::builder()
             .actions(actions)
             .chain_id(params.chain_id)
             .nonce(params.nonce)
+            .fee_payer(fee_payer)
+            .multisig_address(multisig_address)
             .try_build()
             .map_err(TransactionBodyError::group)
     }
@@ -298,6 +470,8 @@ impl Protobuf for TransactionBody {
         let raw::TransactionBody {
             actions,
             params,
+            fee_payer,
+            multisig_address,
         } = proto;
         let Some(params) = params else {
             return Err(TransactionBodyError::unset_params());
@@ -308,11 +482,21 @@ impl Protobuf for TransactionBody {
             .map(Action::try_from_raw)
             .collect::<Result<_, _>>()
             .map_err(TransactionBodyError::action)?;
+        let fee_payer = fee_payer
+            .map(Address::try_from_raw)
+            .transpose()
+            .map_err(TransactionBodyError::fee_payer)?;
+        let multisig_address = multisig_address
+            .map(Address::try_from_raw)
+            .transpose()
+            .map_err(TransactionBodyError::multisig_address)?;
 
         TransactionBody::builder()
             .actions(actions)
             .chain_id(params.chain_id)
             .nonce(params.nonce)
+            .fee_payer(fee_payer)
+            .multisig_address(multisig_address)
             .try_build()
             .map_err(TransactionBodyError::group)
     }
@@ -321,6 +505,8 @@ impl Protobuf for TransactionBody {
         let Self {
             actions,
             params,
+            fee_payer,
+            multisig_address,
         } = self;
         let actions = actions
             .into_actions()
@@ -330,6 +516,8 @@ impl Protobuf for TransactionBody {
         raw::TransactionBody {
             actions,
             params: Some(params.into_raw()),
+            fee_payer: fee_payer.map(Address::into_raw),
+            multisig_address: multisig_address.map(Address::into_raw),
         }
     }
 
@@ -337,12 +525,16 @@ impl Protobuf for TransactionBody {
         let Self {
             actions,
             params,
+            fee_payer,
+            multisig_address,
         } = self;
         let actions = actions.actions().iter().map(Action::to_raw).collect();
         let params = params.clone().into_raw();
         raw::TransactionBody {
             actions,
             params: Some(params),
+            fee_payer: fee_payer.as_ref().map(Address::to_raw),
+            multisig_address: multisig_address.as_ref().map(Address::to_raw),
         }
     }
 }
@@ -373,6 +565,23 @@ impl TransactionBody {
         &self.params.chain_id
     }
 
+    /// Returns the account paying the fees of the transaction, if it is not the account the
+    /// transaction is sent on behalf of.
+    #[must_use]
+    pub fn fee_payer(&self) -> Option<&Address> {
+        self.fee_payer.as_ref()
+    }
+
+    /// Returns the multisig account the transaction is sent on behalf of, if any.
+    #[must_use]
+    pub fn multisig_address(&self) -> Option<&Address> {
+        self.multisig_address.as_ref()
+    }
+
+    /// Signs the transaction body, constructing a transaction.
+    ///
+    /// If the body names a fee payer, the fee payer's signature must be attached with
+    /// [`Transaction::with_fee_payer_signature`] for the transaction to be valid.
     #[must_use]
     pub fn sign(self, signing_key: &SigningKey) -> Transaction {
         let bytes = self.to_raw().encode_to_vec();
@@ -381,11 +590,72 @@ impl TransactionBody {
         Transaction {
             signature,
             verification_key,
+            cosignatures: vec![],
+            fee_payer_signature: None,
             body: self,
             body_bytes: bytes.into(),
         }
     }
 
+    /// Signs the transaction body without constructing a transaction.
+    ///
+    /// Used to collect the signatures of the members of a multisig account, which are then
+    /// combined with [`TransactionBody::into_multisig_transaction`].
+    #[must_use]
+    pub fn sign_detached(&self, signing_key: &SigningKey) -> TransactionSignature {
+        let bytes = self.to_raw().encode_to_vec();
+        TransactionSignature {
+            signature: signing_key.sign(&bytes),
+            verification_key: signing_key.verification_key(),
+        }
+    }
+
+    /// Constructs a transaction sent on behalf of the multisig account named in the body from
+    /// signatures over the transaction body.
+    ///
+    /// The first signature becomes the primary signature of the transaction, the remaining ones
+    /// its cosignatures.
+    ///
+    /// # Errors
+    ///
+    /// - if the body does not name a multisig account
+    /// - if `signatures` is empty
+    /// - if any of the signatures does not verify against the transaction body
+    pub fn into_multisig_transaction(
+        self,
+        signatures: Vec<TransactionSignature>,
+    ) -> Result<Transaction, TransactionError> {
+        if self.multisig_address.is_none() {
+            return Err(TransactionError::missing_multisig_address());
+        }
+        let bytes = self.to_raw().encode_to_vec();
+        let mut signatures = signatures.into_iter();
+        let Some(primary) = signatures.next() else {
+            return Err(TransactionError::no_signatures());
+        };
+        primary
+            .verification_key
+            .verify(&primary.signature, &bytes)
+            .map_err(TransactionError::verification)?;
+        let cosignatures = signatures
+            .enumerate()
+            .map(|(index, cosignature)| {
+                cosignature
+                    .verify(&bytes)
+                    .map(|()| cosignature)
+                    .map_err(|err| TransactionError::cosignature(index, err))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Transaction {
+            signature: primary.signature,
+            verification_key: primary.verification_key,
+            cosignatures,
+            fee_payer_signature: None,
+            body: self,
+            body_bytes: bytes.into(),
+        })
+    }
+
     #[must_use]
     pub fn into_any(self) -> pbjson_types::Any {
         let raw = self.into_raw();
@@ -443,6 +713,14 @@ impl TransactionBodyError {
     fn group(inner: action::group::Error) -> Self {
         Self(TransactionBodyErrorKind::Group(inner))
     }
+
+    fn fee_payer(inner: AddressError) -> Self {
+        Self(TransactionBodyErrorKind::FeePayer(inner))
+    }
+
+    fn multisig_address(inner: AddressError) -> Self {
+        Self(TransactionBodyErrorKind::MultisigAddress(inner))
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -464,6 +742,10 @@ enum TransactionBodyErrorKind {
     DecodeAny(#[source] prost::DecodeError),
     #[error("`actions` field does not form a valid group of actions")]
     Group(#[source] action::group::Error),
+    #[error("`fee_payer` field invalid")]
+    FeePayer(#[source] AddressError),
+    #[error("`multisig_address` field invalid")]
+    MultisigAddress(#[source] AddressError),
 }
 
 #[derive(Default)]
@@ -471,6 +753,8 @@ pub struct TransactionBodyBuilder {
     nonce: u32,
     chain_id: String,
     actions: Vec<Action>,
+    fee_payer: Option<Address>,
+    multisig_address: Option<Address>,
 }
 
 impl TransactionBodyBuilder {
@@ -493,6 +777,8 @@ impl TransactionBodyBuilder {
             chain_id: chain_id.into(),
             nonce: self.nonce,
             actions: self.actions,
+            fee_payer: self.fee_payer,
+            multisig_address: self.multisig_address,
         }
     }
 
@@ -504,6 +790,26 @@ impl TransactionBodyBuilder {
         }
     }
 
+    /// Sets the account paying the fees of the transaction. If unset, the fees are paid by the
+    /// account the transaction is sent on behalf of.
+    #[must_use]
+    pub fn fee_payer<T: Into<Option<Address>>>(self, fee_payer: T) -> Self {
+        Self {
+            fee_payer: fee_payer.into(),
+            ..self
+        }
+    }
+
+    /// Sets the multisig account the transaction is sent on behalf of. If unset, the transaction
+    /// is sent on behalf of the account of its signer.
+    #[must_use]
+    pub fn multisig_address<T: Into<Option<Address>>>(self, multisig_address: T) -> Self {
+        Self {
+            multisig_address: multisig_address.into(),
+            ..self
+        }
+    }
+
     /// Constructs a [`Body`] from the configured builder.
     ///
     /// # Errors
@@ -516,6 +822,8 @@ impl TransactionBodyBuilder {
             nonce,
             chain_id,
             actions,
+            fee_payer,
+            multisig_address,
         } = self;
         let actions = Actions::try_from_list_of_actions(actions)?;
         Ok(TransactionBody {
@@ -524,6 +832,8 @@ impl TransactionBodyBuilder {
                 nonce,
                 chain_id,
             },
+            fee_payer,
+            multisig_address,
         })
     }
 }
@@ -593,6 +903,98 @@ pub struct TransactionParts {
     pub group: Group,
     pub params: TransactionParams,
     pub verification_key: VerificationKey,
+    pub cosigner_keys: Vec<VerificationKey>,
+    pub multisig_address: Option<Address>,
+    pub fee_payer: Option<Address>,
+}
+
+/// A signature over a [`TransactionBody`] together with the key to verify it.
+#[derive(Clone, Debug)]
+pub struct TransactionSignature {
+    signature: Signature,
+    verification_key: VerificationKey,
+}
+
+impl TransactionSignature {
+    #[must_use]
+    pub fn signature(&self) -> Signature {
+        self.signature
+    }
+
+    #[must_use]
+    pub fn verification_key(&self) -> &VerificationKey {
+        &self.verification_key
+    }
+
+    /// Verifies the signature over the protobuf-encoded bytes of a transaction body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signature does not verify against `body_bytes`.
+    pub fn verify(&self, body_bytes: &[u8]) -> Result<(), TransactionSignatureError> {
+        self.verification_key
+            .verify(&self.signature, body_bytes)
+            .map_err(TransactionSignatureError::verification)
+    }
+}
+
+impl Protobuf for TransactionSignature {
+    type Error = TransactionSignatureError;
+    type Raw = raw::TransactionSignature;
+
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let Self::Raw {
+            signature,
+            public_key,
+        } = raw;
+        let signature =
+            Signature::try_from(&**signature).map_err(TransactionSignatureError::signature)?;
+        let verification_key = VerificationKey::try_from(&**public_key)
+            .map_err(TransactionSignatureError::verification_key)?;
+        Ok(Self {
+            signature,
+            verification_key,
+        })
+    }
+
+    fn to_raw(&self) -> Self::Raw {
+        let Self {
+            signature,
+            verification_key,
+        } = self;
+        Self::Raw {
+            signature: Bytes::copy_from_slice(&signature.to_bytes()),
+            public_key: Bytes::copy_from_slice(&verification_key.to_bytes()),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct TransactionSignatureError(TransactionSignatureErrorKind);
+
+impl TransactionSignatureError {
+    fn signature(inner: crypto::Error) -> Self {
+        Self(TransactionSignatureErrorKind::Signature(inner))
+    }
+
+    fn verification_key(inner: crypto::Error) -> Self {
+        Self(TransactionSignatureErrorKind::VerificationKey(inner))
+    }
+
+    fn verification(inner: crypto::Error) -> Self {
+        Self(TransactionSignatureErrorKind::Verification(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum TransactionSignatureErrorKind {
+    #[error("`signature` field invalid")]
+    Signature(#[source] crypto::Error),
+    #[error("`public_key` field invalid")]
+    VerificationKey(#[source] crypto::Error),
+    #[error("transaction body could not be verified given the signature and verification key")]
+    Verification(crypto::Error),
 }
 
 #[cfg(test)]
@@ -646,6 +1048,8 @@ mod tests {
         let tx = Transaction {
             signature,
             verification_key,
+            cosignatures: vec![],
+            fee_payer_signature: None,
             body: body.clone(),
             body_bytes: body.to_raw().encode_to_vec().into(),
         };
@@ -684,4 +1088,206 @@ mod tests {
         // `try_from_raw` verifies the signature
         Transaction::try_from_raw(raw).unwrap();
     }
+
+    fn multisig_address() -> Address {
+        Address::builder()
+            .array([1; 20])
+            .prefix(ASTRIA_ADDRESS_PREFIX)
+            .try_build()
+            .unwrap()
+    }
+
+    fn multisig_transfer_body() -> TransactionBody {
+        TransactionBody::builder()
+            .actions(transfer_body().into_actions())
+            .chain_id("test-1".to_string())
+            .nonce(1)
+            .multisig_address(multisig_address())
+            .try_build()
+            .unwrap()
+    }
+
+    fn transfer_body() -> TransactionBody {
+        let transfer = Transfer {
+            to: Address::builder()
+                .array([0; 20])
+                .prefix(ASTRIA_ADDRESS_PREFIX)
+                .try_build()
+                .unwrap(),
+            amount: 0,
+            asset: asset(),
+            fee_asset: asset(),
+        };
+        TransactionBody::builder()
+            .actions(vec![transfer.into()])
+            .chain_id("test-1".to_string())
+            .nonce(1)
+            .try_build()
+            .unwrap()
+    }
+
+    #[test]
+    fn multisig_transaction_verification_roundtrip() {
+        let alice = SigningKey::from([1; 32]);
+        let bob = SigningKey::from([2; 32]);
+        let body = multisig_transfer_body();
+        let signatures = vec![body.sign_detached(&alice), body.sign_detached(&bob)];
+
+        let signed_tx = body.into_multisig_transaction(signatures).unwrap();
+        assert_eq!(multisig_address().bytes(), *signed_tx.address_bytes());
+
+        // `try_from_raw` verifies the signature and all cosignatures
+        let tx = Transaction::try_from_raw(signed_tx.to_raw()).unwrap();
+        assert_eq!(1, tx.cosignatures().len());
+        assert_eq!(
+            bob.verification_key(),
+            *tx.cosignatures()[0].verification_key()
+        );
+        assert_eq!(Some(&multisig_address()), tx.multisig_address());
+    }
+
+    #[test]
+    fn transaction_with_invalid_cosignature_is_rejected() {
+        let alice = SigningKey::from([1; 32]);
+        let bob = SigningKey::from([2; 32]);
+        let body = multisig_transfer_body();
+        let signed_tx = body
+            .clone()
+            .into_multisig_transaction(vec![body.sign_detached(&alice)])
+            .unwrap();
+
+        let mut raw = signed_tx.to_raw();
+        raw.cosignatures.push(raw::TransactionSignature {
+            signature: Bytes::copy_from_slice(&bob.sign(b"not the body").to_bytes()),
+            public_key: Bytes::copy_from_slice(&bob.verification_key().to_bytes()),
+        });
+        let error = Transaction::try_from_raw(raw).unwrap_err();
+        assert!(
+            matches!(
+                error.0,
+                TransactionErrorKind::Cosignature {
+                    index: 0,
+                    ..
+                }
+            ),
+            "expected a cosignature error, got {error:?}"
+        );
+    }
+
+    #[test]
+    fn multisig_transaction_with_replaced_multisig_address_is_rejected() {
+        let alice = SigningKey::from([1; 32]);
+        let body = multisig_transfer_body();
+        let signed_tx = body
+            .clone()
+            .into_multisig_transaction(vec![body.sign_detached(&alice)])
+            .unwrap();
+
+        let mut raw = signed_tx.to_raw();
+        let mut raw_body = raw::TransactionBody::decode(raw.body.take().unwrap().value).unwrap();
+        raw_body.multisig_address = Some(
+            Address::builder()
+                .array([2; 20])
+                .prefix(ASTRIA_ADDRESS_PREFIX)
+                .try_build()
+                .unwrap()
+                .into_raw(),
+        );
+        raw.body = Some(pbjson_types::Any {
+            type_url: raw::TransactionBody::type_url(),
+            value: raw_body.encode_to_vec().into(),
+        });
+        let error = Transaction::try_from_raw(raw).unwrap_err();
+        assert!(
+            matches!(error.0, TransactionErrorKind::Verification(_)),
+            "expected a verification error, got {error:?}"
+        );
+    }
+
+    #[test]
+    fn multisig_transaction_requires_multisig_address_in_body() {
+        let alice = SigningKey::from([1; 32]);
+        let body = transfer_body();
+        let error = body
+            .clone()
+            .into_multisig_transaction(vec![body.sign_detached(&alice)])
+            .unwrap_err();
+        assert!(
+            matches!(error.0, TransactionErrorKind::MissingMultisigAddress),
+            "expected a missing multisig address error, got {error:?}"
+        );
+    }
+
+    fn sponsored_transfer_body(fee_payer: &SigningKey) -> TransactionBody {
+        let fee_payer = Address::builder()
+            .array(*fee_payer.verification_key().address_bytes())
+            .prefix(ASTRIA_ADDRESS_PREFIX)
+            .try_build()
+            .unwrap();
+        TransactionBody::builder()
+            .actions(transfer_body().into_actions())
+            .chain_id("test-1".to_string())
+            .nonce(1)
+            .fee_payer(fee_payer)
+            .try_build()
+            .unwrap()
+    }
+
+    #[test]
+    fn sponsored_transaction_verification_roundtrip() {
+        let signer = SigningKey::from([1; 32]);
+        let sponsor = SigningKey::from([2; 32]);
+        let body = sponsored_transfer_body(&sponsor);
+        let fee_payer_signature = body.sign_detached(&sponsor);
+
+        let signed_tx = body
+            .sign(&signer)
+            .with_fee_payer_signature(fee_payer_signature)
+            .unwrap();
+        assert_eq!(
+            sponsor.verification_key().address_bytes(),
+            signed_tx.fee_payer_address_bytes()
+        );
+        assert_eq!(
+            signer.verification_key().address_bytes(),
+            signed_tx.address_bytes()
+        );
+
+        // `try_from_raw` verifies the signature and the fee payer signature
+        let tx = Transaction::try_from_raw(signed_tx.to_raw()).unwrap();
+        assert_eq!(
+            sponsor.verification_key(),
+            *tx.fee_payer_signature().unwrap().verification_key()
+        );
+    }
+
+    #[test]
+    fn sponsored_transaction_without_fee_payer_signature_is_rejected() {
+        let signer = SigningKey::from([1; 32]);
+        let sponsor = SigningKey::from([2; 32]);
+        let signed_tx = sponsored_transfer_body(&sponsor).sign(&signer);
+
+        let error = Transaction::try_from_raw(signed_tx.to_raw()).unwrap_err();
+        assert!(
+            matches!(error.0, TransactionErrorKind::MissingFeePayerSignature),
+            "expected a missing fee payer signature error, got {error:?}"
+        );
+    }
+
+    #[test]
+    fn fee_payer_signature_by_other_key_is_rejected() {
+        let signer = SigningKey::from([1; 32]);
+        let sponsor = SigningKey::from([2; 32]);
+        let body = sponsored_transfer_body(&sponsor);
+        let fee_payer_signature = body.sign_detached(&signer);
+
+        let error = body
+            .sign(&signer)
+            .with_fee_payer_signature(fee_payer_signature)
+            .unwrap_err();
+        assert!(
+            matches!(error.0, TransactionErrorKind::FeePayerMismatch),
+            "expected a fee payer mismatch error, got {error:?}"
+        );
+    }
 }
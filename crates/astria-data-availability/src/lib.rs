@@ -0,0 +1,176 @@
+//! Abstractions over the data availability layer that Sequencer blocks are written to by
+//! sequencer-relayer and read from by conductor.
+//!
+//! Writing and reading are split into the [`BlobWriter`] and [`BlobReader`] traits because they
+//! are served by different services of the same network: on Celestia, blobs are submitted through
+//! the gRPC API of a Celestia app and read through the JSON-RPC API of a Celestia node. The
+//! Celestia implementations of the traits live in the services using them.
+//!
+//! [`LocalDa`] implements both traits on top of a directory shared by the writer and the readers,
+//! so that devnets and end-to-end tests can run without a Celestia network.
+//!
+//! Both implementations use Celestia's notions of blobs, namespaces and heights.
+
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display,
+        Formatter,
+    },
+    sync::Arc,
+    time::Duration,
+};
+
+use async_trait::async_trait;
+pub use celestia_types::{
+    nmt::Namespace,
+    Blob,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+mod local;
+
+pub use local::{
+    LocalDa,
+    LocalDaError,
+    PreparedBlobs,
+};
+
+/// A boxed error returned by an implementation of [`BlobReader`].
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// The data availability layer a service connects to.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Backend {
+    /// A Celestia network.
+    Celestia,
+    /// A directory shared by sequencer-relayer and conductor, see [`LocalDa`].
+    Local,
+}
+
+impl Display for Backend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Celestia => "celestia",
+            Self::Local => "local",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Identifies a submission of blobs so that its inclusion can be confirmed, even after a restart.
+///
+/// For Celestia, this is the hash of the blob transaction.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct SubmissionId([u8; 32]);
+
+impl SubmissionId {
+    #[must_use]
+    pub const fn new(id: [u8; 32]) -> Self {
+        Self(id)
+    }
+
+    #[must_use]
+    pub const fn get(self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Converts `self` to a hex-encoded string.
+    #[must_use]
+    pub fn to_hex(self) -> String {
+        hex::encode(self.0)
+    }
+}
+
+impl Display for SubmissionId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+impl Debug for SubmissionId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SubmissionId")
+            .field(&format_args!("{self}"))
+            .finish()
+    }
+}
+
+/// Submits blobs to a data availability layer and confirms their inclusion.
+///
+/// A submission is split into two steps so that the [`SubmissionId`] of prepared blobs can be
+/// persisted before they are submitted. If the outcome of a submission is unknown, for example
+/// because the writer was restarted, the inclusion of the blobs is confirmed through their ID
+/// before submitting them again.
+#[async_trait]
+pub trait BlobWriter: Clone + Send + Sync + 'static {
+    /// Blobs that were prepared for submission.
+    type Prepared: Send + 'static;
+
+    type Error: std::error::Error + Clone + Send + Sync + 'static;
+
+    /// Prepares `blobs` for submission.
+    ///
+    /// `last_error` is the error of the previous attempt to submit the same blobs, if any. It can
+    /// be used to adjust the submission, for example to pay a higher fee.
+    async fn prepare(
+        &mut self,
+        blobs: Arc<Vec<Blob>>,
+        last_error: Option<Self::Error>,
+    ) -> Result<Self::Prepared, Self::Error>;
+
+    /// Returns the ID under which the inclusion of `prepared` can be confirmed.
+    fn submission_id(prepared: &Self::Prepared) -> SubmissionId;
+
+    /// Returns the fee paid for submitting `prepared`, or `None` if the layer charges no fees.
+    fn fee(prepared: &Self::Prepared) -> Option<u64>;
+
+    /// Submits `prepared` and waits for its inclusion, returning the height at which it was
+    /// included.
+    async fn submit(&mut self, prepared: Self::Prepared) -> Result<u64, Self::Error>;
+
+    /// Returns if it is unknown whether the submission that failed with `error` was included, in
+    /// which case its inclusion should be confirmed before submitting it again.
+    fn is_outcome_unknown(error: &Self::Error) -> bool;
+
+    /// Waits up to `timeout` for the submission identified by `id` to be included, returning the
+    /// height at which it was included, or `None` if it was not included in time.
+    async fn confirm_inclusion(&mut self, id: SubmissionId, timeout: Duration) -> Option<u64>;
+}
+
+/// Reads blobs from a data availability layer.
+#[async_trait]
+pub trait BlobReader: Send + Sync + 'static {
+    /// Returns the chain ID of the data availability layer.
+    async fn chain_id(&self) -> Result<String, ReadError>;
+
+    /// Returns the latest height of the data availability layer.
+    async fn latest_height(&self) -> Result<u64, ReadError>;
+
+    /// Returns all blobs at `height` in `namespace`.
+    async fn fetch_blobs(&self, height: u64, namespace: Namespace) -> Result<Vec<Blob>, ReadError>;
+}
+
+/// An error returned by a [`BlobReader`].
+#[derive(Debug, thiserror::Error)]
+pub enum ReadError {
+    /// The data availability layer could not be reached or did not have the requested data yet.
+    /// The request can be retried.
+    #[error("data availability layer is unavailable")]
+    Unavailable(#[source] BoxError),
+    /// The data availability layer rejected the request or returned invalid data.
+    #[error("data availability layer failed the request")]
+    Failed(#[source] BoxError),
+}
+
+impl ReadError {
+    /// Returns if the request can be retried.
+    #[must_use]
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::Unavailable(_))
+    }
+}
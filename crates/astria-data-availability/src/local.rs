@@ -0,0 +1,392 @@
+//! A data availability layer stored in a local directory.
+//!
+//! The directory is laid out as follows:
+//!
+//! - `chain_id`: the chain ID configured by the writer when initializing the directory.
+//! - `latest_height`: the height of the latest submission.
+//! - `blocks/<height>.json`: the JSON encoded blobs submitted at `<height>`.
+//! - `submissions/<submission id>`: the height at which the submission was included.
+//!
+//! Every submission is included at its own height, starting at height 1. Files are written to a
+//! temporary file first and renamed so that readers never observe partially written files.
+
+use std::{
+    io,
+    num::ParseIntError,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tokio::{
+    fs,
+    sync::Mutex,
+};
+
+use crate::{
+    Blob,
+    BlobReader,
+    BlobWriter,
+    Namespace,
+    ReadError,
+    SubmissionId,
+};
+
+const CHAIN_ID_FILE: &str = "chain_id";
+const LATEST_HEIGHT_FILE: &str = "latest_height";
+const BLOCKS_DIR: &str = "blocks";
+const SUBMISSIONS_DIR: &str = "submissions";
+
+/// An error returned by [`LocalDa`].
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum LocalDaError {
+    #[error("failed to access `{}`", .path.display())]
+    Io {
+        path: PathBuf,
+        source: Arc<io::Error>,
+    },
+    #[error("failed to encode blobs as JSON")]
+    Encode(#[source] Arc<serde_json::Error>),
+    #[error("failed to decode blobs in `{}`", .path.display())]
+    Decode {
+        path: PathBuf,
+        source: Arc<serde_json::Error>,
+    },
+    #[error("failed to parse height in `{}`", .path.display())]
+    ParseHeight {
+        path: PathBuf,
+        source: ParseIntError,
+    },
+    #[error(
+        "local data availability layer at `{}` was initialized for chain `{actual}`, but chain \
+         `{expected}` was configured",
+        .dir.display()
+    )]
+    MismatchedChainId {
+        dir: PathBuf,
+        expected: String,
+        actual: String,
+    },
+    #[error("height `{height}` is above the latest height `{latest_height}`")]
+    HeightNotAvailable { height: u64, latest_height: u64 },
+}
+
+impl LocalDaError {
+    fn io(path: &Path, source: io::Error) -> Self {
+        Self::Io {
+            path: path.to_path_buf(),
+            source: Arc::new(source),
+        }
+    }
+}
+
+/// A data availability layer stored in a local directory, for devnets and end-to-end tests.
+///
+/// Only one writer may use a directory at a time, while any number of readers may.
+#[derive(Clone, Debug)]
+pub struct LocalDa {
+    dir: PathBuf,
+    /// Serializes the submissions of this writer.
+    submission_lock: Arc<Mutex<()>>,
+}
+
+/// Blobs prepared for submission to a [`LocalDa`].
+pub struct PreparedBlobs {
+    id: SubmissionId,
+    encoded: Vec<u8>,
+}
+
+impl LocalDa {
+    /// Returns a handle to the local data availability layer in `dir`.
+    ///
+    /// Writers must call [`LocalDa::init`] before submitting blobs.
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            submission_lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Creates the directory for `chain_id` if it does not exist.
+    ///
+    /// # Errors
+    /// Returns an error if the directory could not be created, or if it was initialized for a
+    /// different chain.
+    pub async fn init(&self, chain_id: &str) -> Result<(), LocalDaError> {
+        for dir in [self.dir.join(BLOCKS_DIR), self.dir.join(SUBMISSIONS_DIR)] {
+            fs::create_dir_all(&dir)
+                .await
+                .map_err(|source| LocalDaError::io(&dir, source))?;
+        }
+        let path = self.dir.join(CHAIN_ID_FILE);
+        match fs::read_to_string(&path).await {
+            Ok(actual) if actual == chain_id => Ok(()),
+            Ok(actual) => Err(LocalDaError::MismatchedChainId {
+                dir: self.dir.clone(),
+                expected: chain_id.to_string(),
+                actual,
+            }),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                write_atomically(&path, chain_id.as_bytes()).await
+            }
+            Err(error) => Err(LocalDaError::io(&path, error)),
+        }
+    }
+
+    fn block_path(&self, height: u64) -> PathBuf {
+        self.dir.join(BLOCKS_DIR).join(format!("{height}.json"))
+    }
+
+    fn submission_path(&self, id: SubmissionId) -> PathBuf {
+        self.dir.join(SUBMISSIONS_DIR).join(id.to_hex())
+    }
+
+    async fn read_latest_height(&self) -> Result<u64, LocalDaError> {
+        Ok(read_height(&self.dir.join(LATEST_HEIGHT_FILE))
+            .await?
+            .unwrap_or(0))
+    }
+
+    async fn blobs_at(&self, height: u64) -> Result<Vec<Blob>, LocalDaError> {
+        let latest_height = self.read_latest_height().await?;
+        if height > latest_height {
+            return Err(LocalDaError::HeightNotAvailable {
+                height,
+                latest_height,
+            });
+        }
+        let path = self.block_path(height);
+        match fs::read(&path).await {
+            Ok(encoded) => {
+                serde_json::from_slice(&encoded).map_err(|source| LocalDaError::Decode {
+                    path,
+                    source: Arc::new(source),
+                })
+            }
+            // Heights at or below the latest height without blobs are empty.
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+            Err(error) => Err(LocalDaError::io(&path, error)),
+        }
+    }
+}
+
+#[async_trait]
+impl BlobWriter for LocalDa {
+    type Error = LocalDaError;
+    type Prepared = PreparedBlobs;
+
+    async fn prepare(
+        &mut self,
+        blobs: Arc<Vec<Blob>>,
+        _last_error: Option<Self::Error>,
+    ) -> Result<Self::Prepared, Self::Error> {
+        let encoded =
+            serde_json::to_vec(&*blobs).map_err(|source| LocalDaError::Encode(Arc::new(source)))?;
+        let id = SubmissionId::new(Sha256::digest(&encoded).into());
+        Ok(PreparedBlobs {
+            id,
+            encoded,
+        })
+    }
+
+    fn submission_id(prepared: &Self::Prepared) -> SubmissionId {
+        prepared.id
+    }
+
+    fn fee(_prepared: &Self::Prepared) -> Option<u64> {
+        None
+    }
+
+    async fn submit(&mut self, prepared: Self::Prepared) -> Result<u64, Self::Error> {
+        let _guard = self.submission_lock.lock().await;
+
+        let submission_path = self.submission_path(prepared.id);
+        // Submitting the same blobs again is a no-op.
+        if let Some(height) = read_height(&submission_path).await? {
+            return Ok(height);
+        }
+        let height = self.read_latest_height().await?.saturating_add(1);
+        // The latest height is only advanced after its blobs were written so that readers never
+        // observe a height without its blobs. If the writer exits before recording the
+        // submission, the blobs are submitted again at the next height.
+        write_atomically(&self.block_path(height), &prepared.encoded).await?;
+        write_atomically(
+            &self.dir.join(LATEST_HEIGHT_FILE),
+            height.to_string().as_bytes(),
+        )
+        .await?;
+        write_atomically(&submission_path, height.to_string().as_bytes()).await?;
+        Ok(height)
+    }
+
+    fn is_outcome_unknown(_error: &Self::Error) -> bool {
+        false
+    }
+
+    async fn confirm_inclusion(&mut self, id: SubmissionId, _timeout: Duration) -> Option<u64> {
+        // Submissions are included immediately, so there is nothing to wait for.
+        read_height(&self.submission_path(id)).await.ok().flatten()
+    }
+}
+
+#[async_trait]
+impl BlobReader for LocalDa {
+    async fn chain_id(&self) -> Result<String, ReadError> {
+        let path = self.dir.join(CHAIN_ID_FILE);
+        fs::read_to_string(&path)
+            .await
+            .map_err(|source| ReadError::Unavailable(LocalDaError::io(&path, source).into()))
+    }
+
+    async fn latest_height(&self) -> Result<u64, ReadError> {
+        self.read_latest_height().await.map_err(into_read_error)
+    }
+
+    async fn fetch_blobs(&self, height: u64, namespace: Namespace) -> Result<Vec<Blob>, ReadError> {
+        let mut blobs = self.blobs_at(height).await.map_err(into_read_error)?;
+        blobs.retain(|blob| blob.namespace == namespace);
+        Ok(blobs)
+    }
+}
+
+fn into_read_error(error: LocalDaError) -> ReadError {
+    match error {
+        LocalDaError::Io {
+            ..
+        }
+        | LocalDaError::HeightNotAvailable {
+            ..
+        } => ReadError::Unavailable(error.into()),
+        LocalDaError::Encode(_)
+        | LocalDaError::Decode {
+            ..
+        }
+        | LocalDaError::ParseHeight {
+            ..
+        }
+        | LocalDaError::MismatchedChainId {
+            ..
+        } => ReadError::Failed(error.into()),
+    }
+}
+
+/// Reads the height stored in `path`, returning `None` if the file does not exist.
+async fn read_height(path: &Path) -> Result<Option<u64>, LocalDaError> {
+    match fs::read_to_string(path).await {
+        Ok(height) => height
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|source| LocalDaError::ParseHeight {
+                path: path.to_path_buf(),
+                source,
+            }),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(LocalDaError::io(path, error)),
+    }
+}
+
+async fn write_atomically(path: &Path, contents: &[u8]) -> Result<(), LocalDaError> {
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, contents)
+        .await
+        .map_err(|source| LocalDaError::io(&tmp_path, source))?;
+    fs::rename(&tmp_path, path)
+        .await
+        .map_err(|source| LocalDaError::io(path, source))
+}
+
+#[cfg(test)]
+mod tests {
+    use celestia_types::AppVersion;
+
+    use super::*;
+
+    const CHAIN_ID: &str = "local-da";
+
+    fn namespace(byte: u8) -> Namespace {
+        Namespace::const_v0([byte; 10])
+    }
+
+    fn blob(namespace: Namespace, data: &[u8]) -> Blob {
+        Blob::new(namespace, data.to_vec(), AppVersion::V3).unwrap()
+    }
+
+    async fn submit(da: &mut LocalDa, blobs: Vec<Blob>) -> u64 {
+        let prepared = da.prepare(Arc::new(blobs), None).await.unwrap();
+        da.submit(prepared).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn submitted_blobs_are_read_by_namespace_and_height() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut writer = LocalDa::new(dir.path());
+        writer.init(CHAIN_ID).await.unwrap();
+        let reader = LocalDa::new(dir.path());
+        assert_eq!(reader.latest_height().await.unwrap(), 0);
+
+        let first = submit(
+            &mut writer,
+            vec![blob(namespace(1), b"one"), blob(namespace(2), b"two")],
+        )
+        .await;
+        let second = submit(&mut writer, vec![blob(namespace(1), b"three")]).await;
+        assert_eq!((first, second), (1, 2));
+
+        assert_eq!(reader.chain_id().await.unwrap(), CHAIN_ID);
+        assert_eq!(reader.latest_height().await.unwrap(), 2);
+        let blobs = reader.fetch_blobs(1, namespace(1)).await.unwrap();
+        assert_eq!(blobs.len(), 1);
+        assert_eq!(blobs[0].data, b"one");
+        let blobs = reader.fetch_blobs(2, namespace(2)).await.unwrap();
+        assert!(blobs.is_empty());
+
+        let error = reader.fetch_blobs(3, namespace(1)).await.unwrap_err();
+        assert!(error.is_transient());
+    }
+
+    #[tokio::test]
+    async fn resubmitted_blobs_are_confirmed_at_their_first_height() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut writer = LocalDa::new(dir.path());
+        writer.init(CHAIN_ID).await.unwrap();
+
+        let blobs = Arc::new(vec![blob(namespace(1), b"one")]);
+        let prepared = writer.prepare(blobs.clone(), None).await.unwrap();
+        let id = LocalDa::submission_id(&prepared);
+        assert_eq!(writer.confirm_inclusion(id, Duration::ZERO).await, None);
+        assert_eq!(writer.submit(prepared).await.unwrap(), 1);
+
+        let prepared = writer.prepare(blobs, None).await.unwrap();
+        assert_eq!(LocalDa::submission_id(&prepared), id);
+        assert_eq!(writer.submit(prepared).await.unwrap(), 1);
+        assert_eq!(writer.confirm_inclusion(id, Duration::ZERO).await, Some(1));
+    }
+
+    #[tokio::test]
+    async fn init_rejects_directory_of_other_chain() {
+        let dir = tempfile::tempdir().unwrap();
+        LocalDa::new(dir.path()).init(CHAIN_ID).await.unwrap();
+        LocalDa::new(dir.path()).init(CHAIN_ID).await.unwrap();
+
+        let error = LocalDa::new(dir.path())
+            .init("other-chain")
+            .await
+            .unwrap_err();
+        assert!(matches!(error, LocalDaError::MismatchedChainId { .. }));
+    }
+}
@@ -55,6 +55,7 @@ use astria_core::{
             BridgeAccountLastTxHashResponse,
         },
         fees::v1::TransactionFeeResponse,
+        proof,
         transaction::v1::TransactionBody,
     },
     Protobuf as _,
@@ -114,6 +115,7 @@ impl std::error::Error for Error {
             ErrorKind::AbciQueryDeserialization(e) => Some(e),
             ErrorKind::TendermintRpc(e) => Some(e),
             ErrorKind::NativeConversion(e) => Some(e),
+            ErrorKind::ProofVerification(e) => Some(e),
         }
     }
 }
@@ -129,7 +131,9 @@ impl Error {
     pub fn as_tendermint_rpc(&self) -> Option<&TendermintRpcError> {
         match self.kind() {
             ErrorKind::TendermintRpc(e) => Some(e),
-            ErrorKind::AbciQueryDeserialization(_) | ErrorKind::NativeConversion(_) => None,
+            ErrorKind::AbciQueryDeserialization(_)
+            | ErrorKind::NativeConversion(_)
+            | ErrorKind::ProofVerification(_) => None,
         }
     }
 
@@ -160,6 +164,13 @@ impl Error {
             inner: ErrorKind::native_conversion(target, inner),
         }
     }
+
+    /// Convenience function to construct `Error` containing a `ProofVerificationError`.
+    fn proof_verification(inner: ProofVerificationError) -> Self {
+        Self {
+            inner: ErrorKind::ProofVerification(inner),
+        }
+    }
 }
 
 /// Error if deserialization of the bytes in an abci query response failed.
@@ -280,6 +291,7 @@ pub enum ErrorKind {
     AbciQueryDeserialization(AbciQueryDeserializationError),
     TendermintRpc(TendermintRpcError),
     NativeConversion(DeserializationError),
+    ProofVerification(ProofVerificationError),
 }
 
 impl ErrorKind {
@@ -316,6 +328,93 @@ impl ErrorKind {
     }
 }
 
+/// Error if the state proofs contained in an abci query response could not be verified.
+#[derive(Debug, thiserror::Error)]
+pub enum ProofVerificationError {
+    #[error(
+        "the header at height `{header_height}` does not commit to the state at a queryable height"
+    )]
+    HeaderTooLow { header_height: Height },
+    #[error(
+        "the response for height `{response_height}` must be verified against the header at the \
+         next height, but the header is at height `{header_height}`"
+    )]
+    HeightMismatch {
+        response_height: Height,
+        header_height: Height,
+    },
+    #[error("the abci query response did not contain a proof")]
+    MissingProof,
+    #[error("the key of a proof operation is not valid UTF-8")]
+    NonUtf8Key(#[source] std::string::FromUtf8Error),
+    #[error("the proof operation for key `{key}` has unsupported type `{field_type}`")]
+    UnsupportedType { key: String, field_type: String },
+    #[error("failed decoding the merkle proof for key `{key}`")]
+    Decode {
+        key: String,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    #[error("the merkle proof for key `{key}` does not consist of exactly one commitment proof")]
+    UnexpectedProofCount { key: String },
+    #[error(
+        "the merkle proof for key `{key}` does not prove the value of the response against the \
+         app hash: {reason}"
+    )]
+    Invalid { key: String, reason: String },
+    #[error("expected a proof for key `{key}`, but none was returned")]
+    MissingKey { key: String },
+}
+
+/// A value of the sequencer state that was proven against the app hash of a block header.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProvenValue {
+    key: String,
+    value: Option<Vec<u8>>,
+}
+
+impl ProvenValue {
+    /// Returns the storage key of the proven value.
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the raw bytes stored under the key, or `None` if the proof shows that nothing is
+    /// stored under it.
+    #[must_use]
+    pub fn value(&self) -> Option<&[u8]> {
+        self.value.as_deref()
+    }
+}
+
+/// A sequencer query response together with the proven state it was read from.
+#[derive(Clone, Debug)]
+pub struct Proven<T> {
+    response: T,
+    values: Vec<ProvenValue>,
+}
+
+impl<T> Proven<T> {
+    /// Returns the query response.
+    #[must_use]
+    pub fn response(&self) -> &T {
+        &self.response
+    }
+
+    /// Returns the values the response was read from, proven against the app hash.
+    #[must_use]
+    pub fn values(&self) -> &[ProvenValue] {
+        &self.values
+    }
+
+    /// Returns the query response, dropping the proven values.
+    #[must_use]
+    pub fn into_response(self) -> T {
+        self.response
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum NewBlockStreamError {
     #[error("failed converting new block received from CometBft to sequencer block")]
@@ -449,19 +548,51 @@ pub trait SequencerClientExt: Client {
             .await
             .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
 
-        let proto_response =
-            astria_core::generated::astria::protocol::accounts::v1::BalanceResponse::decode(
-                &*response.value,
-            )
-            .map_err(|e| {
-                Error::abci_query_deserialization(
-                    "astria.sequencer.v1.BalanceResponse",
-                    response,
-                    e,
+        decode_balance_response(response)
+    }
+
+    /// Returns the balance of the given account in the state committed to by `header`, together
+    /// with the account's balances proven against the app hash of `header`.
+    ///
+    /// The caller is responsible for verifying `header`, for example with a CometBFT light client.
+    /// Since the app hash of a block commits to the state after the preceding block, the balance
+    /// is queried at the height preceding that of `header`.
+    ///
+    /// Note that only the returned balances are proven: the proofs do not show that the account
+    /// holds no other assets.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   `astria.sequencer.v1.BalanceResponse`.
+    /// - If the proofs contained in the abci query response cannot be verified against `header`, or
+    ///   do not cover every returned balance.
+    async fn get_balance_with_proof(
+        &self,
+        address: Address,
+        header: &tendermint::block::Header,
+    ) -> Result<Proven<BalanceResponse>, Error> {
+        const PREFIX: &str = "accounts/balance";
+        let path = format!("{PREFIX}/{address}");
+
+        let (response, proofs) = abci_query_with_proof(self, path, header).await?;
+        let response = decode_balance_response(response)?;
+        let values = response
+            .balances
+            .iter()
+            .map(|balance| {
+                proofs.prove(
+                    proof::keys::balance(address.as_bytes(), &balance.denom.to_ibc_prefixed()),
+                    [Some(proof::values::balance(balance.balance))],
                 )
-            })?;
-        BalanceResponse::try_from_raw(&proto_response)
-            .map_err(|e| Error::native_conversion("BalanceResponse", Arc::new(e)))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(Error::proof_verification)?;
+        Ok(Proven {
+            response,
+            values,
+        })
     }
 
     /// Returns the current balance of the given account at the latest height.
@@ -531,14 +662,45 @@ pub trait SequencerClientExt: Client {
             .await
             .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
 
-        let proto_response =
-            astria_core::generated::astria::protocol::accounts::v1::NonceResponse::decode(
-                &*response.value,
-            )
-            .map_err(|e| {
-                Error::abci_query_deserialization("astria.sequencer.v1.NonceResponse", response, e)
-            })?;
-        Ok(proto_response.to_native())
+        decode_nonce_response(response)
+    }
+
+    /// Returns the nonce of the given account in the state committed to by `header`, together
+    /// with the account's nonce proven against the app hash of `header`.
+    ///
+    /// See [`SequencerClientExt::get_balance_with_proof`] for how `header` is used.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   `astria.sequencer.v1.NonceResponse`.
+    /// - If the proofs contained in the abci query response cannot be verified against `header`, or
+    ///   do not cover the account's nonce.
+    async fn get_nonce_with_proof(
+        &self,
+        address: Address,
+        header: &tendermint::block::Header,
+    ) -> Result<Proven<NonceResponse>, Error> {
+        const PREFIX: &str = "accounts/nonce";
+        let path = format!("{PREFIX}/{address}");
+
+        let (response, proofs) = abci_query_with_proof(self, path, header).await?;
+        let response = decode_nonce_response(response)?;
+        let stored_nonce = Some(proof::values::nonce(response.nonce));
+        // The nonce of an account that never sent a transaction is not stored.
+        let candidates = if response.nonce == 0 {
+            vec![stored_nonce, None]
+        } else {
+            vec![stored_nonce]
+        };
+        let values = vec![proofs
+            .prove(proof::keys::nonce(address.as_bytes()), candidates)
+            .map_err(Error::proof_verification)?];
+        Ok(Proven {
+            response,
+            values,
+        })
     }
 
     /// Returns the current nonce of the given account at the latest height.
@@ -564,24 +726,69 @@ pub trait SequencerClientExt: Client {
             .await
             .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
 
-        let proto_response =
-            astria_core::generated::astria::protocol::bridge::v1::BridgeAccountInfoResponse::decode(
-                &*response.value,
-            )
-            .map_err(|e| {
-                Error::abci_query_deserialization(
-                    "astria.protocol.bridge.v1.BridgeAccountInfoResponse",
-                    response,
-                    e,
-                )
-            })?;
-        let native = BridgeAccountInfoResponse::try_from_raw(proto_response).map_err(|e| {
-            Error::native_conversion(
-                "astria.protocol.bridge.v1.BridgeAccountInfoResponse",
-                Arc::new(e),
-            )
-        })?;
-        Ok(native)
+        decode_bridge_account_info_response(response)
+    }
+
+    /// Returns the bridge account info of the given account in the state committed to by
+    /// `header`, together with the account's bridge state proven against the app hash of `header`.
+    ///
+    /// See [`SequencerClientExt::get_balance_with_proof`] for how `header` is used.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   `astria.protocol.bridge.v1.BridgeAccountInfoResponse`.
+    /// - If the proofs contained in the abci query response cannot be verified against `header`, or
+    ///   do not cover the account's bridge state.
+    async fn get_bridge_account_info_with_proof(
+        &self,
+        address: Address,
+        header: &tendermint::block::Header,
+    ) -> Result<Proven<BridgeAccountInfoResponse>, Error> {
+        const PREFIX: &str = "bridge/account_info";
+        let path = format!("{PREFIX}/{address}");
+
+        let (response, proofs) = abci_query_with_proof(self, path, header).await?;
+        let response = decode_bridge_account_info_response(response)?;
+        let address = address.as_bytes();
+        let values = match &response.info {
+            // The rollup ID is stored for every bridge account.
+            None => vec![proofs.prove(proof::keys::bridge_account_rollup_id(address), [None])],
+            Some(info) => vec![
+                proofs.prove(
+                    proof::keys::bridge_account_rollup_id(address),
+                    [Some(proof::values::bridge_account_rollup_id(
+                        &info.rollup_id,
+                    ))],
+                ),
+                proofs.prove(
+                    proof::keys::bridge_account_asset_id(address),
+                    [Some(proof::values::bridge_account_asset_id(
+                        &info.asset.to_ibc_prefixed(),
+                    ))],
+                ),
+                proofs.prove(
+                    proof::keys::bridge_account_sudo_address(address),
+                    [Some(proof::values::bridge_account_address(
+                        info.sudo_address.as_bytes(),
+                    ))],
+                ),
+                proofs.prove(
+                    proof::keys::bridge_account_withdrawer_address(address),
+                    [Some(proof::values::bridge_account_address(
+                        info.withdrawer_address.as_bytes(),
+                    ))],
+                ),
+            ],
+        }
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .map_err(Error::proof_verification)?;
+        Ok(Proven {
+            response,
+            values,
+        })
     }
 
     async fn get_bridge_account_last_transaction_hash(
@@ -718,3 +925,209 @@ pub trait SequencerClientExt: Client {
         }
     }
 }
+
+fn decode_balance_response(
+    response: tendermint_rpc::endpoint::abci_query::AbciQuery,
+) -> Result<BalanceResponse, Error> {
+    let proto_response =
+        astria_core::generated::astria::protocol::accounts::v1::BalanceResponse::decode(
+            &*response.value,
+        )
+        .map_err(|e| {
+            Error::abci_query_deserialization("astria.sequencer.v1.BalanceResponse", response, e)
+        })?;
+    BalanceResponse::try_from_raw(&proto_response)
+        .map_err(|e| Error::native_conversion("BalanceResponse", Arc::new(e)))
+}
+
+fn decode_nonce_response(
+    response: tendermint_rpc::endpoint::abci_query::AbciQuery,
+) -> Result<NonceResponse, Error> {
+    let proto_response =
+        astria_core::generated::astria::protocol::accounts::v1::NonceResponse::decode(
+            &*response.value,
+        )
+        .map_err(|e| {
+            Error::abci_query_deserialization("astria.sequencer.v1.NonceResponse", response, e)
+        })?;
+    Ok(proto_response.to_native())
+}
+
+fn decode_bridge_account_info_response(
+    response: tendermint_rpc::endpoint::abci_query::AbciQuery,
+) -> Result<BridgeAccountInfoResponse, Error> {
+    let proto_response =
+        astria_core::generated::astria::protocol::bridge::v1::BridgeAccountInfoResponse::decode(
+            &*response.value,
+        )
+        .map_err(|e| {
+            Error::abci_query_deserialization(
+                "astria.protocol.bridge.v1.BridgeAccountInfoResponse",
+                response,
+                e,
+            )
+        })?;
+    BridgeAccountInfoResponse::try_from_raw(proto_response).map_err(|e| {
+        Error::native_conversion(
+            "astria.protocol.bridge.v1.BridgeAccountInfoResponse",
+            Arc::new(e),
+        )
+    })
+}
+
+/// Queries `path` at the state committed to by `header` and returns the response together with
+/// the proofs it contains.
+///
+/// The proofs are only decoded; each value of the response must be verified with
+/// [`ResponseProofs::prove`].
+async fn abci_query_with_proof<C>(
+    client: &C,
+    path: String,
+    header: &tendermint::block::Header,
+) -> Result<
+    (
+        tendermint_rpc::endpoint::abci_query::AbciQuery,
+        ResponseProofs,
+    ),
+    Error,
+>
+where
+    C: Client + Sync + ?Sized,
+{
+    // A query height of 0 is interpreted as the latest height, so the header must at least be at
+    // height 2 to commit to the state of a specific height.
+    let query_height = header
+        .height
+        .value()
+        .checked_sub(1)
+        .filter(|height| *height > 0)
+        .and_then(|height| Height::try_from(height).ok())
+        .ok_or_else(|| {
+            Error::proof_verification(ProofVerificationError::HeaderTooLow {
+                header_height: header.height,
+            })
+        })?;
+
+    let response = client
+        .abci_query(Some(path), vec![], Some(query_height), true)
+        .await
+        .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
+    let proofs = ResponseProofs::decode(&response, header).map_err(Error::proof_verification)?;
+    Ok((response, proofs))
+}
+
+/// The merkle proofs contained in an abci query response, keyed by the storage key they prove.
+struct ResponseProofs {
+    root: ibc_types::core::commitment::MerkleRoot,
+    proofs: Vec<(String, ibc_types::core::commitment::MerkleProof)>,
+}
+
+impl ResponseProofs {
+    /// Decodes the proof operations contained in `response`, which must be the response for the
+    /// state committed to by `header`.
+    fn decode(
+        response: &tendermint_rpc::endpoint::abci_query::AbciQuery,
+        header: &tendermint::block::Header,
+    ) -> Result<Self, ProofVerificationError> {
+        use ibc_types::{
+            core::commitment::{
+                MerkleProof,
+                MerkleRoot,
+            },
+            DomainType as _,
+        };
+
+        if response.height.value().checked_add(1) != Some(header.height.value()) {
+            return Err(ProofVerificationError::HeightMismatch {
+                response_height: response.height,
+                header_height: header.height,
+            });
+        }
+        let proof_ops = response
+            .proof
+            .as_ref()
+            .ok_or(ProofVerificationError::MissingProof)?;
+
+        let mut proofs = Vec::with_capacity(proof_ops.ops.len());
+        for op in &proof_ops.ops {
+            let key =
+                String::from_utf8(op.key.clone()).map_err(ProofVerificationError::NonUtf8Key)?;
+            if op.field_type != proof::PROOF_OP_TYPE {
+                return Err(ProofVerificationError::UnsupportedType {
+                    key,
+                    field_type: op.field_type.clone(),
+                });
+            }
+            let proof = MerkleProof::decode(&*op.data).map_err(|source| {
+                ProofVerificationError::Decode {
+                    key: key.clone(),
+                    source: source.into(),
+                }
+            })?;
+            if proof.proofs.len() != 1 {
+                return Err(ProofVerificationError::UnexpectedProofCount {
+                    key,
+                });
+            }
+            proofs.push((key, proof));
+        }
+        Ok(Self {
+            root: MerkleRoot {
+                hash: header.app_hash.as_bytes().to_vec(),
+            },
+            proofs,
+        })
+    }
+
+    /// Verifies that the value stored under `key` is one of `candidates` against the app hash,
+    /// where `None` stands for no value being stored.
+    ///
+    /// The candidates are the stored encodings of the value read from the response, so that a
+    /// successful verification proves the response itself.
+    fn prove<I>(&self, key: String, candidates: I) -> Result<ProvenValue, ProofVerificationError>
+    where
+        I: IntoIterator<Item = Option<Vec<u8>>>,
+    {
+        use ibc_types::core::commitment::MerklePath;
+
+        let Some((_, proof)) = self
+            .proofs
+            .iter()
+            .find(|(proven_key, _)| *proven_key == key)
+        else {
+            return Err(ProofVerificationError::MissingKey {
+                key,
+            });
+        };
+        let specs = [cnidarium::ics23_spec()];
+        let path = MerklePath {
+            key_path: vec![key.clone()],
+        };
+        let mut reason = "no candidate value".to_string();
+        for value in candidates {
+            let verified = match &value {
+                Some(value) => proof.verify_membership(
+                    &specs,
+                    self.root.clone(),
+                    path.clone(),
+                    value.clone(),
+                    0,
+                ),
+                None => proof.verify_non_membership(&specs, self.root.clone(), path.clone()),
+            };
+            match verified {
+                Ok(()) => {
+                    return Ok(ProvenValue {
+                        key,
+                        value,
+                    });
+                }
+                Err(error) => reason = error.to_string(),
+            }
+        }
+        Err(ProofVerificationError::Invalid {
+            key,
+            reason,
+        })
+    }
+}
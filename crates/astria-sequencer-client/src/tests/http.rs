@@ -15,7 +15,10 @@ use astria_core::{
     Protobuf as _,
 };
 use hex_literal::hex;
-use prost::bytes::Bytes;
+use prost::{
+    bytes::Bytes,
+    Message as _,
+};
 use serde_json::json;
 use tendermint::{
     abci::{
@@ -45,6 +48,10 @@ use wiremock::{
 };
 
 use crate::{
+    extension_trait::{
+        ErrorKind,
+        ProofVerificationError,
+    },
     tendermint_rpc::endpoint::broadcast::tx_sync,
     HttpClient,
     SequencerClientExt as _,
@@ -88,12 +95,21 @@ async fn register_abci_query_response(
     server: &MockServer,
     query_path: &str,
     raw: impl prost::Message,
+) -> MockGuard {
+    let response = tendermint_rpc::endpoint::abci_query::AbciQuery {
+        value: raw.encode_to_vec(),
+        ..Default::default()
+    };
+    register_abci_query(server, query_path, response).await
+}
+
+async fn register_abci_query(
+    server: &MockServer,
+    query_path: &str,
+    response: tendermint_rpc::endpoint::abci_query::AbciQuery,
 ) -> MockGuard {
     let response = tendermint_rpc::endpoint::abci_query::Response {
-        response: tendermint_rpc::endpoint::abci_query::AbciQuery {
-            value: raw.encode_to_vec(),
-            ..Default::default()
-        },
+        response,
     };
     let wrapper = Wrapper::new_with_id(Id::Num(1), Some(response), None);
     Mock::given(body_partial_json(json!({
@@ -291,6 +307,271 @@ async fn get_bridge_account_info() {
     assert_eq!(expected_response, actual_response);
 }
 
+fn header(height: u32) -> tendermint::block::Header {
+    tendermint::block::Header {
+        version: tendermint::block::header::Version {
+            block: 1,
+            app: 1,
+        },
+        chain_id: "test".try_into().unwrap(),
+        height: height.into(),
+        time: tendermint::time::Time::from_unix_timestamp(1, 1).unwrap(),
+        last_block_id: None,
+        last_commit_hash: None,
+        data_hash: None,
+        validators_hash: Hash::Sha256([0; 32]),
+        next_validators_hash: Hash::Sha256([0; 32]),
+        consensus_hash: Hash::Sha256([0; 32]),
+        app_hash: tendermint::AppHash::default(),
+        last_results_hash: None,
+        evidence_hash: None,
+        proposer_address: tendermint::account::Id::new([0; 20]),
+    }
+}
+
+#[tokio::test]
+async fn get_nonce_with_proof_rejects_header_at_first_height() {
+    let MockSequencer {
+        client, ..
+    } = MockSequencer::start().await;
+
+    let error = client
+        .get_nonce_with_proof(alice_address(), &header(1))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            error.kind(),
+            ErrorKind::ProofVerification(ProofVerificationError::HeaderTooLow { .. })
+        ),
+        "{error:?}"
+    );
+}
+
+#[tokio::test]
+async fn get_nonce_with_proof_rejects_response_at_wrong_height() {
+    use astria_core::generated::astria::protocol::accounts::v1::NonceResponse;
+    let MockSequencer {
+        server,
+        client,
+    } = MockSequencer::start().await;
+
+    let response = tendermint_rpc::endpoint::abci_query::AbciQuery {
+        value: NonceResponse {
+            height: 10,
+            nonce: 1,
+        }
+        .encode_to_vec(),
+        height: 10u32.into(),
+        ..Default::default()
+    };
+    let _guard = register_abci_query(
+        &server,
+        &format!("accounts/nonce/{}", alice_address()),
+        response,
+    )
+    .await;
+
+    let error = client
+        .get_nonce_with_proof(alice_address(), &header(10))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            error.kind(),
+            ErrorKind::ProofVerification(ProofVerificationError::HeightMismatch { .. })
+        ),
+        "{error:?}"
+    );
+}
+
+#[tokio::test]
+async fn get_nonce_with_proof_rejects_response_without_proof() {
+    use astria_core::generated::astria::protocol::accounts::v1::NonceResponse;
+    let MockSequencer {
+        server,
+        client,
+    } = MockSequencer::start().await;
+
+    let response = tendermint_rpc::endpoint::abci_query::AbciQuery {
+        value: NonceResponse {
+            height: 9,
+            nonce: 1,
+        }
+        .encode_to_vec(),
+        height: 9u32.into(),
+        ..Default::default()
+    };
+    let _guard = register_abci_query(
+        &server,
+        &format!("accounts/nonce/{}", alice_address()),
+        response,
+    )
+    .await;
+
+    let error = client
+        .get_nonce_with_proof(alice_address(), &header(10))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            error.kind(),
+            ErrorKind::ProofVerification(ProofVerificationError::MissingProof)
+        ),
+        "{error:?}"
+    );
+}
+
+#[tokio::test]
+async fn get_nonce_with_proof_rejects_unsupported_proof_type() {
+    use astria_core::generated::astria::protocol::accounts::v1::NonceResponse;
+    let MockSequencer {
+        server,
+        client,
+    } = MockSequencer::start().await;
+
+    let response = tendermint_rpc::endpoint::abci_query::AbciQuery {
+        value: NonceResponse {
+            height: 9,
+            nonce: 1,
+        }
+        .encode_to_vec(),
+        height: 9u32.into(),
+        proof: Some(merkle::proof::ProofOps {
+            ops: vec![merkle::proof::ProofOp {
+                field_type: "ics23:iavl".to_string(),
+                key: b"accounts/nonce".to_vec(),
+                data: vec![],
+            }],
+        }),
+        ..Default::default()
+    };
+    let _guard = register_abci_query(
+        &server,
+        &format!("accounts/nonce/{}", alice_address()),
+        response,
+    )
+    .await;
+
+    let error = client
+        .get_nonce_with_proof(alice_address(), &header(10))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            error.kind(),
+            ErrorKind::ProofVerification(ProofVerificationError::UnsupportedType { .. })
+        ),
+        "{error:?}"
+    );
+}
+
+/// Commits `nonce` as the nonce of alice, returning the proof of it together with a header
+/// committing to the resulting state.
+async fn prove_nonce(nonce: u32) -> (merkle::proof::ProofOps, tendermint::block::Header) {
+    use astria_core::protocol::proof;
+    use cnidarium::{
+        StateDelta,
+        StateWrite as _,
+    };
+
+    let storage = cnidarium::TempStorage::new().await.unwrap();
+    let key = proof::keys::nonce(alice_address().as_bytes());
+    let mut state = StateDelta::new(storage.latest_snapshot());
+    state.put_raw(key.clone(), proof::values::nonce(nonce));
+    storage.commit(state).await.unwrap();
+
+    let snapshot = storage.latest_snapshot();
+    let (_, merkle_proof) = snapshot
+        .get_with_proof(key.clone().into_bytes())
+        .await
+        .unwrap();
+    let proof_ops = merkle::proof::ProofOps {
+        ops: vec![merkle::proof::ProofOp {
+            field_type: proof::PROOF_OP_TYPE.to_string(),
+            key: key.into_bytes(),
+            data: merkle_proof.encode_to_vec(),
+        }],
+    };
+    let mut header = header(10);
+    header.app_hash = snapshot
+        .root_hash()
+        .await
+        .unwrap()
+        .0
+        .to_vec()
+        .try_into()
+        .unwrap();
+    (proof_ops, header)
+}
+
+async fn register_nonce_with_proof(
+    server: &MockServer,
+    nonce: u32,
+    proof: merkle::proof::ProofOps,
+) -> MockGuard {
+    use astria_core::generated::astria::protocol::accounts::v1::NonceResponse;
+
+    let response = tendermint_rpc::endpoint::abci_query::AbciQuery {
+        value: NonceResponse {
+            height: 9,
+            nonce,
+        }
+        .encode_to_vec(),
+        height: 9u32.into(),
+        proof: Some(proof),
+        ..Default::default()
+    };
+    register_abci_query(
+        server,
+        &format!("accounts/nonce/{}", alice_address()),
+        response,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn get_nonce_with_proof_accepts_proven_nonce() {
+    let MockSequencer {
+        server,
+        client,
+    } = MockSequencer::start().await;
+    let (proof, header) = prove_nonce(3).await;
+    let _guard = register_nonce_with_proof(&server, 3, proof).await;
+
+    let proven = client
+        .get_nonce_with_proof(alice_address(), &header)
+        .await
+        .unwrap();
+    assert_eq!(3, proven.response().nonce);
+    assert_eq!(
+        Some(&*astria_core::protocol::proof::values::nonce(3)),
+        proven.values()[0].value()
+    );
+}
+
+#[tokio::test]
+async fn get_nonce_with_proof_rejects_nonce_differing_from_proven_value() {
+    let MockSequencer {
+        server,
+        client,
+    } = MockSequencer::start().await;
+    let (proof, header) = prove_nonce(3).await;
+    let _guard = register_nonce_with_proof(&server, 4, proof).await;
+
+    let error = client
+        .get_nonce_with_proof(alice_address(), &header)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            error.kind(),
+            ErrorKind::ProofVerification(ProofVerificationError::Invalid { .. })
+        ),
+        "{error:?}"
+    );
+}
+
 #[tokio::test]
 async fn get_bridge_account_last_transaction_hash() {
     use astria_core::generated::astria::protocol::bridge::v1::BridgeAccountLastTxHashResponse;
@@ -5,6 +5,7 @@ use std::{
 };
 
 use astria_core::primitive::v1::RollupId;
+use astria_data_availability::Backend;
 use astria_eyre::eyre::{
     self,
     WrapErr,
@@ -27,6 +28,10 @@ pub struct Config {
     pub sequencer_grpc_endpoint: String,
     pub celestia_app_grpc_endpoint: String,
     pub celestia_app_key_file: String,
+    /// The data availability layer to which sequencer blocks are submitted.
+    pub da_backend: Backend,
+    /// The directory used as data availability layer if `da_backend` is `Local`.
+    pub local_da_dir: String,
     pub block_time: u64,
     // Would ideally be private; accessed via the public getter which converts this to a collection
     // of `RollupId`s.  Left public for integration tests.
@@ -5,8 +5,13 @@ use std::{
 };
 
 use astria_core::generated::astria::sequencerblock::v1::sequencer_service_client::SequencerServiceClient;
+use astria_data_availability::{
+    Backend,
+    LocalDa,
+};
 use astria_eyre::eyre::{
     self,
+    ensure,
     WrapErr as _,
 };
 use sequencer_client::HttpClient as SequencerClient;
@@ -17,6 +22,7 @@ use tonic::transport::{
 
 use super::{
     state::State,
+    write::DataAvailability,
     CelestiaClientBuilder,
     CelestiaKeys,
 };
@@ -31,6 +37,8 @@ pub(crate) struct Builder {
     pub(crate) celestia_chain_id: String,
     pub(crate) celestia_app_grpc_endpoint: String,
     pub(crate) celestia_app_key_file: String,
+    pub(crate) da_backend: Backend,
+    pub(crate) local_da_dir: String,
     pub(crate) cometbft_endpoint: String,
     pub(crate) sequencer_poll_period: Duration,
     pub(crate) sequencer_grpc_endpoint: String,
@@ -48,6 +56,8 @@ impl Builder {
             celestia_chain_id,
             celestia_app_grpc_endpoint,
             celestia_app_key_file,
+            da_backend,
+            local_da_dir,
             cometbft_endpoint,
             sequencer_poll_period,
             sequencer_grpc_endpoint,
@@ -71,14 +81,33 @@ impl Builder {
 
         let state = Arc::new(State::new());
 
-        let celestia_client_builder = {
-            let uri: Uri = celestia_app_grpc_endpoint
-                .parse()
-                .wrap_err("failed parsing provided celestia app grpc endpoint as Uri")?;
-            let celestia_keys = CelestiaKeys::from_path(celestia_app_key_file)
-                .wrap_err("failed to get celestia keys from file")?;
-            CelestiaClientBuilder::new(celestia_chain_id, uri, celestia_keys, state.clone())
-                .wrap_err("failed to create celestia client builder")?
+        let data_availability = match da_backend {
+            Backend::Celestia => {
+                let uri: Uri = celestia_app_grpc_endpoint
+                    .parse()
+                    .wrap_err("failed parsing provided celestia app grpc endpoint as Uri")?;
+                let celestia_keys = CelestiaKeys::from_path(celestia_app_key_file)
+                    .wrap_err("failed to get celestia keys from file")?;
+                let client_builder = CelestiaClientBuilder::new(
+                    celestia_chain_id,
+                    uri,
+                    celestia_keys,
+                    state.clone(),
+                )
+                .wrap_err("failed to create celestia client builder")?;
+                DataAvailability::Celestia(client_builder)
+            }
+            Backend::Local => {
+                ensure!(
+                    !local_da_dir.is_empty(),
+                    "a local data availability directory must be set if the local backend is \
+                     selected"
+                );
+                DataAvailability::Local {
+                    da: LocalDa::new(local_da_dir),
+                    chain_id: celestia_chain_id,
+                }
+            }
         };
 
         Ok(super::Relayer {
@@ -88,7 +117,7 @@ impl Builder {
             sequencer_cometbft_client,
             sequencer_grpc_client,
             sequencer_poll_period,
-            celestia_client_builder,
+            data_availability,
             rollup_filter,
             state,
             submission_state_path,
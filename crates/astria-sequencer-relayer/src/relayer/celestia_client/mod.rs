@@ -71,7 +71,12 @@ use astria_core::generated::{
         BlobTx,
     },
 };
+use astria_data_availability::{
+    BlobWriter,
+    SubmissionId,
+};
 use astria_eyre::eyre::Report;
+use async_trait::async_trait;
 pub(super) use builder::{
     Builder as CelestiaClientBuilder,
     BuilderError,
@@ -775,6 +780,43 @@ fn new_signed_tx(
     }
 }
 
+#[async_trait]
+impl BlobWriter for CelestiaClient {
+    type Error = TrySubmitError;
+    type Prepared = BlobTxAndFee;
+
+    async fn prepare(
+        &mut self,
+        blobs: Arc<Vec<Blob>>,
+        last_error: Option<TrySubmitError>,
+    ) -> Result<BlobTxAndFee, TrySubmitError> {
+        self.try_prepare(blobs, last_error).await
+    }
+
+    fn submission_id(prepared: &BlobTxAndFee) -> SubmissionId {
+        BlobTxHash::compute(&prepared.tx).into()
+    }
+
+    fn fee(prepared: &BlobTxAndFee) -> Option<u64> {
+        Some(prepared.fee)
+    }
+
+    async fn submit(&mut self, prepared: BlobTxAndFee) -> Result<u64, TrySubmitError> {
+        let blob_tx_hash = BlobTxHash::compute(&prepared.tx);
+        self.try_submit(blob_tx_hash, prepared.tx).await
+    }
+
+    /// A broadcast that timed out might still have been accepted by the Celestia app.
+    fn is_outcome_unknown(error: &TrySubmitError) -> bool {
+        matches!(error, TrySubmitError::FailedToBroadcastTx(error) if error.is_timeout())
+    }
+
+    async fn confirm_inclusion(&mut self, id: SubmissionId, timeout: Duration) -> Option<u64> {
+        self.confirm_submission_with_timeout(&id.into(), timeout)
+            .await
+    }
+}
+
 pub(in crate::relayer) struct BlobTxAndFee {
     pub(in crate::relayer) tx: BlobTx,
     pub(in crate::relayer) fee: u64,
@@ -835,6 +877,18 @@ impl BlobTxHash {
     }
 }
 
+impl From<SubmissionId> for BlobTxHash {
+    fn from(id: SubmissionId) -> Self {
+        Self(id.get())
+    }
+}
+
+impl From<BlobTxHash> for SubmissionId {
+    fn from(hash: BlobTxHash) -> Self {
+        Self::new(hash.0)
+    }
+}
+
 impl Display for BlobTxHash {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         write!(formatter, "{}", hex(&self.0))
@@ -68,7 +68,6 @@ use celestia_client::{
     BuilderError,
     CelestiaClientBuilder,
     CelestiaKeys,
-    TrySubmitError,
 };
 use state::State;
 pub(crate) use state::StateSnapshot;
@@ -110,8 +109,8 @@ pub(crate) struct Relayer {
     /// The poll period defines the fixed interval at which the sequencer is polled.
     sequencer_poll_period: Duration,
 
-    /// The gRPC client for submitting sequencer blocks to celestia.
-    celestia_client_builder: CelestiaClientBuilder,
+    /// The data availability layer to submit sequencer blocks to.
+    data_availability: write::DataAvailability,
 
     /// The rollups whose data should be included in submissions.
     rollup_filter: IncludeRollup,
@@ -157,7 +156,7 @@ impl Relayer {
         };
 
         let (mut submitter_task, submitter) = spawn_submitter(
-            self.celestia_client_builder.clone(),
+            self.data_availability.clone(),
             self.rollup_filter.clone(),
             self.state.clone(),
             submission_state_at_startup,
@@ -372,7 +371,7 @@ async fn fetch_sequencer_chain_id(
 }
 
 fn spawn_submitter(
-    client_builder: CelestiaClientBuilder,
+    data_availability: write::DataAvailability,
     rollup_filter: IncludeRollup,
     state: Arc<State>,
     submission_state_at_startup: SubmissionStateAtStartup,
@@ -383,7 +382,7 @@ fn spawn_submitter(
     write::BlobSubmitterHandle,
 ) {
     let (submitter, handle) = write::BlobSubmitter::new(
-        client_builder,
+        data_availability,
         rollup_filter,
         state,
         submission_state_at_startup,
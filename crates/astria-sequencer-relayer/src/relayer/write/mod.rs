@@ -1,8 +1,8 @@
 //! A task writing sequencer blocks to Celestia.
 //!
 //! [`BlobSubmitter`] receives [`SequencerBlock`]s over a channel,
-//! converts them to Celestia [`Blob`]s, and writes them to Celestia
-//! using the `blob.Submit` API.
+//! converts them to Celestia [`Blob`]s, and writes them to the
+//! configured data availability layer using its [`BlobWriter`].
 //!
 //! [`BlobSubmitter`] submits converted blobs strictly in the order it
 //! receives blocks and imposes no extra ordering. This means that if
@@ -15,6 +15,10 @@ use std::{
     time::Duration,
 };
 
+use astria_data_availability::{
+    BlobWriter,
+    LocalDa,
+};
 use astria_eyre::eyre::{
     self,
     bail,
@@ -67,7 +71,6 @@ use super::{
     PreparedSubmission,
     StartedSubmission,
     SubmissionStateAtStartup,
-    TrySubmitError,
 };
 use crate::{
     metrics::Metrics,
@@ -84,6 +87,15 @@ struct StartedSubmissionAndFee {
     fee: Option<u64>,
 }
 
+/// The data availability layer that blobs are submitted to.
+#[derive(Clone)]
+pub(super) enum DataAvailability {
+    /// A Celestia network, written to through the client built by the contained builder.
+    Celestia(CelestiaClientBuilder),
+    /// A local directory, which is initialized with the given Celestia chain ID.
+    Local { da: LocalDa, chain_id: String },
+}
+
 #[derive(Clone)]
 pub(super) struct BlobSubmitterHandle {
     tx: mpsc::Sender<Box<SequencerBlock>>,
@@ -112,8 +124,8 @@ impl BlobSubmitterHandle {
 }
 
 pub(super) struct BlobSubmitter {
-    /// The builder for a client to submit blobs to Celestia.
-    client_builder: CelestiaClientBuilder,
+    /// The data availability layer to submit blobs to.
+    data_availability: DataAvailability,
 
     /// The channel over which sequencer blocks are received.
     blocks: mpsc::Receiver<Box<SequencerBlock>>,
@@ -140,7 +152,7 @@ pub(super) struct BlobSubmitter {
 
 impl BlobSubmitter {
     pub(super) fn new(
-        client_builder: CelestiaClientBuilder,
+        data_availability: DataAvailability,
         rollup_filter: IncludeRollup,
         state: Arc<super::State>,
         submission_state_at_startup: SubmissionStateAtStartup,
@@ -151,7 +163,7 @@ impl BlobSubmitter {
         // heuristic about the number of expected blobs in a block.
         let (tx, rx) = mpsc::channel(128);
         let submitter = Self {
-            client_builder,
+            data_availability,
             blocks: rx,
             next_submission: NextSubmission::new(rollup_filter, metrics),
             state,
@@ -166,17 +178,37 @@ impl BlobSubmitter {
         (submitter, handle)
     }
 
-    pub(super) async fn run(mut self) -> eyre::Result<()> {
-        let init_result = select!(
-            () = self.submitter_shutdown_token.cancelled() => return Ok(()),
-            init_result = init_with_retry(self.client_builder.clone()) => init_result,
-        );
-        let client = init_result.map_err(|error| {
-            let message = "failed to initialize celestia client";
-            report_exit(&Err(eyre!(error.to_string())), message);
-            error.wrap_err(message)
-        })?;
+    pub(super) async fn run(self) -> eyre::Result<()> {
+        match self.data_availability.clone() {
+            DataAvailability::Celestia(client_builder) => {
+                let init_result = select!(
+                    () = self.submitter_shutdown_token.cancelled() => return Ok(()),
+                    init_result = init_with_retry(client_builder) => init_result,
+                );
+                let client = init_result.map_err(|error| {
+                    let message = "failed to initialize celestia client";
+                    report_exit(&Err(eyre!(error.to_string())), message);
+                    error.wrap_err(message)
+                })?;
+                self.run_with_client(client).await
+            }
+            DataAvailability::Local {
+                da,
+                chain_id,
+            } => {
+                da.init(&chain_id).await.map_err(|error| {
+                    let message = "failed to initialize local data availability layer";
+                    report_exit(&Err(eyre!(error.to_string())), message);
+                    Report::new(error).wrap_err(message)
+                })?;
+                info!(dir = %da.dir().display(), "initialized local data availability layer");
+                self.state.set_celestia_connected(true);
+                self.run_with_client(da).await
+            }
+        }
+    }
 
+    async fn run_with_client<C: BlobWriter>(mut self, client: C) -> eyre::Result<()> {
         let Some(submission_state_at_startup) = self.submission_state_at_startup.take() else {
             bail!("submission state must be provided at startup");
         };
@@ -308,8 +340,8 @@ impl BlobSubmitter {
 /// submission if confirmed by Celestia, or holding the heights of the last known confirmed
 /// submission in the case of timing out.
 #[instrument(skip_all, err)]
-async fn try_confirm_submission_from_last_session(
-    mut client: CelestiaClient,
+async fn try_confirm_submission_from_last_session<C: BlobWriter>(
+    mut client: C,
     prepared_submission: PreparedSubmission,
     state: Arc<super::State>,
     metrics: &'static Metrics,
@@ -319,7 +351,7 @@ async fn try_confirm_submission_from_last_session(
 
     let timeout = prepared_submission.confirmation_timeout();
     let new_state = if let Some(celestia_height) = client
-        .confirm_submission_with_timeout(blob_tx_hash, timeout)
+        .confirm_inclusion((*blob_tx_hash).into(), timeout)
         .await
     {
         info!(%celestia_height, "confirmed previous session submitted blobs to Celestia");
@@ -349,8 +381,8 @@ async fn try_confirm_submission_from_last_session(
 
 /// Submits new blobs Celestia.
 #[instrument(skip_all, err)]
-async fn submit_blobs(
-    client: CelestiaClient,
+async fn submit_blobs<C: BlobWriter>(
+    client: C,
     data: conversion::Submission,
     state: Arc<super::State>,
     started_submission: StartedSubmission,
@@ -459,9 +491,9 @@ async fn init_with_retry(client_builder: CelestiaClientBuilder) -> eyre::Result<
 }
 
 #[derive(Error, Clone, Debug)]
-enum SubmissionError {
+enum SubmissionError<E> {
     #[error(transparent)]
-    TrySubmit(#[from] TrySubmitError),
+    TrySubmit(E),
     #[error("unrecoverable submission error")]
     Unrecoverable(#[source] Arc<Report>),
     #[error("broadcast tx timed out")]
@@ -469,8 +501,8 @@ enum SubmissionError {
 }
 
 #[instrument(skip_all)]
-async fn submit_with_retry(
-    client: CelestiaClient,
+async fn submit_with_retry<C: BlobWriter>(
+    client: C,
     blobs: Vec<Blob>,
     state: Arc<super::State>,
     started_submission: StartedSubmission,
@@ -482,14 +514,14 @@ async fn submit_with_retry(
     let span = Span::current();
 
     // Create a watch channel to allow the `on_retry` function to provide the received
-    // `TrySubmitError` to the next attempt of the `retry_fn`.
+    // submission error to the next attempt of the `retry_fn`.
     let (last_error_sender, last_error_receiver) = watch::channel(None);
 
     let initial_retry_delay = Duration::from_millis(100);
     let retry_config = tryhard::RetryFutureConfig::new(u32::MAX)
         // 12 seconds is the Celestia block time.
         .max_delay(Duration::from_secs(12))
-        .custom_backoff(|attempt: u32, error: &SubmissionError| {
+        .custom_backoff(|attempt: u32, error: &SubmissionError<C::Error>| {
             if matches!(error, SubmissionError::Unrecoverable(_)) {
                 return tryhard::RetryPolicy::Break;
             }
@@ -500,7 +532,7 @@ async fn submit_with_retry(
             tryhard::RetryPolicy::Delay(delay)
         })
         .on_retry(
-            |attempt: u32, next_delay: Option<Duration>, error: &SubmissionError| {
+            |attempt: u32, next_delay: Option<Duration>, error: &SubmissionError<C::Error>| {
                 metrics.increment_celestia_submission_failure_count();
 
                 let state = Arc::clone(&state);
@@ -541,13 +573,13 @@ async fn submit_with_retry(
 }
 
 #[instrument(skip_all, err(level = Level::WARN))]
-async fn try_submit(
-    mut client: CelestiaClient,
+async fn try_submit<C: BlobWriter>(
+    mut client: C,
     blobs: Arc<Vec<Blob>>,
     started_submission: StartedSubmission,
     largest_sequencer_height: SequencerHeight,
-    last_error_receiver: watch::Receiver<Option<SubmissionError>>,
-) -> Result<StartedSubmissionAndFee, SubmissionError> {
+    last_error_receiver: watch::Receiver<Option<SubmissionError<C::Error>>>,
+) -> Result<StartedSubmissionAndFee, SubmissionError<C::Error>> {
     // Get the error from the last attempt to `try_submit`.
     let maybe_last_error = last_error_receiver.borrow().clone();
     let maybe_try_submit_error = match maybe_last_error {
@@ -571,25 +603,28 @@ async fn try_submit(
         None => None,
     };
 
-    let blob_tx_and_fee = client.try_prepare(blobs, maybe_try_submit_error).await?;
-    let blob_tx_hash = BlobTxHash::compute(&blob_tx_and_fee.tx);
+    let prepared = client
+        .prepare(blobs, maybe_try_submit_error)
+        .await
+        .map_err(SubmissionError::TrySubmit)?;
+    let blob_tx_hash = BlobTxHash::from(C::submission_id(&prepared));
 
     let prepared_submission = started_submission
         .into_prepared(largest_sequencer_height, blob_tx_hash)
         .await
         .map_err(|error| SubmissionError::Unrecoverable(Arc::new(error)))?;
 
-    let fee = blob_tx_and_fee.fee;
-    match client.try_submit(blob_tx_hash, blob_tx_and_fee.tx).await {
+    let fee = C::fee(&prepared);
+    match client.submit(prepared).await {
         Ok(celestia_height) => prepared_submission
             .into_started(celestia_height)
             .await
             .map(|new_state| StartedSubmissionAndFee {
                 new_state,
-                fee: Some(fee),
+                fee,
             })
             .map_err(|error| SubmissionError::Unrecoverable(Arc::new(error))),
-        Err(TrySubmitError::FailedToBroadcastTx(error)) if error.is_timeout() => {
+        Err(error) if C::is_outcome_unknown(&error) => {
             Err(SubmissionError::BroadcastTxTimedOut(prepared_submission))
         }
         Err(error) => Err(SubmissionError::TrySubmit(error)),
@@ -602,15 +637,18 @@ async fn try_submit(
 /// This should only be called where submission state is `Prepared`, meaning we don't yet
 /// know whether that previous submission attempt succeeded or not.
 #[instrument(skip_all, err)]
-async fn try_confirm_submission_from_failed_attempt(
-    mut client: CelestiaClient,
+async fn try_confirm_submission_from_failed_attempt<C: BlobWriter>(
+    mut client: C,
     prepared_submission: PreparedSubmission,
-) -> Result<Option<StartedSubmission>, SubmissionError> {
+) -> Result<Option<StartedSubmission>, SubmissionError<C::Error>> {
     let blob_tx_hash = prepared_submission.blob_tx_hash();
     info!(%blob_tx_hash, "confirming submission of last `BlobTx` from previous attempt");
 
     if let Some(celestia_height) = client
-        .confirm_submission_with_timeout(blob_tx_hash, prepared_submission.confirmation_timeout())
+        .confirm_inclusion(
+            (*blob_tx_hash).into(),
+            prepared_submission.confirmation_timeout(),
+        )
         .await
     {
         info!(%celestia_height, "confirmed previous attempt submitted blobs to Celestia");
@@ -61,6 +61,8 @@ impl SequencerRelayer {
             sequencer_grpc_endpoint,
             celestia_app_grpc_endpoint,
             celestia_app_key_file,
+            da_backend,
+            local_da_dir,
             block_time,
             api_addr,
             submission_state_path,
@@ -73,6 +75,8 @@ impl SequencerRelayer {
             celestia_chain_id,
             celestia_app_grpc_endpoint,
             celestia_app_key_file,
+            da_backend,
+            local_da_dir,
             cometbft_endpoint,
             sequencer_poll_period: Duration::from_millis(block_time),
             sequencer_grpc_endpoint,
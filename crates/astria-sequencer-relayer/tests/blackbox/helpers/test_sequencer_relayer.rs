@@ -19,6 +19,7 @@ use astria_core::{
     crypto::SigningKey,
     primitive::v1::RollupId,
 };
+use astria_data_availability::Backend;
 use astria_grpc_mock::MockGuard as GrpcMockGuard;
 use astria_sequencer_relayer::{
     config::Config,
@@ -715,6 +716,8 @@ impl TestSequencerRelayerConfig {
             sequencer_grpc_endpoint,
             celestia_app_grpc_endpoint,
             celestia_app_key_file: celestia_keyfile.path().to_string_lossy().to_string(),
+            da_backend: Backend::Celestia,
+            local_da_dir: String::new(),
             block_time: 1000,
             only_include_rollups,
             api_addr: "0.0.0.0:0".into(),
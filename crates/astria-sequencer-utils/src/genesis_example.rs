@@ -18,6 +18,7 @@ use astria_core::{
             GenesisAppState,
         },
         transaction::v1::action::{
+            Bond,
             BridgeLock,
             BridgeSudoChange,
             BridgeTransfer,
@@ -25,16 +26,25 @@ use astria_core::{
             CurrencyPairsChange,
             FeeAssetChange,
             FeeChange,
+            GovernanceParametersChange,
             IbcRelayerChange,
             IbcSudoChange,
+            Ics20RateLimitChange,
             Ics20Withdrawal,
             InitBridgeAccount,
             MarketsChange,
+            ProposalSubmission,
+            ProposalVote,
             RecoverIbcClient,
+            RegisterMultisigAccount,
             RollupDataSubmission,
+            ScheduledTransfer,
+            StakingParametersChange,
             SudoAddressChange,
             Transfer,
+            Unbond,
             ValidatorUpdate,
+            VoteExtensionParticipationChange,
         },
     },
     Protobuf,
@@ -131,7 +141,29 @@ fn proto_genesis_state() -> astria_core::generated::astria::protocol::genesis::v
             recover_ibc_client: Some(FeeComponents::<RecoverIbcClient>::new(0, 0).to_raw()),
             currency_pairs_change: Some(FeeComponents::<CurrencyPairsChange>::new(0, 0).to_raw()),
             markets_change: Some(FeeComponents::<MarketsChange>::new(0, 0).to_raw()),
+            register_multisig_account: Some(
+                FeeComponents::<RegisterMultisigAccount>::new(0, 0).to_raw(),
+            ),
+            scheduled_transfer: Some(FeeComponents::<ScheduledTransfer>::new(0, 0).to_raw()),
+            ics20_rate_limit_change: Some(
+                FeeComponents::<Ics20RateLimitChange>::new(0, 0).to_raw(),
+            ),
+            vote_extension_participation_change: Some(
+                FeeComponents::<VoteExtensionParticipationChange>::new(0, 0).to_raw(),
+            ),
+            bond: Some(FeeComponents::<Bond>::new(0, 0).to_raw()),
+            unbond: Some(FeeComponents::<Unbond>::new(0, 0).to_raw()),
+            proposal_submission: Some(FeeComponents::<ProposalSubmission>::new(0, 0).to_raw()),
+            proposal_vote: Some(FeeComponents::<ProposalVote>::new(0, 0).to_raw()),
+            staking_parameters_change: Some(
+                FeeComponents::<StakingParametersChange>::new(0, 0).to_raw(),
+            ),
+            governance_parameters_change: Some(
+                FeeComponents::<GovernanceParametersChange>::new(0, 0).to_raw(),
+            ),
         }),
+        staking: None,
+        governance: None,
     }
 }
 
@@ -1,23 +1,43 @@
 use std::sync::Arc;
 
-use astria_core::protocol::genesis::v1::GenesisAppState;
+use astria_core::{
+    primitive::v1::Address,
+    protocol::genesis::v1::GenesisAppState,
+};
 use astria_eyre::eyre::{
     OptionExt as _,
     Result,
     WrapErr as _,
 };
-use tendermint::abci::request::{
-    BeginBlock,
-    EndBlock,
+use tendermint::abci::{
+    request::{
+        BeginBlock,
+        EndBlock,
+    },
+    Event,
+};
+use tracing::{
+    instrument,
+    Level,
 };
-use tracing::instrument;
 
 use crate::{
-    accounts,
+    accounts::{
+        self,
+        Escrow,
+        StateReadExt as _,
+    },
+    address::StateReadExt as _,
+    app::StateReadExt as _,
     assets,
     component::Component,
 };
 
+/// The maximum number of escrows released at the end of a single block.
+///
+/// Further due escrows stay indexed by their release point and are released in later blocks.
+const MAX_ESCROW_RELEASES_PER_BLOCK: usize = 256;
+
 #[derive(Default)]
 pub(crate) struct AccountsComponent;
 
@@ -56,11 +76,210 @@ impl Component for AccountsComponent {
         Ok(())
     }
 
-    #[instrument(name = "AccountsComponent::end_block", skip_all)]
+    #[instrument(name = "AccountsComponent::end_block", skip_all, err(level = Level::WARN))]
     async fn end_block<S: accounts::StateWriteExt + 'static>(
-        _state: &mut Arc<S>,
+        state: &mut Arc<S>,
         _end_block: &EndBlock,
     ) -> Result<()> {
-        Ok(())
+        let state = Arc::get_mut(state)
+            .ok_or_eyre("must only have one reference to the state; this is a bug")?;
+        release_due_escrows(state)
+            .await
+            .wrap_err("failed to release due escrows")
+    }
+}
+
+/// Credits the recipients of the escrows due at the current block height and timestamp, removing
+/// the escrows from state.
+///
+/// At most `MAX_ESCROW_RELEASES_PER_BLOCK` escrows are released; the rest are carried over to the
+/// following blocks, earliest release point first.
+#[instrument(skip_all, err(level = Level::WARN))]
+async fn release_due_escrows<S: accounts::StateWriteExt>(state: &mut S) -> Result<()> {
+    let height = state
+        .get_block_height()
+        .await
+        .wrap_err("failed to read block height from state")?;
+    let unix_timestamp_seconds = state
+        .get_block_timestamp()
+        .await
+        .wrap_err("failed to read block timestamp from state")?
+        .unix_timestamp()
+        .try_into()
+        .wrap_err("block timestamp is before the unix epoch")?;
+    let due_ids = state
+        .get_due_escrow_ids(
+            height,
+            unix_timestamp_seconds,
+            MAX_ESCROW_RELEASES_PER_BLOCK,
+        )
+        .await
+        .wrap_err("failed to read due escrow ids from state")?;
+    for id in due_ids {
+        let escrow = state
+            .get_escrow(id)
+            .await
+            .wrap_err("failed to read escrow from state")?
+            .ok_or_eyre(
+                "escrow is indexed by its release point but does not exist; this is a bug",
+            )?;
+        state
+            .increase_balance(&escrow.to, &escrow.asset, escrow.amount)
+            .await
+            .wrap_err("failed to increase escrow recipient balance")?;
+        state.delete_escrow(&escrow);
+        let to = state
+            .try_base_prefixed(&escrow.to)
+            .await
+            .wrap_err("failed to construct escrow recipient address")?;
+        state.record(construct_escrow_released_event(&escrow, &to));
+    }
+    Ok(())
+}
+
+/// Creates `abci::Event` of kind `escrow_released` for reporting the release of an escrow.
+fn construct_escrow_released_event(escrow: &Escrow, to: &Address) -> Event {
+    Event::new(
+        "escrow_released",
+        [
+            ("escrowId", escrow.id.to_string()),
+            ("to", to.to_string()),
+            ("asset", escrow.asset.to_string()),
+            ("amount", escrow.amount.to_string()),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::protocol::transaction::v1::action::ReleaseCondition;
+    use cnidarium::StateDelta;
+    use tendermint::Time;
+
+    use super::*;
+    use crate::{
+        accounts::StateWriteExt as _,
+        address::StateWriteExt as _,
+        app::StateWriteExt as _,
+        test_utils::{
+            nria,
+            ASTRIA_PREFIX,
+        },
+    };
+
+    #[tokio::test]
+    async fn end_block_releases_due_escrows_only() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        state.put_block_height(10).unwrap();
+        state
+            .put_block_timestamp(Time::from_unix_timestamp(1_000, 0).unwrap())
+            .unwrap();
+
+        let escrow = |id, release_at| Escrow {
+            id,
+            from: [1; 20],
+            to: [2; 20],
+            asset: nria().to_ibc_prefixed(),
+            amount: 100,
+            release_at,
+        };
+        let due_by_height = escrow(0, ReleaseCondition::Height(10));
+        let due_by_timestamp = escrow(1, ReleaseCondition::UnixTimestamp(1_000));
+        let not_due = escrow(2, ReleaseCondition::Height(11));
+        for escrow in [&due_by_height, &due_by_timestamp, &not_due] {
+            state.put_escrow(escrow).unwrap();
+        }
+
+        let mut state = Arc::new(state);
+        AccountsComponent::end_block(
+            &mut state,
+            &EndBlock {
+                height: 10,
+            },
+        )
+        .await
+        .unwrap();
+        let state = Arc::into_inner(state).unwrap();
+
+        assert_eq!(
+            200,
+            state.get_account_balance(&[2; 20], &nria()).await.unwrap()
+        );
+        assert!(state.get_escrow(0).await.unwrap().is_none());
+        assert!(state.get_escrow(1).await.unwrap().is_none());
+        assert_eq!(Some(not_due), state.get_escrow(2).await.unwrap());
+        assert_eq!(
+            vec![2],
+            state.get_account_escrow_ids(&[2; 20]).await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn end_block_carries_over_escrows_beyond_release_limit() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        state.put_block_height(10).unwrap();
+        state
+            .put_block_timestamp(Time::from_unix_timestamp(1_000, 0).unwrap())
+            .unwrap();
+
+        let max_releases = u64::try_from(MAX_ESCROW_RELEASES_PER_BLOCK).unwrap();
+        let escrow_count = max_releases.checked_add(1).unwrap();
+        for id in 0..escrow_count {
+            state
+                .put_escrow(&Escrow {
+                    id,
+                    from: [1; 20],
+                    to: [2; 20],
+                    asset: nria().to_ibc_prefixed(),
+                    amount: 1,
+                    release_at: ReleaseCondition::Height(10),
+                })
+                .unwrap();
+        }
+
+        let mut state = Arc::new(state);
+        AccountsComponent::end_block(
+            &mut state,
+            &EndBlock {
+                height: 10,
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            u128::from(max_releases),
+            state.get_account_balance(&[2; 20], &nria()).await.unwrap()
+        );
+        assert_eq!(
+            vec![max_releases],
+            state.get_account_escrow_ids(&[2; 20]).await.unwrap()
+        );
+
+        // The remaining escrow is released in the next block.
+        Arc::get_mut(&mut state)
+            .unwrap()
+            .put_block_height(11)
+            .unwrap();
+        AccountsComponent::end_block(
+            &mut state,
+            &EndBlock {
+                height: 11,
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            u128::from(escrow_count),
+            state.get_account_balance(&[2; 20], &nria()).await.unwrap()
+        );
+        assert!(state
+            .get_account_escrow_ids(&[2; 20])
+            .await
+            .unwrap()
+            .is_empty());
     }
 }
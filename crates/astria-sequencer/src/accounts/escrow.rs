@@ -0,0 +1,19 @@
+use astria_core::{
+    primitive::v1::{
+        asset::IbcPrefixed,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1::action::ReleaseCondition,
+};
+
+/// Funds moved out of the `from` account by a scheduled transfer, held until they are released to
+/// the `to` account at the end of the first block meeting `release_at`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Escrow {
+    pub(crate) id: u64,
+    pub(crate) from: [u8; ADDRESS_LEN],
+    pub(crate) to: [u8; ADDRESS_LEN],
+    pub(crate) asset: IbcPrefixed,
+    pub(crate) amount: u128,
+    pub(crate) release_at: ReleaseCondition,
+}
@@ -1,4 +1,6 @@
 pub(crate) mod component;
+mod escrow;
+mod multisig;
 pub(crate) mod query;
 mod state_ext;
 pub(crate) mod storage;
@@ -11,6 +13,8 @@ use astria_core::{
     },
     protocol::transaction::v1::Transaction,
 };
+pub(crate) use escrow::Escrow;
+pub(crate) use multisig::MultisigKeySet;
 pub(crate) use state_ext::{
     AssetBalance,
     InsufficientFunds,
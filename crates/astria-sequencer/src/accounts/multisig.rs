@@ -0,0 +1,74 @@
+use std::collections::BTreeSet;
+
+use astria_core::crypto::VerificationKey;
+
+/// The keys authorized to sign transactions on behalf of a multisig account, together with the
+/// number of distinct keys required to do so.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct MultisigKeySet {
+    verification_keys: Vec<VerificationKey>,
+    threshold: u32,
+}
+
+impl MultisigKeySet {
+    pub(crate) fn new(verification_keys: Vec<VerificationKey>, threshold: u32) -> Self {
+        Self {
+            verification_keys,
+            threshold,
+        }
+    }
+
+    pub(crate) fn verification_keys(&self) -> &[VerificationKey] {
+        &self.verification_keys
+    }
+
+    pub(crate) fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// Returns the number of distinct keys of this set among `signers`.
+    ///
+    /// Signers not in the set and repeated signers are not counted.
+    pub(crate) fn count_signers<'a, I>(&self, signers: I) -> u32
+    where
+        I: IntoIterator<Item = &'a VerificationKey>,
+    {
+        let distinct_signers: BTreeSet<_> = signers
+            .into_iter()
+            .filter(|signer| self.verification_keys.contains(signer))
+            .collect();
+        u32::try_from(distinct_signers.len())
+            .expect("number of keys in a multisig key set is bounded by a small constant")
+    }
+
+    /// Returns whether `signers` contain at least `threshold` distinct keys of this set.
+    pub(crate) fn is_met_by<'a, I>(&self, signers: I) -> bool
+    where
+        I: IntoIterator<Item = &'a VerificationKey>,
+    {
+        self.count_signers(signers) >= self.threshold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::crypto::SigningKey;
+
+    use super::*;
+
+    fn key(seed: u8) -> VerificationKey {
+        SigningKey::from([seed; 32]).verification_key()
+    }
+
+    #[test]
+    fn only_distinct_members_are_counted() {
+        let key_set = MultisigKeySet::new(vec![key(1), key(2), key(3)], 2);
+
+        assert_eq!(0, key_set.count_signers(&[key(4)]));
+        assert_eq!(1, key_set.count_signers(&[key(1), key(1)]));
+        assert_eq!(2, key_set.count_signers(&[key(1), key(4), key(3)]));
+
+        assert!(!key_set.is_met_by(&[key(2), key(2), key(5)]));
+        assert!(key_set.is_met_by(&[key(2), key(3)]));
+    }
+}
@@ -5,7 +5,10 @@ use astria_core::{
     },
     protocol::{
         abci::AbciErrorCode,
-        account::v1::AssetBalance,
+        account::v1::{
+            AssetBalance,
+            Escrow,
+        },
     },
 };
 use astria_eyre::eyre::{
@@ -13,11 +16,7 @@ use astria_eyre::eyre::{
     Result,
     WrapErr as _,
 };
-use cnidarium::{
-    Snapshot,
-    StateRead,
-    Storage,
-};
+use cnidarium::StateRead;
 use futures::TryStreamExt as _;
 use prost::Message as _;
 use tendermint::{
@@ -33,10 +32,16 @@ use tracing::{
     Level,
 };
 
+use super::storage::keys;
 use crate::{
     accounts::StateReadExt as _,
+    address::StateReadExt as _,
     app::StateReadExt as _,
     assets::StateReadExt as _,
+    storage::{
+        prove_keys,
+        QueryState,
+    },
 };
 
 #[instrument(skip_all, fields(%asset), err(level = Level::DEBUG))]
@@ -76,17 +81,17 @@ async fn get_trace_prefixed_account_balances<S: StateRead>(
 /// alphabetically by [`asset::Denom`].
 #[instrument(skip_all)]
 pub(crate) async fn balance_request(
-    storage: Storage,
+    snapshot: QueryState,
     request: request::Query,
     params: Vec<(String, String)>,
 ) -> response::Query {
     use astria_core::protocol::account::v1::BalanceResponse;
-    let (address, snapshot, height) = match preprocess_request(&storage, &request, &params).await {
+    let (address, height) = match preprocess_request(&snapshot, &params).await {
         Ok(tup) => tup,
         Err(err_rsp) => return err_rsp,
     };
 
-    let mut balances = match get_trace_prefixed_account_balances(&snapshot, &address).await {
+    let mut balances = match get_trace_prefixed_account_balances(&*snapshot, &address).await {
         Ok(balance) => balance,
         Err(err) => {
             return response::Query {
@@ -101,6 +106,18 @@ pub(crate) async fn balance_request(
 
     balances.sort_unstable_by(|a, b| a.denom.cmp(&b.denom));
 
+    let proof = if request.prove {
+        let keys = balances
+            .iter()
+            .map(|balance| keys::balance(&address, &balance.denom));
+        match prove_keys(&snapshot, keys).await {
+            Ok(proof) => Some(proof),
+            Err(err) => return proof_error_response(&err, height),
+        }
+    } else {
+        None
+    };
+
     let payload = BalanceResponse {
         height: height.value(),
         balances,
@@ -112,6 +129,7 @@ pub(crate) async fn balance_request(
         code: 0.into(),
         key: request.path.clone().into_bytes().into(),
         value: payload,
+        proof,
         height,
         ..response::Query::default()
     }
@@ -119,12 +137,12 @@ pub(crate) async fn balance_request(
 
 #[instrument(skip_all)]
 pub(crate) async fn nonce_request(
-    storage: Storage,
+    snapshot: QueryState,
     request: request::Query,
     params: Vec<(String, String)>,
 ) -> response::Query {
     use astria_core::protocol::account::v1::NonceResponse;
-    let (address, snapshot, height) = match preprocess_request(&storage, &request, &params).await {
+    let (address, height) = match preprocess_request(&snapshot, &params).await {
         Ok(tup) => tup,
         Err(err_rsp) => return err_rsp,
     };
@@ -140,6 +158,14 @@ pub(crate) async fn nonce_request(
             };
         }
     };
+    let proof = if request.prove {
+        match prove_keys(&snapshot, [keys::nonce(&address)]).await {
+            Ok(proof) => Some(proof),
+            Err(err) => return proof_error_response(&err, height),
+        }
+    } else {
+        None
+    };
     let payload = NonceResponse {
         height: height.value(),
         nonce,
@@ -151,41 +177,125 @@ pub(crate) async fn nonce_request(
         code: tendermint::abci::Code::Ok,
         key: request.path.clone().into_bytes().into(),
         value: payload,
+        proof,
         height,
         ..response::Query::default()
     }
 }
 
-#[instrument(skip_all, fields(%height), err(level = Level::DEBUG))]
-async fn get_snapshot_and_height(storage: &Storage, height: Height) -> Result<(Snapshot, Height)> {
-    let snapshot = match height.value() {
-        0 => storage.latest_snapshot(),
-        other => {
-            let version = storage
-                .latest_snapshot()
-                .get_storage_version_by_height(other)
+#[instrument(skip_all, fields(%address), err(level = Level::DEBUG))]
+async fn get_pending_escrows<S: StateRead>(state: &S, address: &Address) -> Result<Vec<Escrow>> {
+    let ids = state
+        .get_account_escrow_ids(address)
+        .await
+        .wrap_err("failed to get escrow ids of account")?;
+    let mut escrows = Vec::with_capacity(ids.len());
+    for id in ids {
+        let escrow = state
+            .get_escrow(id)
+            .await
+            .wrap_err("failed to get escrow")?
+            .ok_or_eyre("escrow is indexed for account but does not exist; this is a bug")?;
+        escrows.push(Escrow {
+            id: escrow.id,
+            from: state
+                .try_base_prefixed(&escrow.from)
+                .await
+                .wrap_err("failed to construct escrow sender address")?,
+            to: state
+                .try_base_prefixed(&escrow.to)
+                .await
+                .wrap_err("failed to construct escrow recipient address")?,
+            denom: ibc_to_trace(state, &escrow.asset)
                 .await
-                .wrap_err("failed to get storage version from height")?;
-            storage
-                .snapshot(version)
-                .ok_or_eyre("failed to get storage at version")?
+                .wrap_err("failed to map ibc prefixed asset to trace prefixed")?
+                .into(),
+            amount: escrow.amount,
+            release_at: escrow.release_at,
+        });
+    }
+    Ok(escrows)
+}
+
+/// Returns the pending escrows sent from or to the provided address, ordered by escrow id.
+#[instrument(skip_all)]
+pub(crate) async fn pending_escrows_request(
+    snapshot: QueryState,
+    request: request::Query,
+    params: Vec<(String, String)>,
+) -> response::Query {
+    use astria_core::protocol::account::v1::PendingEscrowsResponse;
+    let (address, height) = match preprocess_request(&snapshot, &params).await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let escrows = match get_pending_escrows(&*snapshot, &address).await {
+        Ok(escrows) => escrows,
+        Err(err) => {
+            return response::Query {
+                code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+                info: AbciErrorCode::INTERNAL_ERROR.info(),
+                log: format!("failed getting pending escrows for provided address: {err:#}"),
+                height,
+                ..response::Query::default()
+            };
         }
     };
+
+    let proof = if request.prove {
+        let keys = escrows.iter().map(|escrow| keys::escrow(escrow.id));
+        match prove_keys(&snapshot, keys).await {
+            Ok(proof) => Some(proof),
+            Err(err) => return proof_error_response(&err, height),
+        }
+    } else {
+        None
+    };
+
+    let payload = PendingEscrowsResponse {
+        height: height.value(),
+        escrows,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        proof,
+        height,
+        ..response::Query::default()
+    }
+}
+
+fn proof_error_response(err: &astria_eyre::eyre::Report, height: Height) -> response::Query {
+    response::Query {
+        code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+        info: AbciErrorCode::INTERNAL_ERROR.info(),
+        log: format!("failed to create proof for provided address: {err:#}"),
+        height,
+        ..response::Query::default()
+    }
+}
+
+#[instrument(skip_all, err(level = Level::DEBUG))]
+async fn get_height(snapshot: &QueryState) -> Result<Height> {
     let height: Height = snapshot
         .get_block_height()
         .await
         .wrap_err("failed to get block height from snapshot")?
         .try_into()
         .wrap_err("internal u64 block height does not fit into tendermint i64 `Height`")?;
-    Ok((snapshot, height))
+    Ok(height)
 }
 
 #[instrument(skip_all)]
 async fn preprocess_request(
-    storage: &Storage,
-    request: &request::Query,
+    snapshot: &QueryState,
     params: &[(String, String)],
-) -> Result<(Address, Snapshot, Height), response::Query> {
+) -> Result<(Address, Height), response::Query> {
     let Some(address) = params
         .iter()
         .find_map(|(k, v)| (k == "account").then_some(v))
@@ -206,16 +316,16 @@ async fn preprocess_request(
             log: format!("address could not be constructed from provided parameter: {err:#}"),
             ..response::Query::default()
         })?;
-    let (snapshot, height) = match get_snapshot_and_height(storage, request.height).await {
-        Ok(tup) => tup,
+    let height = match get_height(snapshot).await {
+        Ok(height) => height,
         Err(err) => {
             return Err(response::Query {
                 code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
                 info: AbciErrorCode::INTERNAL_ERROR.info(),
-                log: format!("failed to query internal storage for snapshot and height: {err:#}"),
+                log: format!("failed to query snapshot for height: {err:#}"),
                 ..response::Query::default()
             });
         }
     };
-    Ok((address, snapshot, height))
+    Ok((address, height))
 }
@@ -23,7 +23,10 @@ use cnidarium::{
     StateRead,
     StateWrite,
 };
-use futures::Stream;
+use futures::{
+    Stream,
+    TryStreamExt as _,
+};
 use pin_project_lite::pin_project;
 use thiserror::Error;
 use tracing::{
@@ -39,7 +42,11 @@ use super::storage::{
     },
 };
 use crate::{
-    accounts::AddressBytes,
+    accounts::{
+        AddressBytes,
+        Escrow,
+        MultisigKeySet,
+    },
     storage::StoredValue,
 };
 
@@ -184,6 +191,119 @@ pub(crate) trait StateReadExt: StateRead + crate::assets::StateReadExt {
             .and_then(|value| storage::Nonce::try_from(value).map(u32::from))
             .wrap_err("invalid nonce bytes")
     }
+
+    /// Returns the multisig key set of the account, or `None` if it is not a multisig account.
+    #[instrument(skip_all, fields(address = %address.display_address()), err(level = Level::WARN))]
+    async fn get_multisig_key_set<T: AddressBytes>(
+        &self,
+        address: &T,
+    ) -> Result<Option<MultisigKeySet>> {
+        let Some(bytes) = self
+            .get_raw(&keys::multisig(address))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw multisig key set from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::MultisigKeySet::try_from(value).and_then(MultisigKeySet::try_from)
+            })
+            .wrap_err("invalid multisig key set bytes")
+            .map(Some)
+    }
+
+    #[instrument(skip_all, fields(id), err(level = Level::WARN))]
+    async fn get_escrow(&self, id: u64) -> Result<Option<Escrow>> {
+        let Some(bytes) = self
+            .get_raw(&keys::escrow(id))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw escrow from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::Escrow::try_from(value).map(Escrow::from))
+            .wrap_err("invalid escrow bytes")
+            .map(Some)
+    }
+
+    /// Returns the id to be assigned to the next escrow.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    async fn get_next_escrow_id(&self) -> Result<u64> {
+        let Some(bytes) = self
+            .get_raw(keys::NEXT_ESCROW_ID)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw next escrow id from state")?
+        else {
+            return Ok(0);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::EscrowId::try_from(value).map(u64::from))
+            .wrap_err("invalid next escrow id bytes")
+    }
+
+    /// Returns the ids of all pending escrows sent from or to the account, in ascending order.
+    #[instrument(skip_all, fields(address = %address.display_address()), err(level = Level::WARN))]
+    async fn get_account_escrow_ids<T: AddressBytes>(&self, address: &T) -> Result<Vec<u64>> {
+        let mut stream = std::pin::pin!(self.prefix_raw(&keys::account_escrow_prefix(address)));
+        let mut ids = vec![];
+        while let Some((_, bytes)) = stream
+            .try_next()
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading account escrow ids from state")?
+        {
+            let id = StoredValue::deserialize(&bytes)
+                .and_then(|value| storage::EscrowId::try_from(value).map(u64::from))
+                .wrap_err("invalid escrow id bytes")?;
+            ids.push(id);
+        }
+        Ok(ids)
+    }
+
+    /// Returns the ids of up to `limit` escrows due for release at the given block height and
+    /// timestamp, ordered by release height first and release timestamp second.
+    #[instrument(skip_all, fields(height, unix_timestamp_seconds, limit), err(level = Level::WARN))]
+    async fn get_due_escrow_ids(
+        &self,
+        height: u64,
+        unix_timestamp_seconds: u64,
+        limit: usize,
+    ) -> Result<Vec<u64>> {
+        let mut ids = vec![];
+        for (prefix, release_point) in [
+            (keys::ESCROW_RELEASE_BY_HEIGHT_PREFIX, height),
+            (
+                keys::ESCROW_RELEASE_BY_TIMESTAMP_PREFIX,
+                unix_timestamp_seconds,
+            ),
+        ] {
+            let mut stream = std::pin::pin!(self.prefix_raw(prefix));
+            while let Some((key, bytes)) = stream
+                .try_next()
+                .await
+                .map_err(anyhow_to_eyre)
+                .wrap_err("failed reading escrow release index from state")?
+            {
+                if ids.len() >= limit {
+                    return Ok(ids);
+                }
+                // Keys are ordered by release point, so all further escrows are not yet due.
+                if keys::extract_release_point_from_key(&key)? > release_point {
+                    break;
+                }
+                let id = StoredValue::deserialize(&bytes)
+                    .and_then(|value| storage::EscrowId::try_from(value).map(u64::from))
+                    .wrap_err("invalid escrow id bytes")?;
+                ids.push(id);
+            }
+        }
+        Ok(ids)
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
@@ -218,6 +338,59 @@ pub(crate) trait StateWriteExt: StateWrite {
         Ok(())
     }
 
+    #[instrument(skip_all, fields(address = %address.display_address()), err(level = Level::WARN))]
+    fn put_multisig_key_set<T: AddressBytes>(
+        &mut self,
+        address: &T,
+        key_set: &MultisigKeySet,
+    ) -> Result<()> {
+        let bytes = StoredValue::from(storage::MultisigKeySet::from(key_set))
+            .serialize()
+            .wrap_err("failed to serialize multisig key set")?;
+        self.put_raw(keys::multisig(address), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(id), err(level = Level::WARN))]
+    fn put_next_escrow_id(&mut self, id: u64) -> Result<()> {
+        let bytes = StoredValue::from(storage::EscrowId::from(id))
+            .serialize()
+            .wrap_err("failed to serialize next escrow id")?;
+        self.put_raw(keys::NEXT_ESCROW_ID.to_string(), bytes);
+        Ok(())
+    }
+
+    /// Writes the escrow, indexing it by its release point and by both its sender and recipient.
+    #[instrument(skip_all, fields(id = escrow.id), err(level = Level::WARN))]
+    fn put_escrow(&mut self, escrow: &Escrow) -> Result<()> {
+        let bytes = StoredValue::from(storage::Escrow::from(escrow))
+            .serialize()
+            .wrap_err("failed to serialize escrow")?;
+        self.put_raw(keys::escrow(escrow.id), bytes);
+        let id_bytes = StoredValue::from(storage::EscrowId::from(escrow.id))
+            .serialize()
+            .wrap_err("failed to serialize escrow id")?;
+        self.put_raw(
+            keys::escrow_release(&escrow.release_at, escrow.id),
+            id_bytes.clone(),
+        );
+        self.put_raw(
+            keys::account_escrow(&escrow.from, escrow.id),
+            id_bytes.clone(),
+        );
+        self.put_raw(keys::account_escrow(&escrow.to, escrow.id), id_bytes);
+        Ok(())
+    }
+
+    /// Deletes the escrow and all its index entries.
+    #[instrument(skip_all, fields(id = escrow.id))]
+    fn delete_escrow(&mut self, escrow: &Escrow) {
+        self.delete(keys::escrow(escrow.id));
+        self.delete(keys::escrow_release(&escrow.release_at, escrow.id));
+        self.delete(keys::account_escrow(&escrow.from, escrow.id));
+        self.delete(keys::account_escrow(&escrow.to, escrow.id));
+    }
+
     #[instrument(skip_all, fields(address = %address.display_address(), %asset, amount), err(level = Level::WARN))]
     async fn increase_balance<'a, TAddress, TAsset>(
         &mut self,
@@ -766,4 +939,116 @@ mod tests {
             .await
             .expect_err("should not be able to subtract larger balance than what existed");
     }
+
+    #[tokio::test]
+    async fn put_and_get_multisig_key_set() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address = astria_address(&[42u8; 20]);
+        assert!(state
+            .get_multisig_key_set(&address)
+            .await
+            .unwrap()
+            .is_none());
+
+        let key_set = MultisigKeySet::new(
+            vec![
+                astria_core::crypto::SigningKey::from([1; 32]).verification_key(),
+                astria_core::crypto::SigningKey::from([2; 32]).verification_key(),
+            ],
+            2,
+        );
+        state.put_multisig_key_set(&address, &key_set).unwrap();
+        assert_eq!(
+            Some(key_set),
+            state.get_multisig_key_set(&address).await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn put_get_and_delete_escrows() {
+        use astria_core::protocol::transaction::v1::action::ReleaseCondition;
+
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let escrow = |id, release_at| Escrow {
+            id,
+            from: [1; 20],
+            to: [2; 20],
+            asset: asset_0().to_ibc_prefixed(),
+            amount: 100,
+            release_at,
+        };
+        let escrows = [
+            escrow(0, ReleaseCondition::Height(20)),
+            escrow(1, ReleaseCondition::Height(10)),
+            escrow(2, ReleaseCondition::UnixTimestamp(1_000)),
+            escrow(3, ReleaseCondition::UnixTimestamp(2_000)),
+        ];
+        for escrow in &escrows {
+            state.put_escrow(escrow).unwrap();
+        }
+
+        assert_eq!(Some(escrows[2].clone()), state.get_escrow(2).await.unwrap());
+        assert_eq!(
+            vec![0, 1, 2, 3],
+            state.get_account_escrow_ids(&[1; 20]).await.unwrap()
+        );
+        assert_eq!(
+            vec![0, 1, 2, 3],
+            state.get_account_escrow_ids(&[2; 20]).await.unwrap()
+        );
+        assert!(state
+            .get_account_escrow_ids(&[3; 20])
+            .await
+            .unwrap()
+            .is_empty());
+
+        // Escrows are due in order of their release point, heights first.
+        assert!(state
+            .get_due_escrow_ids(9, 999, 10)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            vec![1, 2],
+            state.get_due_escrow_ids(10, 1_500, 10).await.unwrap()
+        );
+        assert_eq!(
+            vec![1, 0, 2, 3],
+            state.get_due_escrow_ids(20, 2_000, 10).await.unwrap()
+        );
+
+        // At most `limit` escrows are returned.
+        assert_eq!(
+            vec![1, 0, 2],
+            state.get_due_escrow_ids(20, 2_000, 3).await.unwrap()
+        );
+
+        state.delete_escrow(&escrows[1]);
+        assert_eq!(None, state.get_escrow(1).await.unwrap());
+        assert_eq!(
+            vec![0, 2, 3],
+            state.get_account_escrow_ids(&[1; 20]).await.unwrap()
+        );
+        assert_eq!(
+            vec![0, 2, 3],
+            state.get_due_escrow_ids(20, 2_000, 10).await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn next_escrow_id_defaults_to_zero() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert_eq!(0, state.get_next_escrow_id().await.unwrap());
+        state.put_next_escrow_id(5).unwrap();
+        assert_eq!(5, state.get_next_escrow_id().await.unwrap());
+    }
 }
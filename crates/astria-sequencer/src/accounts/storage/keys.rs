@@ -1,6 +1,12 @@
 use std::borrow::Cow;
 
-use astria_core::primitive::v1::asset::IbcPrefixed;
+use astria_core::{
+    primitive::v1::asset::IbcPrefixed,
+    protocol::{
+        proof,
+        transaction::v1::action::ReleaseCondition,
+    },
+};
 use astria_eyre::eyre::{
     OptionExt as _,
     Result,
@@ -17,7 +23,14 @@ use crate::{
 
 const COMPONENT_PREFIX: &str = "accounts/";
 const BALANCE_PREFIX: &str = "balance/";
-const NONCE: &str = "nonce";
+const MULTISIG: &str = "multisig";
+const ACCOUNT_ESCROW_PREFIX: &str = "escrow/";
+pub(in crate::accounts) const ESCROW_PREFIX: &str = "accounts/escrow/";
+pub(in crate::accounts) const NEXT_ESCROW_ID: &str = "accounts/next_escrow_id";
+pub(in crate::accounts) const ESCROW_RELEASE_BY_HEIGHT_PREFIX: &str =
+    "accounts/escrow_release/height/";
+pub(in crate::accounts) const ESCROW_RELEASE_BY_TIMESTAMP_PREFIX: &str =
+    "accounts/escrow_release/time/";
 
 /// Example: `accounts/gGhH....zZ4=/balance/`.
 ///                   |base64 chars|
@@ -28,8 +41,10 @@ pub(in crate::accounts) fn balance_prefix<TAddress: AddressBytes>(address: &TAdd
     )
 }
 
-/// Example: `accounts/gGhH....zZ4=/balance/0202....0202`.
-///                   |base64 chars|       |64 hex chars|
+/// Example: `accounts/gGhH....zZ4=/balance/ibc/0202....0202`.
+///                   |base64 chars|           |64 hex chars|
+///
+/// Balances are returned with proofs, so the key is defined in [`astria_core::protocol::proof`].
 pub(in crate::accounts) fn balance<'a, TAddress, TAsset>(
     address: &TAddress,
     asset: &'a TAsset,
@@ -38,22 +53,76 @@ where
     TAddress: AddressBytes,
     &'a TAsset: Into<Cow<'a, IbcPrefixed>>,
 {
-    format!(
-        "{}/{BALANCE_PREFIX}{}",
-        AccountPrefixer::new(COMPONENT_PREFIX, address),
-        Asset::from(asset)
-    )
+    let asset: Cow<'a, IbcPrefixed> = asset.into();
+    proof::keys::balance(address.address_bytes(), &asset)
 }
 
 /// Example: `accounts/gGhH....zZ4=/nonce`.
 ///                   |base64 chars|
+///
+/// Nonces are returned with proofs, so the key is defined in [`astria_core::protocol::proof`].
 pub(in crate::accounts) fn nonce<TAddress: AddressBytes>(address: &TAddress) -> String {
+    proof::keys::nonce(address.address_bytes())
+}
+
+/// Example: `accounts/gGhH....zZ4=/multisig`.
+///                   |base64 chars|
+pub(in crate::accounts) fn multisig<TAddress: AddressBytes>(address: &TAddress) -> String {
+    format!(
+        "{}/{MULTISIG}",
+        AccountPrefixer::new(COMPONENT_PREFIX, address)
+    )
+}
+
+/// Example: `accounts/escrow/00000000000000000007`.
+///                           |20 decimal digits |
+pub(in crate::accounts) fn escrow(id: u64) -> String {
+    format!("{ESCROW_PREFIX}{id:020}")
+}
+
+/// Example: `accounts/gGhH....zZ4=/escrow/`.
+///                   |base64 chars|
+pub(in crate::accounts) fn account_escrow_prefix<TAddress: AddressBytes>(
+    address: &TAddress,
+) -> String {
     format!(
-        "{}/{NONCE}",
+        "{}/{ACCOUNT_ESCROW_PREFIX}",
         AccountPrefixer::new(COMPONENT_PREFIX, address)
     )
 }
 
+/// Example: `accounts/gGhH....zZ4=/escrow/00000000000000000007`.
+///                   |base64 chars|      |20 decimal digits |
+pub(in crate::accounts) fn account_escrow<TAddress: AddressBytes>(
+    address: &TAddress,
+    id: u64,
+) -> String {
+    format!("{}{id:020}", account_escrow_prefix(address))
+}
+
+/// Example: `accounts/escrow_release/height/00000000000000000100/00000000000000000007`.
+///                                         |  release point   |    escrow id     |
+///
+/// The release point is zero-padded so that iterating the keys under the prefix of the release
+/// condition yields escrows in the order they become due.
+pub(in crate::accounts) fn escrow_release(release_at: &ReleaseCondition, id: u64) -> String {
+    let (prefix, point) = match *release_at {
+        ReleaseCondition::Height(height) => (ESCROW_RELEASE_BY_HEIGHT_PREFIX, height),
+        ReleaseCondition::UnixTimestamp(seconds) => (ESCROW_RELEASE_BY_TIMESTAMP_PREFIX, seconds),
+    };
+    format!("{prefix}{point:020}/{id:020}")
+}
+
+/// Extracts the release point, being a block height or timestamp, from an escrow release key.
+pub(in crate::accounts) fn extract_release_point_from_key(key: &str) -> Result<u64> {
+    key.strip_prefix(ESCROW_RELEASE_BY_HEIGHT_PREFIX)
+        .or_else(|| key.strip_prefix(ESCROW_RELEASE_BY_TIMESTAMP_PREFIX))
+        .and_then(|s| s.split_once('/').map(|(point, _)| point))
+        .ok_or_eyre("failed to strip prefix from escrow release key")?
+        .parse()
+        .wrap_err("failed to parse escrow release key segment as release point")
+}
+
 pub(in crate::accounts) fn extract_asset_from_key(key: &str) -> Result<IbcPrefixed> {
     Ok(key
         .strip_prefix(COMPONENT_PREFIX)
@@ -87,12 +156,45 @@ mod tests {
     fn keys_should_not_change() {
         insta::assert_snapshot!("balance_key", balance(&address(), &asset()));
         insta::assert_snapshot!("nonce_key", nonce(&address()));
+        insta::assert_snapshot!("multisig_key", multisig(&address()));
+        insta::assert_snapshot!("escrow_key", escrow(7));
+        insta::assert_snapshot!("next_escrow_id_key", NEXT_ESCROW_ID);
+        insta::assert_snapshot!("account_escrow_key", account_escrow(&address(), 7));
+        insta::assert_snapshot!(
+            "escrow_release_by_height_key",
+            escrow_release(&ReleaseCondition::Height(100), 7)
+        );
+        insta::assert_snapshot!(
+            "escrow_release_by_timestamp_key",
+            escrow_release(&ReleaseCondition::UnixTimestamp(1_700_000_000), 7)
+        );
     }
 
     #[test]
     fn keys_should_have_component_prefix() {
         assert!(balance(&address(), &asset()).starts_with(COMPONENT_PREFIX));
         assert!(nonce(&address()).starts_with(COMPONENT_PREFIX));
+        assert!(multisig(&address()).starts_with(COMPONENT_PREFIX));
+        assert!(escrow(7).starts_with(COMPONENT_PREFIX));
+        assert!(NEXT_ESCROW_ID.starts_with(COMPONENT_PREFIX));
+        assert!(account_escrow(&address(), 7).starts_with(COMPONENT_PREFIX));
+        assert!(escrow_release(&ReleaseCondition::Height(100), 7).starts_with(COMPONENT_PREFIX));
+        assert!(
+            escrow_release(&ReleaseCondition::UnixTimestamp(100), 7).starts_with(COMPONENT_PREFIX)
+        );
+    }
+
+    #[test]
+    fn account_escrow_prefix_should_be_prefix_of_account_escrow_key() {
+        assert!(account_escrow(&address(), 7).starts_with(&account_escrow_prefix(&address())));
+    }
+
+    #[test]
+    fn should_extract_release_point_from_key() {
+        let key = escrow_release(&ReleaseCondition::Height(100), 7);
+        assert_eq!(100, extract_release_point_from_key(&key).unwrap());
+        let key = escrow_release(&ReleaseCondition::UnixTimestamp(1_700_000_000), 7);
+        assert_eq!(1_700_000_000, extract_release_point_from_key(&key).unwrap());
     }
 
     #[test]
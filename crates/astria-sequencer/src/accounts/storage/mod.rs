@@ -4,5 +4,8 @@ mod values;
 pub(crate) use values::Value;
 pub(super) use values::{
     Balance,
+    Escrow,
+    EscrowId,
+    MultisigKeySet,
     Nonce,
 };
@@ -1,4 +1,15 @@
-use astria_eyre::eyre::bail;
+use astria_core::{
+    crypto::VerificationKey,
+    primitive::v1::{
+        asset::IbcPrefixed,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1::action::ReleaseCondition,
+};
+use astria_eyre::eyre::{
+    bail,
+    WrapErr as _,
+};
 use borsh::{
     BorshDeserialize,
     BorshSerialize,
@@ -11,6 +22,9 @@ pub(crate) struct Value(ValueImpl);
 enum ValueImpl {
     Balance(Balance),
     Nonce(Nonce),
+    MultisigKeySet(MultisigKeySet),
+    Escrow(Escrow),
+    EscrowId(EscrowId),
 }
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
@@ -78,6 +92,162 @@ impl<'a> TryFrom<crate::storage::StoredValue<'a>> for Nonce {
     }
 }
 
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::accounts) struct MultisigKeySet {
+    verification_keys: Vec<[u8; 32]>,
+    threshold: u32,
+}
+
+impl From<&crate::accounts::MultisigKeySet> for MultisigKeySet {
+    fn from(key_set: &crate::accounts::MultisigKeySet) -> Self {
+        MultisigKeySet {
+            verification_keys: key_set
+                .verification_keys()
+                .iter()
+                .map(VerificationKey::to_bytes)
+                .collect(),
+            threshold: key_set.threshold(),
+        }
+    }
+}
+
+impl TryFrom<MultisigKeySet> for crate::accounts::MultisigKeySet {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(key_set: MultisigKeySet) -> Result<Self, Self::Error> {
+        let verification_keys = key_set
+            .verification_keys
+            .into_iter()
+            .map(VerificationKey::try_from)
+            .collect::<Result<_, _>>()
+            .wrap_err("invalid verification key in multisig key set")?;
+        Ok(crate::accounts::MultisigKeySet::new(
+            verification_keys,
+            key_set.threshold,
+        ))
+    }
+}
+
+impl From<MultisigKeySet> for crate::storage::StoredValue<'_> {
+    fn from(key_set: MultisigKeySet) -> Self {
+        crate::storage::StoredValue::Accounts(Value(ValueImpl::MultisigKeySet(key_set)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for MultisigKeySet {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Accounts(Value(ValueImpl::MultisigKeySet(key_set))) =
+            value
+        else {
+            bail!(
+                "accounts stored value type mismatch: expected multisig key set, found {value:?}"
+            );
+        };
+        Ok(key_set)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+enum ReleaseAt {
+    Height(u64),
+    UnixTimestamp(u64),
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::accounts) struct Escrow {
+    id: u64,
+    from: [u8; ADDRESS_LEN],
+    to: [u8; ADDRESS_LEN],
+    asset: [u8; 32],
+    amount: u128,
+    release_at: ReleaseAt,
+}
+
+impl From<&crate::accounts::Escrow> for Escrow {
+    fn from(escrow: &crate::accounts::Escrow) -> Self {
+        let release_at = match escrow.release_at {
+            ReleaseCondition::Height(height) => ReleaseAt::Height(height),
+            ReleaseCondition::UnixTimestamp(seconds) => ReleaseAt::UnixTimestamp(seconds),
+        };
+        Escrow {
+            id: escrow.id,
+            from: escrow.from,
+            to: escrow.to,
+            asset: *escrow.asset.as_bytes(),
+            amount: escrow.amount,
+            release_at,
+        }
+    }
+}
+
+impl From<Escrow> for crate::accounts::Escrow {
+    fn from(escrow: Escrow) -> Self {
+        let release_at = match escrow.release_at {
+            ReleaseAt::Height(height) => ReleaseCondition::Height(height),
+            ReleaseAt::UnixTimestamp(seconds) => ReleaseCondition::UnixTimestamp(seconds),
+        };
+        crate::accounts::Escrow {
+            id: escrow.id,
+            from: escrow.from,
+            to: escrow.to,
+            asset: IbcPrefixed::new(escrow.asset),
+            amount: escrow.amount,
+            release_at,
+        }
+    }
+}
+
+impl From<Escrow> for crate::storage::StoredValue<'_> {
+    fn from(escrow: Escrow) -> Self {
+        crate::storage::StoredValue::Accounts(Value(ValueImpl::Escrow(escrow)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for Escrow {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Accounts(Value(ValueImpl::Escrow(escrow))) = value else {
+            bail!("accounts stored value type mismatch: expected escrow, found {value:?}");
+        };
+        Ok(escrow)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::accounts) struct EscrowId(u64);
+
+impl From<u64> for EscrowId {
+    fn from(id: u64) -> Self {
+        EscrowId(id)
+    }
+}
+
+impl From<EscrowId> for u64 {
+    fn from(id: EscrowId) -> Self {
+        id.0
+    }
+}
+
+impl From<EscrowId> for crate::storage::StoredValue<'_> {
+    fn from(id: EscrowId) -> Self {
+        crate::storage::StoredValue::Accounts(Value(ValueImpl::EscrowId(id)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for EscrowId {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Accounts(Value(ValueImpl::EscrowId(id))) = value else {
+            bail!("accounts stored value type mismatch: expected escrow id, found {value:?}");
+        };
+        Ok(id)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use insta::assert_snapshot;
@@ -95,6 +265,28 @@ mod tests {
             "value_impl_nonce",
             borsh_then_hex(&ValueImpl::Nonce(Nonce(0)))
         );
+        assert_snapshot!(
+            "value_impl_multisig_key_set",
+            borsh_then_hex(&ValueImpl::MultisigKeySet(MultisigKeySet {
+                verification_keys: vec![[1; 32], [2; 32]],
+                threshold: 2,
+            }))
+        );
+        assert_snapshot!(
+            "value_impl_escrow",
+            borsh_then_hex(&ValueImpl::Escrow(Escrow {
+                id: 7,
+                from: [1; ADDRESS_LEN],
+                to: [2; ADDRESS_LEN],
+                asset: [3; 32],
+                amount: 100,
+                release_at: ReleaseAt::Height(10),
+            }))
+        );
+        assert_snapshot!(
+            "value_impl_escrow_id",
+            borsh_then_hex(&ValueImpl::EscrowId(EscrowId(7)))
+        );
     }
 
     // Note: This test must be here instead of in `crate::storage` since `ValueImpl` is not
@@ -107,4 +299,21 @@ mod tests {
             borsh_then_hex(&StoredValue::Accounts(Value(ValueImpl::Nonce(Nonce(0)))))
         );
     }
+
+    #[test]
+    fn proven_values_match_encoding_shared_with_clients() {
+        use astria_core::protocol::proof::values;
+
+        use crate::storage::StoredValue;
+        assert_eq!(
+            values::balance(u128::MAX - 1),
+            StoredValue::from(Balance(u128::MAX - 1))
+                .serialize()
+                .unwrap()
+        );
+        assert_eq!(
+            values::nonce(u32::MAX - 1),
+            StoredValue::from(Nonce(u32::MAX - 1)).serialize().unwrap()
+        );
+    }
 }
@@ -73,7 +73,10 @@ use telemetry::display::{
 use tendermint::{
     abci::{
         self,
-        types::ExecTxResult,
+        types::{
+            CommitInfo,
+            ExecTxResult,
+        },
         Code,
         Event,
     },
@@ -113,12 +116,16 @@ use crate::{
         },
         vote_extension::ProposalHandler,
     },
-    assets::StateWriteExt as _,
+    assets::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
     authority::{
         component::{
             AuthorityComponent,
             AuthorityComponentAppState,
         },
+        record_vote_extension_participation,
         StateReadExt as _,
         StateWriteExt as _,
     },
@@ -126,7 +133,10 @@ use crate::{
         StateReadExt as _,
         StateWriteExt as _,
     },
-    checked_actions::CheckedAction,
+    checked_actions::{
+        use_pre_aspen_validator_updates,
+        CheckedAction,
+    },
     checked_transaction::{
         CheckedTransaction,
         CheckedTransactionExecutionError,
@@ -136,9 +146,14 @@ use crate::{
         component::FeesComponent,
         StateReadExt as _,
     },
+    governance::component::GovernanceComponent,
     grpc::StateWriteExt as _,
-    ibc::component::IbcComponent,
+    ibc::{
+        component::IbcComponent,
+        StateReadExt as _,
+    },
     mempool::{
+        BlockFullness,
         Mempool,
         RemovalReason,
     },
@@ -147,6 +162,8 @@ use crate::{
         block_size_constraints::BlockSizeConstraints,
         commitment::generate_rollup_datas_commitment,
     },
+    staking::component::StakingComponent,
+    storage::StateHistory,
     upgrades::UpgradesHandler,
 };
 
@@ -214,6 +231,10 @@ pub(crate) struct App {
     // used to create and verify vote extensions, if this is a validator node.
     vote_extension_handler: vote_extension::Handler,
 
+    // records the values overwritten by each committed block, so that queries can be answered
+    // against recent heights.
+    state_history: StateHistory,
+
     metrics: &'static Metrics,
 }
 
@@ -234,6 +255,7 @@ impl App {
         mempool: Mempool,
         upgrades_handler: UpgradesHandler,
         vote_extension_handler: vote_extension::Handler,
+        state_history: StateHistory,
         metrics: &'static Metrics,
     ) -> Result<Self> {
         debug!("initializing App instance");
@@ -264,6 +286,7 @@ impl App {
             event_bus,
             upgrades_handler,
             vote_extension_handler,
+            state_history,
             metrics,
         })
     }
@@ -327,6 +350,12 @@ impl App {
         IbcComponent::init_chain(&mut state_tx, &genesis_state)
             .await
             .wrap_err("init_chain failed on IbcComponent")?;
+        StakingComponent::init_chain(&mut state_tx, &genesis_state)
+            .await
+            .wrap_err("init_chain failed on StakingComponent")?;
+        GovernanceComponent::init_chain(&mut state_tx, &genesis_state)
+            .await
+            .wrap_err("init_chain failed on GovernanceComponent")?;
 
         state_tx.apply();
 
@@ -460,16 +489,34 @@ impl App {
         self.metrics
             .record_proposal_transactions(included_txs.len());
 
+        let interchain_rollup_data = self.state.get_cached_block_interchain_rollup_data();
+        ensure!(
+            interchain_rollup_data.is_empty()
+                || !use_pre_aspen_validator_updates(&self.state)
+                    .await
+                    .wrap_err("failed to get upgrade status")?,
+            "interchain rollup data must not be committed to before the Aspen upgrade",
+        );
         let deposits = self.state.get_cached_block_deposits();
         self.metrics.record_proposal_deposits(deposits.len());
 
-        // generate commitment to sequence::Actions and deposits and commitment to the rollup IDs
-        // included in the block, chain on the extended commit info if `Some`, and finally chain on
-        // the tx bytes.
+        // generate commitment to sequence::Actions, interchain rollup data and deposits and
+        // commitment to the rollup IDs included in the block, chain on the extended commit info if
+        // `Some`, and finally chain on the tx bytes.
         let commitments_iter = if uses_data_item_enum {
-            generate_rollup_datas_commitment::<true>(&included_txs, deposits).into_iter()
+            generate_rollup_datas_commitment::<true>(
+                &included_txs,
+                &interchain_rollup_data,
+                deposits,
+            )
+            .into_iter()
         } else {
-            generate_rollup_datas_commitment::<false>(&included_txs, deposits).into_iter()
+            generate_rollup_datas_commitment::<false>(
+                &included_txs,
+                &interchain_rollup_data,
+                deposits,
+            )
+            .into_iter()
         };
 
         let included_txs_encoded_bytes = included_txs
@@ -589,7 +636,7 @@ impl App {
             if let Some(extended_commit_info_with_proof) =
                 &expanded_block_data.extended_commit_info_with_proof
             {
-                let Some(last_commit) = process_proposal.proposed_last_commit else {
+                let Some(last_commit) = &process_proposal.proposed_last_commit else {
                     bail!("proposed last commit is empty; this should not occur")
                 };
 
@@ -597,7 +644,7 @@ impl App {
                 ProposalHandler::validate_proposal(
                     &self.state,
                     process_proposal.height.value(),
-                    &last_commit,
+                    last_commit,
                     extended_commit_info_with_proof.extended_commit_info(),
                 )
                 .await
@@ -641,18 +688,28 @@ impl App {
             self.metrics
                 .record_proposal_transactions(user_submitted_transactions.len());
 
+            let interchain_rollup_data = self.state.get_cached_block_interchain_rollup_data();
+            ensure!(
+                interchain_rollup_data.is_empty()
+                    || !use_pre_aspen_validator_updates(&self.state)
+                        .await
+                        .wrap_err("failed to get upgrade status")?,
+                "interchain rollup data must not be committed to before the Aspen upgrade",
+            );
             let deposits = self.state.get_cached_block_deposits();
             self.metrics.record_proposal_deposits(deposits.len());
 
             let (expected_rollup_datas_root, expected_rollup_ids_root) = if uses_data_item_enum {
                 let commitments = generate_rollup_datas_commitment::<true>(
                     &user_submitted_transactions,
+                    &interchain_rollup_data,
                     deposits,
                 );
                 (commitments.rollup_datas_root, commitments.rollup_ids_root)
             } else {
                 let commitments = generate_rollup_datas_commitment::<false>(
                     &user_submitted_transactions,
+                    &interchain_rollup_data,
                     deposits,
                 );
                 (commitments.rollup_datas_root, commitments.rollup_ids_root)
@@ -683,6 +740,7 @@ impl App {
                 process_proposal.height,
                 process_proposal.time,
                 process_proposal.proposer_address,
+                process_proposal.proposed_last_commit.as_ref(),
                 expanded_block_data,
                 rollup_data_bytes,
                 tx_results,
@@ -1124,8 +1182,9 @@ impl App {
         height: tendermint::block::Height,
         time: tendermint::Time,
         proposer_address: account::Id,
+        last_commit: Option<&CommitInfo>,
         expanded_block_data: ExpandedBlockData,
-        rollup_data_bytes: Vec<(RollupId, Bytes)>,
+        mut rollup_data_bytes: Vec<(RollupId, Bytes)>,
         tx_results: Vec<ExecTxResult>,
         executed_tx_ids: HashSet<TransactionId>,
     ) -> Result<SequencerBlock> {
@@ -1149,6 +1208,25 @@ impl App {
             .await
             .wrap_err("failed to get sudo address from state")?;
 
+        // Record vote extension participation before `end_block`, so that any resulting validator
+        // updates are returned with this block's. Participation is taken from the decided last
+        // commit, not from the extended commit info chosen by the proposer.
+        let participation_events = if expanded_block_data
+            .extended_commit_info_with_proof
+            .is_some()
+        {
+            let Some(last_commit) = last_commit else {
+                bail!("last commit is empty; this should not occur")
+            };
+            let mut state_tx = StateDelta::new(self.state.clone());
+            record_vote_extension_participation(&mut state_tx, last_commit)
+                .await
+                .wrap_err("failed to record vote extension participation")?;
+            self.apply(state_tx)
+        } else {
+            vec![]
+        };
+
         let end_block = self.end_block(height.value(), &sudo_address).await?;
 
         // get deposits for this block from state's ephemeral cache and put them to storage.
@@ -1160,6 +1238,10 @@ impl App {
             .put_deposits(&block_hash, deposits_in_this_block.clone())
             .wrap_err("failed to put deposits to state")?;
 
+        // rollup data submitted by interchain accounts follows the data of the transactions, in
+        // the same order as committed to in the proposal.
+        rollup_data_bytes.extend(self.state.get_cached_block_interchain_rollup_data());
+
         // cometbft expects a result for every tx in the block, so we need to return a
         // tx result for the commitments and other injected data items, even though they're not
         // actually user txs.
@@ -1173,6 +1255,27 @@ impl App {
             .extend(std::iter::repeat(ExecTxResult::default()).take(injected_tx_count));
         finalize_block_tx_results.extend(tx_results);
 
+        let max_block_bytes = self
+            .state
+            .get_consensus_params()
+            .await
+            .wrap_err("failed to get consensus params from storage")?
+            .and_then(|params| usize::try_from(params.block.max_bytes).ok());
+        let block_fullness = BlockFullness {
+            height: height.value(),
+            transaction_count: expanded_block_data.user_submitted_transactions.len(),
+            transaction_bytes: expanded_block_data
+                .user_submitted_transactions
+                .iter()
+                .map(Bytes::len)
+                .sum(),
+            rollup_data_bytes: rollup_data_bytes
+                .iter()
+                .map(|(_rollup_id, data)| data.len())
+                .sum(),
+            max_block_bytes,
+        };
+
         let sequencer_block = SequencerBlockBuilder {
             block_hash: block::Hash::new(block_hash),
             chain_id,
@@ -1203,11 +1306,15 @@ impl App {
         }
 
         let result = PostTransactionExecutionResult {
-            events: end_block.events,
+            events: participation_events
+                .into_iter()
+                .chain(end_block.events)
+                .collect(),
             validator_updates: end_block.validator_updates,
             tx_results: finalize_block_tx_results,
             consensus_param_updates,
             executed_tx_ids,
+            block_fullness,
         };
 
         state_tx.object_put(POST_TRANSACTION_EXECUTION_RESULT_KEY, result);
@@ -1351,6 +1458,7 @@ impl App {
                 finalize_block.height,
                 finalize_block.time,
                 finalize_block.proposer_address,
+                Some(&finalize_block.decided_last_commit),
                 expanded_block_data,
                 rollup_data_bytes,
                 tx_results,
@@ -1366,6 +1474,7 @@ impl App {
             validator_updates,
             consensus_param_updates,
             executed_tx_ids,
+            block_fullness,
         } = self
             .state
             .object_get(POST_TRANSACTION_EXECUTION_RESULT_KEY)
@@ -1373,6 +1482,7 @@ impl App {
                 "post_transaction_execution_result must be present, as txs were already executed \
                  just now or during the proposal phase",
             );
+        self.mempool.record_block_fullness(block_fullness).await;
 
         // prepare the `WriteBatch` for a later commit.
         let app_hash = self
@@ -1419,6 +1529,11 @@ impl App {
             "stored storage version for height"
         );
 
+        let state = self
+            .state_history
+            .record_block(state, height)
+            .await
+            .wrap_err("failed to record state history of block")?;
         let write_batch = storage
             .prepare_commit(state)
             .await
@@ -1524,6 +1639,10 @@ impl App {
         AccountsComponent::end_block(&mut arc_state_tx, &end_block)
             .await
             .wrap_err("end_block failed on AccountsComponent")?;
+        // `StakingComponent` must run before `AuthorityComponent` as it adds validator updates.
+        StakingComponent::end_block(&mut arc_state_tx, &end_block)
+            .await
+            .wrap_err("end_block failed on StakingComponent")?;
         AuthorityComponent::end_block(&mut arc_state_tx, &end_block)
             .await
             .wrap_err("end_block failed on AuthorityComponent")?;
@@ -1533,13 +1652,15 @@ impl App {
         IbcComponent::end_block(&mut arc_state_tx, &end_block)
             .await
             .wrap_err("end_block failed on IbcComponent")?;
+        GovernanceComponent::end_block(&mut arc_state_tx, &end_block)
+            .await
+            .wrap_err("end_block failed on GovernanceComponent")?;
 
         let mut state_tx = Arc::try_unwrap(arc_state_tx)
             .expect("components should not retain copies of shared state");
 
         // gather and return validator updates
-        let validator_updates = self
-            .state
+        let validator_updates = state_tx
             .get_block_validator_updates()
             .await
             .expect("failed getting validator updates");
@@ -1669,6 +1790,9 @@ impl App {
         &mut self,
         block_height: tendermint::block::Height,
     ) -> Result<bool> {
+        self.restore_missing_consensus_params(block_height)
+            .await
+            .wrap_err("failed to restore consensus params missing from state")?;
         let vote_extensions_enable_height = self
             .state
             .get_consensus_params()
@@ -1686,6 +1810,26 @@ impl App {
         )
     }
 
+    /// Writes the consensus params fetched from CometBFT to state if they are missing because
+    /// the state was restored from a state sync snapshot.
+    ///
+    /// Snapshots only contain verifiable state, so the consensus params have to be taken from
+    /// CometBFT, which verified them against the light client during state sync.
+    async fn restore_missing_consensus_params(
+        &mut self,
+        block_height: tendermint::block::Height,
+    ) -> Result<()> {
+        let mut state_tx = StateDelta::new(self.state.clone());
+        if self
+            .upgrades_handler
+            .restore_missing_consensus_params(&mut state_tx, block_height)
+            .await?
+        {
+            let _ = self.apply(state_tx);
+        }
+        Ok(())
+    }
+
     #[cfg(any(test, feature = "benchmark"))]
     pub(crate) fn mempool(&self) -> Mempool {
         self.mempool.clone()
@@ -1793,6 +1937,16 @@ async fn update_mempool_after_finalization<S: StateRead>(
     txs_included_in_block: &HashSet<TransactionId>,
     block_height: u64,
 ) {
+    // the native asset is only known once the chain is initialized or restored, and does not
+    // change afterwards.
+    match state.get_native_asset().await {
+        Ok(Some(native_asset)) => mempool.set_fee_asset(native_asset.to_ibc_prefixed()).await,
+        Ok(None) => {}
+        Err(error) => warn!(
+            error = AsRef::<dyn std::error::Error>::as_ref(&error),
+            "failed to read native asset from state; not updating mempool fee asset"
+        ),
+    }
     mempool
         .run_maintenance(state, recost, txs_included_in_block, block_height)
         .await;
@@ -1817,6 +1971,7 @@ struct PostTransactionExecutionResult {
     validator_updates: Vec<tendermint::validator::Update>,
     consensus_param_updates: Option<tendermint::consensus::Params>,
     executed_tx_ids: HashSet<TransactionId>,
+    block_fullness: BlockFullness,
 }
 
 #[derive(PartialEq)]
@@ -120,6 +120,18 @@ pub(crate) trait StateReadExt: StateRead {
             })
             .wrap_err("invalid consensus params bytes")
     }
+
+    /// Returns `true` if the consensus params were not restored along with the state sync
+    /// snapshot the state was restored from, and still need to be fetched from CometBFT.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    async fn is_consensus_params_missing(&self) -> Result<bool> {
+        let bytes = self
+            .nonverifiable_get_raw(keys::CONSENSUS_PARAMS_MISSING.as_bytes())
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed to read raw consensus params missing marker from state")?;
+        Ok(bytes.is_some())
+    }
 }
 
 impl<T: StateRead> StateReadExt for T {}
@@ -178,6 +190,19 @@ pub(crate) trait StateWriteExt: StateWrite {
             .serialize()
             .wrap_err("failed to serialize consensus params")?;
         self.nonverifiable_put_raw(keys::CONSENSUS_PARAMS.into(), bytes);
+        self.nonverifiable_delete(keys::CONSENSUS_PARAMS_MISSING.into());
+        Ok(())
+    }
+
+    /// Marks the consensus params as missing from state restored at `restored_height`.
+    ///
+    /// The marker is cleared by the next call to [`StateWriteExt::put_consensus_params`].
+    #[instrument(skip_all)]
+    fn put_consensus_params_missing(&mut self, restored_height: u64) -> Result<()> {
+        let bytes = StoredValue::from(storage::BlockHeight::from(restored_height))
+            .serialize()
+            .wrap_err("failed to serialize restored block height")?;
+        self.nonverifiable_put_raw(keys::CONSENSUS_PARAMS_MISSING.into(), bytes);
         Ok(())
     }
 }
@@ -501,7 +526,14 @@ mod tests {
         state.put_consensus_params(updated_params.clone()).unwrap();
         assert_eq!(
             state.get_consensus_params().await.unwrap(),
-            Some(updated_params),
+            Some(updated_params.clone()),
         );
+
+        // writing consensus params clears the missing marker
+        assert!(!state.is_consensus_params_missing().await.unwrap());
+        state.put_consensus_params_missing(10).unwrap();
+        assert!(state.is_consensus_params_missing().await.unwrap());
+        state.put_consensus_params(updated_params).unwrap();
+        assert!(!state.is_consensus_params_missing().await.unwrap());
     }
 }
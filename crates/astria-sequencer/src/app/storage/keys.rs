@@ -3,6 +3,7 @@ pub(in crate::app) const REVISION_NUMBER: &str = "app/revision_number";
 pub(in crate::app) const BLOCK_HEIGHT: &str = "app/block_height";
 pub(in crate::app) const BLOCK_TIMESTAMP: &str = "app/block_timestamp";
 pub(in crate::app) const CONSENSUS_PARAMS: &str = "app/consensus_params";
+pub(in crate::app) const CONSENSUS_PARAMS_MISSING: &str = "app/consensus_params_missing";
 
 pub(in crate::app) fn storage_version_by_height(height: u64) -> String {
     format!("app/storage_version/{height}")
@@ -21,6 +22,7 @@ mod tests {
         insta::assert_snapshot!("block_height_key", BLOCK_HEIGHT);
         insta::assert_snapshot!("block_timestamp_key", BLOCK_TIMESTAMP);
         insta::assert_snapshot!("consensus_params_key", CONSENSUS_PARAMS);
+        insta::assert_snapshot!("consensus_params_missing_key", CONSENSUS_PARAMS_MISSING);
         insta::assert_snapshot!("storage_version_key", storage_version_by_height(42));
     }
 
@@ -31,6 +33,7 @@ mod tests {
         assert!(BLOCK_HEIGHT.starts_with(COMPONENT_PREFIX));
         assert!(BLOCK_TIMESTAMP.starts_with(COMPONENT_PREFIX));
         assert!(CONSENSUS_PARAMS.starts_with(COMPONENT_PREFIX));
+        assert!(CONSENSUS_PARAMS_MISSING.starts_with(COMPONENT_PREFIX));
         assert!(storage_version_by_height(42).starts_with(COMPONENT_PREFIX));
     }
 }
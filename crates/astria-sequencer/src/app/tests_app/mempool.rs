@@ -56,6 +56,7 @@ async fn trigger_cleaning() {
             0,
             &dummy_balances(0, 0),
             dummy_tx_costs(0, 0, 0),
+            None,
         )
         .await
         .unwrap();
@@ -156,7 +157,13 @@ async fn do_not_trigger_cleaning() {
 
     fixture
         .mempool()
-        .insert(tx_fail, 0, &dummy_balances(0, 0), dummy_tx_costs(0, 0, 0))
+        .insert(
+            tx_fail,
+            0,
+            &dummy_balances(0, 0),
+            dummy_tx_costs(0, 0, 0),
+            None,
+        )
         .await
         .unwrap();
 
@@ -220,7 +227,7 @@ async fn maintenance_recosting_promotes() {
     tx_cost.insert(nria().into(), 3);
     let mempool = fixture.mempool();
     mempool
-        .insert(tx_fail_recost_funds, 0, &bob_funds, tx_cost)
+        .insert(tx_fail_recost_funds, 0, &bob_funds, tx_cost, None)
         .await
         .unwrap();
 
@@ -236,7 +243,7 @@ async fn maintenance_recosting_promotes() {
     let mut tx_cost = HashMap::new();
     tx_cost.insert(nria().into(), 0);
     mempool
-        .insert(tx_recost, 0, &sudo_funds, tx_cost)
+        .insert(tx_recost, 0, &sudo_funds, tx_cost, None)
         .await
         .unwrap();
     assert_eq!(mempool.len().await, 2, "two txs in mempool");
@@ -393,7 +400,7 @@ async fn maintenance_funds_added_promotes() {
     tx_cost.insert(nria().into(), 22);
     let mempool = fixture.mempool();
     mempool
-        .insert(tx_fail_transfer_funds, 0, &carol_funds, tx_cost)
+        .insert(tx_fail_transfer_funds, 0, &carol_funds, tx_cost, None)
         .await
         .unwrap();
 
@@ -415,7 +422,7 @@ async fn maintenance_funds_added_promotes() {
     let mut tx_cost = HashMap::new();
     tx_cost.insert(nria().into(), 13);
     mempool
-        .insert(tx_fund, 0, &alice_funds, tx_cost)
+        .insert(tx_fund, 0, &alice_funds, tx_cost, None)
         .await
         .unwrap();
 
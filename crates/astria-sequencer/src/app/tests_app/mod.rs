@@ -547,7 +547,7 @@ async fn app_execution_results_match_proposal_vs_after_proposal() {
     // this simulates executing the same block as a validator (specifically the proposer).
     let mempool = fixture.mempool();
     mempool
-        .insert(tx, 0, &dummy_balances(0, 0), dummy_tx_costs(0, 0, 0))
+        .insert(tx, 0, &dummy_balances(0, 0), dummy_tx_costs(0, 0, 0), None)
         .await
         .unwrap();
 
@@ -661,7 +661,13 @@ async fn app_prepare_proposal_cometbft_max_bytes_overflow_ok() {
 
     let mempool = fixture.mempool();
     mempool
-        .insert(tx_pass, 0, &dummy_balances(0, 0), dummy_tx_costs(0, 0, 0))
+        .insert(
+            tx_pass,
+            0,
+            &dummy_balances(0, 0),
+            dummy_tx_costs(0, 0, 0),
+            None,
+        )
         .await
         .unwrap();
     mempool
@@ -670,6 +676,7 @@ async fn app_prepare_proposal_cometbft_max_bytes_overflow_ok() {
             0,
             &dummy_balances(0, 0),
             dummy_tx_costs(0, 0, 0),
+            None,
         )
         .await
         .unwrap();
@@ -744,7 +751,13 @@ async fn app_prepare_proposal_sequencer_max_bytes_overflow_ok() {
 
     let mempool = fixture.mempool();
     mempool
-        .insert(tx_pass, 0, &dummy_balances(0, 0), dummy_tx_costs(0, 0, 0))
+        .insert(
+            tx_pass,
+            0,
+            &dummy_balances(0, 0),
+            dummy_tx_costs(0, 0, 0),
+            None,
+        )
         .await
         .unwrap();
     mempool
@@ -753,6 +766,7 @@ async fn app_prepare_proposal_sequencer_max_bytes_overflow_ok() {
             0,
             &dummy_balances(0, 0),
             dummy_tx_costs(0, 0, 0),
+            None,
         )
         .await
         .unwrap();
@@ -943,6 +957,11 @@ async fn app_end_block_validator_updates() {
         .state_mut()
         .put_block_validator_updates(ValidatorSet::new_from_updates(validator_updates.clone()))
         .unwrap();
+    // `end_block` relies on the block timestamp written in `begin_block`.
+    fixture
+        .state_mut()
+        .put_block_timestamp(Time::now())
+        .unwrap();
 
     let resp = fixture.app.end_block(1, &proposer_address).await.unwrap();
     // we only assert length here as the ordering of the updates is not guaranteed
@@ -1100,7 +1119,7 @@ async fn app_proposal_fingerprint_triggers_update() {
     // - the finalize block fingerprint should match
     fixture
         .mempool()
-        .insert(tx, 0, &dummy_balances(0, 0), dummy_tx_costs(0, 0, 0))
+        .insert(tx, 0, &dummy_balances(0, 0), dummy_tx_costs(0, 0, 0), None)
         .await
         .unwrap();
     fixture
@@ -1253,7 +1272,7 @@ async fn app_oracle_price_update_events_in_finalize_block() {
     let encoded_extended_commit_info =
         DataItem::ExtendedCommitInfo(extended_commit_info.into_raw().encode_to_vec().into())
             .encode();
-    let commitments = generate_rollup_datas_commitment::<true>(&[], HashMap::new());
+    let commitments = generate_rollup_datas_commitment::<true>(&[], &[], HashMap::new());
     let txs_with_commit_info: Vec<Bytes> = commitments
         .into_iter()
         .chain(std::iter::once(encoded_extended_commit_info))
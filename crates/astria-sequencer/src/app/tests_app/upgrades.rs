@@ -414,6 +414,7 @@ async fn execute_block_99(proposer: &mut Node, validator: &mut Node, non_validat
             0,
             &dummy_balances(0, 0),
             dummy_tx_costs(0, 0, 0),
+            None,
         )
         .await
         .unwrap();
@@ -437,6 +438,7 @@ async fn execute_block_99(proposer: &mut Node, validator: &mut Node, non_validat
             0,
             &dummy_balances(0, 0),
             dummy_tx_costs(0, 0, 0),
+            None,
         )
         .await
         .unwrap();
@@ -524,6 +526,7 @@ async fn execute_block_100(proposer: &mut Node, validator: &mut Node, non_valida
             1,
             &dummy_balances(0, 0),
             dummy_tx_costs(0, 0, 0),
+            None,
         )
         .await
         .unwrap();
@@ -547,6 +550,7 @@ async fn execute_block_100(proposer: &mut Node, validator: &mut Node, non_valida
             1,
             &dummy_balances(0, 0),
             dummy_tx_costs(0, 0, 0),
+            None,
         )
         .await
         .unwrap();
@@ -641,6 +645,7 @@ async fn execute_block_101(proposer: &mut Node, validator: &mut Node, non_valida
             2,
             &dummy_balances(0, 0),
             dummy_tx_costs(0, 0, 0),
+            None,
         )
         .await
         .unwrap();
@@ -664,6 +669,7 @@ async fn execute_block_101(proposer: &mut Node, validator: &mut Node, non_valida
             2,
             &dummy_balances(0, 0),
             dummy_tx_costs(0, 0, 0),
+            None,
         )
         .await
         .unwrap();
@@ -767,6 +773,7 @@ async fn execute_block_102(proposer: &mut Node, validator: &mut Node, non_valida
             3,
             &dummy_balances(0, 0),
             dummy_tx_costs(0, 0, 0),
+            None,
         )
         .await
         .unwrap();
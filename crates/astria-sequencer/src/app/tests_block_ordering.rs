@@ -211,6 +211,7 @@ async fn app_prepare_proposal_account_block_misordering_ok() {
             0,
             &dummy_balances(0, 0),
             dummy_tx_costs(0, 0, 0),
+            None,
         )
         .await
         .unwrap();
@@ -221,6 +222,7 @@ async fn app_prepare_proposal_account_block_misordering_ok() {
             0,
             &dummy_balances(0, 0),
             dummy_tx_costs(0, 0, 0),
+            None,
         )
         .await
         .unwrap();
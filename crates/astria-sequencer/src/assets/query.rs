@@ -3,7 +3,6 @@ use astria_core::{
     protocol::abci::AbciErrorCode,
 };
 use astria_eyre::eyre::WrapErr as _;
-use cnidarium::Storage;
 use hex::FromHex as _;
 use prost::Message as _;
 use tendermint::abci::{
@@ -16,6 +15,7 @@ use tracing::instrument;
 use crate::{
     app::StateReadExt as _,
     assets::StateReadExt as _,
+    storage::QueryState,
 };
 
 // Retrieve the full asset denomination given the asset ID.
@@ -24,14 +24,12 @@ use crate::{
 // `abci-cli query --path=asset/denom/<DENOM_ID>`
 #[instrument(skip_all)]
 pub(crate) async fn denom_request(
-    storage: Storage,
+    snapshot: QueryState,
     request: request::Query,
     params: Vec<(String, String)>,
 ) -> response::Query {
     use astria_core::protocol::asset::v1::DenomResponse;
 
-    // use the latest snapshot, as this is a lookup of id->denom
-    let snapshot = storage.latest_snapshot();
     let asset = match preprocess_request(&params) {
         Ok(asset) => asset,
         Err(err_rsp) => return err_rsp,
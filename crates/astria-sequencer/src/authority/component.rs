@@ -9,6 +9,7 @@ use astria_eyre::eyre::{
     Result,
     WrapErr as _,
 };
+use futures::TryStreamExt as _;
 use tendermint::abci::request::{
     BeginBlock,
     EndBlock,
@@ -67,6 +68,25 @@ impl AuthorityComponent {
         }
         Ok(())
     }
+
+    /// Recomputes the validator count from the validators in verifiable state.
+    ///
+    /// The count is kept in non-verifiable state and is hence not part of state sync snapshots.
+    /// It is only written if there are any validators, as it is not used before `Aspen`.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    pub(crate) async fn restore_validator_count<S: StateWriteExt>(state: &mut S) -> Result<()> {
+        let validator_count = state
+            .get_validators()
+            .try_fold(0_u64, |count, _| async move { Ok(count.saturating_add(1)) })
+            .await
+            .wrap_err("failed to read validators from state")?;
+        if validator_count > 0 {
+            state
+                .put_validator_count(validator_count)
+                .wrap_err("failed to put validator count")?;
+        }
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -1,6 +1,7 @@
 pub(crate) mod component;
 mod state_ext;
 pub(crate) mod storage;
+mod vote_extension_participation;
 
 use std::collections::BTreeMap;
 
@@ -16,6 +17,10 @@ pub(crate) use state_ext::{
     StateReadExt,
     StateWriteExt,
 };
+pub(crate) use vote_extension_participation::{
+    record_vote_extension_participation,
+    ValidatorParticipation,
+};
 
 use crate::accounts::AddressBytes;
 
@@ -50,7 +55,7 @@ impl ValidatorSet {
         self.0.get(address.address_bytes())
     }
 
-    pub(super) fn insert(&mut self, update: ValidatorUpdate) {
+    pub(crate) fn insert(&mut self, update: ValidatorUpdate) {
         self.0
             .insert(*update.verification_key.address_bytes(), update);
     }
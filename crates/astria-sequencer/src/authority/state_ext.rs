@@ -10,7 +10,10 @@ use std::{
 
 use astria_core::{
     primitive::v1::ADDRESS_LEN,
-    protocol::transaction::v1::action::ValidatorUpdate,
+    protocol::transaction::v1::action::{
+        ValidatorUpdate,
+        VoteExtensionParticipation,
+    },
 };
 use astria_eyre::{
     anyhow_to_eyre,
@@ -37,6 +40,7 @@ use super::{
         self,
         keys,
     },
+    ValidatorParticipation,
     ValidatorSet,
 };
 use crate::{
@@ -155,6 +159,69 @@ pub(crate) trait StateReadExt: StateRead {
         }
     }
 
+    /// Returns the requirement that validators provide price vote extensions, if set.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    async fn get_vote_extension_participation(&self) -> Result<Option<VoteExtensionParticipation>> {
+        let Some(bytes) = self
+            .get_raw(keys::VOTE_EXTENSION_PARTICIPATION)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw vote extension participation from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::VoteExtensionParticipation::try_from(value)
+                    .map(VoteExtensionParticipation::from)
+            })
+            .wrap_err("invalid vote extension participation bytes")
+            .map(Some)
+    }
+
+    /// Returns the recorded vote extension participation of `validator`, if any.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    async fn get_validator_participation<TAddress: AddressBytes>(
+        &self,
+        validator: &TAddress,
+    ) -> Result<Option<ValidatorParticipation>> {
+        let Some(bytes) = self
+            .get_raw(&keys::validator_participation(validator))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw validator participation from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::ValidatorParticipation::try_from(value).map(ValidatorParticipation::from)
+            })
+            .wrap_err("invalid validator participation bytes")
+            .map(Some)
+    }
+
+    /// Returns the percentage of its power `validator` retains after being penalized for missing
+    /// vote extensions, or `None` if it has not been penalized.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    async fn get_validator_power_penalty<TAddress: AddressBytes>(
+        &self,
+        validator: &TAddress,
+    ) -> Result<Option<u32>> {
+        let Some(bytes) = self
+            .get_raw(&keys::validator_power_penalty(validator))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw validator power penalty from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::ValidatorPowerPenalty::try_from(value).map(u32::from))
+            .wrap_err("invalid validator power penalty bytes")
+            .map(Some)
+    }
+
     /// Deprecated as of Aspen upgrade
     #[instrument(skip_all, err(level = Level::WARN))]
     async fn pre_aspen_get_validator_set(&self) -> Result<ValidatorSet> {
@@ -223,6 +290,60 @@ pub(crate) trait StateWriteExt: StateWrite {
         Ok(())
     }
 
+    #[instrument(skip_all)]
+    fn put_vote_extension_participation(
+        &mut self,
+        participation: VoteExtensionParticipation,
+    ) -> Result<()> {
+        let bytes = StoredValue::from(storage::VoteExtensionParticipation::from(participation))
+            .serialize()
+            .wrap_err("failed to serialize vote extension participation")?;
+        self.put_raw(keys::VOTE_EXTENSION_PARTICIPATION.to_string(), bytes);
+        Ok(())
+    }
+
+    /// Deletes the requirement that validators provide price vote extensions.
+    ///
+    /// Participation already recorded for individual validators is left in place and is discarded
+    /// as each validator's participation is next recorded under a new requirement.
+    #[instrument(skip_all)]
+    fn delete_vote_extension_participation(&mut self) {
+        self.delete(keys::VOTE_EXTENSION_PARTICIPATION.to_string());
+    }
+
+    #[instrument(skip_all)]
+    fn put_validator_participation<TAddress: AddressBytes>(
+        &mut self,
+        validator: &TAddress,
+        participation: &ValidatorParticipation,
+    ) -> Result<()> {
+        let bytes = StoredValue::from(storage::ValidatorParticipation::from(participation))
+            .serialize()
+            .wrap_err("failed to serialize validator participation")?;
+        self.put_raw(keys::validator_participation(validator), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn delete_validator_participation<TAddress: AddressBytes>(&mut self, validator: &TAddress) {
+        self.delete(keys::validator_participation(validator));
+    }
+
+    #[instrument(skip_all)]
+    fn put_validator_power_penalty<TAddress: AddressBytes>(
+        &mut self,
+        validator: &TAddress,
+        remaining_power_percent: u32,
+    ) -> Result<()> {
+        let bytes = StoredValue::from(storage::ValidatorPowerPenalty::from(
+            remaining_power_percent,
+        ))
+        .serialize()
+        .wrap_err("failed to serialize validator power penalty")?;
+        self.put_raw(keys::validator_power_penalty(validator), bytes);
+        Ok(())
+    }
+
     /// Deprecated as of Aspen upgrade
     #[instrument(skip_all)]
     fn pre_aspen_put_validator_set(&mut self, validator_set: ValidatorSet) -> Result<()> {
@@ -4,6 +4,12 @@ pub(in crate::authority) const SUDO: &str = "authority/sudo";
 pub(in crate::authority) const VALIDATOR_PREFIX: &str = "authority/validator/";
 pub(in crate::authority) const VALIDATOR_COUNT: &str = "authority/validator_count";
 pub(in crate::authority) const VALIDATOR_UPDATES: &str = "authority/validator_updates";
+pub(in crate::authority) const VOTE_EXTENSION_PARTICIPATION: &str =
+    "authority/vote_extension_participation";
+pub(in crate::authority) const VALIDATOR_PARTICIPATION_PREFIX: &str =
+    "authority/validator_participation/";
+pub(in crate::authority) const VALIDATOR_POWER_PENALTY_PREFIX: &str =
+    "authority/validator_power_penalty/";
 
 pub(in crate::authority) const PRE_ASPEN_VALIDATOR_SET: &str = "authority/validator_set"; // Deprecated post Aspen upgrade
 
@@ -15,6 +21,26 @@ pub(in crate::authority) fn validator<TAddress: AddressBytes>(address: &TAddress
     )
 }
 
+pub(in crate::authority) fn validator_participation<TAddress: AddressBytes>(
+    address: &TAddress,
+) -> String {
+    format!(
+        "{}{}",
+        VALIDATOR_PARTICIPATION_PREFIX,
+        hex::encode(address.address_bytes())
+    )
+}
+
+pub(in crate::authority) fn validator_power_penalty<TAddress: AddressBytes>(
+    address: &TAddress,
+) -> String {
+    format!(
+        "{}{}",
+        VALIDATOR_POWER_PENALTY_PREFIX,
+        hex::encode(address.address_bytes())
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use astria_core::{
@@ -36,11 +62,43 @@ mod tests {
         )
     }
 
+    fn snapshot_validator_participation() -> String {
+        validator_participation(
+            &Address::builder()
+                .array([1; ADDRESS_LENGTH])
+                .prefix("astria")
+                .try_build()
+                .unwrap(),
+        )
+    }
+
+    fn snapshot_validator_power_penalty() -> String {
+        validator_power_penalty(
+            &Address::builder()
+                .array([1; ADDRESS_LENGTH])
+                .prefix("astria")
+                .try_build()
+                .unwrap(),
+        )
+    }
+
     #[test]
     fn keys_should_not_change() {
         insta::assert_snapshot!("sudo_address_key", SUDO);
         insta::assert_snapshot!("validator_key", snapshot_validator());
         insta::assert_snapshot!("validator_count_key", VALIDATOR_COUNT);
+        insta::assert_snapshot!(
+            "vote_extension_participation_key",
+            VOTE_EXTENSION_PARTICIPATION
+        );
+        insta::assert_snapshot!(
+            "validator_participation_key",
+            snapshot_validator_participation()
+        );
+        insta::assert_snapshot!(
+            "validator_power_penalty_key",
+            snapshot_validator_power_penalty()
+        );
 
         insta::assert_snapshot!("validator_set_key", PRE_ASPEN_VALIDATOR_SET);
         insta::assert_snapshot!("validator_updates_key", VALIDATOR_UPDATES);
@@ -51,6 +109,9 @@ mod tests {
         assert!(SUDO.starts_with(COMPONENT_PREFIX));
         assert!(snapshot_validator().starts_with(COMPONENT_PREFIX));
         assert!(VALIDATOR_COUNT.starts_with(COMPONENT_PREFIX));
+        assert!(VOTE_EXTENSION_PARTICIPATION.starts_with(COMPONENT_PREFIX));
+        assert!(snapshot_validator_participation().starts_with(COMPONENT_PREFIX));
+        assert!(snapshot_validator_power_penalty().starts_with(COMPONENT_PREFIX));
 
         assert!(PRE_ASPEN_VALIDATOR_SET.starts_with(COMPONENT_PREFIX));
         assert!(VALIDATOR_UPDATES.starts_with(COMPONENT_PREFIX));
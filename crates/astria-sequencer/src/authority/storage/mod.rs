@@ -6,5 +6,8 @@ pub(super) use values::{
     AddressBytes,
     ValidatorCount,
     ValidatorInfoV1,
+    ValidatorParticipation,
+    ValidatorPowerPenalty,
     ValidatorSet,
+    VoteExtensionParticipation,
 };
@@ -13,6 +13,7 @@ use astria_core::{
     protocol::transaction::v1::action::{
         ValidatorName,
         ValidatorUpdate as DomainValidatorUpdate,
+        VoteExtensionParticipation as DomainVoteExtensionParticipation,
     },
 };
 use astria_eyre::eyre::bail;
@@ -24,7 +25,10 @@ use telemetry::display::base64;
 
 use crate::{
     accounts::AddressBytes as DomainAddressBytes,
-    authority::ValidatorSet as DomainValidatorSet,
+    authority::{
+        ValidatorParticipation as DomainValidatorParticipation,
+        ValidatorSet as DomainValidatorSet,
+    },
 };
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
@@ -36,6 +40,9 @@ enum ValueImpl<'a> {
     ValidatorSet(ValidatorSet<'a>),
     ValidatorCount(ValidatorCount),
     ValidatorInfoV1(ValidatorInfoV1<'a>),
+    VoteExtensionParticipation(VoteExtensionParticipation),
+    ValidatorParticipation(ValidatorParticipation<'a>),
+    ValidatorPowerPenalty(ValidatorPowerPenalty),
 }
 
 #[derive(BorshSerialize, BorshDeserialize)]
@@ -251,6 +258,154 @@ impl<'a> TryFrom<crate::storage::StoredValue<'a>> for ValidatorInfoV1<'a> {
     }
 }
 
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::authority) struct VoteExtensionParticipation {
+    window_blocks: u64,
+    max_missed_blocks: u64,
+    power_reduction_percent: u32,
+}
+
+impl From<DomainVoteExtensionParticipation> for VoteExtensionParticipation {
+    fn from(value: DomainVoteExtensionParticipation) -> Self {
+        VoteExtensionParticipation {
+            window_blocks: value.window_blocks,
+            max_missed_blocks: value.max_missed_blocks,
+            power_reduction_percent: value.power_reduction_percent,
+        }
+    }
+}
+
+impl From<VoteExtensionParticipation> for DomainVoteExtensionParticipation {
+    fn from(value: VoteExtensionParticipation) -> Self {
+        DomainVoteExtensionParticipation {
+            window_blocks: value.window_blocks,
+            max_missed_blocks: value.max_missed_blocks,
+            power_reduction_percent: value.power_reduction_percent,
+        }
+    }
+}
+
+impl From<VoteExtensionParticipation> for crate::storage::StoredValue<'_> {
+    fn from(participation: VoteExtensionParticipation) -> Self {
+        crate::storage::StoredValue::Authority(Value(ValueImpl::VoteExtensionParticipation(
+            participation,
+        )))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for VoteExtensionParticipation {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Authority(Value(ValueImpl::VoteExtensionParticipation(
+            participation,
+        ))) = value
+        else {
+            bail!(
+                "authority stored value type mismatch: expected vote extension participation, \
+                 found {value:?}"
+            );
+        };
+        Ok(participation)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::authority) struct ValidatorParticipation<'a> {
+    window_blocks: u64,
+    recorded_blocks: u64,
+    missed_blocks: u64,
+    missed_bitmap: Cow<'a, [u8]>,
+}
+
+impl<'a> From<&'a DomainValidatorParticipation> for ValidatorParticipation<'a> {
+    fn from(value: &'a DomainValidatorParticipation) -> Self {
+        ValidatorParticipation {
+            window_blocks: value.window_blocks,
+            recorded_blocks: value.recorded_blocks,
+            missed_blocks: value.missed_blocks,
+            missed_bitmap: Cow::Borrowed(&value.missed_bitmap),
+        }
+    }
+}
+
+impl From<ValidatorParticipation<'_>> for DomainValidatorParticipation {
+    fn from(value: ValidatorParticipation<'_>) -> Self {
+        DomainValidatorParticipation {
+            window_blocks: value.window_blocks,
+            recorded_blocks: value.recorded_blocks,
+            missed_blocks: value.missed_blocks,
+            missed_bitmap: value.missed_bitmap.into_owned(),
+        }
+    }
+}
+
+impl<'a> From<ValidatorParticipation<'a>> for crate::storage::StoredValue<'a> {
+    fn from(participation: ValidatorParticipation<'a>) -> Self {
+        crate::storage::StoredValue::Authority(Value(ValueImpl::ValidatorParticipation(
+            participation,
+        )))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for ValidatorParticipation<'a> {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Authority(Value(ValueImpl::ValidatorParticipation(
+            participation,
+        ))) = value
+        else {
+            bail!(
+                "authority stored value type mismatch: expected validator participation, found \
+                 {value:?}"
+            );
+        };
+        Ok(participation)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::authority) struct ValidatorPowerPenalty {
+    remaining_power_percent: u32,
+}
+
+impl From<u32> for ValidatorPowerPenalty {
+    fn from(remaining_power_percent: u32) -> Self {
+        ValidatorPowerPenalty {
+            remaining_power_percent,
+        }
+    }
+}
+
+impl From<ValidatorPowerPenalty> for u32 {
+    fn from(value: ValidatorPowerPenalty) -> Self {
+        value.remaining_power_percent
+    }
+}
+
+impl From<ValidatorPowerPenalty> for crate::storage::StoredValue<'_> {
+    fn from(penalty: ValidatorPowerPenalty) -> Self {
+        crate::storage::StoredValue::Authority(Value(ValueImpl::ValidatorPowerPenalty(penalty)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for ValidatorPowerPenalty {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Authority(Value(ValueImpl::ValidatorPowerPenalty(
+            penalty,
+        ))) = value
+        else {
+            bail!(
+                "authority stored value type mismatch: expected validator power penalty, found                  {value:?}"
+            );
+        };
+        Ok(penalty)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use insta::assert_snapshot;
@@ -280,6 +435,31 @@ mod tests {
                 verification_key: VerificationKey(Cow::Borrowed(&[0; 32])),
             }))
         );
+        assert_snapshot!(
+            "value_impl_vote_extension_participation",
+            borsh_then_hex(&ValueImpl::VoteExtensionParticipation(
+                VoteExtensionParticipation {
+                    window_blocks: 1,
+                    max_missed_blocks: 2,
+                    power_reduction_percent: 3,
+                }
+            ))
+        );
+        assert_snapshot!(
+            "value_impl_validator_participation",
+            borsh_then_hex(&ValueImpl::ValidatorParticipation(ValidatorParticipation {
+                window_blocks: 1,
+                recorded_blocks: 2,
+                missed_blocks: 3,
+                missed_bitmap: Cow::Borrowed(&[4]),
+            }))
+        );
+        assert_snapshot!(
+            "value_impl_validator_power_penalty",
+            borsh_then_hex(&ValueImpl::ValidatorPowerPenalty(ValidatorPowerPenalty {
+                remaining_power_percent: 40,
+            }))
+        );
     }
 
     // Note: This test must be here instead of in `crate::storage` since `ValueImpl` is not
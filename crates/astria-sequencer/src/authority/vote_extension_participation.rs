@@ -0,0 +1,613 @@
+//! Tracking of validator participation in price vote extensions.
+//!
+//! While a [`VoteExtensionParticipation`] requirement is set by the sudo address, every validator
+//! listed in the last commit decided by CometBFT is recorded as having provided a price vote
+//! extension or not, over a sliding window of the most recent `window_blocks` blocks. A validator
+//! which missed more than `max_missed_blocks` of a full window has its power reduced by
+//! `power_reduction_percent` through the block's validator updates, after which its participation
+//! is recorded afresh.
+//!
+//! Participation is taken from the decided last commit rather than from the extended commit info
+//! included in the proposal: the latter is chosen by the proposer, which could omit the vote
+//! extensions of honest validators to have them penalized. While vote extensions are enabled,
+//! CometBFT only counts a precommit towards the commit if it carries a vote extension which passed
+//! `VerifyVoteExtension`, so a validator whose commit signature is present provided one.
+//!
+//! The reductions a validator has received are persisted as the percentage of its power it
+//! retains, which the staking component applies whenever it recomputes the validator's power from
+//! its stake. Bonding more stake therefore scales a penalized validator's power up, but does not
+//! lift the penalty.
+//!
+//! A validator whose power is reduced to zero is removed from the validator set, unless it is the
+//! only validator.
+use astria_core::protocol::transaction::v1::action::{
+    ValidatorUpdate,
+    VoteExtensionParticipation,
+};
+use astria_eyre::eyre::{
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::StateWrite;
+use tendermint::abci::{
+    self,
+    types::{
+        BlockSignatureInfo::Flag,
+        CommitInfo,
+        VoteInfo,
+    },
+};
+use tracing::{
+    info,
+    instrument,
+    warn,
+    Level,
+};
+
+use super::{
+    StateReadExt as _,
+    StateWriteExt as _,
+};
+use crate::{
+    accounts::AddressBytes as _,
+    checked_actions::use_pre_aspen_validator_updates,
+};
+
+/// The vote extensions provided and missed by a validator in the current participation window.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ValidatorParticipation {
+    /// The size of the window the participation was recorded for.
+    pub(super) window_blocks: u64,
+    /// The number of blocks recorded since participation was last reset.
+    pub(super) recorded_blocks: u64,
+    /// The number of blocks in the window for which the validator missed its vote extension.
+    pub(super) missed_blocks: u64,
+    /// One bit per block in the window, set if the validator missed its vote extension.
+    pub(super) missed_bitmap: Vec<u8>,
+}
+
+impl ValidatorParticipation {
+    fn new(window_blocks: u64) -> Self {
+        let bitmap_len = usize::try_from(window_blocks.div_ceil(8))
+            .expect("window is bounded by `VoteExtensionParticipation::MAX_WINDOW_BLOCKS`");
+        Self {
+            window_blocks,
+            recorded_blocks: 0,
+            missed_blocks: 0,
+            missed_bitmap: vec![0; bitmap_len],
+        }
+    }
+
+    fn is_window_full(&self) -> bool {
+        self.recorded_blocks >= self.window_blocks
+    }
+
+    /// Records whether the validator missed its vote extension in the next block, replacing the
+    /// oldest block in the window once the window is full.
+    fn record(&mut self, missed: bool) -> Result<()> {
+        let index = self
+            .recorded_blocks
+            .checked_rem(self.window_blocks)
+            .ok_or_eyre("participation window must not be empty")?;
+        let byte = self
+            .missed_bitmap
+            .get_mut(usize::try_from(index / 8).wrap_err("window index must fit in a usize")?)
+            .ok_or_eyre("participation bitmap is shorter than its window")?;
+        let mask = 1_u8 << (index % 8);
+
+        let previously_missed = *byte & mask != 0;
+        if missed {
+            *byte |= mask;
+        } else {
+            *byte &= !mask;
+        }
+        match (previously_missed, missed) {
+            (false, true) => self.missed_blocks = self.missed_blocks.saturating_add(1),
+            (true, false) => self.missed_blocks = self.missed_blocks.saturating_sub(1),
+            _ => {}
+        }
+        self.recorded_blocks = self.recorded_blocks.saturating_add(1);
+        Ok(())
+    }
+}
+
+/// Records which validators provided price vote extensions in the decided `last_commit`, reducing
+/// the power of validators which missed too many of them.
+///
+/// Does nothing if no [`VoteExtensionParticipation`] requirement is set, or before the Aspen
+/// upgrade has been applied.
+#[instrument(skip_all, err(level = Level::WARN))]
+pub(crate) async fn record_vote_extension_participation<S: StateWrite>(
+    mut state: S,
+    last_commit: &CommitInfo,
+) -> Result<()> {
+    let Some(requirement) = state
+        .get_vote_extension_participation()
+        .await
+        .wrap_err("failed to read vote extension participation from storage")?
+    else {
+        return Ok(());
+    };
+    if use_pre_aspen_validator_updates(&state)
+        .await
+        .wrap_err("failed to get upgrade status")?
+    {
+        return Ok(());
+    }
+
+    for vote in &last_commit.votes {
+        let address = vote.validator.address;
+        let Some(validator) = state
+            .get_validator(&address)
+            .await
+            .wrap_err("failed to read validator info from storage")?
+        else {
+            // The validator has been removed since it voted; drop its stale participation.
+            state.delete_validator_participation(&address);
+            continue;
+        };
+
+        let mut participation = match state
+            .get_validator_participation(&address)
+            .await
+            .wrap_err("failed to read validator participation from storage")?
+        {
+            Some(participation) if participation.window_blocks == requirement.window_blocks => {
+                participation
+            }
+            _ => ValidatorParticipation::new(requirement.window_blocks),
+        };
+        participation.record(!provided_vote_extension(vote))?;
+
+        if participation.is_window_full()
+            && participation.missed_blocks > requirement.max_missed_blocks
+        {
+            reduce_power(&mut state, validator, &participation, &requirement).await?;
+            state.delete_validator_participation(&address);
+        } else {
+            state
+                .put_validator_participation(&address, &participation)
+                .wrap_err("failed to write validator participation to storage")?;
+        }
+    }
+    Ok(())
+}
+
+fn provided_vote_extension(vote: &VoteInfo) -> bool {
+    vote.sig_info == Flag(tendermint::block::BlockIdFlag::Commit)
+}
+
+/// Reduces the power of `validator` in state, records the validator's accumulated penalty and adds
+/// the change to the block's validator updates.
+async fn reduce_power<S: StateWrite>(
+    mut state: S,
+    mut validator: ValidatorUpdate,
+    participation: &ValidatorParticipation,
+    requirement: &VoteExtensionParticipation,
+) -> Result<()> {
+    let remaining_percent = 100_u32.saturating_sub(requirement.power_reduction_percent);
+    let penalty = state
+        .get_validator_power_penalty(&validator.verification_key)
+        .await
+        .wrap_err("failed to read validator power penalty from storage")?
+        .unwrap_or(100)
+        .checked_mul(remaining_percent)
+        .map(|penalty| penalty / 100)
+        .ok_or_eyre("validator power penalty must not overflow")?;
+    let reduced_power = u64::from(validator.power)
+        .checked_mul(u64::from(remaining_percent))
+        .and_then(|power| power.checked_div(100))
+        .and_then(|power| u32::try_from(power).ok())
+        .ok_or_eyre("reduced validator power must not overflow")?;
+
+    if reduced_power == 0 {
+        let validator_count = state
+            .get_validator_count()
+            .await
+            .wrap_err("failed to read validator count from storage")?;
+        if validator_count <= 1 {
+            warn!(
+                address = %validator.verification_key.display_address(),
+                missed_blocks = participation.missed_blocks,
+                "not removing the only validator despite missing vote extensions",
+            );
+            return Ok(());
+        }
+        state
+            .put_validator_power_penalty(&validator.verification_key, penalty)
+            .wrap_err("failed to write validator power penalty to storage")?;
+        state.remove_validator(&validator.verification_key).await;
+        state
+            .put_validator_count(validator_count.saturating_sub(1))
+            .wrap_err("failed to write validator count to storage")?;
+        validator.power = 0;
+    } else {
+        validator.power = reduced_power;
+        state
+            .put_validator_power_penalty(&validator.verification_key, penalty)
+            .wrap_err("failed to write validator power penalty to storage")?;
+        state
+            .put_validator(&validator)
+            .wrap_err("failed to write validator info to storage")?;
+    }
+    info!(
+        address = %validator.verification_key.display_address(),
+        missed_blocks = participation.missed_blocks,
+        window_blocks = participation.window_blocks,
+        power = reduced_power,
+        "reduced power of validator for missing vote extensions",
+    );
+    state.record(abci::Event::new(
+        "vote_extension_participation_penalty",
+        [
+            (
+                "validator",
+                validator.verification_key.display_address().to_string(),
+            ),
+            ("missed_blocks", participation.missed_blocks.to_string()),
+            ("window_blocks", participation.window_blocks.to_string()),
+            ("power", reduced_power.to_string()),
+        ],
+    ));
+
+    // Add validator update in nonverifiable state to be used in end_block.
+    let mut validator_updates = state
+        .get_block_validator_updates()
+        .await
+        .wrap_err("failed to read validator updates from storage")?;
+    validator_updates.insert(validator);
+    state
+        .put_block_validator_updates(validator_updates)
+        .wrap_err("failed to write validator updates to storage")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use tendermint::abci::types::Validator;
+
+    use super::*;
+    use crate::test_utils::{
+        Fixture,
+        ALICE,
+        BOB,
+        CAROL,
+    };
+
+    fn requirement(
+        window_blocks: u64,
+        max_missed_blocks: u64,
+        power_reduction_percent: u32,
+    ) -> VoteExtensionParticipation {
+        VoteExtensionParticipation {
+            window_blocks,
+            max_missed_blocks,
+            power_reduction_percent,
+        }
+    }
+
+    fn vote(address: [u8; 20], provided: bool) -> VoteInfo {
+        VoteInfo {
+            validator: Validator {
+                address,
+                power: 10_u32.into(),
+            },
+            sig_info: if provided {
+                Flag(tendermint::block::BlockIdFlag::Commit)
+            } else {
+                Flag(tendermint::block::BlockIdFlag::Absent)
+            },
+        }
+    }
+
+    /// Returns a last commit in which ALICE and BOB provide vote extensions and CAROL does so only
+    /// if `carol_provided` is true.
+    fn last_commit(carol_provided: bool) -> CommitInfo {
+        CommitInfo {
+            round: 0_u16.into(),
+            votes: vec![
+                vote(ALICE.address_bytes(), true),
+                vote(BOB.address_bytes(), true),
+                vote(CAROL.address_bytes(), carol_provided),
+            ],
+        }
+    }
+
+    #[test]
+    fn record_should_slide_window() {
+        let mut participation = ValidatorParticipation::new(3);
+        participation.record(true).unwrap();
+        participation.record(false).unwrap();
+        participation.record(true).unwrap();
+        assert!(participation.is_window_full());
+        assert_eq!(2, participation.missed_blocks);
+
+        // Overwrites the first block, which was missed.
+        participation.record(false).unwrap();
+        assert_eq!(1, participation.missed_blocks);
+
+        // Overwrites the second block, which was not missed.
+        participation.record(true).unwrap();
+        assert_eq!(2, participation.missed_blocks);
+        assert_eq!(5, participation.recorded_blocks);
+    }
+
+    #[tokio::test]
+    async fn should_do_nothing_without_requirement() {
+        let mut fixture = Fixture::default_initialized().await;
+
+        record_vote_extension_participation(fixture.state_mut(), &last_commit(false))
+            .await
+            .unwrap();
+
+        assert!(fixture
+            .state()
+            .get_validator_participation(&CAROL.address_bytes())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn should_record_participation_of_validators() {
+        let mut fixture = Fixture::default_initialized().await;
+        fixture
+            .state_mut()
+            .put_vote_extension_participation(requirement(10, 5, 50))
+            .unwrap();
+
+        for _ in 0..3 {
+            record_vote_extension_participation(fixture.state_mut(), &last_commit(false))
+                .await
+                .unwrap();
+        }
+
+        let alice = fixture
+            .state()
+            .get_validator_participation(&ALICE.address_bytes())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(3, alice.recorded_blocks);
+        assert_eq!(0, alice.missed_blocks);
+        let carol = fixture
+            .state()
+            .get_validator_participation(&CAROL.address_bytes())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(3, carol.recorded_blocks);
+        assert_eq!(3, carol.missed_blocks);
+        assert!(fixture
+            .state()
+            .get_block_validator_updates()
+            .await
+            .unwrap()
+            .get(&CAROL.address_bytes())
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn should_reduce_power_of_absent_validator_once_window_is_full() {
+        let mut fixture = Fixture::default_initialized().await;
+        fixture
+            .state_mut()
+            .put_vote_extension_participation(requirement(4, 2, 50))
+            .unwrap();
+
+        for _ in 0..4 {
+            record_vote_extension_participation(fixture.state_mut(), &last_commit(false))
+                .await
+                .unwrap();
+        }
+
+        let carol = fixture
+            .state()
+            .get_validator(&CAROL.address_bytes())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(5, carol.power);
+        let validator_updates = fixture.state().get_block_validator_updates().await.unwrap();
+        assert_eq!(1, validator_updates.len());
+        assert_eq!(
+            5,
+            validator_updates.get(&CAROL.address_bytes()).unwrap().power
+        );
+        assert!(fixture
+            .state()
+            .get_validator_participation(&CAROL.address_bytes())
+            .await
+            .unwrap()
+            .is_none());
+
+        let events = fixture.into_events();
+        let event = events
+            .iter()
+            .find(|event| event.kind == "vote_extension_participation_penalty")
+            .unwrap();
+        assert!(event
+            .attributes
+            .iter()
+            .any(|attr| attr.key_bytes() == b"power" && attr.value_bytes() == b"5"));
+    }
+
+    #[tokio::test]
+    async fn should_accumulate_power_penalties() {
+        let mut fixture = Fixture::default_initialized().await;
+        fixture
+            .state_mut()
+            .put_vote_extension_participation(requirement(2, 1, 50))
+            .unwrap();
+
+        for _ in 0..4 {
+            record_vote_extension_participation(fixture.state_mut(), &last_commit(false))
+                .await
+                .unwrap();
+        }
+
+        let carol = fixture
+            .state()
+            .get_validator(&CAROL.address_bytes())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(2, carol.power);
+        assert_eq!(
+            Some(25),
+            fixture
+                .state()
+                .get_validator_power_penalty(&CAROL.address_bytes())
+                .await
+                .unwrap()
+        );
+        assert!(fixture
+            .state()
+            .get_validator_power_penalty(&ALICE.address_bytes())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn should_not_reduce_power_of_validator_within_threshold() {
+        let mut fixture = Fixture::default_initialized().await;
+        fixture
+            .state_mut()
+            .put_vote_extension_participation(requirement(4, 2, 50))
+            .unwrap();
+
+        for carol_provided in [false, true, false, true, true] {
+            record_vote_extension_participation(fixture.state_mut(), &last_commit(carol_provided))
+                .await
+                .unwrap();
+        }
+
+        let carol = fixture
+            .state()
+            .get_validator(&CAROL.address_bytes())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(10, carol.power);
+        assert!(fixture
+            .state()
+            .get_block_validator_updates()
+            .await
+            .unwrap()
+            .get(&CAROL.address_bytes())
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn should_remove_absent_validator_if_power_is_reduced_to_zero() {
+        let mut fixture = Fixture::default_initialized().await;
+        fixture
+            .state_mut()
+            .put_vote_extension_participation(requirement(2, 1, 100))
+            .unwrap();
+        let validator_count = fixture.state().get_validator_count().await.unwrap();
+
+        for _ in 0..2 {
+            record_vote_extension_participation(fixture.state_mut(), &last_commit(false))
+                .await
+                .unwrap();
+        }
+
+        assert!(fixture
+            .state()
+            .get_validator(&CAROL.address_bytes())
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            validator_count - 1,
+            fixture.state().get_validator_count().await.unwrap()
+        );
+        assert_eq!(
+            0,
+            fixture
+                .state()
+                .get_block_validator_updates()
+                .await
+                .unwrap()
+                .get(&CAROL.address_bytes())
+                .unwrap()
+                .power
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_remove_only_validator() {
+        let mut fixture = Fixture::uninitialized(None).await;
+        fixture
+            .chain_initializer()
+            .with_genesis_validators(Some((ALICE.verification_key(), 10)))
+            .init()
+            .await;
+        let _ = fixture.run_until_aspen_applied().await;
+        fixture
+            .state_mut()
+            .put_vote_extension_participation(requirement(2, 1, 100))
+            .unwrap();
+
+        let last_commit = CommitInfo {
+            round: 0_u16.into(),
+            votes: vec![vote(ALICE.address_bytes(), false)],
+        };
+        for _ in 0..2 {
+            record_vote_extension_participation(fixture.state_mut(), &last_commit)
+                .await
+                .unwrap();
+        }
+
+        let alice = fixture
+            .state()
+            .get_validator(&ALICE.address_bytes())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(10, alice.power);
+        assert_eq!(1, fixture.state().get_validator_count().await.unwrap());
+        assert!(fixture
+            .state()
+            .get_block_validator_updates()
+            .await
+            .unwrap()
+            .get(&ALICE.address_bytes())
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn should_reset_participation_if_window_changes() {
+        let mut fixture = Fixture::default_initialized().await;
+        fixture
+            .state_mut()
+            .put_vote_extension_participation(requirement(10, 5, 50))
+            .unwrap();
+        for _ in 0..3 {
+            record_vote_extension_participation(fixture.state_mut(), &last_commit(false))
+                .await
+                .unwrap();
+        }
+
+        fixture
+            .state_mut()
+            .put_vote_extension_participation(requirement(20, 5, 50))
+            .unwrap();
+        record_vote_extension_participation(fixture.state_mut(), &last_commit(false))
+            .await
+            .unwrap();
+
+        let carol = fixture
+            .state()
+            .get_validator_participation(&CAROL.address_bytes())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(20, carol.window_blocks);
+        assert_eq!(1, carol.recorded_blocks);
+        assert_eq!(1, carol.missed_blocks);
+    }
+}
@@ -9,7 +9,6 @@ use astria_eyre::eyre::{
     eyre,
     WrapErr as _,
 };
-use cnidarium::Storage;
 use prost::Message as _;
 use tendermint::abci::{
     request,
@@ -18,12 +17,17 @@ use tendermint::abci::{
 };
 use tracing::instrument;
 
+use super::storage::keys;
 use crate::{
     accounts::AddressBytes as _,
     address::StateReadExt,
     app::StateReadExt as _,
     assets::StateReadExt as _,
     bridge::StateReadExt as _,
+    storage::{
+        prove_keys,
+        QueryState,
+    },
 };
 
 fn error_query_response(
@@ -47,7 +51,7 @@ fn error_query_response(
 // this could be significantly shortened.
 #[instrument(skip_all, fields(address = %address.display_address()))]
 async fn get_bridge_account_info(
-    snapshot: cnidarium::Snapshot,
+    snapshot: QueryState,
     address: &Address,
 ) -> Result<Option<BridgeAccountInfo>, response::Query> {
     let rollup_id = match snapshot.get_bridge_account_rollup_id(address).await {
@@ -166,7 +170,7 @@ async fn get_bridge_account_info(
 
 #[instrument(skip_all)]
 pub(crate) async fn bridge_account_info_request(
-    storage: Storage,
+    snapshot: QueryState,
     request: request::Query,
     params: Vec<(String, String)>,
 ) -> response::Query {
@@ -177,7 +181,6 @@ pub(crate) async fn bridge_account_info_request(
         Err(err_rsp) => return err_rsp,
     };
 
-    let snapshot = storage.latest_snapshot();
     let height = match snapshot.get_block_height().await {
         Ok(height) => height,
         Err(err) => {
@@ -189,13 +192,34 @@ pub(crate) async fn bridge_account_info_request(
         }
     };
 
-    let info = match get_bridge_account_info(snapshot, &address).await {
+    let info = match get_bridge_account_info(snapshot.clone(), &address).await {
         Ok(info) => info,
         Err(err) => {
             return err;
         }
     };
 
+    let proof = if request.prove {
+        let keys = [
+            keys::rollup_id(&address),
+            keys::asset_id(&address),
+            keys::bridge_account_sudo_address(&address),
+            keys::bridge_account_withdrawer_address(&address),
+        ];
+        match prove_keys(&snapshot, keys).await {
+            Ok(proof) => Some(proof),
+            Err(err) => {
+                return error_query_response(
+                    Some(err),
+                    AbciErrorCode::INTERNAL_ERROR,
+                    "failed to create proof for provided address",
+                );
+            }
+        }
+    } else {
+        None
+    };
+
     let resp = BridgeAccountInfoResponse {
         height,
         info,
@@ -208,6 +232,7 @@ pub(crate) async fn bridge_account_info_request(
         code: 0.into(),
         key: request.path.clone().into_bytes().into(),
         value: payload,
+        proof,
         height,
         ..response::Query::default()
     }
@@ -215,7 +240,7 @@ pub(crate) async fn bridge_account_info_request(
 
 #[instrument(skip_all)]
 pub(crate) async fn bridge_account_last_tx_hash_request(
-    storage: Storage,
+    snapshot: QueryState,
     request: request::Query,
     params: Vec<(String, String)>,
 ) -> response::Query {
@@ -226,8 +251,6 @@ pub(crate) async fn bridge_account_last_tx_hash_request(
         Err(err_rsp) => return err_rsp,
     };
 
-    // use latest snapshot, as this is a query for latest tx
-    let snapshot = storage.latest_snapshot();
     let height = match snapshot.get_block_height().await {
         Ok(height) => height,
         Err(err) => {
@@ -360,7 +383,8 @@ mod tests {
         };
 
         let params = vec![("address".to_string(), bridge_address.to_string())];
-        let resp = bridge_account_info_request(storage.clone(), query, params).await;
+        let resp =
+            bridge_account_info_request(storage.latest_snapshot().into(), query, params).await;
         assert_eq!(resp.code, 0.into(), "{}", resp.log);
 
         let proto = RawBridgeAccountInfoResponse::decode(resp.value).unwrap();
@@ -377,6 +401,43 @@ mod tests {
         assert_eq!(native, expected);
     }
 
+    #[tokio::test]
+    async fn bridge_account_info_request_returns_proof_if_requested() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        state.put_block_height(1).unwrap();
+        storage.commit(state).await.unwrap();
+
+        let query = request::Query {
+            data: vec![].into(),
+            path: "path".to_string(),
+            height: 0u32.into(),
+            prove: true,
+        };
+
+        let bridge_address = astria_address(&[0u8; 20]);
+        let params = vec![("address".to_string(), bridge_address.to_string())];
+        let resp =
+            bridge_account_info_request(storage.latest_snapshot().into(), query, params).await;
+        assert_eq!(resp.code, 0.into(), "{}", resp.log);
+
+        let proof_keys: Vec<_> = resp
+            .proof
+            .unwrap()
+            .ops
+            .into_iter()
+            .map(|op| op.key)
+            .collect();
+        let expected_keys = vec![
+            keys::rollup_id(&bridge_address).into_bytes(),
+            keys::asset_id(&bridge_address).into_bytes(),
+            keys::bridge_account_sudo_address(&bridge_address).into_bytes(),
+            keys::bridge_account_withdrawer_address(&bridge_address).into_bytes(),
+        ];
+        assert_eq!(proof_keys, expected_keys);
+    }
+
     #[tokio::test]
     async fn bridge_account_last_tx_hash_ok() {
         let storage = cnidarium::TempStorage::new().await.unwrap();
@@ -401,7 +462,9 @@ mod tests {
         };
 
         let params = vec![("address".to_string(), bridge_address.to_string())];
-        let resp = bridge_account_last_tx_hash_request(storage.clone(), query, params).await;
+        let resp =
+            bridge_account_last_tx_hash_request(storage.latest_snapshot().into(), query, params)
+                .await;
         assert_eq!(resp.code, 0.into(), "{}", resp.log);
 
         let proto = RawBridgeAccountLastTxHashResponse::decode(resp.value).unwrap();
@@ -1,4 +1,7 @@
-use astria_core::primitive::v1::RollupId;
+use astria_core::{
+    primitive::v1::RollupId,
+    protocol::proof,
+};
 use base64::{
     display::Base64Display,
     engine::general_purpose::URL_SAFE,
@@ -10,40 +13,35 @@ use crate::{
 };
 
 pub(in crate::bridge) const BRIDGE_ACCOUNT_PREFIX: &str = "bridge/account/";
-const BRIDGE_ACCOUNT_SUDO_PREFIX: &str = "bridge/sudo/";
-const BRIDGE_ACCOUNT_WITHDRAWER_PREFIX: &str = "bridge/withdrawer/";
 
 pub(in crate::bridge) const DEPOSITS_EPHEMERAL: &str = "bridge/deposits";
 const DEPOSIT_PREFIX: &str = "bridge/deposit/";
 
+// The rollup ID, asset, sudo and withdrawer addresses of bridge accounts are returned with proofs,
+// so their keys are defined in `astria_core::protocol::proof`.
+
 /// Example: `bridge/account/gGhH....zZ4=/rollup_id`.
 ///                         |base64 chars|
 pub(in crate::bridge) fn rollup_id<T: AddressBytes>(address: &T) -> String {
-    format!(
-        "{}/rollup_id",
-        AccountPrefixer::new(BRIDGE_ACCOUNT_PREFIX, address)
-    )
+    proof::keys::bridge_account_rollup_id(address.address_bytes())
 }
 
 /// Example: `bridge/account/gGhH....zZ4=/asset_id`.
 ///                         |base64 chars|
 pub(in crate::bridge) fn asset_id<T: AddressBytes>(address: &T) -> String {
-    format!(
-        "{}/asset_id",
-        AccountPrefixer::new(BRIDGE_ACCOUNT_PREFIX, address)
-    )
+    proof::keys::bridge_account_asset_id(address.address_bytes())
 }
 
 /// Example: `bridge/sudo/gGhH....zZ4=`.
 ///                      |base64 chars|
 pub(in crate::bridge) fn bridge_account_sudo_address<T: AddressBytes>(address: &T) -> String {
-    AccountPrefixer::new(BRIDGE_ACCOUNT_SUDO_PREFIX, address).to_string()
+    proof::keys::bridge_account_sudo_address(address.address_bytes())
 }
 
 /// Example: `bridge/withdrawer/gGhH....zZ4=`.
 ///                            |base64 chars|
 pub(in crate::bridge) fn bridge_account_withdrawer_address<T: AddressBytes>(address: &T) -> String {
-    AccountPrefixer::new(BRIDGE_ACCOUNT_WITHDRAWER_PREFIX, address).to_string()
+    proof::keys::bridge_account_withdrawer_address(address.address_bytes())
 }
 
 /// Example: `bridge/account/gGhH....zZ4=/withdrawal_event/<event id>`.
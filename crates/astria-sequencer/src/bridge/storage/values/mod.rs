@@ -98,4 +98,31 @@ mod tests {
             ))))
         );
     }
+    #[test]
+    fn proven_values_match_encoding_shared_with_clients() {
+        use astria_core::protocol::proof::values;
+
+        use crate::storage::StoredValue;
+        let rollup_id = DomainRollupId::new([1; ROLLUP_ID_LEN]);
+        assert_eq!(
+            values::bridge_account_rollup_id(&rollup_id),
+            StoredValue::from(RollupId::from(&rollup_id))
+                .serialize()
+                .unwrap()
+        );
+        let asset = DomainIbcPrefixed::new([2; 32]);
+        assert_eq!(
+            values::bridge_account_asset_id(&asset),
+            StoredValue::from(IbcPrefixedDenom::from(&asset))
+                .serialize()
+                .unwrap()
+        );
+        let address = [3; ADDRESS_LENGTH];
+        assert_eq!(
+            values::bridge_account_address(&address),
+            StoredValue::from(AddressBytes::from(&address))
+                .serialize()
+                .unwrap()
+        );
+    }
 }
@@ -1,5 +1,6 @@
 use astria_core::protocol::transaction::v1::{
     action::{
+        Bond,
         BridgeLock,
         BridgeSudoChange,
         BridgeTransfer,
@@ -7,16 +8,25 @@ use astria_core::protocol::transaction::v1::{
         CurrencyPairsChange,
         FeeAssetChange,
         FeeChange,
+        GovernanceParametersChange,
         IbcRelayerChange,
         IbcSudoChange,
+        Ics20RateLimitChange,
         Ics20Withdrawal,
         InitBridgeAccount,
         MarketsChange,
+        ProposalSubmission,
+        ProposalVote,
         RecoverIbcClient,
+        RegisterMultisigAccount,
         RollupDataSubmission,
+        ScheduledTransfer,
+        StakingParametersChange,
         SudoAddressChange,
         Transfer,
+        Unbond,
         ValidatorUpdate,
+        VoteExtensionParticipationChange,
     },
     Action,
 };
@@ -43,6 +53,16 @@ pub(crate) enum ActionRef<'a> {
     RecoverIbcClient(&'a RecoverIbcClient),
     CurrencyPairsChange(&'a CurrencyPairsChange),
     MarketsChange(&'a MarketsChange),
+    RegisterMultisigAccount(&'a RegisterMultisigAccount),
+    ScheduledTransfer(&'a ScheduledTransfer),
+    Ics20RateLimitChange(&'a Ics20RateLimitChange),
+    VoteExtensionParticipationChange(&'a VoteExtensionParticipationChange),
+    Bond(&'a Bond),
+    Unbond(&'a Unbond),
+    ProposalSubmission(&'a ProposalSubmission),
+    ProposalVote(&'a ProposalVote),
+    StakingParametersChange(&'a StakingParametersChange),
+    GovernanceParametersChange(&'a GovernanceParametersChange),
 }
 
 impl<'a> From<&'a Action> for ActionRef<'a> {
@@ -66,6 +86,20 @@ impl<'a> From<&'a Action> for ActionRef<'a> {
             Action::RecoverIbcClient(action) => ActionRef::RecoverIbcClient(action),
             Action::CurrencyPairsChange(action) => ActionRef::CurrencyPairsChange(action),
             Action::MarketsChange(action) => ActionRef::MarketsChange(action),
+            Action::RegisterMultisigAccount(action) => ActionRef::RegisterMultisigAccount(action),
+            Action::ScheduledTransfer(action) => ActionRef::ScheduledTransfer(action),
+            Action::Ics20RateLimitChange(action) => ActionRef::Ics20RateLimitChange(action),
+            Action::VoteExtensionParticipationChange(action) => {
+                ActionRef::VoteExtensionParticipationChange(action)
+            }
+            Action::Bond(action) => ActionRef::Bond(action),
+            Action::Unbond(action) => ActionRef::Unbond(action),
+            Action::ProposalSubmission(action) => ActionRef::ProposalSubmission(action),
+            Action::ProposalVote(action) => ActionRef::ProposalVote(action),
+            Action::StakingParametersChange(action) => ActionRef::StakingParametersChange(action),
+            Action::GovernanceParametersChange(action) => {
+                ActionRef::GovernanceParametersChange(action)
+            }
         }
     }
 }
@@ -123,6 +157,32 @@ impl<'a> From<&'a CheckedAction> for ActionRef<'a> {
             CheckedAction::MarketsChange(checked_action) => {
                 ActionRef::MarketsChange(checked_action.action())
             }
+            CheckedAction::RegisterMultisigAccount(checked_action) => {
+                ActionRef::RegisterMultisigAccount(checked_action.action())
+            }
+            CheckedAction::ScheduledTransfer(checked_action) => {
+                ActionRef::ScheduledTransfer(checked_action.action())
+            }
+            CheckedAction::Ics20RateLimitChange(checked_action) => {
+                ActionRef::Ics20RateLimitChange(checked_action.action())
+            }
+            CheckedAction::VoteExtensionParticipationChange(checked_action) => {
+                ActionRef::VoteExtensionParticipationChange(checked_action.action())
+            }
+            CheckedAction::Bond(checked_action) => ActionRef::Bond(checked_action.action()),
+            CheckedAction::Unbond(checked_action) => ActionRef::Unbond(checked_action.action()),
+            CheckedAction::ProposalSubmission(checked_action) => {
+                ActionRef::ProposalSubmission(checked_action.action())
+            }
+            CheckedAction::ProposalVote(checked_action) => {
+                ActionRef::ProposalVote(checked_action.action())
+            }
+            CheckedAction::StakingParametersChange(checked_action) => {
+                ActionRef::StakingParametersChange(checked_action.action())
+            }
+            CheckedAction::GovernanceParametersChange(checked_action) => {
+                ActionRef::GovernanceParametersChange(checked_action.action())
+            }
         }
     }
 }
@@ -0,0 +1,359 @@
+use astria_core::{
+    primitive::v1::{
+        asset::IbcPrefixed,
+        ADDRESS_LEN,
+    },
+    protocol::{
+        staking::v1::Delegation,
+        transaction::v1::action::Bond,
+    },
+};
+use astria_eyre::eyre::{
+    ensure,
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    use_pre_aspen_validator_updates,
+    AssetTransfer,
+    TransactionSignerAddressBytes,
+};
+use crate::{
+    accounts::StateWriteExt as _,
+    assets::StateReadExt as _,
+    authority::StateReadExt as _,
+    bridge::StateReadExt as _,
+    staking::{
+        StateReadExt as _,
+        StateWriteExt as _,
+        ValidatorStake,
+    },
+};
+
+#[derive(Debug)]
+pub(crate) struct CheckedBond {
+    action: Bond,
+    tx_signer: TransactionSignerAddressBytes,
+    native_asset: IbcPrefixed,
+}
+
+impl CheckedBond {
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn new<S: StateRead>(
+        action: Bond,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self> {
+        // Run immutable checks.
+        ensure!(action.amount > 0, "cannot bond a zero amount");
+
+        let native_asset = state
+            .get_native_asset()
+            .await
+            .wrap_err("failed to read native asset from storage")?
+            .ok_or_eyre("native asset must be set")?
+            .to_ibc_prefixed();
+
+        let checked_action = Self {
+            action,
+            tx_signer: tx_signer.into(),
+            native_asset,
+        };
+        checked_action.run_mutable_checks(state).await?;
+
+        Ok(checked_action)
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn run_mutable_checks<S: StateRead>(&self, state: S) -> Result<()> {
+        ensure!(
+            state
+                .get_staking_parameters()
+                .await
+                .wrap_err("failed to read staking parameters from storage")?
+                .is_some(),
+            "staking is not enabled",
+        );
+        ensure!(
+            !use_pre_aspen_validator_updates(&state)
+                .await
+                .wrap_err("failed to get upgrade status")?,
+            "staking is not available before the Aspen upgrade",
+        );
+
+        // Ensure the tx signer account is not a bridge account.
+        ensure!(
+            state
+                .get_bridge_account_rollup_id(&self.tx_signer)
+                .await
+                .wrap_err("failed to read bridge account rollup id from storage")?
+                .is_none(),
+            "cannot bond from a bridge account",
+        );
+
+        // Only the validator itself may bond to a validator which is not yet in the validator set.
+        let is_validator = state
+            .get_validator(&self.action.validator)
+            .await
+            .wrap_err("failed to read validator info from storage")?
+            .is_some();
+        ensure!(
+            is_validator || self.action.validator.address_bytes() == self.tx_signer.as_bytes(),
+            "cannot bond to a non-existing validator unless signed by the validator itself",
+        );
+        Ok(())
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        self.run_mutable_checks(&state).await?;
+
+        state
+            .decrease_balance(&self.tx_signer, &self.native_asset, self.action.amount)
+            .await
+            .wrap_err("failed to decrease signer account balance")?;
+
+        let delegated_amount = state
+            .get_delegated_amount(&self.tx_signer, &self.action.validator)
+            .await
+            .wrap_err("failed to read delegation from storage")?
+            .checked_add(self.action.amount)
+            .ok_or_eyre("overflow when increasing delegated amount")?;
+        state
+            .put_delegation(
+                &self.tx_signer,
+                &Delegation {
+                    validator: self.action.validator.clone(),
+                    amount: delegated_amount,
+                },
+            )
+            .wrap_err("failed to write delegation to storage")?;
+
+        let stake = state
+            .get_validator_stake(&self.action.validator)
+            .await
+            .wrap_err("failed to read validator stake from storage")?
+            .map_or(0, |stake| stake.stake)
+            .checked_add(self.action.amount)
+            .ok_or_eyre("overflow when increasing validator stake")?;
+        state
+            .put_validator_stake(&ValidatorStake {
+                validator: self.action.validator.clone(),
+                stake,
+            })
+            .wrap_err("failed to write validator stake to storage")?;
+
+        state.record_stake_change(self.action.validator.clone());
+        Ok(())
+    }
+
+    pub(super) fn action(&self) -> &Bond {
+        &self.action
+    }
+}
+
+impl AssetTransfer for CheckedBond {
+    fn transfer_asset_and_amount(&self) -> Option<(IbcPrefixed, u128)> {
+        Some((self.native_asset, self.action.amount))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        accounts::StateReadExt as _,
+        test_utils::{
+            assert_error_contains,
+            dummy_bond,
+            nria,
+            Fixture,
+            ALICE,
+            BOB_ADDRESS,
+            BOB_ADDRESS_BYTES,
+        },
+    };
+
+    async fn staking_fixture() -> Fixture {
+        let mut fixture = Fixture::uninitialized(None).await;
+        fixture
+            .chain_initializer()
+            .with_staking(10, 100)
+            .init()
+            .await;
+        let _ = fixture.run_until_aspen_applied().await;
+        fixture
+    }
+
+    fn bond_to_alice(amount: u128) -> Bond {
+        Bond {
+            validator: ALICE.verification_key(),
+            amount,
+            ..dummy_bond()
+        }
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_amount_is_zero() {
+        let fixture = staking_fixture().await;
+
+        let err = fixture
+            .new_checked_action(bond_to_alice(0), *BOB_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "cannot bond a zero amount");
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_staking_not_enabled() {
+        let fixture = Fixture::default_initialized().await;
+
+        let err = fixture
+            .new_checked_action(bond_to_alice(100), *BOB_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "staking is not enabled");
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_aspen_not_applied() {
+        let mut fixture = Fixture::uninitialized(None).await;
+        fixture
+            .chain_initializer()
+            .with_staking(10, 100)
+            .init()
+            .await;
+        assert!(use_pre_aspen_validator_updates(fixture.state())
+            .await
+            .unwrap());
+
+        let err = fixture
+            .new_checked_action(bond_to_alice(100), *BOB_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "staking is not available before the Aspen upgrade");
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_signer_is_bridge_account() {
+        let mut fixture = staking_fixture().await;
+        fixture.bridge_initializer(*BOB_ADDRESS).init().await;
+
+        let err = fixture
+            .new_checked_action(bond_to_alice(100), *BOB_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "cannot bond from a bridge account");
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_validator_does_not_exist() {
+        let fixture = staking_fixture().await;
+
+        let err = fixture
+            .new_checked_action(dummy_bond(), *BOB_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(
+            &err,
+            "cannot bond to a non-existing validator unless signed by the validator itself",
+        );
+    }
+
+    #[tokio::test]
+    async fn should_execute() {
+        let mut fixture = staking_fixture().await;
+        let balance_before = fixture.get_nria_balance(&*BOB_ADDRESS_BYTES).await;
+
+        let checked_action: CheckedBond = fixture
+            .new_checked_action(bond_to_alice(150), *BOB_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .into();
+        checked_action.execute(fixture.state_mut()).await.unwrap();
+        let checked_action: CheckedBond = fixture
+            .new_checked_action(bond_to_alice(50), *BOB_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .into();
+        checked_action.execute(fixture.state_mut()).await.unwrap();
+
+        assert_eq!(
+            fixture
+                .state()
+                .get_account_balance(&*BOB_ADDRESS_BYTES, &nria())
+                .await
+                .unwrap(),
+            balance_before.checked_sub(200).unwrap()
+        );
+        assert_eq!(
+            fixture
+                .state()
+                .get_delegated_amount(&*BOB_ADDRESS_BYTES, &ALICE.verification_key())
+                .await
+                .unwrap(),
+            200
+        );
+        assert_eq!(
+            fixture
+                .state()
+                .get_validator_stake(&ALICE.verification_key())
+                .await
+                .unwrap()
+                .unwrap()
+                .stake,
+            200
+        );
+        assert!(fixture
+            .state()
+            .get_block_stake_changes()
+            .contains(&ALICE.verification_key()));
+    }
+
+    #[tokio::test]
+    async fn should_execute_self_bond_of_new_validator() {
+        let mut fixture = staking_fixture().await;
+        let validator = dummy_bond().validator;
+        let signer = *validator.address_bytes();
+        fixture
+            .state_mut()
+            .put_account_balance(&signer, &nria(), 1_000)
+            .unwrap();
+
+        let checked_action: CheckedBond = fixture
+            .new_checked_action(dummy_bond(), signer)
+            .await
+            .unwrap()
+            .into();
+        checked_action.execute(fixture.state_mut()).await.unwrap();
+
+        assert_eq!(
+            fixture
+                .state()
+                .get_delegated_amount(&signer, &validator)
+                .await
+                .unwrap(),
+            100
+        );
+        assert_eq!(
+            fixture
+                .state()
+                .get_validator_stake(&validator)
+                .await
+                .unwrap()
+                .unwrap()
+                .stake,
+            100
+        );
+    }
+}
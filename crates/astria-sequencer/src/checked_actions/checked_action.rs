@@ -8,6 +8,7 @@ use astria_core::{
     protocol::{
         fees::v1::FeeComponents,
         transaction::v1::action::{
+            Bond,
             BridgeLock,
             BridgeSudoChange,
             BridgeTransfer,
@@ -15,16 +16,25 @@ use astria_core::{
             CurrencyPairsChange,
             FeeAssetChange,
             FeeChange,
+            GovernanceParametersChange,
             IbcRelayerChange,
             IbcSudoChange,
+            Ics20RateLimitChange,
             Ics20Withdrawal,
             InitBridgeAccount,
             MarketsChange,
+            ProposalSubmission,
+            ProposalVote,
             RecoverIbcClient,
+            RegisterMultisigAccount,
             RollupDataSubmission,
+            ScheduledTransfer,
+            StakingParametersChange,
             SudoAddressChange,
             Transfer,
+            Unbond,
             ValidatorUpdate,
+            VoteExtensionParticipationChange,
         },
     },
 };
@@ -45,6 +55,7 @@ use super::{
     CheckedActionFeeError,
     CheckedActionInitialCheckError,
     CheckedActionMutableCheckError,
+    CheckedBond,
     CheckedBridgeLock,
     CheckedBridgeSudoChange,
     CheckedBridgeTransfer,
@@ -52,17 +63,26 @@ use super::{
     CheckedCurrencyPairsChange,
     CheckedFeeAssetChange,
     CheckedFeeChange,
+    CheckedGovernanceParametersChange,
     CheckedIbcRelay,
     CheckedIbcRelayerChange,
     CheckedIbcSudoChange,
+    CheckedIcs20RateLimitChange,
     CheckedIcs20Withdrawal,
     CheckedInitBridgeAccount,
     CheckedMarketsChange,
+    CheckedProposalSubmission,
+    CheckedProposalVote,
     CheckedRecoverIbcClient,
+    CheckedRegisterMultisigAccount,
     CheckedRollupDataSubmission,
+    CheckedScheduledTransfer,
+    CheckedStakingParametersChange,
     CheckedSudoAddressChange,
     CheckedTransfer,
+    CheckedUnbond,
     CheckedValidatorUpdate,
+    CheckedVoteExtensionParticipationChange,
 };
 use crate::{
     accounts::{
@@ -98,6 +118,16 @@ pub(crate) enum CheckedAction {
     RecoverIbcClient(CheckedRecoverIbcClient),
     CurrencyPairsChange(CheckedCurrencyPairsChange),
     MarketsChange(CheckedMarketsChange),
+    RegisterMultisigAccount(CheckedRegisterMultisigAccount),
+    ScheduledTransfer(CheckedScheduledTransfer),
+    Ics20RateLimitChange(CheckedIcs20RateLimitChange),
+    VoteExtensionParticipationChange(CheckedVoteExtensionParticipationChange),
+    Bond(CheckedBond),
+    Unbond(CheckedUnbond),
+    ProposalSubmission(CheckedProposalSubmission),
+    ProposalVote(CheckedProposalVote),
+    StakingParametersChange(CheckedStakingParametersChange),
+    GovernanceParametersChange(CheckedGovernanceParametersChange),
 }
 
 impl CheckedAction {
@@ -320,6 +350,126 @@ impl CheckedAction {
         Ok(Self::MarketsChange(checked_action))
     }
 
+    pub(crate) async fn new_register_multisig_account<S: StateRead>(
+        action: RegisterMultisigAccount,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self, CheckedActionInitialCheckError> {
+        let action_name = action.name();
+        let checked_action = CheckedRegisterMultisigAccount::new(action, tx_signer, state)
+            .await
+            .map_err(|source| CheckedActionInitialCheckError::new(action_name, source))?;
+        Ok(Self::RegisterMultisigAccount(checked_action))
+    }
+
+    pub(crate) async fn new_scheduled_transfer<S: StateRead>(
+        action: ScheduledTransfer,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self, CheckedActionInitialCheckError> {
+        let action_name = action.name();
+        let checked_action = CheckedScheduledTransfer::new(action, tx_signer, state)
+            .await
+            .map_err(|source| CheckedActionInitialCheckError::new(action_name, source))?;
+        Ok(Self::ScheduledTransfer(checked_action))
+    }
+
+    pub(crate) async fn new_ics20_rate_limit_change<S: StateRead>(
+        action: Ics20RateLimitChange,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self, CheckedActionInitialCheckError> {
+        let action_name = action.name();
+        let checked_action = CheckedIcs20RateLimitChange::new(action, tx_signer, state)
+            .await
+            .map_err(|source| CheckedActionInitialCheckError::new(action_name, source))?;
+        Ok(Self::Ics20RateLimitChange(checked_action))
+    }
+
+    pub(crate) async fn new_vote_extension_participation_change<S: StateRead>(
+        action: VoteExtensionParticipationChange,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self, CheckedActionInitialCheckError> {
+        let action_name = action.name();
+        let checked_action = CheckedVoteExtensionParticipationChange::new(action, tx_signer, state)
+            .await
+            .map_err(|source| CheckedActionInitialCheckError::new(action_name, source))?;
+        Ok(Self::VoteExtensionParticipationChange(checked_action))
+    }
+
+    pub(crate) async fn new_bond<S: StateRead>(
+        action: Bond,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self, CheckedActionInitialCheckError> {
+        let action_name = action.name();
+        let checked_action = CheckedBond::new(action, tx_signer, state)
+            .await
+            .map_err(|source| CheckedActionInitialCheckError::new(action_name, source))?;
+        Ok(Self::Bond(checked_action))
+    }
+
+    pub(crate) async fn new_unbond<S: StateRead>(
+        action: Unbond,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self, CheckedActionInitialCheckError> {
+        let action_name = action.name();
+        let checked_action = CheckedUnbond::new(action, tx_signer, state)
+            .await
+            .map_err(|source| CheckedActionInitialCheckError::new(action_name, source))?;
+        Ok(Self::Unbond(checked_action))
+    }
+
+    pub(crate) async fn new_proposal_submission<S: StateRead>(
+        action: ProposalSubmission,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self, CheckedActionInitialCheckError> {
+        let action_name = action.name();
+        let checked_action = CheckedProposalSubmission::new(action, tx_signer, state)
+            .await
+            .map_err(|source| CheckedActionInitialCheckError::new(action_name, source))?;
+        Ok(Self::ProposalSubmission(checked_action))
+    }
+
+    pub(crate) async fn new_proposal_vote<S: StateRead>(
+        action: ProposalVote,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self, CheckedActionInitialCheckError> {
+        let action_name = action.name();
+        let checked_action = CheckedProposalVote::new(action, tx_signer, state)
+            .await
+            .map_err(|source| CheckedActionInitialCheckError::new(action_name, source))?;
+        Ok(Self::ProposalVote(checked_action))
+    }
+
+    pub(crate) async fn new_staking_parameters_change<S: StateRead>(
+        action: StakingParametersChange,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self, CheckedActionInitialCheckError> {
+        let action_name = action.name();
+        let checked_action = CheckedStakingParametersChange::new(action, tx_signer, state)
+            .await
+            .map_err(|source| CheckedActionInitialCheckError::new(action_name, source))?;
+        Ok(Self::StakingParametersChange(checked_action))
+    }
+
+    pub(crate) async fn new_governance_parameters_change<S: StateRead>(
+        action: GovernanceParametersChange,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self, CheckedActionInitialCheckError> {
+        let action_name = action.name();
+        let checked_action = CheckedGovernanceParametersChange::new(action, tx_signer, state)
+            .await
+            .map_err(|source| CheckedActionInitialCheckError::new(action_name, source))?;
+        Ok(Self::GovernanceParametersChange(checked_action))
+    }
+
     pub(crate) async fn run_mutable_checks<S: StateRead>(
         &self,
         state: S,
@@ -355,6 +505,30 @@ impl CheckedAction {
                 checked_action.run_mutable_checks(state).await
             }
             Self::MarketsChange(checked_action) => checked_action.run_mutable_checks(state).await,
+            Self::RegisterMultisigAccount(checked_action) => {
+                checked_action.run_mutable_checks(state).await
+            }
+            Self::ScheduledTransfer(checked_action) => {
+                checked_action.run_mutable_checks(state).await
+            }
+            Self::Ics20RateLimitChange(checked_action) => {
+                checked_action.run_mutable_checks(state).await
+            }
+            Self::VoteExtensionParticipationChange(checked_action) => {
+                checked_action.run_mutable_checks(state).await
+            }
+            Self::Bond(checked_action) => checked_action.run_mutable_checks(state).await,
+            Self::Unbond(checked_action) => checked_action.run_mutable_checks(state).await,
+            Self::ProposalSubmission(checked_action) => {
+                checked_action.run_mutable_checks(state).await
+            }
+            Self::ProposalVote(checked_action) => checked_action.run_mutable_checks(state).await,
+            Self::StakingParametersChange(checked_action) => {
+                checked_action.run_mutable_checks(state).await
+            }
+            Self::GovernanceParametersChange(checked_action) => {
+                checked_action.run_mutable_checks(state).await
+            }
         }
         .map_err(|source| CheckedActionMutableCheckError {
             action_name: self.name(),
@@ -366,7 +540,7 @@ impl CheckedAction {
     pub(crate) async fn pay_fees_and_execute<S: StateWrite>(
         &self,
         mut state: S,
-        tx_signer: &[u8; ADDRESS_LENGTH],
+        fee_payer: &[u8; ADDRESS_LENGTH],
         tx_id: &TransactionId,
         position_in_tx: u64,
     ) -> Result<(), CheckedActionExecutionError> {
@@ -374,7 +548,7 @@ impl CheckedAction {
             Self::RollupDataSubmission(checked_action) => {
                 pay_fee(
                     checked_action.action(),
-                    tx_signer,
+                    fee_payer,
                     position_in_tx,
                     &mut state,
                 )
@@ -384,7 +558,7 @@ impl CheckedAction {
             Self::Transfer(checked_action) => {
                 pay_fee(
                     checked_action.action(),
-                    tx_signer,
+                    fee_payer,
                     position_in_tx,
                     &mut state,
                 )
@@ -396,7 +570,7 @@ impl CheckedAction {
             Self::ValidatorUpdate(checked_action) => {
                 pay_fee(
                     checked_action.action(),
-                    tx_signer,
+                    fee_payer,
                     position_in_tx,
                     &mut state,
                 )
@@ -408,7 +582,7 @@ impl CheckedAction {
             Self::SudoAddressChange(checked_action) => {
                 pay_fee(
                     checked_action.action(),
-                    tx_signer,
+                    fee_payer,
                     position_in_tx,
                     &mut state,
                 )
@@ -421,7 +595,7 @@ impl CheckedAction {
                 state.ephemeral_put_ibc_context(*tx_id, position_in_tx);
                 pay_fee(
                     checked_action.action(),
-                    tx_signer,
+                    fee_payer,
                     position_in_tx,
                     &mut state,
                 )
@@ -433,7 +607,7 @@ impl CheckedAction {
             Self::IbcSudoChange(checked_action) => {
                 pay_fee(
                     checked_action.action(),
-                    tx_signer,
+                    fee_payer,
                     position_in_tx,
                     &mut state,
                 )
@@ -445,7 +619,7 @@ impl CheckedAction {
             Self::Ics20Withdrawal(checked_action) => {
                 pay_fee(
                     checked_action.action(),
-                    tx_signer,
+                    fee_payer,
                     position_in_tx,
                     &mut state,
                 )
@@ -457,7 +631,7 @@ impl CheckedAction {
             Self::IbcRelayerChange(checked_action) => {
                 pay_fee(
                     checked_action.action(),
-                    tx_signer,
+                    fee_payer,
                     position_in_tx,
                     &mut state,
                 )
@@ -469,7 +643,7 @@ impl CheckedAction {
             Self::FeeAssetChange(checked_action) => {
                 pay_fee(
                     checked_action.action(),
-                    tx_signer,
+                    fee_payer,
                     position_in_tx,
                     &mut state,
                 )
@@ -481,7 +655,7 @@ impl CheckedAction {
             Self::InitBridgeAccount(checked_action) => {
                 pay_fee(
                     checked_action.action(),
-                    tx_signer,
+                    fee_payer,
                     position_in_tx,
                     &mut state,
                 )
@@ -493,7 +667,7 @@ impl CheckedAction {
             Self::BridgeLock(checked_action) => {
                 pay_fee(
                     checked_action.action(),
-                    tx_signer,
+                    fee_payer,
                     position_in_tx,
                     &mut state,
                 )
@@ -505,7 +679,7 @@ impl CheckedAction {
             Self::BridgeUnlock(checked_action) => {
                 pay_fee(
                     checked_action.action(),
-                    tx_signer,
+                    fee_payer,
                     position_in_tx,
                     &mut state,
                 )
@@ -517,7 +691,7 @@ impl CheckedAction {
             Self::BridgeSudoChange(checked_action) => {
                 pay_fee(
                     checked_action.action(),
-                    tx_signer,
+                    fee_payer,
                     position_in_tx,
                     &mut state,
                 )
@@ -529,7 +703,7 @@ impl CheckedAction {
             Self::BridgeTransfer(checked_action) => {
                 pay_fee(
                     checked_action.action(),
-                    tx_signer,
+                    fee_payer,
                     position_in_tx,
                     &mut state,
                 )
@@ -541,7 +715,7 @@ impl CheckedAction {
             Self::FeeChange(checked_action) => {
                 pay_fee(
                     checked_action.action(),
-                    tx_signer,
+                    fee_payer,
                     position_in_tx,
                     &mut state,
                 )
@@ -553,7 +727,7 @@ impl CheckedAction {
             Self::RecoverIbcClient(checked_action) => {
                 pay_fee(
                     checked_action.action(),
-                    tx_signer,
+                    fee_payer,
                     position_in_tx,
                     &mut state,
                 )
@@ -565,7 +739,7 @@ impl CheckedAction {
             Self::CurrencyPairsChange(checked_action) => {
                 pay_fee(
                     checked_action.action(),
-                    tx_signer,
+                    fee_payer,
                     position_in_tx,
                     &mut state,
                 )
@@ -577,7 +751,127 @@ impl CheckedAction {
             Self::MarketsChange(checked_action) => {
                 pay_fee(
                     checked_action.action(),
-                    tx_signer,
+                    fee_payer,
+                    position_in_tx,
+                    &mut state,
+                )
+                .await?;
+                checked_action.execute(&mut state).await.map_err(|source| {
+                    CheckedActionExecutionError::execution(checked_action.action().name(), source)
+                })
+            }
+            Self::RegisterMultisigAccount(checked_action) => {
+                pay_fee(
+                    checked_action.action(),
+                    fee_payer,
+                    position_in_tx,
+                    &mut state,
+                )
+                .await?;
+                checked_action.execute(&mut state).await.map_err(|source| {
+                    CheckedActionExecutionError::execution(checked_action.action().name(), source)
+                })
+            }
+            Self::ScheduledTransfer(checked_action) => {
+                pay_fee(
+                    checked_action.action(),
+                    fee_payer,
+                    position_in_tx,
+                    &mut state,
+                )
+                .await?;
+                checked_action.execute(&mut state).await.map_err(|source| {
+                    CheckedActionExecutionError::execution(checked_action.action().name(), source)
+                })
+            }
+            Self::Ics20RateLimitChange(checked_action) => {
+                pay_fee(
+                    checked_action.action(),
+                    fee_payer,
+                    position_in_tx,
+                    &mut state,
+                )
+                .await?;
+                checked_action.execute(&mut state).await.map_err(|source| {
+                    CheckedActionExecutionError::execution(checked_action.action().name(), source)
+                })
+            }
+            Self::VoteExtensionParticipationChange(checked_action) => {
+                pay_fee(
+                    checked_action.action(),
+                    fee_payer,
+                    position_in_tx,
+                    &mut state,
+                )
+                .await?;
+                checked_action.execute(&mut state).await.map_err(|source| {
+                    CheckedActionExecutionError::execution(checked_action.action().name(), source)
+                })
+            }
+            Self::Bond(checked_action) => {
+                pay_fee(
+                    checked_action.action(),
+                    fee_payer,
+                    position_in_tx,
+                    &mut state,
+                )
+                .await?;
+                checked_action.execute(&mut state).await.map_err(|source| {
+                    CheckedActionExecutionError::execution(checked_action.action().name(), source)
+                })
+            }
+            Self::Unbond(checked_action) => {
+                pay_fee(
+                    checked_action.action(),
+                    fee_payer,
+                    position_in_tx,
+                    &mut state,
+                )
+                .await?;
+                checked_action.execute(&mut state).await.map_err(|source| {
+                    CheckedActionExecutionError::execution(checked_action.action().name(), source)
+                })
+            }
+            Self::ProposalSubmission(checked_action) => {
+                pay_fee(
+                    checked_action.action(),
+                    fee_payer,
+                    position_in_tx,
+                    &mut state,
+                )
+                .await?;
+                checked_action.execute(&mut state).await.map_err(|source| {
+                    CheckedActionExecutionError::execution(checked_action.action().name(), source)
+                })
+            }
+            Self::ProposalVote(checked_action) => {
+                pay_fee(
+                    checked_action.action(),
+                    fee_payer,
+                    position_in_tx,
+                    &mut state,
+                )
+                .await?;
+                checked_action.execute(&mut state).await.map_err(|source| {
+                    CheckedActionExecutionError::execution(checked_action.action().name(), source)
+                })
+            }
+            Self::StakingParametersChange(checked_action) => {
+                pay_fee(
+                    checked_action.action(),
+                    fee_payer,
+                    position_in_tx,
+                    &mut state,
+                )
+                .await?;
+                checked_action.execute(&mut state).await.map_err(|source| {
+                    CheckedActionExecutionError::execution(checked_action.action().name(), source)
+                })
+            }
+            Self::GovernanceParametersChange(checked_action) => {
+                pay_fee(
+                    checked_action.action(),
+                    fee_payer,
                     position_in_tx,
                     &mut state,
                 )
@@ -609,6 +903,18 @@ impl CheckedAction {
             CheckedAction::RecoverIbcClient(action) => action.transfer_asset_and_amount(),
             CheckedAction::CurrencyPairsChange(action) => action.transfer_asset_and_amount(),
             CheckedAction::MarketsChange(action) => action.transfer_asset_and_amount(),
+            CheckedAction::RegisterMultisigAccount(action) => action.transfer_asset_and_amount(),
+            CheckedAction::ScheduledTransfer(action) => action.transfer_asset_and_amount(),
+            CheckedAction::Ics20RateLimitChange(action) => action.transfer_asset_and_amount(),
+            CheckedAction::VoteExtensionParticipationChange(action) => {
+                action.transfer_asset_and_amount()
+            }
+            CheckedAction::Bond(action) => action.transfer_asset_and_amount(),
+            CheckedAction::Unbond(action) => action.transfer_asset_and_amount(),
+            CheckedAction::ProposalSubmission(action) => action.transfer_asset_and_amount(),
+            CheckedAction::ProposalVote(action) => action.transfer_asset_and_amount(),
+            CheckedAction::StakingParametersChange(action) => action.transfer_asset_and_amount(),
+            CheckedAction::GovernanceParametersChange(action) => action.transfer_asset_and_amount(),
         }
     }
 
@@ -632,14 +938,32 @@ impl CheckedAction {
             CheckedAction::RecoverIbcClient(checked_action) => checked_action.action().name(),
             CheckedAction::CurrencyPairsChange(checked_action) => checked_action.action().name(),
             CheckedAction::MarketsChange(checked_action) => checked_action.action().name(),
+            CheckedAction::RegisterMultisigAccount(checked_action) => {
+                checked_action.action().name()
+            }
+            CheckedAction::ScheduledTransfer(checked_action) => checked_action.action().name(),
+            CheckedAction::Ics20RateLimitChange(checked_action) => checked_action.action().name(),
+            CheckedAction::VoteExtensionParticipationChange(checked_action) => {
+                checked_action.action().name()
+            }
+            CheckedAction::Bond(checked_action) => checked_action.action().name(),
+            CheckedAction::Unbond(checked_action) => checked_action.action().name(),
+            CheckedAction::ProposalSubmission(checked_action) => checked_action.action().name(),
+            CheckedAction::ProposalVote(checked_action) => checked_action.action().name(),
+            CheckedAction::StakingParametersChange(checked_action) => {
+                checked_action.action().name()
+            }
+            CheckedAction::GovernanceParametersChange(checked_action) => {
+                checked_action.action().name()
+            }
         }
     }
 }
 
 #[instrument(skip_all, fields(action = %action.name()), err(level = Level::DEBUG))]
-async fn pay_fee<'a, F, S>(
+pub(super) async fn pay_fee<'a, F, S>(
     action: &'a F,
-    tx_signer: &[u8; ADDRESS_LENGTH],
+    fee_payer: &[u8; ADDRESS_LENGTH],
     position_in_transaction: u64,
     mut state: S,
 ) -> Result<(), CheckedActionExecutionError>
@@ -655,12 +979,12 @@ where
 
     state.add_fee_to_block_fees::<_, F>(fee_asset, total_fee, position_in_transaction);
     state
-        .decrease_balance(tx_signer, fee_asset, total_fee)
+        .decrease_balance(fee_payer, fee_asset, total_fee)
         .await
         .map_err(|source| {
             if source.downcast_ref::<InsufficientFunds>().is_some() {
                 CheckedActionFeeError::InsufficientBalanceToPayFee {
-                    account: *tx_signer,
+                    account: *fee_payer,
                     asset: fee_asset.clone(),
                     amount: total_fee,
                 }
@@ -855,6 +1179,106 @@ impl From<CheckedAction> for CheckedMarketsChange {
     }
 }
 
+#[cfg(test)]
+impl From<CheckedAction> for CheckedRegisterMultisigAccount {
+    fn from(checked_action: CheckedAction) -> Self {
+        let CheckedAction::RegisterMultisigAccount(wrapped_action) = checked_action else {
+            panic!("expected RegisterMultisigAccount");
+        };
+        wrapped_action
+    }
+}
+
+#[cfg(test)]
+impl From<CheckedAction> for CheckedScheduledTransfer {
+    fn from(checked_action: CheckedAction) -> Self {
+        let CheckedAction::ScheduledTransfer(wrapped_action) = checked_action else {
+            panic!("expected ScheduledTransfer");
+        };
+        wrapped_action
+    }
+}
+
+#[cfg(test)]
+impl From<CheckedAction> for CheckedIcs20RateLimitChange {
+    fn from(checked_action: CheckedAction) -> Self {
+        let CheckedAction::Ics20RateLimitChange(wrapped_action) = checked_action else {
+            panic!("expected Ics20RateLimitChange");
+        };
+        wrapped_action
+    }
+}
+
+#[cfg(test)]
+impl From<CheckedAction> for CheckedVoteExtensionParticipationChange {
+    fn from(checked_action: CheckedAction) -> Self {
+        let CheckedAction::VoteExtensionParticipationChange(wrapped_action) = checked_action else {
+            panic!("expected VoteExtensionParticipationChange");
+        };
+        wrapped_action
+    }
+}
+
+#[cfg(test)]
+impl From<CheckedAction> for CheckedBond {
+    fn from(checked_action: CheckedAction) -> Self {
+        let CheckedAction::Bond(wrapped_action) = checked_action else {
+            panic!("expected Bond");
+        };
+        wrapped_action
+    }
+}
+
+#[cfg(test)]
+impl From<CheckedAction> for CheckedUnbond {
+    fn from(checked_action: CheckedAction) -> Self {
+        let CheckedAction::Unbond(wrapped_action) = checked_action else {
+            panic!("expected Unbond");
+        };
+        wrapped_action
+    }
+}
+
+#[cfg(test)]
+impl From<CheckedAction> for CheckedProposalSubmission {
+    fn from(checked_action: CheckedAction) -> Self {
+        let CheckedAction::ProposalSubmission(wrapped_action) = checked_action else {
+            panic!("expected ProposalSubmission");
+        };
+        wrapped_action
+    }
+}
+
+#[cfg(test)]
+impl From<CheckedAction> for CheckedProposalVote {
+    fn from(checked_action: CheckedAction) -> Self {
+        let CheckedAction::ProposalVote(wrapped_action) = checked_action else {
+            panic!("expected ProposalVote");
+        };
+        wrapped_action
+    }
+}
+
+#[cfg(test)]
+impl From<CheckedAction> for CheckedStakingParametersChange {
+    fn from(checked_action: CheckedAction) -> Self {
+        let CheckedAction::StakingParametersChange(wrapped_action) = checked_action else {
+            panic!("expected StakingParametersChange");
+        };
+        wrapped_action
+    }
+}
+
+#[cfg(test)]
+impl From<CheckedAction> for CheckedGovernanceParametersChange {
+    fn from(checked_action: CheckedAction) -> Self {
+        let CheckedAction::GovernanceParametersChange(wrapped_action) = checked_action else {
+            panic!("expected GovernanceParametersChange");
+        };
+        wrapped_action
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
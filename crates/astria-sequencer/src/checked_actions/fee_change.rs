@@ -125,6 +125,36 @@ impl CheckedFeeChange {
             FeeChange::MarketsChange(fees) => state
                 .put_fees(*fees)
                 .wrap_err("failed to write markets change fees to storage"),
+            FeeChange::RegisterMultisigAccount(fees) => state
+                .put_fees(*fees)
+                .wrap_err("failed to write register multisig account fees to storage"),
+            FeeChange::ScheduledTransfer(fees) => state
+                .put_fees(*fees)
+                .wrap_err("failed to write scheduled transfer fees to storage"),
+            FeeChange::Ics20RateLimitChange(fees) => state
+                .put_fees(*fees)
+                .wrap_err("failed to write ics20 rate limit change fees to storage"),
+            FeeChange::VoteExtensionParticipationChange(fees) => state
+                .put_fees(*fees)
+                .wrap_err("failed to write vote extension participation change fees to storage"),
+            FeeChange::Bond(fees) => state
+                .put_fees(*fees)
+                .wrap_err("failed to write bond fees to storage"),
+            FeeChange::Unbond(fees) => state
+                .put_fees(*fees)
+                .wrap_err("failed to write unbond fees to storage"),
+            FeeChange::ProposalSubmission(fees) => state
+                .put_fees(*fees)
+                .wrap_err("failed to write proposal submission fees to storage"),
+            FeeChange::ProposalVote(fees) => state
+                .put_fees(*fees)
+                .wrap_err("failed to write proposal vote fees to storage"),
+            FeeChange::StakingParametersChange(fees) => state
+                .put_fees(*fees)
+                .wrap_err("failed to write staking parameters change fees to storage"),
+            FeeChange::GovernanceParametersChange(fees) => state
+                .put_fees(*fees)
+                .wrap_err("failed to write governance parameters change fees to storage"),
         }
     }
 
@@ -312,6 +342,56 @@ mod tests {
         test_fee_change_action::<MarketsChange>().await;
     }
 
+    #[tokio::test]
+    async fn should_execute_register_multisig_account_fee_change() {
+        test_fee_change_action::<RegisterMultisigAccount>().await;
+    }
+
+    #[tokio::test]
+    async fn should_execute_scheduled_transfer_fee_change() {
+        test_fee_change_action::<ScheduledTransfer>().await;
+    }
+
+    #[tokio::test]
+    async fn should_execute_ics20_rate_limit_change_fee_change() {
+        test_fee_change_action::<Ics20RateLimitChange>().await;
+    }
+
+    #[tokio::test]
+    async fn should_execute_vote_extension_participation_change_fee_change() {
+        test_fee_change_action::<VoteExtensionParticipationChange>().await;
+    }
+
+    #[tokio::test]
+    async fn should_execute_bond_fee_change() {
+        test_fee_change_action::<Bond>().await;
+    }
+
+    #[tokio::test]
+    async fn should_execute_unbond_fee_change() {
+        test_fee_change_action::<Unbond>().await;
+    }
+
+    #[tokio::test]
+    async fn should_execute_proposal_submission_fee_change() {
+        test_fee_change_action::<ProposalSubmission>().await;
+    }
+
+    #[tokio::test]
+    async fn should_execute_proposal_vote_fee_change() {
+        test_fee_change_action::<ProposalVote>().await;
+    }
+
+    #[tokio::test]
+    async fn should_execute_staking_parameters_change_fee_change() {
+        test_fee_change_action::<StakingParametersChange>().await;
+    }
+
+    #[tokio::test]
+    async fn should_execute_governance_parameters_change_fee_change() {
+        test_fee_change_action::<GovernanceParametersChange>().await;
+    }
+
     async fn test_fee_change_action<'a, F>()
     where
         F: FeeHandler,
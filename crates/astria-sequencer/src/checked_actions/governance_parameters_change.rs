@@ -0,0 +1,185 @@
+use astria_core::{
+    primitive::v1::{
+        asset::IbcPrefixed,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1::action::GovernanceParametersChange,
+};
+use astria_eyre::eyre::{
+    ensure,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    AssetTransfer,
+    TransactionSignerAddressBytes,
+};
+use crate::{
+    authority::StateReadExt as _,
+    governance::StateWriteExt as _,
+};
+
+#[derive(Debug)]
+pub(crate) struct CheckedGovernanceParametersChange {
+    action: GovernanceParametersChange,
+    tx_signer: TransactionSignerAddressBytes,
+}
+
+impl CheckedGovernanceParametersChange {
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn new<S: StateRead>(
+        action: GovernanceParametersChange,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self> {
+        // The action has no immutable checks beyond those performed when parsing it.
+        let checked_action = Self {
+            action,
+            tx_signer: tx_signer.into(),
+        };
+        checked_action.run_mutable_checks(state).await?;
+
+        Ok(checked_action)
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn run_mutable_checks<S: StateRead>(&self, state: S) -> Result<()> {
+        // Check that the signer of this tx is the authorized sudo address.
+        let sudo_address = state
+            .get_sudo_address()
+            .await
+            .wrap_err("failed to read sudo address from storage")?;
+        ensure!(
+            &sudo_address == self.tx_signer.as_bytes(),
+            "transaction signer not authorized to change governance parameters",
+        );
+
+        Ok(())
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        self.run_mutable_checks(&state).await?;
+        // Proposals already submitted keep the voting end height and deposit they were submitted
+        // with.
+        state
+            .put_governance_parameters(self.action.parameters)
+            .wrap_err("failed to write governance parameters to storage")
+    }
+
+    pub(super) fn action(&self) -> &GovernanceParametersChange {
+        &self.action
+    }
+}
+
+impl AssetTransfer for CheckedGovernanceParametersChange {
+    fn transfer_asset_and_amount(&self) -> Option<(IbcPrefixed, u128)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::protocol::transaction::v1::action::SudoAddressChange;
+
+    use super::*;
+    use crate::{
+        checked_actions::CheckedSudoAddressChange,
+        governance::StateReadExt as _,
+        test_utils::{
+            assert_error_contains,
+            astria_address,
+            dummy_governance_parameters_change,
+            Fixture,
+            SUDO_ADDRESS_BYTES,
+        },
+    };
+
+    #[tokio::test]
+    async fn should_fail_construction_if_signer_is_not_sudo_address() {
+        let fixture = Fixture::default_initialized().await;
+
+        let tx_signer = [2_u8; ADDRESS_LEN];
+        assert_ne!(*SUDO_ADDRESS_BYTES, tx_signer);
+
+        let err = fixture
+            .new_checked_action(dummy_governance_parameters_change(), tx_signer)
+            .await
+            .unwrap_err();
+        assert_error_contains(
+            &err,
+            "transaction signer not authorized to change governance parameters",
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fail_execution_if_signer_is_not_sudo_address() {
+        let mut fixture = Fixture::default_initialized().await;
+
+        // Construct the checked action while the sudo address is still the tx signer so
+        // construction succeeds.
+        let checked_action: CheckedGovernanceParametersChange = fixture
+            .new_checked_action(dummy_governance_parameters_change(), *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .into();
+
+        // Change the sudo address to something other than the tx signer.
+        let sudo_address_change = SudoAddressChange {
+            new_address: astria_address(&[2; ADDRESS_LEN]),
+        };
+        let checked_sudo_address_change: CheckedSudoAddressChange = fixture
+            .new_checked_action(sudo_address_change, *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .into();
+        checked_sudo_address_change
+            .execute(fixture.state_mut())
+            .await
+            .unwrap();
+
+        // Try to execute the checked action now - should fail due to signer no longer being
+        // authorized.
+        let err = checked_action
+            .execute(fixture.state_mut())
+            .await
+            .unwrap_err();
+        assert_error_contains(
+            &err,
+            "transaction signer not authorized to change governance parameters",
+        );
+    }
+
+    #[tokio::test]
+    async fn should_execute_enabling_governance() {
+        let mut fixture = Fixture::default_initialized().await;
+        assert!(fixture
+            .state()
+            .get_governance_parameters()
+            .await
+            .unwrap()
+            .is_none());
+
+        let action = dummy_governance_parameters_change();
+        let checked_action: CheckedGovernanceParametersChange = fixture
+            .new_checked_action(action.clone(), *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .into();
+        checked_action.execute(fixture.state_mut()).await.unwrap();
+
+        assert_eq!(
+            fixture.state().get_governance_parameters().await.unwrap(),
+            Some(action.parameters)
+        );
+    }
+}
@@ -8,22 +8,16 @@ use astria_core::primitive::v1::{
     asset::IbcPrefixed,
     ADDRESS_LEN,
 };
-use astria_eyre::{
-    anyhow_to_eyre,
-    eyre::{
-        ensure,
-        Result,
-        WrapErr as _,
-    },
+use astria_eyre::eyre::{
+    ensure,
+    Result,
+    WrapErr as _,
 };
 use cnidarium::{
     StateRead,
     StateWrite,
 };
-use penumbra_ibc::{
-    IbcRelay,
-    IbcRelayWithHandlers,
-};
+use penumbra_ibc::IbcRelay;
 use tracing::{
     instrument,
     Level,
@@ -34,14 +28,13 @@ use super::{
     TransactionSignerAddressBytes,
 };
 use crate::ibc::{
-    host_interface::AstriaHost,
-    ics20_transfer::Ics20Transfer,
+    port_router::PortRouter,
     StateReadExt as _,
 };
 
 pub(crate) struct CheckedIbcRelay {
     action: IbcRelay,
-    action_with_handlers: IbcRelayWithHandlers<Ics20Transfer, AstriaHost>,
+    router: PortRouter,
     tx_signer: TransactionSignerAddressBytes,
 }
 
@@ -52,18 +45,17 @@ impl CheckedIbcRelay {
         tx_signer: [u8; ADDRESS_LEN],
         state: S,
     ) -> Result<Self> {
-        let action_with_handlers = action.clone().with_handler::<Ics20Transfer, AstriaHost>();
+        let router = PortRouter::new(action.clone());
 
         // Run immutable checks.
-        action_with_handlers
-            .check_stateless(())
+        router
+            .check_stateless()
             .await
-            .map_err(anyhow_to_eyre)
             .wrap_err("stateless checks failed for ibc action")?;
 
         let checked_action = Self {
             action,
-            action_with_handlers,
+            router,
             tx_signer: tx_signer.into(),
         };
         checked_action.run_mutable_checks(state).await?;
@@ -86,10 +78,9 @@ impl CheckedIbcRelay {
     #[instrument(skip_all, err(level = Level::DEBUG))]
     pub(super) async fn execute<S: StateWrite>(&self, state: S) -> Result<()> {
         self.run_mutable_checks(&state).await?;
-        self.action_with_handlers
+        self.router
             .check_and_execute(state)
             .await
-            .map_err(anyhow_to_eyre)
             .wrap_err("failed executing ibc action")
     }
 
@@ -107,7 +98,7 @@ impl Debug for CheckedIbcRelay {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         formatter
             .debug_struct("CheckedIbcRelay")
-            .field("action", self.action_with_handlers.action())
+            .field("action", &self.action)
             .field("tx_signer", &self.tx_signer)
             .finish()
     }
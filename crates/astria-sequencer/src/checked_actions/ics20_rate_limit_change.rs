@@ -0,0 +1,243 @@
+use astria_core::{
+    primitive::v1::{
+        asset::IbcPrefixed,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1::action::Ics20RateLimitChange,
+};
+use astria_eyre::eyre::{
+    ensure,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    use_pre_aspen_validator_updates,
+    AssetTransfer,
+    TransactionSignerAddressBytes,
+};
+use crate::ibc::{
+    StateReadExt as _,
+    StateWriteExt as _,
+};
+
+#[derive(Debug)]
+pub(crate) struct CheckedIcs20RateLimitChange {
+    action: Ics20RateLimitChange,
+    tx_signer: TransactionSignerAddressBytes,
+}
+
+impl CheckedIcs20RateLimitChange {
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn new<S: StateRead>(
+        action: Ics20RateLimitChange,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self> {
+        // The action has no immutable checks beyond those performed when parsing it.
+        let checked_action = Self {
+            action,
+            tx_signer: tx_signer.into(),
+        };
+        checked_action.run_mutable_checks(state).await?;
+
+        Ok(checked_action)
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn run_mutable_checks<S: StateRead>(&self, state: S) -> Result<()> {
+        ensure!(
+            !use_pre_aspen_validator_updates(&state)
+                .await
+                .wrap_err("failed to get upgrade status")?,
+            "ics20 rate limits are not available before the Aspen upgrade",
+        );
+
+        // Check that the signer of this tx is the authorized IBC sudo address.
+        let ibc_sudo_address = state
+            .get_ibc_sudo_address()
+            .await
+            .wrap_err("failed to read ibc sudo address from storage")?;
+        ensure!(
+            &ibc_sudo_address == self.tx_signer.as_bytes(),
+            "transaction signer not authorized to change ics20 rate limits",
+        );
+
+        Ok(())
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        self.run_mutable_checks(&state).await?;
+        if let Some(rate_limit) = self.action.rate_limit {
+            state
+                .put_ics20_rate_limit(&self.action.channel, &self.action.asset, rate_limit)
+                .wrap_err("failed to write ics20 rate limit to storage")?;
+        } else {
+            state.delete_ics20_rate_limit(&self.action.channel, &self.action.asset);
+        }
+        Ok(())
+    }
+
+    pub(super) fn action(&self) -> &Ics20RateLimitChange {
+        &self.action
+    }
+}
+
+impl AssetTransfer for CheckedIcs20RateLimitChange {
+    fn transfer_asset_and_amount(&self) -> Option<(IbcPrefixed, u128)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::protocol::transaction::v1::action::{
+        IbcSudoChange,
+        Ics20RateLimit,
+    };
+
+    use super::*;
+    use crate::{
+        checked_actions::CheckedIbcSudoChange,
+        test_utils::{
+            assert_error_contains,
+            astria_address,
+            dummy_ics20_rate_limit_change,
+            Fixture,
+            IBC_SUDO_ADDRESS_BYTES,
+            SUDO_ADDRESS_BYTES,
+        },
+    };
+
+    #[tokio::test]
+    async fn should_fail_construction_if_aspen_not_applied() {
+        let mut fixture = Fixture::uninitialized(None).await;
+        fixture.chain_initializer().init().await;
+        assert!(use_pre_aspen_validator_updates(fixture.state())
+            .await
+            .unwrap());
+
+        let err = fixture
+            .new_checked_action(dummy_ics20_rate_limit_change(), *IBC_SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(
+            &err,
+            "ics20 rate limits are not available before the Aspen upgrade",
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_signer_is_not_ibc_sudo_address() {
+        let fixture = Fixture::default_initialized().await;
+
+        let tx_signer = [2_u8; ADDRESS_LEN];
+        assert_ne!(*IBC_SUDO_ADDRESS_BYTES, tx_signer);
+
+        let err = fixture
+            .new_checked_action(dummy_ics20_rate_limit_change(), tx_signer)
+            .await
+            .unwrap_err();
+        assert_error_contains(
+            &err,
+            "transaction signer not authorized to change ics20 rate limits",
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fail_execution_if_signer_is_not_ibc_sudo_address() {
+        let mut fixture = Fixture::default_initialized().await;
+
+        // Construct the checked action while the IBC sudo address is still the tx signer so
+        // construction succeeds.
+        let checked_action: CheckedIcs20RateLimitChange = fixture
+            .new_checked_action(dummy_ics20_rate_limit_change(), *IBC_SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .into();
+
+        // Change the IBC sudo address to something other than the tx signer. The IBC sudo address
+        // is changed by the sudo address.
+        let ibc_sudo_change = IbcSudoChange {
+            new_address: astria_address(&[2; ADDRESS_LEN]),
+        };
+        let checked_ibc_sudo_change: CheckedIbcSudoChange = fixture
+            .new_checked_action(ibc_sudo_change, *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .into();
+        checked_ibc_sudo_change
+            .execute(fixture.state_mut())
+            .await
+            .unwrap();
+
+        // Try to execute the checked action now - should fail due to signer no longer being
+        // authorized.
+        let err = checked_action
+            .execute(fixture.state_mut())
+            .await
+            .unwrap_err();
+        assert_error_contains(
+            &err,
+            "transaction signer not authorized to change ics20 rate limits",
+        );
+    }
+
+    #[tokio::test]
+    async fn should_execute_setting_and_removing_rate_limit() {
+        let mut fixture = Fixture::default_initialized().await;
+
+        let action = dummy_ics20_rate_limit_change();
+        let expected_rate_limit = Ics20RateLimit {
+            max_inflow: 1,
+            max_outflow: 2,
+            window_blocks: 3,
+        };
+        let checked_action: CheckedIcs20RateLimitChange = fixture
+            .new_checked_action(
+                Ics20RateLimitChange {
+                    rate_limit: Some(expected_rate_limit),
+                    ..action.clone()
+                },
+                *IBC_SUDO_ADDRESS_BYTES,
+            )
+            .await
+            .unwrap()
+            .into();
+        checked_action.execute(fixture.state_mut()).await.unwrap();
+        let rate_limit = fixture
+            .state()
+            .get_ics20_rate_limit(&action.channel, &action.asset)
+            .await
+            .unwrap();
+        assert_eq!(Some(expected_rate_limit), rate_limit);
+
+        let checked_action: CheckedIcs20RateLimitChange = fixture
+            .new_checked_action(
+                Ics20RateLimitChange {
+                    rate_limit: None,
+                    ..action.clone()
+                },
+                *IBC_SUDO_ADDRESS_BYTES,
+            )
+            .await
+            .unwrap()
+            .into();
+        checked_action.execute(fixture.state_mut()).await.unwrap();
+        let rate_limit = fixture
+            .state()
+            .get_ics20_rate_limit(&action.channel, &action.asset)
+            .await
+            .unwrap();
+        assert!(rate_limit.is_none());
+    }
+}
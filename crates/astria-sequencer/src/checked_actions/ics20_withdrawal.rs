@@ -34,14 +34,18 @@ use cnidarium::{
     StateWrite,
 };
 use ibc_types::core::channel::{
+    packet::Sequence,
     ChannelId,
     PortId,
 };
-use penumbra_ibc::component::packet::{
-    IBCPacket,
-    SendPacketRead as _,
-    SendPacketWrite as _,
-    Unchecked,
+use penumbra_ibc::component::{
+    packet::{
+        IBCPacket,
+        SendPacketRead as _,
+        SendPacketWrite as _,
+        Unchecked,
+    },
+    ChannelStateReadExt as _,
 };
 use penumbra_proto::core::component::ibc::v1::FungibleTokenPacketData;
 use tracing::{
@@ -62,6 +66,7 @@ use crate::{
         StateWriteExt as _,
     },
     ibc::{
+        rate_limit,
         StateReadExt as _,
         StateWriteExt as _,
     },
@@ -184,6 +189,16 @@ impl CheckedIcs20Withdrawal {
             bail!("signer cannot be a bridge address if bridge address is not set");
         }
 
+        rate_limit::check_ics20_flow(
+            &state,
+            self.ibc_packet.source_channel(),
+            &self.action.denom,
+            rate_limit::Direction::Outflow,
+            self.action.amount,
+        )
+        .await
+        .wrap_err("ics20 withdrawal is not permitted by the channel's rate limit")?;
+
         Ok(())
     }
 
@@ -220,6 +235,24 @@ impl CheckedIcs20Withdrawal {
             .map_err(anyhow_to_eyre)
             .wrap_err("ibc packet failed send check")?;
 
+        let sequence = state
+            .get_send_sequence(
+                self.ibc_packet.source_channel(),
+                self.ibc_packet.source_port(),
+            )
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed to read send sequence of withdrawal channel")?;
+        rate_limit::record_ics20_outflow(
+            &mut state,
+            self.ibc_packet.source_channel(),
+            Sequence(sequence),
+            &self.action.denom,
+            self.action.amount,
+        )
+        .await
+        .wrap_err("failed to record ics20 withdrawal against the channel's rate limit")?;
+
         state
             .decrease_balance(
                 &self.withdrawal_address,
@@ -353,6 +386,7 @@ mod tests {
         primitive::v1::RollupId,
         protocol::transaction::v1::action::{
             BridgeSudoChange,
+            Ics20RateLimit,
             InitBridgeAccount,
         },
     };
@@ -362,6 +396,7 @@ mod tests {
         *,
     };
     use crate::{
+        app::StateWriteExt as _,
         checked_actions::{
             CheckedBridgeSudoChange,
             CheckedInitBridgeAccount,
@@ -616,6 +651,35 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn should_fail_construction_if_rate_limit_exceeded() {
+        let mut fixture = Fixture::default_initialized().await;
+        let action = Ics20WithdrawalBuilder::new().with_amount(11).build();
+        fixture.state_mut().put_block_height(1).unwrap();
+        fixture
+            .state_mut()
+            .put_ics20_rate_limit(
+                &action.source_channel,
+                &action.denom,
+                Ics20RateLimit {
+                    max_inflow: 0,
+                    max_outflow: 10,
+                    window_blocks: 100,
+                },
+            )
+            .unwrap();
+
+        let err = fixture
+            .new_checked_action(action, *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+
+        assert_error_contains(
+            &err,
+            "ics20 withdrawal is not permitted by the channel's rate limit",
+        );
+    }
+
     #[tokio::test]
     async fn should_fail_execution_if_bridge_account_withdrawer_is_not_tx_signer() {
         let mut fixture = Fixture::default_initialized().await;
@@ -0,0 +1,123 @@
+use astria_core::{
+    crypto::ADDRESS_LENGTH,
+    primitive::v1::TransactionId,
+    protocol::transaction::v1::action::{
+        BridgeLock,
+        RollupDataSubmission,
+        Transfer,
+    },
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+
+use super::{
+    checked_action::pay_fee,
+    CheckedActionExecutionError,
+    CheckedActionInitialCheckError,
+    CheckedBridgeLock,
+    CheckedRollupDataSubmission,
+    CheckedTransfer,
+};
+use crate::ibc::StateWriteExt as _;
+
+/// An action executed on behalf of an interchain account.
+///
+/// Only the actions listed here can be executed by a controller chain over an ICS27 channel. This
+/// type is kept separate from [`super::CheckedAction`] so that executing an interchain account
+/// transaction from within an IBC relay action does not recurse into the IBC relay action.
+#[derive(Debug)]
+pub(crate) enum CheckedInterchainAccountAction {
+    Transfer(CheckedTransfer),
+    BridgeLock(CheckedBridgeLock),
+    RollupDataSubmission(CheckedRollupDataSubmission),
+}
+
+impl CheckedInterchainAccountAction {
+    pub(crate) async fn new_transfer<S: StateRead>(
+        action: Transfer,
+        interchain_account: [u8; ADDRESS_LENGTH],
+        state: S,
+    ) -> Result<Self, CheckedActionInitialCheckError> {
+        let action_name = action.name();
+        let checked_action = CheckedTransfer::new(action, interchain_account, state)
+            .await
+            .map_err(|source| CheckedActionInitialCheckError::new(action_name, source))?;
+        Ok(Self::Transfer(checked_action))
+    }
+
+    pub(crate) async fn new_bridge_lock<S: StateRead>(
+        action: BridgeLock,
+        interchain_account: [u8; ADDRESS_LENGTH],
+        tx_id: TransactionId,
+        position_in_tx: u64,
+        state: S,
+    ) -> Result<Self, CheckedActionInitialCheckError> {
+        let action_name = action.name();
+        let checked_action =
+            CheckedBridgeLock::new(action, interchain_account, tx_id, position_in_tx, state)
+                .await
+                .map_err(|source| CheckedActionInitialCheckError::new(action_name, source))?;
+        Ok(Self::BridgeLock(checked_action))
+    }
+
+    pub(crate) fn new_rollup_data_submission(
+        action: RollupDataSubmission,
+    ) -> Result<Self, CheckedActionInitialCheckError> {
+        let action_name = action.name();
+        let checked_action = CheckedRollupDataSubmission::new(action)
+            .map_err(|source| CheckedActionInitialCheckError::new(action_name, source))?;
+        Ok(Self::RollupDataSubmission(checked_action))
+    }
+
+    /// Pays the fees of the action from the interchain account and executes it.
+    ///
+    /// The data of a rollup data submission is cached in the ephemeral store, pending being
+    /// included in the block's rollup data.
+    pub(crate) async fn pay_fees_and_execute<S: StateWrite>(
+        &self,
+        mut state: S,
+        interchain_account: &[u8; ADDRESS_LENGTH],
+        position_in_tx: u64,
+    ) -> Result<(), CheckedActionExecutionError> {
+        match self {
+            Self::Transfer(checked_action) => {
+                pay_fee(
+                    checked_action.action(),
+                    interchain_account,
+                    position_in_tx,
+                    &mut state,
+                )
+                .await?;
+                checked_action.execute(&mut state).await.map_err(|source| {
+                    CheckedActionExecutionError::execution(checked_action.action().name(), source)
+                })
+            }
+            Self::BridgeLock(checked_action) => {
+                pay_fee(
+                    checked_action.action(),
+                    interchain_account,
+                    position_in_tx,
+                    &mut state,
+                )
+                .await?;
+                checked_action.execute(&mut state).await.map_err(|source| {
+                    CheckedActionExecutionError::execution(checked_action.action().name(), source)
+                })
+            }
+            Self::RollupDataSubmission(checked_action) => {
+                pay_fee(
+                    checked_action.action(),
+                    interchain_account,
+                    position_in_tx,
+                    &mut state,
+                )
+                .await?;
+                let action = checked_action.action();
+                state.cache_interchain_rollup_data(action.rollup_id, action.data.clone());
+                Ok(())
+            }
+        }
+    }
+}
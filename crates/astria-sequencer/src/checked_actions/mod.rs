@@ -18,6 +18,7 @@
 //! Failure in this case results in a failed transaction execution and removal from the Mempool.
 
 mod action_ref;
+mod bond;
 mod bridge;
 mod bridge_sudo_change;
 mod checked_action;
@@ -25,20 +26,31 @@ mod currency_pairs_change;
 mod error;
 mod fee_asset_change;
 mod fee_change;
+mod governance_parameters_change;
 mod ibc_relay;
 mod ibc_relayer_change;
 mod ibc_sudo_change;
+mod ics20_rate_limit_change;
 mod ics20_withdrawal;
 mod init_bridge_account;
+mod interchain_account_action;
 mod markets_change;
+mod proposal_submission;
+mod proposal_vote;
+mod proposed_action;
 mod recover_ibc_client;
+mod register_multisig_account;
 mod rollup_data_submission;
+mod scheduled_transfer;
+mod staking_parameters_change;
 mod sudo_address_change;
 #[cfg(test)]
 pub(crate) mod test_utils;
 mod transfer;
+mod unbond;
 pub(crate) mod utils;
 mod validator_update;
+mod vote_extension_participation_change;
 
 use std::fmt::{
     self,
@@ -51,6 +63,7 @@ use astria_core::{
     crypto::ADDRESS_LENGTH,
     primitive::v1::asset::IbcPrefixed,
 };
+pub(crate) use bond::CheckedBond;
 pub(crate) use bridge::{
     CheckedBridgeLock,
     CheckedBridgeTransfer,
@@ -67,20 +80,31 @@ pub(crate) use error::{
 };
 pub(crate) use fee_asset_change::CheckedFeeAssetChange;
 pub(crate) use fee_change::CheckedFeeChange;
+pub(crate) use governance_parameters_change::CheckedGovernanceParametersChange;
 pub(crate) use ibc_relay::CheckedIbcRelay;
 pub(crate) use ibc_relayer_change::CheckedIbcRelayerChange;
 pub(crate) use ibc_sudo_change::CheckedIbcSudoChange;
+pub(crate) use ics20_rate_limit_change::CheckedIcs20RateLimitChange;
 pub(crate) use ics20_withdrawal::CheckedIcs20Withdrawal;
 pub(crate) use init_bridge_account::CheckedInitBridgeAccount;
+pub(crate) use interchain_account_action::CheckedInterchainAccountAction;
 pub(crate) use markets_change::CheckedMarketsChange;
+pub(crate) use proposal_submission::CheckedProposalSubmission;
+pub(crate) use proposal_vote::CheckedProposalVote;
+pub(crate) use proposed_action::CheckedProposedAction;
 pub(crate) use recover_ibc_client::CheckedRecoverIbcClient;
+pub(crate) use register_multisig_account::CheckedRegisterMultisigAccount;
 pub(crate) use rollup_data_submission::CheckedRollupDataSubmission;
+pub(crate) use scheduled_transfer::CheckedScheduledTransfer;
+pub(crate) use staking_parameters_change::CheckedStakingParametersChange;
 pub(crate) use sudo_address_change::CheckedSudoAddressChange;
 pub(crate) use transfer::CheckedTransfer;
+pub(crate) use unbond::CheckedUnbond;
 pub(crate) use validator_update::{
     use_pre_aspen_validator_updates,
     CheckedValidatorUpdate,
 };
+pub(crate) use vote_extension_participation_change::CheckedVoteExtensionParticipationChange;
 
 use crate::accounts::AddressBytes;
 
@@ -0,0 +1,275 @@
+use astria_core::{
+    primitive::v1::{
+        asset::IbcPrefixed,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1::action::ProposalSubmission,
+};
+use astria_eyre::eyre::{
+    ensure,
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    use_pre_aspen_validator_updates,
+    AssetTransfer,
+    CheckedProposedAction,
+    TransactionSignerAddressBytes,
+};
+use crate::{
+    accounts::StateWriteExt as _,
+    app::StateReadExt as _,
+    assets::StateReadExt as _,
+    governance::{
+        Proposal,
+        ProposalStatus,
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+};
+
+#[derive(Debug)]
+pub(crate) struct CheckedProposalSubmission {
+    action: ProposalSubmission,
+    tx_signer: TransactionSignerAddressBytes,
+    native_asset: IbcPrefixed,
+    /// The proposal deposit required at construction, used to check the signer's balance.
+    deposit: u128,
+}
+
+impl CheckedProposalSubmission {
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn new<S: StateRead>(
+        action: ProposalSubmission,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self> {
+        // Run immutable checks.
+        //
+        // Reject proposals whose action would fail its own checks if executed now. The checks are
+        // rerun when the proposal passes, as state may have changed during the voting period.
+        CheckedProposedAction::new(action.action.clone(), &state).await?;
+
+        let native_asset = state
+            .get_native_asset()
+            .await
+            .wrap_err("failed to read native asset from storage")?
+            .ok_or_eyre("native asset must be set")?
+            .to_ibc_prefixed();
+        let deposit = state
+            .get_governance_parameters()
+            .await
+            .wrap_err("failed to read governance parameters from storage")?
+            .map_or(0, |parameters| parameters.proposal_deposit());
+
+        let checked_action = Self {
+            action,
+            tx_signer: tx_signer.into(),
+            native_asset,
+            deposit,
+        };
+        checked_action.run_mutable_checks(state).await?;
+
+        Ok(checked_action)
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn run_mutable_checks<S: StateRead>(&self, state: S) -> Result<()> {
+        ensure_governance_available(&state).await
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        self.run_mutable_checks(&state).await?;
+
+        let parameters = state
+            .get_governance_parameters()
+            .await
+            .wrap_err("failed to read governance parameters from storage")?
+            .ok_or_eyre("governance parameters must be set")?;
+        let voting_end_height = state
+            .get_block_height()
+            .await
+            .wrap_err("failed to read block height from storage")?
+            .checked_add(parameters.voting_period_blocks())
+            .ok_or_eyre("overflow when calculating end of voting period")?;
+        let id = state
+            .get_next_proposal_id()
+            .await
+            .wrap_err("failed to read next proposal id from storage")?;
+        let next_id = id
+            .checked_add(1)
+            .ok_or_eyre("overflow when incrementing proposal id")?;
+        state
+            .put_next_proposal_id(next_id)
+            .wrap_err("failed to write next proposal id to storage")?;
+        // The deposit is taken from the current parameters, which the sudo address may have
+        // changed since construction.
+        let deposit = parameters.proposal_deposit();
+        if deposit > 0 {
+            state
+                .decrease_balance(&self.tx_signer, &self.native_asset, deposit)
+                .await
+                .wrap_err("failed to deduct proposal deposit from signer account balance")?;
+        }
+        state
+            .put_proposal(&Proposal {
+                id,
+                proposer: *self.tx_signer.as_bytes(),
+                action: self.action.action.clone(),
+                voting_end_height,
+                deposit,
+                status: ProposalStatus::Voting,
+            })
+            .wrap_err("failed to write proposal to storage")?;
+        Ok(())
+    }
+
+    pub(super) fn action(&self) -> &ProposalSubmission {
+        &self.action
+    }
+}
+
+impl AssetTransfer for CheckedProposalSubmission {
+    fn transfer_asset_and_amount(&self) -> Option<(IbcPrefixed, u128)> {
+        (self.deposit > 0).then_some((self.native_asset, self.deposit))
+    }
+}
+
+/// Ensures governance is enabled and the Aspen upgrade has been applied.
+pub(super) async fn ensure_governance_available<S: StateRead>(state: &S) -> Result<()> {
+    ensure!(
+        state
+            .get_governance_parameters()
+            .await
+            .wrap_err("failed to read governance parameters from storage")?
+            .is_some(),
+        "governance is not enabled",
+    );
+    ensure!(
+        !use_pre_aspen_validator_updates(state)
+            .await
+            .wrap_err("failed to get upgrade status")?,
+        "governance is not available before the Aspen upgrade",
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        generated::astria::protocol::genesis::v1::GovernanceParameters as RawGovernanceParameters,
+        protocol::genesis::v1::GovernanceParameters,
+        Protobuf as _,
+    };
+
+    use super::*;
+    use crate::test_utils::{
+        assert_error_contains,
+        dummy_proposal_submission,
+        nria,
+        Fixture,
+        ALICE_ADDRESS_BYTES,
+    };
+
+    async fn governance_fixture() -> Fixture {
+        let mut fixture = Fixture::uninitialized(None).await;
+        fixture.chain_initializer().with_governance(10).init().await;
+        let _ = fixture.run_until_aspen_applied().await;
+        fixture
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_governance_not_enabled() {
+        let mut fixture = Fixture::default_initialized().await;
+        let _ = fixture.run_until_aspen_applied().await;
+
+        let err = fixture
+            .new_checked_action(dummy_proposal_submission(), *ALICE_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "governance is not enabled");
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_aspen_not_applied() {
+        let mut fixture = Fixture::uninitialized(None).await;
+        fixture.chain_initializer().with_governance(10).init().await;
+
+        let err = fixture
+            .new_checked_action(dummy_proposal_submission(), *ALICE_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "governance is not available before the Aspen upgrade");
+    }
+
+    #[tokio::test]
+    async fn should_execute() {
+        let mut fixture = governance_fixture().await;
+        let block_height = fixture.block_height().await.value();
+
+        for expected_id in 0..2 {
+            let checked_action: CheckedProposalSubmission = fixture
+                .new_checked_action(dummy_proposal_submission(), *ALICE_ADDRESS_BYTES)
+                .await
+                .unwrap()
+                .into();
+            checked_action.execute(fixture.state_mut()).await.unwrap();
+
+            let proposal = fixture
+                .state()
+                .get_proposal(expected_id)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(proposal.id, expected_id);
+            assert_eq!(proposal.proposer, *ALICE_ADDRESS_BYTES);
+            assert_eq!(proposal.voting_end_height, block_height + 10);
+            assert_eq!(proposal.status, ProposalStatus::Voting);
+        }
+        assert_eq!(fixture.state().get_next_proposal_id().await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn should_deduct_deposit() {
+        let mut fixture = governance_fixture().await;
+        let parameters = GovernanceParameters::try_from_raw(RawGovernanceParameters {
+            voting_period_blocks: 10,
+            proposal_deposit: Some(1_000.into()),
+        })
+        .unwrap();
+        fixture
+            .state_mut()
+            .put_governance_parameters(parameters)
+            .unwrap();
+        let balance_before = fixture.get_nria_balance(&*ALICE_ADDRESS_BYTES).await;
+
+        let checked_action: CheckedProposalSubmission = fixture
+            .new_checked_action(dummy_proposal_submission(), *ALICE_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .into();
+        assert_eq!(
+            checked_action.transfer_asset_and_amount(),
+            Some((nria().to_ibc_prefixed(), 1_000))
+        );
+        checked_action.execute(fixture.state_mut()).await.unwrap();
+
+        let proposal = fixture.state().get_proposal(0).await.unwrap().unwrap();
+        assert_eq!(proposal.deposit, 1_000);
+        assert_eq!(
+            fixture.get_nria_balance(&*ALICE_ADDRESS_BYTES).await,
+            balance_before - 1_000
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use astria_core::{
+    primitive::v1::{
+        asset::IbcPrefixed,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1::action::ProposalVote,
+};
+use astria_eyre::eyre::{
+    ensure,
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    proposal_submission::ensure_governance_available,
+    AssetTransfer,
+    TransactionSignerAddressBytes,
+};
+use crate::{
+    app::StateReadExt as _,
+    authority::StateReadExt as _,
+    governance::{
+        ProposalStatus,
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+};
+
+#[derive(Debug)]
+pub(crate) struct CheckedProposalVote {
+    action: ProposalVote,
+    tx_signer: TransactionSignerAddressBytes,
+}
+
+impl CheckedProposalVote {
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn new<S: StateRead>(
+        action: ProposalVote,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self> {
+        let checked_action = Self {
+            action,
+            tx_signer: tx_signer.into(),
+        };
+        checked_action.run_mutable_checks(state).await?;
+
+        Ok(checked_action)
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn run_mutable_checks<S: StateRead>(&self, state: S) -> Result<()> {
+        ensure_governance_available(&state).await?;
+
+        // Ensure the tx signer is a member of the current validator set.
+        ensure!(
+            state
+                .get_validator(&self.tx_signer)
+                .await
+                .wrap_err("failed to read validator info from storage")?
+                .is_some(),
+            "transaction signer not authorized to vote on proposals",
+        );
+
+        // Ensure the proposal is still in its voting period.
+        let proposal = state
+            .get_proposal(self.action.proposal_id)
+            .await
+            .wrap_err("failed to read proposal from storage")?
+            .ok_or_eyre("proposal does not exist")?;
+        let block_height = state
+            .get_block_height()
+            .await
+            .wrap_err("failed to read block height from storage")?;
+        ensure!(
+            proposal.status == ProposalStatus::Voting && block_height <= proposal.voting_end_height,
+            "voting period of proposal has ended",
+        );
+        Ok(())
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        self.run_mutable_checks(&state).await?;
+        state
+            .put_proposal_vote(
+                self.action.proposal_id,
+                &self.tx_signer,
+                self.action.approve,
+            )
+            .wrap_err("failed to write proposal vote to storage")
+    }
+
+    pub(super) fn action(&self) -> &ProposalVote {
+        &self.action
+    }
+}
+
+impl AssetTransfer for CheckedProposalVote {
+    fn transfer_asset_and_amount(&self) -> Option<(IbcPrefixed, u128)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        governance::Proposal,
+        test_utils::{
+            assert_error_contains,
+            dummy_proposal_submission,
+            dummy_proposal_vote,
+            Fixture,
+            ALICE_ADDRESS_BYTES,
+            SUDO_ADDRESS_BYTES,
+        },
+    };
+
+    async fn governance_fixture_with_proposal(voting_end_height: u64) -> Fixture {
+        let mut fixture = Fixture::uninitialized(None).await;
+        fixture.chain_initializer().with_governance(10).init().await;
+        let _ = fixture.run_until_aspen_applied().await;
+        fixture
+            .state_mut()
+            .put_proposal(&Proposal {
+                id: 0,
+                proposer: *SUDO_ADDRESS_BYTES,
+                action: dummy_proposal_submission().action,
+                voting_end_height,
+                deposit: 0,
+                status: ProposalStatus::Voting,
+            })
+            .unwrap();
+        fixture
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_governance_not_enabled() {
+        let mut fixture = Fixture::default_initialized().await;
+        let _ = fixture.run_until_aspen_applied().await;
+
+        let err = fixture
+            .new_checked_action(dummy_proposal_vote(), *ALICE_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "governance is not enabled");
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_signer_is_not_validator() {
+        let fixture = governance_fixture_with_proposal(u64::MAX).await;
+
+        let err = fixture
+            .new_checked_action(dummy_proposal_vote(), *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(
+            &err,
+            "transaction signer not authorized to vote on proposals",
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_proposal_does_not_exist() {
+        let fixture = governance_fixture_with_proposal(u64::MAX).await;
+
+        let action = ProposalVote {
+            proposal_id: 1,
+            ..dummy_proposal_vote()
+        };
+        let err = fixture
+            .new_checked_action(action, *ALICE_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "proposal does not exist");
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_voting_period_ended() {
+        let fixture = governance_fixture_with_proposal(0).await;
+
+        let err = fixture
+            .new_checked_action(dummy_proposal_vote(), *ALICE_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "voting period of proposal has ended");
+    }
+
+    #[tokio::test]
+    async fn should_execute() {
+        let mut fixture = governance_fixture_with_proposal(u64::MAX).await;
+
+        let checked_action: CheckedProposalVote = fixture
+            .new_checked_action(dummy_proposal_vote(), *ALICE_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .into();
+        checked_action.execute(fixture.state_mut()).await.unwrap();
+
+        assert_eq!(
+            fixture
+                .state()
+                .get_proposal_vote(0, &*ALICE_ADDRESS_BYTES)
+                .await
+                .unwrap(),
+            Some(true)
+        );
+    }
+}
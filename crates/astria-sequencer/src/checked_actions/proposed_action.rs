@@ -0,0 +1,96 @@
+use astria_core::protocol::transaction::v1::action::ProposedAction;
+use astria_eyre::eyre::{
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    CheckedFeeAssetChange,
+    CheckedFeeChange,
+    CheckedIbcRelayerChange,
+    CheckedMarketsChange,
+    CheckedSudoAddressChange,
+};
+use crate::{
+    authority::StateReadExt as _,
+    ibc::StateReadExt as _,
+};
+
+/// A privileged action proposed through governance.
+///
+/// A passing proposal stands in for the signature of the sudo address, or of the IBC sudo address
+/// for `IbcRelayerChange`, so the wrapped action is checked and executed as if signed by that
+/// address. This type is kept separate from [`super::CheckedAction`] since proposed actions are
+/// not part of a transaction and hence pay no fees.
+#[derive(Debug)]
+pub(crate) enum CheckedProposedAction {
+    FeeChange(CheckedFeeChange),
+    FeeAssetChange(CheckedFeeAssetChange),
+    IbcRelayerChange(CheckedIbcRelayerChange),
+    MarketsChange(CheckedMarketsChange),
+    SudoAddressChange(CheckedSudoAddressChange),
+}
+
+impl CheckedProposedAction {
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(crate) async fn new<S: StateRead>(action: ProposedAction, state: S) -> Result<Self> {
+        let action_name = action.name();
+        let signer = if let ProposedAction::IbcRelayerChange(_) = action {
+            state
+                .get_ibc_sudo_address()
+                .await
+                .wrap_err("failed to read ibc sudo address from storage")?
+        } else {
+            state
+                .get_sudo_address()
+                .await
+                .wrap_err("failed to read sudo address from storage")?
+        };
+        let checked_action = match action {
+            ProposedAction::FeeChange(action) => CheckedFeeChange::new(action, signer, &state)
+                .await
+                .map(Self::FeeChange),
+            ProposedAction::FeeAssetChange(action) => {
+                CheckedFeeAssetChange::new(action, signer, &state)
+                    .await
+                    .map(Self::FeeAssetChange)
+            }
+            ProposedAction::IbcRelayerChange(action) => {
+                CheckedIbcRelayerChange::new(action, signer, &state)
+                    .await
+                    .map(Self::IbcRelayerChange)
+            }
+            ProposedAction::MarketsChange(action) => {
+                CheckedMarketsChange::new(action, signer, &state)
+                    .await
+                    .map(Self::MarketsChange)
+            }
+            ProposedAction::SudoAddressChange(action) => {
+                CheckedSudoAddressChange::new(action, signer, &state)
+                    .await
+                    .map(Self::SudoAddressChange)
+            }
+        };
+        checked_action
+            .wrap_err_with(|| format!("proposed `{action_name}` action failed its checks"))
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(crate) async fn execute<S: StateWrite>(&self, state: S) -> Result<()> {
+        match self {
+            Self::FeeChange(checked_action) => checked_action.execute(state).await,
+            Self::FeeAssetChange(checked_action) => checked_action.execute(state).await,
+            Self::IbcRelayerChange(checked_action) => checked_action.execute(state).await,
+            Self::MarketsChange(checked_action) => checked_action.execute(state).await,
+            Self::SudoAddressChange(checked_action) => checked_action.execute(state).await,
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use astria_core::{
+    primitive::v1::{
+        asset::IbcPrefixed,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1::action::RegisterMultisigAccount,
+};
+use astria_eyre::eyre::{
+    ensure,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    use_pre_aspen_validator_updates,
+    AssetTransfer,
+    TransactionSignerAddressBytes,
+};
+use crate::accounts::{
+    MultisigKeySet,
+    StateWriteExt as _,
+};
+
+#[derive(Debug)]
+pub(crate) struct CheckedRegisterMultisigAccount {
+    action: RegisterMultisigAccount,
+    tx_signer: TransactionSignerAddressBytes,
+}
+
+impl CheckedRegisterMultisigAccount {
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn new<S: StateRead>(
+        action: RegisterMultisigAccount,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self> {
+        // The key set and threshold are validated on construction of the domain type. Any account
+        // may become a multisig account, and an existing multisig account may replace its key set,
+        // since the transaction carrying this action must already have met the current threshold.
+        let checked_action = Self {
+            action,
+            tx_signer: tx_signer.into(),
+        };
+        checked_action.run_mutable_checks(state).await?;
+
+        Ok(checked_action)
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn run_mutable_checks<S: StateRead>(&self, state: S) -> Result<()> {
+        ensure!(
+            !use_pre_aspen_validator_updates(&state)
+                .await
+                .wrap_err("failed to get upgrade status")?,
+            "multisig accounts are not available before the Aspen upgrade",
+        );
+        Ok(())
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        self.run_mutable_checks(&state).await?;
+        let key_set =
+            MultisigKeySet::new(self.action.verification_keys.clone(), self.action.threshold);
+        state
+            .put_multisig_key_set(&self.tx_signer, &key_set)
+            .wrap_err("failed to write multisig key set to storage")
+    }
+
+    pub(super) fn action(&self) -> &RegisterMultisigAccount {
+        &self.action
+    }
+}
+
+impl AssetTransfer for CheckedRegisterMultisigAccount {
+    fn transfer_asset_and_amount(&self) -> Option<(IbcPrefixed, u128)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        accounts::StateReadExt as _,
+        test_utils::{
+            assert_error_contains,
+            dummy_register_multisig_account,
+            Fixture,
+            ALICE_ADDRESS_BYTES,
+        },
+    };
+
+    #[tokio::test]
+    async fn should_fail_construction_if_aspen_not_applied() {
+        let mut fixture = Fixture::uninitialized(None).await;
+        fixture.chain_initializer().init().await;
+        assert!(use_pre_aspen_validator_updates(fixture.state())
+            .await
+            .unwrap());
+
+        let err = fixture
+            .new_checked_action(dummy_register_multisig_account(), *ALICE_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(
+            &err,
+            "multisig accounts are not available before the Aspen upgrade",
+        );
+    }
+
+    #[tokio::test]
+    async fn should_execute() {
+        let mut fixture = Fixture::default_initialized().await;
+
+        let action = dummy_register_multisig_account();
+        let checked_action: CheckedRegisterMultisigAccount = fixture
+            .new_checked_action(action.clone(), *ALICE_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .into();
+        checked_action.execute(fixture.state_mut()).await.unwrap();
+
+        let key_set = fixture
+            .state()
+            .get_multisig_key_set(&*ALICE_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .expect("multisig key set should be stored");
+        assert_eq!(action.verification_keys, key_set.verification_keys());
+        assert_eq!(action.threshold, key_set.threshold());
+    }
+}
@@ -0,0 +1,314 @@
+use astria_core::{
+    primitive::v1::{
+        asset::IbcPrefixed,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1::action::{
+        ReleaseCondition,
+        ScheduledTransfer,
+    },
+};
+use astria_eyre::eyre::{
+    ensure,
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    use_pre_aspen_validator_updates,
+    AssetTransfer,
+    TransactionSignerAddressBytes,
+};
+use crate::{
+    accounts::{
+        Escrow,
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    address::StateReadExt as _,
+    app::StateReadExt as _,
+    bridge::StateReadExt as _,
+};
+
+#[derive(Debug)]
+pub(crate) struct CheckedScheduledTransfer {
+    action: ScheduledTransfer,
+    tx_signer: TransactionSignerAddressBytes,
+}
+
+impl CheckedScheduledTransfer {
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn new<S: StateRead>(
+        action: ScheduledTransfer,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self> {
+        // Run immutable checks for base prefix.
+        state
+            .ensure_base_prefix(&action.to)
+            .await
+            .wrap_err("destination address has an unsupported prefix")?;
+
+        let checked_action = Self {
+            action,
+            tx_signer: tx_signer.into(),
+        };
+        checked_action.run_mutable_checks(state).await?;
+
+        Ok(checked_action)
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn run_mutable_checks<S: StateRead>(&self, state: S) -> Result<()> {
+        ensure!(
+            !use_pre_aspen_validator_updates(&state)
+                .await
+                .wrap_err("failed to get upgrade status")?,
+            "scheduled transfers are not available before the Aspen upgrade",
+        );
+
+        // Ensure the tx signer account is not a bridge account.
+        ensure!(
+            state
+                .get_bridge_account_rollup_id(&self.tx_signer)
+                .await
+                .wrap_err("failed to read bridge account rollup id from storage")?
+                .is_none(),
+            "cannot transfer out of bridge account; BridgeUnlock or BridgeTransfer must be used",
+        );
+
+        // Ensure the release point is not in the past.
+        let height = state
+            .get_block_height()
+            .await
+            .wrap_err("failed to read block height from storage")?;
+        let unix_timestamp_seconds: u64 = state
+            .get_block_timestamp()
+            .await
+            .wrap_err("failed to read block timestamp from storage")?
+            .unix_timestamp()
+            .try_into()
+            .wrap_err("block timestamp is before the unix epoch")?;
+        let in_past = match self.action.release_at {
+            ReleaseCondition::Height(release_height) => release_height < height,
+            ReleaseCondition::UnixTimestamp(release_time) => release_time < unix_timestamp_seconds,
+        };
+        ensure!(
+            !in_past,
+            "release point `{}` is in the past; current block is at height {height} and unix \
+             timestamp {unix_timestamp_seconds}",
+            self.action.release_at,
+        );
+        Ok(())
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        self.run_mutable_checks(&state).await?;
+
+        let asset = self.action.asset.to_ibc_prefixed();
+        state
+            .decrease_balance(&self.tx_signer, &asset, self.action.amount)
+            .await
+            .wrap_err("failed to decrease signer account balance")?;
+
+        let id = state
+            .get_next_escrow_id()
+            .await
+            .wrap_err("failed to read next escrow id from storage")?;
+        let next_id = id
+            .checked_add(1)
+            .ok_or_eyre("overflow when incrementing escrow id")?;
+        state
+            .put_next_escrow_id(next_id)
+            .wrap_err("failed to write next escrow id to storage")?;
+
+        let escrow = Escrow {
+            id,
+            from: *self.tx_signer.as_bytes(),
+            to: *self.action.to.as_bytes(),
+            asset,
+            amount: self.action.amount,
+            release_at: self.action.release_at,
+        };
+        state
+            .put_escrow(&escrow)
+            .wrap_err("failed to write escrow to storage")
+    }
+
+    pub(super) fn action(&self) -> &ScheduledTransfer {
+        &self.action
+    }
+}
+
+impl AssetTransfer for CheckedScheduledTransfer {
+    fn transfer_asset_and_amount(&self) -> Option<(IbcPrefixed, u128)> {
+        Some((self.action.asset.to_ibc_prefixed(), self.action.amount))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::test_utils::address_with_prefix,
+        *,
+    };
+    use crate::test_utils::{
+        assert_error_contains,
+        astria_address,
+        dummy_scheduled_transfer,
+        Fixture,
+        ASTRIA_PREFIX,
+        SUDO_ADDRESS,
+        SUDO_ADDRESS_BYTES,
+    };
+
+    #[tokio::test]
+    async fn should_fail_construction_if_destination_address_not_base_prefixed() {
+        let fixture = Fixture::default_initialized().await;
+
+        let prefix = "different_prefix";
+        let action = ScheduledTransfer {
+            to: address_with_prefix([50; ADDRESS_LEN], prefix),
+            ..dummy_scheduled_transfer()
+        };
+        let err = fixture
+            .new_checked_action(action, *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+
+        assert_error_contains(
+            &err,
+            &format!("address has prefix `{prefix}` but only `{ASTRIA_PREFIX}` is permitted"),
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_aspen_not_applied() {
+        let mut fixture = Fixture::uninitialized(None).await;
+        fixture.chain_initializer().init().await;
+        assert!(use_pre_aspen_validator_updates(fixture.state())
+            .await
+            .unwrap());
+
+        let err = fixture
+            .new_checked_action(dummy_scheduled_transfer(), *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(
+            &err,
+            "scheduled transfers are not available before the Aspen upgrade",
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_signer_account_is_bridge_account() {
+        let mut fixture = Fixture::default_initialized().await;
+        fixture.bridge_initializer(*SUDO_ADDRESS).init().await;
+
+        let action = dummy_scheduled_transfer();
+        let err = fixture
+            .new_checked_action(action, *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+
+        assert_error_contains(
+            &err,
+            "cannot transfer out of bridge account; BridgeUnlock or BridgeTransfer must be used",
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_release_height_in_past() {
+        let fixture = Fixture::default_initialized().await;
+        let height = fixture.block_height().await.value();
+
+        let action = ScheduledTransfer {
+            release_at: ReleaseCondition::Height(height.checked_sub(1).unwrap()),
+            ..dummy_scheduled_transfer()
+        };
+        let err = fixture
+            .new_checked_action(action, *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+
+        assert_error_contains(&err, "is in the past");
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_release_timestamp_in_past() {
+        let fixture = Fixture::default_initialized().await;
+
+        let action = ScheduledTransfer {
+            release_at: ReleaseCondition::UnixTimestamp(1_700_000_000),
+            ..dummy_scheduled_transfer()
+        };
+        let err = fixture
+            .new_checked_action(action, *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+
+        assert_error_contains(&err, "is in the past");
+    }
+
+    #[tokio::test]
+    async fn should_execute() {
+        let mut fixture = Fixture::default_initialized().await;
+
+        let action = ScheduledTransfer {
+            to: astria_address(&[50; ADDRESS_LEN]),
+            release_at: ReleaseCondition::UnixTimestamp(1_800_000_000),
+            ..dummy_scheduled_transfer()
+        };
+        let asset = action.asset.to_ibc_prefixed();
+        fixture
+            .state_mut()
+            .put_account_balance(&*SUDO_ADDRESS_BYTES, &asset, action.amount)
+            .unwrap();
+        let checked_action: CheckedScheduledTransfer = fixture
+            .new_checked_action(action.clone(), *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .into();
+        checked_action.execute(fixture.state_mut()).await.unwrap();
+
+        assert_eq!(
+            0,
+            fixture
+                .state()
+                .get_account_balance(&*SUDO_ADDRESS_BYTES, &asset)
+                .await
+                .unwrap()
+        );
+        let expected_escrow = Escrow {
+            id: 0,
+            from: *SUDO_ADDRESS_BYTES,
+            to: [50; ADDRESS_LEN],
+            asset,
+            amount: action.amount,
+            release_at: action.release_at,
+        };
+        assert_eq!(
+            Some(expected_escrow),
+            fixture.state().get_escrow(0).await.unwrap()
+        );
+        assert_eq!(1, fixture.state().get_next_escrow_id().await.unwrap());
+        assert_eq!(
+            vec![0],
+            fixture
+                .state()
+                .get_account_escrow_ids(&[50; ADDRESS_LEN])
+                .await
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,287 @@
+use astria_core::{
+    primitive::v1::{
+        asset::IbcPrefixed,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1::action::StakingParametersChange,
+};
+use astria_eyre::eyre::{
+    ensure,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    AssetTransfer,
+    TransactionSignerAddressBytes,
+};
+use crate::{
+    authority::StateReadExt as _,
+    staking::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+};
+
+#[derive(Debug)]
+pub(crate) struct CheckedStakingParametersChange {
+    action: StakingParametersChange,
+    tx_signer: TransactionSignerAddressBytes,
+}
+
+impl CheckedStakingParametersChange {
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn new<S: StateRead>(
+        action: StakingParametersChange,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self> {
+        // The action has no immutable checks beyond those performed when parsing it.
+        let checked_action = Self {
+            action,
+            tx_signer: tx_signer.into(),
+        };
+        checked_action.run_mutable_checks(state).await?;
+
+        Ok(checked_action)
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn run_mutable_checks<S: StateRead>(&self, state: S) -> Result<()> {
+        // Check that the signer of this tx is the authorized sudo address.
+        let sudo_address = state
+            .get_sudo_address()
+            .await
+            .wrap_err("failed to read sudo address from storage")?;
+        ensure!(
+            &sudo_address == self.tx_signer.as_bytes(),
+            "transaction signer not authorized to change staking parameters",
+        );
+
+        Ok(())
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        self.run_mutable_checks(&state).await?;
+        let old_stake_per_power = state
+            .get_staking_parameters()
+            .await
+            .wrap_err("failed to read staking parameters from storage")?
+            .map(|parameters| parameters.stake_per_power());
+        state
+            .put_staking_parameters(self.action.parameters)
+            .wrap_err("failed to write staking parameters to storage")?;
+
+        // The power of a staked validator is derived from its stake and the stake per power, so
+        // all staked validators have their power recomputed at the end of the block if the latter
+        // changed.
+        if old_stake_per_power != Some(self.action.parameters.stake_per_power()) {
+            for stake in state
+                .get_validator_stakes()
+                .await
+                .wrap_err("failed to read validator stakes from storage")?
+            {
+                state.record_stake_change(stake.validator);
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn action(&self) -> &StakingParametersChange {
+        &self.action
+    }
+}
+
+impl AssetTransfer for CheckedStakingParametersChange {
+    fn transfer_asset_and_amount(&self) -> Option<(IbcPrefixed, u128)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        generated::astria::protocol::genesis::v1 as raw,
+        protocol::{
+            genesis::v1::StakingParameters,
+            transaction::v1::action::SudoAddressChange,
+        },
+        Protobuf as _,
+    };
+
+    use super::*;
+    use crate::{
+        checked_actions::CheckedSudoAddressChange,
+        staking::ValidatorStake,
+        test_utils::{
+            assert_error_contains,
+            astria_address,
+            dummy_staking_parameters_change,
+            Fixture,
+            ALICE,
+            SUDO_ADDRESS_BYTES,
+        },
+    };
+
+    fn staking_parameters(
+        unbonding_period_blocks: u64,
+        stake_per_power: u128,
+    ) -> StakingParameters {
+        StakingParameters::try_from_raw(raw::StakingParameters {
+            unbonding_period_blocks,
+            stake_per_power: Some(stake_per_power.into()),
+        })
+        .unwrap()
+    }
+
+    async fn new_staking_fixture() -> Fixture {
+        let mut fixture = Fixture::uninitialized(None).await;
+        fixture
+            .chain_initializer()
+            .with_staking(10, 100)
+            .init()
+            .await;
+        fixture
+            .state_mut()
+            .put_validator_stake(&ValidatorStake {
+                validator: ALICE.verification_key(),
+                stake: 1000,
+            })
+            .unwrap();
+        fixture
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_signer_is_not_sudo_address() {
+        let fixture = Fixture::default_initialized().await;
+
+        let tx_signer = [2_u8; ADDRESS_LEN];
+        assert_ne!(*SUDO_ADDRESS_BYTES, tx_signer);
+
+        let err = fixture
+            .new_checked_action(dummy_staking_parameters_change(), tx_signer)
+            .await
+            .unwrap_err();
+        assert_error_contains(
+            &err,
+            "transaction signer not authorized to change staking parameters",
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fail_execution_if_signer_is_not_sudo_address() {
+        let mut fixture = Fixture::default_initialized().await;
+
+        // Construct the checked action while the sudo address is still the tx signer so
+        // construction succeeds.
+        let checked_action: CheckedStakingParametersChange = fixture
+            .new_checked_action(dummy_staking_parameters_change(), *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .into();
+
+        // Change the sudo address to something other than the tx signer.
+        let sudo_address_change = SudoAddressChange {
+            new_address: astria_address(&[2; ADDRESS_LEN]),
+        };
+        let checked_sudo_address_change: CheckedSudoAddressChange = fixture
+            .new_checked_action(sudo_address_change, *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .into();
+        checked_sudo_address_change
+            .execute(fixture.state_mut())
+            .await
+            .unwrap();
+
+        // Try to execute the checked action now - should fail due to signer no longer being
+        // authorized.
+        let err = checked_action
+            .execute(fixture.state_mut())
+            .await
+            .unwrap_err();
+        assert_error_contains(
+            &err,
+            "transaction signer not authorized to change staking parameters",
+        );
+    }
+
+    #[tokio::test]
+    async fn should_execute_enabling_staking() {
+        let mut fixture = Fixture::default_initialized().await;
+        assert!(fixture
+            .state()
+            .get_staking_parameters()
+            .await
+            .unwrap()
+            .is_none());
+
+        let action = dummy_staking_parameters_change();
+        let checked_action: CheckedStakingParametersChange = fixture
+            .new_checked_action(action.clone(), *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .into();
+        checked_action.execute(fixture.state_mut()).await.unwrap();
+
+        assert_eq!(
+            fixture.state().get_staking_parameters().await.unwrap(),
+            Some(action.parameters)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_recompute_staked_validators_if_stake_per_power_changes() {
+        let mut fixture = new_staking_fixture().await;
+
+        let checked_action: CheckedStakingParametersChange = fixture
+            .new_checked_action(
+                StakingParametersChange {
+                    parameters: staking_parameters(10, 200),
+                },
+                *SUDO_ADDRESS_BYTES,
+            )
+            .await
+            .unwrap()
+            .into();
+        checked_action.execute(fixture.state_mut()).await.unwrap();
+
+        assert!(fixture
+            .state()
+            .get_block_stake_changes()
+            .contains(&ALICE.verification_key()));
+    }
+
+    #[tokio::test]
+    async fn should_not_recompute_staked_validators_if_stake_per_power_is_unchanged() {
+        let mut fixture = new_staking_fixture().await;
+
+        let parameters = staking_parameters(20, 100);
+        let checked_action: CheckedStakingParametersChange = fixture
+            .new_checked_action(
+                StakingParametersChange {
+                    parameters,
+                },
+                *SUDO_ADDRESS_BYTES,
+            )
+            .await
+            .unwrap()
+            .into();
+        checked_action.execute(fixture.state_mut()).await.unwrap();
+
+        assert_eq!(
+            fixture.state().get_staking_parameters().await.unwrap(),
+            Some(parameters)
+        );
+        assert!(fixture.state().get_block_stake_changes().is_empty());
+    }
+}
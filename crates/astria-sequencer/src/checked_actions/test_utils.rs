@@ -22,21 +22,31 @@ use astria_core::{
 
 use crate::test_utils::{
     astria_address,
+    dummy_bond,
     dummy_bridge_lock,
     dummy_bridge_sudo_change,
     dummy_bridge_transfer,
     dummy_bridge_unlock,
     dummy_currency_pairs_change,
+    dummy_governance_parameters_change,
     dummy_ibc_relay,
+    dummy_ics20_rate_limit_change,
     dummy_ics20_withdrawal,
     dummy_init_bridge_account,
     dummy_markets_change,
+    dummy_proposal_submission,
+    dummy_proposal_vote,
     dummy_recover_ibc_client,
+    dummy_register_multisig_account,
     dummy_rollup_data_submission,
+    dummy_scheduled_transfer,
+    dummy_staking_parameters_change,
     dummy_transfer,
+    dummy_unbond,
+    dummy_vote_extension_participation_change,
 };
 
-pub(crate) fn dummy_actions() -> [Action; 18] {
+pub(crate) fn dummy_actions() -> [Action; 28] {
     let validator_update = ValidatorUpdate {
         power: 101,
         verification_key: VerificationKey::try_from([10; 32]).unwrap(),
@@ -71,6 +81,16 @@ pub(crate) fn dummy_actions() -> [Action; 18] {
         Action::RecoverIbcClient(dummy_recover_ibc_client()),
         Action::CurrencyPairsChange(dummy_currency_pairs_change()),
         Action::MarketsChange(dummy_markets_change()),
+        Action::RegisterMultisigAccount(dummy_register_multisig_account()),
+        Action::ScheduledTransfer(dummy_scheduled_transfer()),
+        Action::Ics20RateLimitChange(dummy_ics20_rate_limit_change()),
+        Action::VoteExtensionParticipationChange(dummy_vote_extension_participation_change()),
+        Action::Bond(dummy_bond()),
+        Action::Unbond(dummy_unbond()),
+        Action::ProposalSubmission(dummy_proposal_submission()),
+        Action::ProposalVote(dummy_proposal_vote()),
+        Action::StakingParametersChange(dummy_staking_parameters_change()),
+        Action::GovernanceParametersChange(dummy_governance_parameters_change()),
     ]
 }
 
@@ -0,0 +1,328 @@
+use astria_core::{
+    primitive::v1::{
+        asset::IbcPrefixed,
+        ADDRESS_LEN,
+    },
+    protocol::{
+        staking::v1::Delegation,
+        transaction::v1::action::{
+            ReleaseCondition,
+            Unbond,
+        },
+    },
+};
+use astria_eyre::eyre::{
+    ensure,
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    use_pre_aspen_validator_updates,
+    AssetTransfer,
+    TransactionSignerAddressBytes,
+};
+use crate::{
+    accounts::{
+        Escrow,
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    app::StateReadExt as _,
+    assets::StateReadExt as _,
+    staking::{
+        StateReadExt as _,
+        StateWriteExt as _,
+        ValidatorStake,
+    },
+};
+
+#[derive(Debug)]
+pub(crate) struct CheckedUnbond {
+    action: Unbond,
+    tx_signer: TransactionSignerAddressBytes,
+}
+
+impl CheckedUnbond {
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn new<S: StateRead>(
+        action: Unbond,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self> {
+        // Run immutable checks.
+        ensure!(action.amount > 0, "cannot unbond a zero amount");
+
+        let checked_action = Self {
+            action,
+            tx_signer: tx_signer.into(),
+        };
+        checked_action.run_mutable_checks(state).await?;
+
+        Ok(checked_action)
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn run_mutable_checks<S: StateRead>(&self, state: S) -> Result<()> {
+        ensure!(
+            state
+                .get_staking_parameters()
+                .await
+                .wrap_err("failed to read staking parameters from storage")?
+                .is_some(),
+            "staking is not enabled",
+        );
+        ensure!(
+            !use_pre_aspen_validator_updates(&state)
+                .await
+                .wrap_err("failed to get upgrade status")?,
+            "staking is not available before the Aspen upgrade",
+        );
+
+        let delegated_amount = state
+            .get_delegated_amount(&self.tx_signer, &self.action.validator)
+            .await
+            .wrap_err("failed to read delegation from storage")?;
+        ensure!(
+            delegated_amount >= self.action.amount,
+            "cannot unbond more than the amount delegated to the validator",
+        );
+        Ok(())
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        self.run_mutable_checks(&state).await?;
+
+        let parameters = state
+            .get_staking_parameters()
+            .await
+            .wrap_err("failed to read staking parameters from storage")?
+            .ok_or_eyre("staking parameters must be set")?;
+
+        let delegated_amount = state
+            .get_delegated_amount(&self.tx_signer, &self.action.validator)
+            .await
+            .wrap_err("failed to read delegation from storage")?
+            .checked_sub(self.action.amount)
+            .ok_or_eyre("underflow when decreasing delegated amount")?;
+        state
+            .put_delegation(
+                &self.tx_signer,
+                &Delegation {
+                    validator: self.action.validator.clone(),
+                    amount: delegated_amount,
+                },
+            )
+            .wrap_err("failed to write delegation to storage")?;
+
+        let stake = state
+            .get_validator_stake(&self.action.validator)
+            .await
+            .wrap_err("failed to read validator stake from storage")?
+            .map_or(0, |stake| stake.stake)
+            .checked_sub(self.action.amount)
+            .ok_or_eyre("underflow when decreasing validator stake")?;
+        state
+            .put_validator_stake(&ValidatorStake {
+                validator: self.action.validator.clone(),
+                stake,
+            })
+            .wrap_err("failed to write validator stake to storage")?;
+
+        state.record_stake_change(self.action.validator.clone());
+
+        // The unbonded funds are held in escrow until the unbonding period has elapsed.
+        let release_height = state
+            .get_block_height()
+            .await
+            .wrap_err("failed to read block height from storage")?
+            .checked_add(parameters.unbonding_period_blocks())
+            .ok_or_eyre("overflow when calculating unbonding release height")?;
+        let asset = state
+            .get_native_asset()
+            .await
+            .wrap_err("failed to read native asset from storage")?
+            .ok_or_eyre("native asset must be set")?
+            .to_ibc_prefixed();
+
+        let id = state
+            .get_next_escrow_id()
+            .await
+            .wrap_err("failed to read next escrow id from storage")?;
+        let next_id = id
+            .checked_add(1)
+            .ok_or_eyre("overflow when incrementing escrow id")?;
+        state
+            .put_next_escrow_id(next_id)
+            .wrap_err("failed to write next escrow id to storage")?;
+
+        let escrow = Escrow {
+            id,
+            from: *self.tx_signer.as_bytes(),
+            to: *self.tx_signer.as_bytes(),
+            asset,
+            amount: self.action.amount,
+            release_at: ReleaseCondition::Height(release_height),
+        };
+        state
+            .put_escrow(&escrow)
+            .wrap_err("failed to write escrow to storage")
+    }
+
+    pub(super) fn action(&self) -> &Unbond {
+        &self.action
+    }
+}
+
+impl AssetTransfer for CheckedUnbond {
+    fn transfer_asset_and_amount(&self) -> Option<(IbcPrefixed, u128)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::protocol::transaction::v1::action::Bond;
+
+    use super::*;
+    use crate::{
+        checked_actions::CheckedBond,
+        test_utils::{
+            assert_error_contains,
+            dummy_bond,
+            dummy_unbond,
+            nria,
+            Fixture,
+            ALICE,
+            BOB_ADDRESS_BYTES,
+        },
+    };
+
+    async fn fixture_with_bond(amount: u128) -> Fixture {
+        let mut fixture = Fixture::uninitialized(None).await;
+        fixture
+            .chain_initializer()
+            .with_staking(10, 100)
+            .init()
+            .await;
+        let _ = fixture.run_until_aspen_applied().await;
+
+        let bond = Bond {
+            validator: ALICE.verification_key(),
+            amount,
+            ..dummy_bond()
+        };
+        let checked_action: CheckedBond = fixture
+            .new_checked_action(bond, *BOB_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .into();
+        checked_action.execute(fixture.state_mut()).await.unwrap();
+        fixture
+    }
+
+    fn unbond_from_alice(amount: u128) -> Unbond {
+        Unbond {
+            validator: ALICE.verification_key(),
+            amount,
+            ..dummy_unbond()
+        }
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_amount_is_zero() {
+        let fixture = fixture_with_bond(100).await;
+
+        let err = fixture
+            .new_checked_action(unbond_from_alice(0), *BOB_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "cannot unbond a zero amount");
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_staking_not_enabled() {
+        let fixture = Fixture::default_initialized().await;
+
+        let err = fixture
+            .new_checked_action(dummy_unbond(), *BOB_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "staking is not enabled");
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_unbonding_more_than_delegated() {
+        let fixture = fixture_with_bond(100).await;
+
+        let err = fixture
+            .new_checked_action(unbond_from_alice(101), *BOB_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(
+            &err,
+            "cannot unbond more than the amount delegated to the validator",
+        );
+    }
+
+    #[tokio::test]
+    async fn should_execute() {
+        let mut fixture = fixture_with_bond(300).await;
+        let balance_before = fixture.get_nria_balance(&*BOB_ADDRESS_BYTES).await;
+        let next_escrow_id = fixture.state().get_next_escrow_id().await.unwrap();
+        let height = fixture.state().get_block_height().await.unwrap();
+
+        let checked_action: CheckedUnbond = fixture
+            .new_checked_action(unbond_from_alice(100), *BOB_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .into();
+        checked_action.execute(fixture.state_mut()).await.unwrap();
+
+        assert_eq!(
+            fixture
+                .state()
+                .get_delegated_amount(&*BOB_ADDRESS_BYTES, &ALICE.verification_key())
+                .await
+                .unwrap(),
+            200
+        );
+        assert_eq!(
+            fixture
+                .state()
+                .get_validator_stake(&ALICE.verification_key())
+                .await
+                .unwrap()
+                .unwrap()
+                .stake,
+            200
+        );
+        // The balance is not returned until the escrow is released.
+        assert_eq!(
+            fixture.get_nria_balance(&*BOB_ADDRESS_BYTES).await,
+            balance_before
+        );
+        let expected_escrow = Escrow {
+            id: next_escrow_id,
+            from: *BOB_ADDRESS_BYTES,
+            to: *BOB_ADDRESS_BYTES,
+            asset: nria().to_ibc_prefixed(),
+            amount: 100,
+            release_at: ReleaseCondition::Height(height.checked_add(10).unwrap()),
+        };
+        assert_eq!(
+            Some(expected_escrow),
+            fixture.state().get_escrow(next_escrow_id).await.unwrap()
+        );
+    }
+}
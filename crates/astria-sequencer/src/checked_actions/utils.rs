@@ -51,6 +51,16 @@ pub(crate) async fn total_fees<'a, I: Iterator<Item = ActionRef<'a>>, S: StateRe
             ActionRef::RecoverIbcClient(action) => fee(action, state).await,
             ActionRef::CurrencyPairsChange(action) => fee(action, state).await,
             ActionRef::MarketsChange(action) => fee(action, state).await,
+            ActionRef::RegisterMultisigAccount(action) => fee(action, state).await,
+            ActionRef::ScheduledTransfer(action) => fee(action, state).await,
+            ActionRef::Ics20RateLimitChange(action) => fee(action, state).await,
+            ActionRef::VoteExtensionParticipationChange(action) => fee(action, state).await,
+            ActionRef::Bond(action) => fee(action, state).await,
+            ActionRef::Unbond(action) => fee(action, state).await,
+            ActionRef::ProposalSubmission(action) => fee(action, state).await,
+            ActionRef::ProposalVote(action) => fee(action, state).await,
+            ActionRef::StakingParametersChange(action) => fee(action, state).await,
+            ActionRef::GovernanceParametersChange(action) => fee(action, state).await,
         }?;
         let Some((fee_asset, fee_amount)) = maybe_fee else {
             // If there's no fee asset, we don't charge fees.
@@ -35,6 +35,7 @@ use crate::{
         StateReadExt as _,
         StateWriteExt as _,
     },
+    staking::StateReadExt as _,
     upgrades::StateReadExt as _,
 };
 
@@ -76,6 +77,32 @@ impl CheckedValidatorUpdate {
             "transaction signer not authorized to update validator set",
         );
 
+        // When staking is enabled, validator power is derived from bonded stake. Validators without
+        // stake (i.e. those added before staking was enabled) can still have their power reduced or
+        // be removed, so that they can be wound down as stake is bonded to other validators.
+        if state
+            .get_staking_parameters()
+            .await
+            .wrap_err("failed to read staking parameters from storage")?
+            .is_some()
+        {
+            let current_power = state
+                .get_validator(&self.action.verification_key)
+                .await
+                .wrap_err("failed to read validator info from storage")?
+                .map(|validator| validator.power);
+            let has_stake = state
+                .get_validator_stake(&self.action.verification_key)
+                .await
+                .wrap_err("failed to read validator stake from storage")?
+                .is_some();
+            ensure!(
+                !has_stake && current_power.is_some_and(|power| self.action.power < power),
+                "validator set is managed by staking; validator updates may only reduce the power \
+                 of validators without stake",
+            );
+        }
+
         if use_pre_aspen_validator_updates(&state)
             .await
             .wrap_err("failed to get upgrade status")?
@@ -206,6 +233,10 @@ mod tests {
     use super::*;
     use crate::{
         checked_actions::CheckedSudoAddressChange,
+        staking::{
+            StateWriteExt as _,
+            ValidatorStake,
+        },
         test_utils::{
             assert_error_contains,
             astria_address,
@@ -449,6 +480,77 @@ mod tests {
         }
     }
 
+    async fn new_staking_fixture() -> Fixture {
+        let mut fixture = Fixture::uninitialized(None).await;
+        fixture
+            .chain_initializer()
+            .with_staking(10, 100)
+            .init()
+            .await;
+        let _ = fixture.run_until_aspen_applied().await;
+        fixture
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_staking_is_enabled_and_power_increases() {
+        let fixture = new_staking_fixture().await;
+
+        let action = dummy_validator_update(99, ALICE.verification_key().to_bytes());
+        let err = fixture
+            .new_checked_action(action, *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(
+            &err,
+            "validator set is managed by staking; validator updates may only reduce the power of \
+             validators without stake",
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fail_construction_if_staking_is_enabled_and_validator_has_stake() {
+        let mut fixture = new_staking_fixture().await;
+        fixture
+            .state_mut()
+            .put_validator_stake(&ValidatorStake {
+                validator: ALICE.verification_key(),
+                stake: 1000,
+            })
+            .unwrap();
+
+        let action = dummy_validator_update(5, ALICE.verification_key().to_bytes());
+        let err = fixture
+            .new_checked_action(action, *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap_err();
+        assert_error_contains(
+            &err,
+            "validator set is managed by staking; validator updates may only reduce the power of \
+             validators without stake",
+        );
+    }
+
+    #[tokio::test]
+    async fn should_execute_power_reduction_of_unstaked_validator_if_staking_is_enabled() {
+        let mut fixture = new_staking_fixture().await;
+
+        let action = dummy_validator_update(5, ALICE.verification_key().to_bytes());
+        let checked_action: CheckedValidatorUpdate = fixture
+            .new_checked_action(action.clone(), *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .into();
+        checked_action.execute(fixture.state_mut()).await.unwrap();
+
+        let retrieved_validator = fixture
+            .state()
+            .get_validator(&*crate::test_utils::ALICE_ADDRESS_BYTES)
+            .await
+            .expect("should get validator")
+            .expect("validator should not be None");
+        assert_eq!(retrieved_validator, action);
+    }
+
     mod pre_aspen {
         #[tokio::test]
         async fn should_fail_construction_if_signer_is_not_sudo_address() {
@@ -0,0 +1,215 @@
+use astria_core::{
+    primitive::v1::{
+        asset::IbcPrefixed,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1::action::VoteExtensionParticipationChange,
+};
+use astria_eyre::eyre::{
+    ensure,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    AssetTransfer,
+    TransactionSignerAddressBytes,
+};
+use crate::authority::{
+    StateReadExt as _,
+    StateWriteExt as _,
+};
+
+#[derive(Debug)]
+pub(crate) struct CheckedVoteExtensionParticipationChange {
+    action: VoteExtensionParticipationChange,
+    tx_signer: TransactionSignerAddressBytes,
+}
+
+impl CheckedVoteExtensionParticipationChange {
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn new<S: StateRead>(
+        action: VoteExtensionParticipationChange,
+        tx_signer: [u8; ADDRESS_LEN],
+        state: S,
+    ) -> Result<Self> {
+        // The action has no immutable checks beyond those performed when parsing it.
+        let checked_action = Self {
+            action,
+            tx_signer: tx_signer.into(),
+        };
+        checked_action.run_mutable_checks(state).await?;
+
+        Ok(checked_action)
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn run_mutable_checks<S: StateRead>(&self, state: S) -> Result<()> {
+        // Check that the signer of this tx is the authorized sudo address.
+        let sudo_address = state
+            .get_sudo_address()
+            .await
+            .wrap_err("failed to read sudo address from storage")?;
+        ensure!(
+            &sudo_address == self.tx_signer.as_bytes(),
+            "transaction signer not authorized to change vote extension participation",
+        );
+
+        Ok(())
+    }
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    pub(super) async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        self.run_mutable_checks(&state).await?;
+        if let Some(participation) = self.action.participation {
+            state
+                .put_vote_extension_participation(participation)
+                .wrap_err("failed to write vote extension participation to storage")?;
+        } else {
+            state.delete_vote_extension_participation();
+        }
+        Ok(())
+    }
+
+    pub(super) fn action(&self) -> &VoteExtensionParticipationChange {
+        &self.action
+    }
+}
+
+impl AssetTransfer for CheckedVoteExtensionParticipationChange {
+    fn transfer_asset_and_amount(&self) -> Option<(IbcPrefixed, u128)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::protocol::transaction::v1::action::{
+        SudoAddressChange,
+        VoteExtensionParticipation,
+    };
+
+    use super::*;
+    use crate::{
+        checked_actions::CheckedSudoAddressChange,
+        test_utils::{
+            assert_error_contains,
+            astria_address,
+            dummy_vote_extension_participation_change,
+            Fixture,
+            SUDO_ADDRESS_BYTES,
+        },
+    };
+
+    #[tokio::test]
+    async fn should_fail_construction_if_signer_is_not_sudo_address() {
+        let fixture = Fixture::default_initialized().await;
+
+        let tx_signer = [2_u8; ADDRESS_LEN];
+        assert_ne!(*SUDO_ADDRESS_BYTES, tx_signer);
+
+        let err = fixture
+            .new_checked_action(dummy_vote_extension_participation_change(), tx_signer)
+            .await
+            .unwrap_err();
+        assert_error_contains(
+            &err,
+            "transaction signer not authorized to change vote extension participation",
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fail_execution_if_signer_is_not_sudo_address() {
+        let mut fixture = Fixture::default_initialized().await;
+
+        // Construct the checked action while the sudo address is still the tx signer so
+        // construction succeeds.
+        let checked_action: CheckedVoteExtensionParticipationChange = fixture
+            .new_checked_action(
+                dummy_vote_extension_participation_change(),
+                *SUDO_ADDRESS_BYTES,
+            )
+            .await
+            .unwrap()
+            .into();
+
+        // Change the sudo address to something other than the tx signer.
+        let sudo_address_change = SudoAddressChange {
+            new_address: astria_address(&[2; ADDRESS_LEN]),
+        };
+        let checked_sudo_address_change: CheckedSudoAddressChange = fixture
+            .new_checked_action(sudo_address_change, *SUDO_ADDRESS_BYTES)
+            .await
+            .unwrap()
+            .into();
+        checked_sudo_address_change
+            .execute(fixture.state_mut())
+            .await
+            .unwrap();
+
+        // Try to execute the checked action now - should fail due to signer no longer being
+        // authorized.
+        let err = checked_action
+            .execute(fixture.state_mut())
+            .await
+            .unwrap_err();
+        assert_error_contains(
+            &err,
+            "transaction signer not authorized to change vote extension participation",
+        );
+    }
+
+    #[tokio::test]
+    async fn should_execute_setting_and_removing_participation() {
+        let mut fixture = Fixture::default_initialized().await;
+
+        let expected_participation = VoteExtensionParticipation {
+            window_blocks: 3,
+            max_missed_blocks: 2,
+            power_reduction_percent: 1,
+        };
+        let checked_action: CheckedVoteExtensionParticipationChange = fixture
+            .new_checked_action(
+                VoteExtensionParticipationChange {
+                    participation: Some(expected_participation),
+                },
+                *SUDO_ADDRESS_BYTES,
+            )
+            .await
+            .unwrap()
+            .into();
+        checked_action.execute(fixture.state_mut()).await.unwrap();
+        let participation = fixture
+            .state()
+            .get_vote_extension_participation()
+            .await
+            .unwrap();
+        assert_eq!(Some(expected_participation), participation);
+
+        let checked_action: CheckedVoteExtensionParticipationChange = fixture
+            .new_checked_action(
+                VoteExtensionParticipationChange {
+                    participation: None,
+                },
+                *SUDO_ADDRESS_BYTES,
+            )
+            .await
+            .unwrap()
+            .into();
+        checked_action.execute(fixture.state_mut()).await.unwrap();
+        let participation = fixture
+            .state()
+            .get_vote_extension_participation()
+            .await
+            .unwrap();
+        assert!(participation.is_none());
+    }
+}
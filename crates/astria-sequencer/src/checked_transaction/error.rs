@@ -12,6 +12,7 @@ use thiserror::Error;
 use super::MAX_TX_BYTES;
 use crate::checked_actions::{
     CheckedActionExecutionError,
+    CheckedActionFeeError,
     CheckedActionInitialCheckError,
 };
 
@@ -50,6 +51,18 @@ pub(crate) enum CheckedTransactionInitialCheckError {
     #[error(transparent)]
     CheckedAction(#[from] CheckedActionInitialCheckError),
 
+    #[error("transaction signers not authorized: {0}")]
+    Unauthorized(#[source] UnauthorizedSignersError),
+
+    #[error("fee payer `{address}` cannot pay the transaction fees: {source}")]
+    FeePayer {
+        address: String,
+        source: CheckedActionFeeError,
+    },
+
+    #[error("transactions cannot name a fee payer before the Aspen upgrade")]
+    FeePayerBeforeAspen,
+
     #[error("internal error: {context}: {source:#}")]
     InternalError {
         context: String,
@@ -81,9 +94,16 @@ impl From<CheckedTransactionInitialCheckError> for tonic::Status {
             | CheckedTransactionInitialCheckError::ChainIdMismatch {
                 ..
             }
-            | CheckedTransactionInitialCheckError::CheckedAction(_) => {
+            | CheckedTransactionInitialCheckError::CheckedAction(_)
+            | CheckedTransactionInitialCheckError::FeePayerBeforeAspen => {
                 tonic::Status::invalid_argument(msg)
             }
+            CheckedTransactionInitialCheckError::Unauthorized(_) => {
+                tonic::Status::permission_denied(msg)
+            }
+            CheckedTransactionInitialCheckError::FeePayer {
+                ..
+            } => tonic::Status::failed_precondition(msg),
             CheckedTransactionInitialCheckError::InternalError {
                 ..
             } => tonic::Status::internal(msg),
@@ -104,6 +124,12 @@ pub(crate) enum CheckedTransactionExecutionError {
     #[error("overflow occurred incrementing action index")]
     ActionIndexOverflowed,
 
+    #[error("transaction signers not authorized: {0}")]
+    Unauthorized(#[source] UnauthorizedSignersError),
+
+    #[error("transactions cannot name a fee payer before the Aspen upgrade")]
+    FeePayerBeforeAspen,
+
     #[error(transparent)]
     CheckedAction(#[from] CheckedActionExecutionError),
 
@@ -122,3 +148,22 @@ impl CheckedTransactionExecutionError {
         }
     }
 }
+
+/// The reasons the signers of a transaction are not authorized to act on behalf of its account.
+#[derive(Debug, Error)]
+pub(crate) enum UnauthorizedSignersError {
+    #[error("transaction names `{address}` as its multisig account, but it is not one")]
+    NotMultisigAccount { address: String },
+
+    #[error("transaction has cosignatures, but its account is not a multisig account")]
+    UnexpectedCosignatures,
+
+    #[error(
+        "transaction signed by {signers} distinct keys of the multisig account, but its threshold \
+         is {threshold}"
+    )]
+    InsufficientSignatures { signers: u32, threshold: u32 },
+
+    #[error("fee payer `{address}` is a multisig account, which cannot sponsor transactions")]
+    MultisigFeePayer { address: String },
+}
@@ -8,6 +8,7 @@ use astria_core::{
     generated::astria::protocol::transaction::v1 as raw,
     primitive::v1::{
         asset::IbcPrefixed,
+        Address,
         RollupId,
         TransactionId,
     },
@@ -36,10 +37,12 @@ use tracing::{
 pub(crate) use self::error::{
     CheckedTransactionExecutionError,
     CheckedTransactionInitialCheckError,
+    UnauthorizedSignersError,
 };
 use crate::{
     accounts::{
         AddressBytes,
+        MultisigKeySet,
         StateReadExt as _,
         StateWriteExt as _,
     },
@@ -49,6 +52,7 @@ use crate::{
         StateWriteExt as _,
     },
     checked_actions::{
+        use_pre_aspen_validator_updates,
         utils::total_fees,
         ActionRef,
         CheckedAction,
@@ -67,7 +71,9 @@ const MAX_TX_BYTES: usize = 256_000;
 /// execution as often as required.
 ///
 /// Nonce checks and account balance checks are excluded from these, as the `Mempool` ensures
-/// transactions put forward for execution have the correct nonces and sufficient balances.
+/// transactions put forward for execution have the correct nonces and sufficient balances. The
+/// exception is the balance of a fee payer other than the transaction's account, which the
+/// `Mempool` does not track, and which is checked once on construction.
 ///
 /// Checks with immutable outcomes are not rechecked.
 ///
@@ -80,6 +86,10 @@ pub(crate) struct CheckedTransaction {
     group: Group,
     params: TransactionParams,
     verification_key: VerificationKey,
+    cosigner_keys: Vec<VerificationKey>,
+    multisig_address: Option<Address>,
+    signer_address: [u8; ADDRESS_LENGTH],
+    fee_payer: Option<Address>,
     tx_bytes: Bytes,
 }
 
@@ -127,14 +137,62 @@ impl CheckedTransaction {
 
         let tx_id = TransactionId::new(sha2::Sha256::digest(&tx_bytes).into());
         let tx_chain_id = tx.chain_id().to_string();
+        let signer_address = *tx.address_bytes();
 
         let TransactionParts {
             actions: unchecked_actions,
             group,
             params,
             verification_key,
+            cosigner_keys,
+            multisig_address,
+            fee_payer,
         } = tx.into_parts();
-        let tx_signer = *verification_key.address_bytes();
+
+        let key_set = state
+            .get_multisig_key_set(&signer_address)
+            .await
+            .map_err(|source| {
+                CheckedTransactionInitialCheckError::internal(
+                    "failed to read multisig key set from storage",
+                    source,
+                )
+            })?;
+        authorize_signers(
+            key_set.as_ref(),
+            &verification_key,
+            &cosigner_keys,
+            multisig_address.as_ref(),
+        )
+        .map_err(CheckedTransactionInitialCheckError::Unauthorized)?;
+
+        if let Some(fee_payer) = &fee_payer {
+            if use_pre_aspen_validator_updates(state)
+                .await
+                .map_err(|source| {
+                    CheckedTransactionInitialCheckError::internal(
+                        "failed to get upgrade status",
+                        source,
+                    )
+                })?
+            {
+                return Err(CheckedTransactionInitialCheckError::FeePayerBeforeAspen);
+            }
+            let fee_payer_key_set =
+                state
+                    .get_multisig_key_set(fee_payer)
+                    .await
+                    .map_err(|source| {
+                        CheckedTransactionInitialCheckError::internal(
+                            "failed to read multisig key set of fee payer from storage",
+                            source,
+                        )
+                    })?;
+            authorize_fee_payer(fee_payer, fee_payer_key_set.as_ref())
+                .map_err(CheckedTransactionInitialCheckError::Unauthorized)?;
+        }
+
+        let tx_signer = signer_address;
         let checked_actions =
             match convert_actions(unchecked_actions, tx_signer, tx_id, state).await {
                 Ok(checked_actions) => checked_actions,
@@ -156,12 +214,20 @@ impl CheckedTransaction {
             });
         }
 
+        if let Some(fee_payer) = &fee_payer {
+            check_fee_payer_balance(fee_payer, &checked_actions, state).await?;
+        }
+
         Ok(Self {
             tx_id,
             actions: checked_actions,
             group,
             params,
             verification_key,
+            cosigner_keys,
+            multisig_address,
+            signer_address,
+            fee_payer,
             tx_bytes,
         })
     }
@@ -191,6 +257,21 @@ impl CheckedTransaction {
         &self.verification_key
     }
 
+    /// Returns the address bytes of the account paying the fees of this transaction.
+    ///
+    /// This is the fee payer named in the transaction if set, or the transaction's account
+    /// otherwise.
+    pub(crate) fn fee_payer_address_bytes(&self) -> &[u8; ADDRESS_LENGTH] {
+        self.fee_payer
+            .as_ref()
+            .map_or(&self.signer_address, Address::as_bytes)
+    }
+
+    /// Returns whether the fees of this transaction are paid by a separate fee payer.
+    pub(crate) fn is_sponsored(&self) -> bool {
+        self.fee_payer.is_some()
+    }
+
     /// Returns the bytes of the encoded `Transaction` from which this `CheckedTransaction` is
     /// constructed.
     pub(crate) fn encoded_bytes(&self) -> &Bytes {
@@ -212,13 +293,20 @@ impl CheckedTransaction {
         })
     }
 
-    /// Returns the total costs involved in executing this transaction, i.e. all of the fees and
-    /// outbound transfers of all actions in this transaction.
+    /// Returns the total costs to the transaction's account involved in executing this
+    /// transaction, i.e. all of the fees and outbound transfers of all actions in this
+    /// transaction.
+    ///
+    /// Fees are excluded if they are paid by a separate fee payer.
     pub(crate) async fn total_costs<S: StateRead>(
         &self,
         state: &S,
     ) -> Result<HashMap<IbcPrefixed, u128>, CheckedActionFeeError> {
-        let mut cost_by_asset = total_fees(self.actions.iter().map(ActionRef::from), state).await?;
+        let mut cost_by_asset = if self.fee_payer.is_some() {
+            HashMap::new()
+        } else {
+            total_fees(self.actions.iter().map(ActionRef::from), state).await?
+        };
 
         for action in &self.actions {
             if let Some((asset, amount)) = action.asset_and_amount_to_transfer() {
@@ -232,6 +320,20 @@ impl CheckedTransaction {
         Ok(cost_by_asset)
     }
 
+    /// Returns the fees charged to the separate fee payer of this transaction, or `None` if the
+    /// transaction's account pays its own fees.
+    pub(crate) async fn fee_payer_costs<S: StateRead>(
+        &self,
+        state: &S,
+    ) -> Result<Option<HashMap<IbcPrefixed, u128>>, CheckedActionFeeError> {
+        if self.fee_payer.is_none() {
+            return Ok(None);
+        }
+        total_fees(self.actions.iter().map(ActionRef::from), state)
+            .await
+            .map(Some)
+    }
+
     /// Re-runs checks that passed during construction of the `CheckedTransaction`, but that might
     /// now fail due to changes in the global state.
     ///
@@ -251,7 +353,7 @@ impl CheckedTransaction {
     ///
     /// Returns an error if the current nonce for the transaction's signer in `state` is different
     /// to this transaction's nonce. Also returns an error if any action fails execution, or the
-    /// signer cannot pay the required execution costs.
+    /// signer cannot pay the required execution costs, or the fee payer cannot pay the fees.
     pub(super) async fn execute<S: StateWrite>(
         &self,
         mut state: S,
@@ -275,6 +377,51 @@ impl CheckedTransaction {
             });
         };
 
+        // The key set of a multisig account might have changed since construction, so the
+        // signers are re-authorized against the current one.
+        let key_set = state
+            .get_multisig_key_set(&self.signer_address)
+            .await
+            .map_err(|source| {
+                CheckedTransactionExecutionError::internal(
+                    "failed to read multisig key set from storage",
+                    source,
+                )
+            })?;
+        authorize_signers(
+            key_set.as_ref(),
+            &self.verification_key,
+            &self.cosigner_keys,
+            self.multisig_address.as_ref(),
+        )
+        .map_err(CheckedTransactionExecutionError::Unauthorized)?;
+
+        if let Some(fee_payer) = &self.fee_payer {
+            if use_pre_aspen_validator_updates(&state)
+                .await
+                .map_err(|source| {
+                    CheckedTransactionExecutionError::internal(
+                        "failed to get upgrade status",
+                        source,
+                    )
+                })?
+            {
+                return Err(CheckedTransactionExecutionError::FeePayerBeforeAspen);
+            }
+            let fee_payer_key_set =
+                state
+                    .get_multisig_key_set(fee_payer)
+                    .await
+                    .map_err(|source| {
+                        CheckedTransactionExecutionError::internal(
+                            "failed to read multisig key set of fee payer from storage",
+                            source,
+                        )
+                    })?;
+            authorize_fee_payer(fee_payer, fee_payer_key_set.as_ref())
+                .map_err(CheckedTransactionExecutionError::Unauthorized)?;
+        }
+
         if state
             .get_bridge_account_rollup_id(self)
             .await
@@ -305,12 +452,12 @@ impl CheckedTransaction {
                 CheckedTransactionExecutionError::internal("failed updating nonce", source)
             })?;
 
-        let tx_signer = *self.verification_key.address_bytes();
+        let fee_payer = *self.fee_payer_address_bytes();
         for (index, action) in self.actions.iter().enumerate() {
             let index = u64::try_from(index)
                 .map_err(|_| CheckedTransactionExecutionError::ActionIndexOverflowed)?;
             action
-                .pay_fees_and_execute(&mut state, &tx_signer, &self.tx_id, index)
+                .pay_fees_and_execute(&mut state, &fee_payer, &self.tx_id, index)
                 .await?;
         }
         Ok(())
@@ -319,10 +466,96 @@ impl CheckedTransaction {
 
 impl AddressBytes for CheckedTransaction {
     fn address_bytes(&self) -> &[u8; ADDRESS_LENGTH] {
-        self.verification_key.address_bytes()
+        &self.signer_address
     }
 }
 
+/// Ensures the signers of a transaction are authorized to act on behalf of its account.
+///
+/// If the account is a multisig account, the primary signer and the cosigners must include at
+/// least `threshold` distinct keys of its key set. Otherwise, the transaction must neither carry
+/// cosignatures nor name a multisig address in its body, in which case it is authorized by its
+/// primary signature alone.
+fn authorize_signers(
+    key_set: Option<&MultisigKeySet>,
+    verification_key: &VerificationKey,
+    cosigner_keys: &[VerificationKey],
+    multisig_address: Option<&Address>,
+) -> Result<(), UnauthorizedSignersError> {
+    let Some(key_set) = key_set else {
+        if let Some(multisig_address) = multisig_address {
+            return Err(UnauthorizedSignersError::NotMultisigAccount {
+                address: multisig_address.to_string(),
+            });
+        }
+        if !cosigner_keys.is_empty() {
+            return Err(UnauthorizedSignersError::UnexpectedCosignatures);
+        }
+        return Ok(());
+    };
+    let signers = key_set.count_signers(std::iter::once(verification_key).chain(cosigner_keys));
+    if signers < key_set.threshold() {
+        return Err(UnauthorizedSignersError::InsufficientSignatures {
+            signers,
+            threshold: key_set.threshold(),
+        });
+    }
+    Ok(())
+}
+
+/// Ensures the fee payer of a transaction is not a multisig account.
+///
+/// The fee payer authorizes the payment with a single signature, which must not bypass the
+/// threshold of a multisig account.
+fn authorize_fee_payer(
+    fee_payer: &Address,
+    key_set: Option<&MultisigKeySet>,
+) -> Result<(), UnauthorizedSignersError> {
+    if key_set.is_some() {
+        return Err(UnauthorizedSignersError::MultisigFeePayer {
+            address: fee_payer.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Ensures the fee payer of a transaction holds enough of each fee asset to pay the fees of all
+/// the transaction's actions.
+async fn check_fee_payer_balance<S: StateRead>(
+    fee_payer: &Address,
+    checked_actions: &[CheckedAction],
+    state: &S,
+) -> Result<(), CheckedTransactionInitialCheckError> {
+    let fees = total_fees(checked_actions.iter().map(ActionRef::from), state)
+        .await
+        .map_err(|source| CheckedTransactionInitialCheckError::FeePayer {
+            address: fee_payer.to_string(),
+            source,
+        })?;
+    for (asset, amount) in fees {
+        let balance = state
+            .get_account_balance(fee_payer, &asset)
+            .await
+            .map_err(|source| {
+                CheckedTransactionInitialCheckError::internal(
+                    "failed to read balance of fee payer from storage",
+                    source,
+                )
+            })?;
+        if balance < amount {
+            return Err(CheckedTransactionInitialCheckError::FeePayer {
+                address: fee_payer.to_string(),
+                source: CheckedActionFeeError::InsufficientBalanceToPayFee {
+                    account: fee_payer.bytes(),
+                    asset: asset.into(),
+                    amount,
+                },
+            });
+        }
+    }
+    Ok(())
+}
+
 async fn convert_actions<S: StateRead>(
     unchecked_actions: Vec<Action>,
     tx_signer: [u8; ADDRESS_LENGTH],
@@ -407,6 +640,38 @@ async fn convert_actions<S: StateRead>(
                     Action::MarketsChange(action) => {
                         CheckedAction::new_markets_change(action, tx_signer, state).await
                     }
+                    Action::RegisterMultisigAccount(action) => {
+                        CheckedAction::new_register_multisig_account(action, tx_signer, state).await
+                    }
+                    Action::ScheduledTransfer(action) => {
+                        CheckedAction::new_scheduled_transfer(action, tx_signer, state).await
+                    }
+                    Action::Ics20RateLimitChange(action) => {
+                        CheckedAction::new_ics20_rate_limit_change(action, tx_signer, state).await
+                    }
+                    Action::VoteExtensionParticipationChange(action) => {
+                        CheckedAction::new_vote_extension_participation_change(
+                            action, tx_signer, state,
+                        )
+                        .await
+                    }
+                    Action::Bond(action) => CheckedAction::new_bond(action, tx_signer, state).await,
+                    Action::Unbond(action) => {
+                        CheckedAction::new_unbond(action, tx_signer, state).await
+                    }
+                    Action::ProposalSubmission(action) => {
+                        CheckedAction::new_proposal_submission(action, tx_signer, state).await
+                    }
+                    Action::ProposalVote(action) => {
+                        CheckedAction::new_proposal_vote(action, tx_signer, state).await
+                    }
+                    Action::StakingParametersChange(action) => {
+                        CheckedAction::new_staking_parameters_change(action, tx_signer, state).await
+                    }
+                    Action::GovernanceParametersChange(action) => {
+                        CheckedAction::new_governance_parameters_change(action, tx_signer, state)
+                            .await
+                    }
                 }
             });
 
@@ -1,4 +1,5 @@
 use astria_core::{
+    crypto::SigningKey,
     generated::protocol::transaction::v1::{
         Transaction as RawTransaction,
         TransactionBody as RawTransactionBody,
@@ -18,15 +19,19 @@ use prost::Name as _;
 
 use super::*;
 use crate::{
+    checked_actions::CheckedActionExecutionError,
     fees::StateReadExt as _,
     test_utils::{
+        astria_address,
         denom_1,
         nria,
         Fixture,
         ALICE,
         ALICE_ADDRESS,
         ALICE_ADDRESS_BYTES,
+        BOB,
         BOB_ADDRESS,
+        CAROL,
         SUDO,
         TEN_QUINTILLION,
     },
@@ -111,6 +116,8 @@ async fn should_fail_construction_if_no_actions() {
             chain_id: "test".to_string(),
         }),
         actions: vec![],
+        fee_payer: None,
+        multisig_address: None,
     };
     let body_bytes = raw_tx_body.encode_to_vec();
     let signature = ALICE.sign(&body_bytes);
@@ -122,6 +129,8 @@ async fn should_fail_construction_if_no_actions() {
             type_url: RawTransactionBody::type_url(),
             value: Bytes::from(body_bytes),
         }),
+        cosignatures: vec![],
+        fee_payer_signature: None,
     };
 
     let encoded_tx = Bytes::from(raw_tx.encode_to_vec());
@@ -389,3 +398,348 @@ async fn execution_should_record_fee_event() {
     assert_eq!(event.attributes[2].key_bytes(), b"feeAmount");
     assert_eq!(event.attributes[3].key_bytes(), b"positionInTransaction");
 }
+
+fn multisig_transfer_body(
+    multisig_address: Option<Address>,
+) -> astria_core::protocol::transaction::v1::TransactionBody {
+    TransactionBodyBuilder::new()
+        .chain_id("test".to_string())
+        .multisig_address(multisig_address)
+        .actions(vec![Transfer {
+            to: *BOB_ADDRESS,
+            amount: 100,
+            asset: nria().into(),
+            fee_asset: nria().into(),
+        }
+        .into()])
+        .try_build()
+        .unwrap()
+}
+
+fn register_multisig_account(fixture: &mut Fixture, address: &Address) {
+    let key_set = MultisigKeySet::new(
+        vec![
+            ALICE.verification_key(),
+            BOB.verification_key(),
+            CAROL.verification_key(),
+        ],
+        2,
+    );
+    fixture
+        .state_mut()
+        .put_multisig_key_set(address, &key_set)
+        .unwrap();
+    fixture
+        .state_mut()
+        .put_account_balance(address, &nria(), TEN_QUINTILLION)
+        .unwrap();
+}
+
+#[tokio::test]
+async fn should_fail_construction_if_multisig_address_is_not_a_multisig_account() {
+    let fixture = Fixture::default_initialized().await;
+
+    let body = multisig_transfer_body(Some(astria_address(&[9; 20])));
+    let signatures = vec![body.sign_detached(&ALICE), body.sign_detached(&BOB)];
+    let tx = body.into_multisig_transaction(signatures).unwrap();
+
+    let encoded_tx = Bytes::from(tx.into_raw().encode_to_vec());
+    let error = CheckedTransaction::new(encoded_tx, fixture.state())
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            error,
+            CheckedTransactionInitialCheckError::Unauthorized(
+                UnauthorizedSignersError::NotMultisigAccount { .. }
+            )
+        ),
+        "{error:?}",
+    );
+}
+
+#[tokio::test]
+async fn should_fail_construction_if_multisig_threshold_not_met() {
+    let mut fixture = Fixture::default_initialized().await;
+    let multisig_address = astria_address(&[9; 20]);
+    register_multisig_account(&mut fixture, &multisig_address);
+
+    // Signing twice with the same key does not count towards the threshold.
+    let body = multisig_transfer_body(Some(multisig_address));
+    let signatures = vec![body.sign_detached(&ALICE), body.sign_detached(&ALICE)];
+    let tx = body.into_multisig_transaction(signatures).unwrap();
+
+    let encoded_tx = Bytes::from(tx.into_raw().encode_to_vec());
+    let error = CheckedTransaction::new(encoded_tx, fixture.state())
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            error,
+            CheckedTransactionInitialCheckError::Unauthorized(
+                UnauthorizedSignersError::InsufficientSignatures {
+                    signers: 1,
+                    threshold: 2
+                }
+            )
+        ),
+        "{error:?}",
+    );
+}
+
+#[tokio::test]
+async fn should_fail_construction_if_multisig_account_signs_alone() {
+    let mut fixture = Fixture::default_initialized().await;
+    register_multisig_account(&mut fixture, &ALICE_ADDRESS);
+
+    let tx = multisig_transfer_body(None).sign(&ALICE);
+    let encoded_tx = Bytes::from(tx.into_raw().encode_to_vec());
+    let error = CheckedTransaction::new(encoded_tx, fixture.state())
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            error,
+            CheckedTransactionInitialCheckError::Unauthorized(
+                UnauthorizedSignersError::InsufficientSignatures { .. }
+            )
+        ),
+        "{error:?}",
+    );
+}
+
+#[tokio::test]
+async fn should_execute_multisig_transfer() {
+    let mut fixture = Fixture::default_initialized().await;
+    let multisig_address = astria_address(&[9; 20]);
+    register_multisig_account(&mut fixture, &multisig_address);
+
+    let body = multisig_transfer_body(Some(multisig_address));
+    let signatures = vec![body.sign_detached(&BOB), body.sign_detached(&CAROL)];
+    let tx = body.into_multisig_transaction(signatures).unwrap();
+
+    let encoded_tx = Bytes::from(tx.into_raw().encode_to_vec());
+    let checked_tx = CheckedTransaction::new(encoded_tx, fixture.state())
+        .await
+        .unwrap();
+    assert_eq!(multisig_address.as_bytes(), checked_tx.address_bytes());
+    checked_tx.execute(fixture.state_mut()).await.unwrap();
+
+    assert_eq!(
+        fixture
+            .state()
+            .get_account_nonce(&multisig_address)
+            .await
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        fixture.get_nria_balance(&*BOB_ADDRESS).await,
+        TEN_QUINTILLION + 100
+    );
+}
+
+#[tokio::test]
+async fn should_fail_execution_if_multisig_key_set_changed() {
+    let mut fixture = Fixture::default_initialized().await;
+    let multisig_address = astria_address(&[9; 20]);
+    register_multisig_account(&mut fixture, &multisig_address);
+
+    let body = multisig_transfer_body(Some(multisig_address));
+    let signatures = vec![body.sign_detached(&ALICE), body.sign_detached(&BOB)];
+    let tx = body.into_multisig_transaction(signatures).unwrap();
+    let encoded_tx = Bytes::from(tx.into_raw().encode_to_vec());
+    let checked_tx = CheckedTransaction::new(encoded_tx, fixture.state())
+        .await
+        .unwrap();
+
+    // Raise the threshold after construction.
+    let key_set = MultisigKeySet::new(
+        vec![
+            ALICE.verification_key(),
+            BOB.verification_key(),
+            CAROL.verification_key(),
+        ],
+        3,
+    );
+    fixture
+        .state_mut()
+        .put_multisig_key_set(&multisig_address, &key_set)
+        .unwrap();
+
+    let error = checked_tx.execute(fixture.state_mut()).await.unwrap_err();
+    assert!(
+        matches!(
+            error,
+            CheckedTransactionExecutionError::Unauthorized(
+                UnauthorizedSignersError::InsufficientSignatures { .. }
+            )
+        ),
+        "{error:?}",
+    );
+}
+
+#[tokio::test]
+async fn should_execute_sponsored_transaction() {
+    let mut fixture = Fixture::default_initialized().await;
+    // The signer holds no funds at all.
+    let signer = SigningKey::from([7; 32]);
+
+    let tx = fixture
+        .checked_tx_builder()
+        .with_rollup_data_submission(vec![1, 2, 3])
+        .with_signer(signer.clone())
+        .with_fee_payer(BOB.clone())
+        .build()
+        .await;
+    assert_eq!(*BOB_ADDRESS.as_bytes(), *tx.fee_payer_address_bytes());
+    assert!(tx.total_costs(fixture.state()).await.unwrap().is_empty());
+    tx.execute(fixture.state_mut()).await.unwrap();
+
+    let fees = fixture
+        .state()
+        .get_fees::<RollupDataSubmission>()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        fixture.get_nria_balance(&*BOB_ADDRESS).await,
+        TEN_QUINTILLION - (fees.base() + fees.multiplier() * 3)
+    );
+    assert_eq!(
+        fixture
+            .state()
+            .get_account_nonce(&signer.address_bytes())
+            .await
+            .unwrap(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn should_fail_construction_if_fee_payer_has_insufficient_balance() {
+    let fixture = Fixture::default_initialized().await;
+    let fee_payer = SigningKey::from([7; 32]);
+
+    let body = TransactionBodyBuilder::new()
+        .chain_id("test".to_string())
+        .actions(multisig_transfer_body().into_actions())
+        .fee_payer(astria_address(&fee_payer.address_bytes()))
+        .try_build()
+        .unwrap();
+    let fee_payer_signature = body.sign_detached(&fee_payer);
+    let tx = body
+        .sign(&ALICE)
+        .with_fee_payer_signature(fee_payer_signature)
+        .unwrap();
+
+    let encoded_tx = Bytes::from(tx.into_raw().encode_to_vec());
+    let error = CheckedTransaction::new(encoded_tx, fixture.state())
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            error,
+            CheckedTransactionInitialCheckError::FeePayer {
+                source: CheckedActionFeeError::InsufficientBalanceToPayFee { .. },
+                ..
+            }
+        ),
+        "{error:?}",
+    );
+}
+
+#[tokio::test]
+async fn should_fail_construction_if_fee_payer_named_before_aspen() {
+    let mut fixture = Fixture::uninitialized(None).await;
+    fixture.chain_initializer().init().await;
+
+    let body = TransactionBodyBuilder::new()
+        .chain_id("test".to_string())
+        .actions(multisig_transfer_body().into_actions())
+        .fee_payer(*BOB_ADDRESS)
+        .try_build()
+        .unwrap();
+    let fee_payer_signature = body.sign_detached(&BOB);
+    let tx = body
+        .sign(&ALICE)
+        .with_fee_payer_signature(fee_payer_signature)
+        .unwrap();
+
+    let encoded_tx = Bytes::from(tx.into_raw().encode_to_vec());
+    let error = CheckedTransaction::new(encoded_tx, fixture.state())
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            error,
+            CheckedTransactionInitialCheckError::FeePayerBeforeAspen
+        ),
+        "{error:?}",
+    );
+}
+
+#[tokio::test]
+async fn should_fail_construction_if_fee_payer_is_multisig_account() {
+    let mut fixture = Fixture::default_initialized().await;
+    register_multisig_account(&mut fixture, &BOB_ADDRESS);
+
+    let body = TransactionBodyBuilder::new()
+        .chain_id("test".to_string())
+        .actions(multisig_transfer_body().into_actions())
+        .fee_payer(*BOB_ADDRESS)
+        .try_build()
+        .unwrap();
+    let fee_payer_signature = body.sign_detached(&BOB);
+    let tx = body
+        .sign(&ALICE)
+        .with_fee_payer_signature(fee_payer_signature)
+        .unwrap();
+
+    let encoded_tx = Bytes::from(tx.into_raw().encode_to_vec());
+    let error = CheckedTransaction::new(encoded_tx, fixture.state())
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            error,
+            CheckedTransactionInitialCheckError::Unauthorized(
+                UnauthorizedSignersError::MultisigFeePayer { .. }
+            )
+        ),
+        "{error:?}",
+    );
+}
+
+#[tokio::test]
+async fn should_fail_execution_if_fee_payer_cannot_pay_fees() {
+    let mut fixture = Fixture::default_initialized().await;
+
+    let tx = fixture
+        .checked_tx_builder()
+        .with_rollup_data_submission(vec![1, 2, 3])
+        .with_signer(ALICE.clone())
+        .with_fee_payer(BOB.clone())
+        .build()
+        .await;
+    fixture
+        .state_mut()
+        .put_account_balance(&*BOB_ADDRESS, &nria(), 0)
+        .unwrap();
+
+    let error = tx.execute(fixture.state_mut()).await.unwrap_err();
+    let CheckedTransactionExecutionError::CheckedAction(CheckedActionExecutionError::Fee(
+        CheckedActionFeeError::InsufficientBalanceToPayFee {
+            account, ..
+        },
+    )) = error
+    else {
+        panic!("expected insufficient balance of fee payer, got {error:?}");
+    };
+    assert_eq!(*BOB_ADDRESS.as_bytes(), account);
+    // The signer's balance is untouched.
+    assert_eq!(
+        fixture.get_nria_balance(&*ALICE_ADDRESS).await,
+        TEN_QUINTILLION
+    );
+}
@@ -46,14 +46,40 @@ pub struct Config {
     pub price_feed_client_timeout_milliseconds: u64,
     /// The maximum number of transactions that can be parked in the mempool.
     pub mempool_parked_max_tx_count: usize,
+    /// How pending transactions are ordered when building a block proposal.
+    pub mempool_transaction_ordering: MempoolTransactionOrdering,
+    /// The maximum number of transactions per account included in a single block proposal. Set to
+    /// 0 for no limit.
+    pub mempool_max_block_txs_per_account: usize,
+    /// Allows replacing a transaction in the mempool with one from the same signer and with the
+    /// same nonce which pays a fee rate at least 10% higher.
+    pub mempool_replace_by_fee: bool,
     /// Disables streaming optimistic blocks over grpc.
     pub no_optimistic_blocks: bool,
+    /// The number of blocks between state sync snapshots. Set to 0 to disable taking snapshots.
+    pub snapshot_interval: u64,
+    /// The number of most recent state sync snapshots to keep on disk.
+    pub snapshot_keep_recent: usize,
+    /// The number of most recent heights at which the chain state is retained for ABCI queries.
+    pub state_retention_blocks: u64,
 }
 
 impl config::Config for Config {
     const PREFIX: &'static str = "ASTRIA_SEQUENCER_";
 }
 
+/// The order in which pending transactions with equal nonce distance to their account's current
+/// nonce are included in a block proposal.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum MempoolTransactionOrdering {
+    /// Transactions seen earlier are included first.
+    #[default]
+    TimeFirstSeen,
+    /// Transactions paying higher fees in the native asset per byte are included first, falling
+    /// back to the time they were first seen. Fees paid in other assets are not counted.
+    FeePerByte,
+}
+
 #[derive(Debug)]
 pub enum AbciListenUrl {
     Tcp(SocketAddr),
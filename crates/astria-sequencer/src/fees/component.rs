@@ -56,6 +56,16 @@ impl Component for FeesComponent {
             recover_ibc_client,
             currency_pairs_change,
             markets_change,
+            register_multisig_account,
+            scheduled_transfer,
+            ics20_rate_limit_change,
+            vote_extension_participation_change,
+            bond,
+            unbond,
+            proposal_submission,
+            proposal_vote,
+            staking_parameters_change,
+            governance_parameters_change,
         } = app_state.fees().clone();
 
         if let Some(transfer_fees) = transfer {
@@ -164,6 +174,67 @@ impl Component for FeesComponent {
                 .wrap_err("failed to store markets change fee components")?;
         }
 
+        if let Some(register_multisig_account_fees) = register_multisig_account {
+            state
+                .put_fees(register_multisig_account_fees)
+                .wrap_err("failed to store register multisig account fee components")?;
+        }
+
+        if let Some(scheduled_transfer_fees) = scheduled_transfer {
+            state
+                .put_fees(scheduled_transfer_fees)
+                .wrap_err("failed to store scheduled transfer fee components")?;
+        }
+
+        if let Some(ics20_rate_limit_change_fees) = ics20_rate_limit_change {
+            state
+                .put_fees(ics20_rate_limit_change_fees)
+                .wrap_err("failed to store ics20 rate limit change fee components")?;
+        }
+
+        if let Some(vote_extension_participation_change_fees) = vote_extension_participation_change
+        {
+            state
+                .put_fees(vote_extension_participation_change_fees)
+                .wrap_err("failed to store vote extension participation change fee components")?;
+        }
+
+        if let Some(bond_fees) = bond {
+            state
+                .put_fees(bond_fees)
+                .wrap_err("failed to store bond fee components")?;
+        }
+
+        if let Some(unbond_fees) = unbond {
+            state
+                .put_fees(unbond_fees)
+                .wrap_err("failed to store unbond fee components")?;
+        }
+
+        if let Some(proposal_submission_fees) = proposal_submission {
+            state
+                .put_fees(proposal_submission_fees)
+                .wrap_err("failed to store proposal submission fee components")?;
+        }
+
+        if let Some(proposal_vote_fees) = proposal_vote {
+            state
+                .put_fees(proposal_vote_fees)
+                .wrap_err("failed to store proposal vote fee components")?;
+        }
+
+        if let Some(staking_parameters_change_fees) = staking_parameters_change {
+            state
+                .put_fees(staking_parameters_change_fees)
+                .wrap_err("failed to store staking parameters change fee components")?;
+        }
+
+        if let Some(governance_parameters_change_fees) = governance_parameters_change {
+            state
+                .put_fees(governance_parameters_change_fees)
+                .wrap_err("failed to store governance parameters change fee components")?;
+        }
+
         Ok(())
     }
 
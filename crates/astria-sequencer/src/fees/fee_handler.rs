@@ -1,6 +1,7 @@
 use astria_core::{
     primitive::v1::asset::Denom,
     protocol::transaction::v1::action::{
+        Bond,
         BridgeLock,
         BridgeSudoChange,
         BridgeTransfer,
@@ -8,16 +9,25 @@ use astria_core::{
         CurrencyPairsChange,
         FeeAssetChange,
         FeeChange,
+        GovernanceParametersChange,
         IbcRelayerChange,
         IbcSudoChange,
+        Ics20RateLimitChange,
         Ics20Withdrawal,
         InitBridgeAccount,
         MarketsChange,
+        ProposalSubmission,
+        ProposalVote,
         RecoverIbcClient,
+        RegisterMultisigAccount,
         RollupDataSubmission,
+        ScheduledTransfer,
+        StakingParametersChange,
         SudoAddressChange,
         Transfer,
+        Unbond,
         ValidatorUpdate,
+        VoteExtensionParticipationChange,
     },
     Protobuf,
 };
@@ -450,6 +460,226 @@ impl FeeHandler for MarketsChange {
     }
 }
 
+impl FeeHandler for RegisterMultisigAccount {
+    fn name(&self) -> &'static str {
+        <Self as Protobuf>::Raw::NAME
+    }
+
+    fn full_name() -> String {
+        <Self as Protobuf>::full_name()
+    }
+
+    fn snake_case_name() -> &'static str {
+        "register_multisig_account"
+    }
+
+    fn variable_component(&self) -> u128 {
+        0
+    }
+
+    fn fee_asset(&self) -> Option<&Denom> {
+        Some(&self.fee_asset)
+    }
+}
+
+impl FeeHandler for ScheduledTransfer {
+    fn name(&self) -> &'static str {
+        <Self as Protobuf>::Raw::NAME
+    }
+
+    fn full_name() -> String {
+        <Self as Protobuf>::full_name()
+    }
+
+    fn snake_case_name() -> &'static str {
+        "scheduled_transfer"
+    }
+
+    fn variable_component(&self) -> u128 {
+        0
+    }
+
+    fn fee_asset(&self) -> Option<&Denom> {
+        Some(&self.fee_asset)
+    }
+}
+
+impl FeeHandler for Bond {
+    fn name(&self) -> &'static str {
+        <Self as Protobuf>::Raw::NAME
+    }
+
+    fn full_name() -> String {
+        <Self as Protobuf>::full_name()
+    }
+
+    fn snake_case_name() -> &'static str {
+        "bond"
+    }
+
+    fn variable_component(&self) -> u128 {
+        0
+    }
+
+    fn fee_asset(&self) -> Option<&Denom> {
+        Some(&self.fee_asset)
+    }
+}
+
+impl FeeHandler for ProposalSubmission {
+    fn name(&self) -> &'static str {
+        <Self as Protobuf>::Raw::NAME
+    }
+
+    fn full_name() -> String {
+        <Self as Protobuf>::full_name()
+    }
+
+    fn snake_case_name() -> &'static str {
+        "proposal_submission"
+    }
+
+    fn variable_component(&self) -> u128 {
+        0
+    }
+
+    fn fee_asset(&self) -> Option<&Denom> {
+        Some(&self.fee_asset)
+    }
+}
+
+impl FeeHandler for ProposalVote {
+    fn name(&self) -> &'static str {
+        <Self as Protobuf>::Raw::NAME
+    }
+
+    fn full_name() -> String {
+        <Self as Protobuf>::full_name()
+    }
+
+    fn snake_case_name() -> &'static str {
+        "proposal_vote"
+    }
+
+    fn variable_component(&self) -> u128 {
+        0
+    }
+
+    fn fee_asset(&self) -> Option<&Denom> {
+        Some(&self.fee_asset)
+    }
+}
+
+impl FeeHandler for Unbond {
+    fn name(&self) -> &'static str {
+        <Self as Protobuf>::Raw::NAME
+    }
+
+    fn full_name() -> String {
+        <Self as Protobuf>::full_name()
+    }
+
+    fn snake_case_name() -> &'static str {
+        "unbond"
+    }
+
+    fn variable_component(&self) -> u128 {
+        0
+    }
+
+    fn fee_asset(&self) -> Option<&Denom> {
+        Some(&self.fee_asset)
+    }
+}
+
+impl FeeHandler for Ics20RateLimitChange {
+    fn name(&self) -> &'static str {
+        <Self as Protobuf>::Raw::NAME
+    }
+
+    fn full_name() -> String {
+        <Self as Protobuf>::full_name()
+    }
+
+    fn snake_case_name() -> &'static str {
+        "ics20_rate_limit_change"
+    }
+
+    fn variable_component(&self) -> u128 {
+        0
+    }
+
+    fn fee_asset(&self) -> Option<&Denom> {
+        None
+    }
+}
+
+impl FeeHandler for VoteExtensionParticipationChange {
+    fn name(&self) -> &'static str {
+        <Self as Protobuf>::Raw::NAME
+    }
+
+    fn full_name() -> String {
+        <Self as Protobuf>::full_name()
+    }
+
+    fn snake_case_name() -> &'static str {
+        "vote_extension_participation_change"
+    }
+
+    fn variable_component(&self) -> u128 {
+        0
+    }
+
+    fn fee_asset(&self) -> Option<&Denom> {
+        None
+    }
+}
+
+impl FeeHandler for StakingParametersChange {
+    fn name(&self) -> &'static str {
+        <Self as Protobuf>::Raw::NAME
+    }
+
+    fn full_name() -> String {
+        <Self as Protobuf>::full_name()
+    }
+
+    fn snake_case_name() -> &'static str {
+        "staking_parameters_change"
+    }
+
+    fn variable_component(&self) -> u128 {
+        0
+    }
+
+    fn fee_asset(&self) -> Option<&Denom> {
+        None
+    }
+}
+
+impl FeeHandler for GovernanceParametersChange {
+    fn name(&self) -> &'static str {
+        <Self as Protobuf>::Raw::NAME
+    }
+
+    fn full_name() -> String {
+        <Self as Protobuf>::full_name()
+    }
+
+    fn snake_case_name() -> &'static str {
+        "governance_parameters_change"
+    }
+
+    fn variable_component(&self) -> u128 {
+        0
+    }
+
+    fn fee_asset(&self) -> Option<&Denom> {
+        None
+    }
+}
+
 /// Returns a modified byte length of the deposit event. Length is calculated with reasonable values
 /// for all fields except `asset` and `destination_chain_address`, ergo it may not be representative
 /// of on-wire length.
@@ -12,6 +12,7 @@ use astria_core::{
         fees::v1::FeeComponents,
         transaction::v1::{
             action::{
+                Bond,
                 BridgeLock,
                 BridgeSudoChange,
                 BridgeTransfer,
@@ -19,16 +20,25 @@ use astria_core::{
                 CurrencyPairsChange,
                 FeeAssetChange,
                 FeeChange,
+                GovernanceParametersChange,
                 IbcRelayerChange,
                 IbcSudoChange,
+                Ics20RateLimitChange,
                 Ics20Withdrawal,
                 InitBridgeAccount,
                 MarketsChange,
+                ProposalSubmission,
+                ProposalVote,
                 RecoverIbcClient,
+                RegisterMultisigAccount,
                 RollupDataSubmission,
+                ScheduledTransfer,
+                StakingParametersChange,
                 SudoAddressChange,
                 Transfer,
+                Unbond,
                 ValidatorUpdate,
+                VoteExtensionParticipationChange,
             },
             TransactionBody,
         },
@@ -40,10 +50,7 @@ use astria_eyre::eyre::{
     OptionExt as _,
     WrapErr as _,
 };
-use cnidarium::{
-    StateRead,
-    Storage,
-};
+use cnidarium::StateRead;
 use futures::{
     FutureExt as _,
     StreamExt as _,
@@ -75,6 +82,7 @@ use crate::{
         ActionRef,
     },
     fees::StateReadExt as _,
+    storage::QueryState,
 };
 
 #[instrument(skip_all, fields(%asset))]
@@ -110,20 +118,17 @@ async fn get_allowed_fee_assets<S: StateRead>(state: &S) -> Vec<Denom> {
 
 #[instrument(skip_all)]
 pub(crate) async fn allowed_fee_assets_request(
-    storage: Storage,
+    snapshot: QueryState,
     request: request::Query,
     _params: Vec<(String, String)>,
 ) -> response::Query {
-    // get last snapshot
-    let snapshot = storage.latest_snapshot();
-
     let height = async {
         snapshot
             .get_block_height()
             .await
             .wrap_err("failed getting block height")
     };
-    let fee_assets = get_allowed_fee_assets(&snapshot).map(Ok);
+    let fee_assets = get_allowed_fee_assets(&*snapshot).map(Ok);
     let (height, fee_assets) = match try_join!(height, fee_assets) {
         Ok(vals) => vals,
         Err(err) => {
@@ -155,19 +160,17 @@ pub(crate) async fn allowed_fee_assets_request(
 }
 
 pub(crate) async fn components(
-    storage: Storage,
+    snapshot: QueryState,
     request: request::Query,
     _params: Vec<(String, String)>,
 ) -> response::Query {
-    let snapshot = storage.latest_snapshot();
-
     let height = async {
         snapshot
             .get_block_height()
             .await
             .wrap_err("failed getting block height")
     };
-    let fee_components = get_all_fee_components(&snapshot).map(Ok);
+    let fee_components = get_all_fee_components(&*snapshot).map(Ok);
     let (height, fee_components) = match try_join!(height, fee_components) {
         Ok(vals) => vals,
         Err(err) => {
@@ -193,7 +196,7 @@ pub(crate) async fn components(
 }
 
 pub(crate) async fn transaction_fee_request(
-    storage: Storage,
+    snapshot: QueryState,
     request: request::Query,
     _params: Vec<(String, String)>,
 ) -> response::Query {
@@ -204,8 +207,6 @@ pub(crate) async fn transaction_fee_request(
         Err(err_rsp) => return err_rsp,
     };
 
-    // use latest snapshot, as this is a query for a transaction fee
-    let snapshot = storage.latest_snapshot();
     let height = match snapshot.get_block_height().await {
         Ok(height) => height,
         Err(err) => {
@@ -219,7 +220,7 @@ pub(crate) async fn transaction_fee_request(
     };
 
     let fees_with_ibc_denoms =
-        match total_fees(tx.actions().iter().map(ActionRef::from), &snapshot).await {
+        match total_fees(tx.actions().iter().map(ActionRef::from), &*snapshot).await {
             Ok(fees) => fees,
             Err(err) => {
                 return response::Query {
@@ -329,6 +330,16 @@ struct AllFeeComponents {
     recover_ibc_client: FetchResult,
     currency_pairs_change: FetchResult,
     markets_change: FetchResult,
+    register_multisig_account: FetchResult,
+    scheduled_transfer: FetchResult,
+    ics20_rate_limit_change: FetchResult,
+    vote_extension_participation_change: FetchResult,
+    bond: FetchResult,
+    unbond: FetchResult,
+    proposal_submission: FetchResult,
+    proposal_vote: FetchResult,
+    staking_parameters_change: FetchResult,
+    governance_parameters_change: FetchResult,
 }
 
 #[derive(serde::Serialize)]
@@ -372,6 +383,16 @@ async fn get_all_fee_components<S: StateRead>(state: &S) -> AllFeeComponents {
         recover_ibc_client,
         currency_pairs_change,
         markets_change,
+        register_multisig_account,
+        scheduled_transfer,
+        ics20_rate_limit_change,
+        vote_extension_participation_change,
+        bond,
+        unbond,
+        proposal_submission,
+        proposal_vote,
+        staking_parameters_change,
+        governance_parameters_change,
     ) = join!(
         state.get_fees::<Transfer>().map(FetchResult::from),
         state
@@ -395,6 +416,28 @@ async fn get_all_fee_components<S: StateRead>(state: &S) -> AllFeeComponents {
             .get_fees::<CurrencyPairsChange>()
             .map(FetchResult::from),
         state.get_fees::<MarketsChange>().map(FetchResult::from),
+        state
+            .get_fees::<RegisterMultisigAccount>()
+            .map(FetchResult::from),
+        state.get_fees::<ScheduledTransfer>().map(FetchResult::from),
+        state
+            .get_fees::<Ics20RateLimitChange>()
+            .map(FetchResult::from),
+        state
+            .get_fees::<VoteExtensionParticipationChange>()
+            .map(FetchResult::from),
+        state.get_fees::<Bond>().map(FetchResult::from),
+        state.get_fees::<Unbond>().map(FetchResult::from),
+        state
+            .get_fees::<ProposalSubmission>()
+            .map(FetchResult::from),
+        state.get_fees::<ProposalVote>().map(FetchResult::from),
+        state
+            .get_fees::<StakingParametersChange>()
+            .map(FetchResult::from),
+        state
+            .get_fees::<GovernanceParametersChange>()
+            .map(FetchResult::from),
     );
     AllFeeComponents {
         transfer,
@@ -415,6 +458,16 @@ async fn get_all_fee_components<S: StateRead>(state: &S) -> AllFeeComponents {
         recover_ibc_client,
         currency_pairs_change,
         markets_change,
+        register_multisig_account,
+        scheduled_transfer,
+        ics20_rate_limit_change,
+        vote_extension_participation_change,
+        bond,
+        unbond,
+        proposal_submission,
+        proposal_vote,
+        staking_parameters_change,
+        governance_parameters_change,
     }
 }
 
@@ -502,7 +555,7 @@ mod test {
             prove: false,
         };
 
-        let resp = transaction_fee_request(storage.clone(), query, vec![]).await;
+        let resp = transaction_fee_request(storage.latest_snapshot().into(), query, vec![]).await;
         assert_eq!(resp.code, 0.into(), "{}", resp.log);
 
         let proto = RawTransactionFeeResponse::decode(&*resp.value).unwrap();
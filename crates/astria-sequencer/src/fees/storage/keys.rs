@@ -46,6 +46,7 @@ mod tests {
     use astria_core::{
         primitive::v1::asset::Denom,
         protocol::transaction::v1::action::{
+            Bond,
             BridgeLock,
             BridgeSudoChange,
             BridgeTransfer,
@@ -53,16 +54,25 @@ mod tests {
             CurrencyPairsChange,
             FeeAssetChange,
             FeeChange,
+            GovernanceParametersChange,
             IbcRelayerChange,
             IbcSudoChange,
+            Ics20RateLimitChange,
             Ics20Withdrawal,
             InitBridgeAccount,
             MarketsChange,
+            ProposalSubmission,
+            ProposalVote,
             RecoverIbcClient,
+            RegisterMultisigAccount,
             RollupDataSubmission,
+            ScheduledTransfer,
+            StakingParametersChange,
             SudoAddressChange,
             Transfer,
+            Unbond,
             ValidatorUpdate,
+            VoteExtensionParticipationChange,
         },
     };
     use insta::assert_snapshot;
@@ -102,6 +112,16 @@ mod tests {
         check::<BridgeTransfer>();
         check::<CurrencyPairsChange>();
         check::<MarketsChange>();
+        check::<RegisterMultisigAccount>();
+        check::<ScheduledTransfer>();
+        check::<Ics20RateLimitChange>();
+        check::<VoteExtensionParticipationChange>();
+        check::<Bond>();
+        check::<Unbond>();
+        check::<ProposalSubmission>();
+        check::<ProposalVote>();
+        check::<StakingParametersChange>();
+        check::<GovernanceParametersChange>();
         assert_snapshot!("allowed_asset_prefix", ALLOWED_ASSET_PREFIX);
         assert_snapshot!("allowed_asset_key", allowed_asset(&test_asset()));
     }
@@ -126,6 +146,16 @@ mod tests {
         assert!(name::<RecoverIbcClient>().starts_with(COMPONENT_PREFIX));
         assert!(name::<CurrencyPairsChange>().starts_with(COMPONENT_PREFIX));
         assert!(name::<MarketsChange>().starts_with(COMPONENT_PREFIX));
+        assert!(name::<RegisterMultisigAccount>().starts_with(COMPONENT_PREFIX));
+        assert!(name::<ScheduledTransfer>().starts_with(COMPONENT_PREFIX));
+        assert!(name::<Ics20RateLimitChange>().starts_with(COMPONENT_PREFIX));
+        assert!(name::<VoteExtensionParticipationChange>().starts_with(COMPONENT_PREFIX));
+        assert!(name::<Bond>().starts_with(COMPONENT_PREFIX));
+        assert!(name::<Unbond>().starts_with(COMPONENT_PREFIX));
+        assert!(name::<ProposalSubmission>().starts_with(COMPONENT_PREFIX));
+        assert!(name::<ProposalVote>().starts_with(COMPONENT_PREFIX));
+        assert!(name::<StakingParametersChange>().starts_with(COMPONENT_PREFIX));
+        assert!(name::<GovernanceParametersChange>().starts_with(COMPONENT_PREFIX));
         assert!(ALLOWED_ASSET_PREFIX.starts_with(COMPONENT_PREFIX));
         assert!(allowed_asset(&test_asset()).starts_with(COMPONENT_PREFIX));
     }
@@ -1,6 +1,7 @@
 use astria_core::protocol::{
     fees::v1::FeeComponents as DomainFeeComponents,
     transaction::v1::action::{
+        Bond,
         BridgeLock,
         BridgeSudoChange,
         BridgeTransfer,
@@ -8,16 +9,25 @@ use astria_core::protocol::{
         CurrencyPairsChange,
         FeeAssetChange,
         FeeChange,
+        GovernanceParametersChange,
         IbcRelayerChange,
         IbcSudoChange,
+        Ics20RateLimitChange,
         Ics20Withdrawal,
         InitBridgeAccount,
         MarketsChange,
+        ProposalSubmission,
+        ProposalVote,
         RecoverIbcClient,
+        RegisterMultisigAccount,
         RollupDataSubmission,
+        ScheduledTransfer,
+        StakingParametersChange,
         SudoAddressChange,
         Transfer,
+        Unbond,
         ValidatorUpdate,
+        VoteExtensionParticipationChange,
     },
 };
 use astria_eyre::eyre::bail;
@@ -54,6 +64,16 @@ enum ValueImpl {
     RecoverIbcClientFees(FeeComponents),
     CurrencyPairsChangeFees(FeeComponents),
     MarketsChangeFees(FeeComponents),
+    RegisterMultisigAccountFees(FeeComponents),
+    ScheduledTransferFees(FeeComponents),
+    Ics20RateLimitChangeFees(FeeComponents),
+    VoteExtensionParticipationChangeFees(FeeComponents),
+    BondFees(FeeComponents),
+    UnbondFees(FeeComponents),
+    ProposalSubmissionFees(FeeComponents),
+    ProposalVoteFees(FeeComponents),
+    StakingParametersChangeFees(FeeComponents),
+    GovernanceParametersChangeFees(FeeComponents),
 }
 
 macro_rules! impl_from_for_fee_storage {
@@ -121,6 +141,16 @@ impl_from_for_fee_storage!(
     DomainFeeComponents<RecoverIbcClient> => RecoverIbcClientFees,
     DomainFeeComponents<CurrencyPairsChange> => CurrencyPairsChangeFees,
     DomainFeeComponents<MarketsChange> => MarketsChangeFees,
+    DomainFeeComponents<RegisterMultisigAccount> => RegisterMultisigAccountFees,
+    DomainFeeComponents<ScheduledTransfer> => ScheduledTransferFees,
+    DomainFeeComponents<Ics20RateLimitChange> => Ics20RateLimitChangeFees,
+    DomainFeeComponents<VoteExtensionParticipationChange> => VoteExtensionParticipationChangeFees,
+    DomainFeeComponents<Bond> => BondFees,
+    DomainFeeComponents<Unbond> => UnbondFees,
+    DomainFeeComponents<ProposalSubmission> => ProposalSubmissionFees,
+    DomainFeeComponents<ProposalVote> => ProposalVoteFees,
+    DomainFeeComponents<StakingParametersChange> => StakingParametersChangeFees,
+    DomainFeeComponents<GovernanceParametersChange> => GovernanceParametersChangeFees,
 );
 
 #[cfg(test)]
@@ -213,6 +243,43 @@ mod tests {
             "value_impl_markets_change_fees",
             value_impl_borsh_as_hex!(MarketsChangeFees),
         );
+        assert_snapshot!(
+            "value_impl_register_multisig_account_fees",
+            value_impl_borsh_as_hex!(RegisterMultisigAccountFees),
+        );
+        assert_snapshot!(
+            "value_impl_scheduled_transfer_fees",
+            value_impl_borsh_as_hex!(ScheduledTransferFees),
+        );
+        assert_snapshot!(
+            "value_impl_ics20_rate_limit_change_fees",
+            value_impl_borsh_as_hex!(Ics20RateLimitChangeFees),
+        );
+        assert_snapshot!(
+            "value_impl_vote_extension_participation_change_fees",
+            value_impl_borsh_as_hex!(VoteExtensionParticipationChangeFees),
+        );
+        assert_snapshot!("value_impl_bond_fees", value_impl_borsh_as_hex!(BondFees));
+        assert_snapshot!(
+            "value_impl_unbond_fees",
+            value_impl_borsh_as_hex!(UnbondFees)
+        );
+        assert_snapshot!(
+            "value_impl_proposal_submission_fees",
+            value_impl_borsh_as_hex!(ProposalSubmissionFees)
+        );
+        assert_snapshot!(
+            "value_impl_proposal_vote_fees",
+            value_impl_borsh_as_hex!(ProposalVoteFees)
+        );
+        assert_snapshot!(
+            "value_impl_staking_parameters_change_fees",
+            value_impl_borsh_as_hex!(StakingParametersChangeFees)
+        );
+        assert_snapshot!(
+            "value_impl_governance_parameters_change_fees",
+            value_impl_borsh_as_hex!(GovernanceParametersChangeFees)
+        );
     }
 
     // Note: This test must be here instead of in `crate::storage` since `ValueImpl` is not
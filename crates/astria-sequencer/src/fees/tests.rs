@@ -394,6 +394,16 @@ fn ensure_fee_handler_consts_valid() {
             Action::RecoverIbcClient(action) => check_names(action),
             Action::CurrencyPairsChange(action) => check_names(action),
             Action::MarketsChange(action) => check_names(action),
+            Action::RegisterMultisigAccount(action) => check_names(action),
+            Action::ScheduledTransfer(action) => check_names(action),
+            Action::Ics20RateLimitChange(action) => check_names(action),
+            Action::VoteExtensionParticipationChange(action) => check_names(action),
+            Action::Bond(action) => check_names(action),
+            Action::Unbond(action) => check_names(action),
+            Action::ProposalSubmission(action) => check_names(action),
+            Action::ProposalVote(action) => check_names(action),
+            Action::StakingParametersChange(action) => check_names(action),
+            Action::GovernanceParametersChange(action) => check_names(action),
         }
     }
 }
@@ -0,0 +1,387 @@
+use std::sync::Arc;
+
+use astria_core::protocol::genesis::v1::GenesisAppState;
+use astria_eyre::eyre::{
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::StateDelta;
+use futures::TryStreamExt as _;
+use tendermint::abci::{
+    request::{
+        BeginBlock,
+        EndBlock,
+    },
+    Event,
+};
+use tracing::{
+    instrument,
+    warn,
+    Level,
+};
+
+use super::{
+    Proposal,
+    ProposalStatus,
+    StateReadExt as _,
+    StateWriteExt,
+};
+use crate::{
+    accounts::StateWriteExt as _,
+    app::StateReadExt as _,
+    assets::StateReadExt as _,
+    authority::StateReadExt as _,
+    checked_actions::CheckedProposedAction,
+    component::Component,
+};
+
+#[derive(Default)]
+pub(crate) struct GovernanceComponent;
+
+#[async_trait::async_trait]
+impl Component for GovernanceComponent {
+    type AppState = GenesisAppState;
+
+    #[instrument(name = "GovernanceComponent::init_chain", skip_all, err)]
+    async fn init_chain<S: StateWriteExt>(mut state: S, app_state: &Self::AppState) -> Result<()> {
+        if let Some(parameters) = app_state.governance() {
+            state
+                .put_governance_parameters(*parameters)
+                .wrap_err("failed to write governance parameters to state")?;
+        }
+        Ok(())
+    }
+
+    #[instrument(name = "GovernanceComponent::begin_block", skip_all)]
+    async fn begin_block<S: StateWriteExt + 'static>(
+        _state: &mut Arc<S>,
+        _begin_block: &BeginBlock,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    #[instrument(name = "GovernanceComponent::end_block", skip_all, err(level = Level::WARN))]
+    async fn end_block<S: StateWriteExt + 'static>(
+        state: &mut Arc<S>,
+        _end_block: &EndBlock,
+    ) -> Result<()> {
+        let state = Arc::get_mut(state)
+            .ok_or_eyre("must only have one reference to the state; this is a bug")?;
+        finalize_proposals(state)
+            .await
+            .wrap_err("failed to finalize proposals")
+    }
+}
+
+/// Tallies the votes of all proposals whose voting period ended at or before the current block
+/// height, executing the actions of those which passed and refunding the deposits of all.
+#[instrument(skip_all, err(level = Level::WARN))]
+async fn finalize_proposals<S: StateWriteExt>(state: &mut S) -> Result<()> {
+    let height = state
+        .get_block_height()
+        .await
+        .wrap_err("failed to read block height from state")?;
+    let ids = state
+        .get_proposal_ids_with_voting_ended(height)
+        .await
+        .wrap_err("failed to read proposals with ended voting period from state")?;
+    for id in ids {
+        let mut proposal = state
+            .get_proposal(id)
+            .await
+            .wrap_err("failed to read proposal from state")?
+            .ok_or_eyre(
+                "proposal is indexed by the end of its voting period but does not exist; this is \
+                 a bug",
+            )?;
+        let (approving_power, total_power) = tally_votes(&*state, id)
+            .await
+            .wrap_err("failed to tally proposal votes")?;
+        proposal.status = if approving_power.saturating_mul(3) > total_power.saturating_mul(2) {
+            execute_proposal(&mut *state, &proposal).await
+        } else {
+            ProposalStatus::Rejected
+        };
+        if proposal.deposit > 0 {
+            let native_asset = state
+                .get_native_asset()
+                .await
+                .wrap_err("failed to read native asset from state")?
+                .ok_or_eyre("native asset must be set")?;
+            state
+                .increase_balance(&proposal.proposer, &native_asset, proposal.deposit)
+                .await
+                .wrap_err("failed to refund proposal deposit")?;
+        }
+        state
+            .put_proposal(&proposal)
+            .wrap_err("failed to write proposal to state")?;
+        state.record(construct_proposal_finalized_event(
+            &proposal,
+            approving_power,
+            total_power,
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the total power of the validators which approved of the proposal, and the total power
+/// of all validators.
+async fn tally_votes<S: StateWriteExt>(state: &S, proposal_id: u64) -> Result<(u64, u64)> {
+    let mut approving_power = 0_u64;
+    let mut total_power = 0_u64;
+    let mut validators = std::pin::pin!(state.get_validators());
+    while let Some(validator) = validators
+        .try_next()
+        .await
+        .wrap_err("failed to read validators from state")?
+    {
+        let power = u64::from(validator.power);
+        total_power = total_power.saturating_add(power);
+        let vote = state
+            .get_proposal_vote(proposal_id, &validator.verification_key)
+            .await
+            .wrap_err("failed to read proposal vote from state")?;
+        if vote == Some(true) {
+            approving_power = approving_power.saturating_add(power);
+        }
+    }
+    Ok((approving_power, total_power))
+}
+
+/// Executes the action of a passing proposal on a fork of the state, only applying its effects if
+/// it succeeds.
+async fn execute_proposal<S: StateWriteExt>(mut state: S, proposal: &Proposal) -> ProposalStatus {
+    let mut delta = StateDelta::new(&mut state);
+    let result = match CheckedProposedAction::new(proposal.action.clone(), &delta).await {
+        Ok(checked_action) => checked_action.execute(&mut delta).await,
+        Err(error) => Err(error),
+    };
+    match result {
+        Ok(()) => {
+            let (state, events) = delta.apply();
+            for event in events {
+                state.record(event);
+            }
+            ProposalStatus::Passed
+        }
+        Err(error) => {
+            warn!(
+                proposal_id = proposal.id,
+                error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                "failed to execute the action of a passing proposal",
+            );
+            ProposalStatus::Failed
+        }
+    }
+}
+
+/// Creates `abci::Event` of kind `proposal_finalized` for reporting the outcome of a proposal.
+fn construct_proposal_finalized_event(
+    proposal: &Proposal,
+    approving_power: u64,
+    total_power: u64,
+) -> Event {
+    Event::new(
+        "proposal_finalized",
+        [
+            ("proposalId", proposal.id.to_string()),
+            ("action", proposal.action.name().to_string()),
+            ("status", proposal.status.to_string()),
+            ("approvingPower", approving_power.to_string()),
+            ("totalPower", total_power.to_string()),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::{
+            asset::Denom,
+            ADDRESS_LEN,
+        },
+        protocol::transaction::v1::action::{
+            FeeAssetChange,
+            ProposedAction,
+        },
+    };
+
+    use super::*;
+    use crate::{
+        app::StateWriteExt as _,
+        fees::StateReadExt as _,
+        test_utils::{
+            nria,
+            Fixture,
+            ALICE_ADDRESS_BYTES,
+            BOB_ADDRESS_BYTES,
+            CAROL_ADDRESS_BYTES,
+        },
+    };
+
+    const VOTING_END_HEIGHT: u64 = 5;
+    const DEPOSIT: u128 = 100;
+
+    fn test_asset() -> Denom {
+        "test".parse().unwrap()
+    }
+
+    async fn fixture_with_proposal(
+        action: FeeAssetChange,
+        approvers: &[[u8; ADDRESS_LEN]],
+    ) -> Fixture {
+        let mut fixture = Fixture::uninitialized(None).await;
+        fixture.chain_initializer().with_governance(10).init().await;
+        let state = fixture.state_mut();
+        state.put_block_height(VOTING_END_HEIGHT).unwrap();
+        state
+            .put_proposal(&Proposal {
+                id: 0,
+                proposer: *ALICE_ADDRESS_BYTES,
+                action: ProposedAction::FeeAssetChange(action),
+                voting_end_height: VOTING_END_HEIGHT,
+                deposit: DEPOSIT,
+                status: ProposalStatus::Voting,
+            })
+            .unwrap();
+        for approver in approvers {
+            state.put_proposal_vote(0, approver, true).unwrap();
+        }
+        fixture
+    }
+
+    async fn proposal_status(fixture: &Fixture) -> ProposalStatus {
+        fixture
+            .state()
+            .get_proposal(0)
+            .await
+            .unwrap()
+            .unwrap()
+            .status
+    }
+
+    #[tokio::test]
+    async fn proposal_is_not_finalized_before_end_of_voting_period() {
+        let mut fixture = fixture_with_proposal(
+            FeeAssetChange::Addition(test_asset()),
+            &[
+                *ALICE_ADDRESS_BYTES,
+                *BOB_ADDRESS_BYTES,
+                *CAROL_ADDRESS_BYTES,
+            ],
+        )
+        .await;
+        fixture
+            .state_mut()
+            .put_block_height(VOTING_END_HEIGHT - 1)
+            .unwrap();
+
+        finalize_proposals(fixture.state_mut()).await.unwrap();
+
+        assert_eq!(proposal_status(&fixture).await, ProposalStatus::Voting);
+        assert!(!fixture
+            .state()
+            .is_allowed_fee_asset(&test_asset())
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn proposal_with_supermajority_is_executed() {
+        let mut fixture = fixture_with_proposal(
+            FeeAssetChange::Addition(test_asset()),
+            &[
+                *ALICE_ADDRESS_BYTES,
+                *BOB_ADDRESS_BYTES,
+                *CAROL_ADDRESS_BYTES,
+            ],
+        )
+        .await;
+
+        finalize_proposals(fixture.state_mut()).await.unwrap();
+
+        assert_eq!(proposal_status(&fixture).await, ProposalStatus::Passed);
+        assert!(fixture
+            .state()
+            .is_allowed_fee_asset(&test_asset())
+            .await
+            .unwrap());
+        assert!(fixture
+            .state()
+            .get_proposal_ids_with_voting_ended(VOTING_END_HEIGHT)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn proposal_without_supermajority_is_rejected() {
+        // Two of three validators with equal power is exactly two thirds, which is not enough.
+        let mut fixture = fixture_with_proposal(
+            FeeAssetChange::Addition(test_asset()),
+            &[*ALICE_ADDRESS_BYTES, *BOB_ADDRESS_BYTES],
+        )
+        .await;
+
+        finalize_proposals(fixture.state_mut()).await.unwrap();
+
+        assert_eq!(proposal_status(&fixture).await, ProposalStatus::Rejected);
+        assert!(!fixture
+            .state()
+            .is_allowed_fee_asset(&test_asset())
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn deposit_is_refunded_when_proposal_is_finalized() {
+        let mut fixture = fixture_with_proposal(FeeAssetChange::Addition(test_asset()), &[]).await;
+        let balance_before = fixture.get_nria_balance(&*ALICE_ADDRESS_BYTES).await;
+
+        finalize_proposals(fixture.state_mut()).await.unwrap();
+
+        assert_eq!(proposal_status(&fixture).await, ProposalStatus::Rejected);
+        assert_eq!(
+            fixture.get_nria_balance(&*ALICE_ADDRESS_BYTES).await,
+            balance_before + DEPOSIT
+        );
+    }
+
+    #[tokio::test]
+    async fn deposit_is_not_refunded_before_end_of_voting_period() {
+        let mut fixture = fixture_with_proposal(FeeAssetChange::Addition(test_asset()), &[]).await;
+        fixture
+            .state_mut()
+            .put_block_height(VOTING_END_HEIGHT - 1)
+            .unwrap();
+        let balance_before = fixture.get_nria_balance(&*ALICE_ADDRESS_BYTES).await;
+
+        finalize_proposals(fixture.state_mut()).await.unwrap();
+
+        assert_eq!(
+            fixture.get_nria_balance(&*ALICE_ADDRESS_BYTES).await,
+            balance_before
+        );
+    }
+
+    #[tokio::test]
+    async fn passing_proposal_whose_action_fails_is_marked_failed() {
+        // Removing the last allowed fee asset fails on execution.
+        let mut fixture = fixture_with_proposal(
+            FeeAssetChange::Removal(nria().into()),
+            &[
+                *ALICE_ADDRESS_BYTES,
+                *BOB_ADDRESS_BYTES,
+                *CAROL_ADDRESS_BYTES,
+            ],
+        )
+        .await;
+
+        finalize_proposals(fixture.state_mut()).await.unwrap();
+
+        assert_eq!(proposal_status(&fixture).await, ProposalStatus::Failed);
+        assert!(fixture.state().is_allowed_fee_asset(&nria()).await.unwrap());
+    }
+}
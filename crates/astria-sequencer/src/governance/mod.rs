@@ -0,0 +1,72 @@
+//! On-chain governance of privileged actions.
+//!
+//! Governance is enabled by setting [`GovernanceParameters`] in the genesis app state, or by the
+//! sudo address with a `GovernanceParametersChange` action. While enabled, any account can submit
+//! a `ProposalSubmission` action wrapping one of the privileged actions otherwise signed by the
+//! sudo address (or the IBC sudo address for `IbcRelayerChange`), depositing the configured
+//! amount of the native asset. Validators vote on the proposal with `ProposalVote` actions until
+//! its voting period ends.
+//!
+//! At the end of the block in which the voting period ends, the proposal passes if validators
+//! holding more than two thirds of the total validator power approved of it, in which case the
+//! proposed action is executed with the authority of the sudo address. The sudo address itself
+//! remains able to execute privileged actions directly as an emergency override. Whatever the
+//! outcome, the deposit is refunded to the proposer once the proposal is finalized.
+//!
+//! [`GovernanceParameters`]: astria_core::protocol::genesis::v1::GovernanceParameters
+pub(crate) mod component;
+mod state_ext;
+pub(crate) mod storage;
+
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+use astria_core::{
+    primitive::v1::ADDRESS_LEN,
+    protocol::transaction::v1::action::ProposedAction,
+};
+pub(crate) use state_ext::{
+    StateReadExt,
+    StateWriteExt,
+};
+
+/// A governance proposal to execute a privileged action.
+#[derive(Clone, Debug)]
+pub(crate) struct Proposal {
+    pub(crate) id: u64,
+    pub(crate) proposer: [u8; ADDRESS_LEN],
+    pub(crate) action: ProposedAction,
+    /// The last block height at which validators can vote on the proposal.
+    pub(crate) voting_end_height: u64,
+    /// The amount of the native asset deposited by the proposer, refunded when the proposal is
+    /// finalized.
+    pub(crate) deposit: u128,
+    pub(crate) status: ProposalStatus,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ProposalStatus {
+    /// The voting period has not yet ended.
+    Voting,
+    /// The proposal passed and its action was executed.
+    Passed,
+    /// The proposal did not receive enough approving votes.
+    Rejected,
+    /// The proposal passed but executing its action failed.
+    Failed,
+}
+
+impl Display for ProposalStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Self::Voting => "voting",
+            Self::Passed => "passed",
+            Self::Rejected => "rejected",
+            Self::Failed => "failed",
+        };
+        f.write_str(status)
+    }
+}
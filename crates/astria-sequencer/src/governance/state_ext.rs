@@ -0,0 +1,332 @@
+use astria_core::protocol::genesis::v1::GovernanceParameters;
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{
+        Result,
+        WrapErr as _,
+    },
+};
+use async_trait::async_trait;
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use futures::TryStreamExt as _;
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    storage::{
+        self,
+        keys,
+    },
+    Proposal,
+    ProposalStatus,
+};
+use crate::{
+    accounts::AddressBytes,
+    storage::StoredValue,
+};
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns the governance parameters, or `None` if governance is not enabled.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    async fn get_governance_parameters(&self) -> Result<Option<GovernanceParameters>> {
+        let Some(bytes) = self
+            .get_raw(keys::PARAMETERS)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw governance parameters from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::GovernanceParameters::try_from(value).map(GovernanceParameters::from)
+            })
+            .wrap_err("invalid governance parameters bytes")
+            .map(Some)
+    }
+
+    #[instrument(skip_all, err(level = Level::WARN))]
+    async fn get_next_proposal_id(&self) -> Result<u64> {
+        let Some(bytes) = self
+            .get_raw(keys::NEXT_PROPOSAL_ID)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw next proposal id from state")?
+        else {
+            return Ok(0);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::ProposalId::try_from(value).map(u64::from))
+            .wrap_err("invalid next proposal id bytes")
+    }
+
+    #[instrument(skip_all, fields(id), err(level = Level::WARN))]
+    async fn get_proposal(&self, id: u64) -> Result<Option<Proposal>> {
+        let Some(bytes) = self
+            .get_raw(&keys::proposal(id))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw proposal from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::Proposal::try_from(value).map(Proposal::from))
+            .wrap_err("invalid proposal bytes")
+            .map(Some)
+    }
+
+    /// Returns the vote of `validator` on the proposal, or `None` if it has not voted.
+    #[instrument(skip_all, fields(proposal_id, validator = %validator.display_address()), err(level = Level::WARN))]
+    async fn get_proposal_vote<T: AddressBytes>(
+        &self,
+        proposal_id: u64,
+        validator: &T,
+    ) -> Result<Option<bool>> {
+        let Some(bytes) = self
+            .get_raw(&keys::vote(proposal_id, validator))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw proposal vote from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::ProposalVote::try_from(value).map(bool::from))
+            .wrap_err("invalid proposal vote bytes")
+            .map(Some)
+    }
+
+    /// Returns the ids of all proposals still in their voting period whose voting period ends at
+    /// or before `height`, ordered by the end of their voting periods.
+    #[instrument(skip_all, fields(height), err(level = Level::WARN))]
+    async fn get_proposal_ids_with_voting_ended(&self, height: u64) -> Result<Vec<u64>> {
+        let mut stream = std::pin::pin!(self.prefix_raw(keys::VOTING_END_PREFIX));
+        let mut ids = vec![];
+        while let Some((key, bytes)) = stream
+            .try_next()
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading voting end index from state")?
+        {
+            // Keys are ordered by end height, so the voting periods of all further proposals have
+            // not yet ended.
+            if keys::extract_end_height_from_key(&key)? > height {
+                break;
+            }
+            let id = StoredValue::deserialize(&bytes)
+                .and_then(|value| storage::ProposalId::try_from(value).map(u64::from))
+                .wrap_err("invalid proposal id bytes")?;
+            ids.push(id);
+        }
+        Ok(ids)
+    }
+}
+
+impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip_all)]
+    fn put_governance_parameters(&mut self, parameters: GovernanceParameters) -> Result<()> {
+        let bytes = StoredValue::from(storage::GovernanceParameters::from(parameters))
+            .serialize()
+            .wrap_err("failed to serialize governance parameters")?;
+        self.put_raw(keys::PARAMETERS.to_string(), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(id), err(level = Level::WARN))]
+    fn put_next_proposal_id(&mut self, id: u64) -> Result<()> {
+        let bytes = StoredValue::from(storage::ProposalId::from(id))
+            .serialize()
+            .wrap_err("failed to serialize next proposal id")?;
+        self.put_raw(keys::NEXT_PROPOSAL_ID.to_string(), bytes);
+        Ok(())
+    }
+
+    /// Writes the proposal, indexing it by the end of its voting period while it is still being
+    /// voted on.
+    #[instrument(skip_all, fields(id = proposal.id), err(level = Level::WARN))]
+    fn put_proposal(&mut self, proposal: &Proposal) -> Result<()> {
+        let bytes = StoredValue::from(storage::Proposal::from(proposal))
+            .serialize()
+            .wrap_err("failed to serialize proposal")?;
+        self.put_raw(keys::proposal(proposal.id), bytes);
+
+        let index_key = keys::voting_end(proposal.voting_end_height, proposal.id);
+        if proposal.status == ProposalStatus::Voting {
+            let id_bytes = StoredValue::from(storage::ProposalId::from(proposal.id))
+                .serialize()
+                .wrap_err("failed to serialize proposal id")?;
+            self.put_raw(index_key, id_bytes);
+        } else {
+            self.delete(index_key);
+        }
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(proposal_id, validator = %validator.display_address(), approve), err(level = Level::WARN))]
+    fn put_proposal_vote<T: AddressBytes>(
+        &mut self,
+        proposal_id: u64,
+        validator: &T,
+        approve: bool,
+    ) -> Result<()> {
+        let bytes = StoredValue::from(storage::ProposalVote::from(approve))
+            .serialize()
+            .wrap_err("failed to serialize proposal vote")?;
+        self.put_raw(keys::vote(proposal_id, validator), bytes);
+        Ok(())
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        generated::astria::protocol::genesis::v1 as raw,
+        primitive::v1::asset::Denom,
+        protocol::transaction::v1::action::{
+            Action,
+            FeeAssetChange,
+            ProposedAction,
+        },
+        Protobuf as _,
+    };
+    use cnidarium::StateDelta;
+
+    use super::*;
+
+    fn proposal(id: u64, voting_end_height: u64) -> Proposal {
+        Proposal {
+            id,
+            proposer: [1; 20],
+            action: ProposedAction::FeeAssetChange(FeeAssetChange::Addition(
+                "test".parse::<Denom>().unwrap(),
+            )),
+            voting_end_height,
+            deposit: 0,
+            status: ProposalStatus::Voting,
+        }
+    }
+
+    #[tokio::test]
+    async fn governance_parameters_round_trip() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert_eq!(state.get_governance_parameters().await.unwrap(), None);
+
+        let parameters = GovernanceParameters::try_from_raw(raw::GovernanceParameters {
+            voting_period_blocks: 10,
+            proposal_deposit: Some(100.into()),
+        })
+        .unwrap();
+        state.put_governance_parameters(parameters).unwrap();
+        assert_eq!(
+            state.get_governance_parameters().await.unwrap(),
+            Some(parameters)
+        );
+    }
+
+    #[tokio::test]
+    async fn next_proposal_id_round_trip() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert_eq!(state.get_next_proposal_id().await.unwrap(), 0);
+        state.put_next_proposal_id(5).unwrap();
+        assert_eq!(state.get_next_proposal_id().await.unwrap(), 5);
+    }
+
+    #[tokio::test]
+    async fn proposal_round_trip() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert!(state.get_proposal(0).await.unwrap().is_none());
+
+        let proposal = proposal(0, 10);
+        state.put_proposal(&proposal).unwrap();
+        let stored = state.get_proposal(0).await.unwrap().unwrap();
+        assert_eq!(stored.id, proposal.id);
+        assert_eq!(stored.proposer, proposal.proposer);
+        assert_eq!(stored.voting_end_height, proposal.voting_end_height);
+        assert_eq!(stored.status, proposal.status);
+        assert_eq!(
+            Action::from(stored.action).to_raw(),
+            Action::from(proposal.action).to_raw()
+        );
+    }
+
+    #[tokio::test]
+    async fn proposal_votes_round_trip() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert_eq!(state.get_proposal_vote(0, &[2; 20]).await.unwrap(), None);
+        state.put_proposal_vote(0, &[2; 20], true).unwrap();
+        state.put_proposal_vote(1, &[2; 20], false).unwrap();
+        assert_eq!(
+            state.get_proposal_vote(0, &[2; 20]).await.unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            state.get_proposal_vote(1, &[2; 20]).await.unwrap(),
+            Some(false)
+        );
+
+        // A validator can change its vote.
+        state.put_proposal_vote(0, &[2; 20], false).unwrap();
+        assert_eq!(
+            state.get_proposal_vote(0, &[2; 20]).await.unwrap(),
+            Some(false)
+        );
+    }
+
+    #[tokio::test]
+    async fn proposals_with_voting_ended_are_ordered_and_exclude_finalized() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        state.put_proposal(&proposal(0, 20)).unwrap();
+        state.put_proposal(&proposal(1, 10)).unwrap();
+        state.put_proposal(&proposal(2, 30)).unwrap();
+        state.put_proposal(&proposal(3, 10)).unwrap();
+
+        assert!(state
+            .get_proposal_ids_with_voting_ended(9)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            state.get_proposal_ids_with_voting_ended(20).await.unwrap(),
+            vec![1, 3, 0]
+        );
+
+        // Finalized proposals are no longer indexed.
+        state
+            .put_proposal(&Proposal {
+                status: ProposalStatus::Rejected,
+                ..proposal(1, 10)
+            })
+            .unwrap();
+        assert_eq!(
+            state.get_proposal_ids_with_voting_ended(30).await.unwrap(),
+            vec![3, 0, 2]
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use astria_eyre::eyre::{
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+
+use crate::accounts::AddressBytes;
+
+pub(in crate::governance) const PARAMETERS: &str = "governance/parameters";
+pub(in crate::governance) const NEXT_PROPOSAL_ID: &str = "governance/next_proposal_id";
+pub(in crate::governance) const PROPOSAL_PREFIX: &str = "governance/proposal/";
+pub(in crate::governance) const VOTE_PREFIX: &str = "governance/vote/";
+pub(in crate::governance) const VOTING_END_PREFIX: &str = "governance/voting_end/";
+
+/// Example: `governance/proposal/00000000000000000007`.
+pub(in crate::governance) fn proposal(id: u64) -> String {
+    format!("{PROPOSAL_PREFIX}{id:020}")
+}
+
+/// Example: `governance/vote/00000000000000000007/0101....0101`.
+///                          |  proposal id   |40 hex chars|
+pub(in crate::governance) fn vote<T: AddressBytes>(proposal_id: u64, validator: &T) -> String {
+    format!(
+        "{VOTE_PREFIX}{proposal_id:020}/{}",
+        hex::encode(validator.address_bytes())
+    )
+}
+
+/// Example: `governance/voting_end/00000000000000000100/00000000000000000007`.
+///                                |    end height    |   proposal id    |
+///
+/// The end height is zero-padded so that iterating the keys under the prefix yields proposals in
+/// the order their voting periods end.
+pub(in crate::governance) fn voting_end(end_height: u64, proposal_id: u64) -> String {
+    format!("{VOTING_END_PREFIX}{end_height:020}/{proposal_id:020}")
+}
+
+/// Extracts the end height of the voting period from a voting end key.
+pub(in crate::governance) fn extract_end_height_from_key(key: &str) -> Result<u64> {
+    key.strip_prefix(VOTING_END_PREFIX)
+        .and_then(|s| s.split_once('/').map(|(height, _)| height))
+        .ok_or_eyre("failed to strip prefix from voting end key")?
+        .parse()
+        .wrap_err("failed to parse voting end key segment as block height")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPONENT_PREFIX: &str = "governance/";
+
+    #[test]
+    fn keys_should_not_change() {
+        insta::assert_snapshot!("parameters_key", PARAMETERS);
+        insta::assert_snapshot!("next_proposal_id_key", NEXT_PROPOSAL_ID);
+        insta::assert_snapshot!("proposal_key", proposal(7));
+        insta::assert_snapshot!("vote_key", vote(7, &[1; 20]));
+        insta::assert_snapshot!("voting_end_key", voting_end(100, 7));
+    }
+
+    #[test]
+    fn keys_should_have_component_prefix() {
+        assert!(PARAMETERS.starts_with(COMPONENT_PREFIX));
+        assert!(NEXT_PROPOSAL_ID.starts_with(COMPONENT_PREFIX));
+        assert!(proposal(7).starts_with(COMPONENT_PREFIX));
+        assert!(vote(7, &[1; 20]).starts_with(COMPONENT_PREFIX));
+        assert!(voting_end(100, 7).starts_with(COMPONENT_PREFIX));
+    }
+
+    #[test]
+    fn should_extract_end_height_from_key() {
+        assert_eq!(
+            extract_end_height_from_key(&voting_end(100, 7)).unwrap(),
+            100
+        );
+        assert!(extract_end_height_from_key(&proposal(7)).is_err());
+    }
+}
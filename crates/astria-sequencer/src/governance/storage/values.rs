@@ -0,0 +1,309 @@
+use std::fmt::{
+    self,
+    Debug,
+    Formatter,
+};
+
+use astria_core::{
+    generated::astria::protocol::transaction::v1 as raw,
+    primitive::v1::ADDRESS_LEN,
+    protocol::{
+        genesis::v1::GovernanceParameters as DomainGovernanceParameters,
+        transaction::v1::action::{
+            Action,
+            ProposedAction,
+        },
+    },
+    Protobuf as _,
+};
+use astria_eyre::eyre::bail;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use prost::Message as _;
+use telemetry::display::base64;
+
+use crate::governance::{
+    Proposal as DomainProposal,
+    ProposalStatus as DomainProposalStatus,
+};
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(crate) struct Value(ValueImpl);
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+enum ValueImpl {
+    GovernanceParameters(GovernanceParameters),
+    ProposalId(ProposalId),
+    Proposal(Proposal),
+    ProposalVote(ProposalVote),
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::governance) struct GovernanceParameters {
+    voting_period_blocks: u64,
+    proposal_deposit: u128,
+}
+
+impl From<DomainGovernanceParameters> for GovernanceParameters {
+    fn from(value: DomainGovernanceParameters) -> Self {
+        GovernanceParameters {
+            voting_period_blocks: value.voting_period_blocks(),
+            proposal_deposit: value.proposal_deposit(),
+        }
+    }
+}
+
+impl From<GovernanceParameters> for DomainGovernanceParameters {
+    fn from(value: GovernanceParameters) -> Self {
+        DomainGovernanceParameters::try_from_raw(
+            astria_core::generated::astria::protocol::genesis::v1::GovernanceParameters {
+                voting_period_blocks: value.voting_period_blocks,
+                proposal_deposit: Some(value.proposal_deposit.into()),
+            },
+        )
+        .expect("governance parameters in storage must be valid")
+    }
+}
+
+impl From<GovernanceParameters> for crate::storage::StoredValue<'_> {
+    fn from(parameters: GovernanceParameters) -> Self {
+        crate::storage::StoredValue::Governance(Value(ValueImpl::GovernanceParameters(parameters)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for GovernanceParameters {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Governance(Value(ValueImpl::GovernanceParameters(
+            parameters,
+        ))) = value
+        else {
+            bail!(
+                "governance stored value type mismatch: expected governance parameters, found \
+                 {value:?}"
+            );
+        };
+        Ok(parameters)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::governance) struct ProposalId(u64);
+
+impl From<u64> for ProposalId {
+    fn from(id: u64) -> Self {
+        ProposalId(id)
+    }
+}
+
+impl From<ProposalId> for u64 {
+    fn from(id: ProposalId) -> Self {
+        id.0
+    }
+}
+
+impl From<ProposalId> for crate::storage::StoredValue<'_> {
+    fn from(id: ProposalId) -> Self {
+        crate::storage::StoredValue::Governance(Value(ValueImpl::ProposalId(id)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for ProposalId {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Governance(Value(ValueImpl::ProposalId(id))) = value
+        else {
+            bail!("governance stored value type mismatch: expected proposal id, found {value:?}");
+        };
+        Ok(id)
+    }
+}
+
+/// The protobuf-encoded `Action` wrapping the proposed action.
+#[derive(BorshSerialize, BorshDeserialize)]
+struct ActionBytes(Vec<u8>);
+
+impl Debug for ActionBytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", base64(&self.0))
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+enum ProposalStatus {
+    Voting,
+    Passed,
+    Rejected,
+    Failed,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::governance) struct Proposal {
+    id: u64,
+    proposer: [u8; ADDRESS_LEN],
+    action: ActionBytes,
+    voting_end_height: u64,
+    deposit: u128,
+    status: ProposalStatus,
+}
+
+impl From<&DomainProposal> for Proposal {
+    fn from(proposal: &DomainProposal) -> Self {
+        let action = Action::from(proposal.action.clone())
+            .into_raw()
+            .encode_to_vec();
+        let status = match proposal.status {
+            DomainProposalStatus::Voting => ProposalStatus::Voting,
+            DomainProposalStatus::Passed => ProposalStatus::Passed,
+            DomainProposalStatus::Rejected => ProposalStatus::Rejected,
+            DomainProposalStatus::Failed => ProposalStatus::Failed,
+        };
+        Proposal {
+            id: proposal.id,
+            proposer: proposal.proposer,
+            action: ActionBytes(action),
+            voting_end_height: proposal.voting_end_height,
+            deposit: proposal.deposit,
+            status,
+        }
+    }
+}
+
+impl From<Proposal> for DomainProposal {
+    fn from(proposal: Proposal) -> Self {
+        let action = raw::Action::decode(&*proposal.action.0)
+            .ok()
+            .and_then(|raw| Action::try_from_raw(raw).ok())
+            .and_then(|action| ProposedAction::try_from(action).ok())
+            .expect("proposed action in storage must be valid");
+        let status = match proposal.status {
+            ProposalStatus::Voting => DomainProposalStatus::Voting,
+            ProposalStatus::Passed => DomainProposalStatus::Passed,
+            ProposalStatus::Rejected => DomainProposalStatus::Rejected,
+            ProposalStatus::Failed => DomainProposalStatus::Failed,
+        };
+        DomainProposal {
+            id: proposal.id,
+            proposer: proposal.proposer,
+            action,
+            voting_end_height: proposal.voting_end_height,
+            deposit: proposal.deposit,
+            status,
+        }
+    }
+}
+
+impl From<Proposal> for crate::storage::StoredValue<'_> {
+    fn from(proposal: Proposal) -> Self {
+        crate::storage::StoredValue::Governance(Value(ValueImpl::Proposal(proposal)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for Proposal {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Governance(Value(ValueImpl::Proposal(proposal))) = value
+        else {
+            bail!("governance stored value type mismatch: expected proposal, found {value:?}");
+        };
+        Ok(proposal)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::governance) struct ProposalVote {
+    approve: bool,
+}
+
+impl From<bool> for ProposalVote {
+    fn from(approve: bool) -> Self {
+        ProposalVote {
+            approve,
+        }
+    }
+}
+
+impl From<ProposalVote> for bool {
+    fn from(vote: ProposalVote) -> Self {
+        vote.approve
+    }
+}
+
+impl From<ProposalVote> for crate::storage::StoredValue<'_> {
+    fn from(vote: ProposalVote) -> Self {
+        crate::storage::StoredValue::Governance(Value(ValueImpl::ProposalVote(vote)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for ProposalVote {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Governance(Value(ValueImpl::ProposalVote(vote))) = value
+        else {
+            bail!("governance stored value type mismatch: expected proposal vote, found {value:?}");
+        };
+        Ok(vote)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use super::*;
+    use crate::test_utils::borsh_then_hex;
+
+    #[test]
+    fn value_impl_existing_variants_unchanged() {
+        assert_snapshot!(
+            "value_impl_governance_parameters",
+            borsh_then_hex(&ValueImpl::GovernanceParameters(GovernanceParameters {
+                voting_period_blocks: 1,
+                proposal_deposit: 2,
+            }))
+        );
+        assert_snapshot!(
+            "value_impl_proposal_id",
+            borsh_then_hex(&ValueImpl::ProposalId(ProposalId(1)))
+        );
+        assert_snapshot!(
+            "value_impl_proposal",
+            borsh_then_hex(&ValueImpl::Proposal(Proposal {
+                id: 1,
+                proposer: [2; ADDRESS_LEN],
+                action: ActionBytes(vec![3, 4]),
+                voting_end_height: 5,
+                deposit: 6,
+                status: ProposalStatus::Rejected,
+            }))
+        );
+        assert_snapshot!(
+            "value_impl_proposal_vote",
+            borsh_then_hex(&ValueImpl::ProposalVote(ProposalVote {
+                approve: true,
+            }))
+        );
+    }
+
+    // Note: This test must be here instead of in `crate::storage` since `ValueImpl` is not
+    // re-exported.
+    #[test]
+    fn stored_value_governance_variant_unchanged() {
+        use crate::storage::StoredValue;
+        assert_snapshot!(
+            "stored_value_governance_variant",
+            borsh_then_hex(&StoredValue::Governance(Value(
+                ValueImpl::GovernanceParameters(GovernanceParameters {
+                    voting_period_blocks: 1,
+                    proposal_deposit: 2,
+                })
+            )))
+        );
+    }
+}
@@ -1,7 +1,23 @@
-use std::sync::Arc;
+use std::{
+    pin::Pin,
+    sync::Arc,
+};
 
 use astria_core::{
+    crypto::ADDRESS_LENGTH,
     generated::mempool::v1::{
+        estimate_fee_response::{
+            BlockFullness as RawBlockFullness,
+            FeeLevel as RawFeeLevel,
+        },
+        mempool_event::{
+            Demoted as RawDemoted,
+            Event as RawEvent,
+            Included as RawIncluded,
+            Inserted as RawInserted,
+            Promoted as RawPromoted,
+            Removed as RawRemovedEvent,
+        },
         transaction_service_server::TransactionService,
         transaction_status::{
             Executed as RawExecuted,
@@ -10,31 +26,55 @@ use astria_core::{
             Removed as RawRemoved,
             Status as RawTransactionStatus,
         },
+        EstimateFeeRequest,
+        EstimateFeeResponse,
         GetTransactionStatusRequest,
+        MempoolEvent as RawMempoolEvent,
+        StreamMempoolEventsRequest,
         SubmitTransactionRequest,
         SubmitTransactionResponse,
         TransactionStatus as TransactionStatusResponse,
     },
     primitive::v1::{
+        Address,
         TransactionId,
         TRANSACTION_ID_LEN,
     },
+    Protobuf as _,
 };
 use bytes::Bytes;
 use cnidarium::Storage;
 use prost::Message as _;
+use tokio::sync::{
+    broadcast,
+    mpsc,
+};
 use tonic::{
+    codegen::tokio_stream::{
+        wrappers::ReceiverStream,
+        Stream,
+    },
     Request,
     Response,
     Status,
 };
+use tracing::{
+    debug,
+    error,
+};
 
 use crate::{
+    address::StateReadExt as _,
     mempool::{
+        BlockFullness,
+        FeeLevel,
         Mempool,
+        MempoolEvent,
+        MempoolEventKind,
         RemovalReason,
         TransactionStatus,
     },
+    proposal::block_size_constraints::MAX_SEQUENCE_DATA_BYTES_PER_BLOCK,
     service::mempool::{
         check_tx,
         CheckTxOutcome,
@@ -42,6 +82,11 @@ use crate::{
     Metrics,
 };
 
+/// The number of events buffered per stream before forwarding waits for the client to catch up.
+const EVENT_STREAM_BUFFER_SIZE: usize = 128;
+
+type GrpcStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send>>;
+
 pub(crate) struct Server {
     storage: Storage,
     mempool: Mempool,
@@ -60,6 +105,8 @@ impl Server {
 
 #[async_trait::async_trait]
 impl TransactionService for Server {
+    type StreamMempoolEventsStream = GrpcStream<RawMempoolEvent>;
+
     async fn get_transaction_status(
         self: Arc<Self>,
         request: Request<GetTransactionStatusRequest>,
@@ -98,6 +145,184 @@ impl TransactionService for Server {
             duplicate: submission_outcome.duplicate,
         }))
     }
+
+    async fn stream_mempool_events(
+        self: Arc<Self>,
+        request: Request<StreamMempoolEventsRequest>,
+    ) -> Result<Response<Self::StreamMempoolEventsStream>, Status> {
+        let filter = EventFilter::try_from_raw(request.into_inner())?;
+        let events = self.mempool.subscribe();
+        let base_prefix = self
+            .storage
+            .latest_snapshot()
+            .get_base_prefix()
+            .await
+            .map_err(|error| {
+                error!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                    "failed to read base prefix from storage",
+                );
+                Status::internal(format!("failed to read base prefix from storage: {error}"))
+            })?;
+
+        let (tx, rx) = mpsc::channel(EVENT_STREAM_BUFFER_SIZE);
+        tokio::spawn(forward_mempool_events(events, filter, base_prefix, tx));
+        Ok(Response::new(
+            Box::pin(ReceiverStream::new(rx)) as GrpcStream<RawMempoolEvent>
+        ))
+    }
+
+    async fn estimate_fee(
+        self: Arc<Self>,
+        request: Request<EstimateFeeRequest>,
+    ) -> Result<Response<EstimateFeeResponse>, Status> {
+        let estimate = self
+            .mempool
+            .estimate_fee(request.into_inner().target_blocks)
+            .await;
+        Ok(Response::new(EstimateFeeResponse {
+            recent_blocks: estimate
+                .recent_blocks
+                .into_iter()
+                .map(block_fullness_to_raw)
+                .collect(),
+            fee_levels: estimate
+                .fee_levels
+                .into_iter()
+                .map(fee_level_to_raw)
+                .collect(),
+            recommended_fee_per_kb: Some(estimate.recommended_fee_per_kb.into()),
+        }))
+    }
+}
+
+fn block_fullness_to_raw(block: BlockFullness) -> RawBlockFullness {
+    RawBlockFullness {
+        height: block.height,
+        transaction_count: usize_to_u64(block.transaction_count),
+        transaction_bytes: usize_to_u64(block.transaction_bytes),
+        rollup_data_bytes: usize_to_u64(block.rollup_data_bytes),
+        max_rollup_data_bytes: usize_to_u64(MAX_SEQUENCE_DATA_BYTES_PER_BLOCK),
+        max_block_bytes: block.max_block_bytes.map_or(0, usize_to_u64),
+    }
+}
+
+fn fee_level_to_raw(level: FeeLevel) -> RawFeeLevel {
+    RawFeeLevel {
+        min_fee_per_kb: Some(level.min_fee_per_kb.into()),
+        transaction_count: usize_to_u64(level.transaction_count),
+        rollup_data_bytes: usize_to_u64(level.rollup_data_bytes),
+        transaction_bytes: usize_to_u64(level.transaction_bytes),
+    }
+}
+
+fn usize_to_u64(value: usize) -> u64 {
+    u64::try_from(value).unwrap_or(u64::MAX)
+}
+
+/// Restricts the events sent on a mempool event stream to those requested by the client.
+struct EventFilter {
+    address_bytes: Option<[u8; ADDRESS_LENGTH]>,
+    tx_id: Option<TransactionId>,
+}
+
+impl EventFilter {
+    fn try_from_raw(raw: StreamMempoolEventsRequest) -> Result<Self, Status> {
+        let address_bytes = raw
+            .signer
+            .map(|signer| {
+                Address::try_from_raw(signer)
+                    .map(Address::bytes)
+                    .map_err(|e| Status::invalid_argument(format!("invalid signer address: {e}")))
+            })
+            .transpose()?;
+        let tx_id = if raw.transaction_hash.is_empty() {
+            None
+        } else {
+            let tx_hash: [u8; TRANSACTION_ID_LEN] =
+                raw.transaction_hash.as_ref().try_into().map_err(|_| {
+                    Status::invalid_argument(format!(
+                        "Invalid transaction hash contained {} bytes, expected \
+                         {TRANSACTION_ID_LEN}",
+                        raw.transaction_hash.len()
+                    ))
+                })?;
+            Some(TransactionId::new(tx_hash))
+        };
+        Ok(Self {
+            address_bytes,
+            tx_id,
+        })
+    }
+
+    fn matches(&self, event: &MempoolEvent) -> bool {
+        self.address_bytes
+            .map_or(true, |address_bytes| address_bytes == event.address_bytes)
+            && self.tx_id.map_or(true, |tx_id| tx_id == event.tx_id)
+    }
+}
+
+/// Forwards the mempool events matching `filter` to the client until either the client
+/// disconnects or falls so far behind that events were dropped, in which case the stream is
+/// terminated with an error.
+async fn forward_mempool_events(
+    mut events: broadcast::Receiver<MempoolEvent>,
+    filter: EventFilter,
+    base_prefix: String,
+    tx: mpsc::Sender<Result<RawMempoolEvent, Status>>,
+) {
+    loop {
+        let event = tokio::select! {
+            () = tx.closed() => break,
+            event = events.recv() => event,
+        };
+        let item = match event {
+            Ok(event) if filter.matches(&event) => mempool_event_to_raw(event, &base_prefix),
+            Ok(_) => continue,
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                debug!(skipped, "mempool event stream fell behind; terminating it");
+                Err(Status::resource_exhausted(format!(
+                    "stream fell behind and missed {skipped} mempool events"
+                )))
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+        let is_terminal = item.is_err();
+        if tx.send(item).await.is_err() || is_terminal {
+            break;
+        }
+    }
+}
+
+fn mempool_event_to_raw(event: MempoolEvent, base_prefix: &str) -> Result<RawMempoolEvent, Status> {
+    let signer = Address::builder()
+        .array(event.address_bytes)
+        .prefix(base_prefix)
+        .try_build()
+        .map_err(|e| Status::internal(format!("failed to construct signer address: {e}")))?;
+    let event_kind = match event.kind {
+        MempoolEventKind::AddedToPending => RawEvent::Inserted(RawInserted {
+            parked: false,
+        }),
+        MempoolEventKind::AddedToParked => RawEvent::Inserted(RawInserted {
+            parked: true,
+        }),
+        MempoolEventKind::Promoted => RawEvent::Promoted(RawPromoted {}),
+        MempoolEventKind::Demoted => RawEvent::Demoted(RawDemoted {}),
+        MempoolEventKind::Removed(RemovalReason::IncludedInBlock(height)) => {
+            RawEvent::Included(RawIncluded {
+                height,
+            })
+        }
+        MempoolEventKind::Removed(reason) => RawEvent::Removed(RawRemovedEvent {
+            reason: reason.to_string(),
+        }),
+    };
+    Ok(RawMempoolEvent {
+        transaction_hash: event.tx_id.get().to_vec().into(),
+        signer: Some(signer.into_raw()),
+        event: Some(event_kind),
+    })
 }
 
 async fn get_transaction_status(
@@ -186,6 +411,7 @@ mod tests {
 
     use astria_core::generated::protocol::transaction::v1::Transaction as RawTransaction;
     use cnidarium::StateDelta;
+    use futures::StreamExt as _;
     use prost::Message as _;
 
     use super::*;
@@ -196,7 +422,9 @@ mod tests {
         test_utils::{
             Fixture,
             ALICE,
+            ALICE_ADDRESS,
             ALICE_ADDRESS_BYTES,
+            BOB,
         },
     };
 
@@ -228,7 +456,7 @@ mod tests {
         let tx_hash_bytes: Bytes = tx.id().get().to_vec().into();
         // Should be inserted into Pending
         mempool
-            .insert(tx, nonce, &HashMap::new(), HashMap::new())
+            .insert(tx, nonce, &HashMap::new(), HashMap::new(), None)
             .await
             .unwrap();
 
@@ -263,6 +491,7 @@ mod tests {
                 nonce.saturating_sub(1),
                 &HashMap::default(),
                 HashMap::default(),
+                None,
             )
             .await
             .unwrap();
@@ -289,7 +518,13 @@ mod tests {
         let tx = new_tx(&fixture, nonce).await;
         let tx_hash_bytes: Bytes = tx.id().get().to_vec().into();
         mempool
-            .insert(tx.clone(), nonce, &HashMap::default(), HashMap::default())
+            .insert(
+                tx.clone(),
+                nonce,
+                &HashMap::default(),
+                HashMap::default(),
+                None,
+            )
             .await
             .unwrap();
 
@@ -332,7 +567,13 @@ mod tests {
         let tx = new_tx(&fixture, nonce).await;
         let tx_hash_bytes: Bytes = tx.id().get().to_vec().into();
         mempool
-            .insert(tx.clone(), nonce, &HashMap::default(), HashMap::default())
+            .insert(
+                tx.clone(),
+                nonce,
+                &HashMap::default(),
+                HashMap::default(),
+                None,
+            )
             .await
             .unwrap();
         let height = 100;
@@ -460,6 +701,7 @@ mod tests {
                 nonce,
                 &HashMap::default(),
                 HashMap::default(),
+                None,
             )
             .await
             .unwrap();
@@ -517,4 +759,171 @@ mod tests {
             )
         );
     }
+
+    #[tokio::test]
+    async fn stream_mempool_events_filters_by_signer() {
+        let fixture = Fixture::default_initialized().await;
+        let mempool = fixture.mempool();
+        let server = new_server(&fixture);
+
+        let req = StreamMempoolEventsRequest {
+            signer: Some(ALICE_ADDRESS.to_raw()),
+            transaction_hash: Bytes::new(),
+        };
+        let mut stream = server
+            .stream_mempool_events(Request::new(req))
+            .await
+            .unwrap()
+            .into_inner();
+
+        let nonce = 1;
+        let bob_tx = fixture
+            .checked_tx_builder()
+            .with_nonce(nonce)
+            .with_signer(BOB.clone())
+            .build()
+            .await;
+        mempool
+            .insert(bob_tx, nonce, &HashMap::default(), HashMap::default(), None)
+            .await
+            .unwrap();
+        let alice_tx = new_tx(&fixture, nonce).await;
+        mempool
+            .insert(
+                alice_tx.clone(),
+                nonce,
+                &HashMap::default(),
+                HashMap::default(),
+                None,
+            )
+            .await
+            .unwrap();
+        let removal_reason = RemovalReason::FailedPrepareProposal("failure reason".to_string());
+        mempool
+            .remove_tx_invalid(alice_tx.clone(), removal_reason.clone())
+            .await;
+
+        let tx_hash_bytes: Bytes = alice_tx.id().get().to_vec().into();
+        assert_eq!(
+            stream.next().await.unwrap().unwrap(),
+            RawMempoolEvent {
+                transaction_hash: tx_hash_bytes.clone(),
+                signer: Some(ALICE_ADDRESS.to_raw()),
+                event: Some(RawEvent::Inserted(RawInserted {
+                    parked: false
+                })),
+            }
+        );
+        assert_eq!(
+            stream.next().await.unwrap().unwrap(),
+            RawMempoolEvent {
+                transaction_hash: tx_hash_bytes,
+                signer: Some(ALICE_ADDRESS.to_raw()),
+                event: Some(RawEvent::Removed(RawRemovedEvent {
+                    reason: removal_reason.to_string()
+                })),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn stream_mempool_events_filters_by_transaction_hash() {
+        let fixture = Fixture::default_initialized().await;
+        let mempool = fixture.mempool();
+        let server = new_server(&fixture);
+
+        let tx1 = new_tx(&fixture, 1).await;
+        let tx2 = new_tx(&fixture, 2).await;
+        let tx2_hash_bytes: Bytes = tx2.id().get().to_vec().into();
+        let req = StreamMempoolEventsRequest {
+            signer: None,
+            transaction_hash: tx2_hash_bytes.clone(),
+        };
+        let mut stream = server
+            .stream_mempool_events(Request::new(req))
+            .await
+            .unwrap()
+            .into_inner();
+
+        // tx2 is parked until tx1 fills the nonce gap and promotes it.
+        for tx in [tx2, tx1] {
+            mempool
+                .insert(tx, 1, &HashMap::default(), HashMap::default(), None)
+                .await
+                .unwrap();
+        }
+
+        let event = stream.next().await.unwrap().unwrap();
+        assert_eq!(event.transaction_hash, tx2_hash_bytes);
+        assert_eq!(
+            event.event,
+            Some(RawEvent::Inserted(RawInserted {
+                parked: true
+            }))
+        );
+        let event = stream.next().await.unwrap().unwrap();
+        assert_eq!(event.transaction_hash, tx2_hash_bytes);
+        assert_eq!(event.event, Some(RawEvent::Promoted(RawPromoted {})));
+    }
+
+    #[tokio::test]
+    async fn stream_mempool_events_fails_if_invalid_transaction_hash() {
+        let fixture = Fixture::default_initialized().await;
+        let server = new_server(&fixture);
+
+        let req = StreamMempoolEventsRequest {
+            signer: None,
+            transaction_hash: vec![0; TRANSACTION_ID_LEN.saturating_sub(10)].into(),
+        };
+        let Err(status) = server.stream_mempool_events(Request::new(req)).await else {
+            panic!("stream should not be opened for an invalid transaction hash");
+        };
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn estimate_fee_works_as_expected() {
+        let fixture = Fixture::default_initialized().await;
+        let mempool = fixture.mempool();
+        let server = new_server(&fixture);
+
+        let block = BlockFullness {
+            height: 1,
+            transaction_count: 1,
+            transaction_bytes: 200,
+            rollup_data_bytes: 3,
+            max_block_bytes: Some(1_000),
+        };
+        mempool.record_block_fullness(block).await;
+        let tx = new_tx(&fixture, 0).await;
+        let tx_bytes = tx.encoded_bytes().len();
+        mempool
+            .insert(tx, 0, &HashMap::new(), HashMap::new(), None)
+            .await
+            .unwrap();
+
+        let rsp = server
+            .estimate_fee(Request::new(EstimateFeeRequest {
+                target_blocks: 1,
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(rsp.recent_blocks, vec![block_fullness_to_raw(block)]);
+        assert_eq!(
+            rsp.recent_blocks[0].max_rollup_data_bytes,
+            usize_to_u64(MAX_SEQUENCE_DATA_BYTES_PER_BLOCK)
+        );
+        assert_eq!(rsp.recent_blocks[0].max_block_bytes, 1_000);
+        assert_eq!(
+            rsp.fee_levels,
+            vec![RawFeeLevel {
+                min_fee_per_kb: Some(0_u128.into()),
+                transaction_count: 1,
+                rollup_data_bytes: 3,
+                transaction_bytes: usize_to_u64(tx_bytes),
+            }]
+        );
+        assert_eq!(rsp.recommended_fee_per_kb, Some(0_u128.into()));
+    }
 }
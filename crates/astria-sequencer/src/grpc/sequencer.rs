@@ -352,6 +352,7 @@ mod tests {
             ValidatorSet,
         },
         grpc::StateWriteExt as _,
+        mempool::BuilderQueuePolicy,
         test_utils::{
             astria_address,
             dummy_balances,
@@ -375,7 +376,7 @@ mod tests {
         let block = make_test_sequencer_block(1);
         let storage = cnidarium::TempStorage::new().await.unwrap();
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, BuilderQueuePolicy::default(), false);
         let mut state_tx = StateDelta::new(storage.latest_snapshot());
         state_tx.put_block_height(1).unwrap();
         state_tx.put_sequencer_block(block).unwrap();
@@ -407,7 +408,7 @@ mod tests {
             .build()
             .await;
         mempool
-            .insert(tx, 0, &dummy_balances(0, 0), dummy_tx_costs(0, 0, 0))
+            .insert(tx, 0, &dummy_balances(0, 0), dummy_tx_costs(0, 0, 0), None)
             .await
             .unwrap();
 
@@ -419,7 +420,7 @@ mod tests {
             .build()
             .await;
         mempool
-            .insert(tx, 0, &dummy_balances(0, 0), dummy_tx_costs(0, 0, 0))
+            .insert(tx, 0, &dummy_balances(0, 0), dummy_tx_costs(0, 0, 0), None)
             .await
             .unwrap();
 
@@ -431,7 +432,7 @@ mod tests {
             .build()
             .await;
         mempool
-            .insert(tx, 0, &dummy_balances(0, 0), dummy_tx_costs(0, 0, 0))
+            .insert(tx, 0, &dummy_balances(0, 0), dummy_tx_costs(0, 0, 0), None)
             .await
             .unwrap();
 
@@ -504,7 +505,7 @@ mod tests {
     #[tokio::test]
     async fn validator_name_request_fails_if_not_a_validator() {
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, BuilderQueuePolicy::default(), false);
         let storage = cnidarium::TempStorage::new().await.unwrap();
 
         let server = Arc::new(SequencerServer::new(
@@ -528,7 +529,7 @@ mod tests {
     #[tokio::test]
     async fn validator_name_request_fails_if_pre_aspen() {
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, BuilderQueuePolicy::default(), false);
         let storage = cnidarium::TempStorage::new().await.unwrap();
         let snapshot = storage.latest_snapshot();
         let mut state = StateDelta::new(snapshot);
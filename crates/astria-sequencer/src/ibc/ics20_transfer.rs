@@ -45,6 +45,7 @@ use astria_eyre::{
     eyre_to_anyhow,
 };
 use cnidarium::{
+    StateDelta,
     StateRead,
     StateWrite,
 };
@@ -92,6 +93,8 @@ use crate::{
         StateWriteExt as _,
     },
     ibc::{
+        packet_forward,
+        rate_limit,
         StateReadExt as _,
         StateWriteExt as _,
     },
@@ -164,7 +167,7 @@ const MAX_ROLLUP_ADDRESS_BYTE_LENGTH: usize = 256;
 /// If `<asset>` was sent to Sequencer and did not originate on Sequencer, then Sequencer will
 /// have prefixed it `Sp/Sc/<asset>` upon receipt. And so when sending, the payload asset
 /// *will* be prefixed `Sp/Sc/<asset>` with `source_port = Sp` and `source_channel = Sc` set.
-fn is_transfer_source_zone(
+pub(super) fn is_transfer_source_zone(
     asset: &denom::TracePrefixed,
     port: &PortId,
     channel: &ChannelId,
@@ -173,7 +176,11 @@ fn is_transfer_source_zone(
 }
 
 /// See [`is_transfer_source_zone`] for what this does.
-fn is_refund_source_zone(asset: &denom::TracePrefixed, port: &PortId, channel: &ChannelId) -> bool {
+pub(super) fn is_refund_source_zone(
+    asset: &denom::TracePrefixed,
+    port: &PortId,
+    channel: &ChannelId,
+) -> bool {
     !is_transfer_source_zone(asset, port, channel)
 }
 
@@ -375,8 +382,11 @@ impl AppHandlerExecute for Ics20Transfer {
             .map_err(|err| eyre_to_anyhow(err).context("failed to read upgrade info"))?
             .is_some();
 
-        let ack = match receive_tokens(&mut state, &msg.packet).await {
-            Ok(()) => TokenTransferAcknowledgement::success(),
+        let ack = match receive_tokens_atomically(&mut state, &msg.packet).await {
+            Ok(Receipt::Credited) => TokenTransferAcknowledgement::success(),
+            // The acknowledgement is written once the forwarded packet is acknowledged or times
+            // out.
+            Ok(Receipt::Forwarded) => return Ok(()),
             Err(e) => {
                 tracing::warn!(
                     error = AsRef::<dyn std::error::Error>::as_ref(&e),
@@ -404,6 +414,14 @@ impl AppHandlerExecute for Ics20Transfer {
         mut state: S,
         msg: &MsgTimeout,
     ) -> anyhow::Result<()> {
+        if packet_forward::timeout_forward(&mut state, &msg.packet)
+            .await
+            .map_err(|err| {
+                eyre_to_anyhow(err).context("failed to handle forwarded packet timeout")
+            })?
+        {
+            return Ok(());
+        }
         refund_tokens(&mut state, &msg.packet).await.map_err(|err| {
             eyre_to_anyhow(err).context("failed to refund tokens during timeout_packet_execute")
         })
@@ -418,11 +436,21 @@ impl AppHandlerExecute for Ics20Transfer {
             serde_json::from_slice(msg.acknowledgement.as_slice()),
             "failed to deserialize token transfer acknowledgement",
         )?;
+        if packet_forward::acknowledge_forward(&mut state, &msg.packet, &ack)
+            .await
+            .map_err(|err| {
+                eyre_to_anyhow(err).context("failed to handle forwarded packet acknowledgement")
+            })?
+        {
+            return Ok(());
+        }
         if !ack.is_successful() {
             return refund_tokens(&mut state, &msg.packet)
                 .await
                 .map_err(|err| eyre_to_anyhow(err).context("failed to refund tokens"));
         }
+        // The transfer completed, so it can no longer be reversed against the rate limit.
+        state.delete_ics20_outflow_height(&msg.packet.chan_on_a, msg.packet.sequence);
         Ok(())
     }
 }
@@ -430,6 +458,39 @@ impl AppHandlerExecute for Ics20Transfer {
 #[async_trait::async_trait]
 impl AppHandler for Ics20Transfer {}
 
+/// The outcome of successfully receiving an ICS20 transfer.
+#[derive(Debug, PartialEq, Eq)]
+enum Receipt {
+    /// The tokens were credited to an address on Sequencer.
+    Credited,
+    /// The tokens were forwarded over another channel.
+    Forwarded,
+}
+
+/// The destination of the tokens of a received ICS20 transfer.
+enum Destination {
+    Account(Address),
+    Forward(packet_forward::Forward),
+}
+
+/// Receives the tokens of `packet` on a fork of the state, only applying its effects if the
+/// transfer succeeds.
+///
+/// This ensures that a failing transfer discards all of its effects, including the inflow
+/// recorded against the channel's rate limit.
+async fn receive_tokens_atomically<S: StateWrite>(
+    mut state: S,
+    packet: &Packet,
+) -> Result<Receipt> {
+    let mut delta = StateDelta::new(&mut state);
+    let receipt = receive_tokens(&mut delta, packet).await?;
+    let (state, events) = delta.apply();
+    for event in events {
+        state.record(event);
+    }
+    Ok(receipt)
+}
+
 #[instrument(
     skip_all,
     fields(
@@ -442,7 +503,7 @@ impl AppHandler for Ics20Transfer {}
     ),
     err,
 )]
-async fn receive_tokens<S: StateWrite>(mut state: S, packet: &Packet) -> Result<()> {
+async fn receive_tokens<S: StateWrite>(mut state: S, packet: &Packet) -> Result<Receipt> {
     let packet_data: FungibleTokenPacketData = serde_json::from_slice(&packet.data)
         .wrap_err("failed to deserialize fungible token packet data")?;
 
@@ -451,14 +512,23 @@ async fn receive_tokens<S: StateWrite>(mut state: S, packet: &Packet) -> Result<
         .parse()
         .wrap_err("failed to parse packet data amount to u128")?;
 
-    let recipient = parse_address_on_sequencer(&state, &packet_data.receiver)
-        .await
-        .with_context(|| {
-            format!(
-                "failed parsing packet.receiver `{}` as the recipient address",
-                packet_data.receiver
-            )
-        })?;
+    // The receiver of a forwarded transfer is not credited, so it need not be an address on
+    // Sequencer.
+    let destination = if let Some(forward) = packet_forward::parse_memo(&packet_data.memo)
+        .wrap_err("failed to parse memo as packet forward memo")?
+    {
+        Destination::Forward(forward)
+    } else {
+        let recipient = parse_address_on_sequencer(&state, &packet_data.receiver)
+            .await
+            .with_context(|| {
+                format!(
+                    "failed parsing packet.receiver `{}` as the recipient address",
+                    packet_data.receiver
+                )
+            })?;
+        Destination::Account(recipient)
+    };
 
     let mut asset = parse_asset(&state, &packet_data.denom)
         .await
@@ -487,6 +557,35 @@ async fn receive_tokens<S: StateWrite>(mut state: S, packet: &Packet) -> Result<
         );
     }
 
+    let inflow_recorded_at = rate_limit::record_ics20_flow(
+        &mut state,
+        &packet.chan_on_b,
+        &asset,
+        rate_limit::Direction::Inflow,
+        amount,
+    )
+    .await
+    .context("failed to record ics20 transfer against the channel's rate limit")?;
+
+    let recipient = match destination {
+        Destination::Account(recipient) => recipient,
+        Destination::Forward(forward) => {
+            packet_forward::forward_tokens(
+                &mut state,
+                packet,
+                &packet_data.sender,
+                forward,
+                &asset,
+                is_source,
+                amount,
+                inflow_recorded_at,
+            )
+            .await
+            .context("failed to forward ics20 transfer")?;
+            return Ok(Receipt::Forwarded);
+        }
+    };
+
     // If `recipient` is a bridge account then create a deposit event to signal to
     // its associated rollup that funds were received.
     //
@@ -527,7 +626,7 @@ async fn receive_tokens<S: StateWrite>(mut state: S, packet: &Packet) -> Result<
         .await
         .context("failed to update user account balance")?;
 
-    Ok(())
+    Ok(Receipt::Credited)
 }
 
 #[instrument(
@@ -590,6 +689,16 @@ async fn refund_tokens<S: StateWrite>(mut state: S, packet: &Packet) -> Result<(
     .await
     .context("failed to refund a sequencer address")?;
 
+    rate_limit::reverse_ics20_outflow(
+        &mut state,
+        &packet.chan_on_a,
+        packet.sequence,
+        &asset,
+        amount,
+    )
+    .await
+    .context("failed to reverse refunded ics20 transfer against the channel's rate limit")?;
+
     Ok(())
 }
 
@@ -625,7 +734,10 @@ async fn refund_tokens_to_sequencer_address<S: StateWrite>(
 }
 
 #[instrument(skip_all, fields(input), err(level = Level::DEBUG))]
-async fn parse_asset<S: StateRead>(state: S, input: &str) -> Result<denom::TracePrefixed> {
+pub(super) async fn parse_asset<S: StateRead>(
+    state: S,
+    input: &str,
+) -> Result<denom::TracePrefixed> {
     let asset = match input
         .parse::<Denom>()
         .wrap_err("failed parsing input as IBC denomination")?
@@ -777,9 +889,12 @@ mod tests {
             RollupId,
             TransactionId,
         },
-        protocol::memos::v1::{
-            Ics20TransferDeposit,
-            Ics20WithdrawalFromRollup,
+        protocol::{
+            memos::v1::{
+                Ics20TransferDeposit,
+                Ics20WithdrawalFromRollup,
+            },
+            transaction::v1::action::Ics20RateLimit,
         },
         sequencerblock::v1::block::Deposit,
     };
@@ -798,17 +913,20 @@ mod tests {
 
     use super::{
         receive_tokens,
+        receive_tokens_atomically,
         refund_tokens,
     };
     use crate::{
         accounts::StateReadExt as _,
         address::StateWriteExt as _,
+        app::StateWriteExt as _,
         assets::StateReadExt as _,
         bridge::{
             StateReadExt as _,
             StateWriteExt as _,
         },
         ibc::{
+            rate_limit,
             StateReadExt as _,
             StateWriteExt,
         },
@@ -942,6 +1060,111 @@ mod tests {
         assert_eq!(user_balance, amount);
     }
 
+    #[tokio::test]
+    async fn receive_fails_if_rate_limit_exceeded() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state_tx = StateDelta::new(snapshot.clone());
+
+        state_tx.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        state_tx
+            .put_ibc_compat_prefix(ASTRIA_COMPAT_PREFIX.to_string())
+            .unwrap();
+        state_tx.put_block_height(1).unwrap();
+        state_tx
+            .put_ics20_rate_limit(
+                &packet().chan_on_b,
+                &sink_asset(),
+                Ics20RateLimit {
+                    max_inflow: 150,
+                    max_outflow: 0,
+                    window_blocks: 10,
+                },
+            )
+            .unwrap();
+
+        let recipient_address = astria_address(&[1; 20]);
+        let amount = 100;
+        let packet_data = FungibleTokenPacketData {
+            denom: nria().to_string(),
+            sender: String::new(),
+            amount: amount.to_string(),
+            receiver: recipient_address.to_string(),
+            memo: String::new(),
+        };
+        let packet = Packet {
+            data: serde_json::to_vec(&packet_data).unwrap(),
+            ..packet()
+        };
+
+        receive_tokens(&mut state_tx, &packet).await.unwrap();
+        let err = receive_tokens(&mut state_tx, &packet).await.unwrap_err();
+        assert!(
+            format!("{err:#}").contains("exceeds rate limit on channel `bchan`"),
+            "unexpected error: {err:#}"
+        );
+
+        let user_balance = state_tx
+            .get_account_balance(&recipient_address, &sink_asset())
+            .await
+            .unwrap();
+        assert_eq!(user_balance, amount);
+    }
+
+    #[tokio::test]
+    async fn forward_over_unknown_channel_fails_without_crediting_receiver() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state_tx = StateDelta::new(snapshot.clone());
+
+        state_tx.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        state_tx
+            .put_ibc_compat_prefix(ASTRIA_COMPAT_PREFIX.to_string())
+            .unwrap();
+        state_tx.put_block_height(1).unwrap();
+        state_tx
+            .put_block_timestamp(tendermint::Time::from_unix_timestamp(1, 0).unwrap())
+            .unwrap();
+        state_tx
+            .put_ibc_channel_balance(&packet().chan_on_b, &nria(), 100)
+            .unwrap();
+
+        // The receiver of a forwarded transfer is not required to be an address on Sequencer.
+        let packet_data = FungibleTokenPacketData {
+            denom: source_asset().to_string(),
+            sender: "cosmos1sender".to_string(),
+            amount: "100".to_string(),
+            receiver: "pfm".to_string(),
+            memo: serde_json::json!({
+                "forward": {
+                    "receiver": "noble1receiver",
+                    "port": "transfer",
+                    "channel": "channel-99",
+                }
+            })
+            .to_string(),
+        };
+        let err = receive_tokens(
+            &mut state_tx,
+            &Packet {
+                data: serde_json::to_vec(&packet_data).unwrap(),
+                ..packet()
+            },
+        )
+        .await
+        .unwrap_err();
+        assert!(
+            format!("{err:#}").contains("failed to forward ics20 transfer"),
+            "unexpected error: {err:#}"
+        );
+
+        let escrow_balance = state_tx
+            .get_ibc_channel_balance(&packet().chan_on_b, &nria())
+            .await
+            .unwrap();
+        assert_eq!(escrow_balance, 100);
+    }
+
     #[tokio::test]
     async fn receive_source_zone_asset_on_bridge_account_and_emit_to_rollup() {
         let storage = cnidarium::TempStorage::new().await.unwrap();
@@ -1170,6 +1393,136 @@ mod tests {
         .expect_err("unknown asset during transfer to bridge account should fail");
     }
 
+    #[tokio::test]
+    async fn failed_receive_discards_recorded_inflow() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state_tx = StateDelta::new(snapshot.clone());
+
+        state_tx.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        state_tx
+            .put_ibc_compat_prefix(ASTRIA_COMPAT_PREFIX.to_string())
+            .unwrap();
+        state_tx.put_block_height(1).unwrap();
+        state_tx
+            .put_block_timestamp(tendermint::Time::from_unix_timestamp(1, 0).unwrap())
+            .unwrap();
+        state_tx
+            .put_ics20_rate_limit(
+                &packet().chan_on_b,
+                &sink_asset(),
+                Ics20RateLimit {
+                    max_inflow: 150,
+                    max_outflow: 0,
+                    window_blocks: 10,
+                },
+            )
+            .unwrap();
+
+        // Forwarding over an unknown channel fails after the inflow was recorded.
+        let packet_data = FungibleTokenPacketData {
+            denom: nria().to_string(),
+            sender: "cosmos1sender".to_string(),
+            amount: "100".to_string(),
+            receiver: "pfm".to_string(),
+            memo: serde_json::json!({
+                "forward": {
+                    "receiver": "noble1receiver",
+                    "port": "transfer",
+                    "channel": "channel-99",
+                }
+            })
+            .to_string(),
+        };
+        receive_tokens_atomically(
+            &mut state_tx,
+            &Packet {
+                data: serde_json::to_vec(&packet_data).unwrap(),
+                ..packet()
+            },
+        )
+        .await
+        .unwrap_err();
+
+        assert!(state_tx
+            .get_ics20_flow(&packet().chan_on_b, &sink_asset())
+            .await
+            .unwrap()
+            .is_none());
+        assert!(!state_tx.has_ibc_asset(&sink_asset()).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn refund_reverses_recorded_outflow() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state_tx = StateDelta::new(snapshot.clone());
+
+        state_tx.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        state_tx
+            .put_ibc_compat_prefix(ASTRIA_COMPAT_PREFIX.to_string())
+            .unwrap();
+        state_tx.put_block_height(1).unwrap();
+        state_tx
+            .put_ics20_rate_limit(
+                &packet().chan_on_a,
+                &nria(),
+                Ics20RateLimit {
+                    max_inflow: 0,
+                    max_outflow: 100,
+                    window_blocks: 10,
+                },
+            )
+            .unwrap();
+
+        let sender_address = astria_address(&[1; 20]);
+        let amount = 100;
+        state_tx
+            .put_ibc_channel_balance(&packet().chan_on_a, &nria(), amount)
+            .unwrap();
+        rate_limit::record_ics20_outflow(
+            &mut state_tx,
+            &packet().chan_on_a,
+            packet().sequence,
+            &nria(),
+            amount,
+        )
+        .await
+        .unwrap();
+
+        let packet_data = FungibleTokenPacketData {
+            denom: nria().to_string(),
+            sender: sender_address.to_string(),
+            amount: amount.to_string(),
+            receiver: "cosmos1receiver".to_string(),
+            memo: String::new(),
+        };
+        refund_tokens(
+            &mut state_tx,
+            &Packet {
+                data: serde_json::to_vec(&packet_data).unwrap(),
+                ..packet()
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            0,
+            state_tx
+                .get_ics20_flow(&packet().chan_on_a, &nria())
+                .await
+                .unwrap()
+                .unwrap()
+                .outflow()
+        );
+        assert!(state_tx
+            .get_ics20_outflow_height(&packet().chan_on_a, packet().sequence)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
     #[tokio::test]
     async fn refund_sequencer_account_with_source_zone_asset() {
         let storage = cnidarium::TempStorage::new().await.unwrap();
@@ -0,0 +1,1116 @@
+//! Channel handshakes and packets of the ICS27 interchain accounts host port.
+//!
+//! Penumbra's IBC message handlers only pass channel handshakes and packets of the `transfer` port
+//! to their app handler and reject those of any other port. Messages on the `icahost` port are
+//! therefore handled here instead, following the channel semantics of ICS4 as implemented by
+//! Penumbra's handlers, before being passed on to [`Ics27Host`].
+//!
+//! Penumbra's handlers cannot be wrapped to serve the port, as the port check is part of the
+//! handlers themselves. The handling here differs from ICS4 as implemented by Penumbra, or from
+//! ibc-go's interchain accounts host, in the following ways:
+//!
+//! - As the host never initiates channels nor sends packets, only the counterparty-initiated steps
+//!   of the channel handshake (`ChanOpenTry` and `ChanOpenConfirm`), the closing confirmation and
+//!   received packets are executed. All other messages are rejected by [`Ics27Host`], so channels
+//!   are only closed by their controller.
+//! - Since [`Ics27Host`] only accepts ordered channels, unordered packet receipts are not
+//!   supported.
+//! - Channel upgrades are not supported; the upgrade sequence of all channels is 0.
+//! - As in Penumbra, a `ChanOpenTry` allocates a new channel for every message carrying a valid
+//!   proof, even if one of the counterparty's channels was already answered. Only the channel named
+//!   by the counterparty's channel end can then be confirmed. Unlike with ibc-go's host, a
+//!   controller whose channel is open is not prevented from opening another one to the same
+//!   interchain account.
+//! - A closed channel is never reopened. A controller reopens its interchain account by opening a
+//!   new channel, which is assigned a new channel id but retains the interchain account.
+
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{
+        bail,
+        ensure,
+        eyre,
+        OptionExt as _,
+        Result,
+        WrapErr as _,
+    },
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use ibc_types::core::{
+    channel::{
+        channel::{
+            ChannelEnd,
+            Counterparty,
+            Order,
+            State as ChannelState,
+        },
+        msgs::{
+            MsgChannelCloseConfirm,
+            MsgChannelOpenConfirm,
+            MsgChannelOpenTry,
+            MsgRecvPacket,
+        },
+        ChannelId,
+        Packet,
+        PortId,
+        TimeoutHeight,
+    },
+    client::Height,
+    commitment::MerkleProof,
+    connection::{
+        ConnectionEnd,
+        ConnectionId,
+        State as ConnectionState,
+    },
+};
+use penumbra_ibc::{
+    component::{
+        app_handler::{
+            AppHandlerCheck as _,
+            AppHandlerExecute as _,
+        },
+        channel::StateWriteExt as _,
+        proof_verification::{
+            ChannelProofVerifier as _,
+            PacketProofVerifier as _,
+        },
+        ChannelStateReadExt as _,
+        ConnectionStateReadExt as _,
+    },
+    IbcRelay,
+};
+use tendermint::abci::Event;
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    host_interface::AstriaHost,
+    ics27_host::Ics27Host,
+};
+use crate::{
+    app::StateReadExt as _,
+    checked_actions::use_pre_aspen_validator_updates,
+};
+
+/// Verifies the proofs of the counterparty chain's state carried by channel handshakes and
+/// packets.
+#[async_trait::async_trait]
+pub(crate) trait ProofVerifier {
+    async fn verify_channel_proof<S: StateRead>(
+        state: &S,
+        connection: &ConnectionEnd,
+        proof: &MerkleProof,
+        proof_height: &Height,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        expected_channel: &ChannelEnd,
+    ) -> Result<()>;
+
+    async fn verify_packet_recv_proof<S: StateRead>(
+        state: &S,
+        connection: &ConnectionEnd,
+        msg: &MsgRecvPacket,
+    ) -> Result<()>;
+}
+
+/// Verifies proofs against the consensus states of the counterparty's light client using
+/// Penumbra's IBC implementation.
+pub(crate) struct PenumbraProofVerifier;
+
+#[async_trait::async_trait]
+impl ProofVerifier for PenumbraProofVerifier {
+    async fn verify_channel_proof<S: StateRead>(
+        state: &S,
+        connection: &ConnectionEnd,
+        proof: &MerkleProof,
+        proof_height: &Height,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        expected_channel: &ChannelEnd,
+    ) -> Result<()> {
+        state
+            .verify_channel_proof(
+                connection,
+                proof,
+                proof_height,
+                channel_id,
+                port_id,
+                expected_channel,
+            )
+            .await
+            .map_err(anyhow_to_eyre)
+    }
+
+    async fn verify_packet_recv_proof<S: StateRead>(
+        state: &S,
+        connection: &ConnectionEnd,
+        msg: &MsgRecvPacket,
+    ) -> Result<()> {
+        state
+            .verify_packet_recv_proof::<AstriaHost>(connection, msg)
+            .await
+            .map_err(anyhow_to_eyre)
+    }
+}
+
+/// Runs the checks of `action` which do not require reading state.
+pub(crate) fn check_stateless(action: &IbcRelay) -> Result<()> {
+    if let IbcRelay::ChannelOpenTry(msg) = action {
+        ensure!(
+            msg.connection_hops_on_b.len() == 1,
+            "channel must have exactly one connection hop",
+        );
+    }
+    Ok(())
+}
+
+/// Checks and executes `action`, a channel handshake or packet message of the ICS27 host port.
+#[instrument(skip_all, err(level = Level::DEBUG))]
+pub(crate) async fn check_and_execute<V: ProofVerifier, S: StateWrite>(
+    state: S,
+    action: &IbcRelay,
+) -> Result<()> {
+    ensure!(
+        !use_pre_aspen_validator_updates(&state)
+            .await
+            .wrap_err("failed to get upgrade status")?,
+        "ics27 interchain accounts are not available before the Aspen upgrade",
+    );
+    match action {
+        IbcRelay::ChannelOpenTry(msg) => chan_open_try::<V, _>(state, msg).await,
+        IbcRelay::ChannelOpenConfirm(msg) => chan_open_confirm::<V, _>(state, msg).await,
+        IbcRelay::ChannelCloseConfirm(msg) => chan_close_confirm::<V, _>(state, msg).await,
+        IbcRelay::RecvPacket(msg) => recv_packet::<V, _>(state, msg).await,
+        IbcRelay::ChannelOpenInit(msg) => Ics27Host::chan_open_init_check(&state, msg)
+            .await
+            .map_err(anyhow_to_eyre),
+        IbcRelay::ChannelOpenAck(msg) => Ics27Host::chan_open_ack_check(&state, msg)
+            .await
+            .map_err(anyhow_to_eyre),
+        IbcRelay::ChannelCloseInit(msg) => Ics27Host::chan_close_init_check(&state, msg)
+            .await
+            .map_err(anyhow_to_eyre),
+        IbcRelay::Timeout(msg) => Ics27Host::timeout_packet_check(&state, msg)
+            .await
+            .map_err(anyhow_to_eyre),
+        IbcRelay::Acknowledgement(msg) => Ics27Host::acknowledge_packet_check(&state, msg)
+            .await
+            .map_err(anyhow_to_eyre),
+        _ => bail!("ibc relay action is not a channel handshake or packet message"),
+    }
+}
+
+#[instrument(skip_all, fields(controller_port = %msg.port_id_on_a), err(level = Level::DEBUG))]
+async fn chan_open_try<V: ProofVerifier, S: StateWrite>(
+    mut state: S,
+    msg: &MsgChannelOpenTry,
+) -> Result<()> {
+    let connection_id = msg
+        .connection_hops_on_b
+        .first()
+        .ok_or_eyre("channel must have a connection hop")?;
+    let connection = get_open_connection(&state, connection_id).await?;
+
+    let expected_channel_on_a = ChannelEnd {
+        state: ChannelState::Init,
+        ordering: msg.ordering,
+        remote: Counterparty::new(msg.port_id_on_b.clone(), None),
+        connection_hops: vec![counterparty_connection_id(&connection)?],
+        version: msg.version_supported_on_a.clone(),
+        upgrade_sequence: 0,
+    };
+    V::verify_channel_proof(
+        &state,
+        &connection,
+        &msg.proof_chan_end_on_a,
+        &msg.proof_height_on_a,
+        &msg.chan_id_on_a,
+        &msg.port_id_on_a,
+        &expected_channel_on_a,
+    )
+    .await
+    .wrap_err("failed to verify proof of counterparty channel")?;
+
+    Ics27Host::chan_open_try_check(&state, msg)
+        .await
+        .map_err(anyhow_to_eyre)?;
+
+    let channel_counter = state
+        .get_channel_counter()
+        .await
+        .map_err(anyhow_to_eyre)
+        .wrap_err("failed to read channel counter from state")?;
+    let channel_id = ChannelId::new(channel_counter);
+    let channel = ChannelEnd {
+        state: ChannelState::TryOpen,
+        ordering: msg.ordering,
+        remote: Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
+        connection_hops: msg.connection_hops_on_b.clone(),
+        version: msg.version_supported_on_a.clone(),
+        upgrade_sequence: 0,
+    };
+    state.put_channel_counter(
+        channel_counter
+            .checked_add(1)
+            .ok_or_eyre("channel counter overflowed")?,
+    );
+    state.put_channel(&channel_id, &msg.port_id_on_b, channel.clone());
+    state.put_send_sequence(&channel_id, &msg.port_id_on_b, 1);
+    state.put_recv_sequence(&channel_id, &msg.port_id_on_b, 1);
+    state.put_ack_sequence(&channel_id, &msg.port_id_on_b, 1);
+    state.record(construct_channel_event(
+        "channel_open_try",
+        &msg.port_id_on_b,
+        &channel_id,
+        &channel,
+    ));
+
+    Ics27Host::chan_open_try_execute(&mut state, msg).await;
+    Ok(())
+}
+
+#[instrument(skip_all, fields(host_channel = %msg.chan_id_on_b), err(level = Level::DEBUG))]
+async fn chan_open_confirm<V: ProofVerifier, S: StateWrite>(
+    mut state: S,
+    msg: &MsgChannelOpenConfirm,
+) -> Result<()> {
+    let mut channel = get_channel(&state, &msg.chan_id_on_b, &msg.port_id_on_b).await?;
+    ensure!(
+        channel.state == ChannelState::TryOpen,
+        "channel `{}` is not in the try open state",
+        msg.chan_id_on_b,
+    );
+    verify_counterparty_channel_state::<V, _>(
+        &state,
+        &msg.port_id_on_b,
+        &msg.chan_id_on_b,
+        &channel,
+        ChannelState::Open,
+        &msg.proof_chan_end_on_a,
+        &msg.proof_height_on_a,
+    )
+    .await?;
+
+    Ics27Host::chan_open_confirm_check(&state, msg)
+        .await
+        .map_err(anyhow_to_eyre)?;
+
+    channel.state = ChannelState::Open;
+    state.put_channel(&msg.chan_id_on_b, &msg.port_id_on_b, channel.clone());
+    state.record(construct_channel_event(
+        "channel_open_confirm",
+        &msg.port_id_on_b,
+        &msg.chan_id_on_b,
+        &channel,
+    ));
+
+    Ics27Host::chan_open_confirm_execute(&mut state, msg).await;
+    Ok(())
+}
+
+#[instrument(skip_all, fields(host_channel = %msg.chan_id_on_b), err(level = Level::DEBUG))]
+async fn chan_close_confirm<V: ProofVerifier, S: StateWrite>(
+    mut state: S,
+    msg: &MsgChannelCloseConfirm,
+) -> Result<()> {
+    let mut channel = get_channel(&state, &msg.chan_id_on_b, &msg.port_id_on_b).await?;
+    ensure!(
+        channel.state != ChannelState::Closed,
+        "channel `{}` is already closed",
+        msg.chan_id_on_b,
+    );
+    verify_counterparty_channel_state::<V, _>(
+        &state,
+        &msg.port_id_on_b,
+        &msg.chan_id_on_b,
+        &channel,
+        ChannelState::Closed,
+        &msg.proof_chan_end_on_a,
+        &msg.proof_height_on_a,
+    )
+    .await?;
+
+    Ics27Host::chan_close_confirm_check(&state, msg)
+        .await
+        .map_err(anyhow_to_eyre)?;
+
+    channel.state = ChannelState::Closed;
+    state.put_channel(&msg.chan_id_on_b, &msg.port_id_on_b, channel.clone());
+    state.record(construct_channel_event(
+        "channel_close_confirm",
+        &msg.port_id_on_b,
+        &msg.chan_id_on_b,
+        &channel,
+    ));
+
+    Ics27Host::chan_close_confirm_execute(&mut state, msg).await;
+    Ok(())
+}
+
+#[instrument(
+    skip_all,
+    fields(host_channel = %msg.packet.chan_on_b, sequence = %msg.packet.sequence),
+    err(level = Level::DEBUG),
+)]
+async fn recv_packet<V: ProofVerifier, S: StateWrite>(
+    mut state: S,
+    msg: &MsgRecvPacket,
+) -> Result<()> {
+    let packet = &msg.packet;
+    let channel = get_channel(&state, &packet.chan_on_b, &packet.port_on_b).await?;
+    ensure!(
+        channel.state == ChannelState::Open,
+        "channel `{}` is not open",
+        packet.chan_on_b,
+    );
+    ensure!(
+        channel.remote.port_id == packet.port_on_a
+            && channel.remote.channel_id.as_ref() == Some(&packet.chan_on_a),
+        "packet source `{}/{}` does not match the counterparty of channel `{}`",
+        packet.port_on_a,
+        packet.chan_on_a,
+        packet.chan_on_b,
+    );
+    ensure!(
+        channel.ordering == Order::Ordered,
+        "channel `{}` is not ordered",
+        packet.chan_on_b,
+    );
+    let connection_id = channel
+        .connection_hops
+        .first()
+        .ok_or_eyre("channel has no connection hop")?;
+    let connection = get_open_connection(&state, connection_id).await?;
+
+    let current_height = Height::new(
+        state
+            .get_revision_number()
+            .await
+            .wrap_err("failed to read revision number from state")?,
+        state
+            .get_block_height()
+            .await
+            .wrap_err("failed to read block height from state")?,
+    )
+    .wrap_err("failed to construct current height")?;
+    if let TimeoutHeight::At(timeout_height) = &packet.timeout_height_on_b {
+        ensure!(
+            current_height < *timeout_height,
+            "packet timed out at height `{timeout_height}`",
+        );
+    }
+    if let Some(timeout_time) = packet.timeout_timestamp_on_b.time {
+        let block_time = state
+            .get_block_timestamp()
+            .await
+            .wrap_err("failed to read block timestamp from state")?;
+        ensure!(
+            block_time < timeout_time,
+            "packet timed out at `{timeout_time}`"
+        );
+    }
+
+    V::verify_packet_recv_proof(&state, &connection, msg)
+        .await
+        .wrap_err("failed to verify proof of packet commitment")?;
+
+    let next_sequence_recv = state
+        .get_recv_sequence(&packet.chan_on_b, &packet.port_on_b)
+        .await
+        .map_err(anyhow_to_eyre)
+        .wrap_err("failed to read next receive sequence from state")?;
+    ensure!(
+        packet.sequence.0 == next_sequence_recv,
+        "packet sequence `{}` does not match next receive sequence `{next_sequence_recv}`",
+        packet.sequence.0,
+    );
+
+    Ics27Host::recv_packet_check(&state, msg)
+        .await
+        .map_err(anyhow_to_eyre)?;
+
+    state.put_recv_sequence(
+        &packet.chan_on_b,
+        &packet.port_on_b,
+        next_sequence_recv
+            .checked_add(1)
+            .ok_or_eyre("next receive sequence overflowed")?,
+    );
+    state.record(construct_recv_packet_event(packet, &channel));
+
+    Ics27Host::recv_packet_execute(&mut state, msg)
+        .await
+        .map_err(anyhow_to_eyre)
+}
+
+/// Verifies that the counterparty of `channel` is in `expected_state`.
+async fn verify_counterparty_channel_state<V: ProofVerifier, S: StateRead>(
+    state: &S,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    channel: &ChannelEnd,
+    expected_state: ChannelState,
+    proof: &MerkleProof,
+    proof_height: &Height,
+) -> Result<()> {
+    let connection_id = channel
+        .connection_hops
+        .first()
+        .ok_or_eyre("channel has no connection hop")?;
+    let connection = get_open_connection(state, connection_id).await?;
+    let counterparty_channel_id = channel
+        .remote
+        .channel_id
+        .as_ref()
+        .ok_or_eyre("channel has no counterparty channel id")?;
+
+    let expected_channel_on_a = ChannelEnd {
+        state: expected_state,
+        ordering: channel.ordering,
+        remote: Counterparty::new(port_id.clone(), Some(channel_id.clone())),
+        connection_hops: vec![counterparty_connection_id(&connection)?],
+        version: channel.version.clone(),
+        upgrade_sequence: 0,
+    };
+    V::verify_channel_proof(
+        state,
+        &connection,
+        proof,
+        proof_height,
+        counterparty_channel_id,
+        &channel.remote.port_id,
+        &expected_channel_on_a,
+    )
+    .await
+    .wrap_err("failed to verify proof of counterparty channel")
+}
+
+async fn get_channel<S: StateRead>(
+    state: &S,
+    channel_id: &ChannelId,
+    port_id: &PortId,
+) -> Result<ChannelEnd> {
+    state
+        .get_channel(channel_id, port_id)
+        .await
+        .map_err(anyhow_to_eyre)
+        .wrap_err("failed to read channel from state")?
+        .ok_or_else(|| eyre!("channel `{port_id}/{channel_id}` not found"))
+}
+
+async fn get_open_connection<S: StateRead>(
+    state: &S,
+    connection_id: &ConnectionId,
+) -> Result<ConnectionEnd> {
+    let connection = state
+        .get_connection(connection_id)
+        .await
+        .map_err(anyhow_to_eyre)
+        .wrap_err("failed to read connection from state")?
+        .ok_or_else(|| eyre!("connection `{connection_id}` not found"))?;
+    ensure!(
+        connection.state == ConnectionState::Open,
+        "connection `{connection_id}` is not open",
+    );
+    Ok(connection)
+}
+
+fn counterparty_connection_id(connection: &ConnectionEnd) -> Result<ConnectionId> {
+    connection
+        .counterparty
+        .connection_id
+        .clone()
+        .ok_or_eyre("connection has no counterparty connection id")
+}
+
+/// Creates `abci::Event` of kind `kind` for reporting a step of a channel handshake, with the
+/// attributes emitted by other IBC implementations.
+fn construct_channel_event(
+    kind: &str,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    channel: &ChannelEnd,
+) -> Event {
+    Event::new(
+        kind,
+        [
+            ("port_id", port_id.to_string()),
+            ("channel_id", channel_id.to_string()),
+            ("counterparty_port_id", channel.remote.port_id.to_string()),
+            (
+                "counterparty_channel_id",
+                channel
+                    .remote
+                    .channel_id
+                    .as_ref()
+                    .map(ToString::to_string)
+                    .unwrap_or_default(),
+            ),
+            (
+                "connection_id",
+                channel
+                    .connection_hops
+                    .first()
+                    .map(ToString::to_string)
+                    .unwrap_or_default(),
+            ),
+            ("version", channel.version.as_str().to_string()),
+        ],
+    )
+}
+
+/// Creates `abci::Event` of kind `recv_packet` for reporting a received packet, with the
+/// attributes emitted by other IBC implementations.
+fn construct_recv_packet_event(packet: &Packet, channel: &ChannelEnd) -> Event {
+    let timeout_height = match &packet.timeout_height_on_b {
+        TimeoutHeight::Never => "0-0".to_string(),
+        TimeoutHeight::At(height) => height.to_string(),
+    };
+    let timeout_timestamp = packet
+        .timeout_timestamp_on_b
+        .time
+        .map_or(0, |time| time.unix_timestamp_nanos());
+    Event::new(
+        "recv_packet",
+        [
+            ("packet_data_hex", hex::encode(&packet.data)),
+            ("packet_timeout_height", timeout_height),
+            ("packet_timeout_timestamp", timeout_timestamp.to_string()),
+            ("packet_sequence", packet.sequence.0.to_string()),
+            ("packet_src_port", packet.port_on_a.to_string()),
+            ("packet_src_channel", packet.chan_on_a.to_string()),
+            ("packet_dst_port", packet.port_on_b.to_string()),
+            ("packet_dst_channel", packet.chan_on_b.to_string()),
+            (
+                "packet_channel_ordering",
+                channel.ordering.as_str().to_string(),
+            ),
+            (
+                "packet_connection",
+                channel
+                    .connection_hops
+                    .first()
+                    .map(ToString::to_string)
+                    .unwrap_or_default(),
+            ),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use astria_core::{
+        generated::astria::protocol::transaction::v1 as raw,
+        primitive::v1::TransactionId,
+        Protobuf as _,
+    };
+    use base64::{
+        engine::general_purpose::STANDARD,
+        Engine as _,
+    };
+    use ibc_proto::{
+        google::protobuf::Any,
+        ibc::apps::interchain_accounts::v1::CosmosTx,
+    };
+    use ibc_types::{
+        core::{
+            channel::{
+                packet::Sequence,
+                Version,
+            },
+            client::ClientId,
+            commitment::MerklePrefix,
+            connection::{
+                self,
+                Counterparty as ConnectionCounterparty,
+            },
+        },
+        timestamp::Timestamp,
+    };
+    use penumbra_ibc::component::connection::StateWriteExt as _;
+    use prost::{
+        Message as _,
+        Name as _,
+    };
+
+    use super::*;
+    use crate::{
+        accounts::StateWriteExt as _,
+        app::StateWriteExt as _,
+        ibc::{
+            ics27_host::{
+                self,
+                interchain_account_address,
+            },
+            port_router::PortRouter,
+            StateWriteExt as _,
+        },
+        test_utils::{
+            assert_error_contains,
+            dummy_ibc_client_state,
+            dummy_transfer,
+            nria,
+            Fixture,
+        },
+    };
+
+    /// Accepts all proofs, standing in for the counterparty's light client.
+    struct AcceptAllProofs;
+
+    #[async_trait::async_trait]
+    impl ProofVerifier for AcceptAllProofs {
+        async fn verify_channel_proof<S: StateRead>(
+            _: &S,
+            _: &ConnectionEnd,
+            _: &MerkleProof,
+            _: &Height,
+            _: &ChannelId,
+            _: &PortId,
+            _: &ChannelEnd,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        async fn verify_packet_recv_proof<S: StateRead>(
+            _: &S,
+            _: &ConnectionEnd,
+            _: &MsgRecvPacket,
+        ) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn host_port() -> PortId {
+        PortId(ics27_host::HOST_PORT.to_string())
+    }
+
+    fn controller_port() -> PortId {
+        PortId("icacontroller-dao".to_string())
+    }
+
+    fn proof() -> MerkleProof {
+        MerkleProof {
+            proofs: vec![],
+        }
+    }
+
+    fn proof_height() -> Height {
+        Height::new(0, 1).unwrap()
+    }
+
+    async fn init_fixture() -> Fixture {
+        let mut fixture = Fixture::default_initialized().await;
+        fixture.state_mut().put_block_height(1).unwrap();
+        fixture.state_mut().put_revision_number(1).unwrap();
+        let timestamp = tendermint::Time::from_unix_timestamp(1, 0).unwrap();
+        fixture.state_mut().put_block_timestamp(timestamp).unwrap();
+        fixture.state_mut().update_connection(
+            &ConnectionId::new(0),
+            ConnectionEnd {
+                state: ConnectionState::Open,
+                client_id: ClientId::default(),
+                counterparty: ConnectionCounterparty {
+                    client_id: ClientId::default(),
+                    connection_id: Some(ConnectionId::new(7)),
+                    prefix: MerklePrefix {
+                        key_prefix: b"ibc".to_vec(),
+                    },
+                },
+                versions: vec![connection::Version::default()],
+                delay_period: Duration::ZERO,
+            },
+        );
+        fixture
+    }
+
+    fn open_try(ordering: Order) -> MsgChannelOpenTry {
+        let version = serde_json::json!({
+            "version": "ics27-1",
+            "controller_connection_id": "connection-7",
+            "host_connection_id": "connection-0",
+            "address": "",
+            "encoding": "proto3",
+            "tx_type": "sdk_multi_msg",
+        })
+        .to_string();
+        MsgChannelOpenTry {
+            port_id_on_b: host_port(),
+            connection_hops_on_b: vec![ConnectionId::new(0)],
+            port_id_on_a: controller_port(),
+            chan_id_on_a: ChannelId::new(3),
+            version_supported_on_a: Version::new(version),
+            proof_chan_end_on_a: proof(),
+            proof_height_on_a: proof_height(),
+            ordering,
+            signer: String::new(),
+        }
+    }
+
+    fn open_confirm() -> MsgChannelOpenConfirm {
+        MsgChannelOpenConfirm {
+            port_id_on_b: host_port(),
+            chan_id_on_b: ChannelId::new(0),
+            proof_chan_end_on_a: proof(),
+            proof_height_on_a: proof_height(),
+            signer: String::new(),
+        }
+    }
+
+    fn recv_packet_msg(sequence: u64, tx: &CosmosTx) -> MsgRecvPacket {
+        let data = serde_json::json!({
+            "type": "TYPE_EXECUTE_TX",
+            "data": STANDARD.encode(tx.encode_to_vec()),
+            "memo": "",
+        })
+        .to_string()
+        .into_bytes();
+        MsgRecvPacket {
+            packet: Packet {
+                sequence: Sequence(sequence),
+                port_on_a: controller_port(),
+                chan_on_a: ChannelId::new(3),
+                port_on_b: host_port(),
+                chan_on_b: ChannelId::new(0),
+                data,
+                timeout_height_on_b: TimeoutHeight::Never,
+                timeout_timestamp_on_b: Timestamp {
+                    time: None,
+                },
+            },
+            proof_commitment_on_a: proof(),
+            proof_height_on_a: proof_height(),
+            signer: String::new(),
+        }
+    }
+
+    fn close_confirm() -> MsgChannelCloseConfirm {
+        MsgChannelCloseConfirm {
+            port_id_on_b: host_port(),
+            chan_id_on_b: ChannelId::new(0),
+            proof_chan_end_on_a: proof(),
+            proof_height_on_a: proof_height(),
+            signer: String::new(),
+            counterparty_upgrade_sequence: 0,
+        }
+    }
+
+    async fn channel_state(fixture: &Fixture) -> ChannelState {
+        get_channel(fixture.state(), &ChannelId::new(0), &host_port())
+            .await
+            .unwrap()
+            .state
+    }
+
+    async fn open_channel(fixture: &mut Fixture) {
+        let open_try = IbcRelay::ChannelOpenTry(open_try(Order::Ordered));
+        check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &open_try)
+            .await
+            .unwrap();
+        let open_confirm = IbcRelay::ChannelOpenConfirm(open_confirm());
+        check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &open_confirm)
+            .await
+            .unwrap();
+        assert_eq!(channel_state(fixture).await, ChannelState::Open);
+    }
+
+    #[tokio::test]
+    async fn open_channel_and_execute_packet() {
+        let mut fixture = init_fixture().await;
+
+        let open_try = IbcRelay::ChannelOpenTry(open_try(Order::Ordered));
+        check_stateless(&open_try).unwrap();
+        check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &open_try)
+            .await
+            .unwrap();
+        assert_eq!(channel_state(&fixture).await, ChannelState::TryOpen);
+
+        let open_confirm = IbcRelay::ChannelOpenConfirm(open_confirm());
+        check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &open_confirm)
+            .await
+            .unwrap();
+        assert_eq!(channel_state(&fixture).await, ChannelState::Open);
+
+        let interchain_account =
+            interchain_account_address(&ConnectionId::new(0), &controller_port());
+        fixture
+            .state_mut()
+            .increase_balance(&interchain_account, &nria(), 1_000)
+            .await
+            .unwrap();
+        fixture
+            .state_mut()
+            .ephemeral_put_ibc_context(TransactionId::new([0; 32]), 0);
+        let transfer = dummy_transfer();
+        let tx = CosmosTx {
+            messages: vec![Any {
+                type_url: raw::Transfer::type_url(),
+                value: transfer.to_raw().encode_to_vec(),
+            }],
+        };
+        let recv_packet = IbcRelay::RecvPacket(recv_packet_msg(1, &tx));
+        check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &recv_packet)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            fixture.get_nria_balance(&transfer.to).await,
+            transfer.amount
+        );
+        assert_eq!(
+            fixture
+                .state()
+                .get_recv_sequence(&ChannelId::new(0), &host_port())
+                .await
+                .unwrap(),
+            2
+        );
+
+        // The packet must not be received twice.
+        let err = check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &recv_packet)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "does not match next receive sequence `2`");
+    }
+
+    #[tokio::test]
+    async fn unordered_channels_are_rejected() {
+        let mut fixture = init_fixture().await;
+
+        let open_try = IbcRelay::ChannelOpenTry(open_try(Order::Unordered));
+        let err = check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &open_try)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "channel order must be ordered for ics27 host");
+    }
+
+    #[tokio::test]
+    async fn packets_are_rejected_before_channel_is_open() {
+        let mut fixture = init_fixture().await;
+
+        let open_try = IbcRelay::ChannelOpenTry(open_try(Order::Ordered));
+        check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &open_try)
+            .await
+            .unwrap();
+
+        let recv_packet = IbcRelay::RecvPacket(recv_packet_msg(1, &CosmosTx::default()));
+        let err = check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &recv_packet)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "channel `channel-0` is not open");
+    }
+
+    #[tokio::test]
+    async fn replayed_handshake_messages_do_not_affect_open_channel() {
+        let mut fixture = init_fixture().await;
+        open_channel(&mut fixture).await;
+
+        let open_confirm = IbcRelay::ChannelOpenConfirm(open_confirm());
+        let err = check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &open_confirm)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "channel `channel-0` is not in the try open state");
+
+        // A replayed `ChanOpenTry` allocates a new channel, which cannot be confirmed with a proof
+        // of the counterparty channel bound to `channel-0`.
+        let open_try = IbcRelay::ChannelOpenTry(open_try(Order::Ordered));
+        check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &open_try)
+            .await
+            .unwrap();
+        assert_eq!(channel_state(&fixture).await, ChannelState::Open);
+        assert_eq!(
+            get_channel(fixture.state(), &ChannelId::new(1), &host_port())
+                .await
+                .unwrap()
+                .state,
+            ChannelState::TryOpen
+        );
+    }
+
+    #[tokio::test]
+    async fn packets_from_wrong_counterparty_are_rejected() {
+        let mut fixture = init_fixture().await;
+        open_channel(&mut fixture).await;
+
+        let mut wrong_channel = recv_packet_msg(1, &CosmosTx::default());
+        wrong_channel.packet.chan_on_a = ChannelId::new(4);
+        let err = check_and_execute::<AcceptAllProofs, _>(
+            fixture.state_mut(),
+            &IbcRelay::RecvPacket(wrong_channel),
+        )
+        .await
+        .unwrap_err();
+        assert_error_contains(
+            &err,
+            "packet source `icacontroller-dao/channel-4` does not match the counterparty of \
+             channel `channel-0`",
+        );
+
+        let mut wrong_port = recv_packet_msg(1, &CosmosTx::default());
+        wrong_port.packet.port_on_a = PortId("icacontroller-other".to_string());
+        let err = check_and_execute::<AcceptAllProofs, _>(
+            fixture.state_mut(),
+            &IbcRelay::RecvPacket(wrong_port),
+        )
+        .await
+        .unwrap_err();
+        assert_error_contains(
+            &err,
+            "packet source `icacontroller-other/channel-3` does not match the counterparty of \
+             channel `channel-0`",
+        );
+
+        assert_eq!(
+            fixture
+                .state()
+                .get_recv_sequence(&ChannelId::new(0), &host_port())
+                .await
+                .unwrap(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn proofs_at_height_without_consensus_state_are_rejected() {
+        let mut fixture = init_fixture().await;
+        fixture
+            .init_active_ibc_client(&ClientId::default(), dummy_ibc_client_state(5))
+            .await;
+
+        // The client only has a consensus state at its latest height.
+        let stale_open_try = MsgChannelOpenTry {
+            proof_height_on_a: Height::new(2, 3).unwrap(),
+            ..open_try(Order::Ordered)
+        };
+        let err = check_and_execute::<PenumbraProofVerifier, _>(
+            fixture.state_mut(),
+            &IbcRelay::ChannelOpenTry(stale_open_try),
+        )
+        .await
+        .unwrap_err();
+        assert_error_contains(&err, "failed to verify proof of counterparty channel");
+        assert_error_contains(&err, "counterparty consensus state not found");
+
+        let err = get_channel(fixture.state(), &ChannelId::new(0), &host_port())
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "channel `icahost/channel-0` not found");
+    }
+
+    #[tokio::test]
+    async fn closed_channel_is_not_reopened_but_replaced() {
+        let mut fixture = init_fixture().await;
+        open_channel(&mut fixture).await;
+
+        let close_confirm = IbcRelay::ChannelCloseConfirm(close_confirm());
+        check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &close_confirm)
+            .await
+            .unwrap();
+        assert_eq!(channel_state(&fixture).await, ChannelState::Closed);
+
+        let err = check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &close_confirm)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "channel `channel-0` is already closed");
+        let open_confirm = IbcRelay::ChannelOpenConfirm(open_confirm());
+        let err = check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &open_confirm)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "channel `channel-0` is not in the try open state");
+        let recv_packet = IbcRelay::RecvPacket(recv_packet_msg(1, &CosmosTx::default()));
+        let err = check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &recv_packet)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "channel `channel-0` is not open");
+
+        // Reopening the interchain account opens a new channel, which executes packets for the
+        // same interchain account.
+        let open_try = IbcRelay::ChannelOpenTry(open_try(Order::Ordered));
+        check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &open_try)
+            .await
+            .unwrap();
+        let open_confirm = IbcRelay::ChannelOpenConfirm(MsgChannelOpenConfirm {
+            chan_id_on_b: ChannelId::new(1),
+            ..open_confirm()
+        });
+        check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &open_confirm)
+            .await
+            .unwrap();
+        assert_eq!(channel_state(&fixture).await, ChannelState::Closed);
+
+        let interchain_account =
+            interchain_account_address(&ConnectionId::new(0), &controller_port());
+        fixture
+            .state_mut()
+            .increase_balance(&interchain_account, &nria(), 1_000)
+            .await
+            .unwrap();
+        fixture
+            .state_mut()
+            .ephemeral_put_ibc_context(TransactionId::new([0; 32]), 0);
+        let transfer = dummy_transfer();
+        let tx = CosmosTx {
+            messages: vec![Any {
+                type_url: raw::Transfer::type_url(),
+                value: transfer.to_raw().encode_to_vec(),
+            }],
+        };
+        let mut packet = recv_packet_msg(1, &tx);
+        packet.packet.chan_on_b = ChannelId::new(1);
+        check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &IbcRelay::RecvPacket(packet))
+            .await
+            .unwrap();
+        assert_eq!(
+            fixture.get_nria_balance(&transfer.to).await,
+            transfer.amount
+        );
+    }
+
+    #[tokio::test]
+    async fn messages_are_rejected_before_aspen() {
+        let mut fixture = Fixture::uninitialized(None).await;
+        fixture.chain_initializer().init().await;
+        assert!(use_pre_aspen_validator_updates(fixture.state())
+            .await
+            .unwrap());
+
+        let open_try = IbcRelay::ChannelOpenTry(open_try(Order::Ordered));
+        let err = check_and_execute::<AcceptAllProofs, _>(fixture.state_mut(), &open_try)
+            .await
+            .unwrap_err();
+        assert_error_contains(
+            &err,
+            "ics27 interchain accounts are not available before the Aspen upgrade",
+        );
+    }
+
+    #[tokio::test]
+    async fn port_router_routes_host_port_messages_past_penumbra() {
+        let mut fixture = Fixture::default_initialized().await;
+
+        let transfer_open_try = MsgChannelOpenTry {
+            port_id_on_b: PortId::transfer(),
+            ..open_try(Order::Unordered)
+        };
+        assert!(matches!(
+            PortRouter::new(IbcRelay::ChannelOpenTry(transfer_open_try)),
+            PortRouter::Penumbra(_),
+        ));
+
+        let router = PortRouter::new(IbcRelay::ChannelOpenTry(open_try(Order::Ordered)));
+        assert!(matches!(router, PortRouter::Ics27Host(_)));
+        // Penumbra would reject the message with "invalid port id"; instead it fails as no
+        // connection has been opened.
+        let err = router
+            .check_and_execute(fixture.state_mut())
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "connection `connection-0` not found");
+    }
+}
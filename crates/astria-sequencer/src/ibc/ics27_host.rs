@@ -0,0 +1,700 @@
+//! This module implements the host side of ICS27 interchain accounts.
+//!
+//! A controller chain opens an ordered channel from its controller port to the `icahost` port of
+//! Sequencer. Packets received over the channel carry a `CosmosTx` of protobuf encoded Sequencer
+//! actions, which are executed on behalf of the interchain account of the controller. Only the
+//! actions listed in [`CheckedInterchainAccountAction`] are allowed, namely transfers, bridge
+//! locks and rollup data submissions. Rollup data submitted by an interchain account is included
+//! in the block's rollup data after the data submitted in the block's transactions.
+//!
+//! The interchain account of a controller is derived from the host connection of the channel and
+//! the controller port, so that it is retained when a controller reopens a closed channel. It
+//! pays the fees of the actions it executes. The actions of a packet are executed atomically: if
+//! any action fails, none of them take effect and an error acknowledgement is written.
+//!
+//! Channel handshakes and packets of the `icahost` port are passed to [`Ics27Host`] by
+//! [`super::ics27_channel`], as Penumbra's IBC message handlers only serve the `transfer` port.
+
+use astria_core::{
+    crypto::ADDRESS_LENGTH,
+    generated::astria::protocol::transaction::v1 as raw,
+    primitive::v1::{
+        TransactionId,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1::action::{
+        BridgeLock,
+        RollupDataSubmission,
+        Transfer,
+    },
+    Protobuf as _,
+};
+use astria_eyre::{
+    anyhow::{
+        self,
+        Context as _,
+    },
+    anyhow_to_eyre,
+    eyre::{
+        bail,
+        ensure,
+        OptionExt as _,
+        Result,
+        WrapErr as _,
+    },
+    eyre_to_anyhow,
+};
+use base64::{
+    engine::general_purpose::STANDARD,
+    Engine as _,
+};
+use cnidarium::{
+    StateDelta,
+    StateRead,
+    StateWrite,
+};
+use ibc_proto::{
+    google::protobuf::Any,
+    ibc::apps::interchain_accounts::v1::CosmosTx,
+};
+use ibc_types::{
+    core::{
+        channel::{
+            channel,
+            msgs::{
+                MsgAcknowledgement,
+                MsgChannelCloseConfirm,
+                MsgChannelCloseInit,
+                MsgChannelOpenAck,
+                MsgChannelOpenConfirm,
+                MsgChannelOpenInit,
+                MsgChannelOpenTry,
+                MsgRecvPacket,
+                MsgTimeout,
+            },
+            Packet,
+            PortId,
+        },
+        connection::ConnectionId,
+    },
+    transfer::acknowledgement::TokenTransferAcknowledgement,
+};
+use penumbra_ibc::component::{
+    app_handler::{
+        AppHandler,
+        AppHandlerCheck,
+        AppHandlerExecute,
+    },
+    ChannelStateReadExt as _,
+};
+use prost::{
+    Message as _,
+    Name as _,
+};
+use serde::Deserialize;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use crate::{
+    checked_actions::CheckedInterchainAccountAction,
+    ibc::StateReadExt as _,
+};
+
+/// The port of the interchain accounts host.
+pub(crate) const HOST_PORT: &str = "icahost";
+
+/// The version of the interchain accounts protocol.
+const VERSION: &str = "ics27-1";
+
+/// The only supported encoding of the messages of a `CosmosTx`.
+const ENCODING: &str = "proto3";
+
+/// The only supported transaction type.
+const TX_TYPE: &str = "sdk_multi_msg";
+
+/// The only supported interchain account packet type.
+const PACKET_TYPE_EXECUTE_TX: &str = "TYPE_EXECUTE_TX";
+
+/// The maximum length of the encoded `InterchainAccountPacketData` in bytes.
+const MAX_PACKET_DATA_BYTE_LENGTH: usize = 65_536;
+
+/// The channel version negotiated by the controller, as specified in ICS27.
+#[derive(Debug, Deserialize)]
+struct Metadata {
+    version: String,
+    controller_connection_id: String,
+    host_connection_id: String,
+    encoding: String,
+    tx_type: String,
+}
+
+/// The data of packets sent by the controller, as specified in ICS27.
+#[derive(Debug, Deserialize)]
+struct InterchainAccountPacketData {
+    #[serde(rename = "type")]
+    packet_type: String,
+    /// The base64 encoded `CosmosTx`.
+    data: String,
+}
+
+/// Ensures the version proposed by the controller for a channel on `host_connection` is
+/// supported.
+fn validate_metadata(version: &str, host_connection: Option<&ConnectionId>) -> Result<()> {
+    let metadata: Metadata = serde_json::from_str(version)
+        .wrap_err("failed to parse channel version as ics27 metadata")?;
+    ensure!(
+        metadata.version == VERSION,
+        "ics27 version must be `{VERSION}`, got `{}`",
+        metadata.version,
+    );
+    ensure!(
+        metadata.encoding == ENCODING,
+        "ics27 encoding must be `{ENCODING}`, got `{}`",
+        metadata.encoding,
+    );
+    ensure!(
+        metadata.tx_type == TX_TYPE,
+        "ics27 tx type must be `{TX_TYPE}`, got `{}`",
+        metadata.tx_type,
+    );
+    ensure!(
+        !metadata.controller_connection_id.is_empty(),
+        "ics27 controller connection id must be set",
+    );
+    let host_connection = host_connection.ok_or_eyre("channel must have a connection hop")?;
+    ensure!(
+        metadata.host_connection_id == host_connection.as_str(),
+        "ics27 host connection id `{}` does not match channel connection `{host_connection}`",
+        metadata.host_connection_id,
+    );
+    Ok(())
+}
+
+/// Returns the address of the interchain account owned by `controller_port` on the other end of
+/// `host_connection`.
+pub(crate) fn interchain_account_address(
+    host_connection: &ConnectionId,
+    controller_port: &PortId,
+) -> [u8; ADDRESS_LEN] {
+    let hash = Sha256::digest(format!("ics27-host/{host_connection}/{controller_port}"));
+    let mut address = [0; ADDRESS_LEN];
+    address.copy_from_slice(&hash[..ADDRESS_LEN]);
+    address
+}
+
+/// The ICS27 interchain accounts host handler.
+///
+/// See [here](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md)
+/// for the specification which this is based on.
+#[derive(Clone)]
+pub(crate) struct Ics27Host;
+
+#[async_trait::async_trait]
+impl AppHandlerCheck for Ics27Host {
+    #[instrument(skip_all)]
+    async fn chan_open_init_check<S: StateRead>(
+        _: S,
+        _: &MsgChannelOpenInit,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("ics27 host does not initiate channels");
+    }
+
+    #[instrument(skip_all)]
+    async fn chan_open_try_check<S: StateRead>(
+        _: S,
+        msg: &MsgChannelOpenTry,
+    ) -> anyhow::Result<()> {
+        if msg.ordering != channel::Order::Ordered {
+            anyhow::bail!("channel order must be ordered for ics27 host");
+        }
+
+        validate_metadata(
+            msg.version_supported_on_a.as_str(),
+            msg.connection_hops_on_b.first(),
+        )
+        .map_err(eyre_to_anyhow)
+    }
+
+    #[instrument(skip_all)]
+    async fn chan_open_ack_check<S: StateRead>(_: S, _: &MsgChannelOpenAck) -> anyhow::Result<()> {
+        anyhow::bail!("ics27 host does not initiate channels");
+    }
+
+    #[instrument(skip_all)]
+    async fn chan_open_confirm_check<S: StateRead>(
+        _: S,
+        _: &MsgChannelOpenConfirm,
+    ) -> anyhow::Result<()> {
+        // accept channel confirmations, port has already been validated, version has already been
+        // validated
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn chan_close_init_check<S: StateRead>(
+        _: S,
+        _: &MsgChannelCloseInit,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("ics27 host always aborts on chan_close_init");
+    }
+
+    #[instrument(skip_all)]
+    async fn chan_close_confirm_check<S: StateRead>(
+        _: S,
+        _: &MsgChannelCloseConfirm,
+    ) -> anyhow::Result<()> {
+        // no action needed
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn recv_packet_check<S: StateRead>(_: S, msg: &MsgRecvPacket) -> anyhow::Result<()> {
+        // most checks performed in `execute`
+        // perform stateless checks here
+        if msg.packet.data.is_empty() {
+            anyhow::bail!("packet data is empty");
+        }
+
+        if msg.packet.data.len() > MAX_PACKET_DATA_BYTE_LENGTH {
+            anyhow::bail!("packet data is too long: exceeds MAX_PACKET_DATA_BYTE_LENGTH");
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn timeout_packet_check<S: StateRead>(_: S, _: &MsgTimeout) -> anyhow::Result<()> {
+        anyhow::bail!("ics27 host does not send packets");
+    }
+
+    #[instrument(skip_all)]
+    async fn acknowledge_packet_check<S: StateRead>(
+        _: S,
+        _: &MsgAcknowledgement,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("ics27 host does not send packets");
+    }
+}
+
+#[async_trait::async_trait]
+impl AppHandlerExecute for Ics27Host {
+    async fn chan_open_init_execute<S: StateWrite>(_: S, _: &MsgChannelOpenInit) {}
+
+    async fn chan_open_try_execute<S: StateWrite>(_: S, _: &MsgChannelOpenTry) {}
+
+    async fn chan_open_ack_execute<S: StateWrite>(_: S, _: &MsgChannelOpenAck) {}
+
+    async fn chan_open_confirm_execute<S: StateWrite>(_: S, _: &MsgChannelOpenConfirm) {}
+
+    async fn chan_close_confirm_execute<S: StateWrite>(_: S, _: &MsgChannelCloseConfirm) {}
+
+    async fn chan_close_init_execute<S: StateWrite>(_: S, _: &MsgChannelCloseInit) {}
+
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    async fn recv_packet_execute<S: StateWrite>(
+        mut state: S,
+        msg: &MsgRecvPacket,
+    ) -> anyhow::Result<()> {
+        use penumbra_ibc::component::packet::WriteAcknowledgement as _;
+
+        // ICS27 acknowledgements use the same JSON encoding as ICS20 acknowledgements.
+        let ack = {
+            // Execute the transaction on a fork of the state so that a failing action discards
+            // the effects of the preceding ones.
+            let mut delta = StateDelta::new(&mut state);
+            match execute_packet(&mut delta, &msg.packet).await {
+                Ok(()) => {
+                    let (state, events) = delta.apply();
+                    for event in events {
+                        state.record(event);
+                    }
+                    TokenTransferAcknowledgement::success()
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                        "failed to execute ics27 transaction"
+                    );
+                    TokenTransferAcknowledgement::Error("ics27 transaction failed".to_string())
+                }
+            }
+        };
+
+        let ack_bytes: Vec<u8> = ack.into();
+
+        state
+            .write_acknowledgement(&msg.packet, &ack_bytes)
+            .await
+            .context("failed to write acknowledgement")
+    }
+
+    async fn timeout_packet_execute<S: StateWrite>(_: S, _: &MsgTimeout) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn acknowledge_packet_execute<S: StateWrite>(
+        _: S,
+        _: &MsgAcknowledgement,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl AppHandler for Ics27Host {}
+
+#[instrument(
+    skip_all,
+    fields(
+        controller_port = %packet.port_on_a,
+        host_channel = %packet.chan_on_b,
+        sequence = %packet.sequence,
+    ),
+    err,
+)]
+async fn execute_packet<S: StateWrite>(mut state: S, packet: &Packet) -> Result<()> {
+    let packet_data: InterchainAccountPacketData = serde_json::from_slice(&packet.data)
+        .wrap_err("failed to decode interchain account packet data json")?;
+    ensure!(
+        packet_data.packet_type == PACKET_TYPE_EXECUTE_TX,
+        "unsupported interchain account packet type `{}`",
+        packet_data.packet_type,
+    );
+    let tx_bytes = STANDARD
+        .decode(&packet_data.data)
+        .wrap_err("failed to decode packet data as base64")?;
+    let tx = CosmosTx::decode(&*tx_bytes).wrap_err("failed to decode packet data as cosmos tx")?;
+
+    let channel = state
+        .get_channel(&packet.chan_on_b, &packet.port_on_b)
+        .await
+        .map_err(anyhow_to_eyre)
+        .wrap_err("failed to read host channel from state")?
+        .ok_or_eyre("host channel not found in state")?;
+    let host_connection = channel
+        .connection_hops
+        .first()
+        .ok_or_eyre("host channel has no connection hop")?;
+    let interchain_account = interchain_account_address(host_connection, &packet.port_on_a);
+
+    execute_tx(&mut state, interchain_account, tx).await
+}
+
+/// Checks and executes all messages of `tx` on behalf of `interchain_account`.
+async fn execute_tx<S: StateWrite>(
+    mut state: S,
+    interchain_account: [u8; ADDRESS_LENGTH],
+    tx: CosmosTx,
+) -> Result<()> {
+    ensure!(!tx.messages.is_empty(), "cosmos tx contains no messages");
+    let context = state
+        .ephemeral_get_ibc_context()
+        .ok_or_eyre("failed to get the ibc context from ephemeral store")?;
+
+    // Run the checks of all actions before executing any of them.
+    let mut checked_actions = Vec::with_capacity(tx.messages.len());
+    for (index, message) in tx.messages.into_iter().enumerate() {
+        let position_in_tx = message_position_in_tx(context.source_action_index, index)?;
+        let checked_action = check_message(
+            &state,
+            message,
+            interchain_account,
+            context.tx_id,
+            position_in_tx,
+        )
+        .await
+        .wrap_err_with(|| format!("check failed for message at index `{index}`"))?;
+        checked_actions.push((position_in_tx, checked_action));
+    }
+
+    for (index, (position_in_tx, checked_action)) in checked_actions.iter().enumerate() {
+        checked_action
+            .pay_fees_and_execute(&mut state, &interchain_account, *position_in_tx)
+            .await
+            .wrap_err_with(|| format!("execution failed for message at index `{index}`"))?;
+    }
+    Ok(())
+}
+
+/// Returns the position of the message at `message_index` of a `CosmosTx` received by the IBC
+/// relay action at `source_action_index` of a Sequencer transaction.
+///
+/// Every message is given its own position so that the deposits and fee events it emits are
+/// distinguishable from those of the other messages of the same packet. The highest bit is set so
+/// that the positions never collide with the indices of the actions of the transaction itself.
+fn message_position_in_tx(source_action_index: u64, message_index: usize) -> Result<u64> {
+    const ACTION_INDEX_LIMIT: u64 = 1 << 31;
+    ensure!(
+        source_action_index < ACTION_INDEX_LIMIT,
+        "source action index `{source_action_index}` is too large for an ics27 message position",
+    );
+    let message_index =
+        u32::try_from(message_index).wrap_err("cosmos tx contains too many messages")?;
+    Ok((1 << 63) | (source_action_index << 32) | u64::from(message_index))
+}
+
+/// Decodes `message` as one of the actions allowed for interchain accounts and checks it.
+async fn check_message<S: StateRead>(
+    state: &S,
+    message: Any,
+    interchain_account: [u8; ADDRESS_LENGTH],
+    tx_id: TransactionId,
+    position_in_tx: u64,
+) -> Result<CheckedInterchainAccountAction> {
+    let checked_action = if message.type_url == raw::Transfer::type_url() {
+        let raw = raw::Transfer::decode(&*message.value)
+            .wrap_err("failed to decode message as transfer")?;
+        let action = Transfer::try_from_raw(raw).wrap_err("failed to convert transfer")?;
+        CheckedInterchainAccountAction::new_transfer(action, interchain_account, state).await?
+    } else if message.type_url == raw::BridgeLock::type_url() {
+        let raw = raw::BridgeLock::decode(&*message.value)
+            .wrap_err("failed to decode message as bridge lock")?;
+        let action = BridgeLock::try_from_raw(raw).wrap_err("failed to convert bridge lock")?;
+        CheckedInterchainAccountAction::new_bridge_lock(
+            action,
+            interchain_account,
+            tx_id,
+            position_in_tx,
+            state,
+        )
+        .await?
+    } else if message.type_url == raw::RollupDataSubmission::type_url() {
+        let raw = raw::RollupDataSubmission::decode(&*message.value)
+            .wrap_err("failed to decode message as rollup data submission")?;
+        let action = RollupDataSubmission::try_from_raw(raw)
+            .wrap_err("failed to convert rollup data submission")?;
+        CheckedInterchainAccountAction::new_rollup_data_submission(action)?
+    } else {
+        bail!(
+            "message type `{}` is not allowed for interchain accounts",
+            message.type_url
+        );
+    };
+    Ok(checked_action)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{
+        accounts::StateWriteExt as _,
+        bridge::StateReadExt as _,
+        fees::StateReadExt as _,
+        ibc::StateWriteExt as _,
+        test_utils::{
+            assert_error_contains,
+            dummy_bridge_lock,
+            dummy_bridge_unlock,
+            dummy_rollup_data_submission,
+            dummy_transfer,
+            nria,
+            Fixture,
+        },
+    };
+
+    fn metadata(host_connection_id: &str) -> String {
+        serde_json::json!({
+            "version": "ics27-1",
+            "controller_connection_id": "connection-7",
+            "host_connection_id": host_connection_id,
+            "address": "",
+            "encoding": "proto3",
+            "tx_type": "sdk_multi_msg",
+        })
+        .to_string()
+    }
+
+    fn any<T: prost::Name>(message: &T) -> Any {
+        Any {
+            type_url: T::type_url(),
+            value: message.encode_to_vec(),
+        }
+    }
+
+    #[test]
+    fn metadata_must_match_host_connection() {
+        let connection = ConnectionId::new(0);
+        validate_metadata(&metadata("connection-0"), Some(&connection)).unwrap();
+
+        let err = validate_metadata(&metadata("connection-1"), Some(&connection)).unwrap_err();
+        assert_error_contains(&err, "does not match channel connection `connection-0`");
+
+        let err = validate_metadata("ics20-1", Some(&connection)).unwrap_err();
+        assert_error_contains(&err, "failed to parse channel version as ics27 metadata");
+    }
+
+    #[test]
+    fn interchain_account_depends_on_connection_and_controller_port() {
+        let port: PortId = "icacontroller-dao".parse().unwrap();
+        let other_port: PortId = "icacontroller-other".parse().unwrap();
+        let account = interchain_account_address(&ConnectionId::new(0), &port);
+        assert_eq!(
+            account,
+            interchain_account_address(&ConnectionId::new(0), &port)
+        );
+        assert_ne!(
+            account,
+            interchain_account_address(&ConnectionId::new(1), &port)
+        );
+        assert_ne!(
+            account,
+            interchain_account_address(&ConnectionId::new(0), &other_port)
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_tx_pays_fees_and_executes_transfers() {
+        let mut fixture = Fixture::default_initialized().await;
+        let interchain_account = [7; ADDRESS_LENGTH];
+        let transfer = dummy_transfer();
+        let fees = fixture
+            .state()
+            .get_fees::<Transfer>()
+            .await
+            .unwrap()
+            .map_or(0, |fees| fees.base());
+        fixture
+            .state_mut()
+            .increase_balance(&interchain_account, &nria(), 1_000)
+            .await
+            .unwrap();
+        fixture
+            .state_mut()
+            .ephemeral_put_ibc_context(TransactionId::new([0; 32]), 0);
+
+        let tx = CosmosTx {
+            messages: vec![any(&transfer.to_raw()), any(&transfer.to_raw())],
+        };
+        execute_tx(fixture.state_mut(), interchain_account, tx)
+            .await
+            .unwrap();
+
+        let amount = transfer.amount.checked_mul(2).unwrap();
+        let total_fees = fees.checked_mul(2).unwrap();
+        assert_eq!(fixture.get_nria_balance(&transfer.to).await, amount);
+        assert_eq!(
+            fixture.get_nria_balance(&interchain_account).await,
+            1_000 - amount - total_fees,
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_tx_caches_rollup_data_submissions() {
+        let mut fixture = Fixture::default_initialized().await;
+        let interchain_account = [7; ADDRESS_LENGTH];
+        let rollup_data_submission = dummy_rollup_data_submission();
+        let cost = fixture
+            .calculate_rollup_data_submission_cost(&rollup_data_submission.data)
+            .await;
+        fixture
+            .state_mut()
+            .increase_balance(&interchain_account, &nria(), 1_000)
+            .await
+            .unwrap();
+        fixture
+            .state_mut()
+            .ephemeral_put_ibc_context(TransactionId::new([0; 32]), 0);
+
+        let tx = CosmosTx {
+            messages: vec![any(&rollup_data_submission.to_raw())],
+        };
+        execute_tx(fixture.state_mut(), interchain_account, tx)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            fixture.state().get_cached_block_interchain_rollup_data(),
+            vec![(
+                rollup_data_submission.rollup_id,
+                rollup_data_submission.data
+            )],
+        );
+        assert_eq!(
+            fixture.get_nria_balance(&interchain_account).await,
+            1_000 - cost,
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_tx_gives_each_message_its_own_position() {
+        let mut fixture = Fixture::default_initialized().await;
+        let interchain_account = [7; ADDRESS_LENGTH];
+        let bridge_lock = dummy_bridge_lock();
+        fixture.bridge_initializer(bridge_lock.to).init().await;
+        fixture
+            .state_mut()
+            .increase_balance(&interchain_account, &nria(), 10_000)
+            .await
+            .unwrap();
+        fixture
+            .state_mut()
+            .ephemeral_put_ibc_context(TransactionId::new([0; 32]), 2);
+
+        let tx = CosmosTx {
+            messages: vec![any(&bridge_lock.to_raw()), any(&bridge_lock.to_raw())],
+        };
+        execute_tx(fixture.state_mut(), interchain_account, tx)
+            .await
+            .unwrap();
+
+        let source_action_indices: Vec<_> = fixture
+            .state()
+            .get_cached_block_deposits()
+            .into_values()
+            .flatten()
+            .map(|deposit| deposit.source_action_index)
+            .collect();
+        assert_eq!(
+            source_action_indices,
+            vec![
+                message_position_in_tx(2, 0).unwrap(),
+                message_position_in_tx(2, 1).unwrap(),
+            ],
+        );
+    }
+
+    #[test]
+    fn message_positions_do_not_collide_with_action_indices() {
+        assert_eq!(message_position_in_tx(0, 0).unwrap(), 1 << 63);
+        assert_eq!(
+            message_position_in_tx(2, 1).unwrap(),
+            (1 << 63) | (2 << 32) | 1
+        );
+        assert_ne!(
+            message_position_in_tx(1, 0).unwrap(),
+            message_position_in_tx(0, 1).unwrap()
+        );
+
+        let err = message_position_in_tx(1 << 31, 0).unwrap_err();
+        assert_error_contains(&err, "is too large for an ics27 message position");
+    }
+
+    #[tokio::test]
+    async fn execute_tx_rejects_actions_not_on_allowlist() {
+        let mut fixture = Fixture::default_initialized().await;
+        fixture
+            .state_mut()
+            .ephemeral_put_ibc_context(TransactionId::new([0; 32]), 0);
+
+        let tx = CosmosTx {
+            messages: vec![
+                any(&dummy_transfer().to_raw()),
+                any(&dummy_bridge_unlock().to_raw()),
+            ],
+        };
+        let err = execute_tx(fixture.state_mut(), [7; ADDRESS_LENGTH], tx)
+            .await
+            .unwrap_err();
+        assert_error_contains(
+            &err,
+            "message type `/astria.protocol.transaction.v1.BridgeUnlock` is not allowed",
+        );
+    }
+}
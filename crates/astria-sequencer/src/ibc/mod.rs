@@ -1,6 +1,12 @@
 pub(crate) mod component;
 pub(crate) mod host_interface;
 pub(crate) mod ics20_transfer;
+pub(crate) mod ics27_channel;
+pub(crate) mod ics27_host;
+pub(crate) mod packet_forward;
+pub(crate) mod port_router;
+pub(crate) mod query;
+pub(crate) mod rate_limit;
 pub(crate) mod storage;
 
 mod state_ext;
@@ -0,0 +1,942 @@
+//! Support for forwarding received ICS20 transfers over another channel.
+//!
+//! A transfer is forwarded if its memo follows the format of the packet forward middleware, for
+//! example:
+//!
+//! ```json
+//! {
+//!   "forward": {
+//!     "receiver": "noble1...",
+//!     "port": "transfer",
+//!     "channel": "channel-1",
+//!     "timeout": "10m",
+//!     "retries": 2,
+//!     "next": { "forward": { ... } }
+//!   }
+//! }
+//! ```
+//!
+//! Instead of crediting the receiver of the inbound packet, the received tokens are sent to
+//! `receiver` over `channel` with `next` as the memo of the outbound packet. The acknowledgement of
+//! the inbound packet is deferred until the outbound packet is acknowledged or times out. A timed
+//! out outbound packet is resent up to `retries` times. If the outbound packet fails, the escrow
+//! accounting of both the inbound and outbound transfer is reverted and an error acknowledgement is
+//! written for the inbound packet, so that the tokens are refunded on the originating chain.
+use std::time::Duration;
+
+use astria_core::primitive::v1::{
+    asset::denom,
+    Address,
+    ADDRESS_LEN,
+};
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{
+        bail,
+        ensure,
+        OptionExt as _,
+        Result,
+        WrapErr as _,
+    },
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use ibc_types::{
+    core::{
+        channel::{
+            packet::Sequence,
+            ChannelId,
+            Packet,
+            PortId,
+        },
+        client::Height as IbcHeight,
+    },
+    transfer::acknowledgement::TokenTransferAcknowledgement,
+};
+use penumbra_ibc::component::{
+    packet::{
+        Checked,
+        IBCPacket,
+        SendPacketRead as _,
+        SendPacketWrite as _,
+        WriteAcknowledgement as _,
+    },
+    ChannelStateReadExt as _,
+};
+use penumbra_proto::penumbra::core::component::ibc::v1::FungibleTokenPacketData;
+use serde::Deserialize;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::ics20_transfer::{
+    is_refund_source_zone,
+    is_transfer_source_zone,
+    parse_asset,
+};
+use crate::{
+    address::StateReadExt as _,
+    app::StateReadExt as _,
+    assets::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    ibc::{
+        rate_limit,
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+};
+
+/// The timeout of a forwarded packet if the memo does not specify one.
+const DEFAULT_FORWARD_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// The number of times a timed out forwarded packet is resent if the memo does not specify it.
+const DEFAULT_FORWARD_RETRIES: u8 = 3;
+
+/// The error acknowledgement written for an inbound packet whose forwarding failed.
+const FORWARD_FAILED_ACKNOWLEDGEMENT: &str = "ics20 transfer failed";
+
+/// The `forward` object of a packet forward memo, as encoded in JSON.
+#[derive(Debug, Deserialize)]
+struct RawForward {
+    receiver: String,
+    port: String,
+    channel: String,
+    #[serde(default)]
+    timeout: Option<RawTimeout>,
+    #[serde(default)]
+    retries: Option<u8>,
+    #[serde(default)]
+    next: Option<serde_json::Value>,
+}
+
+/// A timeout given either as a number of nanoseconds or as a Go duration string like `"10m"`.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RawTimeout {
+    Nanos(u64),
+    Text(String),
+}
+
+/// The instructions to forward a received ICS20 transfer over another channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct Forward {
+    pub(super) receiver: String,
+    pub(super) port: PortId,
+    pub(super) channel: ChannelId,
+    pub(super) timeout: Duration,
+    pub(super) retries: u8,
+    /// The memo of the forwarded packet.
+    pub(super) next: String,
+}
+
+/// A forwarded packet awaiting its acknowledgement or timeout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct InFlightForward {
+    /// The received packet whose tokens were forwarded.
+    pub(crate) inbound_packet: Packet,
+    /// The number of times the forwarded packet is resent if it times out.
+    pub(crate) retries_remaining: u8,
+    /// The timeout of the forwarded packet relative to the block time at which it is sent.
+    pub(crate) timeout: Duration,
+    /// The height at which the received transfer was recorded against the inbound channel's rate
+    /// limit, if it is rate limited.
+    pub(crate) inflow_recorded_at: Option<u64>,
+    /// The height at which the forwarded transfer was recorded against the outbound channel's
+    /// rate limit, if it is rate limited.
+    pub(crate) outflow_recorded_at: Option<u64>,
+}
+
+/// Parses `memo` as a packet forward memo.
+///
+/// Returns `None` if `memo` is not a JSON object with a `forward` field.
+///
+/// # Errors
+///
+/// Returns an error if the `forward` field is present but not a valid instruction to forward.
+pub(super) fn parse_memo(memo: &str) -> Result<Option<Forward>> {
+    let Ok(serde_json::Value::Object(mut fields)) = serde_json::from_str(memo) else {
+        return Ok(None);
+    };
+    let Some(raw) = fields.remove("forward") else {
+        return Ok(None);
+    };
+    let raw: RawForward =
+        serde_json::from_value(raw).wrap_err("failed to parse `forward` field of memo")?;
+
+    ensure!(!raw.receiver.is_empty(), "forward receiver must be set");
+    let port: PortId = raw.port.parse().wrap_err("failed to parse forward port")?;
+    ensure!(
+        port == PortId::transfer(),
+        "only forwarding over the `{}` port is supported",
+        PortId::transfer(),
+    );
+    let channel: ChannelId = raw
+        .channel
+        .parse()
+        .wrap_err("failed to parse forward channel")?;
+    let timeout = match raw.timeout {
+        None => DEFAULT_FORWARD_TIMEOUT,
+        Some(RawTimeout::Nanos(nanos)) => Duration::from_nanos(nanos),
+        Some(RawTimeout::Text(text)) => {
+            parse_duration(&text).wrap_err("failed to parse forward timeout")?
+        }
+    };
+    ensure!(!timeout.is_zero(), "forward timeout must be non-zero");
+    let next = match raw.next {
+        None | Some(serde_json::Value::Null) => String::new(),
+        Some(serde_json::Value::String(next)) => next,
+        Some(next @ serde_json::Value::Object(_)) => next.to_string(),
+        Some(_) => bail!("forward `next` field must be a JSON object or string"),
+    };
+
+    Ok(Some(Forward {
+        receiver: raw.receiver,
+        port,
+        channel,
+        timeout,
+        retries: raw.retries.unwrap_or(DEFAULT_FORWARD_RETRIES),
+        next,
+    }))
+}
+
+/// Parses a Go duration string made up of unsigned integers with units, like `"1h30m"`.
+fn parse_duration(input: &str) -> Result<Duration> {
+    ensure!(!input.is_empty(), "duration must not be empty");
+    let mut nanos: u64 = 0;
+    let mut rest = input;
+    while !rest.is_empty() {
+        let unit_start = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_eyre("duration is missing a unit")?;
+        let (value, tail) = rest.split_at(unit_start);
+        ensure!(!value.is_empty(), "expected a number in duration `{input}`");
+        let value: u64 = value
+            .parse()
+            .wrap_err("failed to parse number in duration")?;
+        let unit_end = tail
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(unit_end);
+        let unit_nanos: u64 = match unit {
+            "ns" => 1,
+            "us" | "µs" => 1_000,
+            "ms" => 1_000_000,
+            "s" => 1_000_000_000,
+            "m" => 60_000_000_000,
+            "h" => 3_600_000_000_000,
+            other => bail!("unknown unit `{other}` in duration `{input}`"),
+        };
+        nanos = value
+            .checked_mul(unit_nanos)
+            .and_then(|value_nanos| nanos.checked_add(value_nanos))
+            .ok_or_eyre("duration overflows u64 nanoseconds")?;
+        rest = tail;
+    }
+    Ok(Duration::from_nanos(nanos))
+}
+
+/// Forwards `amount` of `asset`, received in `inbound_packet`, as instructed by `forward`.
+///
+/// `inbound_is_source` is whether Sequencer is the source zone of the received tokens, in which
+/// case they are released from the escrow of the inbound channel. `inflow_recorded_at` is the
+/// height at which the received transfer was recorded against the inbound channel's rate limit.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "the received transfer is passed in parts as decoded by the caller"
+)]
+#[instrument(skip_all, fields(%forward.channel, %asset, amount), err(level = Level::DEBUG))]
+pub(super) async fn forward_tokens<S: StateWrite>(
+    mut state: S,
+    inbound_packet: &Packet,
+    inbound_sender: &str,
+    forward: Forward,
+    asset: &denom::TracePrefixed,
+    inbound_is_source: bool,
+    amount: u128,
+    inflow_recorded_at: Option<u64>,
+) -> Result<()> {
+    let sender = forwarding_address(&state, &inbound_packet.chan_on_b, inbound_sender)
+        .await
+        .wrap_err("failed to derive forwarding sender address")?;
+    let data = serde_json::to_vec(&FungibleTokenPacketData {
+        amount: amount.to_string(),
+        denom: asset.to_string(),
+        sender: sender.to_string(),
+        receiver: forward.receiver,
+        memo: forward.next,
+    })
+    .wrap_err("failed to serialize forwarded fungible token packet data as JSON")?;
+
+    // Run all checks before writing to state.
+    rate_limit::check_ics20_flow(
+        &state,
+        &forward.channel,
+        asset,
+        rate_limit::Direction::Outflow,
+        amount,
+    )
+    .await
+    .wrap_err("forwarded transfer is not permitted by the channel's rate limit")?;
+    let (checked_packet, sequence) = check_outbound_packet(
+        &state,
+        forward.port.clone(),
+        forward.channel.clone(),
+        data,
+        forward.timeout,
+    )
+    .await?;
+
+    if inbound_is_source {
+        state
+            .decrease_ibc_channel_balance(&inbound_packet.chan_on_b, asset, amount)
+            .await
+            .wrap_err("failed to deduct forwarded funds from IBC escrow account")?;
+    } else if !state
+        .has_ibc_asset(asset)
+        .await
+        .wrap_err("failed to check if IBC asset exists in state")?
+    {
+        state
+            .put_ibc_asset(asset.clone())
+            .wrap_err("failed to write IBC asset to state")?;
+    }
+
+    let outflow_recorded_at = rate_limit::record_ics20_flow(
+        &mut state,
+        &forward.channel,
+        asset,
+        rate_limit::Direction::Outflow,
+        amount,
+    )
+    .await
+    .wrap_err("failed to record forwarded transfer against the channel's rate limit")?;
+
+    // If we're the source of the forwarded tokens, move them to the escrow account of the
+    // outbound channel, otherwise they are burned.
+    if !is_transfer_source_zone(asset, &forward.port, &forward.channel) {
+        increase_ibc_channel_balance(&mut state, &forward.channel, asset, amount)
+            .await
+            .wrap_err("failed to add forwarded funds to IBC escrow account")?;
+    }
+
+    state
+        .put_in_flight_forward(
+            &forward.channel,
+            sequence,
+            &InFlightForward {
+                inbound_packet: inbound_packet.clone(),
+                retries_remaining: forward.retries,
+                timeout: forward.timeout,
+                inflow_recorded_at,
+                outflow_recorded_at,
+            },
+        )
+        .wrap_err("failed to write in-flight forward to storage")?;
+    state.send_packet_execute(checked_packet).await;
+    Ok(())
+}
+
+/// Completes the forwarding of `outbound_packet` if it was sent by [`forward_tokens`].
+///
+/// Writes the acknowledgement of the inbound packet: a success acknowledgement if `ack` is
+/// successful, or an error acknowledgement after reverting the forward otherwise.
+///
+/// Returns `false` if `outbound_packet` was not a forwarded packet.
+#[instrument(skip_all, fields(%outbound_packet.chan_on_a, %outbound_packet.sequence), err)]
+pub(super) async fn acknowledge_forward<S: StateWrite>(
+    mut state: S,
+    outbound_packet: &Packet,
+    ack: &TokenTransferAcknowledgement,
+) -> Result<bool> {
+    let Some(in_flight) = state
+        .get_in_flight_forward(&outbound_packet.chan_on_a, outbound_packet.sequence)
+        .await
+        .wrap_err("failed to read in-flight forward from storage")?
+    else {
+        return Ok(false);
+    };
+    state.delete_in_flight_forward(&outbound_packet.chan_on_a, outbound_packet.sequence);
+
+    let inbound_ack = if ack.is_successful() {
+        TokenTransferAcknowledgement::success()
+    } else {
+        revert_forward(&mut state, outbound_packet, &in_flight)
+            .await
+            .wrap_err("failed to revert forward")?;
+        TokenTransferAcknowledgement::Error(FORWARD_FAILED_ACKNOWLEDGEMENT.to_string())
+    };
+    write_inbound_acknowledgement(&mut state, &in_flight.inbound_packet, inbound_ack).await?;
+    Ok(true)
+}
+
+/// Handles the timeout of `outbound_packet` if it was sent by [`forward_tokens`].
+///
+/// Resends the packet if retries remain. Otherwise, reverts the forward and writes an error
+/// acknowledgement for the inbound packet.
+///
+/// Returns `false` if `outbound_packet` was not a forwarded packet.
+#[instrument(skip_all, fields(%outbound_packet.chan_on_a, %outbound_packet.sequence), err)]
+pub(super) async fn timeout_forward<S: StateWrite>(
+    mut state: S,
+    outbound_packet: &Packet,
+) -> Result<bool> {
+    let Some(in_flight) = state
+        .get_in_flight_forward(&outbound_packet.chan_on_a, outbound_packet.sequence)
+        .await
+        .wrap_err("failed to read in-flight forward from storage")?
+    else {
+        return Ok(false);
+    };
+    state.delete_in_flight_forward(&outbound_packet.chan_on_a, outbound_packet.sequence);
+
+    if let Some(retries_remaining) = in_flight.retries_remaining.checked_sub(1) {
+        let (checked_packet, sequence) = check_outbound_packet(
+            &state,
+            outbound_packet.port_on_a.clone(),
+            outbound_packet.chan_on_a.clone(),
+            outbound_packet.data.clone(),
+            in_flight.timeout,
+        )
+        .await
+        .wrap_err("failed to resend timed out forwarded packet")?;
+        state
+            .put_in_flight_forward(
+                &outbound_packet.chan_on_a,
+                sequence,
+                &InFlightForward {
+                    retries_remaining,
+                    ..in_flight
+                },
+            )
+            .wrap_err("failed to write in-flight forward to storage")?;
+        state.send_packet_execute(checked_packet).await;
+        return Ok(true);
+    }
+
+    revert_forward(&mut state, outbound_packet, &in_flight)
+        .await
+        .wrap_err("failed to revert forward")?;
+    write_inbound_acknowledgement(
+        &mut state,
+        &in_flight.inbound_packet,
+        TokenTransferAcknowledgement::Error(FORWARD_FAILED_ACKNOWLEDGEMENT.to_string()),
+    )
+    .await?;
+    Ok(true)
+}
+
+/// Reverts the escrow accounting of the forward `in_flight` sent as `outbound_packet`.
+///
+/// The forwarded tokens are released from the escrow of the outbound channel if Sequencer was
+/// their source zone, and returned to the escrow of the inbound channel if they were released
+/// from it on receipt. The transfers are removed from the rate limits of both channels if they
+/// were recorded in buckets that still overlap the rate limit windows.
+async fn revert_forward<S: StateWrite>(
+    mut state: S,
+    outbound_packet: &Packet,
+    in_flight: &InFlightForward,
+) -> Result<()> {
+    let inbound_packet = &in_flight.inbound_packet;
+    let outbound_data: FungibleTokenPacketData = serde_json::from_slice(&outbound_packet.data)
+        .wrap_err("failed to deserialize forwarded fungible token packet data")?;
+    let amount: u128 = outbound_data
+        .amount
+        .parse()
+        .wrap_err("failed to parse forwarded packet data amount to u128")?;
+    let asset = parse_asset(&state, &outbound_data.denom)
+        .await
+        .wrap_err_with(|| format!("failed parsing packet.asset `{}`", outbound_data.denom))?;
+
+    if is_refund_source_zone(
+        &asset,
+        &outbound_packet.port_on_a,
+        &outbound_packet.chan_on_a,
+    ) {
+        state
+            .decrease_ibc_channel_balance(&outbound_packet.chan_on_a, &asset, amount)
+            .await
+            .wrap_err("failed to withdraw forwarded funds from outbound IBC escrow account")?;
+    }
+
+    let inbound_data: FungibleTokenPacketData = serde_json::from_slice(&inbound_packet.data)
+        .wrap_err("failed to deserialize inbound fungible token packet data")?;
+    let inbound_asset = parse_asset(&state, &inbound_data.denom)
+        .await
+        .wrap_err_with(|| format!("failed parsing packet.asset `{}`", inbound_data.denom))?;
+    if is_transfer_source_zone(
+        &inbound_asset,
+        &inbound_packet.port_on_a,
+        &inbound_packet.chan_on_a,
+    ) {
+        increase_ibc_channel_balance(&mut state, &inbound_packet.chan_on_b, &asset, amount)
+            .await
+            .wrap_err("failed to return forwarded funds to inbound IBC escrow account")?;
+    }
+
+    if let Some(recorded_at) = in_flight.outflow_recorded_at {
+        rate_limit::reverse_ics20_flow(
+            &mut state,
+            &outbound_packet.chan_on_a,
+            &asset,
+            rate_limit::Direction::Outflow,
+            amount,
+            recorded_at,
+        )
+        .await
+        .wrap_err(
+            "failed to reverse forwarded transfer against the outbound channel's rate limit",
+        )?;
+    }
+    if let Some(recorded_at) = in_flight.inflow_recorded_at {
+        rate_limit::reverse_ics20_flow(
+            &mut state,
+            &inbound_packet.chan_on_b,
+            &asset,
+            rate_limit::Direction::Inflow,
+            amount,
+            recorded_at,
+        )
+        .await
+        .wrap_err(
+            "failed to reverse forwarded transfer against the inbound channel's rate limit",
+        )?;
+    }
+    Ok(())
+}
+
+/// Checks the packet sending `data` over (`port`, `channel`), and returns it together with the
+/// sequence number it will be sent with.
+async fn check_outbound_packet<S: StateRead>(
+    state: S,
+    port: PortId,
+    channel: ChannelId,
+    data: Vec<u8>,
+    timeout: Duration,
+) -> Result<(IBCPacket<Checked>, Sequence)> {
+    let current_timestamp = state
+        .get_block_timestamp()
+        .await
+        .wrap_err("failed to read block timestamp from storage")?;
+    let timeout_timestamp = u64::try_from(current_timestamp.unix_timestamp_nanos())
+        .ok()
+        .zip(u64::try_from(timeout.as_nanos()).ok())
+        .and_then(|(now, timeout)| now.checked_add(timeout))
+        .ok_or_eyre("forward timeout timestamp overflows u64 nanoseconds")?;
+    let timeout_height =
+        IbcHeight::new(u64::MAX, u64::MAX).expect("non-zero arguments should never fail");
+
+    let sequence = state
+        .get_send_sequence(&channel, &port)
+        .await
+        .map_err(anyhow_to_eyre)
+        .wrap_err("failed to read send sequence of forward channel")?;
+    let checked_packet = state
+        .send_packet_check(
+            IBCPacket::new(port, channel, timeout_height, timeout_timestamp, data),
+            current_timestamp,
+        )
+        .await
+        .map_err(anyhow_to_eyre)
+        .wrap_err("forwarded ibc packet failed send check")?;
+    Ok((checked_packet, Sequence(sequence)))
+}
+
+/// Returns the address sending the tokens forwarded from `sender` over `channel`.
+///
+/// The address is derived from the channel and sender of the inbound packet and has no known
+/// private key. Refunds of forwarded packets are returned to the originating chain, never to this
+/// address.
+async fn forwarding_address<S: StateRead>(
+    state: &S,
+    channel: &ChannelId,
+    sender: &str,
+) -> Result<Address> {
+    let base_prefix = state
+        .get_base_prefix()
+        .await
+        .wrap_err("failed to read base prefix from state")?;
+    let hash = Sha256::digest(format!("packet-forward/{channel}/{sender}"));
+    Address::builder()
+        .slice(&hash[..ADDRESS_LEN])
+        .prefix(base_prefix)
+        .try_build()
+        .wrap_err("failed to construct forwarding address")
+}
+
+async fn increase_ibc_channel_balance<S: StateWrite>(
+    mut state: S,
+    channel: &ChannelId,
+    asset: &denom::TracePrefixed,
+    amount: u128,
+) -> Result<()> {
+    let channel_balance = state
+        .get_ibc_channel_balance(channel, asset)
+        .await
+        .wrap_err("failed to read channel balance from storage")?;
+    state
+        .put_ibc_channel_balance(
+            channel,
+            asset,
+            channel_balance
+                .checked_add(amount)
+                .ok_or_eyre("overflow when adding to channel balance")?,
+        )
+        .wrap_err("failed to write channel balance to storage")
+}
+
+async fn write_inbound_acknowledgement<S: StateWrite>(
+    mut state: S,
+    inbound_packet: &Packet,
+    ack: TokenTransferAcknowledgement,
+) -> Result<()> {
+    let ack_bytes: Vec<u8> = ack.into();
+    state
+        .write_acknowledgement(inbound_packet, &ack_bytes)
+        .await
+        .map_err(anyhow_to_eyre)
+        .wrap_err("failed to write acknowledgement of inbound packet")
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::asset::Denom,
+        protocol::transaction::v1::action::Ics20RateLimit,
+    };
+    use cnidarium::StateDelta;
+    use ibc_types::{
+        core::channel::TimeoutHeight,
+        timestamp::Timestamp,
+    };
+
+    use super::*;
+    use crate::{
+        app::StateWriteExt as _,
+        ibc::rate_limit::Direction,
+        test_utils::{
+            assert_error_contains,
+            nria,
+        },
+    };
+
+    fn forward_memo(forward: &serde_json::Value) -> String {
+        serde_json::json!({
+            "forward": forward
+        })
+        .to_string()
+    }
+
+    fn packet(sequence: u64, chan_on_a: &str, chan_on_b: &str, denom: &str) -> Packet {
+        Packet {
+            sequence: Sequence(sequence),
+            port_on_a: PortId::transfer(),
+            chan_on_a: ChannelId(chan_on_a.into()),
+            port_on_b: PortId::transfer(),
+            chan_on_b: ChannelId(chan_on_b.into()),
+            data: serde_json::to_vec(&FungibleTokenPacketData {
+                denom: denom.to_string(),
+                sender: String::new(),
+                amount: "100".to_string(),
+                receiver: String::new(),
+                memo: String::new(),
+            })
+            .unwrap(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp {
+                time: None,
+            },
+        }
+    }
+
+    fn in_flight(inbound_packet: Packet) -> InFlightForward {
+        InFlightForward {
+            inbound_packet,
+            retries_remaining: 0,
+            timeout: DEFAULT_FORWARD_TIMEOUT,
+            inflow_recorded_at: None,
+            outflow_recorded_at: None,
+        }
+    }
+
+    #[test]
+    fn memos_without_forward_field_are_not_forwarded() {
+        assert_eq!(None, parse_memo("").unwrap());
+        assert_eq!(None, parse_memo("not json").unwrap());
+        assert_eq!(None, parse_memo(r#"["forward"]"#).unwrap());
+        assert_eq!(
+            None,
+            parse_memo(r#"{"rollupDepositAddress": "0x1234"}"#).unwrap()
+        );
+    }
+
+    #[test]
+    fn forward_memo_with_defaults_is_parsed() {
+        let memo = forward_memo(&serde_json::json!({
+            "receiver": "noble1receiver",
+            "port": "transfer",
+            "channel": "channel-1",
+        }));
+        assert_eq!(
+            Some(Forward {
+                receiver: "noble1receiver".to_string(),
+                port: PortId::transfer(),
+                channel: ChannelId::new(1),
+                timeout: DEFAULT_FORWARD_TIMEOUT,
+                retries: DEFAULT_FORWARD_RETRIES,
+                next: String::new(),
+            }),
+            parse_memo(&memo).unwrap()
+        );
+    }
+
+    #[test]
+    fn forward_memo_with_nested_forward_is_parsed() {
+        let next = serde_json::json!({
+            "forward": {
+                "receiver": "osmo1receiver",
+                "port": "transfer",
+                "channel": "channel-2",
+            }
+        });
+        let memo = forward_memo(&serde_json::json!({
+            "receiver": "noble1receiver",
+            "port": "transfer",
+            "channel": "channel-1",
+            "timeout": "1h30m",
+            "retries": 0,
+            "next": next,
+        }));
+        let forward = parse_memo(&memo).unwrap().unwrap();
+        assert_eq!(Duration::from_secs(5400), forward.timeout);
+        assert_eq!(0, forward.retries);
+        assert_eq!(
+            next,
+            serde_json::from_str::<serde_json::Value>(&forward.next).unwrap()
+        );
+
+        let memo = forward_memo(&serde_json::json!({
+            "receiver": "noble1receiver",
+            "port": "transfer",
+            "channel": "channel-1",
+            "timeout": 1_000_000_000_u64,
+            "next": next.to_string(),
+        }));
+        let forward = parse_memo(&memo).unwrap().unwrap();
+        assert_eq!(Duration::from_secs(1), forward.timeout);
+        assert_eq!(next.to_string(), forward.next);
+    }
+
+    #[test]
+    fn invalid_forward_memos_are_rejected() {
+        let err = parse_memo(&forward_memo(&serde_json::json!({
+            "receiver": "",
+            "port": "transfer",
+            "channel": "channel-1",
+        })))
+        .unwrap_err();
+        assert_error_contains(&err, "forward receiver must be set");
+
+        let err = parse_memo(&forward_memo(&serde_json::json!({
+            "receiver": "noble1receiver",
+            "port": "icahost",
+            "channel": "channel-1",
+        })))
+        .unwrap_err();
+        assert_error_contains(
+            &err,
+            "only forwarding over the `transfer` port is supported",
+        );
+
+        let err = parse_memo(&forward_memo(&serde_json::json!({
+            "receiver": "noble1receiver",
+            "port": "transfer",
+            "channel": "channel-1",
+            "timeout": "10 minutes",
+        })))
+        .unwrap_err();
+        assert_error_contains(&err, "failed to parse forward timeout");
+
+        let err = parse_memo(&forward_memo(&serde_json::json!({
+            "receiver": "noble1receiver",
+            "port": "transfer",
+            "channel": "channel-1",
+            "next": 42,
+        })))
+        .unwrap_err();
+        assert_error_contains(&err, "must be a JSON object or string");
+    }
+
+    #[test]
+    fn go_durations_are_parsed() {
+        assert_eq!(Duration::from_nanos(5), parse_duration("5ns").unwrap());
+        assert_eq!(Duration::from_micros(5), parse_duration("5us").unwrap());
+        assert_eq!(Duration::from_micros(5), parse_duration("5µs").unwrap());
+        assert_eq!(Duration::from_millis(5), parse_duration("5ms").unwrap());
+        assert_eq!(Duration::from_secs(600), parse_duration("10m").unwrap());
+        assert_eq!(Duration::from_secs(3661), parse_duration("1h1m1s").unwrap());
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("10").is_err());
+        assert!(parse_duration("m").is_err());
+        assert!(parse_duration("10d").is_err());
+        assert!(parse_duration("18446744073709551615h").is_err());
+    }
+
+    #[tokio::test]
+    async fn reverting_forward_of_returned_asset_restores_inbound_escrow() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        // `nria` originates on Sequencer and was returned over `inchan` (so it was released from
+        // the `inchan` escrow), then forwarded over `outchan` (so it was moved to the `outchan`
+        // escrow).
+        state
+            .put_ibc_channel_balance(&ChannelId("outchan".into()), &nria(), 100)
+            .unwrap();
+        let inbound_packet = packet(
+            0,
+            "counterparty",
+            "inchan",
+            &format!("transfer/counterparty/{}", nria()),
+        );
+        let outbound_packet = packet(0, "outchan", "counterparty", &nria().to_string());
+
+        revert_forward(&mut state, &outbound_packet, &in_flight(inbound_packet))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            0,
+            state
+                .get_ibc_channel_balance(&ChannelId("outchan".into()), &nria())
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            100,
+            state
+                .get_ibc_channel_balance(&ChannelId("inchan".into()), &nria())
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn reverting_forward_of_foreign_asset_to_its_origin_leaves_escrows_unchanged() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        // `uatom` was received over `inchan` (so a voucher was minted), then forwarded back over
+        // `inchan` (so the voucher was burned).
+        let asset: Denom = "transfer/inchan/uatom".parse().unwrap();
+        let inbound_packet = packet(0, "counterparty", "inchan", "uatom");
+        let outbound_packet = packet(0, "inchan", "counterparty", &asset.to_string());
+
+        revert_forward(&mut state, &outbound_packet, &in_flight(inbound_packet))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            0,
+            state
+                .get_ibc_channel_balance(&ChannelId("inchan".into()), &asset)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn reverting_forward_only_reverses_flow_recorded_in_current_window() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        // `uatom` was received over `inchan` and forwarded back over `inchan`.
+        let channel = ChannelId("inchan".into());
+        let asset: Denom = "transfer/inchan/uatom".parse().unwrap();
+        state
+            .put_ics20_rate_limit(
+                &channel,
+                &asset,
+                Ics20RateLimit {
+                    max_inflow: 100,
+                    max_outflow: 100,
+                    window_blocks: 5,
+                },
+            )
+            .unwrap();
+        let inbound_packet = packet(0, "counterparty", "inchan", "uatom");
+        let outbound_packet = packet(0, "inchan", "counterparty", &asset.to_string());
+
+        // A forward was recorded at height 1, and a second forward filled the window at height 6
+        // after the first one left it.
+        for height in [1, 6] {
+            state.put_block_height(height).unwrap();
+            for direction in [Direction::Inflow, Direction::Outflow] {
+                rate_limit::record_ics20_flow(&mut state, &channel, &asset, direction, 100)
+                    .await
+                    .unwrap();
+            }
+        }
+
+        // Reverting the first forward must not free up the window filled by the second.
+        state.put_block_height(7).unwrap();
+        revert_forward(
+            &mut state,
+            &outbound_packet,
+            &InFlightForward {
+                inflow_recorded_at: Some(1),
+                outflow_recorded_at: Some(1),
+                ..in_flight(inbound_packet.clone())
+            },
+        )
+        .await
+        .unwrap();
+        for direction in [Direction::Inflow, Direction::Outflow] {
+            rate_limit::check_ics20_flow(&state, &channel, &asset, direction, 1)
+                .await
+                .unwrap_err();
+        }
+
+        // Reverting the second forward frees up the window.
+        revert_forward(
+            &mut state,
+            &outbound_packet,
+            &InFlightForward {
+                inflow_recorded_at: Some(6),
+                outflow_recorded_at: Some(6),
+                ..in_flight(inbound_packet)
+            },
+        )
+        .await
+        .unwrap();
+        for direction in [Direction::Inflow, Direction::Outflow] {
+            rate_limit::check_ics20_flow(&state, &channel, &asset, direction, 100)
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn unknown_packets_are_not_completed_as_forwards() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let outbound_packet = packet(0, "outchan", "counterparty", &nria().to_string());
+
+        assert!(!acknowledge_forward(
+            &mut state,
+            &outbound_packet,
+            &TokenTransferAcknowledgement::success()
+        )
+        .await
+        .unwrap());
+        assert!(!timeout_forward(&mut state, &outbound_packet).await.unwrap());
+    }
+}
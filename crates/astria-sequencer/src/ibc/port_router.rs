@@ -0,0 +1,89 @@
+//! Routing of IBC relay messages to the application bound to their port.
+//!
+//! Penumbra's IBC message handlers only pass channel handshakes and packets of the `transfer` port
+//! to their app handler, which is [`Ics20Transfer`]. Channel handshakes and packets of the
+//! `icahost` port are instead handled by [`ics27_channel`], which passes them on to
+//! [`super::ics27_host::Ics27Host`]. All other messages, namely client and connection messages and
+//! channel messages of any other port, are handled by Penumbra, which rejects the latter.
+
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{
+        Result,
+        WrapErr as _,
+    },
+};
+use cnidarium::StateWrite;
+use ibc_types::core::channel::PortId;
+use penumbra_ibc::{
+    IbcRelay,
+    IbcRelayWithHandlers,
+};
+
+use super::{
+    host_interface::AstriaHost,
+    ics20_transfer::Ics20Transfer,
+    ics27_channel::{
+        self,
+        PenumbraProofVerifier,
+    },
+    ics27_host,
+};
+
+/// An IBC relay message together with the handler of the application bound to its port.
+pub(crate) enum PortRouter {
+    Penumbra(IbcRelayWithHandlers<Ics20Transfer, AstriaHost>),
+    Ics27Host(IbcRelay),
+}
+
+impl PortRouter {
+    pub(crate) fn new(action: IbcRelay) -> Self {
+        if sequencer_port(&action).is_some_and(|port| port.as_str() == ics27_host::HOST_PORT) {
+            Self::Ics27Host(action)
+        } else {
+            Self::Penumbra(action.with_handler::<Ics20Transfer, AstriaHost>())
+        }
+    }
+
+    pub(crate) async fn check_stateless(&self) -> Result<()> {
+        match self {
+            Self::Penumbra(action_with_handlers) => action_with_handlers
+                .check_stateless(())
+                .await
+                .map_err(anyhow_to_eyre),
+            Self::Ics27Host(action) => ics27_channel::check_stateless(action),
+        }
+    }
+
+    pub(crate) async fn check_and_execute<S: StateWrite>(&self, state: S) -> Result<()> {
+        match self {
+            Self::Penumbra(action_with_handlers) => action_with_handlers
+                .check_and_execute(state)
+                .await
+                .map_err(anyhow_to_eyre),
+            Self::Ics27Host(action) => {
+                ics27_channel::check_and_execute::<PenumbraProofVerifier, _>(state, action)
+                    .await
+                    .wrap_err("failed executing ics27 host message")
+            }
+        }
+    }
+}
+
+/// Returns the port on Sequencer's end of the channel `action` refers to, or `None` if `action` is
+/// not a channel handshake or packet message.
+fn sequencer_port(action: &IbcRelay) -> Option<&PortId> {
+    let port = match action {
+        IbcRelay::ChannelOpenInit(msg) => &msg.port_id_on_a,
+        IbcRelay::ChannelOpenTry(msg) => &msg.port_id_on_b,
+        IbcRelay::ChannelOpenAck(msg) => &msg.port_id_on_a,
+        IbcRelay::ChannelOpenConfirm(msg) => &msg.port_id_on_b,
+        IbcRelay::ChannelCloseInit(msg) => &msg.port_id_on_a,
+        IbcRelay::ChannelCloseConfirm(msg) => &msg.port_id_on_b,
+        IbcRelay::RecvPacket(msg) => &msg.packet.port_on_b,
+        IbcRelay::Acknowledgement(msg) => &msg.packet.port_on_a,
+        IbcRelay::Timeout(msg) => &msg.packet.port_on_a,
+        _ => return None,
+    };
+    Some(port)
+}
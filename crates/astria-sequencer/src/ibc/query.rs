@@ -0,0 +1,269 @@
+use astria_core::{
+    primitive::v1::asset,
+    protocol::{
+        abci::AbciErrorCode,
+        ibc::v1::{
+            Ics20RateLimitInfo,
+            Ics20RateLimitsResponse,
+        },
+    },
+};
+use astria_eyre::eyre::{
+    Result,
+    WrapErr as _,
+};
+use ibc_types::core::channel::ChannelId;
+use prost::Message as _;
+use tendermint::abci::{
+    request,
+    response,
+    Code,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use crate::{
+    app::StateReadExt as _,
+    assets::StateReadExt as _,
+    ibc::StateReadExt as _,
+    storage::QueryState,
+};
+
+/// Returns the rate limits on ICS20 transfers over the channel given in `params`, together with
+/// the amounts transferred in the current rolling window of each limit.
+pub(crate) async fn ics20_rate_limits_request(
+    snapshot: QueryState,
+    request: request::Query,
+    params: Vec<(String, String)>,
+) -> response::Query {
+    let channel = match preprocess_request(&params) {
+        Ok(channel) => channel,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let height = match snapshot.get_block_height().await {
+        Ok(height) => height,
+        Err(err) => {
+            return response::Query {
+                code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+                info: AbciErrorCode::INTERNAL_ERROR.info(),
+                log: format!("failed getting block height: {err:#}"),
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let rate_limits = match get_rate_limit_infos(&snapshot, &channel, height).await {
+        Ok(rate_limits) => rate_limits,
+        Err(err) => {
+            return response::Query {
+                code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+                info: AbciErrorCode::INTERNAL_ERROR.info(),
+                log: format!("failed getting ics20 rate limits for provided channel: {err:#}"),
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let Ok(query_height) = tendermint::block::Height::try_from(height) else {
+        return response::Query {
+            code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+            info: AbciErrorCode::INTERNAL_ERROR.info(),
+            log: "internal u64 block height does not fit into tendermint i64 `Height`".into(),
+            ..response::Query::default()
+        };
+    };
+
+    let payload = Ics20RateLimitsResponse {
+        height,
+        rate_limits,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: 0.into(),
+        key: request.path.into_bytes().into(),
+        value: payload,
+        height: query_height,
+        ..response::Query::default()
+    }
+}
+
+#[instrument(skip_all, fields(%channel), err(level = Level::DEBUG))]
+async fn get_rate_limit_infos(
+    snapshot: &QueryState,
+    channel: &ChannelId,
+    height: u64,
+) -> Result<Vec<Ics20RateLimitInfo>> {
+    let rate_limits = snapshot
+        .get_ics20_rate_limits(channel)
+        .await
+        .wrap_err("failed to read ics20 rate limits from storage")?;
+    let mut infos = Vec::with_capacity(rate_limits.len());
+    for (ibc_asset, rate_limit) in rate_limits {
+        let flow = snapshot
+            .get_ics20_flow(channel, &ibc_asset)
+            .await
+            .wrap_err("failed to read ics20 flow from storage")?
+            .unwrap_or_default()
+            .at_height(&rate_limit, height);
+        let trace_asset = snapshot
+            .map_ibc_to_trace_prefixed_asset(&ibc_asset)
+            .await
+            .wrap_err("failed to map ibc prefixed asset to trace prefixed asset")?;
+        let asset: asset::Denom = match trace_asset {
+            Some(trace_asset) => trace_asset.into(),
+            None => ibc_asset.into(),
+        };
+        infos.push(Ics20RateLimitInfo {
+            asset,
+            max_inflow: rate_limit.max_inflow,
+            max_outflow: rate_limit.max_outflow,
+            window_blocks: rate_limit.window_blocks,
+            inflow: flow.inflow(),
+            outflow: flow.outflow(),
+            window_start_height: flow.window_start_height(&rate_limit, height),
+        });
+    }
+    Ok(infos)
+}
+
+fn preprocess_request(params: &[(String, String)]) -> Result<ChannelId, response::Query> {
+    let Some(channel) = params
+        .iter()
+        .find_map(|(k, v)| (k == "channel").then_some(v))
+    else {
+        return Err(response::Query {
+            code: Code::Err(AbciErrorCode::INVALID_PARAMETER.value()),
+            info: AbciErrorCode::INVALID_PARAMETER.info(),
+            log: "path did not contain channel parameter".into(),
+            ..response::Query::default()
+        });
+    };
+    channel
+        .parse()
+        .wrap_err("failed to parse argument as channel id")
+        .map_err(|err| response::Query {
+            code: Code::Err(AbciErrorCode::INVALID_PARAMETER.value()),
+            info: AbciErrorCode::INVALID_PARAMETER.info(),
+            log: format!("channel id could not be constructed from provided parameter: {err:#}"),
+            ..response::Query::default()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        generated::astria::protocol::ibc::v1::Ics20RateLimitsResponse as RawIcs20RateLimitsResponse,
+        protocol::transaction::v1::action::Ics20RateLimit,
+    };
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        app::StateWriteExt as _,
+        assets::StateWriteExt as _,
+        ibc::{
+            rate_limit::{
+                Ics20Flow,
+                Ics20FlowBucket,
+            },
+            StateWriteExt as _,
+        },
+        test_utils::nria,
+    };
+
+    #[tokio::test]
+    async fn ics20_rate_limits_request_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        let channel = ChannelId::new(0);
+        let rate_limit = Ics20RateLimit {
+            max_inflow: 100,
+            max_outflow: 50,
+            window_blocks: 10,
+        };
+        state.put_block_height(12).unwrap();
+        state.put_ibc_asset(nria()).unwrap();
+        state
+            .put_ics20_rate_limit(&channel, &nria(), rate_limit)
+            .unwrap();
+        state
+            .put_ics20_flow(
+                &channel,
+                &nria(),
+                Ics20Flow {
+                    buckets: vec![
+                        Ics20FlowBucket {
+                            start_height: 2,
+                            end_height: 3,
+                            inflow: 1,
+                            outflow: 2,
+                        },
+                        Ics20FlowBucket {
+                            start_height: 3,
+                            end_height: 4,
+                            inflow: 20,
+                            outflow: 30,
+                        },
+                        Ics20FlowBucket {
+                            start_height: 5,
+                            end_height: 6,
+                            inflow: 4,
+                            outflow: 0,
+                        },
+                    ],
+                },
+            )
+            .unwrap();
+        storage.commit(state).await.unwrap();
+
+        let query = request::Query {
+            data: vec![].into(),
+            path: "path".to_string(),
+            height: 0u32.into(),
+            prove: false,
+        };
+        let params = vec![("channel".to_string(), channel.to_string())];
+        let resp = ics20_rate_limits_request(storage.latest_snapshot().into(), query, params).await;
+        assert_eq!(resp.code, 0.into(), "{}", resp.log);
+
+        let proto = RawIcs20RateLimitsResponse::decode(resp.value).unwrap();
+        let native = Ics20RateLimitsResponse::try_from_raw(&proto).unwrap();
+        let expected = Ics20RateLimitsResponse {
+            height: 12,
+            rate_limits: vec![Ics20RateLimitInfo {
+                asset: nria().into(),
+                max_inflow: 100,
+                max_outflow: 50,
+                window_blocks: 10,
+                inflow: 24,
+                outflow: 30,
+                window_start_height: 3,
+            }],
+        };
+        assert_eq!(native, expected);
+    }
+
+    #[tokio::test]
+    async fn ics20_rate_limits_request_fails_for_invalid_channel() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+
+        let query = request::Query {
+            data: vec![].into(),
+            path: "path".to_string(),
+            height: 0u32.into(),
+            prove: false,
+        };
+        let params = vec![("channel".to_string(), "not a channel".to_string())];
+        let resp = ics20_rate_limits_request(storage.latest_snapshot().into(), query, params).await;
+        assert_eq!(
+            resp.code,
+            Code::Err(AbciErrorCode::INVALID_PARAMETER.value())
+        );
+    }
+}
@@ -0,0 +1,667 @@
+//! Rate limits on ICS20 transfers per channel and asset.
+//!
+//! A rate limit caps the amounts of an asset received and sent over a channel within any
+//! `window_blocks` consecutive sequencer blocks. The amounts transferred are recorded in buckets of
+//! consecutive blocks, and a transfer is checked against the sum of all buckets overlapping the
+//! rolling window that ends at the current block. A bucket spans at most `1/MAX_FLOW_BUCKETS` of
+//! the window, so a transfer is counted for at most that many blocks longer than the window.
+//!
+//! Transfers that are refunded are removed from the bucket they were recorded in, as long as that
+//! bucket still overlaps the window.
+use std::{
+    borrow::Cow,
+    fmt::Display,
+};
+
+use astria_core::{
+    primitive::v1::asset,
+    protocol::transaction::v1::action::Ics20RateLimit,
+};
+use astria_eyre::eyre::{
+    ensure,
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use ibc_types::core::channel::{
+    packet::Sequence,
+    ChannelId,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use crate::{
+    app::StateReadExt as _,
+    ibc::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+};
+
+/// The maximum number of buckets the rolling window of a rate limit is split into.
+const MAX_FLOW_BUCKETS: u64 = 100;
+
+/// The direction of an ICS20 transfer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Direction {
+    /// Tokens received over the channel.
+    Inflow,
+    /// Tokens sent over the channel.
+    Outflow,
+}
+
+/// The amounts of an asset transferred over a channel in a range of consecutive blocks.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Ics20FlowBucket {
+    /// The height of the first block of the bucket.
+    pub(crate) start_height: u64,
+    /// The height of the first block after the bucket.
+    pub(crate) end_height: u64,
+    pub(crate) inflow: u128,
+    pub(crate) outflow: u128,
+}
+
+impl Ics20FlowBucket {
+    fn contains(&self, height: u64) -> bool {
+        self.start_height <= height && height < self.end_height
+    }
+
+    fn total_mut(&mut self, direction: Direction) -> &mut u128 {
+        match direction {
+            Direction::Inflow => &mut self.inflow,
+            Direction::Outflow => &mut self.outflow,
+        }
+    }
+}
+
+/// The amounts of an asset transferred over a channel, bucketed by block height.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Ics20Flow {
+    /// The buckets with recorded transfers, ordered by height.
+    pub(crate) buckets: Vec<Ics20FlowBucket>,
+}
+
+impl Ics20Flow {
+    /// Returns the flow as seen by a block at `height`, dropping all buckets that end before the
+    /// rolling window of `rate_limit`.
+    pub(crate) fn at_height(mut self, rate_limit: &Ics20RateLimit, height: u64) -> Self {
+        let window_start_height = window_start_height(rate_limit, height);
+        self.buckets
+            .retain(|bucket| bucket.end_height > window_start_height);
+        self
+    }
+
+    /// Returns the height of the first block whose transfers are counted in the rolling window of
+    /// `rate_limit` ending at `height`.
+    pub(crate) fn window_start_height(&self, rate_limit: &Ics20RateLimit, height: u64) -> u64 {
+        let window_start_height = window_start_height(rate_limit, height);
+        self.buckets.first().map_or(window_start_height, |bucket| {
+            bucket.start_height.min(window_start_height)
+        })
+    }
+
+    /// Returns the total amount received over all buckets.
+    pub(crate) fn inflow(&self) -> u128 {
+        self.total(Direction::Inflow)
+    }
+
+    /// Returns the total amount sent over all buckets.
+    pub(crate) fn outflow(&self) -> u128 {
+        self.total(Direction::Outflow)
+    }
+
+    fn total(&self, direction: Direction) -> u128 {
+        self.buckets
+            .iter()
+            .map(|bucket| match direction {
+                Direction::Inflow => bucket.inflow,
+                Direction::Outflow => bucket.outflow,
+            })
+            .fold(0, u128::saturating_add)
+    }
+
+    /// Adds `amount` to the bucket containing `height`, starting a new bucket if the last bucket
+    /// ends before `height`.
+    fn add(
+        &mut self,
+        rate_limit: &Ics20RateLimit,
+        height: u64,
+        direction: Direction,
+        amount: u128,
+    ) -> Result<()> {
+        if !self
+            .buckets
+            .last()
+            .is_some_and(|bucket| bucket.contains(height))
+        {
+            let bucket_blocks = rate_limit.window_blocks.div_ceil(MAX_FLOW_BUCKETS).max(1);
+            let aligned_start_height = height
+                .checked_rem(bucket_blocks)
+                .map_or(height, |offset| height.saturating_sub(offset));
+            let previous_end_height = self.buckets.last().map_or(0, |bucket| bucket.end_height);
+            self.buckets.push(Ics20FlowBucket {
+                start_height: aligned_start_height.max(previous_end_height),
+                end_height: aligned_start_height.saturating_add(bucket_blocks),
+                inflow: 0,
+                outflow: 0,
+            });
+        }
+        let total = self
+            .buckets
+            .last_mut()
+            .expect("a bucket containing `height` was pushed above if missing")
+            .total_mut(direction);
+        *total = total
+            .checked_add(amount)
+            .ok_or_eyre("overflow when adding to ics20 flow")?;
+        Ok(())
+    }
+
+    /// Subtracts `amount` from the bucket containing `recorded_at`.
+    ///
+    /// Returns `false` if no bucket contains `recorded_at`.
+    fn subtract(&mut self, recorded_at: u64, direction: Direction, amount: u128) -> bool {
+        let Some(bucket) = self
+            .buckets
+            .iter_mut()
+            .find(|bucket| bucket.contains(recorded_at))
+        else {
+            return false;
+        };
+        let total = bucket.total_mut(direction);
+        *total = total.saturating_sub(amount);
+        true
+    }
+}
+
+/// Returns the height of the first block of the rolling window of `rate_limit` ending at `height`.
+fn window_start_height(rate_limit: &Ics20RateLimit, height: u64) -> u64 {
+    height
+        .saturating_add(1)
+        .saturating_sub(rate_limit.window_blocks)
+}
+
+/// Ensures that transferring `amount` of `asset` over `channel` in `direction` is within the
+/// channel's rate limit for the asset.
+#[instrument(skip_all, fields(%channel, %asset, ?direction, amount), err(level = Level::DEBUG))]
+pub(crate) async fn check_ics20_flow<'a, S, TAsset>(
+    state: S,
+    channel: &ChannelId,
+    asset: &'a TAsset,
+    direction: Direction,
+    amount: u128,
+) -> Result<()>
+where
+    S: StateRead,
+    TAsset: Sync + Display,
+    &'a TAsset: Into<Cow<'a, asset::IbcPrefixed>>,
+{
+    flow_after_transfer(&state, channel, asset, direction, amount)
+        .await
+        .map(|_| ())
+}
+
+/// Records the transfer of `amount` of `asset` over `channel` in `direction`.
+///
+/// Returns the height of the block the transfer was recorded in, or `None` if the transfer is not
+/// rate limited. Returns an error without writing to state if the transfer exceeds the channel's
+/// rate limit for the asset.
+#[instrument(skip_all, fields(%channel, %asset, ?direction, amount), err(level = Level::DEBUG))]
+pub(crate) async fn record_ics20_flow<'a, S, TAsset>(
+    mut state: S,
+    channel: &ChannelId,
+    asset: &'a TAsset,
+    direction: Direction,
+    amount: u128,
+) -> Result<Option<u64>>
+where
+    S: StateWrite,
+    TAsset: Sync + Display,
+    &'a TAsset: Into<Cow<'a, asset::IbcPrefixed>>,
+{
+    let Some((flow, height)) =
+        flow_after_transfer(&state, channel, asset, direction, amount).await?
+    else {
+        return Ok(None);
+    };
+    state
+        .put_ics20_flow(channel, asset, flow)
+        .wrap_err("failed to write ics20 flow to storage")?;
+    Ok(Some(height))
+}
+
+/// Reverses a previously recorded transfer of `amount` of `asset` over `channel` in `direction`,
+/// e.g. because the transfer was refunded.
+///
+/// `recorded_at` is the height returned by [`record_ics20_flow`] when the transfer was recorded.
+/// The recorded flow is left unchanged if the bucket the transfer was recorded in no longer
+/// overlaps the rolling window.
+#[instrument(
+    skip_all,
+    fields(%channel, %asset, ?direction, amount, recorded_at),
+    err(level = Level::DEBUG),
+)]
+pub(crate) async fn reverse_ics20_flow<'a, S, TAsset>(
+    mut state: S,
+    channel: &ChannelId,
+    asset: &'a TAsset,
+    direction: Direction,
+    amount: u128,
+    recorded_at: u64,
+) -> Result<()>
+where
+    S: StateWrite,
+    TAsset: Sync + Display,
+    &'a TAsset: Into<Cow<'a, asset::IbcPrefixed>>,
+{
+    let Some(rate_limit) = state
+        .get_ics20_rate_limit(channel, asset)
+        .await
+        .wrap_err("failed to read ics20 rate limit from storage")?
+    else {
+        return Ok(());
+    };
+    let Some(flow) = state
+        .get_ics20_flow(channel, asset)
+        .await
+        .wrap_err("failed to read ics20 flow from storage")?
+    else {
+        return Ok(());
+    };
+    let height = state
+        .get_block_height()
+        .await
+        .wrap_err("failed to read block height from storage")?;
+    let mut flow = flow.at_height(&rate_limit, height);
+    if !flow.subtract(recorded_at, direction, amount) {
+        return Ok(());
+    }
+    state
+        .put_ics20_flow(channel, asset, flow)
+        .wrap_err("failed to write ics20 flow to storage")
+}
+
+/// Records the transfer of `amount` of `asset` sent over `channel` in the packet with `sequence`.
+///
+/// The height the transfer was recorded at is kept until the packet is acknowledged or refunded
+/// through [`reverse_ics20_outflow`].
+#[instrument(skip_all, fields(%channel, %sequence, %asset, amount), err(level = Level::DEBUG))]
+pub(crate) async fn record_ics20_outflow<'a, S, TAsset>(
+    mut state: S,
+    channel: &ChannelId,
+    sequence: Sequence,
+    asset: &'a TAsset,
+    amount: u128,
+) -> Result<()>
+where
+    S: StateWrite,
+    TAsset: Sync + Display,
+    &'a TAsset: Into<Cow<'a, asset::IbcPrefixed>>,
+{
+    let Some(recorded_at) =
+        record_ics20_flow(&mut state, channel, asset, Direction::Outflow, amount).await?
+    else {
+        return Ok(());
+    };
+    state
+        .put_ics20_outflow_height(channel, sequence, recorded_at)
+        .wrap_err("failed to write ics20 outflow height to storage")
+}
+
+/// Reverses the transfer of `amount` of `asset` sent over `channel` in the packet with `sequence`,
+/// if it was recorded by [`record_ics20_outflow`].
+#[instrument(skip_all, fields(%channel, %sequence, %asset, amount), err(level = Level::DEBUG))]
+pub(crate) async fn reverse_ics20_outflow<'a, S, TAsset>(
+    mut state: S,
+    channel: &ChannelId,
+    sequence: Sequence,
+    asset: &'a TAsset,
+    amount: u128,
+) -> Result<()>
+where
+    S: StateWrite,
+    TAsset: Sync + Display,
+    &'a TAsset: Into<Cow<'a, asset::IbcPrefixed>>,
+{
+    let Some(recorded_at) = state
+        .get_ics20_outflow_height(channel, sequence)
+        .await
+        .wrap_err("failed to read ics20 outflow height from storage")?
+    else {
+        return Ok(());
+    };
+    state.delete_ics20_outflow_height(channel, sequence);
+    reverse_ics20_flow(
+        &mut state,
+        channel,
+        asset,
+        Direction::Outflow,
+        amount,
+        recorded_at,
+    )
+    .await
+}
+
+/// Returns the flow after the transfer together with the current block height, or `None` if the
+/// transfer is not rate limited.
+async fn flow_after_transfer<'a, S, TAsset>(
+    state: &S,
+    channel: &ChannelId,
+    asset: &'a TAsset,
+    direction: Direction,
+    amount: u128,
+) -> Result<Option<(Ics20Flow, u64)>>
+where
+    S: StateRead,
+    TAsset: Sync + Display,
+    &'a TAsset: Into<Cow<'a, asset::IbcPrefixed>>,
+{
+    let Some(rate_limit) = state
+        .get_ics20_rate_limit(channel, asset)
+        .await
+        .wrap_err("failed to read ics20 rate limit from storage")?
+    else {
+        return Ok(None);
+    };
+    let height = state
+        .get_block_height()
+        .await
+        .wrap_err("failed to read block height from storage")?;
+    let mut flow = state
+        .get_ics20_flow(channel, asset)
+        .await
+        .wrap_err("failed to read ics20 flow from storage")?
+        .unwrap_or_default()
+        .at_height(&rate_limit, height);
+    flow.add(&rate_limit, height, direction, amount)?;
+
+    let (total, max) = match direction {
+        Direction::Inflow => (flow.inflow(), rate_limit.max_inflow),
+        Direction::Outflow => (flow.outflow(), rate_limit.max_outflow),
+    };
+    ensure!(
+        total <= max,
+        "ics20 transfer of `{amount}` exceeds rate limit on channel `{channel}`: `{total}` \
+         transferred in window starting at height `{start}` but at most `{max}` permitted",
+        start = flow.window_start_height(&rate_limit, height),
+    );
+    Ok(Some((flow, height)))
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::primitive::v1::asset::Denom;
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        app::StateWriteExt as _,
+        test_utils::assert_error_contains,
+    };
+
+    fn asset() -> Denom {
+        "transfer/channel-0/uatom".parse().unwrap()
+    }
+
+    const RATE_LIMIT: Ics20RateLimit = Ics20RateLimit {
+        max_inflow: 100,
+        max_outflow: 10,
+        window_blocks: 5,
+    };
+
+    fn bucket(height: u64, inflow: u128, outflow: u128) -> Ics20FlowBucket {
+        Ics20FlowBucket {
+            start_height: height,
+            end_height: height + 1,
+            inflow,
+            outflow,
+        }
+    }
+
+    async fn outflow<S: StateRead>(state: &S, channel: &ChannelId) -> u128 {
+        state
+            .get_ics20_flow(channel, &asset())
+            .await
+            .unwrap()
+            .unwrap()
+            .outflow()
+    }
+
+    #[test]
+    fn flow_only_counts_buckets_overlapping_rolling_window() {
+        let mut flow = Ics20Flow::default();
+        flow.add(&RATE_LIMIT, 10, Direction::Inflow, 1).unwrap();
+        flow.add(&RATE_LIMIT, 12, Direction::Outflow, 2).unwrap();
+        flow.add(&RATE_LIMIT, 12, Direction::Outflow, 3).unwrap();
+        assert_eq!(vec![bucket(10, 1, 0), bucket(12, 0, 5)], flow.buckets);
+
+        let flow_at_14 = flow.clone().at_height(&RATE_LIMIT, 14);
+        assert_eq!(flow, flow_at_14);
+        assert_eq!(10, flow_at_14.window_start_height(&RATE_LIMIT, 14));
+
+        let flow_at_15 = flow.clone().at_height(&RATE_LIMIT, 15);
+        assert_eq!(vec![bucket(12, 0, 5)], flow_at_15.buckets);
+        assert_eq!(0, flow_at_15.inflow());
+        assert_eq!(5, flow_at_15.outflow());
+        assert_eq!(11, flow_at_15.window_start_height(&RATE_LIMIT, 15));
+
+        assert_eq!(Ics20Flow::default(), flow.at_height(&RATE_LIMIT, 17));
+    }
+
+    #[test]
+    fn flow_buckets_span_a_fraction_of_long_windows() {
+        let rate_limit = Ics20RateLimit {
+            window_blocks: 1000,
+            ..RATE_LIMIT
+        };
+        let mut flow = Ics20Flow::default();
+        flow.add(&rate_limit, 15, Direction::Outflow, 1).unwrap();
+        flow.add(&rate_limit, 19, Direction::Outflow, 2).unwrap();
+        flow.add(&rate_limit, 20, Direction::Outflow, 3).unwrap();
+        assert_eq!(
+            vec![
+                Ics20FlowBucket {
+                    start_height: 10,
+                    end_height: 20,
+                    inflow: 0,
+                    outflow: 3,
+                },
+                Ics20FlowBucket {
+                    start_height: 20,
+                    end_height: 30,
+                    inflow: 0,
+                    outflow: 3,
+                },
+            ],
+            flow.buckets
+        );
+
+        // The first bucket is counted until the window no longer overlaps any of its blocks.
+        assert_eq!(6, flow.clone().at_height(&rate_limit, 1018).outflow());
+        assert_eq!(3, flow.at_height(&rate_limit, 1019).outflow());
+    }
+
+    #[tokio::test]
+    async fn unlimited_transfers_are_not_recorded() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let channel = ChannelId::new(0);
+
+        assert_eq!(
+            None,
+            record_ics20_flow(&mut state, &channel, &asset(), Direction::Inflow, u128::MAX)
+                .await
+                .unwrap()
+        );
+        assert!(state
+            .get_ics20_flow(&channel, &asset())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn transfers_exceeding_rate_limit_are_rejected_until_they_leave_rolling_window() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let channel = ChannelId::new(0);
+        state
+            .put_ics20_rate_limit(&channel, &asset(), RATE_LIMIT)
+            .unwrap();
+
+        state.put_block_height(1).unwrap();
+        assert_eq!(
+            Some(1),
+            record_ics20_flow(&mut state, &channel, &asset(), Direction::Outflow, 6)
+                .await
+                .unwrap()
+        );
+        record_ics20_flow(&mut state, &channel, &asset(), Direction::Inflow, 100)
+            .await
+            .unwrap();
+        state.put_block_height(3).unwrap();
+        record_ics20_flow(&mut state, &channel, &asset(), Direction::Outflow, 4)
+            .await
+            .unwrap();
+        let err = record_ics20_flow(&mut state, &channel, &asset(), Direction::Outflow, 1)
+            .await
+            .unwrap_err();
+        assert_error_contains(&err, "exceeds rate limit on channel `channel-0`");
+        assert_eq!(
+            Some(Ics20Flow {
+                buckets: vec![bucket(1, 100, 6), bucket(3, 0, 4)],
+            }),
+            state.get_ics20_flow(&channel, &asset()).await.unwrap()
+        );
+
+        // The transfers of block 1 left the window, those of block 3 are still counted.
+        state.put_block_height(6).unwrap();
+        check_ics20_flow(&state, &channel, &asset(), Direction::Outflow, 7)
+            .await
+            .unwrap_err();
+        record_ics20_flow(&mut state, &channel, &asset(), Direction::Outflow, 6)
+            .await
+            .unwrap();
+        assert_eq!(
+            Some(Ics20Flow {
+                buckets: vec![bucket(3, 0, 4), bucket(6, 0, 6)],
+            }),
+            state.get_ics20_flow(&channel, &asset()).await.unwrap()
+        );
+
+        // A new window does not start once the previous one elapsed: the transfers of block 6
+        // are counted until block 10.
+        state.put_block_height(7).unwrap();
+        check_ics20_flow(&state, &channel, &asset(), Direction::Outflow, 1)
+            .await
+            .unwrap_err();
+        state.put_block_height(8).unwrap();
+        check_ics20_flow(&state, &channel, &asset(), Direction::Outflow, 4)
+            .await
+            .unwrap();
+        check_ics20_flow(&state, &channel, &asset(), Direction::Outflow, 5)
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn reversed_transfers_are_removed_from_their_recorded_bucket_only() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let channel = ChannelId::new(0);
+        state
+            .put_ics20_rate_limit(&channel, &asset(), RATE_LIMIT)
+            .unwrap();
+
+        state.put_block_height(1).unwrap();
+        record_ics20_flow(&mut state, &channel, &asset(), Direction::Outflow, 6)
+            .await
+            .unwrap();
+        state.put_block_height(3).unwrap();
+        record_ics20_flow(&mut state, &channel, &asset(), Direction::Outflow, 4)
+            .await
+            .unwrap();
+        reverse_ics20_flow(&mut state, &channel, &asset(), Direction::Outflow, 2, 1)
+            .await
+            .unwrap();
+        assert_eq!(
+            Some(Ics20Flow {
+                buckets: vec![bucket(1, 0, 4), bucket(3, 0, 4)],
+            }),
+            state.get_ics20_flow(&channel, &asset()).await.unwrap()
+        );
+        check_ics20_flow(&state, &channel, &asset(), Direction::Outflow, 2)
+            .await
+            .unwrap();
+
+        // Reversals of transfers that left the window leave the recorded flow unchanged.
+        state.put_block_height(6).unwrap();
+        reverse_ics20_flow(&mut state, &channel, &asset(), Direction::Outflow, 4, 1)
+            .await
+            .unwrap();
+        check_ics20_flow(&state, &channel, &asset(), Direction::Outflow, 6)
+            .await
+            .unwrap();
+        check_ics20_flow(&state, &channel, &asset(), Direction::Outflow, 7)
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn refunds_after_window_rolled_over_do_not_free_up_the_new_window() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let channel = ChannelId::new(0);
+        state
+            .put_ics20_rate_limit(&channel, &asset(), RATE_LIMIT)
+            .unwrap();
+
+        state.put_block_height(1).unwrap();
+        record_ics20_outflow(&mut state, &channel, Sequence(1), &asset(), 10)
+            .await
+            .unwrap();
+        assert_eq!(
+            Some(1),
+            state
+                .get_ics20_outflow_height(&channel, Sequence(1))
+                .await
+                .unwrap()
+        );
+
+        // Fresh traffic fills the window after the first transfer left it.
+        state.put_block_height(6).unwrap();
+        record_ics20_outflow(&mut state, &channel, Sequence(2), &asset(), 10)
+            .await
+            .unwrap();
+
+        // The first transfer is refunded: it must not be subtracted from the fresh traffic.
+        state.put_block_height(7).unwrap();
+        reverse_ics20_outflow(&mut state, &channel, Sequence(1), &asset(), 10)
+            .await
+            .unwrap();
+        assert_eq!(10, outflow(&state, &channel).await);
+        assert!(state
+            .get_ics20_outflow_height(&channel, Sequence(1))
+            .await
+            .unwrap()
+            .is_none());
+        check_ics20_flow(&state, &channel, &asset(), Direction::Outflow, 1)
+            .await
+            .unwrap_err();
+
+        // Refunding the second transfer frees up the window.
+        reverse_ics20_outflow(&mut state, &channel, Sequence(2), &asset(), 10)
+            .await
+            .unwrap();
+        assert_eq!(0, outflow(&state, &channel).await);
+        check_ics20_flow(&state, &channel, &asset(), Direction::Outflow, 10)
+            .await
+            .unwrap();
+    }
+}
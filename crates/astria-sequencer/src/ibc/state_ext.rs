@@ -3,10 +3,14 @@ use std::{
     fmt::Display,
 };
 
-use astria_core::primitive::v1::{
-    asset,
-    TransactionId,
-    ADDRESS_LEN,
+use astria_core::{
+    primitive::v1::{
+        asset,
+        RollupId,
+        TransactionId,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1::action::Ics20RateLimit,
 };
 use astria_eyre::{
     anyhow_to_eyre,
@@ -18,11 +22,16 @@ use astria_eyre::{
     },
 };
 use async_trait::async_trait;
+use bytes::Bytes;
 use cnidarium::{
     StateRead,
     StateWrite,
 };
-use ibc_types::core::channel::ChannelId;
+use futures::TryStreamExt as _;
+use ibc_types::core::channel::{
+    packet::Sequence,
+    ChannelId,
+};
 use tracing::{
     debug,
     instrument,
@@ -35,6 +44,10 @@ use super::storage::{
 };
 use crate::{
     accounts::AddressBytes,
+    ibc::{
+        packet_forward::InFlightForward,
+        rate_limit::Ics20Flow,
+    },
     storage::StoredValue,
 };
 
@@ -90,9 +103,137 @@ pub(crate) trait StateReadExt: StateRead {
             .is_some())
     }
 
+    /// Returns the rate limit on ICS20 transfers of `asset` over `channel`, if any.
+    #[instrument(skip_all, fields(%channel, %asset), err(level = Level::WARN))]
+    async fn get_ics20_rate_limit<'a, TAsset>(
+        &self,
+        channel: &ChannelId,
+        asset: &'a TAsset,
+    ) -> Result<Option<Ics20RateLimit>>
+    where
+        TAsset: Sync + Display,
+        &'a TAsset: Into<Cow<'a, asset::IbcPrefixed>>,
+    {
+        let Some(bytes) = self
+            .get_raw(&keys::ics20_rate_limit(channel, asset))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw ics20 rate limit from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::RateLimit::try_from(value).map(Ics20RateLimit::from))
+            .wrap_err("invalid ics20 rate limit bytes")
+            .map(Some)
+    }
+
+    /// Returns the rate limits on ICS20 transfers over `channel`, keyed by asset.
+    #[instrument(skip_all, fields(%channel), err(level = Level::WARN))]
+    async fn get_ics20_rate_limits(
+        &self,
+        channel: &ChannelId,
+    ) -> Result<Vec<(asset::IbcPrefixed, Ics20RateLimit)>> {
+        let mut stream = std::pin::pin!(self.prefix_raw(&keys::ics20_rate_limit_prefix(channel)));
+        let mut rate_limits = vec![];
+        while let Some((key, bytes)) = stream
+            .try_next()
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading ics20 rate limits from state")?
+        {
+            let asset = keys::extract_asset_from_ics20_rate_limit_key(channel, &key)?;
+            let rate_limit = StoredValue::deserialize(&bytes)
+                .and_then(|value| storage::RateLimit::try_from(value).map(Ics20RateLimit::from))
+                .wrap_err("invalid ics20 rate limit bytes")?;
+            rate_limits.push((asset, rate_limit));
+        }
+        Ok(rate_limits)
+    }
+
+    /// Returns the amounts of `asset` recorded as transferred over `channel` against its rate
+    /// limit, if any.
+    #[instrument(skip_all, fields(%channel, %asset), err(level = Level::WARN))]
+    async fn get_ics20_flow<'a, TAsset>(
+        &self,
+        channel: &ChannelId,
+        asset: &'a TAsset,
+    ) -> Result<Option<Ics20Flow>>
+    where
+        TAsset: Sync + Display,
+        &'a TAsset: Into<Cow<'a, asset::IbcPrefixed>>,
+    {
+        let Some(bytes) = self
+            .get_raw(&keys::ics20_rate_limit_flow(channel, asset))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw ics20 flow from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::RateLimitFlow::try_from(value).map(Ics20Flow::from))
+            .wrap_err("invalid ics20 flow bytes")
+            .map(Some)
+    }
+
+    /// Returns the height at which the transfer sent over `channel` in the packet with `sequence`
+    /// was recorded against the channel's rate limit, if any.
+    #[instrument(skip_all, fields(%channel, %sequence), err(level = Level::WARN))]
+    async fn get_ics20_outflow_height(
+        &self,
+        channel: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<Option<u64>> {
+        let Some(bytes) = self
+            .get_raw(&keys::ics20_outflow_height(channel, sequence))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw ics20 outflow height from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::BlockHeight::try_from(value).map(u64::from))
+            .wrap_err("invalid ics20 outflow height bytes")
+            .map(Some)
+    }
+
+    /// Returns the forward awaiting the acknowledgement or timeout of the packet sent over
+    /// `channel` with `sequence`, if any.
+    #[instrument(skip_all, fields(%channel, %sequence), err(level = Level::WARN))]
+    async fn get_in_flight_forward(
+        &self,
+        channel: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<Option<InFlightForward>> {
+        let Some(bytes) = self
+            .get_raw(&keys::in_flight_forward(channel, sequence))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw in-flight forward from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::PacketForward::try_from(value).and_then(InFlightForward::try_from)
+            })
+            .wrap_err("invalid in-flight forward bytes")
+            .map(Some)
+    }
+
     fn ephemeral_get_ibc_context(&mut self) -> Option<Context> {
         self.object_get(keys::CONTEXT_EPHEMERAL)
     }
+
+    /// Returns the rollup data submitted by interchain accounts in the current block, in order of
+    /// execution.
+    #[instrument(skip_all)]
+    fn get_cached_block_interchain_rollup_data(&self) -> Vec<(RollupId, Bytes)> {
+        self.object_get(keys::INTERCHAIN_ROLLUP_DATA_EPHEMERAL)
+            .unwrap_or_default()
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
@@ -164,6 +305,102 @@ pub(crate) trait StateWriteExt: StateWrite {
         self.delete(keys::ibc_relayer(address));
     }
 
+    #[instrument(skip_all, fields(%channel, %asset), err(level = Level::WARN))]
+    fn put_ics20_rate_limit<'a, TAsset>(
+        &mut self,
+        channel: &ChannelId,
+        asset: &'a TAsset,
+        rate_limit: Ics20RateLimit,
+    ) -> Result<()>
+    where
+        TAsset: Display,
+        &'a TAsset: Into<Cow<'a, asset::IbcPrefixed>>,
+    {
+        let bytes = StoredValue::from(storage::RateLimit::from(rate_limit))
+            .serialize()
+            .wrap_err("failed to serialize ics20 rate limit")?;
+        self.put_raw(keys::ics20_rate_limit(channel, asset), bytes);
+        Ok(())
+    }
+
+    /// Deletes the rate limit on ICS20 transfers of `asset` over `channel`, together with the
+    /// amounts transferred in its current window.
+    #[instrument(skip_all, fields(%channel, %asset))]
+    fn delete_ics20_rate_limit<'a, TAsset>(&mut self, channel: &ChannelId, asset: &'a TAsset)
+    where
+        TAsset: Display,
+        &'a TAsset: Into<Cow<'a, asset::IbcPrefixed>>,
+    {
+        self.delete(keys::ics20_rate_limit(channel, asset));
+        self.delete(keys::ics20_rate_limit_flow(channel, asset));
+    }
+
+    #[instrument(skip_all, fields(%channel, %asset), err(level = Level::WARN))]
+    fn put_ics20_flow<'a, TAsset>(
+        &mut self,
+        channel: &ChannelId,
+        asset: &'a TAsset,
+        flow: Ics20Flow,
+    ) -> Result<()>
+    where
+        TAsset: Display,
+        &'a TAsset: Into<Cow<'a, asset::IbcPrefixed>>,
+    {
+        let bytes = StoredValue::from(storage::RateLimitFlow::from(flow))
+            .serialize()
+            .wrap_err("failed to serialize ics20 flow")?;
+        self.put_raw(keys::ics20_rate_limit_flow(channel, asset), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(%channel, %sequence, height), err(level = Level::WARN))]
+    fn put_ics20_outflow_height(
+        &mut self,
+        channel: &ChannelId,
+        sequence: Sequence,
+        height: u64,
+    ) -> Result<()> {
+        let bytes = StoredValue::from(storage::BlockHeight::from(height))
+            .serialize()
+            .wrap_err("failed to serialize ics20 outflow height")?;
+        self.put_raw(keys::ics20_outflow_height(channel, sequence), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(%channel, %sequence))]
+    fn delete_ics20_outflow_height(&mut self, channel: &ChannelId, sequence: Sequence) {
+        self.delete(keys::ics20_outflow_height(channel, sequence));
+    }
+
+    #[instrument(skip_all, fields(%channel, %sequence), err(level = Level::WARN))]
+    fn put_in_flight_forward(
+        &mut self,
+        channel: &ChannelId,
+        sequence: Sequence,
+        forward: &InFlightForward,
+    ) -> Result<()> {
+        let bytes = StoredValue::from(storage::PacketForward::from(forward))
+            .serialize()
+            .wrap_err("failed to serialize in-flight forward")?;
+        self.put_raw(keys::in_flight_forward(channel, sequence), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(%channel, %sequence))]
+    fn delete_in_flight_forward(&mut self, channel: &ChannelId, sequence: Sequence) {
+        self.delete(keys::in_flight_forward(channel, sequence));
+    }
+
+    /// Push the rollup data submitted by an interchain account onto the end of the rollup data
+    /// submitted by interchain accounts in this block. These are held in state's ephemeral store,
+    /// pending being included in the block's rollup data.
+    #[instrument(skip_all, fields(%rollup_id))]
+    fn cache_interchain_rollup_data(&mut self, rollup_id: RollupId, data: Bytes) {
+        let mut cached_data = self.get_cached_block_interchain_rollup_data();
+        cached_data.push((rollup_id, data));
+        self.object_put(keys::INTERCHAIN_ROLLUP_DATA_EPHEMERAL, cached_data);
+    }
+
     fn ephemeral_put_ibc_context(&mut self, tx_id: TransactionId, position_in_tx: u64) {
         self.object_put(
             keys::CONTEXT_EPHEMERAL,
@@ -190,7 +427,10 @@ mod tests {
     use super::*;
     use crate::{
         address::StateWriteExt,
-        ibc::StateWriteExt as _,
+        ibc::{
+            rate_limit::Ics20FlowBucket,
+            StateWriteExt as _,
+        },
         test_utils::{
             astria_address,
             ASTRIA_PREFIX,
@@ -472,4 +712,179 @@ mod tests {
             "set balance for channel/asset pair not what was expected"
         );
     }
+
+    #[tokio::test]
+    async fn ics20_rate_limits_round_trip() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let channel = ChannelId::new(0u64);
+        let other_channel = ChannelId::new(1u64);
+        let rate_limit = Ics20RateLimit {
+            max_inflow: 100,
+            max_outflow: 50,
+            window_blocks: 10,
+        };
+        assert!(state
+            .get_ics20_rate_limit(&channel, &asset_0())
+            .await
+            .unwrap()
+            .is_none());
+
+        state
+            .put_ics20_rate_limit(&channel, &asset_0(), rate_limit)
+            .unwrap();
+        state
+            .put_ics20_rate_limit(&channel, &asset_1(), rate_limit)
+            .unwrap();
+        state
+            .put_ics20_rate_limit(&other_channel, &asset_0(), rate_limit)
+            .unwrap();
+        let flow = Ics20Flow {
+            buckets: vec![Ics20FlowBucket {
+                start_height: 5,
+                end_height: 6,
+                inflow: 1,
+                outflow: 2,
+            }],
+        };
+        state
+            .put_ics20_flow(&channel, &asset_0(), flow.clone())
+            .unwrap();
+
+        assert_eq!(
+            Some(rate_limit),
+            state
+                .get_ics20_rate_limit(&channel, &asset_0())
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            Some(flow),
+            state.get_ics20_flow(&channel, &asset_0()).await.unwrap()
+        );
+        let mut rate_limits = state.get_ics20_rate_limits(&channel).await.unwrap();
+        rate_limits.sort_unstable_by_key(|(asset, _)| *asset.as_bytes());
+        let mut expected = vec![
+            (asset_0().to_ibc_prefixed(), rate_limit),
+            (asset_1().to_ibc_prefixed(), rate_limit),
+        ];
+        expected.sort_unstable_by_key(|(asset, _)| *asset.as_bytes());
+        assert_eq!(expected, rate_limits);
+
+        state.delete_ics20_rate_limit(&channel, &asset_0());
+        assert!(state
+            .get_ics20_rate_limit(&channel, &asset_0())
+            .await
+            .unwrap()
+            .is_none());
+        assert!(state
+            .get_ics20_flow(&channel, &asset_0())
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            1,
+            state.get_ics20_rate_limits(&channel).await.unwrap().len()
+        );
+    }
+
+    #[tokio::test]
+    async fn ics20_outflow_height_round_trip() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let channel = ChannelId::new(0u64);
+        assert!(state
+            .get_ics20_outflow_height(&channel, Sequence(1))
+            .await
+            .unwrap()
+            .is_none());
+
+        state
+            .put_ics20_outflow_height(&channel, Sequence(1), 5)
+            .unwrap();
+        assert_eq!(
+            Some(5),
+            state
+                .get_ics20_outflow_height(&channel, Sequence(1))
+                .await
+                .unwrap()
+        );
+        assert!(state
+            .get_ics20_outflow_height(&channel, Sequence(2))
+            .await
+            .unwrap()
+            .is_none());
+
+        state.delete_ics20_outflow_height(&channel, Sequence(1));
+        assert!(state
+            .get_ics20_outflow_height(&channel, Sequence(1))
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn in_flight_forward_round_trip() {
+        use ibc_types::{
+            core::{
+                channel::{
+                    Packet,
+                    PortId,
+                    TimeoutHeight,
+                },
+                client::Height,
+            },
+            timestamp::Timestamp,
+        };
+
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let channel = ChannelId::new(0u64);
+        let sequence = Sequence(3);
+        let forward = InFlightForward {
+            inbound_packet: Packet {
+                sequence: Sequence(1),
+                port_on_a: PortId::transfer(),
+                chan_on_a: ChannelId::new(5u64),
+                port_on_b: PortId::transfer(),
+                chan_on_b: ChannelId::new(1u64),
+                data: b"data".to_vec(),
+                timeout_height_on_b: TimeoutHeight::At(Height::new(1, 10).unwrap()),
+                timeout_timestamp_on_b: Timestamp::from_nanoseconds(1_000).unwrap(),
+            },
+            retries_remaining: 2,
+            timeout: std::time::Duration::from_secs(60),
+            inflow_recorded_at: Some(4),
+            outflow_recorded_at: None,
+        };
+        assert!(state
+            .get_in_flight_forward(&channel, sequence)
+            .await
+            .unwrap()
+            .is_none());
+
+        state
+            .put_in_flight_forward(&channel, sequence, &forward)
+            .unwrap();
+        assert_eq!(
+            Some(forward),
+            state
+                .get_in_flight_forward(&channel, sequence)
+                .await
+                .unwrap()
+        );
+
+        state.delete_in_flight_forward(&channel, sequence);
+        assert!(state
+            .get_in_flight_forward(&channel, sequence)
+            .await
+            .unwrap()
+            .is_none());
+    }
 }
@@ -1,7 +1,15 @@
 use std::borrow::Cow;
 
 use astria_core::primitive::v1::asset::IbcPrefixed;
-use ibc_types::core::channel::ChannelId;
+use astria_eyre::eyre::{
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use ibc_types::core::channel::{
+    packet::Sequence,
+    ChannelId,
+};
 
 use crate::{
     accounts::AddressBytes,
@@ -13,6 +21,7 @@ use crate::{
 
 pub(in crate::ibc) const IBC_SUDO: &str = "ibc/sudo";
 pub(in crate::ibc) const CONTEXT_EPHEMERAL: &str = "ibc/context";
+pub(in crate::ibc) const INTERCHAIN_ROLLUP_DATA_EPHEMERAL: &str = "ibc/interchain_rollup_data";
 const IBC_RELAYER_PREFIX: &str = "ibc/relayer/";
 
 /// Example: `ibc/channel-xxx/balance/ibc/0101....0101`.
@@ -24,6 +33,58 @@ where
     format!("ibc/{channel}/balance/{}", Asset::from(asset))
 }
 
+/// Example: `ibc/channel-xxx/rate_limit/`.
+///                      |int|
+pub(in crate::ibc) fn ics20_rate_limit_prefix(channel: &ChannelId) -> String {
+    format!("ibc/{channel}/rate_limit/")
+}
+
+/// Example: `ibc/channel-xxx/rate_limit/ibc/0101....0101`.
+///                      |int|              |64 hex chars|
+pub(in crate::ibc) fn ics20_rate_limit<'a, TAsset>(channel: &ChannelId, asset: &'a TAsset) -> String
+where
+    &'a TAsset: Into<Cow<'a, IbcPrefixed>>,
+{
+    format!("{}{}", ics20_rate_limit_prefix(channel), Asset::from(asset))
+}
+
+/// Example: `ibc/channel-xxx/rate_limit_flow/ibc/0101....0101`.
+///                      |int|                   |64 hex chars|
+pub(in crate::ibc) fn ics20_rate_limit_flow<'a, TAsset>(
+    channel: &ChannelId,
+    asset: &'a TAsset,
+) -> String
+where
+    &'a TAsset: Into<Cow<'a, IbcPrefixed>>,
+{
+    format!("ibc/{channel}/rate_limit_flow/{}", Asset::from(asset))
+}
+
+/// Extracts the asset from a key created by [`ics20_rate_limit`] for the given channel.
+pub(in crate::ibc) fn extract_asset_from_ics20_rate_limit_key(
+    channel: &ChannelId,
+    key: &str,
+) -> Result<IbcPrefixed> {
+    Ok(key
+        .strip_prefix(&ics20_rate_limit_prefix(channel))
+        .ok_or_eyre("failed to strip prefix from ics20 rate limit key")?
+        .parse::<Asset>()
+        .wrap_err("failed to parse storage key suffix as asset")?
+        .get())
+}
+
+/// Example: `ibc/channel-xxx/in_flight_forward/5`.
+///                      |int|                   |int|
+pub(in crate::ibc) fn in_flight_forward(channel: &ChannelId, sequence: Sequence) -> String {
+    format!("ibc/{channel}/in_flight_forward/{sequence}")
+}
+
+/// Example: `ibc/channel-xxx/outflow_height/5`.
+///                      |int|                |int|
+pub(in crate::ibc) fn ics20_outflow_height(channel: &ChannelId, sequence: Sequence) -> String {
+    format!("ibc/{channel}/outflow_height/{sequence}")
+}
+
 /// Example: `ibc/relayer/gGhH....zZ4=`.
 ///                      |base64 chars|
 pub(in crate::ibc) fn ibc_relayer<T: AddressBytes>(address: &T) -> String {
@@ -59,18 +120,58 @@ mod tests {
     fn keys_should_not_change() {
         insta::assert_snapshot!("ibc_sudo_key", IBC_SUDO);
         insta::assert_snapshot!("ibc_context_key", CONTEXT_EPHEMERAL);
+        insta::assert_snapshot!(
+            "interchain_rollup_data_key",
+            INTERCHAIN_ROLLUP_DATA_EPHEMERAL
+        );
         insta::assert_snapshot!(
             "channel_balance_key",
             channel_balance(&channel_id(), &asset())
         );
         insta::assert_snapshot!("ibc_relayer_key", ibc_relayer(&address()));
+        insta::assert_snapshot!(
+            "ics20_rate_limit_key",
+            ics20_rate_limit(&channel_id(), &asset())
+        );
+        insta::assert_snapshot!(
+            "ics20_rate_limit_flow_key",
+            ics20_rate_limit_flow(&channel_id(), &asset())
+        );
+        insta::assert_snapshot!(
+            "in_flight_forward_key",
+            in_flight_forward(&channel_id(), Sequence(7))
+        );
+        insta::assert_snapshot!(
+            "ics20_outflow_height_key",
+            ics20_outflow_height(&channel_id(), Sequence(7))
+        );
     }
 
     #[test]
     fn keys_should_have_component_prefix() {
         assert!(IBC_SUDO.starts_with(COMPONENT_PREFIX));
         assert!(CONTEXT_EPHEMERAL.starts_with(COMPONENT_PREFIX));
+        assert!(INTERCHAIN_ROLLUP_DATA_EPHEMERAL.starts_with(COMPONENT_PREFIX));
         assert!(channel_balance(&channel_id(), &asset()).starts_with(COMPONENT_PREFIX));
         assert!(ibc_relayer(&address()).starts_with(COMPONENT_PREFIX));
+        assert!(ics20_rate_limit(&channel_id(), &asset()).starts_with(COMPONENT_PREFIX));
+        assert!(ics20_rate_limit_flow(&channel_id(), &asset()).starts_with(COMPONENT_PREFIX));
+        assert!(in_flight_forward(&channel_id(), Sequence(7)).starts_with(COMPONENT_PREFIX));
+        assert!(ics20_outflow_height(&channel_id(), Sequence(7)).starts_with(COMPONENT_PREFIX));
+    }
+
+    #[test]
+    fn ics20_rate_limit_prefix_should_be_prefix_of_only_ics20_rate_limit_key() {
+        let prefix = ics20_rate_limit_prefix(&channel_id());
+        assert!(ics20_rate_limit(&channel_id(), &asset()).starts_with(&prefix));
+        assert!(!ics20_rate_limit_flow(&channel_id(), &asset()).starts_with(&prefix));
+    }
+
+    #[test]
+    fn should_extract_asset_from_ics20_rate_limit_key() {
+        let asset = IbcPrefixed::new([2; 32]);
+        let key = ics20_rate_limit(&channel_id(), &asset);
+        let recovered_asset = extract_asset_from_ics20_rate_limit_key(&channel_id(), &key).unwrap();
+        assert_eq!(asset, recovered_asset);
     }
 }
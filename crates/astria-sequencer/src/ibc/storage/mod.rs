@@ -5,4 +5,8 @@ pub(crate) use values::Value;
 pub(super) use values::{
     AddressBytes,
     Balance,
+    BlockHeight,
+    PacketForward,
+    RateLimit,
+    RateLimitFlow,
 };
@@ -7,15 +7,32 @@ use std::{
     },
 };
 
-use astria_core::primitive::v1::ADDRESS_LEN;
-use astria_eyre::eyre::bail;
+use astria_core::{
+    primitive::v1::ADDRESS_LEN,
+    protocol::transaction::v1::action::Ics20RateLimit,
+};
+use astria_eyre::eyre::{
+    bail,
+    WrapErr as _,
+};
 use borsh::{
     BorshDeserialize,
     BorshSerialize,
 };
+use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
+use prost::Message as _;
 use telemetry::display::base64;
 
-use crate::accounts::AddressBytes as DomainAddressBytes;
+use crate::{
+    accounts::AddressBytes as DomainAddressBytes,
+    ibc::{
+        packet_forward::InFlightForward,
+        rate_limit::{
+            Ics20Flow,
+            Ics20FlowBucket,
+        },
+    },
+};
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
 pub(crate) struct Value<'a>(ValueImpl<'a>);
@@ -24,6 +41,10 @@ pub(crate) struct Value<'a>(ValueImpl<'a>);
 enum ValueImpl<'a> {
     Balance(Balance),
     AddressBytes(AddressBytes<'a>),
+    RateLimit(RateLimit),
+    RateLimitFlow(RateLimitFlow),
+    PacketForward(PacketForward),
+    BlockHeight(BlockHeight),
 }
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
@@ -97,6 +118,206 @@ impl<'a> TryFrom<crate::storage::StoredValue<'a>> for AddressBytes<'a> {
     }
 }
 
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::ibc) struct RateLimit {
+    max_inflow: u128,
+    max_outflow: u128,
+    window_blocks: u64,
+}
+
+impl From<Ics20RateLimit> for RateLimit {
+    fn from(rate_limit: Ics20RateLimit) -> Self {
+        RateLimit {
+            max_inflow: rate_limit.max_inflow,
+            max_outflow: rate_limit.max_outflow,
+            window_blocks: rate_limit.window_blocks,
+        }
+    }
+}
+
+impl From<RateLimit> for Ics20RateLimit {
+    fn from(rate_limit: RateLimit) -> Self {
+        Ics20RateLimit {
+            max_inflow: rate_limit.max_inflow,
+            max_outflow: rate_limit.max_outflow,
+            window_blocks: rate_limit.window_blocks,
+        }
+    }
+}
+
+impl From<RateLimit> for crate::storage::StoredValue<'_> {
+    fn from(rate_limit: RateLimit) -> Self {
+        crate::storage::StoredValue::Ibc(Value(ValueImpl::RateLimit(rate_limit)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for RateLimit {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Ibc(Value(ValueImpl::RateLimit(rate_limit))) = value
+        else {
+            bail!("ibc stored value type mismatch: expected rate limit, found {value:?}");
+        };
+        Ok(rate_limit)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::ibc) struct RateLimitFlow {
+    buckets: Vec<RateLimitFlowBucket>,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+struct RateLimitFlowBucket {
+    start_height: u64,
+    end_height: u64,
+    inflow: u128,
+    outflow: u128,
+}
+
+impl From<Ics20Flow> for RateLimitFlow {
+    fn from(flow: Ics20Flow) -> Self {
+        RateLimitFlow {
+            buckets: flow
+                .buckets
+                .into_iter()
+                .map(|bucket| RateLimitFlowBucket {
+                    start_height: bucket.start_height,
+                    end_height: bucket.end_height,
+                    inflow: bucket.inflow,
+                    outflow: bucket.outflow,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<RateLimitFlow> for Ics20Flow {
+    fn from(flow: RateLimitFlow) -> Self {
+        Ics20Flow {
+            buckets: flow
+                .buckets
+                .into_iter()
+                .map(|bucket| Ics20FlowBucket {
+                    start_height: bucket.start_height,
+                    end_height: bucket.end_height,
+                    inflow: bucket.inflow,
+                    outflow: bucket.outflow,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<RateLimitFlow> for crate::storage::StoredValue<'_> {
+    fn from(flow: RateLimitFlow) -> Self {
+        crate::storage::StoredValue::Ibc(Value(ValueImpl::RateLimitFlow(flow)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for RateLimitFlow {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Ibc(Value(ValueImpl::RateLimitFlow(flow))) = value else {
+            bail!("ibc stored value type mismatch: expected rate limit flow, found {value:?}");
+        };
+        Ok(flow)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::ibc) struct PacketForward {
+    inbound_packet: Vec<u8>,
+    retries_remaining: u8,
+    timeout_nanos: u64,
+    inflow_recorded_at: Option<u64>,
+    outflow_recorded_at: Option<u64>,
+}
+
+impl From<&InFlightForward> for PacketForward {
+    fn from(forward: &InFlightForward) -> Self {
+        PacketForward {
+            inbound_packet: RawPacket::from(forward.inbound_packet.clone()).encode_to_vec(),
+            retries_remaining: forward.retries_remaining,
+            // Forward timeouts are parsed from u64 nanoseconds, so this never saturates.
+            timeout_nanos: u64::try_from(forward.timeout.as_nanos()).unwrap_or(u64::MAX),
+            inflow_recorded_at: forward.inflow_recorded_at,
+            outflow_recorded_at: forward.outflow_recorded_at,
+        }
+    }
+}
+
+impl TryFrom<PacketForward> for InFlightForward {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(forward: PacketForward) -> Result<Self, Self::Error> {
+        let raw_packet = RawPacket::decode(forward.inbound_packet.as_slice())
+            .wrap_err("failed to decode inbound packet as protobuf")?;
+        let inbound_packet = raw_packet
+            .try_into()
+            .wrap_err("failed to convert raw inbound packet to domain type")?;
+        Ok(InFlightForward {
+            inbound_packet,
+            retries_remaining: forward.retries_remaining,
+            timeout: std::time::Duration::from_nanos(forward.timeout_nanos),
+            inflow_recorded_at: forward.inflow_recorded_at,
+            outflow_recorded_at: forward.outflow_recorded_at,
+        })
+    }
+}
+
+impl From<PacketForward> for crate::storage::StoredValue<'_> {
+    fn from(forward: PacketForward) -> Self {
+        crate::storage::StoredValue::Ibc(Value(ValueImpl::PacketForward(forward)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for PacketForward {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Ibc(Value(ValueImpl::PacketForward(forward))) = value
+        else {
+            bail!("ibc stored value type mismatch: expected packet forward, found {value:?}");
+        };
+        Ok(forward)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::ibc) struct BlockHeight(u64);
+
+impl From<u64> for BlockHeight {
+    fn from(height: u64) -> Self {
+        BlockHeight(height)
+    }
+}
+
+impl From<BlockHeight> for u64 {
+    fn from(height: BlockHeight) -> Self {
+        height.0
+    }
+}
+
+impl From<BlockHeight> for crate::storage::StoredValue<'_> {
+    fn from(height: BlockHeight) -> Self {
+        crate::storage::StoredValue::Ibc(Value(ValueImpl::BlockHeight(height)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for BlockHeight {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Ibc(Value(ValueImpl::BlockHeight(height))) = value else {
+            bail!("ibc stored value type mismatch: expected block height, found {value:?}");
+        };
+        Ok(height)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use insta::assert_snapshot;
@@ -114,6 +335,39 @@ mod tests {
             "value_impl_address_bytes",
             borsh_then_hex(&ValueImpl::AddressBytes((&[0; ADDRESS_LEN]).into()))
         );
+        assert_snapshot!(
+            "value_impl_rate_limit",
+            borsh_then_hex(&ValueImpl::RateLimit(RateLimit {
+                max_inflow: 1,
+                max_outflow: 2,
+                window_blocks: 3,
+            }))
+        );
+        assert_snapshot!(
+            "value_impl_rate_limit_flow",
+            borsh_then_hex(&ValueImpl::RateLimitFlow(RateLimitFlow {
+                buckets: vec![RateLimitFlowBucket {
+                    start_height: 4,
+                    end_height: 5,
+                    inflow: 6,
+                    outflow: 7,
+                }],
+            }))
+        );
+        assert_snapshot!(
+            "value_impl_packet_forward",
+            borsh_then_hex(&ValueImpl::PacketForward(PacketForward {
+                inbound_packet: vec![1, 2, 3],
+                retries_remaining: 4,
+                timeout_nanos: 5,
+                inflow_recorded_at: Some(6),
+                outflow_recorded_at: None,
+            }))
+        );
+        assert_snapshot!(
+            "value_impl_block_height",
+            borsh_then_hex(&ValueImpl::BlockHeight(BlockHeight(8)))
+        );
     }
 
     // Note: This test must be here instead of in `crate::storage` since `ValueImpl` is not
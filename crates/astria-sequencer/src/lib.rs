@@ -21,6 +21,7 @@ pub(crate) mod checked_transaction;
 pub(crate) mod component;
 pub mod config;
 pub(crate) mod fees;
+pub(crate) mod governance;
 pub(crate) mod grpc;
 pub(crate) mod ibc;
 mod mempool;
@@ -29,6 +30,8 @@ pub(crate) mod oracles;
 pub(crate) mod proposal;
 mod sequencer;
 pub(crate) mod service;
+pub(crate) mod staking;
+pub(crate) mod state_sync;
 pub(crate) mod storage;
 #[cfg(any(test, feature = "benchmark"))]
 pub(crate) mod test_utils;
@@ -30,6 +30,7 @@ use crate::{
     },
     benchmark_utils::SIGNER_COUNT,
     mempool::{
+        BuilderQueuePolicy,
         Mempool,
         RemovalReason,
     },
@@ -106,7 +107,7 @@ fn init_mempool<T: MempoolSize>() -> Mempool {
         .build()
         .unwrap();
     let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-    let mempool = Mempool::new(metrics, T::size());
+    let mempool = Mempool::new(metrics, T::size(), BuilderQueuePolicy::default(), false);
     let account_mock_balance = mock_balances(0, 0);
     let tx_mock_cost = mock_tx_cost(0, 0, 0);
     runtime.block_on(async {
@@ -117,7 +118,7 @@ fn init_mempool<T: MempoolSize>() -> Mempool {
                     0,
                     &account_mock_balance.clone(),
                     tx_mock_cost.clone(),
-                )
+                None,)
                 .await
                 .unwrap();
         }
@@ -170,7 +171,7 @@ fn insert<T: MempoolSize>(bencher: divan::Bencher) {
         .bench_values(move |(mempool, tx, mock_balances, mock_tx_cost)| {
             runtime.block_on(async {
                 mempool
-                    .insert(tx, 0, &mock_balances, mock_tx_cost)
+                    .insert(tx, 0, &mock_balances, mock_tx_cost, None)
                     .await
                     .unwrap();
             });
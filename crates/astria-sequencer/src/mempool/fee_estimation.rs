@@ -0,0 +1,275 @@
+use std::collections::VecDeque;
+
+use crate::proposal::block_size_constraints::MAX_SEQUENCE_DATA_BYTES_PER_BLOCK;
+
+/// Max number of recent blocks whose fullness is reported in fee estimates.
+const RECENT_BLOCKS_CAPACITY: usize = 20;
+
+/// The number of encoded transaction bytes fee rates are expressed per.
+///
+/// Fees are commonly of a similar magnitude to transaction sizes, so a rate per single byte would
+/// round most transactions down to the same few values.
+pub(super) const FEE_RATE_BYTES: u128 = 1_000;
+
+/// The size of a finalized block's user submitted contents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct BlockFullness {
+    pub(crate) height: u64,
+    pub(crate) transaction_count: usize,
+    pub(crate) transaction_bytes: usize,
+    /// The total size of the rollup data in the block, limited to
+    /// [`MAX_SEQUENCE_DATA_BYTES_PER_BLOCK`].
+    pub(crate) rollup_data_bytes: usize,
+    /// The maximum size of the block as set by the consensus params, which limits
+    /// `transaction_bytes`. `None` if the consensus params are not known.
+    pub(crate) max_block_bytes: Option<usize>,
+}
+
+/// A pending transaction's fee rate and the block space it takes up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct PendingTransaction {
+    /// The fees paid per [`FEE_RATE_BYTES`] bytes of the encoded transaction, rounded down.
+    pub(super) fee_per_kb: u128,
+    pub(super) transaction_bytes: usize,
+    pub(super) rollup_data_bytes: usize,
+}
+
+/// The pending transactions paying a fee rate in `[min_fee_per_kb, 2 * min_fee_per_kb)`, or
+/// less than one per [`FEE_RATE_BYTES`] bytes if `min_fee_per_kb` is zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct FeeLevel {
+    pub(crate) min_fee_per_kb: u128,
+    pub(crate) transaction_count: usize,
+    pub(crate) transaction_bytes: usize,
+    pub(crate) rollup_data_bytes: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct FeeEstimate {
+    /// The fullness of the most recent blocks, ordered by ascending height.
+    pub(crate) recent_blocks: Vec<BlockFullness>,
+    /// The pending transactions grouped by fee rate, ordered by descending fee rate.
+    pub(crate) fee_levels: Vec<FeeLevel>,
+    /// The fee rate per [`FEE_RATE_BYTES`] bytes a transaction must at least pay to be included
+    /// within the target number of blocks.
+    pub(crate) recommended_fee_per_kb: u128,
+}
+
+/// A bounded record of the fullness of the most recently committed blocks.
+pub(super) struct RecentBlocks {
+    blocks: VecDeque<BlockFullness>,
+}
+
+impl RecentBlocks {
+    pub(super) fn new() -> Self {
+        Self {
+            blocks: VecDeque::with_capacity(RECENT_BLOCKS_CAPACITY),
+        }
+    }
+
+    pub(super) fn record(&mut self, block: BlockFullness) {
+        if self.blocks.len() >= RECENT_BLOCKS_CAPACITY {
+            self.blocks.pop_front();
+        }
+        self.blocks.push_back(block);
+    }
+
+    pub(super) fn to_vec(&self) -> Vec<BlockFullness> {
+        self.blocks.iter().copied().collect()
+    }
+
+    /// Returns the maximum block size of the most recent block, if known.
+    pub(super) fn latest_max_block_bytes(&self) -> Option<usize> {
+        self.blocks.back().and_then(|block| block.max_block_bytes)
+    }
+}
+
+/// Returns the lowest fee rate of the power-of-two fee level containing `fee_per_kb`.
+pub(super) fn fee_level_floor(fee_per_kb: u128) -> u128 {
+    if fee_per_kb == 0 {
+        0
+    } else {
+        1_u128 << fee_per_kb.ilog2()
+    }
+}
+
+/// Groups pending transactions into power-of-two fee levels ordered by descending fee rate.
+pub(super) fn fee_levels(pending: &[PendingTransaction]) -> Vec<FeeLevel> {
+    let mut levels: Vec<FeeLevel> = Vec::new();
+    for tx in pending {
+        let min_fee_per_kb = fee_level_floor(tx.fee_per_kb);
+        if let Some(level) = levels
+            .iter_mut()
+            .find(|level| level.min_fee_per_kb == min_fee_per_kb)
+        {
+            level.transaction_count = level.transaction_count.saturating_add(1);
+            level.transaction_bytes = level.transaction_bytes.saturating_add(tx.transaction_bytes);
+            level.rollup_data_bytes = level.rollup_data_bytes.saturating_add(tx.rollup_data_bytes);
+        } else {
+            levels.push(FeeLevel {
+                min_fee_per_kb,
+                transaction_count: 1,
+                transaction_bytes: tx.transaction_bytes,
+                rollup_data_bytes: tx.rollup_data_bytes,
+            });
+        }
+    }
+    levels.sort_unstable_by(|a, b| b.min_fee_per_kb.cmp(&a.min_fee_per_kb));
+    levels
+}
+
+/// Returns the fee rate per [`FEE_RATE_BYTES`] bytes a new transaction must pay to outbid enough
+/// pending transactions to fit into `target_blocks` blocks.
+///
+/// Blocks are limited both in the total size of their rollup data and, if `max_block_bytes` is
+/// known, in the total size of their encoded transactions. Returns zero if all pending
+/// transactions fit. This ignores nonce and action group ordering, as well as transactions
+/// arriving later, so is only an approximation.
+pub(super) fn recommended_fee_per_kb(
+    mut pending: Vec<PendingTransaction>,
+    target_blocks: u32,
+    max_block_bytes: Option<usize>,
+) -> u128 {
+    let target_blocks = usize::try_from(target_blocks.max(1)).unwrap_or(usize::MAX);
+    let rollup_data_capacity = MAX_SEQUENCE_DATA_BYTES_PER_BLOCK.saturating_mul(target_blocks);
+    let transaction_capacity =
+        max_block_bytes.map_or(usize::MAX, |max| max.saturating_mul(target_blocks));
+    pending.sort_unstable_by(|a, b| b.fee_per_kb.cmp(&a.fee_per_kb));
+    let mut rollup_data_used = 0_usize;
+    let mut transaction_bytes_used = 0_usize;
+    for tx in pending {
+        rollup_data_used = rollup_data_used.saturating_add(tx.rollup_data_bytes);
+        transaction_bytes_used = transaction_bytes_used.saturating_add(tx.transaction_bytes);
+        if rollup_data_used > rollup_data_capacity || transaction_bytes_used > transaction_capacity
+        {
+            return tx.fee_per_kb.saturating_add(1);
+        }
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(height: u64) -> BlockFullness {
+        BlockFullness {
+            height,
+            transaction_count: 1,
+            transaction_bytes: 100,
+            rollup_data_bytes: 50,
+            max_block_bytes: Some(1_000),
+        }
+    }
+
+    fn pending(
+        fee_per_kb: u128,
+        transaction_bytes: usize,
+        rollup_data_bytes: usize,
+    ) -> PendingTransaction {
+        PendingTransaction {
+            fee_per_kb,
+            transaction_bytes,
+            rollup_data_bytes,
+        }
+    }
+
+    #[test]
+    fn recent_blocks_keeps_most_recent() {
+        let mut recent_blocks = RecentBlocks::new();
+        assert_eq!(recent_blocks.latest_max_block_bytes(), None);
+        let last_height = u64::try_from(RECENT_BLOCKS_CAPACITY).unwrap() + 5;
+        for height in 1..=last_height {
+            recent_blocks.record(block(height));
+        }
+
+        let blocks = recent_blocks.to_vec();
+        assert_eq!(blocks.len(), RECENT_BLOCKS_CAPACITY);
+        assert_eq!(blocks.first().unwrap().height, 6);
+        assert_eq!(blocks.last().unwrap().height, last_height);
+        assert_eq!(recent_blocks.latest_max_block_bytes(), Some(1_000));
+    }
+
+    #[test]
+    fn fee_levels_are_grouped_by_power_of_two() {
+        let pending = [
+            pending(0, 100, 10),
+            pending(1_500, 200, 20),
+            pending(5_000, 300, 30),
+            pending(7_999, 400, 40),
+            pending(8_192, 500, 50),
+            pending(4_096, 600, 60),
+        ];
+
+        let expected = vec![
+            FeeLevel {
+                min_fee_per_kb: 8_192,
+                transaction_count: 1,
+                transaction_bytes: 500,
+                rollup_data_bytes: 50,
+            },
+            FeeLevel {
+                min_fee_per_kb: 4_096,
+                transaction_count: 3,
+                transaction_bytes: 1_300,
+                rollup_data_bytes: 130,
+            },
+            FeeLevel {
+                min_fee_per_kb: 1_024,
+                transaction_count: 1,
+                transaction_bytes: 200,
+                rollup_data_bytes: 20,
+            },
+            FeeLevel {
+                min_fee_per_kb: 0,
+                transaction_count: 1,
+                transaction_bytes: 100,
+                rollup_data_bytes: 10,
+            },
+        ];
+        assert_eq!(fee_levels(&pending), expected);
+    }
+
+    #[test]
+    fn recommended_fee_is_zero_if_pending_fits() {
+        let pending = vec![
+            pending(3_000, 100, MAX_SEQUENCE_DATA_BYTES_PER_BLOCK / 2),
+            pending(1_000, 100, MAX_SEQUENCE_DATA_BYTES_PER_BLOCK / 2),
+        ];
+        assert_eq!(recommended_fee_per_kb(pending, 1, Some(200)), 0);
+        assert_eq!(recommended_fee_per_kb(vec![], 1, Some(200)), 0);
+    }
+
+    #[test]
+    fn recommended_fee_outbids_first_transaction_exceeding_rollup_data_limit() {
+        let pending = vec![
+            pending(1_000, 100, MAX_SEQUENCE_DATA_BYTES_PER_BLOCK / 2),
+            pending(5_000, 100, MAX_SEQUENCE_DATA_BYTES_PER_BLOCK / 2),
+            pending(3_000, 100, MAX_SEQUENCE_DATA_BYTES_PER_BLOCK / 2),
+        ];
+        assert_eq!(recommended_fee_per_kb(pending.clone(), 1, None), 1_001);
+        assert_eq!(recommended_fee_per_kb(pending.clone(), 2, None), 0);
+        // Zero target blocks is treated as one.
+        assert_eq!(recommended_fee_per_kb(pending, 0, None), 1_001);
+    }
+
+    #[test]
+    fn recommended_fee_outbids_first_transaction_exceeding_block_size_limit() {
+        let pending = vec![
+            pending(1_000, 700, 0),
+            pending(5_000, 700, 0),
+            pending(3_000, 700, 0),
+        ];
+        assert_eq!(
+            recommended_fee_per_kb(pending.clone(), 1, Some(1_000)),
+            3_001
+        );
+        assert_eq!(
+            recommended_fee_per_kb(pending.clone(), 2, Some(1_000)),
+            1_001
+        );
+        assert_eq!(recommended_fee_per_kb(pending.clone(), 3, Some(1_000)), 0);
+        // Without a known block size limit, only the rollup data limit applies.
+        assert_eq!(recommended_fee_per_kb(pending, 1, None), 0);
+    }
+}
@@ -0,0 +1,600 @@
+use std::{
+    collections::HashSet,
+    fs::{
+        File,
+        OpenOptions,
+    },
+    io::{
+        ErrorKind,
+        Write as _,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use astria_core::primitive::v1::TransactionId;
+use astria_eyre::eyre::{
+    Result,
+    WrapErr as _,
+};
+use bytes::{
+    Buf as _,
+    Bytes,
+};
+use indexmap::IndexMap;
+use tokio::sync::mpsc;
+#[cfg(test)]
+use tokio::sync::oneshot;
+use tracing::{
+    error,
+    warn,
+};
+
+/// The length in bytes of the prefix encoding the length of each journal record.
+const LENGTH_PREFIX_LEN: usize = 4;
+
+/// The length in bytes of the transaction ID carried by each journal record.
+const TX_ID_LEN: usize = 32;
+
+/// Tags a record adding a transaction to the journal.
+const ADDED: u8 = 0;
+
+/// Tags a record removing a transaction from the journal.
+const REMOVED: u8 = 1;
+
+/// The minimum number of stale records before the journal is compacted.
+const MIN_STALE_RECORDS_TO_COMPACT: usize = 1024;
+
+/// Returns the path of the mempool journal, which is a sibling of the directory backing chain
+/// state.
+pub(crate) fn journal_path(db_filepath: &Path) -> PathBuf {
+    let mut file_name = db_filepath
+        .file_name()
+        .map(std::ffi::OsStr::to_os_string)
+        .unwrap_or_default();
+    file_name.push("_mempool_journal");
+    db_filepath.with_file_name(file_name)
+}
+
+/// An on-disk journal of the transactions accepted into the mempool, used to restore the mempool
+/// after a restart.
+///
+/// The journal is a log of records, each prefixed by its length as a big-endian `u32`. A record
+/// either adds a transaction, carrying its ID and encoded bytes, or removes a previously added
+/// transaction, carrying only its ID. Accepted transactions are appended as they are inserted.
+/// After every block, removals are appended for all transactions that left the mempool and the
+/// journal is synced to disk, so that a crash of the host loses at most the transactions accepted
+/// since the last block.
+///
+/// Once the added and removed records of transactions no longer in the mempool outnumber the
+/// records of those still in it, the journal is compacted by rewriting it with only the latter.
+///
+/// All methods block on disk I/O. The mempool does not call them directly but through a
+/// [`JournalWriter`].
+pub(crate) struct Journal {
+    path: PathBuf,
+    file: File,
+    /// The transactions added to the journal and not yet removed from it, in the order they were
+    /// added.
+    live: IndexMap<TransactionId, Bytes>,
+    /// The number of records in the journal that do not add a live transaction.
+    stale_records: usize,
+}
+
+impl Journal {
+    /// Opens the journal at `path` for appending, creating it if it does not exist.
+    ///
+    /// A truncated record at the end of the journal is cut off so that new records are appended
+    /// after the last complete one.
+    pub(crate) fn open(path: PathBuf) -> Result<Self> {
+        let Replayed {
+            txs,
+            records,
+            complete_len,
+        } = replay(&path)?;
+        let file = open_for_append(&path)?;
+        if complete_len
+            < file
+                .metadata()
+                .wrap_err_with(|| {
+                    format!(
+                        "failed reading metadata of mempool journal at `{}`",
+                        path.display()
+                    )
+                })?
+                .len()
+        {
+            file.set_len(complete_len).wrap_err_with(|| {
+                format!("failed truncating mempool journal at `{}`", path.display())
+            })?;
+        }
+        Ok(Self {
+            path,
+            file,
+            stale_records: records.saturating_sub(txs.len()),
+            live: txs,
+        })
+    }
+
+    /// Reads the transactions in the journal at `path` that were added and not removed, in the
+    /// order they were added.
+    ///
+    /// Returns no transactions if the journal does not exist. A truncated record at the end of the
+    /// journal, as left behind by a crash while it was written, is ignored.
+    pub(crate) fn read(path: &Path) -> Result<Vec<Bytes>> {
+        Ok(replay(path)?.txs.into_values().collect())
+    }
+
+    /// Appends a record adding the transaction `tx_id` with encoded bytes `tx_bytes`.
+    ///
+    /// The record is not synced to disk until the next call to [`Journal::sync`].
+    pub(super) fn append(&mut self, tx_id: TransactionId, tx_bytes: Bytes) -> Result<()> {
+        self.file
+            .write_all(&encode_record(ADDED, tx_id, &tx_bytes)?)
+            .wrap_err_with(|| {
+                format!(
+                    "failed appending to mempool journal at `{}`",
+                    self.path.display()
+                )
+            })?;
+        if self.live.insert(tx_id, tx_bytes).is_some() {
+            self.stale_records = self.stale_records.saturating_add(1);
+        }
+        Ok(())
+    }
+
+    /// Appends records removing all live transactions not in `contained_tx_ids` and syncs the
+    /// journal to disk.
+    ///
+    /// If this leaves the journal with more stale than live records, it is instead compacted by
+    /// rewriting it with only the live transactions.
+    pub(super) fn sync(&mut self, contained_tx_ids: &HashSet<TransactionId>) -> Result<()> {
+        let removed: Vec<_> = self
+            .live
+            .keys()
+            .filter(|tx_id| !contained_tx_ids.contains(*tx_id))
+            .copied()
+            .collect();
+        self.live
+            .retain(|tx_id, _| contained_tx_ids.contains(tx_id));
+        let mut records = vec![];
+        for tx_id in removed {
+            records.extend_from_slice(&encode_record(REMOVED, tx_id, &[])?);
+            // both the record adding the transaction and the one removing it are now stale.
+            self.stale_records = self.stale_records.saturating_add(2);
+        }
+
+        if self.stale_records >= MIN_STALE_RECORDS_TO_COMPACT
+            && self.stale_records > self.live.len()
+        {
+            let live = std::mem::take(&mut self.live);
+            return self.rewrite(live);
+        }
+
+        self.file
+            .write_all(&records)
+            .and_then(|()| self.file.sync_data())
+            .wrap_err_with(|| {
+                format!(
+                    "failed syncing mempool journal at `{}`",
+                    self.path.display()
+                )
+            })
+    }
+
+    /// Replaces the contents of the journal with records adding `txs`.
+    ///
+    /// The new contents are written to a temporary file which then atomically replaces the
+    /// journal, so that a crash mid-write leaves the previous journal intact.
+    pub(super) fn rewrite(
+        &mut self,
+        txs: impl IntoIterator<Item = (TransactionId, Bytes)>,
+    ) -> Result<()> {
+        let mut contents = vec![];
+        let mut live = IndexMap::new();
+        for (tx_id, tx_bytes) in txs {
+            contents.extend_from_slice(&encode_record(ADDED, tx_id, &tx_bytes)?);
+            live.insert(tx_id, tx_bytes);
+        }
+        let tmp_path = self.path.with_extension("tmp");
+        let mut tmp_file = File::create(&tmp_path).wrap_err_with(|| {
+            format!(
+                "failed creating temporary mempool journal at `{}`",
+                tmp_path.display()
+            )
+        })?;
+        tmp_file
+            .write_all(&contents)
+            .and_then(|()| tmp_file.sync_all())
+            .wrap_err_with(|| {
+                format!(
+                    "failed writing temporary mempool journal at `{}`",
+                    tmp_path.display()
+                )
+            })?;
+        std::fs::rename(&tmp_path, &self.path).wrap_err_with(|| {
+            format!(
+                "failed moving temporary mempool journal from `{}` to `{}`",
+                tmp_path.display(),
+                self.path.display()
+            )
+        })?;
+        sync_parent_dir(&self.path)?;
+        self.file = open_for_append(&self.path)?;
+        self.live = live;
+        self.stale_records = 0;
+        Ok(())
+    }
+}
+
+/// A handle to a task writing to the mempool journal.
+///
+/// Appending to and syncing the journal blocks on disk I/O, which must neither stall the async
+/// runtime nor be done while holding the mempool's lock. Instead, the mempool sends records to
+/// this task, which applies them to the journal on the blocking thread pool in the order they were
+/// sent. Failures are logged, as losing the journal only affects how many transactions survive a
+/// restart.
+pub(super) struct JournalWriter {
+    commands: mpsc::UnboundedSender<Command>,
+}
+
+impl JournalWriter {
+    /// Spawns a task writing to `journal`, which it first rewrites to contain exactly `txs`.
+    pub(super) fn spawn(journal: Journal, txs: Vec<(TransactionId, Bytes)>) -> Self {
+        let (commands, receiver) = mpsc::unbounded_channel();
+        let writer = Self {
+            commands,
+        };
+        writer.send(Command::Rewrite(txs));
+        tokio::spawn(run_writer(journal, receiver));
+        writer
+    }
+
+    /// Appends a record adding the transaction `tx_id` with encoded bytes `tx_bytes`.
+    pub(super) fn append(&self, tx_id: TransactionId, tx_bytes: Bytes) {
+        self.send(Command::Append {
+            tx_id,
+            tx_bytes,
+        });
+    }
+
+    /// Records all transactions not in `contained_tx_ids` as removed and syncs the journal to disk.
+    pub(super) fn sync(&self, contained_tx_ids: HashSet<TransactionId>) {
+        self.send(Command::Sync(contained_tx_ids));
+    }
+
+    /// Waits until all records sent before this call have been written.
+    #[cfg(test)]
+    pub(super) async fn flush(&self) {
+        let (done, flushed) = oneshot::channel();
+        self.send(Command::Flush(done));
+        let _ = flushed.await;
+    }
+
+    fn send(&self, command: Command) {
+        if self.commands.send(command).is_err() {
+            warn!("mempool journal writer has stopped; the mempool is no longer journaled");
+        }
+    }
+}
+
+enum Command {
+    Append {
+        tx_id: TransactionId,
+        tx_bytes: Bytes,
+    },
+    Sync(HashSet<TransactionId>),
+    Rewrite(Vec<(TransactionId, Bytes)>),
+    #[cfg(test)]
+    Flush(oneshot::Sender<()>),
+}
+
+impl Command {
+    fn apply(self, journal: &mut Journal) {
+        match self {
+            Command::Append {
+                tx_id,
+                tx_bytes,
+            } => {
+                if let Err(error) = journal.append(tx_id, tx_bytes) {
+                    warn!(
+                        error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                        "failed to record transaction in mempool journal; it will not survive a \
+                         restart"
+                    );
+                }
+            }
+            Command::Sync(contained_tx_ids) => {
+                if let Err(error) = journal.sync(&contained_tx_ids) {
+                    warn!(
+                        error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                        "failed to sync mempool journal"
+                    );
+                }
+            }
+            Command::Rewrite(txs) => {
+                if let Err(error) = journal.rewrite(txs) {
+                    warn!(
+                        error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                        "failed to rewrite mempool journal"
+                    );
+                }
+            }
+            #[cfg(test)]
+            Command::Flush(done) => {
+                let _ = done.send(());
+            }
+        }
+    }
+}
+
+async fn run_writer(mut journal: Journal, mut commands: mpsc::UnboundedReceiver<Command>) {
+    while let Some(command) = commands.recv().await {
+        journal = match tokio::task::spawn_blocking(move || {
+            command.apply(&mut journal);
+            journal
+        })
+        .await
+        {
+            Ok(journal) => journal,
+            Err(error) => {
+                error!(%error, "mempool journal writer failed; the mempool is no longer journaled");
+                return;
+            }
+        };
+    }
+}
+
+/// The transactions in a journal and the extent of its records.
+struct Replayed {
+    /// The transactions added and not removed, in the order they were added.
+    txs: IndexMap<TransactionId, Bytes>,
+    /// The number of complete records.
+    records: usize,
+    /// The length in bytes of the complete records.
+    complete_len: u64,
+}
+
+fn replay(path: &Path) -> Result<Replayed> {
+    let mut contents = match std::fs::read(path) {
+        Ok(contents) => Bytes::from(contents),
+        Err(error) if error.kind() == ErrorKind::NotFound => {
+            return Ok(Replayed {
+                txs: IndexMap::new(),
+                records: 0,
+                complete_len: 0,
+            });
+        }
+        Err(error) => {
+            return Err(error).wrap_err_with(|| {
+                format!("failed reading mempool journal at `{}`", path.display())
+            });
+        }
+    };
+    let total_len = contents.len();
+    let mut txs = IndexMap::new();
+    let mut records = 0usize;
+    let mut complete_len = 0;
+    while contents.remaining() >= LENGTH_PREFIX_LEN {
+        let record_len = usize::try_from(contents.get_u32()).expect("u32 must fit into usize");
+        if record_len > contents.remaining() {
+            break;
+        }
+        let mut record = contents.split_to(record_len);
+        complete_len = total_len.saturating_sub(contents.remaining());
+        records = records.saturating_add(1);
+
+        if record.remaining() < TX_ID_LEN.saturating_add(1) {
+            warn!(
+                path = %path.display(),
+                "mempool journal contains a record too short to hold a transaction ID; ignoring it"
+            );
+            continue;
+        }
+        let tag = record.get_u8();
+        let tx_id = TransactionId::new(
+            record
+                .split_to(TX_ID_LEN)
+                .as_ref()
+                .try_into()
+                .expect("split off exactly 32 bytes"),
+        );
+        match tag {
+            ADDED => {
+                txs.insert(tx_id, record);
+            }
+            REMOVED => {
+                txs.shift_remove(&tx_id);
+            }
+            other => warn!(
+                path = %path.display(),
+                tag = other,
+                "mempool journal contains a record with an unknown tag; ignoring it"
+            ),
+        }
+    }
+    if complete_len < total_len {
+        warn!(
+            path = %path.display(),
+            "mempool journal ends in a truncated record; ignoring it"
+        );
+    }
+    Ok(Replayed {
+        txs,
+        records,
+        complete_len: u64::try_from(complete_len).expect("usize must fit into u64"),
+    })
+}
+
+fn open_for_append(path: &Path) -> Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .wrap_err_with(|| format!("failed opening mempool journal at `{}`", path.display()))
+}
+
+/// Syncs the directory containing `path` so that a rename into it survives a crash of the host.
+fn sync_parent_dir(path: &Path) -> Result<()> {
+    let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) else {
+        return Ok(());
+    };
+    File::open(dir)
+        .and_then(|dir| dir.sync_all())
+        .wrap_err_with(|| {
+            format!(
+                "failed syncing directory `{}` of mempool journal",
+                dir.display()
+            )
+        })
+}
+
+fn encode_record(tag: u8, tx_id: TransactionId, tx_bytes: &[u8]) -> Result<Vec<u8>> {
+    let record_len = 1usize
+        .saturating_add(TX_ID_LEN)
+        .saturating_add(tx_bytes.len());
+    let record_len_prefix = u32::try_from(record_len)
+        .wrap_err("transaction is too large to be recorded in the mempool journal")?;
+    let mut record = Vec::with_capacity(LENGTH_PREFIX_LEN.saturating_add(record_len));
+    record.extend_from_slice(&record_len_prefix.to_be_bytes());
+    record.push(tag);
+    record.extend_from_slice(tx_id.as_bytes());
+    record.extend_from_slice(tx_bytes);
+    Ok(record)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tx(byte: u8) -> (TransactionId, Bytes) {
+        (TransactionId::new([byte; 32]), Bytes::from(vec![byte; 8]))
+    }
+
+    #[test]
+    fn journal_path_is_sibling_of_db_filepath() {
+        assert_eq!(
+            PathBuf::from("/tmp/astria_db_mempool_journal"),
+            journal_path(Path::new("/tmp/astria_db")),
+        );
+    }
+
+    #[test]
+    fn missing_journal_reads_as_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(Journal::read(&dir.path().join("journal"))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn appended_and_rewritten_txs_are_read_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal");
+        let mut journal = Journal::open(path.clone()).unwrap();
+        let (first_id, first) = tx(1);
+        let (second_id, second) = tx(2);
+        let (third_id, third) = tx(3);
+        let (fourth_id, fourth) = tx(4);
+
+        journal.append(first_id, first.clone()).unwrap();
+        journal.append(second_id, second.clone()).unwrap();
+        assert_eq!(vec![first, second.clone()], Journal::read(&path).unwrap());
+
+        journal.rewrite([(third_id, third.clone())]).unwrap();
+        journal.append(fourth_id, fourth.clone()).unwrap();
+        assert_eq!(vec![third, fourth], Journal::read(&path).unwrap());
+    }
+
+    #[test]
+    fn sync_records_removed_txs_without_rewriting() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal");
+        let mut journal = Journal::open(path.clone()).unwrap();
+        let (first_id, first) = tx(1);
+        let (second_id, second) = tx(2);
+        journal.append(first_id, first.clone()).unwrap();
+        journal.append(second_id, second.clone()).unwrap();
+        let len_before_sync = std::fs::metadata(&path).unwrap().len();
+
+        let contained = HashSet::from([second_id]);
+        journal.sync(&contained).unwrap();
+
+        assert!(std::fs::metadata(&path).unwrap().len() > len_before_sync);
+        assert_eq!(vec![second.clone()], Journal::read(&path).unwrap());
+
+        // the live transactions are recovered when the journal is reopened
+        let mut journal = Journal::open(path.clone()).unwrap();
+        journal.sync(&HashSet::new()).unwrap();
+        assert!(Journal::read(&path).unwrap().is_empty());
+    }
+
+    #[test]
+    fn sync_compacts_journal_once_most_records_are_stale() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal");
+        let mut journal = Journal::open(path.clone()).unwrap();
+        let tx_bytes = Bytes::from_static(b"tx");
+        for i in 0..MIN_STALE_RECORDS_TO_COMPACT {
+            let mut id = [0; 32];
+            id[..8].copy_from_slice(&i.to_be_bytes());
+            journal
+                .append(TransactionId::new(id), tx_bytes.clone())
+                .unwrap();
+        }
+        let (kept_id, kept) = tx(0xff);
+        journal.append(kept_id, kept.clone()).unwrap();
+
+        let contained = HashSet::from([kept_id]);
+        journal.sync(&contained).unwrap();
+
+        assert_eq!(0, journal.stale_records);
+        assert_eq!(
+            u64::try_from(encode_record(ADDED, kept_id, &kept).unwrap().len()).unwrap(),
+            std::fs::metadata(&path).unwrap().len(),
+        );
+        assert_eq!(vec![kept], Journal::read(&path).unwrap());
+    }
+
+    #[test]
+    fn truncated_record_is_ignored_and_cut_off_on_open() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal");
+        let mut journal = Journal::open(path.clone()).unwrap();
+        let (complete_id, complete) = tx(1);
+        journal.append(complete_id, complete.clone()).unwrap();
+        let mut truncated = encode_record(ADDED, tx(2).0, b"truncated").unwrap();
+        truncated.truncate(7);
+        journal.file.write_all(&truncated).unwrap();
+
+        assert_eq!(vec![complete.clone()], Journal::read(&path).unwrap());
+
+        let mut journal = Journal::open(path.clone()).unwrap();
+        let (appended_id, appended) = tx(3);
+        journal.append(appended_id, appended.clone()).unwrap();
+        assert_eq!(vec![complete, appended], Journal::read(&path).unwrap());
+    }
+
+    #[tokio::test]
+    async fn writer_applies_records_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal");
+        let mut journal = Journal::open(path.clone()).unwrap();
+        let (stale_id, stale) = tx(1);
+        journal.append(stale_id, stale).unwrap();
+        let (first_id, first) = tx(2);
+        let (second_id, second) = tx(3);
+
+        // spawning the writer replaces the journal's contents
+        let writer = JournalWriter::spawn(journal, vec![(first_id, first.clone())]);
+        writer.append(second_id, second.clone());
+        writer.flush().await;
+        assert_eq!(vec![first, second.clone()], Journal::read(&path).unwrap());
+
+        writer.sync(HashSet::from([second_id]));
+        writer.flush().await;
+        assert_eq!(vec![second], Journal::read(&path).unwrap());
+    }
+}
@@ -1,5 +1,7 @@
 // #[cfg(feature = "benchmark")]
 // mod benchmarks;
+mod fee_estimation;
+mod journal;
 mod mempool_state;
 mod transactions_container;
 
@@ -21,18 +23,37 @@ use astria_core::{
     },
 };
 use astria_eyre::eyre::Result;
+use bytes::Bytes;
+use fee_estimation::RecentBlocks;
+pub(crate) use fee_estimation::{
+    BlockFullness,
+    FeeEstimate,
+    FeeLevel,
+};
+use journal::JournalWriter;
+pub(crate) use journal::{
+    journal_path,
+    Journal,
+};
 pub(crate) use mempool_state::get_account_balances;
 use tokio::{
-    sync::RwLock,
+    sync::{
+        broadcast,
+        RwLock,
+    },
     time::Duration,
 };
 use tracing::{
+    debug,
     error,
     instrument,
     warn,
     Level,
 };
-pub(crate) use transactions_container::InsertionError;
+pub(crate) use transactions_container::{
+    InsertionError,
+    SponsoredFees,
+};
 use transactions_container::{
     ParkedTransactions,
     PendingTransactions,
@@ -44,6 +65,7 @@ use crate::{
     accounts,
     accounts::AddressBytes as _,
     checked_transaction::CheckedTransaction,
+    config::MempoolTransactionOrdering,
     Metrics,
 };
 
@@ -55,6 +77,7 @@ pub(crate) enum RemovalReason {
     FailedPrepareProposal(String),
     InternalError,
     IncludedInBlock(u64),
+    Replaced(TransactionId),
 }
 
 impl std::fmt::Display for RemovalReason {
@@ -72,6 +95,9 @@ impl std::fmt::Display for RemovalReason {
             RemovalReason::IncludedInBlock(block_height) => {
                 write!(f, "included in sequencer block {block_height}")
             }
+            RemovalReason::Replaced(tx_id) => {
+                write!(f, "replaced by transaction {tx_id}")
+            }
         }
     }
 }
@@ -83,6 +109,29 @@ const MAX_PARKED_TXS_PER_ACCOUNT: usize = 15;
 /// Max number of transactions to keep in the removal cache. Should be larger than the max number of
 /// transactions allowed in the cometBFT mempool.
 const REMOVAL_CACHE_SIZE: usize = 50_000;
+/// Max number of events buffered for each subscriber to mempool events. Subscribers falling
+/// further behind miss the oldest events.
+const EVENTS_CHANNEL_CAPACITY: usize = 4096;
+
+/// A change to the state of a transaction in the mempool, broadcast to all subscribers obtained
+/// via [`Mempool::subscribe`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct MempoolEvent {
+    pub(crate) tx_id: TransactionId,
+    pub(crate) address_bytes: [u8; ADDRESS_LENGTH],
+    pub(crate) kind: MempoolEventKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum MempoolEventKind {
+    AddedToPending,
+    AddedToParked,
+    Promoted,
+    Demoted,
+    /// The transaction was removed from the mempool. Inclusion in a block is signalled by
+    /// [`RemovalReason::IncludedInBlock`].
+    Removed(RemovalReason),
+}
 
 /// `RemovalCache` is used to signal to `CometBFT` that a
 /// transaction can be removed from the `CometBFT` mempool.
@@ -149,35 +198,98 @@ impl RemovalCache {
 /// The mempool exposes the pending transactions through `builder_queue()`, which returns a copy of
 /// all pending transactions sorted in the order in which they should be executed. The sort order
 /// is firstly by the difference between the transaction nonce and the account's current nonce
-/// (ascending), and then by time first seen (ascending).
+/// (ascending), then by fee paid per byte of the encoded transaction (descending) if the
+/// [`BuilderQueuePolicy`] orders by fee rate, and then by time first seen (ascending).
 ///
 /// The mempool implements the following policies:
-/// 1. Nonce replacement is not allowed.
+/// 1. Nonce replacement is only allowed if replace-by-fee is enabled, in which case a transaction
+///    can be replaced by one with the same signer and nonce which pays a fee rate at least
+///    `MIN_REPLACEMENT_FEE_RATE_BUMP_PERCENT` higher. The replaced transaction is removed with
+///    `RemovalReason::Replaced`.
 /// 2. Accounts cannot have more than `MAX_PARKED_TXS_PER_ACCOUNT` transactions in their parked
 ///    queues.
-/// 3. There is no account limit on pending transactions.
+/// 3. There is no account limit on pending transactions, but the builder queue can be limited to a
+///    maximum number of transactions per account via [`BuilderQueuePolicy`].
 /// 4. Transactions will expire and can be removed after `TX_TTL` time.
 /// 5. If an account has a transaction removed for being invalid or expired, all transactions for
 ///    that account with a higher nonce will be removed as well. This is due to the fact that we do
 ///    not execute failing transactions, so a transaction 'failing' will mean that further account
 ///    nonces will not be able to execute either.
 ///
+/// If a [`Journal`] is enabled, accepted transactions are recorded on disk so that they can be
+/// restored after a restart.
+///
+/// All changes to the state of a transaction (insertion, promotion, demotion and removal) are
+/// broadcast as [`MempoolEvent`]s to subscribers obtained via [`Mempool::subscribe`].
+///
 /// Future extensions to this mempool can include:
 /// - maximum mempool size
 /// - account balance aware pending queue
 #[derive(Clone)]
 pub(crate) struct Mempool {
     inner: Arc<RwLock<MempoolInner>>,
+    events: broadcast::Sender<MempoolEvent>,
+}
+
+/// Controls how pending transactions are ordered and capped in the builder queue.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct BuilderQueuePolicy {
+    /// How transactions with the same group and nonce difference are ordered.
+    pub(crate) ordering: MempoolTransactionOrdering,
+    /// The maximum number of transactions per account in the builder queue, or `None` for no
+    /// limit.
+    pub(crate) max_txs_per_account: Option<NonZeroUsize>,
 }
 
 impl Mempool {
     #[must_use]
-    pub(crate) fn new(metrics: &'static Metrics, parked_max_tx_count: usize) -> Self {
+    pub(crate) fn new(
+        metrics: &'static Metrics,
+        parked_max_tx_count: usize,
+        builder_queue_policy: BuilderQueuePolicy,
+        replace_by_fee: bool,
+    ) -> Self {
+        let (events, _) = broadcast::channel(EVENTS_CHANNEL_CAPACITY);
         Self {
-            inner: Arc::new(RwLock::new(MempoolInner::new(metrics, parked_max_tx_count))),
+            inner: Arc::new(RwLock::new(MempoolInner::new(
+                metrics,
+                parked_max_tx_count,
+                builder_queue_policy,
+                replace_by_fee,
+                events.clone(),
+            ))),
+            events,
         }
     }
 
+    /// Subscribes to the events emitted on changes to the state of transactions in the mempool.
+    ///
+    /// Only events emitted after subscribing are received.
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<MempoolEvent> {
+        self.events.subscribe()
+    }
+
+    /// Sets the asset in which transactions must pay their fees to be prioritized by fee rate.
+    ///
+    /// Fees paid in other assets do not count towards a transaction's fee rate, which is used to
+    /// order the builder queue, to decide whether a transaction may be replaced by fee, and to
+    /// estimate fees. This is expected to be the native asset of the chain. Until it is set, all
+    /// transactions have a fee rate of zero.
+    #[instrument(skip_all)]
+    pub(crate) async fn set_fee_asset(&self, fee_asset: IbcPrefixed) {
+        self.inner.write().await.set_fee_asset(fee_asset);
+    }
+
+    /// Starts recording the transactions in the mempool to `journal`.
+    ///
+    /// The journal is written by a dedicated task, which first rewrites it to contain exactly the
+    /// transactions currently in the mempool. Any transactions read from the journal should
+    /// therefore be re-inserted before calling this.
+    #[instrument(skip_all)]
+    pub(crate) async fn enable_journal(&self, journal: Journal) {
+        self.inner.write().await.enable_journal(journal);
+    }
+
     /// Returns the number of transactions in the mempool.
     #[must_use]
     #[instrument(skip_all)]
@@ -185,8 +297,13 @@ impl Mempool {
         self.inner.read().await.len()
     }
 
-    /// Inserts a transaction into the mempool and does not allow for transaction replacement.
-    /// Will return the reason for insertion failure if failure occurs.
+    /// Inserts a transaction into the mempool. If replace-by-fee is enabled, a transaction with the
+    /// same signer and nonce is replaced if the new transaction pays a sufficiently higher fee
+    /// rate. Will return the reason for insertion failure if failure occurs.
+    ///
+    /// `sponsored_fees` must be set if the fees of the transaction are paid by a separate fee
+    /// payer. The transaction is then only added to pending if the fee payer can cover its fees
+    /// on top of the costs already charged to the fee payer by pending transactions.
     #[instrument(
         skip_all,
         fields(tx_id = %checked_tx.id(), current_account_nonce),
@@ -198,18 +315,20 @@ impl Mempool {
         current_account_nonce: u32,
         current_account_balances: &HashMap<IbcPrefixed, u128>,
         transaction_cost: HashMap<IbcPrefixed, u128>,
+        sponsored_fees: Option<SponsoredFees>,
     ) -> Result<InsertionStatus, InsertionError> {
         self.inner.write().await.insert(
             checked_tx,
             current_account_nonce,
             current_account_balances,
             transaction_cost,
+            sponsored_fees,
         )
     }
 
     /// Returns a copy of all transactions ready for execution, sorted first by the difference
-    /// between a transaction and the account's current nonce and then by the time that the
-    /// transaction was first seen by the appside mempool.
+    /// between a transaction and the account's current nonce, then by fee rate if configured, and
+    /// then by the time that the transaction was first seen by the appside mempool.
     #[instrument(skip_all)]
     pub(crate) async fn builder_queue(&self) -> Vec<Arc<CheckedTransaction>> {
         self.inner.read().await.builder_queue()
@@ -268,6 +387,19 @@ impl Mempool {
         self.inner.read().await.pending_nonce(address_bytes)
     }
 
+    /// Records the fullness of a newly committed block, to be reported in fee estimates.
+    #[instrument(skip_all, fields(height = block.height))]
+    pub(crate) async fn record_block_fullness(&self, block: BlockFullness) {
+        self.inner.write().await.recent_blocks.record(block);
+    }
+
+    /// Estimates the fee rate a transaction must pay to be included within `target_blocks`
+    /// blocks, given the transactions currently pending in the mempool.
+    #[instrument(skip_all)]
+    pub(crate) async fn estimate_fee(&self, target_blocks: u32) -> FeeEstimate {
+        self.inner.read().await.estimate_fee(target_blocks)
+    }
+
     #[instrument(skip_all)]
     pub(crate) async fn transaction_status(
         &self,
@@ -298,12 +430,24 @@ struct MempoolInner {
     parked: ParkedTransactions<MAX_PARKED_TXS_PER_ACCOUNT>,
     comet_bft_removal_cache: RemovalCache,
     contained_txs: HashSet<TransactionId>,
+    builder_queue_policy: BuilderQueuePolicy,
+    replace_by_fee: bool,
+    fee_asset: Option<IbcPrefixed>,
+    journal: Option<JournalWriter>,
+    events: broadcast::Sender<MempoolEvent>,
+    recent_blocks: RecentBlocks,
     metrics: &'static Metrics,
 }
 
 impl MempoolInner {
     #[must_use]
-    fn new(metrics: &'static Metrics, parked_max_tx_count: usize) -> Self {
+    fn new(
+        metrics: &'static Metrics,
+        parked_max_tx_count: usize,
+        builder_queue_policy: BuilderQueuePolicy,
+        replace_by_fee: bool,
+        events: broadcast::Sender<MempoolEvent>,
+    ) -> Self {
         Self {
             pending: PendingTransactions::new(TX_TTL),
             parked: ParkedTransactions::new(TX_TTL, parked_max_tx_count),
@@ -312,10 +456,65 @@ impl MempoolInner {
                     .expect("Removal cache cannot be zero sized"),
             ),
             contained_txs: HashSet::new(),
+            builder_queue_policy,
+            replace_by_fee,
+            fee_asset: None,
+            journal: None,
+            events,
+            recent_blocks: RecentBlocks::new(),
             metrics,
         }
     }
 
+    fn set_fee_asset(&mut self, fee_asset: IbcPrefixed) {
+        if self.fee_asset == Some(fee_asset) {
+            return;
+        }
+        self.fee_asset = Some(fee_asset);
+        self.pending.set_fee_asset(self.fee_asset);
+        self.parked.set_fee_asset(self.fee_asset);
+    }
+
+    fn enable_journal(&mut self, journal: Journal) {
+        let txs = self
+            .pending
+            .encoded_txs()
+            .chain(self.parked.encoded_txs())
+            .map(|(tx_id, tx_bytes)| (*tx_id, tx_bytes.clone()))
+            .collect();
+        self.journal = Some(JournalWriter::spawn(journal, txs));
+    }
+
+    /// Sends an accepted transaction to be appended to the journal, if enabled.
+    fn append_to_journal(&self, tx_id: TransactionId, tx_bytes: Bytes) {
+        if let Some(journal) = &self.journal {
+            journal.append(tx_id, tx_bytes);
+        }
+    }
+
+    /// Sends the transactions still in the mempool to the journal, if enabled, to record those
+    /// that left it and sync it to disk.
+    fn sync_journal(&self) {
+        if let Some(journal) = &self.journal {
+            journal.sync(self.contained_txs.clone());
+        }
+    }
+
+    /// Broadcasts an event to all subscribers.
+    fn emit(
+        &self,
+        tx_id: TransactionId,
+        address_bytes: [u8; ADDRESS_LENGTH],
+        kind: MempoolEventKind,
+    ) {
+        // Sending only fails if there are no subscribers, in which case the event can be dropped.
+        let _ = self.events.send(MempoolEvent {
+            tx_id,
+            address_bytes,
+            kind,
+        });
+    }
+
     #[must_use]
     fn len(&self) -> usize {
         self.contained_txs.len()
@@ -327,79 +526,203 @@ impl MempoolInner {
         current_account_nonce: u32,
         current_account_balances: &HashMap<IbcPrefixed, u128>,
         transaction_costs: HashMap<IbcPrefixed, u128>,
+        sponsored_fees: Option<SponsoredFees>,
     ) -> Result<InsertionStatus, InsertionError> {
-        let ttx_to_insert = TimemarkedTransaction::new(checked_tx, transaction_costs);
+        let tx_bytes = checked_tx.encoded_bytes().clone();
+        let (fees_charged_to_fee_payer, fee_payer_balances) = sponsored_fees.map_or_else(
+            || (HashMap::new(), None),
+            |sponsored_fees| (sponsored_fees.fees, Some(sponsored_fees.fee_payer_balances)),
+        );
+        let ttx_to_insert = TimemarkedTransaction::new(
+            checked_tx,
+            transaction_costs,
+            fees_charged_to_fee_payer,
+            self.fee_asset,
+        );
         let tx_id_to_insert = *ttx_to_insert.id();
+        let address_bytes = *ttx_to_insert.address_bytes();
+        // the account's balances are also charged with the fees of the transactions it sponsors
+        let available_account_balances = self
+            .pending
+            .subtract_sponsored_fees(&address_bytes, current_account_balances.clone());
 
         // try insert into pending
-        match self.pending.add(
-            ttx_to_insert.clone(),
-            current_account_nonce,
-            current_account_balances,
+        let mut pending_result = if self.replace_by_fee {
+            self.pending.add_or_replace(
+                ttx_to_insert.clone(),
+                current_account_nonce,
+                &available_account_balances,
+            )
+        } else {
+            self.pending
+                .add(
+                    ttx_to_insert.clone(),
+                    current_account_nonce,
+                    &available_account_balances,
+                )
+                .map(|()| None)
+        };
+        // a sponsored transaction stays in pending only if its fee payer can cover its fees as well
+        if let (Ok(replaced_ttx), Some(fee_payer), Some(fee_payer_balances)) = (
+            &mut pending_result,
+            ttx_to_insert.sponsoring_fee_payer(),
+            fee_payer_balances.as_ref(),
         ) {
+            if self
+                .pending
+                .fee_payer_remaining_balances(fee_payer, fee_payer_balances.clone())
+                .is_none()
+            {
+                let replaced_ttx = replaced_ttx.take();
+                let error = if replaced_ttx.is_some() {
+                    InsertionError::ReplacementBalanceTooLow
+                } else {
+                    InsertionError::AccountBalanceTooLow
+                };
+                self.pending.undo_insertion(&ttx_to_insert, replaced_ttx);
+                pending_result = Err(error);
+            }
+        }
+        match pending_result {
             Err(InsertionError::NonceGap | InsertionError::AccountBalanceTooLow) => {
                 // try to add to parked queue
-                match self.parked.add(
-                    ttx_to_insert,
-                    current_account_nonce,
-                    current_account_balances,
-                ) {
-                    Ok(()) => {
+                let parked_result = if self.replace_by_fee {
+                    self.parked.add_or_replace(
+                        ttx_to_insert,
+                        current_account_nonce,
+                        current_account_balances,
+                    )
+                } else {
+                    self.parked
+                        .add(
+                            ttx_to_insert,
+                            current_account_nonce,
+                            current_account_balances,
+                        )
+                        .map(|()| None)
+                };
+                match parked_result {
+                    Ok(replaced_ttx) => {
+                        if let Some(replaced_ttx) = replaced_ttx {
+                            self.remove_replaced(
+                                *replaced_ttx.id(),
+                                address_bytes,
+                                tx_id_to_insert,
+                            );
+                        }
+                        self.emit(
+                            tx_id_to_insert,
+                            address_bytes,
+                            MempoolEventKind::AddedToParked,
+                        );
+
                         // log current size of parked
                         self.metrics
                             .set_transactions_in_mempool_parked(self.parked.len());
 
                         // track in contained txs
                         self.contained_txs.insert(tx_id_to_insert);
+                        self.append_to_journal(tx_id_to_insert, tx_bytes);
                         Ok(InsertionStatus::AddedToParked)
                     }
                     Err(err) => Err(err),
                 }
             }
             Err(error) => Err(error),
-            Ok(()) => {
+            Ok(replaced_ttx) => {
+                if let Some(replaced_ttx) = replaced_ttx {
+                    self.remove_replaced(*replaced_ttx.id(), address_bytes, tx_id_to_insert);
+                }
+                self.emit(
+                    tx_id_to_insert,
+                    address_bytes,
+                    MempoolEventKind::AddedToPending,
+                );
+
                 // check parked for txs able to be promoted
-                let address_bytes = ttx_to_insert.address_bytes();
                 let target_nonce = ttx_to_insert
                     .nonce()
                     .checked_add(1)
                     .expect("failed to increment nonce in promotion");
                 let available_balances = self
                     .pending
-                    .subtract_contained_costs(address_bytes, current_account_balances.clone());
-                let promotables =
-                    self.parked
-                        .find_promotables(address_bytes, target_nonce, &available_balances);
+                    .subtract_contained_costs(&address_bytes, available_account_balances.clone());
+                // Only the balances of the fee payer of the inserted transaction are known here.
+                // Parked transactions sponsored by other fee payers are promoted during
+                // maintenance.
+                let fee_payer_balances: HashMap<_, _> = ttx_to_insert
+                    .sponsoring_fee_payer()
+                    .zip(fee_payer_balances)
+                    .and_then(|(fee_payer, balances)| {
+                        self.pending
+                            .fee_payer_remaining_balances(fee_payer, balances)
+                            .map(|remaining| (*fee_payer, remaining))
+                    })
+                    .into_iter()
+                    .collect();
+                let promotables = self.parked.find_promotables(
+                    &address_bytes,
+                    target_nonce,
+                    &available_balances,
+                    fee_payer_balances,
+                );
                 // promote the transactions
                 for ttx_to_promote in promotables {
                     let tx_id_to_promote = *ttx_to_promote.id();
                     if let Err(error) = self.pending.add(
                         ttx_to_promote,
                         current_account_nonce,
-                        current_account_balances,
+                        &available_account_balances,
                     ) {
                         self.contained_txs.remove(&tx_id_to_promote);
                         self.comet_bft_removal_cache
                             .add(tx_id_to_promote, RemovalReason::InternalError);
+                        self.emit(
+                            tx_id_to_promote,
+                            address_bytes,
+                            MempoolEventKind::Removed(RemovalReason::InternalError),
+                        );
                         error!(
                             current_account_nonce,
                             %tx_id_to_promote,
                             %error,
                             "failed to promote transaction during insertion"
                         );
+                    } else {
+                        self.emit(tx_id_to_promote, address_bytes, MempoolEventKind::Promoted);
                     }
                 }
 
                 // track in contained txs
                 self.contained_txs.insert(tx_id_to_insert);
+                self.append_to_journal(tx_id_to_insert, tx_bytes);
 
                 Ok(InsertionStatus::AddedToPending)
             }
         }
     }
 
+    /// Stops tracking the replaced transaction and adds it to the removal cache, so that `CometBFT`
+    /// removes it from its mempool.
+    fn remove_replaced(
+        &mut self,
+        replaced_tx_id: TransactionId,
+        address_bytes: [u8; ADDRESS_LENGTH],
+        replaced_by: TransactionId,
+    ) {
+        self.contained_txs.remove(&replaced_tx_id);
+        self.comet_bft_removal_cache
+            .add(replaced_tx_id, RemovalReason::Replaced(replaced_by));
+        self.emit(
+            replaced_tx_id,
+            address_bytes,
+            MempoolEventKind::Removed(RemovalReason::Replaced(replaced_by)),
+        );
+        debug!(%replaced_tx_id, %replaced_by, "replaced transaction in mempool");
+    }
+
     fn builder_queue(&self) -> Vec<Arc<CheckedTransaction>> {
-        self.pending.builder_queue()
+        self.pending.builder_queue(self.builder_queue_policy)
     }
 
     fn remove_tx_invalid(&mut self, checked_tx: Arc<CheckedTransaction>, reason: RemovalReason) {
@@ -424,11 +747,19 @@ impl MempoolInner {
 
         // Add the original tx first to preserve its reason for removal. The second
         // attempt to add it inside the loop below will be a no-op.
-        self.comet_bft_removal_cache.add(tx_id, reason);
+        self.comet_bft_removal_cache.add(tx_id, reason.clone());
+        self.emit(tx_id, address_bytes, MempoolEventKind::Removed(reason));
         for removed_tx_id in removed_tx_ids {
             self.contained_txs.remove(&removed_tx_id);
             self.comet_bft_removal_cache
                 .add(removed_tx_id, RemovalReason::LowerNonceInvalidated);
+            if removed_tx_id != tx_id {
+                self.emit(
+                    removed_tx_id,
+                    address_bytes,
+                    MempoolEventKind::Removed(RemovalReason::LowerNonceInvalidated),
+                );
+            }
         }
     }
 
@@ -443,14 +774,16 @@ impl MempoolInner {
         txs_included_in_block: &HashSet<TransactionId>,
         block_height: u64,
     ) {
-        let mut removed_txs = Vec::<(TransactionId, RemovalReason)>::new();
+        let mut removed_txs = Vec::<(TransactionId, [u8; ADDRESS_LENGTH], RemovalReason)>::new();
 
         // To clean we need to:
         // 1.) remove stale and expired transactions
         // 2.) recost remaining transactions if needed
-        // 3.) check if we have transactions in pending which need to be demoted due
+        // 3.) demote sponsored transactions from pending whose fee payers can no longer cover
+        //     the fees charged to them
+        // 4.) check if we have transactions in pending which need to be demoted due
         //     to balance decreases
-        // 4.) if there were no demotions, check if parked has transactions we can
+        // 5.) if there were no demotions, check if parked has transactions we can
         //     promote
 
         let addresses: HashSet<[u8; ADDRESS_LENGTH]> = self
@@ -460,6 +793,9 @@ impl MempoolInner {
             .copied()
             .collect();
 
+        let mut account_nonces = HashMap::with_capacity(addresses.len());
+        let mut account_balances = HashMap::with_capacity(addresses.len());
+
         // TODO: Make this concurrent, all account state is separate with IO bound disk reads.
         for address_bytes in &addresses {
             // get current account state
@@ -473,38 +809,80 @@ impl MempoolInner {
                     continue;
                 }
             };
-            let current_balances = match get_account_balances(state, address_bytes).await {
-                Ok(res) => res,
-                Err(error) => {
-                    error!(
-                        address = %telemetry::display::base64(address_bytes),
-                        "failed to fetch account balances when cleaning accounts: {error:#}"
-                    );
-                    continue;
-                }
-            };
+            if cached_account_balances(state, &mut account_balances, address_bytes)
+                .await
+                .is_none()
+            {
+                continue;
+            }
 
             // clean pending and parked of stale and expired
-            removed_txs.extend(self.pending.clean_account_stale_expired(
-                address_bytes,
-                current_nonce,
-                txs_included_in_block,
-                block_height,
-            ));
+            removed_txs.extend(
+                self.pending
+                    .clean_account_stale_expired(
+                        address_bytes,
+                        current_nonce,
+                        txs_included_in_block,
+                        block_height,
+                    )
+                    .into_iter()
+                    .map(|(tx_id, reason)| (tx_id, *address_bytes, reason)),
+            );
             if recost {
                 self.pending.recost_transactions(address_bytes, state).await;
             }
 
-            removed_txs.extend(self.parked.clean_account_stale_expired(
-                address_bytes,
-                current_nonce,
-                txs_included_in_block,
-                block_height,
-            ));
+            removed_txs.extend(
+                self.parked
+                    .clean_account_stale_expired(
+                        address_bytes,
+                        current_nonce,
+                        txs_included_in_block,
+                        block_height,
+                    )
+                    .into_iter()
+                    .map(|(tx_id, reason)| (tx_id, *address_bytes, reason)),
+            );
             if recost {
                 self.parked.recost_transactions(address_bytes, state).await;
             }
 
+            account_nonces.insert(*address_bytes, current_nonce);
+        }
+
+        // demote sponsored transactions whose fee payers cannot cover the costs charged to them
+        for fee_payer in self.pending.fee_payers() {
+            let Some(fee_payer_balances) =
+                cached_account_balances(state, &mut account_balances, &fee_payer).await
+            else {
+                continue;
+            };
+            if self
+                .pending
+                .fee_payer_remaining_balances(&fee_payer, fee_payer_balances.clone())
+                .is_some()
+            {
+                continue;
+            }
+            for demotion_tx in self.pending.demote_sponsored_by(&fee_payer) {
+                let current_nonce = account_nonces
+                    .get(demotion_tx.address_bytes())
+                    .copied()
+                    .unwrap_or_default();
+                self.demote(demotion_tx, current_nonce);
+            }
+        }
+
+        for (address_bytes, current_nonce) in &account_nonces {
+            let Some(current_balances) = account_balances.get(address_bytes) else {
+                continue;
+            };
+            // the account's balances are also charged with the fees of the transactions it
+            // sponsors
+            let current_balances = self
+                .pending
+                .subtract_sponsored_fees(address_bytes, current_balances.clone());
+
             // get transactions to demote from pending
             let demotion_txs = self
                 .pending
@@ -515,20 +893,37 @@ impl MempoolInner {
                 let pending_nonce = self
                     .pending
                     .pending_nonce(address_bytes)
-                    .map_or(current_nonce, |nonce| nonce);
+                    .map_or(*current_nonce, |nonce| nonce);
 
                 let remaining_balances = self
                     .pending
                     .subtract_contained_costs(address_bytes, current_balances.clone());
-                let promotion_txs =
-                    self.parked
-                        .find_promotables(address_bytes, pending_nonce, &remaining_balances);
+                let mut fee_payer_balances = HashMap::new();
+                for fee_payer in self.parked.fee_payers(address_bytes) {
+                    let Some(balances) =
+                        cached_account_balances(state, &mut account_balances, &fee_payer).await
+                    else {
+                        continue;
+                    };
+                    if let Some(remaining) = self
+                        .pending
+                        .fee_payer_remaining_balances(&fee_payer, balances.clone())
+                    {
+                        fee_payer_balances.insert(fee_payer, remaining);
+                    }
+                }
+                let promotion_txs = self.parked.find_promotables(
+                    address_bytes,
+                    pending_nonce,
+                    &remaining_balances,
+                    fee_payer_balances,
+                );
 
                 for promotion_tx in promotion_txs {
                     let tx_id = *promotion_tx.id();
                     if let Err(error) =
                         self.pending
-                            .add(promotion_tx, current_nonce, &current_balances)
+                            .add(promotion_tx, *current_nonce, &current_balances)
                     {
                         self.contained_txs.remove(&tx_id);
                         self.metrics.increment_internal_logic_error();
@@ -537,32 +932,42 @@ impl MempoolInner {
                             current_nonce, %tx_id, %error,
                             "failed to promote transaction during maintenance"
                         );
+                    } else {
+                        self.emit(tx_id, *address_bytes, MempoolEventKind::Promoted);
                     }
                 }
             } else {
                 // add demoted transactions to parked
                 for demotion_tx in demotion_txs {
-                    let tx_id = *demotion_tx.id();
-                    if let Err(error) =
-                        self.parked
-                            .add(demotion_tx, current_nonce, &current_balances)
-                    {
-                        self.contained_txs.remove(&tx_id);
-                        self.metrics.increment_internal_logic_error();
-                        error!(
-                            address = %telemetry::display::base64(&address_bytes),
-                            current_nonce, %tx_id, %error,
-                            "failed to demote transaction during maintenance"
-                        );
-                    }
+                    self.demote(demotion_tx, *current_nonce);
                 }
             }
         }
 
         // add to removal cache for cometbft and remove from the tracked set
-        for (tx_id, reason) in removed_txs {
+        for (tx_id, address_bytes, reason) in removed_txs {
+            self.contained_txs.remove(&tx_id);
+            self.comet_bft_removal_cache.add(tx_id, reason.clone());
+            self.emit(tx_id, address_bytes, MempoolEventKind::Removed(reason));
+        }
+
+        self.sync_journal();
+    }
+
+    /// Moves a transaction removed from pending to parked.
+    fn demote(&mut self, demotion_tx: TimemarkedTransaction, current_nonce: u32) {
+        let tx_id = *demotion_tx.id();
+        let address_bytes = *demotion_tx.address_bytes();
+        if let Err(error) = self.parked.add(demotion_tx, current_nonce, &HashMap::new()) {
             self.contained_txs.remove(&tx_id);
-            self.comet_bft_removal_cache.add(tx_id, reason);
+            self.metrics.increment_internal_logic_error();
+            error!(
+                address = %telemetry::display::base64(&address_bytes),
+                current_nonce, %tx_id, %error,
+                "failed to demote transaction during maintenance"
+            );
+        } else {
+            self.emit(tx_id, address_bytes, MempoolEventKind::Demoted);
         }
     }
 
@@ -585,6 +990,25 @@ impl MempoolInner {
         }
     }
 
+    fn estimate_fee(&self, target_blocks: u32) -> FeeEstimate {
+        let pending = self.pending.fee_rates_and_sizes();
+        let fee_levels = fee_estimation::fee_levels(&pending);
+        // Fee rates only affect the order of inclusion if the builder queue is ordered by them.
+        let recommended_fee_per_kb = match self.builder_queue_policy.ordering {
+            MempoolTransactionOrdering::TimeFirstSeen => 0,
+            MempoolTransactionOrdering::FeePerByte => fee_estimation::recommended_fee_per_kb(
+                pending,
+                target_blocks,
+                self.recent_blocks.latest_max_block_bytes(),
+            ),
+        };
+        FeeEstimate {
+            recent_blocks: self.recent_blocks.to_vec(),
+            fee_levels,
+            recommended_fee_per_kb,
+        }
+    }
+
     #[cfg(test)]
     fn removal_cache(&self) -> HashMap<TransactionId, RemovalReason> {
         self.comet_bft_removal_cache.cache.clone()
@@ -596,6 +1020,32 @@ impl MempoolInner {
     }
 }
 
+/// Returns the balances of an account, reading them from `state` on first access and caching
+/// them in `cache`.
+///
+/// Logs an error and returns `None` if the balances cannot be read.
+async fn cached_account_balances<'a, S: accounts::StateReadExt>(
+    state: &S,
+    cache: &'a mut HashMap<[u8; ADDRESS_LENGTH], HashMap<IbcPrefixed, u128>>,
+    address_bytes: &[u8; ADDRESS_LENGTH],
+) -> Option<&'a HashMap<IbcPrefixed, u128>> {
+    if !cache.contains_key(address_bytes) {
+        match get_account_balances(state, address_bytes).await {
+            Ok(balances) => {
+                cache.insert(*address_bytes, balances);
+            }
+            Err(error) => {
+                error!(
+                    address = %telemetry::display::base64(address_bytes),
+                    "failed to fetch account balances when cleaning accounts: {error:#}"
+                );
+                return None;
+            }
+        }
+    }
+    cache.get(address_bytes)
+}
+
 pub(crate) enum TransactionStatus {
     Pending,
     Parked,
@@ -687,7 +1137,7 @@ mod tests {
         let tx1 = new_alice_tx(&fixture, 1).await;
         assert!(
             mempool
-                .insert(tx1.clone(), 0, &account_balances, tx_costs.clone())
+                .insert(tx1.clone(), 0, &account_balances, tx_costs.clone(), None)
                 .await
                 .is_ok(),
             "should be able to insert nonce 1 transaction into mempool"
@@ -697,7 +1147,7 @@ mod tests {
         // try to insert again
         assert_eq!(
             mempool
-                .insert(tx1, 0, &account_balances, tx_costs.clone())
+                .insert(tx1, 0, &account_balances, tx_costs.clone(), None)
                 .await
                 .unwrap_err(),
             InsertionError::AlreadyPresent,
@@ -714,7 +1164,13 @@ mod tests {
             .await;
         assert_eq!(
             mempool
-                .insert(tx1_replacement, 0, &account_balances, tx_costs.clone(),)
+                .insert(
+                    tx1_replacement,
+                    0,
+                    &account_balances,
+                    tx_costs.clone(),
+                    None,
+                )
                 .await
                 .unwrap_err(),
             InsertionError::NonceTaken,
@@ -725,7 +1181,7 @@ mod tests {
         let tx0 = new_alice_tx(&fixture, 0).await;
         assert_eq!(
             mempool
-                .insert(tx0, 1, &account_balances, tx_costs)
+                .insert(tx0, 1, &account_balances, tx_costs, None)
                 .await
                 .unwrap_err(),
             InsertionError::NonceTooLow,
@@ -733,6 +1189,242 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn replace_by_fee() {
+        let fixture = Fixture::default_initialized().await;
+        let mempool = Mempool::new(fixture.metrics(), 100, BuilderQueuePolicy::default(), true);
+        mempool.set_fee_asset(denom_0().to_ibc_prefixed()).await;
+        let account_balances = dummy_balances(100, 100);
+
+        // insert nonce 0 into pending and nonce 2 into parked
+        let pending_tx = new_alice_tx(&fixture, 0).await;
+        mempool
+            .insert(
+                pending_tx.clone(),
+                0,
+                &account_balances,
+                dummy_tx_costs(10, 10, 0),
+                None,
+            )
+            .await
+            .unwrap();
+        let parked_tx = new_alice_tx(&fixture, 2).await;
+        mempool
+            .insert(
+                parked_tx.clone(),
+                0,
+                &account_balances,
+                dummy_tx_costs(10, 10, 0),
+                None,
+            )
+            .await
+            .unwrap();
+
+        // replacing with equal fees is rejected
+        let underpriced_tx = fixture
+            .checked_tx_builder()
+            .with_nonce(0)
+            .with_rollup_data_submission(vec![2, 3, 4])
+            .with_signer(ALICE.clone())
+            .build()
+            .await;
+        assert_eq!(
+            mempool
+                .insert(
+                    underpriced_tx,
+                    0,
+                    &account_balances,
+                    dummy_tx_costs(10, 10, 0),
+                    None
+                )
+                .await
+                .unwrap_err(),
+            InsertionError::ReplacementFeeTooLow,
+        );
+
+        // replacing with higher fees succeeds in both pending and parked
+        let pending_replacement = fixture
+            .checked_tx_builder()
+            .with_nonce(0)
+            .with_rollup_data_submission(vec![5, 6, 7])
+            .with_signer(ALICE.clone())
+            .build()
+            .await;
+        assert!(matches!(
+            mempool
+                .insert(
+                    pending_replacement.clone(),
+                    0,
+                    &account_balances,
+                    dummy_tx_costs(20, 10, 0),
+                    None
+                )
+                .await,
+            Ok(InsertionStatus::AddedToPending)
+        ));
+        let parked_replacement = fixture
+            .checked_tx_builder()
+            .with_nonce(2)
+            .with_rollup_data_submission(vec![5, 6, 7])
+            .with_signer(ALICE.clone())
+            .build()
+            .await;
+        assert!(matches!(
+            mempool
+                .insert(
+                    parked_replacement.clone(),
+                    0,
+                    &account_balances,
+                    dummy_tx_costs(20, 10, 0),
+                    None
+                )
+                .await,
+            Ok(InsertionStatus::AddedToParked)
+        ));
+        assert_eq!(mempool.len().await, 2);
+
+        // the replaced transactions are reported as removed
+        assert!(matches!(
+            mempool.transaction_status(pending_tx.id()).await,
+            Some(TransactionStatus::Removed(RemovalReason::Replaced(replaced_by)))
+                if replaced_by == *pending_replacement.id()
+        ));
+        assert!(matches!(
+            mempool.transaction_status(parked_tx.id()).await,
+            Some(TransactionStatus::Removed(RemovalReason::Replaced(replaced_by)))
+                if replaced_by == *parked_replacement.id()
+        ));
+        assert!(matches!(
+            mempool.transaction_status(pending_replacement.id()).await,
+            Some(TransactionStatus::Pending)
+        ));
+        assert!(matches!(
+            mempool.transaction_status(parked_replacement.id()).await,
+            Some(TransactionStatus::Parked)
+        ));
+        let builder_queue = mempool.builder_queue().await;
+        assert_eq!(builder_queue.len(), 1);
+        assert_eq!(builder_queue[0].id(), pending_replacement.id());
+    }
+
+    #[tokio::test]
+    async fn replace_by_fee_rejects_unaffordable_replacement() {
+        let fixture = Fixture::default_initialized().await;
+        let mempool = Mempool::new(fixture.metrics(), 100, BuilderQueuePolicy::default(), true);
+        mempool.set_fee_asset(denom_0().to_ibc_prefixed()).await;
+        let account_balances = dummy_balances(100, 100);
+
+        let tx0 = new_alice_tx(&fixture, 0).await;
+        mempool
+            .insert(
+                tx0.clone(),
+                0,
+                &account_balances,
+                dummy_tx_costs(10, 0, 0),
+                None,
+            )
+            .await
+            .unwrap();
+        let tx1 = new_alice_tx(&fixture, 1).await;
+        mempool
+            .insert(tx1, 0, &account_balances, dummy_tx_costs(50, 0, 0), None)
+            .await
+            .unwrap();
+
+        // the replacement's costs plus those of nonce 1 exceed the account's balance
+        let replacement = fixture
+            .checked_tx_builder()
+            .with_nonce(0)
+            .with_rollup_data_submission(vec![2, 3, 4])
+            .with_signer(ALICE.clone())
+            .build()
+            .await;
+        assert_eq!(
+            mempool
+                .insert(
+                    replacement,
+                    0,
+                    &account_balances,
+                    dummy_tx_costs(60, 0, 0),
+                    None
+                )
+                .await
+                .unwrap_err(),
+            InsertionError::ReplacementBalanceTooLow,
+        );
+        assert!(matches!(
+            mempool.transaction_status(tx0.id()).await,
+            Some(TransactionStatus::Pending)
+        ));
+    }
+
+    #[tokio::test]
+    async fn journal_records_contained_transactions() {
+        let mut fixture = Fixture::default_initialized().await;
+        let mempool = fixture.mempool();
+        let account_balances = dummy_balances(100, 100);
+        let tx_costs = dummy_tx_costs(10, 10, 0);
+
+        let tx0 = new_alice_tx(&fixture, 0).await;
+        mempool
+            .insert(tx0.clone(), 0, &account_balances, tx_costs.clone(), None)
+            .await
+            .unwrap();
+
+        // enabling the journal records the transactions already in the mempool
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal");
+        mempool
+            .enable_journal(Journal::open(path.clone()).unwrap())
+            .await;
+        flush_journal(&mempool).await;
+        assert_eq!(
+            vec![tx0.encoded_bytes().clone()],
+            Journal::read(&path).unwrap()
+        );
+
+        // accepted transactions are appended
+        let tx1 = new_alice_tx(&fixture, 1).await;
+        mempool
+            .insert(tx1.clone(), 0, &account_balances, tx_costs.clone(), None)
+            .await
+            .unwrap();
+        flush_journal(&mempool).await;
+        assert_eq!(
+            vec![tx0.encoded_bytes().clone(), tx1.encoded_bytes().clone()],
+            Journal::read(&path).unwrap()
+        );
+
+        // transactions leaving the mempool are dropped on maintenance
+        put_alice_balances(&mut fixture, account_balances);
+        fixture
+            .state_mut()
+            .put_account_nonce(&*ALICE_ADDRESS_BYTES, 1)
+            .unwrap();
+        let mut included_txs = HashSet::new();
+        included_txs.insert(*tx0.id());
+        mempool
+            .run_maintenance(fixture.state(), false, &included_txs, 1)
+            .await;
+        flush_journal(&mempool).await;
+        assert_eq!(
+            vec![tx1.encoded_bytes().clone()],
+            Journal::read(&path).unwrap()
+        );
+    }
+
+    async fn flush_journal(mempool: &Mempool) {
+        mempool
+            .inner
+            .read()
+            .await
+            .journal
+            .as_ref()
+            .expect("journal should be enabled")
+            .flush()
+            .await;
+    }
+
     #[tokio::test]
     async fn single_account_flow_extensive() {
         // This test tries to hit the more complex edges of the mempool with a single account.
@@ -749,7 +1441,7 @@ mod tests {
         let tx1 = new_alice_tx(&fixture, 1).await;
         assert!(
             mempool
-                .insert(tx1, 0, &account_balances, tx_costs.clone())
+                .insert(tx1, 0, &account_balances, tx_costs.clone(), None)
                 .await
                 .is_ok(),
             "should be able to insert nonce 1 transaction into mempool"
@@ -759,7 +1451,7 @@ mod tests {
         let tx2 = new_alice_tx(&fixture, 2).await;
         assert!(
             mempool
-                .insert(tx2, 0, &account_balances, tx_costs.clone())
+                .insert(tx2, 0, &account_balances, tx_costs.clone(), None)
                 .await
                 .is_ok(),
             "should be able to insert nonce 2 transaction into mempool"
@@ -769,7 +1461,7 @@ mod tests {
         let tx0 = new_alice_tx(&fixture, 0).await;
         assert!(
             mempool
-                .insert(tx0, 0, &account_balances, tx_costs.clone())
+                .insert(tx0, 0, &account_balances, tx_costs.clone(), None)
                 .await
                 .is_ok(),
             "should be able to insert nonce 0 transaction into mempool"
@@ -779,7 +1471,7 @@ mod tests {
         let tx4 = new_alice_tx(&fixture, 4).await;
         assert!(
             mempool
-                .insert(tx4, 0, &account_balances, tx_costs.clone())
+                .insert(tx4, 0, &account_balances, tx_costs.clone(), None)
                 .await
                 .is_ok(),
             "should be able to insert nonce 4 transaction into mempool"
@@ -839,19 +1531,19 @@ mod tests {
         let tx4 = new_alice_tx(&fixture, 4).await;
 
         mempool
-            .insert(tx1, 1, &initial_balances, tx_costs.clone())
+            .insert(tx1, 1, &initial_balances, tx_costs.clone(), None)
             .await
             .unwrap();
         mempool
-            .insert(tx2, 1, &initial_balances, tx_costs.clone())
+            .insert(tx2, 1, &initial_balances, tx_costs.clone(), None)
             .await
             .unwrap();
         mempool
-            .insert(tx3, 1, &initial_balances, tx_costs.clone())
+            .insert(tx3, 1, &initial_balances, tx_costs.clone(), None)
             .await
             .unwrap();
         mempool
-            .insert(tx4, 1, &initial_balances, tx_costs.clone())
+            .insert(tx4, 1, &initial_balances, tx_costs.clone(), None)
             .await
             .unwrap();
 
@@ -903,19 +1595,19 @@ mod tests {
         let tx4 = new_alice_tx(&fixture, 4).await;
 
         mempool
-            .insert(tx1, 1, &initial_balances, tx_costs.clone())
+            .insert(tx1, 1, &initial_balances, tx_costs.clone(), None)
             .await
             .unwrap();
         mempool
-            .insert(tx2, 1, &initial_balances, tx_costs.clone())
+            .insert(tx2, 1, &initial_balances, tx_costs.clone(), None)
             .await
             .unwrap();
         mempool
-            .insert(tx3, 1, &initial_balances, tx_costs.clone())
+            .insert(tx3, 1, &initial_balances, tx_costs.clone(), None)
             .await
             .unwrap();
         mempool
-            .insert(tx4, 1, &initial_balances, tx_costs.clone())
+            .insert(tx4, 1, &initial_balances, tx_costs.clone(), None)
             .await
             .unwrap();
 
@@ -974,7 +1666,7 @@ mod tests {
         let tx0 = new_alice_tx(&fixture, 0).await;
         assert!(
             mempool
-                .insert(tx0.clone(), 0, &account_balances, tx_costs.clone())
+                .insert(tx0.clone(), 0, &account_balances, tx_costs.clone(), None)
                 .await
                 .is_ok(),
             "should be able to insert nonce 0 transaction into mempool"
@@ -982,7 +1674,7 @@ mod tests {
         let tx1 = new_alice_tx(&fixture, 1).await;
         assert!(
             mempool
-                .insert(tx1.clone(), 0, &account_balances, tx_costs.clone())
+                .insert(tx1.clone(), 0, &account_balances, tx_costs.clone(), None)
                 .await
                 .is_ok(),
             "should be able to insert nonce 1 transaction into mempool"
@@ -990,7 +1682,7 @@ mod tests {
         let tx3 = new_alice_tx(&fixture, 3).await;
         assert!(
             mempool
-                .insert(tx3.clone(), 0, &account_balances, tx_costs.clone())
+                .insert(tx3.clone(), 0, &account_balances, tx_costs.clone(), None)
                 .await
                 .is_ok(),
             "should be able to insert nonce 3 transaction into mempool"
@@ -998,7 +1690,7 @@ mod tests {
         let tx4 = new_alice_tx(&fixture, 4).await;
         assert!(
             mempool
-                .insert(tx4.clone(), 0, &account_balances, tx_costs.clone())
+                .insert(tx4.clone(), 0, &account_balances, tx_costs.clone(), None)
                 .await
                 .is_ok(),
             "should be able to insert nonce 4 transaction into mempool"
@@ -1006,7 +1698,7 @@ mod tests {
         let tx5 = new_alice_tx(&fixture, 5).await;
         assert!(
             mempool
-                .insert(tx5.clone(), 0, &account_balances, tx_costs.clone())
+                .insert(tx5.clone(), 0, &account_balances, tx_costs.clone(), None)
                 .await
                 .is_ok(),
             "should be able to insert nonce 5 transaction into mempool"
@@ -1079,7 +1771,7 @@ mod tests {
         let tx0 = new_alice_tx(&fixture, 0).await;
         assert!(
             mempool
-                .insert(tx0, 0, &account_balances, tx_costs.clone())
+                .insert(tx0, 0, &account_balances, tx_costs.clone(), None)
                 .await
                 .is_ok(),
             "should be able to insert nonce 0 transaction into mempool"
@@ -1087,7 +1779,7 @@ mod tests {
         let tx1 = new_alice_tx(&fixture, 1).await;
         assert!(
             mempool
-                .insert(tx1, 0, &account_balances, tx_costs.clone())
+                .insert(tx1, 0, &account_balances, tx_costs.clone(), None)
                 .await
                 .is_ok(),
             "should be able to insert nonce 1 transaction into mempool"
@@ -1102,7 +1794,7 @@ mod tests {
             .await;
         assert!(
             mempool
-                .insert(tx100, 100, &account_balances, tx_costs.clone())
+                .insert(tx100, 100, &account_balances, tx_costs.clone(), None)
                 .await
                 .is_ok(),
             "should be able to insert nonce 100 transaction into mempool"
@@ -1115,7 +1807,7 @@ mod tests {
             .await;
         assert!(
             mempool
-                .insert(tx101, 100, &account_balances, tx_costs.clone())
+                .insert(tx101, 100, &account_balances, tx_costs.clone(), None)
                 .await
                 .is_ok(),
             "should be able to insert nonce 101 transaction into mempool"
@@ -1205,14 +1897,14 @@ mod tests {
 
         // check that the parked transaction is in the tracked set
         mempool
-            .insert(tx1.clone(), 0, &account_balances, tx_costs.clone())
+            .insert(tx1.clone(), 0, &account_balances, tx_costs.clone(), None)
             .await
             .unwrap();
         assert!(mempool.is_tracked(tx1.id()).await);
 
         // check that the pending transaction is in the tracked set
         mempool
-            .insert(tx0.clone(), 0, &account_balances, tx_costs.clone())
+            .insert(tx0.clone(), 0, &account_balances, tx_costs.clone(), None)
             .await
             .unwrap();
         assert!(mempool.is_tracked(tx0.id()).await);
@@ -1238,11 +1930,11 @@ mod tests {
         let tx1 = new_alice_tx(&fixture, 1).await;
 
         mempool
-            .insert(tx1.clone(), 0, &account_balances, tx_costs.clone())
+            .insert(tx1.clone(), 0, &account_balances, tx_costs.clone(), None)
             .await
             .unwrap();
         mempool
-            .insert(tx0.clone(), 0, &account_balances, tx_costs.clone())
+            .insert(tx0.clone(), 0, &account_balances, tx_costs.clone(), None)
             .await
             .unwrap();
 
@@ -1271,12 +1963,12 @@ mod tests {
         let tx1 = new_alice_tx(&fixture, 1).await;
 
         mempool
-            .insert(tx1.clone(), 0, &account_balances, tx_costs.clone())
+            .insert(tx1.clone(), 0, &account_balances, tx_costs.clone(), None)
             .await
             .unwrap();
 
         mempool
-            .insert(tx0.clone(), 0, &account_balances, tx_costs.clone())
+            .insert(tx0.clone(), 0, &account_balances, tx_costs.clone(), None)
             .await
             .unwrap();
 
@@ -1290,11 +1982,11 @@ mod tests {
 
         // re-insert the transactions into the mempool
         mempool
-            .insert(tx0.clone(), 0, &account_balances, tx_costs.clone())
+            .insert(tx0.clone(), 0, &account_balances, tx_costs.clone(), None)
             .await
             .unwrap();
         mempool
-            .insert(tx1.clone(), 0, &account_balances, tx_costs.clone())
+            .insert(tx1.clone(), 0, &account_balances, tx_costs.clone(), None)
             .await
             .unwrap();
 
@@ -1313,7 +2005,7 @@ mod tests {
         for nonce in 1..=u32::try_from(MAX_PARKED_TXS_PER_ACCOUNT).unwrap() {
             let tx = new_alice_tx(&fixture, nonce).await;
             mempool
-                .insert(tx, 0, &account_balances, tx_costs.clone())
+                .insert(tx, 0, &account_balances, tx_costs.clone(), None)
                 .await
                 .unwrap();
         }
@@ -1328,7 +2020,7 @@ mod tests {
         .await;
         assert_eq!(
             mempool
-                .insert(tx, 0, &account_balances, tx_costs.clone())
+                .insert(tx, 0, &account_balances, tx_costs.clone(), None)
                 .await
                 .unwrap_err(),
             InsertionError::AccountSizeLimit,
@@ -1351,19 +2043,31 @@ mod tests {
         let tx4 = new_alice_tx(&fixture, 4).await;
 
         mempool
-            .insert(tx1.clone(), 1, &initial_balances.clone(), tx_costs.clone())
+            .insert(
+                tx1.clone(),
+                1,
+                &initial_balances.clone(),
+                tx_costs.clone(),
+                None,
+            )
             .await
             .unwrap();
         mempool
-            .insert(tx2.clone(), 1, &initial_balances.clone(), tx_costs.clone())
+            .insert(
+                tx2.clone(),
+                1,
+                &initial_balances.clone(),
+                tx_costs.clone(),
+                None,
+            )
             .await
             .unwrap();
         mempool
-            .insert(tx3, 1, &initial_balances.clone(), tx_costs.clone())
+            .insert(tx3, 1, &initial_balances.clone(), tx_costs.clone(), None)
             .await
             .unwrap();
         mempool
-            .insert(tx4, 1, &initial_balances.clone(), tx_costs.clone())
+            .insert(tx4, 1, &initial_balances.clone(), tx_costs.clone(), None)
             .await
             .unwrap();
 
@@ -1420,6 +2124,74 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn subscribers_receive_events() {
+        const INCLUDED_TX_BLOCK_NUMBER: u64 = 12;
+
+        let mut fixture = Fixture::default_initialized().await;
+        let mempool = fixture.mempool();
+        let mut events = mempool.subscribe();
+
+        let initial_balances = dummy_balances(4, 0);
+        let tx_costs = dummy_tx_costs(0, 0, 0);
+        let tx1 = new_alice_tx(&fixture, 1).await;
+        let tx2 = new_alice_tx(&fixture, 2).await;
+        let tx3 = new_alice_tx(&fixture, 3).await;
+
+        // tx3 is parked until tx2 fills the nonce gap and promotes it.
+        for tx in [&tx1, &tx3, &tx2] {
+            mempool
+                .insert(tx.clone(), 1, &initial_balances, tx_costs.clone(), None)
+                .await
+                .unwrap();
+        }
+
+        fixture
+            .state_mut()
+            .put_account_nonce(&*ALICE_ADDRESS_BYTES, 2)
+            .unwrap();
+        put_alice_balances(&mut fixture, dummy_balances(0, 0));
+        let included_txs = HashSet::from([*tx1.id()]);
+        mempool
+            .run_maintenance(
+                fixture.state(),
+                false,
+                &included_txs,
+                INCLUDED_TX_BLOCK_NUMBER,
+            )
+            .await;
+
+        mempool
+            .remove_tx_invalid(tx2.clone(), RemovalReason::Expired)
+            .await;
+
+        let event = |tx: &Arc<CheckedTransaction>, kind| MempoolEvent {
+            tx_id: *tx.id(),
+            address_bytes: *ALICE_ADDRESS_BYTES,
+            kind,
+        };
+        let expected = vec![
+            event(&tx1, MempoolEventKind::AddedToPending),
+            event(&tx3, MempoolEventKind::AddedToParked),
+            event(&tx2, MempoolEventKind::AddedToPending),
+            event(&tx3, MempoolEventKind::Promoted),
+            event(
+                &tx1,
+                MempoolEventKind::Removed(RemovalReason::IncludedInBlock(INCLUDED_TX_BLOCK_NUMBER)),
+            ),
+            event(&tx2, MempoolEventKind::Removed(RemovalReason::Expired)),
+            event(
+                &tx3,
+                MempoolEventKind::Removed(RemovalReason::LowerNonceInvalidated),
+            ),
+        ];
+        let mut received = vec![];
+        while let Ok(event) = events.try_recv() {
+            received.push(event);
+        }
+        assert_eq!(expected, received);
+    }
+
     #[tokio::test]
     async fn insert_promoted_tx_removed_if_its_insertion_fails() {
         let fixture = Fixture::default_initialized().await;
@@ -1428,8 +2200,12 @@ mod tests {
         let account_balances = dummy_balances(100, 100);
         let tx_costs = dummy_tx_costs(10, 10, 0);
 
-        let pending_tx_1 =
-            TimemarkedTransaction::new(new_alice_tx(&fixture, 1).await, tx_costs.clone());
+        let pending_tx_1 = TimemarkedTransaction::new(
+            new_alice_tx(&fixture, 1).await,
+            tx_costs.clone(),
+            HashMap::new(),
+            Some(denom_0().to_ibc_prefixed()),
+        );
         let pending_tx_2 = new_alice_tx(&fixture, 2).await;
         // different rollup data so that this transaction's hash is different than the failing tx
         let pending_tx_3 = TimemarkedTransaction::new(
@@ -1441,9 +2217,15 @@ mod tests {
                 .build()
                 .await,
             tx_costs.clone(),
+            HashMap::new(),
+            Some(denom_0().to_ibc_prefixed()),
+        );
+        let failure_tx = TimemarkedTransaction::new(
+            new_alice_tx(&fixture, 3).await,
+            tx_costs.clone(),
+            HashMap::new(),
+            Some(denom_0().to_ibc_prefixed()),
         );
-        let failure_tx =
-            TimemarkedTransaction::new(new_alice_tx(&fixture, 3).await, tx_costs.clone());
 
         let mut inner = mempool.inner.write().await;
 
@@ -1476,7 +2258,7 @@ mod tests {
         // Insert tx nonce 2 to mempool, prompting promotion of tx nonce 3 from parked to pending,
         // which should fail
         mempool
-            .insert(pending_tx_2, 2, &account_balances, tx_costs)
+            .insert(pending_tx_2, 2, &account_balances, tx_costs, None)
             .await
             .unwrap();
 
@@ -1496,4 +2278,57 @@ mod tests {
             "contained txs should not contain the failed tx id"
         );
     }
+
+    #[tokio::test]
+    async fn estimate_fee_reports_recent_blocks_and_pending_fee_levels() {
+        let fixture = Fixture::default_initialized().await;
+        let mempool = fixture.mempool();
+        let account_balances = dummy_balances(100, 100);
+        let tx_costs = dummy_tx_costs(10, 10, 0);
+
+        let block = BlockFullness {
+            height: 1,
+            transaction_count: 2,
+            transaction_bytes: 300,
+            rollup_data_bytes: 100,
+            max_block_bytes: Some(1_000),
+        };
+        mempool.record_block_fullness(block).await;
+
+        // insert nonce 0 into pending and nonce 2 into parked, which should not be reported
+        let pending_tx = new_alice_tx(&fixture, 0).await;
+        let pending_tx_bytes = pending_tx.encoded_bytes().len();
+        mempool
+            .insert(pending_tx, 0, &account_balances, tx_costs.clone(), None)
+            .await
+            .unwrap();
+        mempool
+            .insert(
+                new_alice_tx(&fixture, 2).await,
+                0,
+                &account_balances,
+                tx_costs,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let estimate = mempool.estimate_fee(1).await;
+        assert_eq!(estimate.recent_blocks, vec![block]);
+        // only the fees of 10 in the native asset count; they are less than one per byte of the
+        // encoded tx, but not per 1000 bytes
+        let fee_per_kb =
+            10 * fee_estimation::FEE_RATE_BYTES / u128::try_from(pending_tx_bytes).unwrap();
+        assert!(fee_per_kb > 0);
+        assert_eq!(
+            estimate.fee_levels,
+            vec![FeeLevel {
+                min_fee_per_kb: fee_estimation::fee_level_floor(fee_per_kb),
+                transaction_count: 1,
+                transaction_bytes: pending_tx_bytes,
+                rollup_data_bytes: 3,
+            }]
+        );
+        assert_eq!(estimate.recommended_fee_per_kb, 0);
+    }
 }
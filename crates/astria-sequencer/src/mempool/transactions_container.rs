@@ -8,6 +8,7 @@ use std::{
     },
     fmt,
     mem,
+    num::NonZeroUsize,
     sync::Arc,
 };
 
@@ -24,6 +25,7 @@ use astria_eyre::eyre::{
     Result,
     WrapErr as _,
 };
+use bytes::Bytes;
 use tokio::time::{
     Duration,
     Instant,
@@ -33,35 +35,80 @@ use tracing::{
     instrument,
 };
 
-use super::RemovalReason;
+use super::{
+    fee_estimation::{
+        PendingTransaction,
+        FEE_RATE_BYTES,
+    },
+    BuilderQueuePolicy,
+    RemovalReason,
+};
 use crate::{
     accounts,
     accounts::AddressBytes as _,
     checked_transaction::CheckedTransaction,
+    config::MempoolTransactionOrdering,
 };
 
+/// The fees a sponsored transaction charges to its fee payer, together with the fee payer's
+/// balances in the latest chain state.
+#[derive(Clone, Debug)]
+pub(crate) struct SponsoredFees {
+    pub(crate) fees: HashMap<IbcPrefixed, u128>,
+    pub(crate) fee_payer_balances: HashMap<IbcPrefixed, u128>,
+}
+
 /// `TimemarkedTransaction` is a wrapper around a checked transaction used to keep track of when
 /// that transaction was first seen in the mempool and its total cost to execute.
+///
+/// The costs are charged to the transaction's account. If the transaction is sponsored, its fees
+/// are instead tracked separately as the sponsored fees charged to its fee payer.
+///
+/// The fee rate of the transaction only takes into account the fees paid in `fee_asset`.
 #[derive(Clone, Debug)]
 pub(super) struct TimemarkedTransaction {
     checked_tx: Arc<CheckedTransaction>,
     time_first_seen: Instant,
     costs: HashMap<IbcPrefixed, u128>,
+    sponsored_fees: HashMap<IbcPrefixed, u128>,
+    fee_asset: Option<IbcPrefixed>,
+    fee_rate: FeeRate,
 }
 
 impl TimemarkedTransaction {
     pub(super) fn new(
         checked_tx: Arc<CheckedTransaction>,
         costs: HashMap<IbcPrefixed, u128>,
+        sponsored_fees: HashMap<IbcPrefixed, u128>,
+        fee_asset: Option<IbcPrefixed>,
     ) -> Self {
+        let fee_rate = FeeRate::new(&checked_tx, &costs, &sponsored_fees, fee_asset.as_ref());
         Self {
             checked_tx,
             time_first_seen: Instant::now(),
             costs,
+            sponsored_fees,
+            fee_asset,
+            fee_rate,
         }
     }
 
-    fn priority(&self, current_account_nonce: u32) -> Result<TransactionPriority> {
+    /// Sets the asset in which fees are counted towards the fee rate, recalculating the fee rate.
+    fn set_fee_asset(&mut self, fee_asset: Option<IbcPrefixed>) {
+        self.fee_asset = fee_asset;
+        self.fee_rate = FeeRate::new(
+            &self.checked_tx,
+            &self.costs,
+            &self.sponsored_fees,
+            self.fee_asset.as_ref(),
+        );
+    }
+
+    fn priority(
+        &self,
+        current_account_nonce: u32,
+        ordering: MempoolTransactionOrdering,
+    ) -> Result<TransactionPriority> {
         let Some(nonce_diff) = self.checked_tx.nonce().checked_sub(current_account_nonce) else {
             return Err(eyre!(
                 "transaction nonce {} is less than current account nonce {current_account_nonce}",
@@ -69,10 +116,16 @@ impl TimemarkedTransaction {
             ));
         };
 
+        let fee_rate = match ordering {
+            MempoolTransactionOrdering::TimeFirstSeen => None,
+            MempoolTransactionOrdering::FeePerByte => Some(self.fee_rate),
+        };
+
         Ok(TransactionPriority {
             nonce_diff,
             time_first_seen: self.time_first_seen,
             group: self.checked_tx.group(),
+            fee_rate,
         })
     }
 
@@ -80,19 +133,16 @@ impl TimemarkedTransaction {
         &self,
         available_balances: &mut HashMap<IbcPrefixed, u128>,
     ) -> Result<()> {
-        self.costs.iter().try_for_each(|(denom, cost)| {
-            if *cost == 0 {
-                return Ok(());
-            }
-            let Some(current_balance) = available_balances.get_mut(denom) else {
-                return Err(eyre!("account missing balance for {denom}"));
-            };
-            let Some(new_balance) = current_balance.checked_sub(*cost) else {
-                return Err(eyre!("cost greater than account's balance for {denom}"));
-            };
-            *current_balance = new_balance;
-            Ok(())
-        })
+        deduct(&self.costs, available_balances)
+    }
+
+    /// Deducts the fees charged to the fee payer of a sponsored transaction from the fee payer's
+    /// `available_balances`.
+    pub(super) fn deduct_sponsored_fees(
+        &self,
+        available_balances: &mut HashMap<IbcPrefixed, u128>,
+    ) -> Result<()> {
+        deduct(&self.sponsored_fees, available_balances)
     }
 
     async fn recalculate_costs<S: accounts::StateReadExt>(&mut self, state: &S) -> Result<()> {
@@ -101,6 +151,18 @@ impl TimemarkedTransaction {
             .total_costs(state)
             .await
             .wrap_err("failed to recalculate tx costs")?;
+        self.sponsored_fees = self
+            .checked_tx
+            .fee_payer_costs(state)
+            .await
+            .wrap_err("failed to recalculate tx fees charged to the fee payer")?
+            .unwrap_or_default();
+        self.fee_rate = FeeRate::new(
+            &self.checked_tx,
+            &self.costs,
+            &self.sponsored_fees,
+            self.fee_asset.as_ref(),
+        );
         Ok(())
     }
 
@@ -120,9 +182,58 @@ impl TimemarkedTransaction {
         self.checked_tx.address_bytes()
     }
 
+    /// Returns the address bytes of the separate fee payer if the transaction is sponsored.
+    pub(super) fn sponsoring_fee_payer(&self) -> Option<&[u8; ADDRESS_LENGTH]> {
+        self.checked_tx
+            .is_sponsored()
+            .then(|| self.checked_tx.fee_payer_address_bytes())
+    }
+
     pub(super) fn costs(&self) -> &HashMap<IbcPrefixed, u128> {
         &self.costs
     }
+
+    pub(super) fn encoded_bytes(&self) -> &Bytes {
+        self.checked_tx.encoded_bytes()
+    }
+
+    /// Returns the fees paid by the transaction per [`FEE_RATE_BYTES`] bytes of its encoded size,
+    /// rounded down.
+    pub(super) fn fee_per_kb(&self) -> u128 {
+        self.fee_rate
+            .fees
+            .saturating_mul(FEE_RATE_BYTES)
+            .checked_div(self.fee_rate.size)
+            .unwrap_or_default()
+    }
+
+    /// Returns the total size of the rollup data submitted by the transaction.
+    pub(super) fn rollup_data_bytes(&self) -> usize {
+        self.checked_tx
+            .rollup_data_bytes()
+            .map(|(_rollup_id, data)| data.len())
+            .sum()
+    }
+}
+
+/// Deducts `costs` from `available_balances`, failing if any balance is too low to cover them.
+fn deduct(
+    costs: &HashMap<IbcPrefixed, u128>,
+    available_balances: &mut HashMap<IbcPrefixed, u128>,
+) -> Result<()> {
+    costs.iter().try_for_each(|(denom, cost)| {
+        if *cost == 0 {
+            return Ok(());
+        }
+        let Some(current_balance) = available_balances.get_mut(denom) else {
+            return Err(eyre!("account missing balance for {denom}"));
+        };
+        let Some(new_balance) = current_balance.checked_sub(*cost) else {
+            return Err(eyre!("cost greater than account's balance for {denom}"));
+        };
+        *current_balance = new_balance;
+        Ok(())
+    })
 }
 
 impl fmt::Display for TimemarkedTransaction {
@@ -140,11 +251,106 @@ impl fmt::Display for TimemarkedTransaction {
     }
 }
 
+/// The minimum increase in fee rate, in percent, for a transaction to replace the transaction with
+/// the same nonce in the mempool.
+pub(crate) const MIN_REPLACEMENT_FEE_RATE_BUMP_PERCENT: u128 = 10;
+
+/// The fees paid by a transaction relative to its encoded size in bytes.
+///
+/// Fees are the transaction's total costs in the fee asset less any amount of the fee asset it
+/// transfers out of the signer's account, plus the fees charged to its fee payer in the fee asset
+/// if it is sponsored.
+///
+/// Only fees paid in a single asset are counted, as amounts of different assets are not
+/// comparable. The mempool uses the chain's native asset as the fee asset, so transactions paying
+/// their fees in any other asset have a fee rate of zero, as do all transactions if the fee asset
+/// is not yet known.
+#[derive(Clone, Copy, Debug)]
+struct FeeRate {
+    fees: u128,
+    size: u128,
+}
+
+impl FeeRate {
+    fn new(
+        checked_tx: &CheckedTransaction,
+        costs: &HashMap<IbcPrefixed, u128>,
+        sponsored_fees: &HashMap<IbcPrefixed, u128>,
+        fee_asset: Option<&IbcPrefixed>,
+    ) -> Self {
+        let size = u128::try_from(checked_tx.encoded_bytes().len())
+            .expect("transaction size must fit into u128");
+        let Some(fee_asset) = fee_asset else {
+            return Self {
+                fees: 0,
+                size,
+            };
+        };
+        let transferred = checked_tx
+            .checked_actions()
+            .iter()
+            .filter_map(|action| action.asset_and_amount_to_transfer())
+            .filter(|(asset, _)| asset == fee_asset)
+            .fold(0_u128, |total, (_, amount)| total.saturating_add(amount));
+        let fees = costs
+            .get(fee_asset)
+            .copied()
+            .unwrap_or_default()
+            .saturating_sub(transferred)
+            .saturating_add(sponsored_fees.get(fee_asset).copied().unwrap_or_default());
+        Self {
+            fees,
+            size,
+        }
+    }
+
+    /// Returns `true` if `self` is higher than `replaced` by at least
+    /// [`MIN_REPLACEMENT_FEE_RATE_BUMP_PERCENT`].
+    ///
+    /// Requiring a minimum relative increase prevents replacing a transaction many times over for
+    /// a negligible increase in fees.
+    fn is_sufficient_replacement_for(&self, replaced: &Self) -> bool {
+        // compare `self.fees / self.size >= replaced.fees / replaced.size * (100 + bump) / 100`
+        // without losing precision to integer division.
+        let bumped = replaced
+            .fees
+            .saturating_mul(self.size)
+            .saturating_mul(MIN_REPLACEMENT_FEE_RATE_BUMP_PERCENT.saturating_add(100));
+        let offered = self.fees.saturating_mul(replaced.size).saturating_mul(100);
+        *self > *replaced && offered >= bumped
+    }
+}
+
+impl Ord for FeeRate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // compare `fees / size` without losing precision to integer division.
+        self.fees
+            .saturating_mul(other.size)
+            .cmp(&other.fees.saturating_mul(self.size))
+    }
+}
+
+impl PartialOrd for FeeRate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for FeeRate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for FeeRate {}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 struct TransactionPriority {
     nonce_diff: u32,
     time_first_seen: Instant,
     group: Group,
+    /// Only set if transactions are ordered by their fee rate.
+    fee_rate: Option<FeeRate>,
 }
 
 impl Ord for TransactionPriority {
@@ -161,7 +367,13 @@ impl Ord for TransactionPriority {
             return nonce_diff;
         }
 
-        // then by timestamp if nonce and group are equal
+        // then by fee rate where higher fee rate means higher priority
+        let fee_rate = self.fee_rate.cmp(&other.fee_rate);
+        if fee_rate != Ordering::Equal {
+            return fee_rate;
+        }
+
+        // then by timestamp if nonce, group and fee rate are equal
         self.time_first_seen.cmp(&other.time_first_seen).reverse()
     }
 }
@@ -181,6 +393,8 @@ pub(crate) enum InsertionError {
     AccountSizeLimit,
     AccountBalanceTooLow,
     ParkedSizeLimit,
+    ReplacementFeeTooLow,
+    ReplacementBalanceTooLow,
 }
 
 impl fmt::Display for InsertionError {
@@ -204,6 +418,17 @@ impl fmt::Display for InsertionError {
             InsertionError::ParkedSizeLimit => {
                 write!(f, "parked container size limit reached")
             }
+            InsertionError::ReplacementFeeTooLow => write!(
+                f,
+                "replacement transaction does not pay a fee rate at least \
+                 {MIN_REPLACEMENT_FEE_RATE_BUMP_PERCENT}% higher than the transaction with the \
+                 same nonce in the mempool"
+            ),
+            InsertionError::ReplacementBalanceTooLow => write!(
+                f,
+                "account does not have enough balance to cover costs after replacing the \
+                 transaction with the same nonce in the mempool"
+            ),
         }
     }
 }
@@ -220,7 +445,9 @@ impl From<InsertionError> for tonic::Status {
             InsertionError::AccountSizeLimit | InsertionError::ParkedSizeLimit => {
                 tonic::Status::resource_exhausted(err.to_string())
             }
-            InsertionError::AccountBalanceTooLow => {
+            InsertionError::AccountBalanceTooLow
+            | InsertionError::ReplacementFeeTooLow
+            | InsertionError::ReplacementBalanceTooLow => {
                 tonic::Status::failed_precondition(err.to_string())
             }
         }
@@ -365,7 +592,8 @@ pub(super) struct ParkedTransactionsForAccount<const MAX_TX_COUNT: usize> {
 impl<const MAX_TX_COUNT: usize> ParkedTransactionsForAccount<MAX_TX_COUNT> {
     /// Returns contiguous transactions from front of queue starting from `target_nonce`, removing
     /// the transactions in the process. Will only return transactions if their cost is covered
-    /// by the `available_balances`.
+    /// by the `available_balances`, and the fees of sponsored transactions by the available
+    /// balances of their fee payers in `fee_payer_balances`.
     ///
     /// `target_nonce` should be the next nonce that the pending queue could add.
     ///
@@ -375,12 +603,21 @@ impl<const MAX_TX_COUNT: usize> ParkedTransactionsForAccount<MAX_TX_COUNT> {
         &mut self,
         mut target_nonce: u32,
         mut available_balances: HashMap<IbcPrefixed, u128>,
+        mut fee_payer_balances: HashMap<[u8; ADDRESS_LENGTH], HashMap<IbcPrefixed, u128>>,
     ) -> impl Iterator<Item = TimemarkedTransaction> {
         let mut split_at: u32 = 0;
         for (nonce, ttx) in &self.txs {
             if *nonce != target_nonce || ttx.deduct_costs(&mut available_balances).is_err() {
                 break;
             }
+            if let Some(fee_payer) = ttx.sponsoring_fee_payer() {
+                let Some(fee_payer_balances) = fee_payer_balances.get_mut(fee_payer) else {
+                    break;
+                };
+                if ttx.deduct_sponsored_fees(fee_payer_balances).is_err() {
+                    break;
+                }
+            }
             let Some(next_target) = target_nonce.checked_add(1) else {
                 // We've got contiguous nonces up to `u32::MAX`; return everything.
                 return mem::take(&mut self.txs).into_values();
@@ -504,6 +741,55 @@ pub(super) trait TransactionsForAccount: Default {
         Ok(())
     }
 
+    /// Adds transaction to the container, replacing the transaction with the same nonce if `ttx`
+    /// pays a sufficiently higher fee rate than it.
+    ///
+    /// Returns the replaced transaction, if any. If there is no transaction with the same nonce,
+    /// this behaves like [`Self::add`]. Otherwise, replacement will fail if the fee rate of `ttx`
+    /// does not exceed that of the existing transaction by at least
+    /// [`MIN_REPLACEMENT_FEE_RATE_BUMP_PERCENT`], or if the balance precondition would be broken
+    /// after the replacement.
+    fn add_or_replace(
+        &mut self,
+        ttx: TimemarkedTransaction,
+        current_account_nonce: u32,
+        current_account_balances: &HashMap<IbcPrefixed, u128>,
+    ) -> Result<Option<TimemarkedTransaction>, InsertionError> {
+        let Some(existing_ttx) = self.txs().get(&ttx.nonce()) else {
+            return self
+                .add(ttx, current_account_nonce, current_account_balances)
+                .map(|()| None);
+        };
+
+        if ttx.nonce() < current_account_nonce {
+            return Err(InsertionError::NonceTooLow);
+        }
+
+        if existing_ttx.id() == ttx.id() {
+            return Err(InsertionError::AlreadyPresent);
+        }
+
+        if !ttx
+            .fee_rate
+            .is_sufficient_replacement_for(&existing_ttx.fee_rate)
+        {
+            return Err(InsertionError::ReplacementFeeTooLow);
+        }
+
+        let replaced_ttx = self
+            .txs_mut()
+            .remove(&ttx.nonce())
+            .expect("transaction with the same nonce should be present");
+        if !self.has_balance_to_cover(&ttx, current_account_balances) {
+            self.txs_mut().insert(replaced_ttx.nonce(), replaced_ttx);
+            return Err(InsertionError::ReplacementBalanceTooLow);
+        }
+
+        self.txs_mut().insert(ttx.nonce(), ttx);
+
+        Ok(Some(replaced_ttx))
+    }
+
     /// Removes transactions with the given nonce and higher.
     ///
     /// Note: the given nonce is expected to be present. If it's absent, an error is logged and no
@@ -609,6 +895,17 @@ pub(super) trait TransactionsContainer<T: TransactionsForAccount> {
         self.txs().keys()
     }
 
+    /// Returns the encoded bytes of all contained transactions, in nonce order per account.
+    fn encoded_txs<'a>(&'a self) -> impl Iterator<Item = (&'a TransactionId, &'a Bytes)>
+    where
+        T: 'a,
+    {
+        self.txs()
+            .values()
+            .flat_map(|account_txs| account_txs.txs().values())
+            .map(|ttx| (ttx.id(), ttx.encoded_bytes()))
+    }
+
     /// Recosts transactions for an account.
     ///
     /// Logs an error if fails to recost a transaction.
@@ -633,6 +930,16 @@ pub(super) trait TransactionsContainer<T: TransactionsForAccount> {
         }
     }
 
+    /// Sets the asset in which fees are counted towards the fee rates of all contained
+    /// transactions.
+    fn set_fee_asset(&mut self, fee_asset: Option<IbcPrefixed>) {
+        for account_txs in self.txs_mut().values_mut() {
+            for ttx in account_txs.txs_mut().values_mut() {
+                ttx.set_fee_asset(fee_asset);
+            }
+        }
+    }
+
     /// Adds the transaction to the container.
     ///
     /// `current_account_nonce` should be the current nonce of the account associated with the
@@ -661,6 +968,30 @@ pub(super) trait TransactionsContainer<T: TransactionsForAccount> {
         Ok(())
     }
 
+    /// Adds the transaction to the container, replacing the transaction of the same account with
+    /// the same nonce if `ttx` pays a sufficiently higher fee rate than it.
+    ///
+    /// Returns the replaced transaction, if any. Replacing a transaction does not count towards
+    /// the container's size limit.
+    fn add_or_replace(
+        &mut self,
+        ttx: TimemarkedTransaction,
+        current_account_nonce: u32,
+        current_account_balances: &HashMap<IbcPrefixed, u128>,
+    ) -> Result<Option<TimemarkedTransaction>, InsertionError> {
+        if let Some(account_txs) = self.txs_mut().get_mut(ttx.address_bytes()) {
+            if account_txs.txs().contains_key(&ttx.nonce()) {
+                return account_txs.add_or_replace(
+                    ttx,
+                    current_account_nonce,
+                    current_account_balances,
+                );
+            }
+        }
+        self.add(ttx, current_account_nonce, current_account_balances)
+            .map(|()| None)
+    }
+
     /// Removes the given transaction and any transactions with higher nonces for the relevant
     /// account.
     ///
@@ -810,6 +1141,115 @@ impl PendingTransactions {
         current_balances
     }
 
+    /// Returns remaining balances for an account after accounting for the fees charged to it by
+    /// the pending transactions it sponsors as fee payer.
+    ///
+    /// Note: assumes that the balances in `current_balances` are large enough to cover the fees.
+    /// Missing or insufficient balances are reduced to zero.
+    pub(super) fn subtract_sponsored_fees(
+        &self,
+        fee_payer: &[u8; ADDRESS_LENGTH],
+        mut current_balances: HashMap<IbcPrefixed, u128>,
+    ) -> HashMap<IbcPrefixed, u128> {
+        for ttx in self.sponsored_by(fee_payer) {
+            for (denom, fee) in &ttx.sponsored_fees {
+                if let Some(balance) = current_balances.get_mut(denom) {
+                    *balance = balance.saturating_sub(*fee);
+                }
+            }
+        }
+        current_balances
+    }
+
+    /// Returns what remains of a fee payer's `current_balances` after accounting for the costs of
+    /// its own pending transactions and the fees charged to it by the pending transactions it
+    /// sponsors, or `None` if the balances cannot cover them.
+    pub(super) fn fee_payer_remaining_balances(
+        &self,
+        fee_payer: &[u8; ADDRESS_LENGTH],
+        mut current_balances: HashMap<IbcPrefixed, u128>,
+    ) -> Option<HashMap<IbcPrefixed, u128>> {
+        self.txs
+            .get(fee_payer)
+            .into_iter()
+            .flat_map(|account_txs| account_txs.txs.values())
+            .try_for_each(|ttx| ttx.deduct_costs(&mut current_balances))
+            .ok()?;
+        self.sponsored_by(fee_payer)
+            .try_for_each(|ttx| ttx.deduct_sponsored_fees(&mut current_balances))
+            .ok()?;
+        Some(current_balances)
+    }
+
+    /// Returns the fee payers of all sponsored pending transactions.
+    pub(super) fn fee_payers(&self) -> HashSet<[u8; ADDRESS_LENGTH]> {
+        self.txs
+            .values()
+            .flat_map(|account_txs| account_txs.txs.values())
+            .filter_map(TimemarkedTransaction::sponsoring_fee_payer)
+            .copied()
+            .collect()
+    }
+
+    /// Removes and returns the pending transactions sponsored by `fee_payer`, together with the
+    /// transactions of the same accounts with higher nonces to keep the pending nonces of each
+    /// account free of gaps.
+    pub(super) fn demote_sponsored_by(
+        &mut self,
+        fee_payer: &[u8; ADDRESS_LENGTH],
+    ) -> Vec<TimemarkedTransaction> {
+        let mut demoted = Vec::new();
+        self.txs.retain(|_, account_txs| {
+            let first_sponsored_nonce = account_txs
+                .txs
+                .values()
+                .find(|ttx| ttx.sponsoring_fee_payer() == Some(fee_payer))
+                .map(TimemarkedTransaction::nonce);
+            if let Some(nonce) = first_sponsored_nonce {
+                demoted.extend(account_txs.txs.split_off(&nonce).into_values());
+            }
+            !account_txs.txs.is_empty()
+        });
+        demoted
+    }
+
+    /// Reverts the insertion of `ttx` into the container, re-inserting the transaction it
+    /// replaced if any.
+    ///
+    /// `ttx` must be the most recently inserted transaction of its account, which for newly added
+    /// transactions is always the one with the highest nonce.
+    pub(super) fn undo_insertion(
+        &mut self,
+        ttx: &TimemarkedTransaction,
+        replaced: Option<TimemarkedTransaction>,
+    ) {
+        let Some(account_txs) = self.txs.get_mut(ttx.address_bytes()) else {
+            return;
+        };
+        match replaced {
+            Some(replaced) => {
+                account_txs.txs.insert(replaced.nonce(), replaced);
+            }
+            None => {
+                account_txs.txs.remove(&ttx.nonce());
+            }
+        }
+        if account_txs.txs.is_empty() {
+            self.txs.remove(ttx.address_bytes());
+        }
+    }
+
+    /// Returns the pending transactions sponsored by `fee_payer`.
+    fn sponsored_by<'a>(
+        &'a self,
+        fee_payer: &'a [u8; ADDRESS_LENGTH],
+    ) -> impl Iterator<Item = &'a TimemarkedTransaction> {
+        self.txs
+            .values()
+            .flat_map(|account_txs| account_txs.txs.values())
+            .filter(move |ttx| ttx.sponsoring_fee_payer() == Some(fee_payer))
+    }
+
     /// Returns the highest nonce for an account.
     pub(super) fn pending_nonce(&self, address_bytes: &[u8; ADDRESS_LENGTH]) -> Option<u32> {
         self.txs
@@ -817,9 +1257,25 @@ impl PendingTransactions {
             .and_then(PendingTransactionsForAccount::pending_account_nonce)
     }
 
-    /// Returns a copy of transactions and their hashes sorted by nonce difference and then time
-    /// first seen.
-    pub(super) fn builder_queue(&self) -> Vec<Arc<CheckedTransaction>> {
+    /// Returns the fee rate and size of every pending transaction.
+    pub(super) fn fee_rates_and_sizes(&self) -> Vec<PendingTransaction> {
+        self.txs
+            .values()
+            .flat_map(|account_txs| account_txs.txs.values())
+            .map(|ttx| PendingTransaction {
+                fee_per_kb: ttx.fee_per_kb(),
+                transaction_bytes: ttx.encoded_bytes().len(),
+                rollup_data_bytes: ttx.rollup_data_bytes(),
+            })
+            .collect()
+    }
+
+    /// Returns a copy of transactions and their hashes sorted by group, nonce difference, then
+    /// fee rate if `policy` orders by fee rate, and then time first seen.
+    ///
+    /// At most `policy.max_txs_per_account` transactions with the lowest nonces are returned for
+    /// each account.
+    pub(super) fn builder_queue(&self, policy: BuilderQueuePolicy) -> Vec<Arc<CheckedTransaction>> {
         // Used to hold the values in Vec for sorting.
         struct QueueEntry {
             checked_tx: Arc<CheckedTransaction>,
@@ -837,8 +1293,11 @@ impl PendingTransactions {
                 continue;
             };
 
-            for ttx in account_txs.txs.values() {
-                let priority = match ttx.priority(current_account_nonce) {
+            let max_txs = policy
+                .max_txs_per_account
+                .map_or(usize::MAX, NonZeroUsize::get);
+            for ttx in account_txs.txs.values().take(max_txs) {
+                let priority = match ttx.priority(current_account_nonce, policy.ordering) {
                     Ok(priority) => priority,
                     Err(error) => {
                         // mempool could be off due to node connectivity issues
@@ -879,18 +1338,28 @@ impl<const MAX_PARKED_TXS_PER_ACCOUNT: usize> ParkedTransactions<MAX_PARKED_TXS_
     /// Removes and returns the transactions that can be promoted from parked to pending for
     /// an account. Will only return sequential nonces from `target_nonce` whose costs are
     /// covered by the `available_balance`.
+    ///
+    /// Sponsored transactions are only returned if their fees are covered by the balances of their
+    /// fee payers in `fee_payer_balances`, which should be what remains of the fee payers' balances
+    /// after accounting for the costs charged to them by pending transactions. Fee payers missing
+    /// from `fee_payer_balances` are treated as unable to cover any fees.
     pub(super) fn find_promotables(
         &mut self,
         address_bytes: &[u8; ADDRESS_LENGTH],
         target_nonce: u32,
         available_balance: &HashMap<IbcPrefixed, u128>,
+        fee_payer_balances: HashMap<[u8; ADDRESS_LENGTH], HashMap<IbcPrefixed, u128>>,
     ) -> Vec<TimemarkedTransaction> {
         // Take the collection for this account out of `self` temporarily.
         let Some(mut account_txs) = self.txs.remove(address_bytes) else {
             return Vec::new();
         };
 
-        let removed = account_txs.find_promotables(target_nonce, available_balance.clone());
+        let removed = account_txs.find_promotables(
+            target_nonce,
+            available_balance.clone(),
+            fee_payer_balances,
+        );
 
         // Re-add the collection to `self` if it's not empty.
         if !account_txs.txs().is_empty() {
@@ -899,6 +1368,20 @@ impl<const MAX_PARKED_TXS_PER_ACCOUNT: usize> ParkedTransactions<MAX_PARKED_TXS_
 
         removed.collect()
     }
+
+    /// Returns the fee payers of the sponsored parked transactions of an account.
+    pub(super) fn fee_payers(
+        &self,
+        address_bytes: &[u8; ADDRESS_LENGTH],
+    ) -> HashSet<[u8; ADDRESS_LENGTH]> {
+        self.txs
+            .get(address_bytes)
+            .into_iter()
+            .flat_map(|account_txs| account_txs.txs.values())
+            .filter_map(TimemarkedTransaction::sponsoring_fee_payer)
+            .copied()
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -916,7 +1399,6 @@ mod tests {
             },
         },
     };
-    use bytes::Bytes;
 
     use super::*;
     use crate::{
@@ -948,6 +1430,7 @@ mod tests {
         checked_tx_builder: CheckedTxBuilder<'a>,
         group: Option<Group>,
         cost_map: HashMap<IbcPrefixed, u128>,
+        sponsored_fees: HashMap<IbcPrefixed, u128>,
     }
 
     impl<'a> MockTTXBuilder<'a> {
@@ -956,6 +1439,7 @@ mod tests {
                 checked_tx_builder: fixture.checked_tx_builder().with_signer(ALICE.clone()),
                 group: None,
                 cost_map: dummy_tx_costs(0, 0, 0),
+                sponsored_fees: HashMap::new(),
             }
         }
 
@@ -979,6 +1463,16 @@ mod tests {
             self
         }
 
+        fn sponsored_by(
+            mut self,
+            fee_payer: SigningKey,
+            sponsored_fees: HashMap<IbcPrefixed, u128>,
+        ) -> Self {
+            self.checked_tx_builder = self.checked_tx_builder.with_fee_payer(fee_payer);
+            self.sponsored_fees = sponsored_fees;
+            self
+        }
+
         async fn build(self) -> TimemarkedTransaction {
             let tx = match self.group {
                 Some(Group::UnbundleableSudo) => {
@@ -1022,7 +1516,12 @@ mod tests {
             if let Some(group) = self.group {
                 assert_eq!(group, tx.group());
             }
-            TimemarkedTransaction::new(tx, self.cost_map)
+            TimemarkedTransaction::new(
+                tx,
+                self.cost_map,
+                self.sponsored_fees,
+                Some(denom_0().to_ibc_prefixed()),
+            )
         }
     }
 
@@ -1030,7 +1529,7 @@ mod tests {
     async fn transaction_priority_should_error_if_invalid() {
         let fixture = Fixture::default_initialized().await;
         let ttx = MockTTXBuilder::new(&fixture).nonce(0).build().await;
-        let priority = ttx.priority(1);
+        let priority = ttx.priority(1, MempoolTransactionOrdering::TimeFirstSeen);
 
         assert!(priority
             .unwrap_err()
@@ -1047,21 +1546,25 @@ mod tests {
             group: Group::BundleableGeneral,
             nonce_diff: 0,
             time_first_seen: instant,
+            fee_rate: None,
         };
         let unbundleable_general = TransactionPriority {
             group: Group::UnbundleableGeneral,
             nonce_diff: 0,
             time_first_seen: instant,
+            fee_rate: None,
         };
         let bundleable_sudo = TransactionPriority {
             group: Group::BundleableSudo,
             nonce_diff: 0,
             time_first_seen: instant,
+            fee_rate: None,
         };
         let unbundleable_sudo = TransactionPriority {
             group: Group::UnbundleableSudo,
             nonce_diff: 0,
             time_first_seen: instant,
+            fee_rate: None,
         };
 
         // partial_cmp
@@ -1158,11 +1661,13 @@ mod tests {
             group: Group::BundleableGeneral,
             nonce_diff: 0,
             time_first_seen: instant,
+            fee_rate: None,
         };
         let low = TransactionPriority {
             group: Group::BundleableGeneral,
             nonce_diff: 1,
             time_first_seen: instant,
+            fee_rate: None,
         };
 
         assert!(high.partial_cmp(&high) == Some(Ordering::Equal));
@@ -1207,11 +1712,13 @@ mod tests {
             group: Group::BundleableGeneral,
             nonce_diff: 0,
             time_first_seen: Instant::now(),
+            fee_rate: None,
         };
         let low = TransactionPriority {
             group: Group::BundleableGeneral,
             nonce_diff: 0,
             time_first_seen: Instant::now() + Duration::from_micros(10),
+            fee_rate: None,
         };
 
         assert!(high.partial_cmp(&high) == Some(Ordering::Equal));
@@ -2148,7 +2655,7 @@ mod tests {
             .unwrap();
 
         // get builder queue - should return all transactions from Alice and Bob
-        let builder_queue = pending_txs.builder_queue();
+        let builder_queue = pending_txs.builder_queue(BuilderQueuePolicy::default());
         assert_eq!(
             builder_queue.len(),
             4,
@@ -2189,6 +2696,207 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn pending_transactions_builder_queue_ordered_by_fee_rate() {
+        let fixture = Fixture::default_initialized().await;
+        let mut pending_txs = PendingTransactions::new(TX_TTL);
+
+        // Alice's transaction is seen first but pays lower fees than Bob's and Carol's.
+        let ttx_alice = MockTTXBuilder::new(&fixture)
+            .nonce(1)
+            .cost_map(dummy_tx_costs(10, 0, 0))
+            .build()
+            .await;
+        let ttx_bob = MockTTXBuilder::new(&fixture)
+            .nonce(1)
+            .signer(BOB.clone())
+            .cost_map(dummy_tx_costs(30, 0, 0))
+            .build()
+            .await;
+        let ttx_carol = MockTTXBuilder::new(&fixture)
+            .nonce(1)
+            .signer(CAROL.clone())
+            .cost_map(dummy_tx_costs(20, 0, 0))
+            .build()
+            .await;
+        let account_balances = dummy_balances(100, 100);
+        for ttx in [&ttx_alice, &ttx_bob, &ttx_carol] {
+            pending_txs.add(ttx.clone(), 1, &account_balances).unwrap();
+        }
+
+        let by_time = pending_txs.builder_queue(BuilderQueuePolicy::default());
+        assert_eq!(by_time[0].id(), ttx_alice.checked_tx.id());
+        assert_eq!(by_time[1].id(), ttx_bob.checked_tx.id());
+        assert_eq!(by_time[2].id(), ttx_carol.checked_tx.id());
+
+        let by_fee_rate = pending_txs.builder_queue(BuilderQueuePolicy {
+            ordering: MempoolTransactionOrdering::FeePerByte,
+            max_txs_per_account: None,
+        });
+        assert_eq!(by_fee_rate[0].id(), ttx_bob.checked_tx.id());
+        assert_eq!(by_fee_rate[1].id(), ttx_carol.checked_tx.id());
+        assert_eq!(by_fee_rate[2].id(), ttx_alice.checked_tx.id());
+    }
+
+    #[tokio::test]
+    async fn fee_rate_only_counts_fees_in_fee_asset() {
+        let fixture = Fixture::default_initialized().await;
+
+        let ttx_native = MockTTXBuilder::new(&fixture)
+            .cost_map(dummy_tx_costs(10, 0, 0))
+            .build()
+            .await;
+        let ttx_other = MockTTXBuilder::new(&fixture)
+            .cost_map(dummy_tx_costs(0, 1_000, 0))
+            .build()
+            .await;
+        assert_eq!(10, ttx_native.fee_rate.fees);
+        assert_eq!(0, ttx_other.fee_rate.fees);
+        assert!(ttx_native.fee_rate > ttx_other.fee_rate);
+
+        // switching the fee asset recalculates the fee rate
+        let mut ttx_other = ttx_other;
+        ttx_other.set_fee_asset(Some(denom_1().to_ibc_prefixed()));
+        assert_eq!(1_000, ttx_other.fee_rate.fees);
+        ttx_other.set_fee_asset(None);
+        assert_eq!(0, ttx_other.fee_rate.fees);
+    }
+
+    #[tokio::test]
+    async fn pending_transactions_builder_queue_fee_rate_does_not_override_nonce_order() {
+        let fixture = Fixture::default_initialized().await;
+        let mut pending_txs = PendingTransactions::new(TX_TTL);
+
+        let ttx_1 = MockTTXBuilder::new(&fixture)
+            .nonce(1)
+            .cost_map(dummy_tx_costs(1, 0, 0))
+            .build()
+            .await;
+        let ttx_2 = MockTTXBuilder::new(&fixture)
+            .nonce(2)
+            .cost_map(dummy_tx_costs(50, 0, 0))
+            .build()
+            .await;
+        let account_balances = dummy_balances(100, 100);
+        pending_txs
+            .add(ttx_1.clone(), 1, &account_balances)
+            .unwrap();
+        pending_txs
+            .add(ttx_2.clone(), 1, &account_balances)
+            .unwrap();
+
+        let builder_queue = pending_txs.builder_queue(BuilderQueuePolicy {
+            ordering: MempoolTransactionOrdering::FeePerByte,
+            max_txs_per_account: None,
+        });
+        assert_eq!(builder_queue[0].id(), ttx_1.checked_tx.id());
+        assert_eq!(builder_queue[1].id(), ttx_2.checked_tx.id());
+    }
+
+    #[tokio::test]
+    async fn pending_transactions_builder_queue_caps_txs_per_account() {
+        let fixture = Fixture::default_initialized().await;
+        let mut pending_txs = PendingTransactions::new(TX_TTL);
+
+        let mut bob_ttxs = vec![];
+        for nonce in 1..=3 {
+            bob_ttxs.push(
+                MockTTXBuilder::new(&fixture)
+                    .nonce(nonce)
+                    .signer(BOB.clone())
+                    .build()
+                    .await,
+            );
+        }
+        let ttx_alice = MockTTXBuilder::new(&fixture).nonce(1).build().await;
+        let account_balances = dummy_balances(1, 1);
+        for ttx in &bob_ttxs {
+            pending_txs.add(ttx.clone(), 1, &account_balances).unwrap();
+        }
+        pending_txs
+            .add(ttx_alice.clone(), 1, &account_balances)
+            .unwrap();
+
+        let builder_queue = pending_txs.builder_queue(BuilderQueuePolicy {
+            ordering: MempoolTransactionOrdering::TimeFirstSeen,
+            max_txs_per_account: NonZeroUsize::new(2),
+        });
+        let ids: Vec<_> = builder_queue.iter().map(|tx| *tx.id()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                *bob_ttxs[0].checked_tx.id(),
+                *ttx_alice.checked_tx.id(),
+                *bob_ttxs[1].checked_tx.id(),
+            ],
+            "only Bob's two lowest nonce transactions should be included"
+        );
+        assert_eq!(
+            pending_txs.len(),
+            4,
+            "capped transactions should remain pending"
+        );
+    }
+
+    #[test]
+    fn fee_rate_comparison_accounts_for_size() {
+        let cheap_small = FeeRate {
+            fees: 10,
+            size: 100,
+        };
+        let pricey_large = FeeRate {
+            fees: 20,
+            size: 400,
+        };
+        let same_rate = FeeRate {
+            fees: 40,
+            size: 400,
+        };
+        assert!(cheap_small > pricey_large);
+        assert_eq!(cheap_small, same_rate);
+        assert!(pricey_large < same_rate);
+    }
+
+    #[test]
+    fn replacement_requires_minimum_fee_rate_bump() {
+        let existing = FeeRate {
+            fees: 100,
+            size: 100,
+        };
+        let below_bump = FeeRate {
+            fees: 109,
+            size: 100,
+        };
+        let at_bump = FeeRate {
+            fees: 110,
+            size: 100,
+        };
+        let higher_fees_but_larger = FeeRate {
+            fees: 200,
+            size: 190,
+        };
+        let higher_fees_and_smaller = FeeRate {
+            fees: 100,
+            size: 90,
+        };
+        assert!(!existing.is_sufficient_replacement_for(&existing));
+        assert!(!below_bump.is_sufficient_replacement_for(&existing));
+        assert!(at_bump.is_sufficient_replacement_for(&existing));
+        assert!(!higher_fees_but_larger.is_sufficient_replacement_for(&existing));
+        assert!(higher_fees_and_smaller.is_sufficient_replacement_for(&existing));
+
+        let free = FeeRate {
+            fees: 0,
+            size: 100,
+        };
+        let paying = FeeRate {
+            fees: 1,
+            size: 100,
+        };
+        assert!(!free.is_sufficient_replacement_for(&free));
+        assert!(paying.is_sufficient_replacement_for(&free));
+    }
+
     #[tokio::test]
     async fn parked_transactions_find_promotables() {
         let fixture = Fixture::default_initialized().await;
@@ -2224,11 +2932,21 @@ mod tests {
             .unwrap();
 
         // none should be returned on nonce gap
-        let promotables = parked_txs.find_promotables(&ALICE_ADDRESS_BYTES, 0, &remaining_balances);
+        let promotables = parked_txs.find_promotables(
+            &ALICE_ADDRESS_BYTES,
+            0,
+            &remaining_balances,
+            HashMap::new(),
+        );
         assert_eq!(promotables.len(), 0);
 
         // only first two transactions should be returned
-        let promotables = parked_txs.find_promotables(&ALICE_ADDRESS_BYTES, 1, &remaining_balances);
+        let promotables = parked_txs.find_promotables(
+            &ALICE_ADDRESS_BYTES,
+            1,
+            &remaining_balances,
+            HashMap::new(),
+        );
         assert_eq!(promotables.len(), 2);
         assert_eq!(promotables[0].nonce(), 1);
         assert_eq!(promotables[1].nonce(), 2);
@@ -2240,7 +2958,7 @@ mod tests {
 
         // empty account should be removed
         // remove last
-        parked_txs.find_promotables(&ALICE_ADDRESS_BYTES, 3, &remaining_balances);
+        parked_txs.find_promotables(&ALICE_ADDRESS_BYTES, 3, &remaining_balances, HashMap::new());
         assert_eq!(
             parked_txs.addresses().count(),
             0,
@@ -2248,6 +2966,89 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn parked_transactions_find_promotables_checks_fee_payer_balances() {
+        let fixture = Fixture::default_initialized().await;
+        let mut parked_txs = ParkedTransactions::<MAX_PARKED_TXS_PER_ACCOUNT>::new(TX_TTL, 100);
+
+        let sponsored_ttx = MockTTXBuilder::new(&fixture)
+            .nonce(0)
+            .sponsored_by(BOB.clone(), dummy_tx_costs(10, 0, 0))
+            .build()
+            .await;
+        let balances = dummy_balances(0, 0);
+        parked_txs.add(sponsored_ttx, 0, &balances).unwrap();
+
+        // not promoted if the fee payer's balances are unknown or too low
+        let promotables =
+            parked_txs.find_promotables(&ALICE_ADDRESS_BYTES, 0, &balances, HashMap::new());
+        assert!(promotables.is_empty());
+        let promotables = parked_txs.find_promotables(
+            &ALICE_ADDRESS_BYTES,
+            0,
+            &balances,
+            HashMap::from([(*BOB_ADDRESS_BYTES, dummy_balances(9, 0))]),
+        );
+        assert!(promotables.is_empty());
+
+        // promoted once the fee payer can cover the fees
+        let promotables = parked_txs.find_promotables(
+            &ALICE_ADDRESS_BYTES,
+            0,
+            &balances,
+            HashMap::from([(*BOB_ADDRESS_BYTES, dummy_balances(10, 0))]),
+        );
+        assert_eq!(promotables.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn pending_transactions_charge_sponsored_fees_to_fee_payer() {
+        let fixture = Fixture::default_initialized().await;
+        let mut pending_txs = PendingTransactions::new(TX_TTL);
+        let balances = dummy_balances(0, 0);
+
+        let alice_sponsored_ttx = MockTTXBuilder::new(&fixture)
+            .nonce(0)
+            .sponsored_by(BOB.clone(), dummy_tx_costs(10, 0, 0))
+            .build()
+            .await;
+        let alice_ttx = MockTTXBuilder::new(&fixture).nonce(1).build().await;
+        let carol_sponsored_ttx = MockTTXBuilder::new(&fixture)
+            .nonce(0)
+            .signer(CAROL.clone())
+            .sponsored_by(BOB.clone(), dummy_tx_costs(10, 0, 0))
+            .build()
+            .await;
+        pending_txs.add(alice_sponsored_ttx, 0, &balances).unwrap();
+        pending_txs.add(alice_ttx, 0, &balances).unwrap();
+        pending_txs.add(carol_sponsored_ttx, 0, &balances).unwrap();
+
+        assert_eq!(
+            pending_txs.fee_payers(),
+            HashSet::from([*BOB_ADDRESS_BYTES])
+        );
+        assert_eq!(
+            pending_txs.subtract_sponsored_fees(&BOB_ADDRESS_BYTES, dummy_balances(25, 0))
+                [&denom_0().to_ibc_prefixed()],
+            5
+        );
+        assert_eq!(
+            pending_txs
+                .fee_payer_remaining_balances(&BOB_ADDRESS_BYTES, dummy_balances(20, 0))
+                .unwrap()[&denom_0().to_ibc_prefixed()],
+            0
+        );
+        assert!(pending_txs
+            .fee_payer_remaining_balances(&BOB_ADDRESS_BYTES, dummy_balances(19, 0))
+            .is_none());
+
+        // demoting the sponsored transactions also demotes the higher nonces of their accounts
+        let demoted = pending_txs.demote_sponsored_by(&BOB_ADDRESS_BYTES);
+        assert_eq!(demoted.len(), 3);
+        assert_eq!(pending_txs.len(), 0);
+        assert!(pending_txs.fee_payers().is_empty());
+    }
+
     #[tokio::test]
     async fn pending_transactions_find_demotables() {
         let fixture = Fixture::default_initialized().await;
@@ -2431,7 +3232,7 @@ mod tests {
 
         // get the builder queue
         // note: the account nonces are set to zero when not initialized in the mock state
-        let builder_queue = pending_txs.builder_queue();
+        let builder_queue = pending_txs.builder_queue(BuilderQueuePolicy::default());
 
         // check that the transactions are in the expected order
         let first_tx_id = builder_queue[0].id();
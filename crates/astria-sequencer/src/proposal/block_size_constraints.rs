@@ -9,7 +9,7 @@ use astria_eyre::eyre::{
 use super::commitment::GeneratedCommitments;
 
 /// The maximum number of bytes allowed in sequencer action data.
-const MAX_SEQUENCE_DATA_BYTES_PER_BLOCK: usize = 256_000;
+pub(crate) const MAX_SEQUENCE_DATA_BYTES_PER_BLOCK: usize = 256_000;
 
 /// Struct for organizing block size constraints in prepare proposal
 #[derive(Copy, Clone, serde::Serialize)]
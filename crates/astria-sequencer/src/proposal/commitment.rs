@@ -67,8 +67,8 @@ impl<const USES_DATA_ITEM_ENUM: bool> IntoIterator for GeneratedCommitments<USES
 /// `rollup_datas_commitment` expected at the start of the block.
 ///
 /// This function sorts the block's `sequence::Action`s contained within the transactions
-/// using their `rollup_id`. It also appends the `Deposit`s generated by the block execution
-/// to each rollup's sequenced data.
+/// using their `rollup_id`. It then appends the rollup data submitted by interchain accounts
+/// and the `Deposit`s generated by the block execution to each rollup's sequenced data.
 /// It then returns the merkle root of the tree where each leaf is
 /// a commitment of the rollup data (`sequence::Action`s and `Deposit`s) with the same `rollup_id`.
 /// The leaves are ordered by `rollup_id` in ascending order.
@@ -79,13 +79,19 @@ impl<const USES_DATA_ITEM_ENUM: bool> IntoIterator for GeneratedCommitments<USES
 /// tree.
 pub(crate) fn generate_rollup_datas_commitment<const USES_DATA_ITEM_ENUM: bool>(
     checked_txs: &[Arc<CheckedTransaction>],
+    interchain_rollup_data: &[(RollupId, Bytes)],
     deposits: HashMap<RollupId, Vec<Deposit>>,
 ) -> GeneratedCommitments<USES_DATA_ITEM_ENUM> {
     use prost::Message as _;
 
     let rollup_data_bytes = checked_txs
         .iter()
-        .flat_map(|checked_tx| checked_tx.rollup_data_bytes());
+        .flat_map(|checked_tx| checked_tx.rollup_data_bytes())
+        .chain(
+            interchain_rollup_data
+                .iter()
+                .map(|rollup_data| (&rollup_data.0, &rollup_data.1)),
+        );
     let mut rollup_ids_to_txs = group_rollup_data_submissions_by_rollup_id(rollup_data_bytes);
 
     for (rollup_id, deposit) in deposits {
@@ -159,7 +165,7 @@ mod tests {
         let GeneratedCommitments {
             rollup_datas_root: commitment_0,
             ..
-        } = generate_rollup_datas_commitment::<false>(&[tx], HashMap::new());
+        } = generate_rollup_datas_commitment::<false>(&[tx], &[], HashMap::new());
 
         let tx = fixture
             .checked_tx_builder()
@@ -171,7 +177,7 @@ mod tests {
         let GeneratedCommitments {
             rollup_datas_root: commitment_1,
             ..
-        } = generate_rollup_datas_commitment::<false>(&[tx], HashMap::new());
+        } = generate_rollup_datas_commitment::<false>(&[tx], &[], HashMap::new());
         assert_eq!(commitment_0, commitment_1);
     }
 
@@ -207,7 +213,7 @@ mod tests {
         let GeneratedCommitments {
             rollup_datas_root: actual,
             ..
-        } = generate_rollup_datas_commitment::<false>(&[tx], HashMap::new());
+        } = generate_rollup_datas_commitment::<false>(&[tx], &[], HashMap::new());
 
         let expected = [
             189_u8, 156, 127, 228, 51, 249, 64, 237, 150, 91, 219, 216, 1, 99, 135, 28, 235, 15,
@@ -1,4 +1,7 @@
-use std::time::Duration;
+use std::{
+    num::NonZeroUsize,
+    time::Duration,
+};
 
 use astria_core::generated::price_feed::service::v2::oracle_client::OracleClient;
 use astria_eyre::{
@@ -56,9 +59,23 @@ use crate::{
         AbciListenUrl,
         Config,
     },
-    mempool::Mempool,
+    mempool::{
+        self,
+        BuilderQueuePolicy,
+        Journal,
+        Mempool,
+    },
     metrics::Metrics,
-    service,
+    service::{
+        self,
+        mempool::CheckTxOutcome,
+    },
+    state_sync::{
+        self,
+        SnapshotStore,
+        Snapshotter,
+    },
+    storage::StateHistory,
     upgrades::UpgradesHandler,
 };
 
@@ -76,6 +93,7 @@ struct RunningGrpcServer {
 
 struct RunningAbciServer {
     pub handle: AbciServerHandle,
+    pub snapshotter_handle: Option<JoinHandle<()>>,
     pub shutdown_rx: oneshot::Receiver<()>,
     pub consensus_cancellation_token: tokio_util::sync::CancellationToken,
 }
@@ -150,6 +168,9 @@ impl Sequencer {
             .wrap_err("grpc server task failed")?
             .wrap_err("grpc server failed")?;
         abci_server.handle.abort();
+        if let Some(snapshotter_handle) = abci_server.snapshotter_handle {
+            snapshotter_handle.abort();
+        }
         Ok(())
     }
 
@@ -162,18 +183,13 @@ impl Sequencer {
         register_histogram_global("cnidarium_get_raw_duration_seconds");
         register_histogram_global("cnidarium_nonverifiable_get_raw_duration_seconds");
 
-        let substore_prefixes = vec![penumbra_ibc::IBC_SUBSTORE_PREFIX];
+        let substore_prefixes = vec![penumbra_ibc::IBC_SUBSTORE_PREFIX.to_string()];
 
-        let storage = cnidarium::Storage::load(
-            config.db_filepath.clone(),
-            substore_prefixes
-                .into_iter()
-                .map(ToString::to_string)
-                .collect(),
-        )
-        .await
-        .map_err(anyhow_to_eyre)
-        .wrap_err("failed to load storage backing chain state")?;
+        let storage =
+            cnidarium::Storage::load(config.db_filepath.clone(), substore_prefixes.clone())
+                .await
+                .map_err(anyhow_to_eyre)
+                .wrap_err("failed to load storage backing chain state")?;
         let snapshot = storage.latest_snapshot();
 
         let upgrades_handler =
@@ -205,8 +221,10 @@ impl Sequencer {
         // the genesis state must include the native asset's base
         // denomination, and it is set in storage during init_chain.
         // on subsequent startups, we load the native asset from storage.
-        if storage.latest_version() != u64::MAX {
-            let _ = snapshot
+        let native_asset = if storage.latest_version() == u64::MAX {
+            None
+        } else {
+            let native_asset = snapshot
                 .get_native_asset()
                 .await
                 .context("failed to query state for native asset")?;
@@ -214,18 +232,41 @@ impl Sequencer {
                 .get_base_prefix()
                 .await
                 .context("failed to query state for base prefix")?;
-        }
+            native_asset
+        };
 
-        let mempool = Mempool::new(metrics, config.mempool_parked_max_tx_count);
+        let builder_queue_policy = BuilderQueuePolicy {
+            ordering: config.mempool_transaction_ordering,
+            max_txs_per_account: NonZeroUsize::new(config.mempool_max_block_txs_per_account),
+        };
+        let mempool = Mempool::new(
+            metrics,
+            config.mempool_parked_max_tx_count,
+            builder_queue_policy,
+            config.mempool_replace_by_fee,
+        );
+        if let Some(native_asset) = &native_asset {
+            mempool.set_fee_asset(native_asset.to_ibc_prefixed()).await;
+        }
+        let journal_path = mempool::journal_path(&config.db_filepath);
+        if storage.latest_version() != u64::MAX {
+            let journaled_txs =
+                Journal::read(&journal_path).wrap_err("failed to read mempool journal")?;
+            restore_mempool(journaled_txs, storage.latest_snapshot(), &mempool, metrics).await;
+        }
+        let journal = Journal::open(journal_path).wrap_err("failed to open mempool journal")?;
+        mempool.enable_journal(journal).await;
         let price_feed_client = new_price_feed_client(&config)
             .await
             .wrap_err("failed to create connected price feed client")?;
         let upgrades = upgrades_handler.upgrades().clone();
+        let state_history = StateHistory::new(config.state_retention_blocks);
         let app = App::new(
             snapshot,
             mempool.clone(),
             upgrades_handler,
             crate::app::vote_extension::Handler::new(price_feed_client),
+            state_history.clone(),
             metrics,
         )
         .await
@@ -257,12 +298,29 @@ impl Sequencer {
             grpc_shutdown_rx,
         ));
 
+        let snapshot_store = SnapshotStore::new(state_sync::snapshot_dir(&config.db_filepath));
+        let snapshotter_handle = if config.snapshot_interval == 0 {
+            info!("state sync snapshots are disabled");
+            None
+        } else {
+            let snapshotter = Snapshotter::new(
+                storage.clone(),
+                snapshot_store.clone(),
+                config.snapshot_interval,
+                config.snapshot_keep_recent,
+                substore_prefixes,
+            );
+            Some(tokio::spawn(snapshotter.run()))
+        };
+
         debug!(%config.abci_listen_url, "starting sequencer");
         let consensus_cancellation_token = tokio_util::sync::CancellationToken::new();
         let abci_server_handle = start_abci_server(
             &storage,
             app,
             mempool_service,
+            snapshot_store,
+            state_history,
             config.abci_listen_url,
             abci_shutdown_tx,
             consensus_cancellation_token.clone(),
@@ -275,6 +333,7 @@ impl Sequencer {
         };
         let abci_server = RunningAbciServer {
             handle: abci_server_handle,
+            snapshotter_handle,
             shutdown_rx: abci_shutdown_rx,
             consensus_cancellation_token,
         };
@@ -286,10 +345,43 @@ impl Sequencer {
     }
 }
 
+/// Re-checks the transactions recorded in the mempool journal against the latest state and
+/// re-inserts those that pass into `mempool`. Transactions which fail the checks, for example
+/// because their nonces went stale while the sequencer was down, are discarded.
+#[instrument(skip_all)]
+async fn restore_mempool(
+    journaled_txs: Vec<bytes::Bytes>,
+    snapshot: cnidarium::Snapshot,
+    mempool: &Mempool,
+    metrics: &'static Metrics,
+) {
+    let mut restored: usize = 0;
+    let mut discarded: usize = 0;
+    for tx_bytes in journaled_txs {
+        match service::mempool::check_tx(tx_bytes, snapshot.clone(), mempool, metrics).await {
+            CheckTxOutcome::AddedToPending(_) | CheckTxOutcome::AddedToParked(_) => {
+                restored = restored.saturating_add(1);
+            }
+            CheckTxOutcome::AlreadyInPending(_) | CheckTxOutcome::AlreadyInParked(_) => {}
+            outcome => {
+                debug!(?outcome, "discarding journaled transaction");
+                discarded = discarded.saturating_add(1);
+            }
+        }
+    }
+    info!(restored, discarded, "restored mempool from journal");
+}
+
+#[expect(
+    clippy::too_many_arguments,
+    reason = "this should probably be replaced with a builder or dedicated struct"
+)]
 fn start_abci_server(
     storage: &cnidarium::Storage,
     app: App,
     mempool_service: service::Mempool,
+    snapshot_store: SnapshotStore,
+    state_history: StateHistory,
     listen_url: AbciListenUrl,
     abci_shutdown_tx: oneshot::Sender<()>,
     consensus_cancellation_token: tokio_util::sync::CancellationToken,
@@ -306,9 +398,9 @@ fn start_abci_server(
                     .await
             }
         }));
-    let info_service =
-        service::Info::new(storage.clone()).wrap_err("failed initializing info service")?;
-    let snapshot_service = service::Snapshot;
+    let info_service = service::Info::new(storage.clone(), state_history)
+        .wrap_err("failed initializing info service")?;
+    let snapshot_service = service::Snapshot::new(storage.clone(), snapshot_store);
 
     let server = Server::builder()
         .consensus(consensus_service)
@@ -440,6 +532,7 @@ mod tests {
     use std::path::PathBuf;
 
     use super::*;
+    use crate::config::MempoolTransactionOrdering;
 
     #[tokio::test(start_paused = true)]
     async fn should_wait_while_unable_to_connect() {
@@ -460,7 +553,13 @@ mod tests {
             price_feed_grpc_addr: "http://127.0.0.1:8081".to_string(),
             price_feed_client_timeout_milliseconds: 1,
             mempool_parked_max_tx_count: 1,
+            mempool_transaction_ordering: MempoolTransactionOrdering::default(),
+            mempool_max_block_txs_per_account: 0,
+            mempool_replace_by_fee: false,
             no_optimistic_blocks: false,
+            snapshot_interval: 0,
+            snapshot_keep_recent: 0,
+            state_retention_blocks: 0,
         };
 
         let start = tokio::time::Instant::now();
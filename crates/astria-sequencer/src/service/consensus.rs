@@ -369,6 +369,7 @@ mod tests {
                 0,
                 &dummy_balances(0, 0),
                 dummy_tx_costs(0, 0, 0),
+                None,
             )
             .await
             .unwrap();
@@ -504,6 +505,7 @@ mod tests {
                 0,
                 &dummy_balances(0, 0),
                 dummy_tx_costs(0, 0, 0),
+                None,
             )
             .await
             .unwrap();
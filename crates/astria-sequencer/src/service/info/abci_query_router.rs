@@ -38,7 +38,6 @@ use std::{
     pin::Pin,
 };
 
-use cnidarium::Storage;
 use matchit::{
     Match,
     MatchError,
@@ -48,6 +47,8 @@ use tendermint::abci::{
     response,
 };
 
+use crate::storage::QueryState;
+
 #[derive(Debug, thiserror::Error)]
 #[error("`{route}` is an invalid route")]
 pub(crate) struct InsertError {
@@ -105,11 +106,11 @@ impl BoxedAbciQueryHandler {
 
     pub(super) async fn call(
         self,
-        storage: Storage,
+        snapshot: QueryState,
         request: request::Query,
         params: Vec<(String, String)>,
     ) -> response::Query {
-        self.0.call(storage, request, params).await
+        self.0.call(snapshot, request, params).await
     }
 }
 
@@ -124,7 +125,7 @@ pub(super) trait ErasedAbciQueryHandler: Send {
 
     fn call(
         self: Box<Self>,
-        storage: Storage,
+        snapshot: QueryState,
         request: request::Query,
         params: Vec<(String, String)>,
     ) -> Pin<Box<dyn Future<Output = response::Query> + Send>>;
@@ -155,18 +156,18 @@ where
 
     fn call(
         self: Box<Self>,
-        storage: Storage,
+        snapshot: QueryState,
         request: request::Query,
         params: Vec<(String, String)>,
     ) -> Pin<Box<dyn Future<Output = response::Query> + Send>> {
-        self.handler.call(storage, request, params)
+        self.handler.call(snapshot, request, params)
     }
 }
 
 pub(super) trait AbciQueryHandler: Clone + Send + Sized + 'static {
     fn call(
         self,
-        storage: Storage,
+        snapshot: QueryState,
         request: request::Query,
         params: Vec<(String, String)>,
     ) -> Pin<Box<dyn Future<Output = response::Query> + Send>>;
@@ -174,15 +175,15 @@ pub(super) trait AbciQueryHandler: Clone + Send + Sized + 'static {
 
 impl<F, Fut> AbciQueryHandler for F
 where
-    F: FnOnce(Storage, request::Query, Vec<(String, String)>) -> Fut + Clone + Send + 'static,
+    F: FnOnce(QueryState, request::Query, Vec<(String, String)>) -> Fut + Clone + Send + 'static,
     Fut: Future<Output = response::Query> + Send,
 {
     fn call(
         self,
-        storage: Storage,
+        snapshot: QueryState,
         request: request::Query,
         params: Vec<(String, String)>,
     ) -> Pin<Box<dyn Future<Output = response::Query> + Send>> {
-        Box::pin(async move { self(storage, request, params).await })
+        Box::pin(async move { self(snapshot, request, params).await })
     }
 }
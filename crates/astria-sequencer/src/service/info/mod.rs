@@ -15,14 +15,18 @@ use futures::{
     TryFutureExt as _,
 };
 use penumbra_tower_trace::v038::RequestExt as _;
-use tendermint::v0_38::abci::{
-    request,
-    response::{
-        self,
-        Echo,
+use tendermint::{
+    abci::Code,
+    block::Height,
+    v0_38::abci::{
+        request,
+        response::{
+            self,
+            Echo,
+        },
+        InfoRequest,
+        InfoResponse,
     },
-    InfoRequest,
-    InfoResponse,
 };
 use tokio::try_join;
 use tower::Service;
@@ -39,16 +43,24 @@ use astria_eyre::{
     eyre::Result,
 };
 
-use crate::app::StateReadExt as _;
+use crate::{
+    app::StateReadExt as _,
+    storage::{
+        QueryState,
+        StateHistory,
+    },
+};
 
 #[derive(Clone)]
 pub(crate) struct Info {
     storage: Storage,
+    state_history: StateHistory,
     query_router: abci_query_router::Router,
 }
 
 const ACCOUNT_BALANCE: &str = "accounts/balance/:account";
 const ACCOUNT_NONCE: &str = "accounts/nonce/:account";
+const ACCOUNT_ESCROWS: &str = "accounts/escrows/:account";
 const ASSET_DENOM: &str = "asset/denom/:id";
 const FEE_ALLOWED_ASSETS: &str = "asset/allowed_fee_assets";
 
@@ -59,13 +71,22 @@ const TRANSACTION_FEE: &str = "transaction/fee";
 
 const FEES_COMPONENTS: &str = "fees/components";
 
+const IBC_RATE_LIMITS: &str = "ibc/rate_limits/:channel";
+
+const STAKING_DELEGATIONS: &str = "staking/delegations/:account";
+const STAKING_VALIDATOR: &str = "staking/validator/:address";
+
 impl Info {
-    pub(crate) fn new(storage: Storage) -> Result<Self> {
+    pub(crate) fn new(storage: Storage, state_history: StateHistory) -> Result<Self> {
         let mut query_router = abci_query_router::Router::new();
 
         // NOTE: Skipping error context because `InsertError` contains all required information.
         query_router.insert(ACCOUNT_BALANCE, crate::accounts::query::balance_request)?;
         query_router.insert(ACCOUNT_NONCE, crate::accounts::query::nonce_request)?;
+        query_router.insert(
+            ACCOUNT_ESCROWS,
+            crate::accounts::query::pending_escrows_request,
+        )?;
         query_router.insert(ASSET_DENOM, crate::assets::query::denom_request)?;
         query_router.insert(
             FEE_ALLOWED_ASSETS,
@@ -81,8 +102,21 @@ impl Info {
         )?;
         query_router.insert(TRANSACTION_FEE, crate::fees::query::transaction_fee_request)?;
         query_router.insert(FEES_COMPONENTS, crate::fees::query::components)?;
+        query_router.insert(
+            IBC_RATE_LIMITS,
+            crate::ibc::query::ics20_rate_limits_request,
+        )?;
+        query_router.insert(
+            STAKING_DELEGATIONS,
+            crate::staking::query::delegations_request,
+        )?;
+        query_router.insert(
+            STAKING_VALIDATOR,
+            crate::staking::query::validator_stake_request,
+        )?;
         Ok(Self {
             storage,
+            state_history,
             query_router,
         })
     }
@@ -124,6 +158,9 @@ impl Info {
     }
 
     /// Handles `abci_query` RPCs.
+    ///
+    /// The query is answered against the state at the requested height, or the latest state if
+    /// the requested height is 0.
     async fn handle_abci_query(self, request: request::Query) -> response::Query {
         let (handler, params) = match self.query_router.at(&request.path) {
             Err(err) => {
@@ -147,7 +184,80 @@ impl Info {
                 (handler, params)
             }
         };
-        handler.call(self.storage.clone(), request, params).await
+        let snapshot = match self.snapshot_at(request.height).await {
+            Ok(snapshot) => snapshot,
+            Err(err_rsp) => return err_rsp,
+        };
+        handler.call(snapshot, request, params).await
+    }
+
+    /// Returns the state committed at `height`, or the latest state if `height` is 0.
+    ///
+    /// Past heights are served from the snapshots still cached by the storage if possible, and
+    /// are otherwise reconstructed from the persisted [`StateHistory`].
+    async fn snapshot_at(&self, height: Height) -> Result<QueryState, response::Query> {
+        let latest = self.storage.latest_snapshot();
+        let requested_height = height.value();
+        if requested_height == 0 {
+            return Ok(latest.into());
+        }
+        let latest_height = match latest.get_block_height().await {
+            Ok(latest_height) => latest_height,
+            Err(err) => {
+                return Err(response::Query {
+                    code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+                    info: AbciErrorCode::INTERNAL_ERROR.info(),
+                    log: format!("failed getting latest block height: {err:#}"),
+                    ..response::Query::default()
+                });
+            }
+        };
+        if requested_height == latest_height {
+            return Ok(latest.into());
+        }
+        if requested_height > latest_height {
+            return Err(response::Query {
+                code: Code::Err(AbciErrorCode::INVALID_PARAMETER.value()),
+                info: AbciErrorCode::INVALID_PARAMETER.info(),
+                log: format!(
+                    "requested height {requested_height} is greater than the latest height \
+                     {latest_height}"
+                ),
+                ..response::Query::default()
+            });
+        }
+        if let Some(snapshot) = latest
+            .get_storage_version_by_height(requested_height)
+            .await
+            .ok()
+            .and_then(|version| self.storage.snapshot(version))
+        {
+            return Ok(snapshot.into());
+        }
+        match self
+            .state_history
+            .state_at(latest, latest_height, requested_height)
+            .await
+        {
+            Ok(Some(state)) => Ok(state),
+            Ok(None) => Err(response::Query {
+                code: Code::Err(AbciErrorCode::HEIGHT_PRUNED.value()),
+                info: AbciErrorCode::HEIGHT_PRUNED.info(),
+                log: format!(
+                    "the state at height {requested_height} has been pruned; the latest height is \
+                     {latest_height}"
+                ),
+                ..response::Query::default()
+            }),
+            Err(err) => Err(response::Query {
+                code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+                info: AbciErrorCode::INTERNAL_ERROR.info(),
+                log: format!(
+                    "failed reconstructing the state at height {requested_height}: {err:#}"
+                ),
+                ..response::Query::default()
+            }),
+        }
     }
 }
 
@@ -173,12 +283,16 @@ impl Service<InfoRequest> for Info {
 #[cfg(test)]
 mod tests {
     use astria_core::{
-        primitive::v1::asset,
+        primitive::v1::{
+            asset,
+            Address,
+        },
         protocol::{
             account::v1::BalanceResponse,
             asset::v1::DenomResponse,
             fees::v1::FeeComponents,
             transaction::v1::action::{
+                Bond,
                 BridgeLock,
                 BridgeSudoChange,
                 BridgeTransfer,
@@ -186,16 +300,25 @@ mod tests {
                 CurrencyPairsChange,
                 FeeAssetChange,
                 FeeChange,
+                GovernanceParametersChange,
                 IbcRelayerChange,
                 IbcSudoChange,
+                Ics20RateLimitChange,
                 Ics20Withdrawal,
                 InitBridgeAccount,
                 MarketsChange,
+                ProposalSubmission,
+                ProposalVote,
                 RecoverIbcClient,
+                RegisterMultisigAccount,
                 RollupDataSubmission,
+                ScheduledTransfer,
+                StakingParametersChange,
                 SudoAddressChange,
                 Transfer,
+                Unbond,
                 ValidatorUpdate,
+                VoteExtensionParticipationChange,
             },
         },
     };
@@ -224,7 +347,10 @@ mod tests {
             StateReadExt as _,
             StateWriteExt as _,
         },
-        test_utils::nria,
+        test_utils::{
+            astria_address,
+            nria,
+        },
     };
 
     #[tokio::test]
@@ -269,7 +395,7 @@ mod tests {
 
         let response = {
             let storage = (*storage).clone();
-            let info_service = Info::new(storage).unwrap();
+            let info_service = Info::new(storage, StateHistory::new(0)).unwrap();
             info_service
                 .handle_info_request(info_request)
                 .await
@@ -320,7 +446,7 @@ mod tests {
 
         let response = {
             let storage = (*storage).clone();
-            let info_service = Info::new(storage).unwrap();
+            let info_service = Info::new(storage, StateHistory::new(0)).unwrap();
             info_service
                 .handle_info_request(info_request)
                 .await
@@ -375,7 +501,7 @@ mod tests {
 
         let response = {
             let storage = (*storage).clone();
-            let info_service = Info::new(storage).unwrap();
+            let info_service = Info::new(storage, StateHistory::new(0)).unwrap();
             info_service
                 .handle_info_request(info_request)
                 .await
@@ -423,7 +549,7 @@ mod tests {
 
         let response = {
             let storage = (*storage).clone();
-            let info_service = Info::new(storage).unwrap();
+            let info_service = Info::new(storage, StateHistory::new(0)).unwrap();
             info_service
                 .handle_info_request(info_request)
                 .await
@@ -441,8 +567,147 @@ mod tests {
         assert_json_diff::assert_json_eq!(expected_fees(), actual_fees);
     }
 
+    async fn commit_nonce_at_height(
+        storage: &Storage,
+        state_history: &StateHistory,
+        address: &Address,
+        nonce: u32,
+    ) {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_account_nonce(address, nonce).unwrap();
+        state.put_block_height(u64::from(nonce)).unwrap();
+        let state = state_history
+            .record_block(state, u64::from(nonce))
+            .await
+            .unwrap();
+        storage.commit(state).await.unwrap();
+    }
+
+    async fn query_nonce(
+        info_service: &Info,
+        address: &Address,
+        height: u32,
+        prove: bool,
+    ) -> response::Query {
+        let info_request = InfoRequest::Query(request::Query {
+            path: format!("accounts/nonce/{address}"),
+            data: vec![].into(),
+            height: height.into(),
+            prove,
+        });
+        match info_service
+            .clone()
+            .handle_info_request(info_request)
+            .await
+            .unwrap()
+        {
+            InfoResponse::Query(query) => query,
+            other => panic!("expected InfoResponse::Query, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn query_is_answered_at_retained_height() {
+        use astria_core::generated::astria::protocol::accounts::v1::NonceResponse;
+
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let state_history = StateHistory::new(10);
+        let address = astria_address(&[1; 20]);
+        for nonce in 1..=3 {
+            commit_nonce_at_height(&storage, &state_history, &address, nonce).await;
+        }
+        let info_service = Info::new((*storage).clone(), state_history).unwrap();
+
+        for height in 1..=3 {
+            let query_response = query_nonce(&info_service, &address, height, false).await;
+            assert!(query_response.code.is_ok(), "{query_response:?}");
+            let nonce_response = NonceResponse::decode(query_response.value).unwrap();
+            assert_eq!(nonce_response.height, u64::from(height));
+            assert_eq!(nonce_response.nonce, height);
+        }
+    }
+
+    #[tokio::test]
+    async fn proof_at_reconstructed_height_fails() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let state_history = StateHistory::new(10);
+        let address = astria_address(&[1; 20]);
+        for nonce in 1..=2 {
+            commit_nonce_at_height(&storage, &state_history, &address, nonce).await;
+        }
+        let info_service = Info::new((*storage).clone(), state_history).unwrap();
+
+        let query_response = query_nonce(&info_service, &address, 1, true).await;
+        assert!(query_response.code.is_err(), "{query_response:?}");
+    }
+
+    #[tokio::test]
+    async fn query_at_pruned_height_fails() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let state_history = StateHistory::new(1);
+        let address = astria_address(&[1; 20]);
+        for nonce in 1..=3 {
+            commit_nonce_at_height(&storage, &state_history, &address, nonce).await;
+        }
+        let info_service = Info::new((*storage).clone(), state_history).unwrap();
+
+        let query_response = query_nonce(&info_service, &address, 1, false).await;
+        assert_eq!(
+            query_response.code,
+            Code::Err(AbciErrorCode::HEIGHT_PRUNED.value()),
+            "{query_response:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn query_at_future_height_fails() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let address = astria_address(&[1; 20]);
+        let state_history = StateHistory::new(10);
+        commit_nonce_at_height(&storage, &state_history, &address, 1).await;
+        let info_service = Info::new((*storage).clone(), state_history).unwrap();
+
+        let query_response = query_nonce(&info_service, &address, 2, false).await;
+        assert_eq!(
+            query_response.code,
+            Code::Err(AbciErrorCode::INVALID_PARAMETER.value()),
+            "{query_response:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn nonce_query_returns_proof_if_requested() {
+        use ibc_proto::ibc::core::commitment::v1::MerkleProof;
+
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let address = astria_address(&[1; 20]);
+        let state_history = StateHistory::new(0);
+        commit_nonce_at_height(&storage, &state_history, &address, 1).await;
+        let info_service = Info::new((*storage).clone(), state_history).unwrap();
+
+        let query_response = query_nonce(&info_service, &address, 0, false).await;
+        assert!(query_response.code.is_ok(), "{query_response:?}");
+        assert!(query_response.proof.is_none());
+
+        let query_response = query_nonce(&info_service, &address, 0, true).await;
+        assert!(query_response.code.is_ok(), "{query_response:?}");
+        let proof_ops = query_response.proof.unwrap().ops;
+        assert_eq!(proof_ops.len(), 1);
+        assert_eq!(proof_ops[0].field_type, "ics23:jmt");
+        assert_eq!(
+            proof_ops[0].key,
+            b"accounts/AQEBAQEBAQEBAQEBAQEBAQEBAQE=/nonce".to_vec()
+        );
+        let merkle_proof = MerkleProof::decode(&*proof_ops[0].data).unwrap();
+        assert_eq!(merkle_proof.proofs.len(), 1);
+    }
+
     fn expected_fees() -> serde_json::Value {
         serde_json::json!({
+              "bond": {
+                "base": 21,
+                "multiplier": 21
+              },
               "bridge_lock": {
                 "base": 1,
                 "multiplier": 1
@@ -471,6 +736,10 @@ mod tests {
                 "base": 5,
                 "multiplier": 5
               },
+              "governance_parameters_change": {
+                "base": 26,
+                "multiplier": 26
+              },
               "ibc_relay": {
                 "base": 7,
                 "multiplier": 7
@@ -483,6 +752,10 @@ mod tests {
                 "base": 9,
                 "multiplier": 9
               },
+              "ics20_rate_limit_change": {
+                "base": 19,
+                "multiplier": 19
+              },
               "ics20_withdrawal": {
                 "base": 10,
                 "multiplier": 10
@@ -495,14 +768,34 @@ mod tests {
                 "base": 16,
                 "multiplier": 16
               },
+              "proposal_submission": {
+                "base": 23,
+                "multiplier": 23
+              },
+              "proposal_vote": {
+                "base": 24,
+                "multiplier": 24
+              },
               "recover_ibc_client": {
                 "base": 0,
                 "multiplier": 0
               },
+              "register_multisig_account": {
+                "base": 17,
+                "multiplier": 17
+              },
               "rollup_data_submission": {
                 "base": 11,
                 "multiplier": 11
               },
+              "scheduled_transfer": {
+                "base": 18,
+                "multiplier": 18
+              },
+              "staking_parameters_change": {
+                "base": 25,
+                "multiplier": 25
+              },
               "sudo_address_change": {
                 "base": 12,
                 "multiplier": 12
@@ -511,9 +804,17 @@ mod tests {
                 "base": 13,
                 "multiplier": 13
               },
+              "unbond": {
+                "base": 22,
+                "multiplier": 22
+              },
               "validator_update": {
                 "base": 14,
                 "multiplier": 14
+              },
+              "vote_extension_participation_change": {
+                "base": 20,
+                "multiplier": 20
             }
         })
     }
@@ -573,5 +874,35 @@ mod tests {
         state
             .put_fees(FeeComponents::<MarketsChange>::new(16, 16))
             .unwrap();
+        state
+            .put_fees(FeeComponents::<RegisterMultisigAccount>::new(17, 17))
+            .unwrap();
+        state
+            .put_fees(FeeComponents::<ScheduledTransfer>::new(18, 18))
+            .unwrap();
+        state
+            .put_fees(FeeComponents::<Ics20RateLimitChange>::new(19, 19))
+            .unwrap();
+        state
+            .put_fees(FeeComponents::<VoteExtensionParticipationChange>::new(
+                20, 20,
+            ))
+            .unwrap();
+        state.put_fees(FeeComponents::<Bond>::new(21, 21)).unwrap();
+        state
+            .put_fees(FeeComponents::<Unbond>::new(22, 22))
+            .unwrap();
+        state
+            .put_fees(FeeComponents::<ProposalSubmission>::new(23, 23))
+            .unwrap();
+        state
+            .put_fees(FeeComponents::<ProposalVote>::new(24, 24))
+            .unwrap();
+        state
+            .put_fees(FeeComponents::<StakingParametersChange>::new(25, 25))
+            .unwrap();
+        state
+            .put_fees(FeeComponents::<GovernanceParametersChange>::new(26, 26))
+            .unwrap();
     }
 }
@@ -51,6 +51,7 @@ use crate::{
         AddressBytes as _,
         StateReadExt as _,
     },
+    checked_actions::CheckedActionFeeError,
     checked_transaction::{
         CheckedTransaction,
         CheckedTransactionInitialCheckError,
@@ -61,6 +62,7 @@ use crate::{
         InsertionStatus,
         Mempool as AppMempool,
         RemovalReason,
+        SponsoredFees,
         TransactionStatus,
     },
     metrics::Metrics,
@@ -219,7 +221,12 @@ pub(crate) async fn check_tx<S: StateRead>(
                 | CheckedTransactionInitialCheckError::ChainIdMismatch {
                     ..
                 }
-                | CheckedTransactionInitialCheckError::CheckedAction(_) => {
+                | CheckedTransactionInitialCheckError::CheckedAction(_)
+                | CheckedTransactionInitialCheckError::Unauthorized(_)
+                | CheckedTransactionInitialCheckError::FeePayer {
+                    ..
+                }
+                | CheckedTransactionInitialCheckError::FeePayerBeforeAspen => {
                     metrics.increment_check_tx_failed_action_checks();
                 }
                 CheckedTransactionInitialCheckError::InternalError {
@@ -304,6 +311,31 @@ async fn insert_into_mempool<S: StateRead>(
         finished_fetch_balances.saturating_duration_since(finished_fetch_tx_cost),
     );
 
+    // grab the fees charged to a separate fee payer, together with the fee payer's balances
+    let sponsored_fees = match tx.fee_payer_costs(state).await.map_err(|error| {
+        CheckTxOutcome::InternalError(
+            Report::new(error).wrap_err("failed to calculate fees charged to the fee payer"),
+        )
+    })? {
+        Some(fees) => {
+            let fee_payer = *tx.fee_payer_address_bytes();
+            let fee_payer_balances =
+                get_account_balances(&state, &fee_payer)
+                    .await
+                    .map_err(|error| {
+                        CheckTxOutcome::InternalError(error.wrap_err(format!(
+                            "failed to get balances for fee payer `{}` from storage",
+                            BASE64_STANDARD.encode(fee_payer)
+                        )))
+                    })?;
+            Some(SponsoredFees {
+                fees,
+                fee_payer_balances,
+            })
+        }
+        None => None,
+    };
+
     let actions_count = tx.checked_actions().len();
     let tx_length = tx.encoded_bytes().len();
 
@@ -313,6 +345,7 @@ async fn insert_into_mempool<S: StateRead>(
             current_account_nonce,
             &current_account_balances,
             transaction_costs,
+            sponsored_fees,
         )
         .await
         .map_err(CheckTxOutcome::FailedInsertion)?;
@@ -350,6 +383,7 @@ impl From<RemovalReason> for response::CheckTx {
             RemovalReason::FailedPrepareProposal(_) => AbciErrorCode::TRANSACTION_FAILED_EXECUTION,
             RemovalReason::IncludedInBlock(_) => AbciErrorCode::TRANSACTION_INCLUDED_IN_BLOCK,
             RemovalReason::InternalError => AbciErrorCode::INTERNAL_ERROR,
+            RemovalReason::Replaced(_) => AbciErrorCode::TRANSACTION_REPLACED,
         };
         error_response(code, log)
     }
@@ -375,6 +409,29 @@ impl From<CheckedTransactionInitialCheckError> for response::CheckTx {
             CheckedTransactionInitialCheckError::CheckedAction(_) => {
                 AbciErrorCode::TRANSACTION_FAILED_CHECK_TX
             }
+            CheckedTransactionInitialCheckError::Unauthorized(_) => {
+                AbciErrorCode::INSUFFICIENT_SIGNATURES
+            }
+            CheckedTransactionInitialCheckError::FeePayer {
+                source:
+                    CheckedActionFeeError::InsufficientBalanceToPayFee {
+                        ..
+                    },
+                ..
+            } => AbciErrorCode::INSUFFICIENT_FUNDS,
+            CheckedTransactionInitialCheckError::FeePayer {
+                source:
+                    CheckedActionFeeError::InternalError {
+                        ..
+                    },
+                ..
+            } => AbciErrorCode::INTERNAL_ERROR,
+            CheckedTransactionInitialCheckError::FeePayer {
+                ..
+            }
+            | CheckedTransactionInitialCheckError::FeePayerBeforeAspen => {
+                AbciErrorCode::TRANSACTION_FAILED_CHECK_TX
+            }
             CheckedTransactionInitialCheckError::InternalError {
                 ..
             } => AbciErrorCode::INTERNAL_ERROR,
@@ -415,6 +472,8 @@ impl From<InsertionError> for response::CheckTx {
             }
             InsertionError::AccountSizeLimit => AbciErrorCode::ACCOUNT_SIZE_LIMIT,
             InsertionError::ParkedSizeLimit => AbciErrorCode::PARKED_FULL,
+            InsertionError::ReplacementFeeTooLow => AbciErrorCode::REPLACEMENT_FEE_TOO_LOW,
+            InsertionError::ReplacementBalanceTooLow => AbciErrorCode::INSUFFICIENT_FUNDS,
         };
         error_response(code, log)
     }
@@ -1,32 +1,232 @@
 use std::{
     pin::Pin,
+    sync::Arc,
     task::{
         Context,
         Poll,
     },
 };
 
+use cnidarium::Storage;
 use futures::{
     Future,
     FutureExt,
 };
 use penumbra_tower_trace::v038::RequestExt as _;
-use tendermint::v0_38::abci::{
-    response::{
-        ApplySnapshotChunk,
-        ListSnapshots,
-        LoadSnapshotChunk,
-        OfferSnapshot,
+use tendermint::{
+    abci::response::ApplySnapshotChunkResult,
+    v0_38::abci::{
+        request,
+        response::{
+            ApplySnapshotChunk,
+            ListSnapshots,
+            LoadSnapshotChunk,
+            OfferSnapshot,
+        },
+        SnapshotRequest,
+        SnapshotResponse,
     },
-    SnapshotRequest,
-    SnapshotResponse,
 };
+use tokio::sync::Mutex;
 use tower::Service;
 use tower_abci::BoxError;
-use tracing::Instrument as _;
+use tracing::{
+    info,
+    instrument,
+    warn,
+    Instrument as _,
+};
+
+use crate::state_sync::{
+    ApplyChunkError,
+    ApplyChunkOutcome,
+    OfferError,
+    Restorer,
+    SnapshotStore,
+    SNAPSHOT_FORMAT,
+};
+
+#[derive(Clone)]
+pub(crate) struct Snapshot {
+    storage: Storage,
+    store: SnapshotStore,
+    restorer: Arc<Mutex<Option<Restorer>>>,
+}
+
+impl Snapshot {
+    pub(crate) fn new(storage: Storage, store: SnapshotStore) -> Self {
+        Self {
+            storage,
+            store,
+            restorer: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn handle_snapshot_request(self, request: SnapshotRequest) -> SnapshotResponse {
+        match request {
+            SnapshotRequest::ListSnapshots => {
+                SnapshotResponse::ListSnapshots(self.list_snapshots().await)
+            }
+            SnapshotRequest::OfferSnapshot(offer_snapshot) => {
+                SnapshotResponse::OfferSnapshot(self.offer_snapshot(offer_snapshot).await)
+            }
+            SnapshotRequest::LoadSnapshotChunk(load_snapshot_chunk) => {
+                SnapshotResponse::LoadSnapshotChunk(
+                    self.load_snapshot_chunk(load_snapshot_chunk).await,
+                )
+            }
+            SnapshotRequest::ApplySnapshotChunk(apply_snapshot_chunk) => {
+                SnapshotResponse::ApplySnapshotChunk(
+                    self.apply_snapshot_chunk(apply_snapshot_chunk).await,
+                )
+            }
+        }
+    }
+
+    async fn list_snapshots(&self) -> ListSnapshots {
+        match self.store.list().await {
+            Ok(snapshots) => ListSnapshots {
+                snapshots,
+            },
+            Err(error) => {
+                warn!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                    "failed listing snapshots; responding with no snapshots"
+                );
+                ListSnapshots::default()
+            }
+        }
+    }
+
+    async fn offer_snapshot(&self, offer_snapshot: request::OfferSnapshot) -> OfferSnapshot {
+        let height = offer_snapshot.snapshot.height.value();
+        match Restorer::offer(
+            self.storage.clone(),
+            &offer_snapshot.snapshot,
+            &offer_snapshot.app_hash,
+        ) {
+            Ok(restorer) => {
+                info!(height, "accepted snapshot offer");
+                *self.restorer.lock().await = Some(restorer);
+                OfferSnapshot::Accept
+            }
+            Err(error) => {
+                warn!(
+                    error = &error as &dyn std::error::Error,
+                    height, "rejecting snapshot offer"
+                );
+                match error {
+                    OfferError::UnsupportedFormat(_) => OfferSnapshot::RejectFormat,
+                    OfferError::StorageNotEmpty(_) => OfferSnapshot::Abort,
+                    OfferError::InvalidMetadata(_)
+                    | OfferError::SnapshotHashMismatch
+                    | OfferError::ChunkCountMismatch {
+                        ..
+                    }
+                    | OfferError::AppHashMismatch => OfferSnapshot::Reject,
+                }
+            }
+        }
+    }
 
-#[derive(Clone, Default)]
-pub(crate) struct Snapshot;
+    async fn load_snapshot_chunk(
+        &self,
+        load_snapshot_chunk: request::LoadSnapshotChunk,
+    ) -> LoadSnapshotChunk {
+        if load_snapshot_chunk.format != SNAPSHOT_FORMAT {
+            return LoadSnapshotChunk::default();
+        }
+        let height = load_snapshot_chunk.height.value();
+        let index = load_snapshot_chunk.chunk;
+        match self.store.load_chunk(height, index).await {
+            Ok(Some(chunk)) => LoadSnapshotChunk {
+                chunk,
+            },
+            Ok(None) => {
+                warn!(height, index, "requested snapshot chunk does not exist");
+                LoadSnapshotChunk::default()
+            }
+            Err(error) => {
+                warn!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                    height, index, "failed loading snapshot chunk"
+                );
+                LoadSnapshotChunk::default()
+            }
+        }
+    }
+
+    async fn apply_snapshot_chunk(
+        &self,
+        apply_snapshot_chunk: request::ApplySnapshotChunk,
+    ) -> ApplySnapshotChunk {
+        let request::ApplySnapshotChunk {
+            index,
+            chunk,
+            sender,
+        } = apply_snapshot_chunk;
+        let mut maybe_restorer = self.restorer.lock().await;
+        let Some(restorer) = maybe_restorer.as_mut() else {
+            warn!(
+                index,
+                "received snapshot chunk without an accepted snapshot offer"
+            );
+            return apply_snapshot_chunk_response(ApplySnapshotChunkResult::Abort);
+        };
+        let error = match restorer.apply_chunk(index, &chunk).await {
+            Ok(ApplyChunkOutcome::Applied) => {
+                return apply_snapshot_chunk_response(ApplySnapshotChunkResult::Accept);
+            }
+            Ok(ApplyChunkOutcome::Completed) => {
+                *maybe_restorer = None;
+                return apply_snapshot_chunk_response(ApplySnapshotChunkResult::Accept);
+            }
+            Err(error) => error,
+        };
+        warn!(
+            error = &error as &dyn std::error::Error,
+            index, %sender, "failed applying snapshot chunk"
+        );
+        match error {
+            ApplyChunkError::UnexpectedIndex {
+                expected, ..
+            } => ApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Retry,
+                refetch_chunks: vec![expected],
+                reject_senders: vec![],
+            },
+            ApplyChunkError::ChunkHashMismatch(index) => ApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Retry,
+                refetch_chunks: vec![index],
+                reject_senders: vec![sender],
+            },
+            ApplyChunkError::Decode {
+                ..
+            }
+            | ApplyChunkError::AppHashMismatch {
+                ..
+            } => {
+                *maybe_restorer = None;
+                apply_snapshot_chunk_response(ApplySnapshotChunkResult::RejectSnapshot)
+            }
+            ApplyChunkError::Storage {
+                ..
+            } => {
+                *maybe_restorer = None;
+                apply_snapshot_chunk_response(ApplySnapshotChunkResult::Abort)
+            }
+        }
+    }
+}
+
+fn apply_snapshot_chunk_response(result: ApplySnapshotChunkResult) -> ApplySnapshotChunk {
+    ApplySnapshotChunk {
+        result,
+        refetch_chunks: vec![],
+        reject_senders: vec![],
+    }
+}
 
 impl Service<SnapshotRequest> for Snapshot {
     type Error = BoxError;
@@ -41,26 +241,10 @@ impl Service<SnapshotRequest> for Snapshot {
     fn call(&mut self, req: SnapshotRequest) -> Self::Future {
         let span = req.create_span();
 
-        async move {
-            Ok(match req {
-                SnapshotRequest::ListSnapshots => {
-                    SnapshotResponse::ListSnapshots(ListSnapshots::default())
-                }
-
-                SnapshotRequest::OfferSnapshot(_) => {
-                    SnapshotResponse::OfferSnapshot(OfferSnapshot::default())
-                }
-
-                SnapshotRequest::LoadSnapshotChunk(_) => {
-                    SnapshotResponse::LoadSnapshotChunk(LoadSnapshotChunk::default())
-                }
-
-                SnapshotRequest::ApplySnapshotChunk(_) => {
-                    SnapshotResponse::ApplySnapshotChunk(ApplySnapshotChunk::default())
-                }
-            })
-        }
-        .instrument(span)
-        .boxed()
+        self.clone()
+            .handle_snapshot_request(req)
+            .map(Ok)
+            .instrument(span)
+            .boxed()
     }
 }
@@ -0,0 +1,374 @@
+use std::sync::Arc;
+
+use astria_core::{
+    crypto::VerificationKey,
+    protocol::{
+        genesis::v1::{
+            GenesisAppState,
+            StakingParameters,
+        },
+        transaction::v1::action::{
+            ValidatorName,
+            ValidatorUpdate,
+        },
+    },
+};
+use astria_eyre::eyre::{
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use tendermint::abci::request::{
+    BeginBlock,
+    EndBlock,
+};
+use tracing::{
+    debug,
+    instrument,
+    warn,
+    Level,
+};
+
+use super::{
+    StateReadExt as _,
+    StateWriteExt,
+};
+use crate::{
+    accounts::AddressBytes as _,
+    authority::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    component::Component,
+};
+
+#[derive(Default)]
+pub(crate) struct StakingComponent;
+
+#[async_trait::async_trait]
+impl Component for StakingComponent {
+    type AppState = GenesisAppState;
+
+    #[instrument(name = "StakingComponent::init_chain", skip_all, err)]
+    async fn init_chain<S: StateWriteExt>(mut state: S, app_state: &Self::AppState) -> Result<()> {
+        if let Some(parameters) = app_state.staking() {
+            state
+                .put_staking_parameters(*parameters)
+                .wrap_err("failed to write staking parameters to state")?;
+        }
+        Ok(())
+    }
+
+    #[instrument(name = "StakingComponent::begin_block", skip_all)]
+    async fn begin_block<S: StateWriteExt + 'static>(
+        _state: &mut Arc<S>,
+        _begin_block: &BeginBlock,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    #[instrument(name = "StakingComponent::end_block", skip_all, err(level = Level::WARN))]
+    async fn end_block<S: StateWriteExt + 'static>(
+        state: &mut Arc<S>,
+        _end_block: &EndBlock,
+    ) -> Result<()> {
+        let state = Arc::get_mut(state)
+            .ok_or_eyre("must only have one reference to the state; this is a bug")?;
+
+        let Some(parameters) = state
+            .get_staking_parameters()
+            .await
+            .wrap_err("failed to read staking parameters from state")?
+        else {
+            return Ok(());
+        };
+        for validator in state.get_block_stake_changes() {
+            update_validator_power(&mut *state, validator, &parameters)
+                .await
+                .wrap_err("failed to update validator power from stake")?;
+        }
+        Ok(())
+    }
+}
+
+/// Sets the power of `validator` to its current stake divided by the stake per unit of power,
+/// adding the change to the block's validator updates.
+///
+/// Any penalty the validator received for missing vote extensions is applied to the power derived
+/// from its stake, so changing its stake does not lift the penalty.
+///
+/// A validator whose power drops to zero is removed from the validator set, unless it is the only
+/// validator.
+async fn update_validator_power<S: StateWriteExt>(
+    mut state: S,
+    validator: VerificationKey,
+    parameters: &StakingParameters,
+) -> Result<()> {
+    let stake = state
+        .get_validator_stake(&validator)
+        .await
+        .wrap_err("failed to read validator stake from state")?
+        .map_or(0, |stake| stake.stake);
+    let remaining_power_percent = state
+        .get_validator_power_penalty(&validator)
+        .await
+        .wrap_err("failed to read validator power penalty from state")?
+        .unwrap_or(100);
+    let power = stake
+        .checked_div(parameters.stake_per_power())
+        .ok_or_eyre("stake per power must not be zero")?
+        .saturating_mul(u128::from(remaining_power_percent))
+        / 100;
+    let power = u32::try_from(power).unwrap_or(u32::MAX);
+
+    let validator_count = state
+        .get_validator_count()
+        .await
+        .wrap_err("failed to read validator count from state")?;
+    let update = match state
+        .get_validator(&validator)
+        .await
+        .wrap_err("failed to read validator info from state")?
+    {
+        None if power == 0 => return Ok(()),
+        None => {
+            state
+                .put_validator_count(validator_count.saturating_add(1))
+                .wrap_err("failed to write validator count to state")?;
+            ValidatorUpdate {
+                name: ValidatorName::empty(),
+                power,
+                verification_key: validator,
+            }
+        }
+        Some(existing) if existing.power == power => return Ok(()),
+        Some(existing) if power == 0 => {
+            if validator_count <= 1 {
+                warn!(
+                    address = %validator.display_address(),
+                    "not removing the only validator despite its stake falling below one unit of \
+                     power",
+                );
+                return Ok(());
+            }
+            state.remove_validator(&validator).await;
+            state
+                .put_validator_count(validator_count.saturating_sub(1))
+                .wrap_err("failed to write validator count to state")?;
+            ValidatorUpdate {
+                power,
+                ..existing
+            }
+        }
+        Some(existing) => ValidatorUpdate {
+            power,
+            ..existing
+        },
+    };
+    if update.power > 0 {
+        state
+            .put_validator(&update)
+            .wrap_err("failed to write validator info to state")?;
+    }
+    debug!(
+        address = %update.verification_key.display_address(),
+        stake,
+        power,
+        "updated validator power from stake",
+    );
+
+    // Add validator update in nonverifiable state to be returned from end_block.
+    let mut validator_updates = state
+        .get_block_validator_updates()
+        .await
+        .wrap_err("failed to read validator updates from state")?;
+    validator_updates.insert(update);
+    state
+        .put_block_validator_updates(validator_updates)
+        .wrap_err("failed to write validator updates to state")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        crypto::SigningKey,
+        generated::astria::protocol::genesis::v1 as raw,
+        Protobuf as _,
+    };
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::staking::ValidatorStake;
+
+    fn verification_key(seed: u8) -> VerificationKey {
+        SigningKey::from([seed; 32]).verification_key()
+    }
+
+    fn parameters() -> StakingParameters {
+        StakingParameters::try_from_raw(raw::StakingParameters {
+            unbonding_period_blocks: 10,
+            stake_per_power: Some(100_u128.into()),
+        })
+        .unwrap()
+    }
+
+    fn end_block() -> EndBlock {
+        EndBlock {
+            height: 1,
+        }
+    }
+
+    async fn state_with_validators(validators: &[(u8, u32)]) -> StateDelta<cnidarium::Snapshot> {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_staking_parameters(parameters()).unwrap();
+        for (seed, power) in validators {
+            state
+                .put_validator(&ValidatorUpdate {
+                    name: ValidatorName::empty(),
+                    power: *power,
+                    verification_key: verification_key(*seed),
+                })
+                .unwrap();
+        }
+        state.put_validator_count(validators.len() as u64).unwrap();
+        state
+    }
+
+    fn change_stake<S: StateWriteExt>(mut state: S, seed: u8, stake: u128) {
+        state
+            .put_validator_stake(&ValidatorStake {
+                validator: verification_key(seed),
+                stake,
+            })
+            .unwrap();
+        state.record_stake_change(verification_key(seed));
+    }
+
+    #[tokio::test]
+    async fn end_block_does_nothing_if_staking_disabled() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_validator_count(0).unwrap();
+        change_stake(&mut state, 1, 1_000);
+
+        let mut state = Arc::new(state);
+        StakingComponent::end_block(&mut state, &end_block())
+            .await
+            .unwrap();
+
+        assert!(state
+            .get_validator(&verification_key(1))
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(state.get_block_validator_updates().await.unwrap().len(), 0);
+    }
+
+    #[tokio::test]
+    async fn end_block_adds_new_validator_with_power_from_stake() {
+        let mut state = state_with_validators(&[(1, 10)]).await;
+        change_stake(&mut state, 2, 1_050);
+
+        let mut state = Arc::new(state);
+        StakingComponent::end_block(&mut state, &end_block())
+            .await
+            .unwrap();
+
+        let validator = state
+            .get_validator(&verification_key(2))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(validator.power, 10);
+        assert_eq!(state.get_validator_count().await.unwrap(), 2);
+        let updates = state.get_block_validator_updates().await.unwrap();
+        assert_eq!(updates.get(&verification_key(2)).unwrap().power, 10);
+    }
+
+    #[tokio::test]
+    async fn end_block_updates_power_of_existing_validator() {
+        let mut state = state_with_validators(&[(1, 10), (2, 10)]).await;
+        change_stake(&mut state, 1, 5_000);
+
+        let mut state = Arc::new(state);
+        StakingComponent::end_block(&mut state, &end_block())
+            .await
+            .unwrap();
+
+        let validator = state
+            .get_validator(&verification_key(1))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(validator.power, 50);
+        assert_eq!(state.get_validator_count().await.unwrap(), 2);
+        let updates = state.get_block_validator_updates().await.unwrap();
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates.get(&verification_key(1)).unwrap().power, 50);
+    }
+
+    #[tokio::test]
+    async fn end_block_removes_validator_with_stake_below_one_unit_of_power() {
+        let mut state = state_with_validators(&[(1, 10), (2, 10)]).await;
+        change_stake(&mut state, 1, 99);
+
+        let mut state = Arc::new(state);
+        StakingComponent::end_block(&mut state, &end_block())
+            .await
+            .unwrap();
+
+        assert!(state
+            .get_validator(&verification_key(1))
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(state.get_validator_count().await.unwrap(), 1);
+        let updates = state.get_block_validator_updates().await.unwrap();
+        assert_eq!(updates.get(&verification_key(1)).unwrap().power, 0);
+    }
+
+    #[tokio::test]
+    async fn end_block_keeps_vote_extension_penalty_of_validator() {
+        let mut state = state_with_validators(&[(1, 10), (2, 10)]).await;
+        state
+            .put_validator_power_penalty(&verification_key(1), 50)
+            .unwrap();
+        change_stake(&mut state, 1, 5_000);
+
+        let mut state = Arc::new(state);
+        StakingComponent::end_block(&mut state, &end_block())
+            .await
+            .unwrap();
+
+        let validator = state
+            .get_validator(&verification_key(1))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(validator.power, 25);
+        let updates = state.get_block_validator_updates().await.unwrap();
+        assert_eq!(updates.get(&verification_key(1)).unwrap().power, 25);
+    }
+
+    #[tokio::test]
+    async fn end_block_does_not_remove_only_validator() {
+        let mut state = state_with_validators(&[(1, 10)]).await;
+        change_stake(&mut state, 1, 0);
+
+        let mut state = Arc::new(state);
+        StakingComponent::end_block(&mut state, &end_block())
+            .await
+            .unwrap();
+
+        let validator = state
+            .get_validator(&verification_key(1))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(validator.power, 10);
+        assert_eq!(state.get_validator_count().await.unwrap(), 1);
+        assert_eq!(state.get_block_validator_updates().await.unwrap().len(), 0);
+    }
+}
@@ -0,0 +1,35 @@
+//! Native staking of the sequencer's native asset.
+//!
+//! Staking is enabled by setting [`StakingParameters`] in the genesis app state, or by the sudo
+//! address submitting a `StakingParametersChange` action, which can also replace the parameters
+//! later. Staking cannot be disabled once enabled. While enabled,
+//! accounts bond the native asset to validators with `Bond` actions and withdraw it with `Unbond`
+//! actions. Unbonded stake is held in escrow for the unbonding period before being returned to
+//! the delegator.
+//!
+//! At the end of each block, the power of every validator whose stake changed is recomputed as its
+//! total stake divided by `stake_per_power`, and the resulting validator updates are returned to
+//! CometBFT. A change of `stake_per_power` recomputes the power of every staked validator. Genesis
+//! validators keep their configured power until their stake first changes, and until then the sudo
+//! address can only reduce their power or remove them with `ValidatorUpdate` actions.
+//! Penalties for missing price vote extensions outlast stake changes: the recomputed power is
+//! scaled by the percentage of power the validator retains after its penalties.
+//!
+//! [`StakingParameters`]: astria_core::protocol::genesis::v1::StakingParameters
+pub(crate) mod component;
+pub(crate) mod query;
+mod state_ext;
+pub(crate) mod storage;
+
+use astria_core::crypto::VerificationKey;
+pub(crate) use state_ext::{
+    StateReadExt,
+    StateWriteExt,
+};
+
+/// The total native asset bonded to a validator by all of its delegators.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ValidatorStake {
+    pub(crate) validator: VerificationKey,
+    pub(crate) stake: u128,
+}
@@ -0,0 +1,201 @@
+use astria_core::{
+    crypto::VerificationKey,
+    primitive::v1::Address,
+    protocol::{
+        abci::AbciErrorCode,
+        staking::v1::{
+            DelegationsResponse,
+            ValidatorStakeResponse,
+        },
+    },
+};
+use astria_eyre::eyre::{
+    Result,
+    WrapErr as _,
+};
+use prost::Message as _;
+use tendermint::{
+    abci::{
+        request,
+        response,
+        Code,
+    },
+    block::Height,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use crate::{
+    app::StateReadExt as _,
+    authority::StateReadExt as _,
+    staking::StateReadExt as _,
+    storage::QueryState,
+};
+
+/// Returns all delegations of the account given in `params`.
+pub(crate) async fn delegations_request(
+    snapshot: QueryState,
+    request: request::Query,
+    params: Vec<(String, String)>,
+) -> response::Query {
+    let (address, height) = match preprocess_request(&snapshot, &params, "account").await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let delegations = match snapshot.get_delegations(&address).await {
+        Ok(delegations) => delegations,
+        Err(err) => {
+            return response::Query {
+                code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+                info: AbciErrorCode::INTERNAL_ERROR.info(),
+                log: format!("failed getting delegations for provided address: {err:#}"),
+                height,
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let payload = DelegationsResponse {
+        height: height.value(),
+        delegations,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: 0.into(),
+        key: request.path.into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
+/// Returns the stake bonded to the validator given in `params`, and its current power.
+pub(crate) async fn validator_stake_request(
+    snapshot: QueryState,
+    request: request::Query,
+    params: Vec<(String, String)>,
+) -> response::Query {
+    let (address, height) = match preprocess_request(&snapshot, &params, "address").await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let (validator, stake, power) = match get_validator_stake(&snapshot, &address).await {
+        Ok(Some(found)) => found,
+        Ok(None) => {
+            return response::Query {
+                code: Code::Err(AbciErrorCode::VALUE_NOT_FOUND.value()),
+                info: AbciErrorCode::VALUE_NOT_FOUND.info(),
+                log: "provided address is neither a validator nor has stake bonded to it".into(),
+                height,
+                ..response::Query::default()
+            };
+        }
+        Err(err) => {
+            return response::Query {
+                code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+                info: AbciErrorCode::INTERNAL_ERROR.info(),
+                log: format!("failed getting validator stake for provided address: {err:#}"),
+                height,
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let payload = ValidatorStakeResponse {
+        height: height.value(),
+        validator,
+        stake,
+        power,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: 0.into(),
+        key: request.path.into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
+/// Returns the verification key, bonded stake and current power of the validator at `address`, or
+/// `None` if it is neither a validator nor has stake bonded to it.
+#[instrument(skip_all, fields(%address), err(level = Level::DEBUG))]
+async fn get_validator_stake(
+    snapshot: &QueryState,
+    address: &Address,
+) -> Result<Option<(VerificationKey, u128, u32)>> {
+    let stake = snapshot
+        .get_validator_stake(address)
+        .await
+        .wrap_err("failed to read validator stake from storage")?;
+    let validator = snapshot
+        .get_validator(address)
+        .await
+        .wrap_err("failed to read validator info from storage")?;
+    let power = validator.as_ref().map_or(0, |validator| validator.power);
+    let found = match (stake, validator) {
+        (Some(stake), _) => Some((stake.validator, stake.stake, power)),
+        (None, Some(validator)) => Some((validator.verification_key, 0, power)),
+        (None, None) => None,
+    };
+    Ok(found)
+}
+
+#[instrument(skip_all, err(level = Level::DEBUG))]
+async fn get_height(snapshot: &QueryState) -> Result<Height> {
+    let height: Height = snapshot
+        .get_block_height()
+        .await
+        .wrap_err("failed to get block height from snapshot")?
+        .try_into()
+        .wrap_err("internal u64 block height does not fit into tendermint i64 `Height`")?;
+    Ok(height)
+}
+
+#[instrument(skip_all)]
+async fn preprocess_request(
+    snapshot: &QueryState,
+    params: &[(String, String)],
+    param_name: &str,
+) -> Result<(Address, Height), response::Query> {
+    let Some(address) = params
+        .iter()
+        .find_map(|(k, v)| (k == param_name).then_some(v))
+    else {
+        return Err(response::Query {
+            code: Code::Err(AbciErrorCode::INVALID_PARAMETER.value()),
+            info: AbciErrorCode::INVALID_PARAMETER.info(),
+            log: "path did not contain path parameter".into(),
+            ..response::Query::default()
+        });
+    };
+    let address = address
+        .parse()
+        .wrap_err("failed to parse argument as address")
+        .map_err(|err| response::Query {
+            code: Code::Err(AbciErrorCode::INVALID_PARAMETER.value()),
+            info: AbciErrorCode::INVALID_PARAMETER.info(),
+            log: format!("address could not be constructed from provided parameter: {err:#}"),
+            ..response::Query::default()
+        })?;
+    let height = match get_height(snapshot).await {
+        Ok(height) => height,
+        Err(err) => {
+            return Err(response::Query {
+                code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+                info: AbciErrorCode::INTERNAL_ERROR.info(),
+                log: format!("failed to query snapshot for height: {err:#}"),
+                ..response::Query::default()
+            });
+        }
+    };
+    Ok((address, height))
+}
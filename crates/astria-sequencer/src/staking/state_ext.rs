@@ -0,0 +1,382 @@
+use std::collections::BTreeSet;
+
+use astria_core::{
+    crypto::VerificationKey,
+    protocol::{
+        genesis::v1::StakingParameters,
+        staking::v1::Delegation,
+    },
+};
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{
+        Result,
+        WrapErr as _,
+    },
+};
+use async_trait::async_trait;
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use futures::TryStreamExt as _;
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    storage::{
+        self,
+        keys,
+    },
+    ValidatorStake,
+};
+use crate::{
+    accounts::AddressBytes,
+    storage::StoredValue,
+};
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns the staking parameters, or `None` if staking is not enabled.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    async fn get_staking_parameters(&self) -> Result<Option<StakingParameters>> {
+        let Some(bytes) = self
+            .get_raw(keys::PARAMETERS)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw staking parameters from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::StakingParameters::try_from(value).map(StakingParameters::from)
+            })
+            .wrap_err("invalid staking parameters bytes")
+            .map(Some)
+    }
+
+    /// Returns the amount `delegator` has bonded to `validator`, or zero if it has none.
+    #[instrument(skip_all, fields(delegator = %delegator.display_address()), err(level = Level::WARN))]
+    async fn get_delegated_amount<TDelegator: AddressBytes, TValidator: AddressBytes>(
+        &self,
+        delegator: &TDelegator,
+        validator: &TValidator,
+    ) -> Result<u128> {
+        let Some(bytes) = self
+            .get_raw(&keys::delegation(delegator, validator))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw delegation from state")?
+        else {
+            return Ok(0);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::Delegation::try_from(value).map(Delegation::from))
+            .wrap_err("invalid delegation bytes")
+            .map(|delegation| delegation.amount)
+    }
+
+    /// Returns all delegations of `delegator`, ordered by validator address.
+    #[instrument(skip_all, fields(delegator = %delegator.display_address()), err(level = Level::WARN))]
+    async fn get_delegations<T: AddressBytes>(&self, delegator: &T) -> Result<Vec<Delegation>> {
+        let mut stream = std::pin::pin!(self.prefix_raw(&keys::delegations_prefix(delegator)));
+        let mut delegations = vec![];
+        while let Some((_, bytes)) = stream
+            .try_next()
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading delegations from state")?
+        {
+            let delegation = StoredValue::deserialize(&bytes)
+                .and_then(|value| storage::Delegation::try_from(value).map(Delegation::from))
+                .wrap_err("invalid delegation bytes")?;
+            delegations.push(delegation);
+        }
+        Ok(delegations)
+    }
+
+    #[instrument(skip_all, fields(validator = %validator.display_address()), err(level = Level::WARN))]
+    async fn get_validator_stake<T: AddressBytes>(
+        &self,
+        validator: &T,
+    ) -> Result<Option<ValidatorStake>> {
+        let Some(bytes) = self
+            .get_raw(&keys::validator_stake(validator))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw validator stake from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::ValidatorStake::try_from(value).map(ValidatorStake::from))
+            .wrap_err("invalid validator stake bytes")
+            .map(Some)
+    }
+
+    /// Returns the stakes of all validators with non-zero stake, ordered by validator address.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    async fn get_validator_stakes(&self) -> Result<Vec<ValidatorStake>> {
+        let mut stream = std::pin::pin!(self.prefix_raw(keys::VALIDATOR_STAKE_PREFIX));
+        let mut stakes = vec![];
+        while let Some((_, bytes)) = stream
+            .try_next()
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading validator stakes from state")?
+        {
+            let stake = StoredValue::deserialize(&bytes)
+                .and_then(|value| {
+                    storage::ValidatorStake::try_from(value).map(ValidatorStake::from)
+                })
+                .wrap_err("invalid validator stake bytes")?;
+            stakes.push(stake);
+        }
+        Ok(stakes)
+    }
+
+    /// Returns the validators whose stake changed in the current block.
+    #[instrument(skip_all)]
+    fn get_block_stake_changes(&self) -> BTreeSet<VerificationKey> {
+        self.object_get(keys::BLOCK_STAKE_CHANGES)
+            .unwrap_or_default()
+    }
+}
+
+impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip_all)]
+    fn put_staking_parameters(&mut self, parameters: StakingParameters) -> Result<()> {
+        let bytes = StoredValue::from(storage::StakingParameters::from(parameters))
+            .serialize()
+            .wrap_err("failed to serialize staking parameters")?;
+        self.put_raw(keys::PARAMETERS.to_string(), bytes);
+        Ok(())
+    }
+
+    /// Writes the delegation of `delegator`, deleting it if its amount is zero.
+    #[instrument(skip_all, fields(delegator = %delegator.display_address()))]
+    fn put_delegation<T: AddressBytes>(
+        &mut self,
+        delegator: &T,
+        delegation: &Delegation,
+    ) -> Result<()> {
+        let key = keys::delegation(delegator, &delegation.validator);
+        if delegation.amount == 0 {
+            self.delete(key);
+            return Ok(());
+        }
+        let bytes = StoredValue::from(storage::Delegation::from(delegation))
+            .serialize()
+            .wrap_err("failed to serialize delegation")?;
+        self.put_raw(key, bytes);
+        Ok(())
+    }
+
+    /// Writes the stake of a validator, deleting it if the stake is zero.
+    #[instrument(skip_all, fields(validator = %stake.validator.display_address()))]
+    fn put_validator_stake(&mut self, stake: &ValidatorStake) -> Result<()> {
+        let key = keys::validator_stake(&stake.validator);
+        if stake.stake == 0 {
+            self.delete(key);
+            return Ok(());
+        }
+        let bytes = StoredValue::from(storage::ValidatorStake::from(stake))
+            .serialize()
+            .wrap_err("failed to serialize validator stake")?;
+        self.put_raw(key, bytes);
+        Ok(())
+    }
+
+    /// Records that the stake of `validator` changed in the current block, so that its power is
+    /// recomputed at the end of the block.
+    #[instrument(skip_all)]
+    fn record_stake_change(&mut self, validator: VerificationKey) {
+        let mut changes = self.get_block_stake_changes();
+        changes.insert(validator);
+        self.object_put(keys::BLOCK_STAKE_CHANGES, changes);
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        crypto::SigningKey,
+        generated::astria::protocol::genesis::v1 as raw,
+        Protobuf as _,
+    };
+    use cnidarium::StateDelta;
+
+    use super::*;
+
+    fn verification_key(seed: u8) -> VerificationKey {
+        SigningKey::from([seed; 32]).verification_key()
+    }
+
+    #[tokio::test]
+    async fn staking_parameters_round_trip() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert_eq!(state.get_staking_parameters().await.unwrap(), None);
+
+        let parameters = StakingParameters::try_from_raw(raw::StakingParameters {
+            unbonding_period_blocks: 10,
+            stake_per_power: Some(100_u128.into()),
+        })
+        .unwrap();
+        state.put_staking_parameters(parameters).unwrap();
+        assert_eq!(
+            state.get_staking_parameters().await.unwrap(),
+            Some(parameters)
+        );
+    }
+
+    #[tokio::test]
+    async fn delegations_round_trip() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let delegator = [1; 20];
+        let first = Delegation {
+            validator: verification_key(2),
+            amount: 100,
+        };
+        let second = Delegation {
+            validator: verification_key(3),
+            amount: 200,
+        };
+        state.put_delegation(&delegator, &first).unwrap();
+        state.put_delegation(&delegator, &second).unwrap();
+        state
+            .put_delegation(
+                &[4; 20],
+                &Delegation {
+                    validator: verification_key(2),
+                    amount: 300,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            state
+                .get_delegated_amount(&delegator, &first.validator)
+                .await
+                .unwrap(),
+            100
+        );
+        let mut expected = vec![first.clone(), second.clone()];
+        expected.sort_by_key(|delegation| *delegation.validator.address_bytes());
+        assert_eq!(state.get_delegations(&delegator).await.unwrap(), expected);
+
+        // A zero amount deletes the delegation.
+        state
+            .put_delegation(
+                &delegator,
+                &Delegation {
+                    amount: 0,
+                    ..first.clone()
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            state
+                .get_delegated_amount(&delegator, &first.validator)
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            state.get_delegations(&delegator).await.unwrap(),
+            vec![second]
+        );
+    }
+
+    #[tokio::test]
+    async fn validator_stake_round_trip() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let stake = ValidatorStake {
+            validator: verification_key(1),
+            stake: 1000,
+        };
+        assert_eq!(
+            state.get_validator_stake(&stake.validator).await.unwrap(),
+            None
+        );
+        state.put_validator_stake(&stake).unwrap();
+        assert_eq!(
+            state.get_validator_stake(&stake.validator).await.unwrap(),
+            Some(stake.clone())
+        );
+
+        state
+            .put_validator_stake(&ValidatorStake {
+                stake: 0,
+                ..stake.clone()
+            })
+            .unwrap();
+        assert_eq!(
+            state.get_validator_stake(&stake.validator).await.unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn validator_stakes_are_listed() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert!(state.get_validator_stakes().await.unwrap().is_empty());
+
+        let first = ValidatorStake {
+            validator: verification_key(1),
+            stake: 1000,
+        };
+        let second = ValidatorStake {
+            validator: verification_key(2),
+            stake: 2000,
+        };
+        state.put_validator_stake(&first).unwrap();
+        state.put_validator_stake(&second).unwrap();
+        let mut expected = vec![first.clone(), second.clone()];
+        expected.sort_by_key(|stake| *stake.validator.address_bytes());
+        assert_eq!(state.get_validator_stakes().await.unwrap(), expected);
+
+        state
+            .put_validator_stake(&ValidatorStake {
+                stake: 0,
+                ..first
+            })
+            .unwrap();
+        assert_eq!(state.get_validator_stakes().await.unwrap(), vec![second]);
+    }
+
+    #[tokio::test]
+    async fn block_stake_changes_are_deduplicated() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert!(state.get_block_stake_changes().is_empty());
+        state.record_stake_change(verification_key(1));
+        state.record_stake_change(verification_key(2));
+        state.record_stake_change(verification_key(1));
+        assert_eq!(
+            state.get_block_stake_changes(),
+            BTreeSet::from([verification_key(1), verification_key(2)])
+        );
+    }
+}
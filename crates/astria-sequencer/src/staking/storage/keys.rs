@@ -0,0 +1,79 @@
+use crate::accounts::AddressBytes;
+
+pub(in crate::staking) const PARAMETERS: &str = "staking/parameters";
+pub(in crate::staking) const DELEGATION_PREFIX: &str = "staking/delegation/";
+pub(in crate::staking) const VALIDATOR_STAKE_PREFIX: &str = "staking/validator_stake/";
+pub(in crate::staking) const BLOCK_STAKE_CHANGES: &str = "staking/block_stake_changes"; // NOTE: `BLOCK_STAKE_CHANGES` is only used in the ephemeral store.
+
+/// Example: `staking/delegation/0101....0101/`.
+///                             |40 hex chars|
+pub(in crate::staking) fn delegations_prefix<TAddress: AddressBytes>(
+    delegator: &TAddress,
+) -> String {
+    format!(
+        "{DELEGATION_PREFIX}{}/",
+        hex::encode(delegator.address_bytes())
+    )
+}
+
+/// Example: `staking/delegation/0101....0101/0202....0202`.
+///                             |40 hex chars|40 hex chars|
+pub(in crate::staking) fn delegation<TDelegator: AddressBytes, TValidator: AddressBytes>(
+    delegator: &TDelegator,
+    validator: &TValidator,
+) -> String {
+    format!(
+        "{}{}",
+        delegations_prefix(delegator),
+        hex::encode(validator.address_bytes())
+    )
+}
+
+pub(in crate::staking) fn validator_stake<TAddress: AddressBytes>(validator: &TAddress) -> String {
+    format!(
+        "{VALIDATOR_STAKE_PREFIX}{}",
+        hex::encode(validator.address_bytes())
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        crypto::ADDRESS_LENGTH,
+        primitive::v1::Address,
+    };
+
+    use super::*;
+
+    const COMPONENT_PREFIX: &str = "staking/";
+
+    fn address(byte: u8) -> Address {
+        Address::builder()
+            .array([byte; ADDRESS_LENGTH])
+            .prefix("astria")
+            .try_build()
+            .unwrap()
+    }
+
+    #[test]
+    fn keys_should_not_change() {
+        insta::assert_snapshot!("parameters_key", PARAMETERS);
+        insta::assert_snapshot!("delegations_prefix", delegations_prefix(&address(1)));
+        insta::assert_snapshot!("delegation_key", delegation(&address(1), &address(2)));
+        insta::assert_snapshot!("validator_stake_key", validator_stake(&address(2)));
+    }
+
+    #[test]
+    fn keys_should_have_component_prefix() {
+        assert!(PARAMETERS.starts_with(COMPONENT_PREFIX));
+        assert!(delegations_prefix(&address(1)).starts_with(COMPONENT_PREFIX));
+        assert!(delegation(&address(1), &address(2)).starts_with(COMPONENT_PREFIX));
+        assert!(validator_stake(&address(2)).starts_with(COMPONENT_PREFIX));
+        assert!(BLOCK_STAKE_CHANGES.starts_with(COMPONENT_PREFIX));
+    }
+
+    #[test]
+    fn delegation_key_should_have_delegations_prefix() {
+        assert!(delegation(&address(1), &address(2)).starts_with(&delegations_prefix(&address(1))));
+    }
+}
@@ -0,0 +1,9 @@
+pub(super) mod keys;
+mod values;
+
+pub(crate) use values::Value;
+pub(super) use values::{
+    Delegation,
+    StakingParameters,
+    ValidatorStake,
+};
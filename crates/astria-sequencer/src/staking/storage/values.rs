@@ -0,0 +1,238 @@
+use std::{
+    borrow::Cow,
+    fmt::{
+        self,
+        Debug,
+        Formatter,
+    },
+};
+
+use astria_core::{
+    crypto::VerificationKey as DomainVerificationKey,
+    protocol::{
+        genesis::v1::StakingParameters as DomainStakingParameters,
+        staking::v1::Delegation as DomainDelegation,
+    },
+    Protobuf as _,
+};
+use astria_eyre::eyre::bail;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use telemetry::display::base64;
+
+use crate::staking::ValidatorStake as DomainValidatorStake;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(crate) struct Value<'a>(ValueImpl<'a>);
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+enum ValueImpl<'a> {
+    StakingParameters(StakingParameters),
+    Delegation(Delegation<'a>),
+    ValidatorStake(ValidatorStake<'a>),
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+struct VerificationKey<'a>(Cow<'a, [u8; 32]>);
+
+impl Debug for VerificationKey<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", base64(self.0.as_slice()))
+    }
+}
+
+impl<'a> From<&'a DomainVerificationKey> for VerificationKey<'a> {
+    fn from(value: &'a DomainVerificationKey) -> Self {
+        VerificationKey(Cow::Borrowed(value.as_bytes()))
+    }
+}
+
+impl<'a> From<VerificationKey<'a>> for DomainVerificationKey {
+    fn from(value: VerificationKey<'a>) -> Self {
+        DomainVerificationKey::try_from(value.0.into_owned())
+            .expect("verification key in storage must be valid")
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::staking) struct StakingParameters {
+    unbonding_period_blocks: u64,
+    stake_per_power: u128,
+}
+
+impl From<DomainStakingParameters> for StakingParameters {
+    fn from(value: DomainStakingParameters) -> Self {
+        StakingParameters {
+            unbonding_period_blocks: value.unbonding_period_blocks(),
+            stake_per_power: value.stake_per_power(),
+        }
+    }
+}
+
+impl From<StakingParameters> for DomainStakingParameters {
+    fn from(value: StakingParameters) -> Self {
+        DomainStakingParameters::try_from_raw(
+            astria_core::generated::astria::protocol::genesis::v1::StakingParameters {
+                unbonding_period_blocks: value.unbonding_period_blocks,
+                stake_per_power: Some(value.stake_per_power.into()),
+            },
+        )
+        .expect("staking parameters in storage must be valid")
+    }
+}
+
+impl From<StakingParameters> for crate::storage::StoredValue<'_> {
+    fn from(parameters: StakingParameters) -> Self {
+        crate::storage::StoredValue::Staking(Value(ValueImpl::StakingParameters(parameters)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for StakingParameters {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Staking(Value(ValueImpl::StakingParameters(parameters))) =
+            value
+        else {
+            bail!(
+                "staking stored value type mismatch: expected staking parameters, found {value:?}"
+            );
+        };
+        Ok(parameters)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::staking) struct Delegation<'a> {
+    validator: VerificationKey<'a>,
+    amount: u128,
+}
+
+impl<'a> From<&'a DomainDelegation> for Delegation<'a> {
+    fn from(value: &'a DomainDelegation) -> Self {
+        Delegation {
+            validator: VerificationKey::from(&value.validator),
+            amount: value.amount,
+        }
+    }
+}
+
+impl From<Delegation<'_>> for DomainDelegation {
+    fn from(value: Delegation<'_>) -> Self {
+        DomainDelegation {
+            validator: DomainVerificationKey::from(value.validator),
+            amount: value.amount,
+        }
+    }
+}
+
+impl<'a> From<Delegation<'a>> for crate::storage::StoredValue<'a> {
+    fn from(delegation: Delegation<'a>) -> Self {
+        crate::storage::StoredValue::Staking(Value(ValueImpl::Delegation(delegation)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for Delegation<'a> {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Staking(Value(ValueImpl::Delegation(delegation))) = value
+        else {
+            bail!("staking stored value type mismatch: expected delegation, found {value:?}");
+        };
+        Ok(delegation)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::staking) struct ValidatorStake<'a> {
+    validator: VerificationKey<'a>,
+    stake: u128,
+}
+
+impl<'a> From<&'a DomainValidatorStake> for ValidatorStake<'a> {
+    fn from(value: &'a DomainValidatorStake) -> Self {
+        ValidatorStake {
+            validator: VerificationKey::from(&value.validator),
+            stake: value.stake,
+        }
+    }
+}
+
+impl From<ValidatorStake<'_>> for DomainValidatorStake {
+    fn from(value: ValidatorStake<'_>) -> Self {
+        DomainValidatorStake {
+            validator: DomainVerificationKey::from(value.validator),
+            stake: value.stake,
+        }
+    }
+}
+
+impl<'a> From<ValidatorStake<'a>> for crate::storage::StoredValue<'a> {
+    fn from(stake: ValidatorStake<'a>) -> Self {
+        crate::storage::StoredValue::Staking(Value(ValueImpl::ValidatorStake(stake)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for ValidatorStake<'a> {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Staking(Value(ValueImpl::ValidatorStake(stake))) = value
+        else {
+            bail!("staking stored value type mismatch: expected validator stake, found {value:?}");
+        };
+        Ok(stake)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use super::*;
+    use crate::test_utils::borsh_then_hex;
+
+    #[test]
+    fn value_impl_existing_variants_unchanged() {
+        assert_snapshot!(
+            "value_impl_staking_parameters",
+            borsh_then_hex(&ValueImpl::StakingParameters(StakingParameters {
+                unbonding_period_blocks: 1,
+                stake_per_power: 2,
+            }))
+        );
+        assert_snapshot!(
+            "value_impl_delegation",
+            borsh_then_hex(&ValueImpl::Delegation(Delegation {
+                validator: VerificationKey(Cow::Borrowed(&[0; 32])),
+                amount: 1,
+            }))
+        );
+        assert_snapshot!(
+            "value_impl_validator_stake",
+            borsh_then_hex(&ValueImpl::ValidatorStake(ValidatorStake {
+                validator: VerificationKey(Cow::Borrowed(&[0; 32])),
+                stake: 1,
+            }))
+        );
+    }
+
+    // Note: This test must be here instead of in `crate::storage` since `ValueImpl` is not
+    // re-exported.
+    #[test]
+    fn stored_value_staking_variant_unchanged() {
+        use crate::storage::StoredValue;
+        assert_snapshot!(
+            "stored_value_staking_variant",
+            borsh_then_hex(&StoredValue::Staking(Value(ValueImpl::StakingParameters(
+                StakingParameters {
+                    unbonding_period_blocks: 1,
+                    stake_per_power: 2,
+                }
+            ))))
+        );
+    }
+}
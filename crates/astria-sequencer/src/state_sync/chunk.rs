@@ -0,0 +1,107 @@
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+
+/// The size in bytes after which a chunk is closed and a new one started.
+///
+/// CometBFT limits chunks to 16 MiB, so this leaves ample headroom for a final entry pushing the
+/// chunk over the target size.
+const CHUNK_SIZE_TARGET: usize = 8 * 1024 * 1024;
+
+/// A single key-value pair of the snapshotted verifiable state.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub(super) struct Entry {
+    pub(super) key: String,
+    pub(super) value: Vec<u8>,
+}
+
+impl Entry {
+    fn size(&self) -> usize {
+        self.key.len().saturating_add(self.value.len())
+    }
+}
+
+/// Accumulates state entries into encoded chunks of roughly [`CHUNK_SIZE_TARGET`] bytes.
+pub(super) struct ChunkBuilder {
+    entries: Vec<Entry>,
+    size: usize,
+    size_target: usize,
+}
+
+impl ChunkBuilder {
+    pub(super) fn new() -> Self {
+        Self::with_size_target(CHUNK_SIZE_TARGET)
+    }
+
+    fn with_size_target(size_target: usize) -> Self {
+        Self {
+            entries: Vec::new(),
+            size: 0,
+            size_target,
+        }
+    }
+
+    /// Adds `entry` to the current chunk, returning the encoded chunk if it reached the target
+    /// size.
+    pub(super) fn push(&mut self, entry: Entry) -> Option<Vec<u8>> {
+        self.size = self.size.saturating_add(entry.size());
+        self.entries.push(entry);
+        if self.size >= self.size_target {
+            return Some(self.take());
+        }
+        None
+    }
+
+    /// Returns the encoded final chunk, or `None` if no entries are pending.
+    pub(super) fn finish(mut self) -> Option<Vec<u8>> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        Some(self.take())
+    }
+
+    fn take(&mut self) -> Vec<u8> {
+        self.size = 0;
+        borsh::to_vec(&std::mem::take(&mut self.entries))
+            .expect("borsh serialization of snapshot entries must not fail")
+    }
+}
+
+pub(super) fn decode(chunk: &[u8]) -> std::io::Result<Vec<Entry>> {
+    borsh::from_slice(chunk)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verifiable(key: &str, value_len: usize) -> Entry {
+        Entry {
+            key: key.to_string(),
+            value: vec![1; value_len],
+        }
+    }
+
+    #[test]
+    fn builder_closes_chunk_once_size_target_is_reached() {
+        let mut builder = ChunkBuilder::with_size_target(10);
+        assert!(builder.push(verifiable("a", 4)).is_none());
+        let chunk = builder
+            .push(verifiable("b", 4))
+            .expect("chunk should be closed after reaching 10 bytes");
+        assert_eq!(
+            vec![verifiable("a", 4), verifiable("b", 4)],
+            decode(&chunk).unwrap()
+        );
+
+        assert!(builder.push(verifiable("c", 1)).is_none());
+        let last = builder.finish().unwrap();
+        assert_eq!(vec![verifiable("c", 1)], decode(&last).unwrap());
+    }
+
+    #[test]
+    fn empty_builder_produces_no_chunk() {
+        assert!(ChunkBuilder::new().finish().is_none());
+    }
+}
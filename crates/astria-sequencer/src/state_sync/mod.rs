@@ -0,0 +1,125 @@
+//! ABCI state sync support for the sequencer.
+//!
+//! Snapshots of the cnidarium storage are taken every `snapshot_interval` blocks by the
+//! [`Snapshotter`] and written to a [`SnapshotStore`] on disk. The store is read by
+//! [`crate::service::Snapshot`] to serve `ListSnapshots` and `LoadSnapshotChunk` requests, while
+//! a [`Restorer`] is used to apply the chunks of an offered snapshot to empty storage.
+//!
+//! A snapshot consists of a number of chunks, each holding a borsh-encoded list of key-value
+//! entries from the verifiable stores. The snapshot metadata contains the app hash the snapshot
+//! was taken at and the sha256 hash of every chunk, so that each chunk can be verified as it is
+//! applied, and the fully restored state can be verified against the app hash.
+//!
+//! Non-verifiable state is not covered by the app hash, so it is never taken from a snapshot.
+//! Instead, the [`Restorer`] rebuilds what is needed to execute blocks locally:
+//! - the validator count is recomputed from the restored validators;
+//! - the consensus params are marked as missing and fetched from CometBFT, which verified them
+//!   during state sync, when they are first needed to handle a block.
+//!
+//! Data only served to clients, such as sequencer blocks and bridge deposits for the gRPC and ABCI
+//! query services, and the undo entries of [`crate::storage::StateHistory`], are not available
+//! for heights up to and including the restored height.
+
+mod chunk;
+mod restore;
+mod snapshotter;
+mod store;
+
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use bytes::Bytes;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+
+pub(crate) use self::{
+    restore::{
+        ApplyChunkError,
+        ApplyChunkOutcome,
+        OfferError,
+        Restorer,
+    },
+    snapshotter::Snapshotter,
+    store::SnapshotStore,
+};
+
+/// The only snapshot format currently produced and understood by the sequencer.
+pub(crate) const SNAPSHOT_FORMAT: u32 = 2;
+
+/// Returns the directory in which snapshots are stored, which is a sibling of the directory
+/// backing chain state.
+pub(crate) fn snapshot_dir(db_filepath: &Path) -> PathBuf {
+    let mut file_name = db_filepath
+        .file_name()
+        .map(std::ffi::OsStr::to_os_string)
+        .unwrap_or_default();
+    file_name.push("_snapshots");
+    db_filepath.with_file_name(file_name)
+}
+
+/// The metadata of a snapshot, sent to peers as the `metadata` field of the ABCI snapshot.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Metadata {
+    /// The app hash of the state captured by the snapshot.
+    pub(crate) app_hash: [u8; 32],
+    /// The sha256 hashes of all chunks, in order.
+    pub(crate) chunk_hashes: Vec<[u8; 32]>,
+}
+
+impl Metadata {
+    pub(crate) fn encode(&self) -> Bytes {
+        borsh::to_vec(self)
+            .expect("borsh serialization of snapshot metadata must not fail")
+            .into()
+    }
+
+    pub(crate) fn decode(bytes: &[u8]) -> std::io::Result<Self> {
+        borsh::from_slice(bytes)
+    }
+
+    /// Returns the hash of the snapshot, defined as the sha256 hash of the encoded metadata.
+    pub(crate) fn snapshot_hash(&self) -> [u8; 32] {
+        Sha256::digest(self.encode()).into()
+    }
+
+    pub(crate) fn chunk_count(&self) -> u32 {
+        u32::try_from(self.chunk_hashes.len())
+            .expect("number of chunks is bounded by the size of state and must fit in u32")
+    }
+}
+
+pub(crate) fn chunk_hash(chunk: &[u8]) -> [u8; 32] {
+    Sha256::digest(chunk).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_dir_is_sibling_of_db_filepath() {
+        assert_eq!(
+            PathBuf::from("/tmp/astria_db_snapshots"),
+            snapshot_dir(Path::new("/tmp/astria_db")),
+        );
+    }
+
+    #[test]
+    fn metadata_roundtrips() {
+        let metadata = Metadata {
+            app_hash: [1; 32],
+            chunk_hashes: vec![[2; 32], [3; 32]],
+        };
+        let decoded = Metadata::decode(&metadata.encode()).unwrap();
+        assert_eq!(metadata, decoded);
+        assert_eq!(2, decoded.chunk_count());
+    }
+}
@@ -0,0 +1,416 @@
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{
+        self,
+        WrapErr as _,
+    },
+};
+use cnidarium::{
+    StateDelta,
+    StateWrite as _,
+    Storage,
+};
+use telemetry::display::hex;
+use tendermint::{
+    abci::types::Snapshot,
+    AppHash,
+};
+use thiserror::Error;
+use tracing::{
+    debug,
+    info,
+};
+
+use super::{
+    chunk,
+    chunk_hash,
+    Metadata,
+    SNAPSHOT_FORMAT,
+};
+use crate::{
+    app::StateWriteExt as _,
+    authority::component::AuthorityComponent,
+};
+
+#[derive(Debug, Error)]
+pub(crate) enum OfferError {
+    #[error("snapshot format `{0}` is not supported; only format `{SNAPSHOT_FORMAT}` is")]
+    UnsupportedFormat(u32),
+
+    #[error("storage already contains state at version `{0}`; only empty storage can be restored")]
+    StorageNotEmpty(u64),
+
+    #[error("failed decoding snapshot metadata")]
+    InvalidMetadata(#[source] std::io::Error),
+
+    #[error("snapshot hash does not match the hash of its metadata")]
+    SnapshotHashMismatch,
+
+    #[error("snapshot declares `{declared}` chunks, but its metadata lists `{listed}`")]
+    ChunkCountMismatch { declared: u32, listed: u32 },
+
+    #[error("app hash recorded in snapshot metadata does not match the trusted app hash")]
+    AppHashMismatch,
+}
+
+#[derive(Debug, Error)]
+pub(crate) enum ApplyChunkError {
+    #[error("expected chunk at index `{expected}`, got `{actual}`")]
+    UnexpectedIndex { expected: u32, actual: u32 },
+
+    #[error("hash of chunk at index `{0}` does not match the hash in the snapshot metadata")]
+    ChunkHashMismatch(u32),
+
+    #[error("failed decoding chunk at index `{index}`")]
+    Decode { index: u32, source: std::io::Error },
+
+    #[error(
+        "root hash of restored state `{actual}` does not match app hash of snapshot `{expected}`"
+    )]
+    AppHashMismatch { expected: String, actual: String },
+
+    #[error("failed writing restored state to storage")]
+    Storage { source: eyre::Report },
+}
+
+/// The result of successfully applying a chunk.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum ApplyChunkOutcome {
+    /// The chunk was applied and more chunks are expected.
+    Applied,
+    /// The last chunk was applied and the restored state was committed to storage.
+    Completed,
+}
+
+/// Restores an offered snapshot to empty storage, chunk by chunk.
+///
+/// Every chunk is checked against its hash in the snapshot metadata before being applied. Once
+/// all chunks are applied, the root hash of the restored state is checked against the app hash of
+/// the snapshot before it is committed to storage, together with the non-verifiable state
+/// rebuilt from it.
+pub(crate) struct Restorer {
+    storage: Storage,
+    height: u64,
+    metadata: Metadata,
+    next_chunk: u32,
+    state: StateDelta<cnidarium::Snapshot>,
+}
+
+impl Restorer {
+    /// Checks the offered `snapshot` and prepares restoring it if it is acceptable.
+    ///
+    /// `app_hash` is the trusted app hash at the snapshot's height as provided by CometBFT.
+    pub(crate) fn offer(
+        storage: Storage,
+        snapshot: &Snapshot,
+        app_hash: &AppHash,
+    ) -> Result<Self, OfferError> {
+        if snapshot.format != SNAPSHOT_FORMAT {
+            return Err(OfferError::UnsupportedFormat(snapshot.format));
+        }
+        // the storage version is set to u64::MAX by default when first created
+        if storage.latest_version() != u64::MAX {
+            return Err(OfferError::StorageNotEmpty(storage.latest_version()));
+        }
+        let metadata = Metadata::decode(&snapshot.metadata).map_err(OfferError::InvalidMetadata)?;
+        if metadata.snapshot_hash().as_slice() != &*snapshot.hash {
+            return Err(OfferError::SnapshotHashMismatch);
+        }
+        if metadata.chunk_count() != snapshot.chunks {
+            return Err(OfferError::ChunkCountMismatch {
+                declared: snapshot.chunks,
+                listed: metadata.chunk_count(),
+            });
+        }
+        if metadata.app_hash.as_slice() != app_hash.as_bytes() {
+            return Err(OfferError::AppHashMismatch);
+        }
+        let state = StateDelta::new(storage.latest_snapshot());
+        Ok(Self {
+            storage,
+            height: snapshot.height.value(),
+            metadata,
+            next_chunk: 0,
+            state,
+        })
+    }
+
+    /// Returns the index of the next chunk to be applied.
+    pub(crate) fn next_chunk(&self) -> u32 {
+        self.next_chunk
+    }
+
+    /// Verifies and applies the chunk at `index`, committing the restored state to storage if it
+    /// was the last chunk.
+    pub(crate) async fn apply_chunk(
+        &mut self,
+        index: u32,
+        chunk: &[u8],
+    ) -> Result<ApplyChunkOutcome, ApplyChunkError> {
+        if index != self.next_chunk {
+            return Err(ApplyChunkError::UnexpectedIndex {
+                expected: self.next_chunk,
+                actual: index,
+            });
+        }
+        let index_usize = usize::try_from(index).expect("u32 must fit into usize");
+        if self.metadata.chunk_hashes.get(index_usize) != Some(&chunk_hash(chunk)) {
+            return Err(ApplyChunkError::ChunkHashMismatch(index));
+        }
+        let entries = chunk::decode(chunk).map_err(|source| ApplyChunkError::Decode {
+            index,
+            source,
+        })?;
+        for chunk::Entry {
+            key,
+            value,
+        } in entries
+        {
+            self.state.put_raw(key, value);
+        }
+        self.next_chunk = self
+            .next_chunk
+            .checked_add(1)
+            .expect("chunk index is bounded by the chunk count, which fits into u32");
+        debug!(index, "applied snapshot chunk");
+
+        if self.next_chunk < self.metadata.chunk_count() {
+            return Ok(ApplyChunkOutcome::Applied);
+        }
+        self.commit().await?;
+        Ok(ApplyChunkOutcome::Completed)
+    }
+
+    async fn commit(&mut self) -> Result<(), ApplyChunkError> {
+        let mut state = std::mem::replace(
+            &mut self.state,
+            StateDelta::new(self.storage.latest_snapshot()),
+        );
+        // the snapshot's storage versions refer to the source node's storage; point the restored
+        // height at the version it is committed under in this node's storage.
+        let version = self.storage.latest_version().wrapping_add(1);
+        state
+            .put_storage_version_by_height(self.height, version)
+            .map_err(|source| ApplyChunkError::Storage {
+                source,
+            })?;
+        // non-verifiable state is not part of the snapshot; rebuild what is needed to execute
+        // blocks from the restored verifiable state, or fetch it from CometBFT later.
+        AuthorityComponent::restore_validator_count(&mut state)
+            .await
+            .map_err(|source| ApplyChunkError::Storage {
+                source,
+            })?;
+        state
+            .put_consensus_params_missing(self.height)
+            .map_err(|source| ApplyChunkError::Storage {
+                source,
+            })?;
+
+        let write_batch = self
+            .storage
+            .prepare_commit(state)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed to prepare commit of restored state")
+            .map_err(|source| ApplyChunkError::Storage {
+                source,
+            })?;
+        let root_hash = write_batch.root_hash().0;
+        if root_hash != self.metadata.app_hash {
+            return Err(ApplyChunkError::AppHashMismatch {
+                expected: hex(&self.metadata.app_hash).to_string(),
+                actual: hex(&root_hash).to_string(),
+            });
+        }
+        self.storage
+            .commit_batch(write_batch)
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed to commit restored state")
+            .map_err(|source| ApplyChunkError::Storage {
+                source,
+            })?;
+        info!(
+            height = self.height,
+            version,
+            app_hash = %hex(&root_hash),
+            "restored state from snapshot"
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        crypto::SigningKey,
+        protocol::transaction::v1::action::{
+            ValidatorName,
+            ValidatorUpdate,
+        },
+    };
+    use cnidarium::{
+        StateRead as _,
+        StateWrite as _,
+    };
+
+    use super::*;
+    use crate::{
+        app::{
+            StateReadExt as _,
+            StateWriteExt as _,
+        },
+        authority::{
+            StateReadExt as _,
+            StateWriteExt as _,
+        },
+        state_sync::{
+            SnapshotStore,
+            Snapshotter,
+        },
+    };
+
+    const HEIGHT: u64 = 10;
+
+    struct TakenSnapshot {
+        snapshot: Snapshot,
+        chunks: Vec<bytes::Bytes>,
+        app_hash: AppHash,
+        _dir: tempfile::TempDir,
+    }
+
+    async fn take_snapshot() -> TakenSnapshot {
+        let source = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(source.latest_snapshot());
+        state.put_block_height(HEIGHT).unwrap();
+        state.put_raw("some/key".to_string(), vec![1, 2, 3]);
+        state.nonverifiable_put_raw(b"some/nonverifiable/key".to_vec(), vec![4, 5, 6]);
+        for seed in [1, 2] {
+            state
+                .put_validator(&ValidatorUpdate {
+                    name: ValidatorName::empty(),
+                    power: 10,
+                    verification_key: SigningKey::from([seed; 32]).verification_key(),
+                })
+                .unwrap();
+        }
+        // a wrong count in non-verifiable state must not be restored.
+        state.put_validator_count(5).unwrap();
+        source.commit(state).await.unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let store = SnapshotStore::new(dir.path().to_path_buf());
+        let snapshotter = Snapshotter::new((*source).clone(), store.clone(), 1, 1, vec![]);
+        snapshotter
+            .take_snapshot(source.latest_snapshot(), HEIGHT)
+            .await
+            .unwrap();
+
+        let snapshot = store.list().await.unwrap().remove(0);
+        let mut chunks = vec![];
+        for index in 0..snapshot.chunks {
+            chunks.push(store.load_chunk(HEIGHT, index).await.unwrap().unwrap());
+        }
+        let app_hash = source
+            .latest_snapshot()
+            .root_hash()
+            .await
+            .unwrap()
+            .0
+            .to_vec()
+            .try_into()
+            .unwrap();
+        TakenSnapshot {
+            snapshot,
+            chunks,
+            app_hash,
+            _dir: dir,
+        }
+    }
+
+    #[tokio::test]
+    async fn snapshot_is_restored_to_identical_state() {
+        let taken = take_snapshot().await;
+        let target = cnidarium::TempStorage::new().await.unwrap();
+
+        let mut restorer =
+            Restorer::offer((*target).clone(), &taken.snapshot, &taken.app_hash).unwrap();
+        let last_index = taken.snapshot.chunks.checked_sub(1).unwrap();
+        for (index, chunk) in (0..).zip(&taken.chunks) {
+            let outcome = restorer.apply_chunk(index, chunk).await.unwrap();
+            if index == last_index {
+                assert_eq!(ApplyChunkOutcome::Completed, outcome);
+            } else {
+                assert_eq!(ApplyChunkOutcome::Applied, outcome);
+            }
+        }
+
+        let restored = target.latest_snapshot();
+        let version = target.latest_version();
+        assert_eq!(
+            taken.app_hash.as_bytes(),
+            restored.root_hash().await.unwrap().0.as_slice()
+        );
+        assert_eq!(
+            Some(vec![1, 2, 3]),
+            restored.get_raw("some/key").await.unwrap()
+        );
+        assert_eq!(
+            None,
+            restored
+                .nonverifiable_get_raw(b"some/nonverifiable/key")
+                .await
+                .unwrap(),
+            "non-verifiable state must not be restored from a snapshot"
+        );
+        assert_eq!(2, restored.get_validator_count().await.unwrap());
+        assert!(restored.is_consensus_params_missing().await.unwrap());
+        assert_eq!(
+            version,
+            restored
+                .get_storage_version_by_height(HEIGHT)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn offer_is_rejected_for_mismatched_app_hash() {
+        let taken = take_snapshot().await;
+        let target = cnidarium::TempStorage::new().await.unwrap();
+        let wrong_app_hash = AppHash::try_from(vec![0; 32]).unwrap();
+
+        assert!(matches!(
+            Restorer::offer((*target).clone(), &taken.snapshot, &wrong_app_hash),
+            Err(OfferError::AppHashMismatch)
+        ));
+    }
+
+    #[tokio::test]
+    async fn offer_is_rejected_for_unsupported_format() {
+        let mut taken = take_snapshot().await;
+        taken.snapshot.format = SNAPSHOT_FORMAT.checked_add(1).unwrap();
+        let target = cnidarium::TempStorage::new().await.unwrap();
+
+        assert!(matches!(
+            Restorer::offer((*target).clone(), &taken.snapshot, &taken.app_hash),
+            Err(OfferError::UnsupportedFormat(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn tampered_chunk_is_rejected() {
+        let taken = take_snapshot().await;
+        let target = cnidarium::TempStorage::new().await.unwrap();
+        let mut restorer =
+            Restorer::offer((*target).clone(), &taken.snapshot, &taken.app_hash).unwrap();
+
+        let mut tampered = taken.chunks[0].to_vec();
+        tampered.push(0);
+        assert!(matches!(
+            restorer.apply_chunk(0, &tampered).await,
+            Err(ApplyChunkError::ChunkHashMismatch(0))
+        ));
+        assert_eq!(0, restorer.next_chunk());
+    }
+}
@@ -0,0 +1,144 @@
+use std::pin::pin;
+
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{
+        Result,
+        WrapErr as _,
+    },
+};
+use cnidarium::{
+    Snapshot,
+    StateRead as _,
+    Storage,
+};
+use futures::StreamExt as _;
+use tracing::{
+    debug,
+    info,
+    instrument,
+    warn,
+};
+
+use super::{
+    chunk::{
+        ChunkBuilder,
+        Entry,
+    },
+    Metadata,
+    SnapshotStore,
+};
+use crate::app::StateReadExt as _;
+
+/// Takes a snapshot of the chain state every `interval` blocks and writes it to a
+/// [`SnapshotStore`], keeping only the `keep_recent` most recent snapshots.
+pub(crate) struct Snapshotter {
+    storage: Storage,
+    store: SnapshotStore,
+    interval: u64,
+    keep_recent: usize,
+    substore_prefixes: Vec<String>,
+}
+
+impl Snapshotter {
+    pub(crate) fn new(
+        storage: Storage,
+        store: SnapshotStore,
+        interval: u64,
+        keep_recent: usize,
+        substore_prefixes: Vec<String>,
+    ) -> Self {
+        Self {
+            storage,
+            store,
+            interval,
+            keep_recent,
+            substore_prefixes,
+        }
+    }
+
+    /// Runs the snapshotter until the storage is dropped.
+    ///
+    /// Failing to take a snapshot is not fatal: it is logged and the snapshotter waits for the
+    /// next snapshot height.
+    pub(crate) async fn run(self) {
+        let mut committed_snapshots = self.storage.subscribe();
+        while committed_snapshots.changed().await.is_ok() {
+            let snapshot = committed_snapshots.borrow_and_update().clone();
+            let height = match snapshot.get_block_height().await {
+                Ok(height) => height,
+                Err(error) => {
+                    warn!(
+                        error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                        "failed reading block height of committed state; skipping snapshot check"
+                    );
+                    continue;
+                }
+            };
+            if height == 0 || height.checked_rem(self.interval) != Some(0) {
+                continue;
+            }
+            if let Err(error) = self.take_snapshot(snapshot, height).await {
+                warn!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                    height, "failed taking state sync snapshot"
+                );
+                continue;
+            }
+            if let Err(error) = self.store.prune(self.keep_recent).await {
+                warn!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                    "failed pruning old state sync snapshots"
+                );
+            }
+        }
+        info!("storage was dropped; stopping snapshotter");
+    }
+
+    #[instrument(skip_all, fields(height), err)]
+    pub(crate) async fn take_snapshot(&self, snapshot: Snapshot, height: u64) -> Result<Metadata> {
+        let app_hash = snapshot
+            .root_hash()
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading root hash of snapshotted state")?
+            .0;
+        let mut writer = self.store.begin(height).await?;
+        let mut builder = ChunkBuilder::new();
+
+        // only verifiable state is snapshotted, as non-verifiable state is not covered by the app
+        // hash and could not be checked by the restoring node. the main store is keyed by the
+        // empty prefix.
+        let prefixes = std::iter::once("").chain(self.substore_prefixes.iter().map(String::as_str));
+        for prefix in prefixes {
+            let mut entries = pin!(snapshot.prefix_raw(prefix));
+            while let Some(item) = entries.next().await {
+                let (key, value) = item
+                    .map_err(anyhow_to_eyre)
+                    .wrap_err("failed reading verifiable state")?;
+                // the root hashes of substores are recorded in the main store and are recomputed
+                // when the restored state is committed.
+                if prefix.is_empty() && self.substore_prefixes.contains(&key) {
+                    continue;
+                }
+                if let Some(chunk) = builder.push(Entry {
+                    key,
+                    value,
+                }) {
+                    writer.write_chunk(&chunk).await?;
+                }
+            }
+        }
+        if let Some(chunk) = builder.finish() {
+            writer.write_chunk(&chunk).await?;
+        }
+
+        let metadata = writer.finish(app_hash).await?;
+        debug!(
+            chunks = metadata.chunk_count(),
+            app_hash = %telemetry::display::hex(&metadata.app_hash),
+            "wrote state sync snapshot"
+        );
+        Ok(metadata)
+    }
+}
@@ -0,0 +1,281 @@
+use std::{
+    io::ErrorKind,
+    path::PathBuf,
+};
+
+use astria_eyre::eyre::{
+    Result,
+    WrapErr as _,
+};
+use bytes::Bytes;
+use tendermint::abci::types::Snapshot;
+
+use super::{
+    chunk_hash,
+    Metadata,
+    SNAPSHOT_FORMAT,
+};
+
+const METADATA_FILE_NAME: &str = "metadata";
+const PARTIAL_SUFFIX: &str = "partial";
+
+/// Snapshots stored on disk.
+///
+/// Every complete snapshot is stored in a directory named after its height, containing a
+/// `metadata` file and one file per chunk named after its index. Snapshots are first written to a
+/// `<height>.partial` directory which is only renamed once all chunks and the metadata were
+/// written, so that a crash mid-snapshot never results in an incomplete snapshot being served.
+#[derive(Clone, Debug)]
+pub(crate) struct SnapshotStore {
+    dir: PathBuf,
+}
+
+impl SnapshotStore {
+    pub(crate) fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+        }
+    }
+
+    fn snapshot_dir(&self, height: u64) -> PathBuf {
+        self.dir.join(height.to_string())
+    }
+
+    /// Returns the heights of all complete snapshots in ascending order.
+    async fn heights(&self) -> Result<Vec<u64>> {
+        let mut read_dir = match tokio::fs::read_dir(&self.dir).await {
+            Ok(read_dir) => read_dir,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(error) => {
+                return Err(error).wrap_err_with(|| {
+                    format!("failed reading snapshot directory `{}`", self.dir.display())
+                });
+            }
+        };
+        let mut heights = vec![];
+        while let Some(entry) = read_dir
+            .next_entry()
+            .await
+            .wrap_err("failed reading entry of snapshot directory")?
+        {
+            // partial snapshots and unrelated files don't parse as heights and are ignored.
+            if let Some(height) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<u64>().ok())
+            {
+                heights.push(height);
+            }
+        }
+        heights.sort_unstable();
+        Ok(heights)
+    }
+
+    /// Returns all complete snapshots, most recent first.
+    pub(crate) async fn list(&self) -> Result<Vec<Snapshot>> {
+        let mut snapshots = vec![];
+        for height in self.heights().await?.into_iter().rev() {
+            let metadata_path = self.snapshot_dir(height).join(METADATA_FILE_NAME);
+            let raw_metadata = tokio::fs::read(&metadata_path).await.wrap_err_with(|| {
+                format!(
+                    "failed reading snapshot metadata at `{}`",
+                    metadata_path.display()
+                )
+            })?;
+            let metadata = Metadata::decode(&raw_metadata).wrap_err_with(|| {
+                format!(
+                    "failed decoding snapshot metadata at `{}`",
+                    metadata_path.display()
+                )
+            })?;
+            snapshots.push(Snapshot {
+                height: height
+                    .try_into()
+                    .wrap_err("snapshot height does not fit into a cometbft height")?,
+                format: SNAPSHOT_FORMAT,
+                chunks: metadata.chunk_count(),
+                hash: metadata.snapshot_hash().to_vec().into(),
+                metadata: raw_metadata.into(),
+            });
+        }
+        Ok(snapshots)
+    }
+
+    /// Returns the chunk at `index` of the snapshot at `height`, or `None` if it does not exist.
+    pub(crate) async fn load_chunk(&self, height: u64, index: u32) -> Result<Option<Bytes>> {
+        let chunk_path = self.snapshot_dir(height).join(index.to_string());
+        match tokio::fs::read(&chunk_path).await {
+            Ok(chunk) => Ok(Some(chunk.into())),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error).wrap_err_with(|| {
+                format!(
+                    "failed reading snapshot chunk at `{}`",
+                    chunk_path.display()
+                )
+            }),
+        }
+    }
+
+    /// Starts writing a new snapshot at `height`.
+    pub(crate) async fn begin(&self, height: u64) -> Result<SnapshotWriter> {
+        let partial_dir = self.dir.join(format!("{height}.{PARTIAL_SUFFIX}"));
+        if tokio::fs::try_exists(&partial_dir).await.unwrap_or(false) {
+            tokio::fs::remove_dir_all(&partial_dir)
+                .await
+                .wrap_err_with(|| {
+                    format!(
+                        "failed removing stale partial snapshot at `{}`",
+                        partial_dir.display()
+                    )
+                })?;
+        }
+        tokio::fs::create_dir_all(&partial_dir)
+            .await
+            .wrap_err_with(|| {
+                format!(
+                    "failed creating partial snapshot directory `{}`",
+                    partial_dir.display()
+                )
+            })?;
+        Ok(SnapshotWriter {
+            partial_dir,
+            final_dir: self.snapshot_dir(height),
+            chunk_hashes: vec![],
+        })
+    }
+
+    /// Removes all but the `keep_recent` most recent snapshots.
+    pub(crate) async fn prune(&self, keep_recent: usize) -> Result<()> {
+        let heights = self.heights().await?;
+        let prune_count = heights.len().saturating_sub(keep_recent);
+        for height in heights.into_iter().take(prune_count) {
+            let dir = self.snapshot_dir(height);
+            tokio::fs::remove_dir_all(&dir)
+                .await
+                .wrap_err_with(|| format!("failed removing snapshot at `{}`", dir.display()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the chunks of a snapshot to disk.
+pub(crate) struct SnapshotWriter {
+    partial_dir: PathBuf,
+    final_dir: PathBuf,
+    chunk_hashes: Vec<[u8; 32]>,
+}
+
+impl SnapshotWriter {
+    pub(crate) async fn write_chunk(&mut self, chunk: &[u8]) -> Result<()> {
+        let chunk_path = self.partial_dir.join(self.chunk_hashes.len().to_string());
+        tokio::fs::write(&chunk_path, chunk)
+            .await
+            .wrap_err_with(|| {
+                format!(
+                    "failed writing snapshot chunk to `{}`",
+                    chunk_path.display()
+                )
+            })?;
+        self.chunk_hashes.push(chunk_hash(chunk));
+        Ok(())
+    }
+
+    /// Writes the metadata of the snapshot and marks it as complete.
+    pub(crate) async fn finish(self, app_hash: [u8; 32]) -> Result<Metadata> {
+        let metadata = Metadata {
+            app_hash,
+            chunk_hashes: self.chunk_hashes,
+        };
+        let metadata_path = self.partial_dir.join(METADATA_FILE_NAME);
+        tokio::fs::write(&metadata_path, metadata.encode())
+            .await
+            .wrap_err_with(|| {
+                format!(
+                    "failed writing snapshot metadata to `{}`",
+                    metadata_path.display()
+                )
+            })?;
+        tokio::fs::rename(&self.partial_dir, &self.final_dir)
+            .await
+            .wrap_err_with(|| {
+                format!(
+                    "failed moving complete snapshot from `{}` to `{}`",
+                    self.partial_dir.display(),
+                    self.final_dir.display()
+                )
+            })?;
+        Ok(metadata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn write_snapshot(store: &SnapshotStore, height: u64, chunks: &[&[u8]]) -> Metadata {
+        let mut writer = store.begin(height).await.unwrap();
+        for chunk in chunks {
+            writer.write_chunk(chunk).await.unwrap();
+        }
+        writer
+            .finish([height.try_into().unwrap(); 32])
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn written_snapshots_are_listed_and_loadable() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SnapshotStore::new(dir.path().join("snapshots"));
+        assert!(store.list().await.unwrap().is_empty());
+
+        let metadata = write_snapshot(&store, 10, &[b"first", b"second"]).await;
+        write_snapshot(&store, 20, &[b"third"]).await;
+
+        let snapshots = store.list().await.unwrap();
+        assert_eq!(2, snapshots.len());
+        assert_eq!(20, snapshots[0].height.value());
+        assert_eq!(10, snapshots[1].height.value());
+        assert_eq!(2, snapshots[1].chunks);
+        assert_eq!(metadata.encode(), snapshots[1].metadata);
+        assert_eq!(metadata.snapshot_hash().as_slice(), &*snapshots[1].hash);
+
+        assert_eq!(
+            Some(Bytes::from_static(b"second")),
+            store.load_chunk(10, 1).await.unwrap()
+        );
+        assert_eq!(None, store.load_chunk(10, 2).await.unwrap());
+        assert_eq!(None, store.load_chunk(30, 0).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn unfinished_snapshots_are_not_listed() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SnapshotStore::new(dir.path().to_path_buf());
+        let mut writer = store.begin(10).await.unwrap();
+        writer.write_chunk(b"chunk").await.unwrap();
+
+        assert!(store.list().await.unwrap().is_empty());
+        assert_eq!(None, store.load_chunk(10, 0).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn prune_keeps_most_recent_snapshots() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SnapshotStore::new(dir.path().to_path_buf());
+        for height in [1, 2, 3, 4] {
+            write_snapshot(&store, height, &[b"chunk"]).await;
+        }
+
+        store.prune(2).await.unwrap();
+
+        let heights: Vec<_> = store
+            .list()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|snapshot| snapshot.height.value())
+            .collect();
+        assert_eq!(vec![4, 3], heights);
+    }
+}
@@ -0,0 +1,557 @@
+use std::{
+    any::{
+        Any,
+        TypeId,
+    },
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+    ops::{
+        Deref,
+        RangeBounds,
+    },
+    pin::pin,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use astria_eyre::{
+    anyhow,
+    anyhow_to_eyre,
+    eyre::{
+        OptionExt as _,
+        Result,
+        WrapErr as _,
+    },
+};
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use cnidarium::{
+    Snapshot,
+    StateDelta,
+    StateRead,
+    StateWrite,
+};
+use futures::StreamExt as _;
+use indexmap::IndexMap;
+use tendermint::abci::Event;
+
+const UNDO_PREFIX: &[u8] = b"history/undo/";
+
+/// The maximum number of reconstructed states kept in memory to serve later queries from.
+const MAX_CACHED_STATES: usize = 16;
+
+fn undo_key(height: u64) -> Vec<u8> {
+    [UNDO_PREFIX, &height.to_be_bytes()].concat()
+}
+
+/// Makes the chain state committed at recent heights available to queries.
+///
+/// When a block is committed, the values it overwrote are persisted in non-verifiable storage
+/// as part of the same write batch. The state at a past height is reconstructed by reverting the
+/// blocks committed after it on top of the latest snapshot. Undo entries for blocks outside the
+/// retention window of `retain_blocks` heights are pruned on commit, so the history survives
+/// restarts without pinning any database snapshots.
+///
+/// Reconstructing a state costs one undo entry read per reverted block. To avoid paying this for
+/// every query, the most recently used reconstructed states are cached, and a state is
+/// reconstructed by reverting only the blocks between it and the closest later cached state. The
+/// cache is bounded by [`MAX_CACHED_STATES`] and only holds heights inside the retention window,
+/// as each cached state pins the snapshot it was reconstructed from. Cloning a `StateHistory`
+/// shares its cache.
+#[derive(Clone)]
+pub(crate) struct StateHistory {
+    retain_blocks: u64,
+    /// Reconstructed states by height, from the least to the most recently used.
+    cached_states: Arc<Mutex<IndexMap<u64, StateDelta<Snapshot>>>>,
+}
+
+impl StateHistory {
+    pub(crate) fn new(retain_blocks: u64) -> Self {
+        Self {
+            retain_blocks,
+            cached_states: Arc::new(Mutex::new(IndexMap::new())),
+        }
+    }
+
+    /// Records the values overwritten by the block at `height`, returning the state to be
+    /// committed for the block.
+    ///
+    /// Undo entries which are no longer required to serve the retention window are deleted.
+    pub(crate) async fn record_block(
+        &self,
+        state: StateDelta<Snapshot>,
+        height: u64,
+    ) -> Result<StateDelta<Snapshot>> {
+        let (snapshot, cache) = state.flatten();
+        let mut recorder = ChangeRecorder::new(StateDelta::new(snapshot.clone()));
+        cache.apply_to(&mut recorder);
+        let ChangeRecorder {
+            mut state,
+            verifiable_keys,
+            nonverifiable_keys,
+        } = recorder;
+
+        // serving the oldest retained height requires the undo entries of all later blocks.
+        let first_required = height.saturating_add(2).saturating_sub(self.retain_blocks);
+        if height >= first_required {
+            let mut undo = BlockUndo::default();
+            for key in verifiable_keys {
+                let value = snapshot
+                    .get_raw(&key)
+                    .await
+                    .map_err(anyhow_to_eyre)
+                    .wrap_err_with(|| format!("failed reading previous value of `{key}`"))?;
+                undo.verifiable.push((key, value));
+            }
+            for key in nonverifiable_keys {
+                let value = snapshot
+                    .nonverifiable_get_raw(&key)
+                    .await
+                    .map_err(anyhow_to_eyre)
+                    .wrap_err("failed reading previous non-verifiable value")?;
+                undo.nonverifiable.push((key, value));
+            }
+            let bytes = borsh::to_vec(&undo).wrap_err("failed to serialize block undo entry")?;
+            state.nonverifiable_put_raw(undo_key(height), bytes);
+        }
+
+        let mut undo_entries = pin!(snapshot.nonverifiable_prefix_raw(UNDO_PREFIX));
+        while let Some(item) = undo_entries.next().await {
+            let (key, _) = item
+                .map_err(anyhow_to_eyre)
+                .wrap_err("failed reading block undo entries")?;
+            if undo_height(&key)? >= first_required {
+                break;
+            }
+            state.nonverifiable_delete(key);
+        }
+        Ok(state)
+    }
+
+    /// Returns the state committed at `height`, reconstructed from `latest`, which is the
+    /// snapshot of `latest_height`.
+    ///
+    /// Returns `None` if `height` is outside the retention window or not all blocks since were
+    /// recorded, e.g. because the state was restored from a state sync snapshot.
+    pub(crate) async fn state_at(
+        &self,
+        latest: Snapshot,
+        latest_height: u64,
+        height: u64,
+    ) -> Result<Option<QueryState>> {
+        if latest_height.saturating_sub(height) >= self.retain_blocks {
+            return Ok(None);
+        }
+        let (mut state, reconstructed_height) = self
+            .closest_cached_state(latest_height, height)
+            .unwrap_or_else(|| (StateDelta::new(latest.clone()), latest_height));
+        for reverted_height in (height.saturating_add(1)..=reconstructed_height).rev() {
+            let Some(bytes) = latest
+                .nonverifiable_get_raw(&undo_key(reverted_height))
+                .await
+                .map_err(anyhow_to_eyre)
+                .wrap_err("failed reading block undo entry")?
+            else {
+                return Ok(None);
+            };
+            let undo: BlockUndo = borsh::from_slice(&bytes).wrap_err_with(|| {
+                format!("failed to deserialize undo entry of {reverted_height}")
+            })?;
+            undo.revert(&mut state);
+        }
+        self.cache_state(height, &mut state);
+        Ok(Some(QueryState {
+            state: Arc::new(state),
+            snapshot: None,
+        }))
+    }
+
+    /// Returns a fork of the cached state with the lowest height not below `height`, along with
+    /// its height.
+    ///
+    /// Cached states which fell out of the retention window are evicted first.
+    fn closest_cached_state(
+        &self,
+        latest_height: u64,
+        height: u64,
+    ) -> Option<(StateDelta<Snapshot>, u64)> {
+        let mut cached_states = self
+            .cached_states
+            .lock()
+            .expect("the lock is never poisoned");
+        let first_retained = latest_height
+            .saturating_add(1)
+            .saturating_sub(self.retain_blocks);
+        cached_states.retain(|cached_height, _| *cached_height >= first_retained);
+        let closest_height = cached_states
+            .keys()
+            .copied()
+            .filter(|cached_height| (height..=latest_height).contains(cached_height))
+            .min()?;
+        // move the state to the back to mark it as the most recently used.
+        let mut closest = cached_states.shift_remove(&closest_height)?;
+        let state = closest.fork();
+        cached_states.insert(closest_height, closest);
+        Some((state, closest_height))
+    }
+
+    /// Caches a fork of `state` as the state at `height`, evicting the least recently used state
+    /// if the cache is full.
+    fn cache_state(&self, height: u64, state: &mut StateDelta<Snapshot>) {
+        let mut cached_states = self
+            .cached_states
+            .lock()
+            .expect("the lock is never poisoned");
+        if cached_states.contains_key(&height) {
+            return;
+        }
+        if cached_states.len() >= MAX_CACHED_STATES {
+            cached_states.shift_remove_index(0);
+        }
+        cached_states.insert(height, state.fork());
+    }
+}
+
+fn undo_height(key: &[u8]) -> Result<u64> {
+    let height = key
+        .strip_prefix(UNDO_PREFIX)
+        .and_then(|height| <[u8; 8]>::try_from(height).ok())
+        .ok_or_eyre("invalid block undo entry key")?;
+    Ok(u64::from_be_bytes(height))
+}
+
+/// The values of all keys written by a block, as they were before the block was executed.
+#[derive(Default, BorshSerialize, BorshDeserialize)]
+struct BlockUndo {
+    verifiable: Vec<(String, Option<Vec<u8>>)>,
+    nonverifiable: Vec<(Vec<u8>, Option<Vec<u8>>)>,
+}
+
+impl BlockUndo {
+    fn revert<S: StateWrite>(self, state: &mut S) {
+        for (key, value) in self.verifiable {
+            match value {
+                Some(value) => state.put_raw(key, value),
+                None => state.delete(key),
+            }
+        }
+        for (key, value) in self.nonverifiable {
+            match value {
+                Some(value) => state.nonverifiable_put_raw(key, value),
+                None => state.nonverifiable_delete(key),
+            }
+        }
+    }
+}
+
+/// The state an ABCI query is answered against.
+///
+/// Dereferences to a [`StateDelta`] so that it can be read like any other state.
+#[derive(Clone)]
+pub(crate) struct QueryState {
+    state: Arc<StateDelta<Snapshot>>,
+    snapshot: Option<Snapshot>,
+}
+
+impl QueryState {
+    /// Returns the committed snapshot the state is read from, or `None` if the state was
+    /// reconstructed from the block history.
+    ///
+    /// Only the state of a committed snapshot can be proven against its app hash.
+    pub(crate) fn snapshot(&self) -> Option<&Snapshot> {
+        self.snapshot.as_ref()
+    }
+}
+
+impl From<Snapshot> for QueryState {
+    fn from(snapshot: Snapshot) -> Self {
+        Self {
+            state: Arc::new(StateDelta::new(snapshot.clone())),
+            snapshot: Some(snapshot),
+        }
+    }
+}
+
+impl Deref for QueryState {
+    type Target = StateDelta<Snapshot>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// Forwards all writes to `state`, recording the keys written to.
+struct ChangeRecorder {
+    state: StateDelta<Snapshot>,
+    verifiable_keys: BTreeSet<String>,
+    nonverifiable_keys: BTreeSet<Vec<u8>>,
+}
+
+impl ChangeRecorder {
+    fn new(state: StateDelta<Snapshot>) -> Self {
+        Self {
+            state,
+            verifiable_keys: BTreeSet::new(),
+            nonverifiable_keys: BTreeSet::new(),
+        }
+    }
+}
+
+impl StateRead for ChangeRecorder {
+    type GetRawFut = <StateDelta<Snapshot> as StateRead>::GetRawFut;
+    type NonconsensusPrefixRawStream =
+        <StateDelta<Snapshot> as StateRead>::NonconsensusPrefixRawStream;
+    type NonconsensusRangeRawStream =
+        <StateDelta<Snapshot> as StateRead>::NonconsensusRangeRawStream;
+    type PrefixKeysStream = <StateDelta<Snapshot> as StateRead>::PrefixKeysStream;
+    type PrefixRawStream = <StateDelta<Snapshot> as StateRead>::PrefixRawStream;
+
+    fn get_raw(&self, key: &str) -> Self::GetRawFut {
+        self.state.get_raw(key)
+    }
+
+    fn nonverifiable_get_raw(&self, key: &[u8]) -> Self::GetRawFut {
+        self.state.nonverifiable_get_raw(key)
+    }
+
+    fn object_get<T: Any + Send + Sync + Clone>(&self, key: &'static str) -> Option<T> {
+        self.state.object_get(key)
+    }
+
+    fn object_type(&self, key: &'static str) -> Option<TypeId> {
+        self.state.object_type(key)
+    }
+
+    fn prefix_raw(&self, prefix: &str) -> Self::PrefixRawStream {
+        self.state.prefix_raw(prefix)
+    }
+
+    fn prefix_keys(&self, prefix: &str) -> Self::PrefixKeysStream {
+        self.state.prefix_keys(prefix)
+    }
+
+    fn nonverifiable_prefix_raw(&self, prefix: &[u8]) -> Self::NonconsensusPrefixRawStream {
+        self.state.nonverifiable_prefix_raw(prefix)
+    }
+
+    fn nonverifiable_range_raw(
+        &self,
+        prefix: Option<&[u8]>,
+        range: impl RangeBounds<Vec<u8>>,
+    ) -> anyhow::Result<Self::NonconsensusRangeRawStream> {
+        self.state.nonverifiable_range_raw(prefix, range)
+    }
+}
+
+impl StateWrite for ChangeRecorder {
+    fn put_raw(&mut self, key: String, value: Vec<u8>) {
+        self.verifiable_keys.insert(key.clone());
+        self.state.put_raw(key, value);
+    }
+
+    fn delete(&mut self, key: String) {
+        self.verifiable_keys.insert(key.clone());
+        self.state.delete(key);
+    }
+
+    fn nonverifiable_put_raw(&mut self, key: Vec<u8>, value: Vec<u8>) {
+        self.nonverifiable_keys.insert(key.clone());
+        self.state.nonverifiable_put_raw(key, value);
+    }
+
+    fn nonverifiable_delete(&mut self, key: Vec<u8>) {
+        self.nonverifiable_keys.insert(key.clone());
+        self.state.nonverifiable_delete(key);
+    }
+
+    fn object_put<T: Clone + Any + Send + Sync>(&mut self, key: &'static str, value: T) {
+        self.state.object_put(key, value);
+    }
+
+    fn object_delete(&mut self, key: &'static str) {
+        self.state.object_delete(key);
+    }
+
+    fn object_merge(
+        &mut self,
+        objects: BTreeMap<&'static str, Option<Box<dyn Any + Send + Sync>>>,
+    ) {
+        self.state.object_merge(objects);
+    }
+
+    fn record(&mut self, event: Event) {
+        self.state.record(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::Storage;
+
+    use super::*;
+    use crate::app::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    };
+
+    async fn commit_height(storage: &Storage, history: &StateHistory, height: u64) {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(height).unwrap();
+        if height % 2 == 0 {
+            state.delete("odd".to_string());
+        } else {
+            state.put_raw("odd".to_string(), height.to_be_bytes().to_vec());
+        }
+        let state = history.record_block(state, height).await.unwrap();
+        storage.commit(state).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn past_state_is_reconstructed_within_retention_window() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let history = StateHistory::new(3);
+        for height in 1..=4 {
+            commit_height(&storage, &history, height).await;
+        }
+
+        let latest = storage.latest_snapshot();
+        for height in 2..=4 {
+            let state = history
+                .state_at(latest.clone(), 4, height)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(height, state.get_block_height().await.unwrap());
+            let odd = state.get_raw("odd").await.unwrap();
+            if height % 2 == 0 {
+                assert!(odd.is_none());
+            } else {
+                assert_eq!(Some(height.to_be_bytes().to_vec()), odd);
+            }
+            assert!(state.snapshot().is_none());
+        }
+        assert!(history.state_at(latest, 4, 1).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn past_state_is_reconstructed_from_cached_states() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let history = StateHistory::new(5);
+        for height in 1..=5 {
+            commit_height(&storage, &history, height).await;
+        }
+        let cached_heights = |history: &StateHistory| -> Vec<u64> {
+            history
+                .cached_states
+                .lock()
+                .unwrap()
+                .keys()
+                .copied()
+                .collect()
+        };
+
+        history
+            .state_at(storage.latest_snapshot(), 5, 3)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(vec![3], cached_heights(&history));
+
+        // the state at height 2 is reconstructed from the one cached at height 3, which stays
+        // valid as further blocks are committed
+        commit_height(&storage, &history, 6).await;
+        let latest = storage.latest_snapshot();
+        let state = history
+            .state_at(latest.clone(), 6, 2)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(2, state.get_block_height().await.unwrap());
+        assert!(state.get_raw("odd").await.unwrap().is_none());
+        let state = history.state_at(latest, 6, 3).await.unwrap().unwrap();
+        assert_eq!(3, state.get_block_height().await.unwrap());
+        assert_eq!(
+            Some(3u64.to_be_bytes().to_vec()),
+            state.get_raw("odd").await.unwrap()
+        );
+        assert_eq!(vec![2, 3], cached_heights(&history));
+
+        // cached states outside the retention window are evicted
+        for height in 7..=8 {
+            commit_height(&storage, &history, height).await;
+        }
+        history
+            .state_at(storage.latest_snapshot(), 8, 5)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(vec![5], cached_heights(&history));
+    }
+
+    #[tokio::test]
+    async fn undo_entries_outside_retention_window_are_pruned() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let history = StateHistory::new(2);
+        for height in 1..=4 {
+            commit_height(&storage, &history, height).await;
+        }
+
+        let latest = storage.latest_snapshot();
+        for height in 1..=3 {
+            assert!(latest
+                .nonverifiable_get_raw(&undo_key(height))
+                .await
+                .unwrap()
+                .is_none());
+        }
+        assert!(latest
+            .nonverifiable_get_raw(&undo_key(4))
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn history_survives_reloading_storage() {
+        let dir = tempfile::tempdir().unwrap();
+        let history = StateHistory::new(10);
+        {
+            let storage = Storage::load(dir.path().to_path_buf(), vec![])
+                .await
+                .unwrap();
+            for height in 1..=3 {
+                commit_height(&storage, &history, height).await;
+            }
+            storage.release().await;
+        }
+
+        let storage = Storage::load(dir.path().to_path_buf(), vec![])
+            .await
+            .unwrap();
+        let state = history
+            .state_at(storage.latest_snapshot(), 3, 1)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(1, state.get_block_height().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn nothing_is_recorded_if_retention_is_zero() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let history = StateHistory::new(0);
+        commit_height(&storage, &history, 1).await;
+        commit_height(&storage, &history, 2).await;
+
+        let latest = storage.latest_snapshot();
+        let mut undo_entries = pin!(latest.nonverifiable_prefix_raw(UNDO_PREFIX));
+        assert!(undo_entries.next().await.is_none());
+        assert!(history.state_at(latest, 2, 1).await.unwrap().is_none());
+    }
+}
@@ -1,4 +1,11 @@
+mod history;
 pub(crate) mod keys;
+mod proof;
 mod stored_value;
 
+pub(crate) use history::{
+    QueryState,
+    StateHistory,
+};
+pub(crate) use proof::prove_keys;
 pub(crate) use stored_value::StoredValue;
@@ -0,0 +1,125 @@
+use astria_core::protocol::proof::PROOF_OP_TYPE;
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{
+        OptionExt as _,
+        Result,
+        WrapErr as _,
+    },
+};
+use prost::Message as _;
+use tendermint::merkle::proof::{
+    ProofOp,
+    ProofOps,
+};
+
+use super::QueryState;
+
+/// Returns inclusion proofs of the values stored under `keys`, or non-inclusion proofs if no
+/// value is stored, against the root hash of the snapshot `state` is read from.
+///
+/// Each proof is returned as a [`PROOF_OP_TYPE`] operation.
+///
+/// All keys must be in the main store, so that each proof consists of a single commitment proof.
+/// Fails if `state` was reconstructed from the block history, as there is no committed snapshot
+/// to prove it against.
+pub(crate) async fn prove_keys<I>(state: &QueryState, keys: I) -> Result<ProofOps>
+where
+    I: IntoIterator<Item = String>,
+{
+    let snapshot = state
+        .snapshot()
+        .ok_or_eyre("cannot prove state reconstructed from the block history")?;
+    let mut ops = vec![];
+    for key in keys {
+        let (_, proof) = snapshot
+            .get_with_proof(key.clone().into_bytes())
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err_with(|| format!("failed to create proof for key `{key}`"))?;
+        ops.push(ProofOp {
+            field_type: PROOF_OP_TYPE.to_string(),
+            key: key.into_bytes(),
+            data: proof.encode_to_vec(),
+        });
+    }
+    Ok(ProofOps {
+        ops,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::StateDelta;
+    use ibc_proto::{
+        ibc::core::commitment::v1::MerkleProof as RawMerkleProof,
+        ics23::commitment_proof::Proof,
+    };
+    use ibc_types::core::commitment::{
+        MerklePath,
+        MerkleProof,
+        MerkleRoot,
+    };
+
+    use super::*;
+    use crate::app::StateWriteExt as _;
+
+    fn decode_proof(op: &ProofOp) -> MerkleProof {
+        let raw = RawMerkleProof::decode(&*op.data).unwrap();
+        MerkleProof {
+            proofs: raw.proofs,
+        }
+    }
+
+    fn merkle_path(key: &str) -> MerklePath {
+        MerklePath {
+            key_path: vec![key.to_string()],
+        }
+    }
+
+    #[tokio::test]
+    async fn proofs_verify_against_root_hash() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(1).unwrap();
+        storage.commit(state).await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let root = MerkleRoot {
+            hash: snapshot.root_hash().await.unwrap().0.to_vec(),
+        };
+        let specs = [cnidarium::ics23_spec()];
+
+        let present_key = "app/block_height".to_string();
+        let absent_key = "absent/key".to_string();
+        let proof_ops = prove_keys(
+            &snapshot.clone().into(),
+            [present_key.clone(), absent_key.clone()],
+        )
+        .await
+        .unwrap();
+        assert_eq!(2, proof_ops.ops.len());
+        assert!(proof_ops
+            .ops
+            .iter()
+            .all(|op| op.field_type == PROOF_OP_TYPE));
+
+        let present = decode_proof(&proof_ops.ops[0]);
+        let Some(Proof::Exist(existence_proof)) = &present.proofs[0].proof else {
+            panic!("expected an existence proof for a stored key");
+        };
+        present
+            .verify_membership(
+                &specs,
+                root.clone(),
+                merkle_path(&present_key),
+                existence_proof.value.clone(),
+                0,
+            )
+            .unwrap();
+
+        let absent = decode_proof(&proof_ops.ops[1]);
+        absent
+            .verify_non_membership(&specs, root, merkle_path(&absent_key))
+            .unwrap();
+    }
+}
@@ -22,6 +22,8 @@ pub(crate) enum StoredValue<'a> {
     Upgrades(crate::upgrades::storage::Value<'a>),
     PriceFeedMarketMap(crate::oracles::price_feed::market_map::storage::Value<'a>),
     PriceFeedOracle(crate::oracles::price_feed::oracle::storage::Value<'a>),
+    Staking(crate::staking::storage::Value<'a>),
+    Governance(crate::governance::storage::Value),
 }
 
 impl StoredValue<'_> {
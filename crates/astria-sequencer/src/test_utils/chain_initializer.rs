@@ -4,7 +4,9 @@ use astria_core::{
         Account as RawAccount,
         AddressPrefixes as RawAddressPrefixes,
         GenesisAppState as RawGenesisAppState,
+        GovernanceParameters as RawGovernanceParameters,
         IbcParameters as RawIbcParameters,
+        StakingParameters as RawStakingParameters,
     },
     primitive::v1::Address,
     protocol::{
@@ -126,6 +128,16 @@ impl<'a> ChainInitializer<'a> {
                 recover_ibc_client: None,
                 currency_pairs_change: None,
                 markets_change: None,
+                register_multisig_account: None,
+                scheduled_transfer: None,
+                ics20_rate_limit_change: None,
+                vote_extension_participation_change: None,
+                bond: None,
+                unbond: None,
+                proposal_submission: None,
+                proposal_vote: None,
+                staking_parameters_change: None,
+                governance_parameters_change: None,
             }
             .to_raw(),
         );
@@ -159,6 +171,28 @@ impl<'a> ChainInitializer<'a> {
         self
     }
 
+    /// Enables staking with the given unbonding period and stake per unit of validator power.
+    pub(crate) fn with_staking(
+        mut self,
+        unbonding_period_blocks: u64,
+        stake_per_power: u128,
+    ) -> Self {
+        self.raw_genesis_app_state.staking = Some(RawStakingParameters {
+            unbonding_period_blocks,
+            stake_per_power: Some(stake_per_power.into()),
+        });
+        self
+    }
+
+    /// Enables governance with the given voting period.
+    pub(crate) fn with_governance(mut self, voting_period_blocks: u64) -> Self {
+        self.raw_genesis_app_state.governance = Some(RawGovernanceParameters {
+            voting_period_blocks,
+            proposal_deposit: None,
+        });
+        self
+    }
+
     /// Sets the genesis validators to the given values.
     ///
     /// Their applied names are "Validator 0", "Validator 1", and so on.
@@ -245,6 +279,8 @@ fn dummy_genesis_state() -> RawGenesisAppState {
         ibc_parameters: Some(ibc_parameters),
         allowed_fee_assets: vec![nria().to_string()],
         fees: Some(dummy_genesis_fees().to_raw()),
+        staking: None,
+        governance: None,
     }
 }
 
@@ -268,6 +304,16 @@ fn dummy_genesis_fees() -> GenesisFees {
         recover_ibc_client: Some(FeeComponents::new(16, 1016)),
         currency_pairs_change: Some(FeeComponents::new(17, 1017)),
         markets_change: Some(FeeComponents::new(18, 1018)),
+        register_multisig_account: Some(FeeComponents::new(19, 1019)),
+        scheduled_transfer: Some(FeeComponents::new(20, 1020)),
+        ics20_rate_limit_change: Some(FeeComponents::new(21, 1021)),
+        vote_extension_participation_change: Some(FeeComponents::new(22, 1022)),
+        bond: Some(FeeComponents::new(23, 1023)),
+        unbond: Some(FeeComponents::new(24, 1024)),
+        proposal_submission: Some(FeeComponents::new(25, 1025)),
+        proposal_vote: Some(FeeComponents::new(26, 1026)),
+        staking_parameters_change: Some(FeeComponents::new(27, 1027)),
+        governance_parameters_change: Some(FeeComponents::new(28, 1028)),
     }
 }
 
@@ -293,5 +339,16 @@ fn legacy_fees() -> GenesisFees {
         recover_ibc_client: Some(FeeComponents::new(0, 0)),
         currency_pairs_change: Some(FeeComponents::new(0, 0)),
         markets_change: Some(FeeComponents::new(0, 0)),
+        // NOTE: These actions didn't exist when the legacy genesis was created.
+        register_multisig_account: None,
+        scheduled_transfer: None,
+        ics20_rate_limit_change: None,
+        vote_extension_participation_change: None,
+        bond: None,
+        unbond: None,
+        proposal_submission: None,
+        proposal_vote: None,
+        staking_parameters_change: None,
+        governance_parameters_change: None,
     }
 }
@@ -23,7 +23,10 @@ use super::{
 };
 use crate::{
     checked_transaction::CheckedTransaction,
-    test_utils::nria,
+    test_utils::{
+        astria_address,
+        nria,
+    },
 };
 
 /// A builder for a [`CheckedTransaction`].
@@ -33,6 +36,7 @@ use crate::{
 /// By default, the following values are used:
 ///   * `nonce`: 0
 ///   * `signer`: `SUDO`
+///   * `fee_payer`: `None`, i.e. the signer pays the fees
 ///   * `actions`: a single `RollupDataSubmission` with values:
 ///     * `rollup_id`: `[1; 32]`
 ///     * `data`: `[1, 2, 3]`
@@ -41,6 +45,7 @@ pub(crate) struct CheckedTxBuilder<'a> {
     state: &'a StateDelta<Snapshot>,
     nonce: u32,
     signer: SigningKey,
+    fee_payer: Option<SigningKey>,
     actions: Vec<Action>,
 }
 
@@ -50,6 +55,7 @@ impl<'a> CheckedTxBuilder<'a> {
             state: fixture.state(),
             nonce: 0,
             signer: SUDO.clone(),
+            fee_payer: None,
             actions: vec![],
         }
     }
@@ -64,6 +70,13 @@ impl<'a> CheckedTxBuilder<'a> {
         self
     }
 
+    /// Sets an account other than the signer to pay the transaction's fees, and signs the
+    /// transaction with its key.
+    pub(crate) fn with_fee_payer(mut self, fee_payer: SigningKey) -> Self {
+        self.fee_payer = Some(fee_payer);
+        self
+    }
+
     /// Appends an action to the existing collection of actions.
     pub(crate) fn with_action<T: Into<Action>>(mut self, action: T) -> Self {
         self.actions.push(action.into());
@@ -95,15 +108,25 @@ impl<'a> CheckedTxBuilder<'a> {
             state,
             nonce,
             signer,
+            fee_payer,
             actions,
         } = self;
-        let tx = TransactionBodyBuilder::new()
+        let body = TransactionBodyBuilder::new()
             .nonce(nonce)
             .chain_id("test".to_string())
             .actions(actions)
+            .fee_payer(
+                fee_payer
+                    .as_ref()
+                    .map(|key| astria_address(&key.address_bytes())),
+            )
             .try_build()
-            .unwrap()
-            .sign(&signer);
+            .unwrap();
+        let mut tx = body.sign(&signer);
+        if let Some(fee_payer) = &fee_payer {
+            let fee_payer_signature = tx.body().sign_detached(fee_payer);
+            tx = tx.with_fee_payer_signature(fee_payer_signature).unwrap();
+        }
         let encoded_tx = Bytes::from(tx.into_raw().encode_to_vec());
         Arc::new(CheckedTransaction::new(encoded_tx, state).await.unwrap())
     }
@@ -81,8 +81,12 @@ use crate::{
     },
     fees::StateReadExt as _,
     ibc::host_interface::AstriaHost,
-    mempool::Mempool,
+    mempool::{
+        BuilderQueuePolicy,
+        Mempool,
+    },
     proposal::commitment::generate_rollup_datas_commitment,
+    storage::StateHistory,
     test_utils::nria,
     Metrics,
 };
@@ -109,14 +113,21 @@ impl Fixture {
         let storage = TempStorage::new().await.unwrap().clone();
         let snapshot = storage.latest_snapshot();
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, BuilderQueuePolicy::default(), false);
         let upgrades_handler = upgrades
             .unwrap_or_else(|| UpgradesBuilder::new().set_aspen(Some(1)).build())
             .into();
         let ve_handler = VeHandler::new(None);
-        let app = App::new(snapshot, mempool, upgrades_handler, ve_handler, metrics)
-            .await
-            .unwrap();
+        let app = App::new(
+            snapshot,
+            mempool,
+            upgrades_handler,
+            ve_handler,
+            StateHistory::new(0),
+            metrics,
+        )
+        .await
+        .unwrap();
         Self {
             storage,
             app,
@@ -176,7 +187,7 @@ impl Fixture {
             let txs = match height.cmp(&aspen.activation_height()) {
                 Ordering::Less => {
                     // Use the legacy form of rollup data commitments.
-                    generate_rollup_datas_commitment::<false>(&[], HashMap::new())
+                    generate_rollup_datas_commitment::<false>(&[], &[], HashMap::new())
                         .into_iter()
                         .collect()
                 }
@@ -186,7 +197,7 @@ impl Fixture {
                     let upgrade_change_hashes = DataItem::UpgradeChangeHashes(
                         aspen.changes().map(Change::calculate_hash).collect(),
                     );
-                    generate_rollup_datas_commitment::<true>(&[], HashMap::new())
+                    generate_rollup_datas_commitment::<true>(&[], &[], HashMap::new())
                         .into_iter()
                         .chain(Some(upgrade_change_hashes.encode()))
                         .collect()
@@ -195,7 +206,7 @@ impl Fixture {
                     // Use the new (`DataItem`) form of rollup data commitments. Note the first
                     // block after Aspen doesn't have extended commit info. All
                     // blocks after that should have it.
-                    generate_rollup_datas_commitment::<true>(&[], HashMap::new())
+                    generate_rollup_datas_commitment::<true>(&[], &[], HashMap::new())
                         .into_iter()
                         .collect()
                 }
@@ -367,6 +378,40 @@ impl Fixture {
             Action::MarketsChange(action) => {
                 CheckedAction::new_markets_change(action, tx_signer, self.state()).await
             }
+            Action::RegisterMultisigAccount(action) => {
+                CheckedAction::new_register_multisig_account(action, tx_signer, self.state()).await
+            }
+            Action::ScheduledTransfer(action) => {
+                CheckedAction::new_scheduled_transfer(action, tx_signer, self.state()).await
+            }
+            Action::Ics20RateLimitChange(action) => {
+                CheckedAction::new_ics20_rate_limit_change(action, tx_signer, self.state()).await
+            }
+            Action::VoteExtensionParticipationChange(action) => {
+                CheckedAction::new_vote_extension_participation_change(
+                    action,
+                    tx_signer,
+                    self.state(),
+                )
+                .await
+            }
+            Action::Bond(action) => CheckedAction::new_bond(action, tx_signer, self.state()).await,
+            Action::Unbond(action) => {
+                CheckedAction::new_unbond(action, tx_signer, self.state()).await
+            }
+            Action::ProposalSubmission(action) => {
+                CheckedAction::new_proposal_submission(action, tx_signer, self.state()).await
+            }
+            Action::ProposalVote(action) => {
+                CheckedAction::new_proposal_vote(action, tx_signer, self.state()).await
+            }
+            Action::StakingParametersChange(action) => {
+                CheckedAction::new_staking_parameters_change(action, tx_signer, self.state()).await
+            }
+            Action::GovernanceParametersChange(action) => {
+                CheckedAction::new_governance_parameters_change(action, tx_signer, self.state())
+                    .await
+            }
         }
     }
 
@@ -13,6 +13,10 @@ use astria_core::{
         SigningKey,
         ADDRESS_LENGTH,
     },
+    generated::astria::protocol::genesis::v1::{
+        GovernanceParameters as RawGovernanceParameters,
+        StakingParameters as RawStakingParameters,
+    },
     oracles::price_feed::market_map::v2::{
         Market,
         ProviderConfig,
@@ -29,25 +33,45 @@ use astria_core::{
         RollupId,
     },
     protocol::{
+        genesis::v1::{
+            GovernanceParameters,
+            StakingParameters,
+        },
         price_feed::v1::ExtendedCommitInfoWithCurrencyPairMapping,
         transaction::v1::action::{
+            Bond,
             BridgeLock,
             BridgeSudoChange,
             BridgeTransfer,
             BridgeUnlock,
             CurrencyPairsChange,
+            FeeAssetChange,
+            GovernanceParametersChange,
+            Ics20RateLimit,
+            Ics20RateLimitChange,
             Ics20Withdrawal,
             InitBridgeAccount,
             MarketsChange,
+            ProposalSubmission,
+            ProposalVote,
+            ProposedAction,
             RecoverIbcClient,
+            RegisterMultisigAccount,
+            ReleaseCondition,
             RollupDataSubmission,
+            ScheduledTransfer,
+            StakingParametersChange,
             Transfer,
+            Unbond,
+            VoteExtensionParticipation,
+            VoteExtensionParticipationChange,
         },
     },
     sequencerblock::v1::{
         block::Deposit,
         DataItem,
     },
+    Protobuf as _,
 };
 use bytes::Bytes;
 use ibc_proto::{
@@ -182,7 +206,8 @@ pub(crate) fn transactions_with_extended_commit_info_and_commitments(
     let encoded_extended_commit_info =
         DataItem::ExtendedCommitInfo(extended_commit_info.into_raw().encode_to_vec().into())
             .encode();
-    let commitments = generate_rollup_datas_commitment::<true>(txs, deposits.unwrap_or_default());
+    let commitments =
+        generate_rollup_datas_commitment::<true>(txs, &[], deposits.unwrap_or_default());
     let txs_with_commit_info: Vec<Bytes> = commitments
         .into_iter()
         .chain(std::iter::once(encoded_extended_commit_info))
@@ -396,6 +421,22 @@ pub(crate) fn dummy_recover_ibc_client() -> RecoverIbcClient {
     }
 }
 
+/// Returns a `RegisterMultisigAccount` action with the following dummy values:
+///   * `verification_keys`: the verification keys of `ALICE`, `BOB` and `CAROL`
+///   * `threshold`: 2
+///   * `fee_asset`: nria
+pub(crate) fn dummy_register_multisig_account() -> RegisterMultisigAccount {
+    RegisterMultisigAccount {
+        verification_keys: vec![
+            ALICE.verification_key(),
+            BOB.verification_key(),
+            CAROL.verification_key(),
+        ],
+        threshold: 2,
+        fee_asset: nria().into(),
+    }
+}
+
 /// Returns a `RollupDataSubmission` action with the following dummy values:
 ///   * `rollup_id`: `[1; 32]`
 ///   * `data`: `[1, 2, 3]`
@@ -436,6 +477,123 @@ pub(crate) fn dummy_transfer() -> Transfer {
     }
 }
 
+/// Returns a `ScheduledTransfer` action with the following dummy values:
+///   * `to`: `astria_address(&[50; ADDRESS_LENGTH])`
+///   * `fee_asset`: nria
+///   * `asset`: nria
+///   * `amount`: 100
+///   * `release_at`: height 100
+pub(crate) fn dummy_scheduled_transfer() -> ScheduledTransfer {
+    ScheduledTransfer {
+        to: astria_address(&[50; ADDRESS_LENGTH]),
+        fee_asset: nria().into(),
+        asset: nria().into(),
+        amount: 100,
+        release_at: ReleaseCondition::Height(100),
+    }
+}
+
+/// Returns an `Ics20RateLimitChange` action with the following dummy values:
+///   * `channel`: "channel-0"
+///   * `asset`: nria
+///   * `rate_limit`: max inflow and outflow of 100 over a window of 10 blocks
+pub(crate) fn dummy_ics20_rate_limit_change() -> Ics20RateLimitChange {
+    Ics20RateLimitChange {
+        channel: "channel-0".parse().unwrap(),
+        asset: nria().into(),
+        rate_limit: Some(Ics20RateLimit {
+            max_inflow: 100,
+            max_outflow: 100,
+            window_blocks: 10,
+        }),
+    }
+}
+
+/// Returns a `VoteExtensionParticipationChange` action with the following dummy values:
+///   * `participation`: at most 50 missed vote extensions over a window of 100 blocks, with absent
+///     validators removed
+pub(crate) fn dummy_vote_extension_participation_change() -> VoteExtensionParticipationChange {
+    VoteExtensionParticipationChange {
+        participation: Some(VoteExtensionParticipation {
+            window_blocks: 100,
+            max_missed_blocks: 50,
+            power_reduction_percent: 100,
+        }),
+    }
+}
+
+/// Returns a `Bond` action with the following dummy values:
+///   * `validator`: the verification key of a signing key of all 10s
+///   * `amount`: 100
+///   * `fee_asset`: nria
+pub(crate) fn dummy_bond() -> Bond {
+    Bond {
+        validator: SigningKey::from([10; 32]).verification_key(),
+        amount: 100,
+        fee_asset: nria().into(),
+    }
+}
+
+/// Returns an `Unbond` action with the following dummy values:
+///   * `validator`: the verification key of a signing key of all 10s
+///   * `amount`: 100
+///   * `fee_asset`: nria
+pub(crate) fn dummy_unbond() -> Unbond {
+    Unbond {
+        validator: SigningKey::from([10; 32]).verification_key(),
+        amount: 100,
+        fee_asset: nria().into(),
+    }
+}
+
+/// Returns a `ProposalSubmission` action with the following dummy values:
+///   * `action`: a `FeeAssetChange` adding the asset "test"
+///   * `fee_asset`: nria
+pub(crate) fn dummy_proposal_submission() -> ProposalSubmission {
+    ProposalSubmission {
+        action: ProposedAction::FeeAssetChange(FeeAssetChange::Addition("test".parse().unwrap())),
+        fee_asset: nria().into(),
+    }
+}
+
+/// Returns a `ProposalVote` action with the following dummy values:
+///   * `proposal_id`: 0
+///   * `approve`: true
+///   * `fee_asset`: nria
+pub(crate) fn dummy_proposal_vote() -> ProposalVote {
+    ProposalVote {
+        proposal_id: 0,
+        approve: true,
+        fee_asset: nria().into(),
+    }
+}
+
+/// Returns a `StakingParametersChange` action with the following dummy values:
+///   * `unbonding_period_blocks`: 100
+///   * `stake_per_power`: 1,000,000
+pub(crate) fn dummy_staking_parameters_change() -> StakingParametersChange {
+    StakingParametersChange {
+        parameters: StakingParameters::try_from_raw(RawStakingParameters {
+            unbonding_period_blocks: 100,
+            stake_per_power: Some(1_000_000_u128.into()),
+        })
+        .unwrap(),
+    }
+}
+
+/// Returns a `GovernanceParametersChange` action with the following dummy values:
+///   * `voting_period_blocks`: 100
+///   * `proposal_deposit`: 1,000
+pub(crate) fn dummy_governance_parameters_change() -> GovernanceParametersChange {
+    GovernanceParametersChange {
+        parameters: GovernanceParameters::try_from_raw(RawGovernanceParameters {
+            voting_period_blocks: 100,
+            proposal_deposit: Some(1_000_u128.into()),
+        })
+        .unwrap(),
+    }
+}
+
 pub(crate) fn nria() -> TracePrefixed {
     "nria".parse().unwrap()
 }
@@ -265,6 +265,33 @@ impl UpgradesHandler {
         Ok(Some(params))
     }
 
+    /// Fetches the consensus params at `block_height` from CometBFT and writes them to `state` if
+    /// they are missing from state restored from a state sync snapshot.
+    ///
+    /// Returns `true` if the consensus params were written.
+    pub(crate) async fn restore_missing_consensus_params<S: StateWriteExt>(
+        &self,
+        mut state: S,
+        block_height: tendermint::block::Height,
+    ) -> Result<bool> {
+        if !state
+            .is_consensus_params_missing()
+            .await
+            .wrap_err("failed to read whether consensus params are missing from storage")?
+        {
+            return Ok(false);
+        }
+        let params = self
+            .get_consensus_params_from_cometbft(block_height.value())
+            .await
+            .wrap_err("failed to get consensus params from cometbft")?;
+        info!(%block_height, "restored consensus params missing from state sync snapshot");
+        state
+            .put_consensus_params(params)
+            .wrap_err("failed to put consensus params to storage")?;
+        Ok(true)
+    }
+
     async fn get_consensus_params<S: StateReadExt>(
         &self,
         state: S,